@@ -358,12 +358,14 @@ fn bench_intelligence_serialization(c: &mut Criterion) {
         running_effectiveness: None,
         stride_efficiency: None,
         ground_contact_balance: None,
+        grade_adjusted_pace: None,
         estimated_recovery_time: Some(24.0),
         training_load: Some(75.0),
         aerobic_contribution: Some(85.0),
         temperature_stress: Some(1.05),
         altitude_adjustment: Some(0.98),
         custom_metrics: HashMap::new(),
+        custom_metric_units: HashMap::new(),
     };
 
     let serialized_metrics = serde_json::to_vec(&metrics).unwrap();
@@ -138,7 +138,14 @@ impl FitnessConfig {
         Self::load_env_sport_type(sport_types, "EBIKERIDE", "ebike_ride");
         Self::load_env_sport_type(sport_types, "MOUNTAINBIKERIDE", "mountain_bike");
         Self::load_env_sport_type(sport_types, "GRAVELRIDE", "gravel_ride");
-        // Add more sport types as needed from environment variables
+
+        // Free-form aliases: `SPORT_TYPE_ALIASES=Zwift=virtual_ride,Paddle=paddleboarding`
+        if let Ok(aliases) = env::var("SPORT_TYPE_ALIASES") {
+            let pairs = aliases.split(',').filter_map(|pair| pair.split_once('='));
+            for (alias, internal_name) in pairs {
+                sport_types.insert(alias.trim().to_owned(), internal_name.trim().to_owned());
+            }
+        }
     }
 
     /// Load a single sport type mapping from environment variable
@@ -143,6 +143,8 @@ pub mod timeouts {
     pub const SSE_CONNECTION_TIMEOUT_SECS: u64 = 600; // 10 minutes
     /// OAuth session cookie Max-Age in seconds (matches JWT expiration)
     pub const SESSION_COOKIE_MAX_AGE_SECS: u64 = 86400; // 24 hours
+    /// Default tool execution timeout in seconds (catalog entries may override it)
+    pub const TOOL_EXECUTION_TIMEOUT_SECS: u64 = 120;
 }
 
 /// Cryptographic constants
@@ -155,7 +157,7 @@ pub mod crypto {
 
 /// Security configurations
 pub mod security {
-    /// CORS allowed origins
+    /// CORS allowed origins when `PIERRE_CORS_ORIGINS` is unset (any origin, without credentials)
     pub const CORS_ALLOWED_ORIGINS: &str = "*";
 }
 
@@ -629,6 +631,8 @@ pub mod oauth_rate_limiting {
     pub const TOKEN_RPM: u32 = 30;
     /// Registration endpoint rate limit (requests per minute)
     pub const REGISTER_RPM: u32 = 10;
+    /// Introspection endpoint rate limit (requests per minute)
+    pub const INTROSPECT_RPM: u32 = 60;
     /// Rate limit window duration in seconds
     pub const WINDOW_SECS: u64 = 60;
     /// Rate limiter cleanup threshold
@@ -24,3 +24,15 @@ pub const DAYS_PER_YEAR: f64 = 365.0;
 
 /// Milliseconds per second
 pub const MS_PER_SECOND: f64 = 1000.0;
+
+/// Meters per statute mile
+pub const METERS_PER_MILE: f64 = 1609.344;
+
+/// Meters per foot
+pub const METERS_PER_FOOT: f64 = 0.3048;
+
+/// Kilograms per pound
+pub const KG_PER_POUND: f64 = 0.453_592_37;
+
+/// Seconds per hour
+pub const SECONDS_PER_HOUR: f64 = 3600.0;
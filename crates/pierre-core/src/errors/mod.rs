@@ -39,6 +39,9 @@ use chrono::{ParseError as ChronoParseError, Utc};
 #[cfg(feature = "crypto-errors")]
 use ring::error::Unspecified as RingUnspecified;
 
+use crate::constants::errors::{
+    ERROR_INTERNAL_ERROR, ERROR_INVALID_PARAMS, ERROR_METHOD_NOT_FOUND,
+};
 use crate::constants::http_status::{
     BAD_GATEWAY, BAD_REQUEST, CONFLICT, FORBIDDEN, INTERNAL_SERVER_ERROR, NOT_FOUND,
     SERVICE_UNAVAILABLE, TOO_MANY_REQUESTS, UNAUTHORIZED,
@@ -80,6 +83,8 @@ pub enum ErrorCode {
     InvalidFormat,
     /// Value is outside acceptable range
     ValueOutOfRange,
+    /// Pagination cursor was tampered with or issued for a different query
+    InvalidCursor,
 
     // Resource Management
     /// Requested resource was not found
@@ -129,7 +134,8 @@ impl ErrorCode {
             Self::InvalidInput
             | Self::MissingRequiredField
             | Self::InvalidFormat
-            | Self::ValueOutOfRange => BAD_REQUEST,
+            | Self::ValueOutOfRange
+            | Self::InvalidCursor => BAD_REQUEST,
 
             // 401 Unauthorized - Authentication issues (missing or invalid credentials)
             Self::AuthRequired | Self::AuthInvalid => UNAUTHORIZED,
@@ -180,6 +186,7 @@ impl ErrorCode {
             Self::MissingRequiredField => "A required field is missing from the request",
             Self::InvalidFormat => "The data format is invalid",
             Self::ValueOutOfRange => "The provided value is outside the acceptable range",
+            Self::InvalidCursor => "The pagination cursor is invalid for this request",
             Self::ResourceNotFound => "The requested resource was not found",
             Self::ResourceAlreadyExists => "A resource with this identifier already exists",
             Self::ResourceLocked => "The resource is currently locked and cannot be modified",
@@ -197,6 +204,33 @@ impl ErrorCode {
             Self::SerializationError => "Data serialization/deserialization failed",
         }
     }
+
+    /// Whether a request failing with this error may succeed when retried unchanged
+    #[must_use]
+    pub const fn is_retriable(self) -> bool {
+        matches!(
+            self,
+            Self::RateLimitExceeded
+                | Self::ResourceLocked
+                | Self::ResourceUnavailable
+                | Self::ExternalServiceUnavailable
+                | Self::ExternalRateLimited
+        )
+    }
+
+    /// Get the JSON-RPC error code used when this error ends an MCP or A2A request
+    #[must_use]
+    pub const fn jsonrpc_code(self) -> i32 {
+        match self {
+            Self::InvalidInput
+            | Self::MissingRequiredField
+            | Self::InvalidFormat
+            | Self::ValueOutOfRange
+            | Self::InvalidCursor => ERROR_INVALID_PARAMS,
+            Self::ResourceNotFound => ERROR_METHOD_NOT_FOUND,
+            _ => ERROR_INTERNAL_ERROR,
+        }
+    }
 }
 
 // Simple serialization - just use the debug representation
@@ -227,6 +261,7 @@ impl<'de> Deserialize<'de> for ErrorCode {
             "MissingRequiredField" => Ok(Self::MissingRequiredField),
             "InvalidFormat" => Ok(Self::InvalidFormat),
             "ValueOutOfRange" => Ok(Self::ValueOutOfRange),
+            "InvalidCursor" => Ok(Self::InvalidCursor),
             "ResourceNotFound" => Ok(Self::ResourceNotFound),
             "ResourceAlreadyExists" => Ok(Self::ResourceAlreadyExists),
             "ResourceLocked" => Ok(Self::ResourceLocked),
@@ -256,6 +291,8 @@ pub struct AppError {
     pub message: String,
     /// Optional request `ID` for tracing
     pub request_id: Option<String>,
+    /// Seconds the client should wait before retrying, when known
+    pub retry_after_secs: Option<u64>,
 }
 
 impl AppError {
@@ -266,6 +303,7 @@ impl AppError {
             code,
             message: message.into(),
             request_id: None,
+            retry_after_secs: None,
         }
     }
 
@@ -276,12 +314,27 @@ impl AppError {
         self
     }
 
+    /// Tell the client how long to wait before retrying
+    #[must_use]
+    pub const fn with_retry_after(mut self, retry_after_secs: u64) -> Self {
+        self.retry_after_secs = Some(retry_after_secs);
+        self
+    }
+
     /// Get the `HTTP` status code for this error
     #[must_use]
     pub const fn http_status(&self) -> u16 {
         self.code.http_status()
     }
 
+    /// Whether the request may succeed when retried unchanged
+    ///
+    /// True for retriable error codes and for any error carrying a retry-after hint.
+    #[must_use]
+    pub const fn is_retriable(&self) -> bool {
+        self.code.is_retriable() || self.retry_after_secs.is_some()
+    }
+
     /// Get sanitized message safe for client exposure
     /// Internal error details are replaced with generic messages
     #[must_use]
@@ -293,6 +346,7 @@ impl AppError {
             | ErrorCode::MissingRequiredField
             | ErrorCode::InvalidFormat
             | ErrorCode::ValueOutOfRange
+            | ErrorCode::InvalidCursor
             | ErrorCode::RateLimitExceeded
             | ErrorCode::QuotaExceeded
             | ErrorCode::ExternalRateLimited => self.message.clone(),
@@ -365,6 +419,48 @@ impl From<AppError> for ErrorResponse {
     }
 }
 
+/// Machine-readable error details carried by every MCP and A2A error response
+///
+/// JSON-RPC error codes are coarse and differ between protocols, so protocol
+/// errors also carry these details in their `data` field. Clients branch on
+/// `code` rather than matching on the message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorDetails {
+    /// Stable error code identifying the type of error
+    pub code: ErrorCode,
+    /// Human-readable error message
+    pub message: String,
+    /// Whether the request may succeed when retried unchanged
+    pub retriable: bool,
+    /// Seconds to wait before retrying, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_secs: Option<u64>,
+}
+
+impl ErrorDetails {
+    /// Details for an error with the given code and message
+    #[must_use]
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            retriable: code.is_retriable(),
+            retry_after_secs: None,
+        }
+    }
+}
+
+impl From<&AppError> for ErrorDetails {
+    fn from(error: &AppError) -> Self {
+        Self {
+            code: error.code,
+            message: error.to_string(),
+            retriable: error.is_retriable(),
+            retry_after_secs: error.retry_after_secs,
+        }
+    }
+}
+
 /// Convenience functions for creating common errors
 impl AppError {
     /// Authentication required
@@ -410,6 +506,12 @@ impl AppError {
         Self::new(ErrorCode::InvalidInput, message)
     }
 
+    /// Invalid pagination cursor
+    #[must_use]
+    pub fn invalid_cursor(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidCursor, message)
+    }
+
     /// Internal server error
     #[must_use]
     pub fn internal(message: impl Into<String>) -> Self {
@@ -652,7 +754,8 @@ impl From<ProviderError> for AppError {
             } => Self::external_service(
                 &provider,
                 format!("Rate limit exceeded ({limit_type}): retry after {retry_after_secs}s"),
-            ),
+            )
+            .with_retry_after(retry_after_secs),
             ProviderError::AuthenticationFailed { provider, reason } => {
                 Self::auth_invalid(format!("{provider} authentication failed: {reason}"))
             }
@@ -669,6 +772,14 @@ impl From<ProviderError> for AppError {
                 field,
                 reason,
             } => Self::invalid_input(format!("{provider} invalid data in '{field}': {reason}")),
+            ProviderError::MalformedResponse {
+                provider,
+                field,
+                reason,
+            } => Self::external_service(
+                &provider,
+                format!("Malformed response field '{field}': {reason}"),
+            ),
             ProviderError::NetworkError(details) => {
                 Self::external_service("provider", format!("Network error: {details}"))
             }
@@ -678,6 +789,13 @@ impl From<ProviderError> for AppError {
             ProviderError::UnsupportedFeature { provider, feature } => {
                 Self::invalid_input(format!("{provider} does not support {feature}"))
             }
+            ProviderError::MissingScope { provider, scopes } => Self::new(
+                ErrorCode::PermissionDenied,
+                format!(
+                    "{provider} access is missing scope {}; reconnect to grant it",
+                    scopes.join(", ")
+                ),
+            ),
             ProviderError::HttpError {
                 provider,
                 status,
@@ -710,7 +828,8 @@ impl From<ProviderError> for AppError {
             } => Self::external_service(
                 &provider,
                 format!("Service temporarily unavailable: retry after {retry_after_secs}s"),
-            ),
+            )
+            .with_retry_after(retry_after_secs),
         }
     }
 }
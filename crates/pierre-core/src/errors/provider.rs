@@ -73,6 +73,17 @@ pub enum ProviderError {
         reason: String,
     },
 
+    /// Malformed or partial record rejected in strict validation mode
+    #[error("Malformed response from {provider}: {field} - {reason}")]
+    MalformedResponse {
+        /// Name of the fitness provider
+        provider: String,
+        /// Field that failed validation
+        field: String,
+        /// What was wrong with the field
+        reason: String,
+    },
+
     /// Network error
     #[error("Network error: {0}")]
     NetworkError(String),
@@ -95,6 +106,15 @@ pub enum ProviderError {
         feature: String,
     },
 
+    /// The user's grant lacks a scope the request needs
+    #[error("Provider {provider} access is missing scope {}", scopes.join(", "))]
+    MissingScope {
+        /// Name of the fitness provider
+        provider: String,
+        /// Scopes that must be granted before retrying
+        scopes: Vec<String>,
+    },
+
     /// HTTP client error
     #[error("HTTP client error for {provider}: {status}")]
     HttpError {
@@ -177,8 +197,10 @@ impl ProviderError {
             | Self::TokenRefreshFailed { .. }
             | Self::NotFound { .. }
             | Self::InvalidData { .. }
+            | Self::MalformedResponse { .. }
             | Self::ConfigurationError { .. }
             | Self::UnsupportedFeature { .. }
+            | Self::MissingScope { .. }
             | Self::ParseError { .. }
             | Self::QuotaExceeded { .. } => false,
         }
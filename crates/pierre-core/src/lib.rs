@@ -45,3 +45,6 @@ pub mod intelligence;
 
 /// Admin authentication and authorization types
 pub mod admin;
+
+/// In-process metrics registry with Prometheus text exposition
+pub mod metrics;
@@ -0,0 +1,396 @@
+// ABOUTME: Lightweight in-process metrics registry rendered in the Prometheus text exposition format
+// ABOUTME: Tracks tool requests, provider API calls, rate limits, circuit breakers, and retry budgets
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Metrics
+//!
+//! A minimal hand-rolled registry so the server can be scraped by Prometheus
+//! without pulling a metrics stack into every build. Counters, gauges, and
+//! histograms are keyed by a fixed set of metric families; label values are supplied by
+//! the call sites and escaped on render.
+//!
+//! Instrumented code records into [`MetricsRegistry::global`], which the
+//! `/metrics` route renders with [`MetricsRegistry::render`].
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+/// Counter of tool executions by tool, tenant, and outcome
+pub const TOOL_REQUESTS_TOTAL: &str = "pierre_tool_requests_total";
+/// Histogram of tool execution latency by tool
+pub const TOOL_REQUEST_DURATION_SECONDS: &str = "pierre_tool_request_duration_seconds";
+/// Counter of provider API calls by provider and outcome
+pub const PROVIDER_API_CALLS_TOTAL: &str = "pierre_provider_api_calls_total";
+/// Histogram of provider API latency by provider
+pub const PROVIDER_API_DURATION_SECONDS: &str = "pierre_provider_api_duration_seconds";
+/// Counter of requests rejected for exceeding their rate limit
+pub const RATE_LIMIT_REJECTIONS_TOTAL: &str = "pierre_rate_limit_rejections_total";
+/// Counter of circuit breaker state transitions by provider and new state
+pub const CIRCUIT_BREAKER_TRANSITIONS_TOTAL: &str = "pierre_circuit_breaker_transitions_total";
+/// Gauge of retry tokens left by tenant and provider
+pub const RETRY_BUDGET_REMAINING: &str = "pierre_retry_budget_remaining";
+/// Counter of retries skipped because the retry budget was empty, by tenant and provider
+pub const RETRY_BUDGET_EXHAUSTED_TOTAL: &str = "pierre_retry_budget_exhausted_total";
+
+/// Latency bucket upper bounds in seconds
+const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Label value used when a request has no tenant scope
+const NO_TENANT: &str = "none";
+
+/// Kind of a metric family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+/// Every family the registry exposes, in render order
+const FAMILIES: [(&str, MetricKind, &str); 8] = [
+    (
+        TOOL_REQUESTS_TOTAL,
+        MetricKind::Counter,
+        "Tool executions by tool, tenant, and outcome",
+    ),
+    (
+        TOOL_REQUEST_DURATION_SECONDS,
+        MetricKind::Histogram,
+        "Tool execution latency in seconds",
+    ),
+    (
+        PROVIDER_API_CALLS_TOTAL,
+        MetricKind::Counter,
+        "Fitness provider API calls by provider and outcome",
+    ),
+    (
+        PROVIDER_API_DURATION_SECONDS,
+        MetricKind::Histogram,
+        "Fitness provider API latency in seconds",
+    ),
+    (
+        RATE_LIMIT_REJECTIONS_TOTAL,
+        MetricKind::Counter,
+        "Requests rejected for exceeding their rate limit",
+    ),
+    (
+        CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+        MetricKind::Counter,
+        "Provider circuit breaker state transitions",
+    ),
+    (
+        RETRY_BUDGET_REMAINING,
+        MetricKind::Gauge,
+        "Retry tokens left per tenant and provider",
+    ),
+    (
+        RETRY_BUDGET_EXHAUSTED_TOTAL,
+        MetricKind::Counter,
+        "Retries skipped because the tenant's retry budget was empty",
+    ),
+];
+
+/// Label pairs identifying one series within a family
+type Labels = Vec<(&'static str, String)>;
+
+/// Cumulative bucket counts, sum, and count of one histogram series
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if value <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Series {
+    counters: BTreeMap<(&'static str, Labels), u64>,
+    gauges: BTreeMap<(&'static str, Labels), f64>,
+    histograms: BTreeMap<(&'static str, Labels), Histogram>,
+}
+
+/// Thread-safe registry of counters and histograms
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    series: Mutex<Series>,
+}
+
+impl MetricsRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process-wide registry used by instrumented code and the `/metrics` route
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<MetricsRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Record one tool execution and its latency
+    pub fn record_tool_request(
+        &self,
+        tool: &str,
+        tenant_id: Option<Uuid>,
+        success: bool,
+        duration: Duration,
+    ) {
+        let tenant = tenant_id.map_or_else(|| NO_TENANT.to_owned(), |id| id.to_string());
+        self.increment(
+            TOOL_REQUESTS_TOTAL,
+            vec![
+                ("tool", tool.to_owned()),
+                ("tenant", tenant),
+                ("outcome", outcome(success).to_owned()),
+            ],
+        );
+        self.observe(
+            TOOL_REQUEST_DURATION_SECONDS,
+            vec![("tool", tool.to_owned())],
+            duration,
+        );
+    }
+
+    /// Record one provider API call and its latency
+    pub fn record_provider_call(&self, provider: &str, success: bool, duration: Duration) {
+        self.increment(
+            PROVIDER_API_CALLS_TOTAL,
+            vec![
+                ("provider", provider.to_owned()),
+                ("outcome", outcome(success).to_owned()),
+            ],
+        );
+        self.observe(
+            PROVIDER_API_DURATION_SECONDS,
+            vec![("provider", provider.to_owned())],
+            duration,
+        );
+    }
+
+    /// Record a request rejected by rate limiting for the given auth method
+    pub fn record_rate_limit_rejection(&self, auth_method: &str) {
+        self.increment(
+            RATE_LIMIT_REJECTIONS_TOTAL,
+            vec![("auth_method", auth_method.to_owned())],
+        );
+    }
+
+    /// Record a provider circuit breaker entering `state`
+    pub fn record_circuit_transition(&self, provider: &str, state: &str) {
+        self.increment(
+            CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+            vec![
+                ("provider", provider.to_owned()),
+                ("state", state.to_owned()),
+            ],
+        );
+    }
+
+    /// Set the retry tokens left for `provider` in `tenant_id`'s budget
+    pub fn record_retry_budget(&self, tenant_id: Option<Uuid>, provider: &str, remaining: f64) {
+        self.lock().gauges.insert(
+            (RETRY_BUDGET_REMAINING, budget_labels(tenant_id, provider)),
+            remaining,
+        );
+    }
+
+    /// Record a retry skipped because `tenant_id`'s budget for `provider` was empty
+    pub fn record_retry_budget_exhausted(&self, tenant_id: Option<Uuid>, provider: &str) {
+        self.increment(
+            RETRY_BUDGET_EXHAUSTED_TOTAL,
+            budget_labels(tenant_id, provider),
+        );
+    }
+
+    /// Sum of the counter series in `name` carrying all of `labels`
+    #[must_use]
+    pub fn counter_value(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        self.lock()
+            .counters
+            .iter()
+            .filter(|((family, series), _)| *family == name && labels_match(series, labels))
+            .map(|(_, value)| value)
+            .sum()
+    }
+
+    /// Current value of the gauge series in `name` carrying all of `labels`
+    #[must_use]
+    pub fn gauge_value(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.lock()
+            .gauges
+            .iter()
+            .find(|((family, series), _)| *family == name && labels_match(series, labels))
+            .map(|(_, value)| *value)
+    }
+
+    /// Render every family in the Prometheus text exposition format (version 0.0.4)
+    #[must_use]
+    pub fn render(&self) -> String {
+        let series = self.lock();
+        let mut out = String::new();
+        for (name, kind, help) in FAMILIES {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {}", kind.as_str());
+            match kind {
+                MetricKind::Counter => {
+                    for ((_, labels), value) in series
+                        .counters
+                        .iter()
+                        .filter(|((family, _), _)| *family == name)
+                    {
+                        let _ = writeln!(out, "{name}{} {value}", format_labels(labels, None));
+                    }
+                }
+                MetricKind::Gauge => {
+                    for ((_, labels), value) in series
+                        .gauges
+                        .iter()
+                        .filter(|((family, _), _)| *family == name)
+                    {
+                        let _ = writeln!(out, "{name}{} {value}", format_labels(labels, None));
+                    }
+                }
+                MetricKind::Histogram => {
+                    for ((_, labels), histogram) in series
+                        .histograms
+                        .iter()
+                        .filter(|((family, _), _)| *family == name)
+                    {
+                        render_histogram(&mut out, name, labels, histogram);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    fn increment(&self, name: &'static str, labels: Labels) {
+        *self.lock().counters.entry((name, labels)).or_default() += 1;
+    }
+
+    fn observe(&self, name: &'static str, labels: Labels, duration: Duration) {
+        self.lock()
+            .histograms
+            .entry((name, labels))
+            .or_default()
+            .observe(duration.as_secs_f64());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Series> {
+        // A panic while holding the lock cannot leave counters half-written
+        self.series.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+const fn outcome(success: bool) -> &'static str {
+    if success {
+        "success"
+    } else {
+        "error"
+    }
+}
+
+fn budget_labels(tenant_id: Option<Uuid>, provider: &str) -> Labels {
+    vec![
+        (
+            "tenant",
+            tenant_id.map_or_else(|| NO_TENANT.to_owned(), |id| id.to_string()),
+        ),
+        ("provider", provider.to_owned()),
+    ]
+}
+
+fn labels_match(series: &[(&'static str, String)], wanted: &[(&str, &str)]) -> bool {
+    wanted.iter().all(|(key, value)| {
+        series
+            .iter()
+            .any(|(series_key, series_value)| series_key == key && series_value == value)
+    })
+}
+
+fn render_histogram(
+    out: &mut String,
+    name: &str,
+    labels: &[(&'static str, String)],
+    histogram: &Histogram,
+) {
+    for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+        let le = bound.to_string();
+        let _ = writeln!(
+            out,
+            "{name}_bucket{} {count}",
+            format_labels(labels, Some(&le))
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{name}_bucket{} {}",
+        format_labels(labels, Some("+Inf")),
+        histogram.count
+    );
+    let _ = writeln!(
+        out,
+        "{name}_sum{} {}",
+        format_labels(labels, None),
+        histogram.sum
+    );
+    let _ = writeln!(
+        out,
+        "{name}_count{} {}",
+        format_labels(labels, None),
+        histogram.count
+    );
+}
+
+fn format_labels(labels: &[(&'static str, String)], le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{le}\""));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
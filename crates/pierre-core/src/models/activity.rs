@@ -4,10 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::SportType;
+use super::{PauseDetectionConfig, SportType, TimeSeries};
 
 /// Heart rate zone data for an activity
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +69,9 @@ pub struct TimeSeriesData {
 pub struct SegmentEffort {
     /// Unique identifier for the segment effort
     pub id: String,
+    /// Identifier of the segment this effort was recorded on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_id: Option<String>,
     /// Name of the segment
     pub name: String,
     /// Elapsed time on segment in seconds
@@ -150,8 +153,17 @@ pub struct Activity {
     sport_type: SportType,
     /// When the activity started (UTC)
     start_date: DateTime<Utc>,
-    /// Total duration of the activity in seconds
+    /// IANA time zone where the activity started (e.g., "Asia/Tokyo")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_timezone: Option<String>,
+    /// Offset of the local start time from UTC in seconds (e.g., 32400 for UTC+9)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    utc_offset_seconds: Option<i32>,
+    /// Total duration of the activity in seconds (elapsed time, including pauses)
     duration_seconds: u64,
+    /// Time spent moving in seconds, excluding pauses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moving_time_seconds: Option<u64>,
     /// Total distance covered in meters (if applicable)
     #[serde(skip_serializing_if = "Option::is_none")]
     distance_meters: Option<f64>,
@@ -259,6 +271,12 @@ pub struct Activity {
     /// Suffer score or relative effort rating
     #[serde(skip_serializing_if = "Option::is_none")]
     suffer_score: Option<u32>,
+    /// Device-reported aerobic training effect (0.0-5.0 scale)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aerobic_training_effect: Option<f32>,
+    /// Device-reported anaerobic training effect (0.0-5.0 scale)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    anaerobic_training_effect: Option<f32>,
 
     // Detailed Time-Series Data
     /// Time-series data for advanced analysis
@@ -329,12 +347,51 @@ impl Activity {
         self.start_date
     }
 
+    /// Returns the IANA time zone where the activity started
+    #[must_use]
+    pub fn start_timezone(&self) -> Option<&str> {
+        self.start_timezone.as_deref()
+    }
+
+    /// Returns the offset of the local start time from UTC in seconds
+    #[must_use]
+    pub const fn utc_offset_seconds(&self) -> Option<i32> {
+        self.utc_offset_seconds
+    }
+
+    /// Returns the start time in the activity's local time zone, when the UTC offset is known
+    #[must_use]
+    pub fn local_start_date(&self) -> Option<DateTime<FixedOffset>> {
+        let offset = FixedOffset::east_opt(self.utc_offset_seconds?)?;
+        Some(self.start_date.with_timezone(&offset))
+    }
+
     /// Returns the total duration of the activity in seconds
     #[must_use]
     pub const fn duration_seconds(&self) -> u64 {
         self.duration_seconds
     }
 
+    /// Returns the wall-clock time from start to finish in seconds, including pauses
+    ///
+    /// Same as [`Self::duration_seconds`].
+    #[must_use]
+    pub const fn elapsed_time_seconds(&self) -> u64 {
+        self.duration_seconds
+    }
+
+    /// Returns the time spent moving in seconds, excluding pauses
+    ///
+    /// Falls back to the elapsed time when neither the provider nor the speed
+    /// stream gave a moving time. Pace and speed metrics should use this.
+    #[must_use]
+    pub fn moving_time_seconds(&self) -> u64 {
+        self.moving_time_seconds
+            .map_or(self.duration_seconds, |moving| {
+                moving.min(self.duration_seconds)
+            })
+    }
+
     /// Returns the total distance covered in meters (if applicable)
     #[must_use]
     pub const fn distance_meters(&self) -> Option<f64> {
@@ -521,6 +578,18 @@ impl Activity {
         self.suffer_score
     }
 
+    /// Returns the device-reported aerobic training effect
+    #[must_use]
+    pub const fn aerobic_training_effect(&self) -> Option<f32> {
+        self.aerobic_training_effect
+    }
+
+    /// Returns the device-reported anaerobic training effect
+    #[must_use]
+    pub const fn anaerobic_training_effect(&self) -> Option<f32> {
+        self.anaerobic_training_effect
+    }
+
     /// Returns the time-series data for advanced analysis
     #[must_use]
     pub const fn time_series_data(&self) -> Option<&TimeSeriesData> {
@@ -586,6 +655,115 @@ impl Activity {
     pub fn provider(&self) -> &str {
         &self.provider
     }
+
+    /// Fill every optional metric that is `None` on this activity from `other`
+    ///
+    /// Identity fields (id, name, sport type, start date, duration, moving time, provider)
+    /// are kept.
+    /// Used when merging duplicate records of the same session from different providers.
+    pub fn fill_missing_from(&mut self, other: &Self) {
+        macro_rules! fill_copy {
+            ($($field:ident),* $(,)?) => {
+                $(self.$field = self.$field.or(other.$field);)*
+            };
+        }
+        macro_rules! fill_clone {
+            ($($field:ident),* $(,)?) => {
+                $(if self.$field.is_none() {
+                    self.$field.clone_from(&other.$field);
+                })*
+            };
+        }
+
+        fill_copy!(
+            distance_meters,
+            elevation_gain,
+            average_heart_rate,
+            max_heart_rate,
+            average_speed,
+            max_speed,
+            calories,
+            steps,
+            average_power,
+            max_power,
+            normalized_power,
+            ftp,
+            average_cadence,
+            max_cadence,
+            hrv_score,
+            recovery_heart_rate,
+            temperature,
+            humidity,
+            average_altitude,
+            wind_speed,
+            ground_contact_time,
+            vertical_oscillation,
+            stride_length,
+            running_power,
+            breathing_rate,
+            spo2,
+            training_stress_score,
+            intensity_factor,
+            suffer_score,
+            aerobic_training_effect,
+            anaerobic_training_effect,
+            start_latitude,
+            start_longitude,
+            workout_type,
+            utc_offset_seconds,
+        );
+        fill_clone!(
+            start_timezone,
+            heart_rate_zones,
+            power_zones,
+            time_series_data,
+            city,
+            region,
+            country,
+            trail_name,
+            sport_type_detail,
+            segment_efforts,
+        );
+    }
+
+    /// Use a separately fetched heart rate series as this activity's heart rate stream
+    ///
+    /// Does nothing when the activity already has a heart rate stream, or has other
+    /// streams on timestamps the series cannot be aligned with. Missing average and
+    /// max heart rate are filled from the series. Returns whether it was attached.
+    pub fn attach_heart_rate_series(&mut self, series: &TimeSeries<u16>) -> bool {
+        if series.is_empty() {
+            return false;
+        }
+        let heart_rate: Vec<u32> = series.values.iter().copied().map(u32::from).collect();
+        let max = heart_rate.iter().copied().max();
+        let sum: u64 = heart_rate.iter().copied().map(u64::from).sum();
+        let average = u64::try_from(heart_rate.len())
+            .ok()
+            .and_then(|count| u32::try_from(sum / count).ok());
+
+        match &mut self.time_series_data {
+            Some(data) if data.heart_rate.is_some() => return false,
+            Some(data) if data.timestamps == series.offsets => data.heart_rate = Some(heart_rate),
+            Some(_) => return false,
+            None => {
+                self.time_series_data = Some(TimeSeriesData {
+                    timestamps: series.offsets.clone(),
+                    heart_rate: Some(heart_rate),
+                    power: None,
+                    cadence: None,
+                    speed: None,
+                    altitude: None,
+                    temperature: None,
+                    gps_coordinates: None,
+                });
+            }
+        }
+
+        self.max_heart_rate = self.max_heart_rate.or(max);
+        self.average_heart_rate = self.average_heart_rate.or(average);
+        true
+    }
 }
 
 impl Default for Activity {
@@ -595,7 +773,10 @@ impl Default for Activity {
             name: "Test Activity".into(),
             sport_type: SportType::Run,
             start_date: chrono::Utc::now(),
-            duration_seconds: 1800,        // 30 minutes
+            start_timezone: None,
+            utc_offset_seconds: None,
+            duration_seconds: 1800, // 30 minutes
+            moving_time_seconds: None,
             distance_meters: Some(5000.0), // 5km
             elevation_gain: Some(100.0),
             average_heart_rate: Some(150),
@@ -629,6 +810,8 @@ impl Default for Activity {
             training_stress_score: None,
             intensity_factor: None,
             suffer_score: None,
+            aerobic_training_effect: None,
+            anaerobic_training_effect: None,
             time_series_data: None,
 
             start_latitude: None,
@@ -693,7 +876,10 @@ impl ActivityBuilder {
                 name: name.into(),
                 sport_type,
                 start_date,
+                start_timezone: None,
+                utc_offset_seconds: None,
                 duration_seconds,
+                moving_time_seconds: None,
                 provider: provider.into(),
                 distance_meters: None,
                 elevation_gain: None,
@@ -726,6 +912,8 @@ impl ActivityBuilder {
                 training_stress_score: None,
                 intensity_factor: None,
                 suffer_score: None,
+                aerobic_training_effect: None,
+                anaerobic_training_effect: None,
                 time_series_data: None,
                 start_latitude: None,
                 start_longitude: None,
@@ -740,6 +928,34 @@ impl ActivityBuilder {
         }
     }
 
+    /// Sets the IANA time zone where the activity started
+    #[must_use]
+    pub fn start_timezone(mut self, value: String) -> Self {
+        self.activity.start_timezone = Some(value);
+        self
+    }
+
+    /// Sets the IANA time zone where the activity started (optional)
+    #[must_use]
+    pub fn start_timezone_opt(mut self, value: Option<String>) -> Self {
+        self.activity.start_timezone = value;
+        self
+    }
+
+    /// Sets the UTC offset of the local start time in seconds
+    #[must_use]
+    pub const fn utc_offset_seconds(mut self, value: i32) -> Self {
+        self.activity.utc_offset_seconds = Some(value);
+        self
+    }
+
+    /// Sets the UTC offset of the local start time in seconds (optional)
+    #[must_use]
+    pub const fn utc_offset_seconds_opt(mut self, value: Option<i32>) -> Self {
+        self.activity.utc_offset_seconds = value;
+        self
+    }
+
     /// Sets the distance in meters
     #[must_use]
     pub const fn distance_meters(mut self, value: f64) -> Self {
@@ -1174,6 +1390,51 @@ impl ActivityBuilder {
         self
     }
 
+    /// Sets the aerobic training effect
+    #[must_use]
+    pub const fn aerobic_training_effect(mut self, value: f32) -> Self {
+        self.activity.aerobic_training_effect = Some(value);
+        self
+    }
+
+    /// Sets the aerobic training effect (optional)
+    #[must_use]
+    pub const fn aerobic_training_effect_opt(mut self, value: Option<f32>) -> Self {
+        self.activity.aerobic_training_effect = value;
+        self
+    }
+
+    /// Sets the anaerobic training effect
+    #[must_use]
+    pub const fn anaerobic_training_effect(mut self, value: f32) -> Self {
+        self.activity.anaerobic_training_effect = Some(value);
+        self
+    }
+
+    /// Sets the anaerobic training effect (optional)
+    #[must_use]
+    pub const fn anaerobic_training_effect_opt(mut self, value: Option<f32>) -> Self {
+        self.activity.anaerobic_training_effect = value;
+        self
+    }
+
+    /// Sets the moving time in seconds reported by the provider
+    ///
+    /// Replaced by the moving time detected from the speed stream when the
+    /// activity has one.
+    #[must_use]
+    pub const fn moving_time_seconds(mut self, value: u64) -> Self {
+        self.activity.moving_time_seconds = Some(value);
+        self
+    }
+
+    /// Sets the moving time in seconds reported by the provider (optional)
+    #[must_use]
+    pub const fn moving_time_seconds_opt(mut self, value: Option<u64>) -> Self {
+        self.activity.moving_time_seconds = value;
+        self
+    }
+
     /// Sets the time series data
     #[must_use]
     pub fn time_series_data(mut self, value: TimeSeriesData) -> Self {
@@ -1315,8 +1576,19 @@ impl ActivityBuilder {
     }
 
     /// Builds the Activity instance
-    #[must_use]
-    pub fn build(self) -> Activity {
+    ///
+    /// When the activity has a speed stream, its moving time is recomputed from
+    /// the paused segments found with the default [`PauseDetectionConfig`].
+    #[must_use]
+    pub fn build(mut self) -> Activity {
+        let detected = self
+            .activity
+            .time_series_data
+            .as_ref()
+            .and_then(|data| PauseDetectionConfig::default().moving_time_seconds(data));
+        if let Some(moving) = detected {
+            self.activity.moving_time_seconds = Some(moving.min(self.activity.duration_seconds));
+        }
         self.activity
     }
 }
@@ -0,0 +1,243 @@
+// ABOUTME: Reusable activity search filters and sort keys shared by MCP tools and REST routes
+// ABOUTME: Evaluates sport, distance, duration, date, name, and elevation criteria against activities
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Activity, SportType};
+use crate::errors::{AppError, AppResult};
+
+/// Criteria an activity must satisfy to be returned by a search
+///
+/// Every set criterion must match. Numeric bounds are inclusive; the date range
+/// follows provider query semantics (`after` inclusive, `before` exclusive).
+/// An activity missing a value that a bound refers to (e.g. no distance for an
+/// indoor workout) does not match that bound.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActivityFilter {
+    /// Sport type name, compared case-insensitively (e.g. `run`, `trail_running`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sport_type: Option<String>,
+    /// Minimum distance in meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_distance_meters: Option<f64>,
+    /// Maximum distance in meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_distance_meters: Option<f64>,
+    /// Minimum moving duration in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_duration_seconds: Option<u64>,
+    /// Maximum moving duration in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_duration_seconds: Option<u64>,
+    /// Only activities starting at or after this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<DateTime<Utc>>,
+    /// Only activities starting before this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<DateTime<Utc>>,
+    /// Case-insensitive substring of the activity name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_contains: Option<String>,
+    /// Minimum elevation gain in meters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_elevation_gain: Option<f64>,
+}
+
+impl ActivityFilter {
+    /// Check that the bounds are usable
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::InvalidInput` if a numeric bound is negative or not finite,
+    /// a minimum exceeds its maximum, or `after` is not earlier than `before`
+    pub fn validate(&self) -> AppResult<()> {
+        for (name, value) in [
+            ("min_distance_meters", self.min_distance_meters),
+            ("max_distance_meters", self.max_distance_meters),
+            ("min_elevation_gain", self.min_elevation_gain),
+        ] {
+            if let Some(value) = value {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(AppError::invalid_input(format!(
+                        "{name} must be a non-negative number, got {value}"
+                    )));
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min_distance_meters, self.max_distance_meters) {
+            if min > max {
+                return Err(AppError::invalid_input(format!(
+                    "min_distance_meters ({min}) exceeds max_distance_meters ({max})"
+                )));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_duration_seconds, self.max_duration_seconds) {
+            if min > max {
+                return Err(AppError::invalid_input(format!(
+                    "min_duration_seconds ({min}) exceeds max_duration_seconds ({max})"
+                )));
+            }
+        }
+        if let (Some(after), Some(before)) = (self.after, self.before) {
+            if after >= before {
+                return Err(AppError::invalid_input(format!(
+                    "after ({after}) must be earlier than before ({before})"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether no criterion is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the activity satisfies every set criterion
+    #[must_use]
+    pub fn matches(&self, activity: &Activity) -> bool {
+        self.matches_sport(activity.sport_type())
+            && self.matches_date(activity.start_date())
+            && within(
+                activity.distance_meters(),
+                self.min_distance_meters,
+                self.max_distance_meters,
+            )
+            && within(
+                Some(activity.duration_seconds()),
+                self.min_duration_seconds,
+                self.max_duration_seconds,
+            )
+            && within(activity.elevation_gain(), self.min_elevation_gain, None)
+            && self.name_contains.as_deref().is_none_or(|needle| {
+                activity
+                    .name()
+                    .to_lowercase()
+                    .contains(&needle.to_lowercase())
+            })
+    }
+
+    /// Keep only matching activities, preserving their order
+    #[must_use]
+    pub fn apply(&self, activities: Vec<Activity>) -> Vec<Activity> {
+        activities
+            .into_iter()
+            .filter(|activity| self.matches(activity))
+            .collect()
+    }
+
+    fn matches_date(&self, start_date: DateTime<Utc>) -> bool {
+        self.after.is_none_or(|after| start_date >= after)
+            && self.before.is_none_or(|before| start_date < before)
+    }
+
+    /// Compare against the serialized sport name, including `Other` variants
+    fn matches_sport(&self, sport_type: &SportType) -> bool {
+        let Some(wanted) = self.sport_type.as_deref() else {
+            return true;
+        };
+        match serde_json::to_value(sport_type) {
+            Ok(Value::String(name)) => name.eq_ignore_ascii_case(wanted),
+            Ok(Value::Object(map)) => map
+                .get("other")
+                .and_then(Value::as_str)
+                .is_some_and(|name| name.eq_ignore_ascii_case(wanted)),
+            _ => false,
+        }
+    }
+}
+
+/// Inclusive bounds check where a missing value fails any set bound
+fn within<T: PartialOrd>(value: Option<T>, min: Option<T>, max: Option<T>) -> bool {
+    if min.is_none() && max.is_none() {
+        return true;
+    }
+    value.is_some_and(|value| {
+        min.as_ref().is_none_or(|min| value >= *min) && max.as_ref().is_none_or(|max| value <= *max)
+    })
+}
+
+/// Field activity search results are ordered by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivitySortKey {
+    /// Start time
+    #[default]
+    Date,
+    /// Distance covered
+    Distance,
+    /// Moving duration
+    Duration,
+    /// Elevation gain
+    ElevationGain,
+    /// Activity name, case-insensitive
+    Name,
+}
+
+impl ActivitySortKey {
+    /// Parse a sort key name as accepted by the search tool
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::InvalidInput` for unknown names
+    pub fn parse(name: &str) -> AppResult<Self> {
+        match name.to_lowercase().as_str() {
+            "date" | "start_date" => Ok(Self::Date),
+            "distance" => Ok(Self::Distance),
+            "duration" => Ok(Self::Duration),
+            "elevation" | "elevation_gain" => Ok(Self::ElevationGain),
+            "name" => Ok(Self::Name),
+            other => Err(AppError::invalid_input(format!(
+                "Unknown sort key '{other}'. Valid keys: date, distance, duration, elevation_gain, name"
+            ))),
+        }
+    }
+
+    /// Sort activities by this key
+    ///
+    /// Activities missing the sort value always come last, in either direction.
+    /// Ties keep their existing order.
+    pub fn sort(self, activities: &mut [Activity], descending: bool) {
+        let directed = |ordering: Ordering| {
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        };
+        match self {
+            Self::Date => activities.sort_by(|a, b| directed(a.start_date().cmp(&b.start_date()))),
+            Self::Duration => {
+                activities
+                    .sort_by(|a, b| directed(a.duration_seconds().cmp(&b.duration_seconds())));
+            }
+            Self::Name => activities
+                .sort_by(|a, b| directed(a.name().to_lowercase().cmp(&b.name().to_lowercase()))),
+            Self::Distance | Self::ElevationGain => {
+                let value = |activity: &Activity| {
+                    if self == Self::Distance {
+                        activity.distance_meters()
+                    } else {
+                        activity.elevation_gain()
+                    }
+                };
+                activities.sort_by(|a, b| match (value(a), value(b)) {
+                    (Some(a), Some(b)) => directed(a.total_cmp(&b)),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                });
+            }
+        }
+    }
+}
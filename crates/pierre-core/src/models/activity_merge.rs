@@ -0,0 +1,367 @@
+// ABOUTME: Merges split recordings of one workout into a single canonical activity
+// ABOUTME: Sums totals, weights averages by duration, concatenates streams, and keeps sources for undo
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::ops::Add;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Activity, ActivityBuilder, HeartRateZone, PowerZone, TimeSeriesData};
+use crate::errors::{AppError, AppResult};
+
+/// Default maximum pause between the end of one recording and the start of the next (seconds)
+const DEFAULT_MAX_GAP_SECONDS: u64 = 15 * 60;
+
+/// Rules deciding which activities may be merged
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActivityMergeConfig {
+    /// Maximum pause between the first recording ending and the second starting (seconds)
+    #[serde(default = "default_max_gap_seconds")]
+    pub max_gap_seconds: u64,
+    /// Only merge recordings of the same sport
+    #[serde(default = "default_require_same_sport")]
+    pub require_same_sport: bool,
+}
+
+const fn default_max_gap_seconds() -> u64 {
+    DEFAULT_MAX_GAP_SECONDS
+}
+
+const fn default_require_same_sport() -> bool {
+    true
+}
+
+impl Default for ActivityMergeConfig {
+    fn default() -> Self {
+        Self {
+            max_gap_seconds: default_max_gap_seconds(),
+            require_same_sport: default_require_same_sport(),
+        }
+    }
+}
+
+/// A canonical activity built from two split recordings
+///
+/// The source recordings are retained so the merge can be undone with [`Self::undo`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergedActivity {
+    /// The combined activity
+    pub activity: Activity,
+    /// The original recordings, earliest first
+    pub sources: [Activity; 2],
+}
+
+impl MergedActivity {
+    /// Merge two adjacent recordings of the same workout
+    ///
+    /// Recordings may be passed in either order. The earliest keeps its id, name,
+    /// provider and location; totals (duration, distance, elevation, calories, steps,
+    /// TSS) are summed, averages are weighted by duration, maxima take the larger
+    /// value, and time series are concatenated with the second recording's offsets
+    /// shifted by its start time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::InvalidInput` if the recordings overlap, are further apart
+    /// than `config.max_gap_seconds`, have the same id, or differ in sport while
+    /// `config.require_same_sport` is set
+    pub fn merge(
+        first: Activity,
+        second: Activity,
+        config: &ActivityMergeConfig,
+    ) -> AppResult<Self> {
+        let (first, second) = if second.start_date() < first.start_date() {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        validate_adjacent(&first, &second, config)?;
+
+        let offset_seconds = offset_seconds(first.start_date(), second.start_date());
+        let duration_seconds = first.duration_seconds() + second.duration_seconds();
+        let moving_time_seconds = first.moving_time_seconds() + second.moving_time_seconds();
+        let distance_meters = sum(first.distance_meters(), second.distance_meters());
+        let weights = (first.duration_seconds(), second.duration_seconds());
+
+        let activity = ActivityBuilder::new(
+            first.id(),
+            first.name(),
+            first.sport_type().clone(),
+            first.start_date(),
+            duration_seconds,
+            first.provider(),
+        )
+        .moving_time_seconds(moving_time_seconds)
+        .distance_meters_opt(distance_meters)
+        .elevation_gain_opt(sum(first.elevation_gain(), second.elevation_gain()))
+        .calories_opt(sum(first.calories(), second.calories()))
+        .steps_opt(sum(first.steps(), second.steps()))
+        .training_stress_score_opt(sum(
+            first.training_stress_score(),
+            second.training_stress_score(),
+        ))
+        .average_heart_rate_opt(weighted_u32(
+            first.average_heart_rate(),
+            second.average_heart_rate(),
+            weights,
+        ))
+        .max_heart_rate_opt(max(first.max_heart_rate(), second.max_heart_rate()))
+        .average_speed_opt(average_speed(distance_meters, moving_time_seconds))
+        .max_speed_opt(max(first.max_speed(), second.max_speed()))
+        .average_power_opt(weighted_u32(
+            first.average_power(),
+            second.average_power(),
+            weights,
+        ))
+        .max_power_opt(max(first.max_power(), second.max_power()))
+        .normalized_power_opt(combined_normalized_power(
+            first.normalized_power(),
+            second.normalized_power(),
+            weights,
+        ))
+        .ftp_opt(first.ftp().or(second.ftp()))
+        .average_cadence_opt(weighted_u32(
+            first.average_cadence(),
+            second.average_cadence(),
+            weights,
+        ))
+        .max_cadence_opt(max(first.max_cadence(), second.max_cadence()))
+        .heart_rate_zones_opt(merge_heart_rate_zones(
+            first.heart_rate_zones(),
+            second.heart_rate_zones(),
+        ))
+        .power_zones_opt(merge_power_zones(first.power_zones(), second.power_zones()))
+        .time_series_data_opt(concatenate_streams(
+            first.time_series_data(),
+            second.time_series_data(),
+            offset_seconds,
+        ))
+        .segment_efforts_opt(match (first.segment_efforts(), second.segment_efforts()) {
+            (None, None) => None,
+            (a, b) => Some(
+                a.into_iter()
+                    .flatten()
+                    .chain(b.into_iter().flatten())
+                    .cloned()
+                    .collect(),
+            ),
+        })
+        .temperature_opt(first.temperature().or(second.temperature()))
+        .humidity_opt(first.humidity().or(second.humidity()))
+        .start_timezone_opt(first.start_timezone().map(str::to_owned))
+        .utc_offset_seconds_opt(first.utc_offset_seconds())
+        .start_latitude_opt(first.start_latitude())
+        .start_longitude_opt(first.start_longitude())
+        .city_opt(first.city().map(str::to_owned))
+        .region_opt(first.region().map(str::to_owned))
+        .country_opt(first.country().map(str::to_owned))
+        .trail_name_opt(first.trail_name().map(str::to_owned))
+        .workout_type_opt(first.workout_type())
+        .sport_type_detail_opt(first.sport_type_detail().map(str::to_owned))
+        .build();
+
+        Ok(Self {
+            activity,
+            sources: [first, second],
+        })
+    }
+
+    /// Undo the merge, returning the original recordings earliest first
+    #[must_use]
+    pub fn undo(self) -> (Activity, Activity) {
+        let [first, second] = self.sources;
+        (first, second)
+    }
+}
+
+/// Check that two recordings (earliest first) can be merged
+fn validate_adjacent(
+    first: &Activity,
+    second: &Activity,
+    config: &ActivityMergeConfig,
+) -> AppResult<()> {
+    if first.id() == second.id() {
+        return Err(AppError::invalid_input(format!(
+            "Cannot merge activity {} with itself",
+            first.id()
+        )));
+    }
+
+    if config.require_same_sport && first.sport_type() != second.sport_type() {
+        return Err(AppError::invalid_input(format!(
+            "Cannot merge {:?} activity {} with {:?} activity {}",
+            first.sport_type(),
+            first.id(),
+            second.sport_type(),
+            second.id()
+        )));
+    }
+
+    let first_end_offset = first.duration_seconds();
+    let second_start_offset = offset_seconds(first.start_date(), second.start_date());
+    if second_start_offset < first_end_offset {
+        return Err(AppError::invalid_input(format!(
+            "Activities {} and {} overlap and cannot be merged",
+            first.id(),
+            second.id()
+        )));
+    }
+
+    let gap = second_start_offset - first_end_offset;
+    if gap > config.max_gap_seconds {
+        return Err(AppError::invalid_input(format!(
+            "Activities {} and {} are {gap}s apart, more than the {}s merge window",
+            first.id(),
+            second.id(),
+            config.max_gap_seconds
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whole seconds from `start` to `later`, zero if `later` is earlier
+fn offset_seconds(start: DateTime<Utc>, later: DateTime<Utc>) -> u64 {
+    u64::try_from((later - start).num_seconds()).unwrap_or(0)
+}
+
+/// Sum two optional totals, keeping whichever is present
+fn sum<T: Add<Output = T>>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Larger of two optional maxima, keeping whichever is present
+fn max<T: PartialOrd>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b > a { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Duration-weighted average of two optional integer averages
+fn weighted_u32(a: Option<u32>, b: Option<u32>, (wa, wb): (u64, u64)) -> Option<u32> {
+    match (a, b) {
+        (Some(a), Some(b)) if wa + wb > 0 => {
+            let total = f64::from(a).mul_add(wa as f64, f64::from(b) * wb as f64);
+            Some((total / (wa + wb) as f64).round() as u32)
+        }
+        (a, b) => a.or(b),
+    }
+}
+
+/// Normalized power across both recordings (duration-weighted fourth-power mean)
+fn combined_normalized_power(a: Option<u32>, b: Option<u32>, (wa, wb): (u64, u64)) -> Option<u32> {
+    match (a, b) {
+        (Some(a), Some(b)) if wa + wb > 0 => {
+            let total = f64::from(a)
+                .powi(4)
+                .mul_add(wa as f64, f64::from(b).powi(4) * wb as f64);
+            Some((total / (wa + wb) as f64).powf(0.25).round() as u32)
+        }
+        (a, b) => a.or(b),
+    }
+}
+
+/// Average speed from combined distance and moving time
+fn average_speed(distance_meters: Option<f64>, moving_time_seconds: u64) -> Option<f64> {
+    distance_meters
+        .filter(|_| moving_time_seconds > 0)
+        .map(|distance| distance / moving_time_seconds as f64)
+}
+
+/// Sum time in each heart rate zone when both recordings use the same zones
+fn merge_heart_rate_zones(
+    a: Option<&Vec<HeartRateZone>>,
+    b: Option<&Vec<HeartRateZone>>,
+) -> Option<Vec<HeartRateZone>> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let same_zones = a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(x, y)| x.min_hr == y.min_hr && x.max_hr == y.max_hr);
+            same_zones.then(|| {
+                a.iter()
+                    .zip(b)
+                    .map(|(x, y)| HeartRateZone {
+                        minutes: x.minutes + y.minutes,
+                        ..x.clone()
+                    })
+                    .collect()
+            })
+        }
+        (a, b) => a.or(b).cloned(),
+    }
+}
+
+/// Sum time in each power zone when both recordings use the same zones
+fn merge_power_zones(
+    a: Option<&Vec<PowerZone>>,
+    b: Option<&Vec<PowerZone>>,
+) -> Option<Vec<PowerZone>> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let same_zones = a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(x, y)| x.min_power == y.min_power && x.max_power == y.max_power);
+            same_zones.then(|| {
+                a.iter()
+                    .zip(b)
+                    .map(|(x, y)| PowerZone {
+                        time_in_zone: x.time_in_zone + y.time_in_zone,
+                        ..x.clone()
+                    })
+                    .collect()
+            })
+        }
+        (a, b) => a.or(b).cloned(),
+    }
+}
+
+/// Concatenate streams, shifting the second recording's timestamps by `offset_seconds`
+///
+/// A channel is kept only when both recordings have it, so every channel stays
+/// aligned with the timestamps.
+fn concatenate_streams(
+    a: Option<&TimeSeriesData>,
+    b: Option<&TimeSeriesData>,
+    offset_seconds: u64,
+) -> Option<TimeSeriesData> {
+    let (a, b) = match (a, b) {
+        (Some(a), Some(b)) => (a, b),
+        // A single stream cannot cover the whole merged activity
+        _ => return None,
+    };
+    let offset = u32::try_from(offset_seconds).unwrap_or(u32::MAX);
+
+    Some(TimeSeriesData {
+        timestamps: a
+            .timestamps
+            .iter()
+            .copied()
+            .chain(b.timestamps.iter().map(|t| t.saturating_add(offset)))
+            .collect(),
+        heart_rate: concat(a.heart_rate.as_ref(), b.heart_rate.as_ref()),
+        power: concat(a.power.as_ref(), b.power.as_ref()),
+        cadence: concat(a.cadence.as_ref(), b.cadence.as_ref()),
+        speed: concat(a.speed.as_ref(), b.speed.as_ref()),
+        altitude: concat(a.altitude.as_ref(), b.altitude.as_ref()),
+        temperature: concat(a.temperature.as_ref(), b.temperature.as_ref()),
+        gps_coordinates: concat(a.gps_coordinates.as_ref(), b.gps_coordinates.as_ref()),
+    })
+}
+
+/// Concatenate a channel present in both recordings
+fn concat<T: Clone>(a: Option<&Vec<T>>, b: Option<&Vec<T>>) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some([a.as_slice(), b.as_slice()].concat()),
+        _ => None,
+    }
+}
@@ -0,0 +1,126 @@
+// ABOUTME: Detects paused segments in an activity's speed stream to derive true moving time
+// ABOUTME: Merges stationary stretches split by brief shuffles and ignores stops shorter than a threshold
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde::{Deserialize, Serialize};
+
+use super::TimeSeriesData;
+
+/// Default speed below which the athlete is treated as stationary (m/s, ~1.8 km/h)
+const DEFAULT_SPEED_THRESHOLD_MPS: f32 = 0.5;
+
+/// Default shortest stop counted as a pause (seconds)
+const DEFAULT_MIN_PAUSE_SECONDS: u32 = 10;
+
+/// Default longest stretch of movement that still joins two stops into one pause (seconds)
+const DEFAULT_MAX_RESUME_SECONDS: u32 = 5;
+
+/// Default gap between samples treated as the recording being paused (seconds)
+const DEFAULT_MAX_SAMPLE_GAP_SECONDS: u32 = 60;
+
+/// Thresholds used to split a speed stream into moving and paused segments
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PauseDetectionConfig {
+    /// Speed below which the athlete is stationary (m/s)
+    pub speed_threshold_mps: f32,
+    /// Stops shorter than this count as moving time (seconds)
+    pub min_pause_seconds: u32,
+    /// Stops separated by at most this much movement are merged into one pause (seconds)
+    pub max_resume_seconds: u32,
+    /// A gap between samples longer than this is a pause whatever the speed (seconds)
+    pub max_sample_gap_seconds: u32,
+}
+
+impl Default for PauseDetectionConfig {
+    fn default() -> Self {
+        Self {
+            speed_threshold_mps: DEFAULT_SPEED_THRESHOLD_MPS,
+            min_pause_seconds: DEFAULT_MIN_PAUSE_SECONDS,
+            max_resume_seconds: DEFAULT_MAX_RESUME_SECONDS,
+            max_sample_gap_seconds: DEFAULT_MAX_SAMPLE_GAP_SECONDS,
+        }
+    }
+}
+
+/// A stretch of an activity during which the athlete was not moving
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityPause {
+    /// Offset from the activity start where the pause began (seconds)
+    pub start_offset: u32,
+    /// Offset from the activity start where movement resumed (seconds)
+    pub end_offset: u32,
+}
+
+impl ActivityPause {
+    /// Length of the pause in seconds
+    #[must_use]
+    pub const fn duration_seconds(&self) -> u32 {
+        self.end_offset.saturating_sub(self.start_offset)
+    }
+}
+
+impl PauseDetectionConfig {
+    /// Paused segments of a speed stream, earliest first
+    ///
+    /// An interval between two samples is stationary when the mean of its
+    /// endpoint speeds is below `speed_threshold_mps`, or when the samples are
+    /// further apart than `max_sample_gap_seconds` (the device stopped
+    /// recording). Consecutive stationary intervals form a stop; stops
+    /// separated by no more than `max_resume_seconds` of movement are merged,
+    /// and merged stops shorter than `min_pause_seconds` are dropped.
+    ///
+    /// Returns `None` when the stream has no speed channel or its length does
+    /// not match the timestamps.
+    #[must_use]
+    pub fn detect_pauses(&self, data: &TimeSeriesData) -> Option<Vec<ActivityPause>> {
+        let speed = data.speed.as_ref()?;
+        if speed.len() != data.timestamps.len() {
+            return None;
+        }
+
+        let mut stops: Vec<ActivityPause> = Vec::new();
+        for (i, window) in data.timestamps.windows(2).enumerate() {
+            let (start, end) = (window[0], window[1]);
+            if end <= start || !self.is_stationary(end - start, speed[i], speed[i + 1]) {
+                continue;
+            }
+            match stops.last_mut() {
+                Some(last) if start.saturating_sub(last.end_offset) <= self.max_resume_seconds => {
+                    last.end_offset = end;
+                }
+                _ => stops.push(ActivityPause {
+                    start_offset: start,
+                    end_offset: end,
+                }),
+            }
+        }
+
+        stops.retain(|pause| pause.duration_seconds() >= self.min_pause_seconds);
+        Some(stops)
+    }
+
+    /// Moving time of a speed stream: its span minus every detected pause
+    ///
+    /// Returns `None` when pauses cannot be detected (see [`Self::detect_pauses`])
+    /// or the stream has fewer than two samples.
+    #[must_use]
+    pub fn moving_time_seconds(&self, data: &TimeSeriesData) -> Option<u64> {
+        let (first, last) = (data.timestamps.first()?, data.timestamps.last()?);
+        if data.timestamps.len() < 2 {
+            return None;
+        }
+        let paused: u64 = self
+            .detect_pauses(data)?
+            .iter()
+            .map(|pause| u64::from(pause.duration_seconds()))
+            .sum();
+        Some(u64::from(last.saturating_sub(*first)).saturating_sub(paused))
+    }
+
+    fn is_stationary(&self, gap_seconds: u32, from_speed: f32, to_speed: f32) -> bool {
+        gap_seconds > self.max_sample_gap_seconds
+            || (from_speed + to_speed) / 2.0 < self.speed_threshold_mps
+    }
+}
@@ -0,0 +1,219 @@
+// ABOUTME: Field projection for activities so tools serialize only the fields a caller asks for
+// ABOUTME: Validates requested field names against the serialized Activity fields plus short aliases
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde_json::{Map, Value};
+
+use super::Activity;
+use crate::errors::{AppError, AppResult};
+
+/// Serialized `Activity` field names, in declaration order
+pub const ACTIVITY_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "sport_type",
+    "start_date",
+    "start_timezone",
+    "utc_offset_seconds",
+    "duration_seconds",
+    "moving_time_seconds",
+    "distance_meters",
+    "elevation_gain",
+    "average_heart_rate",
+    "max_heart_rate",
+    "average_speed",
+    "max_speed",
+    "calories",
+    "steps",
+    "heart_rate_zones",
+    "average_power",
+    "max_power",
+    "normalized_power",
+    "power_zones",
+    "ftp",
+    "average_cadence",
+    "max_cadence",
+    "hrv_score",
+    "recovery_heart_rate",
+    "temperature",
+    "humidity",
+    "average_altitude",
+    "wind_speed",
+    "ground_contact_time",
+    "vertical_oscillation",
+    "stride_length",
+    "running_power",
+    "breathing_rate",
+    "spo2",
+    "training_stress_score",
+    "intensity_factor",
+    "suffer_score",
+    "aerobic_training_effect",
+    "anaerobic_training_effect",
+    "time_series_data",
+    "start_latitude",
+    "start_longitude",
+    "city",
+    "region",
+    "country",
+    "trail_name",
+    "workout_type",
+    "sport_type_detail",
+    "segment_efforts",
+    "provider",
+];
+
+/// Short names accepted in place of a serialized field name
+const FIELD_ALIASES: &[(&str, &str)] = &[
+    ("distance", "distance_meters"),
+    ("duration", "duration_seconds"),
+    ("elapsed_time", "duration_seconds"),
+    ("moving_time", "moving_time_seconds"),
+];
+
+/// A validated set of activity fields to serialize
+///
+/// Each requested name becomes a key of the projected object, so aliases are
+/// kept as given (`distance` rather than `distance_meters`). Requested fields
+/// the activity has no value for are `null` so every projected activity has
+/// the same keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityProjection {
+    /// (key in the output, serialized field it reads)
+    fields: Vec<(String, &'static str)>,
+}
+
+impl ActivityProjection {
+    /// Build a projection from field names
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_field` error listing the valid names if any name is
+    /// unknown or no name is given
+    pub fn new<I, S>(names: I) -> AppResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut fields: Vec<(String, &'static str)> = Vec::new();
+        let mut unknown = Vec::new();
+
+        for name in names {
+            let name = name.as_ref().trim();
+            match resolve(name) {
+                Some(source) if !fields.iter().any(|(key, _)| key == name) => {
+                    fields.push((name.to_owned(), source));
+                }
+                Some(_) => {}
+                None => unknown.push(name.to_owned()),
+            }
+        }
+
+        if !unknown.is_empty() {
+            return Err(AppError::invalid_field(
+                "fields",
+                &format!(
+                    "unknown field(s) {}; valid fields: {}",
+                    unknown.join(", "),
+                    Self::valid_fields().join(", ")
+                ),
+            ));
+        }
+        if fields.is_empty() {
+            return Err(AppError::invalid_field(
+                "fields",
+                &format!(
+                    "at least one field is required; valid fields: {}",
+                    Self::valid_fields().join(", ")
+                ),
+            ));
+        }
+
+        Ok(Self { fields })
+    }
+
+    /// Parse the `fields` tool parameter, an array of field names
+    ///
+    /// Returns `None` when the parameter is absent or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_field` error if the parameter is not an array of
+    /// strings or names an unknown field
+    pub fn from_param(value: Option<&Value>) -> AppResult<Option<Self>> {
+        let Some(value) = value.filter(|v| !v.is_null()) else {
+            return Ok(None);
+        };
+        let names = value
+            .as_array()
+            .and_then(|items| items.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+            .ok_or_else(|| AppError::invalid_field("fields", "expected an array of field names"))?;
+        Self::new(names).map(Some)
+    }
+
+    /// Every accepted field name: serialized fields followed by aliases
+    #[must_use]
+    pub fn valid_fields() -> Vec<&'static str> {
+        ACTIVITY_FIELDS
+            .iter()
+            .copied()
+            .chain(FIELD_ALIASES.iter().map(|(alias, _)| *alias))
+            .collect()
+    }
+
+    /// Output keys in the order they were requested
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Serialize only the projected fields of an activity
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the activity cannot be serialized
+    pub fn project(&self, activity: &Activity) -> AppResult<Value> {
+        let Value::Object(mut full) = serde_json::to_value(activity)
+            .map_err(|e| AppError::internal(format!("Failed to serialize activity: {e}")))?
+        else {
+            return Err(AppError::internal(
+                "Activity did not serialize to an object",
+            ));
+        };
+
+        let projected: Map<String, Value> = self
+            .fields
+            .iter()
+            .map(|(key, source)| (key.clone(), full.remove(*source).unwrap_or(Value::Null)))
+            .collect();
+        Ok(Value::Object(projected))
+    }
+
+    /// Project each activity in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an activity cannot be serialized
+    pub fn project_all(&self, activities: &[Activity]) -> AppResult<Value> {
+        activities
+            .iter()
+            .map(|activity| self.project(activity))
+            .collect::<AppResult<Vec<_>>>()
+            .map(Value::Array)
+    }
+}
+
+/// Serialized field a requested name reads, if the name is known
+fn resolve(name: &str) -> Option<&'static str> {
+    ACTIVITY_FIELDS
+        .iter()
+        .copied()
+        .find(|field| *field == name)
+        .or_else(|| {
+            FIELD_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, field)| *field)
+        })
+}
@@ -0,0 +1,23 @@
+// ABOUTME: Per-activity sync record used to compute delta syncs for caching clients
+// ABOUTME: Tracks a content hash, when a change was last seen, and when the activity disappeared
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Last known state of one provider activity, as seen by delta syncs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActivitySyncRecord {
+    /// Provider-specific activity ID
+    pub activity_id: String,
+    /// When the activity started
+    pub start_date: DateTime<Utc>,
+    /// Hash of the activity content when it was last seen
+    pub content_hash: String,
+    /// When the activity was created or last changed, as far as syncs can tell
+    pub updated_at: DateTime<Utc>,
+    /// When a sync found the activity missing from the provider, if it did
+    pub deleted_at: Option<DateTime<Utc>>,
+}
@@ -89,6 +89,8 @@ pub enum PrMetric {
     HighestElevation,
     /// Fastest completion time for a standard distance (seconds)
     FastestTime,
+    /// Fastest elapsed time on a segment (seconds)
+    SegmentTime,
 }
 
 /// Represents a personal record achievement
@@ -105,4 +107,7 @@ pub struct PersonalRecord {
     pub value: f64,
     /// When the record was achieved
     pub date: DateTime<Utc>,
+    /// Segment the record was set on, for `SegmentTime` records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_id: Option<String>,
 }
@@ -6,6 +6,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use super::TenantId;
@@ -35,6 +36,8 @@ pub enum AuditEventType {
     OAuthCredentialsDeleted,
     /// OAuth token was refreshed
     TokenRefreshed,
+    /// OAuth token was revoked
+    TokenRevoked,
 
     // Tenant Events
     /// New tenant was created
@@ -68,6 +71,10 @@ pub enum AuditEventType {
     /// External provider API was called
     ProviderApiCalled,
 
+    // Data Access Events
+    /// User data was exported as a portability archive
+    DataExported,
+
     // Administrative Events
     /// System configuration was changed
     ConfigurationChanged,
@@ -75,6 +82,8 @@ pub enum AuditEventType {
     SystemMaintenance,
     /// Security policy was violated
     SecurityPolicyViolation,
+    /// An admin was issued a token to act as another user
+    UserImpersonated,
 }
 
 /// Severity levels for audit events
@@ -200,4 +209,84 @@ impl AuditEvent {
         self.metadata = metadata;
         self
     }
+
+    /// Record the correlation ID of the originating HTTP request in the metadata
+    ///
+    /// An existing `request_id` entry is kept. Non-object metadata is moved under
+    /// a `value` key so the ID can sit alongside it.
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        let mut map = match self.metadata {
+            Value::Object(map) => map,
+            Value::Null => Map::new(),
+            other => Map::from_iter([("value".to_owned(), other)]),
+        };
+        map.entry("request_id")
+            .or_insert_with(|| Value::String(request_id.to_owned()));
+        self.metadata = Value::Object(map);
+        self
+    }
+}
+
+/// Filters for browsing stored audit events
+///
+/// Every filter is optional and they combine with AND. The time range is
+/// half-open: `start` is inclusive and `end` is exclusive, so consecutive
+/// windows never return the same event twice.
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventFilter {
+    /// Only events for this tenant
+    pub tenant_id: Option<TenantId>,
+    /// Only events recorded for this user
+    pub user_id: Option<Uuid>,
+    /// Only events of this type, as stored (e.g. `"UserLogin"`)
+    pub event_type: Option<String>,
+    /// Only events with this severity
+    pub severity: Option<AuditSeverity>,
+    /// Only events at or after this time
+    pub start: Option<DateTime<Utc>>,
+    /// Only events before this time
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl AuditEventFilter {
+    /// Restrict the filter to one tenant
+    #[must_use]
+    pub const fn with_tenant_id(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+
+    /// Restrict the filter to one user
+    #[must_use]
+    pub const fn with_user_id(mut self, user_id: Uuid) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Restrict the filter to one event type
+    #[must_use]
+    pub fn with_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Restrict the filter to one severity
+    #[must_use]
+    pub const fn with_severity(mut self, severity: AuditSeverity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Restrict the filter to events in `[start, end)`
+    #[must_use]
+    pub const fn with_time_range(
+        mut self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
 }
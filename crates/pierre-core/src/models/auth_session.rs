@@ -0,0 +1,36 @@
+// ABOUTME: Auth session record for JWTs issued to a user at login or refresh
+// ABOUTME: Keyed by the token's jti so revoked sessions can be rejected during validation
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A JWT issued to a user, tracked so it can be listed and revoked
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthSession {
+    /// Session ID, equal to the token's `jti` claim
+    pub id: String,
+    /// User the token was issued to
+    pub user_id: Uuid,
+    /// Client that requested the token (User-Agent), if sent
+    pub client: Option<String>,
+    /// IP address the token was requested from, if known
+    pub ip_address: Option<String>,
+    /// When the token was issued
+    pub issued_at: DateTime<Utc>,
+    /// When the token expires
+    pub expires_at: DateTime<Utc>,
+    /// When the session was revoked, if it was
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl AuthSession {
+    /// Whether the session can still be used at `now`
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
@@ -0,0 +1,86 @@
+// ABOUTME: Manual merge of a duplicate or split record into the primary record of the same session
+// ABOUTME: Keeps the secondary's snapshot so listings can combine them and an unmerge can restore it
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Activity;
+use crate::errors::AppError;
+
+/// How a merged secondary activity combines with its primary
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityMergeKind {
+    /// Both records cover the whole session; the secondary only fills the primary's empty fields
+    #[default]
+    Duplicate,
+    /// Each record covers part of one workout; they are joined with [`super::MergedActivity`]
+    Split,
+}
+
+impl Display for ActivityMergeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ActivityMergeKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "duplicate" => Ok(Self::Duplicate),
+            "split" => Ok(Self::Split),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid activity merge kind: {s}"
+            ))),
+        }
+    }
+}
+
+impl ActivityMergeKind {
+    /// Database string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Duplicate => "duplicate",
+            Self::Split => "split",
+        }
+    }
+}
+
+/// An activity merged into the primary record of the same session
+///
+/// For a [`ActivityMergeKind::Duplicate`] merge both activities cover the whole
+/// session and the secondary only fills the primary's empty fields. For a
+/// [`ActivityMergeKind::Split`] merge the primary is the earlier recording and
+/// listings show the two joined by [`super::MergedActivity`]. Either way the
+/// secondary is hidden from listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateActivityMerge {
+    /// How the secondary combines with the primary
+    #[serde(default)]
+    pub kind: ActivityMergeKind,
+    /// Provider of the activity whose fields win
+    pub primary_provider: String,
+    /// Provider-specific ID of the activity whose fields win
+    pub primary_id: String,
+    /// Provider of the hidden duplicate
+    pub secondary_provider: String,
+    /// Provider-specific ID of the hidden duplicate
+    pub secondary_id: String,
+    /// The duplicate as it was when merged, used to fill gaps in the primary
+    pub secondary: Activity,
+    /// Primary fields that were empty and filled from the secondary at merge time
+    ///
+    /// Always empty for split merges, whose fields are combined rather than filled.
+    pub filled_fields: Vec<String>,
+    /// When the merge was made
+    pub merged_at: DateTime<Utc>,
+}
@@ -0,0 +1,157 @@
+// ABOUTME: Domain models for admin-configurable runtime feature flags
+// ABOUTME: Typed flag values with a default, an optional global value, and per-tenant values
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::fmt;
+
+use super::TenantId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Type of value a feature flag holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagType {
+    /// `true` or `false`
+    Bool,
+    /// Any JSON number
+    Number,
+    /// Any string
+    String,
+}
+
+impl FeatureFlagType {
+    /// Parse a flag type from its string representation
+    #[must_use]
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "bool" => Some(Self::Bool),
+            "number" => Some(Self::Number),
+            "string" => Some(Self::String),
+            _ => None,
+        }
+    }
+
+    /// Convert enum to string
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::Number => "number",
+            Self::String => "string",
+        }
+    }
+}
+
+impl fmt::Display for FeatureFlagType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Value of a feature flag, serialized as a plain JSON boolean, number, or string
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FeatureFlagValue {
+    /// Boolean flag value
+    Bool(bool),
+    /// Numeric flag value
+    Number(f64),
+    /// String flag value
+    String(String),
+}
+
+impl FeatureFlagValue {
+    /// Type of this value
+    #[must_use]
+    pub const fn value_type(&self) -> FeatureFlagType {
+        match self {
+            Self::Bool(_) => FeatureFlagType::Bool,
+            Self::Number(_) => FeatureFlagType::Number,
+            Self::String(_) => FeatureFlagType::String,
+        }
+    }
+
+    /// The boolean value, if this is a boolean
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The numeric value, if this is a number
+    #[must_use]
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The string value, if this is a string
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// A feature flag definition with its default and global values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureFlag {
+    /// Unique flag key (e.g., `tool.get_activities`)
+    pub key: String,
+    /// What the flag controls
+    pub description: Option<String>,
+    /// Type every value of this flag must have
+    pub value_type: FeatureFlagType,
+    /// Value used when neither a global nor a tenant value is set
+    pub default_value: FeatureFlagValue,
+    /// Value set by an admin for every tenant, overriding the default
+    pub global_value: Option<FeatureFlagValue>,
+    /// When the flag was last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A feature flag value set for a single tenant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TenantFeatureFlag {
+    /// Key of the flag this value applies to
+    pub key: String,
+    /// Tenant the value applies to
+    pub tenant_id: TenantId,
+    /// Value for this tenant, overriding the global value and default
+    pub value: FeatureFlagValue,
+    /// When the value was last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Where the effective value of a feature flag came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagSource {
+    /// The flag's default value
+    Default,
+    /// The admin-set global value
+    Global,
+    /// The admin-set value for the tenant
+    Tenant,
+}
+
+/// A feature flag resolved for one tenant (or globally)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EffectiveFeatureFlag {
+    /// The flag definition
+    #[serde(flatten)]
+    pub flag: FeatureFlag,
+    /// Value in effect
+    pub value: FeatureFlagValue,
+    /// Which level the value in effect was set at
+    pub source: FeatureFlagSource,
+}
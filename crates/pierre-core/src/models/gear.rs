@@ -0,0 +1,62 @@
+// ABOUTME: Equipment models for shoes, bikes, and other gear tracked by fitness providers
+// ABOUTME: Gear and GearType definitions with accumulated distance and retirement status
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of equipment an athlete logs activities with
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum GearType {
+    /// Running or walking shoes
+    Shoes,
+    /// Road, mountain, or indoor bike
+    Bike,
+    /// Any other equipment
+    Other,
+}
+
+/// A piece of equipment with the distance logged on it
+///
+/// # Examples
+///
+/// ```rust
+/// use pierre_mcp_server::models::{Gear, GearType};
+///
+/// let shoes = Gear {
+///     id: "g12345".into(),
+///     name: "Daily Trainers".into(),
+///     gear_type: GearType::Shoes,
+///     distance_meters: 612_400.0,
+///     retired: false,
+///     primary: true,
+///     brand: Some("Saucony".into()),
+///     model: Some("Ride 17".into()),
+///     provider: "strava".into(),
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Gear {
+    /// Unique identifier for the gear (provider-specific)
+    pub id: String,
+    /// Name given to the gear by the athlete
+    pub name: String,
+    /// Kind of equipment
+    pub gear_type: GearType,
+    /// Total distance logged with this gear (meters)
+    pub distance_meters: f64,
+    /// Whether the athlete has retired this gear
+    pub retired: bool,
+    /// Whether this is the athlete's default gear for its type
+    pub primary: bool,
+    /// Manufacturer name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    /// Model name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Source provider of this gear data
+    pub provider: String,
+}
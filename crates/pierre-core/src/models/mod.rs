@@ -27,29 +27,52 @@
 //! - `Athlete`: User profile information
 //! - `Stats`: Aggregated fitness statistics
 //! - `PersonalRecord`: Individual performance records
+//! - `Gear`: Shoes, bikes, and other equipment with logged distance
+//! - `Segment`: Named stretch of road or trail with course records
+//! - `ActivitySplit`: Per-kilometer or per-mile split of an activity
+//! - `TimeSeries`: Single metric sampled over an activity (e.g. intraday heart rate)
+//! - `AthleteZones`: Heart rate and power zones configured with the provider
 //! - `SportType`: Enumeration of supported activity types
 
 // Domain modules
 mod activity;
+mod activity_filter;
+mod activity_merge;
+mod activity_pauses;
+mod activity_projection;
 mod athlete;
+mod gear;
 mod health;
 mod nutrition;
 mod oauth;
+mod segment;
 mod sleep;
 mod social;
+mod split;
 mod sport;
+mod sport_alias;
 mod tenant;
+mod time_series;
 mod tool_selection;
 mod user;
+mod zones;
 
 // Re-export all public types for convenience
 // Activity domain
 pub use activity::{
     Activity, ActivityBuilder, HeartRateZone, PowerZone, SegmentEffort, TimeSeriesData,
 };
+pub use activity_filter::{ActivityFilter, ActivitySortKey};
+pub use activity_merge::{ActivityMergeConfig, MergedActivity};
+pub use activity_pauses::{ActivityPause, PauseDetectionConfig};
+pub use activity_projection::{ActivityProjection, ACTIVITY_FIELDS};
+pub use segment::Segment;
+pub use split::{ActivitySplit, SplitUnit};
+pub use time_series::TimeSeries;
 
 // Sport types
 pub use sport::SportType;
+pub use sport_alias::SportTypeNormalizer;
 
 // Sleep domain
 pub use sleep::{SleepSession, SleepStage, SleepStageType};
@@ -62,6 +85,10 @@ pub use nutrition::{FoodItem, MealEntry, MealType, NutritionLog};
 
 // Athlete domain
 pub use athlete::{Athlete, PersonalRecord, PrMetric, Stats};
+pub use zones::{AthleteZones, ZoneRange};
+
+// Gear domain
+pub use gear::{Gear, GearType};
 
 // User domain
 pub use user::{User, UserPhysiologicalProfile, UserStatus, UserTier};
@@ -91,7 +118,9 @@ pub use social::{
 
 // OAuth 2.0 server persistence models
 mod oauth2_server;
-pub use oauth2_server::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
+pub use oauth2_server::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
 
 // User MCP token types for AI client authentication
 mod user_mcp_token;
@@ -103,9 +132,40 @@ pub use user_mcp_token::{
 mod conversation;
 pub use conversation::{ConversationRecord, ConversationSummary, MessageRecord};
 
+// JWT sessions issued at login and refresh
+mod auth_session;
+pub use auth_session::AuthSession;
+
+// Per-activity state for delta syncs
+mod activity_sync;
+pub use activity_sync::ActivitySyncRecord;
+
+// Manually merged duplicate or split records of one session
+mod duplicate_merge;
+pub use duplicate_merge::{ActivityMergeKind, DuplicateActivityMerge};
+
+// Admin-configurable runtime feature flags
+mod feature_flag;
+pub use feature_flag::{
+    EffectiveFeatureFlag, FeatureFlag, FeatureFlagSource, FeatureFlagType, FeatureFlagValue,
+    TenantFeatureFlag,
+};
+
+// Strava push subscriptions and athlete links for webhook syncs
+mod strava_webhook;
+pub use strava_webhook::{StravaAthleteLink, StravaWebhookSubscription};
+
+// Checkpoints for the batched insight re-analysis job
+mod reanalysis;
+pub use reanalysis::ReanalysisCheckpoint;
+
+// Per-tenant tool call counts for billing
+mod tenant_usage;
+pub use tenant_usage::{TenantDailyToolUsage, ToolCallCounts};
+
 // Security audit event types
 mod audit;
-pub use audit::{AuditEvent, AuditEventType, AuditSeverity};
+pub use audit::{AuditEvent, AuditEventFilter, AuditEventType, AuditSeverity};
 
 // Key rotation configuration and version types
 mod key_rotation;
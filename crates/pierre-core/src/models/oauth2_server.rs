@@ -32,6 +32,8 @@ pub struct OAuth2Client {
     pub created_at: DateTime<Utc>,
     /// Optional expiration time for the client registration
     pub expires_at: Option<DateTime<Utc>>,
+    /// Whether authorization requests must carry a PKCE `code_challenge`
+    pub require_pkce: bool,
 }
 
 /// OAuth 2.0 Authorization Code
@@ -82,6 +84,26 @@ pub struct OAuth2RefreshToken {
     pub revoked: bool,
 }
 
+/// Access token issued alongside an OAuth 2.0 refresh token
+///
+/// Access tokens are stateless JWTs; this record links a token's `jti` to the
+/// refresh token issued in the same response so both can be revoked together.
+#[derive(Debug, Clone)]
+pub struct OAuth2IssuedAccessToken {
+    /// JWT ID (`jti` claim) of the access token
+    pub jti: String,
+    /// Refresh token issued in the same token response
+    pub refresh_token: String,
+    /// Client application identifier
+    pub client_id: String,
+    /// User identifier who owns this token
+    pub user_id: Uuid,
+    /// Timestamp when this access token expires
+    pub expires_at: DateTime<Utc>,
+    /// Whether this access token has been revoked
+    pub revoked: bool,
+}
+
 /// OAuth 2.0 State for CSRF Protection
 #[derive(Debug, Clone)]
 pub struct OAuth2State {
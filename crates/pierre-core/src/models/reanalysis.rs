@@ -0,0 +1,53 @@
+// ABOUTME: Checkpoint record for the batched insight re-analysis maintenance job
+// ABOUTME: Stores the user cursor and progress counters so an interrupted run can resume
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Progress of an insight re-analysis run, saved after every batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReanalysisCheckpoint {
+    /// Identifier chosen by the operator; rerunning the same job resumes it
+    pub job_id: String,
+    /// Cursor of the next page of users, `None` before the first batch
+    pub cursor: Option<String>,
+    /// Users whose insights were regenerated and stored
+    pub users_processed: u32,
+    /// Users whose analysis or insight storage failed
+    pub users_failed: u32,
+    /// Insights stored across all processed users
+    pub insights_stored: u32,
+    /// When the job first started
+    pub started_at: DateTime<Utc>,
+    /// When the last batch finished
+    pub updated_at: DateTime<Utc>,
+    /// When the last page of users was processed, `None` while the job is unfinished
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl ReanalysisCheckpoint {
+    /// Checkpoint for a job that has not processed any users yet
+    #[must_use]
+    pub fn new(job_id: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            job_id: job_id.into(),
+            cursor: None,
+            users_processed: 0,
+            users_failed: 0,
+            insights_stored: 0,
+            started_at: now,
+            updated_at: now,
+            completed_at: None,
+        }
+    }
+
+    /// Whether every user matching the job's filter has been processed
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.completed_at.is_some()
+    }
+}
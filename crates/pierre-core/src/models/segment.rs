@@ -0,0 +1,77 @@
+// ABOUTME: Segment models for named stretches of road or trail that athletes race against
+// ABOUTME: Segment definition with grade, elevation, course records, and the athlete's personal best
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::SportType;
+
+/// A named segment with its course records and the athlete's best effort
+///
+/// Efforts on a segment are reported as [`super::SegmentEffort`]s on the
+/// activities that crossed it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Segment {
+    /// Unique identifier for the segment (provider-specific)
+    pub id: String,
+    /// Name of the segment
+    pub name: String,
+    /// Sport the segment is defined for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sport_type: Option<SportType>,
+    /// Length of the segment in meters
+    pub distance_meters: f64,
+    /// Average grade (percentage)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_grade: Option<f32>,
+    /// Steepest grade (percentage)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_grade: Option<f32>,
+    /// Highest elevation on the segment (meters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elevation_high: Option<f64>,
+    /// Lowest elevation on the segment (meters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elevation_low: Option<f64>,
+    /// Total elevation gain along the segment (meters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_elevation_gain: Option<f64>,
+    /// Climb category (0 = uncategorized, 1-4, 5 = HC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub climb_category: Option<u32>,
+    /// City the segment starts in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// State or region the segment starts in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Country the segment starts in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Efforts recorded on the segment by all athletes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort_count: Option<u64>,
+    /// Distinct athletes who have ridden or run the segment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub athlete_count: Option<u64>,
+    /// Fastest men's time as displayed by the provider (e.g. "6:12")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kom_time: Option<String>,
+    /// Fastest women's time as displayed by the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qom_time: Option<String>,
+    /// Athlete's best elapsed time on the segment in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr_elapsed_time: Option<u64>,
+    /// Date of the athlete's best effort
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr_date: Option<NaiveDate>,
+    /// Efforts the athlete has recorded on the segment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub athlete_effort_count: Option<u32>,
+    /// Source provider of this segment data
+    pub provider: String,
+}
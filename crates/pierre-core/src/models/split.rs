@@ -0,0 +1,95 @@
+// ABOUTME: Split models for per-kilometer and per-mile breakdowns of an activity
+// ABOUTME: Split unit parsing plus per-split distance, time, pace, heart rate, and elevation
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::units::{METERS_PER_KM, METERS_PER_MILE};
+
+/// Distance each split covers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitUnit {
+    /// One kilometer per split
+    #[default]
+    Km,
+    /// One statute mile per split
+    Mile,
+}
+
+impl SplitUnit {
+    /// Parse a unit name such as "km", "kilometers", "mi", or "miles"
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "km" | "kilometer" | "kilometers" | "metric" => Some(Self::Km),
+            "mi" | "mile" | "miles" | "imperial" => Some(Self::Mile),
+            _ => None,
+        }
+    }
+
+    /// Length of one split in meters
+    #[must_use]
+    pub const fn meters(self) -> f64 {
+        match self {
+            Self::Km => METERS_PER_KM,
+            Self::Mile => METERS_PER_MILE,
+        }
+    }
+
+    /// Unit name as used in tool arguments and responses
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Km => "km",
+            Self::Mile => "mile",
+        }
+    }
+}
+
+/// One split or lap of an activity
+///
+/// Splits come from the provider (Strava splits or laps) or are computed from
+/// the activity's GPS or speed stream.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActivitySplit {
+    /// 1-based position within the activity
+    pub index: u32,
+    /// Distance covered in meters
+    pub distance_meters: f64,
+    /// Elapsed time in seconds
+    pub elapsed_time_seconds: f64,
+    /// Time spent moving in seconds, when the provider reports it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moving_time_seconds: Option<f64>,
+    /// Seconds per kilometer or mile (matching the requested unit), over moving
+    /// time when known and elapsed time otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pace_seconds_per_unit: Option<f64>,
+    /// Average heart rate (BPM)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_heart_rate: Option<f64>,
+    /// Maximum heart rate (BPM)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_heart_rate: Option<u32>,
+    /// Total climbing in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elevation_gain_meters: Option<f64>,
+    /// Net elevation change from the start to the end of the split in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elevation_change_meters: Option<f64>,
+    /// Whether the split is shorter than one full unit, as the last split usually is
+    pub partial: bool,
+}
+
+impl ActivitySplit {
+    /// Pace in seconds per `unit` for a distance covered in `seconds`
+    ///
+    /// Returns `None` when no distance was covered.
+    #[must_use]
+    pub fn pace(distance_meters: f64, seconds: f64, unit: SplitUnit) -> Option<f64> {
+        (distance_meters > 0.0).then(|| seconds / distance_meters * unit.meters())
+    }
+}
@@ -6,6 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::SportTypeNormalizer;
 use crate::config::FitnessConfig;
 
 /// Enumeration of supported sport/activity types
@@ -116,45 +117,8 @@ impl SportType {
             return Self::from_internal_string(internal_name);
         }
 
-        // Direct string-to-enum mapping
-        match provider_sport {
-            "Run" => Self::Run,
-            "Ride" => Self::Ride,
-            "Swim" => Self::Swim,
-            "Walk" => Self::Walk,
-            "Hike" => Self::Hike,
-            "VirtualRide" => Self::VirtualRide,
-            "VirtualRun" => Self::VirtualRun,
-            "Workout" => Self::Workout,
-            "Yoga" => Self::Yoga,
-            "EBikeRide" => Self::EbikeRide,
-            "MountainBikeRide" => Self::MountainBike,
-            "GravelRide" => Self::GravelRide,
-            "CrossCountrySkiing" => Self::CrossCountrySkiing,
-            "AlpineSkiing" => Self::AlpineSkiing,
-            "Snowboarding" => Self::Snowboarding,
-            "Snowshoe" => Self::Snowshoe,
-            "IceSkate" => Self::IceSkating,
-            "BackcountrySki" => Self::BackcountrySkiing,
-            "Kayaking" => Self::Kayaking,
-            "Canoeing" => Self::Canoeing,
-            "Rowing" => Self::Rowing,
-            "StandUpPaddling" => Self::Paddleboarding,
-            "Surfing" => Self::Surfing,
-            "Kitesurf" => Self::Kitesurfing,
-            "WeightTraining" => Self::StrengthTraining,
-            "Crossfit" => Self::Crossfit,
-            "Pilates" => Self::Pilates,
-            "RockClimbing" => Self::RockClimbing,
-            "TrailRunning" => Self::TrailRunning,
-            "Soccer" => Self::Soccer,
-            "Basketball" => Self::Basketball,
-            "Tennis" => Self::Tennis,
-            "Golf" => Self::Golf,
-            "Skateboard" => Self::Skateboarding,
-            "InlineSkate" => Self::InlineSkating,
-            other => Self::Other(other.to_owned()),
-        }
+        // Built-in provider aliases, preserving unknown labels as `Other`
+        SportTypeNormalizer::new().normalize(provider_sport)
     }
 
     /// Create `SportType` from internal configuration string
@@ -0,0 +1,168 @@
+// ABOUTME: Normalization of provider-specific sport strings into canonical SportType variants
+// ABOUTME: Default alias table covering Strava, Garmin, and Fitbit names, overridable via FitnessConfig
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::SportType;
+use crate::config::FitnessConfig;
+
+/// Maps raw provider sport strings to canonical [`SportType`] variants
+///
+/// Lookups ignore case, spaces, underscores, and hyphens, so `"VirtualRide"`,
+/// `"virtual_ride"`, and `"Virtual Ride"` resolve to the same entry. Configured
+/// overrides win over the built-in table; strings matching neither become
+/// [`SportType::Other`] carrying the original label.
+#[derive(Debug, Clone, Default)]
+pub struct SportTypeNormalizer {
+    /// Normalized alias to internal sport name (as accepted by `SportType::from_internal_string`)
+    overrides: HashMap<String, String>,
+}
+
+impl SportTypeNormalizer {
+    /// Create a normalizer using only the built-in alias table
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a normalizer with the sport type mappings of a fitness configuration as overrides
+    #[must_use]
+    pub fn from_fitness_config(config: &FitnessConfig) -> Self {
+        config
+            .get_sport_mappings()
+            .iter()
+            .fold(Self::new(), |normalizer, (alias, internal_name)| {
+                normalizer.with_alias(alias, internal_name)
+            })
+    }
+
+    /// Process-wide normalizer built from the environment fitness configuration
+    ///
+    /// Provider adapters use this so deployments can remap sport strings with
+    /// `SPORT_TYPE_*` and `SPORT_TYPE_ALIASES` without code changes.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<SportTypeNormalizer> = OnceLock::new();
+        GLOBAL.get_or_init(|| Self::from_fitness_config(&FitnessConfig::load().unwrap_or_default()))
+    }
+
+    /// Add an override mapping `alias` to an internal sport name such as `"virtual_ride"`
+    #[must_use]
+    pub fn with_alias(mut self, alias: &str, internal_name: &str) -> Self {
+        self.overrides
+            .insert(alias_key(alias), internal_name.to_owned());
+        self
+    }
+
+    /// Resolve a raw provider sport string to a canonical sport type
+    #[must_use]
+    pub fn normalize(&self, provider_sport: &str) -> SportType {
+        let key = alias_key(provider_sport);
+        if let Some(internal_name) = self.overrides.get(&key) {
+            return SportType::from_internal_string(internal_name);
+        }
+        default_alias(&key).unwrap_or_else(|| SportType::Other(provider_sport.to_owned()))
+    }
+}
+
+/// Lowercase a sport string and drop separators so naming styles compare equal
+fn alias_key(provider_sport: &str) -> String {
+    provider_sport
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Built-in alias table keyed by [`alias_key`] output
+fn default_alias(key: &str) -> Option<SportType> {
+    let sport = match key {
+        // Running
+        "run" | "running" | "track" | "trackrunning" | "streetrunning" | "outdoorrun" => {
+            SportType::Run
+        }
+        "trailrun" | "trailrunning" => SportType::TrailRunning,
+        "virtualrun" | "treadmill" | "treadmillrunning" | "indoorrun" | "indoorrunning" => {
+            SportType::VirtualRun
+        }
+
+        // Cycling
+        "ride" | "cycling" | "bike" | "biking" | "outdoorbike" | "roadcycling" | "road"
+        | "roadbiking" | "cyclocross" | "cx" => SportType::Ride,
+        "mountainbikeride" | "mountainbike" | "mountainbiking" | "mountain" => {
+            SportType::MountainBike
+        }
+        "virtualride" | "indoorcycling" | "indoorbike" | "spin" | "spinning" => {
+            SportType::VirtualRide
+        }
+        "gravelride" | "gravelcycling" => SportType::GravelRide,
+        "ebikeride" | "emountainbikeride" | "ebike" | "ebiking" => SportType::EbikeRide,
+
+        // Swimming
+        "swim" | "swimming" | "openwaterswimming" | "openwater" | "poolswimming"
+        | "lapswimming" => SportType::Swim,
+
+        // Walking and hiking
+        "walk" | "walking" | "casualwalking" | "speedwalking" => SportType::Walk,
+        "hike" | "hiking" => SportType::Hike,
+
+        // Winter sports
+        "crosscountryskiing" | "nordicski" | "nordicskiing" | "xcskiing" => {
+            SportType::CrossCountrySkiing
+        }
+        "alpineski"
+        | "alpineskiing"
+        | "resortskiing"
+        | "downhillskiing"
+        | "resortskiingsnowboarding" => SportType::AlpineSkiing,
+        "snowboard" | "snowboarding" => SportType::Snowboarding,
+        "snowshoe" | "snowshoeing" => SportType::Snowshoe,
+        "iceskate" | "iceskating" | "skating" => SportType::IceSkating,
+        "backcountryski" | "backcountryskiing" | "backcountryskiingsnowboarding" => {
+            SportType::BackcountrySkiing
+        }
+
+        // Water sports
+        "kayaking" | "kayak" => SportType::Kayaking,
+        "canoeing" | "canoe" => SportType::Canoeing,
+        "rowing" | "row" | "indoorrowing" | "virtualrow" => SportType::Rowing,
+        "standuppaddling" | "standuppaddleboarding" | "sup" | "paddleboarding" => {
+            SportType::Paddleboarding
+        }
+        "surfing" | "surf" => SportType::Surfing,
+        "kitesurf" | "kitesurfing" | "kiteboarding" => SportType::Kitesurfing,
+
+        // Strength and fitness
+        "weighttraining" | "strengthtraining" | "weights" | "weightlifting" => {
+            SportType::StrengthTraining
+        }
+        "crossfit" => SportType::Crossfit,
+        "pilates" => SportType::Pilates,
+        "yoga" => SportType::Yoga,
+
+        // Climbing
+        "rockclimbing" | "climbing" | "indoorclimbing" | "bouldering" => SportType::RockClimbing,
+
+        // Team and racquet sports
+        "soccer" | "football" => SportType::Soccer,
+        "basketball" => SportType::Basketball,
+        "tennis" => SportType::Tennis,
+        "golf" => SportType::Golf,
+
+        // Alternative transport
+        "skateboard" | "skateboarding" => SportType::Skateboarding,
+        "inlineskate" | "inlineskating" | "rollerskating" => SportType::InlineSkating,
+
+        // Generic cardio, equipment, and workout types
+        "workout" | "hiit" | "cardio" | "cardiotraining" | "aerobicworkout" | "elliptical"
+        | "fitnessequipment" | "stairclimbing" | "stairstepper" | "stepper" | "training"
+        | "generic" | "other" => SportType::Workout,
+
+        _ => return None,
+    };
+    Some(sport)
+}
@@ -0,0 +1,37 @@
+// ABOUTME: Strava push subscription and athlete link records for webhook-driven syncs
+// ABOUTME: Maps Strava's owner IDs in webhook events back to Pierre users and tenants
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::TenantId;
+
+/// A push subscription registered with Strava for this server's application
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StravaWebhookSubscription {
+    /// Subscription ID assigned by Strava, echoed in every event
+    pub id: i64,
+    /// Strava application (client) ID the subscription belongs to
+    pub client_id: String,
+    /// Callback URL Strava posts events to
+    pub callback_url: String,
+    /// When the subscription was registered
+    pub created_at: DateTime<Utc>,
+}
+
+/// Link between a Strava athlete and the Pierre user who connected them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StravaAthleteLink {
+    /// Strava athlete ID, the `owner_id` of webhook events
+    pub athlete_id: i64,
+    /// Pierre user who authorized the athlete's account
+    pub user_id: Uuid,
+    /// Tenant the connection was made in
+    pub tenant_id: TenantId,
+    /// When the link was created or last refreshed
+    pub linked_at: DateTime<Utc>,
+}
@@ -0,0 +1,48 @@
+// ABOUTME: Per-tenant tool call counts used for usage-based billing
+// ABOUTME: Splits each tool's daily calls by API key, A2A client, and JWT session usage
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::ops::AddAssign;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Tool calls split by how the caller authenticated
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ToolCallCounts {
+    /// Calls made with API keys
+    pub api_key: u64,
+    /// Calls made by A2A clients
+    pub a2a: u64,
+    /// Calls made with user JWTs
+    pub jwt: u64,
+}
+
+impl ToolCallCounts {
+    /// Calls across every authentication method
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.api_key + self.a2a + self.jwt
+    }
+}
+
+impl AddAssign for ToolCallCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.api_key += other.api_key;
+        self.a2a += other.a2a;
+        self.jwt += other.jwt;
+    }
+}
+
+/// Calls of one tool by a tenant's members on one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TenantDailyToolUsage {
+    /// UTC day the calls were made
+    pub date: NaiveDate,
+    /// Tool (or endpoint, for JWT usage) that was called
+    pub tool_name: String,
+    /// Calls on that day
+    pub calls: ToolCallCounts,
+}
@@ -0,0 +1,69 @@
+// ABOUTME: Single-metric time series normalized across providers (e.g. intraday heart rate)
+// ABOUTME: Samples are offsets in seconds from a UTC start time with the provider's sampling resolution
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One metric sampled over the course of an activity
+///
+/// Unlike [`super::TimeSeriesData`], which carries every stream of an activity
+/// side by side, a `TimeSeries` holds a single stream fetched on its own, such
+/// as Fitbit's intraday heart rate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TimeSeries<T> {
+    /// When the first sample could have been taken (usually the activity start)
+    pub start_time: DateTime<Utc>,
+    /// Nominal seconds between samples as reported by the provider
+    pub resolution_seconds: u32,
+    /// Offset of each sample from `start_time` in seconds, ascending
+    pub offsets: Vec<u32>,
+    /// Sample values, one per offset
+    pub values: Vec<T>,
+}
+
+impl<T> TimeSeries<T> {
+    /// Create an empty series starting at `start_time`
+    #[must_use]
+    pub const fn new(start_time: DateTime<Utc>, resolution_seconds: u32) -> Self {
+        Self {
+            start_time,
+            resolution_seconds,
+            offsets: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Append a sample taken `offset` seconds after the start
+    pub fn push(&mut self, offset: u32, value: T) {
+        self.offsets.push(offset);
+        self.values.push(value);
+    }
+
+    /// Number of samples
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the series has no samples
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Absolute time of each sample paired with its value
+    pub fn samples(&self) -> impl Iterator<Item = (DateTime<Utc>, &T)> {
+        self.offsets
+            .iter()
+            .zip(&self.values)
+            .map(|(&offset, value)| {
+                (
+                    self.start_time + Duration::seconds(i64::from(offset)),
+                    value,
+                )
+            })
+    }
+}
@@ -131,6 +131,8 @@ pub struct ToolCatalogEntry {
     pub requires_provider: Option<String>,
     /// Minimum subscription plan required for this tool
     pub min_plan: TenantPlan,
+    /// Execution timeout override in seconds (None uses the server default, 0 disables)
+    pub timeout_secs: Option<u64>,
     /// When this catalog entry was created
     pub created_at: DateTime<Utc>,
     /// When this catalog entry was last updated
@@ -166,6 +168,8 @@ pub enum ToolEnablementSource {
     Default,
     /// Enabled state from `tenant_tool_overrides`
     TenantOverride,
+    /// Enabled state from a `tool.<name>` feature flag
+    FeatureFlag,
     /// Disabled because tenant plan doesn't meet `min_plan` requirement
     PlanRestriction,
     /// Disabled globally via `PIERRE_DISABLED_TOOLS` environment variable
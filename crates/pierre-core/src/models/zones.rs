@@ -0,0 +1,29 @@
+// ABOUTME: Athlete training zone models for heart rate and power
+// ABOUTME: Contiguous zone ranges as configured by the athlete with their provider
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde::{Deserialize, Serialize};
+
+/// One training zone, from `min` up to (but not including) `max`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ZoneRange {
+    /// Lower bound in BPM or watts
+    pub min: u32,
+    /// Upper bound in BPM or watts; `None` for the open-ended top zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+}
+
+/// Heart rate and power zones the athlete has configured with their provider
+///
+/// Zones are ordered from easiest to hardest. Either list is empty when the
+/// provider has no zones of that kind for the athlete.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AthleteZones {
+    /// Heart rate zones in BPM
+    pub heart_rate: Vec<ZoneRange>,
+    /// Power zones in watts
+    pub power: Vec<ZoneRange>,
+}
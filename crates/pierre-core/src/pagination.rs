@@ -10,6 +10,8 @@ use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::errors::{AppError, AppResult};
+
 /// Opaque pagination cursor containing encoded position information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Cursor(String);
@@ -112,6 +114,76 @@ impl<T> CursorPage<T> {
             count: 0,
         }
     }
+
+    /// Build a page from an in-memory dataset
+    ///
+    /// `items` must be sorted the way the database queries order rows:
+    /// newest first by `(timestamp DESC, id DESC)`, where `key_fn` returns each
+    /// item's timestamp and ID. The decoded cursor marks a position rather than
+    /// an item, so the page stays correct when the cursor's item has since been
+    /// removed. Forward pages hold the items after the cursor (older); backward
+    /// pages hold the items before it (newer). `next_cursor` continues in the
+    /// requested direction and `prev_cursor` turns back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cursor cannot be decoded
+    pub fn from_sorted_slice<F>(
+        items: &[T],
+        params: &PaginationParams,
+        key_fn: F,
+    ) -> AppResult<Self>
+    where
+        T: Clone,
+        F: Fn(&T) -> (DateTime<Utc>, String),
+    {
+        // Cursors keep millisecond precision, so item keys are compared at the same precision
+        let position_key = |item: &T| {
+            let (timestamp, id) = key_fn(item);
+            (timestamp.timestamp_millis(), id)
+        };
+        let cursor_key = params
+            .cursor
+            .as_ref()
+            .map(|cursor| {
+                cursor
+                    .decode()
+                    .map(|(timestamp, id)| (timestamp.timestamp_millis(), id))
+                    .ok_or_else(|| AppError::invalid_input("Invalid cursor format"))
+            })
+            .transpose()?;
+
+        let (start, end) = match params.direction {
+            PaginationDirection::Forward => {
+                let start = cursor_key.map_or(0, |cursor_key| {
+                    items.partition_point(|item| position_key(item) >= cursor_key)
+                });
+                (start, start.saturating_add(params.limit).min(items.len()))
+            }
+            PaginationDirection::Backward => {
+                let end = cursor_key.map_or(items.len(), |cursor_key| {
+                    items.partition_point(|item| position_key(item) > cursor_key)
+                });
+                (end.saturating_sub(params.limit), end)
+            }
+        };
+
+        let page = &items[start..end];
+        let cursor_at = |item: &T| {
+            let (timestamp, id) = key_fn(item);
+            Cursor::new(timestamp, &id)
+        };
+        let older_cursor = page.last().filter(|_| end < items.len()).map(cursor_at);
+        let newer_cursor = page.first().filter(|_| start > 0).map(cursor_at);
+
+        let (next_cursor, prev_cursor) = match params.direction {
+            PaginationDirection::Forward => (older_cursor, newer_cursor),
+            PaginationDirection::Backward => (newer_cursor, older_cursor),
+        };
+        let has_more = next_cursor.is_some();
+
+        Ok(Self::new(page.to_vec(), next_cursor, prev_cursor, has_more))
+    }
 }
 
 /// Pagination parameters for cursor-based queries
@@ -7,7 +7,7 @@
 
 use super::{
     ActivityInsights, AdvancedInsight, AdvancedMetrics, Anomaly, Confidence, InsightSeverity,
-    MetricsCalculator,
+    MetricsCalculator, PatternDetector, PerformancePredictor,
 };
 use crate::config::intelligence::{ActivityAnalyzerConfig, IntelligenceConfig};
 use crate::errors::{AppError, AppResult};
@@ -26,7 +26,10 @@ use crate::physiological_constants::{
     },
     max_speeds::{DEFAULT_MAX_SPEED, MAX_CYCLING_SPEED, MAX_RUNNING_SPEED, MAX_SWIMMING_SPEED},
     performance::{HR_EFFICIENCY_IMPROVEMENT_THRESHOLD, PACE_IMPROVEMENT_THRESHOLD},
-    power::{COMPETITIVE_POWER_TO_WEIGHT, ELITE_POWER_TO_WEIGHT, RECREATIONAL_POWER_TO_WEIGHT},
+    power::{
+        COMPETITIVE_POWER_TO_WEIGHT, ELITE_POWER_TO_WEIGHT, HIGH_VARIABILITY_INDEX,
+        RECREATIONAL_POWER_TO_WEIGHT, STEADY_VARIABILITY_INDEX,
+    },
     running::FAST_PACE_THRESHOLD,
     training_load::{HIGH_TSS_THRESHOLD, LOW_TSS_THRESHOLD},
 };
@@ -51,6 +54,18 @@ fn safe_u32_to_u16(value: u32) -> u16 {
     }
 }
 
+/// Whether the activity is a bike ride of any kind
+const fn is_ride(activity: &Activity) -> bool {
+    matches!(
+        activity.sport_type(),
+        SportType::Ride
+            | SportType::VirtualRide
+            | SportType::EbikeRide
+            | SportType::MountainBike
+            | SportType::GravelRide
+    )
+}
+
 /// Trait for analyzing individual activities
 #[async_trait::async_trait]
 pub trait ActivityAnalyzerTrait {
@@ -253,6 +268,7 @@ impl AdvancedActivityAnalyzer {
                         confidence,
                         severity: InsightSeverity::Info,
                         metadata,
+                        computation_trace: None,
                     });
                 }
             }
@@ -294,6 +310,47 @@ impl AdvancedActivityAnalyzer {
                 confidence: Confidence::High,
                 severity,
                 metadata,
+                computation_trace: None,
+            });
+        }
+
+        // Normalized power insights for rides with power data
+        if let Some(normalized_power) = metrics.normalized_power.filter(|_| is_ride(activity)) {
+            let message = match metrics.variability_index {
+                Some(vi) if vi > HIGH_VARIABILITY_INDEX => {
+                    "Highly variable power - surges cost more than the average suggests".into()
+                }
+                Some(vi) if vi <= STEADY_VARIABILITY_INDEX => {
+                    "Steady power output - well-paced effort".into()
+                }
+                _ => format!("Normalized power of {normalized_power:.0} W"),
+            };
+
+            let mut metadata = HashMap::new();
+            metadata.insert(
+                "normalized_power".into(),
+                serde_json::Value::from(normalized_power),
+            );
+            if let Some(intensity_factor) = metrics.intensity_factor {
+                metadata.insert(
+                    "intensity_factor".into(),
+                    serde_json::Value::from(intensity_factor),
+                );
+            }
+            if let Some(variability_index) = metrics.variability_index {
+                metadata.insert(
+                    "variability_index".into(),
+                    serde_json::Value::from(variability_index),
+                );
+            }
+
+            insights.push(AdvancedInsight {
+                insight_type: "normalized_power".into(),
+                message,
+                confidence: Confidence::High,
+                severity: InsightSeverity::Info,
+                metadata,
+                computation_trace: None,
             });
         }
 
@@ -319,6 +376,41 @@ impl AdvancedActivityAnalyzer {
                 confidence: Confidence::Medium,
                 severity: InsightSeverity::Info,
                 metadata,
+                computation_trace: None,
+            });
+        }
+
+        // Grade-adjusted pace when the run has elevation data
+        if let Some(gap) = metrics.grade_adjusted_pace.filter(|gap| *gap > 0.0) {
+            let mut metadata = HashMap::new();
+            metadata.insert(
+                "grade_adjusted_pace_seconds_per_km".into(),
+                serde_json::Value::from(gap),
+            );
+            let gap_display = PerformancePredictor::format_pace_per_km(1000.0 / gap);
+            let message = match activity.average_speed().filter(|speed| *speed > 0.0) {
+                Some(speed) => {
+                    metadata.insert(
+                        "measured_pace_seconds_per_km".into(),
+                        serde_json::Value::from(1000.0 / speed),
+                    );
+                    format!(
+                        "Grade-adjusted pace {gap_display}/km vs {}/km measured - the equivalent effort on flat ground",
+                        PerformancePredictor::format_pace_per_km(speed)
+                    )
+                }
+                None => format!(
+                    "Grade-adjusted pace {gap_display}/km - the equivalent effort on flat ground"
+                ),
+            };
+
+            insights.push(AdvancedInsight {
+                insight_type: "grade_adjusted_pace".into(),
+                message,
+                confidence: Confidence::Medium,
+                severity: InsightSeverity::Info,
+                metadata,
+                computation_trace: None,
             });
         }
 
@@ -375,7 +467,15 @@ impl AdvancedActivityAnalyzer {
                     .push("Consider incorporating strength training for injury prevention".into());
             }
             SportType::Ride => {
-                // Power data not available in current Activity model
+                if metrics
+                    .variability_index
+                    .is_some_and(|vi| vi > HIGH_VARIABILITY_INDEX)
+                {
+                    recommendations.push(
+                        "Smooth out power surges to lower the cost of the same average power"
+                            .into(),
+                    );
+                }
                 recommendations.push("Remember bike maintenance for optimal performance".into());
             }
             SportType::Swim => {
@@ -436,6 +536,7 @@ impl AdvancedActivityAnalyzer {
                 confidence: Confidence::High,
                 severity: InsightSeverity::Info,
                 metadata,
+                computation_trace: None,
             });
         } else if improvement < -safe_f64_to_f32(PACE_IMPROVEMENT_THRESHOLD) {
             let mut metadata = HashMap::new();
@@ -453,6 +554,7 @@ impl AdvancedActivityAnalyzer {
                 confidence: Confidence::Medium,
                 severity: InsightSeverity::Warning,
                 metadata,
+                computation_trace: None,
             });
         }
 
@@ -519,6 +621,7 @@ impl AdvancedActivityAnalyzer {
                 confidence: Confidence::Medium,
                 severity: InsightSeverity::Info,
                 metadata,
+                computation_trace: None,
             }])
         } else {
             None
@@ -623,6 +726,12 @@ impl ActivityAnalyzerTrait for AdvancedActivityAnalyzer {
             }
         }
 
+        // Check per-point streams for GPS/distance spikes
+        anomalies.extend(PatternDetector::detect_speed_anomalies(
+            activity,
+            &self.config.analysis.speed_anomalies,
+        ));
+
         // Check for missing expected data
         if activity.average_heart_rate().is_none() && *activity.sport_type() != SportType::Swim {
             anomalies.push(Anomaly {
@@ -49,6 +49,8 @@ pub mod tss;
 pub mod vdot;
 /// `VO2max` estimation algorithms
 pub mod vo2max;
+/// Runtime-selectable `VO2max` estimation models
+pub mod vo2max_estimator;
 
 // Re-export algorithm types
 
@@ -70,3 +72,7 @@ pub use tss::TssAlgorithm;
 pub use vdot::VdotAlgorithm;
 /// `VO2max` estimation algorithm
 pub use vo2max::Vo2maxAlgorithm;
+/// Runtime-selectable `VO2max` estimation model
+pub use vo2max_estimator::Vo2maxEstimationAlgorithm;
+/// `VO2max` estimation model trait
+pub use vo2max_estimator::Vo2maxEstimator;
@@ -0,0 +1,485 @@
+// ABOUTME: Runtime-selectable VO2max estimation models sharing a standardized input and output
+// ABOUTME: Implements Cooper, Daniels VDOT, heart rate ratio, and Firstbeat-style HR/speed estimators
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! `VO2max` Estimation Models
+//!
+//! Unlike [`super::Vo2maxAlgorithm`], which evaluates a single field test with its
+//! protocol parameters, the models here estimate `VO2max` from whatever athlete data
+//! is on hand (recent races, heart rate data, age). The model is chosen at runtime,
+//! typically from `AlgorithmConfig::vo2max` / `PIERRE_VO2MAX_ALGORITHM`, and
+//! dispatched through the [`Vo2maxEstimator`] trait object.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pierre_mcp_server::intelligence::algorithms::vo2max_estimator::{
+//!     RaceResult, Vo2maxEstimationAlgorithm, Vo2maxEstimationInput,
+//! };
+//! # use pierre_mcp_server::errors::AppResult;
+//! # fn example() -> AppResult<()> {
+//! let input = Vo2maxEstimationInput {
+//!     recent_races: vec![RaceResult { distance_meters: 5000.0, time_seconds: 1200.0 }],
+//!     ..Vo2maxEstimationInput::default()
+//! };
+//! let estimator = Vo2maxEstimationAlgorithm::DanielsVdot.estimator();
+//! let estimate = estimator.estimate(&input)?; // ≈ 49.8 ml/kg/min
+//! # Ok(())
+//! # }
+//! ```
+
+use super::MaxHrAlgorithm;
+use crate::analysis_config::ConfidenceLevel;
+use crate::errors::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Physiological floor for reported `VO2max` (ml/kg/min)
+const MIN_VO2MAX: f64 = 20.0;
+
+/// Physiological ceiling for reported `VO2max` (ml/kg/min)
+const MAX_VO2MAX: f64 = 90.0;
+
+/// Resting oxygen consumption, one MET (ml/kg/min)
+const RESTING_VO2: f64 = 3.5;
+
+/// Cooper test duration (seconds)
+const COOPER_TEST_SECONDS: f64 = 720.0;
+
+/// Allowed deviation from 12 minutes for an effort to count as a Cooper test (seconds)
+const COOPER_TOLERANCE_SECONDS: f64 = 60.0;
+
+/// Uth-Sørensen heart rate ratio coefficient
+const UTH_COEFFICIENT: f64 = 15.3;
+
+/// ACSM running equation horizontal coefficient (ml/kg/min per m/min)
+const ACSM_RUNNING_COEFFICIENT: f64 = 0.2;
+
+/// Lowest heart rate reserve fraction where HR tracks oxygen uptake linearly
+const MIN_HEART_RATE_RESERVE: f64 = 0.4;
+
+/// Highest heart rate reserve fraction where HR tracks oxygen uptake linearly
+const MAX_HEART_RATE_RESERVE: f64 = 0.95;
+
+/// Efforts required before a Firstbeat-style estimate is considered reliable
+const MIN_RELIABLE_EFFORTS: usize = 3;
+
+/// VDOT spread within which multiple races count as agreeing
+const VDOT_AGREEMENT_SPREAD: f64 = 2.0;
+
+/// A race or time trial result
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RaceResult {
+    /// Race distance in meters
+    pub distance_meters: f64,
+    /// Finish time in seconds
+    pub time_seconds: f64,
+}
+
+/// A steady-state running effort with its average heart rate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HeartRateEffort {
+    /// Average running speed in meters per second
+    pub speed_mps: f64,
+    /// Average heart rate during the effort (bpm)
+    pub average_heart_rate: f64,
+}
+
+/// Standardized input shared by every `VO2max` estimation model
+///
+/// Each model reads the fields it needs and rejects the input if they are missing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Vo2maxEstimationInput {
+    /// Recent race or time trial results
+    #[serde(default)]
+    pub recent_races: Vec<RaceResult>,
+    /// Recent steady-state efforts with heart rate
+    #[serde(default)]
+    pub heart_rate_efforts: Vec<HeartRateEffort>,
+    /// Resting heart rate (bpm)
+    pub resting_heart_rate: Option<f64>,
+    /// Measured maximum heart rate (bpm)
+    pub max_heart_rate: Option<f64>,
+    /// Age in years, used to predict maximum heart rate when not measured
+    pub age: Option<u8>,
+}
+
+/// Result of a `VO2max` estimation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vo2maxEstimate {
+    /// Estimated `VO2max` (ml/kg/min)
+    pub vo2max: f64,
+    /// Confidence in the estimate given the data it was based on
+    pub confidence: ConfidenceLevel,
+    /// Model that produced the estimate
+    pub algorithm: Vo2maxEstimationAlgorithm,
+}
+
+/// A `VO2max` estimation model
+pub trait Vo2maxEstimator: Send + Sync {
+    /// Model identifier
+    fn algorithm(&self) -> Vo2maxEstimationAlgorithm;
+
+    /// Estimate `VO2max` from athlete data
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::InvalidInput` if the data the model needs is missing or
+    /// outside physiological ranges
+    fn estimate(&self, input: &Vo2maxEstimationInput) -> AppResult<Vo2maxEstimate>;
+}
+
+/// `VO2max` estimation model selection
+///
+/// - `CooperTest`: 12-minute run distance (Cooper, 1968)
+/// - `DanielsVdot`: best recent race via the Daniels-Gilbert oxygen cost equations
+/// - `HeartRateRatio`: `HRmax / HRrest` ratio (Uth et al., 2004)
+/// - `FirstbeatLike`: heart rate vs. running speed extrapolated to `HRmax`
+///
+/// # Scientific References
+///
+/// - Cooper, K.H. (1968). "A means of assessing maximal oxygen intake." *JAMA*, 203(3), 201-204.
+/// - Daniels, J. & Gilbert, J. (1979). "Oxygen Power: Performance Tables for Distance Runners."
+/// - Uth, N., et al. (2004). "Estimation of `VO2max` from the ratio between `HRmax` and `HRrest`."
+///   *European Journal of Applied Physiology*, 91(1), 111-115.
+/// - Swain, D.P. & Leutholtz, B.C. (1997). "Heart rate reserve is equivalent to %`VO2` reserve."
+///   *Medicine & Science in Sports & Exercise*, 29(3), 410-414.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vo2maxEstimationAlgorithm {
+    /// Cooper 12-minute run test
+    ///
+    /// Formula: `VO2max = (distance_meters - 504.9) / 44.73`
+    ///
+    /// Uses a recent effort lasting 11-13 minutes, scaled to 12 minutes.
+    CooperTest,
+
+    /// Jack Daniels' VDOT from race performance
+    ///
+    /// Formula: `VDOT = VO2(v) / %max(t)` with
+    /// `VO2(v) = -4.60 + 0.182258v + 0.000104v²` (v in m/min) and
+    /// `%max(t) = 0.8 + 0.1894393e^(-0.012778t) + 0.2989558e^(-0.1932605t)` (t in minutes)
+    ///
+    /// Uses the best of the recent races.
+    #[default]
+    #[serde(alias = "from_vdot")]
+    DanielsVdot,
+
+    /// Uth-Sørensen heart rate ratio
+    ///
+    /// Formula: `VO2max = 15.3 x HRmax / HRrest`
+    HeartRateRatio,
+
+    /// Firstbeat-style heart rate/speed relationship
+    ///
+    /// Each steady-state effort yields `VO2` from the ACSM running equation
+    /// (`3.5 + 0.2 x speed_m_per_min`), which is extrapolated to `HRmax` assuming
+    /// %`HRR` equals %`VO2R`: `VO2max = 3.5 + (VO2 - 3.5) / %HRR`. Efforts are averaged.
+    FirstbeatLike,
+}
+
+impl Vo2maxEstimationAlgorithm {
+    /// Get the estimator implementing this model
+    #[must_use]
+    pub fn estimator(self) -> Box<dyn Vo2maxEstimator> {
+        match self {
+            Self::CooperTest => Box::new(CooperTestEstimator),
+            Self::DanielsVdot => Box::new(DanielsVdotEstimator),
+            Self::HeartRateRatio => Box::new(HeartRateRatioEstimator),
+            Self::FirstbeatLike => Box::new(FirstbeatLikeEstimator),
+        }
+    }
+
+    /// Get algorithm name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CooperTest => "cooper_test",
+            Self::DanielsVdot => "daniels_vdot",
+            Self::HeartRateRatio => "heart_rate_ratio",
+            Self::FirstbeatLike => "firstbeat_like",
+        }
+    }
+
+    /// Get the formula as a string
+    #[must_use]
+    pub const fn formula(self) -> &'static str {
+        match self {
+            Self::CooperTest => "VO2max = (distance - 504.9) / 44.73",
+            Self::DanielsVdot => "VDOT = VO2(velocity) / %max(duration)",
+            Self::HeartRateRatio => "VO2max = 15.3 x HRmax / HRrest",
+            Self::FirstbeatLike => "VO2max = 3.5 + (VO2(speed) - 3.5) / %HRR",
+        }
+    }
+}
+
+impl fmt::Display for Vo2maxEstimationAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Vo2maxEstimationAlgorithm {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cooper" | "cooper_test" => Ok(Self::CooperTest),
+            "daniels" | "daniels_vdot" | "vdot" | "from_vdot" => Ok(Self::DanielsVdot),
+            "uth" | "hr_ratio" | "heart_rate_ratio" => Ok(Self::HeartRateRatio),
+            "firstbeat" | "firstbeat_like" => Ok(Self::FirstbeatLike),
+            other => Err(AppError::invalid_input(format!(
+                "Unknown VO2max estimation algorithm: '{other}'. Valid options: cooper_test, daniels_vdot, heart_rate_ratio, firstbeat_like"
+            ))),
+        }
+    }
+}
+
+/// Cooper 12-minute run test estimator
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CooperTestEstimator;
+
+impl Vo2maxEstimator for CooperTestEstimator {
+    fn algorithm(&self) -> Vo2maxEstimationAlgorithm {
+        Vo2maxEstimationAlgorithm::CooperTest
+    }
+
+    fn estimate(&self, input: &Vo2maxEstimationInput) -> AppResult<Vo2maxEstimate> {
+        // Prefer the effort closest to exactly 12 minutes
+        let effort = input
+            .recent_races
+            .iter()
+            .filter(|race| {
+                race.distance_meters > 0.0
+                    && (race.time_seconds - COOPER_TEST_SECONDS).abs() <= COOPER_TOLERANCE_SECONDS
+            })
+            .min_by(|a, b| {
+                (a.time_seconds - COOPER_TEST_SECONDS)
+                    .abs()
+                    .total_cmp(&(b.time_seconds - COOPER_TEST_SECONDS).abs())
+            })
+            .ok_or_else(|| {
+                AppError::invalid_input(
+                    "Cooper test estimation requires an 11-13 minute maximal effort".to_owned(),
+                )
+            })?;
+
+        let distance_12min = effort.distance_meters * COOPER_TEST_SECONDS / effort.time_seconds;
+        if !(1000.0..=5000.0).contains(&distance_12min) {
+            return Err(AppError::invalid_input(format!(
+                "Cooper test distance {distance_12min:.0}m is outside plausible range (1000-5000m)"
+            )));
+        }
+
+        let confidence = if (effort.time_seconds - COOPER_TEST_SECONDS).abs() < f64::EPSILON {
+            ConfidenceLevel::High
+        } else {
+            ConfidenceLevel::Medium
+        };
+
+        Ok(Vo2maxEstimate {
+            vo2max: clamp_vo2max((distance_12min - 504.9) / 44.73),
+            confidence,
+            algorithm: self.algorithm(),
+        })
+    }
+}
+
+/// Jack Daniels' VDOT race performance estimator
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DanielsVdotEstimator;
+
+impl DanielsVdotEstimator {
+    /// Daniels-Gilbert VDOT for a single race, or `None` if the race is outside
+    /// the velocity (100-500 m/min) and duration (3.5-300 min) the equations cover
+    fn race_vdot(race: &RaceResult) -> Option<f64> {
+        if race.distance_meters <= 0.0 || race.time_seconds <= 0.0 {
+            return None;
+        }
+        let velocity = race.distance_meters / race.time_seconds * 60.0;
+        let minutes = race.time_seconds / 60.0;
+        if !(100.0..=500.0).contains(&velocity) || !(3.5..=300.0).contains(&minutes) {
+            return None;
+        }
+
+        let vo2 =
+            0.000_104_f64.mul_add(velocity * velocity, 0.182_258_f64.mul_add(velocity, -4.60));
+        let percent_max = 0.298_955_8_f64.mul_add(
+            (-0.193_260_5 * minutes).exp(),
+            0.189_439_3_f64.mul_add((-0.012_778 * minutes).exp(), 0.8),
+        );
+        Some(vo2 / percent_max)
+    }
+}
+
+impl Vo2maxEstimator for DanielsVdotEstimator {
+    fn algorithm(&self) -> Vo2maxEstimationAlgorithm {
+        Vo2maxEstimationAlgorithm::DanielsVdot
+    }
+
+    fn estimate(&self, input: &Vo2maxEstimationInput) -> AppResult<Vo2maxEstimate> {
+        let scored: Vec<(f64, f64)> = input
+            .recent_races
+            .iter()
+            .filter_map(|race| Self::race_vdot(race).map(|vdot| (vdot, race.time_seconds)))
+            .collect();
+
+        let &(best_vdot, best_time) = scored
+            .iter()
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .ok_or_else(|| {
+                AppError::invalid_input(
+                    "Daniels VDOT estimation requires at least one race between 3.5 minutes and 5 hours"
+                        .to_owned(),
+                )
+            })?;
+
+        let agreeing = scored
+            .iter()
+            .filter(|(vdot, _)| best_vdot - vdot <= VDOT_AGREEMENT_SPREAD)
+            .count();
+        let confidence = if agreeing >= 2 {
+            ConfidenceLevel::VeryHigh
+        } else if (600.0..=5400.0).contains(&best_time) {
+            // 10-90 minute races sit where the equations were fitted most tightly
+            ConfidenceLevel::High
+        } else {
+            ConfidenceLevel::Medium
+        };
+
+        Ok(Vo2maxEstimate {
+            vo2max: clamp_vo2max(best_vdot),
+            confidence,
+            algorithm: self.algorithm(),
+        })
+    }
+}
+
+/// Uth-Sørensen heart rate ratio estimator
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeartRateRatioEstimator;
+
+impl Vo2maxEstimator for HeartRateRatioEstimator {
+    fn algorithm(&self) -> Vo2maxEstimationAlgorithm {
+        Vo2maxEstimationAlgorithm::HeartRateRatio
+    }
+
+    fn estimate(&self, input: &Vo2maxEstimationInput) -> AppResult<Vo2maxEstimate> {
+        let resting = resting_heart_rate(input)?;
+        let (max, measured) = max_heart_rate(input)?;
+        validate_heart_rate_span(resting, max)?;
+
+        Ok(Vo2maxEstimate {
+            vo2max: clamp_vo2max(UTH_COEFFICIENT * max / resting),
+            confidence: if measured {
+                ConfidenceLevel::High
+            } else {
+                ConfidenceLevel::Medium
+            },
+            algorithm: self.algorithm(),
+        })
+    }
+}
+
+/// Firstbeat-style heart rate/speed estimator
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FirstbeatLikeEstimator;
+
+impl Vo2maxEstimator for FirstbeatLikeEstimator {
+    fn algorithm(&self) -> Vo2maxEstimationAlgorithm {
+        Vo2maxEstimationAlgorithm::FirstbeatLike
+    }
+
+    fn estimate(&self, input: &Vo2maxEstimationInput) -> AppResult<Vo2maxEstimate> {
+        let resting = resting_heart_rate(input)?;
+        let (max, measured) = max_heart_rate(input)?;
+        validate_heart_rate_span(resting, max)?;
+
+        let estimates: Vec<f64> = input
+            .heart_rate_efforts
+            .iter()
+            .filter_map(|effort| {
+                let reserve = (effort.average_heart_rate - resting) / (max - resting);
+                if effort.speed_mps <= 0.0
+                    || !(MIN_HEART_RATE_RESERVE..=MAX_HEART_RATE_RESERVE).contains(&reserve)
+                {
+                    return None;
+                }
+                let vo2 = ACSM_RUNNING_COEFFICIENT.mul_add(effort.speed_mps * 60.0, RESTING_VO2);
+                Some(RESTING_VO2 + (vo2 - RESTING_VO2) / reserve)
+            })
+            .collect();
+
+        if estimates.is_empty() {
+            return Err(AppError::invalid_input(format!(
+                "Firstbeat-style estimation requires steady-state running efforts between {:.0}% and {:.0}% of heart rate reserve",
+                MIN_HEART_RATE_RESERVE * 100.0,
+                MAX_HEART_RATE_RESERVE * 100.0
+            )));
+        }
+
+        let vo2max = estimates.iter().sum::<f64>() / estimates.len() as f64;
+        let enough_efforts = estimates.len() >= MIN_RELIABLE_EFFORTS;
+        let confidence = match (enough_efforts, measured) {
+            (true, true) => ConfidenceLevel::High,
+            (true, false) | (false, true) => ConfidenceLevel::Medium,
+            (false, false) => ConfidenceLevel::Low,
+        };
+
+        Ok(Vo2maxEstimate {
+            vo2max: clamp_vo2max(vo2max),
+            confidence,
+            algorithm: self.algorithm(),
+        })
+    }
+}
+
+/// Resting heart rate from the input, validated to 30-100 bpm
+fn resting_heart_rate(input: &Vo2maxEstimationInput) -> AppResult<f64> {
+    let resting = input.resting_heart_rate.ok_or_else(|| {
+        AppError::invalid_input("Resting heart rate is required for this model".to_owned())
+    })?;
+    if !(30.0..=100.0).contains(&resting) {
+        return Err(AppError::invalid_input(format!(
+            "Resting heart rate {resting:.0} bpm is outside physiological range (30-100 bpm)"
+        )));
+    }
+    Ok(resting)
+}
+
+/// Maximum heart rate, measured if available or predicted from age (Tanaka)
+///
+/// The flag is `true` when the value was measured.
+fn max_heart_rate(input: &Vo2maxEstimationInput) -> AppResult<(f64, bool)> {
+    if let Some(max) = input.max_heart_rate {
+        if !(100.0..=230.0).contains(&max) {
+            return Err(AppError::invalid_input(format!(
+                "Max heart rate {max:.0} bpm is outside physiological range (100-230 bpm)"
+            )));
+        }
+        return Ok((max, true));
+    }
+    let age = input.age.ok_or_else(|| {
+        AppError::invalid_input("Max heart rate or age is required for this model".to_owned())
+    })?;
+    let predicted = MaxHrAlgorithm::Tanaka.estimate(u32::from(age), None)?;
+    Ok((predicted, false))
+}
+
+/// Ensure max heart rate is meaningfully above resting heart rate
+fn validate_heart_rate_span(resting: f64, max: f64) -> AppResult<()> {
+    if max - resting < 40.0 {
+        return Err(AppError::invalid_input(format!(
+            "Max heart rate {max:.0} bpm must exceed resting heart rate {resting:.0} bpm by at least 40 bpm"
+        )));
+    }
+    Ok(())
+}
+
+/// Clamp an estimate to the physiological range
+fn clamp_vo2max(vo2max: f64) -> f64 {
+    vo2max.clamp(MIN_VO2MAX, MAX_VO2MAX)
+}
@@ -17,6 +17,10 @@ use super::{
 use crate::models::{Activity, SportType};
 use crate::physiological_constants::{
     efficiency_defaults::{BASE_EFFICIENCY_SCORE, HR_EFFICIENCY_FACTOR, PACE_PER_KM_FACTOR},
+    heart_rate_zones::{
+        PERMILLE_DIVISOR, ZONE_1_MAX_PERMILLE, ZONE_2_MAX_PERMILLE, ZONE_3_MAX_PERMILLE,
+        ZONE_4_MAX_PERMILLE,
+    },
     performance_calculation::{
         ASSUMED_RESTING_HR, BIKE_DISTANCE_DIVISOR, BIKE_EFFORT_MULTIPLIER, EFFORT_HOUR_FACTOR,
         ELEVATION_EFFORT_DIVISOR, ELEVATION_EFFORT_FACTOR, HR_INTENSITY_EFFORT_FACTOR,
@@ -35,7 +39,7 @@ use crate::physiological_constants::{
         },
     },
 };
-use chrono::{DateTime, Local, Timelike, Utc};
+use chrono::{Local, Timelike};
 use std::fmt::Write;
 use tracing::instrument;
 
@@ -209,6 +213,10 @@ impl ActivityAnalyzer {
 
     /// Calculate heart rate zone distribution
     fn calculate_zone_distribution(activity: &Activity) -> Option<ZoneDistribution> {
+        if let Some(zones) = Self::measured_zone_distribution(activity) {
+            return Some(zones);
+        }
+
         // Estimates zone distribution from avg/max heart rate (detailed HR timeseries not available from all providers)
         if let (Some(avg_hr), Some(max_hr)) =
             (activity.average_heart_rate(), activity.max_heart_rate())
@@ -270,6 +278,42 @@ impl ActivityAnalyzer {
         }
     }
 
+    /// Measure zone distribution from the heart rate stream, with zones as percent of max HR
+    fn measured_zone_distribution(activity: &Activity) -> Option<ZoneDistribution> {
+        let samples = activity
+            .time_series_data()?
+            .heart_rate
+            .as_deref()
+            .filter(|samples| !samples.is_empty())?;
+        let max_hr = activity
+            .max_heart_rate()
+            .or_else(|| samples.iter().copied().max())
+            .filter(|&max_hr| max_hr > 0)?;
+
+        let mut counts = [0_u32; 5];
+        for &hr in samples {
+            let permille = u64::from(hr) * PERMILLE_DIVISOR / u64::from(max_hr);
+            let zone = match permille {
+                p if p < u64::from(ZONE_1_MAX_PERMILLE) => 0,
+                p if p < u64::from(ZONE_2_MAX_PERMILLE) => 1,
+                p if p < u64::from(ZONE_3_MAX_PERMILLE) => 2,
+                p if p < u64::from(ZONE_4_MAX_PERMILLE) => 3,
+                _ => 4,
+            };
+            counts[zone] += 1;
+        }
+
+        let total = safe_u32_to_f32(counts.iter().sum());
+        let percent = |count: u32| safe_u32_to_f32(count) * 100.0 / total;
+        Some(ZoneDistribution {
+            zone1_recovery: percent(counts[0]),
+            zone2_endurance: percent(counts[1]),
+            zone3_tempo: percent(counts[2]),
+            zone4_threshold: percent(counts[3]),
+            zone5_vo2max: percent(counts[4]),
+        })
+    }
+
     /// Detect personal records (simplified version)
     fn detect_personal_records(activity: &Activity) -> Vec<PersonalRecord> {
         let mut records = Vec::new();
@@ -365,7 +409,7 @@ impl ActivityAnalyzer {
         activity: &Activity,
         context: Option<&ActivityContext>,
     ) -> ContextualFactors {
-        let time_of_day = Self::determine_time_of_day(&activity.start_date());
+        let time_of_day = Self::determine_time_of_day(activity);
 
         ContextualFactors {
             weather: None, // Weather analysis was removed
@@ -376,11 +420,14 @@ impl ActivityAnalyzer {
         }
     }
 
-    /// Determine time of day category based on local time
-    fn determine_time_of_day(start_date: &DateTime<Utc>) -> TimeOfDay {
-        // Convert UTC to local time for proper categorization
-        let local_time = start_date.with_timezone(&Local);
-        match local_time.hour() {
+    /// Determine time of day category based on the activity's local start time
+    fn determine_time_of_day(activity: &Activity) -> TimeOfDay {
+        // Prefer the provider-reported offset; fall back to the server zone when unknown
+        let hour = activity.local_start_date().map_or_else(
+            || activity.start_date().with_timezone(&Local).hour(),
+            |local_time| local_time.hour(),
+        );
+        match hour {
             5..=6 => TimeOfDay::EarlyMorning, // 5-7 AM
             7..=10 => TimeOfDay::Morning,     // 7-11 AM
             11..=13 => TimeOfDay::Midday,     // 11 AM - 2 PM
@@ -10,7 +10,12 @@
 //! scoring weights, and insight generation thresholds.
 
 use crate::constants::limits;
+use crate::models::SportType;
+use crate::physiological_constants::max_speeds::{
+    DEFAULT_MAX_SPEED, MAX_CYCLING_SPEED, MAX_RUNNING_SPEED, MAX_SWIMMING_SPEED,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Activity Analyzer Configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -34,6 +39,33 @@ pub struct ActivityAnalysisConfig {
     pub heart_rate_zones: HeartRateZonesConfig,
     /// Power zone definitions
     pub power_zones: PowerZonesConfig,
+    /// Per-point speed spike detection settings
+    #[serde(default)]
+    pub speed_anomalies: SpeedAnomalyConfig,
+}
+
+/// Configuration for detecting GPS/distance spikes in per-point speed streams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeedAnomalyConfig {
+    /// Maximum plausible speed per sport (m/s)
+    pub max_speed_by_sport: HashMap<SportType, f64>,
+    /// Maximum plausible speed for sports missing from the table (m/s)
+    pub default_max_speed: f64,
+    /// Samples on each side of a point used for the median baseline
+    pub median_window_radius: usize,
+    /// Minimum consecutive over-limit samples reported as an anomaly
+    pub min_spike_points: usize,
+}
+
+impl SpeedAnomalyConfig {
+    /// Maximum plausible speed (m/s) for the given sport
+    #[must_use]
+    pub fn max_speed_for(&self, sport_type: &SportType) -> f64 {
+        self.max_speed_by_sport
+            .get(sport_type)
+            .copied()
+            .unwrap_or(self.default_max_speed)
+    }
 }
 
 /// Heart rate zone percentage thresholds
@@ -108,6 +140,32 @@ impl Default for ActivityAnalysisConfig {
             max_reasonable_pace: 15.0, // 15 min/km
             heart_rate_zones: HeartRateZonesConfig::default(),
             power_zones: PowerZonesConfig::default(),
+            speed_anomalies: SpeedAnomalyConfig::default(),
+        }
+    }
+}
+
+impl Default for SpeedAnomalyConfig {
+    fn default() -> Self {
+        let max_speed_by_sport = [
+            (SportType::Run, MAX_RUNNING_SPEED),
+            (SportType::TrailRunning, MAX_RUNNING_SPEED),
+            (SportType::Walk, 5.0), // 18 km/h
+            (SportType::Hike, 5.0),
+            (SportType::Ride, MAX_CYCLING_SPEED),
+            (SportType::MountainBike, MAX_CYCLING_SPEED),
+            (SportType::GravelRide, MAX_CYCLING_SPEED),
+            (SportType::EbikeRide, MAX_CYCLING_SPEED),
+            (SportType::Swim, MAX_SWIMMING_SPEED),
+        ]
+        .into_iter()
+        .collect();
+
+        Self {
+            max_speed_by_sport,
+            default_max_speed: DEFAULT_MAX_SPEED,
+            median_window_radius: 5,
+            min_spike_points: 1,
         }
     }
 }
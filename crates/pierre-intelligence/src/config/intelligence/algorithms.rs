@@ -15,7 +15,7 @@
 //! - **`MaxHR`**: Maximum heart rate estimation (`fox`, `tanaka`, `nes`, `gulati`)
 //! - **FTP**: Functional Threshold Power estimation
 //! - **LTHR**: Lactate Threshold Heart Rate estimation
-//! - **`VO2max`**: Maximum oxygen uptake estimation
+//! - **`VO2max`**: Maximum oxygen uptake estimation (`cooper_test`, `daniels_vdot`, `heart_rate_ratio`, `firstbeat_like`)
 //!
 //! # Configuration Methods
 //!
@@ -23,10 +23,12 @@
 //!    ```bash
 //!    export PIERRE_TSS_ALGORITHM=normalized_power
 //!    export PIERRE_MAXHR_ALGORITHM=tanaka
+//!    export PIERRE_VO2MAX_ALGORITHM=heart_rate_ratio
 //!    ```
 //!
 //! 2. Default values (if env vars not set)
 
+use crate::algorithms::Vo2maxEstimationAlgorithm;
 use serde::{Deserialize, Serialize};
 
 /// Algorithm Selection Configuration
@@ -48,9 +50,9 @@ pub struct AlgorithmConfig {
     #[serde(default = "default_lthr_algorithm")]
     pub lthr: String,
 
-    /// `VO2max` estimation algorithm: `from_vdot`, `cooper_test`, etc.
-    #[serde(default = "default_vo2max_algorithm")]
-    pub vo2max: String,
+    /// `VO2max` estimation model: `cooper_test`, `daniels_vdot`, `heart_rate_ratio`, or `firstbeat_like`
+    #[serde(default)]
+    pub vo2max: Vo2maxEstimationAlgorithm,
 }
 
 /// Default TSS algorithm (`avg_power` for backwards compatibility)
@@ -73,11 +75,6 @@ fn default_lthr_algorithm() -> String {
     "from_maxhr".to_owned()
 }
 
-/// Default `VO2max` algorithm (`from_vdot` as most validated)
-fn default_vo2max_algorithm() -> String {
-    "from_vdot".to_owned()
-}
-
 impl Default for AlgorithmConfig {
     fn default() -> Self {
         Self {
@@ -85,7 +82,7 @@ impl Default for AlgorithmConfig {
             maxhr: default_maxhr_algorithm(),
             ftp: default_ftp_algorithm(),
             lthr: default_lthr_algorithm(),
-            vo2max: default_vo2max_algorithm(),
+            vo2max: Vo2maxEstimationAlgorithm::default(),
         }
     }
 }
@@ -43,7 +43,7 @@ pub mod weather;
 // Re-export all types for convenience
 pub use activity::{
     ActivityAnalysisConfig, ActivityAnalyzerConfig, ActivityInsightsConfig, ActivityScoringConfig,
-    HeartRateZonesConfig, PowerZonesConfig, SeverityThresholds,
+    HeartRateZonesConfig, PowerZonesConfig, SeverityThresholds, SpeedAnomalyConfig,
 };
 pub use algorithms::AlgorithmConfig;
 pub use error::ConfigError;
@@ -200,6 +200,21 @@ impl IntelligenceConfig<true> {
             ));
         }
 
+        // Validate speed anomaly detection
+        let speed = &self.activity_analyzer.analysis.speed_anomalies;
+        if speed.min_spike_points == 0 {
+            return Err(ConfigError::ValueOutOfRange(
+                "min_spike_points must be at least 1",
+            ));
+        }
+        if speed.default_max_speed <= 0.0
+            || speed.max_speed_by_sport.values().any(|speed| *speed <= 0.0)
+        {
+            return Err(ConfigError::ValueOutOfRange(
+                "max speeds for anomaly detection must be positive",
+            ));
+        }
+
         // Validate sleep duration thresholds
         let sleep_dur = &self.sleep_recovery.sleep_duration;
         if sleep_dur.adult_min_hours >= sleep_dur.adult_max_hours {
@@ -569,6 +584,7 @@ impl IntelligenceConfig<true> {
         // Algorithm selection overrides
         Self::apply_env_var("PIERRE_TSS_ALGORITHM", &mut self.algorithms.tss)?;
         Self::apply_env_var("PIERRE_MAXHR_ALGORITHM", &mut self.algorithms.maxhr)?;
+        Self::apply_env_var("PIERRE_VO2MAX_ALGORITHM", &mut self.algorithms.vo2max)?;
 
         Ok(self)
     }
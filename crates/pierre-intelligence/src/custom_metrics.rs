@@ -0,0 +1,607 @@
+// ABOUTME: Sandboxed expression evaluator for user-defined derived activity metrics
+// ABOUTME: Parses arithmetic over a whitelist of activity fields and computes custom metric values
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Custom Metric Expressions
+//!
+//! Lets advanced users define derived metrics such as "TSS per hour" without code
+//! changes. Expressions are restricted to arithmetic (`+`, `-`, `*`, `/`, parentheses,
+//! numeric literals) over a fixed whitelist of activity fields. There are no function
+//! calls, variables, or loops, and expression size and nesting depth are bounded, so
+//! evaluation cost is always linear in the (small) expression size.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pierre_intelligence::custom_metrics::CustomMetricDefinition;
+//!
+//! let definition = CustomMetricDefinition {
+//!     name: "tss_per_hour".to_owned(),
+//!     expression: "training_stress_score / duration_hours".to_owned(),
+//!     unit: Some("TSS/h".to_owned()),
+//! };
+//! assert!(definition.compile().is_ok());
+//! ```
+
+use crate::constants::time_constants::SECONDS_PER_HOUR_F64;
+use crate::metrics::AdvancedMetrics;
+use crate::models::Activity;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum length of a custom metric expression in characters
+pub const MAX_EXPRESSION_LENGTH: usize = 256;
+
+/// Maximum number of tokens in a custom metric expression
+pub const MAX_EXPRESSION_TOKENS: usize = 64;
+
+/// Maximum nesting depth (parentheses and unary operators) of an expression
+pub const MAX_EXPRESSION_DEPTH: usize = 16;
+
+/// Maximum length of a custom metric name
+pub const MAX_METRIC_NAME_LENGTH: usize = 64;
+
+/// Maximum number of custom metrics evaluated per request
+pub const MAX_CUSTOM_METRICS: usize = 16;
+
+/// Errors raised while validating a custom metric definition
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CustomMetricError {
+    /// Metric name is empty, too long, or not `snake_case`
+    #[error("Invalid metric name: {0}")]
+    InvalidName(String),
+
+    /// Expression exceeds the size limits
+    #[error("Expression too large: {0}")]
+    TooLarge(String),
+
+    /// Expression references a field outside the whitelist
+    #[error("Unknown field '{0}' (not in the allowed field list)")]
+    UnknownField(String),
+
+    /// Expression contains a character that is not allowed
+    #[error("Unexpected character '{0}' in expression")]
+    UnexpectedCharacter(char),
+
+    /// Expression is syntactically invalid
+    #[error("Syntax error: {0}")]
+    Syntax(String),
+}
+
+/// Whitelisted activity fields that custom metric expressions may reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomMetricField {
+    /// Total distance in meters
+    DistanceMeters,
+    /// Distance in kilometers
+    DistanceKm,
+    /// Total duration in seconds
+    DurationSeconds,
+    /// Total duration in hours
+    DurationHours,
+    /// Elevation gain in meters
+    ElevationGain,
+    /// Average heart rate (bpm)
+    AverageHeartRate,
+    /// Maximum heart rate (bpm)
+    MaxHeartRate,
+    /// Average speed (m/s)
+    AverageSpeed,
+    /// Maximum speed (m/s)
+    MaxSpeed,
+    /// Calories burned
+    Calories,
+    /// Average power (watts)
+    AveragePower,
+    /// Maximum power (watts)
+    MaxPower,
+    /// Normalized power (watts)
+    NormalizedPower,
+    /// Average cadence
+    AverageCadence,
+    /// Training stress score (provider value or calculated)
+    TrainingStressScore,
+    /// Intensity factor (provider value or calculated)
+    IntensityFactor,
+    /// Training impulse (calculated)
+    Trimp,
+    /// Suffer score / relative effort
+    SufferScore,
+}
+
+impl CustomMetricField {
+    /// All whitelisted fields, in documentation order
+    pub const ALL: [Self; 18] = [
+        Self::DistanceMeters,
+        Self::DistanceKm,
+        Self::DurationSeconds,
+        Self::DurationHours,
+        Self::ElevationGain,
+        Self::AverageHeartRate,
+        Self::MaxHeartRate,
+        Self::AverageSpeed,
+        Self::MaxSpeed,
+        Self::Calories,
+        Self::AveragePower,
+        Self::MaxPower,
+        Self::NormalizedPower,
+        Self::AverageCadence,
+        Self::TrainingStressScore,
+        Self::IntensityFactor,
+        Self::Trimp,
+        Self::SufferScore,
+    ];
+
+    /// Identifier used to reference this field in expressions
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DistanceMeters => "distance_meters",
+            Self::DistanceKm => "distance_km",
+            Self::DurationSeconds => "duration_seconds",
+            Self::DurationHours => "duration_hours",
+            Self::ElevationGain => "elevation_gain",
+            Self::AverageHeartRate => "average_heart_rate",
+            Self::MaxHeartRate => "max_heart_rate",
+            Self::AverageSpeed => "average_speed",
+            Self::MaxSpeed => "max_speed",
+            Self::Calories => "calories",
+            Self::AveragePower => "average_power",
+            Self::MaxPower => "max_power",
+            Self::NormalizedPower => "normalized_power",
+            Self::AverageCadence => "average_cadence",
+            Self::TrainingStressScore => "training_stress_score",
+            Self::IntensityFactor => "intensity_factor",
+            Self::Trimp => "trimp",
+            Self::SufferScore => "suffer_score",
+        }
+    }
+
+    /// Look up a whitelisted field by its expression identifier
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.name() == name)
+    }
+
+    /// Resolve the field value from an activity and its calculated metrics
+    ///
+    /// Calculated metrics take precedence for derived values (TSS, IF, NP) so
+    /// expressions see the same numbers as the rest of the analysis output.
+    #[must_use]
+    pub fn resolve(self, activity: &Activity, metrics: &AdvancedMetrics) -> Option<f64> {
+        match self {
+            Self::DistanceMeters => activity.distance_meters(),
+            Self::DistanceKm => activity.distance_meters().map(|m| m / 1000.0),
+            Self::DurationSeconds => Some(activity.duration_seconds() as f64),
+            Self::DurationHours => Some(activity.duration_seconds() as f64 / SECONDS_PER_HOUR_F64),
+            Self::ElevationGain => activity.elevation_gain(),
+            Self::AverageHeartRate => activity.average_heart_rate().map(f64::from),
+            Self::MaxHeartRate => activity.max_heart_rate().map(f64::from),
+            Self::AverageSpeed => activity.average_speed(),
+            Self::MaxSpeed => activity.max_speed(),
+            Self::Calories => activity.calories().map(f64::from),
+            Self::AveragePower => activity.average_power().map(f64::from),
+            Self::MaxPower => activity.max_power().map(f64::from),
+            Self::NormalizedPower => metrics
+                .normalized_power
+                .or_else(|| activity.normalized_power().map(f64::from)),
+            Self::AverageCadence => activity.average_cadence().map(f64::from),
+            Self::TrainingStressScore => metrics
+                .training_stress_score
+                .or_else(|| activity.training_stress_score().map(f64::from)),
+            Self::IntensityFactor => metrics
+                .intensity_factor
+                .or_else(|| activity.intensity_factor().map(f64::from)),
+            Self::Trimp => metrics.trimp,
+            Self::SufferScore => activity.suffer_score().map(f64::from),
+        }
+    }
+}
+
+impl fmt::Display for CustomMetricField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// User-supplied definition of a derived metric
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomMetricDefinition {
+    /// Metric name (`snake_case`), used as the key in analysis output
+    pub name: String,
+    /// Arithmetic expression over whitelisted fields (e.g. `training_stress_score / duration_hours`)
+    pub expression: String,
+    /// Optional display unit (e.g. "TSS/h")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl CustomMetricDefinition {
+    /// Validate the definition and compile its expression
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, the expression exceeds size limits,
+    /// references a non-whitelisted field, or is syntactically invalid
+    pub fn compile(&self) -> Result<CompiledCustomMetric, CustomMetricError> {
+        validate_metric_name(&self.name)?;
+        let expression = CustomMetricExpression::parse(&self.expression)?;
+        Ok(CompiledCustomMetric {
+            name: self.name.clone(),
+            unit: self.unit.clone(),
+            expression,
+        })
+    }
+}
+
+/// A validated custom metric ready for evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledCustomMetric {
+    name: String,
+    unit: Option<String>,
+    expression: CustomMetricExpression,
+}
+
+impl CompiledCustomMetric {
+    /// Metric name, used as the key in analysis output
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Optional display unit
+    #[must_use]
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
+
+    /// Validated expression
+    #[must_use]
+    pub const fn expression(&self) -> &CustomMetricExpression {
+        &self.expression
+    }
+
+    /// Evaluate this metric for an activity
+    ///
+    /// Returns `None` when a referenced field is unavailable, on division by zero,
+    /// or when the result is not a finite number.
+    #[must_use]
+    pub fn evaluate(&self, activity: &Activity, metrics: &AdvancedMetrics) -> Option<f64> {
+        self.expression.evaluate(activity, metrics)
+    }
+}
+
+/// Compile a batch of definitions, failing on the first invalid one
+///
+/// # Errors
+///
+/// Returns an error if there are more than [`MAX_CUSTOM_METRICS`] definitions,
+/// any definition fails validation, or two definitions share a name
+pub fn compile_definitions(
+    definitions: &[CustomMetricDefinition],
+) -> Result<Vec<CompiledCustomMetric>, CustomMetricError> {
+    if definitions.len() > MAX_CUSTOM_METRICS {
+        return Err(CustomMetricError::TooLarge(format!(
+            "{} metrics exceeds limit of {MAX_CUSTOM_METRICS}",
+            definitions.len()
+        )));
+    }
+    let mut compiled: Vec<CompiledCustomMetric> = Vec::with_capacity(definitions.len());
+    for definition in definitions {
+        if compiled.iter().any(|metric| metric.name == definition.name) {
+            return Err(CustomMetricError::InvalidName(format!(
+                "duplicate metric name '{}'",
+                definition.name
+            )));
+        }
+        compiled.push(definition.compile()?);
+    }
+    Ok(compiled)
+}
+
+/// Binary arithmetic operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOperator {
+    /// Addition
+    Add,
+    /// Subtraction
+    Subtract,
+    /// Multiplication
+    Multiply,
+    /// Division
+    Divide,
+}
+
+/// Parsed custom metric expression
+///
+/// Only constructible through [`CustomMetricExpression::parse`], so every instance
+/// has passed the size, depth and field whitelist checks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomMetricExpression {
+    source: String,
+    root: ExpressionNode,
+}
+
+/// Node of a parsed expression tree
+#[derive(Debug, Clone, PartialEq)]
+enum ExpressionNode {
+    Number(f64),
+    Field(CustomMetricField),
+    Negate(Box<Self>),
+    Binary(BinaryOperator, Box<Self>, Box<Self>),
+}
+
+impl CustomMetricExpression {
+    /// Parse and validate an expression string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression exceeds size limits, contains disallowed
+    /// characters or identifiers, or is syntactically invalid
+    pub fn parse(input: &str) -> Result<Self, CustomMetricError> {
+        if input.trim().is_empty() {
+            return Err(CustomMetricError::Syntax("expression is empty".to_owned()));
+        }
+        let length = input.chars().count();
+        if length > MAX_EXPRESSION_LENGTH {
+            return Err(CustomMetricError::TooLarge(format!(
+                "{length} characters exceeds limit of {MAX_EXPRESSION_LENGTH}"
+            )));
+        }
+
+        let tokens = tokenize(input)?;
+        if tokens.len() > MAX_EXPRESSION_TOKENS {
+            return Err(CustomMetricError::TooLarge(format!(
+                "{} tokens exceeds limit of {MAX_EXPRESSION_TOKENS}",
+                tokens.len()
+            )));
+        }
+
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+        };
+        let root = parser.parse_expression(0)?;
+        if let Some(token) = parser.peek() {
+            return Err(CustomMetricError::Syntax(format!(
+                "unexpected trailing token {token}"
+            )));
+        }
+        Ok(Self {
+            source: input.trim().to_owned(),
+            root,
+        })
+    }
+
+    /// Original expression text
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluate the expression against an activity and its calculated metrics
+    #[must_use]
+    pub fn evaluate(&self, activity: &Activity, metrics: &AdvancedMetrics) -> Option<f64> {
+        self.root.evaluate(activity, metrics)
+    }
+}
+
+impl fmt::Display for CustomMetricExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl ExpressionNode {
+    fn evaluate(&self, activity: &Activity, metrics: &AdvancedMetrics) -> Option<f64> {
+        let value = match self {
+            Self::Number(value) => *value,
+            Self::Field(field) => field.resolve(activity, metrics)?,
+            Self::Negate(inner) => -inner.evaluate(activity, metrics)?,
+            Self::Binary(op, lhs, rhs) => {
+                let left = lhs.evaluate(activity, metrics)?;
+                let right = rhs.evaluate(activity, metrics)?;
+                match op {
+                    BinaryOperator::Add => left + right,
+                    BinaryOperator::Subtract => left - right,
+                    BinaryOperator::Multiply => left * right,
+                    BinaryOperator::Divide => {
+                        if right == 0.0 {
+                            return None;
+                        }
+                        left / right
+                    }
+                }
+            }
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+fn validate_metric_name(name: &str) -> Result<(), CustomMetricError> {
+    if name.is_empty() || name.len() > MAX_METRIC_NAME_LENGTH {
+        return Err(CustomMetricError::InvalidName(format!(
+            "name must be 1-{MAX_METRIC_NAME_LENGTH} characters"
+        )));
+    }
+    let starts_with_letter = name.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+    let snake_case = name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !starts_with_letter || !snake_case {
+        return Err(CustomMetricError::InvalidName(format!(
+            "'{name}' must be snake_case and start with a letter"
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Operator(BinaryOperator),
+    OpenParen,
+    CloseParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "'{value}'"),
+            Self::Identifier(name) => write!(f, "'{name}'"),
+            Self::Operator(op) => f.write_str(match op {
+                BinaryOperator::Add => "'+'",
+                BinaryOperator::Subtract => "'-'",
+                BinaryOperator::Multiply => "'*'",
+                BinaryOperator::Divide => "'/'",
+            }),
+            Self::OpenParen => f.write_str("'('"),
+            Self::CloseParen => f.write_str("')'"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, CustomMetricError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' => i += 1,
+            '+' | '-' | '*' | '/' => {
+                tokens.push(Token::Operator(match c {
+                    '+' => BinaryOperator::Add,
+                    '-' => BinaryOperator::Subtract,
+                    '*' => BinaryOperator::Multiply,
+                    _ => BinaryOperator::Divide,
+                }));
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::OpenParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::CloseParen);
+                i += 1;
+            }
+            '0'..='9' | '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                let value = literal.parse::<f64>().map_err(|_| {
+                    CustomMetricError::Syntax(format!("invalid number '{literal}'"))
+                })?;
+                tokens.push(Token::Number(value));
+            }
+            c if c.is_ascii_lowercase() || c == '_' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_ascii_lowercase()
+                        || chars[i].is_ascii_digit()
+                        || chars[i] == '_')
+                {
+                    i += 1;
+                }
+                tokens.push(Token::Identifier(chars[start..i].iter().collect()));
+            }
+            other => return Err(CustomMetricError::UnexpectedCharacter(other)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser with bounded depth
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    /// expression := term (('+' | '-') term)*
+    fn parse_expression(&mut self, depth: usize) -> Result<ExpressionNode, CustomMetricError> {
+        let mut lhs = self.parse_term(depth)?;
+        while let Some(Token::Operator(op @ (BinaryOperator::Add | BinaryOperator::Subtract))) =
+            self.peek()
+        {
+            let op = *op;
+            self.position += 1;
+            let rhs = self.parse_term(depth)?;
+            lhs = ExpressionNode::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    /// term := factor (('*' | '/') factor)*
+    fn parse_term(&mut self, depth: usize) -> Result<ExpressionNode, CustomMetricError> {
+        let mut lhs = self.parse_factor(depth)?;
+        while let Some(Token::Operator(op @ (BinaryOperator::Multiply | BinaryOperator::Divide))) =
+            self.peek()
+        {
+            let op = *op;
+            self.position += 1;
+            let rhs = self.parse_factor(depth)?;
+            lhs = ExpressionNode::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    /// factor := number | field | '-' factor | '(' expression ')'
+    fn parse_factor(&mut self, depth: usize) -> Result<ExpressionNode, CustomMetricError> {
+        if depth >= MAX_EXPRESSION_DEPTH {
+            return Err(CustomMetricError::TooLarge(format!(
+                "nesting exceeds depth limit of {MAX_EXPRESSION_DEPTH}"
+            )));
+        }
+
+        match self.next().cloned() {
+            Some(Token::Number(value)) => Ok(ExpressionNode::Number(value)),
+            Some(Token::Identifier(name)) => {
+                if matches!(self.peek(), Some(Token::OpenParen)) {
+                    return Err(CustomMetricError::Syntax(format!(
+                        "function calls are not allowed ('{name}')"
+                    )));
+                }
+                CustomMetricField::from_name(&name)
+                    .map(ExpressionNode::Field)
+                    .ok_or(CustomMetricError::UnknownField(name))
+            }
+            Some(Token::Operator(BinaryOperator::Subtract)) => Ok(ExpressionNode::Negate(
+                Box::new(self.parse_factor(depth + 1)?),
+            )),
+            Some(Token::OpenParen) => {
+                let inner = self.parse_expression(depth + 1)?;
+                match self.next() {
+                    Some(Token::CloseParen) => Ok(inner),
+                    _ => Err(CustomMetricError::Syntax("missing closing ')'".to_owned())),
+                }
+            }
+            Some(token) => Err(CustomMetricError::Syntax(format!(
+                "unexpected token {token}"
+            ))),
+            None => Err(CustomMetricError::Syntax(
+                "unexpected end of expression".to_owned(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// ABOUTME: Gear wear monitoring that flags running shoes past their replacement mileage
+// ABOUTME: Compares accumulated gear distance against a configurable shoe replacement threshold
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Gear Wear Module
+//!
+//! Running shoe midsoles lose a large share of their cushioning over their first
+//! several hundred kilometers. Most manufacturers recommend replacement somewhere
+//! between 500 and 800 km, so shoes beyond a threshold in that range are flagged.
+//! Retired gear and non-shoe equipment are never flagged.
+
+use crate::models::{Gear, GearType};
+use serde::{Deserialize, Serialize};
+
+/// Default shoe replacement threshold (kilometers)
+pub const DEFAULT_SHOE_REPLACEMENT_KM: f64 = 800.0;
+
+/// Meters per kilometer
+const METERS_PER_KM: f64 = 1000.0;
+
+/// Warning for a pair of active shoes past the replacement threshold
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GearMileageWarning {
+    /// Identifier of the flagged gear
+    pub gear_id: String,
+    /// Name of the flagged gear
+    pub gear_name: String,
+    /// Distance logged with the gear (kilometers)
+    pub distance_km: f64,
+    /// Threshold the gear exceeded (kilometers)
+    pub threshold_km: f64,
+    /// Distance beyond the threshold (kilometers)
+    pub over_by_km: f64,
+    /// Human-readable replacement advice
+    pub message: String,
+}
+
+/// Flags active shoes whose accumulated distance exceeds a replacement threshold
+#[derive(Debug, Clone, Copy)]
+pub struct GearWearMonitor {
+    shoe_replacement_km: f64,
+}
+
+impl Default for GearWearMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_SHOE_REPLACEMENT_KM)
+    }
+}
+
+impl GearWearMonitor {
+    /// Create a monitor with the given shoe replacement threshold in kilometers
+    #[must_use]
+    pub const fn new(shoe_replacement_km: f64) -> Self {
+        Self {
+            shoe_replacement_km,
+        }
+    }
+
+    /// Shoe replacement threshold in kilometers
+    #[must_use]
+    pub const fn shoe_replacement_km(&self) -> f64 {
+        self.shoe_replacement_km
+    }
+
+    /// Return a warning for every active pair of shoes over the threshold
+    #[must_use]
+    pub fn check(&self, gear: &[Gear]) -> Vec<GearMileageWarning> {
+        gear.iter()
+            .filter(|item| item.gear_type == GearType::Shoes && !item.retired)
+            .filter_map(|item| {
+                let distance_km = item.distance_meters / METERS_PER_KM;
+                (distance_km > self.shoe_replacement_km).then(|| GearMileageWarning {
+                    gear_id: item.id.clone(),
+                    gear_name: item.name.clone(),
+                    distance_km,
+                    threshold_km: self.shoe_replacement_km,
+                    over_by_km: distance_km - self.shoe_replacement_km,
+                    message: format!(
+                        "{} has {distance_km:.0} km logged, past the {:.0} km replacement threshold. \
+                         Consider replacing them to reduce injury risk.",
+                        item.name, self.shoe_replacement_km
+                    ),
+                })
+            })
+            .collect()
+    }
+}
@@ -151,6 +151,7 @@ impl<S: IntelligenceStrategy> AdvancedGoalEngine<S> {
                 confidence: Confidence::High,
                 severity: InsightSeverity::Info,
                 metadata: HashMap::new(),
+                computation_trace: None,
             });
         } else if progress.progress_percentage
             < time_progress.mul_add(100.0, -PROGRESS_TOLERANCE_PERCENTAGE)
@@ -161,6 +162,7 @@ impl<S: IntelligenceStrategy> AdvancedGoalEngine<S> {
                 confidence: Confidence::High,
                 severity: InsightSeverity::Warning,
                 metadata: HashMap::new(),
+                computation_trace: None,
             });
         }
 
@@ -184,6 +186,7 @@ impl<S: IntelligenceStrategy> AdvancedGoalEngine<S> {
                 confidence: Confidence::Medium,
                 severity: InsightSeverity::Info,
                 metadata: HashMap::new(),
+                computation_trace: None,
             });
         }
 
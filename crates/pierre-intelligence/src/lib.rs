@@ -44,6 +44,8 @@ pub mod insights;
 
 /// Advanced activity analysis with contextual insights
 pub mod activity_analyzer;
+/// Sandboxed user-defined metric expressions
+pub mod custom_metrics;
 /// Goal tracking and progress monitoring engine
 pub mod goal_engine;
 /// Performance metrics calculation
@@ -69,6 +71,8 @@ pub mod performance_prediction;
 pub mod statistical_analysis;
 /// Training load calculation and monitoring
 pub mod training_load;
+/// Periodized training plan generation toward a goal race
+pub mod training_plan;
 
 /// Recovery score calculation and recommendations
 pub mod recovery_calculator;
@@ -82,8 +86,16 @@ pub mod recipes;
 
 /// In-memory cache for friend activity summaries
 pub mod friend_activity_cache;
+/// Shoe mileage monitoring for gear replacement warnings
+pub mod gear_wear;
 /// Adapts shared insights to user's training context
 pub mod insight_adapter;
+/// Segment personal record detection from activity efforts
+pub mod segment_prs;
+/// Per-kilometer and per-mile splits computed from activity streams
+pub mod splits;
+/// Time spent in heart rate and power zones from activity streams
+pub mod time_in_zones;
 
 /// Pluggable algorithms for FTP, LTHR, VO2max, etc.
 pub mod algorithms;
@@ -122,6 +134,12 @@ pub use insights::Insight;
 
 // Metrics calculation and zone analysis
 
+/// Compiled custom metric ready for evaluation
+pub use custom_metrics::CompiledCustomMetric;
+/// User-defined derived metric definition
+pub use custom_metrics::CustomMetricDefinition;
+/// Custom metric validation errors
+pub use custom_metrics::CustomMetricError;
 /// Advanced metrics beyond basic stats
 pub use metrics::AdvancedMetrics;
 /// Calculator for fitness metrics
@@ -151,6 +169,10 @@ pub use analysis_config::AnalysisConfig;
 pub use analysis_config::AnalysisConfigError;
 /// Confidence level for insights
 pub use analysis_config::ConfidenceLevel;
+/// Warning for shoes past their replacement mileage
+pub use gear_wear::GearMileageWarning;
+/// Shoe mileage threshold monitor
+pub use gear_wear::GearWearMonitor;
 /// Summary of extracted metric
 pub use metrics_extractor::MetricSummary;
 /// Type of fitness metric
@@ -185,12 +207,18 @@ pub use performance_analyzer_v2::WeeklyLoad;
 pub use performance_prediction::PerformancePredictor;
 /// Race time predictions for distances
 pub use performance_prediction::RacePredictions;
+/// Segment personal record detector
+pub use segment_prs::SegmentPrDetector;
+/// Stream-based split calculator
+pub use splits::SplitCalculator;
 /// Regression analysis result
 pub use statistical_analysis::RegressionResult;
 /// Statistical significance level
 pub use statistical_analysis::SignificanceLevel;
 /// Statistical analysis engine
 pub use statistical_analysis::StatisticalAnalyzer;
+/// Time-in-zone breakdown of a heart rate or power stream
+pub use time_in_zones::{TimeInZones, TimeInZonesCalculator};
 /// Overtraining risk assessment
 pub use training_load::OvertrainingRisk;
 /// Risk level classification
@@ -199,10 +227,18 @@ pub use training_load::RiskLevel;
 pub use training_load::TrainingLoad;
 /// Training load calculator
 pub use training_load::TrainingLoadCalculator;
+/// Daily training load over a window
+pub use training_load::TrainingLoadTrend;
+/// Training load on one day of a trend
+pub use training_load::TrainingLoadTrendPoint;
 /// Current training status
 pub use training_load::TrainingStatus;
 /// TSS data point for training stress
 pub use training_load::TssDataPoint;
+/// Week-by-week training plan toward a race
+pub use training_plan::TrainingPlan;
+/// Periodized training plan generator
+pub use training_plan::TrainingPlanGenerator;
 
 // Re-export sleep and recovery types
 
@@ -705,6 +741,57 @@ pub struct AdvancedInsight {
     pub severity: InsightSeverity,
     /// Additional metadata for the insight
     pub metadata: HashMap<String, serde_json::Value>,
+    /// How the insight was computed, attached only when explanations are requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computation_trace: Option<ComputationTrace>,
+}
+
+impl AdvancedInsight {
+    /// Keep the computation trace only when `explain` is set
+    #[must_use]
+    pub fn explained(mut self, explain: bool) -> Self {
+        if !explain {
+            self.computation_trace = None;
+        }
+        self
+    }
+}
+
+/// Structured account of the inputs, thresholds, and formula behind an insight
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComputationTrace {
+    /// Formula used to reach the conclusion (e.g., `TSB = CTL - ATL`)
+    pub formula: String,
+    /// Measured values fed into the formula
+    pub inputs: Vec<TraceInput>,
+    /// Thresholds the computed values were compared against
+    pub thresholds: Vec<TraceThreshold>,
+}
+
+/// Named input value in a computation trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceInput {
+    /// Input name (e.g., `atl`)
+    pub name: String,
+    /// Input value
+    pub value: f64,
+    /// What the value represents
+    pub description: String,
+}
+
+/// Threshold comparison in a computation trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceThreshold {
+    /// Threshold name (e.g., `fatigued_tsb`)
+    pub name: String,
+    /// Threshold value
+    pub value: f64,
+    /// Comparison applied (e.g., `tsb < fatigued_tsb`)
+    pub comparison: String,
+    /// Whether the comparison held
+    pub triggered: bool,
+    /// Where the threshold comes from (an `IntelligenceConfig` path or a constant)
+    pub source: String,
 }
 
 /// Severity level for insights
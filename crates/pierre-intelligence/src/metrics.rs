@@ -10,9 +10,14 @@ use crate::algorithms::{TrimpAlgorithm, TssAlgorithm};
 use crate::config::intelligence::IntelligenceConfig;
 use crate::constants::physiology::{MAX_GOOD_GCT_MS, MIN_GOOD_GCT_MS, OPTIMAL_GCT_MS};
 use crate::constants::time_constants::SECONDS_PER_HOUR_F64;
+use crate::custom_metrics::CompiledCustomMetric;
 use crate::errors::{AppError, AppResult};
 use crate::models::{Activity, SportType};
 use crate::physiological_constants::{
+    grade_adjusted_pace::{
+        ALTITUDE_SMOOTHING_RADIUS, MAX_MODEL_GRADIENT, MINETTI_COST_COEFFICIENTS,
+        MIN_GRADE_SEGMENT_METERS,
+    },
     metrics_constants::{EFFICIENCY_TIME_MULTIPLIER, MIN_DECOUPLING_DATA_POINTS},
     zone_percentages::{
         HR_ZONE1_UPPER_LIMIT, HR_ZONE2_UPPER_LIMIT, HR_ZONE3_UPPER_LIMIT, HR_ZONE4_UPPER_LIMIT,
@@ -25,6 +30,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tracing::{debug, warn};
 
+/// Rolling window for normalized power, in 1-second samples
+const NORMALIZED_POWER_WINDOW_SECONDS: usize = 30;
+
+/// [`NORMALIZED_POWER_WINDOW_SECONDS`] as a divisor for window averages
+const NORMALIZED_POWER_WINDOW_SAMPLES: u32 = 30;
+
 /// Safe casting helper functions to avoid clippy warnings
 #[inline]
 // Safe: value clamped to u16 range within function
@@ -43,9 +54,9 @@ pub struct AdvancedMetrics {
     pub power_to_weight_ratio: Option<f64>,
     /// Training stress score (TSS)
     pub training_stress_score: Option<f64>,
-    /// Intensity factor
+    /// Intensity factor (normalized power / FTP, or average power / FTP without a power stream)
     pub intensity_factor: Option<f64>,
-    /// Variability index
+    /// Variability index (normalized power / average power)
     pub variability_index: Option<f64>,
     /// Efficiency factor
     pub efficiency_factor: Option<f64>,
@@ -53,7 +64,7 @@ pub struct AdvancedMetrics {
     pub decoupling_percentage: Option<f64>,
 
     // Enhanced power metrics
-    /// Normalized power (4th root of 30-second rolling average of power^4)
+    /// Normalized power (4th root of the mean of 30-second rolling average power^4)
     pub normalized_power: Option<f64>,
     /// Work (kilojoules)
     pub work: Option<f64>,
@@ -67,6 +78,8 @@ pub struct AdvancedMetrics {
     pub stride_efficiency: Option<f64>,
     /// Ground contact balance
     pub ground_contact_balance: Option<f64>,
+    /// Grade-adjusted pace in seconds per km, when elevation data is available
+    pub grade_adjusted_pace: Option<f64>,
 
     // Recovery and physiological metrics
     /// Estimated recovery time in hours
@@ -84,6 +97,9 @@ pub struct AdvancedMetrics {
 
     /// Custom metrics
     pub custom_metrics: HashMap<String, f64>,
+    /// Display units for custom metrics that declare one, keyed by metric name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub custom_metric_units: HashMap<String, String>,
 }
 
 /// Metrics calculator for activities
@@ -163,7 +179,44 @@ impl MetricsCalculator {
         Ok(metrics)
     }
 
-    /// Calculate basic metrics (TRIMP, TSS, intensity factor)
+    /// Calculate all available metrics plus user-defined custom metrics
+    ///
+    /// Custom metrics are evaluated after the built-in metrics so expressions can
+    /// reference calculated values such as TSS or TRIMP. Metrics whose inputs are
+    /// unavailable for this activity are omitted from `custom_metrics`.
+    ///
+    /// # Errors
+    /// Returns an error if metrics calculation fails
+    pub fn calculate_metrics_with_custom(
+        &self,
+        activity: &Activity,
+        custom_metrics: &[CompiledCustomMetric],
+    ) -> AppResult<AdvancedMetrics> {
+        let mut metrics = self.calculate_metrics(activity)?;
+
+        for custom in custom_metrics {
+            if let Some(value) = custom.evaluate(activity, &metrics) {
+                metrics
+                    .custom_metrics
+                    .insert(custom.name().to_owned(), value);
+                if let Some(unit) = custom.unit() {
+                    metrics
+                        .custom_metric_units
+                        .insert(custom.name().to_owned(), unit.to_owned());
+                }
+            } else {
+                debug!(
+                    activity_id = activity.id(),
+                    metric = custom.name(),
+                    "Custom metric unavailable for activity"
+                );
+            }
+        }
+
+        Ok(metrics)
+    }
+
+    /// Calculate basic metrics (TRIMP, TSS)
     fn calculate_basic_metrics(
         &self,
         activity: &Activity,
@@ -182,12 +235,6 @@ impl MetricsCalculator {
             .map(f64::from)
             .or_else(|| self.calculate_tss_from_data(activity));
 
-        // Use actual intensity factor if available, otherwise calculate
-        metrics.intensity_factor = activity
-            .intensity_factor()
-            .map(f64::from)
-            .or_else(|| self.calculate_intensity_factor(activity));
-
         Ok(())
     }
 
@@ -247,11 +294,12 @@ impl MetricsCalculator {
 
         // 3. Fallback: Pace-based TSS estimation for running activities without sensors
         if let Some(distance_m) = activity.distance_meters() {
-            if distance_m > 0.0 && activity.duration_seconds() > 0 {
+            let moving_seconds = activity.moving_time_seconds();
+            if distance_m > 0.0 && moving_seconds > 0 {
                 // Estimate TSS from pace relative to moderate effort
                 // Assumes 10 min/km as baseline moderate effort (TSS = duration in hours * 100)
                 #[allow(clippy::cast_precision_loss)]
-                let pace_s_per_km = activity.duration_seconds() as f64 / (distance_m / 1000.0);
+                let pace_s_per_km = moving_seconds as f64 / (distance_m / 1000.0);
                 let baseline_pace = 600.0; // 10 min/km in seconds
 
                 // Intensity factor: faster pace = higher intensity
@@ -269,14 +317,16 @@ impl MetricsCalculator {
         None
     }
 
-    /// Calculate intensity factor from activity data
-    fn calculate_intensity_factor(&self, activity: &Activity) -> Option<f64> {
-        if let (Some(avg_power), Some(ftp)) = (activity.average_power(), self.ftp) {
-            if ftp > 0.0 {
-                return Some(f64::from(avg_power) / ftp);
-            }
-        }
-        None
+    /// Calculate intensity factor from normalized power, or average power without it
+    fn calculate_intensity_factor(
+        &self,
+        activity: &Activity,
+        normalized_power: Option<f64>,
+    ) -> Option<f64> {
+        let ftp = self.ftp.filter(|ftp| *ftp > 0.0)?;
+        normalized_power
+            .or_else(|| activity.average_power().map(f64::from))
+            .map(|power| power / ftp)
     }
 
     /// Calculate power-based metrics
@@ -298,6 +348,18 @@ impl MetricsCalculator {
                 .and_then(|power_data| self.calculate_normalized_power(power_data))
         });
 
+        // Use actual intensity factor if available, otherwise calculate
+        metrics.intensity_factor = activity
+            .intensity_factor()
+            .map(f64::from)
+            .or_else(|| self.calculate_intensity_factor(activity, metrics.normalized_power));
+
+        // Variability index from summary values; the time series fallback runs later
+        metrics.variability_index = metrics
+            .normalized_power
+            .zip(activity.average_power().filter(|avg| *avg > 0))
+            .map(|(normalized_power, avg_power)| normalized_power / f64::from(avg_power));
+
         // Calculate work (energy) if power is available
         if let Some(avg_power) = activity.average_power() {
             let duration_hours = if activity.duration_seconds() > u64::from(u32::MAX) {
@@ -380,6 +442,8 @@ impl MetricsCalculator {
         if let Some(gct) = activity.ground_contact_time() {
             metrics.ground_contact_balance = Some(Self::calculate_ground_contact_balance(gct));
         }
+
+        metrics.grade_adjusted_pace = Self::calculate_grade_adjusted_pace(activity);
     }
 
     /// Calculate time series based metrics
@@ -389,7 +453,11 @@ impl MetricsCalculator {
         };
 
         // Calculate variability index from time series power data
-        if let Some(power_data) = &time_series.power {
+        if let Some(power_data) = time_series
+            .power
+            .as_ref()
+            .filter(|_| metrics.variability_index.is_none())
+        {
             metrics.variability_index = self.calculate_variability_index(power_data);
         }
 
@@ -445,26 +513,29 @@ impl MetricsCalculator {
             .ok()
     }
 
-    /// Calculate normalized power (4th root of 30-second rolling average of power^4)
+    /// Calculate normalized power from a 1-second power stream
+    ///
+    /// Takes the 30-second rolling average of power, raises each average to
+    /// the 4th power, and returns the 4th root of their mean.
     #[must_use]
     pub fn calculate_normalized_power(&self, power_data: &[u32]) -> Option<f64> {
-        if power_data.len() < 30 {
+        if power_data.len() < NORMALIZED_POWER_WINDOW_SECONDS {
             return None; // Need at least 30 seconds of data
         }
 
-        // Convert to f64 for calculations
-        let power_f64: Vec<f64> = power_data.iter().map(|&p| f64::from(p)).collect();
-
-        // Calculate 30-second rolling averages of power^4
-        let mut rolling_avg_power4 = Vec::new();
-        for i in 29..power_f64.len() {
-            let window = &power_f64[(i - 29)..=i];
-            let avg_power4: f64 = window.iter().map(|&p| p.powi(4)).sum::<f64>() / 30.0;
-            rolling_avg_power4.push(avg_power4);
-        }
-
-        if rolling_avg_power4.is_empty() {
-            return None;
+        // Running window sum keeps this linear in the stream length
+        let window_len = f64::from(NORMALIZED_POWER_WINDOW_SAMPLES);
+        let mut window_sum: f64 = power_data[..NORMALIZED_POWER_WINDOW_SECONDS]
+            .iter()
+            .map(|&p| f64::from(p))
+            .sum();
+        let mut rolling_avg_power4 = vec![(window_sum / window_len).powi(4)];
+        for (&entering, &leaving) in power_data[NORMALIZED_POWER_WINDOW_SECONDS..]
+            .iter()
+            .zip(power_data)
+        {
+            window_sum += f64::from(entering) - f64::from(leaving);
+            rolling_avg_power4.push((window_sum / window_len).powi(4));
         }
 
         // Take the average of all 30-second power^4 values, then take 4th root
@@ -600,6 +671,40 @@ impl MetricsCalculator {
         Some(((second_efficiency - first_efficiency) / first_efficiency) * 100.0)
     }
 
+    /// Grade-adjusted pace of a run from its altitude and speed streams, in seconds per km
+    ///
+    /// The distance stream is integrated from speed and timestamps. Returns
+    /// `None` for other sports or when the activity has no elevation data.
+    #[must_use]
+    pub fn calculate_grade_adjusted_pace(activity: &Activity) -> Option<f64> {
+        if !matches!(
+            *activity.sport_type(),
+            SportType::Run | SportType::TrailRunning
+        ) {
+            return None;
+        }
+        let time_series = activity.time_series_data()?;
+        let (altitude, speed) = (time_series.altitude.as_ref()?, time_series.speed.as_ref()?);
+        let timestamps = &time_series.timestamps;
+        if timestamps.len() != altitude.len() || timestamps.len() != speed.len() {
+            return None;
+        }
+
+        let mut distances = Vec::with_capacity(timestamps.len());
+        let mut covered = 0.0;
+        for (i, &timestamp) in timestamps.iter().enumerate() {
+            if i > 0 {
+                let dt = f64::from(timestamp.saturating_sub(timestamps[i - 1]));
+                covered += f64::from(speed[i - 1] + speed[i]) / 2.0 * dt;
+            }
+            distances.push(covered);
+        }
+        let altitudes: Vec<f64> = altitude.iter().map(|&a| f64::from(a)).collect();
+        let elapsed = f64::from(timestamps.last()?.saturating_sub(*timestamps.first()?));
+
+        grade_adjusted_pace(&distances, &altitudes, elapsed)
+    }
+
     /// Calculate temperature stress factor
     fn calculate_temperature_stress(temperature: f32) -> f64 {
         // Temperature stress increases outside the optimal range of 10-20C
@@ -734,6 +839,67 @@ impl MetricsCalculator {
     }
 }
 
+/// Energy cost of running on `gradient` relative to flat ground (Minetti et al., 2002)
+fn relative_running_cost(gradient: f64) -> f64 {
+    let cost = |i: f64| {
+        MINETTI_COST_COEFFICIENTS
+            .iter()
+            .fold(0.0, |acc, &coefficient| acc.mul_add(i, coefficient))
+    };
+    cost(gradient.clamp(-MAX_MODEL_GRADIENT, MAX_MODEL_GRADIENT)) / cost(0.0)
+}
+
+/// Centered moving average that removes point-to-point altitude noise
+fn smooth_altitudes(altitudes: &[f64]) -> Vec<f64> {
+    (0..altitudes.len())
+        .map(|i| {
+            let window = &altitudes[i.saturating_sub(ALTITUDE_SMOOTHING_RADIUS)
+                ..(i + ALTITUDE_SMOOTHING_RADIUS + 1).min(altitudes.len())];
+            let count = u32::try_from(window.len()).map_or(f64::from(u32::MAX), f64::from);
+            window.iter().sum::<f64>() / count
+        })
+        .collect()
+}
+
+/// Grade-adjusted pace: the flat-ground pace with the same energy cost, in seconds per km
+///
+/// `distances` holds the cumulative distance in meters and `altitudes` the
+/// elevation in meters at each point. Altitudes are smoothed and gradients are
+/// measured over at least `MIN_GRADE_SEGMENT_METERS`, then each stretch is
+/// weighted by the Minetti cost of running at its gradient. Uphill running
+/// therefore yields a faster GAP than the measured pace.
+///
+/// Returns `None` without elevation data, with mismatched streams, or when no
+/// distance was covered.
+#[must_use]
+pub fn grade_adjusted_pace(
+    distances: &[f64],
+    altitudes: &[f64],
+    elapsed_seconds: f64,
+) -> Option<f64> {
+    if distances.len() < 2 || distances.len() != altitudes.len() || elapsed_seconds <= 0.0 {
+        return None;
+    }
+
+    let altitudes = smooth_altitudes(altitudes);
+    let mut equivalent_flat_meters = 0.0;
+    let mut anchor = 0;
+    for point in 1..distances.len() {
+        let segment_meters = distances[point] - distances[anchor];
+        let is_last = point == distances.len() - 1;
+        if segment_meters < MIN_GRADE_SEGMENT_METERS && !is_last {
+            continue;
+        }
+        if segment_meters > 0.0 {
+            let gradient = (altitudes[point] - altitudes[anchor]) / segment_meters;
+            equivalent_flat_meters += segment_meters * relative_running_cost(gradient);
+        }
+        anchor = point;
+    }
+
+    (equivalent_flat_meters > 0.0).then(|| elapsed_seconds / (equivalent_flat_meters / 1000.0))
+}
+
 /// Zone-based analysis for heart rate or power
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZoneAnalysis {
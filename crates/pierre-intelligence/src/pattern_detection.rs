@@ -4,8 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use crate::models::Activity;
+use crate::config::intelligence::SpeedAnomalyConfig;
+use crate::models::{Activity, TimeSeriesData};
 use crate::training_load::RiskLevel;
+use crate::{Anomaly, Confidence, InsightSeverity};
 use chrono::{Datelike, Timelike, Weekday};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,6 +21,12 @@ const HR_DRIFT_THRESHOLD_PERCENT: f64 = 5.0;
 /// Volume spike threshold for injury risk (percent)
 const VOLUME_SPIKE_THRESHOLD_PERCENT: f64 = 10.0;
 
+/// Mean Earth radius for GPS distance calculations (meters)
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Conversion factor from m/s to km/h for anomaly descriptions
+const MPS_TO_KMH: f64 = 3.6;
+
 /// Weekly schedule pattern analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeeklySchedulePattern {
@@ -322,8 +330,131 @@ impl PatternDetector {
         }
     }
 
+    /// Detect physically impossible speed spikes in an activity's per-point streams
+    ///
+    /// Speeds come from the speed stream when present, otherwise they are derived
+    /// from consecutive GPS coordinates. Each run of samples above the sport's
+    /// configured maximum becomes one anomaly, with the median-filtered speed
+    /// around the peak as the expected value.
+    #[must_use]
+    pub fn detect_speed_anomalies(
+        activity: &Activity,
+        config: &SpeedAnomalyConfig,
+    ) -> Vec<Anomaly> {
+        let Some(series) = activity.time_series_data() else {
+            return Vec::new();
+        };
+        let samples = Self::point_speeds(series);
+        if samples.is_empty() {
+            return Vec::new();
+        }
+
+        let speeds: Vec<f64> = samples.iter().map(|(_, speed)| *speed).collect();
+        let baseline = Self::median_filter(&speeds, config.median_window_radius);
+        let max_speed = config.max_speed_for(activity.sport_type());
+
+        let mut anomalies = Vec::new();
+        let mut index = 0;
+        while index < speeds.len() {
+            if speeds[index] <= max_speed {
+                index += 1;
+                continue;
+            }
+
+            let start = index;
+            while index < speeds.len() && speeds[index] > max_speed {
+                index += 1;
+            }
+            if index - start < config.min_spike_points {
+                continue;
+            }
+
+            let peak = (start..index)
+                .max_by(|a, b| speeds[*a].total_cmp(&speeds[*b]))
+                .unwrap_or(start);
+            let expected = baseline[peak].min(max_speed);
+            // A baseline above the limit means the whole stretch is implausible,
+            // not a single bad fix, so the GPS-glitch diagnosis is less certain
+            let sustained = baseline[peak] > max_speed;
+
+            anomalies.push(Anomaly {
+                anomaly_type: "gps_speed_spike".into(),
+                description: format!(
+                    "Speed reached {actual:.1} km/h at {offset}s against a {expected:.1} km/h baseline, \
+                     impossible for {sport_type:?} - likely a GPS or distance error",
+                    actual = speeds[peak] * MPS_TO_KMH,
+                    offset = samples[peak].0,
+                    expected = baseline[peak] * MPS_TO_KMH,
+                    sport_type = activity.sport_type()
+                ),
+                severity: InsightSeverity::Warning,
+                confidence: if sustained {
+                    Confidence::Medium
+                } else {
+                    Confidence::High
+                },
+                affected_metric: "speed".into(),
+                expected_value: Some(expected),
+                actual_value: Some(speeds[peak]),
+            });
+        }
+
+        anomalies
+    }
+
     // === Helper Functions ===
 
+    /// Per-point `(time offset, speed in m/s)` samples from the speed stream or GPS track
+    fn point_speeds(series: &TimeSeriesData) -> Vec<(u32, f64)> {
+        if let Some(speed) = &series.speed {
+            return series
+                .timestamps
+                .iter()
+                .zip(speed)
+                .map(|(offset, speed)| (*offset, f64::from(*speed)))
+                .collect();
+        }
+
+        let Some(coordinates) = &series.gps_coordinates else {
+            return Vec::new();
+        };
+        let points: Vec<_> = series.timestamps.iter().zip(coordinates).collect();
+        points
+            .windows(2)
+            .filter_map(|pair| {
+                let (start_offset, start) = pair[0];
+                let (end_offset, end) = pair[1];
+                let elapsed = end_offset.checked_sub(*start_offset).filter(|s| *s > 0)?;
+                Some((
+                    *end_offset,
+                    Self::haversine_meters(*start, *end) / f64::from(elapsed),
+                ))
+            })
+            .collect()
+    }
+
+    /// Great-circle distance between two `(lat, lon)` points in meters
+    pub(crate) fn haversine_meters((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+        let half_d_lat = ((lat2 - lat1).to_radians() / 2.0).sin();
+        let half_d_lon = ((lon2 - lon1).to_radians() / 2.0).sin();
+        let a = (lat1.to_radians().cos() * lat2.to_radians().cos())
+            .mul_add(half_d_lon * half_d_lon, half_d_lat * half_d_lat);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
+
+    /// Rolling median over `radius` samples on each side, clamped at the edges
+    fn median_filter(values: &[f64], radius: usize) -> Vec<f64> {
+        (0..values.len())
+            .map(|i| {
+                let start = i.saturating_sub(radius);
+                let end = (i + radius + 1).min(values.len());
+                let mut window = values[start..end].to_vec();
+                window.sort_by(f64::total_cmp);
+                window[window.len() / 2]
+            })
+            .collect()
+    }
+
     fn calculate_schedule_consistency(day_freq: &[(Weekday, u32)], total_activities: usize) -> f64 {
         if day_freq.is_empty() || total_activities == 0 {
             return 0.0;
@@ -366,10 +497,10 @@ impl PatternDetector {
         let long_duration = activity.duration_seconds() > 3600; // >1 hour
 
         #[allow(clippy::cast_precision_loss)]
-        let high_speed = activity.duration_seconds() > 0
+        let high_speed = activity.moving_time_seconds() > 0
             && activity
                 .distance_meters()
-                .is_some_and(|d| (d / activity.duration_seconds() as f64) > 3.5); // >3.5 m/s (~4:45 min/km)
+                .is_some_and(|d| (d / activity.moving_time_seconds() as f64) > 3.5); // >3.5 m/s (~4:45 min/km)
 
         high_hr || (long_duration && high_speed)
     }
@@ -486,7 +617,7 @@ impl PatternDetector {
             .iter()
             .filter_map(|a| {
                 let distance = a.distance_meters()?;
-                let duration = a.duration_seconds();
+                let duration = a.moving_time_seconds();
                 #[allow(clippy::cast_precision_loss)]
                 if distance > 0.0 && duration > 0 {
                     Some(duration as f64 / distance) // seconds per meter
@@ -304,6 +304,7 @@ impl<S: IntelligenceStrategy> AdvancedPerformanceAnalyzer<S> {
             },
             severity,
             metadata,
+            computation_trace: None,
         });
 
         // Data quality insight using config min data points
@@ -314,6 +315,7 @@ impl<S: IntelligenceStrategy> AdvancedPerformanceAnalyzer<S> {
                 confidence: Confidence::Medium,
                 severity: InsightSeverity::Warning,
                 metadata: HashMap::new(),
+                computation_trace: None,
             });
         }
 
@@ -327,6 +329,7 @@ impl<S: IntelligenceStrategy> AdvancedPerformanceAnalyzer<S> {
                     confidence: Confidence::High,
                     severity: InsightSeverity::Info,
                     metadata: HashMap::new(),
+                    computation_trace: None,
                 });
             }
         }
@@ -429,6 +429,7 @@ impl PerformanceAnalyzerV2 {
                     InsightSeverity::Warning
                 },
                 metadata,
+                computation_trace: None,
             });
         }
 
@@ -454,6 +455,7 @@ impl PerformanceAnalyzerV2 {
                 InsightSeverity::Warning
             },
             metadata,
+            computation_trace: None,
         });
 
         insights
@@ -742,6 +744,7 @@ impl PerformanceAnalyzerV2 {
                 InsightSeverity::Warning
             },
             metadata,
+            computation_trace: None,
         });
 
         insights
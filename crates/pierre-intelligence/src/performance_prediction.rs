@@ -160,6 +160,23 @@ impl PerformancePredictor {
         Self::generate_race_predictions(distance, duration_f64)
     }
 
+    /// Whether an activity looks like a race effort usable for prediction (>3km, <2 hours)
+    #[must_use]
+    pub fn is_race_effort(activity: &Activity) -> bool {
+        activity.distance_meters().is_some_and(|distance| {
+            let duration = activity.duration_seconds();
+            #[allow(clippy::cast_precision_loss)]
+            let duration_f64 = duration as f64;
+            // At least 3K distance, non-zero duration
+            duration > 0
+                && distance >= 3_000.0
+                // Less than 2 hours
+                && duration < 7_200
+                // Reasonable pace (faster than 8 min/km)
+                && (distance / duration_f64) > (1000.0 / 480.0)
+        })
+    }
+
     /// Find best performance from activities for race prediction
     ///
     /// Looks for fastest pace activities that are likely race efforts (>3km, <2 hours)
@@ -167,21 +184,7 @@ impl PerformancePredictor {
     pub fn find_best_performance(activities: &[Activity]) -> Option<&Activity> {
         activities
             .iter()
-            .filter(|a| {
-                // Filter for likely race efforts
-                a.distance_meters().is_some_and(|distance| {
-                    let duration = a.duration_seconds();
-                    #[allow(clippy::cast_precision_loss)]
-                    let duration_f64 = duration as f64;
-                    // At least 3K distance, non-zero duration
-                    duration > 0
-                        && distance >= 3_000.0
-                        // Less than 2 hours
-                        && duration < 7_200
-                        // Reasonable pace (faster than 8 min/km)
-                        && (distance / duration_f64) > (1000.0 / 480.0)
-                })
-            })
+            .filter(|a| Self::is_race_effort(a))
             .max_by(|a, b| {
                 // Find fastest pace (duration > 0 is guaranteed by filter above)
                 #[allow(clippy::cast_precision_loss)]
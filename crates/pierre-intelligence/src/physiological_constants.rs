@@ -87,6 +87,13 @@ pub mod power {
     /// Recreational level power-to-weight ratio (W/kg)
     /// Trained recreational cyclists typically achieve this level
     pub const RECREATIONAL_POWER_TO_WEIGHT: f64 = 2.0;
+
+    /// Variability index at or below which a ride counts as steadily paced
+    /// Reference: Allen, H. & Coggan, A. (2010). Training and Racing with a Power Meter
+    pub const STEADY_VARIABILITY_INDEX: f64 = 1.05;
+
+    /// Variability index above which a ride counts as highly variable (crits, group rides)
+    pub const HIGH_VARIABILITY_INDEX: f64 = 1.15;
 }
 
 /// Training load and recovery thresholds
@@ -473,6 +480,25 @@ pub mod zone_percentages {
     pub const POWER_ZONE4_UPPER_LIMIT: f64 = 1.05;
 }
 
+/// Grade-adjusted pace constants
+///
+/// Reference: Minetti, A.E. et al. (2002). Energy cost of walking and running at
+/// extreme uphill and downhill slopes. Journal of Applied Physiology, 93(3), 1039-1046.
+pub mod grade_adjusted_pace {
+    /// Coefficients of the Minetti cost-of-running polynomial in J/kg/m,
+    /// highest power of the gradient first
+    pub const MINETTI_COST_COEFFICIENTS: [f64; 6] = [155.4, -30.4, -43.3, 46.3, 19.5, 3.6];
+
+    /// Steepest gradient (as a fraction) the Minetti model was fitted on
+    pub const MAX_MODEL_GRADIENT: f64 = 0.45;
+
+    /// Altitude samples averaged on each side of a point to remove barometer/GPS noise
+    pub const ALTITUDE_SMOOTHING_RADIUS: usize = 2;
+
+    /// Shortest distance a gradient is measured over (meters)
+    pub const MIN_GRADE_SEGMENT_METERS: f64 = 10.0;
+}
+
 /// Metrics calculation constants
 pub mod metrics_constants {
     /// TRIMP calculation exponential factor
@@ -39,6 +39,8 @@
 pub mod conversion;
 /// Core data models for recipes
 pub mod models;
+/// Training-aware ranking of saved recipes
+pub mod suggestions;
 
 // Re-export main types for convenience
 pub use conversion::{convert_to_grams, ConversionError, IngredientDensity};
@@ -46,3 +48,7 @@ pub use models::{
     DietaryRestriction, IngredientUnit, MacroTargets, MealTiming, Recipe, RecipeConstraints,
     RecipeIngredient, SkillLevel, ValidatedNutrition,
 };
+pub use suggestions::{
+    rank_recipes, required_skill_level, satisfies_restriction, DayIntensity, RecipeSuggestion,
+    RecipeSuggestionCriteria,
+};
@@ -185,7 +185,9 @@ pub enum DietaryRestriction {
 }
 
 /// Cooking skill level for recipe complexity filtering
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+///
+/// Levels are ordered from `Beginner` to `Advanced`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SkillLevel {
     /// Simple recipes, basic techniques
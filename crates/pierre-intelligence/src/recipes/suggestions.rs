@@ -0,0 +1,280 @@
+// ABOUTME: Ranks saved recipes for a meal using the day's training load and nutrition needs
+// ABOUTME: Filters by dietary restriction, cooking skill, and meal timing before scoring macro fit
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Training-aware recipe suggestions
+//!
+//! The day's training intensity sets the activity level used for
+//! [`DailyNutritionNeeds`], and the meal's share of those needs becomes the
+//! macro target each recipe is scored against. Harder days raise carbohydrate
+//! needs per kilogram, so the same recipe collection ranks higher-carb meals
+//! first on training days and lighter meals first on rest days.
+//!
+//! Only recipes with validated nutrition are ranked. A recipe is kept when it:
+//! - satisfies every requested [`DietaryRestriction`] (nutrient limits are read
+//!   from the validated nutrition, the rest from recipe tags such as `vegan`)
+//! - needs no more than the cook's [`SkillLevel`]
+//! - was written for the requested [`MealTiming`] or for any meal (`General`)
+
+use serde::{Deserialize, Serialize};
+
+use super::models::{
+    DietaryRestriction, MacroTargets, MealTiming, Recipe, SkillLevel, ValidatedNutrition,
+};
+use crate::config::IntelligenceConfig;
+use crate::nutrition_calculator::{ActivityLevel, DailyNutritionNeeds};
+
+/// Maximum sodium per serving for `LowSodium` (mg)
+const LOW_SODIUM_MAX_MG: f64 = 600.0;
+
+/// Maximum sugar per serving for `LowSugar` (g)
+const LOW_SUGAR_MAX_G: f64 = 10.0;
+
+/// Maximum carbohydrates per serving for `Keto` (g)
+const KETO_MAX_CARBS_G: f64 = 20.0;
+
+/// Recipes up to this many steps and minutes are beginner-friendly
+const BEGINNER_MAX_STEPS: usize = 5;
+const BEGINNER_MAX_MINS: u16 = 30;
+
+/// Recipes up to this many steps and minutes suit an intermediate cook
+const INTERMEDIATE_MAX_STEPS: usize = 10;
+const INTERMEDIATE_MAX_MINS: u16 = 60;
+
+/// Points added when a recipe was written for the requested meal timing
+const TIMING_MATCH_BONUS: f64 = 10.0;
+
+/// Training load of the day a meal is planned for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayIntensity {
+    /// No training planned or completed
+    Rest,
+    /// Easy session (recovery run, mobility)
+    Low,
+    /// Steady endurance or moderate strength session
+    Moderate,
+    /// Intervals, races, or long hard sessions
+    High,
+}
+
+impl DayIntensity {
+    /// Activity level used to estimate the day's energy and carbohydrate needs
+    #[must_use]
+    pub const fn activity_level(self) -> ActivityLevel {
+        match self {
+            Self::Rest => ActivityLevel::Sedentary,
+            Self::Low => ActivityLevel::LightlyActive,
+            Self::Moderate => ActivityLevel::ModeratelyActive,
+            Self::High => ActivityLevel::VeryActive,
+        }
+    }
+
+    /// Meal timing to suggest for when none is requested
+    #[must_use]
+    pub const fn default_meal_timing(self) -> MealTiming {
+        match self {
+            Self::Rest => MealTiming::RestDay,
+            Self::Low | Self::Moderate | Self::High => MealTiming::PostTraining,
+        }
+    }
+}
+
+/// What a suggested recipe must satisfy and the macros it is scored against
+#[derive(Debug, Clone)]
+pub struct RecipeSuggestionCriteria {
+    /// Per-serving targets for this meal
+    pub meal_targets: MacroTargets,
+    /// Meal the recipe is for
+    pub meal_timing: MealTiming,
+    /// Restrictions every recipe must satisfy
+    pub dietary_restrictions: Vec<DietaryRestriction>,
+    /// Most demanding recipe the cook can handle
+    pub skill_level: SkillLevel,
+}
+
+impl RecipeSuggestionCriteria {
+    /// Derive per-meal targets from the day's needs
+    ///
+    /// The meal gets its configured share of TDEE for `meal_timing`, and each
+    /// macro keeps the same share of the day's grams.
+    #[must_use]
+    pub fn for_meal(needs: &DailyNutritionNeeds, meal_timing: MealTiming) -> Self {
+        let meal_calories = IntelligenceConfig::global()
+            .nutrition
+            .meal_tdee_proportions
+            .calories_for_timing(meal_timing, Some(needs.tdee));
+        let daily_calories = needs
+            .fat_g
+            .mul_add(9.0, needs.protein_g.mul_add(4.0, needs.carbs_g * 4.0));
+        let share = if daily_calories > 0.0 {
+            meal_calories / daily_calories
+        } else {
+            0.0
+        };
+
+        Self {
+            meal_targets: MacroTargets {
+                calories: Some(meal_calories),
+                protein_g: Some(needs.protein_g * share),
+                carbs_g: Some(needs.carbs_g * share),
+                fat_g: Some(needs.fat_g * share),
+                fiber_g: None,
+            },
+            meal_timing,
+            dietary_restrictions: Vec::new(),
+            skill_level: SkillLevel::default(),
+        }
+    }
+
+    /// Require every recipe to satisfy these restrictions
+    #[must_use]
+    pub fn with_dietary_restrictions(mut self, restrictions: Vec<DietaryRestriction>) -> Self {
+        self.dietary_restrictions = restrictions;
+        self
+    }
+
+    /// Limit recipes to this skill level
+    #[must_use]
+    pub const fn with_skill_level(mut self, skill_level: SkillLevel) -> Self {
+        self.skill_level = skill_level;
+        self
+    }
+}
+
+/// A recipe that passed every filter, with its ranking score
+#[derive(Debug, Clone)]
+pub struct RecipeSuggestion {
+    /// The suggested recipe
+    pub recipe: Recipe,
+    /// 0-100 macro fit, plus a bonus for an exact meal timing match
+    pub score: f64,
+    /// Skill level the recipe calls for
+    pub required_skill: SkillLevel,
+}
+
+/// Filter and rank recipes for a meal, best match first
+#[must_use]
+pub fn rank_recipes(
+    recipes: Vec<Recipe>,
+    criteria: &RecipeSuggestionCriteria,
+) -> Vec<RecipeSuggestion> {
+    let mut suggestions: Vec<RecipeSuggestion> = recipes
+        .into_iter()
+        .filter(|recipe| {
+            recipe.meal_timing == criteria.meal_timing || recipe.meal_timing == MealTiming::General
+        })
+        .filter(|recipe| {
+            criteria
+                .dietary_restrictions
+                .iter()
+                .all(|restriction| satisfies_restriction(recipe, restriction))
+        })
+        .filter_map(|recipe| {
+            let required_skill = required_skill_level(&recipe);
+            if required_skill > criteria.skill_level {
+                return None;
+            }
+            let nutrition = recipe.nutrition.as_ref()?;
+            let mut score = macro_fit(nutrition, &criteria.meal_targets);
+            if recipe.meal_timing == criteria.meal_timing {
+                score += TIMING_MATCH_BONUS;
+            }
+            Some(RecipeSuggestion {
+                recipe,
+                score,
+                required_skill,
+            })
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.recipe.name.cmp(&b.recipe.name))
+    });
+    suggestions
+}
+
+/// Check whether a recipe satisfies a dietary restriction
+///
+/// Nutrient limits (`LowSodium`, `LowSugar`, `Keto`) are checked against the
+/// validated nutrition per serving. Ingredient-based restrictions need a
+/// matching tag (`gluten_free`, `dairy_free`, `vegan`, `vegetarian`, `nut_free`,
+/// `paleo`, or the custom text); a `vegan` tag also counts as vegetarian and
+/// dairy-free. Recipes without the data to decide are treated as not compliant.
+#[must_use]
+pub fn satisfies_restriction(recipe: &Recipe, restriction: &DietaryRestriction) -> bool {
+    let nutrition = recipe.nutrition.as_ref();
+    let has_tag = |tag: &str| recipe.tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+
+    match restriction {
+        DietaryRestriction::LowSodium => nutrition
+            .and_then(|n| n.sodium_mg)
+            .is_some_and(|sodium| sodium < LOW_SODIUM_MAX_MG),
+        DietaryRestriction::LowSugar => nutrition
+            .and_then(|n| n.sugar_g)
+            .is_some_and(|sugar| sugar < LOW_SUGAR_MAX_G),
+        DietaryRestriction::Keto => nutrition.is_some_and(|n| n.carbs_g < KETO_MAX_CARBS_G),
+        DietaryRestriction::GlutenFree => has_tag("gluten_free"),
+        DietaryRestriction::DairyFree => has_tag("dairy_free") || has_tag("vegan"),
+        DietaryRestriction::Vegan => has_tag("vegan"),
+        DietaryRestriction::Vegetarian => has_tag("vegetarian") || has_tag("vegan"),
+        DietaryRestriction::NutFree => has_tag("nut_free"),
+        DietaryRestriction::Paleo => has_tag("paleo"),
+        DietaryRestriction::Custom(tag) => has_tag(tag),
+    }
+}
+
+/// Estimate the skill level a recipe calls for
+///
+/// A `beginner`, `intermediate`, or `advanced` tag wins. Otherwise the number of
+/// steps and the total time decide, and recipes without a time count as quick.
+#[must_use]
+pub fn required_skill_level(recipe: &Recipe) -> SkillLevel {
+    for (tag, level) in [
+        ("advanced", SkillLevel::Advanced),
+        ("intermediate", SkillLevel::Intermediate),
+        ("beginner", SkillLevel::Beginner),
+    ] {
+        if recipe.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            return level;
+        }
+    }
+
+    let steps = recipe.instructions.len();
+    let minutes = recipe.total_time_mins().unwrap_or(0);
+    if steps <= BEGINNER_MAX_STEPS && minutes <= BEGINNER_MAX_MINS {
+        SkillLevel::Beginner
+    } else if steps <= INTERMEDIATE_MAX_STEPS && minutes <= INTERMEDIATE_MAX_MINS {
+        SkillLevel::Intermediate
+    } else {
+        SkillLevel::Advanced
+    }
+}
+
+/// Score how closely a serving matches the meal targets (0-100)
+///
+/// Each targeted macro contributes its relative error, capped at 100%.
+fn macro_fit(nutrition: &ValidatedNutrition, targets: &MacroTargets) -> f64 {
+    let (total_error, count) = [
+        (nutrition.protein_g, targets.protein_g),
+        (nutrition.carbs_g, targets.carbs_g),
+        (nutrition.fat_g, targets.fat_g),
+    ]
+    .into_iter()
+    .filter_map(|(actual, target)| target.filter(|t| *t > 0.0).map(|t| (actual, t)))
+    .fold((0.0, 0.0), |(total, count), (actual, target)| {
+        (
+            total + ((actual - target).abs() / target).min(1.0),
+            count + 1.0,
+        )
+    });
+
+    if count < 1.0 {
+        return 0.0;
+    }
+    (1.0 - total_error / count) * 100.0
+}
@@ -0,0 +1,100 @@
+// ABOUTME: Segment personal record detection from the efforts recorded during an activity
+// ABOUTME: Emits PersonalRecord entries for efforts that beat the athlete's previous best on a segment
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Segment PR Module
+//!
+//! An effort is a new personal record when the provider ranks it first among the
+//! athlete's efforts on that segment (Strava's `pr_rank`). Providers that do not
+//! rank efforts can still be checked against previously known best times. Efforts
+//! without a segment identifier cannot be compared and are ignored.
+
+use std::collections::HashMap;
+
+use crate::models::{Activity, PersonalRecord, PrMetric, SegmentEffort};
+
+/// Provider PR rank meaning "fastest effort by this athlete"
+const BEST_PR_RANK: u32 = 1;
+
+/// Detects new segment personal records among an activity's efforts
+#[derive(Debug, Clone, Default)]
+pub struct SegmentPrDetector {
+    /// Best elapsed time (seconds) per segment before the activity being checked
+    previous_bests: HashMap<String, u64>,
+}
+
+impl SegmentPrDetector {
+    /// Create a detector that relies on provider PR ranks only
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a detector that also compares unranked efforts against known bests
+    ///
+    /// `previous_bests` maps segment id to the athlete's best elapsed time in
+    /// seconds before the activity being checked.
+    #[must_use]
+    pub const fn with_previous_bests(previous_bests: HashMap<String, u64>) -> Self {
+        Self { previous_bests }
+    }
+
+    /// Personal records set by an activity's efforts, oldest effort first
+    ///
+    /// When a segment was covered more than once, only its fastest effort is
+    /// considered.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Safe: elapsed seconds are far below 2^52
+    pub fn detect(&self, activity_id: &str, efforts: &[SegmentEffort]) -> Vec<PersonalRecord> {
+        let mut fastest: HashMap<&str, &SegmentEffort> = HashMap::new();
+        for effort in efforts.iter().filter(|e| e.elapsed_time > 0) {
+            let Some(segment_id) = effort.segment_id.as_deref() else {
+                continue;
+            };
+            fastest
+                .entry(segment_id)
+                .and_modify(|best| {
+                    if effort.elapsed_time < best.elapsed_time {
+                        *best = effort;
+                    }
+                })
+                .or_insert(effort);
+        }
+
+        let mut records: Vec<PersonalRecord> = fastest
+            .into_iter()
+            .filter(|(segment_id, effort)| self.is_new_best(segment_id, effort))
+            .map(|(segment_id, effort)| PersonalRecord {
+                activity_id: activity_id.to_owned(),
+                metric: PrMetric::SegmentTime,
+                value: effort.elapsed_time as f64,
+                date: effort.start_date,
+                segment_id: Some(segment_id.to_owned()),
+            })
+            .collect();
+        records.sort_by_key(|record| record.date);
+        records
+    }
+
+    /// Personal records set by the segment efforts attached to an activity
+    #[must_use]
+    pub fn detect_in_activity(&self, activity: &Activity) -> Vec<PersonalRecord> {
+        activity
+            .segment_efforts()
+            .map_or_else(Vec::new, |efforts| self.detect(activity.id(), efforts))
+    }
+
+    /// Whether an effort beats the athlete's previous best on its segment
+    fn is_new_best(&self, segment_id: &str, effort: &SegmentEffort) -> bool {
+        effort.pr_rank.map_or_else(
+            || {
+                self.previous_bests
+                    .get(segment_id)
+                    .is_some_and(|&best| effort.elapsed_time < best)
+            },
+            |rank| rank == BEST_PR_RANK,
+        )
+    }
+}
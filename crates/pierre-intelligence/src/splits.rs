@@ -0,0 +1,233 @@
+// ABOUTME: Split computation from an activity's GPS or speed stream when the provider has no laps
+// ABOUTME: Interpolates split boundaries on cumulative distance and bridges GPS gaps between valid fixes
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Split Calculation Module
+//!
+//! Cumulative distance comes from the GPS track when every sample has a
+//! coordinate slot, otherwise from integrating the speed stream. Samples
+//! without a usable GPS fix (dropouts reported as `(0, 0)` or non-finite
+//! values) are skipped, so a gap is bridged by the straight-line distance
+//! between the fixes on either side and its time is spread evenly over that
+//! distance. Split boundaries are interpolated between samples, so each full
+//! split covers exactly one unit.
+
+use crate::models::{ActivitySplit, SplitUnit, TimeSeriesData};
+use crate::pattern_detection::PatternDetector;
+
+/// Shortest trailing remainder reported as a partial split (meters)
+const MIN_PARTIAL_SPLIT_METERS: f64 = 10.0;
+
+/// Distances closer than this to a full unit are treated as full splits (meters)
+const SPLIT_DISTANCE_TOLERANCE_METERS: f64 = 0.001;
+
+/// A point on the activity's distance track
+#[derive(Debug, Clone, Copy)]
+struct TrackPoint {
+    /// Seconds from the activity start
+    time: f64,
+    /// Meters covered since the activity start
+    distance: f64,
+}
+
+/// Computes per-kilometer or per-mile splits from an activity stream
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplitCalculator {
+    unit: SplitUnit,
+}
+
+impl SplitCalculator {
+    /// Create a calculator producing splits of one `unit` each
+    #[must_use]
+    pub const fn new(unit: SplitUnit) -> Self {
+        Self { unit }
+    }
+
+    /// Splits for the stream, in order, ending with a partial split for any remainder
+    ///
+    /// Returns an empty list when the stream has neither usable GPS
+    /// coordinates nor speed samples.
+    #[must_use]
+    pub fn compute(&self, series: &TimeSeriesData) -> Vec<ActivitySplit> {
+        let track = Self::distance_track(series);
+        let (Some(&first), Some(&last)) = (track.first(), track.last()) else {
+            return Vec::new();
+        };
+
+        let split_meters = self.unit.meters();
+        let mut boundaries = vec![first];
+        let mut next = first.distance + split_meters;
+        for pair in track.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            while end.distance >= next && end.distance > start.distance {
+                let fraction = (next - start.distance) / (end.distance - start.distance);
+                boundaries.push(TrackPoint {
+                    time: (end.time - start.time).mul_add(fraction, start.time),
+                    distance: next,
+                });
+                next += split_meters;
+            }
+        }
+        if boundaries
+            .last()
+            .is_some_and(|b| last.distance - b.distance >= MIN_PARTIAL_SPLIT_METERS)
+        {
+            boundaries.push(last);
+        }
+
+        boundaries
+            .windows(2)
+            .zip(1_u32..)
+            .map(|(window, index)| self.split(series, index, window[0], window[1]))
+            .collect()
+    }
+
+    /// Summarize the samples between two boundaries
+    fn split(
+        &self,
+        series: &TimeSeriesData,
+        index: u32,
+        start: TrackPoint,
+        end: TrackPoint,
+    ) -> ActivitySplit {
+        let distance_meters = end.distance - start.distance;
+        let elapsed_time_seconds = end.time - start.time;
+        let in_split = |timestamp: u32| {
+            let time = f64::from(timestamp);
+            time >= start.time && time <= end.time
+        };
+
+        let heart_rates: Vec<u32> = series
+            .heart_rate
+            .as_deref()
+            .map(|hr| {
+                series
+                    .timestamps
+                    .iter()
+                    .zip(hr)
+                    .filter(|(t, bpm)| in_split(**t) && **bpm > 0)
+                    .map(|(_, bpm)| *bpm)
+                    .collect()
+            })
+            .unwrap_or_default();
+        #[allow(clippy::cast_precision_loss)] // Safe: sample counts are far below 2^52
+        let average_heart_rate = (!heart_rates.is_empty()).then(|| {
+            heart_rates.iter().copied().map(f64::from).sum::<f64>() / heart_rates.len() as f64
+        });
+
+        let altitudes: Vec<f64> = series
+            .altitude
+            .as_deref()
+            .map(|altitude| {
+                series
+                    .timestamps
+                    .iter()
+                    .zip(altitude)
+                    .filter(|(t, meters)| in_split(**t) && meters.is_finite())
+                    .map(|(_, meters)| f64::from(*meters))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let elevation_gain_meters = (altitudes.len() >= 2).then(|| {
+            altitudes
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).max(0.0))
+                .sum()
+        });
+        let elevation_change_meters = altitudes
+            .first()
+            .zip(altitudes.last())
+            .filter(|_| altitudes.len() >= 2)
+            .map(|(first, last)| last - first);
+
+        ActivitySplit {
+            index,
+            distance_meters,
+            elapsed_time_seconds,
+            moving_time_seconds: None,
+            pace_seconds_per_unit: ActivitySplit::pace(
+                distance_meters,
+                elapsed_time_seconds,
+                self.unit,
+            ),
+            average_heart_rate,
+            max_heart_rate: heart_rates.iter().copied().max(),
+            elevation_gain_meters,
+            elevation_change_meters,
+            partial: self.unit.meters() - distance_meters > SPLIT_DISTANCE_TOLERANCE_METERS,
+        }
+    }
+
+    /// Cumulative distance at each usable sample, from GPS when available, else speed
+    fn distance_track(series: &TimeSeriesData) -> Vec<TrackPoint> {
+        let samples = series.timestamps.len();
+        match (&series.gps_coordinates, &series.speed) {
+            (Some(coordinates), _) if coordinates.len() == samples => {
+                Self::gps_track(&series.timestamps, coordinates)
+            }
+            (_, Some(speed)) if speed.len() == samples => {
+                Self::speed_track(&series.timestamps, speed)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Distance along valid GPS fixes; samples without a fix are skipped
+    fn gps_track(timestamps: &[u32], coordinates: &[(f64, f64)]) -> Vec<TrackPoint> {
+        let mut track: Vec<TrackPoint> = Vec::with_capacity(timestamps.len());
+        let mut previous: Option<(f64, f64)> = None;
+        for (&timestamp, &point) in timestamps.iter().zip(coordinates) {
+            if !Self::is_valid_fix(point) {
+                continue;
+            }
+            let distance = match (previous, track.last()) {
+                (Some(prev), Some(last)) => {
+                    last.distance + PatternDetector::haversine_meters(prev, point)
+                }
+                _ => 0.0,
+            };
+            track.push(TrackPoint {
+                time: f64::from(timestamp),
+                distance,
+            });
+            previous = Some(point);
+        }
+        track
+    }
+
+    /// Distance integrated from speed with the trapezoid rule
+    fn speed_track(timestamps: &[u32], speed: &[f32]) -> Vec<TrackPoint> {
+        let mut track: Vec<TrackPoint> = Vec::with_capacity(timestamps.len());
+        let mut previous: Option<(u32, f64)> = None;
+        for (&timestamp, &mps) in timestamps.iter().zip(speed) {
+            if !mps.is_finite() || mps < 0.0 {
+                continue;
+            }
+            let mps = f64::from(mps);
+            let distance = match (previous, track.last()) {
+                (Some((prev_time, prev_mps)), Some(last)) => {
+                    let seconds = f64::from(timestamp.saturating_sub(prev_time));
+                    f64::midpoint(prev_mps, mps).mul_add(seconds, last.distance)
+                }
+                _ => 0.0,
+            };
+            track.push(TrackPoint {
+                time: f64::from(timestamp),
+                distance,
+            });
+            previous = Some((timestamp, mps));
+        }
+        track
+    }
+
+    /// Whether a coordinate is a real fix rather than a dropout placeholder
+    fn is_valid_fix((lat, lon): (f64, f64)) -> bool {
+        lat.is_finite()
+            && lon.is_finite()
+            && (-90.0..=90.0).contains(&lat)
+            && (-180.0..=180.0).contains(&lon)
+            && !(lat == 0.0 && lon == 0.0)
+    }
+}
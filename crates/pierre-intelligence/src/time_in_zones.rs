@@ -0,0 +1,225 @@
+// ABOUTME: Time-in-zone computation from heart rate or power streams weighted by sample duration
+// ABOUTME: Uses the athlete's own zone bounds or zones derived from max heart rate or FTP
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Time-in-Zone Module
+//!
+//! Each sample is credited with the time until the next sample, so streams
+//! recorded at one-second and one-minute resolution both add up to the
+//! activity's duration. Gaps longer than [`MAX_SAMPLE_GAP_SECONDS`] (auto-pause,
+//! sensor dropouts) only credit that much, and zero readings are skipped.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::ZoneRange;
+use crate::physiological_constants::heart_rate_zones::{
+    PERMILLE_DIVISOR, ZONE_1_MAX_PERMILLE, ZONE_2_MAX_PERMILLE, ZONE_3_MAX_PERMILLE,
+    ZONE_4_MAX_PERMILLE,
+};
+use crate::physiological_constants::zone_percentages::{
+    POWER_ZONE1_UPPER_LIMIT, POWER_ZONE2_UPPER_LIMIT, POWER_ZONE3_UPPER_LIMIT,
+    POWER_ZONE4_UPPER_LIMIT,
+};
+use crate::ZoneDistribution;
+
+/// Longest stretch of time credited to a single sample (seconds)
+pub const MAX_SAMPLE_GAP_SECONDS: u32 = 60;
+
+/// Time spent in one zone
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ZoneTime {
+    /// 1-based zone number
+    pub zone: u32,
+    /// Lower bound in BPM or watts
+    pub min: u32,
+    /// Upper bound in BPM or watts; `None` for the open-ended top zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+    /// Seconds spent in the zone
+    pub seconds: f64,
+    /// Share of the total time in zones (0-100)
+    pub percentage: f64,
+}
+
+/// Time spent in each zone across one or more activities
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeInZones {
+    /// Zones from easiest to hardest
+    pub zones: Vec<ZoneTime>,
+    /// Seconds with a usable reading, summed over all zones
+    pub total_seconds: f64,
+}
+
+impl TimeInZones {
+    /// No time in any of `zones`
+    #[must_use]
+    pub fn empty(zones: &[ZoneRange]) -> Self {
+        Self {
+            zones: zones
+                .iter()
+                .zip(1_u32..)
+                .map(|(range, zone)| ZoneTime {
+                    zone,
+                    min: range.min,
+                    max: range.max,
+                    seconds: 0.0,
+                    percentage: 0.0,
+                })
+                .collect(),
+            total_seconds: 0.0,
+        }
+    }
+
+    /// Add the time from another breakdown over the same zones
+    pub fn add(&mut self, other: &Self) {
+        for (zone, other_zone) in self.zones.iter_mut().zip(&other.zones) {
+            zone.seconds += other_zone.seconds;
+        }
+        self.update_totals();
+    }
+
+    /// Whether no usable readings were found
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.total_seconds <= 0.0
+    }
+
+    /// Percentages per zone for five-zone models
+    ///
+    /// Returns `None` for other zone counts, such as Strava's seven power zones.
+    #[must_use]
+    pub fn distribution(&self) -> Option<ZoneDistribution> {
+        // Safe: percentages are within 0-100
+        #[allow(clippy::cast_possible_truncation)]
+        let percent = |index: usize| self.zones[index].percentage as f32;
+        (self.zones.len() == 5).then(|| ZoneDistribution {
+            zone1_recovery: percent(0),
+            zone2_endurance: percent(1),
+            zone3_tempo: percent(2),
+            zone4_threshold: percent(3),
+            zone5_vo2max: percent(4),
+        })
+    }
+
+    /// Recompute the total and each zone's share of it
+    fn update_totals(&mut self) {
+        self.total_seconds = self.zones.iter().map(|zone| zone.seconds).sum();
+        for zone in &mut self.zones {
+            zone.percentage = if self.total_seconds > 0.0 {
+                zone.seconds / self.total_seconds * 100.0
+            } else {
+                0.0
+            };
+        }
+    }
+}
+
+/// Computes the time a heart rate or power stream spends in each zone
+#[derive(Debug, Clone)]
+pub struct TimeInZonesCalculator {
+    zones: Vec<ZoneRange>,
+}
+
+impl TimeInZonesCalculator {
+    /// Create a calculator for zones ordered from easiest to hardest
+    #[must_use]
+    pub const fn new(zones: Vec<ZoneRange>) -> Self {
+        Self { zones }
+    }
+
+    /// Five heart rate zones at 60/70/80/90% of `max_hr`
+    #[must_use]
+    pub fn heart_rate_from_max_hr(max_hr: u32) -> Self {
+        let bound = |permille: u32| {
+            let bpm = u64::from(max_hr) * u64::from(permille) / PERMILLE_DIVISOR;
+            u32::try_from(bpm).unwrap_or(u32::MAX)
+        };
+        Self::from_upper_bounds([
+            bound(ZONE_1_MAX_PERMILLE),
+            bound(ZONE_2_MAX_PERMILLE),
+            bound(ZONE_3_MAX_PERMILLE),
+            bound(ZONE_4_MAX_PERMILLE),
+        ])
+    }
+
+    /// Five power zones at 55/75/90/105% of `ftp`
+    #[must_use]
+    pub fn power_from_ftp(ftp: f64) -> Self {
+        // Safe: FTP and its fractions are small positive wattages
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bound = |fraction: f64| (ftp * fraction).round().max(0.0) as u32;
+        Self::from_upper_bounds([
+            bound(POWER_ZONE1_UPPER_LIMIT),
+            bound(POWER_ZONE2_UPPER_LIMIT),
+            bound(POWER_ZONE3_UPPER_LIMIT),
+            bound(POWER_ZONE4_UPPER_LIMIT),
+        ])
+    }
+
+    /// Zones bounded by the given upper limits, with an open-ended top zone
+    fn from_upper_bounds(upper: [u32; 4]) -> Self {
+        let mut zones = Vec::with_capacity(upper.len() + 1);
+        let mut min = 0;
+        for max in upper {
+            zones.push(ZoneRange {
+                min,
+                max: Some(max),
+            });
+            min = max;
+        }
+        zones.push(ZoneRange { min, max: None });
+        Self { zones }
+    }
+
+    /// Zone bounds used for classification
+    #[must_use]
+    pub fn zones(&self) -> &[ZoneRange] {
+        &self.zones
+    }
+
+    /// Time in each zone for samples taken at `timestamps` seconds
+    ///
+    /// `values` are BPM or watts, one per timestamp.
+    #[must_use]
+    pub fn compute(&self, timestamps: &[u32], values: &[u32]) -> TimeInZones {
+        let mut result = TimeInZones::empty(&self.zones);
+        if self.zones.is_empty() {
+            return result;
+        }
+
+        let samples = timestamps.len().min(values.len());
+        let timestamps = &timestamps[..samples];
+        for (index, &value) in values[..samples].iter().enumerate() {
+            if value == 0 {
+                continue;
+            }
+            let seconds = Self::sample_seconds(timestamps, index);
+            result.zones[self.zone_index(value)].seconds += f64::from(seconds);
+        }
+
+        result.update_totals();
+        result
+    }
+
+    /// Seconds credited to the sample at `index`
+    ///
+    /// The last sample lasts as long as the interval before it.
+    fn sample_seconds(timestamps: &[u32], index: usize) -> u32 {
+        let interval = match (timestamps.get(index), timestamps.get(index + 1)) {
+            (Some(&start), Some(&end)) => end.saturating_sub(start),
+            _ if index > 0 => timestamps[index].saturating_sub(timestamps[index - 1]),
+            _ => 1,
+        };
+        interval.min(MAX_SAMPLE_GAP_SECONDS)
+    }
+
+    /// Index of the hardest zone whose lower bound the value reaches
+    fn zone_index(&self, value: u32) -> usize {
+        self.zones
+            .iter()
+            .rposition(|zone| value >= zone.min)
+            .unwrap_or(0)
+    }
+}
@@ -4,19 +4,32 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
+use crate::config::intelligence::TsbConfig;
 use crate::errors::AppError;
 use crate::metrics::MetricsCalculator;
 use crate::models::Activity;
-use chrono::{DateTime, Duration, Utc};
+use crate::{
+    AdvancedInsight, ComputationTrace, Confidence, InsightSeverity, TraceInput, TraceThreshold,
+};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use tracing::instrument;
 
 /// Standard CTL (Chronic Training Load) window - 42 days for long-term fitness
-const CTL_WINDOW_DAYS: i64 = 42;
+pub(crate) const CTL_WINDOW_DAYS: i64 = 42;
 
 /// Standard ATL (Acute Training Load) window - 7 days for short-term fatigue
-const ATL_WINDOW_DAYS: i64 = 7;
+pub(crate) const ATL_WINDOW_DAYS: i64 = 7;
+
+/// ATL above CTL times this ratio counts as an acute load spike
+const ACUTE_LOAD_SPIKE_RATIO: f64 = 1.3;
+
+/// ATL (TSS/day) above which acute load counts as very high
+const VERY_HIGH_ATL: f64 = 150.0;
+
+/// TSB below which fatigue counts as deep
+const DEEP_FATIGUE_TSB: f64 = -10.0;
 
 /// Training load metrics for an athlete
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +53,31 @@ pub struct TssDataPoint {
     pub tss: f64,
 }
 
+/// Training load on a single day of a trend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingLoadTrendPoint {
+    /// Day (midnight UTC) and the total TSS of its sessions, zero on rest days
+    #[serde(flatten)]
+    pub day: TssDataPoint,
+    /// Chronic Training Load at the end of the day
+    pub ctl: f64,
+    /// Acute Training Load at the end of the day
+    pub atl: f64,
+    /// Training Stress Balance (CTL - ATL) at the end of the day
+    pub tsb: f64,
+}
+
+/// Daily training load over a window, with the athlete's state on its last day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingLoadTrend {
+    /// One point per day, oldest first
+    pub points: Vec<TrainingLoadTrendPoint>,
+    /// Training status from the TSB on the last day
+    pub status: TrainingStatus,
+    /// Overtraining risk from the load on the last day
+    pub overtraining_risk: OvertrainingRisk,
+}
+
 /// Calculator for training load metrics
 pub struct TrainingLoadCalculator {
     ctl_window_days: i64,
@@ -226,9 +264,7 @@ impl TrainingLoadCalculator {
             return 0.0;
         }
 
-        // Calculate smoothing factor: α = 2 / (N + 1)
-        #[allow(clippy::cast_precision_loss)]
-        let alpha = 2.0 / (window_days as f64 + 1.0);
+        let alpha = Self::smoothing_factor(window_days);
 
         // Fill in missing days with zero TSS to create continuous time series
         let first_date = tss_data[0].date;
@@ -260,6 +296,73 @@ impl TrainingLoadCalculator {
         ema
     }
 
+    /// Smoothing factor for an N-day exponential moving average: α = 2 / (N + 1)
+    const fn smoothing_factor(window_days: i64) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let window = window_days as f64;
+        2.0 / (window + 1.0)
+    }
+
+    /// Calculate daily CTL, ATL, and TSB from `start` through `end`
+    ///
+    /// Sessions on the same day are summed and days without one count as zero
+    /// TSS, so load decays through gaps. Both averages start from zero at the
+    /// earliest session (or `start` if that is earlier): pass at least a CTL
+    /// window of history before `start` so the first points reflect fitness.
+    /// Sessions after `end` are ignored. The status and overtraining risk
+    /// describe the load on `end`.
+    #[must_use]
+    pub fn calculate_training_load_trend(
+        &self,
+        tss_history: &[TssDataPoint],
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> TrainingLoadTrend {
+        let mut daily_tss: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for point in tss_history {
+            let day = point.date.date_naive();
+            if day <= end {
+                *daily_tss.entry(day).or_insert(0.0) += point.tss;
+            }
+        }
+
+        let ctl_alpha = Self::smoothing_factor(self.ctl_window_days);
+        let atl_alpha = Self::smoothing_factor(self.atl_window_days);
+        let first_day = daily_tss.keys().next().map_or(start, |&day| day.min(start));
+
+        let mut ctl = 0.0;
+        let mut atl = 0.0;
+        let mut points = Vec::new();
+        for day in first_day.iter_days().take_while(|day| *day <= end) {
+            let tss = daily_tss.get(&day).copied().unwrap_or(0.0);
+            ctl = tss.mul_add(ctl_alpha, ctl * (1.0 - ctl_alpha));
+            atl = tss.mul_add(atl_alpha, atl * (1.0 - atl_alpha));
+            if day >= start {
+                points.push(TrainingLoadTrendPoint {
+                    day: TssDataPoint {
+                        date: day.and_time(NaiveTime::MIN).and_utc(),
+                        tss,
+                    },
+                    ctl,
+                    atl,
+                    tsb: Self::calculate_tsb(ctl, atl),
+                });
+            }
+        }
+
+        let final_load = TrainingLoad {
+            ctl,
+            atl,
+            tsb: Self::calculate_tsb(ctl, atl),
+            tss_history: Vec::new(),
+        };
+        TrainingLoadTrend {
+            points,
+            status: Self::interpret_tsb(final_load.tsb),
+            overtraining_risk: Self::check_overtraining_risk(&final_load),
+        }
+    }
+
     /// Interpret TSB value and provide status
     #[must_use]
     pub fn interpret_tsb(tsb: f64) -> TrainingStatus {
@@ -285,32 +388,139 @@ impl TrainingLoadCalculator {
         let mut risk_factors = Vec::new();
 
         // Check for acute load spike
-        if training_load.ctl > 0.0 && training_load.atl > training_load.ctl * 1.3 {
+        if training_load.ctl > 0.0 && training_load.atl > training_load.ctl * ACUTE_LOAD_SPIKE_RATIO
+        {
             risk_factors
                 .push("Acute training load spike detected (>30% above chronic load)".to_owned());
         }
 
         // Check for very high acute load
-        if training_load.atl > 150.0 {
+        if training_load.atl > VERY_HIGH_ATL {
             risk_factors.push("Very high acute training load (>150 TSS/day)".to_owned());
         }
 
         // Check for deep fatigue
-        if training_load.tsb < -10.0 {
+        if training_load.tsb < DEEP_FATIGUE_TSB {
             risk_factors.push("Deep fatigue detected (TSB < -10) - recovery needed".to_owned());
         }
 
-        let risk_level = if risk_factors.len() >= 2 {
-            RiskLevel::High
-        } else if risk_factors.len() == 1 {
-            RiskLevel::Moderate
+        OvertrainingRisk {
+            risk_level: Self::risk_level_for(risk_factors.len()),
+            risk_factors,
+        }
+    }
+
+    /// Overtraining risk as an insight carrying the comparisons behind it
+    ///
+    /// Applies the checks of `check_overtraining_risk`, with the deep fatigue
+    /// threshold taken from `tsb_config.fatigued_tsb`. The computation trace is
+    /// always attached; callers drop it with [`AdvancedInsight::explained`].
+    #[must_use]
+    pub fn overtraining_insight(
+        training_load: &TrainingLoad,
+        tsb_config: &TsbConfig,
+    ) -> AdvancedInsight {
+        let TrainingLoad { ctl, atl, tsb, .. } = *training_load;
+        let thresholds = vec![
+            TraceThreshold {
+                name: "acute_load_spike_ratio".to_owned(),
+                value: ACUTE_LOAD_SPIKE_RATIO,
+                comparison: "atl > ctl * acute_load_spike_ratio".to_owned(),
+                triggered: ctl > 0.0 && atl > ctl * ACUTE_LOAD_SPIKE_RATIO,
+                source: "training_load::ACUTE_LOAD_SPIKE_RATIO".to_owned(),
+            },
+            TraceThreshold {
+                name: "very_high_atl".to_owned(),
+                value: VERY_HIGH_ATL,
+                comparison: "atl > very_high_atl".to_owned(),
+                triggered: atl > VERY_HIGH_ATL,
+                source: "training_load::VERY_HIGH_ATL".to_owned(),
+            },
+            TraceThreshold {
+                name: "fatigued_tsb".to_owned(),
+                value: tsb_config.fatigued_tsb,
+                comparison: "tsb < fatigued_tsb".to_owned(),
+                triggered: tsb < tsb_config.fatigued_tsb,
+                source: "sleep_recovery.training_stress_balance.fatigued_tsb".to_owned(),
+            },
+        ];
+        let triggered = thresholds.iter().filter(|t| t.triggered).count();
+        let risk_level = Self::risk_level_for(triggered);
+
+        let summary = format!("TSB is {tsb:.1} with ATL {atl:.1} against CTL {ctl:.1}");
+        let (severity, message) = match risk_level {
+            RiskLevel::High => (
+                InsightSeverity::Critical,
+                format!("High overtraining risk: {summary} - rest recommended"),
+            ),
+            RiskLevel::Moderate => (
+                InsightSeverity::Warning,
+                format!("Moderate overtraining risk: {summary} - monitor recovery"),
+            ),
+            RiskLevel::Low => (
+                InsightSeverity::Info,
+                format!("Low overtraining risk: {summary}"),
+            ),
+        };
+
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "risk_level".to_owned(),
+            serde_json::Value::from(format!("{risk_level:?}")),
+        );
+        metadata.insert("ctl".to_owned(), serde_json::Value::from(ctl));
+        metadata.insert("atl".to_owned(), serde_json::Value::from(atl));
+        metadata.insert("tsb".to_owned(), serde_json::Value::from(tsb));
+
+        // CTL needs a full window of history before it reflects fitness
+        let history_days = training_load
+            .tss_history
+            .first()
+            .map_or(0, |first| (Utc::now() - first.date).num_days());
+        let confidence = if history_days >= CTL_WINDOW_DAYS {
+            Confidence::High
         } else {
-            RiskLevel::Low
+            Confidence::Medium
         };
 
-        OvertrainingRisk {
-            risk_level,
-            risk_factors,
+        AdvancedInsight {
+            insight_type: "overtraining_risk".to_owned(),
+            message,
+            confidence,
+            severity,
+            metadata,
+            computation_trace: Some(ComputationTrace {
+                formula:
+                    "TSB = CTL - ATL; risk = high if 2+ thresholds hit, moderate if 1, else low"
+                        .to_owned(),
+                inputs: vec![
+                    TraceInput {
+                        name: "ctl".to_owned(),
+                        value: ctl,
+                        description: "Chronic Training Load (42-day EMA of daily TSS)".to_owned(),
+                    },
+                    TraceInput {
+                        name: "atl".to_owned(),
+                        value: atl,
+                        description: "Acute Training Load (7-day EMA of daily TSS)".to_owned(),
+                    },
+                    TraceInput {
+                        name: "tsb".to_owned(),
+                        value: tsb,
+                        description: "Training Stress Balance (CTL - ATL)".to_owned(),
+                    },
+                ],
+                thresholds,
+            }),
+        }
+    }
+
+    /// Risk level from the number of risk factors present
+    const fn risk_level_for(risk_factor_count: usize) -> RiskLevel {
+        match risk_factor_count {
+            0 => RiskLevel::Low,
+            1 => RiskLevel::Moderate,
+            _ => RiskLevel::High,
         }
     }
 
@@ -0,0 +1,572 @@
+// ABOUTME: Periodized training plan generation toward a target race distance and date
+// ABOUTME: Ramps CTL within the athlete's weekly time budget, then tapers load into race day
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Training Plan Generation
+//!
+//! Turns a [`GoalType::Time`] goal into a week-by-week plan of target TSS and
+//! key workouts. Weeks are laid out backwards from the race so the final
+//! session of the plan is the race itself.
+//!
+//! The plan moves through base, build and peak phases, raising CTL by at most
+//! [`MAX_WEEKLY_CTL_RAMP`] per week with periodic recovery weeks that hold CTL
+//! steady. A week's sessions never need more than
+//! [`TimeAvailability::hours_per_week`], and the final weeks taper load so the
+//! athlete reaches race day fresh. CTL, ATL and TSB are projected with the same
+//! exponential moving averages as
+//! [`TrainingLoadCalculator`](crate::training_load::TrainingLoadCalculator).
+
+use crate::errors::{AppError, AppResult};
+use crate::training_load::{TrainingLoad, ATL_WINDOW_DAYS, CTL_WINDOW_DAYS};
+use crate::{Goal, GoalType, TimeAvailability};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::iter;
+
+/// Shortest block that leaves room for both a build and a taper
+const MIN_PLAN_WEEKS: i64 = 4;
+
+/// Longest block generated; races further out get a plan covering the final weeks
+const MAX_PLAN_WEEKS: i64 = 30;
+
+/// Largest weekly CTL increase considered safe (TSS/day per week)
+pub const MAX_WEEKLY_CTL_RAMP: f64 = 5.0;
+
+/// Every Nth base/build week holds CTL steady to absorb training
+const RECOVERY_WEEK_INTERVAL: usize = 4;
+
+/// Share of pre-taper weekly TSS removed by the final taper week
+const TAPER_MAX_REDUCTION: f64 = 0.3;
+
+/// Session length assumed when the athlete has no preference (minutes)
+const DEFAULT_SESSION_MINUTES: f64 = 60.0;
+
+/// Fewest sessions planned per week
+const MIN_SESSIONS_PER_WEEK: usize = 3;
+
+/// Most sessions planned per week
+const MAX_SESSIONS_PER_WEEK: usize = 6;
+
+/// Day offsets within a plan week for 3 to 6 sessions; the last slot holds the long session
+const SESSION_DAY_OFFSETS: [&[i64]; 4] = [
+    &[1, 3, 6],
+    &[0, 2, 4, 6],
+    &[0, 1, 3, 4, 6],
+    &[0, 1, 2, 3, 4, 6],
+];
+
+/// Phase of a periodized training block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrainingPhase {
+    /// Aerobic foundation with light tempo work
+    Base,
+    /// Threshold-focused fitness building
+    Build,
+    /// Race-specific sharpening at the highest load
+    Peak,
+    /// Load held steady to absorb preceding training
+    Recovery,
+    /// Reduced load to shed fatigue before the race
+    Taper,
+}
+
+/// Kind of planned session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkoutType {
+    /// Easy aerobic session
+    Endurance,
+    /// Long steady aerobic session
+    LongEndurance,
+    /// Sustained comfortably hard effort
+    Tempo,
+    /// Intervals around lactate threshold
+    Threshold,
+    /// Short hard intervals near VO2max
+    Vo2Max,
+    /// Blocks at goal race pace
+    RacePace,
+    /// The goal race
+    Race,
+}
+
+impl WorkoutType {
+    /// Typical whole-session intensity factor, used to convert TSS into duration
+    const fn intensity_factor(self) -> f64 {
+        match self {
+            Self::Endurance => 0.70,
+            Self::LongEndurance => 0.72,
+            Self::Tempo => 0.80,
+            Self::RacePace => 0.82,
+            Self::Threshold | Self::Race => 0.85,
+            Self::Vo2Max => 0.88,
+        }
+    }
+
+    /// TSS accumulated per hour at this workout's intensity
+    fn tss_per_hour(self) -> f64 {
+        100.0 * self.intensity_factor() * self.intensity_factor()
+    }
+
+    const fn is_key(self) -> bool {
+        !matches!(self, Self::Endurance)
+    }
+}
+
+/// A single planned session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedSession {
+    /// Day the session is planned for
+    pub date: DateTime<Utc>,
+    /// Kind of session
+    pub workout_type: WorkoutType,
+    /// Target training stress score
+    pub target_tss: f64,
+    /// Planned duration in minutes
+    pub duration_minutes: u32,
+    /// What the session involves
+    pub description: String,
+}
+
+/// One week of a training plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedWeek {
+    /// 1-based week number within the plan
+    pub week_number: u32,
+    /// First day of the week
+    pub start_date: DateTime<Utc>,
+    /// Training phase of the week
+    pub phase: TrainingPhase,
+    /// Target training stress score for the week
+    pub target_tss: f64,
+    /// Planned training time in hours
+    pub planned_hours: f64,
+    /// Projected CTL at the end of the week
+    pub projected_ctl: f64,
+    /// Projected ATL at the end of the week
+    pub projected_atl: f64,
+    /// Projected TSB at the end of the week
+    pub projected_tsb: f64,
+    /// Sessions planned for the week, in date order
+    pub sessions: Vec<PlannedSession>,
+}
+
+/// Periodized week-by-week plan toward a goal race
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingPlan {
+    /// Goal the plan was generated for
+    pub goal_id: String,
+    /// Sport of the goal race
+    pub sport: String,
+    /// Race distance in meters
+    pub race_distance_meters: f64,
+    /// Race date
+    pub race_date: DateTime<Utc>,
+    /// CTL when the plan was generated
+    pub starting_ctl: f64,
+    /// TSB when the plan was generated
+    pub starting_tsb: f64,
+    /// CTL the build phases aim for
+    pub target_peak_ctl: f64,
+    /// Weekly hours available to the athlete
+    pub hours_per_week: f64,
+    /// Planned weeks, in order
+    pub weeks: Vec<PlannedWeek>,
+}
+
+impl TrainingPlan {
+    /// Highest projected CTL across the plan
+    #[must_use]
+    pub fn projected_peak_ctl(&self) -> f64 {
+        self.weeks
+            .iter()
+            .map(|w| w.projected_ctl)
+            .fold(self.starting_ctl, f64::max)
+    }
+
+    /// Weeks in the taper phase
+    pub fn taper_weeks(&self) -> impl Iterator<Item = &PlannedWeek> {
+        self.weeks
+            .iter()
+            .filter(|w| w.phase == TrainingPhase::Taper)
+    }
+}
+
+/// Generator for periodized training plans toward a race
+pub struct TrainingPlanGenerator;
+
+impl TrainingPlanGenerator {
+    /// Generate a week-by-week plan for a time goal
+    ///
+    /// The race date is taken from `goal.target_date`, and `current_load`
+    /// supplies the athlete's CTL and TSB as of `start_date`.
+    ///
+    /// # Errors
+    /// Returns `AppError::InvalidInput` if the goal is not a time goal with a
+    /// positive distance, no training time is available, or the race is fewer
+    /// than four weeks after `start_date`
+    pub fn generate(
+        goal: &Goal,
+        start_date: DateTime<Utc>,
+        current_load: &TrainingLoad,
+        availability: &TimeAvailability,
+    ) -> AppResult<TrainingPlan> {
+        let GoalType::Time { sport, distance } = &goal.goal_type else {
+            return Err(AppError::invalid_input(
+                "Training plans require a time goal for a race distance",
+            ));
+        };
+        let distance = *distance;
+        if !distance.is_finite() || distance <= 0.0 {
+            return Err(AppError::invalid_input(
+                "Race distance must be a positive number of meters",
+            ));
+        }
+        let hours_per_week = availability.hours_per_week;
+        if !hours_per_week.is_finite() || hours_per_week <= 0.0 {
+            return Err(AppError::invalid_input(
+                "At least some weekly training time is required",
+            ));
+        }
+
+        let weeks_to_race = (goal.target_date - start_date).num_days() / 7;
+        if weeks_to_race < MIN_PLAN_WEEKS {
+            return Err(AppError::invalid_input(format!(
+                "Race is {weeks_to_race} weeks away; at least {MIN_PLAN_WEEKS} weeks are needed"
+            )));
+        }
+        let total_weeks = weeks_to_race.min(MAX_PLAN_WEEKS);
+        let plan_start = goal.target_date - Duration::days(total_weeks * 7 - 1);
+
+        let sessions_per_week = sessions_per_week(availability);
+        let phases = assign_phases(total_weeks as usize, taper_week_count(distance));
+
+        // The build ceiling is the CTL sustainable with the least intense weekly mix
+        let lowest_tss_per_hour = [
+            TrainingPhase::Base,
+            TrainingPhase::Build,
+            TrainingPhase::Peak,
+            TrainingPhase::Recovery,
+        ]
+        .into_iter()
+        .map(|phase| mix_tss_per_hour(&session_template(phase, sessions_per_week, false)))
+        .fold(f64::INFINITY, f64::min);
+        let sustainable_ctl = lowest_tss_per_hour * hours_per_week / 7.0;
+        let target_peak_ctl = peak_ctl_for_distance(distance)
+            .min(sustainable_ctl)
+            .max(current_load.ctl);
+
+        let builder = WeekBuilder {
+            sessions_per_week,
+            hours_per_week,
+            target_peak_ctl,
+            distance_meters: distance,
+            noun: session_noun(sport),
+        };
+        let weeks = builder.build_weeks(&phases, plan_start, current_load);
+
+        Ok(TrainingPlan {
+            goal_id: goal.id.clone(),
+            sport: sport.clone(),
+            race_distance_meters: distance,
+            race_date: goal.target_date,
+            starting_ctl: current_load.ctl,
+            starting_tsb: current_load.tsb,
+            target_peak_ctl,
+            hours_per_week,
+            weeks,
+        })
+    }
+}
+
+/// Per-plan settings shared by every generated week
+struct WeekBuilder {
+    sessions_per_week: usize,
+    hours_per_week: f64,
+    target_peak_ctl: f64,
+    distance_meters: f64,
+    noun: &'static str,
+}
+
+impl WeekBuilder {
+    /// Plan each week in turn, projecting load forward as TSS is assigned
+    fn build_weeks(
+        &self,
+        phases: &[TrainingPhase],
+        plan_start: DateTime<Utc>,
+        current_load: &TrainingLoad,
+    ) -> Vec<PlannedWeek> {
+        let mut load = ProjectedLoad {
+            ctl: current_load.ctl,
+            atl: current_load.ctl - current_load.tsb,
+        };
+        let taper_count = phases
+            .iter()
+            .filter(|p| **p == TrainingPhase::Taper)
+            .count();
+        let mut taper_index = 0_u32;
+        let mut pre_taper_tss = 0.0;
+        let mut weeks = Vec::with_capacity(phases.len());
+        let mut week_start = plan_start;
+
+        for (index, phase) in phases.iter().copied().enumerate() {
+            let race_week = index + 1 == phases.len();
+            let template = session_template(phase, self.sessions_per_week, race_week);
+            let weekly_cap = self.hours_per_week * mix_tss_per_hour(&template);
+
+            let desired_tss = match phase {
+                TrainingPhase::Recovery => load.ctl * 7.0,
+                TrainingPhase::Taper => {
+                    taper_index += 1;
+                    pre_taper_tss
+                        * (f64::from(taper_index) / taper_count as f64)
+                            .mul_add(-TAPER_MAX_REDUCTION, 1.0)
+                }
+                TrainingPhase::Base | TrainingPhase::Build | TrainingPhase::Peak => {
+                    let next_ctl = (load.ctl + MAX_WEEKLY_CTL_RAMP)
+                        .min(self.target_peak_ctl)
+                        .max(load.ctl);
+                    load.weekly_tss_to_reach(next_ctl)
+                }
+            };
+            let target_tss = desired_tss.min(weekly_cap).max(0.0);
+            if phase != TrainingPhase::Taper {
+                pre_taper_tss = target_tss;
+            }
+
+            load.apply_week(target_tss);
+
+            let sessions = self.build_sessions(&template, week_start, target_tss);
+            let planned_hours = sessions
+                .iter()
+                .map(|s| s.target_tss / s.workout_type.tss_per_hour())
+                .sum();
+
+            weeks.push(PlannedWeek {
+                week_number: index as u32 + 1,
+                start_date: week_start,
+                phase,
+                target_tss,
+                planned_hours,
+                projected_ctl: load.ctl,
+                projected_atl: load.atl,
+                projected_tsb: load.ctl - load.atl,
+                sessions,
+            });
+            week_start += Duration::days(7);
+        }
+
+        weeks
+    }
+
+    fn build_sessions(
+        &self,
+        template: &[(WorkoutType, f64)],
+        week_start: DateTime<Utc>,
+        weekly_tss: f64,
+    ) -> Vec<PlannedSession> {
+        let offsets = SESSION_DAY_OFFSETS[self.sessions_per_week - MIN_SESSIONS_PER_WEEK];
+        template
+            .iter()
+            .zip(offsets)
+            .map(|((workout, share), offset)| {
+                let target_tss = weekly_tss * share;
+                PlannedSession {
+                    date: week_start + Duration::days(*offset),
+                    workout_type: *workout,
+                    target_tss,
+                    duration_minutes: (target_tss / workout.tss_per_hour() * 60.0).round() as u32,
+                    description: describe(*workout, self.noun, self.distance_meters),
+                }
+            })
+            .collect()
+    }
+}
+
+/// CTL and ATL projected forward day by day
+struct ProjectedLoad {
+    ctl: f64,
+    atl: f64,
+}
+
+impl ProjectedLoad {
+    fn ctl_alpha() -> f64 {
+        2.0 / (CTL_WINDOW_DAYS as f64 + 1.0)
+    }
+
+    fn atl_alpha() -> f64 {
+        2.0 / (ATL_WINDOW_DAYS as f64 + 1.0)
+    }
+
+    /// Weekly TSS which, spread evenly over seven days, moves CTL to `target_ctl`
+    fn weekly_tss_to_reach(&self, target_ctl: f64) -> f64 {
+        let retained = (1.0 - Self::ctl_alpha()).powi(7);
+        let daily = self.ctl.mul_add(-retained, target_ctl) / (1.0 - retained);
+        daily * 7.0
+    }
+
+    /// Advance the projection through a week with TSS spread evenly across days
+    fn apply_week(&mut self, weekly_tss: f64) {
+        let daily = weekly_tss / 7.0;
+        let (ctl_alpha, atl_alpha) = (Self::ctl_alpha(), Self::atl_alpha());
+        for _ in 0..7 {
+            self.ctl = daily.mul_add(ctl_alpha, self.ctl * (1.0 - ctl_alpha));
+            self.atl = daily.mul_add(atl_alpha, self.atl * (1.0 - atl_alpha));
+        }
+    }
+}
+
+/// Number of weekly sessions that fit the athlete's time and preferred session length
+fn sessions_per_week(availability: &TimeAvailability) -> usize {
+    let session_minutes = availability
+        .preferred_duration_minutes
+        .filter(|m| *m > 0)
+        .map_or(DEFAULT_SESSION_MINUTES, f64::from);
+    let sessions = (availability.hours_per_week * 60.0 / session_minutes).round() as usize;
+    sessions.clamp(MIN_SESSIONS_PER_WEEK, MAX_SESSIONS_PER_WEEK)
+}
+
+/// Longer races need longer tapers to shed accumulated fatigue
+fn taper_week_count(distance_meters: f64) -> usize {
+    if distance_meters >= 40_000.0 {
+        3
+    } else if distance_meters >= 20_000.0 {
+        2
+    } else {
+        1
+    }
+}
+
+/// Fitness worth building for a race; shorter races need less chronic load
+fn peak_ctl_for_distance(distance_meters: f64) -> f64 {
+    if distance_meters >= 40_000.0 {
+        75.0
+    } else if distance_meters >= 20_000.0 {
+        60.0
+    } else if distance_meters >= 10_000.0 {
+        50.0
+    } else {
+        40.0
+    }
+}
+
+/// Split the block into base, build and peak weeks followed by the taper
+fn assign_phases(total_weeks: usize, taper_weeks: usize) -> Vec<TrainingPhase> {
+    let taper_weeks = taper_weeks.min(total_weeks - 1);
+    let loading_weeks = total_weeks - taper_weeks;
+    let peak_weeks = (loading_weeks / 4).max(1);
+    let base_weeks = (loading_weeks - peak_weeks) / 2;
+
+    let mut phases: Vec<TrainingPhase> = (0..loading_weeks)
+        .map(|week| {
+            if week >= loading_weeks - peak_weeks {
+                TrainingPhase::Peak
+            } else if (week + 1) % RECOVERY_WEEK_INTERVAL == 0 {
+                TrainingPhase::Recovery
+            } else if week < base_weeks {
+                TrainingPhase::Base
+            } else {
+                TrainingPhase::Build
+            }
+        })
+        .collect();
+    phases.extend(iter::repeat_n(TrainingPhase::Taper, taper_weeks));
+    phases
+}
+
+/// Session kinds and their share of weekly TSS, in date order
+fn session_template(
+    phase: TrainingPhase,
+    sessions_per_week: usize,
+    race_week: bool,
+) -> Vec<(WorkoutType, f64)> {
+    let (key_sessions, final_session): (&[(WorkoutType, f64)], (WorkoutType, f64)) = match phase {
+        TrainingPhase::Base => (
+            &[(WorkoutType::Tempo, 0.15)],
+            (WorkoutType::LongEndurance, 0.30),
+        ),
+        TrainingPhase::Build => (
+            &[(WorkoutType::Threshold, 0.18), (WorkoutType::Tempo, 0.15)],
+            (WorkoutType::LongEndurance, 0.30),
+        ),
+        TrainingPhase::Peak => (
+            &[(WorkoutType::Vo2Max, 0.15), (WorkoutType::RacePace, 0.18)],
+            (WorkoutType::LongEndurance, 0.30),
+        ),
+        TrainingPhase::Recovery => (&[], (WorkoutType::LongEndurance, 0.25)),
+        TrainingPhase::Taper if race_week => {
+            (&[(WorkoutType::RacePace, 0.12)], (WorkoutType::Race, 0.45))
+        }
+        TrainingPhase::Taper => (
+            &[(WorkoutType::RacePace, 0.18)],
+            (WorkoutType::LongEndurance, 0.25),
+        ),
+    };
+
+    let key_count = key_sessions.len().min(sessions_per_week - 1);
+    let keys = &key_sessions[..key_count];
+    let easy_count = sessions_per_week - 1 - key_count;
+    let assigned: f64 = keys.iter().map(|(_, share)| share).sum::<f64>() + final_session.1;
+    let easy_share = if easy_count > 0 {
+        (1.0 - assigned) / easy_count as f64
+    } else {
+        0.0
+    };
+    let scale = if easy_count > 0 { 1.0 } else { 1.0 / assigned };
+
+    // Alternate easy and key sessions so hard days are separated where possible
+    let mut template: Vec<(WorkoutType, f64)> = Vec::with_capacity(sessions_per_week);
+    let mut remaining_keys = keys.iter();
+    let mut easy_left = easy_count;
+    while template.len() < sessions_per_week - 1 {
+        let after_key = template.last().is_none_or(|(workout, _)| workout.is_key());
+        if easy_left > 0 && after_key {
+            template.push((WorkoutType::Endurance, easy_share));
+            easy_left -= 1;
+        } else if let Some((workout, share)) = remaining_keys.next() {
+            template.push((*workout, share * scale));
+        } else {
+            template.push((WorkoutType::Endurance, easy_share));
+            easy_left -= 1;
+        }
+    }
+    template.push((final_session.0, final_session.1 * scale));
+    template
+}
+
+/// Average TSS per hour of a weekly mix, weighting each session by its TSS share
+fn mix_tss_per_hour(template: &[(WorkoutType, f64)]) -> f64 {
+    let hours_per_tss: f64 = template
+        .iter()
+        .map(|(workout, share)| share / workout.tss_per_hour())
+        .sum();
+    1.0 / hours_per_tss
+}
+
+fn session_noun(sport: &str) -> &'static str {
+    let sport = sport.to_lowercase();
+    if sport.contains("run") {
+        "run"
+    } else if sport.contains("ride") || sport.contains("cycl") || sport.contains("bike") {
+        "ride"
+    } else if sport.contains("swim") {
+        "swim"
+    } else {
+        "session"
+    }
+}
+
+fn describe(workout: WorkoutType, noun: &str, distance_meters: f64) -> String {
+    match workout {
+        WorkoutType::Endurance => format!("Easy aerobic {noun}"),
+        WorkoutType::LongEndurance => format!("Long {noun} at steady aerobic effort"),
+        WorkoutType::Tempo => format!("Tempo {noun} with 20-30 min comfortably hard"),
+        WorkoutType::Threshold => format!("Threshold {noun} with 3-4 x 8-10 min intervals"),
+        WorkoutType::Vo2Max => format!("VO2max {noun} with 5-6 x 3 min hard intervals"),
+        WorkoutType::RacePace => format!("Race-pace {noun} with blocks at goal pace"),
+        WorkoutType::Race => format!("Race day: {:.1} km", distance_meters / 1000.0),
+    }
+}
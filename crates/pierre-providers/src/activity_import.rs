@@ -0,0 +1,460 @@
+// ABOUTME: Validates batches of activities imported from FIT, Apple Health, TCX, and GPX files
+// ABOUTME: Produces a structured report of imported, deduplicated, and rejected activities with reasons
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Activity Import Validation
+//!
+//! File imports arrive as a batch of parsed (or unparseable) activities. This
+//! module decides which of them to keep, dropping duplicates of activities the
+//! user already has (or that appear twice in the batch) and rejecting records
+//! that fail sanity checks. Every decision is recorded in an [`ImportReport`] so
+//! users can see exactly what was imported and why anything was skipped.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use chrono::{Duration, Utc};
+//! use pierre_mcp_server::models::{ActivityBuilder, SportType};
+//! use pierre_mcp_server::providers::activity_import::{
+//!     ActivityImportValidator, ImportRecord, ImportSource,
+//! };
+//!
+//! let run = ActivityBuilder::new(
+//!     "fit-1",
+//!     "Morning Run",
+//!     SportType::Run,
+//!     Utc::now() - Duration::days(1),
+//!     1800,
+//!     "fit",
+//! )
+//! .build();
+//! let records = vec![
+//!     ImportRecord::parsed(ImportSource::Fit, "morning.fit", run),
+//!     ImportRecord::failed(ImportSource::Tcx, "broken.tcx", "unexpected end of file"),
+//! ];
+//!
+//! let batch = ActivityImportValidator::default().validate(records, &[]);
+//! assert_eq!(batch.report.imported, 1);
+//! assert_eq!(batch.report.rejected, 1);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::Activity;
+
+/// Default minimum duration for an imported activity (seconds)
+const DEFAULT_MIN_DURATION_SECONDS: u64 = 60;
+
+/// Default maximum duration for an imported activity (seconds, 48 hours)
+const DEFAULT_MAX_DURATION_SECONDS: u64 = 48 * 3600;
+
+/// Default maximum distance for an imported activity (meters)
+const DEFAULT_MAX_DISTANCE_METERS: f64 = 1_000_000.0;
+
+/// Default start time difference still treated as the same activity (seconds)
+const DEFAULT_DEDUP_START_TOLERANCE_SECONDS: i64 = 120;
+
+/// Default relative duration difference still treated as the same activity (percent)
+const DEFAULT_DEDUP_DURATION_TOLERANCE_PERCENT: f64 = 10.0;
+
+/// File format an activity was imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportSource {
+    /// Garmin/ANT+ Flexible and Interoperable Data Transfer file
+    Fit,
+    /// Apple Health export
+    AppleHealth,
+    /// Training Center XML file
+    Tcx,
+    /// GPS Exchange Format file
+    Gpx,
+}
+
+impl fmt::Display for ImportSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fit => write!(f, "fit"),
+            Self::AppleHealth => write!(f, "apple_health"),
+            Self::Tcx => write!(f, "tcx"),
+            Self::Gpx => write!(f, "gpx"),
+        }
+    }
+}
+
+/// One activity from an import batch, before validation
+#[derive(Debug, Clone)]
+pub struct ImportRecord {
+    /// File format the record came from
+    pub source: ImportSource,
+    /// Name of the imported file (or export entry)
+    pub file_name: String,
+    /// Parsed activity, or the parser's error message
+    pub parsed: Result<Activity, String>,
+}
+
+impl ImportRecord {
+    /// Record for a successfully parsed activity
+    #[must_use]
+    pub fn parsed(source: ImportSource, file_name: impl Into<String>, activity: Activity) -> Self {
+        Self {
+            source,
+            file_name: file_name.into(),
+            parsed: Ok(activity),
+        }
+    }
+
+    /// Record for a file the parser could not read
+    #[must_use]
+    pub fn failed(
+        source: ImportSource,
+        file_name: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            source,
+            file_name: file_name.into(),
+            parsed: Err(message.into()),
+        }
+    }
+}
+
+/// Thresholds used to reject and deduplicate imported activities
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportValidationConfig {
+    /// Activities shorter than this are rejected (seconds)
+    #[serde(default = "default_min_duration_seconds")]
+    pub min_duration_seconds: u64,
+    /// Activities longer than this are rejected (seconds)
+    #[serde(default = "default_max_duration_seconds")]
+    pub max_duration_seconds: u64,
+    /// Activities farther than this are rejected (meters)
+    #[serde(default = "default_max_distance_meters")]
+    pub max_distance_meters: f64,
+    /// Start times closer than this are considered the same activity (seconds)
+    #[serde(default = "default_dedup_start_tolerance_seconds")]
+    pub dedup_start_tolerance_seconds: i64,
+    /// Durations closer than this are considered the same activity (percent)
+    #[serde(default = "default_dedup_duration_tolerance_percent")]
+    pub dedup_duration_tolerance_percent: f64,
+}
+
+const fn default_min_duration_seconds() -> u64 {
+    DEFAULT_MIN_DURATION_SECONDS
+}
+
+const fn default_max_duration_seconds() -> u64 {
+    DEFAULT_MAX_DURATION_SECONDS
+}
+
+const fn default_max_distance_meters() -> f64 {
+    DEFAULT_MAX_DISTANCE_METERS
+}
+
+const fn default_dedup_start_tolerance_seconds() -> i64 {
+    DEFAULT_DEDUP_START_TOLERANCE_SECONDS
+}
+
+const fn default_dedup_duration_tolerance_percent() -> f64 {
+    DEFAULT_DEDUP_DURATION_TOLERANCE_PERCENT
+}
+
+impl Default for ImportValidationConfig {
+    fn default() -> Self {
+        Self {
+            min_duration_seconds: default_min_duration_seconds(),
+            max_duration_seconds: default_max_duration_seconds(),
+            max_distance_meters: default_max_distance_meters(),
+            dedup_start_tolerance_seconds: default_dedup_start_tolerance_seconds(),
+            dedup_duration_tolerance_percent: default_dedup_duration_tolerance_percent(),
+        }
+    }
+}
+
+/// Why an imported record was rejected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum RejectionReason {
+    /// The file could not be parsed into an activity
+    ParseFailed {
+        /// Parser error message
+        message: String,
+    },
+    /// Activity is shorter than the configured minimum
+    TooShort {
+        /// Activity duration (seconds)
+        duration_seconds: u64,
+        /// Configured minimum (seconds)
+        min_duration_seconds: u64,
+    },
+    /// Activity is longer than the configured maximum
+    TooLong {
+        /// Activity duration (seconds)
+        duration_seconds: u64,
+        /// Configured maximum (seconds)
+        max_duration_seconds: u64,
+    },
+    /// Activity starts after the import ran
+    FutureStartDate {
+        /// Reported start time
+        start_date: DateTime<Utc>,
+    },
+    /// Distance is negative, not a number, or above the configured maximum
+    InvalidDistance {
+        /// Reported distance (meters)
+        distance_meters: f64,
+    },
+}
+
+impl RejectionReason {
+    /// Stable machine-readable code for grouping rejections
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::ParseFailed { .. } => "parse_failed",
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::FutureStartDate { .. } => "future_start_date",
+            Self::InvalidDistance { .. } => "invalid_distance",
+        }
+    }
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseFailed { message } => write!(f, "could not parse file: {message}"),
+            Self::TooShort {
+                duration_seconds,
+                min_duration_seconds,
+            } => write!(
+                f,
+                "duration {duration_seconds}s is shorter than the {min_duration_seconds}s minimum"
+            ),
+            Self::TooLong {
+                duration_seconds,
+                max_duration_seconds,
+            } => write!(
+                f,
+                "duration {duration_seconds}s is longer than the {max_duration_seconds}s maximum"
+            ),
+            Self::FutureStartDate { start_date } => {
+                write!(f, "start date {} is in the future", start_date.to_rfc3339())
+            }
+            Self::InvalidDistance { distance_meters } => {
+                write!(f, "distance {distance_meters}m is not plausible")
+            }
+        }
+    }
+}
+
+/// A record that was not imported because it failed validation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportRejection {
+    /// File format the record came from
+    pub source: ImportSource,
+    /// Name of the rejected file
+    pub file_name: String,
+    /// Activity id, when the file could be parsed
+    pub activity_id: Option<String>,
+    /// Why it was rejected
+    pub reason: RejectionReason,
+}
+
+/// A record that was not imported because the activity already exists
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportDuplicate {
+    /// File format the record came from
+    pub source: ImportSource,
+    /// Name of the duplicate file
+    pub file_name: String,
+    /// Id of the skipped activity
+    pub activity_id: String,
+    /// Id of the existing (or earlier imported) activity it duplicates
+    pub duplicate_of: String,
+}
+
+/// Summary of what happened to every record in an import batch
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// Records received
+    pub total: usize,
+    /// Activities accepted for import
+    pub imported: usize,
+    /// Records skipped as duplicates
+    pub deduped: usize,
+    /// Records rejected by validation
+    pub rejected: usize,
+    /// Ids of the imported activities, in batch order
+    pub imported_ids: Vec<String>,
+    /// Every duplicate with the activity it matched
+    pub duplicates: Vec<ImportDuplicate>,
+    /// Every rejection with its reason
+    pub rejections: Vec<ImportRejection>,
+}
+
+impl ImportReport {
+    /// Number of rejections per [`RejectionReason::code`]
+    #[must_use]
+    pub fn rejections_by_reason(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for rejection in &self.rejections {
+            *counts.entry(rejection.reason.code()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Activities accepted from an import batch along with the report
+#[derive(Debug, Clone)]
+pub struct ValidatedImport {
+    /// Activities that passed validation and deduplication
+    pub activities: Vec<Activity>,
+    /// What happened to every record
+    pub report: ImportReport,
+}
+
+/// Validates and deduplicates imported activities
+#[derive(Debug, Clone, Default)]
+pub struct ActivityImportValidator {
+    config: ImportValidationConfig,
+}
+
+impl ActivityImportValidator {
+    /// Create a validator with custom thresholds
+    #[must_use]
+    pub const fn new(config: ImportValidationConfig) -> Self {
+        Self { config }
+    }
+
+    /// Validate a batch against the user's existing activities
+    ///
+    /// Records are processed in order, so the first copy of an activity that
+    /// appears twice in a batch is imported and later copies are deduplicated.
+    #[must_use]
+    pub fn validate(&self, records: Vec<ImportRecord>, existing: &[Activity]) -> ValidatedImport {
+        let now = Utc::now();
+        let mut activities: Vec<Activity> = Vec::new();
+        let mut report = ImportReport {
+            total: records.len(),
+            ..ImportReport::default()
+        };
+
+        for record in records {
+            let activity = match record.parsed {
+                Ok(activity) => activity,
+                Err(message) => {
+                    report.rejections.push(ImportRejection {
+                        source: record.source,
+                        file_name: record.file_name,
+                        activity_id: None,
+                        reason: RejectionReason::ParseFailed { message },
+                    });
+                    continue;
+                }
+            };
+
+            if let Some(reason) = self.rejection_reason(&activity, now) {
+                report.rejections.push(ImportRejection {
+                    source: record.source,
+                    file_name: record.file_name,
+                    activity_id: Some(activity.id().to_owned()),
+                    reason,
+                });
+                continue;
+            }
+
+            if let Some(original) = existing
+                .iter()
+                .chain(&activities)
+                .find(|candidate| self.is_duplicate(&activity, candidate))
+            {
+                report.duplicates.push(ImportDuplicate {
+                    source: record.source,
+                    file_name: record.file_name,
+                    activity_id: activity.id().to_owned(),
+                    duplicate_of: original.id().to_owned(),
+                });
+                continue;
+            }
+
+            report.imported_ids.push(activity.id().to_owned());
+            activities.push(activity);
+        }
+
+        report.imported = activities.len();
+        report.deduped = report.duplicates.len();
+        report.rejected = report.rejections.len();
+        ValidatedImport { activities, report }
+    }
+
+    /// First validation rule the activity breaks, if any
+    fn rejection_reason(&self, activity: &Activity, now: DateTime<Utc>) -> Option<RejectionReason> {
+        let duration_seconds = activity.duration_seconds();
+        if duration_seconds < self.config.min_duration_seconds {
+            return Some(RejectionReason::TooShort {
+                duration_seconds,
+                min_duration_seconds: self.config.min_duration_seconds,
+            });
+        }
+        if duration_seconds > self.config.max_duration_seconds {
+            return Some(RejectionReason::TooLong {
+                duration_seconds,
+                max_duration_seconds: self.config.max_duration_seconds,
+            });
+        }
+        if activity.start_date() > now {
+            return Some(RejectionReason::FutureStartDate {
+                start_date: activity.start_date(),
+            });
+        }
+        if let Some(distance_meters) = activity.distance_meters() {
+            if !(0.0..=self.config.max_distance_meters).contains(&distance_meters) {
+                return Some(RejectionReason::InvalidDistance { distance_meters });
+            }
+        }
+        None
+    }
+
+    /// Same id from the same provider, or the same sport at nearly the same time and length
+    fn is_duplicate(&self, activity: &Activity, candidate: &Activity) -> bool {
+        (activity.id() == candidate.id() && activity.provider() == candidate.provider())
+            || is_same_recording(
+                activity,
+                candidate,
+                self.config.dedup_start_tolerance_seconds,
+                self.config.dedup_duration_tolerance_percent,
+            )
+    }
+}
+
+/// Whether two activities are the same sport at nearly the same start time and length
+pub(crate) fn is_same_recording(
+    activity: &Activity,
+    candidate: &Activity,
+    start_tolerance_seconds: i64,
+    duration_tolerance_percent: f64,
+) -> bool {
+    if activity.sport_type() != candidate.sport_type() {
+        return false;
+    }
+
+    let start_gap = (activity.start_date() - candidate.start_date()).abs();
+    if start_gap > Duration::seconds(start_tolerance_seconds) {
+        return false;
+    }
+
+    let longer = activity
+        .duration_seconds()
+        .max(candidate.duration_seconds());
+    let shorter = activity
+        .duration_seconds()
+        .min(candidate.duration_seconds());
+    longer == 0 || (longer - shorter) as f64 / longer as f64 * 100.0 <= duration_tolerance_percent
+}
@@ -34,6 +34,19 @@
 //! }
 //! ```
 //!
+//! ## Bounded Backfills
+//!
+//! [`create_windowed_activity_stream`] stops paging once activities fall outside
+//! an [`ActivityWindow`], so a first sync for a long-standing account cannot page
+//! through its entire history. Where it stopped is recorded in a [`WindowStop`].
+//!
+//! ## Provider Page Sizes
+//!
+//! Providers cap page sizes differently (Strava 200, Garmin 100), so
+//! [`StreamConfig::for_provider`] starts from that provider's documented
+//! default and maximum. Requests above a provider's maximum are clamped with a
+//! warning rather than rejected.
+//!
 //! ## Memory Efficiency
 //!
 //! For a user with 1000 activities fetched in pages of 50:
@@ -42,10 +55,14 @@
 
 use std::collections::VecDeque;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
 
 use async_stream::try_stream;
+use chrono::{DateTime, Duration, Utc};
 use futures_util::Stream;
+use tracing::warn;
 
+use crate::constants::{api_provider_limits, oauth_providers};
 use crate::core::FitnessProvider;
 use crate::errors::provider::ProviderError;
 use crate::models::Activity;
@@ -60,11 +77,49 @@ pub const MIN_PAGE_SIZE: usize = 10;
 /// Maximum page size to prevent memory issues
 pub const MAX_PAGE_SIZE: usize = 200;
 
+/// Page sizes a provider documents for its activity listing endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderPageLimits {
+    /// Page size used when the caller does not ask for one
+    pub default_page_size: usize,
+    /// Largest page the provider returns in one request
+    pub max_page_size: usize,
+}
+
+impl ProviderPageLimits {
+    /// Limits for a provider by name, falling back to the crate-wide defaults
+    #[must_use]
+    pub fn for_provider(provider_name: &str) -> Self {
+        match provider_name {
+            oauth_providers::STRAVA => Self {
+                default_page_size: api_provider_limits::strava::DEFAULT_ACTIVITIES_PER_PAGE,
+                max_page_size: api_provider_limits::strava::MAX_ACTIVITIES_PER_REQUEST,
+            },
+            oauth_providers::GARMIN => Self {
+                default_page_size: api_provider_limits::garmin::DEFAULT_ACTIVITIES_PER_PAGE,
+                max_page_size: api_provider_limits::garmin::MAX_ACTIVITIES_PER_REQUEST,
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+impl Default for ProviderPageLimits {
+    fn default() -> Self {
+        Self {
+            default_page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: MAX_PAGE_SIZE,
+        }
+    }
+}
+
 /// Configuration for activity streaming behavior
 #[derive(Debug, Clone, Copy)]
 pub struct StreamConfig {
     /// Number of activities to fetch per page
     pub page_size: usize,
+    /// Largest page size to request from the provider
+    pub max_page_size: usize,
     /// Maximum total activities to fetch (None for unlimited)
     pub max_activities: Option<usize>,
 }
@@ -73,6 +128,7 @@ impl Default for StreamConfig {
     fn default() -> Self {
         Self {
             page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: MAX_PAGE_SIZE,
             max_activities: None,
         }
     }
@@ -84,10 +140,31 @@ impl StreamConfig {
     pub fn with_page_size(page_size: usize) -> Self {
         Self {
             page_size: page_size.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
+            ..Self::default()
+        }
+    }
+
+    /// Create configuration using a provider's default and maximum page sizes
+    #[must_use]
+    pub fn for_provider(provider_name: &str) -> Self {
+        let limits = ProviderPageLimits::for_provider(provider_name);
+        Self {
+            page_size: limits.default_page_size,
+            max_page_size: limits.max_page_size,
             max_activities: None,
         }
     }
 
+    /// Request a page size, clamped to this configuration's maximum
+    ///
+    /// Asking for more than the provider allows logs a warning and uses the
+    /// maximum instead of failing.
+    #[must_use]
+    pub fn with_requested_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = clamp_page_size(page_size, self.max_page_size);
+        self
+    }
+
     /// Set maximum number of activities to fetch
     #[must_use]
     pub const fn with_max_activities(mut self, max: usize) -> Self {
@@ -96,6 +173,19 @@ impl StreamConfig {
     }
 }
 
+/// Clamp a page size to `[MIN_PAGE_SIZE, max_page_size]`, warning when it is too large
+fn clamp_page_size(page_size: usize, max_page_size: usize) -> usize {
+    let max_page_size = max_page_size.max(MIN_PAGE_SIZE);
+    if page_size > max_page_size {
+        warn!(
+            requested = page_size,
+            max = max_page_size,
+            "Requested activity page size exceeds the provider maximum, clamping"
+        );
+    }
+    page_size.clamp(MIN_PAGE_SIZE, max_page_size)
+}
+
 /// Type alias for the activity stream returned by `activities_stream`
 pub type ActivityStream<'a> =
     Pin<Box<dyn Stream<Item = Result<Activity, ProviderError>> + Send + 'a>>;
@@ -137,12 +227,102 @@ pub fn create_activity_stream(
     provider: &dyn FitnessProvider,
     config: StreamConfig,
 ) -> ActivityStream<'_> {
-    let page_size = config.page_size.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
+    stream_activities(provider, config, None, None)
+}
+
+/// Records where a windowed activity stream stopped paging
+///
+/// Shared between the stream and its caller. Empty until the stream reaches an
+/// activity older than the window, at which point it holds the cursor a later
+/// run can resume from.
+#[derive(Debug, Clone, Default)]
+pub struct WindowStop(Arc<Mutex<Option<Cursor>>>);
+
+impl WindowStop {
+    /// Create an empty stop record
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cursor to resume paging from, if the stream stopped at the window boundary
+    #[must_use]
+    pub fn resume_cursor(&self) -> Option<Cursor> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn record(&self, cursor: Cursor) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(cursor);
+    }
+}
+
+/// Time window bounding how far back a stream pages
+#[derive(Debug, Clone)]
+pub struct ActivityWindow {
+    /// Oldest start time to fetch; paging stops at the first older activity
+    pub not_before: DateTime<Utc>,
+    /// Cursor recorded by an earlier run to continue paging from
+    pub resume_from: Option<Cursor>,
+    /// Filled in when the stream stops at the window boundary
+    pub stop: WindowStop,
+}
+
+impl ActivityWindow {
+    /// Window covering the last `days` days, starting from the newest activity
+    #[must_use]
+    pub fn last_days(days: u32) -> Self {
+        Self {
+            not_before: Utc::now() - Duration::days(i64::from(days)),
+            resume_from: None,
+            stop: WindowStop::new(),
+        }
+    }
+
+    /// Continue paging from a cursor recorded by an earlier run
+    #[must_use]
+    pub fn resuming_from(mut self, cursor: Option<Cursor>) -> Self {
+        self.resume_from = cursor;
+        self
+    }
+}
+
+/// Create a streaming iterator that stops paging at a time window boundary
+///
+/// Activities are fetched newest first. The stream ends at the first activity
+/// that started before `window.not_before` without fetching further pages, and
+/// records in `window.stop` the cursor after the last activity it yielded so a
+/// later run can continue from there.
+pub fn create_windowed_activity_stream(
+    provider: &dyn FitnessProvider,
+    config: StreamConfig,
+    window: ActivityWindow,
+) -> ActivityStream<'_> {
+    stream_activities(
+        provider,
+        config,
+        window.resume_from,
+        Some((window.not_before, window.stop)),
+    )
+}
+
+fn stream_activities(
+    provider: &dyn FitnessProvider,
+    config: StreamConfig,
+    start_cursor: Option<Cursor>,
+    window: Option<(DateTime<Utc>, WindowStop)>,
+) -> ActivityStream<'_> {
+    // Never request more than the provider documents, whatever the config allows
+    let provider_max = ProviderPageLimits::for_provider(provider.name()).max_page_size;
+    let page_size = clamp_page_size(config.page_size, config.max_page_size.min(provider_max));
     let max_activities = config.max_activities;
 
     Box::pin(try_stream! {
         let mut buffer: VecDeque<Activity> = VecDeque::new();
-        let mut next_cursor: Option<Cursor> = None;
+        let mut next_cursor: Option<Cursor> = start_cursor.clone();
+        let mut last_yielded: Option<Cursor> = None;
         let mut yielded_count: usize = 0;
         let mut exhausted = false;
 
@@ -156,6 +336,18 @@ pub fn create_activity_stream(
 
             // Try to yield from buffer first
             if let Some(activity) = buffer.pop_front() {
+                // Stop at the window boundary without fetching further pages
+                if let Some((not_before, stop)) = &window {
+                    if activity.start_date() < *not_before {
+                        let resume = last_yielded
+                            .take()
+                            .or_else(|| start_cursor.clone())
+                            .unwrap_or_else(|| Cursor::new(*not_before, ""));
+                        stop.record(resume);
+                        break;
+                    }
+                    last_yielded = Some(Cursor::new(activity.start_date(), activity.id()));
+                }
                 yielded_count += 1;
                 yield activity;
                 continue;
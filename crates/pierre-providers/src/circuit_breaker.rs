@@ -10,6 +10,7 @@ use std::time::{Duration, Instant};
 use tracing::{info, warn};
 
 use super::errors::provider::ProviderError;
+use super::metrics::MetricsRegistry;
 
 /// Circuit breaker states
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,7 +19,7 @@ pub enum CircuitState {
     Closed,
     /// Circuit is open - requests fail immediately
     Open,
-    /// Testing recovery - allowing one request through
+    /// Testing recovery - allowing a limited number of probe requests through
     HalfOpen,
 }
 
@@ -40,6 +41,40 @@ impl CircuitState {
             Self::HalfOpen => 2,
         }
     }
+
+    /// Label used for this state in metrics
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Bits of the state word holding the `CircuitState`
+const STATE_MASK: u32 = 0xFF;
+/// Offset of the half-open in-flight probe count within the state word
+const PROBES_SHIFT: u32 = 8;
+/// Offset of the half-open success count within the state word
+const SUCCESSES_SHIFT: u32 = 20;
+/// Largest value either half-open counter can hold
+const COUNTER_MAX: u32 = 0xFFF;
+
+/// Pack a state and its half-open counters into a single atomic word
+fn pack(state: CircuitState, probes: u32, successes: u32) -> u32 {
+    u32::from(state.to_u8()) | (probes << PROBES_SHIFT) | (successes << SUCCESSES_SHIFT)
+}
+
+/// Split a state word into the state, in-flight probes, and half-open successes
+#[allow(clippy::cast_possible_truncation)] // Safe: masked to 8 bits
+const fn unpack(word: u32) -> (CircuitState, u32, u32) {
+    (
+        CircuitState::from_u8((word & STATE_MASK) as u8),
+        (word >> PROBES_SHIFT) & COUNTER_MAX,
+        (word >> SUCCESSES_SHIFT) & COUNTER_MAX,
+    )
 }
 
 /// Configuration for circuit breaker behavior
@@ -51,6 +86,8 @@ pub struct CircuitBreakerConfig {
     pub recovery_timeout: Duration,
     /// Duration after which success count resets in closed state
     pub success_threshold: u32,
+    /// Number of probe requests allowed in flight concurrently while half-open
+    pub half_open_max_probes: usize,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -59,6 +96,7 @@ impl Default for CircuitBreakerConfig {
             failure_threshold: 5,
             recovery_timeout: Duration::from_secs(30),
             success_threshold: 2,
+            half_open_max_probes: 1,
         }
     }
 }
@@ -75,9 +113,20 @@ impl CircuitBreakerConfig {
             failure_threshold,
             recovery_timeout,
             success_threshold,
+            half_open_max_probes: 1,
         }
     }
 
+    /// Allow `probes` concurrent probe requests while half-open
+    ///
+    /// The circuit closes once a majority of `probes` (and at least
+    /// `success_threshold`) probes succeed, and re-opens on any probe failure.
+    #[must_use]
+    pub const fn with_half_open_max_probes(mut self, probes: usize) -> Self {
+        self.half_open_max_probes = probes;
+        self
+    }
+
     /// Create a stricter configuration for unreliable providers
     #[must_use]
     pub const fn strict() -> Self {
@@ -85,6 +134,7 @@ impl CircuitBreakerConfig {
             failure_threshold: 3,
             recovery_timeout: Duration::from_secs(60),
             success_threshold: 3,
+            half_open_max_probes: 1,
         }
     }
 
@@ -95,6 +145,7 @@ impl CircuitBreakerConfig {
             failure_threshold: 10,
             recovery_timeout: Duration::from_secs(15),
             success_threshold: 1,
+            half_open_max_probes: 3,
         }
     }
 }
@@ -108,21 +159,24 @@ impl CircuitBreakerConfig {
 ///
 /// - **Closed**: Normal operation, requests pass through. Failures are counted.
 /// - **Open**: Circuit is tripped after threshold failures. All requests fail immediately.
-/// - **Half-Open**: After recovery timeout, one request is allowed through to test recovery.
+/// - **Half-Open**: After recovery timeout, up to `half_open_max_probes` requests are allowed
+///   through concurrently to test recovery. A majority of successful probes closes the
+///   circuit; any failed probe re-opens it immediately.
 ///
 /// # Thread Safety
 ///
 /// All state is managed with atomic operations, making this safe for concurrent access
-/// without requiring mutex locks.
+/// without requiring mutex locks. The half-open probe and success counters share one
+/// atomic word with the state, so admitting a probe and closing or re-opening the
+/// circuit are each a single compare-and-swap.
 pub struct CircuitBreaker {
     /// Provider name for logging and error messages
     provider_name: String,
-    /// Current state (0=Closed, 1=Open, 2=HalfOpen)
+    /// Current state (0=Closed, 1=Open, 2=HalfOpen) in the low byte, with the
+    /// half-open in-flight probe and success counts packed above it
     state: AtomicU32,
     /// Count of consecutive failures
     failure_count: AtomicU32,
-    /// Count of consecutive successes in half-open state
-    success_count: AtomicU32,
     /// Timestamp (epoch millis) when circuit was opened
     last_failure_time: AtomicU64,
     /// Configuration for thresholds and timeouts
@@ -145,7 +199,6 @@ impl CircuitBreaker {
             provider_name: provider_name.to_owned(),
             state: AtomicU32::new(CircuitState::Closed.to_u8().into()),
             failure_count: AtomicU32::new(0),
-            success_count: AtomicU32::new(0),
             last_failure_time: AtomicU64::new(0),
             config,
             start_instant: Instant::now(),
@@ -154,9 +207,8 @@ impl CircuitBreaker {
 
     /// Get current circuit state
     #[must_use]
-    #[allow(clippy::cast_possible_truncation)]
     pub fn state(&self) -> CircuitState {
-        CircuitState::from_u8(self.state.load(Ordering::SeqCst) as u8)
+        unpack(self.state.load(Ordering::SeqCst)).0
     }
 
     /// Get current failure count
@@ -166,15 +218,43 @@ impl CircuitBreaker {
     }
 
     /// Check if circuit allows requests
+    ///
+    /// In half-open state each allowed request occupies a probe slot until its
+    /// outcome is reported through `record_success` or `record_failure`.
     #[must_use]
     pub fn is_allowed(&self) -> bool {
         match self.state() {
             CircuitState::Closed => true,
-            CircuitState::Open => self.should_attempt_recovery(),
-            CircuitState::HalfOpen => false, // Only one request at a time in half-open
+            CircuitState::Open => self.should_attempt_recovery() && self.try_admit_probe(),
+            CircuitState::HalfOpen => self.try_admit_probe(),
         }
     }
 
+    /// Take a half-open probe slot if one is free
+    fn try_admit_probe(&self) -> bool {
+        let max_probes = self.max_probes();
+        self.state
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |word| {
+                let (state, probes, successes) = unpack(word);
+                (state == CircuitState::HalfOpen && probes < max_probes)
+                    .then(|| pack(state, probes + 1, successes))
+            })
+            .is_ok()
+    }
+
+    /// Concurrent probes allowed in half-open state, at least one
+    fn max_probes(&self) -> u32 {
+        u32::try_from(self.config.half_open_max_probes)
+            .unwrap_or(COUNTER_MAX)
+            .clamp(1, COUNTER_MAX)
+    }
+
+    /// Probe successes needed to close the circuit: a majority of the probe
+    /// slots, and never fewer than the configured success threshold
+    fn required_successes(&self) -> u32 {
+        (self.max_probes() / 2 + 1).max(self.config.success_threshold)
+    }
+
     /// Check if we should attempt recovery from open state
     fn should_attempt_recovery(&self) -> bool {
         let last_failure = self.last_failure_time.load(Ordering::SeqCst);
@@ -185,23 +265,35 @@ impl CircuitBreaker {
 
         if elapsed_ms.saturating_sub(last_failure) >= recovery_ms {
             // Attempt transition to half-open
-            let expected = CircuitState::Open.to_u8().into();
-            let new_state = CircuitState::HalfOpen.to_u8().into();
-            if self
-                .state
-                .compare_exchange(expected, new_state, Ordering::SeqCst, Ordering::SeqCst)
-                .is_ok()
-            {
-                info!(
-                    provider = %self.provider_name,
-                    "Circuit breaker transitioning to half-open state for recovery test"
-                );
-                return true;
+            let expected = pack(CircuitState::Open, 0, 0);
+            let new_state = pack(CircuitState::HalfOpen, 0, 0);
+            match self.state.compare_exchange(
+                expected,
+                new_state,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => {
+                    self.record_transition(CircuitState::HalfOpen);
+                    info!(
+                        provider = %self.provider_name,
+                        max_probes = self.max_probes(),
+                        "Circuit breaker transitioning to half-open state for recovery test"
+                    );
+                    return true;
+                }
+                // Another caller moved the circuit to half-open first
+                Err(current) => return unpack(current).0 == CircuitState::HalfOpen,
             }
         }
         false
     }
 
+    /// Count a state change in the global metrics registry
+    fn record_transition(&self, state: CircuitState) {
+        MetricsRegistry::global().record_circuit_transition(&self.provider_name, state.as_str());
+    }
+
     /// Get elapsed time in milliseconds since circuit breaker creation
     fn elapsed_millis(&self) -> u64 {
         // Circuit breakers typically live for minutes/hours, well within u64 millisecond range
@@ -219,13 +311,27 @@ impl CircuitBreaker {
                 self.failure_count.store(0, Ordering::SeqCst);
             }
             CircuitState::HalfOpen => {
-                let count = self.success_count.fetch_add(1, Ordering::SeqCst) + 1;
-                if count >= self.config.success_threshold {
-                    // Recovery confirmed, close circuit
-                    self.state
-                        .store(CircuitState::Closed.to_u8().into(), Ordering::SeqCst);
+                let required = self.required_successes();
+                let closed = self
+                    .state
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |word| {
+                        let (state, probes, successes) = unpack(word);
+                        if state != CircuitState::HalfOpen {
+                            return None;
+                        }
+                        let successes = successes + 1;
+                        Some(if successes >= required {
+                            pack(CircuitState::Closed, 0, 0)
+                        } else {
+                            // Release this probe's slot for the next one
+                            pack(state, probes.saturating_sub(1), successes)
+                        })
+                    })
+                    .is_ok_and(|previous| unpack(previous).2 + 1 >= required);
+                if closed {
+                    // Recovery confirmed by a majority of probes
                     self.failure_count.store(0, Ordering::SeqCst);
-                    self.success_count.store(0, Ordering::SeqCst);
+                    self.record_transition(CircuitState::Closed);
                     info!(
                         provider = %self.provider_name,
                         "Circuit breaker closed - provider recovered"
@@ -245,10 +351,11 @@ impl CircuitBreaker {
                 let count = self.failure_count.fetch_add(1, Ordering::SeqCst) + 1;
                 if count >= self.config.failure_threshold {
                     // Trip the circuit
-                    self.state
-                        .store(CircuitState::Open.to_u8().into(), Ordering::SeqCst);
                     self.last_failure_time
                         .store(self.elapsed_millis(), Ordering::SeqCst);
+                    self.state
+                        .store(CircuitState::Open.to_u8().into(), Ordering::SeqCst);
+                    self.record_transition(CircuitState::Open);
                     warn!(
                         provider = %self.provider_name,
                         failures = count,
@@ -259,16 +366,23 @@ impl CircuitBreaker {
                 }
             }
             CircuitState::HalfOpen => {
-                // Recovery test failed, re-open circuit
-                self.state
-                    .store(CircuitState::Open.to_u8().into(), Ordering::SeqCst);
+                // Any failed probe re-opens the circuit; only the first one reports it
                 self.last_failure_time
                     .store(self.elapsed_millis(), Ordering::SeqCst);
-                self.success_count.store(0, Ordering::SeqCst);
-                warn!(
-                    provider = %self.provider_name,
-                    "Circuit breaker re-opened - recovery test failed"
-                );
+                let reopened = self
+                    .state
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |word| {
+                        (unpack(word).0 == CircuitState::HalfOpen)
+                            .then(|| pack(CircuitState::Open, 0, 0))
+                    })
+                    .is_ok();
+                if reopened {
+                    self.record_transition(CircuitState::Open);
+                    warn!(
+                        provider = %self.provider_name,
+                        "Circuit breaker re-opened - recovery test failed"
+                    );
+                }
             }
             CircuitState::Open => {
                 // Update last failure time
@@ -335,7 +449,6 @@ impl CircuitBreaker {
         self.state
             .store(CircuitState::Closed.to_u8().into(), Ordering::SeqCst);
         self.failure_count.store(0, Ordering::SeqCst);
-        self.success_count.store(0, Ordering::SeqCst);
         info!(
             provider = %self.provider_name,
             "Circuit breaker manually reset to closed state"
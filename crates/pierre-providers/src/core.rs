@@ -122,17 +122,23 @@
 //! This separation allows providers to adapt their specific API formats while
 //! maintaining a consistent interface for the rest of the application.
 
-use crate::errors::provider::ProviderError;
+use crate::errors::provider::{ProviderError, ProviderResult};
 use crate::errors::AppResult;
 use crate::models::TenantId;
 use crate::models::{
-    Activity, Athlete, HealthMetrics, PersonalRecord, RecoveryMetrics, SleepSession, Stats,
+    Activity, ActivitySplit, Athlete, AthleteZones, Gear, HealthMetrics, PersonalRecord,
+    RecoveryMetrics, Segment, SegmentEffort, SleepSession, SplitUnit, Stats, TimeSeries,
 };
 use crate::pagination::{CursorPage, PaginationParams};
+use crate::rate_limit_budget::{ProviderBudget, ProviderBudgetTracker};
+use crate::tenant_concurrency::{TenantConcurrencyLimiter, TenantRequestPermit};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Authentication credentials for `OAuth2` providers (Shared Request Type)
@@ -177,7 +183,7 @@ pub struct OAuth2Credentials {
 /// # Example
 ///
 /// ```rust
-/// use pierre_mcp_server::providers::core::ProviderConfig;
+/// use pierre_mcp_server::providers::core::{ProviderConfig, ProviderValidationMode};
 ///
 /// let config = ProviderConfig {
 ///     name: "strava".to_owned(),
@@ -186,6 +192,8 @@ pub struct OAuth2Credentials {
 ///     api_base_url: "https://www.strava.com/api/v3".to_owned(),
 ///     revoke_url: Some("https://www.strava.com/oauth/deauthorize".to_owned()),
 ///     default_scopes: vec!["activity:read_all".to_owned()],
+///     request_timeout_secs: None,
+///     validation_mode: ProviderValidationMode::Lenient,
 ///};
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -202,6 +210,73 @@ pub struct ProviderConfig {
     pub revoke_url: Option<String>,
     /// Default OAuth scopes to request
     pub default_scopes: Vec<String>,
+    /// Per-request timeout for this provider; `None` keeps the shared client default
+    /// (`HTTP_CLIENT_TIMEOUT_SECS`)
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    /// How malformed or partial records in provider responses are handled
+    #[serde(default)]
+    pub validation_mode: ProviderValidationMode,
+}
+
+/// How an adapter treats malformed or partial records in provider responses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderValidationMode {
+    /// Coerce malformed fields to defaults and log a warning
+    #[default]
+    Lenient,
+    /// Reject the record with `ProviderError::MalformedResponse`
+    Strict,
+}
+
+impl ProviderValidationMode {
+    /// Parse a mode name, case-insensitively
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "lenient" => Some(Self::Lenient),
+            "strict" => Some(Self::Strict),
+            _ => None,
+        }
+    }
+
+    /// Report a malformed field in a provider response
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProviderError::MalformedResponse` in strict mode. Lenient mode
+    /// logs a warning and lets the caller coerce the value.
+    pub fn check_field(
+        self,
+        provider: &str,
+        record_id: &str,
+        field: &str,
+        reason: &str,
+    ) -> ProviderResult<()> {
+        match self {
+            Self::Strict => Err(ProviderError::MalformedResponse {
+                provider: provider.to_owned(),
+                field: field.to_owned(),
+                reason: format!("{reason} (record {record_id})"),
+            }),
+            Self::Lenient => {
+                warn!(
+                    provider,
+                    record_id, field, reason, "Coercing malformed field in provider response"
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ProviderConfig {
+    /// Timeout override applied to this provider's requests, if configured
+    #[must_use]
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout_secs.map(Duration::from_secs)
+    }
 }
 
 /// Query parameters for fetching activities with time-based filtering
@@ -210,22 +285,25 @@ pub struct ProviderConfig {
 /// and timestamp-based filtering. When `before` and `after` are specified,
 /// they enable efficient date range queries without fetching all activities.
 ///
-/// # Strava API Mapping
+/// # Provider Mapping
 ///
-/// - `before`: Maps to Strava's `before` parameter (activities before this epoch timestamp)
-/// - `after`: Maps to Strava's `after` parameter (activities after this epoch timestamp)
-/// - `limit`: Maps to Strava's `per_page` parameter
-/// - `offset`: Converted to page number for Strava's pagination
+/// - Strava: `before`/`after` epoch seconds query parameters
+/// - Fitbit: `beforeDate`/`afterDate` day range, refined client-side
+/// - WHOOP: `start`/`end` ISO 8601 query parameters
+/// - COROS: `start_date`/`end_date` day range
+/// - Garmin, Terra: filtered client-side after fetching
+/// - `limit`: Maps to each provider's page size parameter
+/// - `offset`: Converted to page number or offset where supported
 #[derive(Debug, Clone, Default)]
 pub struct ActivityQueryParams {
     /// Maximum number of activities to return
     pub limit: Option<usize>,
     /// Number of activities to skip (for offset-based pagination)
     pub offset: Option<usize>,
-    /// Unix timestamp (seconds) - return activities before this time
-    pub before: Option<i64>,
-    /// Unix timestamp (seconds) - return activities after this time
-    pub after: Option<i64>,
+    /// Return activities starting before this time (exclusive)
+    pub before: Option<DateTime<Utc>>,
+    /// Return activities starting at or after this time
+    pub after: Option<DateTime<Utc>>,
 }
 
 impl ActivityQueryParams {
@@ -242,7 +320,10 @@ impl ActivityQueryParams {
 
     /// Create new query params with timestamp filtering
     #[must_use]
-    pub const fn with_time_range(before: Option<i64>, after: Option<i64>) -> Self {
+    pub const fn with_time_range(
+        before: Option<DateTime<Utc>>,
+        after: Option<DateTime<Utc>>,
+    ) -> Self {
         Self {
             limit: None,
             offset: None,
@@ -250,6 +331,44 @@ impl ActivityQueryParams {
             after,
         }
     }
+
+    /// Whether a `before` or `after` bound is set
+    #[must_use]
+    pub const fn has_time_range(&self) -> bool {
+        self.before.is_some() || self.after.is_some()
+    }
+
+    /// `before` as Unix epoch seconds
+    #[must_use]
+    pub fn before_timestamp(&self) -> Option<i64> {
+        self.before.map(|dt| dt.timestamp())
+    }
+
+    /// `after` as Unix epoch seconds
+    #[must_use]
+    pub fn after_timestamp(&self) -> Option<i64> {
+        self.after.map(|dt| dt.timestamp())
+    }
+
+    /// Whether an activity start time falls within the `before`/`after` bounds
+    #[must_use]
+    pub fn contains(&self, start_date: DateTime<Utc>) -> bool {
+        self.after.is_none_or(|after| start_date >= after)
+            && self.before.is_none_or(|before| start_date < before)
+    }
+
+    /// Drop activities outside the `before`/`after` bounds
+    ///
+    /// Used by adapters whose upstream API cannot filter by time (or only by day).
+    /// Returns the number of activities removed.
+    pub fn retain_in_range(&self, activities: &mut Vec<Activity>) -> usize {
+        if !self.has_time_range() {
+            return 0;
+        }
+        let before_len = activities.len();
+        activities.retain(|activity| self.contains(activity.start_date()));
+        before_len - activities.len()
+    }
 }
 
 /// Core fitness data provider trait - Shared Request/Response Interface for all providers
@@ -284,6 +403,13 @@ pub trait FitnessProvider: Send + Sync {
     /// Get provider configuration (endpoints, scopes, etc.)
     fn config(&self) -> &ProviderConfig;
 
+    /// Rate-limit budget the provider advertised on its latest response
+    ///
+    /// Providers that do not report their remaining quota return `None`.
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        None
+    }
+
     /// Set `OAuth2` credentials for this provider
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()>;
 
@@ -327,8 +453,8 @@ pub trait FitnessProvider: Send + Sync {
     /// This method supports:
     /// - `limit`: Maximum number of activities to return
     /// - `offset`: Skip this many activities (for offset-based pagination)
-    /// - `before`: Unix timestamp - return activities before this time
-    /// - `after`: Unix timestamp - return activities after this time
+    /// - `before`: return activities starting before this time
+    /// - `after`: return activities starting at or after this time
     ///
     /// For Strava, `before` and `after` map directly to API parameters, enabling
     /// efficient date range queries without fetching all activities first.
@@ -344,7 +470,7 @@ pub trait FitnessProvider: Send + Sync {
     /// let activities = provider.get_activities_with_params(&params).await?;
     ///
     /// // Get activities from the last 30 days
-    /// let thirty_days_ago = (Utc::now() - Duration::days(30)).timestamp();
+    /// let thirty_days_ago = Utc::now() - Duration::days(30);
     /// let time_params = ActivityQueryParams::with_time_range(None, Some(thirty_days_ago));
     /// let recent = provider.get_activities_with_params(&time_params).await?;
     ///
@@ -477,6 +603,84 @@ pub trait FitnessProvider: Send + Sync {
         })
     }
 
+    /// List the athlete's shoes, bikes, and other gear with logged distance
+    ///
+    /// Supported by providers that track equipment (Strava).
+    /// Providers without gear tracking return `UnsupportedFeature` error.
+    async fn list_gear(&self) -> ProviderResult<Vec<Gear>> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: "gear".to_owned(),
+        })
+    }
+
+    /// List the segment efforts recorded during an activity
+    ///
+    /// Supported by providers with segments (Strava).
+    /// Providers without segments return `UnsupportedFeature` error.
+    async fn list_segment_efforts(&self, activity_id: &str) -> ProviderResult<Vec<SegmentEffort>> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: format!("segment_efforts (requested: activity {activity_id})"),
+        })
+    }
+
+    /// Get a segment with its course records and the athlete's best effort
+    ///
+    /// Supported by providers with segments (Strava).
+    /// Providers without segments return `UnsupportedFeature` error.
+    async fn get_segment(&self, segment_id: &str) -> ProviderResult<Segment> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: format!("segments (requested: {segment_id})"),
+        })
+    }
+
+    /// Get the heart rate recorded during an activity, one sample per second or minute
+    ///
+    /// Supported by providers that expose intraday heart rate separately from
+    /// activity summaries (Fitbit). Returns `MissingScope` when the user has not
+    /// granted access to heart rate data, and `UnsupportedFeature` for providers
+    /// without a separate heart rate series.
+    async fn get_activity_hr_series(&self, activity_id: &str) -> ProviderResult<TimeSeries<u16>> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: format!("heart_rate_series (requested: activity {activity_id})"),
+        })
+    }
+
+    /// Get the heart rate and power zones the athlete configured with the provider
+    ///
+    /// Supported by providers that store athlete zones (Strava). Providers
+    /// without athlete zones return `UnsupportedFeature` error, and callers
+    /// fall back to zones derived from configured thresholds.
+    async fn get_athlete_zones(&self) -> ProviderResult<AthleteZones> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: "athlete_zones".to_owned(),
+        })
+    }
+
+    /// Get the splits or laps the provider recorded for an activity
+    ///
+    /// Providers with per-unit splits return those for `unit` and fall back to
+    /// the activity's laps. An empty list means the activity has no split data,
+    /// so callers can compute splits from the activity stream instead.
+    /// Providers without splits return `UnsupportedFeature` error.
+    async fn get_activity_splits(
+        &self,
+        activity_id: &str,
+        unit: SplitUnit,
+    ) -> ProviderResult<Vec<ActivitySplit>> {
+        Err(ProviderError::UnsupportedFeature {
+            provider: self.name().to_owned(),
+            feature: format!(
+                "splits (requested: activity {activity_id}, unit {})",
+                unit.as_str()
+            ),
+        })
+    }
+
     /// Revoke access tokens (disconnect)
     async fn disconnect(&self) -> AppResult<()>;
 }
@@ -491,10 +695,18 @@ pub trait ProviderFactory: Send + Sync {
 }
 
 /// Tenant-aware provider wrapper that handles multi-tenancy
+///
+/// When a [`TenantConcurrencyLimiter`] is attached, every call that reaches the
+/// provider API first waits for one of the tenant's request slots. When a
+/// [`ProviderBudgetTracker`] is attached, calls are delayed or rejected while
+/// the tenant's provider-advertised budget is nearly spent, and the budget the
+/// provider reports back is recorded after each call.
 pub struct TenantProvider {
     inner: Box<dyn FitnessProvider>,
     tenant_id: TenantId,
     user_id: Uuid,
+    limiter: Option<Arc<TenantConcurrencyLimiter>>,
+    budgets: Option<Arc<ProviderBudgetTracker>>,
 }
 
 impl TenantProvider {
@@ -505,9 +717,56 @@ impl TenantProvider {
             inner,
             tenant_id,
             user_id,
+            limiter: None,
+            budgets: None,
         }
     }
 
+    /// Share the tenant's concurrent request limit with other providers using `limiter`
+    #[must_use]
+    pub fn with_concurrency_limiter(mut self, limiter: Arc<TenantConcurrencyLimiter>) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Share the tenant's provider rate-limit budget with other providers using `tracker`
+    #[must_use]
+    pub fn with_budget_tracker(mut self, tracker: Arc<ProviderBudgetTracker>) -> Self {
+        self.budgets = Some(tracker);
+        self
+    }
+
+    /// Wait for a tenant request slot, held until the returned permit is dropped
+    async fn request_slot(&self) -> ProviderResult<Option<TenantRequestPermit>> {
+        match &self.limiter {
+            Some(limiter) => limiter
+                .acquire(self.tenant_id, self.inner.name())
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Run one provider call behind the tenant's request slot and budget
+    async fn guarded<T, E>(&self, call: impl Future<Output = Result<T, E>> + Send) -> Result<T, E>
+    where
+        E: From<ProviderError>,
+    {
+        let _slot = self.request_slot().await?;
+        let Some(tracker) = &self.budgets else {
+            return call.await;
+        };
+
+        tracker
+            .wait_for_budget(self.tenant_id, self.inner.name())
+            .await?;
+        let result = call.await;
+        if let Some(budget) = self.inner.rate_limit_budget() {
+            tracker.record(self.tenant_id, self.inner.name(), budget);
+        }
+        result
+    }
+
     /// Get tenant ID
     #[must_use]
     pub const fn tenant_id(&self) -> TenantId {
@@ -531,6 +790,10 @@ impl FitnessProvider for TenantProvider {
         self.inner.config()
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.inner.rate_limit_budget()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         // Add tenant-specific logging/metrics here
         info!(
@@ -547,40 +810,103 @@ impl FitnessProvider for TenantProvider {
     }
 
     async fn refresh_token_if_needed(&self) -> AppResult<()> {
-        self.inner.refresh_token_if_needed().await
+        self.guarded(self.inner.refresh_token_if_needed()).await
     }
 
     async fn get_athlete(&self) -> AppResult<Athlete> {
-        self.inner.get_athlete().await
+        self.guarded(self.inner.get_athlete()).await
     }
 
     async fn get_activities_with_params(
         &self,
         params: &ActivityQueryParams,
     ) -> AppResult<Vec<Activity>> {
-        self.inner.get_activities_with_params(params).await
+        self.guarded(self.inner.get_activities_with_params(params))
+            .await
     }
 
     async fn get_activities_cursor(
         &self,
         params: &PaginationParams,
     ) -> AppResult<CursorPage<Activity>> {
-        self.inner.get_activities_cursor(params).await
+        self.guarded(self.inner.get_activities_cursor(params)).await
     }
 
     async fn get_activity(&self, id: &str) -> AppResult<Activity> {
-        self.inner.get_activity(id).await
+        self.guarded(self.inner.get_activity(id)).await
     }
 
     async fn get_stats(&self) -> AppResult<Stats> {
-        self.inner.get_stats().await
+        self.guarded(self.inner.get_stats()).await
     }
 
     async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
-        self.inner.get_personal_records().await
+        self.guarded(self.inner.get_personal_records()).await
+    }
+
+    async fn get_sleep_sessions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<Vec<SleepSession>, ProviderError> {
+        self.guarded(self.inner.get_sleep_sessions(start_date, end_date))
+            .await
+    }
+
+    async fn get_latest_sleep_session(&self) -> Result<SleepSession, ProviderError> {
+        self.guarded(self.inner.get_latest_sleep_session()).await
+    }
+
+    async fn get_recovery_metrics(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<Vec<RecoveryMetrics>, ProviderError> {
+        self.guarded(self.inner.get_recovery_metrics(start_date, end_date))
+            .await
+    }
+
+    async fn get_health_metrics(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<Vec<HealthMetrics>, ProviderError> {
+        self.guarded(self.inner.get_health_metrics(start_date, end_date))
+            .await
+    }
+
+    async fn list_gear(&self) -> ProviderResult<Vec<Gear>> {
+        self.guarded(self.inner.list_gear()).await
+    }
+
+    async fn list_segment_efforts(&self, activity_id: &str) -> ProviderResult<Vec<SegmentEffort>> {
+        self.guarded(self.inner.list_segment_efforts(activity_id))
+            .await
+    }
+
+    async fn get_segment(&self, segment_id: &str) -> ProviderResult<Segment> {
+        self.guarded(self.inner.get_segment(segment_id)).await
+    }
+
+    async fn get_activity_hr_series(&self, activity_id: &str) -> ProviderResult<TimeSeries<u16>> {
+        self.guarded(self.inner.get_activity_hr_series(activity_id))
+            .await
+    }
+
+    async fn get_athlete_zones(&self) -> ProviderResult<AthleteZones> {
+        self.guarded(self.inner.get_athlete_zones()).await
+    }
+
+    async fn get_activity_splits(
+        &self,
+        activity_id: &str,
+        unit: SplitUnit,
+    ) -> ProviderResult<Vec<ActivitySplit>> {
+        self.guarded(self.inner.get_activity_splits(activity_id, unit))
+            .await
     }
 
     async fn disconnect(&self) -> AppResult<()> {
-        self.inner.disconnect().await
+        self.guarded(self.inner.disconnect()).await
     }
 }
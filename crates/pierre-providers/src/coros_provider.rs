@@ -22,21 +22,26 @@
 )]
 
 use super::circuit_breaker::CircuitBreaker;
-use super::core::{ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig};
+use super::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use super::errors::provider::ProviderError;
 use crate::constants::oauth_providers;
 use crate::errors::{AppError, AppResult};
-use crate::http_client::shared_client;
+use crate::http_client::{self, shared_client};
+use crate::metrics::MetricsRegistry;
 use crate::models::{
     Activity, ActivityBuilder, Athlete, HealthMetrics, PersonalRecord, RecoveryMetrics,
     SleepSession, SleepStage, SleepStageType, SportType, Stats,
 };
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
+use crate::rate_limit_budget::{parse_standard_headers, BudgetObserver, ProviderBudget};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use std::fmt::Write;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -111,8 +116,15 @@ struct CorosWorkout {
     avg_cadence: Option<u32>,
     /// Average power in watts (for cycling/running with power meter)
     avg_power: Option<u32>,
-    /// Training load/stress score
+    /// Device-computed training load/stress score
+    #[serde(alias = "trainingLoad")]
     training_load: Option<f32>,
+    /// Device-computed aerobic training effect (0.0-5.0)
+    #[serde(alias = "aerobicEffect")]
+    aerobic_effect: Option<f32>,
+    /// Device-computed anaerobic training effect (0.0-5.0)
+    #[serde(alias = "anaerobicEffect")]
+    anaerobic_effect: Option<f32>,
 }
 
 /// COROS sleep session response
@@ -172,6 +184,7 @@ pub struct CorosProvider {
     credentials: RwLock<Option<OAuth2Credentials>>,
     client: Client,
     circuit_breaker: CircuitBreaker,
+    rate_limit_budget: BudgetObserver,
 }
 
 impl CorosProvider {
@@ -192,10 +205,13 @@ impl CorosProvider {
                 .split(' ')
                 .map(str::to_owned)
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         };
 
         Self {
             circuit_breaker: CircuitBreaker::new(oauth_providers::COROS),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
@@ -208,12 +224,32 @@ impl CorosProvider {
         let provider_name = config.name.clone();
         Self {
             circuit_breaker: CircuitBreaker::new(&provider_name),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
         }
     }
 
+    /// Build the `workouts` endpoint for a query
+    ///
+    /// COROS filters by calendar day via `start_date`/`end_date`.
+    #[must_use]
+    pub fn workouts_endpoint(params: &ActivityQueryParams) -> String {
+        let page_limit = params.limit.unwrap_or(25).min(50);
+        let mut endpoint = format!("workouts?limit={page_limit}");
+        if let Some(offset) = params.offset {
+            let _ = write!(endpoint, "&offset={offset}");
+        }
+        if let Some(after) = params.after {
+            let _ = write!(endpoint, "&start_date={}", after.format("%Y-%m-%d"));
+        }
+        if let Some(before) = params.before {
+            let _ = write!(endpoint, "&end_date={}", before.format("%Y-%m-%d"));
+        }
+        endpoint
+    }
+
     /// Retrieve the current access token from credentials
     async fn get_access_token(&self) -> AppResult<String> {
         let token = self
@@ -254,8 +290,15 @@ impl CorosProvider {
             endpoint.trim_start_matches('/')
         );
 
+        let started = Instant::now();
         let result = self.execute_api_request(&url, &access_token).await;
 
+        MetricsRegistry::global().record_provider_call(
+            &self.config.name,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
         // Record success/failure for circuit breaker
         match &result {
             Ok(_) => self.circuit_breaker.record_success(),
@@ -270,16 +313,17 @@ impl CorosProvider {
     where
         T: for<'de> Deserialize<'de>,
     {
-        let response = self
-            .client
-            .get(url)
-            .header("Authorization", format!("Bearer {access_token}"))
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service("COROS", format!("Failed to send request: {e}"))
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client
+                .get(url)
+                .header("Authorization", format!("Bearer {access_token}")),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| AppError::external_service("COROS", format!("Failed to send request: {e}")))?;
 
+        self.rate_limit_budget
+            .observe(parse_standard_headers(response.headers(), Utc::now()));
         let status = response.status();
         debug!("COROS API response status: {status}");
 
@@ -399,10 +443,26 @@ impl CorosProvider {
         .average_cadence_opt(workout.avg_cadence)
         .average_power_opt(workout.avg_power)
         .training_stress_score_opt(workout.training_load)
+        .aerobic_training_effect_opt(workout.aerobic_effect)
+        .anaerobic_training_effect_opt(workout.anaerobic_effect)
         .sport_type_detail_opt(Some(format!("coros_sport_{}", workout.sport_type)))
         .build())
     }
 
+    /// Convert a raw COROS workout JSON payload to our Activity model
+    ///
+    /// Device-computed training load and aerobic/anaerobic effect are carried over
+    /// when present, so training load analysis prefers them over estimates.
+    ///
+    /// # Errors
+    /// Returns error if the payload is not a valid COROS workout or its dates are malformed
+    pub fn convert_workout_json(json: &str) -> AppResult<Activity> {
+        let workout: CorosWorkout = serde_json::from_str(json).map_err(|e| {
+            AppError::external_service("COROS", format!("Failed to parse workout: {e}"))
+        })?;
+        Self::convert_workout(&workout)
+    }
+
     /// Parse datetime from various formats (ISO 8601 or Unix timestamp)
     fn parse_datetime(datetime_str: &str) -> AppResult<DateTime<Utc>> {
         // Try ISO 8601 first
@@ -573,6 +633,10 @@ impl FitnessProvider for CorosProvider {
         &self.config
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.rate_limit_budget.latest()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         info!("Setting COROS credentials");
         *self.credentials.write().await = Some(credentials);
@@ -642,18 +706,17 @@ impl FitnessProvider for CorosProvider {
             ("refresh_token", &refresh_token),
         ];
 
-        let response = self
-            .client
-            .post(&self.config.token_url)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service(
-                    "COROS",
-                    format!("Failed to send token refresh request: {e}"),
-                )
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client.post(&self.config.token_url).form(&params),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| {
+            AppError::external_service(
+                "COROS",
+                format!("Failed to send token refresh request: {e}"),
+            )
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -716,26 +779,7 @@ impl FitnessProvider for CorosProvider {
         &self,
         params: &ActivityQueryParams,
     ) -> AppResult<Vec<Activity>> {
-        let page_limit = params.limit.unwrap_or(25).min(50);
-
-        // Build endpoint with pagination and time filters
-        let mut endpoint = format!("workouts?limit={page_limit}");
-
-        if let Some(offset) = params.offset {
-            let _ = write!(endpoint, "&offset={offset}");
-        }
-
-        if let Some(after) = params.after {
-            if let Some(dt) = chrono::DateTime::from_timestamp(after, 0) {
-                let _ = write!(endpoint, "&start_date={}", dt.format("%Y-%m-%d"));
-            }
-        }
-
-        if let Some(before) = params.before {
-            if let Some(dt) = chrono::DateTime::from_timestamp(before, 0) {
-                let _ = write!(endpoint, "&end_date={}", dt.format("%Y-%m-%d"));
-            }
-        }
+        let endpoint = Self::workouts_endpoint(params);
 
         let response: CorosPaginatedResponse<CorosWorkout> = self.api_request(&endpoint).await?;
 
@@ -749,6 +793,15 @@ impl FitnessProvider for CorosProvider {
             }
         }
 
+        // COROS only filters by calendar day, so trim to the exact time bounds
+        let removed = params.retain_in_range(&mut activities);
+        if removed > 0 {
+            debug!(
+                removed,
+                "COROS workouts filtered client-side to exact before/after bounds"
+            );
+        }
+
         Ok(activities)
     }
 
@@ -950,7 +1003,11 @@ impl FitnessProvider for CorosProvider {
                 if let Some(access_token) = &creds.access_token {
                     let params = [("token", access_token.as_str())];
 
-                    let response = self.client.post(revoke_url).form(&params).send().await;
+                    let response = http_client::send_with_timeout(
+                        self.client.post(revoke_url).form(&params),
+                        self.config.request_timeout(),
+                    )
+                    .await;
 
                     if let Err(e) = response {
                         warn!("Failed to revoke COROS token: {e}");
@@ -0,0 +1,592 @@
+// ABOUTME: Parses uploaded GPX and TCX files into normalized activities with time-series streams
+// ABOUTME: Builds a small element tree from the XML and derives summary metrics from the track points
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Activity File Parsing
+//!
+//! Users without a connected provider can upload GPX or TCX recordings. This
+//! module turns either format into the same [`Activity`] the API providers
+//! produce, including heart rate, cadence, power, altitude, speed and GPS
+//! streams when the file carries them.
+//!
+//! - GPX: track points with `ele` and `time`, plus the Garmin
+//!   `TrackPointExtension` (`hr`, `cad`, `atemp`) and `power` extensions
+//! - TCX: lap totals (`TotalTimeSeconds`, `DistanceMeters`, `Calories`) and
+//!   trackpoints with `HeartRateBpm`, `Cadence`, and the `TPX` `Watts` extension
+//!
+//! Summary metrics come from lap totals when present and are otherwise derived
+//! from the track points. Activity IDs are derived from the file contents, so
+//! uploading the same file twice yields the same ID.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pierre_mcp_server::providers::activity_import::ImportSource;
+//! use pierre_mcp_server::providers::file_formats::{detect_format, parse_activity_file};
+//!
+//! let gpx = br#"<gpx><trk><name>Lunch Run</name><type>running</type><trkseg>
+//!   <trkpt lat="46.5" lon="6.6"><time>2025-06-01T12:00:00Z</time></trkpt>
+//!   <trkpt lat="46.51" lon="6.6"><time>2025-06-01T12:06:00Z</time></trkpt>
+//! </trkseg></trk></gpx>"#;
+//!
+//! let format = detect_format("lunch.gpx", None).unwrap();
+//! assert_eq!(format, ImportSource::Gpx);
+//! let activity = parse_activity_file(format, "lunch.gpx", gpx).unwrap();
+//! assert_eq!(activity.name(), "Lunch Run");
+//! assert_eq!(activity.duration_seconds(), 360);
+//! ```
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use ring::digest::{digest, SHA256};
+
+use crate::activity_import::ImportSource;
+use crate::constants::oauth_providers;
+use crate::errors::provider::ProviderError;
+use crate::models::{Activity, ActivityBuilder, SportType, SportTypeNormalizer, TimeSeriesData};
+
+/// Mean Earth radius used for distances between track points (meters)
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Bytes of the content hash used in activity IDs
+const ACTIVITY_ID_HASH_BYTES: usize = 8;
+
+/// Detect a supported file format from the file name, falling back to the content type
+#[must_use]
+pub fn detect_format(file_name: &str, content_type: Option<&str>) -> Option<ImportSource> {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("gpx") => return Some(ImportSource::Gpx),
+        Some("tcx") => return Some(ImportSource::Tcx),
+        _ => {}
+    }
+    match content_type.map(str::to_ascii_lowercase).as_deref() {
+        Some("application/gpx+xml") => Some(ImportSource::Gpx),
+        Some("application/vnd.garmin.tcx+xml") => Some(ImportSource::Tcx),
+        _ => None,
+    }
+}
+
+/// Parse an uploaded activity file into a normalized activity
+///
+/// # Errors
+///
+/// Returns `ProviderError::InvalidData` if the file is not well-formed XML of the
+/// given format, contains no timestamped track points, or the format cannot be parsed
+pub fn parse_activity_file(
+    format: ImportSource,
+    file_name: &str,
+    bytes: &[u8],
+) -> Result<Activity, ProviderError> {
+    let root = parse_xml(bytes)?;
+    let parsed = match format {
+        ImportSource::Gpx => parse_gpx(&root)?,
+        ImportSource::Tcx => parse_tcx(&root)?,
+        ImportSource::Fit | ImportSource::AppleHealth => {
+            return Err(invalid_data(
+                "format",
+                format!("{format} files cannot be uploaded"),
+            ))
+        }
+    };
+    build_activity(format, file_name, bytes, parsed)
+}
+
+/// XML element with its namespace prefix stripped
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn from_start(start: &BytesStart<'_>) -> Result<Self, ProviderError> {
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| invalid_data("xml", e.to_string()))?;
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| invalid_data("xml", e.to_string()))?;
+            attributes.push((
+                String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned(),
+                value.into_owned(),
+            ));
+        }
+        Ok(Self {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+            attributes,
+            ..Self::default()
+        })
+    }
+
+    fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Self> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// First element with this name anywhere below this one (depth first)
+    fn descendant(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find_map(|child| {
+            (child.name == name)
+                .then_some(child)
+                .or_else(|| child.descendant(name))
+        })
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn text(&self) -> Option<&str> {
+        let text = self.text.trim();
+        (!text.is_empty()).then_some(text)
+    }
+
+    fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).and_then(Self::text)
+    }
+
+    fn child_value<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.child_text(name).and_then(|text| text.parse().ok())
+    }
+
+    fn descendant_value<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.descendant(name)
+            .and_then(Self::text)
+            .and_then(|text| text.parse().ok())
+    }
+}
+
+/// Read the document into an element tree and return its root element
+fn parse_xml(bytes: &[u8]) -> Result<Element, ProviderError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|e| invalid_data("encoding", format!("file is not UTF-8: {e}")))?;
+    let mut reader = Reader::from_str(text.trim_start_matches('\u{feff}'));
+    reader.config_mut().trim_text(true);
+
+    // The bottom of the stack collects the document's top-level elements
+    let mut stack = vec![Element::default()];
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => stack.push(Element::from_start(&start)?),
+            Ok(Event::Empty(start)) => {
+                let element = Element::from_start(&start)?;
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(element);
+                }
+            }
+            Ok(Event::End(_)) => {
+                if stack.len() > 1 {
+                    if let Some(element) = stack.pop() {
+                        if let Some(parent) = stack.last_mut() {
+                            parent.children.push(element);
+                        }
+                    }
+                }
+            }
+            Ok(Event::Text(text)) => {
+                let text = text
+                    .unescape()
+                    .map_err(|e| invalid_data("xml", e.to_string()))?;
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&text);
+                }
+            }
+            Ok(Event::CData(data)) => {
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(invalid_data("xml", e.to_string())),
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err(invalid_data("xml", "unexpected end of file"));
+    }
+    stack
+        .pop()
+        .and_then(|document| document.children.into_iter().next())
+        .ok_or_else(|| invalid_data("xml", "document has no root element"))
+}
+
+/// One sample from a GPX `trkpt` or TCX `Trackpoint`
+#[derive(Debug, Default, Clone)]
+struct TrackPoint {
+    time: Option<DateTime<Utc>>,
+    position: Option<(f64, f64)>,
+    altitude: Option<f64>,
+    /// Cumulative distance recorded by the device (TCX only, meters)
+    distance: Option<f64>,
+    heart_rate: Option<u32>,
+    cadence: Option<u32>,
+    power: Option<u32>,
+    temperature: Option<f32>,
+}
+
+/// Format-independent contents of a parsed file
+#[derive(Debug, Default)]
+struct ParsedFile {
+    name: Option<String>,
+    sport: Option<String>,
+    points: Vec<TrackPoint>,
+    /// Lap totals, preferred over values derived from the points
+    total_seconds: Option<f64>,
+    total_meters: Option<f64>,
+    calories: Option<u32>,
+}
+
+fn parse_gpx(root: &Element) -> Result<ParsedFile, ProviderError> {
+    if root.name != "gpx" {
+        return Err(invalid_data(
+            "gpx",
+            format!("unexpected root element <{}>", root.name),
+        ));
+    }
+    let track = root
+        .child("trk")
+        .ok_or_else(|| invalid_data("trk", "file contains no track"))?;
+
+    let points = track
+        .children_named("trkseg")
+        .flat_map(|segment| segment.children_named("trkpt"))
+        .map(|point| TrackPoint {
+            time: point.child_text("time").and_then(parse_time),
+            position: point
+                .attribute("lat")
+                .and_then(|lat| lat.parse().ok())
+                .zip(point.attribute("lon").and_then(|lon| lon.parse().ok())),
+            altitude: point.child_value("ele"),
+            distance: None,
+            heart_rate: point.descendant_value("hr"),
+            cadence: point.descendant_value("cad"),
+            power: point.descendant_value("power"),
+            temperature: point.descendant_value("atemp"),
+        })
+        .collect();
+
+    Ok(ParsedFile {
+        name: track
+            .child_text("name")
+            .or_else(|| root.child("metadata").and_then(|m| m.child_text("name")))
+            .map(str::to_owned),
+        sport: track.child_text("type").map(str::to_owned),
+        points,
+        ..ParsedFile::default()
+    })
+}
+
+fn parse_tcx(root: &Element) -> Result<ParsedFile, ProviderError> {
+    if root.name != "TrainingCenterDatabase" {
+        return Err(invalid_data(
+            "tcx",
+            format!("unexpected root element <{}>", root.name),
+        ));
+    }
+    let activity = root
+        .child("Activities")
+        .and_then(|activities| activities.child("Activity"))
+        .ok_or_else(|| invalid_data("Activity", "file contains no activity"))?;
+
+    let laps: Vec<&Element> = activity.children_named("Lap").collect();
+    let lap_total = |name: &str| -> Option<f64> {
+        let values: Vec<f64> = laps
+            .iter()
+            .filter_map(|lap| lap.child_value(name))
+            .collect();
+        (!values.is_empty()).then(|| values.iter().sum())
+    };
+    let calories: Vec<u32> = laps
+        .iter()
+        .filter_map(|lap| lap.child_value("Calories"))
+        .collect();
+
+    let points = laps
+        .iter()
+        .flat_map(|lap| lap.children_named("Track"))
+        .flat_map(|track| track.children_named("Trackpoint"))
+        .map(|point| TrackPoint {
+            time: point.child_text("Time").and_then(parse_time),
+            position: point.child("Position").and_then(|position| {
+                position
+                    .child_value("LatitudeDegrees")
+                    .zip(position.child_value("LongitudeDegrees"))
+            }),
+            altitude: point.child_value("AltitudeMeters"),
+            distance: point.child_value("DistanceMeters"),
+            heart_rate: point
+                .child("HeartRateBpm")
+                .and_then(|hr| hr.child_value("Value")),
+            cadence: point
+                .child_value("Cadence")
+                .or_else(|| point.descendant_value("RunCadence")),
+            power: point.descendant_value("Watts"),
+            temperature: None,
+        })
+        .collect();
+
+    Ok(ParsedFile {
+        name: activity.child_text("Notes").map(str::to_owned),
+        sport: activity.attribute("Sport").map(str::to_owned),
+        points,
+        total_seconds: lap_total("TotalTimeSeconds"),
+        total_meters: lap_total("DistanceMeters"),
+        calories: (!calories.is_empty()).then(|| calories.iter().sum()),
+    })
+}
+
+/// Derive summary metrics and streams from the parsed track points
+fn build_activity(
+    format: ImportSource,
+    file_name: &str,
+    bytes: &[u8],
+    parsed: ParsedFile,
+) -> Result<Activity, ProviderError> {
+    let mut points: Vec<TrackPoint> = parsed
+        .points
+        .into_iter()
+        .filter(|point| point.time.is_some())
+        .collect();
+    points.sort_by_key(|point| point.time);
+    let (Some(start), Some(end)) = (
+        points.first().and_then(|point| point.time),
+        points.last().and_then(|point| point.time),
+    ) else {
+        return Err(invalid_data(
+            "time",
+            "file contains no timestamped track points",
+        ));
+    };
+
+    let offsets: Vec<u32> = points
+        .iter()
+        .filter_map(|point| point.time)
+        .map(|time| u32::try_from((time - start).num_seconds()).unwrap_or(0))
+        .collect();
+    let cumulative = cumulative_distances(&points);
+    let speeds = speeds(&offsets, &cumulative);
+
+    // Safe: lap totals are non-negative and far below u64::MAX seconds
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration_seconds = parsed.total_seconds.map_or_else(
+        || u64::try_from((end - start).num_seconds()).unwrap_or(0),
+        |seconds| seconds.max(0.0).round() as u64,
+    );
+    let distance = parsed
+        .total_meters
+        .or_else(|| cumulative.last().copied().filter(|meters| *meters > 0.0));
+
+    let sport = parsed.sport.as_deref().map_or(SportType::Workout, |sport| {
+        SportTypeNormalizer::global().normalize(sport)
+    });
+    let name = parsed.name.unwrap_or_else(|| {
+        file_name
+            .rsplit_once('.')
+            .map_or(file_name, |(stem, _)| stem)
+            .to_owned()
+    });
+
+    let heart_rate = fill_series(points.iter().map(|p| p.heart_rate));
+    let cadence = fill_series(points.iter().map(|p| p.cadence));
+    let power = fill_series(points.iter().map(|p| p.power));
+    let temperature = fill_series(points.iter().map(|p| p.temperature));
+    // Safe: altitudes are well within f32 range
+    #[allow(clippy::cast_possible_truncation)]
+    let altitude = fill_series(points.iter().map(|p| p.altitude.map(|alt| alt as f32)));
+    let gps = fill_series(points.iter().map(|p| p.position));
+    let start_position = points.iter().find_map(|point| point.position);
+
+    let mut builder = ActivityBuilder::new(
+        activity_id(format, bytes),
+        name,
+        sport,
+        start,
+        duration_seconds,
+        oauth_providers::FILE,
+    )
+    .distance_meters_opt(distance)
+    .elevation_gain_opt(elevation_gain(&points))
+    .average_heart_rate_opt(heart_rate.as_deref().and_then(average))
+    .max_heart_rate_opt(
+        heart_rate
+            .as_deref()
+            .and_then(|hr| hr.iter().copied().max()),
+    )
+    .average_cadence_opt(cadence.as_deref().and_then(average))
+    .max_cadence_opt(cadence.as_deref().and_then(|cad| cad.iter().copied().max()))
+    .average_power_opt(power.as_deref().and_then(average))
+    .max_power_opt(
+        power
+            .as_deref()
+            .and_then(|watts| watts.iter().copied().max()),
+    )
+    .calories_opt(parsed.calories)
+    .start_latitude_opt(start_position.map(|(lat, _)| lat))
+    .start_longitude_opt(start_position.map(|(_, lon)| lon));
+
+    if let Some(meters) = distance.filter(|_| duration_seconds > 0) {
+        // Safe: durations are far below 2^52 seconds
+        #[allow(clippy::cast_precision_loss)]
+        let average_speed = meters / duration_seconds as f64;
+        builder = builder.average_speed(average_speed);
+    }
+    if let Some(max_speed) = speeds
+        .as_deref()
+        .and_then(|s| s.iter().copied().reduce(f32::max))
+    {
+        builder = builder.max_speed(f64::from(max_speed));
+    }
+    if let Some(temperatures) = temperature.as_deref() {
+        // Safe: sample counts are far below 2^24
+        #[allow(clippy::cast_precision_loss)]
+        let mean = temperatures.iter().sum::<f32>() / temperatures.len() as f32;
+        builder = builder.temperature(mean);
+    }
+
+    Ok(builder
+        .time_series_data(TimeSeriesData {
+            timestamps: offsets,
+            heart_rate,
+            power,
+            cadence,
+            speed: speeds,
+            altitude,
+            temperature,
+            gps_coordinates: gps,
+        })
+        .build())
+}
+
+/// Distance covered up to each point, from the device's totals or the GPS track
+fn cumulative_distances(points: &[TrackPoint]) -> Vec<f64> {
+    if points.iter().any(|point| point.distance.is_some()) {
+        let mut last = 0.0;
+        return points
+            .iter()
+            .map(|point| {
+                last = point.distance.unwrap_or(last).max(last);
+                last
+            })
+            .collect();
+    }
+
+    let mut total = 0.0;
+    let mut previous: Option<(f64, f64)> = None;
+    points
+        .iter()
+        .map(|point| {
+            if let Some(position) = point.position {
+                if let Some(prev) = previous {
+                    total += haversine_meters(prev, position);
+                }
+                previous = Some(position);
+            }
+            total
+        })
+        .collect()
+}
+
+/// Speed into each point (m/s); `None` when the file has no distance information
+fn speeds(offsets: &[u32], cumulative: &[f64]) -> Option<Vec<f32>> {
+    if cumulative.last().is_none_or(|total| *total <= 0.0) {
+        return None;
+    }
+    let mut speeds = Vec::with_capacity(offsets.len());
+    let mut last_speed = 0.0_f32;
+    for i in 0..offsets.len() {
+        if i > 0 {
+            let elapsed = offsets[i].saturating_sub(offsets[i - 1]);
+            if elapsed > 0 {
+                // Safe: speeds between samples are well within f32 range
+                #[allow(clippy::cast_possible_truncation)]
+                let speed = ((cumulative[i] - cumulative[i - 1]) / f64::from(elapsed)) as f32;
+                last_speed = speed;
+            }
+        }
+        speeds.push(last_speed);
+    }
+    Some(speeds)
+}
+
+/// Total ascent over the recorded altitudes
+fn elevation_gain(points: &[TrackPoint]) -> Option<f64> {
+    let altitudes: Vec<f64> = points.iter().filter_map(|point| point.altitude).collect();
+    (altitudes.len() > 1).then(|| {
+        altitudes
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).max(0.0))
+            .sum()
+    })
+}
+
+/// Align an optional per-point value with the timestamps, carrying the last known
+/// value over gaps; `None` when no point has a value
+fn fill_series<T: Copy>(values: impl Iterator<Item = Option<T>> + Clone) -> Option<Vec<T>> {
+    let mut last = values.clone().flatten().next()?;
+    Some(
+        values
+            .map(|value| {
+                if let Some(value) = value {
+                    last = value;
+                }
+                last
+            })
+            .collect(),
+    )
+}
+
+fn average(values: &[u32]) -> Option<u32> {
+    let count = u64::try_from(values.len())
+        .ok()
+        .filter(|count| *count > 0)?;
+    let sum: u64 = values.iter().copied().map(u64::from).sum();
+    u32::try_from((sum + count / 2) / count).ok()
+}
+
+fn haversine_meters((lat1, lon1): (f64, f64), (lat2, lon2): (f64, f64)) -> f64 {
+    let half_d_lat = ((lat2 - lat1).to_radians() / 2.0).sin();
+    let half_d_lon = ((lon2 - lon1).to_radians() / 2.0).sin();
+    let a = (lat1.to_radians().cos() * lat2.to_radians().cos())
+        .mul_add(half_d_lon * half_d_lon, half_d_lat * half_d_lat);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
+fn parse_time(text: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+/// Stable ID derived from the file contents
+fn activity_id(format: ImportSource, bytes: &[u8]) -> String {
+    let hash = digest(&SHA256, bytes);
+    format!(
+        "{format}-{}",
+        hex::encode(&hash.as_ref()[..ACTIVITY_ID_HASH_BYTES])
+    )
+}
+
+fn invalid_data(field: &str, reason: impl Into<String>) -> ProviderError {
+    ProviderError::InvalidData {
+        provider: oauth_providers::FILE.to_owned(),
+        field: field.to_owned(),
+        reason: reason.into(),
+    }
+}
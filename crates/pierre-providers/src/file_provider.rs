@@ -0,0 +1,215 @@
+// ABOUTME: Pseudo-provider serving activities the user imported from uploaded GPX and TCX files
+// ABOUTME: Needs no OAuth; the caller loads the user's imported activities and hands them over
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # File Import Provider
+//!
+//! Activities uploaded through the file import endpoint are stored per user and
+//! exposed under the `file` provider name, so every tool that takes a
+//! `provider` argument works on them the same way it works on Strava or Garmin
+//! data. The provider itself is a read-only view over the activities it was
+//! created with; see [`crate::file_formats`] for how files are parsed.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::constants::oauth_providers;
+use crate::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderFactory,
+    ProviderValidationMode,
+};
+use crate::errors::provider::ProviderError;
+use crate::errors::AppResult;
+use crate::models::{Activity, Athlete, PersonalRecord, Stats};
+use crate::pagination::{Cursor, CursorPage, PaginationParams};
+
+/// Maximum page size served by cursor pagination
+const MAX_CURSOR_PAGE_SIZE: usize = 100;
+
+/// Default page size for offset pagination
+const DEFAULT_PAGE_SIZE: usize = 30;
+
+/// Provider over a user's imported activity files
+pub struct FileProvider {
+    /// Imported activities, most recent first
+    activities: Vec<Activity>,
+    /// Activity index by ID
+    activity_index: HashMap<String, usize>,
+    config: ProviderConfig,
+}
+
+impl FileProvider {
+    /// Create a provider serving the given imported activities
+    #[must_use]
+    pub fn with_activities(mut activities: Vec<Activity>) -> Self {
+        activities.sort_by_key(|activity| Reverse(activity.start_date()));
+        let activity_index = activities
+            .iter()
+            .enumerate()
+            .map(|(position, activity)| (activity.id().to_owned(), position))
+            .collect();
+        Self {
+            activities,
+            activity_index,
+            config: default_config(),
+        }
+    }
+}
+
+impl Default for FileProvider {
+    fn default() -> Self {
+        Self::with_activities(Vec::new())
+    }
+}
+
+/// Placeholder configuration; the file provider makes no outbound requests
+#[must_use]
+pub fn default_config() -> ProviderConfig {
+    let name = oauth_providers::FILE;
+    ProviderConfig {
+        name: name.to_owned(),
+        auth_url: format!("http://localhost/{name}/auth"),
+        token_url: format!("http://localhost/{name}/token"),
+        api_base_url: format!("http://localhost/{name}/api"),
+        revoke_url: None,
+        default_scopes: Vec::new(),
+        request_timeout_secs: None,
+        validation_mode: ProviderValidationMode::Lenient,
+    }
+}
+
+#[async_trait]
+impl FitnessProvider for FileProvider {
+    fn name(&self) -> &'static str {
+        oauth_providers::FILE
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        &self.config
+    }
+
+    async fn set_credentials(&self, _credentials: OAuth2Credentials) -> AppResult<()> {
+        // No-op: imported files need no credentials
+        Ok(())
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        Ok(Athlete {
+            id: format!("{}_athlete", oauth_providers::FILE),
+            username: "file_import".to_owned(),
+            firstname: None,
+            lastname: None,
+            profile_picture: None,
+            provider: oauth_providers::FILE.to_owned(),
+        })
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        let mut activities = self.activities.clone();
+        params.retain_in_range(&mut activities);
+        Ok(activities
+            .into_iter()
+            .skip(params.offset.unwrap_or(0))
+            .take(params.limit.unwrap_or(DEFAULT_PAGE_SIZE))
+            .collect())
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        let start_index = params.cursor.as_ref().map_or(0, |cursor| {
+            cursor.decode().map_or(0, |(_timestamp, id)| {
+                self.activity_index
+                    .get(&id)
+                    .map_or(0, |position| position + 1)
+            })
+        });
+
+        let items: Vec<Activity> = self
+            .activities
+            .iter()
+            .skip(start_index)
+            .take(params.limit.min(MAX_CURSOR_PAGE_SIZE))
+            .cloned()
+            .collect();
+        let has_more = start_index + items.len() < self.activities.len();
+        let next_cursor = items
+            .last()
+            .filter(|_| has_more)
+            .map(|last| Cursor::new(last.start_date(), last.id()));
+
+        Ok(CursorPage::new(items, next_cursor, None, has_more))
+    }
+
+    async fn get_activity(&self, id: &str) -> AppResult<Activity> {
+        self.activity_index
+            .get(id)
+            .map(|position| self.activities[*position].clone())
+            .ok_or_else(|| {
+                ProviderError::NotFound {
+                    provider: oauth_providers::FILE.to_owned(),
+                    resource_type: "Activity".to_owned(),
+                    resource_id: id.to_owned(),
+                }
+                .into()
+            })
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        Ok(Stats {
+            total_activities: u64::try_from(self.activities.len()).unwrap_or(u64::MAX),
+            total_distance: self
+                .activities
+                .iter()
+                .filter_map(Activity::distance_meters)
+                .sum(),
+            total_duration: self.activities.iter().map(Activity::duration_seconds).sum(),
+            total_elevation_gain: self
+                .activities
+                .iter()
+                .filter_map(Activity::elevation_gain)
+                .sum(),
+        })
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        // Files carry no provider-computed records
+        Ok(Vec::new())
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// Factory for the file import provider
+///
+/// Providers created here are empty; callers serving a user's imports build one
+/// with [`FileProvider::with_activities`] instead.
+pub struct FileProviderFactory;
+
+impl ProviderFactory for FileProviderFactory {
+    fn create(&self, _config: ProviderConfig) -> Box<dyn FitnessProvider> {
+        Box::new(FileProvider::default())
+    }
+
+    fn supported_providers(&self) -> &'static [&'static str] {
+        &[oauth_providers::FILE]
+    }
+}
@@ -10,29 +10,40 @@
 use super::circuit_breaker::CircuitBreaker;
 use super::core::{
     ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderFactory,
+    ProviderValidationMode,
 };
 use super::errors::provider::ProviderError;
 use crate::constants::oauth_providers;
-use crate::errors::{AppError, AppResult};
-use crate::http_client::shared_client;
+use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::http_client::{self, shared_client};
+use crate::metrics::MetricsRegistry;
 use crate::models::{
     Activity, ActivityBuilder, Athlete, HealthMetrics, HeartRateZone, PersonalRecord,
-    RecoveryMetrics, SleepSession, SleepStage, SleepStageType, SportType, Stats,
+    RecoveryMetrics, SleepSession, SleepStage, SleepStageType, SportType, SportTypeNormalizer,
+    Stats, TimeSeries,
 };
 use crate::pagination::{CursorPage, PaginationParams};
+use crate::rate_limit_budget::{parse_fitbit_headers, BudgetObserver, ProviderBudget};
 use async_trait::async_trait;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Timelike, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use serde_json::from_str;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, instrument, warn};
 
 /// Fitbit API base URL
 const FITBIT_API_BASE: &str = "https://api.fitbit.com/1";
 
+/// OAuth scope granting access to activity logs
+const ACTIVITY_SCOPE: &str = "activity";
+
+/// OAuth scope granting access to heart rate data, including intraday series
+const HEART_RATE_SCOPE: &str = "heartrate";
+
 /// Fitbit API error response format
 #[derive(Debug, Deserialize)]
 struct FitbitErrorResponse {
@@ -214,12 +225,36 @@ struct FitbitRestingHrValue {
     resting_heart_rate: Option<u32>,
 }
 
+/// Fitbit intraday heart rate API response
+#[derive(Debug, Deserialize)]
+pub struct FitbitIntradayHeartRateResponse {
+    #[serde(rename = "activities-heart-intraday")]
+    intraday: FitbitIntradayHeartRate,
+}
+
+/// Intraday heart rate samples with their sampling interval
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FitbitIntradayHeartRate {
+    dataset: Vec<FitbitIntradaySample>,
+    dataset_interval: u32,
+    dataset_type: String,
+}
+
+/// One intraday heart rate sample at a local wall-clock time
+#[derive(Debug, Deserialize)]
+struct FitbitIntradaySample {
+    time: String,
+    value: u16,
+}
+
 /// Clean Fitbit provider implementation
 pub struct FitbitProvider {
     config: ProviderConfig,
     credentials: RwLock<Option<OAuth2Credentials>>,
     client: Client,
     circuit_breaker: CircuitBreaker,
+    rate_limit_budget: BudgetObserver,
 }
 
 impl FitbitProvider {
@@ -236,10 +271,13 @@ impl FitbitProvider {
                 .split(' ')
                 .map(str::to_owned)
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         };
 
         Self {
             circuit_breaker: CircuitBreaker::new(oauth_providers::FITBIT),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
@@ -252,12 +290,42 @@ impl FitbitProvider {
         let provider_name = config.name.clone();
         Self {
             circuit_breaker: CircuitBreaker::new(&provider_name),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
         }
     }
 
+    /// Build the `activities/list.json` endpoint for a query
+    ///
+    /// Fitbit filters by calendar day via `beforeDate`/`afterDate`. Without time
+    /// bounds the last 30 days are requested; with only one bound the window
+    /// spans a year.
+    #[must_use]
+    pub fn activities_endpoint(params: &ActivityQueryParams) -> String {
+        let (start_date, end_date) = if params.has_time_range() {
+            let end = params
+                .before
+                .map_or_else(|| Utc::now().date_naive(), |dt| dt.date_naive());
+            let start = params
+                .after
+                .map_or_else(|| end - chrono::Duration::days(365), |dt| dt.date_naive());
+            (start, end)
+        } else {
+            let end = Utc::now().date_naive();
+            (end - chrono::Duration::days(30), end)
+        };
+
+        format!(
+            "user/-/activities/list.json?beforeDate={}&afterDate={}&sort=desc&limit={}&offset={}",
+            end_date.format("%Y-%m-%d"),
+            start_date.format("%Y-%m-%d"),
+            params.limit.unwrap_or(100),
+            params.offset.unwrap_or(0)
+        )
+    }
+
     /// Retrieve the current access token from credentials
     async fn get_access_token(&self) -> AppResult<String> {
         let token = self
@@ -294,9 +362,11 @@ impl FitbitProvider {
                         );
                     }
 
-                    if error_type == "insufficient_scope" {
-                        return AppError::external_service(
-                            "Fitbit",
+                    if error_type == "insufficient_scope"
+                        || error_type == "insufficient_permissions"
+                    {
+                        return AppError::new(
+                            ErrorCode::PermissionDenied,
                             format!("Insufficient permissions: {message}"),
                         );
                     }
@@ -342,8 +412,15 @@ impl FitbitProvider {
             endpoint.trim_start_matches('/')
         );
 
+        let started = Instant::now();
         let result = self.execute_api_request(&url, &access_token).await;
 
+        MetricsRegistry::global().record_provider_call(
+            &self.config.name,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
         // Record success/failure for circuit breaker
         match &result {
             Ok(_) => self.circuit_breaker.record_success(),
@@ -370,14 +447,14 @@ impl FitbitProvider {
     ) -> AppResult<reqwest::Response> {
         debug!("Making HTTP GET request to: {url}");
 
-        self.client
-            .get(url)
-            .header("Authorization", format!("Bearer {access_token}"))
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service("Fitbit", format!("Failed to send request: {e}"))
-            })
+        http_client::send_with_timeout(
+            self.client
+                .get(url)
+                .header("Authorization", format!("Bearer {access_token}")),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| AppError::external_service("Fitbit", format!("Failed to send request: {e}")))
     }
 
     /// Parse Fitbit API response or handle errors
@@ -385,6 +462,8 @@ impl FitbitProvider {
     where
         T: for<'de> Deserialize<'de>,
     {
+        self.rate_limit_budget
+            .observe(parse_fitbit_headers(response.headers(), Utc::now()));
         let status = response.status();
         debug!("Received HTTP response with status: {status}");
 
@@ -400,6 +479,117 @@ impl FitbitProvider {
         })
     }
 
+    /// Whether the stored grant includes heart rate access (assumed when unknown)
+    async fn heart_rate_scope_granted(&self) -> bool {
+        self.credentials
+            .read()
+            .await
+            .as_ref()
+            .is_none_or(|credentials| {
+                credentials.scopes.is_empty()
+                    || credentials
+                        .scopes
+                        .iter()
+                        .flat_map(|scope| scope.split_whitespace())
+                        .any(|scope| scope == HEART_RATE_SCOPE)
+            })
+    }
+
+    /// Wrap an API failure as a provider error, blaming `scope` for permission errors
+    fn to_provider_error(error: &AppError, activity_id: &str, scope: &str) -> ProviderError {
+        match error.code {
+            ErrorCode::PermissionDenied => ProviderError::MissingScope {
+                provider: oauth_providers::FITBIT.to_owned(),
+                scopes: vec![scope.to_owned()],
+            },
+            ErrorCode::ResourceNotFound => ProviderError::NotFound {
+                provider: oauth_providers::FITBIT.to_owned(),
+                resource_type: "activity".to_owned(),
+                resource_id: activity_id.to_owned(),
+            },
+            _ => ProviderError::ApiError {
+                provider: oauth_providers::FITBIT.to_owned(),
+                status_code: 0,
+                message: error.to_string(),
+                retryable: false,
+            },
+        }
+    }
+
+    /// Build the intraday heart rate endpoint covering an activity at 1-second detail
+    ///
+    /// Fitbit selects intraday data by calendar day and `HH:MM` wall-clock range,
+    /// so the range is widened to whole minutes. Activities running past midnight
+    /// are cut off at the end of their start day.
+    #[must_use]
+    pub fn intraday_heart_rate_endpoint(
+        start_date: DateTime<Utc>,
+        duration_seconds: u64,
+    ) -> String {
+        let start = start_date.with_second(0).unwrap_or(start_date);
+        let end = start_date
+            + Duration::seconds(i64::try_from(duration_seconds).unwrap_or(0))
+            + Duration::seconds(59);
+        let end_time = if end.date_naive() == start.date_naive() {
+            end.format("%H:%M").to_string()
+        } else {
+            "23:59".to_owned()
+        };
+
+        format!(
+            "user/-/activities/heart/date/{}/1d/1sec/time/{}/{}.json",
+            start.format("%Y-%m-%d"),
+            start.format("%H:%M"),
+            end_time
+        )
+    }
+
+    /// Convert an intraday heart rate response to a series starting at the activity start
+    ///
+    /// Sample times are wall-clock times on the activity's start day, on the same
+    /// clock as the activity start. Samples outside the activity are dropped; a
+    /// minute sample covering the start is kept at offset zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if a sample time is not `HH:MM:SS`
+    pub fn convert_intraday_heart_rate(
+        response: FitbitIntradayHeartRateResponse,
+        start_date: DateTime<Utc>,
+        duration_seconds: u64,
+    ) -> Result<TimeSeries<u16>, ProviderError> {
+        let intraday = response.intraday;
+        let unit_seconds = if intraday.dataset_type == "minute" {
+            60
+        } else {
+            1
+        };
+        let resolution = intraday.dataset_interval.max(1) * unit_seconds;
+        let duration = i64::try_from(duration_seconds).unwrap_or(i64::MAX);
+
+        let mut series = TimeSeries::new(start_date, resolution);
+        for sample in intraday.dataset {
+            let time = NaiveTime::parse_from_str(&sample.time, "%H:%M:%S").map_err(|e| {
+                ProviderError::InvalidData {
+                    provider: oauth_providers::FITBIT.to_owned(),
+                    field: "activities-heart-intraday.dataset.time".to_owned(),
+                    reason: format!("'{}': {e}", sample.time),
+                }
+            })?;
+            let offset =
+                (start_date.date_naive().and_time(time).and_utc() - start_date).num_seconds();
+            if offset <= -i64::from(resolution) || offset > duration {
+                continue;
+            }
+            series.push(
+                u32::try_from(offset.max(0)).unwrap_or(u32::MAX),
+                sample.value,
+            );
+        }
+
+        Ok(series)
+    }
+
     /// Convert Fitbit activity type ID to our `SportType` enum
     fn parse_sport_type(activity_type_id: u32, activity_name: &str) -> SportType {
         // Fitbit activity type IDs based on their API documentation
@@ -413,7 +603,7 @@ impl FitbitProvider {
             52001 | 17190 => SportType::Yoga,       // Yoga
             15680 => SportType::StrengthTraining,   // Weight Training
             15000 | 15010 | 15020 => SportType::Workout, // Workout types
-            _ => SportType::Other(activity_name.to_owned()),
+            _ => SportTypeNormalizer::global().normalize(activity_name),
         }
     }
 
@@ -596,6 +786,10 @@ impl FitnessProvider for FitbitProvider {
         &self.config
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.rate_limit_budget.latest()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         info!("Setting Fitbit credentials");
         *self.credentials.write().await = Some(credentials);
@@ -668,20 +862,21 @@ impl FitnessProvider for FitbitProvider {
             ("refresh_token", &refresh_token),
         ];
 
-        let response = self
-            .client
-            .post(&self.config.token_url)
-            .header("Authorization", format!("Basic {auth_value}"))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service(
-                    "Fitbit",
-                    format!("Failed to send token refresh request: {e}"),
-                )
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client
+                .post(&self.config.token_url)
+                .header("Authorization", format!("Basic {auth_value}"))
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .form(&params),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| {
+            AppError::external_service(
+                "Fitbit",
+                format!("Failed to send token refresh request: {e}"),
+            )
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -739,38 +934,7 @@ impl FitnessProvider for FitbitProvider {
         &self,
         params: &ActivityQueryParams,
     ) -> AppResult<Vec<Activity>> {
-        // Fitbit API uses date-based pagination with beforeDate/afterDate
-        let fitbit_offset = params.offset.unwrap_or(0);
-
-        // Use before/after timestamps if provided, otherwise default to last 30 days
-        let (start_date, end_date) = if params.before.is_some() || params.after.is_some() {
-            let end = params.before.map_or_else(
-                || chrono::Utc::now().date_naive(),
-                |ts| {
-                    chrono::DateTime::from_timestamp(ts, 0)
-                        .map_or_else(|| chrono::Utc::now().date_naive(), |dt| dt.date_naive())
-                },
-            );
-            let start = params.after.map_or_else(
-                || end - chrono::Duration::days(365),
-                |ts| {
-                    chrono::DateTime::from_timestamp(ts, 0)
-                        .map_or_else(|| end - chrono::Duration::days(365), |dt| dt.date_naive())
-                },
-            );
-            (start, end)
-        } else {
-            let end = chrono::Utc::now().date_naive();
-            (end - chrono::Duration::days(30), end)
-        };
-
-        let endpoint = format!(
-            "user/-/activities/list.json?beforeDate={}&afterDate={}&sort=desc&limit={}&offset={}",
-            end_date.format("%Y-%m-%d"),
-            start_date.format("%Y-%m-%d"),
-            params.limit.unwrap_or(100),
-            fitbit_offset
-        );
+        let endpoint = Self::activities_endpoint(params);
 
         let response: FitbitActivitiesResponse = self.api_request(&endpoint).await?;
 
@@ -784,6 +948,15 @@ impl FitnessProvider for FitbitProvider {
             }
         }
 
+        // Fitbit only filters by calendar day, so trim to the exact time bounds
+        let removed = params.retain_in_range(&mut activities);
+        if removed > 0 {
+            debug!(
+                removed,
+                "Fitbit activities filtered client-side to exact before/after bounds"
+            );
+        }
+
         // Apply limit if specified
         if let Some(limit) = params.limit {
             activities.truncate(limit);
@@ -1046,6 +1219,41 @@ impl FitnessProvider for FitbitProvider {
         Ok(metrics)
     }
 
+    #[instrument(
+        skip(self),
+        fields(provider = "fitbit", api_call = "get_activity_hr_series", activity_id = %activity_id)
+    )]
+    async fn get_activity_hr_series(
+        &self,
+        activity_id: &str,
+    ) -> Result<TimeSeries<u16>, ProviderError> {
+        // Skip the round trips when the stored grant is known to lack heart rate access
+        if !self.heart_rate_scope_granted().await {
+            return Err(ProviderError::MissingScope {
+                provider: oauth_providers::FITBIT.to_owned(),
+                scopes: vec![HEART_RATE_SCOPE.to_owned()],
+            });
+        }
+
+        // The intraday endpoint is addressed by time range, not activity
+        let activity = self
+            .get_activity(activity_id)
+            .await
+            .map_err(|e| Self::to_provider_error(&e, activity_id, ACTIVITY_SCOPE))?;
+        let endpoint =
+            Self::intraday_heart_rate_endpoint(activity.start_date(), activity.duration_seconds());
+        let response: FitbitIntradayHeartRateResponse = self
+            .api_request(&endpoint)
+            .await
+            .map_err(|e| Self::to_provider_error(&e, activity_id, HEART_RATE_SCOPE))?;
+
+        Self::convert_intraday_heart_rate(
+            response,
+            activity.start_date(),
+            activity.duration_seconds(),
+        )
+    }
+
     async fn disconnect(&self) -> AppResult<()> {
         // Clone access token and revoke URL to avoid holding lock across await
         let (access_token_opt, revoke_url_opt) = {
@@ -1060,18 +1268,20 @@ impl FitnessProvider for FitbitProvider {
 
         if let (Some(access_token), Some(revoke_url)) = (access_token_opt, revoke_url_opt) {
             // Fitbit uses POST with token in body
-            self.client
-                .post(&revoke_url)
-                .form(&[("token", access_token.as_str())])
-                .send()
-                .await
-                .inspect_err(|e| {
-                    warn!(
-                        error = ?e,
-                        "Failed to revoke Fitbit access token - continuing with credential cleanup"
-                    );
-                })
-                .ok();
+            http_client::send_with_timeout(
+                self.client
+                    .post(&revoke_url)
+                    .form(&[("token", access_token.as_str())]),
+                self.config.request_timeout(),
+            )
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    error = ?e,
+                    "Failed to revoke Fitbit access token - continuing with credential cleanup"
+                );
+            })
+            .ok();
             info!("Attempted to revoke Fitbit access token");
         }
 
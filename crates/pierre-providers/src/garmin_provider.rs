@@ -5,19 +5,28 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use super::circuit_breaker::CircuitBreaker;
-use super::core::{ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig};
+use super::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use super::errors::provider::ProviderError;
+use super::garmin_session::{
+    resume_session, GarminLoginClient, GarminSessionOutcome, GarminSessionStore,
+};
 use super::utils::{self, RetryConfig};
 use crate::constants::oauth::GARMIN_DEFAULT_SCOPES;
 use crate::constants::{api_provider_limits, oauth_providers};
 use crate::errors::{AppError, AppResult};
-use crate::http_client::shared_client;
-use crate::models::{Activity, ActivityBuilder, Athlete, PersonalRecord, SportType, Stats};
+use crate::http_client::{self, shared_client};
+use crate::metrics::MetricsRegistry;
+use crate::models::{
+    Activity, ActivityBuilder, Athlete, PersonalRecord, SportType, SportTypeNormalizer, Stats,
+};
 use crate::pagination::{CursorPage, PaginationParams};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use reqwest::{Client, StatusCode};
 use serde::Deserialize;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, info, instrument, warn};
 
@@ -37,6 +46,8 @@ struct GarminActivityResponse {
     activity_name: String,
     activity_type: String,
     start_time_gmt: String,
+    /// Offset of the local start time from GMT in seconds
+    start_time_offset_in_seconds: Option<i32>,
     distance: Option<f64>,
     duration: Option<f64>,
     elevation_gain: Option<f64>,
@@ -85,6 +96,8 @@ impl GarminProvider {
                 .split(',')
                 .map(str::to_owned)
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         };
 
         Self {
@@ -109,6 +122,37 @@ impl GarminProvider {
         }
     }
 
+    /// Authenticate from a stored session, logging in only when its tokens cannot be used
+    ///
+    /// Client credentials already set on the provider are kept; only the bearer
+    /// and its expiry are replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session cannot be resumed; see [`resume_session`].
+    pub async fn authenticate_with_session(
+        &self,
+        store: &dyn GarminSessionStore,
+        client: &dyn GarminLoginClient,
+    ) -> AppResult<GarminSessionOutcome> {
+        let (bearer, outcome) = resume_session(store, client).await?;
+        debug!(?outcome, "Garmin session ready");
+
+        let mut guard = self.credentials.write().await;
+        let credentials = guard.get_or_insert_with(|| OAuth2Credentials {
+            client_id: String::new(),
+            client_secret: String::new(),
+            access_token: None,
+            refresh_token: None,
+            expires_at: None,
+            scopes: self.config.default_scopes.clone(),
+        });
+        credentials.access_token = Some(bearer.access_token);
+        credentials.expires_at = Some(bearer.expires_at);
+        drop(guard);
+        Ok(outcome)
+    }
+
     /// Make authenticated API request with rate limit handling and circuit breaker protection
     /// Uses shared retry logic with exponential backoff for 429 errors
     async fn api_request<T>(&self, endpoint: &str) -> AppResult<T>
@@ -153,15 +197,23 @@ impl GarminProvider {
                 api_provider_limits::garmin::ESTIMATED_RATE_LIMIT_BLOCK_DURATION_SECS,
         };
 
+        let started = Instant::now();
         let result = utils::api_request_with_retry(
             &self.client,
             &url,
             &access_token,
             "Garmin",
             &retry_config,
+            self.config.request_timeout(),
         )
         .await;
 
+        MetricsRegistry::global().record_provider_call(
+            &self.config.name,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
         // Record success/failure for circuit breaker
         match &result {
             Ok(_) => self.circuit_breaker.record_success(),
@@ -175,79 +227,7 @@ impl GarminProvider {
     /// Based on Garmin Connect activity types from `activity_types.properties`
     /// Source: <https://connect.garmin.com/modern/main/js/properties/activity_types/activity_types.properties>
     fn parse_sport_type(garmin_type: &str) -> SportType {
-        match garmin_type.to_lowercase().as_str() {
-            // Running variants
-            "running" | "run" | "track" | "track_running" => SportType::Run,
-            "trail_running" | "trail_run" => SportType::TrailRunning,
-            "treadmill" | "treadmill_running" => SportType::VirtualRun,
-
-            // Cycling variants
-            "cycling" | "bike" | "biking" | "road_cycling" | "road" | "cyclocross" | "cx" => {
-                SportType::Ride
-            }
-            "mountain_biking" | "mountain_bike_ride" | "mountain" => SportType::MountainBike,
-            "indoor_cycling" | "spin" | "virtual_ride" => SportType::VirtualRide,
-            "gravel_cycling" | "gravel_ride" => SportType::GravelRide,
-            "ebike" | "e_bike_ride" | "e_bike" => SportType::EbikeRide,
-
-            // Swimming variants
-            "swimming"
-            | "swim"
-            | "open_water_swimming"
-            | "open_water"
-            | "pool_swimming"
-            | "lap_swimming" => SportType::Swim,
-
-            // Walking and hiking
-            "walking" | "walk" | "casual_walking" => SportType::Walk,
-            "hiking" | "hike" => SportType::Hike,
-
-            // Winter sports
-            "resort_skiing" | "alpine_skiing" | "downhill_skiing" => SportType::AlpineSkiing,
-            "backcountry_skiing_snowboarding" | "backcountry_skiing" => {
-                SportType::BackcountrySkiing
-            }
-            "cross_country_skiing" | "xc_skiing" | "nordic_skiing" => SportType::CrossCountrySkiing,
-            "snowboarding" | "snowboard" => SportType::Snowboarding,
-            "snowshoeing" | "snowshoe" => SportType::Snowshoe,
-            "ice_skating" | "skating" => SportType::IceSkating,
-
-            // Water sports
-            "kayaking" | "kayak" => SportType::Kayaking,
-            "canoeing" | "canoe" => SportType::Canoeing,
-            "rowing" | "row" | "indoor_rowing" => SportType::Rowing,
-            "stand_up_paddleboarding" | "sup" | "paddleboarding" => SportType::Paddleboarding,
-            "surfing" | "surf" => SportType::Surfing,
-            "kitesurfing" | "kiteboarding" => SportType::Kitesurfing,
-
-            // Strength and fitness
-            "strength_training" | "weight_training" | "weights" => SportType::StrengthTraining,
-            "crossfit" | "cross_fit" => SportType::Crossfit,
-            "pilates" => SportType::Pilates,
-            "yoga" => SportType::Yoga,
-
-            // Climbing and adventure
-            "rock_climbing" | "climbing" | "bouldering" => SportType::RockClimbing,
-
-            // Team sports
-            "soccer" | "football" => SportType::Soccer,
-            "basketball" => SportType::Basketball,
-            "tennis" => SportType::Tennis,
-            "golf" => SportType::Golf,
-
-            // Alternative transport
-            "skateboarding" | "skateboard" => SportType::Skateboarding,
-            "inline_skating" | "roller_skating" => SportType::InlineSkating,
-
-            // Generic/other - combined cardio, fitness equipment, and generic workout types
-            "cardio" | "cardio_training" | "elliptical" | "fitness_equipment"
-            | "stair_climbing" | "stepper" | "other" | "generic" | "workout" | "training" => {
-                SportType::Workout
-            }
-
-            // Unmapped types fall through to Other variant
-            _ => SportType::Other(garmin_type.to_owned()),
-        }
+        SportTypeNormalizer::global().normalize(garmin_type)
     }
 
     /// Convert Garmin activity response to internal Activity model
@@ -264,6 +244,7 @@ impl GarminProvider {
             activity.duration.map_or(0, utils::conversions::f64_to_u64),
             oauth_providers::GARMIN,
         )
+        .utc_offset_seconds_opt(activity.start_time_offset_in_seconds)
         .distance_meters_opt(activity.distance)
         .elevation_gain_opt(activity.elevation_gain)
         .average_speed_opt(activity.average_speed)
@@ -355,6 +336,7 @@ impl FitnessProvider for GarminProvider {
             &credentials.client_secret,
             &refresh_token,
             "Garmin",
+            self.config.request_timeout(),
         )
         .await?;
 
@@ -411,15 +393,20 @@ impl FitnessProvider for GarminProvider {
             requested_limit, start_offset, params.before, params.after
         );
 
-        // Garmin API doesn't support before/after params directly, they're for client-side filtering
-        if requested_limit <= api_provider_limits::garmin::MAX_ACTIVITIES_PER_REQUEST {
+        // Garmin API doesn't support before/after params directly, so filter client-side
+        if params.has_time_range() {
             return self
-                .get_activities_single_page(requested_limit, start_offset)
+                .get_activities_in_range(params, requested_limit, start_offset)
                 .await;
         }
 
-        self.get_activities_multi_page(requested_limit, start_offset)
-            .await
+        if requested_limit <= api_provider_limits::garmin::MAX_ACTIVITIES_PER_REQUEST {
+            self.get_activities_single_page(requested_limit, start_offset)
+                .await
+        } else {
+            self.get_activities_multi_page(requested_limit, start_offset)
+                .await
+        }
     }
 
     async fn get_activities_cursor(
@@ -505,18 +492,20 @@ impl FitnessProvider for GarminProvider {
         };
 
         if let (Some(access_token), Some(revoke_url)) = (access_token_opt, revoke_url_opt) {
-            self.client
-                .post(&revoke_url)
-                .form(&[("token", access_token.as_str())])
-                .send()
-                .await
-                .inspect_err(|e| {
-                    warn!(
-                        error = ?e,
-                        "Failed to revoke Garmin access token - continuing with credential cleanup"
-                    );
-                })
-                .ok();
+            http_client::send_with_timeout(
+                self.client
+                    .post(&revoke_url)
+                    .form(&[("token", access_token.as_str())]),
+                self.config.request_timeout(),
+            )
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    error = ?e,
+                    "Failed to revoke Garmin access token - continuing with credential cleanup"
+                );
+            })
+            .ok();
             info!("Attempted to revoke Garmin access token");
         }
 
@@ -641,6 +630,55 @@ impl GarminProvider {
         ))
     }
 
+    /// Fetch activities within the `before`/`after` bounds
+    ///
+    /// The bounds are applied before `offset` and `limit`, so pages are scanned from the
+    /// start of the activity list until enough in-range activities are collected.
+    async fn get_activities_in_range(
+        &self,
+        params: &ActivityQueryParams,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<Activity>> {
+        let activities_per_page = api_provider_limits::garmin::MAX_ACTIVITIES_PER_REQUEST;
+        let wanted = offset.saturating_add(limit);
+        let mut in_range = Vec::with_capacity(wanted.min(activities_per_page));
+        let mut scanned = 0;
+
+        loop {
+            let endpoint = Self::build_activities_endpoint(scanned, activities_per_page);
+            let garmin_activities = self
+                .api_request::<Vec<GarminActivityResponse>>(&endpoint)
+                .await?;
+            let page_len = garmin_activities.len();
+            scanned += page_len;
+
+            let mut reached_older = false;
+            for garmin_activity in garmin_activities {
+                let activity = Self::convert_garmin_activity(garmin_activity)?;
+                // Garmin lists newest first, so nothing after this can be in range
+                reached_older |= params
+                    .after
+                    .is_some_and(|after| activity.start_date() < after);
+                if params.contains(activity.start_date()) {
+                    in_range.push(activity);
+                }
+            }
+
+            if in_range.len() >= wanted || page_len < activities_per_page || reached_older {
+                break;
+            }
+        }
+
+        info!(
+            scanned,
+            in_range = in_range.len(),
+            "Garmin activities filtered client-side by before/after bounds"
+        );
+
+        Ok(in_range.into_iter().skip(offset).take(limit).collect())
+    }
+
     async fn get_activities_multi_page(
         &self,
         total_limit: usize,
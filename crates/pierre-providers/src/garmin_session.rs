@@ -0,0 +1,171 @@
+// ABOUTME: Resumable Garmin Connect sessions that reuse stored tokens instead of logging in again
+// ABOUTME: Defines the OAuth1/OAuth2 session tokens, storage and login seams, and the login interval guard
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Garmin Sessions
+//!
+//! A full Garmin Connect login yields a long-lived OAuth1 token, which is then
+//! exchanged for a short-lived OAuth2 bearer used on API calls. Garmin rate
+//! limits logins far more strictly than API calls, so a session is resumed in
+//! this order:
+//!
+//! 1. Reuse the stored bearer while it is still valid.
+//! 2. Exchange the stored OAuth1 token for a new bearer.
+//! 3. Log in again, only if the OAuth1 token is missing or rejected and the last
+//!    login attempt was at least `RECOMMENDED_MIN_LOGIN_INTERVAL_SECS` ago.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::constants::api_provider_limits::garmin::RECOMMENDED_MIN_LOGIN_INTERVAL_SECS;
+use crate::constants::oauth_providers;
+use crate::errors::provider::ProviderError;
+use crate::errors::{AppResult, ErrorCode};
+
+/// Bearers expiring sooner than this are exchanged rather than reused
+const BEARER_EXPIRY_MARGIN_SECS: i64 = 300;
+
+/// Long-lived OAuth1 token issued by a full login
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GarminOAuth1Token {
+    /// OAuth1 token
+    pub oauth_token: String,
+    /// OAuth1 token secret
+    pub oauth_token_secret: String,
+}
+
+/// Short-lived OAuth2 bearer exchanged from the OAuth1 token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GarminOAuth2Token {
+    /// Bearer sent on API calls
+    pub access_token: String,
+    /// When the bearer expires
+    pub expires_at: DateTime<Utc>,
+}
+
+impl GarminOAuth2Token {
+    /// Whether the bearer can still be used at `now`
+    #[must_use]
+    pub fn is_usable_at(&self, now: DateTime<Utc>) -> bool {
+        now + Duration::seconds(BEARER_EXPIRY_MARGIN_SECS) < self.expires_at
+    }
+}
+
+/// Tokens persisted between provider instances for one user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GarminSession {
+    /// Token from the most recent successful login
+    pub oauth1: Option<GarminOAuth1Token>,
+    /// Most recent bearer, if the exchange succeeded
+    pub oauth2: Option<GarminOAuth2Token>,
+    /// When a full login was last attempted, successful or not
+    pub last_login_at: DateTime<Utc>,
+}
+
+/// How a session was made ready for API calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GarminSessionOutcome {
+    /// The stored bearer was still valid
+    Reused,
+    /// The stored OAuth1 token was exchanged for a new bearer
+    Exchanged,
+    /// A full login was performed
+    LoggedIn,
+}
+
+/// Persistent storage for a user's Garmin session
+#[async_trait]
+pub trait GarminSessionStore: Send + Sync {
+    /// Load the stored session, if any
+    async fn load_session(&self) -> AppResult<Option<GarminSession>>;
+
+    /// Replace the stored session
+    async fn save_session(&self, session: &GarminSession) -> AppResult<()>;
+}
+
+/// Garmin Connect authentication endpoints
+#[async_trait]
+pub trait GarminLoginClient: Send + Sync {
+    /// Perform a full login, which Garmin rate limits strictly
+    async fn login(&self) -> AppResult<GarminOAuth1Token>;
+
+    /// Exchange an OAuth1 token for a bearer
+    ///
+    /// Implementations return an `ErrorCode::AuthInvalid` error when Garmin
+    /// rejects the OAuth1 token, which is the only case that triggers a new login.
+    async fn exchange(&self, oauth1: &GarminOAuth1Token) -> AppResult<GarminOAuth2Token>;
+}
+
+/// Seconds until a login is allowed again after an attempt at `last_login_at`, or `None` if allowed now
+#[must_use]
+pub fn login_retry_after(last_login_at: DateTime<Utc>, now: DateTime<Utc>) -> Option<u64> {
+    let elapsed = u64::try_from((now - last_login_at).num_seconds()).unwrap_or(0);
+    (elapsed < RECOMMENDED_MIN_LOGIN_INTERVAL_SECS)
+        .then(|| RECOMMENDED_MIN_LOGIN_INTERVAL_SECS - elapsed)
+}
+
+/// Make a session ready for API calls, logging in only when the stored tokens cannot be used
+///
+/// Returns the bearer to use and how it was obtained. The login attempt time is
+/// saved before contacting Garmin, so failed logins also count towards the
+/// minimum login interval.
+///
+/// # Errors
+///
+/// Returns a rate limit error if a login is needed within the minimum login
+/// interval, or the error from the store or login client.
+pub async fn resume_session(
+    store: &dyn GarminSessionStore,
+    client: &dyn GarminLoginClient,
+) -> AppResult<(GarminOAuth2Token, GarminSessionOutcome)> {
+    let now = Utc::now();
+    let stored = store.load_session().await?;
+
+    if let Some(session) = &stored {
+        if let Some(bearer) = session.oauth2.as_ref().filter(|b| b.is_usable_at(now)) {
+            return Ok((bearer.clone(), GarminSessionOutcome::Reused));
+        }
+        if let Some(oauth1) = &session.oauth1 {
+            match client.exchange(oauth1).await {
+                Ok(bearer) => {
+                    let refreshed = GarminSession {
+                        oauth2: Some(bearer.clone()),
+                        ..session.clone()
+                    };
+                    store.save_session(&refreshed).await?;
+                    return Ok((bearer, GarminSessionOutcome::Exchanged));
+                }
+                Err(e) if e.code == ErrorCode::AuthInvalid => {
+                    info!("Stored Garmin OAuth1 token was rejected, a new login is needed");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        if let Some(retry_after_secs) = login_retry_after(session.last_login_at, now) {
+            return Err(ProviderError::RateLimitExceeded {
+                provider: oauth_providers::GARMIN.to_owned(),
+                retry_after_secs,
+                limit_type: "login".to_owned(),
+            }
+            .into());
+        }
+    }
+
+    let mut session = GarminSession {
+        oauth1: None,
+        oauth2: None,
+        last_login_at: now,
+    };
+    store.save_session(&session).await?;
+
+    let oauth1 = client.login().await?;
+    let bearer = client.exchange(&oauth1).await;
+    session.oauth1 = Some(oauth1);
+    session.oauth2 = bearer.as_ref().ok().cloned();
+    store.save_session(&session).await?;
+    Ok((bearer?, GarminSessionOutcome::LoggedIn))
+}
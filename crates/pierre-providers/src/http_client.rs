@@ -1,10 +1,11 @@
 // ABOUTME: Shared HTTP client with connection pooling for provider API calls
 // ABOUTME: Singleton pattern with configurable timeouts initialized at server startup
+// ABOUTME: Provider requests are sent through `send`, which can record/replay them for tests
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use reqwest::{Client, ClientBuilder};
+use reqwest::{Client, ClientBuilder, RequestBuilder, Response};
 use std::sync::OnceLock;
 use std::time::Duration;
 
@@ -46,3 +47,54 @@ pub fn shared_client() -> &'static Client {
             .unwrap_or_else(|_| Client::new())
     })
 }
+
+/// Failure to obtain a response for a provider request
+#[derive(Debug, thiserror::Error)]
+pub enum SendError {
+    /// The request could not be built or the server could not be reached
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    /// No recorded fixture matched the request, or the fixture could not be read or written
+    #[cfg(feature = "http-replay")]
+    #[error("HTTP replay failed: {0}")]
+    Replay(String),
+}
+
+/// Send a provider API request
+///
+/// All provider adapters send requests through this function rather than
+/// calling `RequestBuilder::send` directly. With the `http-replay` feature
+/// enabled and `PIERRE_HTTP_REPLAY_DIR` set, requests are recorded to or
+/// replayed from fixture files instead (see [`crate::http_replay`]).
+///
+/// # Errors
+///
+/// Returns an error if the request fails or, in replay mode, if no fixture
+/// was recorded for it
+pub async fn send(request: RequestBuilder) -> Result<Response, SendError> {
+    #[cfg(feature = "http-replay")]
+    if let Some(replay) = crate::http_replay::HttpReplay::from_env() {
+        return replay.send(request).await;
+    }
+
+    Ok(request.send().await?)
+}
+
+/// Send a provider API request with the provider's timeout override
+///
+/// The override replaces the shared client timeout for this request only;
+/// with `None` the shared client timeout applies, as with [`send`].
+///
+/// # Errors
+///
+/// Returns an error if the request fails or times out, or, in replay mode,
+/// if no fixture was recorded for it
+pub async fn send_with_timeout(
+    request: RequestBuilder,
+    timeout: Option<Duration>,
+) -> Result<Response, SendError> {
+    match timeout {
+        Some(timeout) => send(request.timeout(timeout)).await,
+        None => send(request).await,
+    }
+}
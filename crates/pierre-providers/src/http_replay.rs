@@ -0,0 +1,282 @@
+// ABOUTME: Record/replay layer for provider HTTP calls so adapter tests run offline and deterministically
+// ABOUTME: Responses are stored as JSON fixtures keyed by a signature of the request method, URL and body
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Record/replay of provider HTTP interactions
+//!
+//! Set `PIERRE_HTTP_REPLAY_DIR` to a fixture directory and every request sent
+//! through [`crate::http_client::send`] is served from it. With
+//! `PIERRE_HTTP_REPLAY_MODE=record` the request goes to the real API first and
+//! the response is written to the directory; the default `replay` mode never
+//! touches the network and fails when no fixture matches.
+//!
+//! Fixtures are keyed by the request method, URL and body. Headers are not part
+//! of the signature and request headers are never written, so bearer tokens and
+//! API keys stay out of the fixture files.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use reqwest::{Request, RequestBuilder, Response};
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tracing::{debug, info};
+
+use crate::http_client::SendError;
+
+/// Environment variable naming the fixture directory; unset disables replay
+pub const REPLAY_DIR_ENV: &str = "PIERRE_HTTP_REPLAY_DIR";
+
+/// Environment variable selecting `record` or `replay` (the default)
+pub const REPLAY_MODE_ENV: &str = "PIERRE_HTTP_REPLAY_MODE";
+
+/// Hex characters of the request signature kept in fixture file names
+const SIGNATURE_PREFIX_LEN: usize = 16;
+
+/// Longest URL path fragment kept in fixture file names
+const MAX_PATH_FRAGMENT_LEN: usize = 64;
+
+/// Replay layer configured from the environment, resolved once per process
+static FROM_ENV: OnceLock<Option<HttpReplay>> = OnceLock::new();
+
+/// Whether requests are sent to the real API or served from fixtures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Send requests to the real API and save each response as a fixture
+    Record,
+    /// Serve responses from fixtures without touching the network
+    Replay,
+}
+
+impl ReplayMode {
+    /// Parse the `PIERRE_HTTP_REPLAY_MODE` value, defaulting to replay
+    #[must_use]
+    pub fn from_str_or_default(value: &str) -> Self {
+        if value.eq_ignore_ascii_case("record") {
+            Self::Record
+        } else {
+            Self::Replay
+        }
+    }
+}
+
+/// A recorded request/response pair as stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    /// The request that produced the response
+    pub request: RecordedRequest,
+    /// The response returned by the provider
+    pub response: RecordedResponse,
+}
+
+/// The signature-relevant parts of a recorded request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method
+    pub method: String,
+    /// Full URL including the query string
+    pub url: String,
+    /// Request body, if any (form and JSON bodies are UTF-8)
+    pub body: Option<String>,
+}
+
+/// A recorded provider response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers in the order received
+    pub headers: Vec<(String, String)>,
+    /// Response body (non-UTF-8 bytes are replaced when recording)
+    pub body: String,
+}
+
+/// Records provider responses to, or replays them from, a fixture directory
+#[derive(Debug, Clone)]
+pub struct HttpReplay {
+    dir: PathBuf,
+    mode: ReplayMode,
+}
+
+impl HttpReplay {
+    /// Create a replay layer over `dir`
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>, mode: ReplayMode) -> Self {
+        Self {
+            dir: dir.into(),
+            mode,
+        }
+    }
+
+    /// Replay layer configured by `PIERRE_HTTP_REPLAY_DIR` and `PIERRE_HTTP_REPLAY_MODE`
+    ///
+    /// The environment is read on first use; returns `None` when no directory is set.
+    pub fn from_env() -> Option<&'static Self> {
+        FROM_ENV
+            .get_or_init(|| {
+                let dir = env::var(REPLAY_DIR_ENV)
+                    .ok()
+                    .filter(|dir| !dir.is_empty())?;
+                let mode = env::var(REPLAY_MODE_ENV).map_or(ReplayMode::Replay, |mode| {
+                    ReplayMode::from_str_or_default(&mode)
+                });
+                info!("Provider HTTP {mode:?} enabled with fixtures in {dir}");
+                Some(Self::new(dir, mode))
+            })
+            .as_ref()
+    }
+
+    /// Fixture directory
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Whether responses are recorded or replayed
+    #[must_use]
+    pub const fn mode(&self) -> ReplayMode {
+        self.mode
+    }
+
+    /// Send `request` according to the configured mode
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be built or sent, if a fixture
+    /// cannot be written while recording, or if no fixture matches while replaying
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, SendError> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        let recorded = RecordedRequest::from_request(&request);
+        let path = self.fixture_path(&recorded);
+
+        let response = match self.mode {
+            ReplayMode::Record => {
+                let response = client.execute(request).await?;
+                let fixture = Fixture {
+                    request: recorded,
+                    response: RecordedResponse::read(response).await?,
+                };
+                self.write_fixture(&path, &fixture).await?;
+                fixture.response
+            }
+            ReplayMode::Replay => Self::read_fixture(&path, &recorded).await?.response,
+        };
+
+        response.into_response()
+    }
+
+    /// Fixture file for a request: `{method}-{path}-{signature}.json`
+    #[must_use]
+    pub fn fixture_path(&self, request: &RecordedRequest) -> PathBuf {
+        let path_fragment: String = reqwest::Url::parse(&request.url)
+            .map(|url| format!("{}{}", url.host_str().unwrap_or_default(), url.path()))
+            .unwrap_or_default()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .take(MAX_PATH_FRAGMENT_LEN)
+            .collect();
+        let signature = request.signature();
+
+        self.dir.join(format!(
+            "{}-{}-{}.json",
+            request.method.to_ascii_lowercase(),
+            path_fragment.trim_matches('_'),
+            &signature[..SIGNATURE_PREFIX_LEN]
+        ))
+    }
+
+    async fn write_fixture(&self, path: &Path, fixture: &Fixture) -> Result<(), SendError> {
+        let json = serde_json::to_string_pretty(fixture)
+            .map_err(|e| SendError::Replay(format!("Failed to serialize fixture: {e}")))?;
+        fs::create_dir_all(&self.dir).await.map_err(|e| {
+            SendError::Replay(format!("Failed to create {}: {e}", self.dir.display()))
+        })?;
+        fs::write(path, json)
+            .await
+            .map_err(|e| SendError::Replay(format!("Failed to write {}: {e}", path.display())))?;
+        debug!(
+            "Recorded {} {} to {}",
+            fixture.request.method,
+            fixture.request.url,
+            path.display()
+        );
+        Ok(())
+    }
+
+    async fn read_fixture(path: &Path, request: &RecordedRequest) -> Result<Fixture, SendError> {
+        let json = fs::read_to_string(path).await.map_err(|e| {
+            SendError::Replay(format!(
+                "No fixture for {} {} at {}: {e}",
+                request.method,
+                request.url,
+                path.display()
+            ))
+        })?;
+        serde_json::from_str(&json)
+            .map_err(|e| SendError::Replay(format!("Invalid fixture {}: {e}", path.display())))
+    }
+}
+
+impl RecordedRequest {
+    fn from_request(request: &Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(|bytes| String::from_utf8_lossy(bytes).into_owned()),
+        }
+    }
+
+    /// Hex SHA-256 of the method, URL and body identifying this request
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let key = format!(
+            "{}\n{}\n{}",
+            self.method,
+            self.url,
+            self.body.as_deref().unwrap_or_default()
+        );
+        hex::encode(digest(&SHA256, key.as_bytes()))
+    }
+}
+
+impl RecordedResponse {
+    async fn read(response: Response) -> Result<Self, SendError> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_owned()))
+            })
+            .collect();
+        let body = response.bytes().await?;
+
+        Ok(Self {
+            status,
+            headers,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        })
+    }
+
+    fn into_response(self) -> Result<Response, SendError> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder
+            .body(self.body)
+            .map(Response::from)
+            .map_err(|e| SendError::Replay(format!("Invalid recorded response: {e}")))
+    }
+}
@@ -7,25 +7,46 @@
 //! Fitness data provider implementations and core abstractions.
 //!
 //! This crate provides the unified provider system for integrating with external
-//! fitness data sources (Strava, Garmin, Fitbit, WHOOP, COROS, Terra).
+//! fitness data sources (Strava, Garmin, Fitbit, WHOOP, COROS, Terra) and with
+//! activity files users upload directly.
 
 // Re-export pierre-core modules so moved files can keep `use crate::errors::*` etc.
 pub use pierre_core::constants;
 pub use pierre_core::errors;
+pub use pierre_core::metrics;
 pub use pierre_core::models;
 pub use pierre_core::pagination;
 
 // Core provider infrastructure
+/// Validation and deduplication of file-imported activities
+pub mod activity_import;
 /// Streaming activity iterator for memory-efficient paginated fetching
 pub mod activity_iterator;
 /// Circuit breaker pattern for provider resilience
 pub mod circuit_breaker;
 /// Core provider traits and interfaces
 pub mod core;
+/// GPX and TCX file parsing into normalized activities
+pub mod file_formats;
+/// Pseudo-provider serving activities imported from uploaded files
+pub mod file_provider;
 /// Shared HTTP client for provider API calls
 pub mod http_client;
+/// Record/replay of provider HTTP interactions for deterministic tests
+#[cfg(feature = "http-replay")]
+pub mod http_replay;
+/// Cross-provider athlete profile reconciliation
+pub mod profile_aggregation;
+/// Provider-advertised rate-limit budgets per tenant
+pub mod rate_limit_budget;
+/// Token-bucket retry budgets per tenant and provider
+pub mod retry_budget;
 /// Service Provider Interface for external providers
 pub mod spi;
+/// Per-tenant cap on concurrent outbound provider requests
+pub mod tenant_concurrency;
+/// Cross-provider activity timeline with per-metric provider priority
+pub mod unified_timeline;
 /// Provider utility functions (retry, type conversion)
 pub mod utils;
 
@@ -40,6 +61,9 @@ pub mod fitbit_provider;
 /// Garmin Connect provider implementation
 #[cfg(feature = "provider-garmin")]
 pub mod garmin_provider;
+/// Resumable Garmin Connect sessions backed by stored tokens
+#[cfg(feature = "provider-garmin")]
+pub mod garmin_session;
 /// Strava API provider implementation
 #[cfg(feature = "provider-strava")]
 pub mod strava_provider;
@@ -52,17 +76,33 @@ pub mod whoop_provider;
 
 // Re-export key types for convenience
 
+pub use activity_import::{
+    ActivityImportValidator, ImportRecord, ImportReport, ImportSource, ImportValidationConfig,
+    RejectionReason, ValidatedImport,
+};
 pub use activity_iterator::{
-    create_activity_stream, ActivityStream, ActivityStreamExt, StreamConfig, DEFAULT_PAGE_SIZE,
-    MAX_PAGE_SIZE, MIN_PAGE_SIZE,
+    create_activity_stream, create_windowed_activity_stream, ActivityStream, ActivityStreamExt,
+    ActivityWindow, ProviderPageLimits, StreamConfig, WindowStop, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE,
+    MIN_PAGE_SIZE,
 };
 pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 pub use core::{
     ActivityQueryParams, FitnessProvider as CoreFitnessProvider, OAuth2Credentials, ProviderConfig,
     ProviderFactory, TenantProvider,
 };
+pub use file_formats::{detect_format, parse_activity_file};
+pub use file_provider::{FileProvider, FileProviderFactory};
 pub use http_client::{initialize_shared_client, shared_client};
 pub use pierre_core::errors::provider::{ProviderError, ProviderResult};
+pub use profile_aggregation::{
+    ProfileAggregationConfig, ProfileConflict, ProfileField, ProviderProfile, ReconciliationRule,
+    SourcedValue, UnifiedAthleteProfile,
+};
+pub use rate_limit_budget::{
+    BudgetObserver, BudgetState, BudgetWindow, ProviderBudget, ProviderBudgetConfig,
+    ProviderBudgetTracker, TenantProviderBudget,
+};
+pub use retry_budget::{RetryBudget, RetryBudgetConfig};
 #[cfg(feature = "provider-coros")]
 pub use spi::CorosDescriptor;
 #[cfg(feature = "provider-fitbit")]
@@ -77,12 +117,20 @@ pub use spi::SyntheticDescriptor;
 pub use spi::SyntheticSleepDescriptor;
 #[cfg(feature = "provider-whoop")]
 pub use spi::WhoopDescriptor;
-pub use spi::{OAuthEndpoints, ProviderBundle, ProviderCapabilities, ProviderDescriptor};
+pub use spi::{
+    FileDescriptor, OAuthEndpoints, ProviderBundle, ProviderCapabilities, ProviderDescriptor,
+};
+pub use tenant_concurrency::{
+    TenantConcurrencyConfig, TenantConcurrencyLimiter, TenantInFlight, TenantRequestPermit,
+};
 #[cfg(feature = "provider-terra")]
 pub use terra::{
     TerraDataCache, TerraDescriptor, TerraProvider, TerraProviderFactory, TerraWebhookHandler,
 };
+pub use unified_timeline::{CanonicalActivity, MetricCategory, ProviderPriority, UnifiedTimeline};
 pub use utils::{
-    with_retry, with_retry_default, RetryBackoffConfig, ENV_RETRY_BASE_DELAY_MS,
-    ENV_RETRY_JITTER_FACTOR, ENV_RETRY_MAX_ATTEMPTS, ENV_RETRY_MAX_DELAY_MS,
+    deduplicate_activities, deduplicate_activities_with_config, with_budgeted_retry, with_retry,
+    with_retry_default, DeduplicationConfig, RetryBackoffConfig, ENV_DEDUP_PROVIDER_PRIORITY,
+    ENV_DEDUP_WINDOW_SECS, ENV_RETRY_BASE_DELAY_MS, ENV_RETRY_JITTER_FACTOR,
+    ENV_RETRY_MAX_ATTEMPTS, ENV_RETRY_MAX_DELAY_MS,
 };
@@ -0,0 +1,345 @@
+// ABOUTME: Reconciles athlete profile fields (weight, FTP, max HR) reported by multiple providers
+// ABOUTME: Applies configurable provider precedence or recency rules and flags conflicting values
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Provider-Agnostic Athlete Profile
+//!
+//! Users often connect several providers that each report their own weight, FTP
+//! and maximum heart rate. This module reconciles those per-provider snapshots
+//! into a single [`UnifiedAthleteProfile`], recording which provider each value
+//! came from and flagging fields where providers disagree beyond a tolerance.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use chrono::Utc;
+//! use pierre_mcp_server::providers::profile_aggregation::{
+//!     ProfileAggregationConfig, ProviderProfile, UnifiedAthleteProfile,
+//! };
+//!
+//! let profiles = vec![
+//!     ProviderProfile {
+//!         weight_kg: Some(72.0),
+//!         ..ProviderProfile::new("garmin", Utc::now())
+//!     },
+//!     ProviderProfile {
+//!         weight_kg: Some(75.0),
+//!         ..ProviderProfile::new("strava", Utc::now())
+//!     },
+//! ];
+//! let profile = UnifiedAthleteProfile::reconcile(&profiles, &ProfileAggregationConfig::default());
+//! assert!(profile.has_conflicts());
+//! ```
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::oauth_providers;
+use crate::models::UserPhysiologicalProfile;
+
+/// Default weight difference tolerated before flagging a conflict (kg)
+const DEFAULT_WEIGHT_TOLERANCE_KG: f64 = 1.0;
+
+/// Default FTP difference tolerated before flagging a conflict (watts)
+const DEFAULT_FTP_TOLERANCE_WATTS: f64 = 5.0;
+
+/// Default max heart rate difference tolerated before flagging a conflict (bpm)
+const DEFAULT_MAX_HR_TOLERANCE_BPM: f64 = 2.0;
+
+/// Profile fields reported by a single provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderProfile {
+    /// Provider that reported these values
+    pub provider: String,
+    /// When the provider last updated these values
+    pub updated_at: DateTime<Utc>,
+    /// Body weight in kilograms
+    pub weight_kg: Option<f64>,
+    /// Functional threshold power in watts
+    pub ftp_watts: Option<u32>,
+    /// Maximum heart rate in bpm
+    pub max_heart_rate: Option<u32>,
+}
+
+impl ProviderProfile {
+    /// Create an empty snapshot for a provider
+    #[must_use]
+    pub fn new(provider: impl Into<String>, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            provider: provider.into(),
+            updated_at,
+            weight_kg: None,
+            ftp_watts: None,
+            max_heart_rate: None,
+        }
+    }
+}
+
+/// Athlete profile fields that are reconciled across providers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileField {
+    /// Body weight
+    Weight,
+    /// Functional threshold power
+    Ftp,
+    /// Maximum heart rate
+    MaxHeartRate,
+}
+
+impl fmt::Display for ProfileField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Weight => write!(f, "weight"),
+            Self::Ftp => write!(f, "ftp"),
+            Self::MaxHeartRate => write!(f, "max_heart_rate"),
+        }
+    }
+}
+
+/// How to choose a value when several providers report one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationRule {
+    /// Highest-ranked provider wins; recency breaks ties between unranked providers
+    #[default]
+    Precedence,
+    /// Most recently updated value wins; precedence breaks ties
+    MostRecent,
+}
+
+/// Configuration for reconciling profile fields across providers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileAggregationConfig {
+    /// Providers in order of trust, highest first; unlisted providers rank last
+    #[serde(default = "default_provider_precedence")]
+    pub provider_precedence: Vec<String>,
+    /// Rule used to pick the winning value
+    #[serde(default)]
+    pub rule: ReconciliationRule,
+    /// Weight difference tolerated before flagging a conflict (kg)
+    #[serde(default = "default_weight_tolerance_kg")]
+    pub weight_tolerance_kg: f64,
+    /// FTP difference tolerated before flagging a conflict (watts)
+    #[serde(default = "default_ftp_tolerance_watts")]
+    pub ftp_tolerance_watts: f64,
+    /// Max heart rate difference tolerated before flagging a conflict (bpm)
+    #[serde(default = "default_max_hr_tolerance_bpm")]
+    pub max_hr_tolerance_bpm: f64,
+}
+
+/// Dedicated devices measure these fields directly, so they outrank aggregators
+pub(crate) fn default_provider_precedence() -> Vec<String> {
+    [
+        oauth_providers::GARMIN,
+        oauth_providers::COROS,
+        oauth_providers::WHOOP,
+        oauth_providers::FITBIT,
+        oauth_providers::STRAVA,
+        oauth_providers::TERRA,
+    ]
+    .into_iter()
+    .map(str::to_owned)
+    .collect()
+}
+
+const fn default_weight_tolerance_kg() -> f64 {
+    DEFAULT_WEIGHT_TOLERANCE_KG
+}
+
+const fn default_ftp_tolerance_watts() -> f64 {
+    DEFAULT_FTP_TOLERANCE_WATTS
+}
+
+const fn default_max_hr_tolerance_bpm() -> f64 {
+    DEFAULT_MAX_HR_TOLERANCE_BPM
+}
+
+impl Default for ProfileAggregationConfig {
+    fn default() -> Self {
+        Self {
+            provider_precedence: default_provider_precedence(),
+            rule: ReconciliationRule::default(),
+            weight_tolerance_kg: default_weight_tolerance_kg(),
+            ftp_tolerance_watts: default_ftp_tolerance_watts(),
+            max_hr_tolerance_bpm: default_max_hr_tolerance_bpm(),
+        }
+    }
+}
+
+impl ProfileAggregationConfig {
+    /// Position of a provider in the precedence list (lower is more trusted)
+    fn rank(&self, provider: &str) -> usize {
+        self.provider_precedence
+            .iter()
+            .position(|p| p.eq_ignore_ascii_case(provider))
+            .unwrap_or(self.provider_precedence.len())
+    }
+
+    const fn tolerance(&self, field: ProfileField) -> f64 {
+        match field {
+            ProfileField::Weight => self.weight_tolerance_kg,
+            ProfileField::Ftp => self.ftp_tolerance_watts,
+            ProfileField::MaxHeartRate => self.max_hr_tolerance_bpm,
+        }
+    }
+}
+
+/// A profile value together with the provider it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourcedValue<T> {
+    /// The value
+    pub value: T,
+    /// Provider that reported it
+    pub provider: String,
+    /// When the provider last updated it
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Providers disagreeing on a field by more than the configured tolerance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileConflict {
+    /// Field the providers disagree on
+    pub field: ProfileField,
+    /// Provider whose value was chosen
+    pub resolved_provider: String,
+    /// Every reported value, in the order they were considered
+    pub values: Vec<SourcedValue<f64>>,
+}
+
+/// Athlete profile reconciled across all connected providers
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnifiedAthleteProfile {
+    /// Reconciled body weight in kilograms
+    pub weight_kg: Option<SourcedValue<f64>>,
+    /// Reconciled functional threshold power in watts
+    pub ftp_watts: Option<SourcedValue<u32>>,
+    /// Reconciled maximum heart rate in bpm
+    pub max_heart_rate: Option<SourcedValue<u32>>,
+    /// Fields where providers disagree beyond tolerance
+    pub conflicts: Vec<ProfileConflict>,
+}
+
+impl UnifiedAthleteProfile {
+    /// Reconcile per-provider profile snapshots into a single profile
+    #[must_use]
+    pub fn reconcile(profiles: &[ProviderProfile], config: &ProfileAggregationConfig) -> Self {
+        let mut conflicts = Vec::new();
+
+        let weight_kg = reconcile_field(
+            profiles,
+            config,
+            ProfileField::Weight,
+            |p| p.weight_kg,
+            &mut conflicts,
+        );
+        let ftp_watts = reconcile_field(
+            profiles,
+            config,
+            ProfileField::Ftp,
+            |p| p.ftp_watts,
+            &mut conflicts,
+        );
+        let max_heart_rate = reconcile_field(
+            profiles,
+            config,
+            ProfileField::MaxHeartRate,
+            |p| p.max_heart_rate,
+            &mut conflicts,
+        );
+
+        Self {
+            weight_kg,
+            ftp_watts,
+            max_heart_rate,
+            conflicts,
+        }
+    }
+
+    /// Whether any field had conflicting values
+    #[must_use]
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+
+    /// Conflict recorded for a field, if any
+    #[must_use]
+    pub fn conflict(&self, field: ProfileField) -> Option<&ProfileConflict> {
+        self.conflicts.iter().find(|c| c.field == field)
+    }
+
+    /// Copy reconciled weight and max heart rate onto a physiological profile
+    ///
+    /// Fields without a reconciled value are left unchanged.
+    pub fn apply_to(&self, profile: &mut UserPhysiologicalProfile) {
+        if let Some(weight) = &self.weight_kg {
+            profile.weight = Some(weight.value);
+        }
+        if let Some(max_hr) = &self.max_heart_rate {
+            profile.max_hr = u16::try_from(max_hr.value).ok().or(profile.max_hr);
+        }
+    }
+}
+
+/// Pick the winning value for one field and record a conflict if providers disagree
+fn reconcile_field<T, F>(
+    profiles: &[ProviderProfile],
+    config: &ProfileAggregationConfig,
+    field: ProfileField,
+    extract: F,
+    conflicts: &mut Vec<ProfileConflict>,
+) -> Option<SourcedValue<T>>
+where
+    T: Copy + Into<f64>,
+    F: Fn(&ProviderProfile) -> Option<T>,
+{
+    let mut candidates: Vec<SourcedValue<T>> = profiles
+        .iter()
+        .filter_map(|p| {
+            extract(p).map(|value| SourcedValue {
+                value,
+                provider: p.provider.clone(),
+                updated_at: p.updated_at,
+            })
+        })
+        .collect();
+
+    match config.rule {
+        ReconciliationRule::Precedence => {
+            candidates.sort_by_key(|c| (config.rank(&c.provider), Reverse(c.updated_at)));
+        }
+        ReconciliationRule::MostRecent => {
+            candidates.sort_by_key(|c| (Reverse(c.updated_at), config.rank(&c.provider)));
+        }
+    }
+
+    let winner = candidates.first()?.clone();
+
+    let (min, max) = candidates
+        .iter()
+        .map(|c| c.value.into())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v: f64| {
+            (lo.min(v), hi.max(v))
+        });
+    if max - min > config.tolerance(field) {
+        conflicts.push(ProfileConflict {
+            field,
+            resolved_provider: winner.provider.clone(),
+            values: candidates
+                .into_iter()
+                .map(|c| SourcedValue {
+                    value: c.value.into(),
+                    provider: c.provider,
+                    updated_at: c.updated_at,
+                })
+                .collect(),
+        });
+    }
+
+    Some(winner)
+}
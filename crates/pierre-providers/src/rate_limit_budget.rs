@@ -0,0 +1,492 @@
+// ABOUTME: Tracks provider-advertised rate-limit budgets per tenant from API response headers
+// ABOUTME: Delays or rejects outbound requests before a provider's remaining budget runs out
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Provider rate-limit budgets
+//!
+//! Our own rate limits say nothing about how much of a provider's quota is
+//! left, but most providers advertise it on every response:
+//!
+//! - Strava: `X-RateLimit-Limit` / `X-RateLimit-Usage` as `15min,daily` pairs,
+//!   plus the stricter `X-ReadRateLimit-*` pair for read requests
+//! - Fitbit: `Fitbit-Rate-Limit-Limit` / `-Remaining` / `-Reset`
+//! - WHOOP, COROS: `X-RateLimit-Limit` / `-Remaining` / `-Reset`
+//!
+//! Adapters parse these headers into a [`ProviderBudget`] after each call.
+//! [`TenantProvider`](crate::core::TenantProvider) hands the latest one to the
+//! [`ProviderBudgetTracker`], which keeps it per tenant and provider. Before the
+//! next request goes out the tracker checks every window that has not reset
+//! yet: once a window is down to its reserve the request is rejected until the
+//! window resets, and once less than the throttle share is left requests are
+//! delayed, longer as the budget gets closer to the reserve.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, TimeZone, Timelike, Utc};
+use pierre_core::models::TenantId;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+use crate::errors::provider::ProviderError;
+use crate::tenant_concurrency::parse_env;
+
+/// Percent of a window left below which requests are delayed when `PIERRE_PROVIDER_BUDGET_THROTTLE_PERCENT` is unset
+pub const DEFAULT_BUDGET_THROTTLE_PERCENT: u32 = 10;
+
+/// Requests kept in reserve when `PIERRE_PROVIDER_BUDGET_RESERVE` is unset
+pub const DEFAULT_BUDGET_RESERVE: u32 = 2;
+
+/// Longest pre-emptive delay in milliseconds when `PIERRE_PROVIDER_BUDGET_MAX_DELAY_MS` is unset
+pub const DEFAULT_BUDGET_MAX_DELAY_MS: u64 = 2000;
+
+/// Seconds until reset assumed when a provider does not say
+const DEFAULT_RESET_SECS: i64 = 60;
+
+/// Thresholds for acting on provider-advertised budgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderBudgetConfig {
+    /// Percent of a window left below which requests are delayed
+    pub throttle_below_percent: u32,
+    /// Requests left in a window at which further requests are rejected
+    pub reserve: u32,
+    /// Delay applied just above the reserve, scaled down as more budget is left
+    pub max_delay: Duration,
+}
+
+impl Default for ProviderBudgetConfig {
+    fn default() -> Self {
+        Self {
+            throttle_below_percent: DEFAULT_BUDGET_THROTTLE_PERCENT,
+            reserve: DEFAULT_BUDGET_RESERVE,
+            max_delay: Duration::from_millis(DEFAULT_BUDGET_MAX_DELAY_MS),
+        }
+    }
+}
+
+impl ProviderBudgetConfig {
+    /// Load budget thresholds from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_PROVIDER_BUDGET_THROTTLE_PERCENT`: Percent left below which requests are delayed (default: 10)
+    /// - `PIERRE_PROVIDER_BUDGET_RESERVE`: Requests left at which requests are rejected (default: 2)
+    /// - `PIERRE_PROVIDER_BUDGET_MAX_DELAY_MS`: Longest pre-emptive delay (default: 2000)
+    ///
+    /// Invalid values fall back to the defaults.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self {
+            throttle_below_percent: parse_env(
+                "PIERRE_PROVIDER_BUDGET_THROTTLE_PERCENT",
+                DEFAULT_BUDGET_THROTTLE_PERCENT,
+            ),
+            reserve: parse_env("PIERRE_PROVIDER_BUDGET_RESERVE", DEFAULT_BUDGET_RESERVE),
+            max_delay: Duration::from_millis(parse_env(
+                "PIERRE_PROVIDER_BUDGET_MAX_DELAY_MS",
+                DEFAULT_BUDGET_MAX_DELAY_MS,
+            )),
+        }
+    }
+}
+
+/// One rate-limit window as advertised by the provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BudgetWindow {
+    /// Window name (`15min`, `daily`, `hourly`, ...)
+    pub window: String,
+    /// Requests allowed in the window
+    pub limit: u32,
+    /// Requests already used in the window
+    pub used: u32,
+    /// When the window starts over
+    pub resets_at: DateTime<Utc>,
+}
+
+impl BudgetWindow {
+    /// Requests left in the window
+    #[must_use]
+    pub const fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// Share of the window left, from 0.0 to 1.0
+    #[must_use]
+    pub fn fraction_remaining(&self) -> f64 {
+        if self.limit == 0 {
+            return 0.0;
+        }
+        f64::from(self.remaining()) / f64::from(self.limit)
+    }
+
+    /// Whether the window has started over since it was observed
+    #[must_use]
+    pub fn has_reset(&self, now: DateTime<Utc>) -> bool {
+        self.resets_at <= now
+    }
+}
+
+/// Provider budget observed on one API response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderBudget {
+    /// Every window the provider reported
+    pub windows: Vec<BudgetWindow>,
+    /// When the response carrying the headers arrived
+    pub observed_at: DateTime<Utc>,
+}
+
+impl ProviderBudget {
+    /// Windows that have not reset by `now`
+    pub fn live_windows(&self, now: DateTime<Utc>) -> impl Iterator<Item = &BudgetWindow> {
+        self.windows
+            .iter()
+            .filter(move |window| !window.has_reset(now))
+    }
+
+    /// Requests left in the most depleted window that has not reset by `now`
+    #[must_use]
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<u32> {
+        self.live_windows(now).map(BudgetWindow::remaining).min()
+    }
+}
+
+/// Parse Strava's `X-RateLimit-*` and `X-ReadRateLimit-*` headers
+///
+/// Each header holds a `15min,daily` pair. The short window resets on the
+/// quarter hour and the daily one at midnight UTC.
+#[must_use]
+pub fn parse_strava_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Option<ProviderBudget> {
+    let quarter_hour = next_quarter_hour(now);
+    let midnight = next_midnight(now);
+
+    let mut windows = Vec::new();
+    for (prefix, names) in [
+        ("X-RateLimit", ["15min", "daily"]),
+        ("X-ReadRateLimit", ["read_15min", "read_daily"]),
+    ] {
+        let limits = header_numbers(headers, &format!("{prefix}-Limit"));
+        let usage = header_numbers(headers, &format!("{prefix}-Usage"));
+        for ((name, resets_at), (limit, used)) in names
+            .into_iter()
+            .zip([quarter_hour, midnight])
+            .zip(limits.into_iter().zip(usage))
+        {
+            windows.push(BudgetWindow {
+                window: name.to_owned(),
+                limit,
+                used,
+                resets_at,
+            });
+        }
+    }
+
+    budget(windows, now)
+}
+
+/// Parse Fitbit's hourly `Fitbit-Rate-Limit-*` headers
+#[must_use]
+pub fn parse_fitbit_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Option<ProviderBudget> {
+    remaining_style_window(headers, "Fitbit-Rate-Limit", "hourly", now)
+        .and_then(|window| budget(vec![window], now))
+}
+
+/// Parse the common `X-RateLimit-Limit` / `-Remaining` / `-Reset` headers
+///
+/// The reset is read as seconds until the window starts over. Limits in the
+/// `100, 100;window=60` form use their first value.
+#[must_use]
+pub fn parse_standard_headers(headers: &HeaderMap, now: DateTime<Utc>) -> Option<ProviderBudget> {
+    remaining_style_window(headers, "X-RateLimit", "default", now)
+        .and_then(|window| budget(vec![window], now))
+}
+
+/// Build a window from `{prefix}-Limit`, `{prefix}-Remaining` and `{prefix}-Reset`
+fn remaining_style_window(
+    headers: &HeaderMap,
+    prefix: &str,
+    name: &str,
+    now: DateTime<Utc>,
+) -> Option<BudgetWindow> {
+    let limit = *header_numbers(headers, &format!("{prefix}-Limit")).first()?;
+    let remaining = *header_numbers(headers, &format!("{prefix}-Remaining")).first()?;
+    let reset_secs = header_numbers(headers, &format!("{prefix}-Reset"))
+        .first()
+        .map_or(DEFAULT_RESET_SECS, |secs| i64::from(*secs));
+
+    Some(BudgetWindow {
+        window: name.to_owned(),
+        limit,
+        used: limit.saturating_sub(remaining),
+        resets_at: now + ChronoDuration::seconds(reset_secs),
+    })
+}
+
+fn budget(windows: Vec<BudgetWindow>, now: DateTime<Utc>) -> Option<ProviderBudget> {
+    (!windows.is_empty()).then_some(ProviderBudget {
+        windows,
+        observed_at: now,
+    })
+}
+
+/// Leading integer of each comma-separated value (`"600,30000"`, `"100;window=60"`)
+fn header_numbers(headers: &HeaderMap, name: &str) -> Vec<u32> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map_while(|part| part.split(';').next()?.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn next_quarter_hour(now: DateTime<Utc>) -> DateTime<Utc> {
+    let into_quarter = i64::from(now.minute() % 15) * 60 + i64::from(now.second());
+    now.with_nanosecond(0).unwrap_or(now) + ChronoDuration::seconds(15 * 60 - into_quarter)
+}
+
+fn next_midnight(now: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(now.year(), now.month(), now.day(), 0, 0, 0)
+        .single()
+        .map_or(now, |midnight| midnight + ChronoDuration::days(1))
+}
+
+/// Latest budget seen by one provider instance
+///
+/// Adapters record every response's headers here; the tenant wrapper reads
+/// it back after each call through `FitnessProvider::rate_limit_budget`.
+#[derive(Debug, Default)]
+pub struct BudgetObserver {
+    latest: Mutex<Option<ProviderBudget>>,
+}
+
+impl BudgetObserver {
+    /// Keep `budget` as the latest one, ignoring responses without budget headers
+    pub fn observe(&self, budget: Option<ProviderBudget>) {
+        if let Some(budget) = budget {
+            *self.latest.lock().unwrap_or_else(PoisonError::into_inner) = Some(budget);
+        }
+    }
+
+    /// Latest observed budget, if any response carried one
+    #[must_use]
+    pub fn latest(&self) -> Option<ProviderBudget> {
+        self.latest
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// What to do with the next request given the remaining budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetDecision {
+    /// Enough budget left
+    Proceed,
+    /// Budget is running low; wait this long first
+    Delay(Duration),
+    /// Budget is down to the reserve until a window resets
+    Reject {
+        /// Seconds until the exhausted window resets
+        retry_after_secs: u64,
+    },
+}
+
+/// How much of a tenant's budget with a provider is left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetState {
+    /// Requests go out without delay
+    Available,
+    /// Requests are delayed
+    Throttled,
+    /// Requests are rejected until a window resets
+    Exhausted,
+}
+
+/// Latest provider budget for one tenant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenantProviderBudget {
+    /// Tenant the budget belongs to
+    pub tenant_id: TenantId,
+    /// Provider that advertised the budget
+    pub provider: String,
+    /// Requests left in the most depleted window, `None` once every window reset
+    pub remaining: Option<u32>,
+    /// Whether requests are currently delayed or rejected
+    pub state: BudgetState,
+    /// Windows as last reported by the provider
+    pub windows: Vec<BudgetWindow>,
+    /// When the provider last reported them
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Keeps provider-advertised budgets per tenant and gates requests on them
+pub struct ProviderBudgetTracker {
+    config: ProviderBudgetConfig,
+    budgets: Mutex<HashMap<(TenantId, String), ProviderBudget>>,
+}
+
+impl ProviderBudgetTracker {
+    /// Create a tracker with the given thresholds
+    #[must_use]
+    pub fn new(config: ProviderBudgetConfig) -> Self {
+        Self {
+            config,
+            budgets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Thresholds this tracker applies
+    #[must_use]
+    pub const fn config(&self) -> &ProviderBudgetConfig {
+        &self.config
+    }
+
+    /// Store the budget a provider just reported for `tenant_id`
+    ///
+    /// Older observations never replace newer ones, so concurrent requests
+    /// finishing out of order keep the freshest budget.
+    pub fn record(&self, tenant_id: TenantId, provider: &str, budget: ProviderBudget) {
+        debug!(
+            tenant_id = %tenant_id,
+            provider,
+            remaining = ?budget.remaining(budget.observed_at),
+            "Provider rate-limit budget updated"
+        );
+        let mut budgets = self.budgets.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = (tenant_id, provider.to_owned());
+        if budgets
+            .get(&key)
+            .is_none_or(|latest| budget.observed_at >= latest.observed_at)
+        {
+            budgets.insert(key, budget);
+        }
+    }
+
+    /// Latest budget `provider` reported for `tenant_id`
+    #[must_use]
+    pub fn budget(&self, tenant_id: TenantId, provider: &str) -> Option<ProviderBudget> {
+        self.budgets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(tenant_id, provider.to_owned()))
+            .cloned()
+    }
+
+    /// Decide whether a request to `provider` for `tenant_id` may go out at `now`
+    #[must_use]
+    pub fn decide(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        now: DateTime<Utc>,
+    ) -> BudgetDecision {
+        self.budget(tenant_id, provider)
+            .map_or(BudgetDecision::Proceed, |budget| {
+                self.decide_for(&budget, now)
+            })
+    }
+
+    fn decide_for(&self, budget: &ProviderBudget, now: DateTime<Utc>) -> BudgetDecision {
+        let exhausted_until = budget
+            .live_windows(now)
+            .filter(|window| window.remaining() <= self.config.reserve)
+            .map(|window| window.resets_at)
+            .max();
+        if let Some(resets_at) = exhausted_until {
+            let retry_after_secs = u64::try_from((resets_at - now).num_seconds())
+                .unwrap_or(0)
+                .max(1);
+            return BudgetDecision::Reject { retry_after_secs };
+        }
+
+        let throttle_below = f64::from(self.config.throttle_below_percent) / 100.0;
+        let tightest = budget
+            .live_windows(now)
+            .map(BudgetWindow::fraction_remaining)
+            .min_by(f64::total_cmp);
+        match tightest {
+            Some(fraction) if fraction < throttle_below => BudgetDecision::Delay(
+                self.config
+                    .max_delay
+                    .mul_f64(1.0 - fraction / throttle_below),
+            ),
+            _ => BudgetDecision::Proceed,
+        }
+    }
+
+    /// Wait until a request to `provider` for `tenant_id` fits the remaining budget
+    ///
+    /// Returns immediately with budget to spare and after a short delay when
+    /// the budget is running low.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RateLimitExceeded` with the time until the window resets when
+    /// the budget is down to the reserve
+    pub async fn wait_for_budget(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> Result<(), ProviderError> {
+        match self.decide(tenant_id, provider, Utc::now()) {
+            BudgetDecision::Proceed => Ok(()),
+            BudgetDecision::Delay(delay) => {
+                debug!(tenant_id = %tenant_id, provider, ?delay, "Provider budget low, delaying request");
+                sleep(delay).await;
+                Ok(())
+            }
+            BudgetDecision::Reject { retry_after_secs } => {
+                warn!(tenant_id = %tenant_id, provider, retry_after_secs, "Provider budget exhausted, rejecting request");
+                Err(ProviderError::RateLimitExceeded {
+                    provider: provider.to_owned(),
+                    retry_after_secs,
+                    limit_type: "provider budget".to_owned(),
+                })
+            }
+        }
+    }
+
+    /// Latest budget of every tenant and provider seen so far
+    #[must_use]
+    pub fn budgets(&self) -> Vec<TenantProviderBudget> {
+        let now = Utc::now();
+        let budgets = self.budgets.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut entries: Vec<TenantProviderBudget> = budgets
+            .iter()
+            .map(|((tenant_id, provider), budget)| TenantProviderBudget {
+                tenant_id: *tenant_id,
+                provider: provider.clone(),
+                remaining: budget.remaining(now),
+                state: match self.decide_for(budget, now) {
+                    BudgetDecision::Proceed => BudgetState::Available,
+                    BudgetDecision::Delay(_) => BudgetState::Throttled,
+                    BudgetDecision::Reject { .. } => BudgetState::Exhausted,
+                },
+                windows: budget.windows.clone(),
+                observed_at: budget.observed_at,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.tenant_id
+                .0
+                .cmp(&b.tenant_id.0)
+                .then_with(|| a.provider.cmp(&b.provider))
+        });
+        entries
+    }
+}
+
+impl Default for ProviderBudgetTracker {
+    fn default() -> Self {
+        Self::new(ProviderBudgetConfig::default())
+    }
+}
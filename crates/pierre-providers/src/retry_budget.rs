@@ -0,0 +1,185 @@
+// ABOUTME: Token-bucket retry budget shared by every retry loop for one tenant and provider
+// ABOUTME: Stops retry storms during provider outages by failing fast once the budget is spent
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Retry budgets
+//!
+//! Backoff limits how often one call retries, but during a provider outage
+//! every in-flight call retries at the same time and multiplies the load on a
+//! provider that is already failing. A [`RetryBudget`] keeps one token bucket
+//! per tenant and provider. Each retry (never the first attempt) takes a
+//! token; the bucket refills at a steady rate up to its capacity. Once a
+//! bucket is empty, retry loops drawing from it return the last error
+//! instead of retrying until tokens trickle back in.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+
+use pierre_core::models::TenantId;
+use tracing::debug;
+
+use crate::metrics::MetricsRegistry;
+use crate::tenant_concurrency::parse_env;
+
+/// Retries a bucket holds when `PIERRE_RETRY_BUDGET_CAPACITY` is unset
+pub const DEFAULT_RETRY_BUDGET_CAPACITY: u32 = 20;
+
+/// Retries added back per second when `PIERRE_RETRY_BUDGET_REFILL_PER_SEC` is unset
+pub const DEFAULT_RETRY_BUDGET_REFILL_PER_SEC: f64 = 1.0;
+
+/// Size and refill rate of each tenant and provider retry budget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryBudgetConfig {
+    /// Retries a full bucket allows in a burst
+    pub capacity: u32,
+    /// Retries added back to the bucket per second
+    pub refill_per_sec: f64,
+}
+
+impl Default for RetryBudgetConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_RETRY_BUDGET_CAPACITY,
+            refill_per_sec: DEFAULT_RETRY_BUDGET_REFILL_PER_SEC,
+        }
+    }
+}
+
+impl RetryBudgetConfig {
+    /// Create a budget config with the given size and refill rate
+    #[must_use]
+    pub const fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        Self {
+            capacity,
+            refill_per_sec,
+        }
+    }
+
+    /// Load the retry budget from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_RETRY_BUDGET_CAPACITY`: Retries per tenant and provider in a burst (default: 20)
+    /// - `PIERRE_RETRY_BUDGET_REFILL_PER_SEC`: Retries added back per second (default: 1.0)
+    ///
+    /// Invalid values fall back to the defaults; a negative refill rate is treated as zero.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self {
+            capacity: parse_env(
+                "PIERRE_RETRY_BUDGET_CAPACITY",
+                DEFAULT_RETRY_BUDGET_CAPACITY,
+            ),
+            refill_per_sec: parse_env(
+                "PIERRE_RETRY_BUDGET_REFILL_PER_SEC",
+                DEFAULT_RETRY_BUDGET_REFILL_PER_SEC,
+            )
+            .max(0.0),
+        }
+    }
+}
+
+/// Tokens left in one bucket as of the last refill
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    /// Add the tokens earned since the last refill, up to `config.capacity`
+    fn refill(&mut self, config: &RetryBudgetConfig, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = elapsed
+            .as_secs_f64()
+            .mul_add(config.refill_per_sec, self.tokens)
+            .min(f64::from(config.capacity));
+        self.refilled_at = now;
+    }
+
+    /// Whole tokens available
+    fn whole_tokens(&self) -> u32 {
+        self.tokens.floor() as u32
+    }
+}
+
+/// Token buckets limiting retries per tenant and provider
+pub struct RetryBudget {
+    config: RetryBudgetConfig,
+    buckets: Mutex<HashMap<(TenantId, String), TokenBucket>>,
+}
+
+impl RetryBudget {
+    /// Create a retry budget where every bucket starts full
+    #[must_use]
+    pub fn new(config: RetryBudgetConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Size and refill rate of every bucket
+    #[must_use]
+    pub const fn config(&self) -> &RetryBudgetConfig {
+        &self.config
+    }
+
+    /// Take one retry token for `provider` in `tenant_id`'s budget
+    ///
+    /// Returns `false` without taking anything when the bucket is empty. The
+    /// tokens left are published as the `pierre_retry_budget_remaining` gauge,
+    /// and an empty bucket counts towards `pierre_retry_budget_exhausted_total`.
+    #[must_use]
+    pub fn try_acquire(&self, tenant_id: TenantId, provider: &str) -> bool {
+        let (acquired, remaining) = self.with_bucket(tenant_id, provider, |bucket| {
+            let acquired = bucket.tokens >= 1.0;
+            if acquired {
+                bucket.tokens -= 1.0;
+            }
+            acquired
+        });
+
+        let metrics = MetricsRegistry::global();
+        metrics.record_retry_budget(Some(tenant_id.0), provider, f64::from(remaining));
+        if !acquired {
+            debug!(tenant_id = %tenant_id, provider, "Retry budget exhausted");
+            metrics.record_retry_budget_exhausted(Some(tenant_id.0), provider);
+        }
+        acquired
+    }
+
+    /// Whole retry tokens left for `provider` in `tenant_id`'s budget
+    #[must_use]
+    pub fn remaining(&self, tenant_id: TenantId, provider: &str) -> u32 {
+        self.with_bucket(tenant_id, provider, |_| ()).1
+    }
+
+    /// Refill the bucket for `tenant_id` and `provider`, creating it full on first use, then run `f` on it
+    fn with_bucket<T>(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        f: impl FnOnce(&mut TokenBucket) -> T,
+    ) -> (T, u32) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets
+            .entry((tenant_id, provider.to_owned()))
+            .or_insert_with(|| TokenBucket {
+                tokens: f64::from(self.config.capacity),
+                refilled_at: now,
+            });
+        bucket.refill(&self.config, now);
+        let result = f(bucket);
+        (result, bucket.whole_tokens())
+    }
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        Self::new(RetryBudgetConfig::default())
+    }
+}
@@ -65,7 +65,8 @@
 //! }
 //! ```
 
-use super::core::{FitnessProvider, ProviderConfig};
+use super::core::{FitnessProvider, ProviderConfig, ProviderValidationMode};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// OAuth endpoint configuration for providers requiring authentication
@@ -97,7 +98,7 @@ bitflags::bitflags! {
     /// Indicates which features a provider supports. Used by the system to
     /// route requests to appropriate providers and generate accurate tool descriptions.
     #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
-    pub struct ProviderCapabilities: u8 {
+    pub struct ProviderCapabilities: u16 {
         /// Provider requires OAuth authentication
         const OAUTH = 0b0000_0001;
         /// Provider supports activity/workout data
@@ -108,10 +109,31 @@ bitflags::bitflags! {
         const RECOVERY_METRICS = 0b0000_1000;
         /// Provider supports health metrics (weight, HRV, etc.)
         const HEALTH_METRICS = 0b0001_0000;
+        /// Provider exposes the athlete's gear (shoes, bikes)
+        const GEAR = 0b0010_0000;
+        /// Provider exposes segments and segment efforts
+        const SEGMENTS = 0b0100_0000;
+        /// Provider serves heart rate series separately from activity streams
+        const HEART_RATE_SERIES = 0b1000_0000;
+        /// Provider pushes data through webhooks instead of being polled
+        const WEBHOOKS = 0b0001_0000_0000;
     }
 }
 
 impl ProviderCapabilities {
+    /// Capability names as reported to clients, in matrix order
+    pub const NAMED: [(&'static str, Self); 9] = [
+        ("oauth", Self::OAUTH),
+        ("activities", Self::ACTIVITIES),
+        ("sleep", Self::SLEEP_TRACKING),
+        ("recovery", Self::RECOVERY_METRICS),
+        ("health", Self::HEALTH_METRICS),
+        ("gear", Self::GEAR),
+        ("segments", Self::SEGMENTS),
+        ("heart_rate_series", Self::HEART_RATE_SERIES),
+        ("webhooks", Self::WEBHOOKS),
+    ];
+
     /// Create capabilities for an activity-only provider (like Strava)
     #[must_use]
     pub const fn activity_only() -> Self {
@@ -166,6 +188,39 @@ impl ProviderCapabilities {
     pub const fn supports_health(&self) -> bool {
         self.contains(Self::HEALTH_METRICS)
     }
+
+    /// Check if gear is supported
+    #[must_use]
+    pub const fn supports_gear(&self) -> bool {
+        self.contains(Self::GEAR)
+    }
+
+    /// Check if segments are supported
+    #[must_use]
+    pub const fn supports_segments(&self) -> bool {
+        self.contains(Self::SEGMENTS)
+    }
+
+    /// Check if separate heart rate series are supported
+    #[must_use]
+    pub const fn supports_heart_rate_series(&self) -> bool {
+        self.contains(Self::HEART_RATE_SERIES)
+    }
+
+    /// Check if data is delivered through webhooks
+    #[must_use]
+    pub const fn supports_webhooks(&self) -> bool {
+        self.contains(Self::WEBHOOKS)
+    }
+
+    /// Every known capability mapped to whether it is supported
+    #[must_use]
+    pub fn matrix(&self) -> BTreeMap<&'static str, bool> {
+        Self::NAMED
+            .iter()
+            .map(|(name, flag)| (*name, self.contains(*flag)))
+            .collect()
+    }
 }
 
 /// Describes a provider's identity and capabilities
@@ -258,6 +313,8 @@ pub trait ProviderDescriptor: Send + Sync {
                 .iter()
                 .map(|s| (*s).to_owned())
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         }
     }
 }
@@ -335,6 +392,8 @@ impl ProviderDescriptor for StravaDescriptor {
 
     fn capabilities(&self) -> ProviderCapabilities {
         ProviderCapabilities::activity_only()
+            .union(ProviderCapabilities::GEAR)
+            .union(ProviderCapabilities::SEGMENTS)
     }
 
     fn oauth_endpoints(&self) -> Option<OAuthEndpoints> {
@@ -426,7 +485,8 @@ impl ProviderDescriptor for FitbitDescriptor {
     }
 
     fn capabilities(&self) -> ProviderCapabilities {
-        ProviderCapabilities::full_health()
+        // Intraday heart rate is a separate endpoint from activity logs
+        ProviderCapabilities::full_health().union(ProviderCapabilities::HEART_RATE_SERIES)
     }
 
     fn oauth_endpoints(&self) -> Option<OAuthEndpoints> {
@@ -454,6 +514,39 @@ impl ProviderDescriptor for FitbitDescriptor {
     }
 }
 
+/// File import pseudo-provider descriptor (uploaded GPX/TCX activities)
+pub struct FileDescriptor;
+
+impl ProviderDescriptor for FileDescriptor {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "File Import"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::ACTIVITIES
+    }
+
+    fn oauth_endpoints(&self) -> Option<OAuthEndpoints> {
+        None // Imported files don't need OAuth
+    }
+
+    fn oauth_params(&self) -> Option<OAuthParams> {
+        None // Imported files don't need OAuth
+    }
+
+    fn api_base_url(&self) -> &'static str {
+        "http://localhost/file/api"
+    }
+
+    fn default_scopes(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
 /// Synthetic provider descriptor (for development/testing)
 #[cfg(feature = "provider-synthetic")]
 pub struct SyntheticDescriptor;
@@ -8,19 +8,29 @@
 // - String ownership for API responses and error handling
 
 use super::circuit_breaker::CircuitBreaker;
-use super::core::{ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig};
-use super::errors::provider::ProviderError;
+use super::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
+use super::errors::provider::{ProviderError, ProviderResult};
+use super::utils::conversions;
 use crate::constants::oauth::STRAVA_DEFAULT_SCOPES;
 use crate::constants::{api_provider_limits, oauth_providers};
 use crate::errors::{AppError, AppResult};
-use crate::http_client::shared_client;
-use crate::models::{Activity, ActivityBuilder, Athlete, PersonalRecord, SportType, Stats};
+use crate::http_client::{self, shared_client};
+use crate::metrics::MetricsRegistry;
+use crate::models::{
+    Activity, ActivityBuilder, ActivitySplit, Athlete, AthleteZones, Gear, GearType,
+    PersonalRecord, Segment, SegmentEffort, SplitUnit, SportType, SportTypeNormalizer, Stats,
+    ZoneRange,
+};
 use crate::pagination::{Cursor, CursorPage, PaginationDirection, PaginationParams};
+use crate::rate_limit_budget::{parse_strava_headers, BudgetObserver, ProviderBudget};
 use async_trait::async_trait;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use std::fmt::Write;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
@@ -48,6 +58,39 @@ struct StravaAthleteResponse {
     profile_medium: Option<String>,
 }
 
+/// Gear lists embedded in the GET /athlete response
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StravaAthleteGearResponse {
+    /// Summary entries for the athlete's shoes
+    #[serde(default)]
+    pub shoes: Vec<StravaGearResponse>,
+    /// Summary entries for the athlete's bikes
+    #[serde(default)]
+    pub bikes: Vec<StravaGearResponse>,
+}
+
+/// Strava gear from GET /gear/{id} (detailed) or the /athlete gear lists (summary)
+#[derive(Debug, Clone, Deserialize)]
+pub struct StravaGearResponse {
+    /// Gear identifier (`g...` for shoes, `b...` for bikes)
+    pub id: String,
+    /// Display name chosen by the athlete
+    pub name: Option<String>,
+    /// Whether this is the default gear for its type
+    #[serde(default)]
+    pub primary: bool,
+    /// Total distance logged with this gear (meters)
+    #[serde(default)]
+    pub distance: f64,
+    /// Whether the gear has been retired
+    #[serde(default)]
+    pub retired: bool,
+    /// Manufacturer name (detailed response only)
+    pub brand_name: Option<String>,
+    /// Model name (detailed response only)
+    pub model_name: Option<String>,
+}
+
 /// Strava map data in API responses
 #[derive(Debug, Clone, Deserialize)]
 pub struct StravaMap {
@@ -63,8 +106,13 @@ pub struct StravaActivityResponse {
     #[serde(rename = "type")]
     activity_type: String,
     start_date: String,
+    /// Local time zone, formatted like "(GMT+09:00) Asia/Tokyo"
+    timezone: Option<String>,
+    /// Offset of the local start time from UTC in seconds
+    utc_offset: Option<f64>,
     distance: Option<f32>,
     elapsed_time: Option<u32>,
+    moving_time: Option<u32>,
     total_elevation_gain: Option<f32>,
     average_speed: Option<f32>,
     max_speed: Option<f32>,
@@ -100,6 +148,8 @@ pub struct StravaSplit {
     pub split: Option<u32>,
     /// Average speed during the split (meters/second)
     pub average_speed: Option<f32>,
+    /// Average heart rate during the split (bpm)
+    pub average_heartrate: Option<f32>,
     /// Pace zone classification (0-5)
     pub pace_zone: Option<u32>,
 }
@@ -152,6 +202,74 @@ pub struct StravaSegmentEffort {
     pub average_cadence: Option<f32>,
     /// Average power output during the segment (watts)
     pub average_watts: Option<f32>,
+    /// When the effort started (RFC 3339)
+    pub start_date: Option<String>,
+    /// Rank among the athlete's efforts on the segment (1-3, absent otherwise)
+    pub pr_rank: Option<u32>,
+    /// Rank on the overall leaderboard (1-10, absent otherwise)
+    pub kom_rank: Option<u32>,
+    /// Summary of the segment the effort was recorded on
+    pub segment: Option<StravaSegmentResponse>,
+}
+
+/// Strava segment from GET /segments/{id} (detailed) or nested in an effort (summary)
+#[derive(Debug, Clone, Deserialize)]
+pub struct StravaSegmentResponse {
+    /// Segment identifier
+    pub id: u64,
+    /// Segment name
+    pub name: Option<String>,
+    /// Sport the segment is defined for ("Ride", "Run", ...)
+    pub activity_type: Option<String>,
+    /// Segment length (meters)
+    pub distance: Option<f64>,
+    /// Average grade (percentage)
+    pub average_grade: Option<f32>,
+    /// Steepest grade (percentage)
+    pub maximum_grade: Option<f32>,
+    /// Highest elevation (meters)
+    pub elevation_high: Option<f64>,
+    /// Lowest elevation (meters)
+    pub elevation_low: Option<f64>,
+    /// Total elevation gain (meters, detailed response only)
+    pub total_elevation_gain: Option<f64>,
+    /// Climb category (0 = uncategorized, 5 = HC)
+    pub climb_category: Option<u32>,
+    /// City the segment starts in
+    pub city: Option<String>,
+    /// State or region the segment starts in
+    pub state: Option<String>,
+    /// Country the segment starts in
+    pub country: Option<String>,
+    /// Efforts by all athletes (detailed response only)
+    pub effort_count: Option<u64>,
+    /// Distinct athletes (detailed response only)
+    pub athlete_count: Option<u64>,
+    /// Course record times (detailed response only)
+    pub xoms: Option<StravaSegmentXoms>,
+    /// Authenticated athlete's best effort (detailed response only)
+    #[serde(alias = "athlete_pr_effort")]
+    pub athlete_segment_stats: Option<StravaAthleteSegmentStats>,
+}
+
+/// Course record times as displayed by Strava (e.g. "6:12")
+#[derive(Debug, Clone, Deserialize)]
+pub struct StravaSegmentXoms {
+    /// Fastest men's time
+    pub kom: Option<String>,
+    /// Fastest women's time
+    pub qom: Option<String>,
+}
+
+/// Authenticated athlete's history on a segment
+#[derive(Debug, Clone, Deserialize)]
+pub struct StravaAthleteSegmentStats {
+    /// Best elapsed time (seconds)
+    pub pr_elapsed_time: Option<u64>,
+    /// Date of the best effort
+    pub pr_date: Option<NaiveDate>,
+    /// Efforts the athlete has recorded
+    pub effort_count: Option<u32>,
 }
 
 /// Detailed activity response from GET /activities/{id} endpoint
@@ -187,14 +305,45 @@ pub struct DetailedActivityResponse {
     pub device_name: Option<String>,
 
     // Complex nested data
-    /// Metric splits (1km or 1mi intervals)
+    /// Metric splits (1km intervals)
     pub splits_metric: Option<Vec<StravaSplit>>,
+    /// Imperial splits (1mi intervals)
+    pub splits_standard: Option<Vec<StravaSplit>>,
     /// Lap data from the activity
     pub laps: Option<Vec<StravaLap>>,
     /// Segment efforts completed during the activity
     pub segment_efforts: Option<Vec<StravaSegmentEffort>>,
 }
 
+/// Strava API response from GET /athlete/zones
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StravaZonesResponse {
+    /// Heart rate zones, present for every athlete
+    pub heart_rate: Option<StravaZoneSet>,
+    /// Power zones, present only when the athlete has set an FTP
+    pub power: Option<StravaZoneSet>,
+}
+
+/// One kind of Strava zones
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StravaZoneSet {
+    /// Whether the athlete edited the zones rather than using Strava's defaults
+    #[serde(default)]
+    pub custom_zones: bool,
+    /// Zones from easiest to hardest
+    #[serde(default)]
+    pub zones: Vec<StravaZoneRange>,
+}
+
+/// Strava zone bounds; the top zone reports `max` as -1
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct StravaZoneRange {
+    /// Lower bound in BPM or watts
+    pub min: i64,
+    /// Upper bound in BPM or watts, -1 when open-ended
+    pub max: i64,
+}
+
 /// Strava API response for stats
 #[derive(Debug, Deserialize)]
 struct StravaStatsResponse {
@@ -227,8 +376,12 @@ pub struct StravaProvider {
     credentials: RwLock<Option<OAuth2Credentials>>,
     client: Client,
     circuit_breaker: CircuitBreaker,
+    rate_limit_budget: BudgetObserver,
 }
 
+/// Share of a unit a Strava split must cover to count as a full split
+const STRAVA_FULL_SPLIT_FRACTION: f64 = 0.99;
+
 /// Convert f32 metric value to u32 for Activity fields
 /// Safe for positive values within u32 range (heart rate, power, cadence, etc.)
 #[inline]
@@ -251,10 +404,13 @@ impl StravaProvider {
                 .split(',')
                 .map(str::to_owned)
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         };
 
         Self {
             circuit_breaker: CircuitBreaker::new(oauth_providers::STRAVA),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
@@ -267,6 +423,7 @@ impl StravaProvider {
         let provider_name = config.name.clone();
         Self {
             circuit_breaker: CircuitBreaker::new(&provider_name),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
@@ -373,8 +530,15 @@ impl StravaProvider {
             endpoint.trim_start_matches('/')
         );
 
+        let started = Instant::now();
         let result = self.execute_api_request::<T>(&url, &access_token).await;
 
+        MetricsRegistry::global().record_provider_call(
+            &self.config.name,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
         // Record success/failure for circuit breaker
         match &result {
             Ok(_) => self.circuit_breaker.record_success(),
@@ -401,14 +565,14 @@ impl StravaProvider {
     ) -> AppResult<reqwest::Response> {
         info!("Making HTTP GET request to: {url}");
 
-        self.client
-            .get(url)
-            .header("Authorization", format!("Bearer {access_token}"))
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service("Strava", format!("Failed to send request: {e}"))
-            })
+        http_client::send_with_timeout(
+            self.client
+                .get(url)
+                .header("Authorization", format!("Bearer {access_token}")),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| AppError::external_service("Strava", format!("Failed to send request: {e}")))
     }
 
     /// Parse Strava API response or handle errors
@@ -416,6 +580,8 @@ impl StravaProvider {
     where
         T: for<'de> Deserialize<'de>,
     {
+        self.rate_limit_budget
+            .observe(parse_strava_headers(response.headers(), Utc::now()));
         let status = response.status();
         info!("Received HTTP response with status: {status}");
 
@@ -433,36 +599,102 @@ impl StravaProvider {
 
     /// Convert Strava activity type to our `SportType` enum
     fn parse_sport_type(strava_type: &str) -> SportType {
-        match strava_type.to_lowercase().as_str() {
-            "run" => SportType::Run,
-            "ride" => SportType::Ride,
-            "swim" => SportType::Swim,
-            "walk" => SportType::Walk,
-            "hike" => SportType::Hike,
-            "workout" => SportType::Workout,
-            "yoga" => SportType::Yoga,
-            "weighttraining" => SportType::StrengthTraining,
-            _ => SportType::Other(strava_type.to_owned()),
+        SportTypeNormalizer::global().normalize(strava_type)
+    }
+
+    /// Extract the IANA zone name from a Strava timezone like "(GMT+09:00) Asia/Tokyo"
+    fn parse_timezone(strava_timezone: &str) -> Option<String> {
+        strava_timezone
+            .rsplit(' ')
+            .next()
+            .filter(|zone| !zone.is_empty() && !zone.starts_with('('))
+            .map(str::to_owned)
+    }
+
+    /// Convert Strava activity response using this provider's validation mode
+    fn convert_strava_activity(&self, activity: StravaActivityResponse) -> AppResult<Activity> {
+        Self::convert_activity(activity, self.config.validation_mode)
+    }
+
+    /// Convert a Strava activity summary to the internal Activity model
+    ///
+    /// # Errors
+    /// Returns error if the start date cannot be parsed, or if a field is
+    /// malformed and `mode` is strict
+    pub fn convert_activity(
+        activity: StravaActivityResponse,
+        mode: ProviderValidationMode,
+    ) -> AppResult<Activity> {
+        Self::strava_activity_builder(activity, mode).map(ActivityBuilder::build)
+    }
+
+    /// Check a Strava activity summary for missing or out-of-range fields
+    ///
+    /// Lenient mode logs each malformed field; conversion then coerces it as before.
+    ///
+    /// # Errors
+    /// Returns `ProviderError::MalformedResponse` for the first malformed field in strict mode
+    pub fn validate_activity(
+        activity: &StravaActivityResponse,
+        mode: ProviderValidationMode,
+    ) -> ProviderResult<()> {
+        let record_id = activity.id.to_string();
+        let check = |field: &str, reason: &str| {
+            mode.check_field(oauth_providers::STRAVA, &record_id, field, reason)
+        };
+
+        if activity.elapsed_time.is_none() {
+            check("elapsed_time", "missing, defaulting to 0 seconds")?;
         }
+
+        let measurements = [
+            ("distance", activity.distance),
+            ("total_elevation_gain", activity.total_elevation_gain),
+            ("average_speed", activity.average_speed),
+            ("max_speed", activity.max_speed),
+            ("average_heartrate", activity.average_heartrate),
+            ("max_heartrate", activity.max_heartrate),
+            ("average_cadence", activity.average_cadence),
+            ("average_watts", activity.average_watts),
+            ("max_watts", activity.max_watts),
+            ("calories", activity.calories),
+            ("suffer_score", activity.suffer_score),
+        ];
+        for (field, value) in measurements {
+            if let Some(value) = value.filter(|v| !v.is_finite() || *v < 0.0) {
+                check(
+                    field,
+                    &format!("expected a non-negative number, got {value}"),
+                )?;
+            }
+        }
+
+        if let Some(latlng) = activity
+            .start_latlng
+            .as_ref()
+            .filter(|latlng| !latlng.is_empty() && latlng.len() != 2)
+        {
+            check(
+                "start_latlng",
+                &format!("expected [lat, lng], got {} values", latlng.len()),
+            )?;
+        }
+
+        Ok(())
     }
 
-    /// Convert Strava activity response to internal Activity model
-    fn convert_strava_activity(activity: StravaActivityResponse) -> AppResult<Activity> {
+    /// Builder populated with the summary-level fields of a Strava activity
+    fn strava_activity_builder(
+        activity: StravaActivityResponse,
+        mode: ProviderValidationMode,
+    ) -> AppResult<ActivityBuilder> {
+        Self::validate_activity(&activity, mode)?;
+
         let start_date = DateTime::parse_from_rfc3339(&activity.start_date)
             .map_err(|e| AppError::internal(format!("Failed to parse activity start date: {e}")))?
             .with_timezone(&Utc);
 
-        let duration_seconds = activity.elapsed_time.map_or_else(
-            || {
-                debug!(
-                    activity_id = %activity.id,
-                    activity_name = %activity.name,
-                    "Strava API returned None for elapsed_time - defaulting to 0 seconds"
-                );
-                0
-            },
-            u64::from,
-        );
+        let duration_seconds = activity.elapsed_time.map_or(0, u64::from);
 
         Ok(ActivityBuilder::new(
             activity.id.to_string(),
@@ -472,6 +704,9 @@ impl StravaProvider {
             duration_seconds,
             oauth_providers::STRAVA,
         )
+        .start_timezone_opt(activity.timezone.as_deref().and_then(Self::parse_timezone))
+        .utc_offset_seconds_opt(activity.utc_offset.map(conversions::f64_to_i32))
+        .moving_time_seconds_opt(activity.moving_time.map(u64::from))
         .distance_meters_opt(activity.distance.map(f64::from))
         .elevation_gain_opt(activity.total_elevation_gain.map(f64::from))
         .average_speed_opt(activity.average_speed.map(f64::from))
@@ -498,8 +733,7 @@ impl StravaProvider {
         .city_opt(activity.location_city)
         .region_opt(activity.location_state)
         .country_opt(activity.location_country)
-        .sport_type_detail_opt(Some(activity.activity_type.clone()))
-        .build())
+        .sport_type_detail_opt(Some(activity.activity_type.clone())))
     }
 
     /// Convert detailed Strava activity response to internal Activity model with all fields populated
@@ -508,23 +742,23 @@ impl StravaProvider {
     /// Returns error if activity date parsing fails or API data is malformed
     pub fn convert_detailed_strava_activity(
         detailed: DetailedActivityResponse,
+        mode: ProviderValidationMode,
     ) -> AppResult<Activity> {
         // Start with summary conversion
-        let activity = Self::convert_strava_activity(detailed.summary)?;
+        let builder = Self::strava_activity_builder(detailed.summary, mode)?;
 
         // Add detailed-only fields that weren't in summary
         // Note: Most fields are already populated by summary conversion
         // Here we only add what's unique to the detailed endpoint
 
-        // Currently, the detailed endpoint provides splits, laps, and segment efforts
-        // but our Activity model doesn't have explicit fields for these yet.
-        // The time_series_data field could be populated from detailed streams endpoint
-        // (which requires a separate API call to /activities/{id}/streams)
-
-        // For now, we just return the activity with summary data
-        // Streams data integration can be added when needed
+        // Splits and laps are served by get_activity_splits. The time_series_data field could be
+        // populated from the streams endpoint (a separate call to /activities/{id}/streams)
+        let segment_efforts = detailed
+            .segment_efforts
+            .map(Self::convert_segment_efforts)
+            .filter(|efforts| !efforts.is_empty());
 
-        Ok(activity)
+        Ok(builder.segment_efforts_opt(segment_efforts).build())
     }
 
     /// Fetch detailed activity data from Strava API
@@ -534,7 +768,7 @@ impl StravaProvider {
     pub async fn get_activity_details(&self, id: &str) -> AppResult<Activity> {
         let endpoint = format!("activities/{id}");
         let detailed_activity: DetailedActivityResponse = self.api_request(&endpoint).await?;
-        Self::convert_detailed_strava_activity(detailed_activity)
+        Self::convert_detailed_strava_activity(detailed_activity, self.config.validation_mode)
     }
 
     /// Fetch activities with optional detailed data enrichment
@@ -611,6 +845,10 @@ impl FitnessProvider for StravaProvider {
         &self.config
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.rate_limit_budget.latest()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         info!("Setting Strava credentials");
         *self.credentials.write().await = Some(credentials);
@@ -680,18 +918,17 @@ impl FitnessProvider for StravaProvider {
             ("refresh_token", &refresh_token),
         ];
 
-        let response = self
-            .client
-            .post(&self.config.token_url)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service(
-                    "Strava",
-                    format!("Failed to send token refresh request: {e}"),
-                )
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client.post(&self.config.token_url).form(&params),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| {
+            AppError::external_service(
+                "Strava",
+                format!("Failed to send token refresh request: {e}"),
+            )
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -751,22 +988,15 @@ impl FitnessProvider for StravaProvider {
 
         // If request is within single page limit, use single page fetch
         if requested_limit <= api_provider_limits::strava::MAX_ACTIVITIES_PER_REQUEST {
-            return self
-                .get_activities_single_page_with_time(
-                    requested_limit,
-                    start_offset,
-                    params.before,
-                    params.after,
-                )
-                .await;
+            return self.get_activities_single_page_with_time(params).await;
         }
 
         // For large requests, use multi-page fetching with time filters
         self.get_activities_multi_page_with_time(
             requested_limit,
             start_offset,
-            params.before,
-            params.after,
+            params.before_timestamp(),
+            params.after_timestamp(),
         )
         .await
     }
@@ -810,7 +1040,7 @@ impl FitnessProvider for StravaProvider {
         // Convert activities
         let mut activities = Vec::new();
         for activity in &strava_activities {
-            activities.push(Self::convert_strava_activity(activity.clone())?);
+            activities.push(self.convert_strava_activity(activity.clone())?);
         }
 
         // Determine if there are more results
@@ -845,7 +1075,7 @@ impl FitnessProvider for StravaProvider {
     async fn get_activity(&self, id: &str) -> AppResult<Activity> {
         let endpoint = format!("activities/{id}");
         let strava_activity: StravaActivityResponse = self.api_request(&endpoint).await?;
-        Self::convert_strava_activity(strava_activity)
+        self.convert_strava_activity(strava_activity)
     }
 
     async fn get_stats(&self) -> AppResult<Stats> {
@@ -885,6 +1115,77 @@ impl FitnessProvider for StravaProvider {
         Ok(vec![])
     }
 
+    async fn list_gear(&self) -> ProviderResult<Vec<Gear>> {
+        let athlete: StravaAthleteGearResponse = self
+            .api_request("athlete")
+            .await
+            .map_err(|e| Self::to_provider_error(&e))?;
+
+        let mut gear = Vec::with_capacity(athlete.shoes.len() + athlete.bikes.len());
+        for (summaries, gear_type) in [
+            (athlete.shoes, GearType::Shoes),
+            (athlete.bikes, GearType::Bike),
+        ] {
+            for summary in summaries {
+                // Brand and model are only on the detailed endpoint; keep the summary if it fails
+                let detail = self
+                    .api_request::<StravaGearResponse>(&format!("gear/{}", summary.id))
+                    .await
+                    .inspect_err(|e| {
+                        warn!(gear_id = %summary.id, error = %e, "Failed to fetch Strava gear details");
+                    })
+                    .unwrap_or(summary);
+                gear.push(Self::convert_gear(detail, gear_type));
+            }
+        }
+
+        Ok(gear)
+    }
+
+    async fn list_segment_efforts(&self, activity_id: &str) -> ProviderResult<Vec<SegmentEffort>> {
+        // The activity list omits efforts; the detailed endpoint includes every one on request
+        let endpoint = format!("activities/{activity_id}?include_all_efforts=true");
+        let detailed: DetailedActivityResponse = self
+            .api_request(&endpoint)
+            .await
+            .map_err(|e| Self::to_provider_error(&e))?;
+
+        Ok(Self::convert_segment_efforts(
+            detailed.segment_efforts.unwrap_or_default(),
+        ))
+    }
+
+    async fn get_segment(&self, segment_id: &str) -> ProviderResult<Segment> {
+        let segment: StravaSegmentResponse = self
+            .api_request(&format!("segments/{segment_id}"))
+            .await
+            .map_err(|e| Self::to_provider_error(&e))?;
+
+        Ok(Self::convert_segment(segment))
+    }
+
+    async fn get_athlete_zones(&self) -> ProviderResult<AthleteZones> {
+        let response: StravaZonesResponse = self
+            .api_request("athlete/zones")
+            .await
+            .map_err(|e| Self::to_provider_error(&e))?;
+
+        Ok(Self::convert_zones(response))
+    }
+
+    async fn get_activity_splits(
+        &self,
+        activity_id: &str,
+        unit: SplitUnit,
+    ) -> ProviderResult<Vec<ActivitySplit>> {
+        let detailed: DetailedActivityResponse = self
+            .api_request(&format!("activities/{activity_id}"))
+            .await
+            .map_err(|e| Self::to_provider_error(&e))?;
+
+        Ok(Self::convert_splits(detailed, unit))
+    }
+
     async fn disconnect(&self) -> AppResult<()> {
         // Clone access token and revoke URL to avoid holding lock across await
         let (access_token_opt, revoke_url_opt) = {
@@ -898,18 +1199,20 @@ impl FitnessProvider for StravaProvider {
         };
 
         if let (Some(access_token), Some(revoke_url)) = (access_token_opt, revoke_url_opt) {
-            self.client
-                .post(&revoke_url)
-                .form(&[("token", access_token.as_str())])
-                .send()
-                .await
-                .inspect_err(|e| {
-                    warn!(
-                        error = ?e,
-                        "Failed to revoke Strava access token - continuing with credential cleanup"
-                    );
-                })
-                .ok();
+            http_client::send_with_timeout(
+                self.client
+                    .post(&revoke_url)
+                    .form(&[("token", access_token.as_str())]),
+                self.config.request_timeout(),
+            )
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    error = ?e,
+                    "Failed to revoke Strava access token - continuing with credential cleanup"
+                );
+            })
+            .ok();
             info!("Attempted to revoke Strava access token");
         }
 
@@ -920,20 +1223,257 @@ impl FitnessProvider for StravaProvider {
 }
 
 impl StravaProvider {
-    /// Fetch activities using single API call with optional time filters
-    async fn get_activities_single_page_with_time(
-        &self,
-        limit: usize,
-        offset: usize,
-        before: Option<i64>,
-        after: Option<i64>,
-    ) -> AppResult<Vec<Activity>> {
+    /// Convert a Strava gear summary or detail response to the unified `Gear` model
+    #[must_use]
+    pub fn convert_gear(gear: StravaGearResponse, gear_type: GearType) -> Gear {
+        Gear {
+            name: gear.name.unwrap_or_else(|| gear.id.clone()),
+            id: gear.id,
+            gear_type,
+            distance_meters: gear.distance,
+            retired: gear.retired,
+            primary: gear.primary,
+            brand: gear.brand_name,
+            model: gear.model_name,
+            provider: oauth_providers::STRAVA.to_owned(),
+        }
+    }
+
+    /// Convert the segment efforts of a detailed activity, skipping malformed entries
+    #[must_use]
+    pub fn convert_segment_efforts(efforts: Vec<StravaSegmentEffort>) -> Vec<SegmentEffort> {
+        efforts
+            .into_iter()
+            .filter_map(Self::convert_segment_effort)
+            .collect()
+    }
+
+    /// Convert a Strava segment effort to the unified `SegmentEffort` model
+    ///
+    /// Returns `None` when the effort has no id or no parseable start date.
+    /// Grade, climb category, and elevation come from the nested segment summary.
+    #[must_use]
+    pub fn convert_segment_effort(effort: StravaSegmentEffort) -> Option<SegmentEffort> {
+        let Some(id) = effort.id else {
+            debug!("Skipping Strava segment effort without an id");
+            return None;
+        };
+        let start_date = effort
+            .start_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc));
+        let Some(start_date) = start_date else {
+            debug!(
+                effort_id = id,
+                "Skipping Strava segment effort without a start date"
+            );
+            return None;
+        };
+
+        let segment = effort.segment;
+        let name = effort
+            .name
+            .or_else(|| segment.as_ref().and_then(|s| s.name.clone()))
+            .unwrap_or_default();
+
+        Some(SegmentEffort {
+            id: id.to_string(),
+            segment_id: segment.as_ref().map(|s| s.id.to_string()),
+            name,
+            elapsed_time: effort.elapsed_time.map_or(0, u64::from),
+            moving_time: effort.moving_time.map(u64::from),
+            start_date,
+            distance: effort.distance.map_or(0.0, f64::from),
+            average_heart_rate: effort.average_heartrate.map(f32_to_u32),
+            max_heart_rate: effort.max_heartrate.map(f32_to_u32),
+            average_cadence: effort.average_cadence.map(f32_to_u32),
+            average_watts: effort.average_watts.map(f32_to_u32),
+            kom_rank: effort.kom_rank,
+            pr_rank: effort.pr_rank,
+            climb_category: segment.as_ref().and_then(|s| s.climb_category),
+            average_grade: segment.as_ref().and_then(|s| s.average_grade),
+            elevation_gain: segment.as_ref().and_then(|s| {
+                s.total_elevation_gain
+                    .or_else(|| Some((s.elevation_high? - s.elevation_low?).max(0.0)))
+            }),
+        })
+    }
+
+    /// Convert a Strava segment summary or detail response to the unified `Segment` model
+    #[must_use]
+    pub fn convert_segment(segment: StravaSegmentResponse) -> Segment {
+        let xoms = segment.xoms;
+        let stats = segment.athlete_segment_stats;
+        Segment {
+            id: segment.id.to_string(),
+            name: segment.name.unwrap_or_else(|| segment.id.to_string()),
+            sport_type: segment.activity_type.as_deref().map(Self::parse_sport_type),
+            distance_meters: segment.distance.unwrap_or(0.0),
+            average_grade: segment.average_grade,
+            maximum_grade: segment.maximum_grade,
+            elevation_high: segment.elevation_high,
+            elevation_low: segment.elevation_low,
+            total_elevation_gain: segment.total_elevation_gain,
+            climb_category: segment.climb_category,
+            city: segment.city,
+            region: segment.state,
+            country: segment.country,
+            effort_count: segment.effort_count,
+            athlete_count: segment.athlete_count,
+            kom_time: xoms.as_ref().and_then(|x| x.kom.clone()),
+            qom_time: xoms.and_then(|x| x.qom),
+            pr_elapsed_time: stats.as_ref().and_then(|s| s.pr_elapsed_time),
+            pr_date: stats.as_ref().and_then(|s| s.pr_date),
+            athlete_effort_count: stats.and_then(|s| s.effort_count),
+            provider: oauth_providers::STRAVA.to_owned(),
+        }
+    }
+
+    /// Athlete zones from a GET /athlete/zones response
+    ///
+    /// Negative or out-of-range bounds, such as the -1 Strava uses for the
+    /// open-ended top zone, become `None`.
+    #[must_use]
+    pub fn convert_zones(response: StravaZonesResponse) -> AthleteZones {
+        let convert = |set: Option<StravaZoneSet>| -> Vec<ZoneRange> {
+            set.map(|set| set.zones)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|zone| ZoneRange {
+                    min: u32::try_from(zone.min).unwrap_or(0),
+                    max: u32::try_from(zone.max).ok().filter(|&max| max > 0),
+                })
+                .collect()
+        };
+        AthleteZones {
+            heart_rate: convert(response.heart_rate),
+            power: convert(response.power),
+        }
+    }
+
+    /// Splits of a detailed activity in `unit`, falling back to its laps
+    ///
+    /// Strava reports 1km splits in `splits_metric` and 1mi splits in
+    /// `splits_standard`. Activities without them (e.g. manual or indoor
+    /// activities) may still have laps.
+    #[must_use]
+    pub fn convert_splits(
+        detailed: DetailedActivityResponse,
+        unit: SplitUnit,
+    ) -> Vec<ActivitySplit> {
+        let splits = match unit {
+            SplitUnit::Km => detailed.splits_metric,
+            SplitUnit::Mile => detailed.splits_standard,
+        }
+        .unwrap_or_default();
+        if !splits.is_empty() {
+            return splits
+                .into_iter()
+                .zip(1_u32..)
+                .map(|(split, index)| Self::convert_split(split, index, unit))
+                .collect();
+        }
+
+        detailed
+            .laps
+            .unwrap_or_default()
+            .into_iter()
+            .zip(1_u32..)
+            .map(|(lap, index)| Self::convert_lap(lap, index, unit))
+            .collect()
+    }
+
+    /// Convert a Strava per-unit split
+    fn convert_split(split: StravaSplit, index: u32, unit: SplitUnit) -> ActivitySplit {
+        let distance_meters = split.distance.map_or(0.0, f64::from);
+        let elapsed_time_seconds = split.elapsed_time.map_or(0.0, f64::from);
+        let moving_time_seconds = split.moving_time.map(f64::from);
+        // Strava's final split holds the remainder; full ones are a few centimeters over a unit
+        let partial = distance_meters < unit.meters() * STRAVA_FULL_SPLIT_FRACTION;
+
+        ActivitySplit {
+            index: split.split.unwrap_or(index),
+            distance_meters,
+            elapsed_time_seconds,
+            moving_time_seconds,
+            pace_seconds_per_unit: ActivitySplit::pace(
+                distance_meters,
+                moving_time_seconds.unwrap_or(elapsed_time_seconds),
+                unit,
+            ),
+            average_heart_rate: split.average_heartrate.map(f64::from),
+            max_heart_rate: None,
+            elevation_gain_meters: None,
+            elevation_change_meters: split.elevation_difference.map(f64::from),
+            partial,
+        }
+    }
+
+    /// Convert a Strava lap; laps are user-defined, so none is marked partial
+    fn convert_lap(lap: StravaLap, index: u32, unit: SplitUnit) -> ActivitySplit {
+        let distance_meters = lap.distance.map_or(0.0, f64::from);
+        let elapsed_time_seconds = lap.elapsed_time.map_or(0.0, f64::from);
+        let moving_time_seconds = lap.moving_time.map(f64::from);
+
+        ActivitySplit {
+            index,
+            distance_meters,
+            elapsed_time_seconds,
+            moving_time_seconds,
+            pace_seconds_per_unit: ActivitySplit::pace(
+                distance_meters,
+                moving_time_seconds.unwrap_or(elapsed_time_seconds),
+                unit,
+            ),
+            average_heart_rate: lap.average_heartrate.map(f64::from),
+            max_heart_rate: lap.max_heartrate.map(f32_to_u32),
+            elevation_gain_meters: lap.total_elevation_gain.map(f64::from),
+            elevation_change_meters: None,
+            partial: false,
+        }
+    }
+
+    /// Wrap an API failure as a provider error for the optional trait methods
+    fn to_provider_error(error: &AppError) -> ProviderError {
+        ProviderError::ApiError {
+            provider: oauth_providers::STRAVA.to_owned(),
+            status_code: 500,
+            message: error.to_string(),
+            retryable: true,
+        }
+    }
+
+    /// Build the `athlete/activities` endpoint for a single page of results
+    ///
+    /// `before`/`after` are sent as Strava's native epoch-second filters so only
+    /// the requested window is downloaded.
+    #[must_use]
+    pub fn activities_endpoint(params: &ActivityQueryParams) -> String {
+        let limit = params
+            .limit
+            .unwrap_or(api_provider_limits::strava::DEFAULT_ACTIVITIES_PER_PAGE)
+            .min(api_provider_limits::strava::MAX_ACTIVITIES_PER_REQUEST);
+        let offset = params.offset.unwrap_or(0);
         let page = if offset > 0 {
             offset / limit.max(1) + 1
         } else {
             1
         };
-        let endpoint = Self::build_activities_endpoint_with_time(limit, page, before, after);
+        Self::build_activities_endpoint_with_time(
+            limit,
+            page,
+            params.before_timestamp(),
+            params.after_timestamp(),
+        )
+    }
+
+    /// Fetch activities using single API call with optional time filters
+    async fn get_activities_single_page_with_time(
+        &self,
+        params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        let endpoint = Self::activities_endpoint(params);
 
         info!("Single page request - endpoint: {}", endpoint);
 
@@ -945,7 +1485,7 @@ impl StravaProvider {
 
         let mut activities = Vec::new();
         for activity in strava_activities {
-            activities.push(Self::convert_strava_activity(activity)?);
+            activities.push(self.convert_strava_activity(activity)?);
         }
 
         Ok(activities)
@@ -970,6 +1510,7 @@ impl StravaProvider {
 
     /// Convert and add Strava activities to the collection
     fn add_converted_activities(
+        &self,
         activities: &mut Vec<Activity>,
         strava_activities: Vec<StravaActivityResponse>,
         limit: usize,
@@ -978,7 +1519,7 @@ impl StravaProvider {
             if activities.len() >= limit {
                 break;
             }
-            activities.push(Self::convert_strava_activity(activity)?);
+            activities.push(self.convert_strava_activity(activity)?);
         }
         Ok(())
     }
@@ -1088,7 +1629,7 @@ impl StravaProvider {
             strava_activities.len()
         );
 
-        Self::add_converted_activities(all_activities, strava_activities, ctx.total_limit)
+        self.add_converted_activities(all_activities, strava_activities, ctx.total_limit)
     }
 }
 
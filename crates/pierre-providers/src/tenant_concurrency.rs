@@ -0,0 +1,308 @@
+// ABOUTME: Tenant-scoped cap on concurrent outbound provider requests with a bounded wait queue
+// ABOUTME: Keeps one tenant's parallel syncs from exhausting a provider's global API quota
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Per-tenant provider request throttle
+//!
+//! Per-endpoint rate limits do not stop a tenant that runs many syncs in
+//! parallel from draining a provider's app-wide quota. Every tenant gets a
+//! semaphore sized to its concurrency limit; requests past the limit wait in a
+//! bounded queue for a free slot and give up after a timeout. Only when the
+//! queue itself is full does a request fail immediately.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use pierre_core::models::TenantId;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::timeout;
+use tracing::{debug, warn};
+
+use crate::errors::provider::ProviderError;
+
+/// Concurrent provider requests per tenant when `PIERRE_PROVIDER_TENANT_MAX_CONCURRENT` is unset
+pub const DEFAULT_TENANT_MAX_CONCURRENT: usize = 8;
+
+/// Requests a tenant may have waiting when `PIERRE_PROVIDER_TENANT_MAX_QUEUED` is unset
+pub const DEFAULT_TENANT_MAX_QUEUED: usize = 64;
+
+/// Seconds a request waits for a slot when `PIERRE_PROVIDER_TENANT_QUEUE_TIMEOUT_SECS` is unset
+pub const DEFAULT_TENANT_QUEUE_TIMEOUT_SECS: u64 = 30;
+
+/// Limits on concurrent provider requests per tenant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantConcurrencyConfig {
+    /// Concurrent requests allowed for tenants without an override
+    pub default_limit: usize,
+    /// Requests that may wait for a slot before new ones are rejected
+    pub max_queued: usize,
+    /// How long a queued request waits for a slot
+    pub queue_timeout: Duration,
+    /// Per-tenant concurrency limits replacing `default_limit`
+    pub tenant_limits: HashMap<TenantId, usize>,
+}
+
+impl Default for TenantConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            default_limit: DEFAULT_TENANT_MAX_CONCURRENT,
+            max_queued: DEFAULT_TENANT_MAX_QUEUED,
+            queue_timeout: Duration::from_secs(DEFAULT_TENANT_QUEUE_TIMEOUT_SECS),
+            tenant_limits: HashMap::new(),
+        }
+    }
+}
+
+impl TenantConcurrencyConfig {
+    /// Load tenant concurrency limits from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_PROVIDER_TENANT_MAX_CONCURRENT`: Concurrent requests per tenant (default: 8, minimum 1)
+    /// - `PIERRE_PROVIDER_TENANT_MAX_QUEUED`: Requests waiting per tenant (default: 64)
+    /// - `PIERRE_PROVIDER_TENANT_QUEUE_TIMEOUT_SECS`: Seconds to wait for a slot (default: 30)
+    /// - `PIERRE_PROVIDER_TENANT_LIMITS`: Per-tenant overrides as `tenant_id=limit` pairs
+    ///   separated by commas
+    ///
+    /// Invalid values fall back to the defaults; invalid overrides are skipped.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let tenant_limits = env::var("PIERRE_PROVIDER_TENANT_LIMITS")
+            .map(|value| parse_tenant_limits(&value))
+            .unwrap_or_default();
+
+        Self {
+            default_limit: parse_env(
+                "PIERRE_PROVIDER_TENANT_MAX_CONCURRENT",
+                DEFAULT_TENANT_MAX_CONCURRENT,
+            ),
+            max_queued: parse_env(
+                "PIERRE_PROVIDER_TENANT_MAX_QUEUED",
+                DEFAULT_TENANT_MAX_QUEUED,
+            ),
+            queue_timeout: Duration::from_secs(parse_env(
+                "PIERRE_PROVIDER_TENANT_QUEUE_TIMEOUT_SECS",
+                DEFAULT_TENANT_QUEUE_TIMEOUT_SECS,
+            )),
+            tenant_limits,
+        }
+    }
+
+    /// Override the concurrency limit for one tenant
+    #[must_use]
+    pub fn with_tenant_limit(mut self, tenant_id: TenantId, limit: usize) -> Self {
+        self.tenant_limits.insert(tenant_id, limit);
+        self
+    }
+
+    /// Concurrency limit applied to `tenant_id` (at least 1)
+    #[must_use]
+    pub fn limit_for(&self, tenant_id: TenantId) -> usize {
+        self.tenant_limits
+            .get(&tenant_id)
+            .copied()
+            .unwrap_or(self.default_limit)
+            .max(1)
+    }
+}
+
+/// Parse `tenant_id=limit` pairs, skipping malformed entries
+fn parse_tenant_limits(value: &str) -> HashMap<TenantId, usize> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let parsed = entry.split_once('=').and_then(|(tenant, limit)| {
+                Some((tenant.trim().parse().ok()?, limit.trim().parse().ok()?))
+            });
+            if parsed.is_none() {
+                warn!("Invalid PIERRE_PROVIDER_TENANT_LIMITS entry '{}'", entry);
+            }
+            parsed
+        })
+        .collect()
+}
+
+/// Parse a numeric environment variable, warning and falling back on invalid input
+pub(crate) fn parse_env<T>(name: &str, default: T) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    env::var(name)
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .inspect_err(|e| warn!("Invalid {} '{}': {}", name, value, e))
+                .ok()
+        })
+        .unwrap_or(default)
+}
+
+/// Request slots for one tenant
+struct TenantSlots {
+    semaphore: Arc<Semaphore>,
+    limit: usize,
+    queued: AtomicUsize,
+}
+
+/// Current request counts for one tenant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenantInFlight {
+    /// Tenant the counts belong to
+    pub tenant_id: TenantId,
+    /// Concurrent requests allowed
+    pub limit: usize,
+    /// Requests currently holding a slot
+    pub in_flight: usize,
+    /// Requests waiting for a slot
+    pub queued: usize,
+}
+
+/// A request's place in a tenant queue, released when dropped
+struct QueuePosition<'a> {
+    queued: &'a AtomicUsize,
+    /// Requests already waiting when this one joined
+    ahead: usize,
+}
+
+impl<'a> QueuePosition<'a> {
+    fn enter(queued: &'a AtomicUsize) -> Self {
+        let ahead = queued.fetch_add(1, Ordering::AcqRel);
+        Self { queued, ahead }
+    }
+}
+
+impl Drop for QueuePosition<'_> {
+    fn drop(&mut self) {
+        self.queued.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Slot held for the duration of one provider request
+///
+/// The slot is returned to the tenant when the permit is dropped.
+pub struct TenantRequestPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Caps concurrent provider requests per tenant across all providers
+pub struct TenantConcurrencyLimiter {
+    config: TenantConcurrencyConfig,
+    tenants: Mutex<HashMap<TenantId, Arc<TenantSlots>>>,
+}
+
+impl TenantConcurrencyLimiter {
+    /// Create a limiter with the given limits
+    #[must_use]
+    pub fn new(config: TenantConcurrencyConfig) -> Self {
+        Self {
+            config,
+            tenants: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Limits this limiter enforces
+    #[must_use]
+    pub const fn config(&self) -> &TenantConcurrencyConfig {
+        &self.config
+    }
+
+    /// Wait for a request slot for `tenant_id`
+    ///
+    /// Returns immediately when the tenant is under its limit. Otherwise the
+    /// request joins the tenant's queue and waits up to the queue timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RateLimitExceeded` if the tenant's queue is full, or `Timeout`
+    /// if no slot frees up within the queue timeout
+    pub async fn acquire(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> Result<TenantRequestPermit, ProviderError> {
+        let slots = self.slots(tenant_id);
+        if let Ok(permit) = Arc::clone(&slots.semaphore).try_acquire_owned() {
+            return Ok(TenantRequestPermit { _permit: permit });
+        }
+
+        // Counted until this request leaves the queue, even if the caller gives up
+        let queue_position = QueuePosition::enter(&slots.queued);
+        if queue_position.ahead >= self.config.max_queued {
+            warn!(tenant_id = %tenant_id, provider, "Tenant provider request queue is full");
+            return Err(ProviderError::RateLimitExceeded {
+                provider: provider.to_owned(),
+                retry_after_secs: 1,
+                limit_type: "tenant concurrency".to_owned(),
+            });
+        }
+
+        debug!(tenant_id = %tenant_id, provider, "Waiting for a tenant provider request slot");
+        let waited = timeout(
+            self.config.queue_timeout,
+            Arc::clone(&slots.semaphore).acquire_owned(),
+        )
+        .await;
+        drop(queue_position);
+
+        match waited {
+            Ok(Ok(permit)) => Ok(TenantRequestPermit { _permit: permit }),
+            // The semaphore is never closed, so acquiring only fails on timeout
+            Ok(Err(_)) | Err(_) => Err(ProviderError::Timeout {
+                provider: provider.to_owned(),
+                operation: "waiting for a tenant request slot",
+                timeout_secs: self.config.queue_timeout.as_secs(),
+            }),
+        }
+    }
+
+    /// Current in-flight and queued request counts for every tenant seen so far
+    #[must_use]
+    pub fn in_flight(&self) -> Vec<TenantInFlight> {
+        let tenants = self.tenants.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut counts: Vec<TenantInFlight> = tenants
+            .iter()
+            .map(|(tenant_id, slots)| TenantInFlight {
+                tenant_id: *tenant_id,
+                limit: slots.limit,
+                in_flight: slots
+                    .limit
+                    .saturating_sub(slots.semaphore.available_permits()),
+                queued: slots.queued.load(Ordering::Acquire),
+            })
+            .collect();
+        counts.sort_by_key(|count| count.tenant_id.0);
+        counts
+    }
+
+    /// Slots for `tenant_id`, created on first use
+    fn slots(&self, tenant_id: TenantId) -> Arc<TenantSlots> {
+        let mut tenants = self.tenants.lock().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(tenants.entry(tenant_id).or_insert_with(|| {
+            let limit = self.config.limit_for(tenant_id);
+            Arc::new(TenantSlots {
+                semaphore: Arc::new(Semaphore::new(limit)),
+                limit,
+                queued: AtomicUsize::new(0),
+            })
+        }))
+    }
+}
+
+impl Default for TenantConcurrencyLimiter {
+    fn default() -> Self {
+        Self::new(TenantConcurrencyConfig::default())
+    }
+}
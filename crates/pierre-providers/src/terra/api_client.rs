@@ -14,6 +14,7 @@
 //! - Deauthenticating users
 
 use crate::errors::provider::ProviderError;
+use crate::http_client;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -175,15 +176,15 @@ impl TerraApiClient {
             body["auth_failure_redirect_url"] = serde_json::json!(failure_url);
         }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("x-api-key", &self.config.api_key)
-            .header("dev-id", &self.config.dev_id)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        let response = http_client::send(
+            self.client
+                .post(&url)
+                .header("x-api-key", &self.config.api_key)
+                .header("dev-id", &self.config.dev_id)
+                .json(&body),
+        )
+        .await
+        .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
@@ -215,15 +216,15 @@ impl TerraApiClient {
     ) -> Result<DeauthResponse, ProviderError> {
         let url = format!("{}/auth/deauthenticateUser", self.config.base_url);
 
-        let response = self
-            .client
-            .delete(&url)
-            .header("x-api-key", &self.config.api_key)
-            .header("dev-id", &self.config.dev_id)
-            .query(&[("user_id", user_id)])
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        let response = http_client::send(
+            self.client
+                .delete(&url)
+                .header("x-api-key", &self.config.api_key)
+                .header("dev-id", &self.config.dev_id)
+                .query(&[("user_id", user_id)]),
+        )
+        .await
+        .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
@@ -252,15 +253,15 @@ impl TerraApiClient {
     pub async fn get_user_info(&self, user_id: &str) -> Result<UserInfoResponse, ProviderError> {
         let url = format!("{}/userInfo", self.config.base_url);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.config.api_key)
-            .header("dev-id", &self.config.dev_id)
-            .query(&[("user_id", user_id)])
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        let response = http_client::send(
+            self.client
+                .get(&url)
+                .header("x-api-key", &self.config.api_key)
+                .header("dev-id", &self.config.dev_id)
+                .query(&[("user_id", user_id)]),
+        )
+        .await
+        .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
@@ -304,20 +305,20 @@ impl TerraApiClient {
         let start_date_str = request.start_date.format("%Y-%m-%d").to_string();
         let end_date_str = request.end_date.format("%Y-%m-%d").to_string();
 
-        let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.config.api_key)
-            .header("dev-id", &self.config.dev_id)
-            .query(&[
-                ("user_id", &request.user_id),
-                ("start_date", &start_date_str),
-                ("end_date", &end_date_str),
-                ("to_webhook", &request.to_webhook.to_string()),
-            ])
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        let response = http_client::send(
+            self.client
+                .get(&url)
+                .header("x-api-key", &self.config.api_key)
+                .header("dev-id", &self.config.dev_id)
+                .query(&[
+                    ("user_id", &request.user_id),
+                    ("start_date", &start_date_str),
+                    ("end_date", &end_date_str),
+                    ("to_webhook", &request.to_webhook.to_string()),
+                ]),
+        )
+        .await
+        .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
@@ -355,14 +356,14 @@ impl TerraApiClient {
     pub async fn list_users(&self) -> Result<Vec<TerraUserInfo>, ProviderError> {
         let url = format!("{}/subscriptions", self.config.base_url);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.config.api_key)
-            .header("dev-id", &self.config.dev_id)
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        let response = http_client::send(
+            self.client
+                .get(&url)
+                .header("x-api-key", &self.config.api_key)
+                .header("dev-id", &self.config.dev_id),
+        )
+        .await
+        .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
@@ -23,29 +23,63 @@
 //!
 //! - **Insert/Update**: Webhook handler stores new data via `store_*` methods
 //! - **Query**: Provider reads data via `get_*` methods
-//! - **Expiry**: Old data is automatically cleaned up (configurable TTL, default 7 days)
+//! - **Expiry**: Each entry expires after the configured TTL (default 7 days)
+//! - **Eviction**: At most `max_users` users are cached; storing data for a new
+//!   user beyond that evicts the least recently used one
+//!
+//! Hits, misses, and evictions are counted and reported by [`TerraDataCache::stats`].
+//! Expiry decisions use a [`CacheClock`] so tests can advance time deterministically.
 
+use crate::constants::cache_config::DEFAULT_CAPACITY;
 use crate::models::{Activity, HealthMetrics, NutritionLog, RecoveryMetrics, SleepSession};
 use chrono::{DateTime, Duration, Utc};
+use lru::LruCache;
 use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Default time-to-live for cached entries in days
+const DEFAULT_TTL_DAYS: i64 = 7;
+
+/// Default maximum items per user per data type
+const DEFAULT_MAX_ITEMS_PER_TYPE: usize = 1000;
+
+/// Source of the current time for cache expiry decisions
+pub trait CacheClock: Send + Sync {
+    /// Current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl CacheClock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
 /// Cache configuration
 #[derive(Debug, Clone)]
-pub struct TerraDataCacheConfig {
-    /// Time-to-live for cached data (default: 7 days)
-    pub ttl_days: i64,
+pub struct TerraCacheConfig {
+    /// Time-to-live for each cached entry (default: 7 days)
+    pub ttl: Duration,
+    /// Maximum number of users with cached data (default: `cache_config::DEFAULT_CAPACITY`)
+    pub max_users: usize,
     /// Maximum items per user per data type (default: 1000)
     pub max_items_per_type: usize,
 }
 
-impl Default for TerraDataCacheConfig {
+impl Default for TerraCacheConfig {
     fn default() -> Self {
         Self {
-            ttl_days: 7,
-            max_items_per_type: 1000,
+            ttl: Duration::days(DEFAULT_TTL_DAYS),
+            max_users: DEFAULT_CAPACITY,
+            max_items_per_type: DEFAULT_MAX_ITEMS_PER_TYPE,
         }
     }
 }
@@ -54,19 +88,16 @@ impl Default for TerraDataCacheConfig {
 #[derive(Debug, Clone)]
 struct CacheEntry<T> {
     data: T,
-    cached_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
 }
 
 impl<T> CacheEntry<T> {
-    fn new(data: T) -> Self {
-        Self {
-            data,
-            cached_at: Utc::now(),
-        }
+    const fn new(data: T, expires_at: DateTime<Utc>) -> Self {
+        Self { data, expires_at }
     }
 
-    fn is_expired(&self, ttl_days: i64) -> bool {
-        Utc::now() > self.cached_at + Duration::days(ttl_days)
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
     }
 }
 
@@ -78,14 +109,16 @@ struct UserCache {
     health_metrics: Vec<CacheEntry<HealthMetrics>>,
     recovery_metrics: Vec<CacheEntry<RecoveryMetrics>>,
     nutrition_logs: Vec<CacheEntry<NutritionLog>>,
+    /// When data last arrived or a re-fetch from Terra was last requested
+    refreshed_at: Option<DateTime<Utc>>,
 }
 
 impl UserCache {
     /// Add an activity to the cache, avoiding duplicates and enforcing limits
-    fn add_activity(&mut self, activity: Activity, max_items: usize) {
+    fn add_activity(&mut self, activity: Activity, expires_at: DateTime<Utc>, max_items: usize) {
         // Check for duplicate by ID
         if !self.activities.iter().any(|e| e.data.id() == activity.id()) {
-            self.activities.push(CacheEntry::new(activity));
+            self.activities.push(CacheEntry::new(activity, expires_at));
 
             // Enforce max items limit
             if self.activities.len() > max_items {
@@ -98,10 +131,15 @@ impl UserCache {
     }
 
     /// Add a sleep session to the cache, avoiding duplicates and enforcing limits
-    fn add_sleep_session(&mut self, sleep: SleepSession, max_items: usize) {
+    fn add_sleep_session(
+        &mut self,
+        sleep: SleepSession,
+        expires_at: DateTime<Utc>,
+        max_items: usize,
+    ) {
         // Check for duplicate by ID
         if !self.sleep_sessions.iter().any(|e| e.data.id == sleep.id) {
-            self.sleep_sessions.push(CacheEntry::new(sleep));
+            self.sleep_sessions.push(CacheEntry::new(sleep, expires_at));
 
             // Enforce max items limit
             if self.sleep_sessions.len() > max_items {
@@ -113,12 +151,18 @@ impl UserCache {
     }
 
     /// Add health metrics to the cache, replacing existing for same date
-    fn add_health_metrics(&mut self, health: HealthMetrics, max_items: usize) {
+    fn add_health_metrics(
+        &mut self,
+        health: HealthMetrics,
+        expires_at: DateTime<Utc>,
+        max_items: usize,
+    ) {
         // Replace or add based on date (one entry per day)
         let date_key = health.date.date_naive();
         self.health_metrics
             .retain(|e| e.data.date.date_naive() != date_key);
-        self.health_metrics.push(CacheEntry::new(health));
+        self.health_metrics
+            .push(CacheEntry::new(health, expires_at));
 
         // Enforce max items limit
         if self.health_metrics.len() > max_items {
@@ -129,12 +173,18 @@ impl UserCache {
     }
 
     /// Add recovery metrics to the cache, replacing existing for same date
-    fn add_recovery_metrics(&mut self, recovery: RecoveryMetrics, max_items: usize) {
+    fn add_recovery_metrics(
+        &mut self,
+        recovery: RecoveryMetrics,
+        expires_at: DateTime<Utc>,
+        max_items: usize,
+    ) {
         // Replace or add based on date
         let date_key = recovery.date.date_naive();
         self.recovery_metrics
             .retain(|e| e.data.date.date_naive() != date_key);
-        self.recovery_metrics.push(CacheEntry::new(recovery));
+        self.recovery_metrics
+            .push(CacheEntry::new(recovery, expires_at));
 
         // Enforce max items limit
         if self.recovery_metrics.len() > max_items {
@@ -145,12 +195,18 @@ impl UserCache {
     }
 
     /// Add nutrition log to the cache, replacing existing for same date
-    fn add_nutrition_log(&mut self, nutrition: NutritionLog, max_items: usize) {
+    fn add_nutrition_log(
+        &mut self,
+        nutrition: NutritionLog,
+        expires_at: DateTime<Utc>,
+        max_items: usize,
+    ) {
         // Replace or add based on date
         let date_key = nutrition.date.date_naive();
         self.nutrition_logs
             .retain(|e| e.data.date.date_naive() != date_key);
-        self.nutrition_logs.push(CacheEntry::new(nutrition));
+        self.nutrition_logs
+            .push(CacheEntry::new(nutrition, expires_at));
 
         // Enforce max items limit
         if self.nutrition_logs.len() > max_items {
@@ -159,6 +215,31 @@ impl UserCache {
             self.nutrition_logs.truncate(max_items);
         }
     }
+
+    /// Drop expired entries of every data type
+    fn remove_expired(&mut self, now: DateTime<Utc>) {
+        self.activities.retain(|e| !e.is_expired(now));
+        self.sleep_sessions.retain(|e| !e.is_expired(now));
+        self.health_metrics.retain(|e| !e.is_expired(now));
+        self.recovery_metrics.retain(|e| !e.is_expired(now));
+        self.nutrition_logs.retain(|e| !e.is_expired(now));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.activities.is_empty()
+            && self.sleep_sessions.is_empty()
+            && self.health_metrics.is_empty()
+            && self.recovery_metrics.is_empty()
+            && self.nutrition_logs.is_empty()
+    }
+}
+
+/// Lookup and eviction counters
+#[derive(Debug, Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
 }
 
 /// Terra data cache for webhook data storage
@@ -166,34 +247,53 @@ impl UserCache {
 /// This cache stores data received from Terra webhooks and makes it available
 /// to the `TerraProvider` for `FitnessProvider` trait implementation.
 pub struct TerraDataCache {
-    config: TerraDataCacheConfig,
-    /// User data cache keyed by Terra user ID
-    users: Arc<RwLock<HashMap<String, UserCache>>>,
+    config: TerraCacheConfig,
+    /// User data cache keyed by Terra user ID, bounded by `max_users`
+    users: Arc<RwLock<LruCache<String, UserCache>>>,
     /// Mapping from `reference_id` to `terra_user_id`
     reference_map: Arc<RwLock<HashMap<String, String>>>,
+    clock: Arc<dyn CacheClock>,
+    counters: CacheCounters,
 }
 
 impl TerraDataCache {
+    /// User capacity used when the configuration specifies zero users
+    const DEFAULT_USER_CAPACITY: NonZeroUsize = match NonZeroUsize::new(DEFAULT_CAPACITY) {
+        Some(n) => n,
+        None => NonZeroUsize::MIN,
+    };
+
     /// Create a new in-memory Terra data cache
     #[must_use]
     pub fn new_in_memory() -> Self {
-        Self {
-            config: TerraDataCacheConfig::default(),
-            users: Arc::new(RwLock::new(HashMap::new())),
-            reference_map: Arc::new(RwLock::new(HashMap::new())),
-        }
+        Self::with_config(TerraCacheConfig::default())
     }
 
     /// Create a new cache with custom configuration
     #[must_use]
-    pub fn with_config(config: TerraDataCacheConfig) -> Self {
+    pub fn with_config(config: TerraCacheConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Create a new cache with custom configuration and clock
+    #[must_use]
+    pub fn with_clock(config: TerraCacheConfig, clock: Arc<dyn CacheClock>) -> Self {
+        let capacity = NonZeroUsize::new(config.max_users).unwrap_or(Self::DEFAULT_USER_CAPACITY);
         Self {
             config,
-            users: Arc::new(RwLock::new(HashMap::new())),
+            users: Arc::new(RwLock::new(LruCache::new(capacity))),
             reference_map: Arc::new(RwLock::new(HashMap::new())),
+            clock,
+            counters: CacheCounters::default(),
         }
     }
 
+    /// Time-to-live applied to newly cached entries
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.config.ttl
+    }
+
     /// Register a mapping from `reference_id` to `terra_user_id`
     pub async fn register_user_mapping(&self, reference_id: &str, terra_user_id: &str) {
         let mut map = self.reference_map.write().await;
@@ -206,15 +306,78 @@ impl TerraDataCache {
         map.get(reference_id).cloned()
     }
 
-    /// Store an activity in the cache
-    pub async fn store_activity(&self, terra_user_id: &str, activity: Activity) {
-        let max_items = self.config.max_items_per_type;
-        self.users
+    /// Run `store` against a user's cache, creating it and evicting the LRU user if needed
+    async fn with_user_mut(
+        &self,
+        terra_user_id: &str,
+        store: impl FnOnce(&mut UserCache, DateTime<Utc>, usize),
+    ) {
+        let now = self.clock.now();
+        let expires_at = now + self.config.ttl;
+        let mut users = self.users.write().await;
+
+        if !users.contains(terra_user_id)
+            && users.len() >= users.cap().get()
+            && users.pop_lru().is_some()
+        {
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let user_cache = users.get_or_insert_mut(terra_user_id.to_owned(), UserCache::default);
+        user_cache.refreshed_at = Some(now);
+        store(user_cache, expires_at, self.config.max_items_per_type);
+    }
+
+    /// Read from a user's live cache, counting the lookup as a hit when it yields data
+    async fn read_user<T>(
+        &self,
+        terra_user_id: &str,
+        read: impl FnOnce(&UserCache, DateTime<Utc>) -> T,
+        has_data: impl FnOnce(&T) -> bool,
+    ) -> Option<T> {
+        let now = self.clock.now();
+        // Lookups refresh the user's LRU position, so they need the write lock
+        let result = self
+            .users
             .write()
             .await
-            .entry(terra_user_id.to_owned())
-            .or_default()
-            .add_activity(activity, max_items);
+            .get(terra_user_id)
+            .map(|cache| read(cache, now));
+
+        let counter = if result.as_ref().is_some_and(has_data) {
+            &self.counters.hits
+        } else {
+            &self.counters.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Whether the user's data is older than the TTL and should be re-fetched from Terra
+    ///
+    /// Data is considered fresh for one TTL after it last arrived via webhook or
+    /// after a re-fetch was last requested with [`Self::mark_refresh_requested`].
+    pub async fn needs_refresh(&self, terra_user_id: &str) -> bool {
+        let now = self.clock.now();
+        self.users
+            .read()
+            .await
+            .peek(terra_user_id)
+            .and_then(|cache| cache.refreshed_at)
+            .is_none_or(|refreshed_at| now >= refreshed_at + self.config.ttl)
+    }
+
+    /// Record that a re-fetch from Terra was requested for the user
+    pub async fn mark_refresh_requested(&self, terra_user_id: &str) {
+        self.with_user_mut(terra_user_id, |_, _, _| {}).await;
+    }
+
+    /// Store an activity in the cache
+    pub async fn store_activity(&self, terra_user_id: &str, activity: Activity) {
+        self.with_user_mut(terra_user_id, |cache, expires_at, max_items| {
+            cache.add_activity(activity, expires_at, max_items);
+        })
+        .await;
     }
 
     /// Store multiple activities in the cache
@@ -231,17 +394,21 @@ impl TerraDataCache {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Vec<Activity> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        let Some(mut activities) = self.users.read().await.get(terra_user_id).map(|cache| {
-            cache
-                .activities
-                .iter()
-                .filter(|e| !e.is_expired(ttl))
-                .map(|e| e.data.clone())
-                .collect::<Vec<_>>()
-        }) else {
+        let Some(mut activities) = self
+            .read_user(
+                terra_user_id,
+                |cache, now| {
+                    cache
+                        .activities
+                        .iter()
+                        .filter(|e| !e.is_expired(now))
+                        .map(|e| e.data.clone())
+                        .collect::<Vec<_>>()
+                },
+                |activities| !activities.is_empty(),
+            )
+            .await
+        else {
             return Vec::new();
         };
 
@@ -254,28 +421,27 @@ impl TerraDataCache {
 
     /// Get a specific activity by ID
     pub async fn get_activity(&self, terra_user_id: &str, activity_id: &str) -> Option<Activity> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        self.users
-            .read()
-            .await
-            .get(terra_user_id)?
-            .activities
-            .iter()
-            .find(|e| e.data.id() == activity_id && !e.is_expired(ttl))
-            .map(|e| e.data.clone())
+        self.read_user(
+            terra_user_id,
+            |cache, now| {
+                cache
+                    .activities
+                    .iter()
+                    .find(|e| e.data.id() == activity_id && !e.is_expired(now))
+                    .map(|e| e.data.clone())
+            },
+            Option::is_some,
+        )
+        .await
+        .flatten()
     }
 
     /// Store a sleep session in the cache
     pub async fn store_sleep_session(&self, terra_user_id: &str, sleep: SleepSession) {
-        let max_items = self.config.max_items_per_type;
-        self.users
-            .write()
-            .await
-            .entry(terra_user_id.to_owned())
-            .or_default()
-            .add_sleep_session(sleep, max_items);
+        self.with_user_mut(terra_user_id, |cache, expires_at, max_items| {
+            cache.add_sleep_session(sleep, expires_at, max_items);
+        })
+        .await;
     }
 
     /// Get sleep sessions for a date range
@@ -285,21 +451,25 @@ impl TerraDataCache {
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
     ) -> Vec<SleepSession> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        let Some(mut sessions) = self.users.read().await.get(terra_user_id).map(|cache| {
-            cache
-                .sleep_sessions
-                .iter()
-                .filter(|e| {
-                    !e.is_expired(ttl)
-                        && e.data.start_time >= start_date
-                        && e.data.start_time <= end_date
-                })
-                .map(|e| e.data.clone())
-                .collect::<Vec<_>>()
-        }) else {
+        let Some(mut sessions) = self
+            .read_user(
+                terra_user_id,
+                |cache, now| {
+                    cache
+                        .sleep_sessions
+                        .iter()
+                        .filter(|e| {
+                            !e.is_expired(now)
+                                && e.data.start_time >= start_date
+                                && e.data.start_time <= end_date
+                        })
+                        .map(|e| e.data.clone())
+                        .collect::<Vec<_>>()
+                },
+                |sessions| !sessions.is_empty(),
+            )
+            .await
+        else {
             return Vec::new();
         };
 
@@ -309,29 +479,28 @@ impl TerraDataCache {
 
     /// Get the latest sleep session
     pub async fn get_latest_sleep_session(&self, terra_user_id: &str) -> Option<SleepSession> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        self.users
-            .read()
-            .await
-            .get(terra_user_id)?
-            .sleep_sessions
-            .iter()
-            .filter(|e| !e.is_expired(ttl))
-            .max_by_key(|e| e.data.start_time)
-            .map(|e| e.data.clone())
+        self.read_user(
+            terra_user_id,
+            |cache, now| {
+                cache
+                    .sleep_sessions
+                    .iter()
+                    .filter(|e| !e.is_expired(now))
+                    .max_by_key(|e| e.data.start_time)
+                    .map(|e| e.data.clone())
+            },
+            Option::is_some,
+        )
+        .await
+        .flatten()
     }
 
     /// Store health metrics in the cache
     pub async fn store_health_metrics(&self, terra_user_id: &str, health: HealthMetrics) {
-        let max_items = self.config.max_items_per_type;
-        self.users
-            .write()
-            .await
-            .entry(terra_user_id.to_owned())
-            .or_default()
-            .add_health_metrics(health, max_items);
+        self.with_user_mut(terra_user_id, |cache, expires_at, max_items| {
+            cache.add_health_metrics(health, expires_at, max_items);
+        })
+        .await;
     }
 
     /// Get health metrics for a date range
@@ -341,19 +510,25 @@ impl TerraDataCache {
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
     ) -> Vec<HealthMetrics> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        let Some(mut metrics) = self.users.read().await.get(terra_user_id).map(|cache| {
-            cache
-                .health_metrics
-                .iter()
-                .filter(|e| {
-                    !e.is_expired(ttl) && e.data.date >= start_date && e.data.date <= end_date
-                })
-                .map(|e| e.data.clone())
-                .collect::<Vec<_>>()
-        }) else {
+        let Some(mut metrics) = self
+            .read_user(
+                terra_user_id,
+                |cache, now| {
+                    cache
+                        .health_metrics
+                        .iter()
+                        .filter(|e| {
+                            !e.is_expired(now)
+                                && e.data.date >= start_date
+                                && e.data.date <= end_date
+                        })
+                        .map(|e| e.data.clone())
+                        .collect::<Vec<_>>()
+                },
+                |metrics| !metrics.is_empty(),
+            )
+            .await
+        else {
             return Vec::new();
         };
 
@@ -363,13 +538,10 @@ impl TerraDataCache {
 
     /// Store recovery metrics in the cache
     pub async fn store_recovery_metrics(&self, terra_user_id: &str, recovery: RecoveryMetrics) {
-        let max_items = self.config.max_items_per_type;
-        self.users
-            .write()
-            .await
-            .entry(terra_user_id.to_owned())
-            .or_default()
-            .add_recovery_metrics(recovery, max_items);
+        self.with_user_mut(terra_user_id, |cache, expires_at, max_items| {
+            cache.add_recovery_metrics(recovery, expires_at, max_items);
+        })
+        .await;
     }
 
     /// Get recovery metrics for a date range
@@ -379,19 +551,25 @@ impl TerraDataCache {
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
     ) -> Vec<RecoveryMetrics> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        let Some(mut metrics) = self.users.read().await.get(terra_user_id).map(|cache| {
-            cache
-                .recovery_metrics
-                .iter()
-                .filter(|e| {
-                    !e.is_expired(ttl) && e.data.date >= start_date && e.data.date <= end_date
-                })
-                .map(|e| e.data.clone())
-                .collect::<Vec<_>>()
-        }) else {
+        let Some(mut metrics) = self
+            .read_user(
+                terra_user_id,
+                |cache, now| {
+                    cache
+                        .recovery_metrics
+                        .iter()
+                        .filter(|e| {
+                            !e.is_expired(now)
+                                && e.data.date >= start_date
+                                && e.data.date <= end_date
+                        })
+                        .map(|e| e.data.clone())
+                        .collect::<Vec<_>>()
+                },
+                |metrics| !metrics.is_empty(),
+            )
+            .await
+        else {
             return Vec::new();
         };
 
@@ -401,13 +579,10 @@ impl TerraDataCache {
 
     /// Store nutrition log in the cache
     pub async fn store_nutrition_log(&self, terra_user_id: &str, nutrition: NutritionLog) {
-        let max_items = self.config.max_items_per_type;
-        self.users
-            .write()
-            .await
-            .entry(terra_user_id.to_owned())
-            .or_default()
-            .add_nutrition_log(nutrition, max_items);
+        self.with_user_mut(terra_user_id, |cache, expires_at, max_items| {
+            cache.add_nutrition_log(nutrition, expires_at, max_items);
+        })
+        .await;
     }
 
     /// Get nutrition logs for a date range
@@ -417,19 +592,25 @@ impl TerraDataCache {
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
     ) -> Vec<NutritionLog> {
-        let ttl = self.config.ttl_days;
-
-        // Chain directly to avoid holding lock guard in named variable
-        let Some(mut logs) = self.users.read().await.get(terra_user_id).map(|cache| {
-            cache
-                .nutrition_logs
-                .iter()
-                .filter(|e| {
-                    !e.is_expired(ttl) && e.data.date >= start_date && e.data.date <= end_date
-                })
-                .map(|e| e.data.clone())
-                .collect::<Vec<_>>()
-        }) else {
+        let Some(mut logs) = self
+            .read_user(
+                terra_user_id,
+                |cache, now| {
+                    cache
+                        .nutrition_logs
+                        .iter()
+                        .filter(|e| {
+                            !e.is_expired(now)
+                                && e.data.date >= start_date
+                                && e.data.date <= end_date
+                        })
+                        .map(|e| e.data.clone())
+                        .collect::<Vec<_>>()
+                },
+                |logs| !logs.is_empty(),
+            )
+            .await
+        else {
             return Vec::new();
         };
 
@@ -439,25 +620,21 @@ impl TerraDataCache {
 
     /// Clean up expired entries from the cache
     pub async fn cleanup_expired(&self) {
+        let now = self.clock.now();
         let mut users = self.users.write().await;
-        let ttl = self.config.ttl_days;
-
-        for user_cache in users.values_mut() {
-            user_cache.activities.retain(|e| !e.is_expired(ttl));
-            user_cache.sleep_sessions.retain(|e| !e.is_expired(ttl));
-            user_cache.health_metrics.retain(|e| !e.is_expired(ttl));
-            user_cache.recovery_metrics.retain(|e| !e.is_expired(ttl));
-            user_cache.nutrition_logs.retain(|e| !e.is_expired(ttl));
-        }
 
-        // Remove empty user caches
-        users.retain(|_, cache| {
-            !cache.activities.is_empty()
-                || !cache.sleep_sessions.is_empty()
-                || !cache.health_metrics.is_empty()
-                || !cache.recovery_metrics.is_empty()
-                || !cache.nutrition_logs.is_empty()
-        });
+        // Collect emptied users first (can't remove while iterating)
+        let empty_users: Vec<String> = users
+            .iter_mut()
+            .filter_map(|(user_id, cache)| {
+                cache.remove_expired(now);
+                cache.is_empty().then(|| user_id.clone())
+            })
+            .collect();
+
+        for user_id in &empty_users {
+            users.pop(user_id);
+        }
     }
 
     /// Get cache statistics for monitoring
@@ -470,7 +647,7 @@ impl TerraDataCache {
         let mut total_recovery_metrics = 0;
         let mut total_nutrition_logs = 0;
 
-        for cache in users.values() {
+        for (_, cache) in users.iter() {
             total_activities += cache.activities.len();
             total_sleep_sessions += cache.sleep_sessions.len();
             total_health_metrics += cache.health_metrics.len();
@@ -487,6 +664,16 @@ impl TerraDataCache {
             total_nutrition_logs,
         }
     }
+
+    /// Lookup and eviction counters since the cache was created
+    #[must_use]
+    pub fn stats(&self) -> TerraCacheMetrics {
+        TerraCacheMetrics {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+        }
+    }
 }
 
 /// Cache statistics for monitoring
@@ -505,3 +692,14 @@ pub struct CacheStats {
     /// Total cached nutrition logs
     pub total_nutrition_logs: usize,
 }
+
+/// Cache lookup and eviction counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerraCacheMetrics {
+    /// Lookups that returned cached data
+    pub hits: u64,
+    /// Lookups that returned no cached data
+    pub misses: u64,
+    /// Users evicted to stay within `max_users`
+    pub evictions: u64,
+}
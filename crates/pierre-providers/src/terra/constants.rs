@@ -26,6 +26,9 @@ pub const TERRA_TOKEN_URL: &str = "https://api.tryterra.co/v2/auth/token";
 /// URL for deauthenticating users
 pub const TERRA_DEAUTH_URL: &str = "https://api.tryterra.co/v2/auth/deauthenticateUser";
 
+/// Data types re-requested from Terra when a user's cached data goes stale
+pub const TERRA_REFRESH_DATA_TYPES: &[&str] = &["activity", "sleep", "body", "daily"];
+
 // =============================================================================
 // Sleep Stage Types
 // Reference: Terra Sleep Data Schema
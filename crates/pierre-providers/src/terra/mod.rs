@@ -87,7 +87,7 @@ pub mod models;
 pub mod webhook;
 
 pub use api_client::{TerraApiClient, TerraApiConfig};
-pub use cache::TerraDataCache;
+pub use cache::{CacheClock, SystemClock, TerraCacheConfig, TerraCacheMetrics, TerraDataCache};
 pub use converters::TerraConverters;
 pub use models::{
     TerraActivity, TerraAthlete, TerraBody, TerraDaily, TerraNutrition, TerraSleep,
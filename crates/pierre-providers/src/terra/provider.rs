@@ -18,10 +18,11 @@ use chrono::{DateTime, Utc};
 use std::cmp::Reverse;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::instrument;
+use tracing::{debug, instrument, warn};
 
 use crate::core::{
     ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderFactory,
+    ProviderValidationMode,
 };
 use crate::errors::provider::ProviderError;
 use crate::errors::AppResult;
@@ -31,10 +32,11 @@ use crate::models::{
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
 use crate::spi::{OAuthEndpoints, OAuthParams, ProviderCapabilities, ProviderDescriptor};
 
-use super::api_client::{TerraApiClient, TerraApiConfig};
+use super::api_client::{HistoricalDataRequest, TerraApiClient, TerraApiConfig};
 use super::cache::TerraDataCache;
 use super::constants::{
-    TERRA_API_BASE_URL, TERRA_DEAUTH_URL, TERRA_TOKEN_URL, TERRA_WIDGET_SESSION_URL,
+    TERRA_API_BASE_URL, TERRA_DEAUTH_URL, TERRA_REFRESH_DATA_TYPES, TERRA_TOKEN_URL,
+    TERRA_WIDGET_SESSION_URL,
 };
 
 /// Terra provider for accessing fitness data from 150+ wearables
@@ -68,6 +70,8 @@ impl TerraProvider {
                     "daily".to_owned(),
                     "nutrition".to_owned(),
                 ],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
             },
             credentials: RwLock::new(None),
             cache,
@@ -106,6 +110,8 @@ impl TerraProvider {
                     "daily".to_owned(),
                     "nutrition".to_owned(),
                 ],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
             },
             credentials: RwLock::new(None),
             cache,
@@ -129,6 +135,37 @@ impl TerraProvider {
                 reason: "Terra user ID not set. Call set_terra_user_id() first.".to_owned(),
             })
     }
+
+    /// Ask Terra to re-send the user's recent data once the cached copy is older than the cache TTL
+    ///
+    /// Terra delivers the requested data asynchronously through the webhook,
+    /// which repopulates the cache. Without an API client nothing is re-fetched.
+    async fn refresh_if_stale(&self, user_id: &str) {
+        let Some(ref client) = self.api_client else {
+            return;
+        };
+        if !self.cache.needs_refresh(user_id).await {
+            return;
+        }
+        // Mark before requesting so concurrent reads don't trigger duplicate requests
+        self.cache.mark_refresh_requested(user_id).await;
+
+        let end_date = Utc::now();
+        let start_date = end_date - self.cache.ttl();
+        for data_type in TERRA_REFRESH_DATA_TYPES {
+            let request = HistoricalDataRequest {
+                user_id: user_id.to_owned(),
+                start_date,
+                end_date,
+                to_webhook: true,
+                data_types: vec![(*data_type).to_owned()],
+            };
+            if let Err(e) = client.request_historical_data(&request).await {
+                warn!(data_type, error = %e, "Failed to request Terra data refresh");
+            }
+        }
+        debug!("Requested Terra data refresh for stale cache");
+    }
 }
 
 #[async_trait]
@@ -206,21 +243,20 @@ impl FitnessProvider for TerraProvider {
         params: &ActivityQueryParams,
     ) -> AppResult<Vec<Activity>> {
         let user_id = self.get_user_id().await?;
+        self.refresh_if_stale(&user_id).await;
         let mut activities = self
             .cache
             .get_activities(&user_id, params.limit, params.offset)
             .await;
 
-        // Apply time filtering if before/after specified
-        if let Some(after_ts) = params.after {
-            if let Some(after_dt) = chrono::DateTime::from_timestamp(after_ts, 0) {
-                activities.retain(|a| a.start_date() >= after_dt);
-            }
-        }
-        if let Some(before_ts) = params.before {
-            if let Some(before_dt) = chrono::DateTime::from_timestamp(before_ts, 0) {
-                activities.retain(|a| a.start_date() < before_dt);
-            }
+        // Terra data is pushed into a local cache, so time filtering happens client-side
+        if params.has_time_range() {
+            let removed = params.retain_in_range(&mut activities);
+            debug!(
+                removed,
+                remaining = activities.len(),
+                "Terra activities filtered client-side by before/after bounds"
+            );
         }
 
         Ok(activities)
@@ -231,6 +267,7 @@ impl FitnessProvider for TerraProvider {
         params: &PaginationParams,
     ) -> AppResult<CursorPage<Activity>> {
         let user_id = self.get_user_id().await?;
+        self.refresh_if_stale(&user_id).await;
 
         // Get all activities and sort by start_date descending
         let mut activities = self.cache.get_activities(&user_id, None, None).await;
@@ -325,6 +362,7 @@ impl FitnessProvider for TerraProvider {
         end_date: DateTime<Utc>,
     ) -> Result<Vec<SleepSession>, ProviderError> {
         let user_id = self.get_user_id().await?;
+        self.refresh_if_stale(&user_id).await;
         let sessions = self
             .cache
             .get_sleep_sessions(&user_id, start_date, end_date)
@@ -359,6 +397,7 @@ impl FitnessProvider for TerraProvider {
         end_date: DateTime<Utc>,
     ) -> Result<Vec<RecoveryMetrics>, ProviderError> {
         let user_id = self.get_user_id().await?;
+        self.refresh_if_stale(&user_id).await;
         let metrics = self
             .cache
             .get_recovery_metrics(&user_id, start_date, end_date)
@@ -376,6 +415,7 @@ impl FitnessProvider for TerraProvider {
         end_date: DateTime<Utc>,
     ) -> Result<Vec<HealthMetrics>, ProviderError> {
         let user_id = self.get_user_id().await?;
+        self.refresh_if_stale(&user_id).await;
         let metrics = self
             .cache
             .get_health_metrics(&user_id, start_date, end_date)
@@ -412,8 +452,8 @@ impl ProviderDescriptor for TerraDescriptor {
     }
 
     fn capabilities(&self) -> ProviderCapabilities {
-        // Terra supports all data types through its unified API
-        ProviderCapabilities::full_health()
+        // Terra supports all data types through its unified API, pushed by webhook
+        ProviderCapabilities::full_health().union(ProviderCapabilities::WEBHOOKS)
     }
 
     fn oauth_endpoints(&self) -> Option<OAuthEndpoints> {
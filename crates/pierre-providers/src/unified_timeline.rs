@@ -0,0 +1,361 @@
+// ABOUTME: Merges the same activity recorded by several providers into one canonical timeline entry
+// ABOUTME: Chooses which provider supplies each metric group from per-user provider priority settings
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Unified Activity Timeline
+//!
+//! A workout recorded on a watch is often synced to several services, so the
+//! same activity arrives from Garmin, Strava and others. This module groups
+//! those copies and builds one canonical record per workout. Each metric group
+//! (heart rate, power, segments, ...) is taken from the highest-priority
+//! provider that reported it, following the user's [`ProviderPriority`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pierre_mcp_server::providers::unified_timeline::{
+//!     MetricCategory, ProviderPriority, UnifiedTimeline,
+//! };
+//!
+//! let priority = ProviderPriority::default()
+//!     .prefer(MetricCategory::HeartRate, &["garmin"])
+//!     .prefer(MetricCategory::Segments, &["strava"]);
+//! let timeline = UnifiedTimeline::build(Vec::new(), &priority);
+//! assert!(timeline.is_empty());
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::activity_import::is_same_recording;
+use crate::constants::oauth_providers;
+use crate::models::{Activity, ActivityBuilder};
+use crate::profile_aggregation::default_provider_precedence;
+
+/// Default start time difference still treated as the same workout (seconds)
+const DEFAULT_MATCH_START_TOLERANCE_SECONDS: i64 = 120;
+
+/// Default relative duration difference still treated as the same workout (percent)
+const DEFAULT_MATCH_DURATION_TOLERANCE_PERCENT: f64 = 10.0;
+
+/// Group of activity fields that is always taken from a single provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricCategory {
+    /// Identity, duration, distance, elevation, speed, location, weather and training load
+    Summary,
+    /// Average/max heart rate, heart rate zones, HRV and recovery heart rate
+    HeartRate,
+    /// Average/max/normalized power, power zones, FTP and running power
+    Power,
+    /// Average and max cadence
+    Cadence,
+    /// Calories burned
+    Calories,
+    /// Segment efforts
+    Segments,
+    /// Per-second time series (HR, power, GPS, ...)
+    Streams,
+}
+
+impl MetricCategory {
+    /// Every category, in the order they are resolved
+    pub const ALL: [Self; 7] = [
+        Self::Summary,
+        Self::HeartRate,
+        Self::Power,
+        Self::Cadence,
+        Self::Calories,
+        Self::Segments,
+        Self::Streams,
+    ];
+
+    /// Whether an activity carries data for this category
+    fn is_reported_by(self, activity: &Activity) -> bool {
+        match self {
+            Self::Summary => true,
+            Self::HeartRate => {
+                activity.average_heart_rate().is_some() || activity.max_heart_rate().is_some()
+            }
+            Self::Power => activity.average_power().is_some() || activity.max_power().is_some(),
+            Self::Cadence => {
+                activity.average_cadence().is_some() || activity.max_cadence().is_some()
+            }
+            Self::Calories => activity.calories().is_some(),
+            Self::Segments => activity.segment_efforts().is_some_and(|s| !s.is_empty()),
+            Self::Streams => activity.time_series_data().is_some(),
+        }
+    }
+}
+
+impl fmt::Display for MetricCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Summary => write!(f, "summary"),
+            Self::HeartRate => write!(f, "heart_rate"),
+            Self::Power => write!(f, "power"),
+            Self::Cadence => write!(f, "cadence"),
+            Self::Calories => write!(f, "calories"),
+            Self::Segments => write!(f, "segments"),
+            Self::Streams => write!(f, "streams"),
+        }
+    }
+}
+
+/// Per-user preference for which provider's metrics win in the unified timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderPriority {
+    /// Providers in order of trust for every category, highest first
+    #[serde(default = "default_provider_precedence")]
+    pub default_order: Vec<String>,
+    /// Category-specific orders that take precedence over `default_order`
+    #[serde(default = "default_metric_overrides")]
+    pub metric_overrides: BTreeMap<MetricCategory, Vec<String>>,
+    /// Start times closer than this are treated as the same workout (seconds)
+    #[serde(default = "default_match_start_tolerance_seconds")]
+    pub match_start_tolerance_seconds: i64,
+    /// Durations closer than this are treated as the same workout (percent)
+    #[serde(default = "default_match_duration_tolerance_percent")]
+    pub match_duration_tolerance_percent: f64,
+}
+
+/// Strava is the only provider with segment leaderboards
+fn default_metric_overrides() -> BTreeMap<MetricCategory, Vec<String>> {
+    BTreeMap::from([(
+        MetricCategory::Segments,
+        vec![oauth_providers::STRAVA.to_owned()],
+    )])
+}
+
+const fn default_match_start_tolerance_seconds() -> i64 {
+    DEFAULT_MATCH_START_TOLERANCE_SECONDS
+}
+
+const fn default_match_duration_tolerance_percent() -> f64 {
+    DEFAULT_MATCH_DURATION_TOLERANCE_PERCENT
+}
+
+impl Default for ProviderPriority {
+    fn default() -> Self {
+        Self {
+            default_order: default_provider_precedence(),
+            metric_overrides: default_metric_overrides(),
+            match_start_tolerance_seconds: default_match_start_tolerance_seconds(),
+            match_duration_tolerance_percent: default_match_duration_tolerance_percent(),
+        }
+    }
+}
+
+impl ProviderPriority {
+    /// Prefer `providers` (highest first) for one category
+    #[must_use]
+    pub fn prefer(mut self, category: MetricCategory, providers: &[&str]) -> Self {
+        self.metric_overrides.insert(
+            category,
+            providers.iter().map(|p| (*p).to_owned()).collect(),
+        );
+        self
+    }
+
+    /// Position of a provider for a category (lower wins)
+    ///
+    /// Providers missing from the category override fall back to their
+    /// position in `default_order`, after every overridden provider.
+    fn rank(&self, category: MetricCategory, provider: &str) -> (usize, usize) {
+        let position =
+            |order: &[String]| order.iter().position(|p| p.eq_ignore_ascii_case(provider));
+        self.metric_overrides
+            .get(&category)
+            .and_then(|order| position(order))
+            .map_or_else(
+                || (1, position(&self.default_order).unwrap_or(usize::MAX)),
+                |index| (0, index),
+            )
+    }
+}
+
+/// One workout in the unified timeline, merged from every provider that recorded it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CanonicalActivity {
+    /// Merged record with each metric group taken from its winning provider
+    pub activity: Activity,
+    /// Provider that supplied each metric group present on the record
+    pub metric_sources: BTreeMap<MetricCategory, String>,
+    /// Every provider copy that was merged, in priority order for the summary
+    pub sources: Vec<Activity>,
+}
+
+impl CanonicalActivity {
+    /// Provider whose values were used for a metric group
+    #[must_use]
+    pub fn source_for(&self, category: MetricCategory) -> Option<&str> {
+        self.metric_sources.get(&category).map(String::as_str)
+    }
+
+    /// Providers that recorded this workout
+    pub fn providers(&self) -> impl Iterator<Item = &str> {
+        self.sources.iter().map(Activity::provider)
+    }
+
+    /// Merge copies of the same workout according to `priority`
+    fn merge(mut copies: Vec<Activity>, priority: &ProviderPriority) -> Self {
+        copies.sort_by_key(|a| priority.rank(MetricCategory::Summary, a.provider()));
+
+        let metric_sources: BTreeMap<MetricCategory, usize> = MetricCategory::ALL
+            .into_iter()
+            .filter_map(|category| {
+                copies
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, a)| category.is_reported_by(a))
+                    .min_by_key(|(_, a)| priority.rank(category, a.provider()))
+                    .map(|(index, _)| (category, index))
+            })
+            .collect();
+        let source = |category| &copies[metric_sources.get(&category).copied().unwrap_or(0)];
+
+        let activity = build_canonical(
+            source(MetricCategory::Summary),
+            source(MetricCategory::HeartRate),
+            source(MetricCategory::Power),
+            source(MetricCategory::Cadence),
+            source(MetricCategory::Calories),
+            source(MetricCategory::Segments),
+            source(MetricCategory::Streams),
+        );
+        let metric_sources = metric_sources
+            .into_iter()
+            .map(|(category, index)| (category, copies[index].provider().to_owned()))
+            .collect();
+
+        Self {
+            activity,
+            metric_sources,
+            sources: copies,
+        }
+    }
+}
+
+/// Activities from every connected provider with duplicates merged, newest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnifiedTimeline {
+    /// Canonical record per workout
+    pub activities: Vec<CanonicalActivity>,
+}
+
+impl UnifiedTimeline {
+    /// Group copies of the same workout and merge each group by provider priority
+    ///
+    /// Two activities are copies when they come from different providers, share a
+    /// sport, and start and last within the priority's matching tolerances.
+    #[must_use]
+    pub fn build(mut activities: Vec<Activity>, priority: &ProviderPriority) -> Self {
+        activities.sort_by_key(Activity::start_date);
+
+        let mut groups: Vec<Vec<Activity>> = Vec::new();
+        for activity in activities {
+            let group = groups.iter_mut().rev().find(|group| {
+                group.iter().all(|a| a.provider() != activity.provider())
+                    && group.iter().any(|a| {
+                        is_same_recording(
+                            a,
+                            &activity,
+                            priority.match_start_tolerance_seconds,
+                            priority.match_duration_tolerance_percent,
+                        )
+                    })
+            });
+            match group {
+                Some(group) => group.push(activity),
+                None => groups.push(vec![activity]),
+            }
+        }
+
+        let mut activities: Vec<CanonicalActivity> = groups
+            .into_iter()
+            .map(|copies| CanonicalActivity::merge(copies, priority))
+            .collect();
+        activities.reverse();
+        Self { activities }
+    }
+
+    /// Number of distinct workouts
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.activities.len()
+    }
+
+    /// Whether the timeline has no workouts
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.activities.is_empty()
+    }
+}
+
+/// Assemble the canonical record from the winning copy of each metric group
+fn build_canonical(
+    summary: &Activity,
+    heart_rate: &Activity,
+    power: &Activity,
+    cadence: &Activity,
+    calories: &Activity,
+    segments: &Activity,
+    streams: &Activity,
+) -> Activity {
+    ActivityBuilder::new(
+        summary.id(),
+        summary.name(),
+        summary.sport_type().clone(),
+        summary.start_date(),
+        summary.duration_seconds(),
+        summary.provider(),
+    )
+    .distance_meters_opt(summary.distance_meters())
+    .elevation_gain_opt(summary.elevation_gain())
+    .average_speed_opt(summary.average_speed())
+    .max_speed_opt(summary.max_speed())
+    .steps_opt(summary.steps())
+    .temperature_opt(summary.temperature())
+    .humidity_opt(summary.humidity())
+    .average_altitude_opt(summary.average_altitude())
+    .wind_speed_opt(summary.wind_speed())
+    .ground_contact_time_opt(summary.ground_contact_time())
+    .vertical_oscillation_opt(summary.vertical_oscillation())
+    .stride_length_opt(summary.stride_length())
+    .breathing_rate_opt(summary.breathing_rate())
+    .spo2_opt(summary.spo2())
+    .training_stress_score_opt(summary.training_stress_score())
+    .intensity_factor_opt(summary.intensity_factor())
+    .suffer_score_opt(summary.suffer_score())
+    .start_timezone_opt(summary.start_timezone().map(str::to_owned))
+    .utc_offset_seconds_opt(summary.utc_offset_seconds())
+    .start_latitude_opt(summary.start_latitude())
+    .start_longitude_opt(summary.start_longitude())
+    .city_opt(summary.city().map(str::to_owned))
+    .region_opt(summary.region().map(str::to_owned))
+    .country_opt(summary.country().map(str::to_owned))
+    .trail_name_opt(summary.trail_name().map(str::to_owned))
+    .workout_type_opt(summary.workout_type())
+    .sport_type_detail_opt(summary.sport_type_detail().map(str::to_owned))
+    .average_heart_rate_opt(heart_rate.average_heart_rate())
+    .max_heart_rate_opt(heart_rate.max_heart_rate())
+    .heart_rate_zones_opt(heart_rate.heart_rate_zones().cloned())
+    .hrv_score_opt(heart_rate.hrv_score())
+    .recovery_heart_rate_opt(heart_rate.recovery_heart_rate())
+    .average_power_opt(power.average_power())
+    .max_power_opt(power.max_power())
+    .normalized_power_opt(power.normalized_power())
+    .power_zones_opt(power.power_zones().cloned())
+    .ftp_opt(power.ftp())
+    .running_power_opt(power.running_power())
+    .average_cadence_opt(cadence.average_cadence())
+    .max_cadence_opt(cadence.max_cadence())
+    .calories_opt(calories.calories())
+    .segment_efforts_opt(segments.segment_efforts().cloned())
+    .time_series_data_opt(streams.time_series_data().cloned())
+    .build()
+}
@@ -1,10 +1,12 @@
 // ABOUTME: Shared utilities for fitness provider implementations
-// ABOUTME: Type conversions, retry logic, token refresh, and common patterns
+// ABOUTME: Type conversions, retry logic, token refresh, activity deduplication, and common patterns
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use crate::errors::{AppError, AppResult};
+use crate::http_client;
+use crate::models::{Activity, TenantId};
 use chrono::{TimeZone, Utc};
 use rand::Rng;
 use reqwest::{Client, StatusCode};
@@ -15,8 +17,11 @@ use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
+use super::activity_import::is_same_recording;
 use super::core::OAuth2Credentials;
 use super::errors::provider::{ProviderError, ProviderResult};
+use super::profile_aggregation::default_provider_precedence;
+use super::retry_budget::RetryBudget;
 
 /// Configuration for retry behavior
 #[derive(Debug, Clone)]
@@ -87,6 +92,20 @@ pub mod conversions {
         }
         t.to_u32().map_or(u32::MAX, |v| v)
     }
+
+    /// Safely convert f64 to i32, clamping to valid range
+    /// Used for signed values such as UTC offsets in seconds
+    #[must_use]
+    pub fn f64_to_i32(value: f64) -> i32 {
+        if !value.is_finite() {
+            return 0;
+        }
+        value
+            .trunc()
+            .clamp(f64::from(i32::MIN), f64::from(i32::MAX))
+            .to_i32()
+            .unwrap_or(0)
+    }
 }
 
 /// Result of checking if a response should be retried
@@ -165,6 +184,8 @@ fn api_error(status: StatusCode, text: &str, provider_name: &str) -> AppError {
 
 /// Make an authenticated HTTP GET request with retry logic
 ///
+/// `request_timeout` overrides the shared client timeout for each attempt.
+///
 /// # Errors
 ///
 /// Returns an error if:
@@ -178,6 +199,7 @@ pub async fn api_request_with_retry<T>(
     access_token: &str,
     provider_name: &str,
     retry_config: &RetryConfig,
+    request_timeout: Option<Duration>,
 ) -> AppResult<T>
 where
     T: for<'de> Deserialize<'de>,
@@ -186,14 +208,16 @@ where
 
     let mut attempt = 0;
     loop {
-        let response = client
-            .get(url)
-            .header("Authorization", format!("Bearer {access_token}"))
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service(provider_name, format!("Failed to send request: {e}"))
-            })?;
+        let response = http_client::send_with_timeout(
+            client
+                .get(url)
+                .header("Authorization", format!("Bearer {access_token}")),
+            request_timeout,
+        )
+        .await
+        .map_err(|e| {
+            AppError::external_service(provider_name, format!("Failed to send request: {e}"))
+        })?;
 
         let status = response.status();
         info!("Received HTTP response with status: {status}");
@@ -240,6 +264,8 @@ pub struct TokenRefreshResponse {
 
 /// Refresh `OAuth2` access token using refresh token
 ///
+/// `request_timeout` overrides the shared client timeout for the token request.
+///
 /// # Errors
 ///
 /// Returns an error if:
@@ -253,6 +279,7 @@ pub async fn refresh_oauth_token(
     client_secret: &str,
     refresh_token: &str,
     provider_name: &str,
+    request_timeout: Option<Duration>,
 ) -> AppResult<OAuth2Credentials> {
     info!("Refreshing {provider_name} access token");
 
@@ -263,17 +290,15 @@ pub async fn refresh_oauth_token(
         ("refresh_token", refresh_token),
     ];
 
-    let response = client
-        .post(token_url)
-        .form(&params)
-        .send()
-        .await
-        .map_err(|e| {
-            AppError::external_service(
-                provider_name,
-                format!("Failed to send token refresh request: {e}"),
-            )
-        })?;
+    let response =
+        http_client::send_with_timeout(client.post(token_url).form(&params), request_timeout)
+            .await
+            .map_err(|e| {
+                AppError::external_service(
+                    provider_name,
+                    format!("Failed to send token refresh request: {e}"),
+                )
+            })?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -622,6 +647,57 @@ fn evaluate_retry_attempt<T>(
     }
 }
 
+/// Retry budget a retry loop draws from, and the tenant and provider it is charged to
+struct BudgetScope<'a> {
+    budget: &'a RetryBudget,
+    tenant_id: TenantId,
+    provider: &'a str,
+}
+
+/// Run `operation` until it succeeds, fails for good, or the budget refuses a retry
+async fn retry_loop<T, F, Fut>(
+    operation_name: &str,
+    config: &RetryBackoffConfig,
+    budget: Option<&BudgetScope<'_>>,
+    operation: F,
+) -> ProviderResult<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ProviderResult<T>>,
+{
+    let mut last_error: Option<ProviderError> = None;
+
+    for attempt in 0..=config.max_attempts {
+        let decision = evaluate_retry_attempt(operation().await, attempt, config, operation_name);
+
+        match decision {
+            RetryLoopDecision::Success(result) => return Ok(result),
+            RetryLoopDecision::Failure(err) => return Err(err),
+            RetryLoopDecision::Retry { delay, error } => {
+                if let Some(scope) = budget {
+                    if !scope.budget.try_acquire(scope.tenant_id, scope.provider) {
+                        warn!(
+                            tenant_id = %scope.tenant_id,
+                            provider = scope.provider,
+                            "Operation '{operation_name}' not retried, retry budget exhausted: {error}"
+                        );
+                        return Err(error);
+                    }
+                }
+                sleep(delay).await;
+                last_error = Some(error);
+            }
+        }
+    }
+
+    // This should be unreachable due to the loop logic, but handle it gracefully
+    Err(last_error.unwrap_or_else(|| {
+        ProviderError::NetworkError(format!(
+            "Operation '{operation_name}' failed: max retries exceeded"
+        ))
+    }))
+}
+
 /// Execute an async operation with automatic retry on retryable errors
 ///
 /// This function wraps any async operation that returns `ProviderResult<T>` and
@@ -671,27 +747,39 @@ where
     F: Fn() -> Fut,
     Fut: Future<Output = ProviderResult<T>>,
 {
-    let mut last_error: Option<ProviderError> = None;
-
-    for attempt in 0..=config.max_attempts {
-        let decision = evaluate_retry_attempt(operation().await, attempt, config, operation_name);
-
-        match decision {
-            RetryLoopDecision::Success(result) => return Ok(result),
-            RetryLoopDecision::Failure(err) => return Err(err),
-            RetryLoopDecision::Retry { delay, error } => {
-                sleep(delay).await;
-                last_error = Some(error);
-            }
-        }
-    }
+    retry_loop(operation_name, config, None, operation).await
+}
 
-    // This should be unreachable due to the loop logic, but handle it gracefully
-    Err(last_error.unwrap_or_else(|| {
-        ProviderError::NetworkError(format!(
-            "Operation '{operation_name}' failed: max retries exceeded"
-        ))
-    }))
+/// Execute an async operation with retry, drawing every retry from a shared budget
+///
+/// Behaves like [`with_retry`], except that each retry first takes a token
+/// from `budget` for `tenant_id` and `provider`. The first attempt is always
+/// made; when a retry finds the budget empty, the error that triggered it is
+/// returned straight away, so an outage cannot multiply the load on a failing
+/// provider across every concurrent call.
+///
+/// # Errors
+///
+/// Returns the last error if all retry attempts are exhausted, a non-retryable
+/// error is encountered, or the retry budget is empty.
+pub async fn with_budgeted_retry<T, F, Fut>(
+    operation_name: &str,
+    config: &RetryBackoffConfig,
+    budget: &RetryBudget,
+    tenant_id: TenantId,
+    provider: &str,
+    operation: F,
+) -> ProviderResult<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ProviderResult<T>>,
+{
+    let scope = BudgetScope {
+        budget,
+        tenant_id,
+        provider,
+    };
+    retry_loop(operation_name, config, Some(&scope), operation).await
 }
 
 /// Execute an async operation with retry, using default configuration
@@ -709,3 +797,171 @@ where
 {
     with_retry(operation_name, &RetryBackoffConfig::default(), operation).await
 }
+
+/// Environment variable name for the duplicate start-time window in seconds
+pub const ENV_DEDUP_WINDOW_SECS: &str = "PIERRE_DEDUP_WINDOW_SECS";
+/// Environment variable name for the comma-separated provider priority list
+pub const ENV_DEDUP_PROVIDER_PRIORITY: &str = "PIERRE_DEDUP_PROVIDER_PRIORITY";
+
+/// Default start time difference still treated as the same session (seconds)
+const DEFAULT_DEDUP_START_TOLERANCE_SECONDS: i64 = 90;
+
+/// Default relative duration difference still treated as the same session (percent)
+const DEFAULT_DEDUP_DURATION_TOLERANCE_PERCENT: f64 = 10.0;
+
+/// Default relative distance difference still treated as the same session (percent)
+const DEFAULT_DEDUP_DISTANCE_TOLERANCE_PERCENT: f64 = 5.0;
+
+/// Configuration for merging the same session synced through several providers
+#[derive(Debug, Clone)]
+pub struct DeduplicationConfig {
+    /// Start times closer than this are treated as the same session (seconds)
+    pub start_tolerance_seconds: i64,
+    /// Durations closer than this are treated as the same session (percent)
+    pub duration_tolerance_percent: f64,
+    /// Distances closer than this are treated as the same session (percent)
+    pub distance_tolerance_percent: f64,
+    /// Providers in order of preference, highest first; the winner's record is kept
+    pub provider_priority: Vec<String>,
+}
+
+impl Default for DeduplicationConfig {
+    fn default() -> Self {
+        Self {
+            start_tolerance_seconds: DEFAULT_DEDUP_START_TOLERANCE_SECONDS,
+            duration_tolerance_percent: DEFAULT_DEDUP_DURATION_TOLERANCE_PERCENT,
+            distance_tolerance_percent: DEFAULT_DEDUP_DISTANCE_TOLERANCE_PERCENT,
+            provider_priority: default_provider_precedence(),
+        }
+    }
+}
+
+impl DeduplicationConfig {
+    /// Create a deduplication config from environment variables
+    ///
+    /// Reads the following environment variables:
+    /// - `PIERRE_DEDUP_WINDOW_SECS`: Start time tolerance in seconds (default: 90)
+    /// - `PIERRE_DEDUP_PROVIDER_PRIORITY`: Comma-separated providers, most preferred first
+    ///   (default: garmin, coros, whoop, fitbit, strava, terra)
+    ///
+    /// Invalid values are logged as warnings and fall back to defaults.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let start_tolerance_seconds = i64::try_from(parse_env_u64(
+            ENV_DEDUP_WINDOW_SECS,
+            defaults.start_tolerance_seconds.unsigned_abs(),
+            0,
+            3600,
+        ))
+        .unwrap_or(defaults.start_tolerance_seconds);
+
+        let provider_priority = env::var(ENV_DEDUP_PROVIDER_PRIORITY)
+            .ok()
+            .map(|val| {
+                val.split(',')
+                    .map(|name| name.trim().to_lowercase())
+                    .filter(|name| !name.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|names| !names.is_empty())
+            .unwrap_or(defaults.provider_priority);
+
+        Self {
+            start_tolerance_seconds,
+            provider_priority,
+            ..defaults
+        }
+    }
+
+    /// Position of a provider in the priority list (lower wins, unknown providers last)
+    fn rank(&self, provider: &str) -> usize {
+        self.provider_priority
+            .iter()
+            .position(|p| p.eq_ignore_ascii_case(provider))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Whether two activities from different providers record the same session
+    ///
+    /// They must share a sport, start and last within the tolerances, and, when
+    /// both report a distance, cover nearly the same distance.
+    #[must_use]
+    pub fn is_duplicate(&self, activity: &Activity, candidate: &Activity) -> bool {
+        if activity
+            .provider()
+            .eq_ignore_ascii_case(candidate.provider())
+            || !is_same_recording(
+                activity,
+                candidate,
+                self.start_tolerance_seconds,
+                self.duration_tolerance_percent,
+            )
+        {
+            return false;
+        }
+
+        match (activity.distance_meters(), candidate.distance_meters()) {
+            (Some(a), Some(b)) if a > 0.0 && b > 0.0 => {
+                (a - b).abs() / a.max(b) * 100.0 <= self.distance_tolerance_percent
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Merge activities synced through several providers, using `DeduplicationConfig::from_env()`
+///
+/// Convenience wrapper around `deduplicate_activities_with_config`.
+#[must_use]
+pub fn deduplicate_activities(activities: Vec<Activity>) -> Vec<Activity> {
+    deduplicate_activities_with_config(activities, &DeduplicationConfig::from_env())
+}
+
+/// Merge activities synced through several providers into one record per session
+///
+/// Activities from the same provider are never merged, so genuinely distinct
+/// back-to-back sessions are kept. Each group of duplicates keeps the record from
+/// the most preferred provider and fills its missing fields from the other copies.
+/// Results are returned oldest first.
+#[must_use]
+pub fn deduplicate_activities_with_config(
+    mut activities: Vec<Activity>,
+    config: &DeduplicationConfig,
+) -> Vec<Activity> {
+    activities.sort_by_key(Activity::start_date);
+
+    let mut groups: Vec<Vec<Activity>> = Vec::new();
+    for activity in activities {
+        let group = groups.iter_mut().rev().find(|group| {
+            group
+                .iter()
+                .all(|member| config.is_duplicate(member, &activity))
+        });
+        match group {
+            Some(group) => group.push(activity),
+            None => groups.push(vec![activity]),
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter_map(|mut group| {
+            group.sort_by_key(|a| config.rank(a.provider()));
+            let mut copies = group.into_iter();
+            let mut kept = copies.next()?;
+            for copy in copies {
+                debug!(
+                    kept_provider = kept.provider(),
+                    kept_id = kept.id(),
+                    merged_provider = copy.provider(),
+                    merged_id = copy.id(),
+                    "Merged duplicate activity from another provider"
+                );
+                kept.fill_missing_from(&copy);
+            }
+            Some(kept)
+        })
+        .collect()
+}
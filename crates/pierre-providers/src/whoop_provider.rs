@@ -19,21 +19,26 @@
 )]
 
 use super::circuit_breaker::CircuitBreaker;
-use super::core::{ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig};
+use super::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use super::errors::provider::ProviderError;
 use crate::constants::oauth_providers;
 use crate::errors::{AppError, AppResult};
-use crate::http_client::shared_client;
+use crate::http_client::{self, shared_client};
+use crate::metrics::MetricsRegistry;
 use crate::models::{
     Activity, ActivityBuilder, Athlete, HealthMetrics, PersonalRecord, RecoveryMetrics,
     SleepSession, SleepStage, SleepStageType, SportType, Stats,
 };
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
+use crate::rate_limit_budget::{parse_standard_headers, BudgetObserver, ProviderBudget};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use std::fmt::Write;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -186,6 +191,7 @@ pub struct WhoopProvider {
     credentials: RwLock<Option<OAuth2Credentials>>,
     client: Client,
     circuit_breaker: CircuitBreaker,
+    rate_limit_budget: BudgetObserver,
 }
 
 impl WhoopProvider {
@@ -202,10 +208,13 @@ impl WhoopProvider {
                 .split(' ')
                 .map(str::to_owned)
                 .collect(),
+            request_timeout_secs: None,
+            validation_mode: ProviderValidationMode::Lenient,
         };
 
         Self {
             circuit_breaker: CircuitBreaker::new(oauth_providers::WHOOP),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
@@ -218,12 +227,33 @@ impl WhoopProvider {
         let provider_name = config.name.clone();
         Self {
             circuit_breaker: CircuitBreaker::new(&provider_name),
+            rate_limit_budget: BudgetObserver::default(),
             config,
             credentials: RwLock::new(None),
             client: shared_client().clone(),
         }
     }
 
+    /// Build the `activity/workout` endpoint for a query
+    ///
+    /// WHOOP supports server-side time filtering via ISO 8601 `start`/`end` parameters.
+    #[must_use]
+    pub fn workouts_endpoint(params: &ActivityQueryParams) -> String {
+        let page_limit = params.limit.unwrap_or(25).min(50);
+        let mut endpoint = format!("activity/workout?limit={page_limit}");
+        if let Some(after) = params.after {
+            let _ = write!(
+                endpoint,
+                "&start={}",
+                after.format("%Y-%m-%dT%H:%M:%S%.3fZ")
+            );
+        }
+        if let Some(before) = params.before {
+            let _ = write!(endpoint, "&end={}", before.format("%Y-%m-%dT%H:%M:%S%.3fZ"));
+        }
+        endpoint
+    }
+
     /// Retrieve the current access token from credentials
     async fn get_access_token(&self) -> AppResult<String> {
         let token = self
@@ -264,8 +294,15 @@ impl WhoopProvider {
             endpoint.trim_start_matches('/')
         );
 
+        let started = Instant::now();
         let result = self.execute_api_request(&url, &access_token).await;
 
+        MetricsRegistry::global().record_provider_call(
+            &self.config.name,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
         // Record success/failure for circuit breaker
         match &result {
             Ok(_) => self.circuit_breaker.record_success(),
@@ -280,16 +317,17 @@ impl WhoopProvider {
     where
         T: for<'de> Deserialize<'de>,
     {
-        let response = self
-            .client
-            .get(url)
-            .header("Authorization", format!("Bearer {access_token}"))
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service("WHOOP", format!("Failed to send request: {e}"))
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client
+                .get(url)
+                .header("Authorization", format!("Bearer {access_token}")),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| AppError::external_service("WHOOP", format!("Failed to send request: {e}")))?;
 
+        self.rate_limit_budget
+            .observe(parse_standard_headers(response.headers(), Utc::now()));
         let status = response.status();
         debug!("WHOOP API response status: {status}");
 
@@ -582,6 +620,10 @@ impl FitnessProvider for WhoopProvider {
         &self.config
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.rate_limit_budget.latest()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         info!("Setting WHOOP credentials");
         *self.credentials.write().await = Some(credentials);
@@ -651,18 +693,17 @@ impl FitnessProvider for WhoopProvider {
             ("refresh_token", &refresh_token),
         ];
 
-        let response = self
-            .client
-            .post(&self.config.token_url)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                AppError::external_service(
-                    "WHOOP",
-                    format!("Failed to send token refresh request: {e}"),
-                )
-            })?;
+        let response = http_client::send_with_timeout(
+            self.client.post(&self.config.token_url).form(&params),
+            self.config.request_timeout(),
+        )
+        .await
+        .map_err(|e| {
+            AppError::external_service(
+                "WHOOP",
+                format!("Failed to send token refresh request: {e}"),
+            )
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -722,8 +763,6 @@ impl FitnessProvider for WhoopProvider {
         &self,
         params: &ActivityQueryParams,
     ) -> AppResult<Vec<Activity>> {
-        let page_limit = params.limit.unwrap_or(25).min(50);
-
         // WHOOP uses token-based pagination, offset is not directly supported
         // For offset support, we'd need to paginate through until we reach the offset
         // For simplicity, we'll fetch from the beginning
@@ -731,18 +770,7 @@ impl FitnessProvider for WhoopProvider {
             warn!("WHOOP provider offset pagination is limited - fetching from beginning");
         }
 
-        // Build endpoint with optional time filter (WHOOP supports start/end parameters)
-        let mut endpoint = format!("activity/workout?limit={page_limit}");
-        if let Some(after) = params.after {
-            if let Some(dt) = chrono::DateTime::from_timestamp(after, 0) {
-                let _ = write!(endpoint, "&start={}", dt.format("%Y-%m-%dT%H:%M:%S%.3fZ"));
-            }
-        }
-        if let Some(before) = params.before {
-            if let Some(dt) = chrono::DateTime::from_timestamp(before, 0) {
-                let _ = write!(endpoint, "&end={}", dt.format("%Y-%m-%dT%H:%M:%S%.3fZ"));
-            }
-        }
+        let endpoint = Self::workouts_endpoint(params);
 
         let response: WhoopPaginatedResponse<WhoopWorkout> = self.api_request(&endpoint).await?;
 
@@ -994,18 +1022,20 @@ impl FitnessProvider for WhoopProvider {
         };
 
         if let (Some(access_token), Some(revoke_url)) = (access_token_opt, revoke_url_opt) {
-            self.client
-                .post(&revoke_url)
-                .form(&[("token", access_token.as_str())])
-                .send()
-                .await
-                .inspect_err(|e| {
-                    warn!(
-                        error = ?e,
-                        "Failed to revoke WHOOP access token - continuing with credential cleanup"
-                    );
-                })
-                .ok();
+            http_client::send_with_timeout(
+                self.client
+                    .post(&revoke_url)
+                    .form(&[("token", access_token.as_str())]),
+                self.config.request_timeout(),
+            )
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    error = ?e,
+                    "Failed to revoke WHOOP access token - continuing with credential cleanup"
+                );
+            })
+            .ok();
             info!("Attempted to revoke WHOOP access token");
         }
 
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the predict_race_times tool in the tool catalog
+-- ABOUTME: Race time predictions (5K to marathon) from recent runs via VDOT
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-048', 'predict_race_times', 'Race Time Predictions', 'Predict 5K, 10K, half marathon and marathon times from recent runs using VDOT', 'analysis', 1, NULL, 'professional');
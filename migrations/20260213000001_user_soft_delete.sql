@@ -0,0 +1,6 @@
+-- ABOUTME: Migration adding soft delete support to users
+-- ABOUTME: Soft-deleted users stay hidden until restored or purged after the recovery window
+
+ALTER TABLE users ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,14 @@
+-- ABOUTME: Migration tracking OAuth2 access tokens issued alongside refresh tokens
+-- ABOUTME: Links each access token jti to its refresh token so revocation covers both
+
+CREATE TABLE IF NOT EXISTS oauth2_access_tokens (
+    jti TEXT PRIMARY KEY,
+    refresh_token TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    revoked INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (client_id) REFERENCES oauth2_clients(client_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_oauth2_access_tokens_refresh_token ON oauth2_access_tokens(refresh_token);
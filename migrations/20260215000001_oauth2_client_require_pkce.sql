@@ -0,0 +1,4 @@
+-- ABOUTME: Migration adding a per-client PKCE requirement to OAuth2 clients
+-- ABOUTME: Existing clients keep requiring a code_challenge on every authorization request
+
+ALTER TABLE oauth2_clients ADD COLUMN require_pkce INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,4 @@
+-- ABOUTME: Migration adding a per-tenant rate limit mode to tenants
+-- ABOUTME: Existing tenants keep enforcing their limits; 'observe' only records would-be blocks
+
+ALTER TABLE tenants ADD COLUMN rate_limit_mode TEXT NOT NULL DEFAULT 'enforce' CHECK (rate_limit_mode IN ('enforce', 'observe'));
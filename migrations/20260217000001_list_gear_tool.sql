@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the list_gear tool in the tool catalog
+-- ABOUTME: Lists shoes and bikes with distance and warns about worn-out shoes
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-049', 'list_gear', 'List Gear', 'List shoes and bikes with logged distance and flag shoes past their replacement mileage', 'fitness', 1, NULL, 'starter');
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the search_activities tool in the tool catalog
+-- ABOUTME: Finds activities by sport, distance, duration, date range, name, and elevation
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-050', 'search_activities', 'Search Activities', 'Search activities by sport type, distance, duration, date range, name text, and elevation gain', 'fitness', 1, NULL, 'starter');
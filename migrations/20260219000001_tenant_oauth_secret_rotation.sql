@@ -0,0 +1,8 @@
+-- ABOUTME: Adds client secret rotation state to tenant OAuth credentials
+-- ABOUTME: Stores the pending secret and when the previous secret stops being accepted
+
+-- New secret awaiting promotion; client_secret_encrypted keeps the previous one until then
+ALTER TABLE tenant_oauth_credentials ADD COLUMN pending_client_secret_encrypted TEXT;
+
+-- End of the grace period during which the previous secret is still accepted (RFC 3339)
+ALTER TABLE tenant_oauth_credentials ADD COLUMN previous_secret_expires_at TEXT;
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the export_user_data tool in the tool catalog
+-- ABOUTME: Exports profile, goals, insights, connections, and recent activities for data portability
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-051', 'export_user_data', 'Export User Data', 'Export profile, goals, insights, provider connections, and recent activities as a portable archive', 'fitness', 1, NULL, 'starter');
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_recovery_score tool in the tool catalog
+-- ABOUTME: Recovery score pulled from provider sleep, overnight HRV, and training stress balance
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-052', 'get_recovery_score', 'Get Recovery Score', 'Recovery score from the latest provider sleep session, overnight HRV, and training stress balance', 'sleep', 1, NULL, 'professional');
@@ -0,0 +1,15 @@
+-- ABOUTME: Migration adding a per-tenant activity backfill window and per-connection resume markers
+-- ABOUTME: Backfills stop paging past max_backfill_days and record where they stopped so a later run can continue
+
+ALTER TABLE tenants ADD COLUMN max_backfill_days INTEGER NOT NULL DEFAULT 365 CHECK (max_backfill_days > 0);
+
+CREATE TABLE IF NOT EXISTS activity_backfill_markers (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    resume_cursor TEXT NOT NULL,
+    window_days INTEGER NOT NULL,
+    activities_fetched INTEGER NOT NULL DEFAULT 0,
+    stopped_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, tenant_id, provider)
+);
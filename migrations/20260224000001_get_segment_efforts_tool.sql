@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_segment_efforts tool in the tool catalog
+-- ABOUTME: Lists an activity's segment efforts, new segment PRs, and segment course records
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-053', 'get_segment_efforts', 'Get Segment Efforts', 'List segment efforts for an activity with PR and KOM ranks, new segment PRs, and segment course records', 'fitness', 1, NULL, 'starter');
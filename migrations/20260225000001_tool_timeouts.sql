@@ -0,0 +1,12 @@
+-- ABOUTME: Adds per-tool execution timeout overrides to the tool catalog
+-- ABOUTME: NULL uses the server default (MCP_TOOL_TIMEOUT_SECS); long-running analysis tools get more time
+
+ALTER TABLE tool_catalog ADD COLUMN timeout_secs INTEGER CHECK (timeout_secs IS NULL OR timeout_secs >= 0);
+
+UPDATE tool_catalog SET timeout_secs = 180 WHERE tool_name IN (
+    'analyze_performance_trends',
+    'analyze_training_load',
+    'calculate_fitness_score',
+    'detect_patterns'
+);
+UPDATE tool_catalog SET timeout_secs = 300 WHERE tool_name = 'export_user_data';
@@ -0,0 +1,4 @@
+-- ABOUTME: Adds a retirement deadline to RSA signing keys so a rolled-over key keeps validating for an overlap window
+-- ABOUTME: NULL means the key is not retiring; keys past retires_at are dropped from JWKS and deleted
+
+ALTER TABLE rsa_keypairs ADD COLUMN retires_at TEXT;
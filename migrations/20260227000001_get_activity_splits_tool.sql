@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_activity_splits tool in the tool catalog
+-- ABOUTME: Per-kilometer or per-mile splits from provider laps or computed from the activity stream
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-054', 'get_activity_splits', 'Get Activity Splits', 'Per-kilometer or per-mile splits with pace, heart rate, and elevation from provider laps or the activity stream', 'fitness', 1, NULL, 'starter');
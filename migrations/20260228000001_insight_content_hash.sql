@@ -0,0 +1,7 @@
+-- ABOUTME: Adds a content hash to insights so re-running an analysis upserts instead of duplicating
+-- ABOUTME: Rows stored before this migration keep a NULL hash and are de-duplicated on read
+
+ALTER TABLE insights ADD COLUMN content_hash TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_insights_user_content_hash
+    ON insights(user_id, content_hash);
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the analyze_time_in_zones tool in the tool catalog
+-- ABOUTME: Time spent in each heart rate and power zone from activity streams
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-055', 'analyze_time_in_zones', 'Time in Zones', 'Minutes spent in each heart rate and power zone for an activity or date range, using the athlete''s own zones when available', 'analysis', 1, NULL, 'professional');
@@ -0,0 +1,14 @@
+-- ABOUTME: Migration for the auth_sessions table tracking JWTs issued at login and refresh
+-- ABOUTME: Rows are keyed by the token jti; revoked_at marks sessions rejected during validation
+
+CREATE TABLE IF NOT EXISTS auth_sessions (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    client TEXT,
+    ip_address TEXT,
+    issued_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    revoked_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_auth_sessions_user_id ON auth_sessions(user_id);
@@ -0,0 +1,16 @@
+-- ABOUTME: Migration for the imported_activities table holding activities uploaded as GPX or TCX files
+-- ABOUTME: Activities are stored as normalized JSON and served through the `file` pseudo-provider
+
+CREATE TABLE IF NOT EXISTS imported_activities (
+    id TEXT NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    source TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    activity_data TEXT NOT NULL,
+    imported_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_imported_activities_user_start
+    ON imported_activities(user_id, start_date DESC);
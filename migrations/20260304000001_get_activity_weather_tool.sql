@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_activity_weather tool in the tool catalog
+-- ABOUTME: Historical weather at an activity's start location and time
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-056', 'get_activity_weather', 'Get Activity Weather', 'Historical temperature, humidity, wind, and conditions at an activity''s start location and time', 'fitness', 1, NULL, 'starter');
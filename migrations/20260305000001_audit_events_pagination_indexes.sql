@@ -0,0 +1,8 @@
+-- ABOUTME: Composite (timestamp, id) indexes for keyset pagination over audit_events
+-- ABOUTME: Replaces the single-column timestamp and tenant indexes, which the new ones cover as prefixes
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_timestamp_id ON audit_events(timestamp, id);
+CREATE INDEX IF NOT EXISTS idx_audit_events_tenant_timestamp_id ON audit_events(tenant_id, timestamp, id);
+
+DROP INDEX IF EXISTS idx_audit_events_timestamp;
+DROP INDEX IF EXISTS idx_audit_events_tenant;
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the suggest_recipes tool in the tool catalog
+-- ABOUTME: Ranks saved recipes against the day's training intensity and macro needs
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-057', 'suggest_recipes', 'Suggest Recipes', 'Rank saved recipes for a meal using the day''s training intensity, dietary restrictions, and skill level', 'recipes', 1, NULL, 'starter');
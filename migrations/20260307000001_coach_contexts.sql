@@ -0,0 +1,17 @@
+-- ABOUTME: Migration for coach_contexts storing system prompts injected ahead of MCP coaching
+-- ABOUTME: A NULL coach_id is the tenant-wide context; a coach row overrides it for that coach
+
+CREATE TABLE IF NOT EXISTS coach_contexts (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    coach_id TEXT REFERENCES coaches(id) ON DELETE CASCADE,
+    system_context TEXT NOT NULL,
+    updated_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_coach_contexts_tenant
+    ON coach_contexts(tenant_id) WHERE coach_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_coach_contexts_coach
+    ON coach_contexts(tenant_id, coach_id) WHERE coach_id IS NOT NULL;
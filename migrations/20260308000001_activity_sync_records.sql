@@ -0,0 +1,17 @@
+-- ABOUTME: Migration for activity_sync_records, the per-activity state behind delta syncs
+-- ABOUTME: Content hashes detect edits; deleted_at marks activities that vanished from the provider
+
+CREATE TABLE IF NOT EXISTS activity_sync_records (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    activity_id TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    deleted_at TEXT,
+    PRIMARY KEY (user_id, tenant_id, provider, activity_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_activity_sync_records_start
+    ON activity_sync_records(user_id, tenant_id, provider, start_date);
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_activities_delta tool in the tool catalog
+-- ABOUTME: Activities created, updated, or deleted since a timestamp
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-058', 'get_activities_delta', 'Get Activities Delta', 'Activities created or updated since a timestamp, plus IDs of activities deleted since then', 'fitness', 1, NULL, 'starter');
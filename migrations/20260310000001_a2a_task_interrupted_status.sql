@@ -0,0 +1,33 @@
+-- ABOUTME: Migration allowing the 'interrupted' A2A task status
+-- ABOUTME: Marks tasks that were still running when the server shut down
+
+-- SQLite doesn't support ALTER TABLE to modify CHECK constraints, so we recreate
+
+-- Create new table with expanded constraint
+CREATE TABLE IF NOT EXISTS a2a_tasks_new (
+    id TEXT PRIMARY KEY,
+    client_id TEXT NOT NULL REFERENCES a2a_clients(id) ON DELETE CASCADE,
+    task_type TEXT NOT NULL,
+    input_data TEXT NOT NULL,
+    output_data TEXT,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'running', 'completed', 'failed', 'cancelled', 'interrupted')),
+    error_message TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    completed_at TEXT
+);
+
+-- Copy existing data (if any)
+INSERT OR IGNORE INTO a2a_tasks_new
+SELECT id, client_id, task_type, input_data, output_data, status, error_message, created_at, updated_at, completed_at
+FROM a2a_tasks;
+
+-- Drop old table
+DROP TABLE IF EXISTS a2a_tasks;
+
+-- Rename new table
+ALTER TABLE a2a_tasks_new RENAME TO a2a_tasks;
+
+-- Recreate indexes
+CREATE INDEX IF NOT EXISTS idx_a2a_tasks_client_id ON a2a_tasks(client_id);
+CREATE INDEX IF NOT EXISTS idx_a2a_tasks_status ON a2a_tasks(status);
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the list_connected_providers tool in the tool catalog
+-- ABOUTME: Connection status, granted scopes, and last sync time per provider
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-059', 'list_connected_providers', 'List Connected Providers', 'Connection status, granted scopes, and last sync time for each fitness provider', 'connections', 1, NULL, 'starter');
@@ -0,0 +1,19 @@
+-- ABOUTME: Migration for admin-configurable feature flags and their per-tenant values
+-- ABOUTME: Values are JSON-encoded booleans, numbers, or strings matching the flag's value_type
+
+CREATE TABLE IF NOT EXISTS feature_flags (
+    key TEXT PRIMARY KEY,
+    description TEXT,
+    value_type TEXT NOT NULL CHECK (value_type IN ('bool', 'number', 'string')),
+    default_value TEXT NOT NULL,
+    global_value TEXT,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS tenant_feature_flags (
+    key TEXT NOT NULL REFERENCES feature_flags(key) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    value TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (key, tenant_id)
+);
@@ -0,0 +1,5 @@
+-- ABOUTME: Registers the get_training_load_trend tool in the tool catalog
+-- ABOUTME: Daily CTL/ATL/TSB series for charting; gets the long-running analysis timeout
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan, timeout_secs) VALUES
+('tc-060', 'get_training_load_trend', 'Training Load Trend', 'Daily CTL, ATL, and TSB series over a window with training status and overtraining risk', 'analysis', 1, NULL, 'professional', 180);
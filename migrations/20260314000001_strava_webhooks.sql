@@ -0,0 +1,19 @@
+-- ABOUTME: Migration for Strava push subscriptions and athlete links
+-- ABOUTME: Webhook events carry Strava athlete IDs, which athlete links map back to Pierre users
+
+CREATE TABLE IF NOT EXISTS strava_webhook_subscriptions (
+    id INTEGER PRIMARY KEY,
+    client_id TEXT NOT NULL UNIQUE,
+    callback_url TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS strava_athlete_links (
+    athlete_id INTEGER NOT NULL,
+    user_id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    linked_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, tenant_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_strava_athlete_links_athlete ON strava_athlete_links(athlete_id);
@@ -0,0 +1,13 @@
+-- ABOUTME: Migration for insight re-analysis job checkpoints
+-- ABOUTME: Records the user cursor and counters after each batch so interrupted runs resume
+
+CREATE TABLE IF NOT EXISTS reanalysis_checkpoints (
+    job_id TEXT PRIMARY KEY,
+    cursor TEXT,
+    users_processed INTEGER NOT NULL DEFAULT 0,
+    users_failed INTEGER NOT NULL DEFAULT 0,
+    insights_stored INTEGER NOT NULL DEFAULT 0,
+    started_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    completed_at TEXT
+);
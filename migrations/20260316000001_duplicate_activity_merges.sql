@@ -0,0 +1,18 @@
+-- ABOUTME: Migration for duplicate_activity_merges, manual merges of duplicate records of one session
+-- ABOUTME: Each row hides one secondary activity behind a primary and keeps its snapshot for unmerge
+
+CREATE TABLE IF NOT EXISTS duplicate_activity_merges (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    secondary_provider TEXT NOT NULL,
+    secondary_id TEXT NOT NULL,
+    primary_provider TEXT NOT NULL,
+    primary_id TEXT NOT NULL,
+    secondary_snapshot TEXT NOT NULL,
+    filled_fields TEXT NOT NULL,
+    merged_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, tenant_id, secondary_provider, secondary_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_duplicate_activity_merges_primary
+    ON duplicate_activity_merges(user_id, tenant_id, primary_provider, primary_id);
@@ -0,0 +1,6 @@
+-- ABOUTME: Registers the merge_activities and unmerge_activities tools in the tool catalog
+-- ABOUTME: Manually combine duplicate records of one session, and undo the merge
+
+INSERT OR IGNORE INTO tool_catalog (id, tool_name, display_name, description, category, is_enabled_by_default, requires_provider, min_plan) VALUES
+('tc-061', 'merge_activities', 'Merge Activities', 'Merge a duplicate activity into a primary one, filling the primary''s gaps and hiding the duplicate', 'fitness', 1, NULL, 'starter'),
+('tc-062', 'unmerge_activities', 'Unmerge Activities', 'Undo a merge so both activities are listed separately again', 'fitness', 1, NULL, 'starter');
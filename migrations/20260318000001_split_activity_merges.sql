@@ -0,0 +1,4 @@
+-- ABOUTME: Adds a merge kind to duplicate_activity_merges so split recordings can be joined
+-- ABOUTME: Merges stored before this migration are duplicate merges that only fill gaps
+
+ALTER TABLE duplicate_activity_merges ADD COLUMN merge_kind TEXT NOT NULL DEFAULT 'duplicate';
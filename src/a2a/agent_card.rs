@@ -10,6 +10,12 @@
 //! enabling agent discovery and capability negotiation.
 
 use crate::constants::api_tier_limits::{STARTER_REQUESTS_PER_MONTH, TRIAL_REQUESTS_PER_MONTH};
+use crate::constants::tools::{
+    ANALYZE_ACTIVITY, ANALYZE_TIME_IN_ZONES, COMPARE_ACTIVITIES, GET_ACTIVITIES,
+    GET_ACTIVITY_SPLITS, GET_ATHLETE, GET_RECOVERY_SCORE, GET_SEGMENT_EFFORTS, GET_STATS,
+    LIST_GEAR, SEARCH_ACTIVITIES,
+};
+use crate::providers::ProviderCapabilities;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -390,6 +396,37 @@ impl AgentCard {
         metadata
     }
 
+    /// Provider capability a tool needs from at least one connected provider
+    ///
+    /// Tools that only use Pierre's own data need none.
+    #[must_use]
+    pub fn required_provider_capabilities(tool_name: &str) -> ProviderCapabilities {
+        match tool_name {
+            GET_ACTIVITIES
+            | GET_ATHLETE
+            | GET_STATS
+            | SEARCH_ACTIVITIES
+            | ANALYZE_ACTIVITY
+            | COMPARE_ACTIVITIES
+            | GET_ACTIVITY_SPLITS
+            | ANALYZE_TIME_IN_ZONES => ProviderCapabilities::ACTIVITIES,
+            LIST_GEAR => ProviderCapabilities::GEAR,
+            GET_SEGMENT_EFFORTS => ProviderCapabilities::SEGMENTS,
+            GET_RECOVERY_SCORE => ProviderCapabilities::SLEEP_TRACKING,
+            _ => ProviderCapabilities::empty(),
+        }
+    }
+
+    /// Keep only the tools the caller's connected providers can serve
+    ///
+    /// `supported` is the union of the connected providers' capabilities.
+    #[must_use]
+    pub fn restricted_to(mut self, supported: ProviderCapabilities) -> Self {
+        self.tools
+            .retain(|tool| supported.contains(Self::required_provider_capabilities(&tool.name)));
+        self
+    }
+
     /// Serialize the agent card to JSON
     ///
     /// # Errors
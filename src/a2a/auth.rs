@@ -16,6 +16,7 @@ use crate::constants::time::HOUR_SECONDS;
 use crate::database_plugins::DatabaseProvider;
 use crate::errors::{AppError, AppResult};
 use crate::mcp::resources::ServerResources;
+use crate::metrics::MetricsRegistry;
 use crate::providers::errors::ProviderError;
 use crate::rate_limiting::UnifiedRateLimitInfo;
 use serde::{Deserialize, Serialize};
@@ -155,6 +156,7 @@ impl A2AAuthenticator {
                     })?;
 
                 if rate_limit_status.is_rate_limited {
+                    MetricsRegistry::global().record_rate_limit_rejection("a2a_client");
                     let err = ProviderError::RateLimitExceeded {
                         provider: "A2A Client Authentication".to_owned(),
                         retry_after_secs: rate_limit_status.reset_at.map_or(3600, |dt| {
@@ -16,6 +16,7 @@
 use crate::a2a::auth::A2AClient;
 use crate::a2a::system_user::A2ASystemUserService;
 use crate::a2a::{map_db_error, A2AError};
+use crate::analytics::AnalyticsSink;
 use crate::api_keys::{ApiKeyManager, ApiKeyTier, CreateApiKeyRequest};
 use crate::constants::rate_limits::DEFAULT_BURST_LIMIT;
 use crate::constants::tiers;
@@ -193,16 +194,22 @@ pub struct A2AUsageParams {
 pub struct A2AClientManager {
     database: Arc<Database>,
     system_user_service: Arc<A2ASystemUserService>,
+    analytics: Arc<dyn AnalyticsSink>,
     active_sessions: Arc<RwLock<HashMap<String, A2ASession>>>,
 }
 
 impl A2AClientManager {
     /// Creates a new A2A client manager instance
     #[must_use]
-    pub fn new(database: Arc<Database>, system_user_service: Arc<A2ASystemUserService>) -> Self {
+    pub fn new(
+        database: Arc<Database>,
+        system_user_service: Arc<A2ASystemUserService>,
+        analytics: Arc<dyn AnalyticsSink>,
+    ) -> Self {
         Self {
             database,
             system_user_service,
+            analytics,
             active_sessions: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -480,8 +487,8 @@ impl A2AClientManager {
         let end_of_day = chrono::Utc::now();
 
         let today_stats = self
-            .database
-            .get_a2a_usage_stats(client_id, start_of_day, end_of_day)
+            .analytics
+            .a2a_usage_stats(client_id, start_of_day, end_of_day)
             .await
             .map_err(|e| A2AError::InternalError(format!("Failed to get today's stats: {e}")))?;
 
@@ -497,8 +504,8 @@ impl A2AClientManager {
         // Get total requests (use a long period to approximate total)
         let total_start = chrono::Utc::now() - chrono::Duration::days(365);
         let total_stats = self
-            .database
-            .get_a2a_usage_stats(client_id, total_start, chrono::Utc::now())
+            .analytics
+            .a2a_usage_stats(client_id, total_start, chrono::Utc::now())
             .await
             .map_err(|e| A2AError::InternalError(format!("Failed to get total stats: {e}")))?;
 
@@ -638,7 +645,7 @@ impl A2AClientManager {
     ///
     /// # Errors
     ///
-    /// Returns an error if database storage fails
+    /// Returns an error if the analytics sink fails to store the record
     pub async fn record_detailed_usage(&self, params: A2AUsageParams) -> Result<(), A2AError> {
         let usage = A2AUsage {
             id: None,
@@ -658,8 +665,8 @@ impl A2AClientManager {
             granted_scopes: params.granted_scopes,
         };
 
-        self.database
-            .record_a2a_usage(&usage)
+        self.analytics
+            .record_a2a_usage(usage)
             .await
             .map_err(|e| A2AError::InternalError(format!("Failed to record A2A usage: {e}")))?;
 
@@ -19,6 +19,8 @@ pub mod client;
 pub mod protocol;
 /// System user management for A2A agents
 pub mod system_user;
+/// Task status updates and partial results streamed to SSE subscribers
+pub mod task_updates;
 
 use crate::errors::AppError;
 
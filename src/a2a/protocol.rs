@@ -15,6 +15,7 @@
 
 use crate::a2a::A2A_VERSION;
 use crate::database_plugins::DatabaseProvider;
+use crate::errors::{ErrorCode, ErrorDetails};
 use crate::jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
 use crate::mcp::resources::ServerResources;
 use crate::mcp::schema::OAuthAppCredentials;
@@ -87,8 +88,31 @@ pub enum A2AError {
 /// A2A protocol error response (JSON-RPC 2.0 error)
 pub type A2AErrorResponse = JsonRpcError;
 
+impl A2AError {
+    /// Stable error code carried in the error response details
+    #[must_use]
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidRequest(_) => ErrorCode::InvalidInput,
+            Self::AuthenticationFailed(_)
+            | Self::ClientNotRegistered(_)
+            | Self::InvalidSessionToken(_) => ErrorCode::AuthInvalid,
+            Self::DatabaseError(_) => ErrorCode::DatabaseError,
+            Self::InternalError(_) => ErrorCode::InternalError,
+            Self::ClientDeactivated(_) | Self::InsufficientPermissions(_) => {
+                ErrorCode::PermissionDenied
+            }
+            Self::RateLimitExceeded(_) => ErrorCode::RateLimitExceeded,
+            Self::SessionExpired(_) => ErrorCode::AuthExpired,
+            Self::ResourceNotFound(_) => ErrorCode::ResourceNotFound,
+            Self::ServiceUnavailable(_) => ErrorCode::ResourceUnavailable,
+        }
+    }
+}
+
 impl From<A2AError> for A2AErrorResponse {
     fn from(error: A2AError) -> Self {
+        let error_code = error.error_code();
         let (code, message) = match error {
             A2AError::InvalidRequest(msg) => (-32602, format!("Invalid params: {msg}")),
             A2AError::AuthenticationFailed(msg) => {
@@ -108,11 +132,7 @@ impl From<A2AError> for A2AErrorResponse {
             A2AError::ServiceUnavailable(msg) => (-32010, format!("Service unavailable: {msg}")),
         };
 
-        Self {
-            code,
-            message,
-            data: None,
-        }
+        Self::with_error_code(code, error_code, message)
     }
 }
 
@@ -290,6 +310,8 @@ pub enum TaskStatus {
     Failed,
     /// Task was cancelled by user or system
     Cancelled,
+    /// Task was still running when the server shut down
+    Interrupted,
 }
 
 impl Display for TaskStatus {
@@ -300,10 +322,22 @@ impl Display for TaskStatus {
             Self::Completed => write!(f, "completed"),
             Self::Failed => write!(f, "failed"),
             Self::Cancelled => write!(f, "cancelled"),
+            Self::Interrupted => write!(f, "interrupted"),
         }
     }
 }
 
+impl TaskStatus {
+    /// Whether the task has finished and will not change status again
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed | Self::Failed | Self::Cancelled | Self::Interrupted
+        )
+    }
+}
+
 /// A2A Protocol Server implementation
 pub struct A2AServer {
     /// A2A protocol version
@@ -332,7 +366,16 @@ impl A2AServer {
     }
 
     /// Handle incoming A2A request
+    ///
+    /// When served over HTTP the response metadata carries the request ID.
     pub async fn handle_request(&self, request: A2ARequest) -> A2AResponse {
+        self.dispatch_request(request)
+            .await
+            .with_current_request_id()
+    }
+
+    /// Route an A2A request to its method handler
+    async fn dispatch_request(&self, request: A2ARequest) -> A2AResponse {
         match request.method.as_str() {
             "a2a/initialize" => {
                 // Use OAuth-aware initialization if authentication is provided
@@ -391,16 +434,12 @@ impl A2AServer {
         ) -> Pin<Box<dyn Future<Output = A2AResponse> + Send + '_>>,
     {
         let Some(resources) = &self.resources else {
-            return A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32000,
-                    message: "A2A server not properly configured".into(),
-                    data: None,
-                }),
-                id: request.id.clone(),
-            };
+            return A2AResponse::error_with_code(
+                request.id.clone(),
+                -32000,
+                ErrorCode::ConfigError,
+                "A2A server not properly configured",
+            );
         };
         match Self::authenticate_request(&request, resources) {
             Ok(user_id) => handler(self, request, user_id).await,
@@ -433,6 +472,7 @@ impl A2AServer {
             result: Some(result),
             error: None,
             id: request.id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -440,16 +480,12 @@ impl A2AServer {
     async fn handle_initialize_with_oauth(&self, request: A2ARequest) -> A2AResponse {
         // Extract resources with defensive error handling
         let Some(resources) = self.resources.as_ref() else {
-            return A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32603,
-                    message: "Internal error: Server resources not initialized".to_owned(),
-                    data: None,
-                }),
-                id: request.id.clone(),
-            };
+            return A2AResponse::error_with_code(
+                request.id.clone(),
+                -32603,
+                ErrorCode::ConfigError,
+                "Internal error: Server resources not initialized",
+            );
         };
 
         let user_id = match Self::authenticate_request(&request, resources) {
@@ -522,17 +558,14 @@ impl A2AServer {
                 result: Some(result),
                 error: None,
                 id: request.id,
+                metadata: HashMap::new(),
             },
-            Err(e) => A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32603,
-                    message: format!("Failed to serialize A2A initialize response: {e}"),
-                    data: None,
-                }),
-                id: request.id,
-            },
+            Err(e) => A2AResponse::error_with_code(
+                request.id,
+                -32603,
+                ErrorCode::SerializationError,
+                format!("Failed to serialize A2A initialize response: {e}"),
+            ),
         }
     }
 
@@ -548,17 +581,12 @@ impl A2AServer {
 
         // Extract auth token from request
         let auth_token = request.auth_token.as_deref().ok_or_else(|| {
-            Box::new(A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32001,
-                    message: "Authentication token required for OAuth credential storage"
-                        .to_owned(),
-                    data: None,
-                }),
-                id: Some(request_id.clone()), // Safe: JSON value ownership for error response
-            })
+            Box::new(A2AResponse::error_with_code(
+                Some(request_id.clone()),
+                -32001,
+                ErrorCode::AuthRequired,
+                "Authentication token required for OAuth credential storage",
+            ))
         })?;
 
         // Validate token and extract user_id
@@ -568,29 +596,21 @@ impl A2AServer {
         {
             Ok(claims) => Uuid::parse_str(&claims.sub).map_or_else(
                 |_| {
-                    Err(Box::new(A2AResponse {
-                        jsonrpc: "2.0".into(),
-                        result: None,
-                        error: Some(A2AErrorResponse {
-                            code: -32001,
-                            message: "Invalid user ID in authentication token".to_owned(),
-                            data: None,
-                        }),
-                        id: Some(request_id.clone()), // Safe: JSON value ownership for error response
-                    }))
+                    Err(Box::new(A2AResponse::error_with_code(
+                        Some(request_id.clone()),
+                        -32001,
+                        ErrorCode::AuthInvalid,
+                        "Invalid user ID in authentication token",
+                    )))
                 },
                 Ok,
             ),
-            Err(_) => Err(Box::new(A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32001,
-                    message: "Invalid authentication token".to_owned(),
-                    data: None,
-                }),
-                id: Some(request_id),
-            })),
+            Err(_) => Err(Box::new(A2AResponse::error_with_code(
+                Some(request_id),
+                -32001,
+                ErrorCode::AuthInvalid,
+                "Invalid authentication token",
+            ))),
         }
     }
 
@@ -616,15 +636,13 @@ impl A2AServer {
     ) -> Result<(), A2AResponse> {
         let owned_ids = Self::get_owned_client_ids(user_id, resources)
             .await
-            .map_err(|e| A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32000,
-                    message: format!("Failed to resolve client ownership: {e}"),
-                    data: None,
-                }),
-                id: request_id.cloned(),
+            .map_err(|e| {
+                A2AResponse::error_with_code(
+                    request_id.cloned(),
+                    -32000,
+                    ErrorCode::DatabaseError,
+                    format!("Failed to resolve client ownership: {e}"),
+                )
             })?;
 
         if !owned_ids.iter().any(|id| id == client_id) {
@@ -635,30 +653,22 @@ impl A2AServer {
 
     /// Create a standard permission denied error response
     fn permission_denied_error(request_id: Option<Value>) -> A2AResponse {
-        A2AResponse {
-            jsonrpc: "2.0".into(),
-            result: None,
-            error: Some(A2AErrorResponse {
-                code: -32001,
-                message: "Permission denied: client does not belong to authenticated user".into(),
-                data: None,
-            }),
-            id: request_id,
-        }
+        A2AResponse::error_with_code(
+            request_id,
+            -32001,
+            ErrorCode::PermissionDenied,
+            "Permission denied: client does not belong to authenticated user",
+        )
     }
 
     /// Create a standard server-not-configured error response
     fn server_not_configured_error(request_id: Option<Value>) -> A2AResponse {
-        A2AResponse {
-            jsonrpc: "2.0".into(),
-            result: None,
-            error: Some(A2AErrorResponse {
-                code: -32000,
-                message: "A2A server not properly configured".into(),
-                data: None,
-            }),
-            id: request_id,
-        }
+        A2AResponse::error_with_code(
+            request_id,
+            -32000,
+            ErrorCode::ConfigError,
+            "A2A server not properly configured",
+        )
     }
 
     /// Store OAuth credentials provided during A2A initialization
@@ -705,6 +715,7 @@ impl A2AServer {
             result: Some(json!({"status": "received"})),
             error: None,
             id: request.id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -724,6 +735,7 @@ impl A2AServer {
                 jsonrpc: "2.0".into(),
                 result: Some(json!({
                     "stream_url": format!("{}/a2a/tasks/{}/stream", base_url, id),
+                    "events_url": format!("{}/a2a/tasks/{}/events", base_url, id),
                     "stream_type": "text/event-stream",
                     "protocol": "SSE",
                     "keep_alive_interval_seconds": 15,
@@ -731,6 +743,7 @@ impl A2AServer {
                 })),
                 error: None,
                 id: request.id,
+                metadata: HashMap::new(),
             }
         } else {
             // Return generic streaming info if no specific ID provided
@@ -744,6 +757,7 @@ impl A2AServer {
                 })),
                 error: None,
                 id: request.id,
+                metadata: HashMap::new(),
             }
         }
     }
@@ -757,16 +771,12 @@ impl A2AServer {
                 Ok(params) => params,
                 Err(e) => {
                     error!("Failed to parse A2A task create parameters: {}", e);
-                    return A2AResponse {
-                        jsonrpc: "2.0".into(),
-                        result: None,
-                        error: Some(A2AErrorResponse {
-                            code: -32602,
-                            message: format!("Invalid parameters: {e}"),
-                            data: None,
-                        }),
-                        id: request.id,
-                    };
+                    return A2AResponse::error_with_code(
+                        request.id,
+                        -32602,
+                        ErrorCode::InvalidInput,
+                        format!("Invalid parameters: {e}"),
+                    );
                 }
             };
 
@@ -800,16 +810,12 @@ impl A2AServer {
                 id
             }
             Err(e) => {
-                return A2AResponse {
-                    jsonrpc: "2.0".into(),
-                    result: None,
-                    error: Some(A2AErrorResponse {
-                        code: -32000,
-                        message: format!("Failed to persist task: {e}"),
-                        data: None,
-                    }),
-                    id: request.id,
-                };
+                return A2AResponse::error_with_code(
+                    request.id,
+                    -32000,
+                    ErrorCode::DatabaseError,
+                    format!("Failed to persist task: {e}"),
+                );
             }
         };
 
@@ -834,20 +840,18 @@ impl A2AServer {
                 result: Some(task_value),
                 error: None,
                 id: request.id,
+                metadata: HashMap::new(),
             },
-            Err(e) => A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32603,
-                    message: "Internal error: Failed to serialize task".to_owned(),
-                    data: Some(json!({
-                        "error": e.to_string(),
-                        "context": "Task serialization failed"
-                    })),
-                }),
-                id: request.id,
-            },
+            Err(e) => A2AResponse::from_error(
+                request.id,
+                A2AErrorResponse::with_error_code(
+                    -32603,
+                    ErrorCode::SerializationError,
+                    "Internal error: Failed to serialize task",
+                )
+                .with_extra("error", json!(e.to_string()))
+                .with_extra("context", json!("Task serialization failed")),
+            ),
         }
     }
 
@@ -862,16 +866,12 @@ impl A2AServer {
             Ok(params) => params,
             Err(e) => {
                 error!("Failed to parse A2A task get parameters: {}", e);
-                return A2AResponse {
-                    jsonrpc: "2.0".into(),
-                    result: None,
-                    error: Some(A2AErrorResponse {
-                        code: -32602,
-                        message: format!("Invalid parameters: {e}"),
-                        data: None,
-                    }),
-                    id: request.id,
-                };
+                return A2AResponse::error_with_code(
+                    request.id,
+                    -32602,
+                    ErrorCode::InvalidInput,
+                    format!("Invalid parameters: {e}"),
+                );
             }
         };
 
@@ -897,28 +897,21 @@ impl A2AServer {
                     result: Some(to_value(task).unwrap_or_default()),
                     error: None,
                     id: request.id,
+                    metadata: HashMap::new(),
                 }
             }
-            Ok(None) => A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32601,
-                    message: "Task not found".into(),
-                    data: None,
-                }),
-                id: request.id,
-            },
-            Err(e) => A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32000,
-                    message: format!("Database error: {e}"),
-                    data: None,
-                }),
-                id: request.id,
-            },
+            Ok(None) => A2AResponse::error_with_code(
+                request.id,
+                -32601,
+                ErrorCode::ResourceNotFound,
+                "Task not found",
+            ),
+            Err(e) => A2AResponse::error_with_code(
+                request.id,
+                -32000,
+                ErrorCode::DatabaseError,
+                format!("Database error: {e}"),
+            ),
         }
     }
 
@@ -931,16 +924,12 @@ impl A2AServer {
         let owned_client_ids = match Self::get_owned_client_ids(&user_id, resources).await {
             Ok(ids) => ids,
             Err(e) => {
-                return A2AResponse {
-                    jsonrpc: "2.0".into(),
-                    result: None,
-                    error: Some(A2AErrorResponse {
-                        code: -32000,
-                        message: format!("Failed to resolve client ownership: {e}"),
-                        data: None,
-                    }),
-                    id: request.id,
-                };
+                return A2AResponse::error_with_code(
+                    request.id,
+                    -32000,
+                    ErrorCode::DatabaseError,
+                    format!("Failed to resolve client ownership: {e}"),
+                );
             }
         };
 
@@ -977,6 +966,7 @@ impl A2AServer {
                 "completed" => Some(TaskStatus::Completed),
                 "failed" => Some(TaskStatus::Failed),
                 "cancelled" => Some(TaskStatus::Cancelled),
+                "interrupted" => Some(TaskStatus::Interrupted),
                 _ => None,
             });
 
@@ -996,18 +986,15 @@ impl A2AServer {
                     })),
                     error: None,
                     id: request.id,
+                    metadata: HashMap::new(),
                 }
             }
-            Err(e) => A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32000,
-                    message: format!("Database error: {e}"),
-                    data: None,
-                }),
-                id: request.id,
-            },
+            Err(e) => A2AResponse::error_with_code(
+                request.id,
+                -32000,
+                ErrorCode::DatabaseError,
+                format!("Database error: {e}"),
+            ),
         }
     }
 
@@ -1047,6 +1034,7 @@ impl A2AServer {
             result: Some(json!({ "tools": tools })),
             error: None,
             id: request.id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -1080,28 +1068,20 @@ impl A2AServer {
             {
                 Ok(Some(ctx)) => ctx,
                 Ok(None) => {
-                    return A2AResponse {
-                        jsonrpc: "2.0".into(),
-                        result: None,
-                        error: Some(A2AErrorResponse {
-                            code: -32001,
-                            message: "User does not belong to any tenant".into(),
-                            data: None,
-                        }),
-                        id: request.id,
-                    };
+                    return A2AResponse::error_with_code(
+                        request.id,
+                        -32001,
+                        ErrorCode::PermissionDenied,
+                        "User does not belong to any tenant",
+                    );
                 }
                 Err(e) => {
-                    return A2AResponse {
-                        jsonrpc: "2.0".into(),
-                        result: None,
-                        error: Some(A2AErrorResponse {
-                            code: -32603,
-                            message: format!("Failed to resolve tenant context: {e}"),
-                            data: None,
-                        }),
-                        id: request.id,
-                    };
+                    return A2AResponse::error_with_code(
+                        request.id,
+                        -32603,
+                        ErrorCode::InternalError,
+                        format!("Failed to resolve tenant context: {e}"),
+                    );
                 }
             };
 
@@ -1127,29 +1107,26 @@ impl A2AServer {
                     })),
                     error: None,
                     id: request.id,
+                    metadata: HashMap::new(),
                 },
-                Err(e) => A2AResponse {
-                    jsonrpc: "2.0".into(),
-                    result: None,
-                    error: Some(A2AErrorResponse {
-                        code: -32000,
-                        message: format!("Tool execution failed: {e}"),
-                        data: None,
-                    }),
-                    id: request.id,
-                },
+                Err(e) => A2AResponse::from_error(
+                    request.id,
+                    A2AErrorResponse::from_details(
+                        -32000,
+                        ErrorDetails {
+                            message: format!("Tool execution failed: {e}"),
+                            ..ErrorDetails::from(&e)
+                        },
+                    ),
+                ),
             }
         } else {
-            A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32601,
-                    message: format!("Unknown tool: {tool_name}"),
-                    data: None,
-                }),
-                id: request.id,
-            }
+            A2AResponse::error_with_code(
+                request.id,
+                -32601,
+                ErrorCode::ResourceNotFound,
+                format!("Unknown tool: {tool_name}"),
+            )
         }
     }
 
@@ -1173,18 +1150,15 @@ impl A2AServer {
                 })),
                 error: None,
                 id: request.id,
+                metadata: HashMap::new(),
             }
         } else {
-            A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32602,
-                    message: "Missing required parameter: task_id".into(),
-                    data: None,
-                }),
-                id: request.id,
-            }
+            A2AResponse::error_with_code(
+                request.id,
+                -32602,
+                ErrorCode::MissingRequiredField,
+                "Missing required parameter: task_id",
+            )
         }
     }
 
@@ -1209,6 +1183,7 @@ impl A2AServer {
             })),
             error: None,
             id: request.id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -1228,22 +1203,17 @@ impl A2AServer {
             })),
             error: None,
             id: request.id,
+            metadata: HashMap::new(),
         }
     }
 
     fn handle_unknown_method(request: A2ARequest) -> A2AResponse {
-        let error = A2AErrorResponse {
-            code: -32601,
-            message: format!("Method not found: {}", request.method),
-            data: None,
-        };
-
-        A2AResponse {
-            jsonrpc: "2.0".into(),
-            result: None,
-            error: Some(error),
-            id: request.id,
-        }
+        A2AResponse::error_with_code(
+            request.id,
+            -32601,
+            ErrorCode::ResourceNotFound,
+            format!("Method not found: {}", request.method),
+        )
     }
 }
 
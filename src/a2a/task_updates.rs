@@ -0,0 +1,89 @@
+// ABOUTME: A2A task status transitions and partial results with live event streaming
+// ABOUTME: Persists task updates and publishes them to SSE subscribers of /a2a/tasks/:task_id/events
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! A2A Task Updates
+//!
+//! Long-running A2A tasks report progress through these functions instead of
+//! writing to the database directly, so clients subscribed to the task's event
+//! stream see each transition as it happens rather than polling `a2a/tasks/get`.
+
+use crate::a2a::protocol::TaskStatus;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::mcp::resources::ServerResources;
+#[cfg(feature = "transport-sse")]
+use crate::sse::A2ATaskEvent;
+#[cfg(feature = "transport-sse")]
+use chrono::Utc;
+use serde_json::Value;
+
+/// Persist a task status change and stream it to subscribers
+///
+/// Terminal statuses are followed by a `done` event carrying `result` or `error`,
+/// which closes the subscribers' streams. Running tasks are tracked so graceful
+/// shutdown can wait for them.
+///
+/// # Errors
+///
+/// Returns an error if the task status cannot be persisted
+pub async fn update_task_status(
+    resources: &ServerResources,
+    task_id: &str,
+    status: TaskStatus,
+    result: Option<Value>,
+    error: Option<String>,
+) -> AppResult<()> {
+    resources
+        .database
+        .update_a2a_task_status(task_id, &status, result.as_ref(), error.as_deref())
+        .await?;
+
+    if status == TaskStatus::Running {
+        resources.shutdown.task_started(task_id);
+    } else if status.is_terminal() {
+        resources.shutdown.task_finished(task_id);
+    }
+
+    #[cfg(feature = "transport-sse")]
+    {
+        let manager = &resources.sse_manager;
+        manager
+            .publish_a2a_task_event(&A2ATaskEvent::Status {
+                task_id: task_id.to_owned(),
+                status: status.clone(),
+                timestamp: Utc::now(),
+            })
+            .await;
+
+        if status.is_terminal() {
+            manager
+                .publish_a2a_task_event(&A2ATaskEvent::Done {
+                    task_id: task_id.to_owned(),
+                    status,
+                    result,
+                    error,
+                })
+                .await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Stream an incremental piece of a running task's result to subscribers
+///
+/// Chunks are not persisted; the full result is stored by the final
+/// [`update_task_status`] call.
+#[cfg(feature = "transport-sse")]
+pub async fn publish_task_chunk(resources: &ServerResources, task_id: &str, data: Value) {
+    resources
+        .sse_manager
+        .publish_a2a_task_event(&A2ATaskEvent::Chunk {
+            task_id: task_id.to_owned(),
+            data,
+        })
+        .await;
+}
@@ -15,10 +15,11 @@ use crate::a2a::{
         A2AClientManager, A2ARateLimitStatus, ClientCredentials, ClientRegistrationRequest,
         ClientUsageStats,
     },
-    protocol::A2AError,
+    protocol::{A2AError, A2AErrorResponse},
 };
 use crate::constants::time::DAY_SECONDS;
 use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, ErrorCode, ErrorDetails};
 use crate::mcp::resources::ServerResources;
 use crate::mcp::tenant_isolation::extract_tenant_context_internal;
 use crate::protocols::universal::{UniversalRequest, UniversalToolExecutor};
@@ -99,10 +100,11 @@ impl A2ARoutes {
     /// Extract and validate JWT token from Authorization header
     fn extract_jwt_token(auth_header: Option<&str>) -> Result<String, Value> {
         let auth = auth_header.ok_or_else(|| {
-            json!({
-                "code": -32001,
-                "message": "Missing Authorization header"
-            })
+            json!(A2AErrorResponse::with_error_code(
+                -32001,
+                ErrorCode::AuthRequired,
+                "Missing Authorization header",
+            ))
         })?;
 
         let token = extract_bearer_token(auth).map_err(|e| {
@@ -110,10 +112,11 @@ impl A2ARoutes {
                 error = %e,
                 "Failed to extract bearer token from A2A authorization header"
             );
-            json!({
-                "code": -32001,
-                "message": "Invalid authorization header format"
-            })
+            json!(A2AErrorResponse::with_error_code(
+                -32001,
+                ErrorCode::AuthMalformed,
+                "Invalid authorization header format",
+            ))
         })?;
 
         Ok(token.to_owned())
@@ -130,10 +133,11 @@ impl A2ARoutes {
                     error = %e,
                     "A2A authentication token validation failed"
                 );
-                json!({
-                    "code": -32001,
-                    "message": "Invalid or expired authentication token"
-                })
+                json!(A2AErrorResponse::with_error_code(
+                    -32001,
+                    ErrorCode::AuthInvalid,
+                    "Invalid or expired authentication token",
+                ))
             })
     }
 
@@ -554,14 +558,18 @@ impl A2ARoutes {
                 Ok(response)
             }
             Err(e) => {
+                let message = format!("Tool execution failed: {e}");
+                let error = A2AErrorResponse::from_details(
+                    -32000,
+                    ErrorDetails {
+                        message,
+                        ..ErrorDetails::from(&AppError::from(e))
+                    },
+                );
                 let error_response = json!({
                     "jsonrpc": "2.0",
                     "id": id,
-                    "error": {
-                        "code": -32000,
-                        "message": format!("Tool execution failed: {e}"),
-                        "data": null
-                    }
+                    "error": error
                 });
                 Ok(error_response)
             }
@@ -622,10 +630,11 @@ impl A2ARoutes {
             Err(e) => Ok(json!({
                 "jsonrpc": "2.0",
                 "id": id,
-                "error": {
-                    "code": -32000,
-                    "message": format!("Failed to update session: {e}")
-                }
+                "error": A2AErrorResponse::with_error_code(
+                    -32000,
+                    ErrorCode::DatabaseError,
+                    format!("Failed to update session: {e}"),
+                )
             })),
         }
     }
@@ -695,18 +704,20 @@ impl A2ARoutes {
             _ => Ok(json!({
                 "jsonrpc": "2.0",
                 "id": id,
-                "error": {
-                    "code": -32601,
-                    "message": format!("Method '{method}' not found"),
-                    "data": {
-                        "available_methods": [
-                            "tools.execute",
-                            "client.info",
-                            "session.heartbeat",
-                            "capabilities.list"
-                        ]
-                    }
-                }
+                "error": A2AErrorResponse::with_error_code(
+                    -32601,
+                    ErrorCode::ResourceNotFound,
+                    format!("Method '{method}' not found"),
+                )
+                .with_extra(
+                    "available_methods",
+                    json!([
+                        "tools.execute",
+                        "client.info",
+                        "session.heartbeat",
+                        "capabilities.list"
+                    ]),
+                )
             })),
         }
     }
@@ -18,6 +18,8 @@
 //! - Public keys distributed via `/.well-known/jwks.json`
 //! - Multiple keys supported for graceful rotation
 //! - Old keys retained during rotation window
+//! - Rollover on a shared manager: a new key signs immediately while the
+//!   retiring keys keep validating until their overlap window ends
 //!
 //! ## Example
 //!
@@ -41,8 +43,9 @@
 //! ```
 
 use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{
     decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
 };
@@ -101,6 +104,8 @@ pub struct RsaKeyPair {
     pub created_at: DateTime<Utc>,
     /// Whether this is the currently active signing key
     pub is_active: bool,
+    /// When a retiring key stops validating tokens (`None` while not retiring)
+    pub retires_at: Option<DateTime<Utc>>,
 }
 
 impl RsaKeyPair {
@@ -133,9 +138,16 @@ impl RsaKeyPair {
             public_key,
             created_at: Utc::now(),
             is_active: true,
+            retires_at: None,
         })
     }
 
+    /// Whether the key's overlap window has ended at `now`
+    #[must_use]
+    pub fn is_retired_at(&self, now: DateTime<Utc>) -> bool {
+        self.retires_at.is_some_and(|retires_at| retires_at <= now)
+    }
+
     /// Convert public key to JWK format
     ///
     /// # Errors
@@ -203,6 +215,7 @@ impl RsaKeyPair {
             public_key,
             created_at: Utc::now(),
             is_active: false, // Imported keys start inactive
+            retires_at: None,
         })
     }
 
@@ -229,24 +242,86 @@ impl RsaKeyPair {
     }
 }
 
-/// JWKS manager for key lifecycle management
-pub struct JwksManager {
-    /// All keys (active and historical)
-    keys: HashMap<String, RsaKeyPair>,
+/// Persisted RSA keypair: kid, private key PEM, public key PEM, creation time,
+/// active flag, and retirement deadline
+pub type RsaKeypairRecord = (
+    String,
+    String,
+    String,
+    DateTime<Utc>,
+    bool,
+    Option<DateTime<Utc>>,
+);
+
+/// Result of rolling over to a new signing key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRollover {
+    /// Key ID of the new signing key
+    pub new_kid: String,
+    /// Key IDs of keys that stopped signing and are now retiring
+    pub retiring_kids: Vec<String>,
+    /// When the retiring keys stop validating tokens
+    pub retires_at: DateTime<Utc>,
+}
+
+/// Keys known to the manager and the one currently used for signing
+#[derive(Default)]
+struct KeyRing {
+    /// All keys (active, retiring and historical)
+    keys: HashMap<String, Arc<RsaKeyPair>>,
     /// Currently active key ID for signing
     active_key_id: Option<String>,
 }
 
+impl KeyRing {
+    /// Insert a key, making it the signing key if it is active
+    fn insert(&mut self, key_pair: RsaKeyPair) {
+        if key_pair.is_active {
+            self.deactivate_current();
+            self.active_key_id = Some(key_pair.kid.clone());
+        }
+        self.keys.insert(key_pair.kid.clone(), Arc::new(key_pair));
+    }
+
+    /// Clear the active flag of the current signing key, if any
+    fn deactivate_current(&mut self) {
+        if let Some(prev_active_kid) = &self.active_key_id {
+            if let Some(prev_key) = self.keys.get_mut(prev_active_kid) {
+                Arc::make_mut(prev_key).is_active = false;
+            }
+        }
+    }
+}
+
+/// JWKS manager for key lifecycle management
+///
+/// Keys sit behind a lock so a shared manager can roll over to a new signing
+/// key at runtime while requests keep signing and verifying tokens.
+pub struct JwksManager {
+    ring: RwLock<KeyRing>,
+}
+
 impl JwksManager {
     /// Create new JWKS manager
     #[must_use]
     pub fn new() -> Self {
         Self {
-            keys: HashMap::new(),
-            active_key_id: None,
+            ring: RwLock::new(KeyRing::default()),
         }
     }
 
+    fn read_ring(&self) -> RwLockReadGuard<'_, KeyRing> {
+        self.ring.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write_ring(&self) -> RwLockWriteGuard<'_, KeyRing> {
+        self.ring.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn ring_mut(&mut self) -> &mut KeyRing {
+        self.ring.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Generate and register new RSA key pair with production-grade 4096-bit key size
     ///
     /// # Errors
@@ -268,16 +343,8 @@ impl JwksManager {
     ) -> AppResult<()> {
         let key_pair = RsaKeyPair::generate_with_key_size(kid, key_size_bits)?;
 
-        // Deactivate previous active key if exists
-        if let Some(prev_active_kid) = &self.active_key_id {
-            if let Some(prev_key) = self.keys.get_mut(prev_active_kid) {
-                prev_key.is_active = false;
-            }
-        }
-
-        // Set new key as active
-        self.active_key_id = Some(kid.to_owned());
-        self.keys.insert(kid.to_owned(), key_pair);
+        // Deactivates the previous active key and makes this one the signing key
+        self.ring_mut().insert(key_pair);
 
         Ok(())
     }
@@ -286,27 +353,37 @@ impl JwksManager {
     ///
     /// # Errors
     /// Returns error if no active key exists
-    pub fn get_active_key(&self) -> AppResult<&RsaKeyPair> {
-        let kid = self
+    pub fn get_active_key(&self) -> AppResult<Arc<RsaKeyPair>> {
+        let ring = self.read_ring();
+        let kid = ring
             .active_key_id
             .as_ref()
             .ok_or_else(|| AppError::internal("No active signing key"))?;
 
-        self.keys
+        ring.keys
             .get(kid)
+            .cloned()
             .ok_or_else(|| AppError::internal(format!("Active key not found: {kid}")))
     }
 
     /// Get key by ID
+    ///
+    /// Returns `None` for a retiring key whose overlap window has ended, even
+    /// before [`Self::retire_expired_keys`] removes it.
     #[must_use]
-    pub fn get_key(&self, kid: &str) -> Option<&RsaKeyPair> {
-        self.keys.get(kid)
+    pub fn get_key(&self, kid: &str) -> Option<Arc<RsaKeyPair>> {
+        let now = Utc::now();
+        self.read_ring()
+            .keys
+            .get(kid)
+            .filter(|key| !key.is_retired_at(now))
+            .cloned()
     }
 
     /// Get all keys (for validation)
     #[must_use]
-    pub fn get_all_keys(&self) -> Vec<&RsaKeyPair> {
-        self.keys.values().collect()
+    pub fn get_all_keys(&self) -> Vec<Arc<RsaKeyPair>> {
+        self.read_ring().keys.values().cloned().collect()
     }
 
     /// Register an existing RSA key pair from PEM format (for database loading)
@@ -324,30 +401,26 @@ impl JwksManager {
         key_pair.created_at = created_at;
         key_pair.is_active = is_active;
 
-        // If this key is marked active, deactivate current active key
-        if is_active {
-            if let Some(prev_active_kid) = &self.active_key_id {
-                if let Some(prev_key) = self.keys.get_mut(prev_active_kid) {
-                    prev_key.is_active = false;
-                }
-            }
-            self.active_key_id = Some(kid.to_owned());
-        }
-
-        self.keys.insert(kid.to_owned(), key_pair);
+        // If this key is marked active, it replaces the current active key
+        self.ring_mut().insert(key_pair);
         Ok(())
     }
 
     /// Load keys from database tuples
     ///
+    /// Retiring keys keep their deadline; keys already past it are loaded but
+    /// no longer validate and are dropped by [`Self::retire_expired_keys`].
+    ///
     /// # Errors
     /// Returns error if key import fails
-    pub fn load_keys_from_database(
-        &mut self,
-        keypairs: Vec<(String, String, String, DateTime<Utc>, bool)>,
-    ) -> AppResult<()> {
-        for (kid, private_key_pem, _public_key_pem, created_at, is_active) in keypairs {
-            self.register_keypair_from_pem(&kid, &private_key_pem, created_at, is_active)?;
+    pub fn load_keys_from_database(&mut self, keypairs: Vec<RsaKeypairRecord>) -> AppResult<()> {
+        let ring = self.ring_mut();
+        for (kid, private_key_pem, _public_key_pem, created_at, is_active, retires_at) in keypairs {
+            let mut key_pair = RsaKeyPair::import_private_key_pem(&kid, &private_key_pem)?;
+            key_pair.created_at = created_at;
+            key_pair.is_active = is_active;
+            key_pair.retires_at = retires_at;
+            ring.insert(key_pair);
         }
         Ok(())
     }
@@ -364,14 +437,29 @@ impl JwksManager {
 
     /// Get JWKS structure
     ///
+    /// Lists every key that still validates tokens, newest first, so clients
+    /// holding tokens signed by a retiring key can still verify them.
+    ///
     /// # Errors
     /// Returns error if JWK conversion fails
     pub fn get_jwks(&self) -> AppResult<JsonWebKeySet> {
-        let mut keys = Vec::new();
+        let now = Utc::now();
+        let ring = self.read_ring();
+        let mut key_pairs: Vec<&Arc<RsaKeyPair>> = ring
+            .keys
+            .values()
+            .filter(|key| !key.is_retired_at(now))
+            .collect();
+        key_pairs.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| b.kid.cmp(&a.kid))
+        });
 
-        for key_pair in self.keys.values() {
-            keys.push(key_pair.to_jwk()?);
-        }
+        let keys = key_pairs
+            .into_iter()
+            .map(|key_pair| key_pair.to_jwk())
+            .collect::<AppResult<Vec<_>>>()?;
 
         Ok(JsonWebKeySet { keys })
     }
@@ -394,20 +482,78 @@ impl JwksManager {
         self.generate_rsa_key_pair_with_size(&new_kid, key_size_bits)?;
 
         // Clean up old keys (keep only MAX_HISTORICAL_KEYS)
-        self.cleanup_old_keys();
+        Self::cleanup_old_keys(self.ring_mut());
 
         Ok(new_kid)
     }
 
+    /// Roll over to a freshly generated signing key on a shared manager
+    ///
+    /// The new key signs every token issued from now on. Keys that signed
+    /// before stay published in JWKS and keep validating tokens for `overlap`,
+    /// after which [`Self::retire_expired_keys`] drops them. A key that is
+    /// already retiring keeps its earlier deadline.
+    ///
+    /// # Errors
+    /// Returns error if key generation fails
+    pub fn begin_rollover(
+        &self,
+        key_size_bits: usize,
+        overlap: Duration,
+    ) -> AppResult<KeyRollover> {
+        // Microseconds keep back-to-back rollovers from reusing a kid
+        let new_kid = format!("key_{}", Utc::now().format("%Y%m%d_%H%M%S_%6f"));
+        // Generating a large key takes a while, so do it before taking the lock
+        let key_pair = RsaKeyPair::generate_with_key_size(&new_kid, key_size_bits)?;
+        let retires_at = key_pair.created_at + overlap;
+
+        let mut ring = self.write_ring();
+        let mut retiring_kids = Vec::new();
+        for (kid, key) in &mut ring.keys {
+            let key = Arc::make_mut(key);
+            key.is_active = false;
+            key.retires_at = Some(key.retires_at.map_or(retires_at, |at| at.min(retires_at)));
+            retiring_kids.push(kid.clone());
+        }
+        retiring_kids.sort();
+        ring.insert(key_pair);
+
+        Ok(KeyRollover {
+            new_kid,
+            retiring_kids,
+            retires_at,
+        })
+    }
+
+    /// Drop retiring keys whose overlap window ended at or before `now`
+    ///
+    /// Returns the key IDs that were removed; tokens signed by them no longer
+    /// validate.
+    #[must_use]
+    pub fn retire_expired_keys(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut ring = self.write_ring();
+        let mut retired: Vec<String> = ring
+            .keys
+            .values()
+            .filter(|key| key.is_retired_at(now))
+            .map(|key| key.kid.clone())
+            .collect();
+        for kid in &retired {
+            ring.keys.remove(kid);
+        }
+        retired.sort();
+        retired
+    }
+
     /// Remove old keys beyond retention limit
-    fn cleanup_old_keys(&mut self) {
-        if self.keys.len() <= MAX_HISTORICAL_KEYS {
+    fn cleanup_old_keys(ring: &mut KeyRing) {
+        if ring.keys.len() <= MAX_HISTORICAL_KEYS {
             return;
         }
 
         // Sort keys by creation time, with kid as tiebreaker for deterministic behavior
         // This ensures consistent ordering on systems with low timestamp resolution (Windows)
-        let mut sorted_keys: Vec<_> = self
+        let mut sorted_keys: Vec<_> = ring
             .keys
             .iter()
             .map(|(kid, key)| (kid.clone(), key.created_at))
@@ -418,8 +564,8 @@ impl JwksManager {
         // Remove oldest keys beyond limit
         let to_remove = sorted_keys.len() - MAX_HISTORICAL_KEYS;
         for (kid, _) in sorted_keys.iter().take(to_remove) {
-            if Some(kid) != self.active_key_id.as_ref() {
-                self.keys.remove(kid);
+            if Some(kid) != ring.active_key_id.as_ref() {
+                ring.keys.remove(kid);
             }
         }
     }
@@ -427,8 +573,9 @@ impl JwksManager {
     /// Check if key rotation is needed
     #[must_use]
     pub fn should_rotate_keys(&self) -> bool {
-        if let Some(active_kid) = &self.active_key_id {
-            if let Some(active_key) = self.keys.get(active_kid) {
+        let ring = self.read_ring();
+        if let Some(active_kid) = &ring.active_key_id {
+            if let Some(active_key) = ring.keys.get(active_kid) {
                 let age = Utc::now() - active_key.created_at;
                 return age.num_days() >= KEY_ROTATION_DAYS;
             }
@@ -40,6 +40,8 @@ pub use jwks::JsonWebKey;
 pub use jwks::JsonWebKeySet;
 /// JWKS manager for key rotation
 pub use jwks::JwksManager;
+/// Outcome of rolling over to a new signing key
+pub use jwks::KeyRollover;
 /// RSA key pair for JWT signing
 pub use jwks::RsaKeyPair;
 
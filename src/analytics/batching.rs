@@ -0,0 +1,156 @@
+// ABOUTME: Analytics sink buffering usage records in memory and forwarding them in batches
+// ABOUTME: Flushes when a batch fills up, on a background interval, before rollups, and at shutdown
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::mem;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::warn;
+
+use super::AnalyticsSink;
+use crate::api_keys::{ApiKeyUsage, ApiKeyUsageStats};
+use crate::database::{A2AUsage, A2AUsageStats};
+use crate::errors::AppResult;
+
+/// Records waiting to be forwarded
+#[derive(Default)]
+struct PendingUsage {
+    api_key: Vec<ApiKeyUsage>,
+    a2a: Vec<A2AUsage>,
+}
+
+/// Analytics sink that buffers records and forwards them to `inner` in batches
+///
+/// A batch is forwarded as soon as `batch_size` records of one kind are
+/// buffered, and everything buffered is forwarded on [`AnalyticsSink::flush`].
+/// Rollups flush first so they include every recorded request. A batch the
+/// inner sink rejects is dropped and the error returned to the caller that
+/// triggered the write.
+pub struct BatchingAnalyticsSink {
+    inner: Arc<dyn AnalyticsSink>,
+    batch_size: usize,
+    pending: Mutex<PendingUsage>,
+}
+
+impl BatchingAnalyticsSink {
+    /// Buffer up to `batch_size` records of each kind before forwarding them to `inner`
+    #[must_use]
+    pub fn new(inner: Arc<dyn AnalyticsSink>, batch_size: usize) -> Self {
+        Self {
+            inner,
+            batch_size: batch_size.max(1),
+            pending: Mutex::new(PendingUsage::default()),
+        }
+    }
+
+    /// Number of records buffered and not yet forwarded
+    #[must_use]
+    pub fn pending_records(&self) -> usize {
+        let pending = self.pending();
+        pending.api_key.len() + pending.a2a.len()
+    }
+
+    /// Flush the sink every `period` until it is dropped
+    pub fn spawn_flush_task(self: &Arc<Self>, period: Duration) {
+        let sink = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let Some(sink) = sink.upgrade() else {
+                    break;
+                };
+                if let Err(e) = sink.flush().await {
+                    warn!("Failed to flush buffered usage records: {}", e);
+                }
+            }
+        });
+    }
+
+    fn pending(&self) -> MutexGuard<'_, PendingUsage> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Take the buffered records of one kind if there are at least `threshold`
+    fn take_if_full<T>(buffer: &mut Vec<T>, threshold: usize) -> Option<Vec<T>> {
+        (buffer.len() >= threshold).then(|| mem::take(buffer))
+    }
+}
+
+#[async_trait]
+impl AnalyticsSink for BatchingAnalyticsSink {
+    async fn record_api_key_usage(&self, usage: ApiKeyUsage) -> AppResult<()> {
+        let batch = {
+            let mut pending = self.pending();
+            pending.api_key.push(usage);
+            Self::take_if_full(&mut pending.api_key, self.batch_size)
+        };
+        let Some(batch) = batch else {
+            return Ok(());
+        };
+        self.inner.record_api_key_usage_batch(batch).await
+    }
+
+    async fn record_a2a_usage(&self, usage: A2AUsage) -> AppResult<()> {
+        let batch = {
+            let mut pending = self.pending();
+            pending.a2a.push(usage);
+            Self::take_if_full(&mut pending.a2a, self.batch_size)
+        };
+        let Some(batch) = batch else {
+            return Ok(());
+        };
+        self.inner.record_a2a_usage_batch(batch).await
+    }
+
+    async fn api_key_usage_stats(
+        &self,
+        api_key_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<ApiKeyUsageStats> {
+        self.flush().await?;
+        self.inner
+            .api_key_usage_stats(api_key_id, start_date, end_date)
+            .await
+    }
+
+    async fn a2a_usage_stats(
+        &self,
+        client_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<A2AUsageStats> {
+        self.flush().await?;
+        self.inner
+            .a2a_usage_stats(client_id, start_date, end_date)
+            .await
+    }
+
+    async fn flush(&self) -> AppResult<()> {
+        let PendingUsage { api_key, a2a } = mem::take(&mut *self.pending());
+
+        // Forward both kinds even if the first batch fails
+        let api_key_result = if api_key.is_empty() {
+            Ok(())
+        } else {
+            self.inner.record_api_key_usage_batch(api_key).await
+        };
+        let a2a_result = if a2a.is_empty() {
+            Ok(())
+        } else {
+            self.inner.record_a2a_usage_batch(a2a).await
+        };
+        self.inner.flush().await?;
+        api_key_result.and(a2a_result)
+    }
+}
@@ -0,0 +1,63 @@
+// ABOUTME: Default analytics sink writing usage records to the relational database
+// ABOUTME: Delegates recording and rollups to the DatabaseProvider usage methods
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::AnalyticsSink;
+use crate::api_keys::{ApiKeyUsage, ApiKeyUsageStats};
+use crate::database::{A2AUsage, A2AUsageStats};
+use crate::database_plugins::factory::Database;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+
+/// Analytics sink storing every record in the server database
+pub struct DatabaseAnalyticsSink {
+    database: Arc<Database>,
+}
+
+impl DatabaseAnalyticsSink {
+    /// Create a sink writing to `database`
+    #[must_use]
+    pub const fn new(database: Arc<Database>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl AnalyticsSink for DatabaseAnalyticsSink {
+    async fn record_api_key_usage(&self, usage: ApiKeyUsage) -> AppResult<()> {
+        self.database.record_api_key_usage(&usage).await
+    }
+
+    async fn record_a2a_usage(&self, usage: A2AUsage) -> AppResult<()> {
+        self.database.record_a2a_usage(&usage).await
+    }
+
+    async fn api_key_usage_stats(
+        &self,
+        api_key_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<ApiKeyUsageStats> {
+        self.database
+            .get_api_key_usage_stats(api_key_id, start_date, end_date)
+            .await
+    }
+
+    async fn a2a_usage_stats(
+        &self,
+        client_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<A2AUsageStats> {
+        self.database
+            .get_a2a_usage_stats(client_id, start_date, end_date)
+            .await
+    }
+}
@@ -0,0 +1,116 @@
+// ABOUTME: Pluggable storage backend for API key and A2A usage analytics
+// ABOUTME: Defines the AnalyticsSink trait and builds the configured sink (database or batched)
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Analytics Sinks
+//!
+//! Usage records for API keys and A2A clients are written through an
+//! [`AnalyticsSink`] instead of straight to the database, so high-volume
+//! deployments can offload them to another store. The same sink answers the
+//! usage rollups shown on dashboards and usage endpoints.
+//!
+//! [`DatabaseAnalyticsSink`] is the default and writes each record as it
+//! arrives. [`BatchingAnalyticsSink`] buffers records in memory and forwards
+//! them to another sink in batches, either when a batch fills up or on a
+//! fixed interval.
+//!
+//! Per-key and per-client rate limits count requests in the database, so a
+//! sink that does not eventually write through to it disables usage-based
+//! quotas.
+
+/// Batching sink that buffers records before forwarding them
+pub mod batching;
+/// Default sink writing records to the relational database
+pub mod database;
+
+pub use batching::BatchingAnalyticsSink;
+pub use database::DatabaseAnalyticsSink;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::api_keys::{ApiKeyUsage, ApiKeyUsageStats};
+use crate::config::{AnalyticsSinkConfig, AnalyticsSinkKind};
+use crate::database::{A2AUsage, A2AUsageStats};
+use crate::database_plugins::factory::Database;
+use crate::errors::AppResult;
+
+/// Storage backend for usage analytics
+#[async_trait]
+pub trait AnalyticsSink: Send + Sync {
+    /// Record a single API key request
+    async fn record_api_key_usage(&self, usage: ApiKeyUsage) -> AppResult<()>;
+
+    /// Record a single A2A request
+    async fn record_a2a_usage(&self, usage: A2AUsage) -> AppResult<()>;
+
+    /// Record several API key requests at once
+    ///
+    /// The default writes the records one at a time; stores with bulk inserts
+    /// should override it.
+    async fn record_api_key_usage_batch(&self, usages: Vec<ApiKeyUsage>) -> AppResult<()> {
+        for usage in usages {
+            self.record_api_key_usage(usage).await?;
+        }
+        Ok(())
+    }
+
+    /// Record several A2A requests at once
+    ///
+    /// The default writes the records one at a time; stores with bulk inserts
+    /// should override it.
+    async fn record_a2a_usage_batch(&self, usages: Vec<A2AUsage>) -> AppResult<()> {
+        for usage in usages {
+            self.record_a2a_usage(usage).await?;
+        }
+        Ok(())
+    }
+
+    /// Aggregate an API key's requests between `start_date` and `end_date`
+    async fn api_key_usage_stats(
+        &self,
+        api_key_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<ApiKeyUsageStats>;
+
+    /// Aggregate an A2A client's requests between `start_date` and `end_date`
+    async fn a2a_usage_stats(
+        &self,
+        client_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<A2AUsageStats>;
+
+    /// Write any buffered records to the underlying store
+    async fn flush(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// Build the sink selected by `config`, writing through to `database`
+///
+/// The batched sink starts a background task flushing it every
+/// [`AnalyticsSinkConfig::flush_interval`], so this must run inside a Tokio runtime.
+#[must_use]
+pub fn create_analytics_sink(
+    config: &AnalyticsSinkConfig,
+    database: Arc<Database>,
+) -> Arc<dyn AnalyticsSink> {
+    let database_sink = Arc::new(DatabaseAnalyticsSink::new(database));
+    match config.kind() {
+        AnalyticsSinkKind::Database => database_sink,
+        AnalyticsSinkKind::Batched => {
+            let sink = Arc::new(BatchingAnalyticsSink::new(
+                database_sink,
+                config.batch_size(),
+            ));
+            sink.spawn_flush_task(config.flush_interval());
+            sink
+        }
+    }
+}
@@ -261,8 +261,8 @@ impl ApiKeyRoutes {
 
         let stats = self
             .resources
-            .database
-            .get_api_key_usage_stats(api_key_id, start_date, end_date)
+            .analytics
+            .api_key_usage_stats(api_key_id, start_date, end_date)
             .await?;
 
         Ok(ApiKeyUsageResponse { stats })
@@ -28,8 +28,12 @@ use tracing::{debug, error, warn};
 use uuid::Uuid;
 
 use crate::admin::jwks::JwksManager;
+use crate::config::AuthConfig;
 use crate::constants::{
-    limits::{OAUTH_ACCESS_TOKEN_EXPIRY_HOURS, USER_SESSION_EXPIRY_HOURS},
+    limits::{
+        ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES, OAUTH_ACCESS_TOKEN_EXPIRY_HOURS,
+        USER_SESSION_EXPIRY_HOURS,
+    },
     service_names::{MCP, PIERRE_MCP_SERVER},
     time_constants::SECONDS_PER_HOUR,
 };
@@ -39,6 +43,9 @@ use crate::models::{AuthRequest, AuthResponse, User, UserSession};
 use crate::rate_limiting::UnifiedRateLimitInfo;
 use crate::utils::uuid::parse_uuid;
 
+/// Scope carried by admin impersonation tokens
+pub const ADMIN_IMPERSONATION_SCOPE: &str = "impersonation:read";
+
 /// Response for checking system setup status
 ///
 /// Used by admin setup flow to determine if initial configuration is needed.
@@ -148,13 +155,15 @@ pub struct Claims {
     pub iat: i64,
     /// Expiration timestamp
     pub exp: i64,
-    /// Issuer (who issued the token)
+    /// Issuer (who issued the token); empty when absent from a decoded token
+    #[serde(default)]
     pub iss: String,
     /// JWT ID (unique identifier for this token)
     pub jti: String,
     /// Available fitness providers
     pub providers: Vec<String>,
-    /// Audience (who the token is intended for)
+    /// Audience (who the token is intended for); empty when absent from a decoded token
+    #[serde(default)]
     pub aud: String,
     /// Active tenant `ID` for this session (user can belong to multiple tenants)
     /// This is the tenant context for all operations in this session.
@@ -167,6 +176,20 @@ pub struct Claims {
     /// Impersonation session ID for audit trail
     #[serde(skip_serializing_if = "Option::is_none")]
     pub impersonation_session_id: Option<String>,
+    /// Admin token ID that issued this token through `POST /admin/impersonate`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonated_by: Option<String>,
+    /// Scope restricting what the token may be used for; absent on regular tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+impl Claims {
+    /// Whether this token lets someone act as the subject user on their behalf
+    #[must_use]
+    pub const fn is_impersonation(&self) -> bool {
+        self.impersonator_id.is_some() || self.impersonated_by.is_some()
+    }
 }
 
 /// Authentication result with user context and rate limiting info
@@ -200,6 +223,13 @@ pub enum AuthMethod {
         /// `API` key tier
         tier: String,
     },
+    /// Short-lived `JWT` issued to an admin acting as the user
+    ImpersonationToken {
+        /// User tier for rate limiting
+        tier: String,
+        /// Admin token `ID` the actions are attributed to
+        impersonated_by: String,
+    },
 }
 
 impl AuthMethod {
@@ -209,6 +239,7 @@ impl AuthMethod {
         match self {
             Self::JwtToken { .. } => "JWT Token",
             Self::ApiKey { .. } => "API Key",
+            Self::ImpersonationToken { .. } => "Impersonation Token",
         }
     }
 
@@ -222,28 +253,145 @@ impl AuthMethod {
             Self::ApiKey { key_id, tier } => {
                 format!("API Key (tier: {tier}, id: {key_id})")
             }
+            Self::ImpersonationToken {
+                tier,
+                impersonated_by,
+            } => {
+                format!("Impersonation Token (tier: {tier}, impersonated by: {impersonated_by})")
+            }
+        }
+    }
+
+    /// Admin token `ID` when the request runs under an impersonation token
+    #[must_use]
+    pub fn impersonated_by(&self) -> Option<&str> {
+        match self {
+            Self::ImpersonationToken {
+                impersonated_by, ..
+            } => Some(impersonated_by),
+            Self::JwtToken { .. } | Self::ApiKey { .. } => None,
         }
     }
 }
 
+/// Issuer and audience enforced on `JWT` tokens
+///
+/// Both values are written into every issued token and must match on validation.
+/// Setting `allow_missing_claims` lets tokens minted before the claims were enforced
+/// keep working until they expire; tokens carrying a different value are always rejected.
+#[derive(Debug, Clone)]
+pub struct JwtClaimsPolicy {
+    /// Expected `iss` claim
+    pub issuer: String,
+    /// Expected `aud` claim
+    pub audience: String,
+    /// Accept tokens that omit `iss` or `aud`
+    pub allow_missing_claims: bool,
+}
+
+impl Default for JwtClaimsPolicy {
+    fn default() -> Self {
+        Self {
+            issuer: PIERRE_MCP_SERVER.to_owned(),
+            audience: MCP.to_owned(),
+            allow_missing_claims: false,
+        }
+    }
+}
+
+impl JwtClaimsPolicy {
+    /// Build the policy from the `PIERRE_JWT_*` authentication settings
+    #[must_use]
+    pub fn from_auth_config(config: &AuthConfig) -> Self {
+        Self {
+            issuer: config.jwt_issuer.clone(),
+            audience: config.jwt_audience.clone(),
+            allow_missing_claims: config.jwt_allow_missing_claims,
+        }
+    }
+
+    /// Check the `iss` and `aud` claims of a decoded token against this policy
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtValidationError::TokenInvalid`] if a claim does not match, or
+    /// is missing while `allow_missing_claims` is disabled
+    pub fn check(&self, claims: &Claims) -> Result<(), JwtValidationError> {
+        self.check_claim("iss", &claims.iss, &self.issuer, &claims.sub)?;
+        self.check_claim("aud", &claims.aud, &self.audience, &claims.sub)
+    }
+
+    fn check_claim(
+        &self,
+        name: &str,
+        actual: &str,
+        expected: &str,
+        subject: &str,
+    ) -> Result<(), JwtValidationError> {
+        if actual.is_empty() {
+            if self.allow_missing_claims {
+                warn!(
+                    "Accepting JWT without {} claim for user {} (missing claims allowed)",
+                    name, subject
+                );
+                return Ok(());
+            }
+            return Err(JwtValidationError::TokenInvalid {
+                reason: format!("Token is missing the required {name} claim"),
+            });
+        }
+        if actual != expected {
+            warn!(
+                "JWT {} claim mismatch for user {}: expected {}, got {}",
+                name, subject, expected, actual
+            );
+            return Err(JwtValidationError::TokenInvalid {
+                reason: format!("Token {name} claim '{actual}' is not accepted"),
+            });
+        }
+        Ok(())
+    }
+}
+
 /// Authentication manager for `JWT` tokens and user sessions
+#[derive(Clone)]
 pub struct AuthManager {
     token_expiry_hours: i64,
+    claims_policy: JwtClaimsPolicy,
 }
 
-impl Clone for AuthManager {
-    fn clone(&self) -> Self {
+impl AuthManager {
+    /// Create a new authentication manager with the default issuer and audience
+    #[must_use]
+    pub fn new(token_expiry_hours: i64) -> Self {
         Self {
-            token_expiry_hours: self.token_expiry_hours,
+            token_expiry_hours,
+            claims_policy: JwtClaimsPolicy::default(),
         }
     }
-}
 
-impl AuthManager {
-    /// Create a new authentication manager
+    /// Use a custom issuer/audience policy for issued and validated tokens
+    #[must_use]
+    pub fn with_claims_policy(mut self, claims_policy: JwtClaimsPolicy) -> Self {
+        self.claims_policy = claims_policy;
+        self
+    }
+
+    /// Issuer and audience policy applied to tokens
     #[must_use]
-    pub const fn new(token_expiry_hours: i64) -> Self {
-        Self { token_expiry_hours }
+    pub const fn claims_policy(&self) -> &JwtClaimsPolicy {
+        &self.claims_policy
+    }
+
+    /// RS256 validation with `iss`/`aud` left to [`JwtClaimsPolicy::check`]
+    ///
+    /// The library checks would reject tokens lacking the claims outright, which
+    /// would make the missing-claims grace period impossible.
+    fn rs256_validation(validate_exp: bool) -> Validation {
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.validate_exp = validate_exp;
+        validation.validate_aud = false;
+        validation
     }
 
     /// Generate a `JWT` token for a user with RS256 asymmetric signing
@@ -290,13 +438,15 @@ impl AuthManager {
             email: user.email.clone(),
             iat: now.timestamp(),
             exp: expiry.timestamp(),
-            iss: PIERRE_MCP_SERVER.to_owned(),
+            iss: self.claims_policy.issuer.clone(),
             jti: Uuid::new_v4().to_string(),
             providers: user.available_providers(),
-            aud: MCP.to_owned(),
+            aud: self.claims_policy.audience.clone(),
             active_tenant_id,
             impersonator_id: None,
             impersonation_session_id: None,
+            impersonated_by: None,
+            scope: None,
         };
 
         // Get active RSA key from JWKS manager
@@ -338,13 +488,15 @@ impl AuthManager {
             email: target_user.email.clone(),
             iat: now.timestamp(),
             exp: expiry.timestamp(),
-            iss: PIERRE_MCP_SERVER.to_owned(),
+            iss: self.claims_policy.issuer.clone(),
             jti: Uuid::new_v4().to_string(),
             providers: target_user.available_providers(),
-            aud: MCP.to_owned(),
+            aud: self.claims_policy.audience.clone(),
             active_tenant_id,
             impersonator_id: Some(impersonator_id.to_string()),
             impersonation_session_id: Some(session_id.to_owned()),
+            impersonated_by: None,
+            scope: None,
         };
 
         // Get active RSA key from JWKS manager
@@ -362,6 +514,56 @@ impl AuthManager {
         Ok(token)
     }
 
+    /// Generate a short-lived token for an admin acting as another user
+    ///
+    /// The token carries the issuing admin token in `impersonated_by` and the
+    /// read-only [`ADMIN_IMPERSONATION_SCOPE`], expires after
+    /// `ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES`, and cannot be refreshed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - JWT encoding fails due to invalid claims
+    /// - JWKS manager has no active key
+    pub fn generate_admin_impersonation_token(
+        &self,
+        target_user: &User,
+        admin_token_id: &str,
+        jwks_manager: &JwksManager,
+        active_tenant_id: Option<String>,
+    ) -> AppResult<(String, Claims)> {
+        let now = Utc::now();
+        let expiry = now + Duration::minutes(ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES);
+
+        let claims = Claims {
+            sub: target_user.id.to_string(),
+            email: target_user.email.clone(),
+            iat: now.timestamp(),
+            exp: expiry.timestamp(),
+            iss: self.claims_policy.issuer.clone(),
+            jti: Uuid::new_v4().to_string(),
+            providers: target_user.available_providers(),
+            aud: self.claims_policy.audience.clone(),
+            active_tenant_id,
+            impersonator_id: None,
+            impersonation_session_id: None,
+            impersonated_by: Some(admin_token_id.to_owned()),
+            scope: Some(ADMIN_IMPERSONATION_SCOPE.to_owned()),
+        };
+
+        let active_key = jwks_manager.get_active_key()?;
+        let encoding_key = active_key.encoding_key()?;
+
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(active_key.kid.clone());
+
+        let token = encode(&header, &claims, &encoding_key).map_err(|e| {
+            AppError::internal(format!("Failed to encode impersonation JWT token: {e}"))
+        })?;
+
+        Ok((token, claims))
+    }
+
     /// Validate a RS256 JWT token using JWKS public keys
     ///
     /// # Errors
@@ -392,15 +594,15 @@ impl AuthManager {
             .decoding_key()
             .map_err(|e| AppError::auth_invalid(format!("Failed to get decoding key: {e}")))?;
 
-        let mut validation = Validation::new(Algorithm::RS256);
-        validation.validate_exp = true;
-        validation.set_audience(&[MCP]);
-        validation.set_issuer(&[PIERRE_MCP_SERVER]);
+        let token_data = decode::<Claims>(token, &decoding_key, &Self::rs256_validation(true))
+            .map_err(|e| {
+                error!("RS256 JWT validation failed: {:?}", e);
+                AppError::auth_invalid(format!("JWT validation failed: {e}"))
+            })?;
 
-        let token_data = decode::<Claims>(token, &decoding_key, &validation).map_err(|e| {
-            error!("RS256 JWT validation failed: {:?}", e);
-            AppError::auth_invalid(format!("JWT validation failed: {e}"))
-        })?;
+        self.claims_policy
+            .check(&token_data.claims)
+            .map_err(|e| AppError::auth_invalid(format!("JWT validation failed: {e}")))?;
 
         Ok(token_data.claims)
     }
@@ -477,7 +679,7 @@ impl AuthManager {
     ) -> Result<Claims, JwtValidationError> {
         debug!("Validating RS256 JWT token (length: {} chars)", token.len());
 
-        let claims = Self::decode_token_claims(token, jwks_manager)?;
+        let claims = self.decode_token_claims(token, jwks_manager)?;
         Self::validate_claims_expiry(&claims)?;
 
         debug!(
@@ -488,7 +690,10 @@ impl AuthManager {
     }
 
     /// Decode RS256 JWT token claims without expiration validation
+    ///
+    /// Issuer and audience are still enforced by the claims policy.
     fn decode_token_claims(
+        &self,
         token: &str,
         jwks_manager: &JwksManager,
     ) -> Result<Claims, JwtValidationError> {
@@ -519,14 +724,12 @@ impl AuthManager {
                     reason: format!("Failed to get decoding key: {e}"),
                 })?;
 
-        let mut validation_no_exp = Validation::new(Algorithm::RS256);
-        validation_no_exp.validate_exp = false;
-        validation_no_exp.set_audience(&[MCP]);
-        validation_no_exp.set_issuer(&[PIERRE_MCP_SERVER]);
-
-        decode::<Claims>(token, &decoding_key, &validation_no_exp)
+        let claims = decode::<Claims>(token, &decoding_key, &Self::rs256_validation(false))
             .map(|token_data| token_data.claims)
-            .map_err(|e| Self::convert_jwt_error(&e))
+            .map_err(|e| Self::convert_jwt_error(&e))?;
+
+        self.claims_policy.check(&claims)?;
+        Ok(claims)
     }
 
     /// Validate claims expiration with detailed logging
@@ -625,9 +828,16 @@ impl AuthManager {
     ) -> AppResult<String> {
         // First validate the old token signature (even if expired)
         // This ensures the refresh request is legitimate
-        Self::decode_token_claims(old_token, jwks_manager).map_err(|e| -> AppError {
-            AppError::auth_invalid(format!("Failed to validate old token for refresh: {e}"))
-        })?;
+        let claims = self
+            .decode_token_claims(old_token, jwks_manager)
+            .map_err(|e| AppError::auth_invalid(format!("Failed to validate old token: {e}")))?;
+
+        // Impersonation tokens must expire on schedule
+        if claims.is_impersonation() {
+            return Err(AppError::auth_invalid(
+                "Impersonation tokens cannot be refreshed",
+            ));
+        }
 
         // Generate new token - atomic counter ensures uniqueness
         self.generate_token(user, jwks_manager)
@@ -706,13 +916,15 @@ impl AuthManager {
             email: format!("oauth_{user_id}@system.local"),
             iat: now.timestamp(),
             exp: expiry.timestamp(),
-            iss: PIERRE_MCP_SERVER.to_owned(),
+            iss: self.claims_policy.issuer.clone(),
             jti: Uuid::new_v4().to_string(),
             providers: scopes.to_vec(),
-            aud: MCP.to_owned(),
+            aud: self.claims_policy.audience.clone(),
             active_tenant_id,
             impersonator_id: None,
             impersonation_session_id: None,
+            impersonated_by: None,
+            scope: None,
         };
 
         // Get active RSA key from JWKS manager
@@ -755,13 +967,15 @@ impl AuthManager {
             email: "client_credentials".to_owned(),
             iat: now.timestamp(),
             exp: expiry.timestamp(),
-            iss: PIERRE_MCP_SERVER.to_owned(),
+            iss: self.claims_policy.issuer.clone(),
             jti: Uuid::new_v4().to_string(),
             providers: scopes.to_vec(),
-            aud: MCP.to_owned(),
+            aud: self.claims_policy.audience.clone(),
             active_tenant_id,
             impersonator_id: None,
             impersonation_session_id: None,
+            impersonated_by: None,
+            scope: None,
         };
 
         // Get active RSA key from JWKS manager
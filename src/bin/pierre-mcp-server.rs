@@ -16,7 +16,7 @@ use clap::{error::ErrorKind, Parser};
 #[cfg(feature = "provider-synthetic")]
 use pierre_mcp_server::providers::set_synthetic_database_pool;
 use pierre_mcp_server::{
-    auth::AuthManager,
+    auth::{AuthManager, JwtClaimsPolicy},
     cache::factory::Cache,
     config::environment::{LlmProviderType, ServerConfig, TokioRuntimeConfig},
     constants::init_server_config,
@@ -37,7 +37,7 @@ use pierre_mcp_server::{
 type Result<T> = AppResult<T>;
 use std::{env, sync::Arc};
 use tokio::runtime::{Builder, Runtime};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// Command-line arguments for the Pierre MCP server
 #[derive(Parser)]
@@ -350,6 +350,12 @@ async fn initialize_core_systems(config: &ServerConfig) -> Result<(Database, Aut
     key_manager.complete_initialization(&mut database).await?;
     info!("Two-tier key management system fully initialized");
 
+    // Secrets written before per-tenant data keys still use the global DEK
+    let reencrypted = database.reencrypt_tenant_secrets().await?;
+    if reencrypted > 0 {
+        info!("Re-encrypted {reencrypted} tenant secrets under per-tenant data keys");
+    }
+
     let jwt_secret_string = initialize_jwt_secret(&database, config).await?;
     let auth_manager = create_auth_manager(config);
 
@@ -442,8 +448,17 @@ async fn initialize_jwt_secret(database: &Database, config: &ServerConfig) -> Re
 fn create_auth_manager(config: &ServerConfig) -> AuthManager {
     #[allow(clippy::cast_possible_wrap)]
     {
-        let auth_manager = AuthManager::new(config.auth.jwt_expiry_hours as i64);
-        info!("Authentication manager initialized with RS256");
+        let auth_manager = AuthManager::new(config.auth.jwt_expiry_hours as i64)
+            .with_claims_policy(JwtClaimsPolicy::from_auth_config(&config.auth));
+        info!(
+            "Authentication manager initialized with RS256 (issuer: {}, audience: {})",
+            config.auth.jwt_issuer, config.auth.jwt_audience
+        );
+        if config.auth.jwt_allow_missing_claims {
+            warn!(
+                "PIERRE_JWT_ALLOW_MISSING_CLAIMS is enabled - tokens without iss/aud are accepted"
+            );
+        }
         auth_manager
     }
 }
@@ -697,6 +712,7 @@ fn display_dashboard_endpoints(host: &str, port: u16) {
         endpoints: &[
             ("Health Check:", "GET", "/health"),
             ("Plugin Status:", "GET", "/health/plugins"),
+            ("Provider Health:", "GET", "/health/providers"),
             ("System Status:", "GET", "/dashboard/status"),
             ("User Dashboard:", "GET", "/dashboard/user"),
             ("Admin Dashboard:", "GET", "/dashboard/admin"),
@@ -0,0 +1,43 @@
+// ABOUTME: Database backup and restore commands for pierre-cli
+// ABOUTME: Snapshots SQLite (optionally encrypted with the DEK) or dumps PostgreSQL via pg_dump/pg_restore
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::path::PathBuf;
+
+use pierre_mcp_server::{
+    database_plugins::backup::{backup_database, restore_database},
+    errors::AppResult,
+};
+
+type Result<T> = AppResult<T>;
+use tracing::info;
+
+/// Write a timestamped backup of the database
+pub async fn backup(
+    database_url: &str,
+    output: PathBuf,
+    encrypt: bool,
+    database_key: &[u8; 32],
+) -> Result<()> {
+    info!("Backing up database to {}", output.display());
+
+    let path = backup_database(database_url, &output, encrypt.then_some(database_key)).await?;
+
+    println!("Success Database backup written to {}", path.display());
+    if encrypt {
+        println!("The backup is encrypted with this server's database encryption key.");
+    }
+    Ok(())
+}
+
+/// Replace the database contents with a backup
+pub async fn restore(database_url: &str, input: PathBuf, database_key: &[u8; 32]) -> Result<()> {
+    info!("Restoring database from {}", input.display());
+
+    restore_database(database_url, &input, Some(database_key)).await?;
+
+    println!("Success Database restored from {}", input.display());
+    Ok(())
+}
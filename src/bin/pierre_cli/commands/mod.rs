@@ -1,8 +1,10 @@
 // ABOUTME: Re-exports command modules for pierre-cli
-// ABOUTME: Provides access to token and user management commands
+// ABOUTME: Provides access to database, token, provider OAuth token and user management commands
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
+pub mod db;
+pub mod oauth_token;
 pub mod token;
 pub mod user;
@@ -0,0 +1,91 @@
+// ABOUTME: Provider OAuth token inspection commands for pierre-cli
+// ABOUTME: Lists a user's stored tokens and validates them live without printing secret material
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use pierre_mcp_server::{
+    database_plugins::factory::Database,
+    errors::AppResult,
+    oauth2_client::token_inspection::{
+        list_user_oauth_tokens, validate_user_oauth_token, AthleteProfileCheck, OAuthTokenSummary,
+        TokenValidity,
+    },
+};
+
+type Result<T> = AppResult<T>;
+use tracing::info;
+
+/// List the provider tokens stored for a user
+pub async fn list(database: &Database, email: &str) -> Result<()> {
+    info!("List Listing OAuth tokens for {}", email);
+
+    let tokens = list_user_oauth_tokens(database, email).await?;
+
+    if tokens.is_empty() {
+        println!("No provider OAuth tokens stored for {email}.");
+        return Ok(());
+    }
+
+    println!("\nList Provider OAuth Tokens for {email}:");
+    println!("{}", "=".repeat(80));
+    for token in &tokens {
+        display_token_summary(token);
+    }
+    println!("\nTokens are shown without secret material.");
+    Ok(())
+}
+
+/// Check a user's stored token for a provider against the provider
+pub async fn validate(database: &Database, email: &str, provider: &str) -> Result<()> {
+    info!("Validating {} OAuth token for {}", provider, email);
+
+    let reports =
+        validate_user_oauth_token(database, &AthleteProfileCheck, email, provider).await?;
+
+    println!("\nProvider OAuth Token Validation for {email}:");
+    println!("{}", "=".repeat(80));
+    for report in &reports {
+        display_token_summary(&report.token);
+        let marker = match report.validity {
+            TokenValidity::Valid => "Success",
+            TokenValidity::Expired | TokenValidity::Revoked => "Error",
+        };
+        println!("   Status: {marker} {}", report.validity);
+    }
+    Ok(())
+}
+
+fn display_token_summary(token: &OAuthTokenSummary) {
+    println!("Key Provider: {}", token.provider);
+    println!("   Tenant: {}", token.tenant_id);
+    if token.scopes.is_empty() {
+        println!("   Scopes: (none recorded)");
+    } else {
+        println!("   Scopes: {}", token.scopes.join(", "));
+    }
+    match token.expires_at {
+        Some(expires_at) if token.is_expired() => {
+            println!(
+                "   Expires: {} (expired)",
+                expires_at.format("%Y-%m-%d %H:%M UTC")
+            );
+        }
+        Some(expires_at) => {
+            println!("   Expires: {}", expires_at.format("%Y-%m-%d %H:%M UTC"));
+        }
+        None => println!("   Expires: Unknown"),
+    }
+    println!(
+        "   Refresh Token: {}",
+        if token.has_refresh_token {
+            "Success Available"
+        } else {
+            "Error Missing"
+        }
+    );
+    println!(
+        "   Updated: {}",
+        token.updated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+}
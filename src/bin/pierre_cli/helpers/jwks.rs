@@ -5,7 +5,7 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use pierre_mcp_server::{
-    admin::jwks::JwksManager,
+    admin::jwks::{JwksManager, RsaKeypairRecord},
     database_plugins::{factory::Database, DatabaseProvider},
     errors::AppError,
 };
@@ -34,7 +34,7 @@ pub async fn generate_and_persist_keypair(
 /// Load existing RSA keypairs from database into JWKS manager
 pub fn load_existing_keypairs(
     jwks_manager: &mut JwksManager,
-    keypairs: Vec<(String, String, String, chrono::DateTime<chrono::Utc>, bool)>,
+    keypairs: Vec<RsaKeypairRecord>,
 ) -> Result<(), AppError> {
     info!(
         "Loading {} persisted RSA keypairs from database",
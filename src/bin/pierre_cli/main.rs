@@ -1,5 +1,5 @@
 // ABOUTME: Pierre CLI - unified command-line tool for Pierre MCP Server management
-// ABOUTME: Handles user creation, token management, and administrative operations
+// ABOUTME: Handles user creation, token management, database backups, and administrative operations
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -26,6 +26,18 @@
 //!
 //! # Show token statistics
 //! pierre-cli token stats
+//!
+//! # List a user's stored provider OAuth tokens (never prints the tokens themselves)
+//! pierre-cli token oauth list --user athlete@example.com
+//!
+//! # Check a user's provider token live: valid, expired or revoked
+//! pierre-cli token oauth validate --user athlete@example.com --provider strava
+//!
+//! # Back up the database (timestamped; --encrypt uses the database encryption key)
+//! pierre-cli db backup --output ./backups --encrypt
+//!
+//! # Restore the database from a backup (stop the server first)
+//! pierre-cli db restore --input ./backups/pierre-20250601T080000Z.db.enc
 //! ```
 
 mod commands;
@@ -42,6 +54,7 @@ use pierre_mcp_server::{
 
 type Result<T> = AppResult<T>;
 use std::env;
+use std::path::PathBuf;
 use tracing::info;
 
 use helpers::jwks::initialize_jwks_manager;
@@ -83,6 +96,12 @@ enum Command {
         #[command(subcommand)]
         action: TokenCommand,
     },
+
+    /// Database backup and restore commands
+    Db {
+        #[command(subcommand)]
+        action: DbCommand,
+    },
 }
 
 #[non_exhaustive]
@@ -174,6 +193,56 @@ enum TokenCommand {
         #[arg(long, default_value = "30")]
         days: u32,
     },
+
+    /// Inspect users' stored provider OAuth tokens
+    Oauth {
+        #[command(subcommand)]
+        action: OAuthTokenCommand,
+    },
+}
+
+#[non_exhaustive]
+#[derive(Subcommand)]
+enum OAuthTokenCommand {
+    /// List a user's stored provider tokens (provider, scopes, expiry, refresh availability)
+    List {
+        /// User email
+        #[arg(long)]
+        user: String,
+    },
+
+    /// Check a user's provider token against the provider: valid, expired or revoked
+    Validate {
+        /// User email
+        #[arg(long)]
+        user: String,
+
+        /// Provider name (e.g., strava)
+        #[arg(long)]
+        provider: String,
+    },
+}
+
+#[non_exhaustive]
+#[derive(Subcommand)]
+enum DbCommand {
+    /// Write a timestamped backup (`SQLite`: online snapshot, `PostgreSQL`: `pg_dump`)
+    Backup {
+        /// Backup file or directory; a timestamp is added to the file name
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Encrypt the `SQLite` backup with AES-256-GCM using the database encryption key
+        #[arg(long)]
+        encrypt: bool,
+    },
+
+    /// Restore the database from a backup (stop the server first)
+    Restore {
+        /// Backup file to restore
+        #[arg(long)]
+        input: PathBuf,
+    },
 }
 
 #[tokio::main]
@@ -210,6 +279,20 @@ async fn main() -> Result<()> {
     key_manager.complete_initialization(&mut database).await?;
     info!("Two-tier key management system fully initialized for pierre-cli");
 
+    // Backups work on the database file/server directly, so release the pool first
+    if let Command::Db { action } = cli.command {
+        database.close().await;
+        let database_key = key_manager.database_key();
+        return match action {
+            DbCommand::Backup { output, encrypt } => {
+                commands::db::backup(&database_url, output, encrypt, database_key).await
+            }
+            DbCommand::Restore { input } => {
+                commands::db::restore(&database_url, input, database_key).await
+            }
+        };
+    }
+
     // Run database migrations to ensure admin_tokens table exists
     info!("Running database migrations...");
     database.migrate().await?;
@@ -268,7 +351,17 @@ async fn main() -> Result<()> {
             TokenCommand::Stats { token_id, days } => {
                 commands::token::stats(&database, token_id, days).await?;
             }
+            TokenCommand::Oauth { action } => match action {
+                OAuthTokenCommand::List { user } => {
+                    commands::oauth_token::list(&database, &user).await?;
+                }
+                OAuthTokenCommand::Validate { user, provider } => {
+                    commands::oauth_token::validate(&database, &user, &provider).await?;
+                }
+            },
         },
+        // Database commands return before migrations run
+        Command::Db { .. } => {}
     }
 
     Ok(())
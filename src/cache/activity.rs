@@ -0,0 +1,223 @@
+// ABOUTME: Per-user, per-provider activity list cache placed in front of provider API calls
+// ABOUTME: TTL entries keyed on query params with an injectable clock and webhook-driven invalidation
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Activity Cache
+//!
+//! Repeated `get_activities` calls within seconds of each other would otherwise
+//! each reach the provider API and burn through its rate limit. [`ActivityCache`]
+//! keeps the activity list returned for every user, provider, and query for a TTL
+//! (by default [`DEFAULT_ANALYTICS_CACHE_TTL_SECS`]). When a provider webhook
+//! reports new data, [`ActivityCache::invalidate`] drops every cached query for
+//! that user and provider so the next call fetches fresh data.
+//!
+//! Expiry is measured with a [`Clock`], so tests can inject a fake clock instead
+//! of sleeping.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::constants::defaults::DEFAULT_ANALYTICS_CACHE_TTL_SECS;
+use crate::errors::AppResult;
+use crate::models::Activity;
+use crate::providers::core::ActivityQueryParams;
+
+/// Source of the current instant used for cache expiry
+pub trait Clock: Send + Sync {
+    /// Current instant
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by [`Instant::now`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Query parameters that distinguish one cached activity list from another
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct QueryKey {
+    limit: Option<usize>,
+    offset: Option<usize>,
+    before: Option<DateTime<Utc>>,
+    after: Option<DateTime<Utc>>,
+}
+
+impl From<&ActivityQueryParams> for QueryKey {
+    fn from(params: &ActivityQueryParams) -> Self {
+        Self {
+            limit: params.limit,
+            offset: params.offset,
+            before: params.before,
+            after: params.after,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct EntryKey {
+    user_id: Uuid,
+    provider: String,
+    query: QueryKey,
+}
+
+struct Entry {
+    activities: Vec<Activity>,
+    expires_at: Instant,
+}
+
+/// TTL cache of provider activity lists keyed by user, provider, and query
+pub struct ActivityCache {
+    entries: Mutex<HashMap<EntryKey, Entry>>,
+    ttl: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for ActivityCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_ANALYTICS_CACHE_TTL_SECS))
+    }
+}
+
+impl ActivityCache {
+    /// Create a cache with the given TTL using the system clock
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_clock(ttl, Arc::new(SystemClock))
+    }
+
+    /// Create a cache with the given TTL measured by `clock`
+    #[must_use]
+    pub fn with_clock(ttl: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+            clock,
+        }
+    }
+
+    /// How long entries stay valid after being stored
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Cached activities for this query, if present and not expired
+    #[must_use]
+    pub fn get(
+        &self,
+        user_id: Uuid,
+        provider: &str,
+        params: &ActivityQueryParams,
+    ) -> Option<Vec<Activity>> {
+        let key = EntryKey {
+            user_id,
+            provider: provider.to_owned(),
+            query: params.into(),
+        };
+        let now = self.clock.now();
+        let mut entries = self.lock();
+        match entries.get(&key) {
+            Some(entry) if entry.expires_at > now => Some(entry.activities.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store the activities returned for this query
+    pub fn insert(
+        &self,
+        user_id: Uuid,
+        provider: &str,
+        params: &ActivityQueryParams,
+        activities: Vec<Activity>,
+    ) {
+        let key = EntryKey {
+            user_id,
+            provider: provider.to_owned(),
+            query: params.into(),
+        };
+        let expires_at = self.clock.now() + self.ttl;
+        self.lock().insert(
+            key,
+            Entry {
+                activities,
+                expires_at,
+            },
+        );
+    }
+
+    /// Return cached activities, or call `fetch` and cache its result
+    ///
+    /// With `no_cache` set the cached entry is ignored and the provider is always
+    /// called; the fresh result still replaces the cached one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `fetch`; failed fetches are not cached
+    pub async fn get_or_fetch<F, Fut>(
+        &self,
+        user_id: Uuid,
+        provider: &str,
+        params: &ActivityQueryParams,
+        no_cache: bool,
+        fetch: F,
+    ) -> AppResult<Vec<Activity>>
+    where
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = AppResult<Vec<Activity>>> + Send,
+    {
+        if !no_cache {
+            if let Some(activities) = self.get(user_id, provider, params) {
+                debug!(%user_id, provider, "Activity cache hit");
+                return Ok(activities);
+            }
+        }
+
+        let activities = fetch().await?;
+        self.insert(user_id, provider, params, activities.clone());
+        Ok(activities)
+    }
+
+    /// Drop every cached query for a user and provider
+    ///
+    /// Call this when a provider webhook reports new or changed data for the user.
+    /// Returns the number of entries removed.
+    pub fn invalidate(&self, user_id: Uuid, provider: &str) -> usize {
+        self.remove_where(|key| key.user_id == user_id && key.provider == provider)
+    }
+
+    /// Drop every cached query for a user across all providers
+    ///
+    /// Returns the number of entries removed.
+    pub fn invalidate_user(&self, user_id: Uuid) -> usize {
+        self.remove_where(|key| key.user_id == user_id)
+    }
+
+    fn remove_where(&self, matches: impl Fn(&EntryKey) -> bool) -> usize {
+        let mut entries = self.lock();
+        let before = entries.len();
+        entries.retain(|key, _| !matches(key));
+        before - entries.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<EntryKey, Entry>> {
+        // Entries are replaced whole, so a panic mid-operation cannot leave one half-written
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -81,6 +81,7 @@ impl Cache {
                     activity_list_secs: server_config.cache.ttl.activity_list_secs,
                     activity_secs: server_config.cache.ttl.activity_secs,
                     stats_secs: server_config.cache.ttl.stats_secs,
+                    analysis_secs: server_config.cache.ttl.analysis_secs,
                 },
             },
         );
@@ -4,6 +4,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
+/// Per-user activity list cache in front of provider API calls
+pub mod activity;
 /// Cache factory for creating cache providers
 pub mod factory;
 /// In-memory cache implementation
@@ -15,8 +17,9 @@ use crate::config::admin::service::AdminConfigService;
 use crate::config::environment::RedisConnectionConfig;
 use crate::constants::cache::{
     DEFAULT_CACHE_MAX_ENTRIES, DEFAULT_CLEANUP_INTERVAL_SECS, TTL_ACTIVITY_LIST_SECS,
-    TTL_ACTIVITY_SECS, TTL_PROFILE_SECS, TTL_STATS_SECS,
+    TTL_ACTIVITY_SECS, TTL_ANALYSIS_SECS, TTL_PROFILE_SECS, TTL_STATS_SECS,
 };
+use crate::constants::defaults::DEFAULT_WEATHER_CACHE_TTL_SECS;
 use crate::errors::AppResult;
 use pierre_core::models::TenantId;
 use serde::{Deserialize, Serialize};
@@ -230,6 +233,8 @@ pub struct CacheTtlConfig {
     pub activity_secs: u64,
     /// Stats cache TTL in seconds (default: 6 hours)
     pub stats_secs: u64,
+    /// Analysis result cache TTL in seconds (default: 7 days)
+    pub analysis_secs: u64,
 }
 
 impl Default for CacheTtlConfig {
@@ -239,6 +244,7 @@ impl Default for CacheTtlConfig {
             activity_list_secs: TTL_ACTIVITY_LIST_SECS,
             activity_secs: TTL_ACTIVITY_SECS,
             stats_secs: TTL_STATS_SECS,
+            analysis_secs: TTL_ANALYSIS_SECS,
         }
     }
 }
@@ -254,6 +260,7 @@ impl CacheTtlConfig {
                 Duration::from_secs(self.activity_secs)
             }
             CacheResource::Stats { .. } => Duration::from_secs(self.stats_secs),
+            CacheResource::AnalysisResult { .. } => Duration::from_secs(self.analysis_secs),
         }
     }
 
@@ -315,11 +322,20 @@ impl CacheTtlConfig {
         )
         .await;
 
+        let analysis_secs = Self::get_ttl_value(
+            admin_config,
+            "cache.analysis_ttl_secs",
+            tenant_id,
+            defaults.analysis_secs,
+        )
+        .await;
+
         Self {
             profile_secs,
             activity_list_secs,
             activity_secs,
             stats_secs,
+            analysis_secs,
         }
     }
 
@@ -438,6 +454,20 @@ pub enum CacheResource {
         /// Activity ID
         activity_id: u64,
     },
+    /// Analysis result keyed on a hash of its exact inputs (7d TTL)
+    AnalysisResult {
+        /// Analysis name (e.g., `fitness_score`)
+        analysis: String,
+        /// Version of the algorithm that produced the result
+        algorithm_version: u32,
+        /// Hash of the input activities and parameters
+        input_hash: String,
+    },
+    /// Historical weather at an activity's start location and time (30min TTL)
+    ActivityWeather {
+        /// Activity ID
+        activity_id: String,
+    },
 }
 
 impl CacheResource {
@@ -451,6 +481,8 @@ impl CacheResource {
                 Duration::from_secs(TTL_ACTIVITY_SECS)
             }
             Self::Stats { .. } => Duration::from_secs(TTL_STATS_SECS),
+            Self::AnalysisResult { .. } => Duration::from_secs(TTL_ANALYSIS_SECS),
+            Self::ActivityWeather { .. } => Duration::from_secs(DEFAULT_WEATHER_CACHE_TTL_SECS),
         }
     }
 }
@@ -481,6 +513,12 @@ impl fmt::Display for CacheResource {
             Self::DetailedActivity { activity_id } => {
                 write!(f, "detailed_activity:{activity_id}")
             }
+            Self::AnalysisResult {
+                analysis,
+                algorithm_version,
+                input_hash,
+            } => write!(f, "analysis:{analysis}:v{algorithm_version}:{input_hash}"),
+            Self::ActivityWeather { activity_id } => write!(f, "activity_weather:{activity_id}"),
         }
     }
 }
@@ -0,0 +1,88 @@
+// ABOUTME: Coach context templates injected ahead of MCP coaching conversations
+// ABOUTME: Substitutes athlete variables such as name and current CTL into stored templates
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Coach Context
+//!
+//! A coach context is a short system prompt a tenant (or a single coach) stores
+//! so every MCP client starts coaching conversations with the same framing.
+//! Templates may reference these variables:
+//!
+//! | Placeholder        | Value                                  |
+//! |--------------------|----------------------------------------|
+//! | `{{athlete_name}}` | User display name, or email if unset   |
+//! | `{{ctl}}`          | Chronic training load (fitness)        |
+//! | `{{atl}}`          | Acute training load (fatigue)          |
+//! | `{{tsb}}`          | Training stress balance (form)         |
+//!
+//! Training load values are rendered with one decimal, or `unknown` when no
+//! activity data is available. Unrecognized placeholders are left untouched.
+
+/// Context used when neither the tenant nor the coach has stored one
+pub const DEFAULT_COACH_CONTEXT: &str = "You are a fitness coach working with {{athlete_name}}. \
+Current fitness (CTL) is {{ctl}}, fatigue (ATL) is {{atl}} and form (TSB) is {{tsb}}. \
+Ground advice in the athlete's recent training data and keep recommendations practical and safe.";
+
+/// Rendered in place of a training load value that could not be calculated
+const UNKNOWN_VALUE: &str = "unknown";
+
+/// Placeholders that need the athlete's training load
+const TRAINING_LOAD_PLACEHOLDERS: [&str; 3] = ["{{ctl}}", "{{atl}}", "{{tsb}}"];
+
+/// Values substituted into a coach context template
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoachContextVariables {
+    /// Name the coach addresses the athlete by
+    pub athlete_name: String,
+    /// Chronic training load, if known
+    pub ctl: Option<f64>,
+    /// Acute training load, if known
+    pub atl: Option<f64>,
+    /// Training stress balance, if known
+    pub tsb: Option<f64>,
+}
+
+impl CoachContextVariables {
+    /// Variables for an athlete whose training load is not known
+    #[must_use]
+    pub fn for_athlete(athlete_name: impl Into<String>) -> Self {
+        Self {
+            athlete_name: athlete_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the training load values
+    #[must_use]
+    pub const fn with_training_load(mut self, ctl: f64, atl: f64, tsb: f64) -> Self {
+        self.ctl = Some(ctl);
+        self.atl = Some(atl);
+        self.tsb = Some(tsb);
+        self
+    }
+}
+
+/// Whether a template references any training load variable
+///
+/// Lets callers skip fetching activities when the template does not need them.
+#[must_use]
+pub fn references_training_load(template: &str) -> bool {
+    TRAINING_LOAD_PLACEHOLDERS
+        .iter()
+        .any(|placeholder| template.contains(placeholder))
+}
+
+/// Substitute the variables into a coach context template
+#[must_use]
+pub fn render_coach_context(template: &str, variables: &CoachContextVariables) -> String {
+    let format_load =
+        |value: Option<f64>| value.map_or_else(|| UNKNOWN_VALUE.to_owned(), |v| format!("{v:.1}"));
+
+    template
+        .replace("{{athlete_name}}", &variables.athlete_name)
+        .replace("{{ctl}}", &format_load(variables.ctl))
+        .replace("{{atl}}", &format_load(variables.atl))
+        .replace("{{tsb}}", &format_load(variables.tsb))
+}
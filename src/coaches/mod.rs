@@ -27,9 +27,14 @@
 //! System prompt for the AI.
 //! ```
 
+/// Coach context templates with athlete variable substitution
+pub mod context;
 /// Coach markdown file parser with frontmatter and section extraction
 pub mod parser;
 
+pub use context::{
+    references_training_load, render_coach_context, CoachContextVariables, DEFAULT_COACH_CONTEXT,
+};
 pub use parser::{
     parse_coach_content, parse_coach_file, parse_frontmatter, parse_sections, to_markdown,
     CoachDefinition, CoachFrontmatter, CoachPrerequisites, CoachSections, CoachStartup,
@@ -416,6 +416,33 @@ impl AdminConfigService {
             },
         );
 
+        Self::add_definition(
+            &mut defs,
+            ParameterDefinition {
+                key: "algorithm.vo2max".to_owned(),
+                display_name: "VO2max Estimation".to_owned(),
+                description: "Model used to estimate VO2max from races and heart rate data"
+                    .to_owned(),
+                category: "algorithms".to_owned(),
+                data_type: ConfigDataType::Enum,
+                default_value: serde_json::json!("daniels_vdot"),
+                valid_range: None,
+                enum_options: Some(vec![
+                    "cooper_test".to_owned(),
+                    "daniels_vdot".to_owned(),
+                    "heart_rate_ratio".to_owned(),
+                    "firstbeat_like".to_owned(),
+                ]),
+                units: Some("ml/kg/min".to_owned()),
+                scientific_basis: Some(
+                    "Daniels & Gilbert 1979 oxygen cost and duration equations".to_owned(),
+                ),
+                env_variable: Some("PIERRE_VO2MAX_ALGORITHM".to_owned()),
+                is_runtime_configurable: true,
+                requires_restart: false,
+            },
+        );
+
         // Recommendation Engine
         Self::add_definition(
             &mut defs,
@@ -908,6 +935,30 @@ impl AdminConfigService {
             },
         );
 
+        Self::add_definition(
+            &mut defs,
+            ParameterDefinition {
+                key: "cache.analysis_ttl_secs".to_owned(),
+                display_name: "Analysis Result Cache TTL".to_owned(),
+                description: "Time-to-live for cached analysis results keyed by input hash"
+                    .to_owned(),
+                category: "cache_ttl".to_owned(),
+                data_type: ConfigDataType::Integer,
+                default_value: serde_json::json!(604_800),
+                valid_range: Some(ParameterRange {
+                    min: serde_json::json!(0),
+                    max: serde_json::json!(2_592_000),
+                    step: Some(3600.0),
+                }),
+                enum_options: None,
+                units: Some("seconds".to_owned()),
+                scientific_basis: None,
+                env_variable: Some("CACHE_TTL_ANALYSIS_SECS".to_owned()),
+                is_runtime_configurable: true,
+                requires_restart: false,
+            },
+        );
+
         // Strava Provider Settings
         Self::add_definition(
             &mut defs,
@@ -0,0 +1,180 @@
+// ABOUTME: Analytics sink configuration from environment variables
+// ABOUTME: Parses PIERRE_ANALYTICS_SINK and batching settings controlling where usage records are written
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use tracing::warn;
+
+use crate::errors::AppError;
+
+/// Records buffered before a batched sink writes them when `PIERRE_ANALYTICS_BATCH_SIZE` is unset
+pub const DEFAULT_ANALYTICS_BATCH_SIZE: usize = 100;
+
+/// Seconds between background flushes when `PIERRE_ANALYTICS_FLUSH_INTERVAL_SECS` is unset
+pub const DEFAULT_ANALYTICS_FLUSH_INTERVAL_SECS: u64 = 5;
+
+/// Where API key and A2A usage records are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalyticsSinkKind {
+    /// Write each record to the database as it is recorded
+    #[default]
+    Database,
+    /// Buffer records in memory and write them to the database in batches
+    Batched,
+}
+
+impl AnalyticsSinkKind {
+    /// Name used in `PIERRE_ANALYTICS_SINK`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Database => "database",
+            Self::Batched => "batched",
+        }
+    }
+}
+
+impl fmt::Display for AnalyticsSinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AnalyticsSinkKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "database" | "db" => Ok(Self::Database),
+            "batched" | "batch" => Ok(Self::Batched),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid analytics sink: {s} (expected database or batched)"
+            ))),
+        }
+    }
+}
+
+/// Configuration for the analytics sink receiving usage records
+///
+/// The default sink writes every API key and A2A usage record straight to the
+/// database. The batched sink trades a short delay for fewer writes on busy
+/// deployments; usage-based rate limits see buffered records only after the
+/// next flush.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_ANALYTICS_SINK=batched
+/// export PIERRE_ANALYTICS_BATCH_SIZE=500
+/// export PIERRE_ANALYTICS_FLUSH_INTERVAL_SECS=2
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalyticsSinkConfig {
+    kind: AnalyticsSinkKind,
+    batch_size: usize,
+    flush_interval_secs: u64,
+}
+
+impl Default for AnalyticsSinkConfig {
+    fn default() -> Self {
+        Self::database()
+    }
+}
+
+impl AnalyticsSinkConfig {
+    /// Load analytics sink configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_ANALYTICS_SINK`: `database` (default) or `batched`
+    /// - `PIERRE_ANALYTICS_BATCH_SIZE`: Records buffered before a batch is written (default: 100)
+    /// - `PIERRE_ANALYTICS_FLUSH_INTERVAL_SECS`: Seconds between background flushes (default: 5)
+    ///
+    /// Invalid values fall back to the defaults.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let kind = env::var("PIERRE_ANALYTICS_SINK")
+            .ok()
+            .and_then(|value| {
+                value
+                    .parse()
+                    .inspect_err(|e| warn!("Invalid PIERRE_ANALYTICS_SINK: {}", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+        let batch_size =
+            parse_positive("PIERRE_ANALYTICS_BATCH_SIZE").unwrap_or(DEFAULT_ANALYTICS_BATCH_SIZE);
+        let flush_interval_secs = parse_positive("PIERRE_ANALYTICS_FLUSH_INTERVAL_SECS")
+            .unwrap_or(DEFAULT_ANALYTICS_FLUSH_INTERVAL_SECS);
+
+        Self {
+            kind,
+            batch_size,
+            flush_interval_secs,
+        }
+    }
+
+    /// Write every record straight to the database
+    #[must_use]
+    pub const fn database() -> Self {
+        Self {
+            kind: AnalyticsSinkKind::Database,
+            batch_size: DEFAULT_ANALYTICS_BATCH_SIZE,
+            flush_interval_secs: DEFAULT_ANALYTICS_FLUSH_INTERVAL_SECS,
+        }
+    }
+
+    /// Buffer records and write them to the database in batches
+    #[must_use]
+    pub const fn batched(batch_size: usize, flush_interval_secs: u64) -> Self {
+        Self {
+            kind: AnalyticsSinkKind::Batched,
+            batch_size,
+            flush_interval_secs,
+        }
+    }
+
+    /// Which sink receives usage records
+    #[must_use]
+    pub const fn kind(&self) -> AnalyticsSinkKind {
+        self.kind
+    }
+
+    /// Records buffered before a batch is written
+    #[must_use]
+    pub const fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// How often buffered records are written even if the batch is not full
+    #[must_use]
+    pub const fn flush_interval(&self) -> Duration {
+        Duration::from_secs(self.flush_interval_secs)
+    }
+}
+
+/// Parse a non-zero number from `key`, warning about invalid values
+fn parse_positive<T>(key: &str) -> Option<T>
+where
+    T: FromStr + Default + PartialEq,
+    T::Err: fmt::Display,
+{
+    let value = env::var(key).ok()?;
+    match value.trim().parse::<T>() {
+        Ok(parsed) if parsed != T::default() => Some(parsed),
+        Ok(_) => {
+            warn!("Invalid {} '{}': must be greater than zero", key, value);
+            None
+        }
+        Err(e) => {
+            warn!("Invalid {} '{}': {}", key, value, e);
+            None
+        }
+    }
+}
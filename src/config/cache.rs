@@ -5,6 +5,7 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use crate::constants::{cache, oauth_rate_limiting, rate_limiting_bursts, redis, system_config};
+use crate::rate_limiting::RateLimitAlgorithm;
 use serde::{Deserialize, Serialize};
 use std::env;
 
@@ -59,6 +60,8 @@ pub struct CacheTtlConfig {
     pub activity_secs: u64,
     /// Stats cache TTL in seconds (default: 6 hours)
     pub stats_secs: u64,
+    /// Analysis result cache TTL in seconds (default: 7 days, `0` disables analysis caching)
+    pub analysis_secs: u64,
 }
 
 impl Default for CacheTtlConfig {
@@ -68,6 +71,7 @@ impl Default for CacheTtlConfig {
             activity_list_secs: cache::TTL_ACTIVITY_LIST_SECS,
             activity_secs: cache::TTL_ACTIVITY_SECS,
             stats_secs: cache::TTL_STATS_SECS,
+            analysis_secs: cache::TTL_ANALYSIS_SECS,
         }
     }
 }
@@ -93,6 +97,10 @@ impl CacheTtlConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(cache::TTL_STATS_SECS),
+            analysis_secs: env::var("CACHE_TTL_ANALYSIS_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(cache::TTL_ANALYSIS_SECS),
         }
     }
 }
@@ -182,6 +190,8 @@ pub struct RateLimitConfig {
     pub oauth_token_rpm: u32,
     /// OAuth register endpoint rate limit (requests per minute)
     pub oauth_register_rpm: u32,
+    /// OAuth introspect endpoint rate limit (requests per minute)
+    pub oauth_introspect_rpm: u32,
     /// Rate limit window duration in seconds
     pub rate_limit_window_secs: u64,
     /// Rate limiter cleanup threshold
@@ -190,6 +200,9 @@ pub struct RateLimitConfig {
     pub stale_entry_timeout_secs: u64,
     /// Admin-provisioned API key default monthly request limit
     pub admin_provisioned_api_key_monthly_limit: u32,
+    /// Algorithm used to count API key and JWT usage against their limits
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
 }
 
 impl Default for RateLimitConfig {
@@ -201,10 +214,12 @@ impl Default for RateLimitConfig {
             oauth_authorize_rpm: oauth_rate_limiting::AUTHORIZE_RPM,
             oauth_token_rpm: oauth_rate_limiting::TOKEN_RPM,
             oauth_register_rpm: oauth_rate_limiting::REGISTER_RPM,
+            oauth_introspect_rpm: oauth_rate_limiting::INTROSPECT_RPM,
             rate_limit_window_secs: oauth_rate_limiting::WINDOW_SECS,
             cleanup_threshold: oauth_rate_limiting::CLEANUP_THRESHOLD,
             stale_entry_timeout_secs: oauth_rate_limiting::STALE_ENTRY_TIMEOUT_SECS,
             admin_provisioned_api_key_monthly_limit: system_config::STARTER_MONTHLY_LIMIT,
+            algorithm: RateLimitAlgorithm::default(),
         }
     }
 }
@@ -238,6 +253,10 @@ impl RateLimitConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(oauth_rate_limiting::REGISTER_RPM),
+            oauth_introspect_rpm: env::var("OAUTH_INTROSPECT_RATE_LIMIT_RPM")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(oauth_rate_limiting::INTROSPECT_RPM),
             rate_limit_window_secs: env::var("OAUTH2_RATE_LIMIT_WINDOW_SECS")
                 .ok()
                 .and_then(|s| s.parse().ok())
@@ -254,6 +273,10 @@ impl RateLimitConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(system_config::STARTER_MONTHLY_LIMIT),
+            algorithm: env::var("RATE_LIMIT_ALGORITHM")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_default(),
         }
     }
 }
@@ -0,0 +1,188 @@
+// ABOUTME: Data retention configuration for analytics and audit tables from environment variables
+// ABOUTME: Parses per-table PIERRE_RETENTION_*_DAYS windows and the purge batch size
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::warn;
+
+/// Days API key usage rows are kept when `PIERRE_RETENTION_API_KEY_USAGE_DAYS` is unset
+pub const DEFAULT_API_KEY_USAGE_RETENTION_DAYS: u32 = 90;
+/// Days A2A usage rows are kept when `PIERRE_RETENTION_A2A_USAGE_DAYS` is unset
+pub const DEFAULT_A2A_USAGE_RETENTION_DAYS: u32 = 90;
+/// Days audit events are kept when `PIERRE_RETENTION_AUDIT_EVENTS_DAYS` is unset
+pub const DEFAULT_AUDIT_EVENTS_RETENTION_DAYS: u32 = 365;
+/// Rows deleted per statement when `PIERRE_RETENTION_PURGE_BATCH_SIZE` is unset
+pub const DEFAULT_PURGE_BATCH_SIZE: u32 = 1000;
+
+/// Append-only table whose old rows are purged by the retention policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetentionTable {
+    /// Per-request API key usage (`api_key_usage`)
+    ApiKeyUsage,
+    /// Per-request A2A client usage (`a2a_usage`)
+    A2aUsage,
+    /// Security audit events (`audit_events`)
+    AuditEvents,
+}
+
+impl RetentionTable {
+    /// Every table covered by the retention policy
+    pub const ALL: [Self; 3] = [Self::ApiKeyUsage, Self::A2aUsage, Self::AuditEvents];
+
+    /// Database table name
+    #[must_use]
+    pub const fn table_name(self) -> &'static str {
+        match self {
+            Self::ApiKeyUsage => "api_key_usage",
+            Self::A2aUsage => "a2a_usage",
+            Self::AuditEvents => "audit_events",
+        }
+    }
+
+    /// Environment variable holding this table's retention window
+    const fn env_var(self) -> &'static str {
+        match self {
+            Self::ApiKeyUsage => "PIERRE_RETENTION_API_KEY_USAGE_DAYS",
+            Self::A2aUsage => "PIERRE_RETENTION_A2A_USAGE_DAYS",
+            Self::AuditEvents => "PIERRE_RETENTION_AUDIT_EVENTS_DAYS",
+        }
+    }
+
+    /// Retention window used when the environment variable is unset
+    const fn default_days(self) -> u32 {
+        match self {
+            Self::ApiKeyUsage => DEFAULT_API_KEY_USAGE_RETENTION_DAYS,
+            Self::A2aUsage => DEFAULT_A2A_USAGE_RETENTION_DAYS,
+            Self::AuditEvents => DEFAULT_AUDIT_EVENTS_RETENTION_DAYS,
+        }
+    }
+}
+
+impl fmt::Display for RetentionTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.table_name())
+    }
+}
+
+/// Retention policy for analytics and audit tables
+///
+/// Usage and audit rows are recorded on every request and would otherwise
+/// accumulate forever. Rows older than a table's retention window are purged
+/// by the periodic maintenance task in bounded batches. A window of zero days
+/// keeps that table's rows indefinitely.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_RETENTION_API_KEY_USAGE_DAYS=30
+/// export PIERRE_RETENTION_AUDIT_EVENTS_DAYS=0   # keep audit events forever
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataRetentionConfig {
+    api_key_usage_days: u32,
+    a2a_usage_days: u32,
+    audit_events_days: u32,
+    batch_size: u32,
+}
+
+impl Default for DataRetentionConfig {
+    fn default() -> Self {
+        Self {
+            api_key_usage_days: DEFAULT_API_KEY_USAGE_RETENTION_DAYS,
+            a2a_usage_days: DEFAULT_A2A_USAGE_RETENTION_DAYS,
+            audit_events_days: DEFAULT_AUDIT_EVENTS_RETENTION_DAYS,
+            batch_size: DEFAULT_PURGE_BATCH_SIZE,
+        }
+    }
+}
+
+impl DataRetentionConfig {
+    /// Load the retention policy from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_RETENTION_API_KEY_USAGE_DAYS`: Days to keep API key usage (default: 90)
+    /// - `PIERRE_RETENTION_A2A_USAGE_DAYS`: Days to keep A2A usage (default: 90)
+    /// - `PIERRE_RETENTION_AUDIT_EVENTS_DAYS`: Days to keep audit events (default: 365)
+    /// - `PIERRE_RETENTION_PURGE_BATCH_SIZE`: Rows deleted per statement (default: 1000)
+    ///
+    /// `0` days keeps a table forever. Invalid values fall back to the default.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let batch_size = parse_env_u32(
+            "PIERRE_RETENTION_PURGE_BATCH_SIZE",
+            DEFAULT_PURGE_BATCH_SIZE,
+        );
+        RetentionTable::ALL
+            .into_iter()
+            .fold(Self::default(), |config, table| {
+                config.with_retention_days(
+                    table,
+                    parse_env_u32(table.env_var(), table.default_days()),
+                )
+            })
+            .with_batch_size(batch_size)
+    }
+
+    /// Set the retention window for one table (`0` keeps its rows forever)
+    #[must_use]
+    pub const fn with_retention_days(mut self, table: RetentionTable, days: u32) -> Self {
+        match table {
+            RetentionTable::ApiKeyUsage => self.api_key_usage_days = days,
+            RetentionTable::A2aUsage => self.a2a_usage_days = days,
+            RetentionTable::AuditEvents => self.audit_events_days = days,
+        }
+        self
+    }
+
+    /// Set the number of rows deleted per statement (at least one)
+    #[must_use]
+    pub const fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = if batch_size == 0 { 1 } else { batch_size };
+        self
+    }
+
+    /// Days rows of `table` are kept (`0` = forever)
+    #[must_use]
+    pub const fn retention_days(&self, table: RetentionTable) -> u32 {
+        match table {
+            RetentionTable::ApiKeyUsage => self.api_key_usage_days,
+            RetentionTable::A2aUsage => self.a2a_usage_days,
+            RetentionTable::AuditEvents => self.audit_events_days,
+        }
+    }
+
+    /// Rows deleted per statement
+    #[must_use]
+    pub const fn batch_size(&self) -> u32 {
+        self.batch_size
+    }
+
+    /// Rows of `table` recorded before this instant are expired, or `None` when kept forever
+    #[must_use]
+    pub fn purge_cutoff(&self, table: RetentionTable, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.retention_days(table) {
+            0 => None,
+            days => Some(now - Duration::days(i64::from(days))),
+        }
+    }
+}
+
+/// Parse a non-negative integer environment variable, warning on invalid values
+fn parse_env_u32(name: &str, default: u32) -> u32 {
+    env::var(name)
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .inspect_err(|e| warn!("Invalid {} '{}': {}", name, value, e))
+                .ok()
+        })
+        .unwrap_or(default)
+}
@@ -199,6 +199,66 @@ impl PostgresPoolConfig {
     }
 }
 
+/// `SQLite` connection pool configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SqlitePoolConfig {
+    /// Maximum number of connections in the pool
+    pub max_connections: u32,
+    /// How long a connection waits on a locked database before failing, in milliseconds
+    pub busy_timeout_ms: u64,
+    /// Use write-ahead logging instead of the rollback journal
+    ///
+    /// WAL lets readers proceed while a write is in progress, which greatly
+    /// improves concurrent read throughput. Ignored for in-memory databases.
+    pub wal_mode: bool,
+    /// Enforce foreign key constraints (`PRAGMA foreign_keys`)
+    pub foreign_keys: bool,
+}
+
+impl Default for SqlitePoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: database::POOL_MAX_SIZE,
+            busy_timeout_ms: database::SQLITE_BUSY_TIMEOUT_MS,
+            wal_mode: false,
+            foreign_keys: true,
+        }
+    }
+}
+
+impl SqlitePoolConfig {
+    /// Load `SQLite` pool configuration from environment (or defaults)
+    ///
+    /// # Environment Variables
+    ///
+    /// - `SQLITE_MAX_CONNECTIONS`: Maximum pool size (default: 10)
+    /// - `SQLITE_BUSY_TIMEOUT_MS`: Busy timeout in milliseconds (default: 5000)
+    /// - `SQLITE_WAL_MODE`: Enable WAL journal mode (default: false)
+    /// - `SQLITE_FOREIGN_KEYS`: Enforce foreign keys (default: true)
+    #[must_use]
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_connections: env::var("SQLITE_MAX_CONNECTIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_connections),
+            busy_timeout_ms: env::var("SQLITE_BUSY_TIMEOUT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.busy_timeout_ms),
+            wal_mode: env::var("SQLITE_WAL_MODE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.wal_mode),
+            foreign_keys: env::var("SQLITE_FOREIGN_KEYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.foreign_keys),
+        }
+    }
+}
+
 /// Configuration for automatic database backups
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct BackupConfig {
@@ -27,7 +27,7 @@ pub use crate::config::cache::{
 };
 // Database
 pub use crate::config::database::{
-    BackupConfig, DatabaseConfig, DatabaseUrl, PostgresPoolConfig, SqlxConfig,
+    BackupConfig, DatabaseConfig, DatabaseUrl, PostgresPoolConfig, SqlitePoolConfig, SqlxConfig,
 };
 // Goal management
 pub use crate::config::goal_management::GoalManagementConfig;
@@ -41,8 +41,9 @@ pub use crate::config::network::{
 };
 // OAuth
 pub use crate::config::oauth::{
-    default_provider, get_oauth_config, load_provider_env_config, FirebaseConfig,
-    OAuth2ServerConfig, OAuthConfig, OAuthProviderConfig, ProviderEnvConfig,
+    default_provider, get_oauth_config, load_provider_env_config, load_provider_request_timeout,
+    load_provider_validation_mode, FirebaseConfig, OAuth2ServerConfig, OAuthConfig,
+    OAuthProviderConfig, ProviderEnvConfig,
 };
 // Security
 pub use crate::config::security::{
@@ -187,6 +188,9 @@ impl ServerConfig {
         self.validate_oauth_providers();
         self.validate_oauth2_issuer_url()?;
         self.security.validate_tls()?;
+        self.sse
+            .session_cookie
+            .validate(&self.security.headers.environment)?;
         Ok(())
     }
 
@@ -0,0 +1,77 @@
+// ABOUTME: Feature flag cache configuration from environment variables
+// ABOUTME: Parses PIERRE_FEATURE_FLAG_REFRESH_SECS controlling how often flags are reloaded from the database
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+use std::time::Duration;
+
+use tracing::warn;
+
+/// Seconds between feature flag reloads when `PIERRE_FEATURE_FLAG_REFRESH_SECS` is unset
+pub const DEFAULT_FEATURE_FLAG_REFRESH_SECS: u64 = 30;
+
+/// Configuration for the in-memory feature flag cache
+///
+/// Flag changes made through the admin API on this instance apply at once;
+/// the periodic refresh picks up changes made through other instances
+/// sharing the database.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_FEATURE_FLAG_REFRESH_SECS=10
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureFlagsConfig {
+    refresh_secs: u64,
+}
+
+impl Default for FeatureFlagsConfig {
+    fn default() -> Self {
+        Self::with_refresh_secs(DEFAULT_FEATURE_FLAG_REFRESH_SECS)
+    }
+}
+
+impl FeatureFlagsConfig {
+    /// Load feature flag configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_FEATURE_FLAG_REFRESH_SECS`: Seconds between reloads from the
+    ///   database (default: 30). Invalid or zero values fall back to the default.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let refresh_secs = env::var("PIERRE_FEATURE_FLAG_REFRESH_SECS")
+            .ok()
+            .and_then(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .inspect_err(|e| {
+                        warn!(
+                            "Invalid PIERRE_FEATURE_FLAG_REFRESH_SECS '{}': {}",
+                            value, e
+                        );
+                    })
+                    .ok()
+            })
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_FEATURE_FLAG_REFRESH_SECS);
+
+        Self::with_refresh_secs(refresh_secs)
+    }
+
+    /// Create a configuration with an explicit refresh interval
+    #[must_use]
+    pub const fn with_refresh_secs(refresh_secs: u64) -> Self {
+        Self { refresh_secs }
+    }
+
+    /// How often flags are reloaded from the database
+    #[must_use]
+    pub const fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_secs)
+    }
+}
@@ -17,6 +17,8 @@ pub struct LoggingConfig {
     pub redaction_features: RedactionFeatures,
     /// Placeholder for redacted sensitive data
     pub redaction_placeholder: String,
+    /// Values left unredacted in log output, for debugging (exact values or `@domain` suffixes)
+    pub redaction_allowlist: Vec<String>,
     /// Enable sampling for debug-level logs (reduces log volume)
     pub debug_sampling_enabled: bool,
     /// Debug log sampling rate (1.0 = all logs, 0.1 = 10% of logs)
@@ -29,6 +31,7 @@ impl Default for LoggingConfig {
             redact_pii: true, // Enabled by default for safety
             redaction_features: RedactionFeatures::ALL,
             redaction_placeholder: "[REDACTED]".to_owned(),
+            redaction_allowlist: Vec::new(),
             debug_sampling_enabled: false,
             debug_sampling_rate: 1.0,
         }
@@ -68,6 +71,12 @@ impl LoggingConfig {
             redact_pii,
             redaction_features: features,
             redaction_placeholder: env_var_or("PIERRE_REDACTION_PLACEHOLDER", "[REDACTED]"),
+            redaction_allowlist: env_var_or("PIERRE_LOG_REDACT_ALLOWLIST", "")
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(str::to_owned)
+                .collect(),
             debug_sampling_enabled: env_var_or("PIERRE_LOG_SAMPLE_ENABLED", "false")
                 .parse()
                 .unwrap_or(false),
@@ -85,7 +94,7 @@ impl Serialize for LoggingConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("LoggingConfig", 7)?;
+        let mut state = serializer.serialize_struct("LoggingConfig", 8)?;
         state.serialize_field("redact_pii", &self.redact_pii)?;
         state.serialize_field(
             "redact_headers",
@@ -102,6 +111,7 @@ impl Serialize for LoggingConfig {
             &self.redaction_features.contains(RedactionFeatures::EMAILS),
         )?;
         state.serialize_field("redaction_placeholder", &self.redaction_placeholder)?;
+        state.serialize_field("redaction_allowlist", &self.redaction_allowlist)?;
         state.serialize_field("debug_sampling_enabled", &self.debug_sampling_enabled)?;
         state.serialize_field("debug_sampling_rate", &self.debug_sampling_rate)?;
         state.end()
@@ -134,6 +144,7 @@ impl<'de> Deserialize<'de> for LoggingConfig {
                 let mut redact_body_fields = None;
                 let mut mask_emails = None;
                 let mut redaction_placeholder = None;
+                let mut redaction_allowlist = None;
                 let mut debug_sampling_enabled = None;
                 let mut debug_sampling_rate = None;
 
@@ -154,6 +165,9 @@ impl<'de> Deserialize<'de> for LoggingConfig {
                         "redaction_placeholder" => {
                             redaction_placeholder = Some(map.next_value()?);
                         }
+                        "redaction_allowlist" => {
+                            redaction_allowlist = Some(map.next_value()?);
+                        }
                         "debug_sampling_enabled" => {
                             debug_sampling_enabled = Some(map.next_value()?);
                         }
@@ -173,6 +187,7 @@ impl<'de> Deserialize<'de> for LoggingConfig {
                 let mask_emails = mask_emails.unwrap_or(true);
                 let redaction_placeholder =
                     redaction_placeholder.unwrap_or_else(|| "[REDACTED]".to_owned());
+                let redaction_allowlist = redaction_allowlist.unwrap_or_default();
                 let debug_sampling_enabled = debug_sampling_enabled.unwrap_or(false);
                 let debug_sampling_rate = debug_sampling_rate.unwrap_or(1.0);
 
@@ -191,6 +206,7 @@ impl<'de> Deserialize<'de> for LoggingConfig {
                     redact_pii,
                     redaction_features: features,
                     redaction_placeholder,
+                    redaction_allowlist,
                     debug_sampling_enabled,
                     debug_sampling_rate,
                 })
@@ -203,6 +219,7 @@ impl<'de> Deserialize<'de> for LoggingConfig {
             "redact_body_fields",
             "mask_emails",
             "redaction_placeholder",
+            "redaction_allowlist",
             "debug_sampling_enabled",
             "debug_sampling_rate",
         ];
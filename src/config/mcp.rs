@@ -4,8 +4,9 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use crate::constants::{limits, mcp_transport, network_config, rate_limits};
+use crate::constants::{limits, mcp_transport, network_config, rate_limits, timeouts};
 use crate::errors::{AppError, AppResult};
+use crate::middleware::compression::CompressionCodec;
 use serde::{Deserialize, Serialize};
 use std::env;
 
@@ -28,6 +29,12 @@ pub struct McpConfig {
     pub websocket_channel_capacity: usize,
     /// TCP keep-alive timeout in seconds
     pub tcp_keep_alive_secs: u64,
+    /// Default tool execution timeout in seconds (0 disables the timeout)
+    #[serde(default)]
+    pub tool_timeout_secs: u64,
+    /// HTTP response compression settings
+    #[serde(default)]
+    pub compression: CompressionConfig,
 }
 
 impl McpConfig {
@@ -61,6 +68,52 @@ impl McpConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(network_config::TCP_KEEP_ALIVE_SECS),
+            tool_timeout_secs: env::var("MCP_TOOL_TIMEOUT_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(timeouts::TOOL_EXECUTION_TIMEOUT_SECS),
+            compression: CompressionConfig::from_env(),
+        }
+    }
+}
+
+/// HTTP response compression configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressionConfig {
+    /// Minimum response body size in bytes before compression is applied
+    pub min_size_bytes: usize,
+    /// Codecs offered to clients via `Accept-Encoding` negotiation (empty disables compression)
+    pub codecs: Vec<CompressionCodec>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            min_size_bytes: limits::MAX_RESPONSE_SIZE / 10,
+            codecs: CompressionCodec::ALL.to_vec(),
+        }
+    }
+}
+
+impl CompressionConfig {
+    /// Load response compression configuration from environment
+    ///
+    /// `MCP_COMPRESSION_CODECS` is a comma-separated list of `gzip`, `deflate`, and `br`;
+    /// unknown entries are ignored and `none` disables compression.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            min_size_bytes: env::var("MCP_COMPRESSION_MIN_SIZE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(defaults.min_size_bytes),
+            codecs: env::var("MCP_COMPRESSION_CODECS").map_or(defaults.codecs, |codecs| {
+                codecs
+                    .split(',')
+                    .filter_map(|codec| codec.parse().ok())
+                    .collect()
+            }),
         }
     }
 }
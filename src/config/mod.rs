@@ -32,12 +32,18 @@ use tracing::{debug, info};
 use crate::errors::AppResult;
 
 // Core configuration type modules (extracted from environment.rs)
+/// Analytics sink selection and batching for usage records via environment variables
+pub mod analytics_sink;
 /// External API provider configuration (Strava, Fitbit, Garmin APIs)
 pub mod api_providers;
 /// Cache and rate limiting configuration (Redis, TTLs, rate limits)
 pub mod cache;
+/// Data retention policy for analytics and audit tables via environment variables
+pub mod data_retention;
 /// Database configuration (`DatabaseUrl`, pools, backups, `SQLx`)
 pub mod database;
+/// Feature flag cache refresh interval via environment variables
+pub mod feature_flags;
 /// Goal management configuration
 pub mod goal_management;
 /// Logging and PII redaction configuration
@@ -50,14 +56,24 @@ pub mod network;
 pub mod oauth;
 /// Security configuration (auth, headers, monitoring)
 pub mod security;
+/// Graceful shutdown grace period via environment variables
+pub mod shutdown;
+/// JWT signing key rollover policy (overlap window, key size) via environment variables
+pub mod signing_keys;
 /// Sleep tool operational parameters (activity limits, trend thresholds)
 pub mod sleep_tool_params;
 /// Social insights configuration for coach-mediated sharing
 pub mod social;
+/// Strava push subscription callback URL and verify token via environment variables
+pub mod strava_webhooks;
+/// Per-tool provider OAuth scope requirements for scope step-up via environment variables
+pub mod tool_scopes;
 /// Tool selection configuration for global tool disabling via environment variables
 pub mod tool_selection;
 /// Core configuration type definitions (`LogLevel`, `Environment`, `LlmProviderType`)
 pub mod types;
+/// User deletion configuration (soft delete recovery window) via environment variables
+pub mod user_deletion;
 
 // Main orchestrator module
 /// Environment and server configuration orchestrator
@@ -93,12 +109,14 @@ pub use environment::{LlmProviderType, ServerConfig};
 pub use types::{Environment, LlmModelConfig, LogLevel};
 
 // Re-export database types
-pub use database::{BackupConfig, DatabaseConfig, DatabaseUrl, PostgresPoolConfig, SqlxConfig};
+pub use database::{
+    BackupConfig, DatabaseConfig, DatabaseUrl, PostgresPoolConfig, SqlitePoolConfig, SqlxConfig,
+};
 
 // Re-export OAuth types
 pub use oauth::{
-    default_provider, get_oauth_config, load_provider_env_config, FirebaseConfig,
-    OAuth2ServerConfig, OAuthConfig, OAuthProviderConfig, ProviderEnvConfig,
+    default_provider, get_oauth_config, load_provider_env_config, load_provider_request_timeout,
+    FirebaseConfig, OAuth2ServerConfig, OAuthConfig, OAuthProviderConfig, ProviderEnvConfig,
 };
 
 // Re-export API provider types
@@ -109,7 +127,8 @@ pub use api_providers::{
 
 // Re-export network types
 pub use network::{
-    CorsConfig, HttpClientConfig, RouteTimeoutConfig, SseBufferStrategy, SseConfig, TlsConfig,
+    CorsConfig, HttpClientConfig, RouteTimeoutConfig, SessionCookieConfig, SseBufferStrategy,
+    SseConfig, TlsConfig,
 };
 
 // Re-export cache types
@@ -164,6 +183,30 @@ pub use routes::{ConfigurationRoutes, FitnessConfigurationRoutes};
 // Re-export tool selection types
 pub use tool_selection::ToolSelectionConfig;
 
+// Re-export tool scope requirements
+pub use tool_scopes::ToolScopeRequirements;
+
+// Re-export user deletion configuration
+pub use user_deletion::UserDeletionConfig;
+
+// Re-export data retention configuration
+pub use data_retention::{DataRetentionConfig, RetentionTable};
+
+// Re-export signing key rollover configuration
+pub use signing_keys::SigningKeyRolloverConfig;
+
+// Re-export graceful shutdown configuration
+pub use shutdown::ShutdownConfig;
+
+// Re-export feature flag cache configuration
+pub use feature_flags::FeatureFlagsConfig;
+
+// Re-export Strava push subscription configuration
+pub use strava_webhooks::StravaWebhookConfig;
+
+// Re-export analytics sink configuration
+pub use analytics_sink::{AnalyticsSinkConfig, AnalyticsSinkKind};
+
 // Re-export social insights configuration types
 pub use social::{
     ActivityFetchLimitsConfig, DistanceMilestoneConfig, DistanceRelevanceScores, MilestoneConfig,
@@ -4,8 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use crate::constants::{network_config, timeouts};
+use crate::config::types::Environment;
+use crate::constants::{network_config, security, timeouts};
 use crate::errors::{AppError, AppResult};
+use crate::security::cookies::SameSitePolicy;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::path::PathBuf;
@@ -127,10 +129,8 @@ pub struct SseConfig {
     pub cleanup_interval_secs: u64,
     /// Connection timeout in seconds (connections inactive for this duration will be removed)
     pub connection_timeout_secs: u64,
-    /// OAuth session cookie Max-Age in seconds
-    pub session_cookie_max_age_secs: u64,
-    /// Enable Secure flag on cookies (requires HTTPS)
-    pub session_cookie_secure: bool,
+    /// Attributes of the auth and OAuth session cookies
+    pub session_cookie: SessionCookieConfig,
     /// Maximum buffer size for SSE event queue per connection
     pub max_buffer_size: usize,
     /// Behavior when buffer is full
@@ -146,8 +146,7 @@ impl Default for SseConfig {
         Self {
             cleanup_interval_secs: timeouts::SSE_CLEANUP_INTERVAL_SECS,
             connection_timeout_secs: timeouts::SSE_CONNECTION_TIMEOUT_SECS,
-            session_cookie_max_age_secs: timeouts::SESSION_COOKIE_MAX_AGE_SECS,
-            session_cookie_secure: false, // Default to false for development, override in production
+            session_cookie: SessionCookieConfig::default(),
             max_buffer_size: 1000,
             buffer_overflow_strategy: SseBufferStrategy::default(),
             broadcast_channel_size: network_config::SSE_BROADCAST_CHANNEL_SIZE,
@@ -187,19 +186,7 @@ impl SseConfig {
             .map_err(|e| {
                 AppError::invalid_input(format!("Invalid SSE_CONNECTION_TIMEOUT_SECS value: {e}"))
             })?,
-            session_cookie_max_age_secs: env_var_or(
-                "SESSION_COOKIE_MAX_AGE_SECS",
-                &timeouts::SESSION_COOKIE_MAX_AGE_SECS.to_string(),
-            )
-            .parse()
-            .map_err(|e| {
-                AppError::invalid_input(format!("Invalid SESSION_COOKIE_MAX_AGE_SECS value: {e}"))
-            })?,
-            session_cookie_secure: env_var_or("SESSION_COOKIE_SECURE", "false")
-                .parse()
-                .map_err(|e| {
-                    AppError::invalid_input(format!("Invalid SESSION_COOKIE_SECURE value: {e}"))
-                })?,
+            session_cookie: SessionCookieConfig::from_env()?,
             max_buffer_size: env_var_or("SSE_MAX_BUFFER_SIZE", "1000")
                 .parse()
                 .map_err(|e| {
@@ -226,6 +213,110 @@ impl SseConfig {
     }
 }
 
+/// Attributes applied to the `auth_token` and `pierre_session` cookies
+///
+/// Defaults are `Secure`, `HttpOnly`, `SameSite=Lax` and `Path=/` with no
+/// `Domain`, so the cookie is only sent back to the host that set it. Set a
+/// domain such as `example.com` to share the session with frontends served
+/// from sibling subdomains.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionCookieConfig {
+    /// OAuth session cookie Max-Age in seconds (the auth cookie follows the JWT lifetime)
+    pub max_age_secs: u64,
+    /// Enable Secure flag on cookies (requires HTTPS)
+    pub secure: bool,
+    /// `SameSite` policy
+    pub same_site: SameSitePolicy,
+    /// `Domain` attribute, or `None` for a host-only cookie
+    pub domain: Option<String>,
+    /// `Path` attribute
+    pub path: String,
+}
+
+impl Default for SessionCookieConfig {
+    fn default() -> Self {
+        Self {
+            max_age_secs: timeouts::SESSION_COOKIE_MAX_AGE_SECS,
+            secure: true,
+            same_site: SameSitePolicy::Lax,
+            domain: None,
+            path: "/".to_owned(),
+        }
+    }
+}
+
+impl SessionCookieConfig {
+    /// Load session cookie attributes from environment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a session cookie environment variable cannot be parsed
+    pub fn from_env() -> AppResult<Self> {
+        let domain = env::var("SESSION_COOKIE_DOMAIN")
+            .ok()
+            .map(|domain| domain.trim().to_owned())
+            .filter(|domain| !domain.is_empty());
+
+        Ok(Self {
+            max_age_secs: env_var_or(
+                "SESSION_COOKIE_MAX_AGE_SECS",
+                &timeouts::SESSION_COOKIE_MAX_AGE_SECS.to_string(),
+            )
+            .parse()
+            .map_err(|e| {
+                AppError::invalid_input(format!("Invalid SESSION_COOKIE_MAX_AGE_SECS value: {e}"))
+            })?,
+            secure: env_var_or("SESSION_COOKIE_SECURE", "true")
+                .parse()
+                .map_err(|e| {
+                    AppError::invalid_input(format!("Invalid SESSION_COOKIE_SECURE value: {e}"))
+                })?,
+            same_site: env_var_or("SESSION_COOKIE_SAME_SITE", "lax").parse()?,
+            domain,
+            path: env_var_or("SESSION_COOKIE_PATH", "/"),
+        })
+    }
+
+    /// Validate the cookie attributes for the given environment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cookie would be sent over plain HTTP in production,
+    /// if `SameSite=None` is used without `Secure` (browsers reject it), or if the
+    /// domain or path would corrupt the `Set-Cookie` header
+    pub fn validate(&self, environment: &Environment) -> AppResult<()> {
+        if environment.is_production() && !self.secure {
+            return Err(AppError::invalid_input(
+                "SESSION_COOKIE_SECURE must be true in production",
+            ));
+        }
+        if self.same_site == SameSitePolicy::None && !self.secure {
+            return Err(AppError::invalid_input(
+                "SESSION_COOKIE_SAME_SITE=none requires SESSION_COOKIE_SECURE=true",
+            ));
+        }
+        if !self.path.starts_with('/') || !is_cookie_attribute_value(&self.path) {
+            return Err(AppError::invalid_input(format!(
+                "Invalid SESSION_COOKIE_PATH value: {}",
+                self.path
+            )));
+        }
+        if let Some(domain) = &self.domain {
+            if !is_cookie_attribute_value(domain) || domain.contains(char::is_whitespace) {
+                return Err(AppError::invalid_input(format!(
+                    "Invalid SESSION_COOKIE_DOMAIN value: {domain}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `value` can be written as a cookie attribute value without ending the attribute
+fn is_cookie_attribute_value(value: &str) -> bool {
+    !value.is_empty() && !value.contains(';') && !value.chars().any(char::is_control)
+}
+
 /// Strategy for handling SSE buffer overflow
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -242,7 +333,7 @@ pub enum SseBufferStrategy {
 /// CORS (Cross-Origin Resource Sharing) configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CorsConfig {
-    /// Comma-separated list of allowed origins
+    /// Comma-separated list of allowed origins, or `*` (or empty) for any origin
     pub allowed_origins: String,
     /// Allow localhost in development mode
     pub allow_localhost_dev: bool,
@@ -250,16 +341,38 @@ pub struct CorsConfig {
 
 impl CorsConfig {
     /// Load CORS configuration from environment
+    ///
+    /// `PIERRE_CORS_ORIGINS` takes precedence over the legacy
+    /// `CORS_ALLOWED_ORIGINS`; with neither set any origin is allowed.
     #[must_use]
     pub fn from_env() -> Self {
         Self {
-            allowed_origins: env::var("CORS_ALLOWED_ORIGINS").unwrap_or_default(),
+            allowed_origins: env::var("PIERRE_CORS_ORIGINS")
+                .or_else(|_| env::var("CORS_ALLOWED_ORIGINS"))
+                .unwrap_or_else(|_| security::CORS_ALLOWED_ORIGINS.to_owned()),
             allow_localhost_dev: env::var("CORS_ALLOW_LOCALHOST_DEV")
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(true),
         }
     }
+
+    /// Whether any origin is allowed rather than an explicit allowlist
+    #[must_use]
+    pub fn allows_any_origin(&self) -> bool {
+        let origins = self.origins();
+        origins.is_empty() || origins.contains(&"*")
+    }
+
+    /// Allowlisted origins, trimmed and without empty entries
+    #[must_use]
+    pub fn origins(&self) -> Vec<&str> {
+        self.allowed_origins
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .collect()
+    }
 }
 
 /// TLS configuration
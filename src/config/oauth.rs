@@ -5,6 +5,7 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use crate::constants::oauth_providers;
+use crate::providers::core::ProviderValidationMode;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::env;
@@ -242,6 +243,8 @@ pub struct OAuth2ServerConfig {
     pub default_login_email: Option<String>,
     /// Default password for OAuth login page (dev/test only - NEVER use in production!)
     pub default_login_password: Option<String>,
+    /// Reject `plain` PKCE challenges and accept only `S256` (default: true)
+    pub require_s256: bool,
 }
 
 impl Default for OAuth2ServerConfig {
@@ -250,6 +253,7 @@ impl Default for OAuth2ServerConfig {
             issuer_url: "http://localhost:8081".to_owned(),
             default_login_email: None,
             default_login_password: None,
+            require_s256: true,
         }
     }
 }
@@ -268,6 +272,9 @@ impl OAuth2ServerConfig {
                 .unwrap_or_else(|_| format!("http://localhost:{http_port}")),
             default_login_email: env::var("OAUTH_DEFAULT_EMAIL").ok(),
             default_login_password: env::var("OAUTH_DEFAULT_PASSWORD").ok(),
+            require_s256: env_var_or("OAUTH2_REQUIRE_S256", "true")
+                .parse()
+                .unwrap_or(true),
         }
     }
 }
@@ -548,6 +555,63 @@ pub fn load_provider_env_config(
     )
 }
 
+/// Load a provider's request timeout override from `PIERRE_<PROVIDER>_TIMEOUT_SECS`
+///
+/// Returns `None` when the variable is unset, so the provider keeps the shared
+/// client timeout (`HTTP_CLIENT_TIMEOUT_SECS`). Zero or unparsable values are
+/// ignored with a warning.
+///
+/// # Examples
+///
+/// ```bash
+/// # Garmin's wellness endpoints can take longer than other providers
+/// export PIERRE_GARMIN_TIMEOUT_SECS=90
+/// ```
+#[must_use]
+pub fn load_provider_request_timeout(provider: &str) -> Option<u64> {
+    let name = format!("PIERRE_{}_TIMEOUT_SECS", provider.to_uppercase());
+    let value = env::var(&name).ok()?;
+    match value.trim().parse::<u64>() {
+        Ok(secs) if secs > 0 => Some(secs),
+        Ok(_) => {
+            warn!("{name} must be greater than zero, using the shared client timeout");
+            None
+        }
+        Err(e) => {
+            warn!("Invalid {name} '{value}': {e}, using the shared client timeout");
+            None
+        }
+    }
+}
+
+/// Load a provider's response validation mode from `PIERRE_<PROVIDER>_VALIDATION_MODE`
+///
+/// Falls back to `PIERRE_PROVIDER_VALIDATION_MODE`, then to lenient. In strict
+/// mode malformed provider records are rejected instead of coerced to defaults.
+/// Unknown values are ignored with a warning.
+///
+/// # Examples
+///
+/// ```bash
+/// export PIERRE_PROVIDER_VALIDATION_MODE=strict
+/// export PIERRE_GARMIN_VALIDATION_MODE=lenient
+/// ```
+#[must_use]
+pub fn load_provider_validation_mode(provider: &str) -> ProviderValidationMode {
+    let provider_var = format!("PIERRE_{}_VALIDATION_MODE", provider.to_uppercase());
+    [provider_var.as_str(), "PIERRE_PROVIDER_VALIDATION_MODE"]
+        .into_iter()
+        .find_map(|name| {
+            let value = env::var(name).ok()?;
+            let mode = ProviderValidationMode::parse(&value);
+            if mode.is_none() {
+                warn!("Invalid {name} '{value}': expected lenient or strict");
+            }
+            mode
+        })
+        .unwrap_or_default()
+}
+
 /// Parse comma-separated scopes
 #[must_use]
 pub fn parse_scopes(scopes_str: &str) -> Vec<String> {
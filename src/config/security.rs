@@ -6,6 +6,7 @@
 
 use crate::config::network::{parse_origins, TlsConfig};
 use crate::config::types::Environment;
+use crate::constants::service_names::{MCP, PIERRE_MCP_SERVER};
 use crate::constants::system_monitoring;
 use crate::errors::{AppError, AppResult};
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,23 @@ pub struct AuthConfig {
     pub enable_refresh_tokens: bool,
     /// Admin token cache TTL in seconds (default: 300 = 5 minutes)
     pub admin_token_cache_ttl_secs: u64,
+    /// `iss` claim set on issued JWTs and required on validated ones
+    #[serde(default = "default_jwt_issuer")]
+    pub jwt_issuer: String,
+    /// `aud` claim set on issued JWTs and required on validated ones
+    #[serde(default = "default_jwt_audience")]
+    pub jwt_audience: String,
+    /// Accept JWTs that omit `iss` or `aud` (grace period while older tokens expire)
+    #[serde(default)]
+    pub jwt_allow_missing_claims: bool,
+}
+
+fn default_jwt_issuer() -> String {
+    PIERRE_MCP_SERVER.to_owned()
+}
+
+fn default_jwt_audience() -> String {
+    MCP.to_owned()
 }
 
 impl Default for AuthConfig {
@@ -28,6 +46,9 @@ impl Default for AuthConfig {
             jwt_expiry_hours: 24,
             enable_refresh_tokens: false,
             admin_token_cache_ttl_secs: 300, // 5 minutes
+            jwt_issuer: default_jwt_issuer(),
+            jwt_audience: default_jwt_audience(),
+            jwt_allow_missing_claims: false,
         }
     }
 }
@@ -57,6 +78,15 @@ impl AuthConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(300),
+            jwt_issuer: env_var_or("PIERRE_JWT_ISSUER", PIERRE_MCP_SERVER),
+            jwt_audience: env_var_or("PIERRE_JWT_AUDIENCE", MCP),
+            jwt_allow_missing_claims: env_var_or("PIERRE_JWT_ALLOW_MISSING_CLAIMS", "false")
+                .parse()
+                .map_err(|e| {
+                    AppError::invalid_input(format!(
+                        "Invalid PIERRE_JWT_ALLOW_MISSING_CLAIMS value: {e}"
+                    ))
+                })?,
         })
     }
 }
@@ -0,0 +1,75 @@
+// ABOUTME: Graceful shutdown configuration from environment variables
+// ABOUTME: Parses PIERRE_SHUTDOWN_GRACE_PERIOD_SECS bounding how long shutdown waits for in-flight work
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+use std::time::Duration;
+
+use tracing::warn;
+
+/// Seconds shutdown waits for in-flight work when `PIERRE_SHUTDOWN_GRACE_PERIOD_SECS` is unset
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS: u64 = 30;
+
+/// Configuration for draining the server on SIGTERM or Ctrl-C
+///
+/// Once shutdown starts, new connections are refused while in-flight HTTP
+/// requests and running A2A tasks get up to the grace period to finish.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_SHUTDOWN_GRACE_PERIOD_SECS=60
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShutdownConfig {
+    grace_period_secs: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self::with_grace_period_secs(DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS)
+    }
+}
+
+impl ShutdownConfig {
+    /// Load shutdown configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_SHUTDOWN_GRACE_PERIOD_SECS`: Seconds to wait for in-flight work
+    ///   (default: 30). Invalid values fall back to the default.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let grace_period_secs = env::var("PIERRE_SHUTDOWN_GRACE_PERIOD_SECS")
+            .ok()
+            .and_then(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .inspect_err(|e| {
+                        warn!(
+                            "Invalid PIERRE_SHUTDOWN_GRACE_PERIOD_SECS '{}': {}",
+                            value, e
+                        );
+                    })
+                    .ok()
+            })
+            .unwrap_or(DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS);
+
+        Self::with_grace_period_secs(grace_period_secs)
+    }
+
+    /// Create a configuration with an explicit grace period
+    #[must_use]
+    pub const fn with_grace_period_secs(grace_period_secs: u64) -> Self {
+        Self { grace_period_secs }
+    }
+
+    /// How long shutdown waits for in-flight work before giving up on it
+    #[must_use]
+    pub const fn grace_period(&self) -> Duration {
+        Duration::from_secs(self.grace_period_secs)
+    }
+}
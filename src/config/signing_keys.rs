@@ -0,0 +1,151 @@
+// ABOUTME: JWT signing key rollover configuration from environment variables
+// ABOUTME: Parses the overlap window for retiring RSA keys, the rollover key size and check interval
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+use std::time::Duration as StdDuration;
+
+use chrono::Duration;
+use tracing::warn;
+
+/// Hours a retiring key keeps validating when `PIERRE_JWKS_ROLLOVER_OVERLAP_HOURS` is unset
+pub const DEFAULT_ROLLOVER_OVERLAP_HOURS: u32 = 24;
+/// RSA key size for rolled-over keys when `PIERRE_RSA_KEY_SIZE` is unset
+pub const DEFAULT_ROLLOVER_KEY_SIZE_BITS: usize = 4096;
+/// Minutes between rollover checks when `PIERRE_JWKS_ROLLOVER_CHECK_MINUTES` is unset
+pub const DEFAULT_ROLLOVER_CHECK_MINUTES: u32 = 60;
+
+/// Rollover policy for the RSA keys that sign JWTs
+///
+/// Automatic rollover is off unless `PIERRE_JWKS_ROLLOVER_ENABLED` is set.
+/// When enabled and the active signing key is due for rotation, a new key takes over
+/// signing immediately. The previous key stays in JWKS and keeps validating
+/// tokens it signed until the overlap window ends, then it is deleted. The
+/// overlap should be at least as long as the longest-lived token.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_JWKS_ROLLOVER_ENABLED=true
+/// export PIERRE_JWKS_ROLLOVER_OVERLAP_HOURS=72
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningKeyRolloverConfig {
+    enabled: bool,
+    overlap_hours: u32,
+    key_size_bits: usize,
+    check_interval_minutes: u32,
+}
+
+impl Default for SigningKeyRolloverConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            overlap_hours: DEFAULT_ROLLOVER_OVERLAP_HOURS,
+            key_size_bits: DEFAULT_ROLLOVER_KEY_SIZE_BITS,
+            check_interval_minutes: DEFAULT_ROLLOVER_CHECK_MINUTES,
+        }
+    }
+}
+
+impl SigningKeyRolloverConfig {
+    /// Load the rollover policy from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_JWKS_ROLLOVER_ENABLED`: Roll over the signing key automatically (default: false)
+    /// - `PIERRE_JWKS_ROLLOVER_OVERLAP_HOURS`: Hours a retiring key keeps validating (default: 24)
+    /// - `PIERRE_JWKS_ROLLOVER_CHECK_MINUTES`: Minutes between rollover checks (default: 60)
+    /// - `PIERRE_RSA_KEY_SIZE`: RSA key size for new signing keys (default: 4096)
+    ///
+    /// Invalid values fall back to the default.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default()
+            .with_enabled(parse_env("PIERRE_JWKS_ROLLOVER_ENABLED", false))
+            .with_overlap_hours(parse_env(
+                "PIERRE_JWKS_ROLLOVER_OVERLAP_HOURS",
+                DEFAULT_ROLLOVER_OVERLAP_HOURS,
+            ))
+            .with_check_interval_minutes(parse_env(
+                "PIERRE_JWKS_ROLLOVER_CHECK_MINUTES",
+                DEFAULT_ROLLOVER_CHECK_MINUTES,
+            ))
+            .with_key_size_bits(parse_env(
+                "PIERRE_RSA_KEY_SIZE",
+                DEFAULT_ROLLOVER_KEY_SIZE_BITS,
+            ))
+    }
+
+    /// Turn automatic rollover on or off
+    #[must_use]
+    pub const fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set how many hours a retiring key keeps validating tokens
+    #[must_use]
+    pub const fn with_overlap_hours(mut self, hours: u32) -> Self {
+        self.overlap_hours = hours;
+        self
+    }
+
+    /// Set the RSA key size used for new signing keys
+    #[must_use]
+    pub const fn with_key_size_bits(mut self, bits: usize) -> Self {
+        self.key_size_bits = bits;
+        self
+    }
+
+    /// Set the minutes between rollover checks (at least one)
+    #[must_use]
+    pub const fn with_check_interval_minutes(mut self, minutes: u32) -> Self {
+        self.check_interval_minutes = if minutes == 0 { 1 } else { minutes };
+        self
+    }
+
+    /// Whether the signing key is rolled over automatically
+    #[must_use]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// How long a retiring key keeps validating tokens
+    #[must_use]
+    pub fn overlap(&self) -> Duration {
+        Duration::hours(i64::from(self.overlap_hours))
+    }
+
+    /// RSA key size for new signing keys
+    #[must_use]
+    pub const fn key_size_bits(&self) -> usize {
+        self.key_size_bits
+    }
+
+    /// Time between rollover checks
+    #[must_use]
+    pub fn check_interval(&self) -> StdDuration {
+        StdDuration::from_secs(u64::from(self.check_interval_minutes) * 60)
+    }
+}
+
+/// Parse an environment variable, warning and falling back on invalid input
+fn parse_env<T>(name: &str, default: T) -> T
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    env::var(name)
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .inspect_err(|e| warn!("Invalid {} '{}': {}", name, value, e))
+                .ok()
+        })
+        .unwrap_or(default)
+}
@@ -0,0 +1,73 @@
+// ABOUTME: Strava push subscription configuration from environment variables
+// ABOUTME: Parses STRAVA_WEBHOOK_CALLBACK_URL and STRAVA_WEBHOOK_VERIFY_TOKEN for webhook-driven syncs
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+
+use tracing::warn;
+
+/// Configuration for receiving Strava activity events by push subscription
+///
+/// Both variables must be set for the server to register a subscription
+/// with Strava; without them activities are only fetched by polling.
+///
+/// # Example
+///
+/// ```bash
+/// export STRAVA_WEBHOOK_CALLBACK_URL=https://api.example.com/api/webhooks/strava
+/// export STRAVA_WEBHOOK_VERIFY_TOKEN=a-long-random-string
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StravaWebhookConfig {
+    /// Public URL Strava posts events to
+    pub callback_url: Option<String>,
+    /// Token Strava echoes in the subscription verification challenge
+    pub verify_token: Option<String>,
+}
+
+impl StravaWebhookConfig {
+    /// Load Strava webhook configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `STRAVA_WEBHOOK_CALLBACK_URL`: Public URL of `/api/webhooks/strava`
+    /// - `STRAVA_WEBHOOK_VERIFY_TOKEN`: Secret echoed back during verification
+    #[must_use]
+    pub fn from_env() -> Self {
+        let read = |name: &str| {
+            env::var(name)
+                .ok()
+                .map(|value| value.trim().to_owned())
+                .filter(|value| !value.is_empty())
+        };
+        let config = Self {
+            callback_url: read("STRAVA_WEBHOOK_CALLBACK_URL"),
+            verify_token: read("STRAVA_WEBHOOK_VERIFY_TOKEN"),
+        };
+
+        if config.callback_url.is_some() != config.verify_token.is_some() {
+            warn!(
+                "Strava webhooks need both STRAVA_WEBHOOK_CALLBACK_URL and STRAVA_WEBHOOK_VERIFY_TOKEN; push sync is disabled"
+            );
+        }
+
+        config
+    }
+
+    /// Create a configuration with an explicit callback URL and verify token
+    #[must_use]
+    pub fn new(callback_url: impl Into<String>, verify_token: impl Into<String>) -> Self {
+        Self {
+            callback_url: Some(callback_url.into()),
+            verify_token: Some(verify_token.into()),
+        }
+    }
+
+    /// Whether push subscriptions are configured
+    #[must_use]
+    pub const fn enabled(&self) -> bool {
+        self.callback_url.is_some() && self.verify_token.is_some()
+    }
+}
@@ -0,0 +1,102 @@
+// ABOUTME: Per-tool provider OAuth scope requirements from environment variables
+// ABOUTME: Parses PIERRE_TOOL_REQUIRED_SCOPES to decide when a tool call needs a scope step-up
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::HashMap;
+use std::env;
+
+use tracing::warn;
+
+/// Provider OAuth scopes that individual tools need beyond the initial grant
+///
+/// When a tool is called for a provider whose stored token lacks one of the
+/// configured scopes, the call is answered with a re-authorization URL that
+/// requests only the missing scopes (a scope step-up) instead of running the tool.
+///
+/// # Example
+///
+/// ```bash
+/// # tool/provider=scope[,scope...] entries separated by semicolons
+/// export PIERRE_TOOL_REQUIRED_SCOPES="get_athlete/strava=profile:read_all;analyze_sleep_quality/fitbit=sleep,heartrate"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolScopeRequirements {
+    /// Required scopes keyed by `(tool_name, provider)`
+    requirements: HashMap<(String, String), Vec<String>>,
+}
+
+impl ToolScopeRequirements {
+    /// Load scope requirements from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_TOOL_REQUIRED_SCOPES`: Semicolon-separated `tool/provider=scopes` entries,
+    ///   where scopes are comma-separated. Malformed entries are logged and skipped.
+    #[must_use]
+    pub fn from_env() -> Self {
+        env::var("PIERRE_TOOL_REQUIRED_SCOPES")
+            .map(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+
+    /// Parse requirements from the `PIERRE_TOOL_REQUIRED_SCOPES` format
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        let mut requirements = Self::default();
+
+        for entry in value.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry.split_once('=').and_then(|(target, scopes)| {
+                let (tool, provider) = target.split_once('/')?;
+                let scopes: Vec<&str> = scopes
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                (!tool.trim().is_empty() && !provider.trim().is_empty() && !scopes.is_empty())
+                    .then_some((tool.trim(), provider.trim(), scopes))
+            });
+
+            match parsed {
+                Some((tool, provider, scopes)) => {
+                    requirements = requirements.require(tool, provider, &scopes);
+                }
+                None => warn!("Ignoring malformed PIERRE_TOOL_REQUIRED_SCOPES entry '{entry}'"),
+            }
+        }
+
+        requirements
+    }
+
+    /// Require `scopes` from `provider` whenever `tool_name` is called for it
+    ///
+    /// Repeated calls for the same tool and provider accumulate scopes.
+    #[must_use]
+    pub fn require(mut self, tool_name: &str, provider: &str, scopes: &[&str]) -> Self {
+        let required = self
+            .requirements
+            .entry((tool_name.to_owned(), provider.to_lowercase()))
+            .or_default();
+        for scope in scopes {
+            if !required.iter().any(|existing| existing == scope) {
+                required.push((*scope).to_owned());
+            }
+        }
+        self
+    }
+
+    /// Scopes `tool_name` needs from `provider` (empty when none are configured)
+    #[must_use]
+    pub fn required_scopes(&self, tool_name: &str, provider: &str) -> &[String] {
+        self.requirements
+            .get(&(tool_name.to_owned(), provider.to_lowercase()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Check if no tool has scope requirements
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_empty()
+    }
+}
@@ -0,0 +1,102 @@
+// ABOUTME: User deletion configuration from environment variables
+// ABOUTME: Parses PIERRE_USER_RECOVERY_WINDOW_DAYS controlling how long soft-deleted users can be restored
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::env;
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::warn;
+
+/// Days a deleted user can be restored when `PIERRE_USER_RECOVERY_WINDOW_DAYS` is unset
+pub const DEFAULT_RECOVERY_WINDOW_DAYS: u32 = 30;
+
+/// Configuration for soft-deleting users before the cascading hard delete
+///
+/// Deleting a user hides them immediately; their data is only purged once the
+/// recovery window has passed, so accidental deletions can be undone. A window
+/// of zero days disables soft delete and removes users immediately.
+///
+/// # Example
+///
+/// ```bash
+/// export PIERRE_USER_RECOVERY_WINDOW_DAYS=14
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserDeletionConfig {
+    recovery_window_days: u32,
+}
+
+impl Default for UserDeletionConfig {
+    fn default() -> Self {
+        Self::with_recovery_window_days(DEFAULT_RECOVERY_WINDOW_DAYS)
+    }
+}
+
+impl UserDeletionConfig {
+    /// Load user deletion configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_USER_RECOVERY_WINDOW_DAYS`: Days a deleted user stays restorable
+    ///   (default: 30, `0` = delete immediately). Invalid values fall back to the default.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let recovery_window_days = env::var("PIERRE_USER_RECOVERY_WINDOW_DAYS")
+            .ok()
+            .and_then(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .inspect_err(|e| {
+                        warn!(
+                            "Invalid PIERRE_USER_RECOVERY_WINDOW_DAYS '{}': {}",
+                            value, e
+                        );
+                    })
+                    .ok()
+            })
+            .unwrap_or(DEFAULT_RECOVERY_WINDOW_DAYS);
+
+        Self::with_recovery_window_days(recovery_window_days)
+    }
+
+    /// Create a configuration with an explicit recovery window
+    #[must_use]
+    pub const fn with_recovery_window_days(recovery_window_days: u32) -> Self {
+        Self {
+            recovery_window_days,
+        }
+    }
+
+    /// Days a deleted user stays restorable
+    #[must_use]
+    pub const fn recovery_window_days(&self) -> u32 {
+        self.recovery_window_days
+    }
+
+    /// Whether deletions are soft (restorable) rather than immediate
+    #[must_use]
+    pub const fn soft_delete_enabled(&self) -> bool {
+        self.recovery_window_days > 0
+    }
+
+    /// Length of the recovery window
+    #[must_use]
+    pub fn recovery_window(&self) -> Duration {
+        Duration::days(i64::from(self.recovery_window_days))
+    }
+
+    /// Users deleted before this instant are past the window and may be purged
+    #[must_use]
+    pub fn purge_cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.recovery_window()
+    }
+
+    /// Last instant a user deleted at `deleted_at` can be restored
+    #[must_use]
+    pub fn restorable_until(&self, deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+        deleted_at + self.recovery_window()
+    }
+}
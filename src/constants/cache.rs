@@ -22,6 +22,9 @@ pub const TTL_ACTIVITY_SECS: u64 = 3_600; // 1 hour
 /// Stats cache TTL (6 hours) - stats aggregate over time windows
 pub const TTL_STATS_SECS: u64 = 21_600; // 6 hours
 
+/// Analysis result cache TTL (7 days) - keyed on an input hash, so entries never go stale
+pub const TTL_ANALYSIS_SECS: u64 = 604_800; // 7 days
+
 /// Redis connection pool minimum size
 pub const REDIS_POOL_MIN_SIZE: usize = 2;
 
@@ -139,6 +139,8 @@ pub mod limits {
     pub const USER_SESSION_EXPIRY_HOURS: i64 = 24;
     /// OAuth access token expiry hours (1 hour per RFC 8252 Security Best Practices)
     pub const OAUTH_ACCESS_TOKEN_EXPIRY_HOURS: i64 = 1;
+    /// Admin impersonation token expiry minutes (short-lived and never refreshed)
+    pub const ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES: i64 = 15;
     /// Maximum request size in bytes
     pub const MAX_REQUEST_SIZE: usize = 1_048_576; // 1MB
     /// Maximum response size in bytes
@@ -183,6 +185,8 @@ pub mod timeouts {
     pub const SSE_CONNECTION_TIMEOUT_SECS: u64 = 600; // 10 minutes
     /// OAuth session cookie Max-Age in seconds (matches JWT expiration)
     pub const SESSION_COOKIE_MAX_AGE_SECS: u64 = 86400; // 24 hours
+    /// Default tool execution timeout in seconds (catalog entries may override it)
+    pub const TOOL_EXECUTION_TIMEOUT_SECS: u64 = 120;
 }
 
 /// Cryptographic constants
@@ -195,7 +199,7 @@ pub mod crypto {
 
 /// Security configurations
 pub mod security {
-    /// CORS allowed origins
+    /// CORS allowed origins when `PIERRE_CORS_ORIGINS` is unset (any origin, without credentials)
     pub const CORS_ALLOWED_ORIGINS: &str = "*";
 }
 
@@ -293,6 +297,8 @@ pub mod database {
     pub const MAX_RETRY_DELAY_MS: u64 = 30_000;
     /// Exponential backoff base multiplier
     pub const RETRY_BACKOFF_MULTIPLIER: u32 = 2;
+    /// `SQLite` busy timeout in milliseconds before a locked database returns `SQLITE_BUSY`
+    pub const SQLITE_BUSY_TIMEOUT_MS: u64 = 5_000;
 }
 
 /// Redis connection configuration
@@ -368,6 +374,8 @@ pub mod json_fields {
     pub const SPORT_TYPE: &str = "sport_type";
     /// Output format field for serialization format (json, toon)
     pub const FORMAT: &str = "format";
+    /// Skip cached activity lists and fetch fresh data from the provider
+    pub const NO_CACHE: &str = "no_cache";
 }
 
 /// System configuration messages
@@ -728,6 +736,7 @@ pub mod rate_limiting_bursts {
 /// - `OAUTH_AUTHORIZE_RATE_LIMIT_RPM` - Authorization endpoint rate limit (default: 60 requests/minute)
 /// - `OAUTH_TOKEN_RATE_LIMIT_RPM` - Token endpoint rate limit (default: 30 requests/minute)
 /// - `OAUTH_REGISTER_RATE_LIMIT_RPM` - Registration endpoint rate limit (default: 10 requests/minute)
+/// - `OAUTH_INTROSPECT_RATE_LIMIT_RPM` - Introspection endpoint rate limit (default: 60 requests/minute)
 /// - `OAUTH2_RATE_LIMIT_WINDOW_SECS` - Rate limit window duration (default: 60 seconds)
 pub mod oauth_rate_limiting {
     /// Authorization endpoint rate limit (requests per minute)
@@ -742,6 +751,10 @@ pub mod oauth_rate_limiting {
     /// Protects /oauth2/register from bulk client creation
     pub const REGISTER_RPM: u32 = 10;
 
+    /// Introspection endpoint rate limit (requests per minute)
+    /// Protects /oauth2/introspect from token guessing
+    pub const INTROSPECT_RPM: u32 = 60;
+
     /// Rate limit window duration in seconds
     /// Time window for counting rate limit violations
     pub const WINDOW_SECS: u64 = 60;
@@ -53,6 +53,12 @@ pub const SYNTHETIC: &str = "synthetic";
 /// the provider implementation is gated behind `provider-synthetic` feature.
 pub const SYNTHETIC_SLEEP: &str = "synthetic_sleep";
 
+/// File import pseudo-provider identifier.
+///
+/// Serves activities the user uploaded as GPX or TCX files. Always available;
+/// it needs no OAuth connection.
+pub const FILE: &str = "file";
+
 /// Get statically-known OAuth providers
 ///
 /// **Deprecated**: Use `crate::providers::get_supported_providers()` instead,
@@ -90,6 +96,21 @@ pub const STRAVA_DEFAULT_SCOPES: &str = "activity:read_all";
 #[cfg(feature = "provider-fitbit")]
 pub const FITBIT_DEFAULT_SCOPES: &str = "activity profile sleep heartrate weight";
 
+/// Strava scope for reading the public athlete profile
+pub const STRAVA_READ_SCOPE: &str = "read";
+
+/// Strava scope for reading the athlete's activities
+pub const STRAVA_ACTIVITY_READ_SCOPE: &str = "activity:read";
+
+/// Strava scope for reading the full athlete profile, including gear
+pub const STRAVA_PROFILE_READ_ALL_SCOPE: &str = "profile:read_all";
+
+/// Fitbit scope for reading activities
+pub const FITBIT_ACTIVITY_SCOPE: &str = "activity";
+
+/// Fitbit scope for reading the user profile
+pub const FITBIT_PROFILE_SCOPE: &str = "profile";
+
 /// Garmin default scopes
 #[cfg(feature = "provider-garmin")]
 pub const GARMIN_DEFAULT_SCOPES: &str = "wellness:read,activities:read";
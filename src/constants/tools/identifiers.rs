@@ -12,6 +12,24 @@ pub const GET_ACTIVITIES: &str = "get_activities";
 pub const GET_ATHLETE: &str = "get_athlete";
 /// Tool identifier for retrieving athlete statistics
 pub const GET_STATS: &str = "get_stats";
+/// Tool identifier for listing shoes, bikes, and other gear
+pub const LIST_GEAR: &str = "list_gear";
+/// Tool identifier for listing an activity's segment efforts and segment PRs
+pub const GET_SEGMENT_EFFORTS: &str = "get_segment_efforts";
+/// Tool identifier for per-kilometer or per-mile activity splits
+pub const GET_ACTIVITY_SPLITS: &str = "get_activity_splits";
+/// Tool identifier for historical weather at an activity's start location and time
+pub const GET_ACTIVITY_WEATHER: &str = "get_activity_weather";
+/// Tool identifier for searching activities with text and metadata filters
+pub const SEARCH_ACTIVITIES: &str = "search_activities";
+/// Tool identifier for activities created, updated, or deleted since a timestamp
+pub const GET_ACTIVITIES_DELTA: &str = "get_activities_delta";
+/// Tool identifier for merging a duplicate activity into a primary activity
+pub const MERGE_ACTIVITIES: &str = "merge_activities";
+/// Tool identifier for undoing an activity merge
+pub const UNMERGE_ACTIVITIES: &str = "unmerge_activities";
+/// Tool identifier for exporting all of a user's data as a portable archive
+pub const EXPORT_USER_DATA: &str = "export_user_data";
 /// Tool identifier for retrieving AI-powered activity insights
 pub const GET_ACTIVITY_INTELLIGENCE: &str = "get_activity_intelligence";
 
@@ -20,6 +38,8 @@ pub const GET_ACTIVITY_INTELLIGENCE: &str = "get_activity_intelligence";
 pub const CONNECT_PROVIDER: &str = "connect_provider"; // Unified Pierre + Provider OAuth flow
 /// Tool identifier for checking connection status with fitness providers
 pub const GET_CONNECTION_STATUS: &str = "get_connection_status";
+/// Tool identifier for listing provider connections with token health and last sync
+pub const LIST_CONNECTED_PROVIDERS: &str = "list_connected_providers";
 /// Tool identifier for disconnecting from fitness providers
 pub const DISCONNECT_PROVIDER: &str = "disconnect_provider";
 
@@ -50,10 +70,16 @@ pub const DELETE_FITNESS_CONFIG: &str = "delete_fitness_config";
 
 /// Advanced analytics tools
 pub const PREDICT_PERFORMANCE: &str = "predict_performance";
+/// Tool identifier for predicting race times from recent runs
+pub const PREDICT_RACE_TIMES: &str = "predict_race_times";
+/// Tool identifier for computing time spent in heart rate and power zones
+pub const ANALYZE_TIME_IN_ZONES: &str = "analyze_time_in_zones";
 /// Tool identifier for analyzing whether fitness goals are achievable
 pub const ANALYZE_GOAL_FEASIBILITY: &str = "analyze_goal_feasibility";
 /// Tool identifier for analyzing training load and recovery needs
 pub const ANALYZE_TRAINING_LOAD: &str = "analyze_training_load";
+/// Tool identifier for the daily CTL/ATL/TSB training load series
+pub const GET_TRAINING_LOAD_TREND: &str = "get_training_load_trend";
 /// Tool identifier for calculating overall fitness score
 pub const CALCULATE_FITNESS_SCORE: &str = "calculate_fitness_score";
 /// Tool identifier for generating personalized training recommendations
@@ -61,6 +87,9 @@ pub const GENERATE_RECOMMENDATIONS: &str = "generate_recommendations";
 /// Tool identifier for goal suggestion functionality
 pub const SUGGEST_GOALS: &str = "suggest_goals";
 
+/// Sleep and recovery tools
+pub const GET_RECOVERY_SCORE: &str = "get_recovery_score";
+
 /// Nutrition analysis tools
 pub const CALCULATE_DAILY_NUTRITION: &str = "calculate_daily_nutrition";
 /// Tool identifier for searching food items
@@ -91,6 +120,8 @@ pub const SEARCH_RECIPES: &str = "search_recipes";
 pub const SAVE_RECIPE: &str = "save_recipe";
 /// Tool identifier for validating recipe nutrition
 pub const VALIDATE_RECIPE: &str = "validate_recipe";
+/// Tool identifier for ranking saved recipes against the day's training
+pub const SUGGEST_RECIPES: &str = "suggest_recipes";
 
 /// Coach management tools (custom AI personas)
 pub const LIST_COACHES: &str = "list_coaches";
@@ -118,6 +149,8 @@ pub const HIDE_COACH: &str = "hide_coach";
 pub const SHOW_COACH: &str = "show_coach";
 /// Tool identifier for listing hidden coaches
 pub const LIST_HIDDEN_COACHES: &str = "list_hidden_coaches";
+/// Tool identifier for getting the coaching system context with athlete variables
+pub const GET_COACH_CONTEXT: &str = "get_coach_context";
 
 /// Admin coach management tools (system coaches)
 /// Tool identifier for listing system coaches (admin only)
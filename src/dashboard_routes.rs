@@ -240,8 +240,8 @@ impl DashboardRoutes {
             // Today's usage
             let today_stats = self
                 .resources
-                .database
-                .get_api_key_usage_stats(&api_key.id, today_start, Utc::now())
+                .analytics
+                .api_key_usage_stats(&api_key.id, today_start, Utc::now())
                 .await
                 .map_err(|e| {
                     AppError::database(format!("Failed to get API key usage stats for today: {e}"))
@@ -251,8 +251,8 @@ impl DashboardRoutes {
             // This month's usage
             let month_stats = self
                 .resources
-                .database
-                .get_api_key_usage_stats(&api_key.id, month_start, Utc::now())
+                .analytics
+                .api_key_usage_stats(&api_key.id, month_start, Utc::now())
                 .await
                 .map_err(|e| {
                     AppError::database(format!("Failed to get API key usage stats for month: {e}"))
@@ -266,8 +266,8 @@ impl DashboardRoutes {
             let tier_name = format!("{:?}", api_key.tier).to_lowercase();
             let month_stats = self
                 .resources
-                .database
-                .get_api_key_usage_stats(&api_key.id, month_start, Utc::now())
+                .analytics
+                .api_key_usage_stats(&api_key.id, month_start, Utc::now())
                 .await
                 .map_err(|e| {
                     AppError::database(format!(
@@ -355,8 +355,8 @@ impl DashboardRoutes {
             for api_key in &api_keys {
                 let stats = self
                     .resources
-                    .database
-                    .get_api_key_usage_stats(&api_key.id, day_start, day_end)
+                    .analytics
+                    .api_key_usage_stats(&api_key.id, day_start, day_end)
                     .await
                     .map_err(|e| {
                         AppError::database(format!(
@@ -583,8 +583,8 @@ impl DashboardRoutes {
         for api_key in api_keys {
             let stats = self
                 .resources
-                .database
-                .get_api_key_usage_stats(&api_key.id, start_date, end_date)
+                .analytics
+                .api_key_usage_stats(&api_key.id, start_date, end_date)
                 .await
                 .map_err(|e| {
                     AppError::database(format!(
@@ -790,8 +790,8 @@ impl DashboardRoutes {
         for api_key in keys_to_check {
             let stats = self
                 .resources
-                .database
-                .get_api_key_usage_stats(&api_key.id, start_time, Utc::now())
+                .analytics
+                .api_key_usage_stats(&api_key.id, start_time, Utc::now())
                 .await
                 .map_err(|e| {
                     AppError::database(format!("Failed to get API key usage stats: {e}"))
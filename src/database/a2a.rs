@@ -917,10 +917,7 @@ impl Database {
     ) -> AppResult<()> {
         let output_json = result.map(serde_json::to_string).transpose()?;
 
-        let completed_at = match status {
-            TaskStatus::Completed | TaskStatus::Failed | TaskStatus::Cancelled => Some(Utc::now()),
-            _ => None,
-        };
+        let completed_at = status.is_terminal().then(Utc::now);
 
         sqlx::query(
             r"
@@ -0,0 +1,169 @@
+// ABOUTME: Database operations for per-tenant activity backfill windows and resume markers
+// ABOUTME: Reads and updates tenants.max_backfill_days and upserts markers left by truncated backfills
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::errors::{AppError, AppResult};
+use crate::pagination::Cursor;
+use crate::providers::backfill::BackfillMarker;
+use chrono::{DateTime, Utc};
+use pierre_core::models::TenantId;
+use sqlx::Row;
+use uuid::Uuid;
+
+impl Database {
+    /// Get how many days back activity backfills may page for a tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tenant does not exist or the query fails
+    pub async fn get_tenant_max_backfill_days_impl(&self, tenant_id: TenantId) -> AppResult<u32> {
+        let days: Option<i64> =
+            sqlx::query_scalar("SELECT max_backfill_days FROM tenants WHERE id = ?")
+                .bind(tenant_id.to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Database query failed: {e}")))?;
+
+        let days = days.ok_or_else(|| AppError::not_found(format!("Tenant {tenant_id}")))?;
+        u32::try_from(days)
+            .map_err(|e| AppError::database(format!("Invalid max_backfill_days {days}: {e}")))
+    }
+
+    /// Set how many days back activity backfills may page for a tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `days` is zero, the tenant does not exist, or the update fails
+    pub async fn set_tenant_max_backfill_days_impl(
+        &self,
+        tenant_id: TenantId,
+        days: u32,
+    ) -> AppResult<()> {
+        if days == 0 {
+            return Err(AppError::invalid_input(
+                "max_backfill_days must be at least 1",
+            ));
+        }
+
+        let result =
+            sqlx::query("UPDATE tenants SET max_backfill_days = ?, updated_at = ? WHERE id = ?")
+                .bind(i64::from(days))
+                .bind(Utc::now().to_rfc3339())
+                .bind(tenant_id.to_string())
+                .execute(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Failed to update tenant: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Tenant {tenant_id}")));
+        }
+        Ok(())
+    }
+
+    /// Get the marker left by a truncated backfill for a user's provider connection
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored row is malformed
+    pub async fn get_backfill_marker_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<Option<BackfillMarker>> {
+        let row = sqlx::query(
+            r"
+            SELECT resume_cursor, window_days, activities_fetched, stopped_at
+            FROM activity_backfill_markers
+            WHERE user_id = ? AND tenant_id = ? AND provider = ?
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .bind(provider)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get backfill marker: {e}")))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let window_days: i64 = row.get("window_days");
+        let activities_fetched: i64 = row.get("activities_fetched");
+        let stopped_at: String = row.get("stopped_at");
+
+        Ok(Some(BackfillMarker {
+            user_id,
+            tenant_id,
+            provider: provider.to_owned(),
+            resume_cursor: Cursor::from_string(row.get("resume_cursor")),
+            window_days: u32::try_from(window_days)
+                .map_err(|e| AppError::database(format!("Invalid window_days: {e}")))?,
+            activities_fetched: u32::try_from(activities_fetched)
+                .map_err(|e| AppError::database(format!("Invalid activities_fetched: {e}")))?,
+            stopped_at: DateTime::parse_from_rfc3339(&stopped_at)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| AppError::database(format!("Invalid stopped_at timestamp: {e}")))?,
+        }))
+    }
+
+    /// Store a backfill marker, replacing any earlier marker for the same connection
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database upsert fails
+    pub async fn save_backfill_marker_impl(&self, marker: &BackfillMarker) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO activity_backfill_markers
+                (user_id, tenant_id, provider, resume_cursor, window_days, activities_fetched, stopped_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (user_id, tenant_id, provider) DO UPDATE SET
+                resume_cursor = excluded.resume_cursor,
+                window_days = excluded.window_days,
+                activities_fetched = excluded.activities_fetched,
+                stopped_at = excluded.stopped_at
+            ",
+        )
+        .bind(marker.user_id.to_string())
+        .bind(marker.tenant_id.to_string())
+        .bind(&marker.provider)
+        .bind(marker.resume_cursor.as_str())
+        .bind(i64::from(marker.window_days))
+        .bind(i64::from(marker.activities_fetched))
+        .bind(marker.stopped_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to save backfill marker: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Remove the backfill marker for a user's provider connection
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails
+    pub async fn clear_backfill_marker_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            "DELETE FROM activity_backfill_markers WHERE user_id = ? AND tenant_id = ? AND provider = ?",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to clear backfill marker: {e}")))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+// ABOUTME: Database operations for per-activity sync records used by delta syncs
+// ABOUTME: Lists records for a provider window and upserts the state found by the latest sync
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::mappers::parse_rfc3339_timestamp;
+use crate::errors::{AppError, AppResult};
+use crate::models::{ActivitySyncRecord, TenantId};
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use uuid::Uuid;
+
+impl Database {
+    /// List sync records for activities that started at or after `started_after`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored timestamp is invalid
+    pub async fn get_activity_sync_records_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        started_after: DateTime<Utc>,
+    ) -> AppResult<Vec<ActivitySyncRecord>> {
+        let rows = sqlx::query(
+            r"
+            SELECT activity_id, start_date, content_hash, updated_at, deleted_at
+            FROM activity_sync_records
+            WHERE user_id = ?1 AND tenant_id = ?2 AND provider = ?3 AND start_date >= ?4
+            ORDER BY start_date DESC
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .bind(provider)
+        .bind(started_after.to_rfc3339())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list activity sync records: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let start_date: String = row.try_get("start_date")?;
+                let updated_at: String = row.try_get("updated_at")?;
+                let deleted_at: Option<String> = row.try_get("deleted_at")?;
+                Ok(ActivitySyncRecord {
+                    activity_id: row.try_get("activity_id")?,
+                    start_date: parse_rfc3339_timestamp(&start_date, "start_date")?,
+                    content_hash: row.try_get("content_hash")?,
+                    updated_at: parse_rfc3339_timestamp(&updated_at, "updated_at")?,
+                    deleted_at: deleted_at.as_deref().map(parse_timestamp).transpose()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Insert or replace sync records for a provider
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any upsert fails; no records are written in that case
+    pub async fn upsert_activity_sync_records_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        records: &[ActivitySyncRecord],
+    ) -> AppResult<()> {
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        for record in records {
+            sqlx::query(
+                r"
+                INSERT INTO activity_sync_records
+                    (user_id, tenant_id, provider, activity_id, start_date, content_hash, updated_at, deleted_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT(user_id, tenant_id, provider, activity_id) DO UPDATE SET
+                    start_date = excluded.start_date,
+                    content_hash = excluded.content_hash,
+                    updated_at = excluded.updated_at,
+                    deleted_at = excluded.deleted_at
+                ",
+            )
+            .bind(user_id.to_string())
+            .bind(tenant_id.to_string())
+            .bind(provider)
+            .bind(&record.activity_id)
+            .bind(record.start_date.to_rfc3339())
+            .bind(&record.content_hash)
+            .bind(record.updated_at.to_rfc3339())
+            .bind(record.deleted_at.map(|dt| dt.to_rfc3339()))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to store activity sync record: {e}"))
+            })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to commit activity sync records: {e}")))
+    }
+}
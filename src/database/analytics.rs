@@ -6,12 +6,17 @@
 
 use super::Database;
 use crate::dashboard_routes::ToolUsage;
+use crate::database_plugins::shared::insights::{
+    canonicalize, insight_content_hash, insight_type_of,
+};
+use crate::database_plugins::shared::transactions::SqliteTransactionGuard;
 use crate::errors::{AppError, AppResult};
-use crate::models::TenantId;
+use crate::models::{TenantDailyToolUsage, TenantId, ToolCallCounts};
 use crate::rate_limiting::JwtUsage;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
+use std::collections::HashSet;
 use tracing::{error, warn};
 use uuid::Uuid;
 
@@ -38,6 +43,60 @@ pub struct RequestLog {
     pub error_message: Option<String>,
 }
 
+/// Calendar period that activity rollups are grouped by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Granularity {
+    /// ISO weeks starting on Monday (UTC)
+    Week,
+    /// Calendar months (UTC)
+    Month,
+}
+
+impl Granularity {
+    /// Unit name as used by `date_trunc` and interval literals
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    /// `SQLite` expression truncating the timestamp `column` to its bucket start (UTC date)
+    fn sqlite_bucket_of(self, column: &str) -> String {
+        match self {
+            Self::Week => format!("strftime('%Y-%m-%d', {column}, '-6 days', 'weekday 1')"),
+            Self::Month => format!("strftime('%Y-%m-%d', {column}, 'start of month')"),
+        }
+    }
+
+    /// `SQLite` date modifier advancing one bucket
+    const fn sqlite_step(self) -> &'static str {
+        match self {
+            Self::Week => "+7 days",
+            Self::Month => "+1 month",
+        }
+    }
+}
+
+/// Activity totals for one week or month
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollupBucket {
+    /// Start of the bucket (midnight UTC on a Monday or the first of the month)
+    pub bucket_start: DateTime<Utc>,
+    /// Number of activities started in the bucket
+    pub activity_count: u64,
+    /// Total distance (meters)
+    pub total_distance_meters: f64,
+    /// Total moving time (seconds)
+    pub total_duration_seconds: u64,
+    /// Total elevation gain (meters)
+    pub total_elevation_gain_meters: f64,
+    /// Mean of the activities' average heart rates, if any recorded one
+    pub average_heart_rate: Option<f64>,
+}
+
 impl Database {
     /// Record JWT usage for rate limiting
     ///
@@ -107,6 +166,30 @@ impl Database {
         Ok(())
     }
 
+    /// Get timestamps of a user's JWT requests made at or after `since`, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn get_jwt_usage_times_impl(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r"
+            SELECT timestamp FROM jwt_usage
+            WHERE user_id = $1 AND timestamp >= $2
+            ORDER BY timestamp ASC
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get JWT usage times: {e}")))
+    }
+
     /// Get current JWT usage count for a user (for rate limiting)
     ///
     /// # Errors
@@ -283,8 +366,50 @@ impl Database {
         Ok(())
     }
 
+    /// Replace a goal's stored data
+    ///
+    /// The `id` key added by [`Self::get_user_goals_impl`] is not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the goal does not exist for the user or the database operation fails.
+    pub async fn update_goal_data_impl(
+        &self,
+        goal_id: &str,
+        user_id: Uuid,
+        mut goal_data: serde_json::Value,
+    ) -> AppResult<()> {
+        if let Some(obj) = goal_data.as_object_mut() {
+            obj.remove("id");
+        }
+        let goal_json = serde_json::to_string(&goal_data)?;
+
+        let result = sqlx::query(
+            r"
+            UPDATE goals
+            SET goal_data = $1, updated_at = $2
+            WHERE id = $3 AND user_id = $4
+            ",
+        )
+        .bind(goal_json)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(goal_id)
+        .bind(user_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update goal: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Goal {goal_id}")));
+        }
+        Ok(())
+    }
+
     /// Store an insight for a user (full 4-parameter version)
     ///
+    /// Storing an insight identical to one the user already has refreshes its
+    /// timestamp and returns the existing ID instead of adding a duplicate.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database operation fails or if JSON serialization fails.
@@ -295,27 +420,14 @@ impl Database {
         insight_type: &str,
         insight_data: serde_json::Value,
     ) -> AppResult<String> {
-        let insight_id = Uuid::new_v4().to_string();
-        let insight_json = serde_json::to_string(&insight_data)?;
-        let now = chrono::Utc::now().to_rfc3339();
-
-        sqlx::query(
-            r"
-            INSERT INTO insights (id, user_id, activity_id, insight_type, insight_data, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ",
+        Self::upsert_insight(
+            &self.pool,
+            user_id,
+            activity_id.as_deref(),
+            insight_type,
+            &insight_data,
         )
-        .bind(&insight_id)
-        .bind(user_id.to_string())
-        .bind(activity_id)
-        .bind(insight_type)
-        .bind(insight_json)
-        .bind(&now)
-        .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to store insight: {e}")))?;
-
-        Ok(insight_id)
     }
 
     /// Store an insight for a user (simplified 2-parameter version for trait compatibility)
@@ -329,16 +441,92 @@ impl Database {
         insight_data: serde_json::Value,
     ) -> AppResult<String> {
         // Extract insight type from the JSON data or use a default
-        let insight_type = insight_data
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("general");
+        let insight_type = insight_type_of(&insight_data).to_owned();
 
         // Call the full 4-parameter version with defaults
-        self.store_insight_full(user_id, None, insight_type, insight_data.clone()) // Safe: JSON value ownership for insight storage
+        self.store_insight_full(user_id, None, &insight_type, insight_data)
             .await
     }
 
+    /// Store many insights for a user in a single transaction
+    ///
+    /// Returns the insight IDs in input order. Insights the user already has,
+    /// or that repeat earlier in the batch, map to the existing ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any insert fails, in which case none are stored.
+    pub async fn store_insights_batch_impl(
+        &self,
+        user_id: Uuid,
+        insights: Vec<serde_json::Value>,
+    ) -> AppResult<Vec<String>> {
+        if insights.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+        let mut guard = SqliteTransactionGuard::new(tx);
+
+        let mut insight_ids = Vec::with_capacity(insights.len());
+        for insight_data in &insights {
+            let insight_id = Self::upsert_insight(
+                guard.executor()?,
+                user_id,
+                None,
+                insight_type_of(insight_data),
+                insight_data,
+            )
+            .await?;
+            insight_ids.push(insight_id);
+        }
+
+        guard.commit().await?;
+        Ok(insight_ids)
+    }
+
+    /// Insert an insight, or refresh the existing row with the same content hash
+    async fn upsert_insight<'e, E>(
+        executor: E,
+        user_id: Uuid,
+        activity_id: Option<&str>,
+        insight_type: &str,
+        insight_data: &serde_json::Value,
+    ) -> AppResult<String>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let insight_id = Uuid::new_v4().to_string();
+        let insight_json = serde_json::to_string(insight_data)?;
+        let content_hash = insight_content_hash(activity_id, insight_type, insight_data);
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let row = sqlx::query(
+            r"
+            INSERT INTO insights (id, user_id, activity_id, insight_type, insight_data, content_hash, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT(user_id, content_hash) DO UPDATE SET created_at = excluded.created_at
+            RETURNING id
+            ",
+        )
+        .bind(&insight_id)
+        .bind(user_id.to_string())
+        .bind(activity_id)
+        .bind(insight_type)
+        .bind(insight_json)
+        .bind(&content_hash)
+        .bind(&now)
+        .fetch_one(executor)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store insight: {e}")))?;
+
+        Ok(row.get("id"))
+    }
+
     /// Get recent insights for a user (trait-compatible 3-parameter version)
     ///
     /// # Errors
@@ -380,11 +568,15 @@ impl Database {
         .await
         .map_err(|e| AppError::database(format!("Failed to get user insights: {e}")))?;
 
+        // Rows stored before content hashing may repeat; keep the most recent copy
+        let mut seen = HashSet::new();
         let mut insights = Vec::new();
         for row in rows {
             let insight_json: String = row.get("insight_data");
             let insight: serde_json::Value = serde_json::from_str(&insight_json)?;
-            insights.push(insight);
+            if seen.insert(canonicalize(&insight).to_string()) {
+                insights.push(insight);
+            }
         }
 
         Ok(insights)
@@ -680,6 +872,228 @@ impl Database {
         Ok(tool_usage)
     }
 
+    /// Get tool usage summed across all members of a tenant within a time range (internal implementation)
+    ///
+    /// # Errors
+    /// Returns error if database operation fails
+    pub async fn get_tenant_tool_usage_impl(
+        &self,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> AppResult<Vec<ToolUsage>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                aku.tool_name,
+                COUNT(*) as usage_count,
+                AVG(CAST(aku.response_time_ms AS REAL)) as avg_response_time,
+                COUNT(CASE WHEN aku.status_code < 400 THEN 1 END) as success_count
+            FROM api_key_usage aku
+            JOIN api_keys ak ON aku.api_key_id = ak.id
+            JOIN tenant_users tu ON tu.user_id = ak.user_id
+            WHERE tu.tenant_id = $1 AND aku.timestamp BETWEEN $2 AND $3
+            GROUP BY aku.tool_name
+            ORDER BY usage_count DESC, aku.tool_name
+            ",
+        )
+        .bind(tenant_id.to_string())
+        .bind(start_time)
+        .bind(end_time)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant tool usage: {e}")))?;
+
+        let mut tool_usage = Vec::with_capacity(rows.len());
+        for row in rows {
+            let tool_name: String = row.get("tool_name");
+            let usage_count: i64 = row.get("usage_count");
+            let success_count: i64 = row.get("success_count");
+            let avg_response_time: Option<f64> = row.get("avg_response_time");
+
+            #[allow(clippy::cast_precision_loss)]
+            let success_rate = if usage_count > 0 {
+                (success_count as f64 / usage_count as f64) * 100.0
+            } else {
+                0.0
+            };
+
+            tool_usage.push(ToolUsage {
+                tool_name,
+                #[allow(clippy::cast_sign_loss)]
+                request_count: usage_count as u64,
+                success_rate,
+                average_response_time: avg_response_time.unwrap_or(0.0),
+            });
+        }
+
+        Ok(tool_usage)
+    }
+
+    /// Get per-day tool call counts for a tenant's members over `[start, end)` (internal implementation)
+    ///
+    /// API key, A2A, and JWT usage are combined in one grouped query. Usage
+    /// is attributed to every tenant the calling user belongs to; JWT usage
+    /// records the endpoint as the tool name.
+    ///
+    /// # Errors
+    /// Returns error if the database operation fails or a row is invalid
+    pub async fn get_tenant_daily_tool_usage_impl(
+        &self,
+        tenant_id: TenantId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TenantDailyToolUsage>> {
+        let rows = sqlx::query(
+            r"
+            WITH members AS (
+                SELECT user_id FROM tenant_users WHERE tenant_id = ?1
+            ),
+            calls AS (
+                SELECT date(aku.timestamp) AS usage_date, aku.tool_name, 'api_key' AS source
+                FROM api_key_usage aku
+                JOIN api_keys ak ON aku.api_key_id = ak.id
+                WHERE ak.user_id IN (SELECT user_id FROM members)
+                  AND aku.timestamp >= ?2 AND aku.timestamp < ?3
+                UNION ALL
+                SELECT date(au.timestamp), au.tool_name, 'a2a'
+                FROM a2a_usage au
+                JOIN a2a_clients ac ON au.client_id = ac.id
+                WHERE ac.user_id IN (SELECT user_id FROM members)
+                  AND au.timestamp >= ?2 AND au.timestamp < ?3
+                UNION ALL
+                SELECT date(ju.timestamp), ju.endpoint, 'jwt'
+                FROM jwt_usage ju
+                WHERE ju.user_id IN (SELECT user_id FROM members)
+                  AND ju.timestamp >= ?2 AND ju.timestamp < ?3
+            )
+            SELECT
+                usage_date,
+                tool_name,
+                SUM(CASE WHEN source = 'api_key' THEN 1 ELSE 0 END) AS api_key_calls,
+                SUM(CASE WHEN source = 'a2a' THEN 1 ELSE 0 END) AS a2a_calls,
+                SUM(CASE WHEN source = 'jwt' THEN 1 ELSE 0 END) AS jwt_calls
+            FROM calls
+            GROUP BY usage_date, tool_name
+            ORDER BY usage_date, tool_name
+            ",
+        )
+        .bind(tenant_id.to_string())
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant daily tool usage: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let usage_date: String = row.get("usage_date");
+                let count = |column: &str| u64::try_from(row.get::<i64, _>(column)).unwrap_or(0);
+                Ok(TenantDailyToolUsage {
+                    date: NaiveDate::parse_from_str(&usage_date, "%Y-%m-%d").map_err(|e| {
+                        AppError::database(format!("Invalid usage date '{usage_date}': {e}"))
+                    })?,
+                    tool_name: row.get("tool_name"),
+                    calls: ToolCallCounts {
+                        api_key: count("api_key_calls"),
+                        a2a: count("a2a_calls"),
+                        jwt: count("jwt_calls"),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Get per-week or per-month activity totals for a user (internal implementation)
+    ///
+    /// Buckets are computed in UTC over `[start, end)` in a single grouped query;
+    /// every bucket in the range is returned, including those with no activities.
+    ///
+    /// # Errors
+    /// Returns error if the range is empty or the database operation fails
+    pub async fn get_activity_rollups_impl(
+        &self,
+        user_id: Uuid,
+        granularity: Granularity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<RollupBucket>> {
+        if end <= start {
+            return Err(AppError::invalid_input(
+                "Rollup range end must be after its start",
+            ));
+        }
+
+        let step = granularity.sqlite_step();
+        let query = format!(
+            r"
+            WITH RECURSIVE buckets(bucket_start) AS (
+                SELECT {range_start}
+                UNION ALL
+                SELECT date(bucket_start, '{step}') FROM buckets
+                WHERE julianday(date(bucket_start, '{step}')) < julianday(?3)
+            ),
+            totals AS (
+                SELECT {activity_bucket} AS bucket_start,
+                       COUNT(*) AS activity_count,
+                       TOTAL(distance_meters) AS total_distance,
+                       SUM(duration_seconds) AS total_duration,
+                       TOTAL(elevation_gain) AS total_elevation,
+                       AVG(average_heart_rate) AS average_heart_rate
+                FROM synthetic_activities
+                WHERE user_id = ?1
+                  AND julianday(start_date) >= julianday(?2)
+                  AND julianday(start_date) < julianday(?3)
+                GROUP BY 1
+            )
+            SELECT b.bucket_start,
+                   COALESCE(t.activity_count, 0) AS activity_count,
+                   COALESCE(t.total_distance, 0.0) AS total_distance,
+                   COALESCE(t.total_duration, 0) AS total_duration,
+                   COALESCE(t.total_elevation, 0.0) AS total_elevation,
+                   t.average_heart_rate
+            FROM buckets b
+            LEFT JOIN totals t ON t.bucket_start = b.bucket_start
+            ORDER BY b.bucket_start
+            ",
+            range_start = granularity.sqlite_bucket_of("?2"),
+            activity_bucket = granularity.sqlite_bucket_of("start_date"),
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(user_id.to_string())
+            .bind(start.to_rfc3339())
+            .bind(end.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to get activity rollups: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let bucket_start: String = row.get("bucket_start");
+                let bucket_start = NaiveDate::parse_from_str(&bucket_start, "%Y-%m-%d")
+                    .map_err(|e| {
+                        AppError::database(format!(
+                            "Invalid rollup bucket date '{bucket_start}': {e}"
+                        ))
+                    })?
+                    .and_time(NaiveTime::MIN)
+                    .and_utc();
+                let activity_count: i64 = row.get("activity_count");
+                let total_duration: i64 = row.get("total_duration");
+
+                Ok(RollupBucket {
+                    bucket_start,
+                    activity_count: u64::try_from(activity_count).unwrap_or(0),
+                    total_distance_meters: row.get("total_distance"),
+                    total_duration_seconds: u64::try_from(total_duration).unwrap_or(0),
+                    total_elevation_gain_meters: row.get("total_elevation"),
+                    average_heart_rate: row.get("average_heart_rate"),
+                })
+            })
+            .collect()
+    }
+
     /// Get top tools analysis for a user (public API)
     ///
     /// # Errors
@@ -6,10 +6,11 @@
 
 use super::Database;
 use crate::api_keys::{ApiKey, ApiKeyTier, ApiKeyUsage, ApiKeyUsageStats};
+use crate::database_plugins::shared::transactions::SqliteTransactionGuard;
 use crate::errors::{AppError, AppResult};
 use chrono::{DateTime, Duration, Utc};
 use sqlx::sqlite::SqliteRow;
-use sqlx::Row;
+use sqlx::{Executor, Row, Sqlite};
 use tracing::{debug, warn};
 use uuid::Uuid;
 
@@ -20,51 +21,27 @@ impl Database {
     ///
     /// Returns an error if the database operation fails
     pub async fn create_api_key_impl(&self, api_key: &ApiKey) -> AppResult<()> {
-        // Handle enterprise tier unlimited requests by storing NULL
-        let rate_limit_requests = if api_key.tier == ApiKeyTier::Enterprise {
-            None
-        } else {
-            Some(i32::try_from(api_key.rate_limit_requests).map_err(|e| {
-                AppError::internal(format!(
-                    "Integer conversion failed for rate_limit_requests: {e}"
-                ))
-            })?)
-        };
+        insert_api_key(&self.pool, api_key).await
+    }
 
-        sqlx::query(
-            r"
-            INSERT INTO api_keys (
-                id, user_id, name, description, key_hash, key_prefix, tier,
-                rate_limit_requests, rate_limit_window_seconds, is_active,
-                expires_at, created_at
-            ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
-            )
-            ",
-        )
-        .bind(&api_key.id)
-        .bind(api_key.user_id.to_string())
-        .bind(&api_key.name)
-        .bind(&api_key.description)
-        .bind(&api_key.key_hash)
-        .bind(&api_key.key_prefix)
-        .bind(api_key.tier.as_str())
-        .bind(rate_limit_requests)
-        .bind(
-            i32::try_from(api_key.rate_limit_window_seconds).map_err(|e| {
-                AppError::internal(format!(
-                    "Integer conversion failed for rate_limit_window_seconds: {e}"
-                ))
-            })?,
-        )
-        .bind(api_key.is_active)
-        .bind(api_key.expires_at)
-        .bind(api_key.created_at)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| AppError::database(format!("Failed to create API key: {e}")))?;
+    /// Create several `API` keys atomically: either all of them are stored or none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any insert fails, in which case the transaction is rolled back
+    pub async fn create_api_keys_batch_impl(&self, api_keys: &[ApiKey]) -> AppResult<()> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+        let mut guard = SqliteTransactionGuard::new(tx);
 
-        Ok(())
+        for api_key in api_keys {
+            insert_api_key(guard.executor()?, api_key).await?;
+        }
+
+        guard.commit().await
     }
 
     /// Get an `API` key by its prefix (for validation)
@@ -347,6 +324,30 @@ impl Database {
         Ok(())
     }
 
+    /// Get timestamps of `API` key requests made at or after `since`, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails
+    pub async fn get_api_key_usage_times_impl(
+        &self,
+        api_key_id: &str,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r"
+            SELECT timestamp FROM api_key_usage
+            WHERE api_key_id = $1 AND timestamp >= $2
+            ORDER BY timestamp ASC
+            ",
+        )
+        .bind(api_key_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get API key usage times: {e}")))
+    }
+
     /// Get current usage count for an `API` key (for rate limiting)
     ///
     /// # Errors
@@ -629,3 +630,55 @@ impl Database {
         self.get_api_key_current_usage_impl(api_key_id).await
     }
 }
+
+/// Insert a single `API` key row using the given pool or transaction connection
+async fn insert_api_key<'e, E>(executor: E, api_key: &ApiKey) -> AppResult<()>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    // Handle enterprise tier unlimited requests by storing NULL
+    let rate_limit_requests = if api_key.tier == ApiKeyTier::Enterprise {
+        None
+    } else {
+        Some(i32::try_from(api_key.rate_limit_requests).map_err(|e| {
+            AppError::internal(format!(
+                "Integer conversion failed for rate_limit_requests: {e}"
+            ))
+        })?)
+    };
+
+    sqlx::query(
+        r"
+        INSERT INTO api_keys (
+            id, user_id, name, description, key_hash, key_prefix, tier,
+            rate_limit_requests, rate_limit_window_seconds, is_active,
+            expires_at, created_at
+        ) VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12
+        )
+        ",
+    )
+    .bind(&api_key.id)
+    .bind(api_key.user_id.to_string())
+    .bind(&api_key.name)
+    .bind(&api_key.description)
+    .bind(&api_key.key_hash)
+    .bind(&api_key.key_prefix)
+    .bind(api_key.tier.as_str())
+    .bind(rate_limit_requests)
+    .bind(
+        i32::try_from(api_key.rate_limit_window_seconds).map_err(|e| {
+            AppError::internal(format!(
+                "Integer conversion failed for rate_limit_window_seconds: {e}"
+            ))
+        })?,
+    )
+    .bind(api_key.is_active)
+    .bind(api_key.expires_at)
+    .bind(api_key.created_at)
+    .execute(executor)
+    .await
+    .map_err(|e| AppError::database(format!("Failed to create API key: {e}")))?;
+
+    Ok(())
+}
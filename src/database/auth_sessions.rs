@@ -0,0 +1,181 @@
+// ABOUTME: Database operations for JWT auth sessions issued at login and refresh
+// ABOUTME: Records sessions by jti, lists a user's active sessions, and revokes one or all of them
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use pierre_core::models::AuthSession;
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+impl Database {
+    /// Record a session for a newly issued token
+    ///
+    /// Recording the same `jti` twice keeps the first row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database insert fails
+    pub async fn record_auth_session_impl(&self, session: &AuthSession) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO auth_sessions (id, user_id, client, ip_address, issued_at, expires_at, revoked_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT(id) DO NOTHING
+            ",
+        )
+        .bind(&session.id)
+        .bind(session.user_id.to_string())
+        .bind(&session.client)
+        .bind(&session.ip_address)
+        .bind(session.issued_at.to_rfc3339())
+        .bind(session.expires_at.to_rfc3339())
+        .bind(session.revoked_at.map(|at| at.to_rfc3339()))
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to record auth session: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get a session by its ID (the token's `jti`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored row is malformed
+    pub async fn get_auth_session_impl(&self, session_id: &str) -> AppResult<Option<AuthSession>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, user_id, client, ip_address, issued_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE id = ?1
+            ",
+        )
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get auth session: {e}")))?;
+
+        row.map(|row| Self::row_to_auth_session(&row)).transpose()
+    }
+
+    /// List a user's sessions that are neither revoked nor expired, newest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored row is malformed
+    pub async fn list_active_auth_sessions_impl(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<Vec<AuthSession>> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, user_id, client, ip_address, issued_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE user_id = ?1
+              AND revoked_at IS NULL
+              AND expires_at > ?2
+            ORDER BY issued_at DESC
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list auth sessions: {e}")))?;
+
+        rows.iter().map(Self::row_to_auth_session).collect()
+    }
+
+    /// Revoke one of a user's sessions
+    ///
+    /// Returns `false` if the user has no unrevoked session with that ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails
+    pub async fn revoke_auth_session_impl(
+        &self,
+        user_id: Uuid,
+        session_id: &str,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE auth_sessions
+            SET revoked_at = ?1
+            WHERE id = ?2 AND user_id = ?3 AND revoked_at IS NULL
+            ",
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(session_id)
+        .bind(user_id.to_string())
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to revoke auth session: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Revoke all of a user's sessions, optionally keeping one
+    ///
+    /// Returns the number of sessions revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails
+    pub async fn revoke_auth_sessions_impl(
+        &self,
+        user_id: Uuid,
+        except_session_id: Option<&str>,
+    ) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            UPDATE auth_sessions
+            SET revoked_at = ?1
+            WHERE user_id = ?2
+              AND revoked_at IS NULL
+              AND (?3 IS NULL OR id != ?3)
+            ",
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(user_id.to_string())
+        .bind(except_session_id)
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to revoke auth sessions: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Convert a database row to an `AuthSession`
+    fn row_to_auth_session(row: &SqliteRow) -> AppResult<AuthSession> {
+        let parse_timestamp = |column: &str, value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| AppError::database(format!("Invalid {column} timestamp: {e}")))
+        };
+
+        let user_id: String = row.try_get("user_id")?;
+        let issued_at: String = row.try_get("issued_at")?;
+        let expires_at: String = row.try_get("expires_at")?;
+        let revoked_at: Option<String> = row.try_get("revoked_at")?;
+
+        Ok(AuthSession {
+            id: row.try_get("id")?,
+            user_id: Uuid::parse_str(&user_id)
+                .map_err(|e| AppError::database(format!("Invalid user_id in auth session: {e}")))?,
+            client: row.try_get("client")?,
+            ip_address: row.try_get("ip_address")?,
+            issued_at: parse_timestamp("issued_at", &issued_at)?,
+            expires_at: parse_timestamp("expires_at", &expires_at)?,
+            revoked_at: revoked_at
+                .as_deref()
+                .map(|value| parse_timestamp("revoked_at", value))
+                .transpose()?,
+        })
+    }
+}
@@ -1031,6 +1031,132 @@ impl CoachesManager {
         row.map(|r| row_to_coach(&r)).transpose()
     }
 
+    // ============================================
+    // Coach Context Methods
+    // ============================================
+
+    /// Get the coach context that applies to a tenant and optional coach
+    ///
+    /// A context stored for the coach takes precedence over the tenant-wide one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if database operation fails
+    pub async fn get_coach_context(
+        &self,
+        tenant_id: TenantId,
+        coach_id: Option<&str>,
+    ) -> AppResult<Option<CoachContext>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, tenant_id, coach_id, system_context, updated_by, updated_at
+            FROM coach_contexts
+            WHERE tenant_id = $1 AND (coach_id = $2 OR coach_id IS NULL)
+            ORDER BY coach_id IS NULL
+            LIMIT 1
+            ",
+        )
+        .bind(tenant_id.to_string())
+        .bind(coach_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get coach context: {e}")))?;
+
+        row.map(|r| row_to_coach_context(&r)).transpose()
+    }
+
+    /// Store the coach context for a tenant, or for one coach when `coach_id` is set
+    ///
+    /// Replaces any context already stored for the same scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is empty or database operation fails
+    pub async fn set_coach_context(
+        &self,
+        tenant_id: TenantId,
+        coach_id: Option<&str>,
+        system_context: &str,
+        updated_by: Uuid,
+    ) -> AppResult<CoachContext> {
+        if system_context.trim().is_empty() {
+            return Err(AppError::invalid_input("Coach context cannot be empty"));
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        let updated = sqlx::query(
+            r"
+            UPDATE coach_contexts SET system_context = $1, updated_by = $2, updated_at = $3
+            WHERE tenant_id = $4 AND coach_id IS $5
+            ",
+        )
+        .bind(system_context)
+        .bind(updated_by.to_string())
+        .bind(&now)
+        .bind(tenant_id.to_string())
+        .bind(coach_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update coach context: {e}")))?;
+
+        if updated.rows_affected() == 0 {
+            sqlx::query(
+                r"
+                INSERT INTO coach_contexts
+                    (id, tenant_id, coach_id, system_context, updated_by, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $6)
+                ",
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(tenant_id.to_string())
+            .bind(coach_id)
+            .bind(system_context)
+            .bind(updated_by.to_string())
+            .bind(&now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to store coach context: {e}")))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to commit coach context: {e}")))?;
+
+        self.get_coach_context(tenant_id, coach_id)
+            .await?
+            .ok_or_else(|| AppError::internal("Coach context missing after store"))
+    }
+
+    /// Remove the coach context for a tenant, or for one coach when `coach_id` is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if database operation fails
+    pub async fn delete_coach_context(
+        &self,
+        tenant_id: TenantId,
+        coach_id: Option<&str>,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM coach_contexts WHERE tenant_id = $1 AND coach_id IS $2
+            ",
+        )
+        .bind(tenant_id.to_string())
+        .bind(coach_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to delete coach context: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     // ============================================
     // System Coach Methods (Admin Operations)
     // ============================================
@@ -3039,6 +3165,23 @@ fn compute_content_hash(content: &serde_json::Value) -> String {
     format!("{:016x}", hasher.finish())
 }
 
+/// Convert a database row to a `CoachContext` struct
+fn row_to_coach_context(row: &SqliteRow) -> AppResult<CoachContext> {
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map_err(|e| AppError::internal(format!("Invalid datetime: {e}")))?
+        .with_timezone(&Utc);
+
+    Ok(CoachContext {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        coach_id: row.get("coach_id"),
+        system_context: row.get("system_context"),
+        updated_by: row.get("updated_by"),
+        updated_at,
+    })
+}
+
 /// Convert a database row to a `CoachVersion` struct
 fn row_to_coach_version(row: &SqliteRow) -> AppResult<CoachVersion> {
     let id: String = row.get("id");
@@ -3087,6 +3230,23 @@ pub struct CoachAssignment {
     pub assigned_by: Option<String>,
 }
 
+/// System context injected ahead of coaching conversations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoachContext {
+    /// Unique identifier
+    pub id: String,
+    /// Tenant the context belongs to
+    pub tenant_id: String,
+    /// Coach the context applies to, or `None` for the tenant-wide context
+    pub coach_id: Option<String>,
+    /// Template with `{{variable}}` placeholders
+    pub system_context: String,
+    /// Admin who last changed the context
+    pub updated_by: Option<String>,
+    /// When the context was last changed
+    pub updated_at: DateTime<Utc>,
+}
+
 /// Store admin statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoreAdminStats {
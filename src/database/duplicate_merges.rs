@@ -0,0 +1,133 @@
+// ABOUTME: Database operations for manual merges of duplicate and split activities
+// ABOUTME: Stores, lists, and deletes merges together with the hidden activity's snapshot
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::errors::{AppError, AppResult};
+use crate::models::{ActivityMergeKind, DuplicateActivityMerge, TenantId};
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use uuid::Uuid;
+
+impl Database {
+    /// Store a merge, hiding its secondary activity from listings
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secondary activity is already merged or the insert fails
+    pub async fn create_duplicate_merge_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        merge: &DuplicateActivityMerge,
+    ) -> AppResult<()> {
+        let snapshot = serde_json::to_string(&merge.secondary)?;
+        let filled_fields = serde_json::to_string(&merge.filled_fields)?;
+
+        sqlx::query(
+            r"
+            INSERT INTO duplicate_activity_merges
+                (user_id, tenant_id, secondary_provider, secondary_id, primary_provider, primary_id,
+                 secondary_snapshot, filled_fields, merged_at, merge_kind)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .bind(&merge.secondary_provider)
+        .bind(&merge.secondary_id)
+        .bind(&merge.primary_provider)
+        .bind(&merge.primary_id)
+        .bind(snapshot)
+        .bind(filled_fields)
+        .bind(merge.merged_at.to_rfc3339())
+        .bind(merge.kind.as_str())
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store duplicate merge: {e}")))?;
+
+        Ok(())
+    }
+
+    /// List a user's duplicate merges, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored merge cannot be decoded
+    pub async fn list_duplicate_merges_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<DuplicateActivityMerge>> {
+        let rows = sqlx::query(
+            r"
+            SELECT secondary_provider, secondary_id, primary_provider, primary_id,
+                   secondary_snapshot, filled_fields, merged_at, merge_kind
+            FROM duplicate_activity_merges
+            WHERE user_id = ?1 AND tenant_id = ?2
+            ORDER BY merged_at ASC
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list duplicate merges: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let snapshot: String = row.try_get("secondary_snapshot")?;
+                let filled_fields: String = row.try_get("filled_fields")?;
+                let merged_at: String = row.try_get("merged_at")?;
+                let kind: String = row.try_get("merge_kind")?;
+                Ok(DuplicateActivityMerge {
+                    kind: kind.parse::<ActivityMergeKind>()?,
+                    primary_provider: row.try_get("primary_provider")?,
+                    primary_id: row.try_get("primary_id")?,
+                    secondary_provider: row.try_get("secondary_provider")?,
+                    secondary_id: row.try_get("secondary_id")?,
+                    secondary: serde_json::from_str(&snapshot)?,
+                    filled_fields: serde_json::from_str(&filled_fields)?,
+                    merged_at: DateTime::parse_from_rfc3339(&merged_at)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .map_err(|e| {
+                            AppError::database(format!("Invalid duplicate merge timestamp: {e}"))
+                        })?,
+                })
+            })
+            .collect()
+    }
+
+    /// Delete the merge hiding a secondary activity
+    ///
+    /// Returns whether a merge was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails
+    pub async fn delete_duplicate_merge_impl(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        secondary_provider: &str,
+        secondary_id: &str,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM duplicate_activity_merges
+            WHERE user_id = ?1 AND tenant_id = ?2 AND secondary_provider = ?3 AND secondary_id = ?4
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.to_string())
+        .bind(secondary_provider)
+        .bind(secondary_id)
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to delete duplicate merge: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
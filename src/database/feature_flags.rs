@@ -0,0 +1,219 @@
+// ABOUTME: Database operations for admin-configurable feature flags
+// ABOUTME: Handles CRUD for the feature_flags and tenant_feature_flags tables
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::mappers::parse_rfc3339_timestamp;
+use crate::errors::{AppError, AppResult};
+use crate::models::{FeatureFlag, FeatureFlagType, FeatureFlagValue, TenantFeatureFlag, TenantId};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+/// Encode a flag value for storage
+pub(crate) fn encode_flag_value(value: &FeatureFlagValue) -> AppResult<String> {
+    serde_json::to_string(value)
+        .map_err(|e| AppError::internal(format!("Failed to encode feature flag value: {e}")))
+}
+
+/// Decode a stored flag value
+pub(crate) fn decode_flag_value(value: &str) -> AppResult<FeatureFlagValue> {
+    serde_json::from_str(value)
+        .map_err(|e| AppError::database(format!("Invalid stored feature flag value: {e}")))
+}
+
+/// Parse a stored flag type
+pub(crate) fn decode_flag_type(value: &str) -> AppResult<FeatureFlagType> {
+    FeatureFlagType::parse_str(value)
+        .ok_or_else(|| AppError::database(format!("Invalid feature flag type: {value}")))
+}
+
+fn map_feature_flag_row(row: &SqliteRow) -> AppResult<FeatureFlag> {
+    let value_type: String = row.try_get("value_type")?;
+    let default_value: String = row.try_get("default_value")?;
+    let global_value: Option<String> = row.try_get("global_value")?;
+    let updated_at: String = row.try_get("updated_at")?;
+    Ok(FeatureFlag {
+        key: row.try_get("key")?,
+        description: row.try_get("description")?,
+        value_type: decode_flag_type(&value_type)?,
+        default_value: decode_flag_value(&default_value)?,
+        global_value: global_value.as_deref().map(decode_flag_value).transpose()?,
+        updated_at: parse_rfc3339_timestamp(&updated_at, "updated_at")?,
+    })
+}
+
+fn map_tenant_feature_flag_row(row: &SqliteRow) -> AppResult<TenantFeatureFlag> {
+    let tenant_id: String = row.try_get("tenant_id")?;
+    let value: String = row.try_get("value")?;
+    let updated_at: String = row.try_get("updated_at")?;
+    Ok(TenantFeatureFlag {
+        key: row.try_get("key")?,
+        tenant_id: tenant_id
+            .parse()
+            .map_err(|e| AppError::database(format!("Invalid feature flag tenant ID: {e}")))?,
+        value: decode_flag_value(&value)?,
+        updated_at: parse_rfc3339_timestamp(&updated_at, "updated_at")?,
+    })
+}
+
+impl Database {
+    /// List every feature flag definition
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored value is invalid
+    pub async fn list_feature_flags_impl(&self) -> AppResult<Vec<FeatureFlag>> {
+        let rows = sqlx::query(
+            r"
+            SELECT key, description, value_type, default_value, global_value, updated_at
+            FROM feature_flags
+            ORDER BY key
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list feature flags: {e}")))?;
+
+        rows.iter().map(map_feature_flag_row).collect()
+    }
+
+    /// Create or replace a feature flag definition
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert fails
+    pub async fn upsert_feature_flag_impl(&self, flag: &FeatureFlag) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO feature_flags
+                (key, description, value_type, default_value, global_value, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT(key) DO UPDATE SET
+                description = excluded.description,
+                value_type = excluded.value_type,
+                default_value = excluded.default_value,
+                global_value = excluded.global_value,
+                updated_at = excluded.updated_at
+            ",
+        )
+        .bind(&flag.key)
+        .bind(&flag.description)
+        .bind(flag.value_type.as_str())
+        .bind(encode_flag_value(&flag.default_value)?)
+        .bind(
+            flag.global_value
+                .as_ref()
+                .map(encode_flag_value)
+                .transpose()?,
+        )
+        .bind(flag.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store feature flag: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Delete a feature flag and every tenant value set for it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails
+    pub async fn delete_feature_flag_impl(&self, key: &str) -> AppResult<bool> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        sqlx::query("DELETE FROM tenant_feature_flags WHERE key = ?1")
+            .bind(key)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to delete tenant feature flags: {e}"))
+            })?;
+        let result = sqlx::query("DELETE FROM feature_flags WHERE key = ?1")
+            .bind(key)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to delete feature flag: {e}")))?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::database(format!("Failed to commit feature flag delete: {e}"))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List every per-tenant feature flag value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored value is invalid
+    pub async fn list_tenant_feature_flags_impl(&self) -> AppResult<Vec<TenantFeatureFlag>> {
+        let rows = sqlx::query(
+            r"
+            SELECT key, tenant_id, value, updated_at
+            FROM tenant_feature_flags
+            ORDER BY key, tenant_id
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant feature flags: {e}")))?;
+
+        rows.iter().map(map_tenant_feature_flag_row).collect()
+    }
+
+    /// Create or replace a feature flag value for one tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert fails
+    pub async fn upsert_tenant_feature_flag_impl(&self, flag: &TenantFeatureFlag) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO tenant_feature_flags (key, tenant_id, value, updated_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(key, tenant_id) DO UPDATE SET
+                value = excluded.value,
+                updated_at = excluded.updated_at
+            ",
+        )
+        .bind(&flag.key)
+        .bind(flag.tenant_id.to_string())
+        .bind(encode_flag_value(&flag.value)?)
+        .bind(flag.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store tenant feature flag: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Delete a feature flag value for one tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails
+    pub async fn delete_tenant_feature_flag_impl(
+        &self,
+        key: &str,
+        tenant_id: TenantId,
+    ) -> AppResult<bool> {
+        let result =
+            sqlx::query("DELETE FROM tenant_feature_flags WHERE key = ?1 AND tenant_id = ?2")
+                .bind(key)
+                .bind(tenant_id.to_string())
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to delete tenant feature flag: {e}"))
+                })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
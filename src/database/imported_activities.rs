@@ -0,0 +1,81 @@
+// ABOUTME: Database operations for activities imported from uploaded GPX and TCX files
+// ABOUTME: Stores normalized activities as JSON per user and lists them for the file provider
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::errors::{AppError, AppResult};
+use crate::models::Activity;
+use chrono::Utc;
+use sqlx::Row;
+use uuid::Uuid;
+
+impl Database {
+    /// Store an imported activity, replacing an earlier import with the same ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the activity cannot be serialized or the insert fails
+    pub async fn store_imported_activity_impl(
+        &self,
+        user_id: Uuid,
+        source: &str,
+        file_name: &str,
+        activity: &Activity,
+    ) -> AppResult<()> {
+        let activity_data = serde_json::to_string(activity)?;
+        sqlx::query(
+            r"
+            INSERT INTO imported_activities (id, user_id, source, file_name, start_date, activity_data, imported_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT(user_id, id) DO UPDATE SET
+                source = excluded.source,
+                file_name = excluded.file_name,
+                start_date = excluded.start_date,
+                activity_data = excluded.activity_data,
+                imported_at = excluded.imported_at
+            ",
+        )
+        .bind(activity.id())
+        .bind(user_id.to_string())
+        .bind(source)
+        .bind(file_name)
+        .bind(activity.start_date().to_rfc3339())
+        .bind(activity_data)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store imported activity: {e}")))?;
+
+        Ok(())
+    }
+
+    /// List a user's imported activities, most recent first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored activity cannot be deserialized
+    pub async fn get_imported_activities_impl(&self, user_id: Uuid) -> AppResult<Vec<Activity>> {
+        let rows = sqlx::query(
+            r"
+            SELECT activity_data
+            FROM imported_activities
+            WHERE user_id = ?1
+            ORDER BY start_date DESC
+            ",
+        )
+        .bind(user_id.to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list imported activities: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let data: String = row.try_get("activity_data")?;
+                serde_json::from_str(&data)
+                    .map_err(|e| AppError::database(format!("Invalid imported activity data: {e}")))
+            })
+            .collect()
+    }
+}
@@ -6,24 +6,36 @@
 
 /// Agent-to-Agent (A2A) authentication and usage tracking
 pub mod a2a;
+/// Per-tenant activity backfill windows and resume markers
+pub mod activity_backfill;
+/// Per-activity content hashes and deletion markers for delta syncs
+pub mod activity_sync;
 /// Admin token management and authorization
 pub mod admin;
 /// Analytics and usage statistics database operations
 pub mod analytics;
 /// API key management and validation
 pub mod api_keys;
+/// JWT auth sessions issued at login and refresh, with revocation
+pub mod auth_sessions;
 /// Chat conversation and message storage
 pub mod chat;
 /// Coach authors (creator profiles for Store)
 pub mod coach_authors;
 /// Coaches (custom AI personas) storage and management
 pub mod coaches;
+/// Manual merges of duplicate activities and the hidden activity snapshots
+pub mod duplicate_merges;
 /// Database error types
 pub mod errors;
+/// Admin-configurable feature flags and per-tenant values
+pub mod feature_flags;
 /// User fitness configuration storage and retrieval
 pub mod fitness_configurations;
 /// Impersonation session management for super admin user impersonation
 pub mod impersonation;
+/// Activities imported from uploaded GPX and TCX files
+pub mod imported_activities;
 /// Mobility features (stretching exercises and yoga poses)
 pub mod mobility;
 /// OAuth callback notification handling
@@ -32,16 +44,22 @@ pub mod oauth_notifications;
 pub mod password_reset_tokens;
 /// Provider connections: unified connection tracking for all provider types
 pub mod provider_connections;
+/// Checkpoints for the batched insight re-analysis job
+pub mod reanalysis;
 /// Recipe storage and management for nutrition planning
 pub mod recipes;
 /// System coaches seeding for server startup
 pub mod seed_coaches;
 /// Social features (friend connections, shared insights)
 pub mod social;
+/// Strava push subscriptions and athlete links for webhook-driven syncs
+pub mod strava_webhooks;
 /// Synthetic provider activities storage
 pub mod synthetic_activities;
 /// System settings for admin-configurable options
 pub mod system_settings;
+/// Re-encryption of tenant secrets under per-tenant data keys
+pub mod tenant_keys;
 /// Tool selection and per-tenant MCP tool configuration
 pub mod tool_selection;
 /// User MCP token management for AI client authentication
@@ -50,11 +68,14 @@ pub mod user_mcp_tokens;
 pub mod user_oauth_tokens;
 /// User account management and authentication
 pub mod users;
+/// Tenant webhook registrations and delivery log
+pub mod webhooks;
 
 /// Test utilities for database operations
 pub mod test_utils;
 
 pub use a2a::{A2AUsage, A2AUsageStats};
+pub use analytics::{Granularity, RollupBucket};
 pub use chat::{ChatManager, ConversationRecord, ConversationSummary, MessageRecord};
 pub use coach_authors::{
     CoachAuthor, CoachAuthorsManager, CreateAuthorRequest, UpdateAuthorRequest,
@@ -77,43 +98,67 @@ pub use user_oauth_tokens::OAuthTokenData;
 use crate::a2a::auth::A2AClient;
 use crate::a2a::client::A2ASession;
 use crate::a2a::protocol::{A2ATask, TaskStatus};
-use crate::admin::jwks::JwksManager;
+use crate::admin::jwks::{JwksManager, RsaKeypairRecord};
 use crate::admin::jwt::AdminJwtManager;
 use crate::admin::models::{
     AdminToken, AdminTokenUsage, CreateAdminTokenRequest, GeneratedAdminToken,
 };
 use crate::api_keys::{ApiKey, ApiKeyUsage, ApiKeyUsageStats};
+use crate::config::data_retention::RetentionTable;
+use crate::config::environment::SqlitePoolConfig;
 use crate::config::fitness::FitnessConfig;
 use crate::dashboard_routes::{RequestLog, ToolUsage};
-use crate::database_plugins::{shared, DatabaseProvider};
+use crate::database_plugins::shared::encryption::HasEncryption;
+use crate::database_plugins::{shared, DatabaseProvider, PoolStats, SchemaVersion};
 use crate::errors::{AppError, AppResult};
 use crate::models::{
-    AuthorizationCode, ConnectionType, OAuthApp, ProviderConnection, Tenant, TenantPlan,
-    TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp, UserOAuthToken,
-    UserStatus,
+    Activity, ActivitySyncRecord, AuthSession, AuthorizationCode, ConnectionType,
+    DuplicateActivityMerge, FeatureFlag, OAuthApp, ProviderConnection, ReanalysisCheckpoint,
+    StravaAthleteLink, StravaWebhookSubscription, Tenant, TenantDailyToolUsage, TenantFeatureFlag,
+    TenantPlan, TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp,
+    UserOAuthToken, UserStatus,
 };
 use crate::oauth2_client::OAuthClientState;
-use crate::oauth2_server::models::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
-use crate::pagination::{CursorPage, PaginationParams};
+use crate::oauth2_server::models::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
+use crate::pagination::{Cursor, CursorPage, PaginationParams};
 use crate::permissions::impersonation::ImpersonationSession;
-use crate::rate_limiting::JwtUsage;
-use crate::security::audit::AuditEvent;
+use crate::providers::backfill::BackfillMarker;
+use crate::rate_limiting::{JwtUsage, RateLimitMode};
+use crate::security::audit::{AuditEvent, AuditEventFilter};
 use crate::security::key_rotation::KeyVersion;
 use crate::tenant::llm_manager::{LlmCredentialRecord, LlmCredentialSummary};
 use crate::tenant::oauth_manager::TenantOAuthCredentials;
+use crate::tenant::webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+};
+use crate::utils::uuid::parse_uuid;
 use base64::engine::general_purpose::{self, STANDARD};
 use base64::Engine;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SubsecRound, Utc};
 use pierre_core::models::TenantId;
 use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
 use ring::digest::{digest, SHA256};
 use ring::hmac;
 use ring::rand::{SecureRandom, SystemRandom};
 use serde_json::Value;
-use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteRow};
+use sqlx::{Pool, Row, Sqlite};
+use std::str::FromStr;
+use std::time::Duration;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+/// Migrations embedded at compile time from `./migrations`
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Whether a `SQLite` URL points at an in-memory database
+fn is_in_memory_url(database_url: &str) -> bool {
+    database_url.contains(":memory:") || database_url.contains("mode=memory")
+}
+
 /// Database connection pool with encryption support
 #[derive(Clone)]
 pub struct Database {
@@ -132,18 +177,41 @@ impl Database {
     /// - `SQLite` file creation fails
     /// - Migration process fails
     /// - Encryption key is invalid
-    async fn new_impl(database_url: &str, encryption_key: Vec<u8>) -> AppResult<Self> {
+    async fn new_impl(
+        database_url: &str,
+        encryption_key: Vec<u8>,
+        pool_config: &SqlitePoolConfig,
+    ) -> AppResult<Self> {
         // Ensure SQLite creates the database file if it doesn't exist
-        let connection_options = if database_url.starts_with("sqlite:") {
-            format!("{database_url}?mode=rwc")
-        } else {
-            database_url.to_owned()
-        };
+        let mut connect_options = SqliteConnectOptions::from_str(database_url)
+            .map_err(|e| AppError::database(format!("Invalid database URL: {e}")))?
+            .create_if_missing(true)
+            .busy_timeout(Duration::from_millis(pool_config.busy_timeout_ms))
+            .foreign_keys(pool_config.foreign_keys);
+
+        // In-memory databases have no journal file, so only set the mode on disk
+        if !is_in_memory_url(database_url) {
+            connect_options = connect_options.journal_mode(if pool_config.wal_mode {
+                SqliteJournalMode::Wal
+            } else {
+                SqliteJournalMode::Delete
+            });
+        }
 
-        let pool = SqlitePool::connect(&connection_options)
+        let pool = SqlitePoolOptions::new()
+            .max_connections(pool_config.max_connections)
+            .connect_with(connect_options)
             .await
             .map_err(|e| AppError::database(format!("Failed to connect to database: {e}")))?;
 
+        info!(
+            max_connections = pool_config.max_connections,
+            busy_timeout_ms = pool_config.busy_timeout_ms,
+            wal_mode = pool_config.wal_mode,
+            foreign_keys = pool_config.foreign_keys,
+            "SQLite connection pool configured"
+        );
+
         let db = Self {
             pool,
             encryption_key,
@@ -168,7 +236,24 @@ impl Database {
     /// - Migration process fails
     /// - Encryption key is invalid
     pub async fn new(database_url: &str, encryption_key: Vec<u8>) -> AppResult<Self> {
-        Self::new_impl(database_url, encryption_key).await
+        Self::new_impl(database_url, encryption_key, &SqlitePoolConfig::from_env()).await
+    }
+
+    /// Create a new database connection with explicit pool settings
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Database URL is invalid or malformed
+    /// - Database connection fails
+    /// - `SQLite` file creation fails
+    /// - Migration process fails
+    pub async fn new_with_pool_config(
+        database_url: &str,
+        encryption_key: Vec<u8>,
+        pool_config: &SqlitePoolConfig,
+    ) -> AppResult<Self> {
+        Self::new_impl(database_url, encryption_key, pool_config).await
     }
 
     /// Get a reference to the database pool for advanced operations
@@ -237,18 +322,57 @@ impl Database {
     async fn migrate_impl(&self) -> AppResult<()> {
         info!("Running database migrations...");
 
+        // Check before running: sqlx would otherwise fail with a bare "previously applied
+        // but missing" error, or worse, an older binary would run against unknown columns
+        self.schema_version_impl().await?.ensure_supported()?;
+
         // Run all pending migrations embedded at compile-time from ./migrations directory
         // Using compile-time macro which embeds migrations into the binary
         // This ensures migrations are available regardless of working directory
-        sqlx::migrate!("./migrations")
+        MIGRATOR
             .run(&self.pool)
             .await
             .map_err(|e| AppError::database(format!("Migration failed: {e}")))?;
 
-        info!("Database migrations completed successfully");
+        let version = self.schema_version_impl().await?;
+        info!(
+            applied = version.applied,
+            supported = version.supported,
+            "Database migrations completed successfully"
+        );
         Ok(())
     }
 
+    /// Highest applied migration recorded by sqlx alongside the highest one embedded in the binary
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the migrations table cannot be queried
+    async fn schema_version_impl(&self) -> AppResult<SchemaVersion> {
+        let has_migrations_table: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to inspect migrations table: {e}")))?;
+
+        let applied = if has_migrations_table {
+            sqlx::query_scalar("SELECT MAX(version) FROM _sqlx_migrations WHERE success = 1")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to read applied schema version: {e}"))
+                })?
+        } else {
+            None
+        };
+
+        Ok(SchemaVersion {
+            applied,
+            supported: MIGRATOR.iter().map(|migration| migration.version).max(),
+        })
+    }
+
     /// Encrypt sensitive data using AES-256-GCM
     ///
     /// # Errors
@@ -501,13 +625,11 @@ impl Database {
     /// # Errors
     ///
     /// Returns an error if database query fails
-    pub async fn load_rsa_keypairs(
-        &self,
-    ) -> AppResult<Vec<(String, String, String, DateTime<Utc>, bool)>> {
+    pub async fn load_rsa_keypairs(&self) -> AppResult<Vec<RsaKeypairRecord>> {
         use sqlx::Row;
 
         let rows = sqlx::query(
-            "SELECT kid, private_key_pem, public_key_pem, created_at, is_active FROM rsa_keypairs ORDER BY created_at DESC",
+            "SELECT kid, private_key_pem, public_key_pem, created_at, is_active, retires_at FROM rsa_keypairs ORDER BY created_at DESC",
         )
         .fetch_all(&self.pool)
 
@@ -532,8 +654,18 @@ impl Database {
             let is_active: bool = row
                 .try_get("is_active")
                 .map_err(|e| AppError::database(format!("Failed to get is_active: {e}")))?;
-
-            keypairs.push((kid, private_key_pem, public_key_pem, created_at, is_active));
+            let retires_at: Option<DateTime<Utc>> = row
+                .try_get("retires_at")
+                .map_err(|e| AppError::database(format!("Failed to get retires_at: {e}")))?;
+
+            keypairs.push((
+                kid,
+                private_key_pem,
+                public_key_pem,
+                created_at,
+                is_active,
+                retires_at,
+            ));
         }
 
         Ok(keypairs)
@@ -624,7 +756,7 @@ impl Database {
 
         // Generate new secret
         let secret_value = match secret_type {
-            "admin_jwt_secret" => AdminJwtManager::generate_jwt_secret(),
+            "admin_jwt_secret" | "cursor_signing_secret" => AdminJwtManager::generate_jwt_secret(),
             "database_encryption_key" => {
                 // Return existing key (already loaded during initialization)
                 return Ok(STANDARD.encode(&self.encryption_key));
@@ -923,6 +1055,51 @@ impl Database {
         Ok(tenants)
     }
 
+    /// Get the rate limit mode configured for a tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tenant does not exist or the stored mode is invalid
+    pub async fn get_tenant_rate_limit_mode_impl(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<RateLimitMode> {
+        let mode: Option<String> =
+            sqlx::query_scalar("SELECT rate_limit_mode FROM tenants WHERE id = ?")
+                .bind(tenant_id.to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Database query failed: {e}")))?;
+
+        mode.ok_or_else(|| AppError::not_found(format!("Tenant {tenant_id}")))?
+            .parse()
+    }
+
+    /// Set the rate limit mode for a tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tenant does not exist or the database update fails
+    pub async fn set_tenant_rate_limit_mode_impl(
+        &self,
+        tenant_id: TenantId,
+        mode: RateLimitMode,
+    ) -> AppResult<()> {
+        let result =
+            sqlx::query("UPDATE tenants SET rate_limit_mode = ?, updated_at = ? WHERE id = ?")
+                .bind(mode.as_str())
+                .bind(Utc::now().to_rfc3339())
+                .bind(tenant_id.to_string())
+                .execute(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Failed to update tenant: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Tenant {tenant_id}")));
+        }
+        Ok(())
+    }
+
     /// Store tenant OAuth credentials
     ///
     /// # Errors
@@ -938,8 +1115,11 @@ impl Database {
             "{}|{}|tenant_oauth_credentials",
             credentials.tenant_id, credentials.provider
         );
-        let encrypted_secret =
-            self.encrypt_data_with_aad(&credentials.client_secret, &aad_context)?;
+        let encrypted_secret = self.encrypt_data_for_tenant(
+            credentials.tenant_id,
+            &credentials.client_secret,
+            &aad_context,
+        )?;
 
         // Convert scopes to JSON array for SQLite
         let scopes_json = serde_json::to_string(&credentials.scopes)?;
@@ -958,6 +1138,8 @@ impl Database {
                 redirect_uri = excluded.redirect_uri,
                 scopes = excluded.scopes,
                 rate_limit_per_day = excluded.rate_limit_per_day,
+                pending_client_secret_encrypted = NULL,
+                previous_secret_expires_at = NULL,
                 updated_at = excluded.updated_at
             ",
         )
@@ -989,6 +1171,7 @@ impl Database {
         let rows = sqlx::query(
             r"
             SELECT provider, client_id, client_secret_encrypted,
+                   pending_client_secret_encrypted, previous_secret_expires_at,
                    redirect_uri, scopes, rate_limit_per_day
             FROM tenant_oauth_credentials
             WHERE tenant_id = ? AND is_active = 1
@@ -1013,6 +1196,13 @@ impl Database {
                     row.try_get("client_secret_encrypted").map_err(|e| {
                         AppError::database(format!("Failed to get client_secret_encrypted: {e}"))
                     })?;
+                let (client_secret, previous_client_secret) = self
+                    .decrypt_tenant_oauth_secrets_from_row(
+                        &row,
+                        tenant_id,
+                        &provider,
+                        &encrypted_secret,
+                    )?;
                 let redirect_uri: String = row
                     .try_get("redirect_uri")
                     .map_err(|e| AppError::database(format!("Failed to get redirect_uri: {e}")))?;
@@ -1023,11 +1213,6 @@ impl Database {
                     AppError::database(format!("Failed to get rate_limit_per_day: {e}"))
                 })?;
 
-                // Decrypt the client secret using AAD binding
-                // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
-                let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
-                let client_secret = self.decrypt_data_with_aad(&encrypted_secret, &aad_context)?;
-
                 let scopes: Vec<String> = serde_json::from_str(&scopes_json)?;
 
                 Ok(TenantOAuthCredentials {
@@ -1035,6 +1220,7 @@ impl Database {
                     provider,
                     client_id,
                     client_secret,
+                    previous_client_secret,
                     redirect_uri,
                     scopes,
                     rate_limit_per_day: u32::try_from(rate_limit).unwrap_or(0),
@@ -1058,6 +1244,7 @@ impl Database {
         let row = sqlx::query(
             r"
             SELECT client_id, client_secret_encrypted,
+                   pending_client_secret_encrypted, previous_secret_expires_at,
                    redirect_uri, scopes, rate_limit_per_day
             FROM tenant_oauth_credentials
             WHERE tenant_id = ? AND provider = ? AND is_active = 1
@@ -1078,6 +1265,13 @@ impl Database {
                     row.try_get("client_secret_encrypted").map_err(|e| {
                         AppError::database(format!("Failed to get client_secret_encrypted: {e}"))
                     })?;
+                let (client_secret, previous_client_secret) = self
+                    .decrypt_tenant_oauth_secrets_from_row(
+                        &row,
+                        tenant_id,
+                        provider,
+                        &encrypted_secret,
+                    )?;
                 let redirect_uri: String = row
                     .try_get("redirect_uri")
                     .map_err(|e| AppError::database(format!("Failed to get redirect_uri: {e}")))?;
@@ -1088,11 +1282,6 @@ impl Database {
                     AppError::database(format!("Failed to get rate_limit_per_day: {e}"))
                 })?;
 
-                // Decrypt the client secret using AAD binding
-                // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
-                let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
-                let client_secret = self.decrypt_data_with_aad(&encrypted_secret, &aad_context)?;
-
                 let scopes: Vec<String> = serde_json::from_str(&scopes_json)?;
 
                 Ok(Some(TenantOAuthCredentials {
@@ -1100,6 +1289,7 @@ impl Database {
                     provider: provider.to_owned(),
                     client_id,
                     client_secret,
+                    previous_client_secret,
                     redirect_uri,
                     scopes,
                     rate_limit_per_day: u32::try_from(rate_limit).unwrap_or(0),
@@ -1109,6 +1299,122 @@ impl Database {
         }
     }
 
+    /// Decrypt the active and previous client secrets of a tenant OAuth credentials row
+    fn decrypt_tenant_oauth_secrets_from_row(
+        &self,
+        row: &SqliteRow,
+        tenant_id: TenantId,
+        provider: &str,
+        encrypted_secret: &str,
+    ) -> AppResult<(String, Option<String>)> {
+        let pending_secret: Option<String> = row
+            .try_get("pending_client_secret_encrypted")
+            .map_err(|e| {
+                AppError::database(format!(
+                    "Failed to get pending_client_secret_encrypted: {e}"
+                ))
+            })?;
+        let previous_expires_at: Option<String> =
+            row.try_get("previous_secret_expires_at").map_err(|e| {
+                AppError::database(format!("Failed to get previous_secret_expires_at: {e}"))
+            })?;
+        let previous_expires_at = previous_expires_at
+            .map(|value| {
+                DateTime::parse_from_rfc3339(&value)
+                    .map(|expires_at| expires_at.with_timezone(&Utc))
+                    .map_err(|e| {
+                        AppError::database(format!("Invalid previous_secret_expires_at: {e}"))
+                    })
+            })
+            .transpose()?;
+
+        // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
+        let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+        shared::encryption::decrypt_tenant_oauth_secrets(
+            self,
+            tenant_id,
+            &aad_context,
+            encrypted_secret,
+            pending_secret.as_deref(),
+            previous_expires_at,
+        )
+    }
+
+    /// Stage a new tenant OAuth client secret, keeping the current one valid until `previous_expires_at`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no credentials exist for the tenant and provider,
+    /// or if encryption or the database operation fails
+    pub async fn rotate_tenant_oauth_secret_impl(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
+        let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+        let encrypted_secret = self.encrypt_data_for_tenant(tenant_id, new_secret, &aad_context)?;
+
+        let result = sqlx::query(
+            r"
+            UPDATE tenant_oauth_credentials
+            SET pending_client_secret_encrypted = ?,
+                previous_secret_expires_at = ?,
+                updated_at = ?
+            WHERE tenant_id = ? AND provider = ? AND is_active = 1
+            ",
+        )
+        .bind(&encrypted_secret)
+        .bind(previous_expires_at.to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .bind(tenant_id.to_string())
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to rotate OAuth client secret: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!(
+                "OAuth credentials for tenant {tenant_id} and provider {provider}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Promote a pending tenant OAuth client secret and purge the previous one
+    ///
+    /// Returns `false` when no rotation was pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails
+    pub async fn promote_tenant_oauth_secret_impl(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE tenant_oauth_credentials
+            SET client_secret_encrypted = pending_client_secret_encrypted,
+                pending_client_secret_encrypted = NULL,
+                previous_secret_expires_at = NULL,
+                updated_at = ?
+            WHERE tenant_id = ? AND provider = ? AND pending_client_secret_encrypted IS NOT NULL
+            ",
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(tenant_id.to_string())
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to promote OAuth client secret: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     // ================================
     // User Configuration (SQLite implementations)
     // ================================
@@ -1221,6 +1527,37 @@ impl Database {
         Ok(())
     }
 
+    /// Mark RSA keypair as retiring at the given time (internal implementation)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    async fn retire_rsa_keypair_impl(&self, kid: &str, retires_at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query("UPDATE rsa_keypairs SET is_active = 0, retires_at = ?1 WHERE kid = ?2")
+            .bind(retires_at)
+            .bind(kid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Delete RSA keypair (internal implementation)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    async fn delete_rsa_keypair_impl(&self, kid: &str) -> AppResult<()> {
+        sqlx::query("DELETE FROM rsa_keypairs WHERE kid = ?1")
+            .bind(kid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
     // ================================
     // OAuth App Management (SQLite implementations)
     // ================================
@@ -1333,8 +1670,8 @@ impl Database {
     pub async fn store_oauth2_client_impl(&self, client: &OAuth2Client) -> AppResult<()> {
         sqlx::query(
             r"
-            INSERT INTO oauth2_clients (id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            INSERT INTO oauth2_clients (id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at, require_pkce)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
             "
         )
         .bind(&client.id)
@@ -1348,6 +1685,7 @@ impl Database {
         .bind(&client.scope)
         .bind(client.created_at)
         .bind(client.expires_at)
+        .bind(client.require_pkce)
         .execute(&self.pool)
 
             .await
@@ -1365,7 +1703,7 @@ impl Database {
     pub async fn get_oauth2_client_impl(&self, client_id: &str) -> AppResult<Option<OAuth2Client>> {
         let row = sqlx::query(
             r"
-            SELECT id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at
+            SELECT id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at, require_pkce
             FROM oauth2_clients
             WHERE client_id = ?1
             "
@@ -1416,6 +1754,9 @@ impl Database {
                 expires_at: row
                     .try_get("expires_at")
                     .map_err(|e| AppError::database(format!("Failed to get expires_at: {e}")))?,
+                require_pkce: row
+                    .try_get("require_pkce")
+                    .map_err(|e| AppError::database(format!("Failed to get require_pkce: {e}")))?,
             }))
         } else {
             Ok(None)
@@ -1634,6 +1975,128 @@ impl Database {
         }
     }
 
+    /// Record `OAuth2` access token issued alongside a refresh token (internal implementation)
+    ///
+    /// The linked refresh token is HMAC-SHA256 hashed, matching `oauth2_refresh_tokens`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    pub async fn store_oauth2_access_token_impl(
+        &self,
+        access_token: &OAuth2IssuedAccessToken,
+    ) -> AppResult<()> {
+        let refresh_token_hash = self.hash_token_for_storage_impl(&access_token.refresh_token);
+
+        sqlx::query(
+            r"
+            INSERT INTO oauth2_access_tokens (jti, refresh_token, client_id, user_id, expires_at, revoked)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ",
+        )
+        .bind(&access_token.jti)
+        .bind(&refresh_token_hash)
+        .bind(&access_token.client_id)
+        .bind(access_token.user_id.to_string())
+        .bind(access_token.expires_at)
+        .bind(access_token.revoked)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get recorded `OAuth2` access token by `jti` (internal implementation)
+    ///
+    /// The returned `refresh_token` is the stored hash, not the plaintext token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    pub async fn get_oauth2_access_token_impl(
+        &self,
+        jti: &str,
+    ) -> AppResult<Option<OAuth2IssuedAccessToken>> {
+        let row = sqlx::query(
+            r"
+            SELECT jti, refresh_token, client_id, user_id, expires_at, revoked
+            FROM oauth2_access_tokens
+            WHERE jti = ?1
+            ",
+        )
+        .bind(jti)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Database query failed: {e}")))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(OAuth2IssuedAccessToken {
+            jti: row
+                .try_get("jti")
+                .map_err(|e| AppError::database(format!("Failed to get jti: {e}")))?,
+            refresh_token: row
+                .try_get("refresh_token")
+                .map_err(|e| AppError::database(format!("Failed to get refresh_token: {e}")))?,
+            client_id: row
+                .try_get("client_id")
+                .map_err(|e| AppError::database(format!("Failed to get client_id: {e}")))?,
+            user_id: Uuid::parse_str(
+                &row.try_get::<String, _>("user_id")
+                    .map_err(|e| AppError::database(format!("Failed to get user_id: {e}")))?,
+            )
+            .map_err(|e| AppError::database(format!("Failed to parse user_id: {e}")))?,
+            expires_at: row
+                .try_get("expires_at")
+                .map_err(|e| AppError::database(format!("Failed to get expires_at: {e}")))?,
+            revoked: row
+                .try_get("revoked")
+                .map_err(|e| AppError::database(format!("Failed to get revoked: {e}")))?,
+        }))
+    }
+
+    /// Revoke recorded `OAuth2` access token by `jti` (internal implementation)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    async fn revoke_oauth2_access_token_impl(&self, jti: &str) -> AppResult<()> {
+        sqlx::query("UPDATE oauth2_access_tokens SET revoked = 1 WHERE jti = ?1")
+            .bind(jti)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Revoke `OAuth2` access tokens issued with a refresh token (internal implementation)
+    ///
+    /// The input token is HMAC-SHA256 hashed before querying.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    async fn revoke_oauth2_access_tokens_for_refresh_token_impl(
+        &self,
+        refresh_token: &str,
+    ) -> AppResult<u64> {
+        let refresh_token_hash = self.hash_token_for_storage_impl(refresh_token);
+
+        let result = sqlx::query(
+            "UPDATE oauth2_access_tokens SET revoked = 1 WHERE refresh_token = ?1 AND revoked = 0",
+        )
+        .bind(&refresh_token_hash)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Atomically consume `OAuth2` auth code (internal implementation)
     ///
     /// # Errors
@@ -2056,24 +2519,246 @@ impl Database {
         let severity_str = format!("{:?}", event.severity);
         let metadata_json = serde_json::to_string(&event.metadata)?;
 
-        sqlx::query(query)
-            .bind(event.event_id.to_string())
-            .bind(&event_type_str)
-            .bind(&severity_str)
-            .bind(&event.description)
-            .bind("security") // source - using generic security source
-            .bind(&event.result)
-            .bind(event.tenant_id.map(|id| id.to_string()))
-            .bind(event.user_id.map(|id| id.to_string()))
-            .bind(&event.source_ip)
-            .bind(&event.user_agent)
-            .bind(&metadata_json)
-            .bind(event.timestamp)
+        sqlx::query(query)
+            .bind(event.event_id.to_string())
+            .bind(&event_type_str)
+            .bind(&severity_str)
+            .bind(&event.description)
+            .bind("security") // source - using generic security source
+            .bind(&event.result)
+            .bind(event.tenant_id.map(|id| id.to_string()))
+            .bind(event.user_id.map(|id| id.to_string()))
+            .bind(&event.source_ip)
+            .bind(&event.user_agent)
+            .bind(&metadata_json)
+            // Millisecond precision matches pagination cursors exactly
+            .bind(event.timestamp.trunc_subsecs(3))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get audit events, newest first (internal implementation)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or a stored identifier is malformed
+    async fn get_audit_events_impl(
+        &self,
+        tenant_id: Option<TenantId>,
+        event_type: Option<&str>,
+        limit: Option<u32>,
+    ) -> AppResult<Vec<AuditEvent>> {
+        let mut query = String::from(
+            r"
+            SELECT id, event_type, severity, message, result,
+                   tenant_id, user_id, ip_address, user_agent, metadata, timestamp
+            FROM audit_events
+            WHERE 1 = 1
+            ",
+        );
+        if tenant_id.is_some() {
+            query.push_str(" AND tenant_id = ?");
+        }
+        if event_type.is_some() {
+            query.push_str(" AND event_type = ?");
+        }
+        query.push_str(" ORDER BY timestamp DESC");
+        if limit.is_some() {
+            query.push_str(" LIMIT ?");
+        }
+
+        let mut sql_query = sqlx::query(&query);
+        if let Some(tid) = tenant_id {
+            sql_query = sql_query.bind(tid.to_string());
+        }
+        if let Some(et) = event_type {
+            sql_query = sql_query.bind(et);
+        }
+        if let Some(l) = limit {
+            sql_query = sql_query.bind(i64::from(l));
+        }
+
+        let rows = sql_query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to get audit events: {e}")))?;
+
+        rows.iter().map(Self::row_to_audit_event).collect()
+    }
+
+    /// Get one page of audit events matching `filter`, newest first (internal implementation)
+    ///
+    /// Pages are keyed on `(timestamp, id)` rather than offsets, so events recorded
+    /// while a client is paging never shift or repeat rows on later pages, and the
+    /// row-value comparison lets `SQLite` seek straight into the timestamp indexes.
+    /// Only forward pagination is supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cursor is invalid, the limit is too large, the
+    /// database query fails, or a stored identifier is malformed
+    async fn get_audit_events_page_impl(
+        &self,
+        filter: &AuditEventFilter,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<AuditEvent>> {
+        // Fetch one extra row to find out whether another page follows
+        let fetch_limit = i64::try_from(params.limit + 1)
+            .map_err(|_| AppError::invalid_input("Pagination limit too large"))?;
+        let position = params
+            .cursor
+            .as_ref()
+            .map(|cursor| {
+                cursor
+                    .decode()
+                    .ok_or_else(|| AppError::invalid_input("Invalid cursor format"))
+            })
+            .transpose()?;
+
+        let mut query = String::from(
+            r"
+            SELECT id, event_type, severity, message, result,
+                   tenant_id, user_id, ip_address, user_agent, metadata, timestamp
+            FROM audit_events
+            WHERE 1 = 1
+            ",
+        );
+        if filter.tenant_id.is_some() {
+            query.push_str(" AND tenant_id = ?");
+        }
+        if filter.user_id.is_some() {
+            query.push_str(" AND user_id = ?");
+        }
+        if filter.event_type.is_some() {
+            query.push_str(" AND event_type = ?");
+        }
+        if filter.severity.is_some() {
+            query.push_str(" AND severity = ?");
+        }
+        if filter.start.is_some() {
+            query.push_str(" AND timestamp >= ?");
+        }
+        if filter.end.is_some() {
+            query.push_str(" AND timestamp < ?");
+        }
+        if position.is_some() {
+            query.push_str(" AND (timestamp, id) < (?, ?)");
+        }
+        query.push_str(" ORDER BY timestamp DESC, id DESC LIMIT ?");
+
+        let mut sql_query = sqlx::query(&query);
+        if let Some(tid) = filter.tenant_id {
+            sql_query = sql_query.bind(tid.to_string());
+        }
+        if let Some(uid) = filter.user_id {
+            sql_query = sql_query.bind(uid.to_string());
+        }
+        if let Some(et) = &filter.event_type {
+            sql_query = sql_query.bind(et);
+        }
+        if let Some(severity) = &filter.severity {
+            sql_query = sql_query.bind(format!("{severity:?}"));
+        }
+        if let Some(start) = filter.start {
+            sql_query = sql_query.bind(start);
+        }
+        if let Some(end) = filter.end {
+            sql_query = sql_query.bind(end);
+        }
+        if let Some((timestamp, id)) = position {
+            sql_query = sql_query.bind(timestamp).bind(id);
+        }
+
+        let rows = sql_query
+            .bind(fetch_limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to get audit events page: {e}")))?;
+
+        let mut events = rows
+            .iter()
+            .map(Self::row_to_audit_event)
+            .collect::<AppResult<Vec<_>>>()?;
+        let has_more = events.len() > params.limit;
+        events.truncate(params.limit);
+
+        let next_cursor = events
+            .last()
+            .filter(|_| has_more)
+            .map(|event| Cursor::new(event.timestamp, &event.event_id.to_string()));
+
+        Ok(CursorPage::new(events, next_cursor, None, has_more))
+    }
+
+    /// Map an `audit_events` row to an `AuditEvent`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a stored identifier is malformed
+    fn row_to_audit_event(row: &SqliteRow) -> AppResult<AuditEvent> {
+        let event_id: String = row.get("id");
+        let event_type: String = row.get("event_type");
+        let severity: String = row.get("severity");
+        let tenant_id: Option<String> = row.get("tenant_id");
+        let user_id: Option<String> = row.get("user_id");
+        let metadata: Option<String> = row.get("metadata");
+
+        Ok(AuditEvent {
+            event_id: Uuid::parse_str(&event_id)
+                .map_err(|e| AppError::database(format!("Invalid audit event UUID: {e}")))?,
+            event_type: shared::enums::str_to_audit_event_type(&event_type),
+            severity: shared::enums::str_to_audit_severity(&severity),
+            timestamp: row.get("timestamp"),
+            user_id: user_id.as_deref().map(parse_uuid).transpose()?,
+            tenant_id: tenant_id
+                .as_deref()
+                .map(parse_uuid)
+                .transpose()?
+                .map(TenantId::from_uuid),
+            source_ip: row.get("ip_address"),
+            user_agent: row.get("user_agent"),
+            session_id: None, // Not stored in current schema
+            description: row.get("message"),
+            metadata: metadata
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_else(|| Value::Object(serde_json::Map::new())),
+            resource: None,             // Not stored in current schema
+            action: "audit".to_owned(), // Default action
+            result: row.get("result"),
+        })
+    }
+
+    /// Delete one batch of expired rows from a retention-managed table (internal implementation)
+    ///
+    /// Deleting by `rowid` through a limited subquery keeps each statement short so
+    /// writers serving requests are not blocked for long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails
+    async fn purge_records_before_impl(
+        &self,
+        table: RetentionTable,
+        before: DateTime<Utc>,
+        batch_size: u32,
+    ) -> AppResult<u64> {
+        let table_name = table.table_name();
+        let query = format!(
+            "DELETE FROM {table_name} WHERE rowid IN \
+             (SELECT rowid FROM {table_name} WHERE timestamp < ?1 LIMIT ?2)"
+        );
+
+        let result = sqlx::query(&query)
+            .bind(before)
+            .bind(i64::from(batch_size))
             .execute(&self.pool)
             .await
-            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+            .map_err(|e| AppError::database(format!("Failed to purge {table_name}: {e}")))?;
 
-        Ok(())
+        Ok(result.rows_affected())
     }
 
     // ================================
@@ -2533,6 +3218,10 @@ impl shared::encryption::HasEncryption for Database {
         Self::decrypt_data_with_aad_impl(self, encrypted, aad)
     }
 
+    fn tenant_data_key(&self, tenant_id: TenantId) -> AppResult<[u8; 32]> {
+        shared::encryption::derive_tenant_data_key(&self.encryption_key, tenant_id)
+    }
+
     fn hash_token_for_storage(&self, token: &str) -> AppResult<String> {
         Ok(Self::hash_token_for_storage_impl(self, token))
     }
@@ -2545,7 +3234,7 @@ use async_trait::async_trait;
 impl DatabaseProvider for Database {
     async fn new(database_url: &str, encryption_key: Vec<u8>) -> AppResult<Self> {
         // Call inherent impl directly to avoid infinite recursion
-        Self::new_impl(database_url, encryption_key).await
+        Self::new_impl(database_url, encryption_key, &SqlitePoolConfig::from_env()).await
     }
 
     async fn migrate(&self) -> AppResult<()> {
@@ -2553,6 +3242,18 @@ impl DatabaseProvider for Database {
         Self::migrate_impl(self).await
     }
 
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats::from_pool_counts(
+            self.pool.options().get_max_connections(),
+            self.pool.size(),
+            self.pool.num_idle(),
+        )
+    }
+
+    async fn schema_version(&self) -> AppResult<SchemaVersion> {
+        Self::schema_version_impl(self).await
+    }
+
     async fn create_user(&self, user: &User) -> AppResult<Uuid> {
         Self::create_user_impl(self, user).await
     }
@@ -2626,6 +3327,22 @@ impl DatabaseProvider for Database {
         Self::delete_user(self, user_id).await
     }
 
+    async fn soft_delete_user(&self, user_id: Uuid, deleted_at: DateTime<Utc>) -> AppResult<()> {
+        Self::soft_delete_user(self, user_id, deleted_at).await
+    }
+
+    async fn restore_deleted_user(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> AppResult<User> {
+        Self::restore_deleted_user(self, user_id, deleted_since).await
+    }
+
+    async fn purge_deleted_users(&self, deleted_before: DateTime<Utc>) -> AppResult<u64> {
+        Self::purge_deleted_users(self, deleted_before).await
+    }
+
     async fn get_first_admin_user(&self) -> AppResult<Option<User>> {
         Self::get_first_admin_user(self).await
     }
@@ -2655,6 +3372,15 @@ impl DatabaseProvider for Database {
         Self::update_goal_progress_impl(self, goal_id, user_id, current_value).await
     }
 
+    async fn update_goal_data(
+        &self,
+        goal_id: &str,
+        user_id: Uuid,
+        goal_data: Value,
+    ) -> AppResult<()> {
+        Self::update_goal_data_impl(self, goal_id, user_id, goal_data).await
+    }
+
     async fn get_user_configuration(&self, user_id: &str) -> AppResult<Option<String>> {
         Self::get_user_configuration_impl(self, user_id).await
     }
@@ -2667,6 +3393,14 @@ impl DatabaseProvider for Database {
         Self::store_insight_impl(self, user_id, insight_data).await
     }
 
+    async fn store_insights_batch(
+        &self,
+        user_id: Uuid,
+        insights: Vec<Value>,
+    ) -> AppResult<Vec<String>> {
+        Self::store_insights_batch_impl(self, user_id, insights).await
+    }
+
     async fn get_user_insights(
         &self,
         user_id: Uuid,
@@ -2680,6 +3414,10 @@ impl DatabaseProvider for Database {
         Self::create_api_key_impl(self, api_key).await
     }
 
+    async fn create_api_keys_batch(&self, api_keys: &[ApiKey]) -> AppResult<()> {
+        Self::create_api_keys_batch_impl(self, api_keys).await
+    }
+
     async fn get_api_key_by_prefix(&self, prefix: &str, hash: &str) -> AppResult<Option<ApiKey>> {
         Self::get_api_key_by_prefix_impl(self, prefix, hash).await
     }
@@ -2738,6 +3476,14 @@ impl DatabaseProvider for Database {
         Self::get_api_key_current_usage_impl(self, api_key_id).await
     }
 
+    async fn get_api_key_usage_times(
+        &self,
+        api_key_id: &str,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        Self::get_api_key_usage_times_impl(self, api_key_id, since).await
+    }
+
     async fn get_api_key_usage_stats(
         &self,
         api_key_id: &str,
@@ -2755,6 +3501,14 @@ impl DatabaseProvider for Database {
         Self::get_jwt_current_usage_impl(self, user_id).await
     }
 
+    async fn get_jwt_usage_times(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        Self::get_jwt_usage_times_impl(self, user_id, since).await
+    }
+
     async fn get_request_logs(
         &self,
         user_id: Option<Uuid>,
@@ -2989,6 +3743,61 @@ impl DatabaseProvider for Database {
         Self::update_provider_last_sync(self, user_id, tenant_id, provider, sync_time).await
     }
 
+    async fn get_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        started_after: DateTime<Utc>,
+    ) -> AppResult<Vec<ActivitySyncRecord>> {
+        Self::get_activity_sync_records_impl(self, user_id, tenant_id, provider, started_after)
+            .await
+    }
+
+    async fn upsert_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        records: &[ActivitySyncRecord],
+    ) -> AppResult<()> {
+        Self::upsert_activity_sync_records_impl(self, user_id, tenant_id, provider, records).await
+    }
+
+    async fn create_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        merge: &DuplicateActivityMerge,
+    ) -> AppResult<()> {
+        Self::create_duplicate_merge_impl(self, user_id, tenant_id, merge).await
+    }
+
+    async fn list_duplicate_merges(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<DuplicateActivityMerge>> {
+        Self::list_duplicate_merges_impl(self, user_id, tenant_id).await
+    }
+
+    async fn delete_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        secondary_provider: &str,
+        secondary_id: &str,
+    ) -> AppResult<bool> {
+        Self::delete_duplicate_merge_impl(
+            self,
+            user_id,
+            tenant_id,
+            secondary_provider,
+            secondary_id,
+        )
+        .await
+    }
+
     async fn get_top_tools_analysis(
         &self,
         user_id: Uuid,
@@ -2998,6 +3807,34 @@ impl DatabaseProvider for Database {
         Self::get_top_tools_analysis_impl(self, user_id, start_time, end_time).await
     }
 
+    async fn get_tenant_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> AppResult<Vec<ToolUsage>> {
+        Self::get_tenant_tool_usage_impl(self, tenant_id, start_time, end_time).await
+    }
+
+    async fn get_tenant_daily_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TenantDailyToolUsage>> {
+        Self::get_tenant_daily_tool_usage_impl(self, tenant_id, start, end).await
+    }
+
+    async fn get_activity_rollups(
+        &self,
+        user_id: Uuid,
+        granularity: Granularity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<RollupBucket>> {
+        Self::get_activity_rollups_impl(self, user_id, granularity, start, end).await
+    }
+
     async fn create_admin_token(
         &self,
         request: &CreateAdminTokenRequest,
@@ -3095,9 +3932,7 @@ impl DatabaseProvider for Database {
         .await
     }
 
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> AppResult<Vec<(String, String, String, DateTime<Utc>, bool)>> {
+    async fn load_rsa_keypairs(&self) -> AppResult<Vec<RsaKeypairRecord>> {
         Self::load_rsa_keypairs(self).await
     }
 
@@ -3105,6 +3940,14 @@ impl DatabaseProvider for Database {
         Self::update_rsa_keypair_active_status_impl(self, kid, is_active).await
     }
 
+    async fn retire_rsa_keypair(&self, kid: &str, retires_at: DateTime<Utc>) -> AppResult<()> {
+        Self::retire_rsa_keypair_impl(self, kid, retires_at).await
+    }
+
+    async fn delete_rsa_keypair(&self, kid: &str) -> AppResult<()> {
+        Self::delete_rsa_keypair_impl(self, kid).await
+    }
+
     // ================================
     // User MCP Tokens (AI Client Authentication)
     // ================================
@@ -3157,6 +4000,18 @@ impl DatabaseProvider for Database {
         Self::list_tenants_for_user_impl(self, user_id).await
     }
 
+    async fn get_tenant_rate_limit_mode(&self, tenant_id: TenantId) -> AppResult<RateLimitMode> {
+        Self::get_tenant_rate_limit_mode_impl(self, tenant_id).await
+    }
+
+    async fn set_tenant_rate_limit_mode(
+        &self,
+        tenant_id: TenantId,
+        mode: RateLimitMode,
+    ) -> AppResult<()> {
+        Self::set_tenant_rate_limit_mode_impl(self, tenant_id, mode).await
+    }
+
     async fn store_tenant_oauth_credentials(
         &self,
         credentials: &TenantOAuthCredentials,
@@ -3179,6 +4034,31 @@ impl DatabaseProvider for Database {
         Self::get_tenant_oauth_credentials_impl(self, tenant_id, provider).await
     }
 
+    async fn rotate_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        Self::rotate_tenant_oauth_secret_impl(
+            self,
+            tenant_id,
+            provider,
+            new_secret,
+            previous_expires_at,
+        )
+        .await
+    }
+
+    async fn promote_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<bool> {
+        Self::promote_tenant_oauth_secret_impl(self, tenant_id, provider).await
+    }
+
     async fn create_oauth_app(&self, app: &OAuthApp) -> AppResult<()> {
         Self::create_oauth_app_impl(self, app).await
     }
@@ -3226,6 +4106,31 @@ impl DatabaseProvider for Database {
         Self::revoke_oauth2_refresh_token_impl(self, token).await
     }
 
+    async fn store_oauth2_access_token(
+        &self,
+        access_token: &OAuth2IssuedAccessToken,
+    ) -> AppResult<()> {
+        Self::store_oauth2_access_token_impl(self, access_token).await
+    }
+
+    async fn get_oauth2_access_token(
+        &self,
+        jti: &str,
+    ) -> AppResult<Option<OAuth2IssuedAccessToken>> {
+        Self::get_oauth2_access_token_impl(self, jti).await
+    }
+
+    async fn revoke_oauth2_access_token(&self, jti: &str) -> AppResult<()> {
+        Self::revoke_oauth2_access_token_impl(self, jti).await
+    }
+
+    async fn revoke_oauth2_access_tokens_for_refresh_token(
+        &self,
+        refresh_token: &str,
+    ) -> AppResult<u64> {
+        Self::revoke_oauth2_access_tokens_for_refresh_token_impl(self, refresh_token).await
+    }
+
     async fn consume_auth_code(
         &self,
         code: &str,
@@ -3344,7 +4249,24 @@ impl DatabaseProvider for Database {
         event_type: Option<&str>,
         limit: Option<u32>,
     ) -> AppResult<Vec<AuditEvent>> {
-        Self::get_audit_events(self, tenant_id, event_type, limit).await
+        Self::get_audit_events_impl(self, tenant_id, event_type, limit).await
+    }
+
+    async fn get_audit_events_page(
+        &self,
+        filter: &AuditEventFilter,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<AuditEvent>> {
+        Self::get_audit_events_page_impl(self, filter, params).await
+    }
+
+    async fn purge_records_before(
+        &self,
+        table: RetentionTable,
+        before: DateTime<Utc>,
+        batch_size: u32,
+    ) -> AppResult<u64> {
+        Self::purge_records_before_impl(self, table, before, batch_size).await
     }
 
     async fn get_user_tenant_role(
@@ -3865,6 +4787,10 @@ impl DatabaseProvider for Database {
         Self::decrypt_data_with_aad_impl(self, encrypted, aad)
     }
 
+    async fn reencrypt_tenant_secrets(&self) -> AppResult<u64> {
+        Self::reencrypt_tenant_secrets_impl(self).await
+    }
+
     // ================================
     // Tool Selection
     // ================================
@@ -3934,10 +4860,89 @@ impl DatabaseProvider for Database {
         Self::count_enabled_tools_impl(self, tenant_id).await
     }
 
+    async fn list_feature_flags(&self) -> AppResult<Vec<FeatureFlag>> {
+        Self::list_feature_flags_impl(self).await
+    }
+
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> AppResult<()> {
+        Self::upsert_feature_flag_impl(self, flag).await
+    }
+
+    async fn delete_feature_flag(&self, key: &str) -> AppResult<bool> {
+        Self::delete_feature_flag_impl(self, key).await
+    }
+
+    async fn list_tenant_feature_flags(&self) -> AppResult<Vec<TenantFeatureFlag>> {
+        Self::list_tenant_feature_flags_impl(self).await
+    }
+
+    async fn upsert_tenant_feature_flag(&self, flag: &TenantFeatureFlag) -> AppResult<()> {
+        Self::upsert_tenant_feature_flag_impl(self, flag).await
+    }
+
+    async fn delete_tenant_feature_flag(&self, key: &str, tenant_id: TenantId) -> AppResult<bool> {
+        Self::delete_tenant_feature_flag_impl(self, key, tenant_id).await
+    }
+
+    async fn save_strava_webhook_subscription(
+        &self,
+        subscription: &StravaWebhookSubscription,
+    ) -> AppResult<()> {
+        Self::save_strava_webhook_subscription_impl(self, subscription).await
+    }
+
+    async fn get_strava_webhook_subscription(
+        &self,
+        subscription_id: i64,
+    ) -> AppResult<Option<StravaWebhookSubscription>> {
+        Self::get_strava_webhook_subscription_impl(self, subscription_id).await
+    }
+
+    async fn delete_strava_webhook_subscription(&self, subscription_id: i64) -> AppResult<bool> {
+        Self::delete_strava_webhook_subscription_impl(self, subscription_id).await
+    }
+
+    async fn link_strava_athlete(&self, link: &StravaAthleteLink) -> AppResult<()> {
+        Self::link_strava_athlete_impl(self, link).await
+    }
+
+    async fn get_strava_athlete_links(&self, athlete_id: i64) -> AppResult<Vec<StravaAthleteLink>> {
+        Self::get_strava_athlete_links_impl(self, athlete_id).await
+    }
+
+    async fn unlink_strava_athlete(&self, athlete_id: i64) -> AppResult<bool> {
+        Self::unlink_strava_athlete_impl(self, athlete_id).await
+    }
+
+    async fn get_reanalysis_checkpoint(
+        &self,
+        job_id: &str,
+    ) -> AppResult<Option<ReanalysisCheckpoint>> {
+        Self::get_reanalysis_checkpoint_impl(self, job_id).await
+    }
+
+    async fn save_reanalysis_checkpoint(&self, checkpoint: &ReanalysisCheckpoint) -> AppResult<()> {
+        Self::save_reanalysis_checkpoint_impl(self, checkpoint).await
+    }
+
     async fn user_has_synthetic_activities(&self, user_id: Uuid) -> AppResult<bool> {
         Self::user_has_synthetic_activities_impl(self, user_id).await
     }
 
+    async fn store_imported_activity(
+        &self,
+        user_id: Uuid,
+        source: &str,
+        file_name: &str,
+        activity: &Activity,
+    ) -> AppResult<()> {
+        Self::store_imported_activity_impl(self, user_id, source, file_name, activity).await
+    }
+
+    async fn get_imported_activities(&self, user_id: Uuid) -> AppResult<Vec<Activity>> {
+        Self::get_imported_activities_impl(self, user_id).await
+    }
+
     // ================================
     // Provider Connections
     // ================================
@@ -4103,6 +5108,116 @@ impl DatabaseProvider for Database {
     async fn invalidate_user_reset_tokens(&self, user_id: Uuid) -> AppResult<()> {
         Self::invalidate_user_reset_tokens_impl(self, user_id).await
     }
+
+    async fn record_auth_session(&self, session: &AuthSession) -> AppResult<()> {
+        Self::record_auth_session_impl(self, session).await
+    }
+
+    async fn get_auth_session(&self, session_id: &str) -> AppResult<Option<AuthSession>> {
+        Self::get_auth_session_impl(self, session_id).await
+    }
+
+    async fn list_active_auth_sessions(&self, user_id: Uuid) -> AppResult<Vec<AuthSession>> {
+        Self::list_active_auth_sessions_impl(self, user_id).await
+    }
+
+    async fn revoke_auth_session(&self, user_id: Uuid, session_id: &str) -> AppResult<bool> {
+        Self::revoke_auth_session_impl(self, user_id, session_id).await
+    }
+
+    async fn revoke_auth_sessions(
+        &self,
+        user_id: Uuid,
+        except_session_id: Option<&str>,
+    ) -> AppResult<u64> {
+        Self::revoke_auth_sessions_impl(self, user_id, except_session_id).await
+    }
+
+    async fn create_tenant_webhook(&self, webhook: &TenantWebhook) -> AppResult<()> {
+        Self::create_tenant_webhook_impl(self, webhook).await
+    }
+
+    async fn get_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<Option<TenantWebhook>> {
+        Self::get_tenant_webhook_impl(self, webhook_id).await
+    }
+
+    async fn list_tenant_webhooks(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<TenantWebhook>> {
+        Self::list_tenant_webhooks_impl(self, tenant_id).await
+    }
+
+    async fn delete_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<bool> {
+        Self::delete_tenant_webhook_impl(self, webhook_id).await
+    }
+
+    async fn create_webhook_delivery(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        Self::create_webhook_delivery_impl(self, delivery).await
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        Self::claim_due_webhook_deliveries_impl(self, now, lease_until, limit).await
+    }
+
+    async fn record_webhook_delivery_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &WebhookDeliveryAttempt,
+    ) -> AppResult<()> {
+        Self::record_webhook_delivery_attempt_impl(self, delivery, attempt).await
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        Self::list_webhook_deliveries_impl(self, webhook_id, status, limit).await
+    }
+
+    async fn list_webhook_delivery_attempts(
+        &self,
+        delivery_id: Uuid,
+    ) -> AppResult<Vec<WebhookDeliveryAttempt>> {
+        Self::list_webhook_delivery_attempts_impl(self, delivery_id).await
+    }
+
+    async fn get_tenant_max_backfill_days(&self, tenant_id: TenantId) -> AppResult<u32> {
+        Self::get_tenant_max_backfill_days_impl(self, tenant_id).await
+    }
+
+    async fn set_tenant_max_backfill_days(&self, tenant_id: TenantId, days: u32) -> AppResult<()> {
+        Self::set_tenant_max_backfill_days_impl(self, tenant_id, days).await
+    }
+
+    async fn get_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<Option<BackfillMarker>> {
+        Self::get_backfill_marker_impl(self, user_id, tenant_id, provider).await
+    }
+
+    async fn save_backfill_marker(&self, marker: &BackfillMarker) -> AppResult<()> {
+        Self::save_backfill_marker_impl(self, marker).await
+    }
+
+    async fn clear_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()> {
+        Self::clear_backfill_marker_impl(self, user_id, tenant_id, provider).await
+    }
 }
 
 /// Generate a secure encryption key (32 bytes for AES-256)
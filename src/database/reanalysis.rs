@@ -0,0 +1,100 @@
+// ABOUTME: Database operations for insight re-analysis job checkpoints
+// ABOUTME: Handles get and upsert on the reanalysis_checkpoints table
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::mappers::parse_rfc3339_timestamp;
+use crate::errors::{AppError, AppResult};
+use crate::models::ReanalysisCheckpoint;
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+/// Read a non-negative counter column
+fn parse_counter(row: &SqliteRow, column: &str) -> AppResult<u32> {
+    let value: i64 = row.try_get(column)?;
+    u32::try_from(value).map_err(|e| AppError::database(format!("Invalid {column}: {e}")))
+}
+
+fn map_checkpoint_row(row: &SqliteRow) -> AppResult<ReanalysisCheckpoint> {
+    let started_at: String = row.try_get("started_at")?;
+    let updated_at: String = row.try_get("updated_at")?;
+    let completed_at: Option<String> = row.try_get("completed_at")?;
+    Ok(ReanalysisCheckpoint {
+        job_id: row.try_get("job_id")?,
+        cursor: row.try_get("cursor")?,
+        users_processed: parse_counter(row, "users_processed")?,
+        users_failed: parse_counter(row, "users_failed")?,
+        insights_stored: parse_counter(row, "insights_stored")?,
+        started_at: parse_rfc3339_timestamp(&started_at, "started_at")?,
+        updated_at: parse_rfc3339_timestamp(&updated_at, "updated_at")?,
+        completed_at: completed_at.as_deref().map(parse_timestamp).transpose()?,
+    })
+}
+
+impl Database {
+    /// Get the checkpoint of a re-analysis job
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored row is invalid
+    pub async fn get_reanalysis_checkpoint_impl(
+        &self,
+        job_id: &str,
+    ) -> AppResult<Option<ReanalysisCheckpoint>> {
+        let row = sqlx::query(
+            r"
+            SELECT job_id, cursor, users_processed, users_failed, insights_stored,
+                   started_at, updated_at, completed_at
+            FROM reanalysis_checkpoints
+            WHERE job_id = ?1
+            ",
+        )
+        .bind(job_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get re-analysis checkpoint: {e}")))?;
+
+        row.as_ref().map(map_checkpoint_row).transpose()
+    }
+
+    /// Store the checkpoint of a re-analysis job, replacing the previous one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert fails
+    pub async fn save_reanalysis_checkpoint_impl(
+        &self,
+        checkpoint: &ReanalysisCheckpoint,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO reanalysis_checkpoints
+                (job_id, cursor, users_processed, users_failed, insights_stored,
+                 started_at, updated_at, completed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            ON CONFLICT(job_id) DO UPDATE SET
+                cursor = excluded.cursor,
+                users_processed = excluded.users_processed,
+                users_failed = excluded.users_failed,
+                insights_stored = excluded.insights_stored,
+                updated_at = excluded.updated_at,
+                completed_at = excluded.completed_at
+            ",
+        )
+        .bind(&checkpoint.job_id)
+        .bind(checkpoint.cursor.as_deref())
+        .bind(i64::from(checkpoint.users_processed))
+        .bind(i64::from(checkpoint.users_failed))
+        .bind(i64::from(checkpoint.insights_stored))
+        .bind(checkpoint.started_at.to_rfc3339())
+        .bind(checkpoint.updated_at.to_rfc3339())
+        .bind(checkpoint.completed_at.map(|at| at.to_rfc3339()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to save re-analysis checkpoint: {e}")))?;
+
+        Ok(())
+    }
+}
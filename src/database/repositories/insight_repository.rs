@@ -35,6 +35,19 @@ impl InsightRepository for InsightRepositoryImpl {
             })
     }
 
+    async fn store_batch(
+        &self,
+        user_id: Uuid,
+        insights: Vec<Value>,
+    ) -> Result<Vec<String>, DatabaseError> {
+        self.db
+            .store_insights_batch(user_id, insights)
+            .await
+            .map_err(|e| DatabaseError::QueryError {
+                context: e.to_string(),
+            })
+    }
+
     async fn list(
         &self,
         user_id: Uuid,
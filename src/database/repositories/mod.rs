@@ -7,6 +7,7 @@
 use crate::a2a::auth::A2AClient;
 use crate::a2a::client::A2ASession;
 use crate::a2a::protocol::{A2ATask, TaskStatus};
+use crate::admin::jwks::RsaKeypairRecord;
 use crate::api_keys::{ApiKey, ApiKeyUsage, ApiKeyUsageStats};
 use crate::database::{
     A2AUsage, A2AUsageStats, ConversationRecord, ConversationSummary, CreateUserMcpTokenRequest,
@@ -363,6 +364,14 @@ pub trait UsageRepository: Send + Sync {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> Result<Vec<crate::dashboard_routes::ToolUsage>, DatabaseError>;
+
+    /// Get tool usage summed across all members of a tenant
+    async fn get_tenant_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<crate::dashboard_routes::ToolUsage>, DatabaseError>;
 }
 
 /// A2A (Agent-to-Agent) client and session management repository
@@ -512,6 +521,13 @@ pub trait InsightRepository: Send + Sync {
     /// Store an AI-generated insight
     async fn store(&self, user_id: Uuid, insight_data: Value) -> Result<String, DatabaseError>;
 
+    /// Store many insights in one transaction, skipping ones the user already has
+    async fn store_batch(
+        &self,
+        user_id: Uuid,
+        insights: Vec<Value>,
+    ) -> Result<Vec<String>, DatabaseError>;
+
     /// Get insights for a user
     async fn list(
         &self,
@@ -780,9 +796,7 @@ pub trait SecurityRepository: Send + Sync {
     ) -> Result<(), DatabaseError>;
 
     /// Load all RSA keypairs from database
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> Result<Vec<(String, String, String, DateTime<Utc>, bool)>, DatabaseError>;
+    async fn load_rsa_keypairs(&self) -> Result<Vec<RsaKeypairRecord>, DatabaseError>;
 
     /// Update active status of RSA keypair
     async fn update_rsa_keypair_status(
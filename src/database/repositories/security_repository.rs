@@ -5,6 +5,7 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use super::SecurityRepository;
+use crate::admin::jwks::RsaKeypairRecord;
 use crate::database::DatabaseError;
 use crate::database_plugins::factory::Database;
 use async_trait::async_trait;
@@ -50,9 +51,7 @@ impl SecurityRepository for SecurityRepositoryImpl {
             })
     }
 
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> Result<Vec<(String, String, String, DateTime<Utc>, bool)>, DatabaseError> {
+    async fn load_rsa_keypairs(&self) -> Result<Vec<RsaKeypairRecord>, DatabaseError> {
         self.db
             .load_rsa_keypairs()
             .await
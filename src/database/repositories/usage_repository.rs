@@ -11,6 +11,7 @@ use crate::database_plugins::factory::Database;
 use crate::rate_limiting::JwtUsage;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use pierre_core::models::TenantId;
 use uuid::Uuid;
 
 /// SQLite/PostgreSQL implementation of `UsageRepository`
@@ -124,4 +125,18 @@ impl UsageRepository for UsageRepositoryImpl {
                 context: e.to_string(),
             })
     }
+
+    async fn get_tenant_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<crate::dashboard_routes::ToolUsage>, DatabaseError> {
+        self.db
+            .get_tenant_tool_usage(tenant_id, start_time, end_time)
+            .await
+            .map_err(|e| DatabaseError::QueryError {
+                context: e.to_string(),
+            })
+    }
 }
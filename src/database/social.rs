@@ -1086,6 +1086,7 @@ impl SocialManager {
             LEFT JOIN user_social_settings uss ON u.id = uss.user_id
             WHERE (uss.discoverable = 1 OR uss.discoverable IS NULL)
               AND u.user_status = 'active'
+              AND u.deleted_at IS NULL
               AND (u.email LIKE $1 OR u.display_name LIKE $1)
             ORDER BY u.display_name, u.email
             LIMIT $2
@@ -0,0 +1,180 @@
+// ABOUTME: Database operations for Strava push subscriptions and athlete links
+// ABOUTME: Handles CRUD for the strava_webhook_subscriptions and strava_athlete_links tables
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::mappers::parse_rfc3339_timestamp;
+use crate::errors::{AppError, AppResult};
+use crate::models::{StravaAthleteLink, StravaWebhookSubscription};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+fn map_subscription_row(row: &SqliteRow) -> AppResult<StravaWebhookSubscription> {
+    let created_at: String = row.try_get("created_at")?;
+    Ok(StravaWebhookSubscription {
+        id: row.try_get("id")?,
+        client_id: row.try_get("client_id")?,
+        callback_url: row.try_get("callback_url")?,
+        created_at: parse_rfc3339_timestamp(&created_at, "created_at")?,
+    })
+}
+
+fn map_athlete_link_row(row: &SqliteRow) -> AppResult<StravaAthleteLink> {
+    let user_id: String = row.try_get("user_id")?;
+    let tenant_id: String = row.try_get("tenant_id")?;
+    let linked_at: String = row.try_get("linked_at")?;
+    Ok(StravaAthleteLink {
+        athlete_id: row.try_get("athlete_id")?,
+        user_id: Uuid::parse_str(&user_id)
+            .map_err(|e| AppError::database(format!("Invalid Strava link user ID: {e}")))?,
+        tenant_id: tenant_id
+            .parse()
+            .map_err(|e| AppError::database(format!("Invalid Strava link tenant ID: {e}")))?,
+        linked_at: parse_rfc3339_timestamp(&linked_at, "linked_at")?,
+    })
+}
+
+impl Database {
+    /// Store the push subscription for a Strava application, replacing any previous one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert fails
+    pub async fn save_strava_webhook_subscription_impl(
+        &self,
+        subscription: &StravaWebhookSubscription,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO strava_webhook_subscriptions (id, client_id, callback_url, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(client_id) DO UPDATE SET
+                id = excluded.id,
+                callback_url = excluded.callback_url,
+                created_at = excluded.created_at
+            ",
+        )
+        .bind(subscription.id)
+        .bind(&subscription.client_id)
+        .bind(&subscription.callback_url)
+        .bind(subscription.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store Strava subscription: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get a stored push subscription by its Strava subscription ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored row is invalid
+    pub async fn get_strava_webhook_subscription_impl(
+        &self,
+        subscription_id: i64,
+    ) -> AppResult<Option<StravaWebhookSubscription>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, client_id, callback_url, created_at
+            FROM strava_webhook_subscriptions
+            WHERE id = ?1
+            ",
+        )
+        .bind(subscription_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get Strava subscription: {e}")))?;
+
+        row.as_ref().map(map_subscription_row).transpose()
+    }
+
+    /// Delete a stored push subscription
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails
+    pub async fn delete_strava_webhook_subscription_impl(
+        &self,
+        subscription_id: i64,
+    ) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM strava_webhook_subscriptions WHERE id = ?1")
+            .bind(subscription_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to delete Strava subscription: {e}"))
+            })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Link a Strava athlete to the user and tenant that connected them
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upsert fails
+    pub async fn link_strava_athlete_impl(&self, link: &StravaAthleteLink) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO strava_athlete_links (athlete_id, user_id, tenant_id, linked_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(user_id, tenant_id) DO UPDATE SET
+                athlete_id = excluded.athlete_id,
+                linked_at = excluded.linked_at
+            ",
+        )
+        .bind(link.athlete_id)
+        .bind(link.user_id.to_string())
+        .bind(link.tenant_id.to_string())
+        .bind(link.linked_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to link Strava athlete: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get every user and tenant linked to a Strava athlete
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a stored row is invalid
+    pub async fn get_strava_athlete_links_impl(
+        &self,
+        athlete_id: i64,
+    ) -> AppResult<Vec<StravaAthleteLink>> {
+        let rows = sqlx::query(
+            r"
+            SELECT athlete_id, user_id, tenant_id, linked_at
+            FROM strava_athlete_links
+            WHERE athlete_id = ?1
+            ORDER BY linked_at
+            ",
+        )
+        .bind(athlete_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get Strava athlete links: {e}")))?;
+
+        rows.iter().map(map_athlete_link_row).collect()
+    }
+
+    /// Remove every link to a Strava athlete
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails
+    pub async fn unlink_strava_athlete_impl(&self, athlete_id: i64) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM strava_athlete_links WHERE athlete_id = ?1")
+            .bind(athlete_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to unlink Strava athlete: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,141 @@
+// ABOUTME: Re-encrypts tenant secrets stored under the global DEK with per-tenant data keys
+// ABOUTME: Covers tenant OAuth client secrets, webhook secrets, and LLM API keys
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::encryption::{
+    create_llm_credentials_aad_context, reencrypt_for_tenant,
+};
+use crate::errors::{AppError, AppResult};
+use crate::models::TenantId;
+use crate::utils::uuid::parse_uuid;
+use sqlx::Row;
+
+impl Database {
+    /// Re-encrypt tenant secrets still sealed with the DEK under their tenant's data key
+    ///
+    /// Rows already using the tenant key are left untouched, so running this
+    /// again only rewrites secrets stored by older versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a secret fails to decrypt or a database operation fails
+    pub async fn reencrypt_tenant_secrets_impl(&self) -> AppResult<u64> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::database(format!("Failed to begin tenant key migration: {e}"))
+        })?;
+        let mut reencrypted = 0;
+
+        let rows = sqlx::query(
+            r"
+            SELECT tenant_id, provider, client_secret_encrypted, pending_client_secret_encrypted
+            FROM tenant_oauth_credentials
+            ",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant OAuth secrets: {e}")))?;
+        for row in rows {
+            let tenant_id_str: String = row.try_get("tenant_id")?;
+            let tenant_id = TenantId::from(parse_uuid(&tenant_id_str)?);
+            let provider: String = row.try_get("provider")?;
+            let secret: String = row.try_get("client_secret_encrypted")?;
+            let pending: Option<String> = row.try_get("pending_client_secret_encrypted")?;
+
+            let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+            let new_secret = reencrypt_for_tenant(self, tenant_id, &secret, &aad_context)?;
+            let new_pending = pending
+                .as_deref()
+                .map(|pending| reencrypt_for_tenant(self, tenant_id, pending, &aad_context))
+                .transpose()?
+                .flatten();
+            if new_secret.is_none() && new_pending.is_none() {
+                continue;
+            }
+
+            sqlx::query(
+                r"
+                UPDATE tenant_oauth_credentials
+                SET client_secret_encrypted = ?, pending_client_secret_encrypted = ?
+                WHERE tenant_id = ? AND provider = ?
+                ",
+            )
+            .bind(new_secret.unwrap_or(secret))
+            .bind(new_pending.or(pending))
+            .bind(&tenant_id_str)
+            .bind(&provider)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to re-encrypt tenant OAuth secret: {e}"))
+            })?;
+            reencrypted += 1;
+        }
+
+        let rows = sqlx::query("SELECT id, tenant_id, secret_encrypted FROM tenant_webhooks")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to list webhook secrets: {e}")))?;
+        for row in rows {
+            let id_str: String = row.try_get("id")?;
+            let id = parse_uuid(&id_str)?;
+            let tenant_id = TenantId::from(parse_uuid(&row.try_get::<String, _>("tenant_id")?)?);
+            let secret: String = row.try_get("secret_encrypted")?;
+
+            let aad_context = format!("{tenant_id}|{id}|tenant_webhooks");
+            let Some(new_secret) = reencrypt_for_tenant(self, tenant_id, &secret, &aad_context)?
+            else {
+                continue;
+            };
+            sqlx::query("UPDATE tenant_webhooks SET secret_encrypted = ? WHERE id = ?")
+                .bind(&new_secret)
+                .bind(&id_str)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to re-encrypt webhook secret: {e}"))
+                })?;
+            reencrypted += 1;
+        }
+
+        let rows = sqlx::query(
+            "SELECT id, tenant_id, user_id, provider, api_key_encrypted FROM user_llm_credentials",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list LLM credentials: {e}")))?;
+        for row in rows {
+            let id: String = row.try_get("id")?;
+            let tenant_id = TenantId::from(parse_uuid(&row.try_get::<String, _>("tenant_id")?)?);
+            let user_id = row
+                .try_get::<Option<String>, _>("user_id")?
+                .as_deref()
+                .map(parse_uuid)
+                .transpose()?;
+            let provider: String = row.try_get("provider")?;
+            let api_key: String = row.try_get("api_key_encrypted")?;
+
+            let aad_context = create_llm_credentials_aad_context(tenant_id, user_id, &provider);
+            let Some(new_api_key) = reencrypt_for_tenant(self, tenant_id, &api_key, &aad_context)?
+            else {
+                continue;
+            };
+            sqlx::query("UPDATE user_llm_credentials SET api_key_encrypted = ? WHERE id = ?")
+                .bind(&new_api_key)
+                .bind(&id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to re-encrypt LLM API key: {e}"))
+                })?;
+            reencrypted += 1;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::database(format!("Failed to commit tenant key migration: {e}"))
+        })?;
+        Ok(reencrypted)
+    }
+}
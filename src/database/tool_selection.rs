@@ -26,7 +26,7 @@ impl Database {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             ORDER BY category, tool_name
             ",
@@ -51,7 +51,7 @@ impl Database {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE tool_name = ?
             ",
@@ -77,7 +77,7 @@ impl Database {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE category = ?
             ORDER BY tool_name
@@ -116,7 +116,7 @@ impl Database {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE min_plan IN ({placeholders})
             ORDER BY category, tool_name
@@ -317,6 +317,9 @@ fn map_tool_catalog_row(row: &SqliteRow) -> AppResult<ToolCatalogEntry> {
         requires_provider: row.get("requires_provider"),
         min_plan: TenantPlan::parse_str(&min_plan_str)
             .ok_or_else(|| AppError::internal(format!("Invalid min_plan: {min_plan_str}")))?,
+        timeout_secs: row
+            .get::<Option<i64>, _>("timeout_secs")
+            .and_then(|secs| u64::try_from(secs).ok()),
         created_at: DateTime::parse_from_rfc3339(&created_at_str)
             .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc)),
         updated_at: DateTime::parse_from_rfc3339(&updated_at_str)
@@ -11,6 +11,7 @@ use crate::intelligence::{FitnessLevel, TimeAvailability, UserFitnessProfile, Us
 use crate::models::{TenantId, User, UserStatus};
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
 use crate::permissions::UserRole;
+use chrono::{DateTime, Utc};
 use sqlx::sqlite::SqliteRow;
 use sqlx::Row;
 use tracing::warn;
@@ -118,7 +119,7 @@ impl Database {
             SELECT id, email, display_name, password_hash, tier,
                    is_active, user_status, is_admin, approved_by, approved_at,
                    created_at, last_active, firebase_uid, auth_provider
-            FROM users WHERE id = $1 AND tenant_id = $2
+            FROM users WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL
         ";
 
         let row = sqlx::query(query)
@@ -175,7 +176,7 @@ impl Database {
             SELECT id, email, display_name, password_hash, tier,
                    is_active, user_status, is_admin, approved_by, approved_at,
                    created_at, last_active, firebase_uid, auth_provider
-            FROM users WHERE {field} = $1
+            FROM users WHERE {field} = $1 AND deleted_at IS NULL
             "
         );
 
@@ -285,7 +286,7 @@ impl Database {
     ///
     /// Returns an error if the database query fails
     pub async fn get_user_count_impl(&self) -> AppResult<i64> {
-        let count = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        let count = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE deleted_at IS NULL")
             .fetch_one(&self.pool)
             .await
             .map_err(|e| AppError::database(format!("Failed to get user count: {e}")))?;
@@ -484,17 +485,27 @@ impl Database {
     ) -> AppResult<Vec<User>> {
         let rows = if let Some(tid) = tenant_id {
             sqlx::query(
-                "SELECT * FROM users WHERE user_status = ?1 AND tenant_id = ?2 ORDER BY created_at DESC",
+                r"
+                SELECT * FROM users
+                WHERE user_status = ?1 AND tenant_id = ?2 AND deleted_at IS NULL
+                ORDER BY created_at DESC
+                ",
             )
             .bind(status)
             .bind(tid.to_string())
             .fetch_all(&self.pool)
             .await
         } else {
-            sqlx::query("SELECT * FROM users WHERE user_status = ?1 ORDER BY created_at DESC")
-                .bind(status)
-                .fetch_all(&self.pool)
-                .await
+            sqlx::query(
+                r"
+                SELECT * FROM users
+                WHERE user_status = ?1 AND deleted_at IS NULL
+                ORDER BY created_at DESC
+                ",
+            )
+            .bind(status)
+            .fetch_all(&self.pool)
+            .await
         }
         .map_err(|e| AppError::database(format!("Failed to get users by status: {e}")))?;
 
@@ -544,7 +555,7 @@ impl Database {
                        is_active, user_status, is_admin, approved_by, approved_at,
                        created_at, last_active, firebase_uid, auth_provider
                 FROM users
-                WHERE user_status = ?1
+                WHERE user_status = ?1 AND deleted_at IS NULL
                   AND (created_at < ?2 OR (created_at = ?2 AND id < ?3))
                 ORDER BY created_at DESC, id DESC
                 LIMIT ?4
@@ -557,7 +568,7 @@ impl Database {
                        is_active, user_status, is_admin, approved_by, approved_at,
                        created_at, last_active, firebase_uid, auth_provider
                 FROM users
-                WHERE user_status = ?1
+                WHERE user_status = ?1 AND deleted_at IS NULL
                 ORDER BY created_at DESC, id DESC
                 LIMIT ?2
             ";
@@ -820,7 +831,7 @@ impl Database {
             SELECT id, email, password_hash, display_name, tier, plan_tier, is_admin,
                    is_active, user_status, created_at, last_active, tenant_id
             FROM users
-            WHERE is_admin = 1
+            WHERE is_admin = 1 AND deleted_at IS NULL
             ORDER BY created_at ASC
             LIMIT 1
             ",
@@ -865,6 +876,89 @@ impl Database {
         Ok(())
     }
 
+    /// Hide a user until they are restored or purged
+    ///
+    /// Soft-deleted users are excluded from user lookups and listings, but their
+    /// data is kept so the deletion can be undone within the recovery window.
+    ///
+    /// # Errors
+    /// Returns error if the user is not found, already deleted, or the update fails
+    pub async fn soft_delete_user(
+        &self,
+        user_id: Uuid,
+        deleted_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let result = sqlx::query(
+            r"
+            UPDATE users SET deleted_at = ?1
+            WHERE id = ?2 AND deleted_at IS NULL
+            ",
+        )
+        .bind(deleted_at)
+        .bind(user_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to soft delete user: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("User {user_id} not found")));
+        }
+
+        Ok(())
+    }
+
+    /// Undo a soft delete made at or after `deleted_since`
+    ///
+    /// # Errors
+    /// Returns error if no recoverable deleted user exists or the update fails
+    pub async fn restore_deleted_user(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> AppResult<User> {
+        let result = sqlx::query(
+            r"
+            UPDATE users SET deleted_at = NULL
+            WHERE id = ?1 AND deleted_at IS NOT NULL AND deleted_at >= ?2
+            ",
+        )
+        .bind(user_id.to_string())
+        .bind(deleted_since)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to restore user: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!(
+                "Recoverable deleted user {user_id}"
+            )));
+        }
+
+        self.get_user_global_impl(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("User {user_id}")))
+    }
+
+    /// Permanently delete users soft-deleted before `deleted_before`
+    ///
+    /// Related records are removed via foreign key CASCADE constraints.
+    ///
+    /// # Errors
+    /// Returns error if the database operation fails
+    pub async fn purge_deleted_users(&self, deleted_before: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM users WHERE deleted_at IS NOT NULL AND deleted_at < ?1
+            ",
+        )
+        .bind(deleted_before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to purge deleted users: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Update user's display name
     ///
     /// # Errors
@@ -0,0 +1,386 @@
+// ABOUTME: Database operations for tenant webhooks and their delivery log
+// ABOUTME: Stores encrypted signing secrets, queues deliveries, and records every delivery attempt
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use super::Database;
+use crate::database_plugins::shared::encryption::HasEncryption;
+use crate::database_plugins::shared::mappers::parse_rfc3339_timestamp;
+use crate::errors::{AppError, AppResult};
+use crate::tenant::webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+};
+use crate::utils::uuid::parse_uuid;
+use chrono::{DateTime, SecondsFormat, Utc};
+use pierre_core::models::TenantId;
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+const DELIVERY_COLUMNS: &str = "id, webhook_id, tenant_id, event_type, payload, status, attempts, \
+     next_attempt_at, last_status_code, last_error, created_at, completed_at";
+
+/// Fixed-width RFC 3339 so stored timestamps compare correctly as strings
+fn db_timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// AAD context binding a webhook secret to its tenant and webhook
+fn secret_aad(tenant_id: TenantId, webhook_id: Uuid) -> String {
+    format!("{tenant_id}|{webhook_id}|tenant_webhooks")
+}
+
+impl Database {
+    /// Register a tenant webhook, encrypting its signing secret
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encryption or the database insert fails
+    pub async fn create_tenant_webhook_impl(&self, webhook: &TenantWebhook) -> AppResult<()> {
+        let encrypted_secret = self.encrypt_data_for_tenant(
+            webhook.tenant_id,
+            &webhook.secret,
+            &secret_aad(webhook.tenant_id, webhook.id),
+        )?;
+        let event_types = serde_json::to_string(&webhook.event_types)?;
+
+        sqlx::query(
+            r"
+            INSERT INTO tenant_webhooks
+                (id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            ",
+        )
+        .bind(webhook.id.to_string())
+        .bind(webhook.tenant_id.to_string())
+        .bind(&webhook.url)
+        .bind(&encrypted_secret)
+        .bind(&event_types)
+        .bind(webhook.active)
+        .bind(db_timestamp(webhook.created_at))
+        .bind(db_timestamp(webhook.updated_at))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create tenant webhook: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get a tenant webhook by ID, with its secret decrypted
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query or secret decryption fails
+    pub async fn get_tenant_webhook_impl(
+        &self,
+        webhook_id: Uuid,
+    ) -> AppResult<Option<TenantWebhook>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at
+            FROM tenant_webhooks WHERE id = ?
+            ",
+        )
+        .bind(webhook_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant webhook: {e}")))?;
+
+        row.map(|r| self.row_to_tenant_webhook(&r)).transpose()
+    }
+
+    /// List webhooks, optionally restricted to one tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query or secret decryption fails
+    pub async fn list_tenant_webhooks_impl(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<TenantWebhook>> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at
+            FROM tenant_webhooks
+            WHERE ?1 IS NULL OR tenant_id = ?1
+            ORDER BY created_at
+            ",
+        )
+        .bind(tenant_id.map(|id| id.to_string()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant webhooks: {e}")))?;
+
+        rows.iter().map(|r| self.row_to_tenant_webhook(r)).collect()
+    }
+
+    /// Delete a webhook together with its delivery log
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails
+    pub async fn delete_tenant_webhook_impl(&self, webhook_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM tenant_webhooks WHERE id = ?")
+            .bind(webhook_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to delete tenant webhook: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Queue a delivery
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database insert fails
+    pub async fn create_webhook_delivery_impl(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO webhook_deliveries
+                (id, webhook_id, tenant_id, event_type, payload, status, attempts,
+                 next_attempt_at, last_status_code, last_error, created_at, completed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ",
+        )
+        .bind(delivery.id.to_string())
+        .bind(delivery.webhook_id.to_string())
+        .bind(delivery.tenant_id.to_string())
+        .bind(delivery.event_type.as_str())
+        .bind(serde_json::to_string(&delivery.payload)?)
+        .bind(delivery.status.as_str())
+        .bind(i64::from(delivery.attempts))
+        .bind(db_timestamp(delivery.next_attempt_at))
+        .bind(delivery.last_status_code.map(i64::from))
+        .bind(delivery.last_error.as_deref())
+        .bind(db_timestamp(delivery.created_at))
+        .bind(delivery.completed_at.map(db_timestamp))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to queue webhook delivery: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Claim up to `limit` pending deliveries that are due at `now`
+    ///
+    /// Claimed deliveries have `next_attempt_at` pushed to `lease_until`, so
+    /// other workers skip them. If the claiming worker dies before recording
+    /// an attempt, the delivery becomes due again once the lease expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails
+    pub async fn claim_due_webhook_deliveries_impl(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        let query = format!(
+            r"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = ?1
+            WHERE id IN (
+                SELECT id FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= ?2
+                ORDER BY next_attempt_at
+                LIMIT ?3
+            )
+            RETURNING {DELIVERY_COLUMNS}
+            "
+        );
+        let rows = sqlx::query(&query)
+            .bind(db_timestamp(lease_until))
+            .bind(db_timestamp(now))
+            .bind(i64::from(limit))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to claim webhook deliveries: {e}")))?;
+
+        rows.iter().map(Self::row_to_webhook_delivery).collect()
+    }
+
+    /// Record an attempt and persist the delivery's resulting state atomically
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction fails
+    pub async fn record_webhook_delivery_attempt_impl(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &WebhookDeliveryAttempt,
+    ) -> AppResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        sqlx::query(
+            r"
+            INSERT INTO webhook_delivery_attempts
+                (id, delivery_id, attempt, status_code, error, duration_ms, attempted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(attempt.delivery_id.to_string())
+        .bind(i64::from(attempt.attempt))
+        .bind(attempt.status_code.map(i64::from))
+        .bind(attempt.error.as_deref())
+        .bind(i64::try_from(attempt.duration_ms).unwrap_or(i64::MAX))
+        .bind(db_timestamp(attempt.attempted_at))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to record webhook attempt: {e}")))?;
+
+        sqlx::query(
+            r"
+            UPDATE webhook_deliveries
+            SET status = ?, attempts = ?, next_attempt_at = ?, last_status_code = ?,
+                last_error = ?, completed_at = ?
+            WHERE id = ?
+            ",
+        )
+        .bind(delivery.status.as_str())
+        .bind(i64::from(delivery.attempts))
+        .bind(db_timestamp(delivery.next_attempt_at))
+        .bind(delivery.last_status_code.map(i64::from))
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.completed_at.map(db_timestamp))
+        .bind(delivery.id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update webhook delivery: {e}")))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to commit webhook attempt: {e}")))
+    }
+
+    /// List a webhook's deliveries, newest first, optionally filtered by status
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails
+    pub async fn list_webhook_deliveries_impl(
+        &self,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        let query = format!(
+            r"
+            SELECT {DELIVERY_COLUMNS}
+            FROM webhook_deliveries
+            WHERE webhook_id = ?1 AND (?2 IS NULL OR status = ?2)
+            ORDER BY created_at DESC
+            LIMIT ?3
+            "
+        );
+        let rows = sqlx::query(&query)
+            .bind(webhook_id.to_string())
+            .bind(status.map(|s| s.as_str()))
+            .bind(i64::from(limit))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to list webhook deliveries: {e}")))?;
+
+        rows.iter().map(Self::row_to_webhook_delivery).collect()
+    }
+
+    /// List the attempts made for a delivery in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails
+    pub async fn list_webhook_delivery_attempts_impl(
+        &self,
+        delivery_id: Uuid,
+    ) -> AppResult<Vec<WebhookDeliveryAttempt>> {
+        let rows = sqlx::query(
+            r"
+            SELECT delivery_id, attempt, status_code, error, duration_ms, attempted_at
+            FROM webhook_delivery_attempts
+            WHERE delivery_id = ?
+            ORDER BY attempt
+            ",
+        )
+        .bind(delivery_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list webhook attempts: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let delivery_id: String = row.try_get("delivery_id")?;
+                let attempt: i64 = row.try_get("attempt")?;
+                let status_code: Option<i64> = row.try_get("status_code")?;
+                let duration_ms: i64 = row.try_get("duration_ms")?;
+                let attempted_at: String = row.try_get("attempted_at")?;
+                Ok(WebhookDeliveryAttempt {
+                    delivery_id: parse_uuid(&delivery_id)?,
+                    attempt: u32::try_from(attempt).unwrap_or(0),
+                    status_code: status_code.and_then(|code| u16::try_from(code).ok()),
+                    error: row.try_get("error")?,
+                    duration_ms: u64::try_from(duration_ms).unwrap_or(0),
+                    attempted_at: parse_rfc3339_timestamp(&attempted_at, "attempted_at")?,
+                })
+            })
+            .collect()
+    }
+
+    fn row_to_tenant_webhook(&self, row: &SqliteRow) -> AppResult<TenantWebhook> {
+        let id = parse_uuid(&row.try_get::<String, _>("id")?)?;
+        let tenant_id = TenantId::from(parse_uuid(&row.try_get::<String, _>("tenant_id")?)?);
+        let encrypted_secret: String = row.try_get("secret_encrypted")?;
+        let event_types: String = row.try_get("event_types")?;
+        let created_at: String = row.try_get("created_at")?;
+        let updated_at: String = row.try_get("updated_at")?;
+
+        Ok(TenantWebhook {
+            id,
+            tenant_id,
+            url: row.try_get("url")?,
+            secret: self.decrypt_data_for_tenant(
+                tenant_id,
+                &encrypted_secret,
+                &secret_aad(tenant_id, id),
+            )?,
+            event_types: serde_json::from_str(&event_types)?,
+            active: row.try_get("is_active")?,
+            created_at: parse_rfc3339_timestamp(&created_at, "created_at")?,
+            updated_at: parse_rfc3339_timestamp(&updated_at, "updated_at")?,
+        })
+    }
+
+    fn row_to_webhook_delivery(row: &SqliteRow) -> AppResult<WebhookDelivery> {
+        let event_type: String = row.try_get("event_type")?;
+        let payload: String = row.try_get("payload")?;
+        let status: String = row.try_get("status")?;
+        let attempts: i64 = row.try_get("attempts")?;
+        let next_attempt_at: String = row.try_get("next_attempt_at")?;
+        let last_status_code: Option<i64> = row.try_get("last_status_code")?;
+        let created_at: String = row.try_get("created_at")?;
+        let completed_at: Option<String> = row.try_get("completed_at")?;
+
+        Ok(WebhookDelivery {
+            id: parse_uuid(&row.try_get::<String, _>("id")?)?,
+            webhook_id: parse_uuid(&row.try_get::<String, _>("webhook_id")?)?,
+            tenant_id: TenantId::from(parse_uuid(&row.try_get::<String, _>("tenant_id")?)?),
+            event_type: event_type.parse()?,
+            payload: serde_json::from_str(&payload)?,
+            status: status.parse()?,
+            attempts: u32::try_from(attempts).unwrap_or(0),
+            next_attempt_at: parse_rfc3339_timestamp(&next_attempt_at, "next_attempt_at")?,
+            last_status_code: last_status_code.and_then(|code| u16::try_from(code).ok()),
+            last_error: row.try_get("last_error")?,
+            created_at: parse_rfc3339_timestamp(&created_at, "created_at")?,
+            completed_at: completed_at
+                .map(|value| parse_rfc3339_timestamp(&value, "completed_at"))
+                .transpose()?,
+        })
+    }
+}
@@ -0,0 +1,426 @@
+// ABOUTME: Database snapshot and restore for SQLite (VACUUM INTO) and PostgreSQL (pg_dump/pg_restore)
+// ABOUTME: Writes timestamped backups, optionally AES-256-GCM encrypted with the database encryption key
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Database backup and restore
+//!
+//! `SQLite` databases are snapshotted with `VACUUM INTO`, which reads a
+//! consistent view through a regular connection and is safe while the server
+//! is running. The snapshot can be encrypted with AES-256-GCM using the
+//! bootstrapped database encryption key (DEK), so stored secrets are not
+//! exposed at rest. `PostgreSQL` databases are dumped and restored with
+//! `pg_dump`/`pg_restore` using the configured connection URL. The password is
+//! taken out of the URL and passed through `PGPASSWORD`, so it never appears in
+//! the tools' command lines.
+//!
+//! Restoring a `SQLite` backup replaces the database file, so the server should
+//! be stopped first.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit, Nonce};
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use sqlx::{Connection, SqliteConnection};
+use tokio::{fs, task};
+use tracing::info;
+use url::Url;
+
+use super::factory::{detect_database_type, DatabaseType};
+use crate::errors::{AppError, AppResult};
+
+/// Magic bytes identifying an encrypted Pierre backup
+const ENCRYPTED_BACKUP_MAGIC: &[u8; 8] = b"PIERBAK1";
+
+/// Header every plaintext `SQLite` database file starts with
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// AES-GCM nonce length in bytes
+const NONCE_LEN: usize = 12;
+
+/// Timestamp format embedded in backup file names
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Build the timestamped file a backup is written to
+///
+/// When `output` is an existing directory the file is created inside it as
+/// `pierre-<timestamp>.<ext>`; otherwise the timestamp is appended to the
+/// file stem, e.g. `backups/users.db` becomes `backups/users-20250601T080000Z.db`.
+#[must_use]
+pub fn timestamped_backup_path(
+    output: &Path,
+    database_type: DatabaseType,
+    encrypted: bool,
+    now: DateTime<Utc>,
+) -> PathBuf {
+    let timestamp = now.format(TIMESTAMP_FORMAT);
+    let default_extension = match database_type {
+        DatabaseType::SQLite => "db",
+        DatabaseType::PostgreSQL => "dump",
+    };
+
+    let mut path = if output.is_dir() {
+        output.join(format!("pierre-{timestamp}.{default_extension}"))
+    } else {
+        let stem = output
+            .file_stem()
+            .map_or_else(|| "pierre".into(), |s| s.to_string_lossy());
+        let file_name = output.extension().map_or_else(
+            || format!("{stem}-{timestamp}"),
+            |ext| format!("{stem}-{timestamp}.{}", ext.to_string_lossy()),
+        );
+        output.with_file_name(file_name)
+    };
+
+    if encrypted {
+        let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".enc");
+        path.set_file_name(file_name);
+    }
+    path
+}
+
+/// Back up the database at `database_url` to a timestamped file derived from `output`
+///
+/// `encryption_key` encrypts `SQLite` backups with AES-256-GCM; it is not
+/// supported for `PostgreSQL`, whose dumps are produced by `pg_dump`.
+///
+/// Returns the path of the written backup.
+///
+/// # Errors
+///
+/// Returns an error if the URL is unsupported, the snapshot or dump fails,
+/// encryption is requested for `PostgreSQL`, or the backup cannot be written.
+pub async fn backup_database(
+    database_url: &str,
+    output: &Path,
+    encryption_key: Option<&[u8; 32]>,
+) -> AppResult<PathBuf> {
+    let database_type = detect_database_type(database_url)?;
+    let path = timestamped_backup_path(output, database_type, encryption_key.is_some(), Utc::now());
+    if path.exists() {
+        return Err(AppError::invalid_input(format!(
+            "Backup file {} already exists",
+            path.display()
+        )));
+    }
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to create backup directory: {e}")))?;
+    }
+
+    match database_type {
+        DatabaseType::SQLite => backup_sqlite(database_url, &path, encryption_key).await?,
+        DatabaseType::PostgreSQL => {
+            if encryption_key.is_some() {
+                return Err(AppError::invalid_input(
+                    "Encrypted backups are only supported for SQLite",
+                ));
+            }
+            let (connection_url, password) = split_pg_password(database_url)?;
+            run_pg_tool(
+                "pg_dump",
+                vec![
+                    "--format=custom".into(),
+                    format!("--file={}", path.display()),
+                    format!("--dbname={connection_url}"),
+                ],
+                password,
+            )
+            .await?;
+        }
+    }
+
+    info!("Database backup written to {}", path.display());
+    Ok(path)
+}
+
+/// Restore the database at `database_url` from a backup created by [`backup_database`]
+///
+/// Encrypted `SQLite` backups are detected automatically and require the same
+/// `encryption_key` they were written with.
+///
+/// # Errors
+///
+/// Returns an error if the backup cannot be read, decrypted or validated, or
+/// if replacing the database fails.
+pub async fn restore_database(
+    database_url: &str,
+    input: &Path,
+    encryption_key: Option<&[u8; 32]>,
+) -> AppResult<()> {
+    match detect_database_type(database_url)? {
+        DatabaseType::SQLite => restore_sqlite(database_url, input, encryption_key).await?,
+        DatabaseType::PostgreSQL => {
+            let (connection_url, password) = split_pg_password(database_url)?;
+            run_pg_tool(
+                "pg_restore",
+                vec![
+                    "--clean".into(),
+                    "--if-exists".into(),
+                    "--no-owner".into(),
+                    "--single-transaction".into(),
+                    format!("--dbname={connection_url}"),
+                    input.display().to_string(),
+                ],
+                password,
+            )
+            .await?;
+        }
+    }
+
+    info!("Database restored from {}", input.display());
+    Ok(())
+}
+
+/// Resolve the database file behind a `sqlite:` URL
+fn sqlite_file_path(database_url: &str) -> AppResult<PathBuf> {
+    let path = database_url
+        .trim_start_matches("sqlite:")
+        .trim_start_matches("//")
+        .split('?')
+        .next()
+        .unwrap_or_default();
+    if path.is_empty() || path == ":memory:" {
+        return Err(AppError::invalid_input(
+            "In-memory SQLite databases cannot be backed up or restored",
+        ));
+    }
+    Ok(PathBuf::from(path))
+}
+
+async fn backup_sqlite(
+    database_url: &str,
+    path: &Path,
+    encryption_key: Option<&[u8; 32]>,
+) -> AppResult<()> {
+    let source = sqlite_file_path(database_url)?;
+    let snapshot = encryption_key.map_or_else(|| path.to_path_buf(), |_| sibling(path, "tmp"));
+
+    let mut connection = SqliteConnection::connect(&format!("sqlite:{}", source.display()))
+        .await
+        .map_err(|e| AppError::database(format!("Failed to open database for backup: {e}")))?;
+    let result = sqlx::query("VACUUM INTO ?1")
+        .bind(snapshot.display().to_string())
+        .execute(&mut connection)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to snapshot database: {e}")));
+    connection
+        .close()
+        .await
+        .map_err(|e| AppError::database(format!("Failed to close database: {e}")))?;
+    result?;
+
+    if let Some(key) = encryption_key {
+        let plaintext = read_file(&snapshot).await?;
+        let written = write_file(path, &encrypt_backup(key, &plaintext)?).await;
+        remove_if_exists(&snapshot).await?;
+        written?;
+    }
+    Ok(())
+}
+
+async fn restore_sqlite(
+    database_url: &str,
+    input: &Path,
+    encryption_key: Option<&[u8; 32]>,
+) -> AppResult<()> {
+    let target = sqlite_file_path(database_url)?;
+    let data = read_file(input).await?;
+
+    let database = if data.starts_with(ENCRYPTED_BACKUP_MAGIC) {
+        let key = encryption_key.ok_or_else(|| {
+            AppError::invalid_input("Backup is encrypted but no encryption key is available")
+        })?;
+        decrypt_backup(key, &data)?
+    } else {
+        data
+    };
+    if !database.starts_with(SQLITE_HEADER) {
+        return Err(AppError::invalid_input(format!(
+            "{} is not a SQLite backup",
+            input.display()
+        )));
+    }
+
+    // Stage next to the target so the final rename stays on one filesystem
+    let staged = sibling(&target, "restore");
+    write_file(&staged, &database).await?;
+    if let Err(e) = check_integrity(&staged).await {
+        remove_if_exists(&staged).await?;
+        return Err(e);
+    }
+
+    for suffix in ["-wal", "-shm"] {
+        let mut journal = target.clone().into_os_string();
+        journal.push(suffix);
+        remove_if_exists(Path::new(&journal)).await?;
+    }
+    fs::rename(&staged, &target)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to replace database file: {e}")))
+}
+
+/// Reject staged restores that `SQLite` itself considers corrupt
+async fn check_integrity(path: &Path) -> AppResult<()> {
+    let mut connection = SqliteConnection::connect(&format!("sqlite:{}", path.display()))
+        .await
+        .map_err(|e| AppError::database(format!("Failed to open backup: {e}")))?;
+    let status: String = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_one(&mut connection)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to check backup integrity: {e}")))?;
+    connection
+        .close()
+        .await
+        .map_err(|e| AppError::database(format!("Failed to close backup: {e}")))?;
+
+    if status == "ok" {
+        Ok(())
+    } else {
+        Err(AppError::invalid_input(format!(
+            "Backup failed integrity check: {status}"
+        )))
+    }
+}
+
+/// Encrypt a backup as `magic || nonce || ciphertext`
+fn encrypt_backup(key: &[u8; 32], plaintext: &[u8]) -> AppResult<Vec<u8>> {
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| AppError::internal(format!("Invalid key length: {e}")))?;
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|e| AppError::internal(format!("Backup encryption failed: {e}")))?;
+
+    let mut result =
+        Vec::with_capacity(ENCRYPTED_BACKUP_MAGIC.len() + NONCE_LEN + ciphertext.len());
+    result.extend_from_slice(ENCRYPTED_BACKUP_MAGIC);
+    result.extend_from_slice(&nonce_bytes);
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+fn decrypt_backup(key: &[u8; 32], data: &[u8]) -> AppResult<Vec<u8>> {
+    let (nonce, ciphertext) = data
+        .strip_prefix(ENCRYPTED_BACKUP_MAGIC.as_slice())
+        .filter(|body| body.len() >= NONCE_LEN)
+        .map(|body| body.split_at(NONCE_LEN))
+        .ok_or_else(|| AppError::invalid_input("Encrypted backup is truncated"))?;
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| AppError::internal(format!("Invalid key length: {e}")))?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            AppError::invalid_input(
+                "Failed to decrypt backup: it was written with a different encryption key or is corrupt",
+            )
+        })
+}
+
+/// Split a `PostgreSQL` URL into the URL without its password and the password
+///
+/// Both the `user:password@` form and a `password` query parameter are
+/// removed. The password is percent-decoded so it can be set as `PGPASSWORD`.
+///
+/// # Errors
+///
+/// Returns an error if the URL cannot be parsed
+pub fn split_pg_password(database_url: &str) -> AppResult<(String, Option<String>)> {
+    let mut url = Url::parse(database_url)
+        .map_err(|e| AppError::invalid_input(format!("Invalid PostgreSQL URL: {e}")))?;
+
+    let mut password = url
+        .password()
+        .map(|encoded| urlencoding::decode(encoded).map(|decoded| decoded.into_owned()))
+        .transpose()
+        .map_err(|e| AppError::invalid_input(format!("Invalid PostgreSQL password: {e}")))?;
+    url.set_password(None)
+        .map_err(|()| AppError::invalid_input("PostgreSQL URL cannot carry a password"))?;
+
+    if url.query_pairs().any(|(key, _)| key == "password") {
+        let mut kept = Vec::new();
+        for (key, value) in url.query_pairs() {
+            if key == "password" {
+                password = Some(value.into_owned());
+            } else {
+                kept.push((key.into_owned(), value.into_owned()));
+            }
+        }
+        if kept.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+    }
+
+    Ok((url.into(), password))
+}
+
+/// Run `pg_dump`/`pg_restore`, surfacing its stderr on failure
+///
+/// `password` is passed through `PGPASSWORD` rather than the command line.
+async fn run_pg_tool(
+    program: &'static str,
+    args: Vec<String>,
+    password: Option<String>,
+) -> AppResult<()> {
+    let output = task::spawn_blocking(move || {
+        let mut command = Command::new(program);
+        command.args(&args);
+        if let Some(password) = password {
+            command.env("PGPASSWORD", password);
+        }
+        command.output()
+    })
+    .await
+    .map_err(|e| AppError::internal(format!("{program} task failed: {e}")))?
+    .map_err(|e| AppError::internal(format!("Failed to run {program}: {e}")))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(AppError::database(format!(
+            "{program} failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{suffix}"));
+    path.with_file_name(file_name)
+}
+
+async fn read_file(path: &Path) -> AppResult<Vec<u8>> {
+    fs::read(path)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to read {}: {e}", path.display())))
+}
+
+async fn write_file(path: &Path, data: &[u8]) -> AppResult<()> {
+    fs::write(path, data)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to write {}: {e}", path.display())))
+}
+
+async fn remove_if_exists(path: &Path) -> AppResult<()> {
+    match fs::remove_file(path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(AppError::internal(format!(
+            "Failed to remove {}: {e}",
+            path.display()
+        ))),
+    }
+}
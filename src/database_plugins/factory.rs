@@ -8,38 +8,47 @@
 //! This module provides automatic database type detection and creation
 //! based on connection strings.
 
-use super::DatabaseProvider;
+use super::{DatabaseProvider, PoolStats, SchemaVersion};
 use crate::a2a::auth::A2AClient;
 use crate::a2a::client::A2ASession;
 use crate::a2a::protocol::{A2ATask, TaskStatus};
-use crate::admin::jwks::JwksManager;
+use crate::admin::jwks::{JwksManager, RsaKeypairRecord};
 use crate::admin::models::{
     AdminToken, AdminTokenUsage, CreateAdminTokenRequest, GeneratedAdminToken,
 };
 use crate::api_keys::{ApiKey, ApiKeyUsage, ApiKeyUsageStats};
+use crate::config::data_retention::RetentionTable;
 use crate::config::fitness::FitnessConfig;
 use crate::config::social::SocialInsightsConfig;
 use crate::dashboard_routes::{RequestLog, ToolUsage};
 use crate::database::{
     A2AUsage, A2AUsageStats, ConversationRecord, ConversationSummary, CreateUserMcpTokenRequest,
-    MessageRecord, UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
+    Granularity, MessageRecord, RollupBucket, UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
 };
 use crate::errors::{AppError, AppResult};
 use crate::models::OAuthNotification;
 use crate::models::{
-    AuthorizationCode, ConnectionType, OAuthApp, ProviderConnection, Tenant, TenantPlan,
-    TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp, UserOAuthToken,
-    UserStatus,
+    Activity, ActivitySyncRecord, AuthSession, AuthorizationCode, ConnectionType,
+    DuplicateActivityMerge, FeatureFlag, OAuthApp, ProviderConnection, ReanalysisCheckpoint,
+    StravaAthleteLink, StravaWebhookSubscription, Tenant, TenantDailyToolUsage, TenantFeatureFlag,
+    TenantPlan, TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp,
+    UserOAuthToken, UserStatus,
 };
 use crate::oauth2_client::OAuthClientState;
-use crate::oauth2_server::models::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
+use crate::oauth2_server::models::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
 use crate::pagination::{CursorPage, PaginationParams};
 use crate::permissions::impersonation::ImpersonationSession;
-use crate::rate_limiting::JwtUsage;
-use crate::security::audit::AuditEvent;
+use crate::providers::backfill::BackfillMarker;
+use crate::rate_limiting::{JwtUsage, RateLimitMode};
+use crate::security::audit::{AuditEvent, AuditEventFilter};
 use crate::security::key_rotation::KeyVersion;
 use crate::tenant::llm_manager::{LlmCredentialRecord, LlmCredentialSummary};
 use crate::tenant::oauth_manager::TenantOAuthCredentials;
+use crate::tenant::webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use pierre_core::models::TenantId;
@@ -125,6 +134,15 @@ impl Database {
         }
     }
 
+    /// Close every pooled connection, waiting until they are released
+    pub async fn close(&self) {
+        match self {
+            Self::SQLite(db) => db.pool().close().await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.close().await,
+        }
+    }
+
     /// Update the encryption key used for token encryption/decryption
     ///
     /// This is called after the actual DEK is loaded from the database during
@@ -403,6 +421,22 @@ impl DatabaseProvider for Database {
         }
     }
 
+    fn pool_stats(&self) -> PoolStats {
+        match self {
+            Self::SQLite(db) => db.pool_stats(),
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.pool_stats(),
+        }
+    }
+
+    async fn schema_version(&self) -> AppResult<SchemaVersion> {
+        match self {
+            Self::SQLite(db) => db.schema_version().await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.schema_version().await,
+        }
+    }
+
     /// Create a new user in the database
     ///
     /// # Errors
@@ -634,6 +668,34 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn soft_delete_user(&self, user_id: Uuid, deleted_at: DateTime<Utc>) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.soft_delete_user(user_id, deleted_at).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.soft_delete_user(user_id, deleted_at).await,
+        }
+    }
+
+    async fn restore_deleted_user(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> AppResult<User> {
+        match self {
+            Self::SQLite(db) => db.restore_deleted_user(user_id, deleted_since).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.restore_deleted_user(user_id, deleted_since).await,
+        }
+    }
+
+    async fn purge_deleted_users(&self, deleted_before: DateTime<Utc>) -> AppResult<u64> {
+        match self {
+            Self::SQLite(db) => db.purge_deleted_users(deleted_before).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.purge_deleted_users(deleted_before).await,
+        }
+    }
+
     /// Create or update a user profile with the provided data
     ///
     /// # Errors
@@ -733,6 +795,26 @@ impl DatabaseProvider for Database {
         }
     }
 
+    /// Replace the stored data of a specific goal
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Goal does not exist
+    /// - Database update fails
+    async fn update_goal_data(
+        &self,
+        goal_id: &str,
+        user_id: Uuid,
+        goal_data: serde_json::Value,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.update_goal_data_impl(goal_id, user_id, goal_data).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.update_goal_data(goal_id, user_id, goal_data).await,
+        }
+    }
+
     /// Get user configuration data by user ID
     ///
     /// # Errors
@@ -785,6 +867,25 @@ impl DatabaseProvider for Database {
         }
     }
 
+    /// Store a batch of insights for a user in one transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any insert in the batch fails (no insights are stored)
+    /// - Database connection issues
+    async fn store_insights_batch(
+        &self,
+        user_id: uuid::Uuid,
+        insights: Vec<serde_json::Value>,
+    ) -> AppResult<Vec<String>> {
+        match self {
+            Self::SQLite(db) => db.store_insights_batch(user_id, insights).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.store_insights_batch(user_id, insights).await,
+        }
+    }
+
     /// Get insights for a user with optional filtering
     ///
     /// # Errors
@@ -823,6 +924,19 @@ impl DatabaseProvider for Database {
         }
     }
 
+    /// Create several API keys in a single transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any insert fails; no key is stored in that case
+    async fn create_api_keys_batch(&self, api_keys: &[ApiKey]) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.create_api_keys_batch(api_keys).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.create_api_keys_batch(api_keys).await,
+        }
+    }
+
     /// Get an API key by its prefix and hash
     ///
     /// # Errors
@@ -967,6 +1081,18 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_api_key_usage_times(
+        &self,
+        api_key_id: &str,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        match self {
+            Self::SQLite(db) => db.get_api_key_usage_times(api_key_id, since).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_api_key_usage_times(api_key_id, since).await,
+        }
+    }
+
     async fn get_api_key_usage_stats(
         &self,
         api_key_id: &str,
@@ -1002,6 +1128,18 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_jwt_usage_times(
+        &self,
+        user_id: uuid::Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        match self {
+            Self::SQLite(db) => db.get_jwt_usage_times(user_id, since).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_jwt_usage_times(user_id, since).await,
+        }
+    }
+
     async fn get_request_logs(
         &self,
         user_id: Option<uuid::Uuid>,
@@ -1349,6 +1487,94 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_activity_sync_records(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        started_after: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<Vec<ActivitySyncRecord>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_activity_sync_records_impl(user_id, tenant_id, provider, started_after)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.get_activity_sync_records(user_id, tenant_id, provider, started_after)
+                    .await
+            }
+        }
+    }
+
+    async fn upsert_activity_sync_records(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        records: &[ActivitySyncRecord],
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.upsert_activity_sync_records_impl(user_id, tenant_id, provider, records)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.upsert_activity_sync_records(user_id, tenant_id, provider, records)
+                    .await
+            }
+        }
+    }
+
+    async fn create_duplicate_merge(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+        merge: &DuplicateActivityMerge,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.create_duplicate_merge_impl(user_id, tenant_id, merge)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.create_duplicate_merge(user_id, tenant_id, merge).await,
+        }
+    }
+
+    async fn list_duplicate_merges(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<DuplicateActivityMerge>> {
+        match self {
+            Self::SQLite(db) => db.list_duplicate_merges_impl(user_id, tenant_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_duplicate_merges(user_id, tenant_id).await,
+        }
+    }
+
+    async fn delete_duplicate_merge(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+        secondary_provider: &str,
+        secondary_id: &str,
+    ) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => {
+                db.delete_duplicate_merge_impl(user_id, tenant_id, secondary_provider, secondary_id)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.delete_duplicate_merge(user_id, tenant_id, secondary_provider, secondary_id)
+                    .await
+            }
+        }
+    }
+
     async fn get_top_tools_analysis(
         &self,
         user_id: uuid::Uuid,
@@ -1368,6 +1594,61 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_tenant_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start_time: chrono::DateTime<chrono::Utc>,
+        end_time: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<Vec<ToolUsage>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_tenant_tool_usage(tenant_id, start_time, end_time)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.get_tenant_tool_usage(tenant_id, start_time, end_time)
+                    .await
+            }
+        }
+    }
+
+    async fn get_tenant_daily_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<Vec<TenantDailyToolUsage>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_tenant_daily_tool_usage_impl(tenant_id, start, end)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_tenant_daily_tool_usage(tenant_id, start, end).await,
+        }
+    }
+
+    async fn get_activity_rollups(
+        &self,
+        user_id: Uuid,
+        granularity: Granularity,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<Vec<RollupBucket>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_activity_rollups(user_id, granularity, start, end)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.get_activity_rollups(user_id, granularity, start, end)
+                    .await
+            }
+        }
+    }
+
     // ================================
     // Admin Token Management
     // ================================
@@ -1560,6 +1841,26 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_tenant_rate_limit_mode(&self, tenant_id: TenantId) -> AppResult<RateLimitMode> {
+        match self {
+            Self::SQLite(db) => db.get_tenant_rate_limit_mode(tenant_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_tenant_rate_limit_mode(tenant_id).await,
+        }
+    }
+
+    async fn set_tenant_rate_limit_mode(
+        &self,
+        tenant_id: TenantId,
+        mode: RateLimitMode,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.set_tenant_rate_limit_mode(tenant_id, mode).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.set_tenant_rate_limit_mode(tenant_id, mode).await,
+        }
+    }
+
     async fn store_tenant_oauth_credentials(
         &self,
         credentials: &TenantOAuthCredentials,
@@ -1594,6 +1895,38 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn rotate_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.rotate_tenant_oauth_secret(tenant_id, provider, new_secret, previous_expires_at)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.rotate_tenant_oauth_secret(tenant_id, provider, new_secret, previous_expires_at)
+                    .await
+            }
+        }
+    }
+
+    async fn promote_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.promote_tenant_oauth_secret(tenant_id, provider).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.promote_tenant_oauth_secret(tenant_id, provider).await,
+        }
+    }
+
     // OAuth app registration implementations
     async fn create_oauth_app(&self, app: &OAuthApp) -> AppResult<()> {
         match self {
@@ -1690,47 +2023,94 @@ impl DatabaseProvider for Database {
         }
     }
 
-    async fn consume_auth_code(
+    async fn store_oauth2_access_token(
         &self,
-        code: &str,
-        client_id: &str,
-        redirect_uri: &str,
-        now: DateTime<Utc>,
-    ) -> AppResult<Option<OAuth2AuthCode>> {
+        access_token: &OAuth2IssuedAccessToken,
+    ) -> AppResult<()> {
         match self {
-            Self::SQLite(db) => {
-                db.consume_auth_code(code, client_id, redirect_uri, now)
-                    .await
-            }
+            Self::SQLite(db) => db.store_oauth2_access_token(access_token).await,
             #[cfg(feature = "postgresql")]
-            Self::PostgreSQL(db) => {
-                db.consume_auth_code(code, client_id, redirect_uri, now)
-                    .await
-            }
+            Self::PostgreSQL(db) => db.store_oauth2_access_token(access_token).await,
         }
     }
 
-    async fn consume_refresh_token(
+    async fn get_oauth2_access_token(
         &self,
-        token: &str,
-        client_id: &str,
-        now: DateTime<Utc>,
-    ) -> AppResult<Option<OAuth2RefreshToken>> {
+        jti: &str,
+    ) -> AppResult<Option<OAuth2IssuedAccessToken>> {
         match self {
-            Self::SQLite(db) => db.consume_refresh_token(token, client_id, now).await,
+            Self::SQLite(db) => db.get_oauth2_access_token(jti).await,
             #[cfg(feature = "postgresql")]
-            Self::PostgreSQL(db) => db.consume_refresh_token(token, client_id, now).await,
+            Self::PostgreSQL(db) => db.get_oauth2_access_token(jti).await,
         }
     }
 
-    async fn get_refresh_token_by_value(
-        &self,
-        token: &str,
-    ) -> AppResult<Option<OAuth2RefreshToken>> {
+    async fn revoke_oauth2_access_token(&self, jti: &str) -> AppResult<()> {
         match self {
-            Self::SQLite(db) => db.get_refresh_token_by_value(token).await,
+            Self::SQLite(db) => db.revoke_oauth2_access_token(jti).await,
             #[cfg(feature = "postgresql")]
-            Self::PostgreSQL(db) => db.get_refresh_token_by_value(token).await,
+            Self::PostgreSQL(db) => db.revoke_oauth2_access_token(jti).await,
+        }
+    }
+
+    async fn revoke_oauth2_access_tokens_for_refresh_token(
+        &self,
+        refresh_token: &str,
+    ) -> AppResult<u64> {
+        match self {
+            Self::SQLite(db) => {
+                db.revoke_oauth2_access_tokens_for_refresh_token(refresh_token)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.revoke_oauth2_access_tokens_for_refresh_token(refresh_token)
+                    .await
+            }
+        }
+    }
+
+    async fn consume_auth_code(
+        &self,
+        code: &str,
+        client_id: &str,
+        redirect_uri: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<Option<OAuth2AuthCode>> {
+        match self {
+            Self::SQLite(db) => {
+                db.consume_auth_code(code, client_id, redirect_uri, now)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.consume_auth_code(code, client_id, redirect_uri, now)
+                    .await
+            }
+        }
+    }
+
+    async fn consume_refresh_token(
+        &self,
+        token: &str,
+        client_id: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<Option<OAuth2RefreshToken>> {
+        match self {
+            Self::SQLite(db) => db.consume_refresh_token(token, client_id, now).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.consume_refresh_token(token, client_id, now).await,
+        }
+    }
+
+    async fn get_refresh_token_by_value(
+        &self,
+        token: &str,
+    ) -> AppResult<Option<OAuth2RefreshToken>> {
+        match self {
+            Self::SQLite(db) => db.get_refresh_token_by_value(token).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_refresh_token_by_value(token).await,
         }
     }
 
@@ -1914,6 +2294,31 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn get_audit_events_page(
+        &self,
+        filter: &AuditEventFilter,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<AuditEvent>> {
+        match self {
+            Self::SQLite(db) => db.get_audit_events_page(filter, params).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_audit_events_page(filter, params).await,
+        }
+    }
+
+    async fn purge_records_before(
+        &self,
+        table: RetentionTable,
+        before: DateTime<Utc>,
+        batch_size: u32,
+    ) -> AppResult<u64> {
+        match self {
+            Self::SQLite(db) => db.purge_records_before(table, before, batch_size).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.purge_records_before(table, before, batch_size).await,
+        }
+    }
+
     // ================================
     // User OAuth Tokens (Multi-Tenant)
     // ================================
@@ -2381,9 +2786,7 @@ impl DatabaseProvider for Database {
     }
 
     /// Load all RSA keypairs from database
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> AppResult<Vec<(String, String, String, chrono::DateTime<chrono::Utc>, bool)>> {
+    async fn load_rsa_keypairs(&self) -> AppResult<Vec<RsaKeypairRecord>> {
         match self {
             Self::SQLite(db) => db.load_rsa_keypairs().await,
             #[cfg(feature = "postgresql")]
@@ -2400,6 +2803,28 @@ impl DatabaseProvider for Database {
         }
     }
 
+    /// Mark RSA keypair as retiring at the given time
+    async fn retire_rsa_keypair(
+        &self,
+        kid: &str,
+        retires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.retire_rsa_keypair(kid, retires_at).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.retire_rsa_keypair(kid, retires_at).await,
+        }
+    }
+
+    /// Delete RSA keypair
+    async fn delete_rsa_keypair(&self, kid: &str) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.delete_rsa_keypair(kid).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.delete_rsa_keypair(kid).await,
+        }
+    }
+
     // ================================
     // User MCP Tokens (AI Client Authentication)
     // ================================
@@ -2629,6 +3054,14 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn reencrypt_tenant_secrets(&self) -> AppResult<u64> {
+        match self {
+            Self::SQLite(db) => db.reencrypt_tenant_secrets().await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.reencrypt_tenant_secrets().await,
+        }
+    }
+
     // ================================
     // Tool Selection
     // ================================
@@ -2756,6 +3189,133 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn list_feature_flags(&self) -> AppResult<Vec<FeatureFlag>> {
+        match self {
+            Self::SQLite(db) => db.list_feature_flags_impl().await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_feature_flags().await,
+        }
+    }
+
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.upsert_feature_flag_impl(flag).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.upsert_feature_flag(flag).await,
+        }
+    }
+
+    async fn delete_feature_flag(&self, key: &str) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.delete_feature_flag_impl(key).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.delete_feature_flag(key).await,
+        }
+    }
+
+    async fn list_tenant_feature_flags(&self) -> AppResult<Vec<TenantFeatureFlag>> {
+        match self {
+            Self::SQLite(db) => db.list_tenant_feature_flags_impl().await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_tenant_feature_flags().await,
+        }
+    }
+
+    async fn upsert_tenant_feature_flag(&self, flag: &TenantFeatureFlag) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.upsert_tenant_feature_flag_impl(flag).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.upsert_tenant_feature_flag(flag).await,
+        }
+    }
+
+    async fn delete_tenant_feature_flag(&self, key: &str, tenant_id: TenantId) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.delete_tenant_feature_flag_impl(key, tenant_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.delete_tenant_feature_flag(key, tenant_id).await,
+        }
+    }
+
+    async fn save_strava_webhook_subscription(
+        &self,
+        subscription: &StravaWebhookSubscription,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.save_strava_webhook_subscription_impl(subscription).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.save_strava_webhook_subscription(subscription).await,
+        }
+    }
+
+    async fn get_strava_webhook_subscription(
+        &self,
+        subscription_id: i64,
+    ) -> AppResult<Option<StravaWebhookSubscription>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_strava_webhook_subscription_impl(subscription_id)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_strava_webhook_subscription(subscription_id).await,
+        }
+    }
+
+    async fn delete_strava_webhook_subscription(&self, subscription_id: i64) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => {
+                db.delete_strava_webhook_subscription_impl(subscription_id)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.delete_strava_webhook_subscription(subscription_id).await,
+        }
+    }
+
+    async fn link_strava_athlete(&self, link: &StravaAthleteLink) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.link_strava_athlete_impl(link).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.link_strava_athlete(link).await,
+        }
+    }
+
+    async fn get_strava_athlete_links(&self, athlete_id: i64) -> AppResult<Vec<StravaAthleteLink>> {
+        match self {
+            Self::SQLite(db) => db.get_strava_athlete_links_impl(athlete_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_strava_athlete_links(athlete_id).await,
+        }
+    }
+
+    async fn unlink_strava_athlete(&self, athlete_id: i64) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.unlink_strava_athlete_impl(athlete_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.unlink_strava_athlete(athlete_id).await,
+        }
+    }
+
+    async fn get_reanalysis_checkpoint(
+        &self,
+        job_id: &str,
+    ) -> AppResult<Option<ReanalysisCheckpoint>> {
+        match self {
+            Self::SQLite(db) => db.get_reanalysis_checkpoint_impl(job_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_reanalysis_checkpoint(job_id).await,
+        }
+    }
+
+    async fn save_reanalysis_checkpoint(&self, checkpoint: &ReanalysisCheckpoint) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.save_reanalysis_checkpoint_impl(checkpoint).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.save_reanalysis_checkpoint(checkpoint).await,
+        }
+    }
+
     async fn user_has_synthetic_activities(&self, user_id: Uuid) -> AppResult<bool> {
         match self {
             Self::SQLite(db) => db.user_has_synthetic_activities_impl(user_id).await,
@@ -2764,6 +3324,34 @@ impl DatabaseProvider for Database {
         }
     }
 
+    async fn store_imported_activity(
+        &self,
+        user_id: Uuid,
+        source: &str,
+        file_name: &str,
+        activity: &Activity,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.store_imported_activity_impl(user_id, source, file_name, activity)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.store_imported_activity(user_id, source, file_name, activity)
+                    .await
+            }
+        }
+    }
+
+    async fn get_imported_activities(&self, user_id: Uuid) -> AppResult<Vec<Activity>> {
+        match self {
+            Self::SQLite(db) => db.get_imported_activities_impl(user_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_imported_activities(user_id).await,
+        }
+    }
+
     // ================================
     // Provider Connections
     // ================================
@@ -3076,6 +3664,213 @@ impl DatabaseProvider for Database {
             Self::PostgreSQL(db) => db.invalidate_user_reset_tokens(user_id).await,
         }
     }
+
+    async fn record_auth_session(&self, session: &AuthSession) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.record_auth_session_impl(session).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.record_auth_session(session).await,
+        }
+    }
+
+    async fn get_auth_session(&self, session_id: &str) -> AppResult<Option<AuthSession>> {
+        match self {
+            Self::SQLite(db) => db.get_auth_session_impl(session_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_auth_session(session_id).await,
+        }
+    }
+
+    async fn list_active_auth_sessions(&self, user_id: uuid::Uuid) -> AppResult<Vec<AuthSession>> {
+        match self {
+            Self::SQLite(db) => db.list_active_auth_sessions_impl(user_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_active_auth_sessions(user_id).await,
+        }
+    }
+
+    async fn revoke_auth_session(&self, user_id: uuid::Uuid, session_id: &str) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.revoke_auth_session_impl(user_id, session_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.revoke_auth_session(user_id, session_id).await,
+        }
+    }
+
+    async fn revoke_auth_sessions(
+        &self,
+        user_id: uuid::Uuid,
+        except_session_id: Option<&str>,
+    ) -> AppResult<u64> {
+        match self {
+            Self::SQLite(db) => {
+                db.revoke_auth_sessions_impl(user_id, except_session_id)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.revoke_auth_sessions(user_id, except_session_id).await,
+        }
+    }
+
+    async fn create_tenant_webhook(&self, webhook: &TenantWebhook) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.create_tenant_webhook_impl(webhook).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.create_tenant_webhook(webhook).await,
+        }
+    }
+
+    async fn get_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<Option<TenantWebhook>> {
+        match self {
+            Self::SQLite(db) => db.get_tenant_webhook_impl(webhook_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_tenant_webhook(webhook_id).await,
+        }
+    }
+
+    async fn list_tenant_webhooks(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<TenantWebhook>> {
+        match self {
+            Self::SQLite(db) => db.list_tenant_webhooks_impl(tenant_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_tenant_webhooks(tenant_id).await,
+        }
+    }
+
+    async fn delete_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<bool> {
+        match self {
+            Self::SQLite(db) => db.delete_tenant_webhook_impl(webhook_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.delete_tenant_webhook(webhook_id).await,
+        }
+    }
+
+    async fn create_webhook_delivery(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.create_webhook_delivery_impl(delivery).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.create_webhook_delivery(delivery).await,
+        }
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        match self {
+            Self::SQLite(db) => {
+                db.claim_due_webhook_deliveries_impl(now, lease_until, limit)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                db.claim_due_webhook_deliveries(now, lease_until, limit)
+                    .await
+            }
+        }
+    }
+
+    async fn record_webhook_delivery_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &WebhookDeliveryAttempt,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.record_webhook_delivery_attempt_impl(delivery, attempt)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.record_webhook_delivery_attempt(delivery, attempt).await,
+        }
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        match self {
+            Self::SQLite(db) => {
+                db.list_webhook_deliveries_impl(webhook_id, status, limit)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_webhook_deliveries(webhook_id, status, limit).await,
+        }
+    }
+
+    async fn list_webhook_delivery_attempts(
+        &self,
+        delivery_id: Uuid,
+    ) -> AppResult<Vec<WebhookDeliveryAttempt>> {
+        match self {
+            Self::SQLite(db) => db.list_webhook_delivery_attempts_impl(delivery_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.list_webhook_delivery_attempts(delivery_id).await,
+        }
+    }
+
+    async fn get_tenant_max_backfill_days(&self, tenant_id: TenantId) -> AppResult<u32> {
+        match self {
+            Self::SQLite(db) => db.get_tenant_max_backfill_days_impl(tenant_id).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_tenant_max_backfill_days(tenant_id).await,
+        }
+    }
+
+    async fn set_tenant_max_backfill_days(&self, tenant_id: TenantId, days: u32) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.set_tenant_max_backfill_days_impl(tenant_id, days).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.set_tenant_max_backfill_days(tenant_id, days).await,
+        }
+    }
+
+    async fn get_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<Option<BackfillMarker>> {
+        match self {
+            Self::SQLite(db) => {
+                db.get_backfill_marker_impl(user_id, tenant_id, provider)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.get_backfill_marker(user_id, tenant_id, provider).await,
+        }
+    }
+
+    async fn save_backfill_marker(&self, marker: &BackfillMarker) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => db.save_backfill_marker_impl(marker).await,
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.save_backfill_marker(marker).await,
+        }
+    }
+
+    async fn clear_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()> {
+        match self {
+            Self::SQLite(db) => {
+                db.clear_backfill_marker_impl(user_id, tenant_id, provider)
+                    .await
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => db.clear_backfill_marker(user_id, tenant_id, provider).await,
+        }
+    }
 }
 
 // Implement HasEncryption for the factory Database enum
@@ -3116,4 +3911,18 @@ impl super::shared::encryption::HasEncryption for Database {
             }
         }
     }
+
+    fn tenant_data_key(&self, tenant_id: TenantId) -> AppResult<[u8; 32]> {
+        match self {
+            Self::SQLite(db) => {
+                use super::shared::encryption::HasEncryption as HE;
+                HE::tenant_data_key(db, tenant_id)
+            }
+            #[cfg(feature = "postgresql")]
+            Self::PostgreSQL(db) => {
+                use super::shared::encryption::HasEncryption as HE;
+                HE::tenant_data_key(db, tenant_id)
+            }
+        }
+    }
 }
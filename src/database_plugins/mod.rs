@@ -7,36 +7,46 @@
 use crate::a2a::auth::A2AClient;
 use crate::a2a::client::A2ASession;
 use crate::a2a::protocol::{A2ATask, TaskStatus};
-use crate::admin::jwks::JwksManager;
+use crate::admin::jwks::{JwksManager, RsaKeypairRecord};
 use crate::admin::models::{
     AdminToken, AdminTokenUsage, CreateAdminTokenRequest, GeneratedAdminToken,
 };
 use crate::api_keys::{ApiKey, ApiKeyUsage, ApiKeyUsageStats};
+use crate::config::data_retention::RetentionTable;
 use crate::config::fitness::FitnessConfig;
 use crate::dashboard_routes::{RequestLog, ToolUsage};
 use crate::database::{
-    ConversationRecord, ConversationSummary, CreateUserMcpTokenRequest, MessageRecord,
-    UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
+    ConversationRecord, ConversationSummary, CreateUserMcpTokenRequest, Granularity, MessageRecord,
+    RollupBucket, UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
 };
-use crate::errors::AppResult;
+use crate::errors::{AppError, AppResult};
 use crate::models::OAuthNotification;
 use crate::models::{
-    AuthorizationCode, ConnectionType, OAuthApp, ProviderConnection, Tenant, TenantPlan,
-    TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp, UserOAuthToken,
-    UserStatus,
+    Activity, ActivitySyncRecord, AuthSession, AuthorizationCode, ConnectionType,
+    DuplicateActivityMerge, FeatureFlag, OAuthApp, ProviderConnection, ReanalysisCheckpoint,
+    StravaAthleteLink, StravaWebhookSubscription, Tenant, TenantDailyToolUsage, TenantFeatureFlag,
+    TenantPlan, TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp,
+    UserOAuthToken, UserStatus,
 };
 use crate::oauth2_client::OAuthClientState;
-use crate::oauth2_server::models::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
+use crate::oauth2_server::models::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
 use crate::pagination::{CursorPage, PaginationParams};
 use crate::permissions::impersonation::ImpersonationSession;
-use crate::rate_limiting::JwtUsage;
-use crate::security::audit::AuditEvent;
+use crate::providers::backfill::BackfillMarker;
+use crate::rate_limiting::{JwtUsage, RateLimitMode};
+use crate::security::audit::{AuditEvent, AuditEventFilter};
 use crate::security::key_rotation::KeyVersion;
 use crate::tenant::llm_manager::{LlmCredentialRecord, LlmCredentialSummary};
 use crate::tenant::oauth_manager::TenantOAuthCredentials;
+use crate::tenant::webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use pierre_core::models::TenantId;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
@@ -47,6 +57,8 @@ pub use crate::database::A2AUsage;
 /// A2A usage statistics
 pub use crate::database::A2AUsageStats;
 
+/// Database backup and restore (SQLite snapshots, PostgreSQL dumps)
+pub mod backup;
 /// Database provider factory
 pub mod factory;
 // Phase 3: sqlite.rs wrapper eliminated - Database now implements DatabaseProvider directly
@@ -56,9 +68,79 @@ pub mod factory;
 #[cfg(feature = "postgresql")]
 pub mod postgres;
 
+/// PostgreSQL LISTEN/NOTIFY push delivery for OAuth notifications
+#[cfg(feature = "postgresql")]
+pub mod oauth_notification_listener;
+
 /// Shared database logic (enum conversions, validation, mappers, encryption, etc.)
 pub mod shared;
 
+/// Point-in-time snapshot of a database connection pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStats {
+    /// Configured upper bound on open connections
+    pub max_connections: u32,
+    /// Connections currently checked out of the pool
+    pub active: u32,
+    /// Open connections waiting in the pool
+    pub idle: u32,
+    /// Tasks waiting for a connection, when the pool exposes it
+    ///
+    /// `sqlx` does not report its waiter queue, so this is `None` for both
+    /// backends; an exhausted pool shows up as `active == max_connections`.
+    pub pending: Option<u32>,
+}
+
+impl PoolStats {
+    /// Build stats from the open connection count and the idle count reported by `sqlx`
+    #[must_use]
+    pub fn from_pool_counts(max_connections: u32, size: u32, idle: usize) -> Self {
+        let idle = u32::try_from(idle).unwrap_or(u32::MAX).min(size);
+        Self {
+            max_connections,
+            active: size - idle,
+            idle,
+            pending: None,
+        }
+    }
+}
+
+/// Migration versions seen by the running binary
+///
+/// Versions are the numeric prefixes of the files in `migrations/`. A database
+/// whose `applied` version exceeds `supported` was migrated by a newer release,
+/// and starting against it would silently miss columns this binary does not know.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaVersion {
+    /// Highest migration recorded in the database, `None` before the first migration
+    ///
+    /// Also `None` for `PostgreSQL`, whose schema is created idempotently and not versioned.
+    pub applied: Option<i64>,
+    /// Highest migration embedded in this binary, `None` if the backend is not versioned
+    pub supported: Option<i64>,
+}
+
+impl SchemaVersion {
+    /// Refuse schemas this binary cannot safely run against
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Database` if the database schema is newer than the binary
+    pub fn ensure_supported(&self) -> AppResult<()> {
+        if let (Some(applied), Some(supported)) = (self.applied, self.supported) {
+            if applied > supported {
+                return Err(AppError::database(format!(
+                    "Database schema version {applied} is newer than this binary supports \
+                     ({supported}). Refusing to start to avoid running against unknown columns; \
+                     upgrade Pierre to a release that includes migration {applied} or restore a \
+                     backup taken before the upgrade"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Core database abstraction trait
 ///
 /// All database implementations must implement this trait to provide
@@ -73,6 +155,12 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Run database migrations to set up schema
     async fn migrate(&self) -> AppResult<()>;
 
+    /// Snapshot of connection pool usage for health reporting
+    fn pool_stats(&self) -> PoolStats;
+
+    /// Applied and supported migration versions for health reporting
+    async fn schema_version(&self) -> AppResult<SchemaVersion>;
+
     // ================================
     // User Management
     // ================================
@@ -147,6 +235,21 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Associated data (tokens, conversations, etc.) are cascade deleted.
     async fn delete_user(&self, user_id: Uuid) -> AppResult<()>;
 
+    /// Hide a user until they are restored or purged
+    ///
+    /// Soft-deleted users are excluded from user lookups, listings and counts.
+    async fn soft_delete_user(&self, user_id: Uuid, deleted_at: DateTime<Utc>) -> AppResult<()>;
+
+    /// Undo a soft delete made at or after `deleted_since`, returning the restored user
+    async fn restore_deleted_user(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> AppResult<User>;
+
+    /// Permanently delete users soft-deleted before `deleted_before`, returning how many
+    async fn purge_deleted_users(&self, deleted_before: DateTime<Utc>) -> AppResult<u64>;
+
     /// Get the first admin user by creation date
     ///
     /// Used for system seeding to associate with a valid admin user
@@ -254,6 +357,14 @@ pub trait DatabaseProvider: Send + Sync + Clone {
         current_value: f64,
     ) -> AppResult<()>;
 
+    /// Replace a goal's stored data, scoped to the owning user
+    async fn update_goal_data(
+        &self,
+        goal_id: &str,
+        user_id: Uuid,
+        goal_data: Value,
+    ) -> AppResult<()>;
+
     /// Get user configuration data
     async fn get_user_configuration(&self, user_id: &str) -> AppResult<Option<String>>;
 
@@ -267,6 +378,16 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Store an AI-generated insight
     async fn store_insight(&self, user_id: Uuid, insight_data: Value) -> AppResult<String>;
 
+    /// Store many insights in one transaction, returning their IDs in input order
+    ///
+    /// Insights whose content matches one the user already has are not
+    /// duplicated; the existing ID is returned for them.
+    async fn store_insights_batch(
+        &self,
+        user_id: Uuid,
+        insights: Vec<Value>,
+    ) -> AppResult<Vec<String>>;
+
     /// Get insights for a user
     async fn get_user_insights(
         &self,
@@ -282,6 +403,9 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Create a new API key
     async fn create_api_key(&self, api_key: &ApiKey) -> AppResult<()>;
 
+    /// Create several API keys in a single transaction (all or nothing)
+    async fn create_api_keys_batch(&self, api_keys: &[ApiKey]) -> AppResult<()>;
+
     /// Get API key by its prefix and hash
     async fn get_api_key_by_prefix(&self, prefix: &str, hash: &str) -> AppResult<Option<ApiKey>>;
 
@@ -322,6 +446,13 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Get current usage count for an API key
     async fn get_api_key_current_usage(&self, api_key_id: &str) -> AppResult<u32>;
 
+    /// Get timestamps of API key requests made at or after `since`, oldest first
+    async fn get_api_key_usage_times(
+        &self,
+        api_key_id: &str,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>>;
+
     /// Get usage statistics for an API key
     async fn get_api_key_usage_stats(
         &self,
@@ -340,6 +471,13 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Get current JWT usage count for rate limiting (current month)
     async fn get_jwt_current_usage(&self, user_id: Uuid) -> AppResult<u32>;
 
+    /// Get timestamps of a user's JWT requests made at or after `since`, oldest first
+    async fn get_jwt_usage_times(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>>;
+
     // ================================
     // Request Logs & System Stats
     // ================================
@@ -491,6 +629,48 @@ pub trait DatabaseProvider: Send + Sync + Clone {
         sync_time: DateTime<Utc>,
     ) -> AppResult<()>;
 
+    /// List delta sync records for a provider's activities that started at or after `started_after`
+    async fn get_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        started_after: DateTime<Utc>,
+    ) -> AppResult<Vec<ActivitySyncRecord>>;
+
+    /// Insert or replace delta sync records for a provider's activities
+    async fn upsert_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        records: &[ActivitySyncRecord],
+    ) -> AppResult<()>;
+
+    /// Store a manual merge of a duplicate activity, hiding the secondary from listings
+    async fn create_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        merge: &DuplicateActivityMerge,
+    ) -> AppResult<()>;
+
+    /// List a user's manual duplicate merges, oldest first
+    async fn list_duplicate_merges(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<DuplicateActivityMerge>>;
+
+    /// Delete the merge hiding a secondary activity; returns whether one existed
+    async fn delete_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        secondary_provider: &str,
+        secondary_id: &str,
+    ) -> AppResult<bool>;
+
     // ================================
     // Analytics & Intelligence
     // ================================
@@ -503,6 +683,33 @@ pub trait DatabaseProvider: Send + Sync + Clone {
         end_time: DateTime<Utc>,
     ) -> AppResult<Vec<ToolUsage>>;
 
+    /// Get tool usage summed across all members of a tenant
+    async fn get_tenant_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> AppResult<Vec<ToolUsage>>;
+
+    /// Get per-day, per-tool call counts across API key, A2A, and JWT usage
+    /// of a tenant's members over `[start, end)`
+    async fn get_tenant_daily_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TenantDailyToolUsage>>;
+
+    /// Get per-week or per-month activity totals for a user over `[start, end)`,
+    /// including empty buckets
+    async fn get_activity_rollups(
+        &self,
+        user_id: Uuid,
+        granularity: Granularity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<RollupBucket>>;
+
     // ================================
     // Admin Token Management
     // ================================
@@ -580,13 +787,17 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     ) -> AppResult<()>;
 
     /// Load all RSA keypairs from database
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> AppResult<Vec<(String, String, String, DateTime<Utc>, bool)>>;
+    async fn load_rsa_keypairs(&self) -> AppResult<Vec<RsaKeypairRecord>>;
 
     /// Update active status of RSA keypair
     async fn update_rsa_keypair_active_status(&self, kid: &str, is_active: bool) -> AppResult<()>;
 
+    /// Mark RSA keypair as retiring: no longer used for signing, still valid until `retires_at`
+    async fn retire_rsa_keypair(&self, kid: &str, retires_at: DateTime<Utc>) -> AppResult<()>;
+
+    /// Delete RSA keypair once it is no longer needed for validation
+    async fn delete_rsa_keypair(&self, kid: &str) -> AppResult<()>;
+
     // ================================
     // User MCP Tokens (AI Client Authentication)
     // ================================
@@ -633,6 +844,16 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// List tenants for a user
     async fn list_tenants_for_user(&self, user_id: Uuid) -> AppResult<Vec<Tenant>>;
 
+    /// Get how rate limit decisions are applied to a tenant's requests
+    async fn get_tenant_rate_limit_mode(&self, tenant_id: TenantId) -> AppResult<RateLimitMode>;
+
+    /// Set how rate limit decisions are applied to a tenant's requests
+    async fn set_tenant_rate_limit_mode(
+        &self,
+        tenant_id: TenantId,
+        mode: RateLimitMode,
+    ) -> AppResult<()>;
+
     /// Store tenant OAuth credentials
     async fn store_tenant_oauth_credentials(
         &self,
@@ -652,6 +873,27 @@ pub trait DatabaseProvider: Send + Sync + Clone {
         provider: &str,
     ) -> AppResult<Option<TenantOAuthCredentials>>;
 
+    /// Stage a new tenant OAuth client secret for rotation
+    ///
+    /// The new secret becomes the active one while the current secret stays
+    /// valid as the previous secret until `previous_expires_at`.
+    async fn rotate_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+
+    /// Make a pending tenant OAuth client secret permanent and purge the previous one
+    ///
+    /// Returns `false` when no rotation was pending.
+    async fn promote_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<bool>;
+
     // ================================
     // OAuth App Registration
     // ================================
@@ -694,6 +936,29 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Revoke OAuth 2.0 refresh token
     async fn revoke_oauth2_refresh_token(&self, token: &str) -> AppResult<()>;
 
+    /// Record an OAuth 2.0 access token issued alongside a refresh token
+    async fn store_oauth2_access_token(
+        &self,
+        access_token: &OAuth2IssuedAccessToken,
+    ) -> AppResult<()>;
+
+    /// Get a recorded OAuth 2.0 access token by its `jti` claim
+    async fn get_oauth2_access_token(
+        &self,
+        jti: &str,
+    ) -> AppResult<Option<OAuth2IssuedAccessToken>>;
+
+    /// Revoke a recorded OAuth 2.0 access token by its `jti` claim
+    async fn revoke_oauth2_access_token(&self, jti: &str) -> AppResult<()>;
+
+    /// Revoke every access token issued alongside a refresh token
+    ///
+    /// Returns the number of access tokens newly revoked.
+    async fn revoke_oauth2_access_tokens_for_refresh_token(
+        &self,
+        refresh_token: &str,
+    ) -> AppResult<u64>;
+
     /// Atomically consume OAuth 2.0 authorization code (check-and-set in single operation)
     ///
     /// This method prevents race conditions by performing validation and marking as used
@@ -845,6 +1110,26 @@ pub trait DatabaseProvider: Send + Sync + Clone {
         limit: Option<u32>,
     ) -> AppResult<Vec<AuditEvent>>;
 
+    /// Get one page of audit events matching `filter`, newest first
+    ///
+    /// Pages are ordered by `(timestamp, id)` descending and continue from the
+    /// cursor's position, so events recorded between requests do not shift later
+    /// pages. Only forward pagination is supported.
+    async fn get_audit_events_page(
+        &self,
+        filter: &AuditEventFilter,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<AuditEvent>>;
+
+    /// Delete up to `batch_size` rows of a retention-managed table recorded before
+    /// `before`, returning how many were deleted
+    async fn purge_records_before(
+        &self,
+        table: RetentionTable,
+        before: DateTime<Utc>,
+        batch_size: u32,
+    ) -> AppResult<u64>;
+
     // ================================
     // Tenant User Management
     // ================================
@@ -1056,6 +1341,17 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Returns an error if decryption fails (e.g., invalid data, AAD mismatch, tampered data)
     fn decrypt_data_with_aad(&self, encrypted: &str, aad: &str) -> AppResult<String>;
 
+    /// Re-encrypt tenant secrets still sealed with the global DEK under their tenant's data key
+    ///
+    /// Covers tenant OAuth client secrets, webhook secrets and LLM API keys.
+    /// Secrets already using their tenant key are skipped, so this is safe to
+    /// run on every startup. Returns the number of rows rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a secret fails to decrypt or a database operation fails
+    async fn reencrypt_tenant_secrets(&self) -> AppResult<u64>;
+
     // ================================
     // Tool Selection
     // ================================
@@ -1108,6 +1404,69 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// Count enabled tools for a tenant
     async fn count_enabled_tools(&self, tenant_id: TenantId) -> AppResult<usize>;
 
+    // ================================
+    // Feature Flags
+    // ================================
+
+    /// List every feature flag definition
+    async fn list_feature_flags(&self) -> AppResult<Vec<FeatureFlag>>;
+
+    /// Create or replace a feature flag definition
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> AppResult<()>;
+
+    /// Delete a feature flag and its per-tenant values
+    async fn delete_feature_flag(&self, key: &str) -> AppResult<bool>;
+
+    /// List every per-tenant feature flag value
+    async fn list_tenant_feature_flags(&self) -> AppResult<Vec<TenantFeatureFlag>>;
+
+    /// Create or replace a feature flag value for one tenant
+    async fn upsert_tenant_feature_flag(&self, flag: &TenantFeatureFlag) -> AppResult<()>;
+
+    /// Delete a feature flag value for one tenant (revert to the global value or default)
+    async fn delete_tenant_feature_flag(&self, key: &str, tenant_id: TenantId) -> AppResult<bool>;
+
+    // ================================
+    // Strava Webhooks
+    // ================================
+
+    /// Store the push subscription for a Strava application, replacing any previous one
+    async fn save_strava_webhook_subscription(
+        &self,
+        subscription: &StravaWebhookSubscription,
+    ) -> AppResult<()>;
+
+    /// Get a stored push subscription by its Strava subscription ID
+    async fn get_strava_webhook_subscription(
+        &self,
+        subscription_id: i64,
+    ) -> AppResult<Option<StravaWebhookSubscription>>;
+
+    /// Delete a stored push subscription
+    async fn delete_strava_webhook_subscription(&self, subscription_id: i64) -> AppResult<bool>;
+
+    /// Link a Strava athlete to the user and tenant that connected them
+    async fn link_strava_athlete(&self, link: &StravaAthleteLink) -> AppResult<()>;
+
+    /// Get every user and tenant linked to a Strava athlete
+    async fn get_strava_athlete_links(&self, athlete_id: i64) -> AppResult<Vec<StravaAthleteLink>>;
+
+    /// Remove every link to a Strava athlete (after they deauthorize the application)
+    async fn unlink_strava_athlete(&self, athlete_id: i64) -> AppResult<bool>;
+
+    // ================================
+    // Insight Re-analysis
+    // ================================
+
+    /// Get the checkpoint of a re-analysis job
+    async fn get_reanalysis_checkpoint(
+        &self,
+        job_id: &str,
+    ) -> AppResult<Option<ReanalysisCheckpoint>>;
+
+    /// Store the checkpoint of a re-analysis job, replacing the previous one
+    async fn save_reanalysis_checkpoint(&self, checkpoint: &ReanalysisCheckpoint) -> AppResult<()>;
+
     // ================================
     // Synthetic Provider Support
     // ================================
@@ -1118,6 +1477,22 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     /// provider should be shown as "connected" for a user.
     async fn user_has_synthetic_activities(&self, user_id: Uuid) -> AppResult<bool>;
 
+    // ================================
+    // Imported Activity Files
+    // ================================
+
+    /// Store an activity imported from an uploaded file, replacing an earlier import with the same ID
+    async fn store_imported_activity(
+        &self,
+        user_id: Uuid,
+        source: &str,
+        file_name: &str,
+        activity: &Activity,
+    ) -> AppResult<()>;
+
+    /// List a user's imported activities, most recent first
+    async fn get_imported_activities(&self, user_id: Uuid) -> AppResult<Vec<Activity>>;
+
     // ================================
     // Provider Connections
     // ================================
@@ -1263,4 +1638,110 @@ pub trait DatabaseProvider: Send + Sync + Clone {
     ///
     /// Called after a successful password change to prevent stale tokens from being used.
     async fn invalidate_user_reset_tokens(&self, user_id: Uuid) -> AppResult<()>;
+
+    // ================================
+    // Auth Sessions
+    // ================================
+
+    /// Record a session for a JWT issued at login or refresh
+    async fn record_auth_session(&self, session: &AuthSession) -> AppResult<()>;
+
+    /// Get a session by its ID (the token's `jti`)
+    async fn get_auth_session(&self, session_id: &str) -> AppResult<Option<AuthSession>>;
+
+    /// List a user's sessions that are neither revoked nor expired, newest first
+    async fn list_active_auth_sessions(&self, user_id: Uuid) -> AppResult<Vec<AuthSession>>;
+
+    /// Revoke one of a user's sessions; returns `false` if no unrevoked session matched
+    async fn revoke_auth_session(&self, user_id: Uuid, session_id: &str) -> AppResult<bool>;
+
+    /// Revoke all of a user's sessions except `except_session_id`; returns the number revoked
+    async fn revoke_auth_sessions(
+        &self,
+        user_id: Uuid,
+        except_session_id: Option<&str>,
+    ) -> AppResult<u64>;
+
+    // ================================
+    // Tenant Webhooks
+    // ================================
+
+    /// Register a tenant webhook; the signing secret is stored encrypted
+    async fn create_tenant_webhook(&self, webhook: &TenantWebhook) -> AppResult<()>;
+
+    /// Get a tenant webhook by ID with its secret decrypted
+    async fn get_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<Option<TenantWebhook>>;
+
+    /// List webhooks, optionally restricted to one tenant
+    async fn list_tenant_webhooks(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<TenantWebhook>>;
+
+    /// Delete a webhook and its delivery log
+    async fn delete_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<bool>;
+
+    /// Queue a webhook delivery
+    async fn create_webhook_delivery(&self, delivery: &WebhookDelivery) -> AppResult<()>;
+
+    /// Claim pending deliveries due at `now`, leasing them until `lease_until`
+    ///
+    /// A claimed delivery that never records an attempt becomes due again when
+    /// the lease expires, which keeps delivery at-least-once across crashes.
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>>;
+
+    /// Record a delivery attempt and persist the delivery's new state atomically
+    async fn record_webhook_delivery_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &WebhookDeliveryAttempt,
+    ) -> AppResult<()>;
+
+    /// List a webhook's deliveries, newest first, optionally filtered by status
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>>;
+
+    /// List the attempts made for a delivery in order
+    async fn list_webhook_delivery_attempts(
+        &self,
+        delivery_id: Uuid,
+    ) -> AppResult<Vec<WebhookDeliveryAttempt>>;
+
+    // ================================
+    // Activity Backfill
+    // ================================
+
+    /// Get how many days back activity backfills may page for a tenant
+    async fn get_tenant_max_backfill_days(&self, tenant_id: TenantId) -> AppResult<u32>;
+
+    /// Set how many days back activity backfills may page for a tenant
+    async fn set_tenant_max_backfill_days(&self, tenant_id: TenantId, days: u32) -> AppResult<()>;
+
+    /// Get the marker left by a truncated backfill for a user's provider connection
+    async fn get_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<Option<BackfillMarker>>;
+
+    /// Store a backfill marker, replacing any earlier marker for the same connection
+    async fn save_backfill_marker(&self, marker: &BackfillMarker) -> AppResult<()>;
+
+    /// Remove the backfill marker for a user's provider connection
+    async fn clear_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()>;
 }
@@ -0,0 +1,228 @@
+// ABOUTME: PostgreSQL LISTEN/NOTIFY listener that pushes OAuth notifications to connected clients
+// ABOUTME: Reconnects with exponential backoff, replays missed rows, and deduplicates by notification id
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! `PostgreSQL` push delivery for OAuth notifications
+//!
+//! On `PostgreSQL`, storing an OAuth notification issues a `NOTIFY` on the
+//! [`OAUTH_NOTIFICATION_CHANNEL`] channel carrying the notification id. The
+//! listener in this module holds a dedicated `LISTEN` connection, loads each
+//! announced row, and forwards it to the websocket/SSE broadcast channel, so
+//! every server instance sharing the database delivers the notification
+//! without polling.
+//!
+//! After every (re)connect the listener replays rows created since the last
+//! one it saw, covering notifications sent while it was disconnected. Replayed
+//! and announced rows can overlap, so delivery is deduplicated by id.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sqlx::postgres::{PgListener, PgRow};
+use sqlx::{Pool, Postgres, Row};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::errors::{AppError, AppResult};
+use crate::mcp::schema::OAuthCompletedNotification;
+use crate::models::OAuthNotification;
+
+/// `PostgreSQL` channel used to announce new OAuth notifications
+pub const OAUTH_NOTIFICATION_CHANNEL: &str = "oauth_notifications";
+
+/// Delay before the first reconnect attempt
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound for the reconnect delay
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Number of delivered notification ids remembered for deduplication
+const DEDUP_CAPACITY: usize = 1024;
+
+/// Bounded set of recently delivered notification ids
+struct RecentIds {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl RecentIds {
+    fn new() -> Self {
+        Self {
+            ids: HashSet::with_capacity(DEDUP_CAPACITY),
+            order: VecDeque::with_capacity(DEDUP_CAPACITY),
+        }
+    }
+
+    /// Remember an id, returning `false` if it was already delivered
+    fn insert(&mut self, id: &str) -> bool {
+        if self.ids.contains(id) {
+            return false;
+        }
+        if self.order.len() == DEDUP_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.ids.insert(id.to_owned());
+        self.order.push_back(id.to_owned());
+        true
+    }
+}
+
+/// Forwards OAuth notifications announced via `NOTIFY` to the broadcast channel
+pub struct OAuthNotificationListener {
+    pool: Pool<Postgres>,
+    sender: broadcast::Sender<OAuthCompletedNotification>,
+    delivered: RecentIds,
+    replay_from: DateTime<Utc>,
+}
+
+impl OAuthNotificationListener {
+    /// Create a listener that delivers notifications created from now on
+    #[must_use]
+    pub fn new(
+        pool: Pool<Postgres>,
+        sender: broadcast::Sender<OAuthCompletedNotification>,
+    ) -> Self {
+        Self {
+            pool,
+            sender,
+            delivered: RecentIds::new(),
+            replay_from: Utc::now(),
+        }
+    }
+
+    /// Run the listener on a background task until the runtime shuts down
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(mut self) {
+        let mut backoff = INITIAL_RECONNECT_BACKOFF;
+        loop {
+            if let Err(e) = self.listen(&mut backoff).await {
+                warn!(
+                    "OAuth notification listener disconnected: {}; reconnecting in {:?}",
+                    e, backoff
+                );
+            }
+            sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        }
+    }
+
+    /// Listen on one connection until it fails
+    ///
+    /// Resets `backoff` once the connection is established and caught up.
+    async fn listen(&mut self, backoff: &mut Duration) -> AppResult<()> {
+        let mut listener = PgListener::connect_with(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to connect listener: {e}")))?;
+        listener
+            .listen(OAUTH_NOTIFICATION_CHANNEL)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to LISTEN: {e}")))?;
+
+        self.replay_missed().await?;
+        *backoff = INITIAL_RECONNECT_BACKOFF;
+        info!(
+            "Listening for OAuth notifications on channel '{}'",
+            OAUTH_NOTIFICATION_CHANNEL
+        );
+
+        loop {
+            let notification = listener
+                .try_recv()
+                .await
+                .map_err(|e| AppError::database(format!("Failed to receive notification: {e}")))?
+                .ok_or_else(|| AppError::database("Listener connection closed"))?;
+
+            let id = notification.payload();
+            match self.fetch_notification(id).await? {
+                Some(row) => self.deliver(&row),
+                None => debug!("OAuth notification {} no longer exists", id),
+            }
+        }
+    }
+
+    /// Deliver rows stored while the listener was not connected
+    async fn replay_missed(&mut self) -> AppResult<()> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, user_id, provider, success, message, expires_at, created_at, read_at
+            FROM oauth_notifications
+            WHERE created_at >= $1
+            ORDER BY created_at
+            ",
+        )
+        .bind(self.replay_from)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to replay OAuth notifications: {e}")))?;
+
+        for row in &rows {
+            self.deliver(&notification_from_row(row));
+        }
+        Ok(())
+    }
+
+    async fn fetch_notification(&self, id: &str) -> AppResult<Option<OAuthNotification>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, user_id, provider, success, message, expires_at, created_at, read_at
+            FROM oauth_notifications
+            WHERE id = $1
+            ",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to fetch OAuth notification: {e}")))?;
+
+        Ok(row.as_ref().map(notification_from_row))
+    }
+
+    /// Forward a notification unless it was already delivered
+    fn deliver(&mut self, notification: &OAuthNotification) {
+        if notification.created_at > self.replay_from {
+            self.replay_from = notification.created_at;
+        }
+        if !self.delivered.insert(&notification.id) {
+            debug!("Skipping duplicate OAuth notification {}", notification.id);
+            return;
+        }
+
+        let message = OAuthCompletedNotification::new(
+            notification.provider.clone(),
+            notification.success,
+            notification.message.clone(),
+            Some(notification.user_id.clone()),
+        );
+        if let Err(e) = self.sender.send(message) {
+            debug!(
+                "No active receivers for OAuth notification {}: {}",
+                notification.id, e
+            );
+        }
+    }
+}
+
+fn notification_from_row(row: &PgRow) -> OAuthNotification {
+    OAuthNotification {
+        id: row.get("id"),
+        user_id: row.get::<Uuid, _>("user_id").to_string(),
+        provider: row.get("provider"),
+        success: row.get("success"),
+        message: row.get("message"),
+        expires_at: row.get("expires_at"),
+        created_at: row.get("created_at"),
+        read_at: row.get("read_at"),
+    }
+}
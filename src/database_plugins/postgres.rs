@@ -8,56 +8,73 @@
 //! This module provides `PostgreSQL` support for cloud deployments,
 //! implementing the same interface as the `SQLite` version.
 
-use super::{shared, DatabaseProvider};
+use super::{shared, DatabaseProvider, PoolStats, SchemaVersion};
 use crate::a2a::auth::A2AClient;
 use crate::a2a::client::A2ASession;
 use crate::a2a::protocol::{A2ATask, TaskStatus};
-use crate::admin::jwks::JwksManager;
+use crate::admin::jwks::{JwksManager, RsaKeypairRecord};
 use crate::admin::jwt::AdminJwtManager;
 use crate::admin::models::{
     AdminPermissions, AdminToken, AdminTokenUsage, CreateAdminTokenRequest, GeneratedAdminToken,
 };
 use crate::api_keys::{ApiKey, ApiKeyTier, ApiKeyUsage, ApiKeyUsageStats};
+use crate::config::data_retention::RetentionTable;
 use crate::config::environment::PostgresPoolConfig;
 use crate::config::fitness::FitnessConfig;
 use crate::constants::http_status::{BAD_REQUEST, SUCCESS_MAX, SUCCESS_MIN};
 use crate::constants::tiers;
 use crate::dashboard_routes::{RequestLog, ToolUsage};
+use crate::database::feature_flags::{decode_flag_type, decode_flag_value, encode_flag_value};
 use crate::database::{
     A2AUsage, A2AUsageStats, ConversationRecord, ConversationSummary, CreateUserMcpTokenRequest,
-    MessageRecord, UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
+    Granularity, MessageRecord, RollupBucket, UserMcpToken, UserMcpTokenCreated, UserMcpTokenInfo,
+};
+use crate::database_plugins::oauth_notification_listener::{
+    OAuthNotificationListener, OAUTH_NOTIFICATION_CHANNEL,
 };
 use crate::database_plugins::shared::encryption::HasEncryption;
+use crate::database_plugins::shared::transactions::PostgresTransactionGuard;
 use crate::errors::{AppError, AppResult};
+use crate::mcp::schema::OAuthCompletedNotification;
 use crate::models::OAuthNotification;
 use crate::models::{
-    AuthorizationCode, ConnectionType, OAuthApp, ProviderConnection, Tenant, TenantPlan,
-    TenantToolOverride, ToolCatalogEntry, ToolCategory, User, UserOAuthApp, UserOAuthToken,
-    UserStatus, UserTier,
+    Activity, ActivityMergeKind, ActivitySyncRecord, AuthSession, AuthorizationCode,
+    ConnectionType, DuplicateActivityMerge, FeatureFlag, OAuthApp, ProviderConnection,
+    ReanalysisCheckpoint, StravaAthleteLink, StravaWebhookSubscription, Tenant,
+    TenantDailyToolUsage, TenantFeatureFlag, TenantPlan, TenantToolOverride, ToolCallCounts,
+    ToolCatalogEntry, ToolCategory, User, UserOAuthApp, UserOAuthToken, UserStatus, UserTier,
 };
 use crate::oauth2_client::OAuthClientState;
-use crate::oauth2_server::models::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
+use crate::oauth2_server::models::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
 use crate::permissions::impersonation::ImpersonationSession;
 use crate::permissions::UserRole;
-use crate::rate_limiting::JwtUsage;
-use crate::security::audit::{AuditEvent, AuditEventType, AuditSeverity};
+use crate::providers::backfill::BackfillMarker;
+use crate::rate_limiting::{JwtUsage, RateLimitMode};
+use crate::security::audit::{AuditEvent, AuditEventFilter};
 use crate::security::key_rotation::KeyVersion;
 use crate::tenant::llm_manager::{LlmCredentialRecord, LlmCredentialSummary};
+use crate::tenant::webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookEventType,
+};
 use crate::tenant::TenantOAuthCredentials;
 use crate::utils::uuid::parse_uuid;
 use async_trait::async_trait;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine as Base64Engine;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, SubsecRound, Utc};
 use pierre_core::models::TenantId;
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 use sqlx::postgres::{PgPoolOptions, PgRow};
-use sqlx::{Pool, Postgres, Row};
-use std::collections::HashMap;
+use sqlx::{Executor, Pool, Postgres, Row};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
@@ -75,6 +92,31 @@ type ToolCatalogSeedEntry<'a> = (
     &'a str,
 );
 
+/// Catalog timeout overrides (seconds) for tools that routinely outlast the default,
+/// matching the `tool_timeouts` `SQLite` migration
+const LONG_RUNNING_TOOL_TIMEOUTS: [(&str, i32); 6] = [
+    ("analyze_performance_trends", 180),
+    ("analyze_training_load", 180),
+    ("get_training_load_trend", 180),
+    ("calculate_fitness_score", 180),
+    ("detect_patterns", 180),
+    ("export_user_data", 300),
+];
+
+/// Type alias for tenant OAuth credentials rows
+/// Fields: (provider, `client_id`, `client_secret_encrypted`, `pending_client_secret_encrypted`,
+/// `previous_secret_expires_at`, `redirect_uri`, scopes, `rate_limit_per_day`)
+type TenantOAuthCredentialsRow = (
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<DateTime<Utc>>,
+    String,
+    Vec<String>,
+    i32,
+);
+
 /// `PostgreSQL` database implementation
 #[derive(Clone)]
 pub struct PostgresDatabase {
@@ -88,6 +130,18 @@ impl PostgresDatabase {
         self.pool.close().await;
     }
 
+    /// Start pushing newly stored OAuth notifications to `sender`
+    ///
+    /// The listener runs until the runtime shuts down, reconnecting with backoff
+    /// whenever its `LISTEN` connection drops.
+    #[must_use]
+    pub fn spawn_oauth_notification_listener(
+        &self,
+        sender: broadcast::Sender<OAuthCompletedNotification>,
+    ) -> JoinHandle<()> {
+        OAuthNotificationListener::new(self.pool.clone(), sender).spawn()
+    }
+
     /// Update the encryption key used for token encryption/decryption
     ///
     /// This is called after the actual DEK is loaded from the database during
@@ -182,6 +236,9 @@ impl PostgresDatabase {
             requires_provider: row.get("requires_provider"),
             min_plan: TenantPlan::parse_str(&min_plan_str)
                 .ok_or_else(|| AppError::internal(format!("Invalid min_plan: {min_plan_str}")))?,
+            timeout_secs: row
+                .get::<Option<i32>, _>("timeout_secs")
+                .and_then(|secs| u64::try_from(secs).ok()),
             created_at,
             updated_at,
         })
@@ -206,6 +263,48 @@ impl PostgresDatabase {
             updated_at,
         }
     }
+
+    /// Build tenant OAuth credentials from a row, decrypting the active and previous secrets
+    fn tenant_oauth_credentials_from_row(
+        &self,
+        tenant_id: TenantId,
+        row: TenantOAuthCredentialsRow,
+    ) -> AppResult<TenantOAuthCredentials> {
+        let (
+            provider,
+            client_id,
+            encrypted_secret,
+            pending_secret,
+            previous_expires_at,
+            redirect_uri,
+            scopes,
+            rate_limit,
+        ) = row;
+
+        // Decrypt the client secrets using AAD binding
+        // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
+        let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+        let (client_secret, previous_client_secret) =
+            shared::encryption::decrypt_tenant_oauth_secrets(
+                self,
+                tenant_id,
+                &aad_context,
+                &encrypted_secret,
+                pending_secret.as_deref(),
+                previous_expires_at,
+            )?;
+
+        Ok(TenantOAuthCredentials {
+            tenant_id,
+            provider,
+            client_id,
+            client_secret,
+            previous_client_secret,
+            redirect_uri,
+            scopes,
+            rate_limit_per_day: u32::try_from(rate_limit).unwrap_or(0),
+        })
+    }
 }
 
 impl PostgresDatabase {
@@ -343,6 +442,22 @@ impl DatabaseProvider for PostgresDatabase {
         Self::new_impl(database_url, encryption_key, &pool_config).await
     }
 
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats::from_pool_counts(
+            self.pool.options().get_max_connections(),
+            self.pool.size(),
+            self.pool.num_idle(),
+        )
+    }
+
+    async fn schema_version(&self) -> AppResult<SchemaVersion> {
+        // Tables are created with IF NOT EXISTS on every start, so there is no version to compare
+        Ok(SchemaVersion {
+            applied: None,
+            supported: None,
+        })
+    }
+
     async fn migrate(&self) -> AppResult<()> {
         self.create_users_table().await?;
         self.create_user_profiles_table().await?;
@@ -356,7 +471,17 @@ impl DatabaseProvider for PostgresDatabase {
         self.create_rsa_keypairs_table().await?;
         self.create_tenant_tables().await?;
         self.create_tool_selection_tables().await?;
+        self.create_feature_flag_tables().await?;
+        self.create_strava_webhook_tables().await?;
+        self.create_reanalysis_tables().await?;
         self.create_chat_tables().await?;
+        self.create_webhook_tables().await?;
+        self.create_backfill_tables().await?;
+        self.create_auth_session_tables().await?;
+        self.create_imported_activity_tables().await?;
+        self.create_activity_sync_tables().await?;
+        self.create_duplicate_merge_tables().await?;
+        self.create_audit_event_tables().await?;
         self.create_indexes().await?;
         Ok(())
     }
@@ -398,7 +523,7 @@ impl DatabaseProvider for PostgresDatabase {
                    role, user_status, approved_by, approved_at, created_at, last_active,
                    firebase_uid, auth_provider
             FROM users
-            WHERE id = $1 AND tenant_id = $2
+            WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL
             ",
         )
         .bind(user_id)
@@ -455,7 +580,7 @@ impl DatabaseProvider for PostgresDatabase {
                    role, user_status, approved_by, approved_at, created_at, last_active,
                    firebase_uid, auth_provider
             FROM users
-            WHERE id = $1
+            WHERE id = $1 AND deleted_at IS NULL
             ",
         )
         .bind(user_id)
@@ -511,7 +636,7 @@ impl DatabaseProvider for PostgresDatabase {
                    role, user_status, approved_by, approved_at, created_at, last_active,
                    firebase_uid, auth_provider
             FROM users
-            WHERE email = $1
+            WHERE email = $1 AND deleted_at IS NULL
             ",
         )
         .bind(email)
@@ -573,7 +698,7 @@ impl DatabaseProvider for PostgresDatabase {
                    role, user_status, approved_by, approved_at, created_at, last_active,
                    firebase_uid, auth_provider
             FROM users
-            WHERE is_admin = true
+            WHERE is_admin = true AND deleted_at IS NULL
             ORDER BY created_at ASC
             LIMIT 1
             ",
@@ -630,7 +755,7 @@ impl DatabaseProvider for PostgresDatabase {
                    role, user_status, approved_by, approved_at, created_at, last_active,
                    firebase_uid, auth_provider
             FROM users
-            WHERE firebase_uid = $1
+            WHERE firebase_uid = $1 AND deleted_at IS NULL
             ",
         )
         .bind(firebase_uid)
@@ -696,7 +821,7 @@ impl DatabaseProvider for PostgresDatabase {
     }
 
     async fn get_user_count(&self) -> AppResult<i64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM users")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM users WHERE deleted_at IS NULL")
             .fetch_one(&self.pool)
             .await
             .map_err(|e| AppError::database(format!("Failed to get user count: {e}")))?;
@@ -729,6 +854,7 @@ impl DatabaseProvider for PostgresDatabase {
                        created_at, last_active, firebase_uid, auth_provider
                 FROM users
                 WHERE COALESCE(user_status, 'active') = $1 AND tenant_id = $2
+                  AND deleted_at IS NULL
                 ORDER BY created_at DESC
                 ",
             )
@@ -743,7 +869,7 @@ impl DatabaseProvider for PostgresDatabase {
                        role, COALESCE(user_status, 'active') as user_status, approved_by, approved_at,
                        created_at, last_active, firebase_uid, auth_provider
                 FROM users
-                WHERE COALESCE(user_status, 'active') = $1
+                WHERE COALESCE(user_status, 'active') = $1 AND deleted_at IS NULL
                 ORDER BY created_at DESC
                 ",
             )
@@ -808,7 +934,7 @@ impl DatabaseProvider for PostgresDatabase {
                    COALESCE(user_status, 'active') as user_status, approved_by, approved_at,
                    created_at, last_active, firebase_uid, auth_provider
             FROM users
-            WHERE COALESCE(user_status, 'active') = $1
+            WHERE COALESCE(user_status, 'active') = $1 AND deleted_at IS NULL
               AND (created_at < $2 OR (created_at = $2 AND id::text < $3))
             ORDER BY created_at DESC, id DESC
             LIMIT $4
@@ -818,7 +944,7 @@ impl DatabaseProvider for PostgresDatabase {
             SELECT id, email, display_name, password_hash, tier, tenant_id, is_active, is_admin,
                    COALESCE(user_status, 'active') as user_status, approved_by, approved_at, created_at, last_active
             FROM users
-            WHERE COALESCE(user_status, 'active') = $1
+            WHERE COALESCE(user_status, 'active') = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC, id DESC
             LIMIT $2
         ";
@@ -1024,6 +1150,68 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
+    async fn soft_delete_user(&self, user_id: Uuid, deleted_at: DateTime<Utc>) -> AppResult<()> {
+        let result = sqlx::query(
+            r"
+            UPDATE users SET deleted_at = $1
+            WHERE id = $2 AND deleted_at IS NULL
+            ",
+        )
+        .bind(deleted_at)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to soft delete user: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("User {user_id} not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn restore_deleted_user(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> AppResult<User> {
+        let result = sqlx::query(
+            r"
+            UPDATE users SET deleted_at = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL AND deleted_at >= $2
+            ",
+        )
+        .bind(user_id)
+        .bind(deleted_since)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to restore user: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!(
+                "Recoverable deleted user {user_id}"
+            )));
+        }
+
+        self.get_user_global(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("User {user_id}")))
+    }
+
+    async fn purge_deleted_users(&self, deleted_before: DateTime<Utc>) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM users WHERE deleted_at IS NOT NULL AND deleted_at < $1
+            ",
+        )
+        .bind(deleted_before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to purge deleted users: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn upsert_user_profile(&self, user_id: Uuid, profile_data: Value) -> AppResult<()> {
         let now = chrono::Utc::now().to_rfc3339();
         sqlx::query(
@@ -1084,7 +1272,7 @@ impl DatabaseProvider for PostgresDatabase {
     async fn get_user_goals(&self, user_id: Uuid) -> AppResult<Vec<Value>> {
         let rows = sqlx::query(
             r"
-            SELECT goal_data
+            SELECT id, goal_data
             FROM goals
             WHERE user_id = $1
             ORDER BY created_at DESC
@@ -1095,7 +1283,20 @@ impl DatabaseProvider for PostgresDatabase {
         .await
         .map_err(|e| AppError::database(format!("Failed to get user goals: {e}")))?;
 
-        Ok(rows.into_iter().map(|row| row.get("goal_data")).collect())
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let mut goal: Value = row.get("goal_data");
+                // Match SQLite, which returns the goal ID inside the goal object
+                if let Value::Object(ref mut map) = goal {
+                    map.insert(
+                        "id".into(),
+                        Value::String(row.get::<Uuid, _>("id").to_string()),
+                    );
+                }
+                goal
+            })
+            .collect())
     }
 
     async fn update_goal_progress(
@@ -1125,6 +1326,38 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
+    async fn update_goal_data(
+        &self,
+        goal_id: &str,
+        user_id: Uuid,
+        mut goal_data: Value,
+    ) -> AppResult<()> {
+        let goal_uuid =
+            parse_uuid(goal_id).map_err(|_| AppError::not_found(format!("Goal {goal_id}")))?;
+        if let Some(obj) = goal_data.as_object_mut() {
+            obj.remove("id");
+        }
+
+        let result = sqlx::query(
+            r"
+            UPDATE goals
+            SET goal_data = $1, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $2 AND user_id = $3
+            ",
+        )
+        .bind(&goal_data)
+        .bind(goal_uuid)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update goal: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Goal {goal_id}")));
+        }
+        Ok(())
+    }
+
     async fn get_user_configuration(&self, user_id: &str) -> AppResult<Option<String>> {
         // First ensure the user_configurations table exists
         sqlx::query(
@@ -1200,27 +1433,32 @@ impl DatabaseProvider for PostgresDatabase {
     }
 
     async fn store_insight(&self, user_id: Uuid, insight_data: Value) -> AppResult<String> {
-        let insight_id = Uuid::new_v4().to_string();
-        let now = chrono::Utc::now().to_rfc3339();
-        let insight_json = serde_json::to_string(&insight_data)
-            .map_err(|e| AppError::database(format!("Failed to serialize insight: {e}")))?;
+        upsert_insight(&self.pool, user_id, &insight_data).await
+    }
 
-        sqlx::query(
-            r"
-            INSERT INTO insights (id, user_id, insight_type, insight_data, created_at)
-            VALUES ($1, $2, $3, $4, $5)
-            ",
-        )
-        .bind(&insight_id)
-        .bind(user_id)
-        .bind("general") // Default insight type since it's not provided separately
-        .bind(&insight_json)
-        .bind(&now)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| AppError::database(format!("Failed to store insight: {e}")))?;
+    async fn store_insights_batch(
+        &self,
+        user_id: Uuid,
+        insights: Vec<Value>,
+    ) -> AppResult<Vec<String>> {
+        if insights.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+        let mut guard = PostgresTransactionGuard::new(tx);
+
+        let mut insight_ids = Vec::with_capacity(insights.len());
+        for insight_data in &insights {
+            insight_ids.push(upsert_insight(guard.executor()?, user_id, insight_data).await?);
+        }
 
-        Ok(insight_id)
+        guard.commit().await?;
+        Ok(insight_ids)
     }
 
     async fn get_user_insights(
@@ -1264,32 +1502,32 @@ impl DatabaseProvider for PostgresDatabase {
             .map_err(|e| AppError::database(format!("Failed to get user insights: {e}")))?
         };
 
-        Ok(rows.into_iter().map(|row| row.get("content")).collect())
+        // Rows stored before content hashing may repeat; keep the most recent copy
+        let mut seen = HashSet::new();
+        Ok(rows
+            .into_iter()
+            .map(|row| row.get::<Value, _>("content"))
+            .filter(|insight| seen.insert(shared::insights::canonicalize(insight).to_string()))
+            .collect())
     }
 
     async fn create_api_key(&self, api_key: &ApiKey) -> AppResult<()> {
-        sqlx::query(
-            r"
-            INSERT INTO api_keys (id, user_id, name, key_prefix, key_hash, description, tier, is_active, rate_limit_requests, rate_limit_window_seconds, expires_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            ",
-        )
-        .bind(&api_key.id)
-        .bind(api_key.user_id)
-        .bind(&api_key.name)
-        .bind(&api_key.key_prefix)
-        .bind(&api_key.key_hash)
-        .bind(&api_key.description)
-        .bind(format!("{:?}", api_key.tier).to_lowercase())
-        .bind(api_key.is_active)
-        .bind(i32::try_from(api_key.rate_limit_requests).unwrap_or(i32::MAX))
-        .bind(i32::try_from(api_key.rate_limit_window_seconds).unwrap_or(i32::MAX))
-        .bind(api_key.expires_at)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| AppError::database(format!("Failed to create API key: {e}")))?;
+        insert_api_key(&self.pool, api_key).await
+    }
 
-        Ok(())
+    async fn create_api_keys_batch(&self, api_keys: &[ApiKey]) -> AppResult<()> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+        let mut guard = PostgresTransactionGuard::new(tx);
+
+        for api_key in api_keys {
+            insert_api_key(guard.executor()?, api_key).await?;
+        }
+
+        guard.commit().await
     }
 
     async fn get_api_key_by_prefix(&self, prefix: &str, hash: &str) -> AppResult<Option<ApiKey>> {
@@ -1684,6 +1922,25 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(u32::try_from(row.get::<i64, _>("count").max(0)).unwrap_or(0))
     }
 
+    async fn get_api_key_usage_times(
+        &self,
+        api_key_id: &str,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r"
+            SELECT timestamp FROM api_key_usage
+            WHERE api_key_id = $1 AND timestamp >= $2
+            ORDER BY timestamp ASC
+            ",
+        )
+        .bind(api_key_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get API key usage times: {e}")))
+    }
+
     async fn get_api_key_usage_stats(
         &self,
         api_key_id: &str,
@@ -1817,6 +2074,25 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(u32::try_from(row.get::<i64, _>("count").max(0)).unwrap_or(0))
     }
 
+    async fn get_jwt_usage_times(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r"
+            SELECT timestamp FROM jwt_usage
+            WHERE user_id = $1 AND timestamp >= $2
+            ORDER BY timestamp ASC
+            ",
+        )
+        .bind(user_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get JWT usage times: {e}")))
+    }
+
     async fn get_request_logs(
         &self,
         user_id: Option<Uuid>,
@@ -2794,6 +3070,184 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
+    async fn get_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        started_after: DateTime<Utc>,
+    ) -> AppResult<Vec<ActivitySyncRecord>> {
+        let rows = sqlx::query(
+            r"
+            SELECT activity_id, start_date, content_hash, updated_at, deleted_at
+            FROM activity_sync_records
+            WHERE user_id = $1 AND tenant_id = $2 AND provider = $3 AND start_date >= $4
+            ORDER BY start_date DESC
+            ",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .bind(provider)
+        .bind(started_after)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list activity sync records: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ActivitySyncRecord {
+                activity_id: row.get("activity_id"),
+                start_date: row.get("start_date"),
+                content_hash: row.get("content_hash"),
+                updated_at: row.get("updated_at"),
+                deleted_at: row.get("deleted_at"),
+            })
+            .collect())
+    }
+
+    async fn upsert_activity_sync_records(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        records: &[ActivitySyncRecord],
+    ) -> AppResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        for record in records {
+            sqlx::query(
+                r"
+                INSERT INTO activity_sync_records
+                    (user_id, tenant_id, provider, activity_id, start_date, content_hash, updated_at, deleted_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (user_id, tenant_id, provider, activity_id) DO UPDATE SET
+                    start_date = EXCLUDED.start_date,
+                    content_hash = EXCLUDED.content_hash,
+                    updated_at = EXCLUDED.updated_at,
+                    deleted_at = EXCLUDED.deleted_at
+                ",
+            )
+            .bind(user_id)
+            .bind(tenant_id.0)
+            .bind(provider)
+            .bind(&record.activity_id)
+            .bind(record.start_date)
+            .bind(&record.content_hash)
+            .bind(record.updated_at)
+            .bind(record.deleted_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to store activity sync record: {e}"))
+            })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to commit activity sync records: {e}")))
+    }
+
+    async fn create_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        merge: &DuplicateActivityMerge,
+    ) -> AppResult<()> {
+        let snapshot = serde_json::to_value(&merge.secondary)?;
+        let filled_fields = serde_json::to_value(&merge.filled_fields)?;
+
+        sqlx::query(
+            r"
+            INSERT INTO duplicate_activity_merges
+                (user_id, tenant_id, secondary_provider, secondary_id, primary_provider, primary_id,
+                 secondary_snapshot, filled_fields, merged_at, merge_kind)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .bind(&merge.secondary_provider)
+        .bind(&merge.secondary_id)
+        .bind(&merge.primary_provider)
+        .bind(&merge.primary_id)
+        .bind(snapshot)
+        .bind(filled_fields)
+        .bind(merge.merged_at)
+        .bind(merge.kind.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store duplicate merge: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn list_duplicate_merges(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<DuplicateActivityMerge>> {
+        let rows = sqlx::query(
+            r"
+            SELECT secondary_provider, secondary_id, primary_provider, primary_id,
+                   secondary_snapshot, filled_fields, merged_at, merge_kind
+            FROM duplicate_activity_merges
+            WHERE user_id = $1 AND tenant_id = $2
+            ORDER BY merged_at ASC
+            ",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list duplicate merges: {e}")))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let snapshot: Value = row.get("secondary_snapshot");
+                let filled_fields: Value = row.get("filled_fields");
+                let kind: String = row.get("merge_kind");
+                Ok(DuplicateActivityMerge {
+                    kind: kind.parse::<ActivityMergeKind>()?,
+                    primary_provider: row.get("primary_provider"),
+                    primary_id: row.get("primary_id"),
+                    secondary_provider: row.get("secondary_provider"),
+                    secondary_id: row.get("secondary_id"),
+                    secondary: serde_json::from_value(snapshot)?,
+                    filled_fields: serde_json::from_value(filled_fields)?,
+                    merged_at: row.get("merged_at"),
+                })
+            })
+            .collect()
+    }
+
+    async fn delete_duplicate_merge(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        secondary_provider: &str,
+        secondary_id: &str,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            DELETE FROM duplicate_activity_merges
+            WHERE user_id = $1 AND tenant_id = $2 AND secondary_provider = $3 AND secondary_id = $4
+            ",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .bind(secondary_provider)
+        .bind(secondary_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to delete duplicate merge: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn get_top_tools_analysis(
         &self,
         user_id: Uuid,
@@ -2862,14 +3316,207 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(tool_usage)
     }
 
-    // ================================
-    // Admin Token Management (PostgreSQL)
-    // ================================
-
-    async fn create_admin_token(
+    async fn get_tenant_tool_usage(
         &self,
-        request: &CreateAdminTokenRequest,
-        admin_jwt_secret: &str,
+        tenant_id: TenantId,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> AppResult<Vec<ToolUsage>> {
+        let rows = sqlx::query(
+            r"
+            SELECT aku.tool_name, COUNT(*) as usage_count,
+                   AVG(aku.response_time_ms)::FLOAT8 as avg_response_time,
+                   COUNT(CASE WHEN aku.status_code < 400 THEN 1 END) as success_count
+            FROM api_key_usage aku
+            JOIN api_keys ak ON aku.api_key_id = ak.id
+            JOIN tenant_users tu ON tu.user_id = ak.user_id
+            WHERE tu.tenant_id = $1 AND aku.timestamp BETWEEN $2 AND $3
+            GROUP BY aku.tool_name
+            ORDER BY usage_count DESC, aku.tool_name
+            ",
+        )
+        .bind(tenant_id.0)
+        .bind(start_time)
+        .bind(end_time)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant tool usage: {e}")))?;
+
+        let mut tool_usage = Vec::with_capacity(rows.len());
+        for row in rows {
+            use sqlx::Row;
+
+            let tool_name: String = row
+                .try_get("tool_name")
+                .unwrap_or_else(|_| "unknown".into());
+            let usage_count: i64 = row.try_get("usage_count").unwrap_or(0);
+            let avg_response_time: Option<f64> = row.try_get("avg_response_time").ok().flatten();
+            let success_count: i64 = row.try_get("success_count").unwrap_or(0);
+
+            tool_usage.push(ToolUsage {
+                tool_name,
+                request_count: u64::try_from(usage_count.max(0)).unwrap_or(0),
+                success_rate: if usage_count > 0 {
+                    f64::from(u32::try_from(success_count.max(0)).unwrap_or(0))
+                        / f64::from(u32::try_from(usage_count.max(1)).unwrap_or(1))
+                        * 100.0
+                } else {
+                    0.0
+                },
+                average_response_time: avg_response_time.unwrap_or(0.0),
+            });
+        }
+
+        Ok(tool_usage)
+    }
+
+    async fn get_tenant_daily_tool_usage(
+        &self,
+        tenant_id: TenantId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<TenantDailyToolUsage>> {
+        let rows = sqlx::query(
+            r"
+            WITH members AS (
+                SELECT user_id FROM tenant_users WHERE tenant_id = $1
+            ),
+            calls AS (
+                SELECT (aku.timestamp AT TIME ZONE 'UTC')::date AS usage_date,
+                       aku.endpoint AS tool_name, 'api_key' AS source
+                FROM api_key_usage aku
+                JOIN api_keys ak ON aku.api_key_id = ak.id
+                WHERE ak.user_id IN (SELECT user_id FROM members)
+                  AND aku.timestamp >= $2 AND aku.timestamp < $3
+                UNION ALL
+                SELECT (au.timestamp AT TIME ZONE 'UTC')::date, au.endpoint, 'a2a'
+                FROM a2a_usage au
+                JOIN a2a_clients ac ON au.client_id = ac.client_id
+                WHERE ac.user_id IN (SELECT user_id FROM members)
+                  AND au.timestamp >= $2 AND au.timestamp < $3
+                UNION ALL
+                SELECT (ju.timestamp AT TIME ZONE 'UTC')::date, ju.endpoint, 'jwt'
+                FROM jwt_usage ju
+                WHERE ju.user_id IN (SELECT user_id FROM members)
+                  AND ju.timestamp >= $2 AND ju.timestamp < $3
+            )
+            SELECT
+                usage_date,
+                tool_name,
+                COUNT(*) FILTER (WHERE source = 'api_key') AS api_key_calls,
+                COUNT(*) FILTER (WHERE source = 'a2a') AS a2a_calls,
+                COUNT(*) FILTER (WHERE source = 'jwt') AS jwt_calls
+            FROM calls
+            GROUP BY usage_date, tool_name
+            ORDER BY usage_date, tool_name
+            ",
+        )
+        .bind(tenant_id.0)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant daily tool usage: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let count = |column: &str| u64::try_from(row.get::<i64, _>(column)).unwrap_or(0);
+                TenantDailyToolUsage {
+                    date: row.get("usage_date"),
+                    tool_name: row.get("tool_name"),
+                    calls: ToolCallCounts {
+                        api_key: count("api_key_calls"),
+                        a2a: count("a2a_calls"),
+                        jwt: count("jwt_calls"),
+                    },
+                }
+            })
+            .collect())
+    }
+    async fn get_activity_rollups(
+        &self,
+        user_id: Uuid,
+        granularity: Granularity,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<RollupBucket>> {
+        if end <= start {
+            return Err(AppError::invalid_input(
+                "Rollup range end must be after its start",
+            ));
+        }
+
+        let unit = granularity.as_str();
+        let query = format!(
+            r"
+            WITH buckets AS (
+                SELECT generate_series(
+                    date_trunc('{unit}', $2 AT TIME ZONE 'UTC'),
+                    $3 AT TIME ZONE 'UTC' - INTERVAL '1 microsecond',
+                    INTERVAL '1 {unit}'
+                ) AS bucket_start
+            ),
+            totals AS (
+                SELECT date_trunc('{unit}', start_date::TIMESTAMPTZ AT TIME ZONE 'UTC') AS bucket_start,
+                       COUNT(*) AS activity_count,
+                       COALESCE(SUM(distance_meters), 0)::FLOAT8 AS total_distance,
+                       COALESCE(SUM(duration_seconds), 0)::BIGINT AS total_duration,
+                       COALESCE(SUM(elevation_gain), 0)::FLOAT8 AS total_elevation,
+                       AVG(average_heart_rate)::FLOAT8 AS average_heart_rate
+                FROM synthetic_activities
+                WHERE user_id = $1
+                  AND start_date::TIMESTAMPTZ >= $2
+                  AND start_date::TIMESTAMPTZ < $3
+                GROUP BY 1
+            )
+            SELECT b.bucket_start,
+                   COALESCE(t.activity_count, 0) AS activity_count,
+                   COALESCE(t.total_distance, 0) AS total_distance,
+                   COALESCE(t.total_duration, 0) AS total_duration,
+                   COALESCE(t.total_elevation, 0) AS total_elevation,
+                   t.average_heart_rate
+            FROM buckets b
+            LEFT JOIN totals t ON t.bucket_start = b.bucket_start
+            ORDER BY b.bucket_start
+            "
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(user_id)
+            .bind(start)
+            .bind(end)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to get activity rollups: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let bucket_start: NaiveDateTime = row.get("bucket_start");
+                let activity_count: i64 = row.get("activity_count");
+                let total_duration: i64 = row.get("total_duration");
+
+                RollupBucket {
+                    bucket_start: bucket_start.and_utc(),
+                    activity_count: u64::try_from(activity_count).unwrap_or(0),
+                    total_distance_meters: row.get("total_distance"),
+                    total_duration_seconds: u64::try_from(total_duration).unwrap_or(0),
+                    total_elevation_gain_meters: row.get("total_elevation"),
+                    average_heart_rate: row.get("average_heart_rate"),
+                }
+            })
+            .collect())
+    }
+
+    // ================================
+    // Admin Token Management (PostgreSQL)
+    // ================================
+
+    async fn create_admin_token(
+        &self,
+        request: &CreateAdminTokenRequest,
+        admin_jwt_secret: &str,
         jwks_manager: &JwksManager,
     ) -> AppResult<GeneratedAdminToken> {
         use uuid::Uuid;
@@ -3288,11 +3935,9 @@ impl DatabaseProvider for PostgresDatabase {
     }
 
     /// Load all RSA keypairs from database
-    async fn load_rsa_keypairs(
-        &self,
-    ) -> AppResult<Vec<(String, String, String, DateTime<Utc>, bool)>> {
+    async fn load_rsa_keypairs(&self) -> AppResult<Vec<RsaKeypairRecord>> {
         let rows = sqlx::query(
-            "SELECT kid, private_key_pem, public_key_pem, created_at, is_active FROM rsa_keypairs ORDER BY created_at DESC",
+            "SELECT kid, private_key_pem, public_key_pem, created_at, is_active, retires_at FROM rsa_keypairs ORDER BY created_at DESC",
         )
         .fetch_all(&self.pool).await.map_err(|e| AppError::database(format!("Failed to fetch records: {e}")))?;
 
@@ -3303,8 +3948,16 @@ impl DatabaseProvider for PostgresDatabase {
             let public_key_pem: String = row.get("public_key_pem");
             let created_at: DateTime<Utc> = row.get("created_at");
             let is_active: bool = row.get("is_active");
+            let retires_at: Option<DateTime<Utc>> = row.get("retires_at");
 
-            keypairs.push((kid, private_key_pem, public_key_pem, created_at, is_active));
+            keypairs.push((
+                kid,
+                private_key_pem,
+                public_key_pem,
+                created_at,
+                is_active,
+                retires_at,
+            ));
         }
 
         Ok(keypairs)
@@ -3322,6 +3975,29 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
+    /// Mark RSA keypair as retiring at the given time
+    async fn retire_rsa_keypair(&self, kid: &str, retires_at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query("UPDATE rsa_keypairs SET is_active = false, retires_at = $1 WHERE kid = $2")
+            .bind(retires_at)
+            .bind(kid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Delete RSA keypair
+    async fn delete_rsa_keypair(&self, kid: &str) -> AppResult<()> {
+        sqlx::query("DELETE FROM rsa_keypairs WHERE kid = $1")
+            .bind(kid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
     // ================================
     // Multi-Tenant Management
     // ================================
@@ -3478,6 +4154,38 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(tenants)
     }
 
+    async fn get_tenant_rate_limit_mode(&self, tenant_id: TenantId) -> AppResult<RateLimitMode> {
+        let mode: Option<String> =
+            sqlx::query_scalar("SELECT rate_limit_mode FROM tenants WHERE id = $1")
+                .bind(tenant_id.0)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Failed to fetch record: {e}")))?;
+
+        mode.ok_or_else(|| AppError::not_found(format!("Tenant {tenant_id}")))?
+            .parse()
+    }
+
+    async fn set_tenant_rate_limit_mode(
+        &self,
+        tenant_id: TenantId,
+        mode: RateLimitMode,
+    ) -> AppResult<()> {
+        let result = sqlx::query(
+            "UPDATE tenants SET rate_limit_mode = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+        )
+        .bind(mode.as_str())
+        .bind(tenant_id.0)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update record: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Tenant {tenant_id}")));
+        }
+        Ok(())
+    }
+
     /// Store tenant OAuth credentials
     async fn store_tenant_oauth_credentials(
         &self,
@@ -3489,8 +4197,12 @@ impl DatabaseProvider for PostgresDatabase {
             "{}|{}|tenant_oauth_credentials",
             credentials.tenant_id, credentials.provider
         );
-        let encrypted_secret =
-            HasEncryption::encrypt_data_with_aad(self, &credentials.client_secret, &aad_context)?;
+        let encrypted_secret = HasEncryption::encrypt_data_for_tenant(
+            self,
+            credentials.tenant_id,
+            &credentials.client_secret,
+            &aad_context,
+        )?;
 
         // Convert scopes Vec<String> to PostgreSQL array format
         let scopes_array: Vec<&str> = credentials.scopes.iter().map(String::as_str).collect();
@@ -3509,6 +4221,8 @@ impl DatabaseProvider for PostgresDatabase {
                 redirect_uri = EXCLUDED.redirect_uri,
                 scopes = EXCLUDED.scopes,
                 rate_limit_per_day = EXCLUDED.rate_limit_per_day,
+                pending_client_secret_encrypted = NULL,
+                previous_secret_expires_at = NULL,
                 updated_at = EXCLUDED.updated_at
             ",
         )
@@ -3532,9 +4246,10 @@ impl DatabaseProvider for PostgresDatabase {
         &self,
         tenant_id: TenantId,
     ) -> AppResult<Vec<TenantOAuthCredentials>> {
-        let rows = sqlx::query_as::<_, (String, String, String, String, Vec<String>, i32)>(
+        let rows = sqlx::query_as::<_, TenantOAuthCredentialsRow>(
             r"
             SELECT provider, client_id, client_secret_encrypted,
+                   pending_client_secret_encrypted, previous_secret_expires_at,
                    redirect_uri, scopes, rate_limit_per_day
             FROM tenant_oauth_credentials
             WHERE tenant_id = $1 AND is_active = true
@@ -3546,33 +4261,9 @@ impl DatabaseProvider for PostgresDatabase {
         .await
         .map_err(|e| AppError::database(format!("Failed to fetch records: {e}")))?;
 
-        let credentials = rows
-            .into_iter()
-            .map(
-                |(provider, client_id, encrypted_secret, redirect_uri, scopes, rate_limit)| {
-                    // Decrypt the client secret using AAD binding
-                    // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
-                    let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
-                    let client_secret = HasEncryption::decrypt_data_with_aad(
-                        self,
-                        &encrypted_secret,
-                        &aad_context,
-                    )?;
-
-                    Ok(TenantOAuthCredentials {
-                        tenant_id,
-                        provider,
-                        client_id,
-                        client_secret,
-                        redirect_uri,
-                        scopes,
-                        rate_limit_per_day: u32::try_from(rate_limit).unwrap_or(0),
-                    })
-                },
-            )
-            .collect::<AppResult<Vec<_>>>()?;
-
-        Ok(credentials)
+        rows.into_iter()
+            .map(|row| self.tenant_oauth_credentials_from_row(tenant_id, row))
+            .collect()
     }
 
     /// Get tenant OAuth credentials for specific provider
@@ -3581,9 +4272,10 @@ impl DatabaseProvider for PostgresDatabase {
         tenant_id: TenantId,
         provider: &str,
     ) -> AppResult<Option<TenantOAuthCredentials>> {
-        let row = sqlx::query_as::<_, (String, String, String, Vec<String>, i32)>(
+        let row = sqlx::query_as::<_, TenantOAuthCredentialsRow>(
             r"
-            SELECT client_id, client_secret_encrypted,
+            SELECT provider, client_id, client_secret_encrypted,
+                   pending_client_secret_encrypted, previous_secret_expires_at,
                    redirect_uri, scopes, rate_limit_per_day
             FROM tenant_oauth_credentials
             WHERE tenant_id = $1 AND provider = $2 AND is_active = true
@@ -3595,26 +4287,71 @@ impl DatabaseProvider for PostgresDatabase {
         .await
         .map_err(|e| AppError::database(format!("Failed to fetch optional record: {e}")))?;
 
-        match row {
-            Some((client_id, encrypted_secret, redirect_uri, scopes, rate_limit)) => {
-                // Decrypt the client secret using AAD binding
-                // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
-                let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
-                let client_secret =
-                    HasEncryption::decrypt_data_with_aad(self, &encrypted_secret, &aad_context)?;
-
-                Ok(Some(TenantOAuthCredentials {
-                    tenant_id,
-                    provider: provider.to_owned(),
-                    client_id,
-                    client_secret,
-                    redirect_uri,
-                    scopes,
-                    rate_limit_per_day: u32::try_from(rate_limit).unwrap_or(0),
-                }))
-            }
-            None => Ok(None),
+        row.map(|row| self.tenant_oauth_credentials_from_row(tenant_id, row))
+            .transpose()
+    }
+
+    /// Stage a new tenant OAuth client secret for rotation
+    async fn rotate_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        previous_expires_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        // AAD context format: "{tenant_id}|{provider}|tenant_oauth_credentials"
+        let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+        let encrypted_secret =
+            HasEncryption::encrypt_data_for_tenant(self, tenant_id, new_secret, &aad_context)?;
+
+        let result = sqlx::query(
+            r"
+            UPDATE tenant_oauth_credentials
+            SET pending_client_secret_encrypted = $1,
+                previous_secret_expires_at = $2,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE tenant_id = $3 AND provider = $4 AND is_active = true
+            ",
+        )
+        .bind(&encrypted_secret)
+        .bind(previous_expires_at)
+        .bind(tenant_id.0)
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to rotate OAuth client secret: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!(
+                "OAuth credentials for tenant {tenant_id} and provider {provider}"
+            )));
         }
+        Ok(())
+    }
+
+    /// Promote a pending tenant OAuth client secret and purge the previous one
+    async fn promote_tenant_oauth_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE tenant_oauth_credentials
+            SET client_secret_encrypted = pending_client_secret_encrypted,
+                pending_client_secret_encrypted = NULL,
+                previous_secret_expires_at = NULL,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE tenant_id = $1 AND provider = $2 AND pending_client_secret_encrypted IS NOT NULL
+            ",
+        )
+        .bind(tenant_id.0)
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to promote OAuth client secret: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
     }
 
     // ================================
@@ -4205,7 +4942,8 @@ impl DatabaseProvider for PostgresDatabase {
             .bind(&event.source_ip)
             .bind(&event.user_agent)
             .bind(&metadata_json)
-            .bind(event.timestamp)
+            // Millisecond precision matches pagination cursors exactly
+            .bind(event.timestamp.trunc_subsecs(3))
             .execute(&self.pool)
             .await
             .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
@@ -4213,12 +4951,10 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
-    /// Complex audit query with dynamic filtering, pagination, and exhaustive enum mapping
+    /// Complex audit query with dynamic filtering and pagination
     ///
     /// JUSTIFICATION for `#[allow(clippy::too_many_lines)]`:
     /// - Dynamic SQL query building with optional filters (`tenant_id`, `event_type`, `limit`)
-    /// - Exhaustive match for 25+ `AuditEventType` variants (cannot be extracted without loss of context)
-    /// - Exhaustive match for `AuditSeverity` variants
     /// - Row-to-struct mapping with UUID parsing and JSON deserialization
     /// - Refactoring would fragment audit event construction logic across multiple functions
     #[allow(clippy::too_many_lines)]
@@ -4232,7 +4968,8 @@ impl DatabaseProvider for PostgresDatabase {
 
         let mut query = r"
             SELECT id, event_type, severity, message, source, result,
-                   tenant_id, user_id, ip_address, user_agent, metadata, timestamp
+                   tenant_id, user_id, host(ip_address) AS ip_address, user_agent, metadata,
+                   timestamp
             FROM audit_events
             WHERE true
         "
@@ -4284,101 +5021,154 @@ impl DatabaseProvider for PostgresDatabase {
             .await
             .map_err(|e| AppError::database(format!("Failed to get audit events: {e}")))?;
 
-        let mut events = Vec::new();
-        for row in rows {
-            let event_id_str: String = row.get("id");
-            let event_id = uuid::Uuid::parse_str(&event_id_str)
-                .map_err(|e| AppError::database(format!("Invalid audit event UUID: {e}")))?;
-
-            let event_type_str: String = row.get("event_type");
-            let event_type = match event_type_str.as_str() {
-                "UserLogin" => AuditEventType::UserLogin,
-                "UserLogout" => AuditEventType::UserLogout,
-                "AuthenticationFailed" => AuditEventType::AuthenticationFailed,
-                "ApiKeyUsed" => AuditEventType::ApiKeyUsed,
-                "OAuthCredentialsAccessed" => AuditEventType::OAuthCredentialsAccessed,
-                "OAuthCredentialsModified" => AuditEventType::OAuthCredentialsModified,
-                "OAuthCredentialsCreated" => AuditEventType::OAuthCredentialsCreated,
-                "OAuthCredentialsDeleted" => AuditEventType::OAuthCredentialsDeleted,
-                "TokenRefreshed" => AuditEventType::TokenRefreshed,
-                "TenantCreated" => AuditEventType::TenantCreated,
-                "TenantModified" => AuditEventType::TenantModified,
-                "TenantDeleted" => AuditEventType::TenantDeleted,
-                "TenantUserAdded" => AuditEventType::TenantUserAdded,
-                "TenantUserRemoved" => AuditEventType::TenantUserRemoved,
-                "TenantUserRoleChanged" => AuditEventType::TenantUserRoleChanged,
-                "DataEncrypted" => AuditEventType::DataEncrypted,
-                "DataDecrypted" => AuditEventType::DataDecrypted,
-                "KeyRotated" => AuditEventType::KeyRotated,
-                "EncryptionFailed" => AuditEventType::EncryptionFailed,
-                "ToolExecutionFailed" => AuditEventType::ToolExecutionFailed,
-                "ProviderApiCalled" => AuditEventType::ProviderApiCalled,
-                "ConfigurationChanged" => AuditEventType::ConfigurationChanged,
-                "SystemMaintenance" => AuditEventType::SystemMaintenance,
-                "SecurityPolicyViolation" => AuditEventType::SecurityPolicyViolation,
-                _ => AuditEventType::ToolExecuted, // Default fallback
-            };
-
-            let severity_str: String = row.get("severity");
-            let severity = match severity_str.as_str() {
-                "Warning" => AuditSeverity::Warning,
-                "Error" => AuditSeverity::Error,
-                "Critical" => AuditSeverity::Critical,
-                _ => AuditSeverity::Info, // Default fallback
-            };
+        rows.iter().map(Self::row_to_audit_event).collect()
+    }
 
-            let tenant_id_str: Option<String> = row.get("tenant_id");
-            let tenant_id = if let Some(tid) = tenant_id_str {
-                Some(TenantId::from_uuid(parse_uuid(&tid)?))
-            } else {
-                None
-            };
+    async fn get_audit_events_page(
+        &self,
+        filter: &AuditEventFilter,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<AuditEvent>> {
+        // Fetch one extra row to find out whether another page follows
+        let fetch_limit = i64::try_from(params.limit + 1)
+            .map_err(|_| AppError::invalid_input("Pagination limit too large"))?;
+        let position = params
+            .cursor
+            .as_ref()
+            .map(|cursor| {
+                cursor
+                    .decode()
+                    .ok_or_else(|| AppError::invalid_input("Invalid cursor format"))
+            })
+            .transpose()?;
 
-            let user_id_str: Option<String> = row.get("user_id");
-            let user_id = if let Some(uid) = user_id_str {
-                Some(parse_uuid(&uid)?)
-            } else {
-                None
-            };
+        let mut conditions = Vec::new();
+        let mut bind_count = 0;
+        let mut next_param = || {
+            bind_count += 1;
+            format!("${bind_count}")
+        };
+        if filter.tenant_id.is_some() {
+            conditions.push(format!("tenant_id = {}", next_param()));
+        }
+        if filter.user_id.is_some() {
+            conditions.push(format!("user_id = {}", next_param()));
+        }
+        if filter.event_type.is_some() {
+            conditions.push(format!("event_type = {}", next_param()));
+        }
+        if filter.severity.is_some() {
+            conditions.push(format!("severity = {}", next_param()));
+        }
+        if filter.start.is_some() {
+            conditions.push(format!("timestamp >= {}", next_param()));
+        }
+        if filter.end.is_some() {
+            conditions.push(format!("timestamp < {}", next_param()));
+        }
+        if position.is_some() {
+            // Row-value comparison seeks directly into the (timestamp, id) index
+            let (timestamp_param, id_param) = (next_param(), next_param());
+            conditions.push(format!("(timestamp, id) < ({timestamp_param}, {id_param})"));
+        }
+        let limit_param = next_param();
 
-            let metadata_json: String = row.get("metadata");
-            let metadata: serde_json::Value = serde_json::from_str(&metadata_json)
-                .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let query = format!(
+            r"
+            SELECT id, event_type, severity, message, result,
+                   tenant_id, user_id, host(ip_address) AS ip_address, user_agent, metadata,
+                   timestamp
+            FROM audit_events
+            {where_clause}
+            ORDER BY timestamp DESC, id DESC
+            LIMIT {limit_param}
+            "
+        );
 
-            let event = AuditEvent {
-                event_id,
-                event_type,
-                severity,
-                timestamp: row.get("timestamp"),
-                user_id,
-                tenant_id,
-                source_ip: row.get("ip_address"),
-                user_agent: row.get("user_agent"),
-                session_id: None, // Not stored in current schema
-                description: row.get("message"),
-                metadata,
-                resource: None,             // Not stored in current schema
-                action: "audit".to_owned(), // Default action
-                result: row.get("result"),
-            };
-            events.push(event);
+        let mut sql_query = sqlx::query(&query);
+        if let Some(tid) = filter.tenant_id {
+            sql_query = sql_query.bind(tid.to_string());
+        }
+        if let Some(uid) = filter.user_id {
+            sql_query = sql_query.bind(uid.to_string());
+        }
+        if let Some(et) = &filter.event_type {
+            sql_query = sql_query.bind(et);
+        }
+        if let Some(severity) = &filter.severity {
+            sql_query = sql_query.bind(format!("{severity:?}"));
+        }
+        if let Some(start) = filter.start {
+            sql_query = sql_query.bind(start);
+        }
+        if let Some(end) = filter.end {
+            sql_query = sql_query.bind(end);
+        }
+        if let Some((timestamp, id)) = position {
+            sql_query = sql_query.bind(timestamp).bind(id);
         }
 
-        Ok(events)
-    }
+        let rows = sql_query
+            .bind(fetch_limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to get audit events page: {e}")))?;
 
-    // UserOAuthToken Methods - PostgreSQL implementations
-    // ================================
+        let mut events = rows
+            .iter()
+            .map(Self::row_to_audit_event)
+            .collect::<AppResult<Vec<_>>>()?;
+        let has_more = events.len() > params.limit;
+        events.truncate(params.limit);
 
-    async fn upsert_user_oauth_token(&self, token: &UserOAuthToken) -> AppResult<()> {
-        // SECURITY: Encrypt OAuth tokens at rest with AAD binding (AES-256-GCM)
-        let encrypted_access_token = shared::encryption::encrypt_oauth_token(
-            self,
-            &token.access_token,
-            &token.tenant_id,
-            token.user_id,
-            &token.provider,
-        )?;
+        let next_cursor = events
+            .last()
+            .filter(|_| has_more)
+            .map(|event| Cursor::new(event.timestamp, &event.event_id.to_string()));
+
+        Ok(CursorPage::new(events, next_cursor, None, has_more))
+    }
+
+    async fn purge_records_before(
+        &self,
+        table: RetentionTable,
+        before: DateTime<Utc>,
+        batch_size: u32,
+    ) -> AppResult<u64> {
+        // Bounded batches by ctid keep row locks short while the server is serving
+        let table_name = table.table_name();
+        let query = format!(
+            "DELETE FROM {table_name} WHERE ctid IN \
+             (SELECT ctid FROM {table_name} WHERE timestamp < $1 LIMIT $2)"
+        );
+
+        let result = sqlx::query(&query)
+            .bind(before)
+            .bind(i64::from(batch_size))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to purge {table_name}: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
+    // UserOAuthToken Methods - PostgreSQL implementations
+    // ================================
+
+    async fn upsert_user_oauth_token(&self, token: &UserOAuthToken) -> AppResult<()> {
+        // SECURITY: Encrypt OAuth tokens at rest with AAD binding (AES-256-GCM)
+        let encrypted_access_token = shared::encryption::encrypt_oauth_token(
+            self,
+            &token.access_token,
+            &token.tenant_id,
+            token.user_id,
+            &token.provider,
+        )?;
 
         let encrypted_refresh_token = token
             .refresh_token
@@ -4779,7 +5569,7 @@ impl DatabaseProvider for PostgresDatabase {
 
         // Generate new secret
         let secret_value = match secret_type {
-            "admin_jwt_secret" => AdminJwtManager::generate_jwt_secret(),
+            "admin_jwt_secret" | "cursor_signing_secret" => AdminJwtManager::generate_jwt_secret(),
             _ => {
                 return Err(AppError::invalid_input(format!(
                     "Unknown secret type: {secret_type}"
@@ -4844,7 +5634,6 @@ impl DatabaseProvider for PostgresDatabase {
         expires_at: Option<&str>,
     ) -> AppResult<String> {
         let notification_id = Uuid::new_v4().to_string();
-        let now = chrono::Utc::now().to_rfc3339();
 
         sqlx::query(
             r"
@@ -4853,16 +5642,30 @@ impl DatabaseProvider for PostgresDatabase {
             ",
         )
         .bind(&notification_id)
-        .bind(user_id.to_string())
+        .bind(user_id)
         .bind(provider)
         .bind(success)
         .bind(message)
         .bind(expires_at)
-        .bind(&now)
+        .bind(Utc::now())
         .execute(&self.pool)
         .await
         .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
 
+        // Push delivery is best effort: listeners replay missed rows on reconnect
+        // and clients can still poll, so a failed NOTIFY must not fail the insert
+        if let Err(e) = sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(OAUTH_NOTIFICATION_CHANNEL)
+            .bind(&notification_id)
+            .execute(&self.pool)
+            .await
+        {
+            warn!(
+                "Failed to NOTIFY OAuth notification {}: {}",
+                notification_id, e
+            );
+        }
+
         Ok(notification_id)
     }
 
@@ -5216,8 +6019,8 @@ impl DatabaseProvider for PostgresDatabase {
 
     async fn store_oauth2_client(&self, client: &OAuth2Client) -> AppResult<()> {
         sqlx::query(
-            "INSERT INTO oauth2_clients (id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+            "INSERT INTO oauth2_clients (id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at, require_pkce)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)"
         )
         .bind(&client.id)
         .bind(&client.client_id)
@@ -5230,6 +6033,7 @@ impl DatabaseProvider for PostgresDatabase {
         .bind(&client.scope)
         .bind(client.created_at)
         .bind(client.expires_at)
+        .bind(client.require_pkce)
         .execute(&self.pool).await.map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
 
         Ok(())
@@ -5237,7 +6041,7 @@ impl DatabaseProvider for PostgresDatabase {
 
     async fn get_oauth2_client(&self, client_id: &str) -> AppResult<Option<OAuth2Client>> {
         let row = sqlx::query(
-            "SELECT id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at
+            "SELECT id, client_id, client_secret_hash, redirect_uris, grant_types, response_types, client_name, client_uri, scope, created_at, expires_at, require_pkce
              FROM oauth2_clients WHERE client_id = $1"
         )
         .bind(client_id)
@@ -5263,6 +6067,7 @@ impl DatabaseProvider for PostgresDatabase {
                 scope: row.get("scope"),
                 created_at: row.get("created_at"),
                 expires_at: row.get("expires_at"),
+                require_pkce: row.get("require_pkce"),
             }))
         } else {
             Ok(None)
@@ -5420,6 +6225,106 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(())
     }
 
+    /// Record an OAuth 2.0 access token issued alongside a refresh token
+    ///
+    /// The linked refresh token is HMAC-SHA256 hashed, matching `oauth2_refresh_tokens`.
+    async fn store_oauth2_access_token(
+        &self,
+        access_token: &OAuth2IssuedAccessToken,
+    ) -> AppResult<()> {
+        let refresh_token_hash =
+            HasEncryption::hash_token_for_storage(self, &access_token.refresh_token)?;
+
+        sqlx::query(
+            "INSERT INTO oauth2_access_tokens (jti, refresh_token, client_id, user_id, expires_at, revoked)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(&access_token.jti)
+        .bind(&refresh_token_hash)
+        .bind(&access_token.client_id)
+        .bind(access_token.user_id)
+        .bind(access_token.expires_at)
+        .bind(access_token.revoked)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Get a recorded OAuth 2.0 access token by its `jti` claim
+    async fn get_oauth2_access_token(
+        &self,
+        jti: &str,
+    ) -> AppResult<Option<OAuth2IssuedAccessToken>> {
+        let row = sqlx::query(
+            "SELECT jti, refresh_token, client_id, user_id, expires_at, revoked
+             FROM oauth2_access_tokens
+             WHERE jti = $1",
+        )
+        .bind(jti)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to fetch optional record: {e}")))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(OAuth2IssuedAccessToken {
+            jti: row
+                .try_get("jti")
+                .map_err(|e| AppError::database(format!("Failed to parse jti column: {e}")))?,
+            refresh_token: row.try_get("refresh_token").map_err(|e| {
+                AppError::database(format!("Failed to parse refresh_token column: {e}"))
+            })?,
+            client_id: row.try_get("client_id").map_err(|e| {
+                AppError::database(format!("Failed to parse client_id column: {e}"))
+            })?,
+            user_id: row
+                .try_get("user_id")
+                .map_err(|e| AppError::database(format!("Failed to parse user_id column: {e}")))?,
+            expires_at: row.try_get("expires_at").map_err(|e| {
+                AppError::database(format!("Failed to parse expires_at column: {e}"))
+            })?,
+            revoked: row
+                .try_get("revoked")
+                .map_err(|e| AppError::database(format!("Failed to parse revoked column: {e}")))?,
+        }))
+    }
+
+    /// Revoke a recorded OAuth 2.0 access token by its `jti` claim
+    async fn revoke_oauth2_access_token(&self, jti: &str) -> AppResult<()> {
+        sqlx::query("UPDATE oauth2_access_tokens SET revoked = true WHERE jti = $1")
+            .bind(jti)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Revoke every access token issued alongside a refresh token
+    ///
+    /// The input token is HMAC-SHA256 hashed before querying.
+    async fn revoke_oauth2_access_tokens_for_refresh_token(
+        &self,
+        refresh_token: &str,
+    ) -> AppResult<u64> {
+        let refresh_token_hash = HasEncryption::hash_token_for_storage(self, refresh_token)?;
+
+        let result = sqlx::query(
+            "UPDATE oauth2_access_tokens SET revoked = true
+             WHERE refresh_token = $1 AND revoked = false",
+        )
+        .bind(&refresh_token_hash)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Database operation failed: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Atomically consume OAuth 2.0 authorization code
     ///
     /// Implements atomic check-and-set using UPDATE...RETURNING
@@ -6332,6 +7237,119 @@ impl DatabaseProvider for PostgresDatabase {
         shared::encryption::HasEncryption::decrypt_data_with_aad(self, encrypted, aad)
     }
 
+    async fn reencrypt_tenant_secrets(&self) -> AppResult<u64> {
+        use shared::encryption::{create_llm_credentials_aad_context, reencrypt_for_tenant};
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::database(format!("Failed to begin tenant key migration: {e}"))
+        })?;
+        let mut reencrypted = 0;
+
+        let rows = sqlx::query(
+            r"
+            SELECT tenant_id, provider, client_secret_encrypted, pending_client_secret_encrypted
+            FROM tenant_oauth_credentials
+            ",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant OAuth secrets: {e}")))?;
+        for row in rows {
+            let tenant_id = TenantId::from(row.try_get::<Uuid, _>("tenant_id")?);
+            let provider: String = row.try_get("provider")?;
+            let secret: String = row.try_get("client_secret_encrypted")?;
+            let pending: Option<String> = row.try_get("pending_client_secret_encrypted")?;
+
+            let aad_context = format!("{tenant_id}|{provider}|tenant_oauth_credentials");
+            let new_secret = reencrypt_for_tenant(self, tenant_id, &secret, &aad_context)?;
+            let new_pending = pending
+                .as_deref()
+                .map(|pending| reencrypt_for_tenant(self, tenant_id, pending, &aad_context))
+                .transpose()?
+                .flatten();
+            if new_secret.is_none() && new_pending.is_none() {
+                continue;
+            }
+
+            sqlx::query(
+                r"
+                UPDATE tenant_oauth_credentials
+                SET client_secret_encrypted = $1, pending_client_secret_encrypted = $2
+                WHERE tenant_id = $3 AND provider = $4
+                ",
+            )
+            .bind(new_secret.unwrap_or(secret))
+            .bind(new_pending.or(pending))
+            .bind(tenant_id.as_uuid())
+            .bind(&provider)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to re-encrypt tenant OAuth secret: {e}"))
+            })?;
+            reencrypted += 1;
+        }
+
+        let rows = sqlx::query("SELECT id, tenant_id, secret_encrypted FROM tenant_webhooks")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to list webhook secrets: {e}")))?;
+        for row in rows {
+            let id: Uuid = row.try_get("id")?;
+            let tenant_id = TenantId::from(row.try_get::<Uuid, _>("tenant_id")?);
+            let secret: String = row.try_get("secret_encrypted")?;
+
+            let aad_context = Self::webhook_secret_aad(tenant_id, id);
+            let Some(new_secret) = reencrypt_for_tenant(self, tenant_id, &secret, &aad_context)?
+            else {
+                continue;
+            };
+            sqlx::query("UPDATE tenant_webhooks SET secret_encrypted = $1 WHERE id = $2")
+                .bind(&new_secret)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to re-encrypt webhook secret: {e}"))
+                })?;
+            reencrypted += 1;
+        }
+
+        let rows = sqlx::query(
+            "SELECT id, tenant_id, user_id, provider, api_key_encrypted FROM user_llm_credentials",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list LLM credentials: {e}")))?;
+        for row in rows {
+            let id: Uuid = row.try_get("id")?;
+            let tenant_id = TenantId::from(row.try_get::<Uuid, _>("tenant_id")?);
+            let user_id: Option<Uuid> = row.try_get("user_id")?;
+            let provider: String = row.try_get("provider")?;
+            let api_key: String = row.try_get("api_key_encrypted")?;
+
+            let aad_context = create_llm_credentials_aad_context(tenant_id, user_id, &provider);
+            let Some(new_api_key) = reencrypt_for_tenant(self, tenant_id, &api_key, &aad_context)?
+            else {
+                continue;
+            };
+            sqlx::query("UPDATE user_llm_credentials SET api_key_encrypted = $1 WHERE id = $2")
+                .bind(&new_api_key)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to re-encrypt LLM API key: {e}"))
+                })?;
+            reencrypted += 1;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::database(format!("Failed to commit tenant key migration: {e}"))
+        })?;
+        Ok(reencrypted)
+    }
+
     // ================================
     // Tool Selection (PostgreSQL implementation)
     // ================================
@@ -6341,7 +7359,7 @@ impl DatabaseProvider for PostgresDatabase {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             ORDER BY category, tool_name
             ",
@@ -6358,7 +7376,7 @@ impl DatabaseProvider for PostgresDatabase {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE tool_name = $1
             ",
@@ -6379,7 +7397,7 @@ impl DatabaseProvider for PostgresDatabase {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE category = $1
             ORDER BY tool_name
@@ -6405,7 +7423,7 @@ impl DatabaseProvider for PostgresDatabase {
             r"
             SELECT id, tool_name, display_name, description, category,
                    is_enabled_by_default, requires_provider, min_plan,
-                   created_at, updated_at
+                   timeout_secs, created_at, updated_at
             FROM tool_catalog
             WHERE min_plan = ANY($1)
             ORDER BY category, tool_name
@@ -6556,94 +7574,468 @@ impl DatabaseProvider for PostgresDatabase {
         Ok(count)
     }
 
-    async fn user_has_synthetic_activities(&self, user_id: Uuid) -> AppResult<bool> {
-        let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM synthetic_activities WHERE user_id = $1 LIMIT 1",
+    async fn list_feature_flags(&self) -> AppResult<Vec<FeatureFlag>> {
+        let rows = sqlx::query(
+            r"
+            SELECT key, description, value_type, default_value, global_value, updated_at
+            FROM feature_flags
+            ORDER BY key
+            ",
         )
-        .bind(user_id)
-        .fetch_one(&self.pool)
-        .await?;
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list feature flags: {e}")))?;
 
-        Ok(count > 0)
+        rows.iter()
+            .map(|row| {
+                let value_type: String = row.get("value_type");
+                let default_value: String = row.get("default_value");
+                let global_value: Option<String> = row.get("global_value");
+                Ok(FeatureFlag {
+                    key: row.get("key"),
+                    description: row.get("description"),
+                    value_type: decode_flag_type(&value_type)?,
+                    default_value: decode_flag_value(&default_value)?,
+                    global_value: global_value.as_deref().map(decode_flag_value).transpose()?,
+                    updated_at: row.get("updated_at"),
+                })
+            })
+            .collect()
     }
 
-    // ================================
-    // Provider Connections (PostgreSQL implementation)
-    // ================================
+    async fn upsert_feature_flag(&self, flag: &FeatureFlag) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO feature_flags
+                (key, description, value_type, default_value, global_value, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (key) DO UPDATE SET
+                description = EXCLUDED.description,
+                value_type = EXCLUDED.value_type,
+                default_value = EXCLUDED.default_value,
+                global_value = EXCLUDED.global_value,
+                updated_at = EXCLUDED.updated_at
+            ",
+        )
+        .bind(&flag.key)
+        .bind(&flag.description)
+        .bind(flag.value_type.as_str())
+        .bind(encode_flag_value(&flag.default_value)?)
+        .bind(
+            flag.global_value
+                .as_ref()
+                .map(encode_flag_value)
+                .transpose()?,
+        )
+        .bind(flag.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store feature flag: {e}")))?;
 
-    async fn register_provider_connection(
-        &self,
-        user_id: Uuid,
-        tenant_id: TenantId,
-        provider: &str,
-        connection_type: &ConnectionType,
-        metadata: Option<&str>,
-    ) -> AppResult<()> {
-        let id = Uuid::new_v4().to_string();
-        let now = Utc::now();
-        let conn_type_str = connection_type.as_str();
+        Ok(())
+    }
+
+    async fn delete_feature_flag(&self, key: &str) -> AppResult<bool> {
+        // Tenant values are removed by ON DELETE CASCADE
+        let result = sqlx::query("DELETE FROM feature_flags WHERE key = $1")
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to delete feature flag: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_tenant_feature_flags(&self) -> AppResult<Vec<TenantFeatureFlag>> {
+        let rows = sqlx::query(
+            r"
+            SELECT key, tenant_id, value, updated_at
+            FROM tenant_feature_flags
+            ORDER BY key, tenant_id
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant feature flags: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let value: String = row.get("value");
+                Ok(TenantFeatureFlag {
+                    key: row.get("key"),
+                    tenant_id: row.get("tenant_id"),
+                    value: decode_flag_value(&value)?,
+                    updated_at: row.get("updated_at"),
+                })
+            })
+            .collect()
+    }
 
+    async fn upsert_tenant_feature_flag(&self, flag: &TenantFeatureFlag) -> AppResult<()> {
         sqlx::query(
             r"
-            INSERT INTO provider_connections (id, user_id, tenant_id, provider, connection_type, connected_at, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            ON CONFLICT(user_id, tenant_id, provider) DO UPDATE SET
-                connection_type = EXCLUDED.connection_type,
-                connected_at = EXCLUDED.connected_at,
-                metadata = EXCLUDED.metadata
+            INSERT INTO tenant_feature_flags (key, tenant_id, value, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (key, tenant_id) DO UPDATE SET
+                value = EXCLUDED.value,
+                updated_at = EXCLUDED.updated_at
             ",
         )
-        .bind(&id)
-        .bind(user_id.to_string())
-        .bind(tenant_id.0)
-        .bind(provider)
-        .bind(conn_type_str)
-        .bind(now.to_rfc3339())
-        .bind(metadata)
+        .bind(&flag.key)
+        .bind(flag.tenant_id.0)
+        .bind(encode_flag_value(&flag.value)?)
+        .bind(flag.updated_at)
         .execute(&self.pool)
-        .await?;
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store tenant feature flag: {e}")))?;
 
         Ok(())
     }
 
-    async fn remove_provider_connection(
+    async fn delete_tenant_feature_flag(&self, key: &str, tenant_id: TenantId) -> AppResult<bool> {
+        let result =
+            sqlx::query("DELETE FROM tenant_feature_flags WHERE key = $1 AND tenant_id = $2")
+                .bind(key)
+                .bind(tenant_id.0)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    AppError::database(format!("Failed to delete tenant feature flag: {e}"))
+                })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn save_strava_webhook_subscription(
         &self,
-        user_id: Uuid,
-        tenant_id: TenantId,
-        provider: &str,
+        subscription: &StravaWebhookSubscription,
     ) -> AppResult<()> {
         sqlx::query(
-            "DELETE FROM provider_connections WHERE user_id = $1 AND tenant_id = $2 AND provider = $3",
+            r"
+            INSERT INTO strava_webhook_subscriptions (id, client_id, callback_url, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (client_id) DO UPDATE SET
+                id = EXCLUDED.id,
+                callback_url = EXCLUDED.callback_url,
+                created_at = EXCLUDED.created_at
+            ",
         )
-        .bind(user_id.to_string())
-        .bind(tenant_id.0)
-        .bind(provider)
+        .bind(subscription.id)
+        .bind(&subscription.client_id)
+        .bind(&subscription.callback_url)
+        .bind(subscription.created_at)
         .execute(&self.pool)
-        .await?;
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store Strava subscription: {e}")))?;
 
         Ok(())
     }
 
-    async fn get_user_provider_connections(
+    async fn get_strava_webhook_subscription(
         &self,
-        user_id: Uuid,
-        tenant_id: Option<TenantId>,
-    ) -> AppResult<Vec<ProviderConnection>> {
-        let rows = if let Some(tid) = tenant_id {
-            sqlx::query(
-                r"
-                SELECT id, user_id, tenant_id, provider, connection_type, connected_at, metadata
-                FROM provider_connections
-                WHERE user_id = $1 AND tenant_id = $2
-                ORDER BY connected_at DESC
-                ",
-            )
-            .bind(user_id.to_string())
-            .bind(tid.to_string())
-            .fetch_all(&self.pool)
-            .await?
-        } else {
-            sqlx::query(
+        subscription_id: i64,
+    ) -> AppResult<Option<StravaWebhookSubscription>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, client_id, callback_url, created_at
+            FROM strava_webhook_subscriptions
+            WHERE id = $1
+            ",
+        )
+        .bind(subscription_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get Strava subscription: {e}")))?;
+
+        Ok(row.map(|row| StravaWebhookSubscription {
+            id: row.get("id"),
+            client_id: row.get("client_id"),
+            callback_url: row.get("callback_url"),
+            created_at: row.get("created_at"),
+        }))
+    }
+
+    async fn delete_strava_webhook_subscription(&self, subscription_id: i64) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM strava_webhook_subscriptions WHERE id = $1")
+            .bind(subscription_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to delete Strava subscription: {e}"))
+            })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn link_strava_athlete(&self, link: &StravaAthleteLink) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO strava_athlete_links (athlete_id, user_id, tenant_id, linked_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, tenant_id) DO UPDATE SET
+                athlete_id = EXCLUDED.athlete_id,
+                linked_at = EXCLUDED.linked_at
+            ",
+        )
+        .bind(link.athlete_id)
+        .bind(link.user_id)
+        .bind(link.tenant_id.0)
+        .bind(link.linked_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to link Strava athlete: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn get_strava_athlete_links(&self, athlete_id: i64) -> AppResult<Vec<StravaAthleteLink>> {
+        let rows = sqlx::query(
+            r"
+            SELECT athlete_id, user_id, tenant_id, linked_at
+            FROM strava_athlete_links
+            WHERE athlete_id = $1
+            ORDER BY linked_at
+            ",
+        )
+        .bind(athlete_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get Strava athlete links: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| StravaAthleteLink {
+                athlete_id: row.get("athlete_id"),
+                user_id: row.get("user_id"),
+                tenant_id: row.get("tenant_id"),
+                linked_at: row.get("linked_at"),
+            })
+            .collect())
+    }
+
+    async fn unlink_strava_athlete(&self, athlete_id: i64) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM strava_athlete_links WHERE athlete_id = $1")
+            .bind(athlete_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to unlink Strava athlete: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_reanalysis_checkpoint(
+        &self,
+        job_id: &str,
+    ) -> AppResult<Option<ReanalysisCheckpoint>> {
+        let row = sqlx::query(
+            r"
+            SELECT job_id, cursor, users_processed, users_failed, insights_stored,
+                   started_at, updated_at, completed_at
+            FROM reanalysis_checkpoints
+            WHERE job_id = $1
+            ",
+        )
+        .bind(job_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get re-analysis checkpoint: {e}")))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let counter = |column: &str| -> AppResult<u32> {
+            let value: i64 = row.get(column);
+            u32::try_from(value).map_err(|e| AppError::database(format!("Invalid {column}: {e}")))
+        };
+
+        Ok(Some(ReanalysisCheckpoint {
+            job_id: row.get("job_id"),
+            cursor: row.get("cursor"),
+            users_processed: counter("users_processed")?,
+            users_failed: counter("users_failed")?,
+            insights_stored: counter("insights_stored")?,
+            started_at: row.get("started_at"),
+            updated_at: row.get("updated_at"),
+            completed_at: row.get("completed_at"),
+        }))
+    }
+
+    async fn save_reanalysis_checkpoint(&self, checkpoint: &ReanalysisCheckpoint) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO reanalysis_checkpoints
+                (job_id, cursor, users_processed, users_failed, insights_stored,
+                 started_at, updated_at, completed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (job_id) DO UPDATE SET
+                cursor = EXCLUDED.cursor,
+                users_processed = EXCLUDED.users_processed,
+                users_failed = EXCLUDED.users_failed,
+                insights_stored = EXCLUDED.insights_stored,
+                updated_at = EXCLUDED.updated_at,
+                completed_at = EXCLUDED.completed_at
+            ",
+        )
+        .bind(&checkpoint.job_id)
+        .bind(checkpoint.cursor.as_deref())
+        .bind(i64::from(checkpoint.users_processed))
+        .bind(i64::from(checkpoint.users_failed))
+        .bind(i64::from(checkpoint.insights_stored))
+        .bind(checkpoint.started_at)
+        .bind(checkpoint.updated_at)
+        .bind(checkpoint.completed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to save re-analysis checkpoint: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn user_has_synthetic_activities(&self, user_id: Uuid) -> AppResult<bool> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM synthetic_activities WHERE user_id = $1 LIMIT 1",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn store_imported_activity(
+        &self,
+        user_id: Uuid,
+        source: &str,
+        file_name: &str,
+        activity: &Activity,
+    ) -> AppResult<()> {
+        let activity_data = serde_json::to_value(activity)?;
+        sqlx::query(
+            r"
+            INSERT INTO imported_activities (id, user_id, source, file_name, start_date, activity_data, imported_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, id) DO UPDATE SET
+                source = EXCLUDED.source,
+                file_name = EXCLUDED.file_name,
+                start_date = EXCLUDED.start_date,
+                activity_data = EXCLUDED.activity_data,
+                imported_at = EXCLUDED.imported_at
+            ",
+        )
+        .bind(activity.id())
+        .bind(user_id)
+        .bind(source)
+        .bind(file_name)
+        .bind(activity.start_date())
+        .bind(activity_data)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to store imported activity: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn get_imported_activities(&self, user_id: Uuid) -> AppResult<Vec<Activity>> {
+        let rows = sqlx::query(
+            r"
+            SELECT activity_data
+            FROM imported_activities
+            WHERE user_id = $1
+            ORDER BY start_date DESC
+            ",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list imported activities: {e}")))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let data: Value = row.get("activity_data");
+                serde_json::from_value(data)
+                    .map_err(|e| AppError::database(format!("Invalid imported activity data: {e}")))
+            })
+            .collect()
+    }
+
+    // ================================
+    // Provider Connections (PostgreSQL implementation)
+    // ================================
+
+    async fn register_provider_connection(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+        connection_type: &ConnectionType,
+        metadata: Option<&str>,
+    ) -> AppResult<()> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+        let conn_type_str = connection_type.as_str();
+
+        sqlx::query(
+            r"
+            INSERT INTO provider_connections (id, user_id, tenant_id, provider, connection_type, connected_at, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT(user_id, tenant_id, provider) DO UPDATE SET
+                connection_type = EXCLUDED.connection_type,
+                connected_at = EXCLUDED.connected_at,
+                metadata = EXCLUDED.metadata
+            ",
+        )
+        .bind(&id)
+        .bind(user_id.to_string())
+        .bind(tenant_id.0)
+        .bind(provider)
+        .bind(conn_type_str)
+        .bind(now.to_rfc3339())
+        .bind(metadata)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_provider_connection(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            "DELETE FROM provider_connections WHERE user_id = $1 AND tenant_id = $2 AND provider = $3",
+        )
+        .bind(user_id.to_string())
+        .bind(tenant_id.0)
+        .bind(provider)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_user_provider_connections(
+        &self,
+        user_id: Uuid,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<ProviderConnection>> {
+        let rows = if let Some(tid) = tenant_id {
+            sqlx::query(
+                r"
+                SELECT id, user_id, tenant_id, provider, connection_type, connected_at, metadata
+                FROM provider_connections
+                WHERE user_id = $1 AND tenant_id = $2
+                ORDER BY connected_at DESC
+                ",
+            )
+            .bind(user_id.to_string())
+            .bind(tid.to_string())
+            .fetch_all(&self.pool)
+            .await?
+        } else {
+            sqlx::query(
                 r"
                 SELECT id, user_id, tenant_id, provider, connection_type, connected_at, metadata
                 FROM provider_connections
@@ -7168,76 +8560,642 @@ impl DatabaseProvider for PostgresDatabase {
 
         Ok(())
     }
-}
-
-impl PostgresDatabase {
-    /// Generate a new MCP token with secure random bytes
-    fn generate_mcp_token() -> String {
-        use rand::RngCore;
-        let mut rng = rand::thread_rng();
-        let mut bytes = [0u8; 32];
-        rng.fill_bytes(&mut bytes);
-        format!("pmcp_{}", URL_SAFE_NO_PAD.encode(bytes))
-    }
 
-    /// Hash a token for storage
-    fn hash_mcp_token(token: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(token.as_bytes());
-        hex::encode(hasher.finalize())
-    }
-
-    /// Update token usage statistics
-    async fn update_user_mcp_token_usage(&self, token_id: &str) -> AppResult<()> {
+    async fn record_auth_session(&self, session: &AuthSession) -> AppResult<()> {
         sqlx::query(
             r"
-            UPDATE user_mcp_tokens
-            SET last_used_at = $1, usage_count = usage_count + 1
-            WHERE id = $2
+            INSERT INTO auth_sessions (id, user_id, client, ip_address, issued_at, expires_at, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO NOTHING
             ",
         )
-        .bind(chrono::Utc::now())
-        .bind(token_id)
+        .bind(&session.id)
+        .bind(session.user_id)
+        .bind(&session.client)
+        .bind(&session.ip_address)
+        .bind(session.issued_at)
+        .bind(session.expires_at)
+        .bind(session.revoked_at)
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to update user MCP token usage: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to record auth session: {e}")))?;
 
         Ok(())
     }
 
-    /// Convert database row to `UserOAuthToken` with decryption
-    ///
-    /// SECURITY: Decrypts OAuth tokens from database storage (AES-256-GCM with AAD)
-    fn row_to_user_oauth_token(&self, row: &PgRow) -> AppResult<UserOAuthToken> {
-        use sqlx::Row;
-
-        let user_id: uuid::Uuid = row
-            .try_get("user_id")
-            .map_err(|e| AppError::database(format!("Failed to parse user_id column: {e}")))?;
-        let tenant_id: String = row
-            .try_get("tenant_id")
-            .map_err(|e| AppError::database(format!("Failed to parse tenant_id column: {e}")))?;
-        let provider: String = row
-            .try_get("provider")
-            .map_err(|e| AppError::database(format!("Failed to parse provider column: {e}")))?;
+    async fn get_auth_session(&self, session_id: &str) -> AppResult<Option<AuthSession>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, user_id, client, ip_address, issued_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE id = $1
+            ",
+        )
+        .bind(session_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get auth session: {e}")))?;
 
-        // Decrypt access token
-        let encrypted_access_token: String = row
-            .try_get("access_token")
-            .map_err(|e| AppError::database(format!("Failed to parse access_token column: {e}")))?;
-        let access_token = shared::encryption::decrypt_oauth_token(
-            self,
-            &encrypted_access_token,
-            &tenant_id,
-            user_id,
-            &provider,
-        )?;
+        row.map(|row| Self::row_to_auth_session(&row)).transpose()
+    }
 
-        // Decrypt refresh token (optional)
-        let refresh_token = row
-            .try_get::<Option<String>, _>("refresh_token")
-            .map_err(|e| AppError::database(format!("Failed to parse refresh_token column: {e}")))?
-            .map(|encrypted_rt| {
+    async fn list_active_auth_sessions(&self, user_id: Uuid) -> AppResult<Vec<AuthSession>> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, user_id, client, ip_address, issued_at, expires_at, revoked_at
+            FROM auth_sessions
+            WHERE user_id = $1
+              AND revoked_at IS NULL
+              AND expires_at > $2
+            ORDER BY issued_at DESC
+            ",
+        )
+        .bind(user_id)
+        .bind(Utc::now())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list auth sessions: {e}")))?;
+
+        rows.iter().map(Self::row_to_auth_session).collect()
+    }
+
+    async fn revoke_auth_session(&self, user_id: Uuid, session_id: &str) -> AppResult<bool> {
+        let result = sqlx::query(
+            r"
+            UPDATE auth_sessions
+            SET revoked_at = $1
+            WHERE id = $2 AND user_id = $3 AND revoked_at IS NULL
+            ",
+        )
+        .bind(Utc::now())
+        .bind(session_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to revoke auth session: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_auth_sessions(
+        &self,
+        user_id: Uuid,
+        except_session_id: Option<&str>,
+    ) -> AppResult<u64> {
+        let result = sqlx::query(
+            r"
+            UPDATE auth_sessions
+            SET revoked_at = $1
+            WHERE user_id = $2
+              AND revoked_at IS NULL
+              AND ($3::TEXT IS NULL OR id <> $3)
+            ",
+        )
+        .bind(Utc::now())
+        .bind(user_id)
+        .bind(except_session_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to revoke auth sessions: {e}")))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn create_tenant_webhook(&self, webhook: &TenantWebhook) -> AppResult<()> {
+        let encrypted_secret = HasEncryption::encrypt_data_for_tenant(
+            self,
+            webhook.tenant_id,
+            &webhook.secret,
+            &Self::webhook_secret_aad(webhook.tenant_id, webhook.id),
+        )?;
+        let event_types: Vec<&str> = webhook
+            .event_types
+            .iter()
+            .map(WebhookEventType::as_str)
+            .collect();
+
+        sqlx::query(
+            r"
+            INSERT INTO tenant_webhooks
+                (id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ",
+        )
+        .bind(webhook.id)
+        .bind(webhook.tenant_id.0)
+        .bind(&webhook.url)
+        .bind(&encrypted_secret)
+        .bind(&event_types)
+        .bind(webhook.active)
+        .bind(webhook.created_at)
+        .bind(webhook.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create tenant webhook: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn get_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<Option<TenantWebhook>> {
+        let row = sqlx::query(
+            r"
+            SELECT id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at
+            FROM tenant_webhooks WHERE id = $1
+            ",
+        )
+        .bind(webhook_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get tenant webhook: {e}")))?;
+
+        row.map(|r| self.row_to_tenant_webhook(&r)).transpose()
+    }
+
+    async fn list_tenant_webhooks(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> AppResult<Vec<TenantWebhook>> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, tenant_id, url, secret_encrypted, event_types, is_active, created_at, updated_at
+            FROM tenant_webhooks
+            WHERE $1::UUID IS NULL OR tenant_id = $1
+            ORDER BY created_at
+            ",
+        )
+        .bind(tenant_id.map(|id| id.0))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list tenant webhooks: {e}")))?;
+
+        rows.iter().map(|r| self.row_to_tenant_webhook(r)).collect()
+    }
+
+    async fn delete_tenant_webhook(&self, webhook_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query("DELETE FROM tenant_webhooks WHERE id = $1")
+            .bind(webhook_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to delete tenant webhook: {e}")))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_webhook_delivery(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO webhook_deliveries
+                (id, webhook_id, tenant_id, event_type, payload, status, attempts,
+                 next_attempt_at, last_status_code, last_error, created_at, completed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ",
+        )
+        .bind(delivery.id)
+        .bind(delivery.webhook_id)
+        .bind(delivery.tenant_id.0)
+        .bind(delivery.event_type.as_str())
+        .bind(&delivery.payload)
+        .bind(delivery.status.as_str())
+        .bind(i32::try_from(delivery.attempts).unwrap_or(i32::MAX))
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_status_code.map(i32::from))
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.created_at)
+        .bind(delivery.completed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to queue webhook delivery: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(
+            r"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = $1
+            WHERE id IN (
+                SELECT id FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= $2
+                ORDER BY next_attempt_at
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, webhook_id, tenant_id, event_type, payload, status, attempts,
+                      next_attempt_at, last_status_code, last_error, created_at, completed_at
+            ",
+        )
+        .bind(lease_until)
+        .bind(now)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to claim webhook deliveries: {e}")))?;
+
+        rows.iter().map(Self::row_to_webhook_delivery).collect()
+    }
+
+    async fn record_webhook_delivery_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &WebhookDeliveryAttempt,
+    ) -> AppResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to begin transaction: {e}")))?;
+
+        sqlx::query(
+            r"
+            INSERT INTO webhook_delivery_attempts
+                (id, delivery_id, attempt, status_code, error, duration_ms, attempted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ",
+        )
+        .bind(Uuid::new_v4())
+        .bind(attempt.delivery_id)
+        .bind(i32::try_from(attempt.attempt).unwrap_or(i32::MAX))
+        .bind(attempt.status_code.map(i32::from))
+        .bind(attempt.error.as_deref())
+        .bind(i64::try_from(attempt.duration_ms).unwrap_or(i64::MAX))
+        .bind(attempt.attempted_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to record webhook attempt: {e}")))?;
+
+        sqlx::query(
+            r"
+            UPDATE webhook_deliveries
+            SET status = $1, attempts = $2, next_attempt_at = $3, last_status_code = $4,
+                last_error = $5, completed_at = $6
+            WHERE id = $7
+            ",
+        )
+        .bind(delivery.status.as_str())
+        .bind(i32::try_from(delivery.attempts).unwrap_or(i32::MAX))
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_status_code.map(i32::from))
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.completed_at)
+        .bind(delivery.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update webhook delivery: {e}")))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::database(format!("Failed to commit webhook attempt: {e}")))
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: u32,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(
+            r"
+            SELECT id, webhook_id, tenant_id, event_type, payload, status, attempts,
+                   next_attempt_at, last_status_code, last_error, created_at, completed_at
+            FROM webhook_deliveries
+            WHERE webhook_id = $1 AND ($2::TEXT IS NULL OR status = $2)
+            ORDER BY created_at DESC
+            LIMIT $3
+            ",
+        )
+        .bind(webhook_id)
+        .bind(status.map(|s| s.as_str()))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list webhook deliveries: {e}")))?;
+
+        rows.iter().map(Self::row_to_webhook_delivery).collect()
+    }
+
+    async fn list_webhook_delivery_attempts(
+        &self,
+        delivery_id: Uuid,
+    ) -> AppResult<Vec<WebhookDeliveryAttempt>> {
+        let rows = sqlx::query(
+            r"
+            SELECT delivery_id, attempt, status_code, error, duration_ms, attempted_at
+            FROM webhook_delivery_attempts
+            WHERE delivery_id = $1
+            ORDER BY attempt
+            ",
+        )
+        .bind(delivery_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to list webhook attempts: {e}")))?;
+
+        rows.iter()
+            .map(|row| {
+                let attempt: i32 = row.try_get("attempt")?;
+                let status_code: Option<i32> = row.try_get("status_code")?;
+                let duration_ms: i64 = row.try_get("duration_ms")?;
+                Ok(WebhookDeliveryAttempt {
+                    delivery_id: row.try_get("delivery_id")?,
+                    attempt: u32::try_from(attempt).unwrap_or(0),
+                    status_code: status_code.and_then(|code| u16::try_from(code).ok()),
+                    error: row.try_get("error")?,
+                    duration_ms: u64::try_from(duration_ms).unwrap_or(0),
+                    attempted_at: row.try_get("attempted_at")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_tenant_max_backfill_days(&self, tenant_id: TenantId) -> AppResult<u32> {
+        let days: Option<i32> =
+            sqlx::query_scalar("SELECT max_backfill_days FROM tenants WHERE id = $1")
+                .bind(tenant_id.0)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| AppError::database(format!("Failed to fetch record: {e}")))?;
+
+        let days = days.ok_or_else(|| AppError::not_found(format!("Tenant {tenant_id}")))?;
+        u32::try_from(days)
+            .map_err(|e| AppError::database(format!("Invalid max_backfill_days {days}: {e}")))
+    }
+
+    async fn set_tenant_max_backfill_days(&self, tenant_id: TenantId, days: u32) -> AppResult<()> {
+        let days = i32::try_from(days)
+            .ok()
+            .filter(|days| *days > 0)
+            .ok_or_else(|| AppError::invalid_input("max_backfill_days must be at least 1"))?;
+
+        let result = sqlx::query(
+            "UPDATE tenants SET max_backfill_days = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+        )
+        .bind(days)
+        .bind(tenant_id.0)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update record: {e}")))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(format!("Tenant {tenant_id}")));
+        }
+        Ok(())
+    }
+
+    async fn get_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<Option<BackfillMarker>> {
+        let row = sqlx::query(
+            r"
+            SELECT resume_cursor, window_days, activities_fetched, stopped_at
+            FROM activity_backfill_markers
+            WHERE user_id = $1 AND tenant_id = $2 AND provider = $3
+            ",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .bind(provider)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to get backfill marker: {e}")))?;
+
+        row.map(|row| -> AppResult<BackfillMarker> {
+            let window_days: i32 = row.try_get("window_days")?;
+            let activities_fetched: i32 = row.try_get("activities_fetched")?;
+            Ok(BackfillMarker {
+                user_id,
+                tenant_id,
+                provider: provider.to_owned(),
+                resume_cursor: Cursor::from_string(row.try_get("resume_cursor")?),
+                window_days: u32::try_from(window_days).unwrap_or(0),
+                activities_fetched: u32::try_from(activities_fetched).unwrap_or(0),
+                stopped_at: row.try_get("stopped_at")?,
+            })
+        })
+        .transpose()
+    }
+
+    async fn save_backfill_marker(&self, marker: &BackfillMarker) -> AppResult<()> {
+        sqlx::query(
+            r"
+            INSERT INTO activity_backfill_markers
+                (user_id, tenant_id, provider, resume_cursor, window_days, activities_fetched, stopped_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, tenant_id, provider) DO UPDATE SET
+                resume_cursor = EXCLUDED.resume_cursor,
+                window_days = EXCLUDED.window_days,
+                activities_fetched = EXCLUDED.activities_fetched,
+                stopped_at = EXCLUDED.stopped_at
+            ",
+        )
+        .bind(marker.user_id)
+        .bind(marker.tenant_id.0)
+        .bind(&marker.provider)
+        .bind(marker.resume_cursor.as_str())
+        .bind(i32::try_from(marker.window_days).unwrap_or(i32::MAX))
+        .bind(i32::try_from(marker.activities_fetched).unwrap_or(i32::MAX))
+        .bind(marker.stopped_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to save backfill marker: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn clear_backfill_marker(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        provider: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            "DELETE FROM activity_backfill_markers WHERE user_id = $1 AND tenant_id = $2 AND provider = $3",
+        )
+        .bind(user_id)
+        .bind(tenant_id.0)
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to clear backfill marker: {e}")))?;
+
+        Ok(())
+    }
+}
+
+impl PostgresDatabase {
+    /// AAD context binding a webhook secret to its tenant and webhook
+    fn webhook_secret_aad(tenant_id: TenantId, webhook_id: Uuid) -> String {
+        format!("{tenant_id}|{webhook_id}|tenant_webhooks")
+    }
+
+    fn row_to_tenant_webhook(&self, row: &PgRow) -> AppResult<TenantWebhook> {
+        let id: Uuid = row.try_get("id")?;
+        let tenant_id = TenantId::from(row.try_get::<Uuid, _>("tenant_id")?);
+        let encrypted_secret: String = row.try_get("secret_encrypted")?;
+        let event_types: Vec<String> = row.try_get("event_types")?;
+
+        Ok(TenantWebhook {
+            id,
+            tenant_id,
+            url: row.try_get("url")?,
+            secret: HasEncryption::decrypt_data_for_tenant(
+                self,
+                tenant_id,
+                &encrypted_secret,
+                &Self::webhook_secret_aad(tenant_id, id),
+            )?,
+            event_types: event_types
+                .iter()
+                .map(|event| event.parse())
+                .collect::<AppResult<_>>()?,
+            active: row.try_get("is_active")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+
+    fn row_to_webhook_delivery(row: &PgRow) -> AppResult<WebhookDelivery> {
+        let event_type: String = row.try_get("event_type")?;
+        let status: String = row.try_get("status")?;
+        let attempts: i32 = row.try_get("attempts")?;
+        let last_status_code: Option<i32> = row.try_get("last_status_code")?;
+
+        Ok(WebhookDelivery {
+            id: row.try_get("id")?,
+            webhook_id: row.try_get("webhook_id")?,
+            tenant_id: TenantId::from(row.try_get::<Uuid, _>("tenant_id")?),
+            event_type: event_type.parse()?,
+            payload: row.try_get("payload")?,
+            status: status.parse()?,
+            attempts: u32::try_from(attempts).unwrap_or(0),
+            next_attempt_at: row.try_get("next_attempt_at")?,
+            last_status_code: last_status_code.and_then(|code| u16::try_from(code).ok()),
+            last_error: row.try_get("last_error")?,
+            created_at: row.try_get("created_at")?,
+            completed_at: row.try_get("completed_at")?,
+        })
+    }
+
+    fn row_to_audit_event(row: &PgRow) -> AppResult<AuditEvent> {
+        let event_id: String = row.try_get("id")?;
+        let event_type: String = row.try_get("event_type")?;
+        let severity: String = row.try_get("severity")?;
+        let tenant_id: Option<String> = row.try_get("tenant_id")?;
+        let user_id: Option<String> = row.try_get("user_id")?;
+        let metadata: Option<String> = row.try_get("metadata")?;
+
+        Ok(AuditEvent {
+            event_id: Uuid::parse_str(&event_id)
+                .map_err(|e| AppError::database(format!("Invalid audit event UUID: {e}")))?,
+            event_type: shared::enums::str_to_audit_event_type(&event_type),
+            severity: shared::enums::str_to_audit_severity(&severity),
+            timestamp: row.try_get("timestamp")?,
+            user_id: user_id.as_deref().map(parse_uuid).transpose()?,
+            tenant_id: tenant_id
+                .as_deref()
+                .map(parse_uuid)
+                .transpose()?
+                .map(TenantId::from_uuid),
+            source_ip: row.try_get("ip_address")?,
+            user_agent: row.try_get("user_agent")?,
+            session_id: None, // Not stored in current schema
+            description: row.try_get("message")?,
+            metadata: metadata
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new())),
+            resource: None,             // Not stored in current schema
+            action: "audit".to_owned(), // Default action
+            result: row.try_get("result")?,
+        })
+    }
+
+    fn row_to_auth_session(row: &PgRow) -> AppResult<AuthSession> {
+        Ok(AuthSession {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            client: row.try_get("client")?,
+            ip_address: row.try_get("ip_address")?,
+            issued_at: row.try_get("issued_at")?,
+            expires_at: row.try_get("expires_at")?,
+            revoked_at: row.try_get("revoked_at")?,
+        })
+    }
+
+    /// Generate a new MCP token with secure random bytes
+    fn generate_mcp_token() -> String {
+        use rand::RngCore;
+        let mut rng = rand::thread_rng();
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        format!("pmcp_{}", URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    /// Hash a token for storage
+    fn hash_mcp_token(token: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(token.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Update token usage statistics
+    async fn update_user_mcp_token_usage(&self, token_id: &str) -> AppResult<()> {
+        sqlx::query(
+            r"
+            UPDATE user_mcp_tokens
+            SET last_used_at = $1, usage_count = usage_count + 1
+            WHERE id = $2
+            ",
+        )
+        .bind(chrono::Utc::now())
+        .bind(token_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to update user MCP token usage: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Convert database row to `UserOAuthToken` with decryption
+    ///
+    /// SECURITY: Decrypts OAuth tokens from database storage (AES-256-GCM with AAD)
+    fn row_to_user_oauth_token(&self, row: &PgRow) -> AppResult<UserOAuthToken> {
+        use sqlx::Row;
+
+        let user_id: uuid::Uuid = row
+            .try_get("user_id")
+            .map_err(|e| AppError::database(format!("Failed to parse user_id column: {e}")))?;
+        let tenant_id: String = row
+            .try_get("tenant_id")
+            .map_err(|e| AppError::database(format!("Failed to parse tenant_id column: {e}")))?;
+        let provider: String = row
+            .try_get("provider")
+            .map_err(|e| AppError::database(format!("Failed to parse provider column: {e}")))?;
+
+        // Decrypt access token
+        let encrypted_access_token: String = row
+            .try_get("access_token")
+            .map_err(|e| AppError::database(format!("Failed to parse access_token column: {e}")))?;
+        let access_token = shared::encryption::decrypt_oauth_token(
+            self,
+            &encrypted_access_token,
+            &tenant_id,
+            user_id,
+            &provider,
+        )?;
+
+        // Decrypt refresh token (optional)
+        let refresh_token = row
+            .try_get::<Option<String>, _>("refresh_token")
+            .map_err(|e| AppError::database(format!("Failed to parse refresh_token column: {e}")))?
+            .map(|encrypted_rt| {
                 shared::encryption::decrypt_oauth_token(
                     self,
                     &encrypted_rt,
@@ -7248,204 +9206,623 @@ impl PostgresDatabase {
             })
             .transpose()?;
 
-        Ok(UserOAuthToken {
-            id: row
-                .try_get("id")
-                .map_err(|e| AppError::database(format!("Failed to parse id column: {e}")))?,
-            user_id,
-            tenant_id,
-            provider,
-            access_token,
-            refresh_token,
-            token_type: row.try_get("token_type").map_err(|e| {
-                AppError::database(format!("Failed to parse token_type column: {e}"))
-            })?,
-            expires_at: row.try_get("expires_at").map_err(|e| {
-                AppError::database(format!("Failed to parse expires_at column: {e}"))
-            })?,
-            scope: row.try_get("scope").ok(),
-            created_at: row.try_get("created_at").map_err(|e| {
-                AppError::database(format!("Failed to parse created_at column: {e}"))
-            })?,
-            updated_at: row.try_get("updated_at").map_err(|e| {
-                AppError::database(format!("Failed to parse updated_at column: {e}"))
-            })?,
-        })
+        Ok(UserOAuthToken {
+            id: row
+                .try_get("id")
+                .map_err(|e| AppError::database(format!("Failed to parse id column: {e}")))?,
+            user_id,
+            tenant_id,
+            provider,
+            access_token,
+            refresh_token,
+            token_type: row.try_get("token_type").map_err(|e| {
+                AppError::database(format!("Failed to parse token_type column: {e}"))
+            })?,
+            expires_at: row.try_get("expires_at").map_err(|e| {
+                AppError::database(format!("Failed to parse expires_at column: {e}"))
+            })?,
+            scope: row.try_get("scope").ok(),
+            created_at: row.try_get("created_at").map_err(|e| {
+                AppError::database(format!("Failed to parse created_at column: {e}"))
+            })?,
+            updated_at: row.try_get("updated_at").map_err(|e| {
+                AppError::database(format!("Failed to parse updated_at column: {e}"))
+            })?,
+        })
+    }
+
+    async fn create_users_table(&self) -> AppResult<()> {
+        // OAuth tokens are stored in user_oauth_tokens table, not here
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS users (
+                id UUID PRIMARY KEY,
+                email TEXT UNIQUE NOT NULL,
+                display_name TEXT,
+                password_hash TEXT NOT NULL,
+                tier TEXT NOT NULL DEFAULT 'starter' CHECK (tier IN ('starter', 'professional', 'enterprise')),
+                tenant_id TEXT,
+                is_active BOOLEAN NOT NULL DEFAULT true,
+                user_status TEXT NOT NULL DEFAULT 'pending' CHECK (user_status IN ('pending', 'active', 'suspended')),
+                is_admin BOOLEAN NOT NULL DEFAULT false,
+                role TEXT NOT NULL DEFAULT 'user' CHECK (role IN ('super_admin', 'admin', 'user')),
+                approved_by UUID REFERENCES users(id),
+                approved_at TIMESTAMPTZ,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                last_active TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                firebase_uid TEXT,
+                auth_provider TEXT NOT NULL DEFAULT 'email'
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create users table: {e}")))?;
+
+        // Create unique index for Firebase UID lookups (enforces uniqueness for non-null values)
+        sqlx::query(
+            r"
+            CREATE UNIQUE INDEX IF NOT EXISTS idx_users_firebase_uid
+            ON users(firebase_uid)
+            WHERE firebase_uid IS NOT NULL
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create firebase_uid index: {e}")))?;
+
+        // Create index for auth provider queries
+        sqlx::query(
+            r"
+            CREATE INDEX IF NOT EXISTS idx_users_auth_provider ON users(auth_provider)
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create auth_provider index: {e}")))?;
+
+        // Soft delete support: hidden users are purged after the recovery window
+        sqlx::query("ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to add deleted_at column: {e}")))?;
+
+        sqlx::query(
+            r"
+            CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users(deleted_at)
+            WHERE deleted_at IS NOT NULL
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create deleted_at index: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn create_user_profiles_table(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS user_profiles (
+                user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+                profile_data JSONB NOT NULL,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create user_profiles table: {e}")))?;
+        Ok(())
+    }
+
+    async fn create_goals_table(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS goals (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                goal_data JSONB NOT NULL,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create goals table: {e}")))?;
+        Ok(())
+    }
+
+    async fn create_insights_table(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS insights (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                insight_type TEXT NOT NULL,
+                content JSONB NOT NULL,
+                metadata JSONB,
+                content_hash TEXT,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create insights table: {e}")))?;
+
+        sqlx::query("ALTER TABLE insights ADD COLUMN IF NOT EXISTS content_hash TEXT")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to add insights content_hash column: {e}"))
+            })?;
+
+        sqlx::query(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_insights_user_content_hash ON insights(user_id, content_hash)",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create insights hash index: {e}")))?;
+        Ok(())
+    }
+
+    async fn create_api_keys_tables(&self) -> AppResult<()> {
+        // Create api_keys table
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS api_keys (
+                id TEXT PRIMARY KEY,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                key_prefix TEXT NOT NULL,
+                key_hash TEXT NOT NULL,
+                description TEXT,
+                tier TEXT NOT NULL CHECK (tier IN ('trial', 'starter', 'professional', 'enterprise')),
+                is_active BOOLEAN NOT NULL DEFAULT true,
+                rate_limit_requests INTEGER NOT NULL,
+                rate_limit_window_seconds INTEGER NOT NULL,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                expires_at TIMESTAMPTZ,
+                last_used_at TIMESTAMPTZ,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create api_keys table: {e}")))?;
+
+        // Create api_key_usage table
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS api_key_usage (
+                id SERIAL PRIMARY KEY,
+                api_key_id TEXT NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
+                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                endpoint TEXT NOT NULL,
+                response_time_ms INTEGER,
+                status_code SMALLINT NOT NULL,
+                method TEXT,
+                request_size_bytes INTEGER,
+                response_size_bytes INTEGER,
+                ip_address INET,
+                user_agent TEXT
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create api_key_usage table: {e}")))?;
+        Ok(())
+    }
+
+    async fn create_a2a_tables(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS a2a_clients (
+                client_id TEXT PRIMARY KEY,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                description TEXT,
+                client_secret_hash TEXT NOT NULL,
+                api_key_hash TEXT NOT NULL,
+                capabilities TEXT[] NOT NULL DEFAULT '{}',
+                redirect_uris TEXT[] NOT NULL DEFAULT '{}',
+                contact_email TEXT,
+                is_active BOOLEAN NOT NULL DEFAULT true,
+                rate_limit_per_minute INTEGER NOT NULL DEFAULT 100,
+                rate_limit_per_day INTEGER DEFAULT 10000,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create a2a_clients table: {e}")))?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS a2a_sessions (
+                session_token TEXT PRIMARY KEY,
+                client_id TEXT NOT NULL REFERENCES a2a_clients(client_id) ON DELETE CASCADE,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                granted_scopes TEXT[] NOT NULL DEFAULT '{}',
+                is_active BOOLEAN NOT NULL DEFAULT true,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                expires_at TIMESTAMPTZ NOT NULL,
+                last_active_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create a2a_sessions table: {e}")))?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS a2a_tasks (
+                task_id TEXT PRIMARY KEY,
+                session_token TEXT NOT NULL REFERENCES a2a_sessions(session_token) ON DELETE CASCADE,
+                task_type TEXT NOT NULL,
+                parameters JSONB NOT NULL,
+                status TEXT NOT NULL DEFAULT 'pending',
+                result JSONB,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create a2a_tasks table: {e}")))?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS a2a_usage (
+                id SERIAL PRIMARY KEY,
+                client_id TEXT NOT NULL REFERENCES a2a_clients(client_id) ON DELETE CASCADE,
+                session_token TEXT REFERENCES a2a_sessions(session_token) ON DELETE SET NULL,
+                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                endpoint TEXT NOT NULL,
+                response_time_ms INTEGER,
+                status_code SMALLINT NOT NULL,
+                method TEXT,
+                request_size_bytes INTEGER,
+                response_size_bytes INTEGER,
+                ip_address INET,
+                user_agent TEXT,
+                protocol_version TEXT NOT NULL DEFAULT 'v1',
+                client_capabilities TEXT[] DEFAULT '{}',
+                granted_scopes TEXT[] DEFAULT '{}'
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create a2a_usage table: {e}")))?;
+        Ok(())
     }
 
-    async fn create_users_table(&self) -> AppResult<()> {
-        // OAuth tokens are stored in user_oauth_tokens table, not here
+    async fn create_admin_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS users (
-                id UUID PRIMARY KEY,
-                email TEXT UNIQUE NOT NULL,
-                display_name TEXT,
-                password_hash TEXT NOT NULL,
-                tier TEXT NOT NULL DEFAULT 'starter' CHECK (tier IN ('starter', 'professional', 'enterprise')),
-                tenant_id TEXT,
+            CREATE TABLE IF NOT EXISTS admin_tokens (
+                id TEXT PRIMARY KEY,
+                service_name TEXT NOT NULL,
+                service_description TEXT,
+                token_hash TEXT NOT NULL,
+                token_prefix TEXT NOT NULL,
+                jwt_secret_hash TEXT NOT NULL,
+                permissions TEXT NOT NULL DEFAULT '[]',
+                is_super_admin BOOLEAN NOT NULL DEFAULT false,
                 is_active BOOLEAN NOT NULL DEFAULT true,
-                user_status TEXT NOT NULL DEFAULT 'pending' CHECK (user_status IN ('pending', 'active', 'suspended')),
-                is_admin BOOLEAN NOT NULL DEFAULT false,
-                role TEXT NOT NULL DEFAULT 'user' CHECK (role IN ('super_admin', 'admin', 'user')),
-                approved_by UUID REFERENCES users(id),
-                approved_at TIMESTAMPTZ,
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                last_active TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                firebase_uid TEXT,
-                auth_provider TEXT NOT NULL DEFAULT 'email'
+                expires_at TIMESTAMPTZ,
+                last_used_at TIMESTAMPTZ,
+                last_used_ip INET,
+                usage_count BIGINT NOT NULL DEFAULT 0
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create users table: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create admin_tokens table: {e}")))?;
 
-        // Create unique index for Firebase UID lookups (enforces uniqueness for non-null values)
         sqlx::query(
             r"
-            CREATE UNIQUE INDEX IF NOT EXISTS idx_users_firebase_uid
-            ON users(firebase_uid)
-            WHERE firebase_uid IS NOT NULL
+            CREATE TABLE IF NOT EXISTS admin_token_usage (
+                id SERIAL PRIMARY KEY,
+                admin_token_id TEXT NOT NULL REFERENCES admin_tokens(id) ON DELETE CASCADE,
+                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                action TEXT NOT NULL,
+                target_resource TEXT,
+                ip_address INET,
+                user_agent TEXT,
+                request_size_bytes INTEGER,
+                success BOOLEAN NOT NULL,
+                method TEXT,
+                response_time_ms INTEGER
+            )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create firebase_uid index: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!("Failed to create admin_token_usage table: {e}"))
+        })?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS admin_provisioned_keys (
+                id SERIAL PRIMARY KEY,
+                admin_token_id TEXT NOT NULL REFERENCES admin_tokens(id) ON DELETE CASCADE,
+                api_key_id TEXT NOT NULL,
+                user_email TEXT NOT NULL,
+                requested_tier TEXT NOT NULL,
+                provisioned_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                provisioned_by_service TEXT NOT NULL,
+                rate_limit_requests INTEGER NOT NULL,
+                rate_limit_period TEXT NOT NULL,
+                key_status TEXT NOT NULL DEFAULT 'active',
+                revoked_at TIMESTAMPTZ,
+                revoked_reason TEXT
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create admin_provisioned_keys table: {e}"
+            ))
+        })?;
+        Ok(())
+    }
+
+    async fn create_jwt_usage_table(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS jwt_usage (
+                id SERIAL PRIMARY KEY,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                endpoint TEXT NOT NULL,
+                response_time_ms INTEGER,
+                status_code INTEGER NOT NULL,
+                method TEXT,
+                request_size_bytes INTEGER,
+                response_size_bytes INTEGER,
+                ip_address INET,
+                user_agent TEXT
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create jwt_usage table: {e}")))?;
+        Ok(())
+    }
+
+    /// Create OAuth notifications table for MCP resource delivery
+    async fn create_oauth_notifications_table(&self) -> AppResult<()> {
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS oauth_notifications (
+                id TEXT PRIMARY KEY,
+                user_id UUID NOT NULL,
+                provider TEXT NOT NULL,
+                success BOOLEAN NOT NULL DEFAULT true,
+                message TEXT NOT NULL,
+                expires_at TEXT,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                read_at TIMESTAMPTZ,
+                FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!("Failed to create oauth_notifications table: {e}"))
+        })?;
+
+        // Create indices for efficient queries
+        sqlx::query(
+            r"
+            CREATE INDEX IF NOT EXISTS idx_oauth_notifications_user_id
+            ON oauth_notifications (user_id)
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create index idx_oauth_notifications_user_id: {e}"
+            ))
+        })?;
+
+        sqlx::query(
+            r"
+            CREATE INDEX IF NOT EXISTS idx_oauth_notifications_user_unread
+            ON oauth_notifications (user_id, read_at)
+            WHERE read_at IS NULL
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create index idx_oauth_notifications_user_unread: {e}"
+            ))
+        })?;
+
+        Ok(())
+    }
 
-        // Create index for auth provider queries
+    /// Create RSA keypairs table for JWT signing key persistence
+    async fn create_rsa_keypairs_table(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE INDEX IF NOT EXISTS idx_users_auth_provider ON users(auth_provider)
+            CREATE TABLE IF NOT EXISTS rsa_keypairs (
+                kid TEXT PRIMARY KEY,
+                private_key_pem TEXT NOT NULL,
+                public_key_pem TEXT NOT NULL,
+                created_at TIMESTAMPTZ NOT NULL,
+                is_active BOOLEAN NOT NULL DEFAULT false,
+                key_size_bits INTEGER NOT NULL
+            )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create auth_provider index: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create rsa_keypairs table: {e}")))?;
 
-        Ok(())
-    }
+        sqlx::query("ALTER TABLE rsa_keypairs ADD COLUMN IF NOT EXISTS retires_at TIMESTAMPTZ")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to add rsa_keypairs.retires_at column: {e}"))
+            })?;
 
-    async fn create_user_profiles_table(&self) -> AppResult<()> {
+        // Create index for active key lookup
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS user_profiles (
-                user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
-                profile_data JSONB NOT NULL,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
-            )
+            CREATE INDEX IF NOT EXISTS idx_rsa_keypairs_active
+            ON rsa_keypairs (is_active)
+            WHERE is_active = true
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create user_profiles table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create index idx_rsa_keypairs_active: {e}"
+            ))
+        })?;
+
         Ok(())
     }
 
-    async fn create_goals_table(&self) -> AppResult<()> {
+    /// Creates complete multi-tenant database schema with all required tables
+    ///
+    /// JUSTIFICATION for `#[allow(clippy::too_many_lines)]`:
+    /// - Creates 6 interdependent tables in a single atomic migration
+    /// - Each table has comprehensive schema: constraints, foreign keys, indexes, defaults
+    /// - Splitting into separate functions obscures the complete schema structure
+    /// - Database migrations benefit from having the full DDL in one location for review
+    /// - Tables: `tenants`, `tenant_oauth_credentials`, `tenant_users`, `tenant_provider_usage`,
+    ///   `oauth_apps`, `authorization_codes`, `user_oauth_tokens`
+    #[allow(clippy::too_many_lines)]
+    async fn create_tenant_tables(&self) -> AppResult<()> {
+        // Create tenants table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS goals (
+            CREATE TABLE IF NOT EXISTS tenants (
                 id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                goal_data JSONB NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                slug VARCHAR(100) UNIQUE NOT NULL,
+                domain VARCHAR(255) UNIQUE,
+                subscription_tier VARCHAR(50) DEFAULT 'starter' CHECK (subscription_tier IN ('starter', 'professional', 'enterprise')),
+                is_active BOOLEAN DEFAULT true,
+                rate_limit_mode VARCHAR(20) NOT NULL DEFAULT 'enforce' CHECK (rate_limit_mode IN ('enforce', 'observe')),
+                max_backfill_days INTEGER NOT NULL DEFAULT 365 CHECK (max_backfill_days > 0),
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
                 updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
             )
-            ",
+            "
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create goals table: {e}")))?;
-        Ok(())
-    }
+        .map_err(|e| AppError::database(format!("Failed to create tenants table: {e}")))?;
 
-    async fn create_insights_table(&self) -> AppResult<()> {
+        // Create tenant_oauth_credentials table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS insights (
+            CREATE TABLE IF NOT EXISTS tenant_oauth_credentials (
                 id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                insight_type TEXT NOT NULL,
-                content JSONB NOT NULL,
-                metadata JSONB,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+                provider VARCHAR(50) NOT NULL,
+                client_id VARCHAR(255) NOT NULL,
+                client_secret_encrypted TEXT NOT NULL,
+                pending_client_secret_encrypted TEXT,
+                previous_secret_expires_at TIMESTAMPTZ,
+                redirect_uri VARCHAR(500) NOT NULL,
+                scopes TEXT[] DEFAULT '{}',
+                rate_limit_per_day INTEGER DEFAULT 15000,
+                is_active BOOLEAN DEFAULT true,
+                configured_by UUID REFERENCES users(id),
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(tenant_id, provider)
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create insights table: {e}")))?;
-        Ok(())
-    }
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create tenant_oauth_credentials table: {e}"
+            ))
+        })?;
 
-    async fn create_api_keys_tables(&self) -> AppResult<()> {
-        // Create api_keys table
+        // Create tenant_users table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS api_keys (
-                id TEXT PRIMARY KEY,
+            CREATE TABLE IF NOT EXISTS tenant_users (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
                 user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                name TEXT NOT NULL,
-                key_prefix TEXT NOT NULL,
-                key_hash TEXT NOT NULL,
-                description TEXT,
-                tier TEXT NOT NULL CHECK (tier IN ('trial', 'starter', 'professional', 'enterprise')),
-                is_active BOOLEAN NOT NULL DEFAULT true,
-                rate_limit_requests INTEGER NOT NULL,
-                rate_limit_window_seconds INTEGER NOT NULL,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                expires_at TIMESTAMPTZ,
-                last_used_at TIMESTAMPTZ,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+                role VARCHAR(50) DEFAULT 'member' CHECK (role IN ('owner', 'admin', 'billing', 'member')),
+                invited_at TIMESTAMPTZ NOT NULL,
+                joined_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(tenant_id, user_id)
             )
-            ",
+            "
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create api_keys table: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create tenant_users table: {e}")))?;
 
-        // Create api_key_usage table
+        // Create tenant_provider_usage table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS api_key_usage (
-                id SERIAL PRIMARY KEY,
-                api_key_id TEXT NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
-                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                endpoint TEXT NOT NULL,
-                response_time_ms INTEGER,
-                status_code SMALLINT NOT NULL,
-                method TEXT,
-                request_size_bytes INTEGER,
-                response_size_bytes INTEGER,
-                ip_address INET,
-                user_agent TEXT
+            CREATE TABLE IF NOT EXISTS tenant_provider_usage (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+                provider VARCHAR(50) NOT NULL,
+                usage_date DATE NOT NULL,
+                request_count INTEGER DEFAULT 0,
+                error_count INTEGER DEFAULT 0,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(tenant_id, provider, usage_date)
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create api_key_usage table: {e}")))?;
-        Ok(())
-    }
+        .map_err(|e| {
+            AppError::database(format!("Failed to create tenant_provider_usage table: {e}"))
+        })?;
 
-    async fn create_a2a_tables(&self) -> AppResult<()> {
+        // Create OAuth Apps table for app registration
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS a2a_clients (
-                client_id TEXT PRIMARY KEY,
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                name TEXT NOT NULL,
+            CREATE TABLE IF NOT EXISTS oauth_apps (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                client_id VARCHAR(255) UNIQUE NOT NULL,
+                client_secret VARCHAR(255) NOT NULL,
+                name VARCHAR(255) NOT NULL,
                 description TEXT,
-                client_secret_hash TEXT NOT NULL,
-                api_key_hash TEXT NOT NULL,
-                capabilities TEXT[] NOT NULL DEFAULT '{}',
                 redirect_uris TEXT[] NOT NULL DEFAULT '{}',
-                contact_email TEXT,
-                is_active BOOLEAN NOT NULL DEFAULT true,
-                rate_limit_per_minute INTEGER NOT NULL DEFAULT 100,
-                rate_limit_per_day INTEGER DEFAULT 10000,
+                scopes TEXT[] NOT NULL DEFAULT '{}',
+                app_type VARCHAR(50) DEFAULT 'web' CHECK (app_type IN ('desktop', 'web', 'mobile', 'server')),
+                owner_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                is_active BOOLEAN DEFAULT true,
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
                 updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
             )
@@ -7453,519 +9830,579 @@ impl PostgresDatabase {
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create a2a_clients table: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create oauth_apps table: {e}")))?;
 
+        // Create Authorization Code table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS a2a_sessions (
-                session_token TEXT PRIMARY KEY,
-                client_id TEXT NOT NULL REFERENCES a2a_clients(client_id) ON DELETE CASCADE,
+            CREATE TABLE IF NOT EXISTS authorization_codes (
+                code VARCHAR(255) PRIMARY KEY,
+                client_id VARCHAR(255) NOT NULL REFERENCES oauth_apps(client_id) ON DELETE CASCADE,
                 user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                granted_scopes TEXT[] NOT NULL DEFAULT '{}',
-                is_active BOOLEAN NOT NULL DEFAULT true,
+                redirect_uri VARCHAR(500) NOT NULL,
+                scope VARCHAR(500) NOT NULL,
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                expires_at TIMESTAMPTZ NOT NULL,
-                last_active_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+                expires_at TIMESTAMPTZ NOT NULL
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create a2a_sessions table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!("Failed to create authorization_codes table: {e}"))
+        })?;
 
+        // Create user_oauth_tokens table for per-user, per-tenant OAuth tokens
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS a2a_tasks (
-                task_id TEXT PRIMARY KEY,
-                session_token TEXT NOT NULL REFERENCES a2a_sessions(session_token) ON DELETE CASCADE,
-                task_type TEXT NOT NULL,
-                parameters JSONB NOT NULL,
-                status TEXT NOT NULL DEFAULT 'pending',
-                result JSONB,
+            CREATE TABLE IF NOT EXISTS user_oauth_tokens (
+                id VARCHAR(255) PRIMARY KEY,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                tenant_id VARCHAR(255) NOT NULL,
+                provider VARCHAR(50) NOT NULL,
+                access_token TEXT NOT NULL,
+                refresh_token TEXT,
+                token_type VARCHAR(50) DEFAULT 'bearer',
+                expires_at TIMESTAMPTZ,
+                scope TEXT,
+                last_sync TIMESTAMPTZ,
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(user_id, tenant_id, provider)
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create a2a_tasks table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!("Failed to create user_oauth_tokens table: {e}"))
+        })?;
+
+        Ok(())
+    }
 
+    /// Create tool selection tables for per-tenant MCP tool configuration
+    async fn create_tool_selection_tables(&self) -> AppResult<()> {
+        // Create tool_catalog table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS a2a_usage (
-                id SERIAL PRIMARY KEY,
-                client_id TEXT NOT NULL REFERENCES a2a_clients(client_id) ON DELETE CASCADE,
-                session_token TEXT REFERENCES a2a_sessions(session_token) ON DELETE SET NULL,
-                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                endpoint TEXT NOT NULL,
-                response_time_ms INTEGER,
-                status_code SMALLINT NOT NULL,
-                method TEXT,
-                request_size_bytes INTEGER,
-                response_size_bytes INTEGER,
-                ip_address INET,
-                user_agent TEXT,
-                protocol_version TEXT NOT NULL DEFAULT 'v1',
-                client_capabilities TEXT[] DEFAULT '{}',
-                granted_scopes TEXT[] DEFAULT '{}'
+            CREATE TABLE IF NOT EXISTS tool_catalog (
+                id VARCHAR(255) PRIMARY KEY,
+                tool_name VARCHAR(255) NOT NULL UNIQUE,
+                display_name VARCHAR(255) NOT NULL,
+                description TEXT NOT NULL,
+                category VARCHAR(50) NOT NULL CHECK (category IN (
+                    'fitness', 'analysis', 'goals', 'nutrition',
+                    'recipes', 'sleep', 'configuration', 'connections'
+                )),
+                is_enabled_by_default BOOLEAN NOT NULL DEFAULT true,
+                requires_provider VARCHAR(50),
+                min_plan VARCHAR(50) NOT NULL DEFAULT 'starter' CHECK (min_plan IN ('starter', 'professional', 'enterprise')),
+                timeout_secs INTEGER CHECK (timeout_secs IS NULL OR timeout_secs >= 0),
+                created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create a2a_usage table: {e}")))?;
-        Ok(())
-    }
+        .map_err(|e| AppError::database(format!("Failed to create tool_catalog table: {e}")))?;
 
-    async fn create_admin_tables(&self) -> AppResult<()> {
+        // Per-tool execution timeout override (NULL uses the server default)
+        sqlx::query("ALTER TABLE tool_catalog ADD COLUMN IF NOT EXISTS timeout_secs INTEGER")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to add timeout_secs column: {e}")))?;
+
+        // Create tenant_tool_overrides table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS admin_tokens (
-                id TEXT PRIMARY KEY,
-                service_name TEXT NOT NULL,
-                service_description TEXT,
-                token_hash TEXT NOT NULL,
-                token_prefix TEXT NOT NULL,
-                jwt_secret_hash TEXT NOT NULL,
-                permissions TEXT NOT NULL DEFAULT '[]',
-                is_super_admin BOOLEAN NOT NULL DEFAULT false,
-                is_active BOOLEAN NOT NULL DEFAULT true,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                expires_at TIMESTAMPTZ,
-                last_used_at TIMESTAMPTZ,
-                last_used_ip INET,
-                usage_count BIGINT NOT NULL DEFAULT 0
+            CREATE TABLE IF NOT EXISTS tenant_tool_overrides (
+                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+                tool_name VARCHAR(255) NOT NULL REFERENCES tool_catalog(tool_name) ON DELETE CASCADE,
+                is_enabled BOOLEAN NOT NULL,
+                enabled_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+                reason TEXT,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(tenant_id, tool_name)
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create admin_tokens table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!("Failed to create tenant_tool_overrides table: {e}"))
+        })?;
 
+        // Create indexes for tool selection tables
         sqlx::query(
-            r"
-            CREATE TABLE IF NOT EXISTS admin_token_usage (
-                id SERIAL PRIMARY KEY,
-                admin_token_id TEXT NOT NULL REFERENCES admin_tokens(id) ON DELETE CASCADE,
-                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                action TEXT NOT NULL,
-                target_resource TEXT,
-                ip_address INET,
-                user_agent TEXT,
-                request_size_bytes INTEGER,
-                success BOOLEAN NOT NULL,
-                method TEXT,
-                response_time_ms INTEGER
-            )
-            ",
+            "CREATE INDEX IF NOT EXISTS idx_tool_catalog_category ON tool_catalog(category)",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!("Failed to create admin_token_usage table: {e}"))
+            AppError::database(format!(
+                "Failed to create index idx_tool_catalog_category: {e}"
+            ))
         })?;
 
         sqlx::query(
-            r"
-            CREATE TABLE IF NOT EXISTS admin_provisioned_keys (
-                id SERIAL PRIMARY KEY,
-                admin_token_id TEXT NOT NULL REFERENCES admin_tokens(id) ON DELETE CASCADE,
-                api_key_id TEXT NOT NULL,
-                user_email TEXT NOT NULL,
-                requested_tier TEXT NOT NULL,
-                provisioned_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                provisioned_by_service TEXT NOT NULL,
-                rate_limit_requests INTEGER NOT NULL,
-                rate_limit_period TEXT NOT NULL,
-                key_status TEXT NOT NULL DEFAULT 'active',
-                revoked_at TIMESTAMPTZ,
-                revoked_reason TEXT
-            )
-            ",
+            "CREATE INDEX IF NOT EXISTS idx_tool_catalog_min_plan ON tool_catalog(min_plan)",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create admin_provisioned_keys table: {e}"
+                "Failed to create index idx_tool_catalog_min_plan: {e}"
             ))
         })?;
-        Ok(())
-    }
 
-    async fn create_jwt_usage_table(&self) -> AppResult<()> {
         sqlx::query(
-            r"
-            CREATE TABLE IF NOT EXISTS jwt_usage (
-                id SERIAL PRIMARY KEY,
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                timestamp TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                endpoint TEXT NOT NULL,
-                response_time_ms INTEGER,
-                status_code INTEGER NOT NULL,
-                method TEXT,
-                request_size_bytes INTEGER,
-                response_size_bytes INTEGER,
-                ip_address INET,
-                user_agent TEXT
-            )
-            ",
+            "CREATE INDEX IF NOT EXISTS idx_tenant_tool_overrides_tenant ON tenant_tool_overrides(tenant_id)",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create jwt_usage table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create index idx_tenant_tool_overrides_tenant: {e}"
+            ))
+        })?;
+
+        // Seed tool_catalog with default tools
+        self.seed_tool_catalog().await?;
+
         Ok(())
     }
 
-    /// Create OAuth notifications table for MCP resource delivery
-    async fn create_oauth_notifications_table(&self) -> AppResult<()> {
+    /// Create tenant webhook registration and delivery log tables
+    async fn create_webhook_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS oauth_notifications (
-                id TEXT PRIMARY KEY,
-                user_id UUID NOT NULL,
-                provider TEXT NOT NULL,
-                success BOOLEAN NOT NULL DEFAULT true,
-                message TEXT NOT NULL,
-                expires_at TEXT,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                read_at TIMESTAMPTZ,
-                FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+            CREATE TABLE IF NOT EXISTS tenant_webhooks (
+                id UUID PRIMARY KEY,
+                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+                url TEXT NOT NULL,
+                secret_encrypted TEXT NOT NULL,
+                event_types TEXT[] NOT NULL DEFAULT '{}',
+                is_active BOOLEAN NOT NULL DEFAULT true,
+                created_at TIMESTAMPTZ NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| {
-            AppError::database(format!("Failed to create oauth_notifications table: {e}"))
-        })?;
+        .map_err(|e| AppError::database(format!("Failed to create tenant_webhooks table: {e}")))?;
 
-        // Create indices for efficient queries
         sqlx::query(
             r"
-            CREATE INDEX IF NOT EXISTS idx_oauth_notifications_user_id
-            ON oauth_notifications (user_id)
+            CREATE TABLE IF NOT EXISTS webhook_deliveries (
+                id UUID PRIMARY KEY,
+                webhook_id UUID NOT NULL REFERENCES tenant_webhooks(id) ON DELETE CASCADE,
+                tenant_id UUID NOT NULL,
+                event_type VARCHAR(100) NOT NULL,
+                payload JSONB NOT NULL,
+                status VARCHAR(20) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'delivered', 'dead_letter')),
+                attempts INTEGER NOT NULL DEFAULT 0,
+                next_attempt_at TIMESTAMPTZ NOT NULL,
+                last_status_code INTEGER,
+                last_error TEXT,
+                created_at TIMESTAMPTZ NOT NULL,
+                completed_at TIMESTAMPTZ
+            )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!(
-                "Failed to create index idx_oauth_notifications_user_id: {e}"
-            ))
+            AppError::database(format!("Failed to create webhook_deliveries table: {e}"))
         })?;
 
         sqlx::query(
             r"
-            CREATE INDEX IF NOT EXISTS idx_oauth_notifications_user_unread
-            ON oauth_notifications (user_id, read_at)
-            WHERE read_at IS NULL
+            CREATE TABLE IF NOT EXISTS webhook_delivery_attempts (
+                id UUID PRIMARY KEY,
+                delivery_id UUID NOT NULL REFERENCES webhook_deliveries(id) ON DELETE CASCADE,
+                attempt INTEGER NOT NULL,
+                status_code INTEGER,
+                error TEXT,
+                duration_ms BIGINT NOT NULL,
+                attempted_at TIMESTAMPTZ NOT NULL
+            )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create index idx_oauth_notifications_user_unread: {e}"
+                "Failed to create webhook_delivery_attempts table: {e}"
             ))
         })?;
 
+        for (name, definition) in [
+            ("idx_tenant_webhooks_tenant", "tenant_webhooks(tenant_id)"),
+            (
+                "idx_webhook_deliveries_due",
+                "webhook_deliveries(status, next_attempt_at)",
+            ),
+            (
+                "idx_webhook_deliveries_webhook",
+                "webhook_deliveries(webhook_id, created_at)",
+            ),
+            (
+                "idx_webhook_delivery_attempts_delivery",
+                "webhook_delivery_attempts(delivery_id, attempt)",
+            ),
+        ] {
+            sqlx::query(&format!(
+                "CREATE INDEX IF NOT EXISTS {name} ON {definition}"
+            ))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to create index {name}: {e}")))?;
+        }
+
         Ok(())
     }
 
-    /// Create RSA keypairs table for JWT signing key persistence
-    async fn create_rsa_keypairs_table(&self) -> AppResult<()> {
+    /// Create the table tracking JWT sessions issued at login and refresh
+    async fn create_auth_session_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS rsa_keypairs (
-                kid TEXT PRIMARY KEY,
-                private_key_pem TEXT NOT NULL,
-                public_key_pem TEXT NOT NULL,
-                created_at TIMESTAMPTZ NOT NULL,
-                is_active BOOLEAN NOT NULL DEFAULT false,
-                key_size_bits INTEGER NOT NULL
+            CREATE TABLE IF NOT EXISTS auth_sessions (
+                id TEXT PRIMARY KEY,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                client TEXT,
+                ip_address TEXT,
+                issued_at TIMESTAMPTZ NOT NULL,
+                expires_at TIMESTAMPTZ NOT NULL,
+                revoked_at TIMESTAMPTZ
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create rsa_keypairs table: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create auth_sessions table: {e}")))?;
 
-        // Create index for active key lookup
         sqlx::query(
-            r"
-            CREATE INDEX IF NOT EXISTS idx_rsa_keypairs_active
-            ON rsa_keypairs (is_active)
-            WHERE is_active = true
-            ",
+            "CREATE INDEX IF NOT EXISTS idx_auth_sessions_user_id ON auth_sessions(user_id)",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create index idx_rsa_keypairs_active: {e}"
+                "Failed to create index idx_auth_sessions_user_id: {e}"
             ))
         })?;
 
         Ok(())
     }
 
-    /// Creates complete multi-tenant database schema with all required tables
-    ///
-    /// JUSTIFICATION for `#[allow(clippy::too_many_lines)]`:
-    /// - Creates 6 interdependent tables in a single atomic migration
-    /// - Each table has comprehensive schema: constraints, foreign keys, indexes, defaults
-    /// - Splitting into separate functions obscures the complete schema structure
-    /// - Database migrations benefit from having the full DDL in one location for review
-    /// - Tables: `tenants`, `tenant_oauth_credentials`, `tenant_users`, `tenant_provider_usage`,
-    ///   `oauth_apps`, `authorization_codes`, `user_oauth_tokens`
-    #[allow(clippy::too_many_lines)]
-    async fn create_tenant_tables(&self) -> AppResult<()> {
-        // Create tenants table
+    /// Create the security audit log and the indexes used to page through it
+    async fn create_audit_event_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS tenants (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                name VARCHAR(255) NOT NULL,
-                slug VARCHAR(100) UNIQUE NOT NULL,
-                domain VARCHAR(255) UNIQUE,
-                subscription_tier VARCHAR(50) DEFAULT 'starter' CHECK (subscription_tier IN ('starter', 'professional', 'enterprise')),
-                is_active BOOLEAN DEFAULT true,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            CREATE TABLE IF NOT EXISTS audit_events (
+                id TEXT PRIMARY KEY,
+                event_type TEXT NOT NULL,
+                severity TEXT NOT NULL,
+                message TEXT NOT NULL,
+                source TEXT NOT NULL,
+                result TEXT NOT NULL,
+                tenant_id TEXT,
+                user_id TEXT,
+                ip_address INET,
+                user_agent TEXT,
+                metadata TEXT,
+                timestamp TIMESTAMPTZ NOT NULL
             )
-            "
+            ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create tenants table: {e}")))?;
+        .map_err(|e| AppError::database(format!("Failed to create audit_events table: {e}")))?;
 
-        // Create tenant_oauth_credentials table
+        for (name, definition) in [
+            (
+                "idx_audit_events_timestamp_id",
+                "audit_events(timestamp, id)",
+            ),
+            (
+                "idx_audit_events_tenant_timestamp_id",
+                "audit_events(tenant_id, timestamp, id)",
+            ),
+        ] {
+            sqlx::query(&format!(
+                "CREATE INDEX IF NOT EXISTS {name} ON {definition}"
+            ))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to create index {name}: {e}")))?;
+        }
+
+        Ok(())
+    }
+
+    async fn create_feature_flag_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS tenant_oauth_credentials (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
-                provider VARCHAR(50) NOT NULL,
-                client_id VARCHAR(255) NOT NULL,
-                client_secret_encrypted TEXT NOT NULL,
-                redirect_uri VARCHAR(500) NOT NULL,
-                scopes TEXT[] DEFAULT '{}',
-                rate_limit_per_day INTEGER DEFAULT 15000,
-                is_active BOOLEAN DEFAULT true,
-                configured_by UUID REFERENCES users(id),
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(tenant_id, provider)
+            CREATE TABLE IF NOT EXISTS feature_flags (
+                key VARCHAR(255) PRIMARY KEY,
+                description TEXT,
+                value_type VARCHAR(16) NOT NULL CHECK (value_type IN ('bool', 'number', 'string')),
+                default_value TEXT NOT NULL,
+                global_value TEXT,
+                updated_at TIMESTAMPTZ NOT NULL
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to create feature_flags table: {e}")))?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS tenant_feature_flags (
+                key VARCHAR(255) NOT NULL REFERENCES feature_flags(key) ON DELETE CASCADE,
+                tenant_id UUID NOT NULL,
+                value TEXT NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (key, tenant_id)
             )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!(
-                "Failed to create tenant_oauth_credentials table: {e}"
-            ))
+            AppError::database(format!("Failed to create tenant_feature_flags table: {e}"))
         })?;
 
-        // Create tenant_users table
+        Ok(())
+    }
+
+    async fn create_strava_webhook_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS tenant_users (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                role VARCHAR(50) DEFAULT 'member' CHECK (role IN ('owner', 'admin', 'billing', 'member')),
-                invited_at TIMESTAMPTZ NOT NULL,
-                joined_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(tenant_id, user_id)
+            CREATE TABLE IF NOT EXISTS strava_webhook_subscriptions (
+                id BIGINT PRIMARY KEY,
+                client_id VARCHAR(255) NOT NULL UNIQUE,
+                callback_url TEXT NOT NULL,
+                created_at TIMESTAMPTZ NOT NULL
             )
-            "
+            ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create tenant_users table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create strava_webhook_subscriptions table: {e}"
+            ))
+        })?;
 
-        // Create tenant_provider_usage table
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS tenant_provider_usage (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
-                provider VARCHAR(50) NOT NULL,
-                usage_date DATE NOT NULL,
-                request_count INTEGER DEFAULT 0,
-                error_count INTEGER DEFAULT 0,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(tenant_id, provider, usage_date)
+            CREATE TABLE IF NOT EXISTS strava_athlete_links (
+                athlete_id BIGINT NOT NULL,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                tenant_id UUID NOT NULL,
+                linked_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (user_id, tenant_id)
             )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!("Failed to create tenant_provider_usage table: {e}"))
+            AppError::database(format!("Failed to create strava_athlete_links table: {e}"))
         })?;
 
-        // Create OAuth Apps table for app registration
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS oauth_apps (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                client_id VARCHAR(255) UNIQUE NOT NULL,
-                client_secret VARCHAR(255) NOT NULL,
-                name VARCHAR(255) NOT NULL,
-                description TEXT,
-                redirect_uris TEXT[] NOT NULL DEFAULT '{}',
-                scopes TEXT[] NOT NULL DEFAULT '{}',
-                app_type VARCHAR(50) DEFAULT 'web' CHECK (app_type IN ('desktop', 'web', 'mobile', 'server')),
-                owner_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                is_active BOOLEAN DEFAULT true,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
-            )
+            CREATE INDEX IF NOT EXISTS idx_strava_athlete_links_athlete
+            ON strava_athlete_links(athlete_id)
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create oauth_apps table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!("Failed to create strava_athlete_links index: {e}"))
+        })?;
 
-        // Create Authorization Code table
+        Ok(())
+    }
+
+    async fn create_reanalysis_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS authorization_codes (
-                code VARCHAR(255) PRIMARY KEY,
-                client_id VARCHAR(255) NOT NULL REFERENCES oauth_apps(client_id) ON DELETE CASCADE,
-                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                redirect_uri VARCHAR(500) NOT NULL,
-                scope VARCHAR(500) NOT NULL,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                expires_at TIMESTAMPTZ NOT NULL
+            CREATE TABLE IF NOT EXISTS reanalysis_checkpoints (
+                job_id VARCHAR(255) PRIMARY KEY,
+                cursor TEXT,
+                users_processed BIGINT NOT NULL DEFAULT 0,
+                users_failed BIGINT NOT NULL DEFAULT 0,
+                insights_stored BIGINT NOT NULL DEFAULT 0,
+                started_at TIMESTAMPTZ NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL,
+                completed_at TIMESTAMPTZ
             )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!("Failed to create authorization_codes table: {e}"))
+            AppError::database(format!(
+                "Failed to create reanalysis_checkpoints table: {e}"
+            ))
         })?;
 
-        // Create user_oauth_tokens table for per-user, per-tenant OAuth tokens
+        Ok(())
+    }
+
+    async fn create_activity_sync_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS user_oauth_tokens (
-                id VARCHAR(255) PRIMARY KEY,
+            CREATE TABLE IF NOT EXISTS activity_sync_records (
                 user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-                tenant_id VARCHAR(255) NOT NULL,
+                tenant_id UUID NOT NULL,
                 provider VARCHAR(50) NOT NULL,
-                access_token TEXT NOT NULL,
-                refresh_token TEXT,
-                token_type VARCHAR(50) DEFAULT 'bearer',
-                expires_at TIMESTAMPTZ,
-                scope TEXT,
-                last_sync TIMESTAMPTZ,
-                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(user_id, tenant_id, provider)
+                activity_id TEXT NOT NULL,
+                start_date TIMESTAMPTZ NOT NULL,
+                content_hash VARCHAR(64) NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL,
+                deleted_at TIMESTAMPTZ,
+                PRIMARY KEY (user_id, tenant_id, provider, activity_id)
             )
             ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!("Failed to create user_oauth_tokens table: {e}"))
+            AppError::database(format!("Failed to create activity_sync_records table: {e}"))
+        })?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_activity_sync_records_start ON activity_sync_records(user_id, tenant_id, provider, start_date)",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create index idx_activity_sync_records_start: {e}"
+            ))
         })?;
 
         Ok(())
     }
 
-    /// Create tool selection tables for per-tenant MCP tool configuration
-    async fn create_tool_selection_tables(&self) -> AppResult<()> {
-        // Create tool_catalog table
+    async fn create_duplicate_merge_tables(&self) -> AppResult<()> {
         sqlx::query(
             r"
-            CREATE TABLE IF NOT EXISTS tool_catalog (
-                id VARCHAR(255) PRIMARY KEY,
-                tool_name VARCHAR(255) NOT NULL UNIQUE,
-                display_name VARCHAR(255) NOT NULL,
-                description TEXT NOT NULL,
-                category VARCHAR(50) NOT NULL CHECK (category IN (
-                    'fitness', 'analysis', 'goals', 'nutrition',
-                    'recipes', 'sleep', 'configuration', 'connections'
-                )),
-                is_enabled_by_default BOOLEAN NOT NULL DEFAULT true,
-                requires_provider VARCHAR(50),
-                min_plan VARCHAR(50) NOT NULL DEFAULT 'starter' CHECK (min_plan IN ('starter', 'professional', 'enterprise')),
-                created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+            CREATE TABLE IF NOT EXISTS duplicate_activity_merges (
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                tenant_id UUID NOT NULL,
+                secondary_provider VARCHAR(50) NOT NULL,
+                secondary_id TEXT NOT NULL,
+                primary_provider VARCHAR(50) NOT NULL,
+                primary_id TEXT NOT NULL,
+                secondary_snapshot JSONB NOT NULL,
+                filled_fields JSONB NOT NULL,
+                merged_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (user_id, tenant_id, secondary_provider, secondary_id)
             )
             ",
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| AppError::database(format!("Failed to create tool_catalog table: {e}")))?;
+        .map_err(|e| {
+            AppError::database(format!(
+                "Failed to create duplicate_activity_merges table: {e}"
+            ))
+        })?;
 
-        // Create tenant_tool_overrides table
         sqlx::query(
-            r"
-            CREATE TABLE IF NOT EXISTS tenant_tool_overrides (
-                id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-                tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
-                tool_name VARCHAR(255) NOT NULL REFERENCES tool_catalog(tool_name) ON DELETE CASCADE,
-                is_enabled BOOLEAN NOT NULL,
-                enabled_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
-                reason TEXT,
-                created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(tenant_id, tool_name)
-            )
-            ",
+            "ALTER TABLE duplicate_activity_merges ADD COLUMN IF NOT EXISTS merge_kind VARCHAR(20) NOT NULL DEFAULT 'duplicate'",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::database(format!("Failed to create tenant_tool_overrides table: {e}"))
+            AppError::database(format!(
+                "Failed to add duplicate_activity_merges merge_kind column: {e}"
+            ))
         })?;
 
-        // Create indexes for tool selection tables
         sqlx::query(
-            "CREATE INDEX IF NOT EXISTS idx_tool_catalog_category ON tool_catalog(category)",
+            "CREATE INDEX IF NOT EXISTS idx_duplicate_activity_merges_primary ON duplicate_activity_merges(user_id, tenant_id, primary_provider, primary_id)",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create index idx_tool_catalog_category: {e}"
+                "Failed to create index idx_duplicate_activity_merges_primary: {e}"
             ))
         })?;
 
+        Ok(())
+    }
+
+    async fn create_imported_activity_tables(&self) -> AppResult<()> {
         sqlx::query(
-            "CREATE INDEX IF NOT EXISTS idx_tool_catalog_min_plan ON tool_catalog(min_plan)",
+            r"
+            CREATE TABLE IF NOT EXISTS imported_activities (
+                id TEXT NOT NULL,
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                source VARCHAR(20) NOT NULL,
+                file_name TEXT NOT NULL,
+                start_date TIMESTAMPTZ NOT NULL,
+                activity_data JSONB NOT NULL,
+                imported_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (user_id, id)
+            )
+            ",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::database(format!("Failed to create imported_activities table: {e}"))
+        })?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_imported_activities_user_start ON imported_activities(user_id, start_date DESC)",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create index idx_tool_catalog_min_plan: {e}"
+                "Failed to create index idx_imported_activities_user_start: {e}"
             ))
         })?;
 
+        Ok(())
+    }
+
+    async fn create_backfill_tables(&self) -> AppResult<()> {
         sqlx::query(
-            "CREATE INDEX IF NOT EXISTS idx_tenant_tool_overrides_tenant ON tenant_tool_overrides(tenant_id)",
+            r"
+            CREATE TABLE IF NOT EXISTS activity_backfill_markers (
+                user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                tenant_id UUID NOT NULL,
+                provider VARCHAR(50) NOT NULL,
+                resume_cursor TEXT NOT NULL,
+                window_days INTEGER NOT NULL,
+                activities_fetched INTEGER NOT NULL DEFAULT 0,
+                stopped_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (user_id, tenant_id, provider)
+            )
+            ",
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
             AppError::database(format!(
-                "Failed to create index idx_tenant_tool_overrides_tenant: {e}"
+                "Failed to create activity_backfill_markers table: {e}"
             ))
         })?;
 
-        // Seed tool_catalog with default tools
-        self.seed_tool_catalog().await?;
-
         Ok(())
     }
 
@@ -8543,6 +10980,156 @@ impl PostgresDatabase {
                 None,
                 "starter",
             ),
+            (
+                "tc-048",
+                "predict_race_times",
+                "Race Time Predictions",
+                "Predict 5K, 10K, half marathon and marathon times from recent runs using VDOT",
+                "analysis",
+                true,
+                None,
+                "professional",
+            ),
+            (
+                "tc-049",
+                "list_gear",
+                "List Gear",
+                "List shoes and bikes with logged distance and flag shoes past their replacement mileage",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-050",
+                "search_activities",
+                "Search Activities",
+                "Search activities by sport type, distance, duration, date range, name text, and elevation gain",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-051",
+                "export_user_data",
+                "Export User Data",
+                "Export profile, goals, insights, provider connections, and recent activities as a portable archive",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-052",
+                "get_recovery_score",
+                "Get Recovery Score",
+                "Recovery score from the latest provider sleep session, overnight HRV, and training stress balance",
+                "sleep",
+                true,
+                None,
+                "professional",
+            ),
+            (
+                "tc-053",
+                "get_segment_efforts",
+                "Get Segment Efforts",
+                "List segment efforts for an activity with PR and KOM ranks, new segment PRs, and segment course records",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-054",
+                "get_activity_splits",
+                "Get Activity Splits",
+                "Per-kilometer or per-mile splits with pace, heart rate, and elevation from provider laps or the activity stream",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-055",
+                "analyze_time_in_zones",
+                "Time in Zones",
+                "Minutes spent in each heart rate and power zone for an activity or date range, using the athlete's own zones when available",
+                "analysis",
+                true,
+                None,
+                "professional",
+            ),
+            (
+                "tc-056",
+                "get_activity_weather",
+                "Get Activity Weather",
+                "Historical temperature, humidity, wind, and conditions at an activity's start location and time",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-057",
+                "suggest_recipes",
+                "Suggest Recipes",
+                "Rank saved recipes for a meal using the day's training intensity, dietary restrictions, and skill level",
+                "recipes",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-058",
+                "get_activities_delta",
+                "Get Activities Delta",
+                "Activities created or updated since a timestamp, plus IDs of activities deleted since then",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-059",
+                "list_connected_providers",
+                "List Connected Providers",
+                "Connection status, granted scopes, and last sync time for each fitness provider",
+                "connections",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-060",
+                "get_training_load_trend",
+                "Training Load Trend",
+                "Daily CTL, ATL, and TSB series over a window with training status and overtraining risk",
+                "analysis",
+                true,
+                None,
+                "professional",
+            ),
+            (
+                "tc-061",
+                "merge_activities",
+                "Merge Activities",
+                "Merge a duplicate activity into a primary one, filling the primary's gaps and hiding the duplicate",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
+            (
+                "tc-062",
+                "unmerge_activities",
+                "Unmerge Activities",
+                "Undo a merge so both activities are listed separately again",
+                "fitness",
+                true,
+                None,
+                "starter",
+            ),
         ];
 
         for (
@@ -8576,6 +11163,20 @@ impl PostgresDatabase {
             .map_err(|e| AppError::database(format!("Failed to seed tool {tool_name}: {e}")))?;
         }
 
+        // Long-running tools get more time than the server-wide default
+        for (tool_name, timeout_secs) in LONG_RUNNING_TOOL_TIMEOUTS {
+            sqlx::query(
+                "UPDATE tool_catalog SET timeout_secs = $1 WHERE tool_name = $2 AND timeout_secs IS NULL",
+            )
+            .bind(timeout_secs)
+            .bind(tool_name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::database(format!("Failed to seed timeout for tool {tool_name}: {e}"))
+            })?;
+        }
+
         Ok(())
     }
 
@@ -8894,4 +11495,69 @@ impl shared::encryption::HasEncryption for PostgresDatabase {
         let tag = hmac::sign(&key, token.as_bytes());
         Ok(general_purpose::STANDARD.encode(tag.as_ref()))
     }
+
+    fn tenant_data_key(&self, tenant_id: TenantId) -> AppResult<[u8; 32]> {
+        shared::encryption::derive_tenant_data_key(&self.encryption_key, tenant_id)
+    }
+}
+
+/// Insert an insight, or refresh the existing row with the same content hash
+async fn upsert_insight<'e, E>(
+    executor: E,
+    user_id: Uuid,
+    insight_data: &Value,
+) -> AppResult<String>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    let insight_type = shared::insights::insight_type_of(insight_data);
+    let content_hash = shared::insights::insight_content_hash(None, insight_type, insight_data);
+
+    let row = sqlx::query(
+        r"
+        INSERT INTO insights (id, user_id, insight_type, content, content_hash)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (user_id, content_hash) DO UPDATE SET created_at = CURRENT_TIMESTAMP
+        RETURNING id
+        ",
+    )
+    .bind(Uuid::new_v4())
+    .bind(user_id)
+    .bind(insight_type)
+    .bind(insight_data)
+    .bind(&content_hash)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| AppError::database(format!("Failed to store insight: {e}")))?;
+
+    Ok(row.get::<Uuid, _>("id").to_string())
+}
+
+/// Insert a single API key row using the given pool or transaction connection
+async fn insert_api_key<'e, E>(executor: E, api_key: &ApiKey) -> AppResult<()>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    sqlx::query(
+        r"
+        INSERT INTO api_keys (id, user_id, name, key_prefix, key_hash, description, tier, is_active, rate_limit_requests, rate_limit_window_seconds, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        ",
+    )
+    .bind(&api_key.id)
+    .bind(api_key.user_id)
+    .bind(&api_key.name)
+    .bind(&api_key.key_prefix)
+    .bind(&api_key.key_hash)
+    .bind(&api_key.description)
+    .bind(format!("{:?}", api_key.tier).to_lowercase())
+    .bind(api_key.is_active)
+    .bind(i32::try_from(api_key.rate_limit_requests).unwrap_or(i32::MAX))
+    .bind(i32::try_from(api_key.rate_limit_window_seconds).unwrap_or(i32::MAX))
+    .bind(api_key.expires_at)
+    .execute(executor)
+    .await
+    .map_err(|e| AppError::database(format!("Failed to create API key: {e}")))?;
+
+    Ok(())
 }
@@ -8,10 +8,31 @@
 //!
 //! This module harmonizes encryption across PostgreSQL and SQLite, ensuring
 //! consistent security for sensitive data at rest using AES-256-GCM with AAD binding.
+//!
+//! Tenant secrets (OAuth client secrets, webhook secrets, LLM API keys) are
+//! sealed with a per-tenant data key derived from the database encryption key
+//! (DEK) with HKDF, so one tenant's ciphertext is useless with another tenant's
+//! key. Tenant-key ciphertext carries the [`TENANT_KEY_PREFIX`]; values without
+//! it predate tenant keys and are still read with the DEK until
+//! `reencrypt_tenant_secrets` rewrites them.
 
-use crate::errors::AppResult;
+use crate::errors::{AppError, AppResult};
+use crate::models::TenantId;
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+use ring::hkdf::{Salt, HKDF_SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
 use uuid::Uuid;
 
+/// Prefix marking ciphertext sealed with a tenant data key instead of the DEK
+///
+/// `:` is not in the base64 alphabet, so it never starts DEK ciphertext.
+pub const TENANT_KEY_PREFIX: &str = "tk1:";
+
+/// HKDF salt separating tenant data keys from other keys derived from the DEK
+const TENANT_KEY_SALT: &[u8] = b"pierre-tenant-data-key-v1";
+
 /// Create AAD (Additional Authenticated Data) context for token encryption
 ///
 /// Format: `"{tenant_id}|{user_id}|{provider}|{table}"`
@@ -49,6 +70,133 @@ pub fn create_token_aad_context(
     format!("{tenant_id}|{user_id}|{provider}|{table}")
 }
 
+/// Create AAD context for a stored LLM API key
+///
+/// Format: `"{tenant_id}|{user_id or tenant-default}|{provider}|user_llm_credentials"`
+#[must_use]
+pub fn create_llm_credentials_aad_context(
+    tenant_id: TenantId,
+    user_id: Option<Uuid>,
+    provider: &str,
+) -> String {
+    let user_part = user_id.map_or_else(|| "tenant-default".to_owned(), |u| u.to_string());
+    format!("{tenant_id}|{user_part}|{provider}|user_llm_credentials")
+}
+
+/// Derive a tenant's data key from the database encryption key
+///
+/// HKDF-SHA256 with the tenant ID as info, so every tenant gets an independent
+/// 256-bit key without storing any extra key material.
+///
+/// # Errors
+/// * Returns error if HKDF expansion fails
+pub fn derive_tenant_data_key(dek: &[u8], tenant_id: TenantId) -> AppResult<[u8; 32]> {
+    let prk = Salt::new(HKDF_SHA256, TENANT_KEY_SALT).extract(dek);
+    let info = tenant_id.to_string();
+    let info_bytes = [info.as_bytes()];
+    let okm = prk
+        .expand(&info_bytes, HKDF_SHA256)
+        .map_err(|e| AppError::internal(format!("Failed to expand tenant key material: {e}")))?;
+
+    let mut key = [0u8; 32];
+    okm.fill(&mut key)
+        .map_err(|e| AppError::internal(format!("Failed to fill tenant data key: {e}")))?;
+    Ok(key)
+}
+
+/// Encrypt data with an explicit AES-256-GCM key and AAD
+///
+/// Output matches `HasEncryption::encrypt_data_with_aad`: base64(nonce || ciphertext || tag).
+///
+/// # Errors
+/// * Returns error if the key is invalid or encryption fails
+pub fn encrypt_with_key(key: &[u8], data: &str, aad_context: &str) -> AppResult<String> {
+    let mut nonce_bytes = [0u8; 12];
+    SystemRandom::new()
+        .fill(&mut nonce_bytes)
+        .map_err(|e| AppError::internal(format!("Failed to generate nonce: {e}")))?;
+    let nonce = Nonce::assume_unique_for_key(nonce_bytes);
+
+    let unbound_key = UnboundKey::new(&AES_256_GCM, key)
+        .map_err(|e| AppError::internal(format!("Failed to create encryption key: {e}")))?;
+    let mut data_bytes = data.as_bytes().to_vec();
+    LessSafeKey::new(unbound_key)
+        .seal_in_place_append_tag(nonce, Aad::from(aad_context.as_bytes()), &mut data_bytes)
+        .map_err(|e| AppError::internal(format!("Failed to encrypt data: {e}")))?;
+
+    let mut combined = nonce_bytes.to_vec();
+    combined.extend(data_bytes);
+    Ok(general_purpose::STANDARD.encode(combined))
+}
+
+/// Decrypt data produced by [`encrypt_with_key`] with the same key and AAD
+///
+/// # Errors
+/// * Returns error if the data is malformed, the key is wrong, or the AAD does not match
+pub fn decrypt_with_key(key: &[u8], encrypted: &str, aad_context: &str) -> AppResult<String> {
+    let combined = general_purpose::STANDARD
+        .decode(encrypted)
+        .map_err(|e| AppError::internal(format!("Failed to decode base64: {e}")))?;
+    if combined.len() < 12 {
+        return Err(AppError::internal("Invalid encrypted data: too short"));
+    }
+
+    let (nonce_bytes, encrypted_bytes) = combined.split_at(12);
+    let nonce = Nonce::assume_unique_for_key(
+        nonce_bytes
+            .try_into()
+            .map_err(|e| AppError::internal(format!("Invalid nonce size: {e}")))?,
+    );
+    let unbound_key = UnboundKey::new(&AES_256_GCM, key)
+        .map_err(|e| AppError::internal(format!("Failed to create decryption key: {e}")))?;
+
+    let mut decrypted_data = encrypted_bytes.to_vec();
+    let decrypted = LessSafeKey::new(unbound_key)
+        .open_in_place(
+            nonce,
+            Aad::from(aad_context.as_bytes()),
+            &mut decrypted_data,
+        )
+        .map_err(|e| {
+            AppError::internal(format!(
+                "Decryption failed (wrong tenant key, AAD mismatch or tampered data): {e:?}"
+            ))
+        })?;
+
+    String::from_utf8(decrypted.to_vec())
+        .map_err(|e| AppError::internal(format!("Failed to convert decrypted data to string: {e}")))
+}
+
+/// Whether a stored value was sealed with a tenant data key
+#[must_use]
+pub fn is_tenant_encrypted(encrypted: &str) -> bool {
+    encrypted.starts_with(TENANT_KEY_PREFIX)
+}
+
+/// Re-encrypt a DEK-sealed value under the tenant's data key
+///
+/// Returns `None` when the value already uses the tenant key, so callers only
+/// write back rows that changed.
+///
+/// # Errors
+/// * Returns error if the legacy value fails to decrypt or re-encryption fails
+pub fn reencrypt_for_tenant<D>(
+    db: &D,
+    tenant_id: TenantId,
+    encrypted: &str,
+    aad_context: &str,
+) -> AppResult<Option<String>>
+where
+    D: HasEncryption + ?Sized,
+{
+    if is_tenant_encrypted(encrypted) {
+        return Ok(None);
+    }
+    let plaintext = db.decrypt_data_with_aad(encrypted, aad_context)?;
+    db.encrypt_data_for_tenant(tenant_id, &plaintext, aad_context)
+        .map(Some)
+}
+
 /// Encrypt OAuth token with AAD binding
 ///
 /// Uses AES-256-GCM encryption with Additional Authenticated Data to prevent
@@ -149,6 +297,47 @@ where
     db.decrypt_data_with_aad(encrypted_token, &aad_context)
 }
 
+/// Decrypt a tenant's OAuth client secrets, honouring a pending rotation
+///
+/// Without a pending secret the stored secret is returned as-is. While a
+/// rotation is pending the new secret becomes the active one, and the stored
+/// secret is returned as the previous secret until `previous_expires_at`.
+///
+/// # Returns
+/// * `Ok((client_secret, previous_client_secret))`
+///
+/// # Errors
+/// * Returns error if any secret that needs to be returned fails to decrypt
+pub fn decrypt_tenant_oauth_secrets<D>(
+    db: &D,
+    tenant_id: TenantId,
+    aad_context: &str,
+    encrypted_secret: &str,
+    pending_encrypted_secret: Option<&str>,
+    previous_expires_at: Option<DateTime<Utc>>,
+) -> AppResult<(String, Option<String>)>
+where
+    D: HasEncryption,
+{
+    let Some(pending_encrypted_secret) = pending_encrypted_secret else {
+        return Ok((
+            db.decrypt_data_for_tenant(tenant_id, encrypted_secret, aad_context)?,
+            None,
+        ));
+    };
+
+    let client_secret =
+        db.decrypt_data_for_tenant(tenant_id, pending_encrypted_secret, aad_context)?;
+    let previous_client_secret = if previous_expires_at.is_some_and(|expires| expires > Utc::now())
+    {
+        Some(db.decrypt_data_for_tenant(tenant_id, encrypted_secret, aad_context)?)
+    } else {
+        None
+    };
+
+    Ok((client_secret, previous_client_secret))
+}
+
 /// Trait for databases that support encryption
 ///
 /// Both `PostgreSQL` and `SQLite` must implement this trait to use shared
@@ -202,6 +391,48 @@ pub trait HasEncryption {
     /// Returns error if AAD doesn't match or data is tampered/corrupted
     fn decrypt_data_with_aad(&self, encrypted: &str, aad: &str) -> AppResult<String>;
 
+    /// Derive the data key for a tenant from this database's encryption key
+    ///
+    /// # Errors
+    /// Returns error if key derivation fails
+    fn tenant_data_key(&self, tenant_id: TenantId) -> AppResult<[u8; 32]>;
+
+    /// Encrypt tenant-scoped data with the tenant's derived data key
+    ///
+    /// The AAD still binds the ciphertext to its row; the tenant key additionally
+    /// keeps it unreadable with any other tenant's key.
+    ///
+    /// # Errors
+    /// Returns error if key derivation or encryption fails
+    fn encrypt_data_for_tenant(
+        &self,
+        tenant_id: TenantId,
+        data: &str,
+        aad: &str,
+    ) -> AppResult<String> {
+        let key = self.tenant_data_key(tenant_id)?;
+        Ok(format!(
+            "{TENANT_KEY_PREFIX}{}",
+            encrypt_with_key(&key, data, aad)?
+        ))
+    }
+
+    /// Decrypt tenant-scoped data, falling back to the DEK for values stored before tenant keys
+    ///
+    /// # Errors
+    /// Returns error if the tenant key or AAD doesn't match or data is tampered/corrupted
+    fn decrypt_data_for_tenant(
+        &self,
+        tenant_id: TenantId,
+        encrypted: &str,
+        aad: &str,
+    ) -> AppResult<String> {
+        match encrypted.strip_prefix(TENANT_KEY_PREFIX) {
+            Some(sealed) => decrypt_with_key(&self.tenant_data_key(tenant_id)?, sealed, aad),
+            None => self.decrypt_data_with_aad(encrypted, aad),
+        }
+    }
+
     /// Compute a keyed HMAC-SHA256 hash for secure token storage
     ///
     /// Used for refresh tokens where we need deterministic lookups but don't
@@ -8,6 +8,7 @@ use crate::a2a::protocol::TaskStatus;
 use crate::constants::tiers;
 use crate::models::{UserStatus, UserTier};
 use crate::permissions::UserRole;
+use crate::security::audit::{AuditEventType, AuditSeverity};
 
 /// Convert `UserTier` enum to database string representation
 ///
@@ -106,6 +107,7 @@ pub fn str_to_user_status(s: &str) -> UserStatus {
 /// assert_eq!(task_status_to_str(&TaskStatus::Completed), "completed");
 /// assert_eq!(task_status_to_str(&TaskStatus::Failed), "failed");
 /// assert_eq!(task_status_to_str(&TaskStatus::Cancelled), "cancelled");
+/// assert_eq!(task_status_to_str(&TaskStatus::Interrupted), "interrupted");
 /// ```
 #[must_use]
 #[inline]
@@ -116,6 +118,7 @@ pub const fn task_status_to_str(status: &TaskStatus) -> &'static str {
         TaskStatus::Completed => "completed",
         TaskStatus::Failed => "failed",
         TaskStatus::Cancelled => "cancelled",
+        TaskStatus::Interrupted => "interrupted",
     }
 }
 
@@ -139,6 +142,7 @@ pub fn str_to_task_status(s: &str) -> TaskStatus {
         "completed" => TaskStatus::Completed,
         "failed" => TaskStatus::Failed,
         "cancelled" => TaskStatus::Cancelled,
+        "interrupted" => TaskStatus::Interrupted,
         _ => TaskStatus::Pending,
     }
 }
@@ -177,3 +181,62 @@ pub const fn user_role_to_str(role: &UserRole) -> &'static str {
 pub fn str_to_user_role(s: &str) -> UserRole {
     UserRole::from_str_lossy(s)
 }
+
+/// Convert a stored audit event type (its `Debug` name) back to `AuditEventType`
+///
+/// Unknown values default to `ToolExecuted`.
+///
+/// # Examples
+/// ```
+/// use pierre_mcp_server::database_plugins::shared::enums::str_to_audit_event_type;
+/// use pierre_mcp_server::security::audit::AuditEventType;
+///
+/// assert!(matches!(str_to_audit_event_type("DataExported"), AuditEventType::DataExported));
+/// assert!(matches!(str_to_audit_event_type("unknown"), AuditEventType::ToolExecuted)); // Default
+/// ```
+#[must_use]
+pub fn str_to_audit_event_type(s: &str) -> AuditEventType {
+    match s {
+        "UserLogin" => AuditEventType::UserLogin,
+        "UserLogout" => AuditEventType::UserLogout,
+        "AuthenticationFailed" => AuditEventType::AuthenticationFailed,
+        "ApiKeyUsed" => AuditEventType::ApiKeyUsed,
+        "OAuthCredentialsAccessed" => AuditEventType::OAuthCredentialsAccessed,
+        "OAuthCredentialsModified" => AuditEventType::OAuthCredentialsModified,
+        "OAuthCredentialsCreated" => AuditEventType::OAuthCredentialsCreated,
+        "OAuthCredentialsDeleted" => AuditEventType::OAuthCredentialsDeleted,
+        "TokenRefreshed" => AuditEventType::TokenRefreshed,
+        "TokenRevoked" => AuditEventType::TokenRevoked,
+        "TenantCreated" => AuditEventType::TenantCreated,
+        "TenantModified" => AuditEventType::TenantModified,
+        "TenantDeleted" => AuditEventType::TenantDeleted,
+        "TenantUserAdded" => AuditEventType::TenantUserAdded,
+        "TenantUserRemoved" => AuditEventType::TenantUserRemoved,
+        "TenantUserRoleChanged" => AuditEventType::TenantUserRoleChanged,
+        "DataEncrypted" => AuditEventType::DataEncrypted,
+        "DataDecrypted" => AuditEventType::DataDecrypted,
+        "KeyRotated" => AuditEventType::KeyRotated,
+        "EncryptionFailed" => AuditEventType::EncryptionFailed,
+        "ToolExecutionFailed" => AuditEventType::ToolExecutionFailed,
+        "ProviderApiCalled" => AuditEventType::ProviderApiCalled,
+        "DataExported" => AuditEventType::DataExported,
+        "ConfigurationChanged" => AuditEventType::ConfigurationChanged,
+        "SystemMaintenance" => AuditEventType::SystemMaintenance,
+        "SecurityPolicyViolation" => AuditEventType::SecurityPolicyViolation,
+        "UserImpersonated" => AuditEventType::UserImpersonated,
+        _ => AuditEventType::ToolExecuted,
+    }
+}
+
+/// Convert a stored audit severity (its `Debug` name) back to `AuditSeverity`
+///
+/// Unknown values default to `Info`.
+#[must_use]
+pub fn str_to_audit_severity(s: &str) -> AuditSeverity {
+    match s {
+        "Warning" => AuditSeverity::Warning,
+        "Error" => AuditSeverity::Error,
+        "Critical" => AuditSeverity::Critical,
+        _ => AuditSeverity::Info,
+    }
+}
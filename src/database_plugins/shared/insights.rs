@@ -0,0 +1,60 @@
+// ABOUTME: Insight helpers shared by the SQLite and PostgreSQL insight storage
+// ABOUTME: Derives the insight type and a canonical content hash used to de-duplicate re-run analyses
+
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// Insight type used when the insight JSON has no `type` field
+pub const DEFAULT_INSIGHT_TYPE: &str = "general";
+
+/// Insight type taken from the insight's `type` field
+#[must_use]
+pub fn insight_type_of(insight_data: &Value) -> &str {
+    insight_data
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_INSIGHT_TYPE)
+}
+
+/// Copy of `value` with every object's keys in sorted order
+///
+/// Two insights with the same fields serialize identically regardless of
+/// the order the analyzer inserted them.
+#[must_use]
+pub fn canonicalize(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let canonical: Map<String, Value> = keys
+                .into_iter()
+                .map(|key| (key.clone(), canonicalize(&map[key])))
+                .collect();
+            Value::Object(canonical)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(canonicalize).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Hex SHA-256 of an insight's activity, type, and canonical content
+///
+/// Re-running an analysis that produces the same insight yields the same
+/// hash, which the `(user_id, content_hash)` unique index turns into an upsert.
+#[must_use]
+pub fn insight_content_hash(
+    activity_id: Option<&str>,
+    insight_type: &str,
+    insight_data: &Value,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(activity_id.unwrap_or_default().as_bytes());
+    hasher.update([0]);
+    hasher.update(insight_type.as_bytes());
+    hasher.update([0]);
+    hasher.update(canonicalize(insight_data).to_string().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
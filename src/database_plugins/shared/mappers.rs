@@ -248,6 +248,18 @@ where
     Ok(Uuid::parse_str(&uuid_str)?)
 }
 
+/// Parse an RFC 3339 timestamp stored in a TEXT column
+///
+/// `column` names the column in the error message.
+///
+/// # Errors
+/// Returns a database error if the value is not a valid RFC 3339 timestamp
+pub fn parse_rfc3339_timestamp(value: &str, column: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| AppError::database(format!("Invalid {column} timestamp: {e}")))
+}
+
 /// Parse `UserMcpToken` from database row (database-agnostic)
 ///
 /// Works with both `PostgreSQL` and `SQLite` backends.
@@ -21,3 +21,6 @@ pub mod builders;
 
 /// Transaction retry patterns (deadlock handling, exponential backoff)
 pub mod transactions;
+
+/// Insight type extraction and content hashing for de-duplication
+pub mod insights;
@@ -16,6 +16,11 @@
 //! - **JSON**: Default format, universal compatibility
 //! - **TOON**: Token-efficient format optimized for LLM input
 //!
+//! ## Unit Systems
+//!
+//! Tool output is computed in SI units. The `units` submodule converts it to
+//! a user's preferred unit system (metric or imperial) before serialization.
+//!
 //! ## Usage
 //!
 //! ```rust,no_run
@@ -28,6 +33,10 @@
 //! }
 //! ```
 
+mod units;
+
+pub use units::{apply_unit_system, convert_units, UnitSystem, UNITS_KEY};
+
 use serde::Serialize;
 use std::{error::Error, fmt};
 #[cfg(feature = "toon")]
@@ -0,0 +1,337 @@
+// ABOUTME: Unit system conversion applied to tool output at serialization time
+// ABOUTME: Converts SI distances, paces, speeds, temperatures, and weights to a user's preferred units
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Unit System Conversion
+//!
+//! Tools always compute and return SI values. When a user prefers imperial
+//! units, [`convert_units`] rewrites the serialized output in one pass so no
+//! tool needs conversion code of its own. Fields are recognized by name:
+//! values are converted in place, and fields whose name carries a unit suffix
+//! are renamed (`distance_meters` becomes `distance_miles`). A top-level
+//! `units` object records the unit of every converted quantity.
+
+use std::{fmt, mem};
+
+use serde_json::{json, Map, Value};
+
+use crate::constants::units::{
+    KG_PER_POUND, METERS_PER_FOOT, METERS_PER_KM, METERS_PER_MILE, SECONDS_PER_HOUR,
+    SECONDS_PER_MINUTE,
+};
+
+/// Key of the annotation object added to converted output
+pub const UNITS_KEY: &str = "units";
+
+/// Measurement system used when presenting values to a user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    /// SI units (meters, seconds per km, Celsius, kilograms) - the native output
+    #[default]
+    Metric,
+    /// US customary units (miles, feet, min/mile, Fahrenheit, pounds)
+    Imperial,
+}
+
+impl UnitSystem {
+    /// Parse a preference string (case-insensitive)
+    /// Returns `Metric` for unrecognized values
+    #[must_use]
+    pub fn from_str_param(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "imperial" | "us" | "customary" => Self::Imperial,
+            _ => Self::Metric,
+        }
+    }
+
+    /// Get the unit system name as a string
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Metric => "metric",
+            Self::Imperial => "imperial",
+        }
+    }
+
+    /// Unit labels for every quantity in this system
+    #[must_use]
+    pub fn labels(self) -> Value {
+        let mut labels = Map::new();
+        labels.insert("system".to_owned(), Value::from(self.as_str()));
+        for quantity in Quantity::ALL {
+            labels.insert(
+                quantity.name().to_owned(),
+                Value::from(quantity.label(self)),
+            );
+        }
+        Value::Object(labels)
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Physical quantity a field measures, with its metric and imperial scales
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantity {
+    /// Meters / miles
+    Distance,
+    /// Kilometers / miles
+    DistanceKm,
+    /// Meters / feet
+    Elevation,
+    /// Meters per second / miles per hour
+    Speed,
+    /// Seconds per km / seconds per mile
+    Pace,
+    /// Minutes per km / minutes per mile (number or "m:ss")
+    PaceMinutes,
+    /// Celsius / Fahrenheit
+    Temperature,
+    /// Kilograms / pounds
+    Weight,
+}
+
+impl Quantity {
+    /// Quantities listed in the `units` annotation
+    const ALL: [Self; 6] = [
+        Self::Distance,
+        Self::Elevation,
+        Self::Speed,
+        Self::PaceMinutes,
+        Self::Temperature,
+        Self::Weight,
+    ];
+
+    /// Name used in the `units` annotation
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Distance | Self::DistanceKm => "distance",
+            Self::Elevation => "elevation",
+            Self::Speed => "speed",
+            Self::Pace | Self::PaceMinutes => "pace",
+            Self::Temperature => "temperature",
+            Self::Weight => "weight",
+        }
+    }
+
+    /// Display label of the quantity in a unit system
+    const fn label(self, system: UnitSystem) -> &'static str {
+        match (self, system) {
+            (Self::Distance, UnitSystem::Metric) => "m",
+            (Self::DistanceKm, UnitSystem::Metric) => "km",
+            (Self::Distance | Self::DistanceKm, UnitSystem::Imperial) => "mi",
+            (Self::Elevation, UnitSystem::Metric) => "m",
+            (Self::Elevation, UnitSystem::Imperial) => "ft",
+            (Self::Speed, UnitSystem::Metric) => "m/s",
+            (Self::Speed, UnitSystem::Imperial) => "mph",
+            (Self::Pace, UnitSystem::Metric) => "s/km",
+            (Self::Pace, UnitSystem::Imperial) => "s/mi",
+            (Self::PaceMinutes, UnitSystem::Metric) => "min/km",
+            (Self::PaceMinutes, UnitSystem::Imperial) => "min/mi",
+            (Self::Temperature, UnitSystem::Metric) => "°C",
+            (Self::Temperature, UnitSystem::Imperial) => "°F",
+            (Self::Weight, UnitSystem::Metric) => "kg",
+            (Self::Weight, UnitSystem::Imperial) => "lb",
+        }
+    }
+
+    /// Convert a metric value to imperial
+    fn to_imperial(self, value: f64) -> f64 {
+        match self {
+            Self::Distance => value / METERS_PER_MILE,
+            Self::DistanceKm => value * METERS_PER_KM / METERS_PER_MILE,
+            Self::Elevation => value / METERS_PER_FOOT,
+            Self::Speed => value * SECONDS_PER_HOUR / METERS_PER_MILE,
+            Self::Pace | Self::PaceMinutes => value * METERS_PER_MILE / METERS_PER_KM,
+            Self::Temperature => value.mul_add(9.0 / 5.0, 32.0),
+            Self::Weight => value / KG_PER_POUND,
+        }
+    }
+
+    /// Convert an imperial value to metric
+    fn to_metric(self, value: f64) -> f64 {
+        match self {
+            Self::Distance => value * METERS_PER_MILE,
+            Self::DistanceKm => value * METERS_PER_MILE / METERS_PER_KM,
+            Self::Elevation => value * METERS_PER_FOOT,
+            Self::Speed => value * METERS_PER_MILE / SECONDS_PER_HOUR,
+            Self::Pace | Self::PaceMinutes => value * METERS_PER_KM / METERS_PER_MILE,
+            Self::Temperature => (value - 32.0) * 5.0 / 9.0,
+            Self::Weight => value * KG_PER_POUND,
+        }
+    }
+}
+
+/// A recognized output field: its name in each unit system and what it measures
+struct FieldRule {
+    metric_key: &'static str,
+    imperial_key: &'static str,
+    quantity: Quantity,
+}
+
+/// Shorthand for a field rule
+const fn rule(
+    metric_key: &'static str,
+    imperial_key: &'static str,
+    quantity: Quantity,
+) -> FieldRule {
+    FieldRule {
+        metric_key,
+        imperial_key,
+        quantity,
+    }
+}
+
+/// Every field converted between unit systems
+///
+/// Imperial keys must be unique so conversions round-trip.
+const FIELD_RULES: &[FieldRule] = &[
+    rule("distance", "distance", Quantity::Distance),
+    rule("distance_meters", "distance_miles", Quantity::Distance),
+    rule(
+        "total_distance_km",
+        "total_distance_miles",
+        Quantity::DistanceKm,
+    ),
+    rule("elevation_gain", "elevation_gain", Quantity::Elevation),
+    rule(
+        "total_elevation_gain",
+        "total_elevation_gain",
+        Quantity::Elevation,
+    ),
+    rule("elevation_high", "elevation_high", Quantity::Elevation),
+    rule("elevation_low", "elevation_low", Quantity::Elevation),
+    rule("elevation_meters", "elevation_feet", Quantity::Elevation),
+    rule("average_altitude", "average_altitude", Quantity::Elevation),
+    rule("average_speed", "average_speed", Quantity::Speed),
+    rule("max_speed", "max_speed", Quantity::Speed),
+    rule("wind_speed", "wind_speed", Quantity::Speed),
+    rule(
+        "pace_seconds_per_km",
+        "pace_seconds_per_mile",
+        Quantity::Pace,
+    ),
+    rule("pace_min_km", "pace_min_mile", Quantity::PaceMinutes),
+    rule(
+        "predicted_pace_min_km",
+        "predicted_pace_min_mile",
+        Quantity::PaceMinutes,
+    ),
+    rule("temperature", "temperature", Quantity::Temperature),
+    rule("weight_kg", "weight_lb", Quantity::Weight),
+];
+
+impl FieldRule {
+    /// Field name in a unit system
+    const fn key(&self, system: UnitSystem) -> &'static str {
+        match system {
+            UnitSystem::Metric => self.metric_key,
+            UnitSystem::Imperial => self.imperial_key,
+        }
+    }
+
+    /// Convert a value expressed in `from` units to `to` units
+    fn convert(&self, value: f64, from: UnitSystem) -> f64 {
+        match from {
+            UnitSystem::Metric => self.quantity.to_imperial(value),
+            UnitSystem::Imperial => self.quantity.to_metric(value),
+        }
+    }
+}
+
+/// Convert every recognized field in `value` from one unit system to another
+///
+/// Walks nested objects and arrays. Non-numeric values (and paces that are not
+/// "m:ss" strings) are left untouched. When anything was converted and `value`
+/// is an object, a `units` annotation describing the target system is set.
+/// Returns whether any field was converted.
+pub fn convert_units(value: &mut Value, from: UnitSystem, to: UnitSystem) -> bool {
+    if from == to {
+        return false;
+    }
+    let converted = convert_value(value, from, to);
+    if converted {
+        if let Value::Object(map) = value {
+            map.insert(UNITS_KEY.to_owned(), to.labels());
+        }
+    }
+    converted
+}
+
+/// Convert SI tool output to the user's preferred unit system
+///
+/// Equivalent to [`convert_units`] from [`UnitSystem::Metric`].
+pub fn apply_unit_system(value: &mut Value, system: UnitSystem) -> bool {
+    convert_units(value, UnitSystem::Metric, system)
+}
+
+fn convert_value(value: &mut Value, from: UnitSystem, to: UnitSystem) -> bool {
+    match value {
+        Value::Object(map) => convert_object(map, from, to),
+        Value::Array(items) => items.iter_mut().fold(false, |converted, item| {
+            convert_value(item, from, to) | converted
+        }),
+        _ => false,
+    }
+}
+
+fn convert_object(map: &mut Map<String, Value>, from: UnitSystem, to: UnitSystem) -> bool {
+    let mut converted = false;
+    for (key, mut field) in mem::take(map) {
+        let matched = FIELD_RULES
+            .iter()
+            .find(|candidate| candidate.key(from) == key)
+            .and_then(|matched| Some((matched, convert_field(matched, &field, from)?)));
+        match matched {
+            Some((matched, new_value)) => {
+                converted = true;
+                map.insert(matched.key(to).to_owned(), new_value);
+            }
+            None => {
+                // Previous annotations describe the source system; recompute them
+                if key == UNITS_KEY && field.get("system").is_some() {
+                    continue;
+                }
+                converted |= convert_value(&mut field, from, to);
+                map.insert(key, field);
+            }
+        }
+    }
+    converted
+}
+
+/// Convert a single field value, or `None` when it is not a convertible number
+fn convert_field(rule: &FieldRule, field: &Value, from: UnitSystem) -> Option<Value> {
+    match field {
+        Value::Number(number) => {
+            let value = number.as_f64()?;
+            Some(json!(rule.convert(value, from)))
+        }
+        Value::String(text) if rule.quantity == Quantity::PaceMinutes => {
+            let minutes = parse_minutes(text)?;
+            Some(Value::from(format_minutes(rule.convert(minutes, from))))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a "m:ss" pace string into fractional minutes
+fn parse_minutes(text: &str) -> Option<f64> {
+    let (minutes, seconds) = text.trim().split_once(':')?;
+    let minutes: u32 = minutes.parse().ok()?;
+    let seconds: u32 = seconds.parse().ok()?;
+    (seconds < 60).then(|| f64::from(minutes) + f64::from(seconds) / SECONDS_PER_MINUTE)
+}
+
+/// Format fractional minutes as a "m:ss" pace string
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Safe: paces are small positive values
+fn format_minutes(minutes: f64) -> String {
+    let total_seconds = (minutes * SECONDS_PER_MINUTE).round().max(0.0) as u64;
+    format!("{}:{:02}", total_seconds / 60, total_seconds % 60)
+}
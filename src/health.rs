@@ -6,6 +6,14 @@
 
 //! Health check endpoints and monitoring utilities
 
+/// Per-tenant provider connectivity probes
+pub mod providers;
+
+pub use providers::{
+    ProviderHealthChecker, ProviderHealthConfig, ProviderHealthEntry, ProviderHealthReport,
+    ProviderProbe, TokenValidityProbe,
+};
+
 use std::env;
 use std::error::Error as StdError;
 use std::fmt;
@@ -21,12 +29,14 @@ use std::string::FromUtf8Error;
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::sync::RwLock;
 use tokio::time::interval;
 use tracing::{error, info};
 
+use crate::config::{DataRetentionConfig, UserDeletionConfig};
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 use crate::constants::system_monitoring::BYTES_TO_MB_DIVISOR;
 #[cfg(target_os = "linux")]
@@ -37,6 +47,7 @@ use crate::constants::{
 };
 use crate::database_plugins::{factory::Database, DatabaseProvider};
 use crate::errors::AppResult;
+use crate::services::data_retention::purge_expired_records;
 use crate::utils::http_client::get_health_check_timeout_secs;
 
 /// Errors that can occur during health probe operations
@@ -195,9 +206,12 @@ impl HealthChecker {
         health_checker
     }
 
-    /// Periodic task to clean up expired API keys
+    /// Periodic task to clean up expired API keys, purge users past their recovery window,
+    /// and purge analytics and audit rows past their retention window
     async fn periodic_cleanup_task(database: Arc<Database>) {
         let mut ticker = interval(Duration::from_secs(HOUR_SECONDS as u64)); // Run every hour
+        let user_deletion = UserDeletionConfig::from_env();
+        let data_retention = DataRetentionConfig::from_env();
 
         loop {
             ticker.tick().await;
@@ -212,6 +226,22 @@ impl HealthChecker {
                     error!("Failed to cleanup expired API keys: {}", e);
                 }
             }
+
+            match database
+                .purge_deleted_users(user_deletion.purge_cutoff(Utc::now()))
+                .await
+            {
+                Ok(count) => {
+                    if count > 0 {
+                        info!("Purged {} users past their recovery window", count);
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to purge deleted users: {}", e);
+                }
+            }
+
+            purge_expired_records(database.as_ref(), &data_retention, Utc::now()).await;
         }
     }
 
@@ -508,11 +538,14 @@ impl HealthChecker {
         let user_count = self.database.get_user_count().await?;
 
         let query_duration = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let schema_version = self.database.schema_version().await?;
 
         Ok(serde_json::json!({
             "backend": format!("{:?}", self.database.database_type()),
             "backend_info": self.database.backend_info(),
             "query_duration_ms": query_duration,
+            "pool": self.database.pool_stats(),
+            "schema_version": schema_version,
             "status": "connected",
             "user_count": user_count
         }))
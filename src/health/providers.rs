@@ -0,0 +1,400 @@
+// ABOUTME: Per-tenant provider connectivity health checks backed by lightweight API probes
+// ABOUTME: Probes each tenant's configured OAuth providers concurrently and caches the report
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Provider connectivity health
+//!
+//! For every tenant with stored OAuth credentials, each configured provider is
+//! probed with a cheap authenticated call (the athlete profile) using the token
+//! of a user connected through that tenant. Probes run concurrently behind a
+//! semaphore, each bounded by a timeout, and the report is cached so frequent
+//! health polling does not hammer provider APIs.
+//!
+//! A failing provider is reported as [`HealthStatus::Degraded`], never
+//! [`HealthStatus::Unhealthy`]: one provider outage must not fail the service
+//! health check.
+//!
+//! The report also carries each tenant's current in-flight and queued provider
+//! requests and the rate-limit budget each provider last advertised. Both are
+//! read live on every call, even when the probe results come from cache.
+
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use pierre_core::models::TenantId;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{RwLock, Semaphore};
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+use super::HealthStatus;
+use crate::database_plugins::{factory::Database, DatabaseProvider};
+use crate::errors::{AppError, AppResult};
+use crate::mcp::resources::ServerResources;
+use crate::protocols::universal::auth_service::AuthService;
+use crate::providers::rate_limit_budget::{ProviderBudgetTracker, TenantProviderBudget};
+use crate::providers::tenant_concurrency::{TenantConcurrencyLimiter, TenantInFlight};
+
+/// Seconds a provider health report is reused when `PIERRE_PROVIDER_HEALTH_CACHE_TTL_SECS` is unset
+pub const DEFAULT_PROVIDER_HEALTH_CACHE_TTL_SECS: u64 = 300;
+
+/// Seconds a single probe may take when `PIERRE_PROVIDER_HEALTH_TIMEOUT_SECS` is unset
+pub const DEFAULT_PROVIDER_PROBE_TIMEOUT_SECS: u64 = 5;
+
+/// Probes in flight at once when `PIERRE_PROVIDER_HEALTH_MAX_CONCURRENCY` is unset
+pub const DEFAULT_PROVIDER_PROBE_CONCURRENCY: usize = 4;
+
+/// Configuration for provider connectivity probes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderHealthConfig {
+    /// How long a report is served from cache
+    pub cache_ttl: Duration,
+    /// Maximum duration of a single probe
+    pub probe_timeout: Duration,
+    /// Maximum number of probes running at once
+    pub max_concurrent_probes: usize,
+}
+
+impl Default for ProviderHealthConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(DEFAULT_PROVIDER_HEALTH_CACHE_TTL_SECS),
+            probe_timeout: Duration::from_secs(DEFAULT_PROVIDER_PROBE_TIMEOUT_SECS),
+            max_concurrent_probes: DEFAULT_PROVIDER_PROBE_CONCURRENCY,
+        }
+    }
+}
+
+impl ProviderHealthConfig {
+    /// Load provider health configuration from environment variables
+    ///
+    /// # Environment Variables
+    ///
+    /// - `PIERRE_PROVIDER_HEALTH_CACHE_TTL_SECS`: Seconds a report is cached (default: 300)
+    /// - `PIERRE_PROVIDER_HEALTH_TIMEOUT_SECS`: Per-probe timeout in seconds (default: 5)
+    /// - `PIERRE_PROVIDER_HEALTH_MAX_CONCURRENCY`: Concurrent probes (default: 4, minimum 1)
+    ///
+    /// Invalid values fall back to the defaults.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let cache_ttl_secs = parse_env(
+            "PIERRE_PROVIDER_HEALTH_CACHE_TTL_SECS",
+            DEFAULT_PROVIDER_HEALTH_CACHE_TTL_SECS,
+        );
+        let timeout_secs = parse_env(
+            "PIERRE_PROVIDER_HEALTH_TIMEOUT_SECS",
+            DEFAULT_PROVIDER_PROBE_TIMEOUT_SECS,
+        );
+        let max_concurrent_probes = parse_env(
+            "PIERRE_PROVIDER_HEALTH_MAX_CONCURRENCY",
+            DEFAULT_PROVIDER_PROBE_CONCURRENCY,
+        );
+
+        Self {
+            cache_ttl: Duration::from_secs(cache_ttl_secs),
+            probe_timeout: Duration::from_secs(timeout_secs),
+            max_concurrent_probes: max_concurrent_probes.max(1),
+        }
+    }
+}
+
+/// Parse a numeric environment variable, warning and falling back on invalid input
+fn parse_env<T>(name: &str, default: T) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    env::var(name)
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .inspect_err(|e| warn!("Invalid {} '{}': {}", name, value, e))
+                .ok()
+        })
+        .unwrap_or(default)
+}
+
+/// Connectivity result for one provider configured by one tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderHealthEntry {
+    /// Provider name (strava, fitbit, ...)
+    pub provider: String,
+    /// Tenant that configured the provider credentials
+    pub tenant: TenantId,
+    /// `healthy` when the probe succeeded, `degraded` otherwise
+    pub status: HealthStatus,
+    /// Probe duration in milliseconds
+    pub latency_ms: u64,
+    /// Failure reason when the probe did not succeed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Provider connectivity report across all tenants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderHealthReport {
+    /// `healthy` when every probe succeeded, `degraded` otherwise
+    pub status: HealthStatus,
+    /// One entry per tenant and configured provider
+    pub providers: Vec<ProviderHealthEntry>,
+    /// Current provider requests per tenant against its concurrency limit
+    pub in_flight: Vec<TenantInFlight>,
+    /// Remaining provider-advertised rate-limit budget per tenant and provider
+    pub budgets: Vec<TenantProviderBudget>,
+    /// When the probes ran (Unix seconds)
+    pub checked_at: u64,
+    /// Whether this report was served from cache
+    pub cached: bool,
+}
+
+/// Lightweight check that a tenant's credentials for a provider work
+#[async_trait]
+pub trait ProviderProbe: Send + Sync {
+    /// Return `Ok` when the provider accepted an authenticated request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing why the provider could not be reached
+    async fn probe(&self, tenant_id: TenantId, provider: &str) -> AppResult<()>;
+}
+
+/// Probe that fetches the athlete profile with a connected user's token
+///
+/// The token of any user connected to the provider through the tenant is used,
+/// refreshing it if needed, so a revoked OAuth app or broken credentials show up
+/// as a failed probe.
+pub struct TokenValidityProbe {
+    resources: Arc<ServerResources>,
+}
+
+impl TokenValidityProbe {
+    /// Create a probe using the server's provider registry and database
+    #[must_use]
+    pub const fn new(resources: Arc<ServerResources>) -> Self {
+        Self { resources }
+    }
+}
+
+#[async_trait]
+impl ProviderProbe for TokenValidityProbe {
+    async fn probe(&self, tenant_id: TenantId, provider: &str) -> AppResult<()> {
+        let tokens = self
+            .resources
+            .database
+            .get_tenant_provider_tokens(tenant_id, provider)
+            .await?;
+        let Some(token) = tokens.first() else {
+            return Err(AppError::not_found(format!(
+                "connected {provider} user to probe with"
+            )));
+        };
+
+        let tenant = tenant_id.to_string();
+        let client = AuthService::new(Arc::clone(&self.resources))
+            .create_authenticated_provider(provider, token.user_id, Some(&tenant))
+            .await
+            .map_err(|response| {
+                AppError::external_service(
+                    provider,
+                    response
+                        .error
+                        .unwrap_or_else(|| "Provider authentication failed".to_owned()),
+                )
+            })?;
+
+        client.get_athlete().await.map(|_| ())
+    }
+}
+
+/// Checks provider connectivity for every tenant with stored OAuth credentials
+pub struct ProviderHealthChecker {
+    database: Arc<Database>,
+    probe: Arc<dyn ProviderProbe>,
+    config: ProviderHealthConfig,
+    cached_report: RwLock<Option<(ProviderHealthReport, Instant)>>,
+    concurrency_limiter: Option<Arc<TenantConcurrencyLimiter>>,
+    budget_tracker: Option<Arc<ProviderBudgetTracker>>,
+}
+
+impl ProviderHealthChecker {
+    /// Create a checker using the given probe
+    #[must_use]
+    pub fn new(
+        database: Arc<Database>,
+        probe: Arc<dyn ProviderProbe>,
+        config: ProviderHealthConfig,
+    ) -> Self {
+        Self {
+            database,
+            probe,
+            config,
+            cached_report: RwLock::new(None),
+            concurrency_limiter: None,
+            budget_tracker: None,
+        }
+    }
+
+    /// Report in-flight provider requests tracked by `limiter`
+    #[must_use]
+    pub fn with_concurrency_limiter(mut self, limiter: Arc<TenantConcurrencyLimiter>) -> Self {
+        self.concurrency_limiter = Some(limiter);
+        self
+    }
+
+    /// Report provider rate-limit budgets tracked by `tracker`
+    #[must_use]
+    pub fn with_budget_tracker(mut self, tracker: Arc<ProviderBudgetTracker>) -> Self {
+        self.budget_tracker = Some(tracker);
+        self
+    }
+
+    /// Create a checker that probes providers with connected users' tokens
+    #[must_use]
+    pub fn from_resources(resources: &Arc<ServerResources>) -> Self {
+        Self::new(
+            Arc::clone(&resources.database),
+            Arc::new(TokenValidityProbe::new(Arc::clone(resources))),
+            ProviderHealthConfig::from_env(),
+        )
+        .with_concurrency_limiter(resources.provider_registry.concurrency_limiter())
+        .with_budget_tracker(resources.provider_registry.budget_tracker())
+    }
+
+    /// Return the provider connectivity report, probing only when the cache expired
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tenants or their provider credentials cannot be listed
+    pub async fn check(&self) -> AppResult<ProviderHealthReport> {
+        {
+            let cached = self.cached_report.read().await;
+            if let Some((report, checked_at)) = cached.as_ref() {
+                if checked_at.elapsed() < self.config.cache_ttl {
+                    return Ok(ProviderHealthReport {
+                        in_flight: self.in_flight(),
+                        budgets: self.budgets(),
+                        cached: true,
+                        ..report.clone()
+                    });
+                }
+            }
+        }
+
+        let targets = self.probe_targets().await?;
+        info!(targets = targets.len(), "Probing provider connectivity");
+
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrent_probes.max(1)));
+        let providers = join_all(
+            targets
+                .into_iter()
+                .map(|(tenant, provider)| self.probe_one(&semaphore, tenant, provider)),
+        )
+        .await;
+
+        let status = if providers
+            .iter()
+            .all(|entry| entry.status == HealthStatus::Healthy)
+        {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Degraded
+        };
+
+        let report = ProviderHealthReport {
+            status,
+            providers,
+            in_flight: self.in_flight(),
+            budgets: self.budgets(),
+            checked_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            cached: false,
+        };
+
+        *self.cached_report.write().await = Some((report.clone(), Instant::now()));
+
+        Ok(report)
+    }
+
+    /// Current in-flight provider requests per tenant, empty without a limiter
+    fn in_flight(&self) -> Vec<TenantInFlight> {
+        self.concurrency_limiter
+            .as_deref()
+            .map_or_else(Vec::new, TenantConcurrencyLimiter::in_flight)
+    }
+
+    /// Latest provider rate-limit budgets per tenant, empty without a tracker
+    fn budgets(&self) -> Vec<TenantProviderBudget> {
+        self.budget_tracker
+            .as_deref()
+            .map_or_else(Vec::new, ProviderBudgetTracker::budgets)
+    }
+
+    /// List every (tenant, provider) pair with stored OAuth credentials
+    async fn probe_targets(&self) -> AppResult<Vec<(TenantId, String)>> {
+        let mut targets = Vec::new();
+        for tenant in self.database.get_all_tenants().await? {
+            let credentials = self.database.get_tenant_oauth_providers(tenant.id).await?;
+            targets.extend(
+                credentials
+                    .into_iter()
+                    .map(|credential| (tenant.id, credential.provider)),
+            );
+        }
+        Ok(targets)
+    }
+
+    /// Run one probe once a semaphore permit is available
+    async fn probe_one(
+        &self,
+        semaphore: &Semaphore,
+        tenant: TenantId,
+        provider: String,
+    ) -> ProviderHealthEntry {
+        // The semaphore is never closed, so acquiring only fails if that changes
+        let _permit = semaphore.acquire().await.ok();
+
+        let start = Instant::now();
+        let outcome = timeout(
+            self.config.probe_timeout,
+            self.probe.probe(tenant, &provider),
+        )
+        .await;
+        let latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let message = match outcome {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e.to_string()),
+            Err(_) => Some(format!(
+                "Probe timed out after {:?}",
+                self.config.probe_timeout
+            )),
+        };
+
+        if let Some(reason) = &message {
+            warn!(tenant_id = %tenant, provider = %provider, reason = %reason, "Provider probe failed");
+        }
+
+        ProviderHealthEntry {
+            status: if message.is_none() {
+                HealthStatus::Healthy
+            } else {
+                HealthStatus::Degraded
+            },
+            provider,
+            tenant,
+            latency_ms,
+            message,
+        }
+    }
+}
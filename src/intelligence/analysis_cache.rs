@@ -0,0 +1,177 @@
+// ABOUTME: Versioned analysis result caching keyed on a hash of the exact inputs
+// ABOUTME: Reuses results for identical activities and algorithm versions across sessions
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::cache::factory::Cache;
+use crate::cache::{CacheKey, CacheResource};
+use crate::errors::{AppError, AppResult};
+use crate::models::{Activity, TenantId};
+
+/// Versions of the cached analysis algorithms
+///
+/// Bump a version whenever the algorithm's output for the same inputs changes;
+/// results cached under the previous version are then no longer reused.
+pub mod algorithm_versions {
+    /// `calculate_fitness_score` (CTL, consistency, and performance trend blend)
+    pub const FITNESS_SCORE: u32 = 1;
+    /// `analyze_training_load` (CTL/ATL/TSB with weekly TSS breakdown and overtraining insight)
+    pub const TRAINING_LOAD: u32 = 2;
+    /// `predict_performance` (Riegel race time prediction)
+    pub const PERFORMANCE_PREDICTION: u32 = 1;
+}
+
+/// Everything that determines the result of an analysis
+#[derive(Debug, Clone)]
+pub struct AnalysisInput<'a> {
+    /// Analysis name (e.g., `fitness_score`)
+    pub analysis: &'a str,
+    /// Version of the algorithm computing the result
+    pub algorithm_version: u32,
+    /// Activities the analysis runs over, in the order it receives them
+    pub activities: &'a [Activity],
+    /// Any other inputs, such as the timeframe or the date the analysis is relative to
+    pub parameters: Value,
+}
+
+impl AnalysisInput<'_> {
+    /// SHA-256 of the algorithm version, parameters, and every activity (hex-encoded)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an input cannot be serialized
+    pub fn input_hash(&self) -> AppResult<String> {
+        let serialize_error = |e: serde_json::Error| {
+            AppError::internal(format!("Failed to hash analysis input: {e}"))
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.analysis.as_bytes());
+        hasher.update(self.algorithm_version.to_be_bytes());
+        hasher.update(serde_json::to_vec(&self.parameters).map_err(serialize_error)?);
+        for activity in self.activities {
+            hasher.update(serde_json::to_vec(activity).map_err(serialize_error)?);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+/// Analysis result together with the version and input hash that produced it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedAnalysis {
+    /// Analysis name
+    pub analysis: String,
+    /// Algorithm version that produced `result`
+    pub algorithm_version: u32,
+    /// Hash of the inputs that produced `result`
+    pub input_hash: String,
+    /// When `result` was computed
+    pub computed_at: DateTime<Utc>,
+    /// The analysis output
+    pub result: Value,
+    /// Whether `result` was served from the cache rather than computed for this call
+    #[serde(skip)]
+    pub cache_hit: bool,
+}
+
+impl CachedAnalysis {
+    /// Cache metadata to attach to tool responses
+    #[must_use]
+    pub fn metadata(&self) -> Value {
+        serde_json::json!({
+            "cache_hit": self.cache_hit,
+            "algorithm_version": self.algorithm_version,
+            "input_hash": self.input_hash,
+            "computed_at": self.computed_at.to_rfc3339(),
+        })
+    }
+}
+
+/// Caches analysis results per user, keyed on the hash of their inputs
+///
+/// Identical inputs reuse the stored result; a changed activity, parameter, or
+/// algorithm version produces a new hash and forces recomputation. Cache failures
+/// never fail the analysis: they are logged and the result is computed.
+pub struct AnalysisResultCache<'a> {
+    cache: &'a Cache,
+    ttl: Duration,
+}
+
+impl<'a> AnalysisResultCache<'a> {
+    /// Create an analysis cache storing results for `ttl` (zero disables caching)
+    #[must_use]
+    pub const fn new(cache: &'a Cache, ttl: Duration) -> Self {
+        Self { cache, ttl }
+    }
+
+    /// Return the cached result for `input`, or compute and store it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be hashed
+    pub async fn get_or_compute<F>(
+        &self,
+        tenant_id: TenantId,
+        user_id: Uuid,
+        provider: &str,
+        input: &AnalysisInput<'_>,
+        compute: F,
+    ) -> AppResult<CachedAnalysis>
+    where
+        F: FnOnce() -> Value + Send,
+    {
+        let input_hash = input.input_hash()?;
+        let key = CacheKey::new(
+            tenant_id,
+            user_id,
+            provider.to_owned(),
+            CacheResource::AnalysisResult {
+                analysis: input.analysis.to_owned(),
+                algorithm_version: input.algorithm_version,
+                input_hash: input_hash.clone(),
+            },
+        );
+
+        if !self.ttl.is_zero() {
+            match self.cache.get::<CachedAnalysis>(&key).await {
+                Ok(Some(mut cached)) => {
+                    debug!(
+                        "Analysis cache hit for {} v{} ({})",
+                        input.analysis, input.algorithm_version, input_hash
+                    );
+                    cached.cache_hit = true;
+                    return Ok(cached);
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Analysis cache read failed for {}: {}", input.analysis, e),
+            }
+        }
+
+        let computed = CachedAnalysis {
+            analysis: input.analysis.to_owned(),
+            algorithm_version: input.algorithm_version,
+            input_hash,
+            computed_at: Utc::now(),
+            result: compute(),
+            cache_hit: false,
+        };
+
+        if !self.ttl.is_zero() {
+            if let Err(e) = self.cache.set(&key, &computed, self.ttl).await {
+                warn!("Analysis cache write failed for {}: {}", input.analysis, e);
+            }
+        }
+
+        Ok(computed)
+    }
+}
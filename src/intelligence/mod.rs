@@ -17,15 +17,18 @@ pub use pierre_intelligence::*;
 
 // Re-export submodules for path-based access (e.g., crate::intelligence::algorithms::FtpAlgorithm)
 pub use pierre_intelligence::{
-    activity_analyzer, algorithms, analysis_config, analyzer, friend_activity_cache, goal_engine,
-    insight_adapter, insights, metrics, metrics_extractor, nutrition_calculator, pattern_detection,
-    performance_analyzer, performance_analyzer_v2, performance_prediction, physiological_constants,
-    recipes, recommendation_engine, recovery_calculator, sleep_analysis, statistical_analysis,
-    training_load, visitor,
+    activity_analyzer, algorithms, analysis_config, analyzer, custom_metrics,
+    friend_activity_cache, gear_wear, goal_engine, insight_adapter, insights, metrics,
+    metrics_extractor, nutrition_calculator, pattern_detection, performance_analyzer,
+    performance_analyzer_v2, performance_prediction, physiological_constants, recipes,
+    recommendation_engine, recovery_calculator, segment_prs, sleep_analysis, splits,
+    statistical_analysis, time_in_zones, training_load, training_plan, visitor,
 };
 
 // Local submodules that remain in the main crate (external deps: HTTP, LLM, etc.)
 
+/// Versioned analysis result caching keyed on input hashes
+pub mod analysis_cache;
 /// Location and geographic context
 pub mod location;
 /// Weather data integration and analysis
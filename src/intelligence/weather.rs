@@ -281,7 +281,23 @@ impl WeatherService {
             )));
         }
 
-        let weather_response: OpenWeatherResponse = response.json().await?;
+        let body = response.text().await?;
+        Self::parse_openweather_response(&body, timestamp)
+    }
+
+    /// Convert an `OpenWeatherMap` timemachine response body into conditions
+    ///
+    /// Picks the hourly data point closest to `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not a valid timemachine response or has no data points
+    pub fn parse_openweather_response(
+        body: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<WeatherConditions, WeatherError> {
+        let weather_response: OpenWeatherResponse = serde_json::from_str(body)
+            .map_err(|e| WeatherError::ApiError(format!("Invalid OpenWeather response: {e}")))?;
 
         // Find the closest data point to our timestamp
         let target_timestamp = timestamp.timestamp();
@@ -35,6 +35,8 @@
 //! let error_response = JsonRpcResponse::error(request.id, -32600, "Invalid Request");
 //! ```
 
+use crate::errors::{AppError, ErrorCode, ErrorDetails};
+use crate::middleware::current_request_id;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -43,6 +45,9 @@ use std::fmt;
 /// JSON-RPC 2.0 version string
 pub const JSONRPC_VERSION: &str = "2.0";
 
+/// Response metadata key carrying the HTTP request correlation ID
+pub const REQUEST_ID_METADATA_KEY: &str = "request_id";
+
 /// JSON-RPC 2.0 Request
 ///
 /// This is the unified request structure used by all protocols.
@@ -121,11 +126,20 @@ pub struct JsonRpcResponse {
 
     /// Request identifier for correlation
     pub id: Option<Value>,
+
+    /// Protocol-specific metadata (additional extensions)
+    /// Not part of JSON-RPC spec; carries e.g. the HTTP request correlation ID
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub metadata: HashMap<String, String>,
 }
 
 /// JSON-RPC 2.0 Error Object
 ///
 /// Standard error structure with code, message, and optional data.
+/// Errors built with [`JsonRpcError::with_error_code`],
+/// [`JsonRpcError::from_details`] or [`JsonRpcError::from_app_error`] carry
+/// an [`ErrorDetails`] object as `data`, so clients can branch on its stable
+/// `code` and `retriable` fields.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsonRpcError {
     /// Error code (standard codes: -32700 to -32600)
@@ -205,6 +219,7 @@ impl JsonRpcResponse {
             result: Some(result),
             error: None,
             id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -220,6 +235,7 @@ impl JsonRpcResponse {
                 data: None,
             }),
             id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -240,6 +256,36 @@ impl JsonRpcResponse {
                 data: Some(data),
             }),
             id,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Create an error response carrying a stable error code
+    #[must_use]
+    pub fn error_with_code(
+        id: Option<Value>,
+        code: i32,
+        error_code: ErrorCode,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::from_error(id, JsonRpcError::with_error_code(code, error_code, message))
+    }
+
+    /// Create an error response from an application error
+    #[must_use]
+    pub fn from_app_error(id: Option<Value>, error: &AppError) -> Self {
+        Self::from_error(id, JsonRpcError::from_app_error(error))
+    }
+
+    /// Create an error response from an error object
+    #[must_use]
+    pub fn from_error(id: Option<Value>, error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_owned(),
+            result: None,
+            error: Some(error),
+            id,
+            metadata: HashMap::new(),
         }
     }
 
@@ -254,6 +300,28 @@ impl JsonRpcResponse {
     pub const fn is_error(&self) -> bool {
         self.error.is_some()
     }
+
+    /// Add metadata to the response
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get metadata value
+    #[must_use]
+    pub fn get_metadata(&self, key: &str) -> Option<&String> {
+        self.metadata.get(key)
+    }
+
+    /// Tag the response with the ID of the HTTP request being handled, if any
+    #[must_use]
+    pub fn with_current_request_id(self) -> Self {
+        match current_request_id() {
+            Some(request_id) => self.with_metadata(REQUEST_ID_METADATA_KEY, request_id.0),
+            None => self,
+        }
+    }
 }
 
 impl JsonRpcError {
@@ -276,6 +344,52 @@ impl JsonRpcError {
             data: Some(data),
         }
     }
+
+    /// Create an error whose data holds the details for `error_code`
+    #[must_use]
+    pub fn with_error_code(code: i32, error_code: ErrorCode, message: impl Into<String>) -> Self {
+        Self::from_details(code, ErrorDetails::new(error_code, message))
+    }
+
+    /// Create an error with `details` as its data and their message as its message
+    #[must_use]
+    pub fn from_details(code: i32, details: ErrorDetails) -> Self {
+        Self {
+            code,
+            message: details.message.clone(),
+            data: serde_json::to_value(details).ok(),
+        }
+    }
+
+    /// Create an error from an application error, mapping its code to a JSON-RPC code
+    #[must_use]
+    pub fn from_app_error(error: &AppError) -> Self {
+        Self::from_details(error.code.jsonrpc_code(), ErrorDetails::from(error))
+    }
+
+    /// Add a protocol-specific field next to the error details in `data`
+    #[must_use]
+    pub fn with_extra(mut self, key: &str, value: Value) -> Self {
+        match &mut self.data {
+            Some(Value::Object(data)) => {
+                data.insert(key.to_owned(), value);
+            }
+            _ => {
+                let mut data = serde_json::Map::new();
+                data.insert(key.to_owned(), value);
+                self.data = Some(Value::Object(data));
+            }
+        }
+        self
+    }
+
+    /// Error details carried in `data`, if the error has them
+    #[must_use]
+    pub fn details(&self) -> Option<ErrorDetails> {
+        self.data
+            .as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
 }
 
 /// Standard JSON-RPC error codes
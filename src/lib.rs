@@ -138,9 +138,15 @@ pub mod middleware;
 /// Health checks and monitoring
 pub mod health;
 
+/// Prometheus metrics registry for tool, provider, and rate limit instrumentation
+pub mod metrics;
+
 /// `API` key management for B2B authentication
 pub mod api_keys;
 
+/// Pluggable storage backends for `API` key and A2A usage analytics
+pub mod analytics;
+
 /// `HTTP` routes for `API` key management
 pub mod api_key_routes;
 
@@ -14,6 +14,8 @@
 
 /// Core system plugin adapters (database, cache, auth)
 pub mod plugins;
+/// Graceful shutdown: signal handling and draining of in-flight work
+pub mod shutdown;
 
 use crate::errors::{AppError, AppResult};
 use async_trait::async_trait;
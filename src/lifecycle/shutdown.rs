@@ -0,0 +1,333 @@
+// ABOUTME: Graceful shutdown coordination for the HTTP server and A2A tasks
+// ABOUTME: Stops accepting connections, drains in-flight work within a grace period, then closes the database
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Graceful Shutdown
+//!
+//! On SIGTERM or Ctrl-C the [`ShutdownCoordinator`] records a deadline one
+//! grace period away. The HTTP server stops accepting connections and keeps
+//! serving in-flight requests until they finish or the deadline passes, then
+//! [`finish_shutdown`] waits for running A2A tasks until the same deadline.
+//! Tasks still running at the deadline are marked
+//! [`TaskStatus::Interrupted`] so clients can tell them apart from failures,
+//! and the database pool is closed last.
+//!
+//! Audit records are written before a request's response is returned, so
+//! draining the requests persists them. Usage records buffered by the
+//! analytics sink are flushed just before the pool is closed.
+
+use crate::a2a::protocol::TaskStatus;
+use crate::a2a::task_updates::update_task_status;
+use crate::errors::{AppError, AppResult};
+use crate::mcp::resources::ServerResources;
+use axum::extract::{Request, State};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+use std::collections::HashSet;
+use std::future::IntoFuture;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::{watch, Notify};
+use tokio::time::{sleep_until, timeout_at, Instant};
+use tracing::{error, info, warn};
+
+/// Error stored on A2A tasks that were still running at the shutdown deadline
+pub const INTERRUPTED_TASK_ERROR: &str = "Server shut down before the task finished";
+
+/// Tracks in-flight work and signals every part of the server to shut down
+pub struct ShutdownCoordinator {
+    grace_period: Duration,
+    deadline: watch::Sender<Option<Instant>>,
+    in_flight_requests: AtomicUsize,
+    running_tasks: Mutex<HashSet<String>>,
+    changed: Notify,
+}
+
+/// Counts a request as in flight until dropped
+pub struct InFlightRequest<'a> {
+    coordinator: &'a ShutdownCoordinator,
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        self.coordinator
+            .in_flight_requests
+            .fetch_sub(1, Ordering::SeqCst);
+        self.coordinator.changed.notify_waiters();
+    }
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator that gives in-flight work `grace_period` to finish
+    #[must_use]
+    pub fn new(grace_period: Duration) -> Self {
+        Self {
+            grace_period,
+            deadline: watch::Sender::new(None),
+            in_flight_requests: AtomicUsize::new(0),
+            running_tasks: Mutex::new(HashSet::new()),
+            changed: Notify::new(),
+        }
+    }
+
+    /// How long in-flight work may take once shutdown starts
+    #[must_use]
+    pub const fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
+
+    /// Start shutting down, returning false if shutdown had already started
+    ///
+    /// The deadline is set by the first call and is not extended by later ones.
+    pub fn trigger(&self) -> bool {
+        let deadline = Instant::now() + self.grace_period;
+        self.deadline.send_if_modified(|current| {
+            if current.is_some() {
+                return false;
+            }
+            *current = Some(deadline);
+            true
+        })
+    }
+
+    /// Whether shutdown has started
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        self.deadline.borrow().is_some()
+    }
+
+    /// Wait until shutdown starts and return the deadline for in-flight work
+    pub async fn triggered(&self) -> Instant {
+        let mut receiver = self.deadline.subscribe();
+        let deadline = receiver
+            .wait_for(Option::is_some)
+            .await
+            .ok()
+            .and_then(|deadline| *deadline);
+        // The sender lives as long as `self`, so the wait only ends once triggered
+        deadline.unwrap_or_else(Instant::now)
+    }
+
+    /// Trigger shutdown when the process receives Ctrl-C or SIGTERM
+    pub fn spawn_signal_listener(self: &Arc<Self>) {
+        let coordinator = Arc::clone(self);
+        tokio::spawn(async move {
+            wait_for_signal().await;
+            if coordinator.trigger() {
+                info!(
+                    "Shutdown signal received, draining in-flight work for up to {}s",
+                    coordinator.grace_period.as_secs()
+                );
+            }
+        });
+    }
+
+    /// Count a request as in flight until the returned guard is dropped
+    #[must_use]
+    pub fn track_request(&self) -> InFlightRequest<'_> {
+        self.in_flight_requests.fetch_add(1, Ordering::SeqCst);
+        InFlightRequest { coordinator: self }
+    }
+
+    /// Number of HTTP requests currently being served
+    #[must_use]
+    pub fn in_flight_requests(&self) -> usize {
+        self.in_flight_requests.load(Ordering::SeqCst)
+    }
+
+    /// Record that an A2A task started running
+    pub fn task_started(&self, task_id: &str) {
+        self.tasks().insert(task_id.to_owned());
+    }
+
+    /// Record that an A2A task reached a terminal status
+    pub fn task_finished(&self, task_id: &str) {
+        self.tasks().remove(task_id);
+        self.changed.notify_waiters();
+    }
+
+    /// IDs of the A2A tasks currently running
+    #[must_use]
+    pub fn running_tasks(&self) -> Vec<String> {
+        let mut task_ids: Vec<String> = self.tasks().iter().cloned().collect();
+        task_ids.sort_unstable();
+        task_ids
+    }
+
+    /// Wait until no requests are in flight, returning false if the deadline passed first
+    pub async fn wait_for_requests(&self, deadline: Instant) -> bool {
+        self.wait_until(deadline, |coordinator| {
+            coordinator.in_flight_requests() == 0
+        })
+        .await
+    }
+
+    /// Wait until no A2A tasks are running, returning false if the deadline passed first
+    pub async fn wait_for_tasks(&self, deadline: Instant) -> bool {
+        self.wait_until(deadline, |coordinator| coordinator.tasks().is_empty())
+            .await
+    }
+
+    async fn wait_until(&self, deadline: Instant, done: impl Fn(&Self) -> bool) -> bool {
+        loop {
+            let notified = self.changed.notified();
+            tokio::pin!(notified);
+            // Register before checking so a change in between is not missed
+            notified.as_mut().enable();
+            if done(self) {
+                return true;
+            }
+            if timeout_at(deadline, notified).await.is_err() {
+                return done(self);
+            }
+        }
+    }
+
+    fn tasks(&self) -> MutexGuard<'_, HashSet<String>> {
+        self.running_tasks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Wait for Ctrl-C, or SIGTERM on Unix
+async fn wait_for_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Middleware counting each request as in flight while it is served
+pub async fn track_in_flight(
+    State(coordinator): State<Arc<ShutdownCoordinator>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let _in_flight = coordinator.track_request();
+    next.run(req).await
+}
+
+/// Serve `app` until shutdown is triggered, then drain in-flight requests
+///
+/// New connections are refused as soon as shutdown starts. Returns true if
+/// every in-flight request finished, or false if the grace period ran out
+/// first and the remaining connections were dropped.
+///
+/// # Errors
+///
+/// Returns an error if the server fails before shutdown
+pub async fn serve_with_graceful_shutdown(
+    listener: TcpListener,
+    app: Router,
+    coordinator: Arc<ShutdownCoordinator>,
+) -> AppResult<bool> {
+    let app = app.layer(middleware::from_fn_with_state(
+        Arc::clone(&coordinator),
+        track_in_flight,
+    ));
+    let signal = {
+        let coordinator = Arc::clone(&coordinator);
+        async move {
+            coordinator.triggered().await;
+            info!("HTTP server no longer accepting connections");
+        }
+    };
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(signal)
+    .into_future();
+
+    tokio::select! {
+        result = server => {
+            result.map_err(|e| AppError::internal(format!("Transport error: {e}")))?;
+            info!("HTTP server drained all in-flight requests");
+            Ok(true)
+        }
+        () = async { sleep_until(coordinator.triggered().await).await } => {
+            warn!(
+                "Shutdown grace period elapsed with {} request(s) still in flight",
+                coordinator.in_flight_requests()
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Wait for running A2A tasks, interrupt the rest, flush buffered usage, and close the database pool
+///
+/// Triggers shutdown if it has not started yet and waits until its deadline.
+/// Returns the IDs of tasks marked [`TaskStatus::Interrupted`].
+pub async fn finish_shutdown(resources: &ServerResources) -> Vec<String> {
+    resources.shutdown.trigger();
+    let deadline = resources.shutdown.triggered().await;
+    let interrupted = interrupt_running_tasks(resources, deadline).await;
+
+    if let Err(e) = resources.analytics.flush().await {
+        error!("Failed to flush buffered usage records: {}", e);
+    }
+    resources.database.close().await;
+    info!("Database connections closed, shutdown complete");
+    interrupted
+}
+
+/// Wait for running A2A tasks until `deadline`, then mark the rest interrupted
+///
+/// Returns the IDs of tasks marked [`TaskStatus::Interrupted`].
+pub async fn interrupt_running_tasks(
+    resources: &ServerResources,
+    deadline: Instant,
+) -> Vec<String> {
+    let coordinator = &resources.shutdown;
+    if coordinator.wait_for_tasks(deadline).await {
+        return Vec::new();
+    }
+
+    let interrupted = coordinator.running_tasks();
+    for task_id in &interrupted {
+        warn!("A2A task {} still running at shutdown deadline", task_id);
+        if let Err(e) = update_task_status(
+            resources,
+            task_id,
+            TaskStatus::Interrupted,
+            None,
+            Some(INTERRUPTED_TASK_ERROR.to_owned()),
+        )
+        .await
+        {
+            error!("Failed to mark A2A task {} interrupted: {}", task_id, e);
+        }
+    }
+    interrupted
+}
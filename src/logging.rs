@@ -6,9 +6,19 @@
 
 //! Production-ready logging configuration with structured output
 
+/// Structured JSON output with request-scoped context fields
+pub mod json;
+/// Masking of emails, tokens, and API keys in formatted log output
+pub mod redaction;
 /// Tenant-aware logging utilities and context management
 pub mod tenant;
 
+/// Re-export JSON output building blocks
+pub use json::{JsonEventFormat, RequestContextLayer};
+
+/// Re-export log output redaction
+pub use redaction::{LogRedactor, RedactingMakeWriter};
+
 /// Re-export tenant logging utilities
 pub use tenant::{
     record_performance_metrics, record_request_context, record_tenant_context, ProviderApiContext,
@@ -20,6 +30,7 @@ use crate::errors::AppResult;
 use serde_json::json;
 use std::env;
 use std::io;
+use std::sync::Arc;
 use tracing::{info, warn};
 use tracing_subscriber::{
     fmt::{self, format::FmtSpan},
@@ -71,6 +82,8 @@ pub struct LoggingConfig {
     pub features: LogFeatures,
     /// Request ID header name
     pub request_id_header: String,
+    /// Redaction applied to every formatted log line
+    pub redaction: LogRedactor,
 }
 
 /// Log output format options
@@ -103,6 +116,7 @@ impl Default for LoggingConfig {
                 truncate_mcp: true, // Default to readable logs
             },
             request_id_header: "x-request-id".into(),
+            redaction: LogRedactor::default(),
         }
     }
 }
@@ -113,7 +127,11 @@ impl LoggingConfig {
     pub fn from_env() -> Self {
         let level = env::var("RUST_LOG").unwrap_or_else(|_| "info".into());
 
-        let format = match env::var("LOG_FORMAT").as_deref() {
+        // PIERRE_LOG_FORMAT takes precedence over the legacy LOG_FORMAT
+        let format = match env::var("PIERRE_LOG_FORMAT")
+            .or_else(|_| env::var("LOG_FORMAT"))
+            .as_deref()
+        {
             Ok("json") => LogFormat::Json,
             Ok("compact") => LogFormat::Compact,
             _ => LogFormat::Pretty,
@@ -148,6 +166,7 @@ impl LoggingConfig {
             },
             request_id_header: env::var("REQUEST_ID_HEADER")
                 .unwrap_or_else(|_| "x-request-id".into()),
+            redaction: LogRedactor::from_env(),
         }
     }
 
@@ -214,24 +233,23 @@ impl LoggingConfig {
 
         // Create base registry
         let registry = tracing_subscriber::registry().with(env_filter);
+        let writer = RedactingMakeWriter::new(io::stdout, Arc::new(self.redaction.clone()));
 
         match self.format {
             LogFormat::Json => {
                 let json_layer = fmt::layer()
-                    .with_file(self.output.location)
-                    .with_line_number(self.output.location)
-                    .with_thread_ids(self.output.thread)
-                    .with_thread_names(self.output.thread)
-                    .with_target(true)
-                    .with_writer(io::stdout)
+                    .event_format(JsonEventFormat::new(
+                        self.output.location,
+                        self.output.thread,
+                    ))
+                    .with_writer(writer)
                     .with_span_events(if self.output.spans {
                         FmtSpan::NEW | FmtSpan::CLOSE
                     } else {
                         FmtSpan::NONE
-                    })
-                    .json();
+                    });
 
-                registry.with(json_layer).init();
+                registry.with(RequestContextLayer).with(json_layer).init();
             }
             LogFormat::Pretty => {
                 let pretty_layer = fmt::layer()
@@ -240,7 +258,7 @@ impl LoggingConfig {
                     .with_thread_ids(self.output.thread)
                     .with_thread_names(self.output.thread)
                     .with_target(true)
-                    .with_writer(io::stdout)
+                    .with_writer(writer)
                     .with_span_events(if self.output.spans {
                         FmtSpan::NEW | FmtSpan::CLOSE
                     } else {
@@ -257,7 +275,7 @@ impl LoggingConfig {
                     .with_thread_ids(false)
                     .with_thread_names(false)
                     .with_target(false)
-                    .with_writer(io::stdout)
+                    .with_writer(writer)
                     .with_span_events(FmtSpan::NONE);
 
                 registry.with(compact_layer).init();
@@ -334,6 +352,7 @@ impl LoggingConfig {
                 truncate_mcp: false, // Production wants full logs
             },
             request_id_header: "x-request-id".into(),
+            redaction: LogRedactor::default(),
         }
     }
 }
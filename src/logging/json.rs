@@ -0,0 +1,268 @@
+// ABOUTME: Line-delimited JSON log output with request-scoped tenant, user, and request identifiers
+// ABOUTME: Span layer captures correlation fields; event formatter lifts them to top-level JSON keys
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Structured JSON log lines for log aggregators
+//!
+//! Every line is one JSON object with the same top-level keys:
+//! `timestamp`, `level`, `target`, `message`, `tenant_id`, `user_id` and `request_id`.
+//! The identifiers come from the innermost enclosing span that recorded them
+//! (see [`crate::middleware::tracing::create_request_span`]) and are `null` outside
+//! a request. Remaining event fields are nested under `fields`.
+
+use std::fmt;
+use std::thread;
+
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Span and event field names lifted to top-level keys of every JSON line
+const CONTEXT_FIELDS: [&str; 3] = ["tenant_id", "user_id", "request_id"];
+
+/// Request correlation identifiers recorded on a span
+#[derive(Debug, Clone, Default)]
+struct SpanRequestContext {
+    tenant_id: Option<String>,
+    user_id: Option<String>,
+    request_id: Option<String>,
+}
+
+impl SpanRequestContext {
+    fn slot(&mut self, name: &str) -> Option<&mut Option<String>> {
+        match name {
+            "tenant_id" => Some(&mut self.tenant_id),
+            "user_id" => Some(&mut self.user_id),
+            "request_id" => Some(&mut self.request_id),
+            _ => None,
+        }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.tenant_id.is_none() && self.user_id.is_none() && self.request_id.is_none()
+    }
+
+    /// Take values set on an inner span over the ones inherited from outer spans
+    fn overlay(&mut self, inner: &Self) {
+        for (slot, value) in [
+            (&mut self.tenant_id, &inner.tenant_id),
+            (&mut self.user_id, &inner.user_id),
+            (&mut self.request_id, &inner.request_id),
+        ] {
+            if value.is_some() {
+                slot.clone_from(value);
+            }
+        }
+    }
+}
+
+impl Visit for SpanRequestContext {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if let Some(slot) = self.slot(field.name()) {
+            *slot = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if let Some(slot) = self.slot(field.name()) {
+            *slot = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// Layer that stores `tenant_id`, `user_id` and `request_id` span fields for the JSON formatter
+///
+/// Fields declared as `Empty` and recorded later (e.g. after authentication) are
+/// picked up as well.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestContextLayer;
+
+impl<S> Layer<S> for RequestContextLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut context = SpanRequestContext::default();
+        attrs.record(&mut context);
+        if context.is_empty() {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(context);
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(context) = extensions.get_mut::<SpanRequestContext>() {
+            values.record(context);
+            return;
+        }
+        let mut context = SpanRequestContext::default();
+        values.record(&mut context);
+        if !context.is_empty() {
+            extensions.insert(context);
+        }
+    }
+}
+
+/// Collects event fields into a JSON map, separating the message
+#[derive(Default)]
+struct JsonFieldVisitor {
+    message: Option<String>,
+    fields: Map<String, Value>,
+}
+
+impl JsonFieldVisitor {
+    fn insert(&mut self, field: &Field, value: Value) {
+        if field.name() == "message" {
+            self.message = Some(match value {
+                Value::String(s) => s,
+                other => other.to_string(),
+            });
+        } else {
+            self.fields.insert(field.name().to_owned(), value);
+        }
+    }
+}
+
+impl Visit for JsonFieldVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, Value::from(format!("{value:?}")));
+    }
+}
+
+/// Event formatter producing one JSON object per line with consistent top-level keys
+///
+/// Use together with [`RequestContextLayer`] so span identifiers are available.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonEventFormat {
+    /// Include source file and line number
+    pub location: bool,
+    /// Include thread id and name
+    pub thread: bool,
+}
+
+impl JsonEventFormat {
+    /// Create a formatter with the given optional fields
+    #[must_use]
+    pub const fn new(location: bool, thread: bool) -> Self {
+        Self { location, thread }
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for JsonEventFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+
+        let mut visitor = JsonFieldVisitor::default();
+        event.record(&mut visitor);
+
+        let mut context = SpanRequestContext::default();
+        let mut span_name = None;
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                if let Some(span_context) = span.extensions().get::<SpanRequestContext>() {
+                    context.overlay(span_context);
+                }
+                span_name = Some(span.name());
+            }
+        }
+
+        let mut line = Map::new();
+        line.insert(
+            "timestamp".to_owned(),
+            Value::from(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+        );
+        line.insert("level".to_owned(), Value::from(metadata.level().as_str()));
+        line.insert("target".to_owned(), Value::from(metadata.target()));
+        line.insert(
+            "message".to_owned(),
+            visitor.message.take().map_or(Value::Null, Value::from),
+        );
+
+        // Identifiers logged directly on the event win over span context
+        for (name, span_value) in
+            CONTEXT_FIELDS
+                .into_iter()
+                .zip([context.tenant_id, context.user_id, context.request_id])
+        {
+            let value = visitor
+                .fields
+                .remove(name)
+                .or_else(|| span_value.map(Value::from))
+                .unwrap_or(Value::Null);
+            line.insert(name.to_owned(), value);
+        }
+
+        if let Some(name) = span_name {
+            line.insert("span".to_owned(), Value::from(name));
+        }
+        if self.location {
+            line.insert(
+                "file".to_owned(),
+                metadata.file().map_or(Value::Null, Value::from),
+            );
+            line.insert(
+                "line".to_owned(),
+                metadata.line().map_or(Value::Null, Value::from),
+            );
+        }
+        if self.thread {
+            let current = thread::current();
+            line.insert(
+                "thread_id".to_owned(),
+                Value::from(format!("{:?}", current.id())),
+            );
+            line.insert(
+                "thread_name".to_owned(),
+                current.name().map_or(Value::Null, Value::from),
+            );
+        }
+        if !visitor.fields.is_empty() {
+            line.insert("fields".to_owned(), Value::Object(visitor.fields));
+        }
+
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
@@ -0,0 +1,228 @@
+// ABOUTME: Masks emails, bearer tokens, and API keys in formatted log output before it is written
+// ABOUTME: Wraps any MakeWriter so pretty, compact, and JSON formats share the same redaction pass
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Redaction of sensitive values in log output
+//!
+//! Redaction runs on the fully formatted line, so it covers messages, fields and
+//! span context alike, whichever output format is configured:
+//!
+//! - email addresses are masked with [`mask_email`] (`u***@e***.com`)
+//! - `Bearer <token>` credentials become `Bearer [REDACTED]`
+//! - API keys (`pk_live_...`, `pk_trial_...`) become `[REDACTED]`
+//!
+//! Allowlisted values are left as-is to help debugging. An entry matches a value
+//! exactly, and entries starting with `@` match every email in that domain.
+//!
+//! ```rust
+//! use pierre_mcp_server::logging::LogRedactor;
+//!
+//! let redactor = LogRedactor::default().with_allowlist(["@pierre.test"]);
+//! let line = redactor.redact("login by alice@example.com and ops@pierre.test");
+//! assert_eq!(line, "login by a***@e***.com and ops@pierre.test");
+//! ```
+
+use std::borrow::Cow;
+use std::io;
+use std::sync::{Arc, OnceLock};
+
+use regex::{Captures, Regex};
+use tracing::Metadata;
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::config::logging::LoggingConfig as RedactionSettings;
+use crate::middleware::redaction::{mask_email, RedactionFeatures};
+
+/// Sensitive value patterns, compiled once
+struct Patterns {
+    bearer: Regex,
+    api_key: Regex,
+    email: Regex,
+}
+
+/// Get the compiled patterns (cached)
+///
+/// Returns None if compilation fails (should never happen with hardcoded patterns)
+fn patterns() -> Option<&'static Patterns> {
+    static PATTERNS: OnceLock<Option<Patterns>> = OnceLock::new();
+    PATTERNS
+        .get_or_init(|| {
+            Some(Patterns {
+                bearer: Regex::new(r"\b(Bearer\s+)([A-Za-z0-9\-._~+/]+=*)").ok()?,
+                api_key: Regex::new(r"\bpk_[a-z]+_[A-Za-z0-9]{8,}").ok()?,
+                email: Regex::new(r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}").ok()?,
+            })
+        })
+        .as_ref()
+}
+
+/// Masks sensitive values in formatted log lines
+#[derive(Debug, Clone)]
+pub struct LogRedactor {
+    enabled: bool,
+    mask_emails: bool,
+    placeholder: String,
+    allowlist: Vec<String>,
+}
+
+impl Default for LogRedactor {
+    fn default() -> Self {
+        Self::from_settings(&RedactionSettings::default())
+    }
+}
+
+impl LogRedactor {
+    /// Create a redactor from the logging redaction settings
+    #[must_use]
+    pub fn from_settings(settings: &RedactionSettings) -> Self {
+        Self {
+            enabled: settings.redact_pii,
+            mask_emails: settings
+                .redaction_features
+                .contains(RedactionFeatures::EMAILS),
+            placeholder: settings.redaction_placeholder.clone(),
+            allowlist: settings.redaction_allowlist.clone(),
+        }
+    }
+
+    /// Create a redactor from `PIERRE_LOG_REDACT*` environment variables
+    ///
+    /// Reads the environment directly because logging is initialized before the
+    /// server configuration is loaded.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_settings(&RedactionSettings::from_env())
+    }
+
+    /// Leave the given values unredacted
+    #[must_use]
+    pub fn with_allowlist<I, S>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowlist.extend(entries.into_iter().map(Into::into));
+        self
+    }
+
+    /// Check if redaction is disabled
+    #[must_use]
+    pub const fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+
+    /// Mask sensitive values in a formatted log line
+    #[must_use]
+    pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let Some(patterns) = patterns().filter(|_| self.enabled) else {
+            return Cow::Borrowed(text);
+        };
+
+        let text = patterns.bearer.replace_all(text, |caps: &Captures| {
+            if self.is_allowed(&caps[2]) {
+                caps[0].to_owned()
+            } else {
+                format!("{}{}", &caps[1], self.placeholder)
+            }
+        });
+        let text = replace_cow(text, |line| {
+            patterns.api_key.replace_all(line, |caps: &Captures| {
+                if self.is_allowed(&caps[0]) {
+                    caps[0].to_owned()
+                } else {
+                    self.placeholder.clone()
+                }
+            })
+        });
+        if !self.mask_emails {
+            return text;
+        }
+        replace_cow(text, |line| {
+            patterns.email.replace_all(line, |caps: &Captures| {
+                if self.is_allowed(&caps[0]) {
+                    caps[0].to_owned()
+                } else {
+                    mask_email(&caps[0])
+                }
+            })
+        })
+    }
+
+    fn is_allowed(&self, value: &str) -> bool {
+        self.allowlist.iter().any(|entry| {
+            entry == value || (entry.starts_with('@') && value.ends_with(entry.as_str()))
+        })
+    }
+}
+
+/// Apply a replacement pass, keeping the borrow when nothing matched
+fn replace_cow<'a>(text: Cow<'a, str>, pass: impl Fn(&str) -> Cow<'_, str>) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(line) => pass(line),
+        Cow::Owned(line) => Cow::Owned(pass(&line).into_owned()),
+    }
+}
+
+/// `MakeWriter` that redacts every formatted line before handing it to the inner writer
+#[derive(Debug, Clone)]
+pub struct RedactingMakeWriter<M> {
+    inner: M,
+    redactor: Arc<LogRedactor>,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    /// Wrap a writer factory with the given redactor
+    #[must_use]
+    pub const fn new(inner: M, redactor: Arc<LogRedactor>) -> Self {
+        Self { inner, redactor }
+    }
+}
+
+impl<'a, M> MakeWriter<'a> for RedactingMakeWriter<M>
+where
+    M: MakeWriter<'a>,
+{
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer(),
+            redactor: Arc::clone(&self.redactor),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer_for(meta),
+            redactor: Arc::clone(&self.redactor),
+        }
+    }
+}
+
+/// Writer produced by [`RedactingMakeWriter`]
+///
+/// The fmt layer writes each event as a single buffer, so patterns never
+/// straddle two writes.
+#[derive(Debug)]
+pub struct RedactingWriter<W> {
+    inner: W,
+    redactor: Arc<LogRedactor>,
+}
+
+impl<W: io::Write> io::Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match std::str::from_utf8(buf) {
+            Ok(text) => self
+                .inner
+                .write_all(self.redactor.redact(text).as_bytes())?,
+            Err(_) => self.inner.write_all(buf)?,
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,85 @@
+// ABOUTME: Exposes the configuration parameter catalog as browsable MCP resources
+// ABOUTME: Lists one resource per parameter and reads its effective value with catalog metadata
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Configuration Parameter Resources
+//!
+//! Every parameter in the configuration catalog is available to MCP clients as a
+//! resource at `pierre://config/{parameter}`, e.g.
+//! `pierre://config/heart_rate.anaerobic_threshold`. `resources/list` returns one
+//! entry per parameter and `resources/read` returns the parameter's current
+//! effective value together with its type, valid range, units and description.
+
+use serde_json::{json, Value};
+
+use crate::config::catalog::{CatalogBuilder, ConfigParameter};
+use crate::config::runtime::RuntimeConfig;
+
+/// URI scheme and path shared by all configuration parameter resources
+pub const CONFIG_RESOURCE_URI_PREFIX: &str = "pierre://config/";
+
+/// MIME type of configuration parameter resource contents
+const CONFIG_RESOURCE_MIME_TYPE: &str = "application/json";
+
+/// Stable resource URI of the configuration parameter `key`
+#[must_use]
+pub fn config_resource_uri(key: &str) -> String {
+    format!("{CONFIG_RESOURCE_URI_PREFIX}{key}")
+}
+
+/// Resource descriptors for every parameter in the configuration catalog
+#[must_use]
+pub fn list_config_resources() -> Vec<Value> {
+    CatalogBuilder::build()
+        .categories
+        .into_iter()
+        .flat_map(|category| category.modules)
+        .flat_map(|module| module.parameters)
+        .map(|parameter| {
+            json!({
+                "uri": config_resource_uri(&parameter.key),
+                "name": parameter.key,
+                "description": parameter.description,
+                "mimeType": CONFIG_RESOURCE_MIME_TYPE,
+            })
+        })
+        .collect()
+}
+
+/// Contents of the configuration parameter resource at `uri`
+///
+/// Returns `None` if `uri` is not a configuration resource or names a
+/// parameter that is not in the catalog.
+#[must_use]
+pub fn read_config_resource(uri: &str) -> Option<Value> {
+    let key = uri.strip_prefix(CONFIG_RESOURCE_URI_PREFIX)?;
+    let parameter = CatalogBuilder::get_parameter(key)?;
+    let text = serde_json::to_string_pretty(&parameter_contents(&parameter)).ok()?;
+
+    Some(json!({
+        "uri": uri,
+        "mimeType": CONFIG_RESOURCE_MIME_TYPE,
+        "text": text,
+    }))
+}
+
+/// Effective value of `parameter` plus the catalog metadata describing it
+fn parameter_contents(parameter: &ConfigParameter) -> Value {
+    let value = RuntimeConfig::new()
+        .get_value(&parameter.key)
+        .unwrap_or_else(|| parameter.default_value.clone()); // Safe: catalog default is the fallback value
+
+    json!({
+        "key": parameter.key,
+        "value": value,
+        "default_value": parameter.default_value,
+        "data_type": parameter.data_type,
+        "valid_range": parameter.valid_range,
+        "units": parameter.units,
+        "description": parameter.description,
+        "scientific_basis": parameter.scientific_basis,
+        "requires_vo2_max": parameter.requires_vo2_max,
+    })
+}
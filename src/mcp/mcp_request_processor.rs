@@ -10,6 +10,7 @@
 // - JSON value ownership for MCP protocol serialization
 
 use super::{
+    config_resources::{list_config_resources, read_config_resource},
     multitenant::{McpError, McpRequest, McpResponse},
     protocol::ProtocolHandler,
     resources::ServerResources,
@@ -17,11 +18,14 @@ use super::{
     tenant_isolation::extract_tenant_context_internal,
     tool_handlers::ToolHandlers,
 };
-use crate::constants::errors::{ERROR_INTERNAL_ERROR, ERROR_METHOD_NOT_FOUND};
+use crate::constants::errors::{
+    ERROR_INTERNAL_ERROR, ERROR_INVALID_PARAMS, ERROR_METHOD_NOT_FOUND,
+};
 use crate::constants::protocol::{mcp_protocol_version, JSONRPC_VERSION};
 use crate::constants::tools::PUBLIC_DISCOVERY_TOOLS;
-use crate::errors::{AppError, AppResult};
+use crate::errors::{AppError, AppResult, ErrorCode, ErrorDetails};
 use crate::models::TenantId;
+use crate::types::json_schemas::ResourceReadParams;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -77,16 +81,16 @@ impl McpRequestProcessor {
         error!("Request params: {:?}", request.params);
         error!("Full error details: {:#}", e);
 
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            id: request.id.clone(),
-            result: None,
-            error: Some(McpError {
-                code: ERROR_INTERNAL_ERROR,
-                message: format!("Internal server error: {e}"),
-                data: None,
-            }),
-        }
+        McpResponse::from_error(
+            request.id.clone(),
+            McpError::from_details(
+                ERROR_INTERNAL_ERROR,
+                ErrorDetails {
+                    message: format!("Internal server error: {e}"),
+                    ..ErrorDetails::from(e)
+                },
+            ),
+        )
     }
 
     /// Process an MCP request and generate response
@@ -156,6 +160,7 @@ impl McpRequestProcessor {
             id: request.id.clone(),
             result: Some(server_info),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
@@ -168,6 +173,7 @@ impl McpRequestProcessor {
             id: request.id.clone(),
             result: Some(serde_json::json!({})),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
@@ -176,16 +182,12 @@ impl McpRequestProcessor {
         debug!("Handling authenticate request");
 
         // Always return authentication parameter error for authenticate method
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            id: request.id.clone(),
-            result: None,
-            error: Some(McpError {
-                code: -32602, // Invalid params
-                message: "Invalid authentication parameters".to_owned(),
-                data: None,
-            }),
-        }
+        McpResponse::error_with_code(
+            request.id.clone(),
+            -32602,
+            ErrorCode::AuthInvalid,
+            "Invalid authentication parameters",
+        )
     }
 
     /// Handle tools/list request with tiered visibility based on authentication state
@@ -207,6 +209,7 @@ impl McpRequestProcessor {
             id: request.id.clone(),
             result: Some(serde_json::json!({ "tools": tools })),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
@@ -381,12 +384,50 @@ impl McpRequestProcessor {
     fn handle_resources(request: &McpRequest) -> McpResponse {
         debug!("Handling resources request: {}", request.method);
 
-        // Return empty resources list for now
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            id: request.id.clone(),
-            result: Some(serde_json::json!({ "resources": [] })),
-            error: None,
+        match request.method.as_str() {
+            "resources/list" => McpResponse::success(
+                request.id.clone(),
+                serde_json::json!({ "resources": list_config_resources() }),
+            ),
+            "resources/read" => Self::handle_resources_read(request),
+            _ => McpResponse::success(request.id.clone(), serde_json::json!({ "resources": [] })),
+        }
+    }
+
+    /// Handle resources/read request for configuration parameter resources
+    fn handle_resources_read(request: &McpRequest) -> McpResponse {
+        let params = request
+            .params
+            .clone()
+            .map(serde_json::from_value::<ResourceReadParams>);
+        let uri = match params {
+            Some(Ok(params)) => params.uri,
+            Some(Err(e)) => {
+                return McpResponse::error(
+                    request.id.clone(),
+                    ERROR_INVALID_PARAMS,
+                    format!("Invalid resource read parameters: {e}"),
+                )
+            }
+            None => {
+                return McpResponse::error(
+                    request.id.clone(),
+                    ERROR_INVALID_PARAMS,
+                    "Missing parameters for resources/read",
+                )
+            }
+        };
+
+        match read_config_resource(&uri) {
+            Some(contents) => McpResponse::success(
+                request.id.clone(),
+                serde_json::json!({ "contents": [contents] }),
+            ),
+            None => McpResponse::error(
+                request.id.clone(),
+                ERROR_METHOD_NOT_FOUND,
+                format!("Unknown resource URI: {uri}"),
+            ),
         }
     }
 
@@ -400,6 +441,7 @@ impl McpRequestProcessor {
             id: request.id.clone(),
             result: Some(serde_json::json!({ "prompts": [] })),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
@@ -437,16 +479,12 @@ impl McpRequestProcessor {
             "sampling/createMessage" => {
                 // Check if sampling peer is available (only for stdio transport)
                 let Some(sampling_peer) = &self.resources.sampling_peer else {
-                    return Ok(McpResponse {
-                        jsonrpc: JSONRPC_VERSION.to_owned(),
-                        id: request.id.clone(),
-                        result: None,
-                        error: Some(McpError {
-                            code: ERROR_METHOD_NOT_FOUND,
-                            message: "Sampling not available (stdio transport only)".to_owned(),
-                            data: None,
-                        }),
-                    });
+                    return Ok(McpResponse::error_with_code(
+                        request.id.clone(),
+                        ERROR_METHOD_NOT_FOUND,
+                        ErrorCode::ResourceUnavailable,
+                        "Sampling not available (stdio transport only)",
+                    ));
                 };
 
                 // Parse request parameters
@@ -455,30 +493,22 @@ impl McpRequestProcessor {
                         match serde_json::from_value::<CreateMessageRequest>(params.clone()) {
                             Ok(req) => req,
                             Err(e) => {
-                                return Ok(McpResponse {
-                                    jsonrpc: JSONRPC_VERSION.to_owned(),
-                                    id: request.id.clone(),
-                                    result: None,
-                                    error: Some(McpError {
-                                        code: -32602, // Invalid params
-                                        message: format!("Invalid sampling parameters: {e}"),
-                                        data: None,
-                                    }),
-                                });
+                                return Ok(McpResponse::error_with_code(
+                                    request.id.clone(),
+                                    -32602,
+                                    ErrorCode::InvalidInput,
+                                    format!("Invalid sampling parameters: {e}"),
+                                ));
                             }
                         }
                     }
                     None => {
-                        return Ok(McpResponse {
-                            jsonrpc: JSONRPC_VERSION.to_owned(),
-                            id: request.id.clone(),
-                            result: None,
-                            error: Some(McpError {
-                                code: -32602,
-                                message: "Missing sampling parameters".to_owned(),
-                                data: None,
-                            }),
-                        });
+                        return Ok(McpResponse::error_with_code(
+                            request.id.clone(),
+                            -32602,
+                            ErrorCode::MissingRequiredField,
+                            "Missing sampling parameters",
+                        ));
                     }
                 };
 
@@ -490,30 +520,23 @@ impl McpRequestProcessor {
                             id: request.id.clone(),
                             result: Some(result_value),
                             error: None,
+                            metadata: HashMap::new(),
                         }),
-                        Err(e) => Ok(McpResponse {
-                            jsonrpc: JSONRPC_VERSION.to_owned(),
-                            id: request.id.clone(),
-                            result: None,
-                            error: Some(McpError {
-                                code: ERROR_INTERNAL_ERROR,
-                                message: format!("Failed to serialize sampling result: {e}"),
-                                data: None,
-                            }),
-                        }),
+                        Err(e) => Ok(McpResponse::error_with_code(
+                            request.id.clone(),
+                            ERROR_INTERNAL_ERROR,
+                            ErrorCode::SerializationError,
+                            format!("Failed to serialize sampling result: {e}"),
+                        )),
                     },
                     Err(e) => {
                         warn!("Sampling request failed: {e}");
-                        Ok(McpResponse {
-                            jsonrpc: JSONRPC_VERSION.to_owned(),
-                            id: request.id.clone(),
-                            result: None,
-                            error: Some(McpError {
-                                code: ERROR_INTERNAL_ERROR,
-                                message: format!("Sampling failed: {e}"),
-                                data: None,
-                            }),
-                        })
+                        Ok(McpResponse::error_with_code(
+                            request.id.clone(),
+                            ERROR_INTERNAL_ERROR,
+                            ErrorCode::ExternalServiceError,
+                            format!("Sampling failed: {e}"),
+                        ))
                     }
                 }
             }
@@ -525,16 +548,12 @@ impl McpRequestProcessor {
     fn handle_unknown_method(request: &McpRequest) -> McpResponse {
         warn!("Unknown MCP method: {}", request.method);
 
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            id: request.id.clone(),
-            result: None,
-            error: Some(McpError {
-                code: ERROR_METHOD_NOT_FOUND,
-                message: format!("Unknown method: {}", request.method),
-                data: None,
-            }),
-        }
+        McpResponse::error_with_code(
+            request.id.clone(),
+            ERROR_METHOD_NOT_FOUND,
+            ErrorCode::ResourceNotFound,
+            format!("Unknown method: {}", request.method),
+        )
     }
 
     /// Handle notification (no response required)
@@ -4,6 +4,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
+/// Configuration parameter catalog exposed as MCP resources
+pub mod config_resources;
 /// MCP request processing and routing
 pub mod mcp_request_processor;
 /// Multi-tenant MCP server implementation
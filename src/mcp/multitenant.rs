@@ -17,6 +17,7 @@ use super::{
     resources::ServerResources,
     tool_handlers::{McpOAuthCredentials, ToolRoutingContext},
 };
+use crate::analytics::AnalyticsSink;
 use crate::api_keys::ApiKeyUsage;
 use crate::auth::{AuthManager, AuthResult};
 use crate::config::environment::ServerConfig;
@@ -28,8 +29,9 @@ use crate::constants::{
     protocol::JSONRPC_VERSION,
 };
 use crate::database_plugins::{factory::Database, DatabaseProvider};
-use crate::errors::{AppError, AppResult};
+use crate::errors::{AppError, AppResult, ErrorCode};
 use crate::jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
+use crate::lifecycle::shutdown::{finish_shutdown, serve_with_graceful_shutdown};
 use crate::mcp::schema::ProgressNotification;
 use crate::protocols::converter::ProtocolConverter;
 use crate::protocols::universal::tool_registry::ToolId;
@@ -42,6 +44,7 @@ use crate::tenant::{TenantContext, TenantOAuthClient};
 use crate::types::json_schemas;
 use chrono::Utc;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::env;
 use std::fmt::Write;
 use std::net::SocketAddr;
@@ -53,7 +56,8 @@ use tracing::{debug, error, info, warn, Level};
 use uuid::Uuid;
 
 use crate::constants::service_names::PIERRE_MCP_SERVER;
-use crate::middleware::{request_id_middleware, setup_cors};
+use crate::health::{ProviderHealthChecker, ProviderHealthReport};
+use crate::middleware::{request_id_middleware, setup_compression, setup_cors};
 #[cfg(feature = "oauth")]
 use crate::oauth2_server::OAuth2RateLimiter;
 #[cfg(feature = "client-admin-api")]
@@ -120,7 +124,7 @@ impl MultiTenantMcpServer {
         skip(request, resources),
         fields(
             method = %request.method,
-            request_id = ?request.id,
+            rpc_id = ?request.id,
         )
     )]
     pub async fn handle_request(
@@ -128,7 +132,10 @@ impl MultiTenantMcpServer {
         resources: &Arc<ServerResources>,
     ) -> Option<McpResponse> {
         let processor = McpRequestProcessor::new(resources.clone());
-        processor.handle_request(request).await
+        processor
+            .handle_request(request)
+            .await
+            .map(McpResponse::with_current_request_id)
     }
 
     /// Extract tenant context from MCP request headers
@@ -192,9 +199,9 @@ impl MultiTenantMcpServer {
     ///
     /// # Errors
     ///
-    /// Returns an error if the usage cannot be recorded in the database
+    /// Returns an error if the analytics sink fails to store the usage
     pub async fn record_api_key_usage(
-        database: &Arc<Database>,
+        analytics: &dyn AnalyticsSink,
         api_key_id: &str,
         tool_name: &str,
         response_time: Duration,
@@ -222,8 +229,8 @@ impl MultiTenantMcpServer {
             user_agent: None,          // Would need to be passed from request context
         };
 
-        database
-            .record_api_key_usage(&usage)
+        analytics
+            .record_api_key_usage(usage)
             .await
             .map_err(|e| AppError::database(format!("Failed to record API key usage: {e}")))?;
         Ok(())
@@ -420,6 +427,7 @@ impl MultiTenantMcpServer {
             })),
             error: None,
             id: Some(request_id),
+            metadata: HashMap::new(),
         }
     }
 
@@ -654,6 +662,7 @@ impl MultiTenantMcpServer {
             })),
             error: None,
             id: Some(request_id),
+            metadata: HashMap::new(),
         }
     }
 
@@ -670,16 +679,12 @@ impl MultiTenantMcpServer {
             "Tool execution failed for {} with provider {}: {} (success=false)",
             tool_name, provider_name, error_msg
         );
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            result: None,
-            error: Some(McpError {
-                code: ERROR_INTERNAL_ERROR,
-                message: error_msg,
-                data: None,
-            }),
-            id: Some(request_id),
-        }
+        McpResponse::error_with_code(
+            Some(request_id),
+            ERROR_INTERNAL_ERROR,
+            ErrorCode::InternalError,
+            error_msg,
+        )
     }
 
     // Tool routing now uses ToolId::from_name() to validate tools
@@ -701,16 +706,12 @@ impl MultiTenantMcpServer {
         let params = match serde_json::from_value::<json_schemas::ProviderParams>(args.clone()) {
             Ok(p) => p,
             Err(e) => {
-                return McpResponse {
-                    jsonrpc: JSONRPC_VERSION.to_owned(),
-                    result: None,
-                    error: Some(McpError {
-                        code: ERROR_INVALID_PARAMS,
-                        message: format!("Invalid provider parameters: {e}"),
-                        data: None,
-                    }),
-                    id: Some(request_id),
-                };
+                return McpResponse::error_with_code(
+                    Some(request_id),
+                    ERROR_INVALID_PARAMS,
+                    ErrorCode::InvalidInput,
+                    format!("Invalid provider parameters: {e}"),
+                );
             }
         };
         let provider_name = params.provider.as_deref().unwrap_or("");
@@ -747,16 +748,12 @@ impl MultiTenantMcpServer {
         if ToolId::from_name(tool_name).is_some() {
             None
         } else {
-            Some(McpResponse {
-                jsonrpc: JSONRPC_VERSION.to_owned(),
-                result: None,
-                error: Some(McpError {
-                    code: ERROR_METHOD_NOT_FOUND,
-                    message: format!("Unknown tool: {tool_name}"),
-                    data: None,
-                }),
-                id: Some(request_id),
-            })
+            Some(McpResponse::error_with_code(
+                Some(request_id),
+                ERROR_METHOD_NOT_FOUND,
+                ErrorCode::ResourceNotFound,
+                format!("Unknown tool: {tool_name}"),
+            ))
         }
     }
 
@@ -842,6 +839,7 @@ impl MultiTenantMcpServer {
                         result: Some(result_value),
                         error: None,
                         id: Some(request_id),
+                        metadata: HashMap::new(),
                     },
                     Err(e) => Self::create_tool_error_response(
                         tool_name,
@@ -877,12 +875,17 @@ impl MultiTenantMcpServer {
     /// Run HTTP server (convenience method)
     ///
     /// Starts the Axum HTTP server on the specified port using the embedded resources.
+    /// On SIGTERM or Ctrl-C the server drains in-flight work, then closes the database.
     ///
     /// # Errors
     /// Returns an error if server setup or routing configuration fails
     pub async fn run(&self, port: u16) -> AppResult<()> {
-        self.run_http_server_with_resources_axum(port, self.resources.clone())
-            .await
+        self.resources.shutdown.spawn_signal_listener();
+        let result = self
+            .run_http_server_with_resources_axum(port, self.resources.clone())
+            .await;
+        finish_shutdown(&self.resources).await;
+        result
     }
 
     /// Run HTTP server with Axum framework
@@ -903,6 +906,7 @@ impl MultiTenantMcpServer {
 
         // Apply middleware layers (order matters - applied bottom-up)
         let app = app
+            .layer(setup_compression(&resources.config.mcp.compression))
             .layer(
                 TraceLayer::new_for_http()
                     .make_span_with(
@@ -917,7 +921,7 @@ impl MultiTenantMcpServer {
                     ),
             )
             .layer(middleware::from_fn(request_id_middleware))
-            .layer(setup_cors(&resources.config))
+            .layer(setup_cors(&resources.config.cors))
             .layer(Self::create_security_headers_layer(&resources.config));
 
         // Create server address using host from config (defaults to localhost, can be 0.0.0.0 for network access)
@@ -931,12 +935,7 @@ impl MultiTenantMcpServer {
         let listener = TcpListener::bind(addr)
             .await
             .map_err(|e| AppError::internal(format!("Transport error: {e}")))?;
-        axum::serve(
-            listener,
-            app.into_make_service_with_connect_info::<SocketAddr>(),
-        )
-        .await
-        .map_err(|e| AppError::internal(format!("Transport error: {e}")))?;
+        serve_with_graceful_shutdown(listener, app, Arc::clone(&resources.shutdown)).await?;
 
         Ok(())
     }
@@ -954,7 +953,10 @@ impl MultiTenantMcpServer {
     fn setup_axum_router(resources: &Arc<ServerResources>) -> axum::Router {
         use axum::{middleware::from_fn_with_state, Router};
 
-        use crate::middleware::csrf_protection_layer;
+        use crate::middleware::{
+            csrf_protection_layer, impersonation_read_only_layer, rate_limiting_middleware,
+        };
+        use crate::routes::metrics::MetricsRoutes;
 
         // ═══════════════════════════════════════════════════════════════
         // CONDITIONAL IMPORTS - Based on feature flags
@@ -962,6 +964,8 @@ impl MultiTenantMcpServer {
 
         #[cfg(feature = "protocol-a2a")]
         use crate::routes::a2a::A2ARoutes;
+        #[cfg(feature = "client-settings")]
+        use crate::routes::activity_import::ActivityImportRoutes;
         #[cfg(feature = "client-admin-api")]
         use crate::routes::admin::AdminRoutes;
         #[cfg(feature = "client-api-keys")]
@@ -977,6 +981,8 @@ impl MultiTenantMcpServer {
         #[cfg(feature = "client-dashboard")]
         use crate::routes::dashboard::DashboardRoutes;
         #[cfg(feature = "client-settings")]
+        use crate::routes::data_export::DataExportRoutes;
+        #[cfg(feature = "client-settings")]
         use crate::routes::fitness::FitnessConfigurationRoutes;
         #[cfg(feature = "client-impersonation")]
         use crate::routes::impersonation::ImpersonationRoutes;
@@ -1005,11 +1011,13 @@ impl MultiTenantMcpServer {
         use crate::config::routes::{admin_config_router, AdminConfigState};
 
         // ═══════════════════════════════════════════════════════════════
-        // HEALTH ROUTES - Always enabled
+        // HEALTH AND METRICS ROUTES - Always enabled
         // ═══════════════════════════════════════════════════════════════
 
-        let health_routes = Self::create_axum_health_routes();
-        let app = Router::new().merge(health_routes);
+        let health_routes = Self::create_axum_health_routes(resources);
+        let app = Router::new()
+            .merge(health_routes)
+            .merge(MetricsRoutes::routes());
 
         // ═══════════════════════════════════════════════════════════════
         // CLIENT-ADMIN-API ROUTES
@@ -1030,7 +1038,8 @@ impl MultiTenantMcpServer {
                 admin_api_key_limit,
                 admin_token_cache_ttl,
                 resources.tool_selection.clone(),
-            );
+            )
+            .with_feature_flags(resources.feature_flags.clone());
             let admin_routes = AdminRoutes::routes(admin_context);
 
             let admin_config_routes = resources.admin_config.as_ref().map_or_else(
@@ -1060,6 +1069,12 @@ impl MultiTenantMcpServer {
         #[cfg(feature = "protocol-rest")]
         let app = app.merge(AuthRoutes::routes(Arc::clone(resources)));
 
+        #[cfg(feature = "provider-strava")]
+        let app = {
+            use crate::routes::StravaWebhookRoutes;
+            app.merge(StravaWebhookRoutes::routes(Arc::clone(resources)))
+        };
+
         #[cfg(feature = "oauth")]
         let app = {
             let oauth2_context = OAuth2Context {
@@ -1105,7 +1120,9 @@ impl MultiTenantMcpServer {
         #[cfg(feature = "client-settings")]
         let app = app
             .merge(ConfigurationRoutes::routes(Arc::clone(resources)))
-            .merge(FitnessConfigurationRoutes::routes(Arc::clone(resources)));
+            .merge(FitnessConfigurationRoutes::routes(Arc::clone(resources)))
+            .merge(DataExportRoutes::routes(Arc::clone(resources)))
+            .merge(ActivityImportRoutes::routes(Arc::clone(resources)));
 
         #[cfg(feature = "client-chat")]
         let app = app.merge(ChatRoutes::routes(Arc::clone(resources)));
@@ -1172,20 +1189,47 @@ impl MultiTenantMcpServer {
         // Applied globally but only activates for cookie-authenticated
         // state-changing requests (POST/PUT/DELETE/PATCH). Bearer token
         // and API key requests pass through without CSRF validation.
-        app.layer(from_fn_with_state(
+        let app = app.layer(from_fn_with_state(
             Arc::clone(resources),
             csrf_protection_layer,
+        ));
+
+        // ═══════════════════════════════════════════════════════════════
+        // IMPERSONATION READ-ONLY LAYER
+        // ═══════════════════════════════════════════════════════════════
+        // Refuses POST/PUT/DELETE/PATCH requests authenticated with an
+        // admin impersonation token. MCP tool calls are checked per tool.
+        let app = app.layer(from_fn_with_state(
+            Arc::clone(resources),
+            impersonation_read_only_layer,
+        ));
+
+        // ═══════════════════════════════════════════════════════════════
+        // RATE LIMIT HEADERS LAYER
+        // ═══════════════════════════════════════════════════════════════
+        // Adds RateLimit-* headers to authenticated responses and forwards
+        // over-limit requests from tenants in observe mode.
+        app.layer(from_fn_with_state(
+            Arc::clone(resources),
+            rate_limiting_middleware,
         ))
     }
 
     /// Create health check routes for Axum
-    fn create_axum_health_routes() -> axum::Router {
-        use axum::{routing::get, Json, Router};
+    fn create_axum_health_routes(resources: &Arc<ServerResources>) -> axum::Router {
+        use axum::{extract::State, routing::get, Json, Router};
 
-        async fn health_handler() -> Json<serde_json::Value> {
+        async fn health_handler(
+            State(resources): State<Arc<ServerResources>>,
+        ) -> Json<serde_json::Value> {
             Json(serde_json::json!({
                 "status": "ok",
-                "service": PIERRE_MCP_SERVER
+                "service": PIERRE_MCP_SERVER,
+                "database": {
+                    "backend": resources.database.backend_info(),
+                    "pool": resources.database.pool_stats(),
+                    "schema_version": resources.database.schema_version().await.ok()
+                }
             }))
         }
 
@@ -1196,9 +1240,25 @@ impl MultiTenantMcpServer {
             }))
         }
 
+        async fn providers_health_handler(
+            State(checker): State<Arc<ProviderHealthChecker>>,
+        ) -> Result<Json<ProviderHealthReport>, AppError> {
+            Ok(Json(checker.check().await?))
+        }
+
+        // One checker for the server lifetime so its cached report is reused
+        let provider_health = Arc::new(ProviderHealthChecker::from_resources(resources));
+
         Router::new()
-            .route("/health", get(health_handler))
+            .route(
+                "/health",
+                get(health_handler).with_state(Arc::clone(resources)),
+            )
             .route("/health/plugins", get(plugins_health_handler))
+            .route(
+                "/health/providers",
+                get(providers_health_handler).with_state(provider_health),
+            )
     }
 
     /// Create security headers layer for Axum
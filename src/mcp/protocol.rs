@@ -23,6 +23,7 @@ use crate::constants::{
     protocol::{server_name_multitenant, SERVER_VERSION},
 };
 use crate::database_plugins::DatabaseProvider;
+use crate::errors::ErrorCode;
 use crate::mcp::resources::ServerResources;
 use crate::mcp::schema::{
     get_tools, CompleteRequest, CompleteResult, Completion, InitializeRequest, InitializeResponse,
@@ -54,10 +55,11 @@ fn extract_user_id_from_token(
     request_id: &Value,
 ) -> Result<Uuid, Box<McpResponse>> {
     let Some(token) = auth_token else {
-        return Err(Box::new(McpResponse::error(
+        return Err(Box::new(McpResponse::error_with_code(
             Some(request_id.clone()),
             ERROR_INVALID_PARAMS,
-            "Authentication token required".to_owned(),
+            ErrorCode::AuthRequired,
+            "Authentication token required",
         )));
     };
 
@@ -66,19 +68,21 @@ fn extract_user_id_from_token(
         .validate_token(token, &resources.jwks_manager)
         .map_err(|e| {
             error!("Authentication failed: {}", e);
-            Box::new(McpResponse::error(
+            Box::new(McpResponse::error_with_code(
                 Some(request_id.clone()),
                 ERROR_INVALID_PARAMS,
-                "Authentication required".to_owned(),
+                ErrorCode::AuthInvalid,
+                "Authentication required",
             ))
         })?;
 
     Uuid::parse_str(&claims.sub).map_err(|_| {
         error!("Invalid user ID in token: {}", claims.sub);
-        Box::new(McpResponse::error(
+        Box::new(McpResponse::error_with_code(
             Some(request_id.clone()),
             ERROR_INVALID_PARAMS,
-            "Invalid user ID in token".to_owned(),
+            ErrorCode::AuthInvalid,
+            "Invalid user ID in token",
         ))
     })
 }
@@ -89,17 +93,19 @@ fn parse_resource_params(
     request_id: &Value,
 ) -> Result<json_schemas::ResourceReadParams, Box<McpResponse>> {
     let Some(params) = params else {
-        return Err(Box::new(McpResponse::error(
+        return Err(Box::new(McpResponse::error_with_code(
             Some(request_id.clone()),
             ERROR_INVALID_PARAMS,
-            "Missing parameters".to_owned(),
+            ErrorCode::MissingRequiredField,
+            "Missing parameters",
         )));
     };
 
     serde_json::from_value::<json_schemas::ResourceReadParams>(params.clone()).map_err(|e| {
-        Box::new(McpResponse::error(
+        Box::new(McpResponse::error_with_code(
             Some(request_id.clone()),
             ERROR_INVALID_PARAMS,
+            ErrorCode::InvalidInput,
             format!("Invalid resource read parameters: {e}"),
         ))
     })
@@ -186,10 +192,11 @@ impl ProtocolHandler {
             .as_ref()
             .and_then(|params| serde_json::from_value::<InitializeRequest>(params.clone()).ok())
         else {
-            return McpResponse::error(
+            return McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_INVALID_PARAMS,
-                "Invalid initialize request parameters".to_owned(),
+                ErrorCode::InvalidInput,
+                "Invalid initialize request parameters",
             );
         };
 
@@ -201,10 +208,11 @@ impl ProtocolHandler {
         } else {
             // Return error for unsupported versions
             let supported_versions = Self::SUPPORTED_VERSIONS.join(", ");
-            return McpResponse::error(
+            return McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_VERSION_MISMATCH,
-                format!("{MSG_VERSION_MISMATCH}. Client version: {client_version}, Supported versions: {supported_versions}")
+                ErrorCode::InvalidInput,
+                format!("{MSG_VERSION_MISMATCH}. Client version: {client_version}, Supported versions: {supported_versions}"),
             );
         };
 
@@ -237,9 +245,10 @@ impl ProtocolHandler {
             Ok(result) => McpResponse::success(Some(request_id), result),
             Err(e) => {
                 error!("Failed to serialize initialize response: {}", e);
-                McpResponse::error(
+                McpResponse::error_with_code(
                     Some(request_id),
                     ERROR_SERIALIZATION,
+                    ErrorCode::SerializationError,
                     format!("{MSG_SERIALIZATION}: {e}"),
                 )
             }
@@ -351,17 +360,19 @@ impl ProtocolHandler {
                     }
                     Err(e) => {
                         error!("Failed to fetch OAuth notifications: {}", e);
-                        McpResponse::error(
+                        McpResponse::error_with_code(
                             Some(request_id),
                             ERROR_AUTHENTICATION,
+                            ErrorCode::DatabaseError,
                             format!("{MSG_AUTHENTICATION}: Failed to fetch notifications - {e}"),
                         )
                     }
                 }
             }
-            _ => McpResponse::error(
+            _ => McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_METHOD_NOT_FOUND,
+                ErrorCode::ResourceNotFound,
                 format!("Unknown resource URI: {uri}"),
             ),
         }
@@ -370,9 +381,10 @@ impl ProtocolHandler {
     /// Handle unknown method request
     pub fn handle_unknown_method(request: McpRequest) -> McpResponse {
         let request_id = request.id.unwrap_or_else(default_request_id);
-        McpResponse::error(
+        McpResponse::error_with_code(
             Some(request_id),
             ERROR_METHOD_NOT_FOUND,
+            ErrorCode::ResourceNotFound,
             format!("Unknown method: {}", request.method),
         )
     }
@@ -389,10 +401,11 @@ impl ProtocolHandler {
             match request.params.and_then(|p| serde_json::from_value(p).ok()) {
                 Some(req) => req,
                 None => {
-                    return McpResponse::error(
+                    return McpResponse::error_with_code(
                         Some(request_id),
                         ERROR_INVALID_PARAMS,
-                        "Invalid authentication parameters".to_owned(),
+                        ErrorCode::InvalidInput,
+                        "Invalid authentication parameters",
                     );
                 }
             };
@@ -410,9 +423,10 @@ impl ProtocolHandler {
                 .as_deref()
                 .unwrap_or("Authentication failed");
             info!("MCP authentication failed: {}", error_msg);
-            McpResponse::error(
+            McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_INVALID_PARAMS,
+                ErrorCode::AuthInvalid,
                 format!("Authentication failed: {error_msg}"),
             )
         }
@@ -427,10 +441,11 @@ impl ProtocolHandler {
 
         // Extract auth token from request
         let auth_token = request.auth_token.as_deref().ok_or_else(|| {
-            Box::new(McpResponse::error(
+            Box::new(McpResponse::error_with_code(
                 Some(request_id.clone()),
                 ERROR_AUTHENTICATION,
-                "Authentication token required for OAuth credential storage".to_owned(),
+                ErrorCode::AuthRequired,
+                "Authentication token required for OAuth credential storage",
             ))
         })?;
 
@@ -441,18 +456,20 @@ impl ProtocolHandler {
         {
             Ok(claims) => Uuid::parse_str(&claims.sub).map_or_else(
                 |_| {
-                    Err(Box::new(McpResponse::error(
+                    Err(Box::new(McpResponse::error_with_code(
                         Some(request_id.clone()),
                         ERROR_AUTHENTICATION,
-                        "Invalid user ID in authentication token".to_owned(),
+                        ErrorCode::AuthInvalid,
+                        "Invalid user ID in authentication token",
                     )))
                 },
                 Ok,
             ),
-            Err(_) => Err(Box::new(McpResponse::error(
+            Err(_) => Err(Box::new(McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_AUTHENTICATION,
-                "Invalid authentication token".to_owned(),
+                ErrorCode::AuthInvalid,
+                "Invalid authentication token",
             ))),
         }
     }
@@ -529,17 +546,19 @@ impl ProtocolHandler {
                     Ok(result_value) => McpResponse::success(Some(request_id), result_value),
                     Err(e) => {
                         error!("Failed to serialize completion result: {}", e);
-                        McpResponse::error(
+                        McpResponse::error_with_code(
                             Some(request_id),
                             ERROR_SERIALIZATION,
+                            ErrorCode::SerializationError,
                             format!("{MSG_SERIALIZATION}: {e}"),
                         )
                     }
                 }
             }
-            Err(e) => McpResponse::error(
+            Err(e) => McpResponse::error_with_code(
                 Some(request_id),
                 ERROR_INVALID_PARAMS,
+                ErrorCode::InvalidInput,
                 format!("Invalid completion request: {e}"),
             ),
         }
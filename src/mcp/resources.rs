@@ -15,12 +15,18 @@
 
 use crate::a2a::client::A2AClientManager;
 use crate::a2a::system_user::A2ASystemUserService;
-use crate::admin::jwks::JwksManager;
+use crate::admin::jwks::{JwksManager, RsaKeypairRecord};
+use crate::admin::jwt::AdminJwtManager;
 use crate::admin::FirebaseAuth;
+use crate::analytics::{create_analytics_sink, AnalyticsSink};
 use crate::auth::AuthManager;
+use crate::cache::activity::ActivityCache;
 use crate::cache::factory::Cache;
 use crate::config::admin::AdminConfigService;
 use crate::config::environment::ServerConfig;
+use crate::config::{
+    AnalyticsSinkConfig, FeatureFlagsConfig, ShutdownConfig, ToolScopeRequirements,
+};
 use crate::database::coaches::CoachesManager;
 use crate::database::recipes::RecipeManager;
 use crate::database_plugins::factory::Database;
@@ -30,6 +36,7 @@ use crate::intelligence::{
     ActivityIntelligence, ContextualFactors, PerformanceMetrics, TimeOfDay, TrendDirection,
     TrendIndicators,
 };
+use crate::lifecycle::shutdown::ShutdownCoordinator;
 use crate::llm::LlmProvider;
 use crate::mcp::sampling_peer::SamplingPeer;
 use crate::mcp::schema::{OAuthCompletedNotification, ProgressNotification};
@@ -41,6 +48,8 @@ use crate::plugins::executor::PluginToolExecutor;
 use crate::protocols::universal::types::CancellationToken;
 use crate::providers::ProviderRegistry;
 use crate::security::csrf::CsrfTokenManager;
+use crate::security::cursor_signing::CursorSigner;
+use crate::services::feature_flags::FeatureFlags;
 #[cfg(feature = "transport-sse")]
 use crate::sse::SseManager;
 use crate::tenant::{oauth_manager::TenantOAuthManager, TenantOAuthClient};
@@ -148,6 +157,8 @@ pub struct ServerResources {
     pub oauth_notification_sender: Option<broadcast::Sender<OAuthCompletedNotification>>,
     /// Cache layer for performance optimization
     pub cache: Arc<Cache>,
+    /// Per-user activity list cache in front of provider API calls
+    pub activity_cache: Arc<ActivityCache>,
     /// Optional plugin executor for custom tool implementations
     pub plugin_executor: Option<Arc<PluginToolExecutor>>,
     /// Configuration for PII redaction in logs and responses
@@ -158,6 +169,8 @@ pub struct ServerResources {
     pub csrf_manager: Arc<CsrfTokenManager>,
     /// CSRF validation middleware
     pub csrf_middleware: Arc<CsrfMiddleware>,
+    /// Signs pagination cursors returned to clients and verifies them on the way back
+    pub cursor_signer: Arc<CursorSigner>,
     /// Optional sampling peer for server-initiated LLM requests (stdio transport only)
     pub sampling_peer: Option<Arc<SamplingPeer>>,
     /// Optional progress notification sender (stdio transport only)
@@ -170,10 +183,18 @@ pub struct ServerResources {
     pub admin_config: Option<Arc<AdminConfigService>>,
     /// Tool selection service for per-tenant MCP tool filtering
     pub tool_selection: Arc<ToolSelectionService>,
+    /// Admin-configurable runtime feature flags gating tools and algorithms
+    pub feature_flags: Arc<FeatureFlags>,
+    /// Provider OAuth scopes individual tools require, used to trigger scope step-up
+    pub tool_scope_requirements: Arc<ToolScopeRequirements>,
     /// Central registry for MCP tool discovery and execution
     pub tool_registry: Arc<ToolRegistry>,
     /// Optional LLM provider for insight validation and generation (injected for testing)
     pub llm_provider: Option<Arc<dyn LlmProvider>>,
+    /// Tracks in-flight requests and running A2A tasks for graceful shutdown
+    pub shutdown: Arc<ShutdownCoordinator>,
+    /// Storage backend receiving API key and A2A usage records
+    pub analytics: Arc<dyn AnalyticsSink>,
 }
 
 impl ServerResources {
@@ -211,10 +232,15 @@ impl ServerResources {
         // Create A2A system user service once for shared use
         let a2a_system_user_service = Arc::new(A2ASystemUserService::new(database_arc.clone()));
 
+        // Create the analytics sink selected by PIERRE_ANALYTICS_SINK
+        let analytics =
+            create_analytics_sink(&AnalyticsSinkConfig::from_env(), database_arc.clone());
+
         // Create A2A client manager once for shared use
         let a2a_client_manager = Arc::new(A2AClientManager::new(
             database_arc.clone(),
             a2a_system_user_service.clone(),
+            analytics.clone(),
         ));
 
         // Wrap cache in Arc for shared access across handlers
@@ -263,6 +289,9 @@ impl ServerResources {
         // Create CSRF validation middleware
         let csrf_middleware = Arc::new(CsrfMiddleware::new(csrf_manager.clone()));
 
+        // Load the cursor signing key so cursors stay valid across restarts
+        let cursor_signer = Self::init_cursor_signer(&database_arc).await;
+
         // Create Firebase auth handler if configured
         let firebase_auth = if config.firebase.is_configured() {
             Some(Arc::new(FirebaseAuth::new(config.firebase.clone())))
@@ -274,8 +303,17 @@ impl ServerResources {
         // This provides runtime-configurable parameters via admin API
         let admin_config = Self::init_admin_config_service(&database_arc).await;
 
+        // Load feature flags and keep them refreshed from the database
+        let feature_flags = Self::init_feature_flags(&database_arc).await;
+
         // Create tool selection service for per-tenant tool filtering
-        let tool_selection = Arc::new(ToolSelectionService::new(database_arc.clone()));
+        let tool_selection = Arc::new(
+            ToolSelectionService::new(database_arc.clone())
+                .with_feature_flags(feature_flags.clone()),
+        );
+
+        // Load per-tool provider scope requirements for OAuth scope step-up
+        let tool_scope_requirements = Arc::new(ToolScopeRequirements::from_env());
 
         // Create and populate tool registry with all built-in tools
         let tool_registry = Arc::new(Self::create_tool_registry());
@@ -298,19 +336,27 @@ impl ServerResources {
             a2a_system_user_service,
             oauth_notification_sender: None,
             cache: cache_arc,
+            activity_cache: Arc::new(ActivityCache::default()),
             plugin_executor: None,
             redaction_config,
             oauth2_rate_limiter,
             csrf_manager,
             csrf_middleware,
+            cursor_signer,
             sampling_peer: None,
             progress_notification_sender: None,
             cancellation_registry: Arc::new(RwLock::new(HashMap::new())),
             firebase_auth,
             admin_config,
             tool_selection,
+            feature_flags,
+            tool_scope_requirements,
             tool_registry,
             llm_provider,
+            shutdown: Arc::new(ShutdownCoordinator::new(
+                ShutdownConfig::from_env().grace_period(),
+            )),
+            analytics,
         }
     }
 
@@ -386,6 +432,36 @@ impl ServerResources {
         Ok(())
     }
 
+    /// Create the cursor signer from the stored signing secret
+    ///
+    /// Falls back to a per-process key if the secret cannot be loaded, which
+    /// invalidates outstanding cursors on restart but keeps pagination working.
+    async fn init_cursor_signer(database: &Arc<Database>) -> Arc<CursorSigner> {
+        let signer = CursorSigner::from_database(database)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Failed to load cursor signing secret, using an ephemeral key: {}",
+                    e
+                );
+                CursorSigner::new(AdminJwtManager::generate_jwt_secret().as_bytes())
+            });
+        Arc::new(signer)
+    }
+
+    /// Load feature flags and start their periodic refresh
+    ///
+    /// A failed initial load is logged and leaves every flag undefined until
+    /// the next refresh succeeds.
+    async fn init_feature_flags(database: &Arc<Database>) -> Arc<FeatureFlags> {
+        let feature_flags = Arc::new(FeatureFlags::new(database.clone()));
+        if let Err(e) = feature_flags.refresh().await {
+            warn!("Failed to load feature flags: {}", e);
+        }
+        feature_flags.spawn_refresh_task(FeatureFlagsConfig::from_env().refresh_interval());
+        feature_flags
+    }
+
     /// Initialize admin config service if `SQLite` is available
     ///
     /// Returns None if no `SQLite` pool or initialization fails.
@@ -468,7 +544,7 @@ impl ServerResources {
 
     fn load_existing_keys(
         jwks_manager: &mut JwksManager,
-        keypairs: Vec<(String, String, String, chrono::DateTime<Utc>, bool)>,
+        keypairs: Vec<RsaKeypairRecord>,
     ) -> AppResult<()> {
         info!(
             "Loading {} persisted RSA keypairs from database",
@@ -12,7 +12,9 @@
 
 use crate::constants::{
     get_server_config,
-    json_fields::{ACTIVITY_ID, AFTER, BEFORE, FORMAT, LIMIT, MODE, OFFSET, PROVIDER, SPORT_TYPE},
+    json_fields::{
+        ACTIVITY_ID, AFTER, BEFORE, FORMAT, LIMIT, MODE, NO_CACHE, OFFSET, PROVIDER, SPORT_TYPE,
+    },
     tools::{
         ACTIVATE_COACH, ADMIN_ASSIGN_COACH, ADMIN_CREATE_SYSTEM_COACH, ADMIN_DELETE_SYSTEM_COACH,
         ADMIN_GET_SYSTEM_COACH, ADMIN_LIST_COACH_ASSIGNMENTS, ADMIN_LIST_SYSTEM_COACHES,
@@ -20,9 +22,10 @@ use crate::constants::{
         CREATE_COACH, DEACTIVATE_COACH, DELETE_COACH, DELETE_FITNESS_CONFIG, DELETE_RECIPE,
         DISCONNECT_PROVIDER, GET_ACTIVE_COACH, GET_ACTIVITIES, GET_ACTIVITY_INTELLIGENCE,
         GET_ATHLETE, GET_COACH, GET_CONNECTION_STATUS, GET_FITNESS_CONFIG, GET_RECIPE,
-        GET_RECIPE_CONSTRAINTS, GET_STATS, HIDE_COACH, LIST_COACHES, LIST_FITNESS_CONFIGS,
-        LIST_HIDDEN_COACHES, LIST_RECIPES, SAVE_RECIPE, SEARCH_COACHES, SEARCH_RECIPES,
-        SET_FITNESS_CONFIG, SHOW_COACH, TOGGLE_COACH_FAVORITE, UPDATE_COACH, VALIDATE_RECIPE,
+        GET_RECIPE_CONSTRAINTS, GET_STATS, HIDE_COACH, LIST_COACHES, LIST_CONNECTED_PROVIDERS,
+        LIST_FITNESS_CONFIGS, LIST_HIDDEN_COACHES, LIST_RECIPES, SAVE_RECIPE, SEARCH_COACHES,
+        SEARCH_RECIPES, SET_FITNESS_CONFIG, SHOW_COACH, TOGGLE_COACH_FAVORITE, UPDATE_COACH,
+        VALIDATE_RECIPE,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -830,16 +833,19 @@ pub fn get_tools() -> Vec<ToolSchema> {
     tools
 }
 
-/// Apply behavioral annotations to tools based on their operation semantics
-fn apply_tool_annotations(tools: &mut [ToolSchema]) {
-    for tool in tools.iter_mut() {
-        tool.annotations = match tool.name.as_str() {
-            // Read-only data retrieval and analytics tools
-            GET_ACTIVITIES
+/// Whether a tool only reads data and has no side effects
+///
+/// Impersonation tokens are limited to these tools.
+#[must_use]
+pub fn is_read_only_tool(tool_name: &str) -> bool {
+    matches!(
+        tool_name,
+        GET_ACTIVITIES
             | GET_ATHLETE
             | GET_STATS
             | GET_ACTIVITY_INTELLIGENCE
             | GET_CONNECTION_STATUS
+            | LIST_CONNECTED_PROVIDERS
             | GET_ACTIVE_COACH
             | GET_COACH
             | GET_RECIPE
@@ -854,7 +860,16 @@ fn apply_tool_annotations(tools: &mut [ToolSchema]) {
             | ANALYZE_ACTIVITY
             | ADMIN_LIST_SYSTEM_COACHES
             | ADMIN_GET_SYSTEM_COACH
-            | ADMIN_LIST_COACH_ASSIGNMENTS => Some(read_only_annotations()),
+            | ADMIN_LIST_COACH_ASSIGNMENTS
+    )
+}
+
+/// Apply behavioral annotations to tools based on their operation semantics
+fn apply_tool_annotations(tools: &mut [ToolSchema]) {
+    for tool in tools.iter_mut() {
+        tool.annotations = match tool.name.as_str() {
+            // Read-only data retrieval and analytics tools
+            name if is_read_only_tool(name) => Some(read_only_annotations()),
             // Destructive operations (delete, disconnect)
             DELETE_COACH
             | DELETE_RECIPE
@@ -1051,6 +1066,16 @@ fn create_get_activities_tool() -> ToolSchema {
 
     properties.insert(FORMAT.to_owned(), format_property());
 
+    properties.insert(
+        NO_CACHE.to_owned(),
+        PropertySchema {
+            property_type: "boolean".into(),
+            description: Some(
+                "Bypass cached activity lists and fetch fresh data from the provider. Use only when the user expects very recent activities that may not be cached yet. Default: false.".into(),
+            ),
+        },
+    );
+
     ToolSchema {
         name: GET_ACTIVITIES.to_owned(),
         description: "Get fitness activities from a provider. Use mode='summary' (default) for listing activities - returns compact data safe for LLM context. Use mode='detailed' only for single activity analysis. Combine with before/after timestamps and sport_type filter to efficiently query large date ranges. Response metadata includes pagination info (offset, limit, returned_count, has_more) to enable intelligent pagination through large result sets. Response includes token_estimate with estimated_tokens, context_usage_percent, and guidance for managing LLM context limits. Default: 90-day time window applied when 'after' not specified.".into(),
@@ -1350,6 +1375,28 @@ fn create_calculate_metrics_tool() -> ToolSchema {
         },
     );
 
+    properties.insert(
+        "custom_metrics".into(),
+        PropertySchema {
+            property_type: "array".into(),
+            description: Some(
+                "Optional user-defined metrics, each {name, expression, unit?}. Expressions use + - * / and parentheses over activity fields (e.g. 'training_stress_score / duration_hours')"
+                    .to_owned(),
+            ),
+        },
+    );
+
+    properties.insert(
+        "ftp".into(),
+        PropertySchema {
+            property_type: "number".into(),
+            description: Some(
+                "Functional threshold power used for intensity factor and TSS in custom metrics"
+                    .to_owned(),
+            ),
+        },
+    );
+
     properties.insert(FORMAT.to_owned(), format_property());
 
     ToolSchema {
@@ -1838,6 +1885,16 @@ fn create_analyze_training_load_tool() -> ToolSchema {
         },
     );
 
+    properties.insert(
+        "explain".into(),
+        PropertySchema {
+            property_type: "boolean".into(),
+            description: Some(
+                "Attach a computation_trace to each insight listing its inputs, thresholds, and formula (default: false)".into(),
+            ),
+        },
+    );
+
     properties.insert(FORMAT.to_owned(), format_property());
 
     ToolSchema {
@@ -1918,6 +1975,16 @@ fn create_update_user_configuration_tool() -> ToolSchema {
         },
     );
 
+    properties.insert(
+        "provider_priority".into(),
+        PropertySchema {
+            property_type: "object".into(),
+            description: Some(
+                "Provider order per metric used to merge duplicate activities (optional)".into(),
+            ),
+        },
+    );
+
     ToolSchema {
         name: "update_user_configuration".into(),
         description: "Update user's configuration by applying a profile and/or parameter overrides"
@@ -6,14 +6,15 @@
 
 use super::multitenant::{McpError, McpRequest, McpResponse, MultiTenantMcpServer};
 use super::resources::ServerResources;
+use super::schema::is_read_only_tool;
 use super::tenant_isolation::extract_tenant_context_internal;
 use crate::auth::AuthMethod as AuthResultMethod;
 use crate::auth::AuthResult;
 use crate::constants::{
     errors::{
-        ERROR_INTERNAL_ERROR, ERROR_INVALID_PARAMS, ERROR_METHOD_NOT_FOUND, ERROR_TOKEN_EXPIRED,
-        ERROR_TOKEN_INVALID, ERROR_TOKEN_MALFORMED, ERROR_UNAUTHORIZED, MSG_TOKEN_EXPIRED,
-        MSG_TOKEN_INVALID, MSG_TOKEN_MALFORMED,
+        ERROR_AUTHORIZATION, ERROR_INTERNAL_ERROR, ERROR_INVALID_PARAMS, ERROR_METHOD_NOT_FOUND,
+        ERROR_TOKEN_EXPIRED, ERROR_TOKEN_INVALID, ERROR_TOKEN_MALFORMED, ERROR_UNAUTHORIZED,
+        MSG_TOKEN_EXPIRED, MSG_TOKEN_INVALID, MSG_TOKEN_MALFORMED,
     },
     protocol::JSONRPC_VERSION,
     tools::{CONNECT_PROVIDER, DISCONNECT_PROVIDER, GET_CONNECTION_STATUS},
@@ -27,6 +28,7 @@ use crate::tools::context::{AuthMethod, ToolExecutionContext};
 use crate::tools::result::ToolResult;
 use crate::types::json_schemas;
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::Arc;
 use std::time::Instant;
@@ -73,7 +75,7 @@ impl ToolHandlers {
         skip(request, resources),
         fields(
             method = %request.method,
-            request_id = ?request.id,
+            rpc_id = ?request.id,
             tool_name = Empty,
             user_id = Empty,
             tenant_id = Empty,
@@ -154,14 +156,15 @@ impl ToolHandlers {
                             user_id = %auth_result.user_id,
                             "User has no tenant membership - rejecting tool execution"
                         );
-                        return McpResponse::error_with_data(
+                        return McpResponse::from_error(
                             request.id,
-                            ERROR_UNAUTHORIZED,
-                            "User must be assigned to a tenant to execute tools".to_owned(),
-                            serde_json::json!({
-                                "error_type": "tenant_required",
-                                "user_id": auth_result.user_id.to_string()
-                            }),
+                            McpError::with_error_code(
+                                ERROR_UNAUTHORIZED,
+                                ErrorCode::PermissionDenied,
+                                "User must be assigned to a tenant to execute tools",
+                            )
+                            .with_extra("error_type", json!("tenant_required"))
+                            .with_extra("user_id", json!(auth_result.user_id.to_string())),
                         );
                     }
                     Err(e) => {
@@ -171,14 +174,15 @@ impl ToolHandlers {
                             error = %e,
                             "Tenant context extraction failed - rejecting tool execution"
                         );
-                        return McpResponse::error_with_data(
+                        return McpResponse::from_error(
                             request.id,
-                            ERROR_INTERNAL_ERROR,
-                            "Failed to extract tenant context".to_owned(),
-                            serde_json::json!({
-                                "error_type": "tenant_extraction_failed",
-                                "detailed_error": e.to_string()
-                            }),
+                            McpError::with_error_code(
+                                ERROR_INTERNAL_ERROR,
+                                e.code,
+                                "Failed to extract tenant context",
+                            )
+                            .with_extra("error_type", json!("tenant_extraction_failed"))
+                            .with_extra("detailed_error", json!(e.to_string())),
                         );
                     }
                 };
@@ -223,19 +227,15 @@ impl ToolHandlers {
                     "Tool {} not enabled for tenant {} - rejecting",
                     tool_name, tenant_context.tenant_id
                 );
-                Some(McpResponse {
-                    jsonrpc: JSONRPC_VERSION.to_owned(),
-                    id: request_id,
-                    result: None,
-                    error: Some(McpError {
-                        code: ERROR_METHOD_NOT_FOUND,
-                        message: format!(
-                            "Tool '{tool_name}' is not available for your tenant. \
-                             Contact your administrator to enable it."
-                        ),
-                        data: None,
-                    }),
-                })
+                Some(McpResponse::error_with_code(
+                    request_id,
+                    ERROR_METHOD_NOT_FOUND,
+                    ErrorCode::PermissionDenied,
+                    format!(
+                        "Tool '{tool_name}' is not available for your tenant. \
+                         Contact your administrator to enable it."
+                    ),
+                ))
             }
             Err(e) => {
                 debug!(
@@ -257,6 +257,7 @@ impl ToolHandlers {
             tool_name = Empty,
             user_id = %auth_result.user_id,
             tenant_id = %tenant_context.tenant_id,
+            impersonated_by = Empty,
             success = Empty,
             duration_ms = Empty,
         )
@@ -269,16 +270,12 @@ impl ToolHandlers {
     ) -> McpResponse {
         let Some(params) = request.params else {
             error!("Missing request parameters in tools/call");
-            return McpResponse {
-                jsonrpc: "2.0".to_owned(),
-                id: request.id,
-                result: None,
-                error: Some(McpError {
-                    code: ERROR_INVALID_PARAMS,
-                    message: "Invalid params: Missing request parameters".to_owned(),
-                    data: None,
-                }),
-            };
+            return McpResponse::error_with_code(
+                request.id,
+                ERROR_INVALID_PARAMS,
+                ErrorCode::MissingRequiredField,
+                "Invalid params: Missing request parameters",
+            );
         };
 
         // Parse tool call parameters with type safety
@@ -286,16 +283,12 @@ impl ToolHandlers {
             Ok(p) => p,
             Err(e) => {
                 error!("Failed to parse tool call parameters: {}", e);
-                return McpResponse {
-                    jsonrpc: "2.0".to_owned(),
-                    id: request.id,
-                    result: None,
-                    error: Some(McpError {
-                        code: ERROR_INVALID_PARAMS,
-                        message: format!("Invalid tool call parameters: {e}"),
-                        data: None,
-                    }),
-                };
+                return McpResponse::error_with_code(
+                    request.id,
+                    ERROR_INVALID_PARAMS,
+                    ErrorCode::InvalidInput,
+                    format!("Invalid tool call parameters: {e}"),
+                );
             }
         };
 
@@ -306,6 +299,27 @@ impl ToolHandlers {
         // Record tool name in span
         tracing::Span::current().record("tool_name", tool_name.as_str());
 
+        // Impersonation tokens are read-only and every call is attributed to the admin
+        if let Some(admin_token_id) = auth_result.auth_method.impersonated_by() {
+            tracing::Span::current().record("impersonated_by", admin_token_id);
+            if !is_read_only_tool(tool_name) {
+                warn!(
+                    user_id = %user_id,
+                    impersonated_by = %admin_token_id,
+                    "Rejected non-read-only tool {} under impersonation token",
+                    tool_name
+                );
+                return McpResponse::error_with_code(
+                    request.id,
+                    ERROR_AUTHORIZATION,
+                    ErrorCode::PermissionDenied,
+                    format!(
+                        "Tool '{tool_name}' is not available to impersonation tokens, which are read-only"
+                    ),
+                );
+            }
+        }
+
         // Check if tool is enabled for this tenant
         if let Some(error_response) =
             Self::check_tool_enabled(resources, &tenant_context, tool_name, request.id.clone())
@@ -382,24 +396,37 @@ impl ToolHandlers {
 
         // Determine specific error code based on error message
         let error_message = e.to_string();
-        let (error_code, error_msg) = if error_message.contains("JWT token expired") {
-            (ERROR_TOKEN_EXPIRED, MSG_TOKEN_EXPIRED)
+        let (code, error_code, error_msg) = if error_message.contains("JWT token expired") {
+            (
+                ERROR_TOKEN_EXPIRED,
+                ErrorCode::AuthExpired,
+                MSG_TOKEN_EXPIRED,
+            )
         } else if error_message.contains("JWT token signature is invalid") {
-            (ERROR_TOKEN_INVALID, MSG_TOKEN_INVALID)
+            (
+                ERROR_TOKEN_INVALID,
+                ErrorCode::AuthInvalid,
+                MSG_TOKEN_INVALID,
+            )
         } else if error_message.contains("JWT token is malformed") {
-            (ERROR_TOKEN_MALFORMED, MSG_TOKEN_MALFORMED)
+            (
+                ERROR_TOKEN_MALFORMED,
+                ErrorCode::AuthMalformed,
+                MSG_TOKEN_MALFORMED,
+            )
         } else {
-            (ERROR_UNAUTHORIZED, "Authentication required")
+            (
+                ERROR_UNAUTHORIZED,
+                ErrorCode::AuthRequired,
+                "Authentication required",
+            )
         };
 
-        McpResponse::error_with_data(
+        McpResponse::from_error(
             request.id,
-            error_code,
-            error_msg.to_owned(),
-            serde_json::json!({
-                "detailed_error": error_message,
-                "authentication_failed": true
-            }),
+            McpError::with_error_code(code, error_code, error_msg)
+                .with_extra("detailed_error", json!(error_message))
+                .with_extra("authentication_failed", json!(true)),
         )
     }
 
@@ -413,7 +440,9 @@ impl ToolHandlers {
     ) -> ToolExecutionContext {
         // Map AuthResult auth_method to ToolExecutionContext AuthMethod
         let auth_method = match &ctx.auth_result.auth_method {
-            AuthResultMethod::JwtToken { .. } => AuthMethod::JwtBearer,
+            AuthResultMethod::JwtToken { .. } | AuthResultMethod::ImpersonationToken { .. } => {
+                AuthMethod::JwtBearer
+            }
             AuthResultMethod::ApiKey { .. } => AuthMethod::ApiKey,
         };
 
@@ -455,28 +484,13 @@ impl ToolHandlers {
                 "isError": result.is_error
             })),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
     /// Convert an `AppError` to an `McpResponse`
     fn error_to_mcp_response(error: &AppError, request_id: Value) -> McpResponse {
-        let error_code = match error.code {
-            ErrorCode::ResourceNotFound => ERROR_METHOD_NOT_FOUND,
-            ErrorCode::InvalidInput => ERROR_INVALID_PARAMS,
-            ErrorCode::PermissionDenied => ERROR_UNAUTHORIZED,
-            _ => ERROR_INTERNAL_ERROR,
-        };
-
-        McpResponse {
-            jsonrpc: JSONRPC_VERSION.to_owned(),
-            id: Some(request_id),
-            result: None,
-            error: Some(McpError {
-                code: error_code,
-                message: error.to_string(),
-                data: None,
-            }),
-        }
+        McpResponse::from_app_error(Some(request_id), error)
     }
 
     /// Route tool calls to appropriate handlers based on tool type and tenant context
@@ -550,6 +564,7 @@ impl ToolHandlers {
                     "isError": true
                 })),
                 error: None,
+                metadata: HashMap::new(),
             };
         }
 
@@ -574,6 +589,7 @@ impl ToolHandlers {
                 "message": format!("Please complete unified authentication with Pierre and {} in your browser.", provider_name.to_uppercase())
             })),
             error: None,
+            metadata: HashMap::new(),
         }
     }
 
@@ -618,16 +634,12 @@ impl ToolHandlers {
             match serde_json::from_value::<json_schemas::DisconnectProviderParams>(args.clone()) {
                 Ok(p) => p,
                 Err(e) => {
-                    return McpResponse {
-                        jsonrpc: JSONRPC_VERSION.to_owned(),
-                        result: None,
-                        error: Some(McpError {
-                            code: ERROR_INVALID_PARAMS,
-                            message: format!("Invalid disconnect_provider parameters: {e}"),
-                            data: None,
-                        }),
-                        id: Some(request_id),
-                    };
+                    return McpResponse::error_with_code(
+                        Some(request_id),
+                        ERROR_INVALID_PARAMS,
+                        ErrorCode::InvalidInput,
+                        format!("Invalid disconnect_provider parameters: {e}"),
+                    );
                 }
             };
 
@@ -12,6 +12,7 @@ use crate::models::{
     CategorySummary, EffectiveTool, TenantPlan, TenantToolOverride, ToolAvailabilitySummary,
     ToolCatalogEntry, ToolCategory, ToolEnablementSource,
 };
+use crate::services::feature_flags::FeatureFlags;
 use lru::LruCache;
 use pierre_core::models::TenantId;
 use std::collections::HashMap;
@@ -36,14 +37,20 @@ struct CacheEntry {
 /// The service applies tool enablement in the following precedence order:
 /// 1. **Global Disabled** (`PIERRE_DISABLED_TOOLS`) - Highest priority
 /// 2. **Plan Restriction** - Tools require minimum plan level
-/// 3. **Tenant Override** - Admin-configured per-tenant settings
-/// 4. **Catalog Default** - Default enablement from `tool_catalog` table
+/// 3. **Feature Flag** - `tool.<name>` boolean flag, global or per tenant
+/// 4. **Tenant Override** - Admin-configured per-tenant settings
+/// 5. **Catalog Default** - Default enablement from `tool_catalog` table
+///
+/// Feature flags are applied on every lookup rather than cached, so toggling
+/// a flag takes effect without waiting for the cache TTL.
 pub struct ToolSelectionService {
     database: Arc<Database>,
     cache: Arc<RwLock<LruCache<TenantId, CacheEntry>>>,
     cache_ttl: Duration,
     /// Global tool selection configuration from environment
     config: ToolSelectionConfig,
+    /// Runtime flags gating individual tools
+    feature_flags: Option<Arc<FeatureFlags>>,
 }
 
 impl ToolSelectionService {
@@ -75,6 +82,7 @@ impl ToolSelectionService {
             cache: Arc::new(RwLock::new(LruCache::new(CACHE_SIZE))),
             cache_ttl: Duration::from_secs(300),
             config,
+            feature_flags: None,
         }
     }
 
@@ -87,9 +95,17 @@ impl ToolSelectionService {
             cache: Arc::new(RwLock::new(LruCache::new(CACHE_SIZE))),
             cache_ttl,
             config,
+            feature_flags: None,
         }
     }
 
+    /// Consult `tool.<name>` feature flags when computing tool enablement
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlags>) -> Self {
+        self.feature_flags = Some(feature_flags);
+        self
+    }
+
     /// Get the list of globally disabled tool names
     ///
     /// Returns an empty vector if no tools are globally disabled.
@@ -116,7 +132,7 @@ impl ToolSelectionService {
             if let Some(entry) = cache.peek(&tenant_id) {
                 if entry.cached_at.elapsed() < self.cache_ttl {
                     debug!("Tool selection cache hit for tenant {tenant_id}");
-                    return Ok(entry.tools.clone());
+                    return Ok(self.apply_feature_flags(tenant_id, entry.tools.clone()));
                 }
             }
         }
@@ -137,7 +153,7 @@ impl ToolSelectionService {
             );
         }
 
-        Ok(tools)
+        Ok(self.apply_feature_flags(tenant_id, tools))
     }
 
     /// Get only enabled tools for a tenant (for `tools/list` endpoint)
@@ -171,7 +187,10 @@ impl ToolSelectionService {
                     return Ok(entry
                         .tools
                         .iter()
-                        .any(|t| t.tool_name == tool_name && t.is_enabled));
+                        .find(|t| t.tool_name == tool_name)
+                        .is_some_and(|t| {
+                            self.apply_feature_flag(tenant_id, t.clone()).is_enabled
+                        }));
                 }
             }
         }
@@ -194,6 +213,11 @@ impl ToolSelectionService {
             return Ok(false);
         }
 
+        // Check feature flag
+        if let Some(is_enabled) = self.tool_flag(tenant_id, tool_name) {
+            return Ok(is_enabled);
+        }
+
         // Check tenant override (only if tenant exists - ignore errors here)
         if let Ok(Some(override_entry)) = self
             .database
@@ -338,6 +362,43 @@ impl ToolSelectionService {
         self.database.get_tool_catalog().await
     }
 
+    /// Value of the `tool.<name>` feature flag for a tenant, if one is defined
+    fn tool_flag(&self, tenant_id: TenantId, tool_name: &str) -> Option<bool> {
+        self.feature_flags
+            .as_ref()?
+            .tool_enabled(tool_name, tenant_id)
+    }
+
+    /// Overlay feature flags on tools computed without them
+    fn apply_feature_flags(
+        &self,
+        tenant_id: TenantId,
+        tools: Vec<EffectiveTool>,
+    ) -> Vec<EffectiveTool> {
+        if self.feature_flags.is_none() {
+            return tools;
+        }
+        tools
+            .into_iter()
+            .map(|tool| self.apply_feature_flag(tenant_id, tool))
+            .collect()
+    }
+
+    /// Let a flag decide a tool's state unless global disabling or the plan already has
+    fn apply_feature_flag(&self, tenant_id: TenantId, mut tool: EffectiveTool) -> EffectiveTool {
+        if matches!(
+            tool.source,
+            ToolEnablementSource::GlobalDisabled | ToolEnablementSource::PlanRestriction
+        ) {
+            return tool;
+        }
+        if let Some(is_enabled) = self.tool_flag(tenant_id, &tool.tool_name) {
+            tool.is_enabled = is_enabled;
+            tool.source = ToolEnablementSource::FeatureFlag;
+        }
+        tool
+    }
+
     /// Compute effective tools for a tenant (no caching)
     ///
     /// If the tenant doesn't exist in the database, falls back to catalog defaults
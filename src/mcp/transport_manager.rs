@@ -9,9 +9,21 @@
 // - Shared resource distribution across stdio, SSE, and HTTP transports
 
 use super::resources::ServerResources;
-use crate::errors::{AppError, AppResult};
+use crate::config::SigningKeyRolloverConfig;
+#[cfg(all(feature = "provider-strava", feature = "transport-http"))]
+use crate::config::StravaWebhookConfig;
+#[cfg(feature = "postgresql")]
+use crate::database_plugins::factory::Database;
+use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::jsonrpc::JsonRpcError;
+use crate::lifecycle::shutdown::finish_shutdown;
 use crate::mcp::schema::OAuthCompletedNotification;
+use crate::services::signing_key_rollover::SigningKeyRolloverWorker;
+#[cfg(all(feature = "provider-strava", feature = "transport-http"))]
+use crate::services::strava_webhooks::{StravaSubscriptionClient, StravaWebhookManager};
+use crate::services::webhook_delivery::WebhookDispatcher;
 use std::sync::Arc;
+use std::time::Duration;
 #[cfg(feature = "transport-stdio")]
 use tokio::io::{stdin, stdout, AsyncBufReadExt, BufReader};
 use tokio::sync::broadcast;
@@ -22,11 +34,18 @@ use tokio::sync::mpsc;
 #[cfg(feature = "transport-stdio")]
 use tokio::sync::Mutex;
 #[cfg(feature = "transport-http")]
-use tokio::time::{sleep, Duration};
+use tokio::time::sleep;
 use tracing::{error, info, warn};
 #[cfg(feature = "transport-sse")]
 use uuid::Uuid;
 
+/// How often the webhook delivery worker looks for due deliveries
+const WEBHOOK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Delay before registering the Strava push subscription, so the HTTP server can answer its challenge
+#[cfg(all(feature = "provider-strava", feature = "transport-http"))]
+const STRAVA_SUBSCRIPTION_DELAY: Duration = Duration::from_secs(5);
+
 /// Log the status of a transport feature
 fn log_transport_status(name: &str, enabled: bool, extra: Option<String>) {
     let status = if enabled { "ENABLED" } else { "DISABLED" };
@@ -85,6 +104,7 @@ impl TransportManager {
 
         let mut resources_clone = (*self.resources).clone();
         resources_clone.set_oauth_notification_sender(self.notification_sender.clone());
+        self.spawn_oauth_notification_listener();
 
         let stdout_handle = Arc::new(Mutex::new(stdout()));
         let sampling_peer = Arc::new(super::sampling_peer::SamplingPeer::new(stdout_handle));
@@ -157,9 +177,9 @@ impl TransportManager {
         });
     }
 
-    /// Run HTTP server with restart on failure
+    /// Run HTTP server with restart on failure until shutdown is triggered
     #[cfg(feature = "transport-http")]
-    async fn run_http_server_loop(shared_resources: Arc<ServerResources>, port: u16) -> ! {
+    async fn run_http_server_loop(shared_resources: Arc<ServerResources>, port: u16) {
         loop {
             info!("Starting unified Axum HTTP server on port {}", port);
 
@@ -168,6 +188,12 @@ impl TransportManager {
                 .run_http_server_with_resources_axum(port, shared_resources.clone())
                 .await;
 
+            if shared_resources.shutdown.is_triggered() {
+                if let Err(e) = result {
+                    warn!("HTTP server stopped with error during shutdown: {}", e);
+                }
+                return;
+            }
             Self::handle_server_restart(result).await;
         }
     }
@@ -197,10 +223,13 @@ impl TransportManager {
         Self::log_enabled_transports(port);
 
         let shared_resources = self.prepare_resources();
+        shared_resources.shutdown.spawn_signal_listener();
 
         self.spawn_background_transports(&shared_resources);
 
-        Self::run_primary_transport(shared_resources, port).await
+        let result = Self::run_primary_transport(shared_resources.clone(), port).await;
+        finish_shutdown(&shared_resources).await;
+        result
     }
 
     /// Log which transports are enabled at startup
@@ -219,6 +248,11 @@ impl TransportManager {
     fn prepare_resources(&self) -> Arc<ServerResources> {
         let mut resources_clone = (*self.resources).clone();
         resources_clone.set_oauth_notification_sender(self.notification_sender.clone());
+        self.spawn_oauth_notification_listener();
+        self.spawn_webhook_delivery_worker();
+        self.spawn_signing_key_rollover_worker();
+        #[cfg(all(feature = "provider-strava", feature = "transport-http"))]
+        self.spawn_strava_webhook_registration();
 
         #[cfg(feature = "transport-stdio")]
         {
@@ -232,6 +266,73 @@ impl TransportManager {
         Arc::new(resources_clone)
     }
 
+    /// Push OAuth notifications stored by any instance via `PostgreSQL` LISTEN/NOTIFY
+    ///
+    /// `SQLite` deployments keep polling, so this is a no-op for them.
+    fn spawn_oauth_notification_listener(&self) {
+        #[cfg(not(feature = "postgresql"))]
+        let _ = self; // Suppress unused warnings
+
+        #[cfg(feature = "postgresql")]
+        if let Database::PostgreSQL(db) = self.resources.database.as_ref() {
+            let _listener = db.spawn_oauth_notification_listener(self.notification_sender.clone());
+            info!("OAuth notification push delivery enabled (PostgreSQL LISTEN/NOTIFY)");
+        }
+    }
+
+    /// Deliver queued tenant webhook events in the background
+    fn spawn_webhook_delivery_worker(&self) {
+        match WebhookDispatcher::new(Arc::clone(&self.resources.database)) {
+            Ok(dispatcher) => {
+                let _worker = Arc::new(dispatcher).spawn(WEBHOOK_POLL_INTERVAL);
+            }
+            Err(e) => error!("Webhook delivery worker not started: {}", e),
+        }
+    }
+
+    /// Roll over the JWT signing key in the background when enabled
+    fn spawn_signing_key_rollover_worker(&self) {
+        let config = SigningKeyRolloverConfig::from_env();
+        if !config.enabled() {
+            return;
+        }
+        let worker = SigningKeyRolloverWorker::new(
+            Arc::clone(&self.resources.database),
+            Arc::clone(&self.resources.jwks_manager),
+            config,
+        );
+        let _worker = Arc::new(worker).spawn();
+    }
+
+    /// Register the Strava push subscription in the background when configured
+    #[cfg(all(feature = "provider-strava", feature = "transport-http"))]
+    fn spawn_strava_webhook_registration(&self) {
+        let config = StravaWebhookConfig::from_env();
+        if !config.enabled() {
+            return;
+        }
+        let resources = Arc::clone(&self.resources);
+        tokio::spawn(async move {
+            // Strava calls the callback URL before answering the registration request
+            sleep(STRAVA_SUBSCRIPTION_DELAY).await;
+            let client = match StravaSubscriptionClient::from_server_config(
+                &resources.config.external_services.strava_api.base_url,
+            ) {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Strava push subscription not registered: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = StravaWebhookManager::new(&resources, client)
+                .ensure_subscription(&config)
+                .await
+            {
+                error!("Strava push subscription not registered: {}", e);
+            }
+        });
+    }
+
     /// Spawn background transports (stdio, SSE)
     fn spawn_background_transports(&self, shared_resources: &Arc<ServerResources>) {
         #[cfg(feature = "transport-stdio")]
@@ -247,13 +348,14 @@ impl TransportManager {
         }
     }
 
-    /// Run the primary transport (HTTP or wait for signal)
+    /// Run the primary transport (HTTP or wait for signal) until shutdown is triggered
     #[cfg(feature = "transport-http")]
     async fn run_primary_transport(
         shared_resources: Arc<ServerResources>,
         port: u16,
     ) -> AppResult<()> {
-        Self::run_http_server_loop(shared_resources, port).await
+        Self::run_http_server_loop(shared_resources, port).await;
+        Ok(())
     }
 
     #[cfg(not(feature = "transport-http"))]
@@ -261,20 +363,19 @@ impl TransportManager {
         shared_resources: Arc<ServerResources>,
         port: u16,
     ) -> AppResult<()> {
-        let _ = (shared_resources, port); // Suppress unused warnings
+        let _ = port; // Suppress unused warnings
 
         #[cfg(feature = "transport-stdio")]
         {
             info!("Running in non-HTTP mode with stdio transport");
-            tokio::signal::ctrl_c()
-                .await
-                .map_err(|e| AppError::internal(format!("Failed to wait for ctrl-c: {e}")))?;
+            shared_resources.shutdown.triggered().await;
             info!("Received shutdown signal, exiting...");
             return Ok(());
         }
 
         #[cfg(not(feature = "transport-stdio"))]
         {
+            let _ = shared_resources; // Suppress unused warnings
             warn!("No transports enabled - server has nothing to do");
             Err(AppError::config(
                 "No transports enabled. Enable at least one of: transport-http, transport-stdio",
@@ -336,10 +437,7 @@ impl StdioTransport {
     fn parse_error_response() -> serde_json::Value {
         serde_json::json!({
             "jsonrpc": "2.0",
-            "error": {
-                "code": -32700,
-                "message": "Parse error"
-            },
+            "error": JsonRpcError::with_error_code(-32700, ErrorCode::InvalidFormat, "Parse error"),
             "id": null
         })
     }
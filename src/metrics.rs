@@ -0,0 +1,13 @@
+// ABOUTME: Prometheus metrics registry re-exported from pierre-core
+// ABOUTME: Shared by tool execution, rate limiting, providers, and the /metrics route
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Metrics
+//!
+//! This module re-exports the in-process metrics registry from `pierre-core`
+//! so provider crates and the server record into the same counters. The
+//! registry is served by [`crate::routes::metrics::MetricsRoutes`].
+
+pub use pierre_core::metrics::*;
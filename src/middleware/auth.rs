@@ -1,25 +1,35 @@
 // ABOUTME: MCP authentication middleware for request authentication and authorization
-// ABOUTME: Handles JWT tokens and API keys with rate limiting and user context extraction
+// ABOUTME: Handles JWT tokens and API keys with rate limiting and user context extraction, and keeps impersonation tokens read-only
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use crate::admin::jwks::JwksManager;
-use crate::api_keys::ApiKeyManager;
-use crate::auth::{AuthManager, AuthMethod, AuthResult};
+use crate::api_keys::{ApiKey, ApiKeyManager};
+use crate::auth::{AuthManager, AuthMethod, AuthResult, ADMIN_IMPERSONATION_SCOPE};
 use crate::config::environment::RateLimitConfig;
-use crate::constants::key_prefixes;
+use crate::constants::{key_prefixes, time_constants::SECONDS_PER_MONTH};
 use crate::database_plugins::{factory::Database, DatabaseProvider};
-use crate::errors::{AppError, AppResult};
+use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::mcp::resources::ServerResources;
+use crate::metrics::MetricsRegistry;
+use crate::models::TenantId;
+use crate::models::User;
 use crate::providers::errors::ProviderError;
-use crate::rate_limiting::UnifiedRateLimitCalculator;
+use crate::rate_limiting::{RateLimitAlgorithm, RateLimitMode, UnifiedRateLimitCalculator};
 use crate::security::cookies::get_cookie_value;
 use crate::utils::errors::auth_error;
 use crate::utils::uuid::parse_uuid;
-use axum::http::HeaderMap;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{HeaderMap, Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::{Duration, Utc};
 use std::sync::Arc;
 use tracing::field::Empty;
 use tracing::{debug, info, warn};
+use uuid::Uuid;
 
 /// Middleware for `MCP` protocol authentication
 #[derive(Clone)]
@@ -66,6 +76,7 @@ impl McpAuthMiddleware {
             auth_method = Empty,
             user_id = Empty,
             tenant_id = Empty,
+            impersonated_by = Empty,
             success = Empty,
         )
     )]
@@ -113,6 +124,10 @@ impl McpAuthMiddleware {
 
     /// Authenticate `MCP` request and extract user context with rate limiting
     ///
+    /// Requests over their limit are rejected unless the tenant is in
+    /// [`RateLimitMode::Observe`], in which case the result is returned with
+    /// `rate_limit.is_rate_limited` still set.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -128,6 +143,7 @@ impl McpAuthMiddleware {
             auth_method = Empty,
             user_id = Empty,
             tenant_id = Empty,
+            impersonated_by = Empty,
             success = Empty,
         )
     )]
@@ -231,13 +247,16 @@ impl McpAuthMiddleware {
         self.api_key_manager.is_key_valid(&db_key)?;
 
         // Get current usage for rate limiting
-        let current_usage = self.database.get_api_key_current_usage(&db_key.id).await?;
+        let current_usage = self.api_key_usage(&db_key).await?;
         let rate_limit = self
             .rate_limit_calculator
             .calculate_api_key_rate_limit(&db_key, current_usage);
 
         // Check rate limit
-        if rate_limit.is_rate_limited {
+        if rate_limit.is_rate_limited
+            && self.rate_limit_mode(db_key.user_id, None).await == RateLimitMode::Enforce
+        {
+            MetricsRegistry::global().record_rate_limit_rejection("api_key");
             let err = ProviderError::RateLimitExceeded {
                 provider: "API Key Authentication".to_owned(),
                 retry_after_secs: rate_limit.reset_at.map_or(3600, |dt| {
@@ -279,6 +298,26 @@ impl McpAuthMiddleware {
             .validate_token_detailed(token, &self.jwks_manager)
             .map_err(|e| AppError::auth_invalid(format!("JWT validation failed: {e}")))?;
 
+        // OAuth2 access tokens can be revoked before they expire (RFC 7009)
+        if self
+            .database
+            .get_oauth2_access_token(&claims.jti)
+            .await?
+            .is_some_and(|t| t.revoked)
+        {
+            return Err(AppError::auth_invalid("Token has been revoked"));
+        }
+
+        // Login sessions can be revoked by the user through /api/auth/sessions
+        if self
+            .database
+            .get_auth_session(&claims.jti)
+            .await?
+            .is_some_and(|session| session.revoked_at.is_some())
+        {
+            return Err(AppError::auth_invalid("Session has been revoked"));
+        }
+
         let user_id = parse_uuid(&claims.sub)
             .map_err(|_| AppError::auth_invalid("Invalid user ID in token"))?;
 
@@ -303,26 +342,120 @@ impl McpAuthMiddleware {
             .ok_or_else(|| AppError::not_found(format!("User {user_id}")))?;
 
         // Get current usage for rate limiting
-        let current_usage = self.database.get_jwt_current_usage(user_id).await?;
+        let current_usage = self.jwt_usage(&user).await?;
         let rate_limit = self
             .rate_limit_calculator
             .calculate_jwt_rate_limit(&user, current_usage);
 
         // Check rate limit
-        if rate_limit.is_rate_limited {
+        if rate_limit.is_rate_limited
+            && self.rate_limit_mode(user_id, active_tenant_id).await == RateLimitMode::Enforce
+        {
+            MetricsRegistry::global().record_rate_limit_rejection("jwt_token");
             return Err(auth_error("JWT token rate limit exceeded"));
         }
 
+        let tier = format!("{:?}", user.tier).to_lowercase();
+        let auth_method = if let Some(admin_token_id) = claims.impersonated_by {
+            // Attribute everything done with this token to the admin who requested it
+            tracing::Span::current().record("impersonated_by", admin_token_id.as_str());
+            info!(
+                user_id = %user_id,
+                impersonated_by = %admin_token_id,
+                jti = %claims.jti,
+                "Request authenticated with admin impersonation token"
+            );
+            AuthMethod::ImpersonationToken {
+                tier,
+                impersonated_by: admin_token_id,
+            }
+        } else {
+            AuthMethod::JwtToken { tier }
+        };
+
         Ok(AuthResult {
             user_id,
-            auth_method: AuthMethod::JwtToken {
-                tier: format!("{:?}", user.tier).to_lowercase(),
-            },
+            auth_method,
             rate_limit,
             active_tenant_id,
         })
     }
 
+    /// Count an `API` key's usage with the configured rate limit algorithm
+    async fn api_key_usage(&self, api_key: &ApiKey) -> AppResult<u32> {
+        let algorithm = self.rate_limit_calculator.algorithm();
+        if algorithm == RateLimitAlgorithm::FixedWindow {
+            return self.database.get_api_key_current_usage(&api_key.id).await;
+        }
+
+        let window = Duration::seconds(i64::from(api_key.rate_limit_window_seconds));
+        let now = Utc::now();
+        let request_times = self
+            .database
+            .get_api_key_usage_times(&api_key.id, algorithm.history_start(window, now))
+            .await?;
+        Ok(algorithm.effective_usage(&request_times, api_key.rate_limit_requests, window, now))
+    }
+
+    /// Count a user's JWT usage with the configured rate limit algorithm
+    async fn jwt_usage(&self, user: &User) -> AppResult<u32> {
+        let algorithm = self.rate_limit_calculator.algorithm();
+        if algorithm == RateLimitAlgorithm::FixedWindow {
+            return self.database.get_jwt_current_usage(user.id).await;
+        }
+
+        let Some(limit) = user.tier.monthly_limit() else {
+            return Ok(0);
+        };
+        let window = Duration::seconds(i64::from(SECONDS_PER_MONTH));
+        let now = Utc::now();
+        let request_times = self
+            .database
+            .get_jwt_usage_times(user.id, algorithm.history_start(window, now))
+            .await?;
+        Ok(algorithm.effective_usage(&request_times, limit, window, now))
+    }
+
+    /// Resolve the rate limit mode of the tenant a request is made against
+    ///
+    /// Falls back to the user's default tenant, and to enforcement when the
+    /// tenant or its mode cannot be resolved.
+    async fn rate_limit_mode(
+        &self,
+        user_id: Uuid,
+        active_tenant_id: Option<Uuid>,
+    ) -> RateLimitMode {
+        let tenant_id = match active_tenant_id {
+            Some(tenant_id) => Some(TenantId::from_uuid(tenant_id)),
+            None => self
+                .database
+                .list_tenants_for_user(user_id)
+                .await
+                .ok()
+                .and_then(|tenants| tenants.first().map(|tenant| tenant.id)),
+        };
+        let Some(tenant_id) = tenant_id else {
+            return RateLimitMode::Enforce;
+        };
+
+        match self.database.get_tenant_rate_limit_mode(tenant_id).await {
+            Ok(mode) => {
+                if mode == RateLimitMode::Observe {
+                    debug!(
+                        user_id = %user_id,
+                        tenant_id = %tenant_id,
+                        "Rate limit exceeded in observe mode, forwarding request"
+                    );
+                }
+                mode
+            }
+            Err(e) => {
+                warn!(tenant_id = %tenant_id, error = %e, "Failed to resolve rate limit mode, enforcing");
+                RateLimitMode::Enforce
+            }
+        }
+    }
+
     /// Check if user has access to specific provider
     ///
     /// # Errors
@@ -339,9 +472,77 @@ impl McpAuthMiddleware {
         Ok(claims.providers.contains(&provider.to_owned()))
     }
 
+    /// Whether `token` is an admin impersonation token limited to [`ADMIN_IMPERSONATION_SCOPE`]
+    ///
+    /// Invalid tokens are not treated as read-only; the handler's own
+    /// authentication rejects them.
+    #[must_use]
+    pub fn is_read_only_token(&self, token: &str) -> bool {
+        self.auth_manager
+            .validate_token(token, &self.jwks_manager)
+            .is_ok_and(|claims| claims.scope.as_deref() == Some(ADMIN_IMPERSONATION_SCOPE))
+    }
+
     /// Get reference to the auth manager for testing purposes
     #[must_use]
     pub const fn auth_manager(&self) -> &AuthManager {
         &self.auth_manager
     }
 }
+
+/// Paths checked per operation rather than per HTTP method
+///
+/// Every MCP JSON-RPC call is a POST; the tool handlers refuse tools that are
+/// not read-only to impersonation tokens.
+const READ_ONLY_TOKEN_EXEMPT_PATHS: &[&str] = &["/mcp"];
+
+/// Axum middleware layer keeping admin impersonation tokens read-only
+///
+/// Tokens issued by `POST /admin/impersonate` carry the read-only
+/// [`ADMIN_IMPERSONATION_SCOPE`]. State-changing requests (any method but GET,
+/// HEAD and OPTIONS) presenting one in the Authorization header or the
+/// `auth_token` cookie are refused before they reach a route handler.
+///
+/// # Errors
+///
+/// Returns 403 for a state-changing request authenticated with an impersonation token.
+pub async fn impersonation_read_only_layer(
+    State(resources): State<Arc<ServerResources>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    let method = request.method().clone();
+    let path = request.uri().path().to_owned();
+    if matches!(method, Method::GET | Method::HEAD | Method::OPTIONS)
+        || READ_ONLY_TOKEN_EXEMPT_PATHS.contains(&path.as_str())
+    {
+        return Ok(next.run(request).await);
+    }
+
+    let headers = request.headers();
+    let header_token = headers
+        .get("authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|auth| auth.strip_prefix("Bearer "));
+    let cookie_token = get_cookie_value(headers, "auth_token");
+    let read_only = header_token
+        .into_iter()
+        .chain(cookie_token.as_deref())
+        .any(|token| resources.auth_middleware.is_read_only_token(token));
+
+    if read_only {
+        warn!(
+            method = %method,
+            path = %path,
+            "Rejected state-changing request under impersonation token"
+        );
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            format!(
+                "{method} {path} is not available to impersonation tokens, which are read-only"
+            ),
+        ));
+    }
+
+    Ok(next.run(request).await)
+}
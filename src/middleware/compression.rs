@@ -0,0 +1,136 @@
+// ABOUTME: Response compression negotiation for large HTTP and MCP-HTTP response bodies
+// ABOUTME: Compresses with gzip, deflate, or brotli per Accept-Encoding above a configurable size
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::fmt;
+use std::str::FromStr;
+
+use axum::body::HttpBody;
+use http::{header::CONTENT_LENGTH, Response};
+use serde::{Deserialize, Serialize};
+use tower_http::compression::{
+    predicate::{NotForContentType, Predicate},
+    CompressionLayer,
+};
+
+use crate::config::mcp::CompressionConfig;
+use crate::errors::AppError;
+
+/// Content types that are already compressed or must not be buffered by an encoder
+const INCOMPRESSIBLE_CONTENT_TYPES: [NotForContentType; 5] = [
+    NotForContentType::IMAGES,
+    NotForContentType::GRPC,
+    NotForContentType::SSE,
+    NotForContentType::const_new("application/gzip"),
+    NotForContentType::const_new("application/zip"),
+];
+
+/// Content coding a response body may be compressed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionCodec {
+    /// `gzip` content coding
+    Gzip,
+    /// `deflate` (zlib) content coding
+    Deflate,
+    /// `br` (brotli) content coding
+    Br,
+}
+
+impl CompressionCodec {
+    /// All supported codecs, enabled by default
+    pub const ALL: [Self; 3] = [Self::Gzip, Self::Deflate, Self::Br];
+
+    /// Content coding token as used in `Accept-Encoding` and `Content-Encoding`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+            Self::Br => "br",
+        }
+    }
+}
+
+impl fmt::Display for CompressionCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CompressionCodec {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "gzip" => Ok(Self::Gzip),
+            "deflate" => Ok(Self::Deflate),
+            "br" | "brotli" => Ok(Self::Br),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid compression codec: {s}"
+            ))),
+        }
+    }
+}
+
+/// Compress only responses of at least `min_size_bytes` that are not already compressed
+///
+/// Bodies of unknown length (streams) are compressed unless their content type is excluded.
+/// Responses that already carry a `Content-Encoding` are never recompressed.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressionPredicate {
+    min_size_bytes: u64,
+}
+
+impl CompressionPredicate {
+    /// Create a predicate with the given minimum body size
+    #[must_use]
+    pub const fn new(min_size_bytes: u64) -> Self {
+        Self { min_size_bytes }
+    }
+}
+
+impl Predicate for CompressionPredicate {
+    fn should_compress<B>(&self, response: &Response<B>) -> bool
+    where
+        B: HttpBody,
+    {
+        let size = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .or_else(|| response.body().size_hint().exact());
+
+        size.is_none_or(|size| size >= self.min_size_bytes)
+            && INCOMPRESSIBLE_CONTENT_TYPES
+                .iter()
+                .all(|content_type| content_type.should_compress(response))
+    }
+}
+
+/// Configure response compression for the HTTP server
+///
+/// Negotiates the codec from the request's `Accept-Encoding` among the enabled codecs,
+/// sets `Content-Encoding`, and adds `Vary: Accept-Encoding` to compressible responses
+/// so caches keep encoded and plain variants apart.
+///
+/// # Examples
+///
+/// ```bash
+/// # Compress bodies of 64 KiB or more with gzip only
+/// export MCP_COMPRESSION_MIN_SIZE=65536
+/// export MCP_COMPRESSION_CODECS="gzip"
+/// ```
+#[must_use]
+pub fn setup_compression(config: &CompressionConfig) -> CompressionLayer<CompressionPredicate> {
+    let enabled = |codec| config.codecs.contains(&codec);
+
+    CompressionLayer::new()
+        .gzip(enabled(CompressionCodec::Gzip))
+        .deflate(enabled(CompressionCodec::Deflate))
+        .br(enabled(CompressionCodec::Br))
+        .compress_when(CompressionPredicate::new(config.min_size_bytes as u64))
+}
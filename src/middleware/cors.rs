@@ -1,22 +1,28 @@
 // ABOUTME: CORS middleware configuration for HTTP API endpoints
-// ABOUTME: Provides Cross-Origin Resource Sharing setup for web client access
+// ABOUTME: Echoes allowlisted origins with credentials, or allows any origin without credentials
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use crate::config::environment::ServerConfig;
+use crate::config::environment::CorsConfig;
 use http::{header::HeaderName, HeaderValue, Method};
 use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{info, warn};
 
 /// Configure CORS settings for the MCP server
 ///
-/// Configures cross-origin requests based on `CORS_ALLOWED_ORIGINS` environment variable.
-/// Supports both wildcard ("*") for development and specific origin lists for production.
+/// Configures cross-origin requests based on the `PIERRE_CORS_ORIGINS`
+/// environment variable (or the legacy `CORS_ALLOWED_ORIGINS`).
 ///
 /// # Security Considerations
 ///
-/// - Uses `CORS_ALLOWED_ORIGINS` environment variable for origin control
-/// - Falls back to wildcard (*) if env var is empty or "*"
+/// - With an allowlist, only a matching `Origin` is echoed back in
+///   `Access-Control-Allow-Origin` and `Access-Control-Allow-Credentials: true`
+///   is sent, so cookie-authenticated browser sessions work cross-origin
+/// - Requests and preflights from other origins get no CORS headers
+/// - The wildcard (`*`, or no origins configured) allows any origin without
+///   credentials and logs a warning, since browsers then refuse to send the
+///   session cookies the web dashboard relies on
 /// - Permits standard HTTP methods (GET, POST, PUT, DELETE, OPTIONS, PATCH)
 /// - Includes custom headers for fitness provider authentication
 /// - Includes tenant identification headers for multi-tenancy
@@ -28,48 +34,20 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 /// - Provider headers: x-strava-client-id, x-fitbit-client-id, etc.
 /// - Tenant headers: x-tenant-name, x-tenant-id
 /// - API key header: x-pierre-api-key
+/// - CSRF header: x-csrf-token
 ///
 /// # Examples
 ///
 /// ```bash
-/// # Allow all origins (development)
-/// export CORS_ALLOWED_ORIGINS="*"
+/// # Allow all origins without credentials (development)
+/// export PIERRE_CORS_ORIGINS="*"
 ///
-/// # Allow specific origins (production)
-/// export CORS_ALLOWED_ORIGINS="https://app.example.com,https://admin.example.com"
+/// # Allow specific origins with credentials (production)
+/// export PIERRE_CORS_ORIGINS="https://app.example.com,https://admin.example.com"
 /// ```
-pub fn setup_cors(config: &ServerConfig) -> CorsLayer {
-    // Parse allowed origins from configuration
-    let allow_origin =
-        if config.cors.allowed_origins.is_empty() || config.cors.allowed_origins == "*" {
-            // Development mode: allow any origin
-            AllowOrigin::any()
-        } else {
-            // Production mode: parse comma-separated origin list
-            let origins: Vec<HeaderValue> = config
-                .cors
-                .allowed_origins
-                .split(',')
-                .filter_map(|s| {
-                    let trimmed = s.trim();
-                    if trimmed.is_empty() {
-                        None
-                    } else {
-                        HeaderValue::from_str(trimmed).ok()
-                    }
-                })
-                .collect();
-
-            if origins.is_empty() {
-                // Fallback to any if parsing failed
-                AllowOrigin::any()
-            } else {
-                AllowOrigin::list(origins)
-            }
-        };
-
-    CorsLayer::new()
-        .allow_origin(allow_origin)
+#[must_use]
+pub fn setup_cors(config: &CorsConfig) -> CorsLayer {
+    let layer = CorsLayer::new()
         .allow_headers([
             HeaderName::from_static("content-type"),
             HeaderName::from_static("authorization"),
@@ -85,6 +63,7 @@ pub fn setup_cors(config: &ServerConfig) -> CorsLayer {
             HeaderName::from_static("x-pierre-api-key"),
             HeaderName::from_static("x-tenant-name"),
             HeaderName::from_static("x-tenant-id"),
+            HeaderName::from_static("x-csrf-token"),
         ])
         .allow_methods([
             Method::GET,
@@ -93,5 +72,29 @@ pub fn setup_cors(config: &ServerConfig) -> CorsLayer {
             Method::DELETE,
             Method::OPTIONS,
             Method::PATCH,
-        ])
+        ]);
+
+    if config.allows_any_origin() {
+        warn!(
+            "CORS allows any origin; credentialed cross-origin requests (session cookies) \
+             will be rejected by browsers. Set PIERRE_CORS_ORIGINS to an allowlist in production"
+        );
+        return layer.allow_origin(AllowOrigin::any());
+    }
+
+    let origins: Vec<HeaderValue> = config
+        .origins()
+        .into_iter()
+        .filter_map(|origin| {
+            HeaderValue::from_str(origin)
+                .inspect_err(|_| warn!(origin, "Ignoring invalid CORS origin"))
+                .ok()
+        })
+        .collect();
+    info!(origins = ?config.origins(), "CORS allowlist configured with credentials");
+
+    // An allowlist that failed to parse denies cross-origin requests rather than opening up
+    layer
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_credentials(true)
 }
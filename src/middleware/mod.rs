@@ -8,6 +8,8 @@
 pub mod admin_guard;
 /// Authentication middleware for MCP and API requests
 pub mod auth;
+/// Response compression negotiation
+pub mod compression;
 /// CORS middleware configuration
 pub mod cors;
 /// CSRF validation middleware
@@ -30,6 +32,8 @@ pub use admin_guard::require_admin;
 
 // Authentication middleware
 
+/// Impersonation layer for Axum router (refuses state-changing requests from read-only impersonation tokens)
+pub use auth::impersonation_read_only_layer;
 /// MCP authentication middleware
 pub use auth::McpAuthMiddleware;
 /// CSRF protection layer for Axum router (validates cookie-auth state-changing requests)
@@ -37,6 +41,13 @@ pub use csrf::csrf_protection_layer;
 /// CSRF validation middleware
 pub use csrf::CsrfMiddleware;
 
+// Response compression
+
+/// Setup response compression layer for HTTP endpoints
+pub use compression::setup_compression;
+/// Response compression codec
+pub use compression::CompressionCodec;
+
 // CORS middleware
 
 /// Setup CORS layer for HTTP endpoints
@@ -50,8 +61,14 @@ pub use rate_limiting::check_rate_limit_and_respond;
 pub use rate_limiting::create_rate_limit_error;
 /// Create rate limit headers
 pub use rate_limiting::create_rate_limit_headers;
+/// Create standard `RateLimit-*` headers
+pub use rate_limiting::create_standard_rate_limit_headers;
 /// Rate limit headers module
 pub use rate_limiting::headers;
+/// Rate limiting middleware function
+pub use rate_limiting::rate_limiting_middleware;
+/// Rate limit enforcement mode
+pub use rate_limiting::RateLimitMode;
 
 // PII-safe logging and redaction
 
@@ -76,6 +93,8 @@ pub use redaction::RedactionFeatures;
 
 // Request ID middleware
 
+/// Request ID of the HTTP request currently being handled
+pub use request_id::current_request_id;
 /// Request ID middleware function
 pub use request_id::request_id_middleware;
 /// Request ID extractor
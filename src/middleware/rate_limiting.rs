@@ -8,10 +8,26 @@
 //!
 //! This module provides utilities for adding standard HTTP rate limiting headers
 //! to responses and creating proper 429 status codes when limits are exceeded.
+//!
+//! [`rate_limiting_middleware`] attaches the headers to authenticated responses
+//! and lets tenants in [`RateLimitMode::Observe`] see who *would* be throttled
+//! before their limits are enforced.
+
+use std::sync::Arc;
+
+use axum::{body::Body, extract::State, http::Request, middleware::Next, response::Response};
+use http::{header::AUTHORIZATION, HeaderMap, HeaderValue};
+use serde_json::json;
+use tracing::warn;
 
+use crate::auth::AuthResult;
 use crate::errors::{AppError, ErrorCode};
+use crate::mcp::resources::ServerResources;
 use crate::rate_limiting::UnifiedRateLimitInfo;
-use http::{HeaderMap, HeaderValue};
+use crate::security::audit::{AuditEvent, AuditEventType, AuditSeverity, SecurityAuditor};
+use crate::security::cookies::get_cookie_value;
+
+pub use crate::rate_limiting::RateLimitMode;
 
 /// HTTP header names for rate limiting
 pub mod headers {
@@ -29,6 +45,14 @@ pub mod headers {
     pub const X_RATE_LIMIT_AUTH_METHOD: &str = "X-RateLimit-AuthMethod";
     /// HTTP header name for retry-after duration in seconds
     pub const RETRY_AFTER: &str = "Retry-After";
+    /// Standard header name for maximum requests allowed in the current window
+    pub const RATE_LIMIT_LIMIT: &str = "RateLimit-Limit";
+    /// Standard header name for remaining requests in the current window
+    pub const RATE_LIMIT_REMAINING: &str = "RateLimit-Remaining";
+    /// Standard header name for seconds until the current window resets
+    pub const RATE_LIMIT_RESET: &str = "RateLimit-Reset";
+    /// Header set on forwarded requests that would have been blocked in enforce mode
+    pub const RATE_LIMIT_OBSERVED_EXCEEDED: &str = "RateLimit-Observed-Exceeded";
 }
 
 /// Create a `HeaderMap` with rate limit headers
@@ -86,13 +110,20 @@ pub fn create_rate_limit_headers(rate_limit_info: &UnifiedRateLimitInfo) -> Head
 pub fn create_rate_limit_error(rate_limit_info: &UnifiedRateLimitInfo) -> AppError {
     let limit = rate_limit_info.limit.unwrap_or(0);
 
-    AppError::new(
+    let error = AppError::new(
         ErrorCode::RateLimitExceeded,
         format!(
             "Rate limit exceeded. You have reached your limit of {} requests for the {} tier",
             limit, rate_limit_info.tier
         ),
-    )
+    );
+    match rate_limit_info.reset_at {
+        Some(reset_at) => {
+            let retry_after = (reset_at - chrono::Utc::now()).num_seconds().max(0);
+            error.with_retry_after(retry_after.unsigned_abs())
+        }
+        None => error,
+    }
 }
 
 /// Helper function to check rate limits and return appropriate response
@@ -109,3 +140,103 @@ pub fn check_rate_limit_and_respond(
         Ok(())
     }
 }
+
+/// Create a `HeaderMap` with the standard `RateLimit-*` headers
+///
+/// Unlimited tiers have no limit information and produce an empty map.
+#[must_use]
+pub fn create_standard_rate_limit_headers(rate_limit_info: &UnifiedRateLimitInfo) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    if let Some(limit) = rate_limit_info.limit {
+        headers.insert(headers::RATE_LIMIT_LIMIT, HeaderValue::from(limit));
+    }
+
+    if let Some(remaining) = rate_limit_info.remaining {
+        headers.insert(headers::RATE_LIMIT_REMAINING, HeaderValue::from(remaining));
+    }
+
+    if let Some(reset_at) = rate_limit_info.reset_at {
+        let reset_in = (reset_at - chrono::Utc::now()).num_seconds().max(0);
+        headers.insert(headers::RATE_LIMIT_RESET, HeaderValue::from(reset_in));
+    }
+
+    headers
+}
+
+/// Axum middleware that applies rate limit decisions and emits `RateLimit-*` headers
+///
+/// Authenticated responses carry the standard rate limit headers. A request over
+/// its limit only authenticates when its tenant is in [`RateLimitMode::Observe`]:
+/// it is forwarded with `RateLimit-Observed-Exceeded: true` and a would-be-block
+/// audit event. Unauthenticated and enforced over-limit requests pass through
+/// unchanged so route handlers reject them as before.
+pub async fn rate_limiting_middleware(
+    State(resources): State<Arc<ServerResources>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let headers = request.headers();
+    let has_credentials =
+        headers.contains_key(AUTHORIZATION) || get_cookie_value(headers, "auth_token").is_some();
+    if !has_credentials {
+        return next.run(request).await;
+    }
+
+    let Ok(auth) = resources
+        .auth_middleware
+        .authenticate_request_with_headers(headers)
+        .await
+    else {
+        return next.run(request).await;
+    };
+
+    let observed_exceeded = auth.rate_limit.is_rate_limited;
+    if observed_exceeded {
+        audit_observed_exceeded(&resources, &auth, request.uri().path()).await;
+    }
+
+    let mut response = next.run(request).await;
+    let response_headers = response.headers_mut();
+    response_headers.extend(create_standard_rate_limit_headers(&auth.rate_limit));
+    if observed_exceeded {
+        response_headers.insert(
+            headers::RATE_LIMIT_OBSERVED_EXCEEDED,
+            HeaderValue::from_static("true"),
+        );
+    }
+
+    response
+}
+
+/// Record a request that observe mode forwarded despite being over its limit
+async fn audit_observed_exceeded(resources: &ServerResources, auth: &AuthResult, path: &str) {
+    let rate_limit = &auth.rate_limit;
+    let event = AuditEvent::new(
+        AuditEventType::SecurityPolicyViolation,
+        AuditSeverity::Warning,
+        format!(
+            "Rate limit of {} requests exceeded in observe mode",
+            rate_limit.limit.unwrap_or(0)
+        ),
+        "rate_limit".to_owned(),
+        "observed".to_owned(),
+    )
+    .with_user_id(auth.user_id)
+    .with_resource(path.to_owned())
+    .with_metadata(json!({
+        "mode": RateLimitMode::Observe.as_str(),
+        "path": path,
+        "tier": rate_limit.tier,
+        "auth_method": rate_limit.auth_method,
+        "limit": rate_limit.limit,
+        "remaining": rate_limit.remaining,
+    }));
+
+    if let Err(e) = SecurityAuditor::new(resources.database.clone())
+        .log_event(event)
+        .await
+    {
+        warn!(user_id = %auth.user_id, error = %e, "Failed to record observed rate limit event");
+    }
+}
@@ -6,26 +6,36 @@
 
 //! Request ID middleware for request correlation
 //!
-//! This middleware generates a unique UUID v4 for each incoming request,
-//! adds it to the request extensions for use by handlers, and includes it
-//! in the response headers for client-side correlation.
+//! This middleware reuses the caller's `X-Request-Id` header when it is a
+//! reasonable correlation token, or generates a UUID v4 otherwise. The id is
+//! added to the request extensions and a task-local for the rest of the request,
+//! attached to a `request` tracing span so every event logged while handling the
+//! request carries it, and echoed back in the response header.
 
 use axum::{extract::Request, middleware::Next, response::Response};
-use http::HeaderValue;
+use http::{HeaderMap, HeaderValue};
 use std::fmt::{self, Display, Formatter};
-use tracing::Span;
+use tracing::{info_span, Instrument};
 use uuid::Uuid;
 
 /// Request ID header name
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
-/// Request ID middleware that generates and propagates correlation IDs
+/// Longest client-supplied request ID accepted before a new one is generated
+pub const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: RequestId;
+}
+
+/// Request ID middleware that accepts or generates and propagates correlation IDs
 ///
 /// This middleware:
-/// 1. Generates a unique UUID v4 for each request
+/// 1. Reuses a valid incoming `X-Request-Id` header, or generates a UUID v4
 /// 2. Adds the request ID to request extensions for handler access
-/// 3. Records the request ID in the current tracing span
-/// 4. Includes the request ID in the response header
+/// 3. Makes it available through [`current_request_id`] while the request runs
+/// 4. Runs the request inside a `request` span carrying a `request_id` field
+/// 5. Includes the request ID in the response header
 ///
 /// # Example
 ///
@@ -39,21 +49,22 @@ pub const REQUEST_ID_HEADER: &str = "x-request-id";
 ///     .layer(middleware::from_fn(request_id_middleware));
 /// ```
 pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
-    // Generate unique request ID
-    let request_id = Uuid::new_v4().to_string();
-
-    // Record request ID in current tracing span
-    let span = Span::current();
-    span.record("request_id", &request_id);
+    let request_id =
+        incoming_request_id(req.headers()).unwrap_or_else(|| RequestId(Uuid::new_v4().to_string()));
 
     // Add to request extensions for handler access
-    req.extensions_mut().insert(RequestId(request_id.clone()));
+    req.extensions_mut().insert(request_id.clone());
+
+    let span = info_span!("request", request_id = %request_id);
 
-    // Process request
-    let mut response = next.run(req).await;
+    // Process request with the id in scope for handlers and log events
+    let mut response = CURRENT_REQUEST_ID
+        .scope(request_id.clone(), next.run(req))
+        .instrument(span)
+        .await;
 
     // Add request ID to response header
-    if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+    if let Ok(header_value) = HeaderValue::from_str(request_id.as_str()) {
         response
             .headers_mut()
             .insert(REQUEST_ID_HEADER, header_value);
@@ -62,10 +73,30 @@ pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
     response
 }
 
+/// Request ID of the HTTP request currently being handled, if any
+///
+/// Only set inside [`request_id_middleware`]; work spawned onto other tasks
+/// and non-HTTP transports see `None`.
+#[must_use]
+pub fn current_request_id() -> Option<RequestId> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Extract a client-supplied request ID that is safe to log and echo back
+fn incoming_request_id(headers: &HeaderMap) -> Option<RequestId> {
+    let value = headers.get(REQUEST_ID_HEADER)?.to_str().ok()?.trim();
+    let valid = !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+    valid.then(|| RequestId(value.to_owned()))
+}
+
 /// Request ID extractor for use in handlers
 ///
 /// This can be extracted in any Axum handler to access the request ID
-/// generated by the middleware.
+/// accepted or generated by the middleware.
 ///
 /// # Example
 ///
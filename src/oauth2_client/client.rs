@@ -163,6 +163,22 @@ impl OAuth2Client {
     ///
     /// Returns an error if the authorization URL is malformed
     pub fn get_authorization_url(&self, state: &str) -> AppResult<String> {
+        self.get_authorization_url_for_scopes(state, &self.config.scopes)
+    }
+
+    /// Get authorization URL requesting `scopes` instead of the configured scopes
+    ///
+    /// Used for scope step-up, where only the scopes missing from an existing
+    /// grant are requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the authorization URL is malformed
+    pub fn get_authorization_url_for_scopes(
+        &self,
+        state: &str,
+        scopes: &[String],
+    ) -> AppResult<String> {
         let mut url = Url::parse(&self.config.auth_url)
             .map_err(|e| AppError::invalid_input(format!("Invalid auth URL: {e}")))?;
 
@@ -170,7 +186,7 @@ impl OAuth2Client {
             .append_pair("client_id", &self.config.client_id)
             .append_pair("redirect_uri", &self.config.redirect_uri)
             .append_pair("response_type", "code")
-            .append_pair("scope", &self.config.scopes.join(" "))
+            .append_pair("scope", &scopes.join(" "))
             .append_pair("state", state);
 
         Ok(url.to_string())
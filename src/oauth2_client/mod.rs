@@ -17,6 +17,8 @@
 pub mod client;
 /// Multi-tenant OAuth client wrapper
 pub mod tenant_client;
+/// Read-only inspection and live validation of stored provider tokens
+pub mod token_inspection;
 
 // Re-export main OAuth 2.0 client types
 pub use client::{OAuth2Client, OAuth2Config, OAuth2Token, OAuthClientState, PkceParams};
@@ -0,0 +1,337 @@
+// ABOUTME: Read-only inspection of users' stored provider OAuth tokens for operators and users
+// ABOUTME: Summarizes tokens without secret material, reports connection status, and checks tokens live
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Stored Token Inspection
+//!
+//! Backs the `pierre-cli token oauth` commands and the `list_connected_providers`
+//! tool. Tokens are read through the
+//! database layer, which decrypts them, but only [`OAuthTokenSummary`] values
+//! leave this module: provider, scopes, expiry and whether a refresh token is
+//! stored. The decrypted access token is only handed to a
+//! [`ProviderTokenCheck`] for the live check and never to the caller.
+//!
+//! The live check never refreshes: a token the provider refuses is reported as
+//! [`TokenValidity::Expired`] when its expiry has passed and as
+//! [`TokenValidity::Revoked`] otherwise.
+//!
+//! [`list_provider_connections`] reports a [`ConnectionStatus`] per provider
+//! from the stored expiry alone, so it makes no provider calls unless a check
+//! is passed in.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::database_plugins::{factory::Database, DatabaseProvider};
+use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::models::{TenantId, UserOAuthToken};
+use crate::providers::core::OAuth2Credentials;
+use crate::providers::registry::create_provider;
+
+/// A stored provider token with all secret material left out
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OAuthTokenSummary {
+    /// Provider name (strava, fitbit, ...)
+    pub provider: String,
+    /// Tenant the token was stored for
+    pub tenant_id: String,
+    /// Granted scopes
+    pub scopes: Vec<String>,
+    /// When the access token expires, if the provider said
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Whether a refresh token is stored alongside the access token
+    pub has_refresh_token: bool,
+    /// When the token was last stored or refreshed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OAuthTokenSummary {
+    /// Summarize a stored token, leaving out its secrets
+    #[must_use]
+    pub fn from_token(token: &UserOAuthToken) -> Self {
+        Self {
+            provider: token.provider.clone(),
+            tenant_id: token.tenant_id.clone(),
+            scopes: token
+                .scope
+                .as_deref()
+                .map(|scope| {
+                    scope
+                        .split([',', ' '])
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            expires_at: token.expires_at,
+            has_refresh_token: token.refresh_token.is_some(),
+            updated_at: token.updated_at,
+        }
+    }
+
+    /// Whether the stored expiry has passed
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+/// Outcome of checking a stored token against its provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenValidity {
+    /// The provider accepted the token
+    Valid,
+    /// The provider refused the token and its expiry has passed
+    Expired,
+    /// The provider refused the token before its expiry
+    Revoked,
+}
+
+impl TokenValidity {
+    /// Lowercase name used in CLI output
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Valid => "valid",
+            Self::Expired => "expired",
+            Self::Revoked => "revoked",
+        }
+    }
+}
+
+impl fmt::Display for TokenValidity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Provider's answer to an authenticated request made with a stored token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderTokenResponse {
+    /// The request succeeded
+    Accepted,
+    /// The provider refused the token
+    Rejected,
+}
+
+/// Live check of an access token against its provider
+#[async_trait]
+pub trait ProviderTokenCheck: Send + Sync {
+    /// Make an authenticated request to `provider` with `access_token`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the provider could not be asked, e.g. it is
+    /// unsupported, unreachable or failed for a reason unrelated to the token
+    async fn check(&self, provider: &str, access_token: &str) -> AppResult<ProviderTokenResponse>;
+}
+
+/// Checks a token by fetching the athlete profile, the cheapest authenticated call
+pub struct AthleteProfileCheck;
+
+#[async_trait]
+impl ProviderTokenCheck for AthleteProfileCheck {
+    async fn check(&self, provider: &str, access_token: &str) -> AppResult<ProviderTokenResponse> {
+        let client = create_provider(provider)?;
+        // Without a refresh token or expiry the client cannot swap in a new token mid-check
+        client
+            .set_credentials(OAuth2Credentials {
+                client_id: String::new(),
+                client_secret: String::new(),
+                access_token: Some(access_token.to_owned()),
+                refresh_token: None,
+                expires_at: None,
+                scopes: Vec::new(),
+            })
+            .await?;
+
+        match client.get_athlete().await {
+            Ok(_) => Ok(ProviderTokenResponse::Accepted),
+            Err(e) if is_token_rejection(&e) => Ok(ProviderTokenResponse::Rejected),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Whether a provider error means the token itself was refused
+fn is_token_rejection(error: &AppError) -> bool {
+    matches!(
+        error.code,
+        ErrorCode::AuthInvalid | ErrorCode::AuthExpired | ErrorCode::ExternalAuthFailed
+    ) || ["401 Unauthorized", "403 Forbidden"]
+        .iter()
+        // Provider clients report HTTP failures as external service errors naming the status
+        .any(|status| error.message.contains(status))
+}
+
+/// Result of validating one stored token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenValidationReport {
+    /// The token that was checked
+    pub token: OAuthTokenSummary,
+    /// What the provider said about it
+    pub validity: TokenValidity,
+}
+
+/// Summarize every provider token stored for a user
+///
+/// # Errors
+///
+/// Returns an error if no user has the email or the tokens cannot be read
+pub async fn list_user_oauth_tokens(
+    database: &Database,
+    email: &str,
+) -> AppResult<Vec<OAuthTokenSummary>> {
+    let user = database.get_user_by_email_required(email).await?;
+    let tokens = database.get_user_oauth_tokens(user.id, None).await?;
+    Ok(tokens.iter().map(OAuthTokenSummary::from_token).collect())
+}
+
+/// Check a user's stored tokens for one provider, one report per tenant
+///
+/// # Errors
+///
+/// Returns an error if no user has the email, the user has no token for the
+/// provider, or the provider could not be asked
+pub async fn validate_user_oauth_token(
+    database: &Database,
+    check: &dyn ProviderTokenCheck,
+    email: &str,
+    provider: &str,
+) -> AppResult<Vec<TokenValidationReport>> {
+    let user = database.get_user_by_email_required(email).await?;
+    let tokens: Vec<UserOAuthToken> = database
+        .get_user_oauth_tokens(user.id, None)
+        .await?
+        .into_iter()
+        .filter(|token| token.provider == provider)
+        .collect();
+    if tokens.is_empty() {
+        return Err(AppError::not_found(format!("{provider} token for {email}")));
+    }
+
+    let mut reports = Vec::with_capacity(tokens.len());
+    for token in &tokens {
+        let summary = OAuthTokenSummary::from_token(token);
+        let validity = match check.check(provider, &token.access_token).await? {
+            ProviderTokenResponse::Accepted => TokenValidity::Valid,
+            ProviderTokenResponse::Rejected if summary.is_expired() => TokenValidity::Expired,
+            ProviderTokenResponse::Rejected => TokenValidity::Revoked,
+        };
+        reports.push(TokenValidationReport {
+            token: summary,
+            validity,
+        });
+    }
+    Ok(reports)
+}
+
+/// Whether a user's connection to a provider can currently be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionStatus {
+    /// A token is stored and usable
+    Connected,
+    /// The stored access token has expired; it is refreshed on next use if a refresh token is stored
+    Expired,
+    /// The provider refused the token before its expiry (only detected by a live check)
+    Revoked,
+    /// No token is stored for the provider
+    NotConnected,
+}
+
+/// Connection state of one provider for a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProviderConnectionReport {
+    /// Provider name (strava, fitbit, ...)
+    pub provider: String,
+    /// Whether the connection can currently be used
+    pub status: ConnectionStatus,
+    /// Granted scopes, empty when not connected
+    pub scopes: Vec<String>,
+    /// When the access token expires, if the provider said
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Whether a refresh token is stored alongside the access token
+    pub has_refresh_token: bool,
+    /// When activities were last synced from the provider
+    pub last_sync: Option<DateTime<Utc>>,
+    /// Why the live check could not be completed, if it was requested and failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub probe_error: Option<String>,
+}
+
+/// Report a user's connection to each of `providers` within a tenant
+///
+/// Statuses come from the stored tokens. When `check` is given, each stored
+/// token is also checked live against its provider, which can reveal revoked
+/// tokens; a check that fails is reported in `probe_error` and leaves the
+/// stored status in place.
+///
+/// # Errors
+///
+/// Returns an error if the stored tokens or sync times cannot be read
+pub async fn list_provider_connections(
+    database: &Database,
+    check: Option<&dyn ProviderTokenCheck>,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    providers: &[&str],
+) -> AppResult<Vec<ProviderConnectionReport>> {
+    let tokens = database
+        .get_user_oauth_tokens(user_id, Some(tenant_id))
+        .await?;
+
+    let mut reports = Vec::with_capacity(providers.len());
+    for &provider in providers {
+        let last_sync = database
+            .get_provider_last_sync(user_id, tenant_id, provider)
+            .await?;
+        let Some(token) = tokens.iter().find(|token| token.provider == provider) else {
+            reports.push(ProviderConnectionReport {
+                provider: provider.to_owned(),
+                status: ConnectionStatus::NotConnected,
+                scopes: Vec::new(),
+                expires_at: None,
+                has_refresh_token: false,
+                last_sync,
+                probe_error: None,
+            });
+            continue;
+        };
+
+        let summary = OAuthTokenSummary::from_token(token);
+        let mut status = if summary.is_expired() {
+            ConnectionStatus::Expired
+        } else {
+            ConnectionStatus::Connected
+        };
+        let mut probe_error = None;
+        if let Some(check) = check {
+            match check.check(provider, &token.access_token).await {
+                Ok(ProviderTokenResponse::Accepted) => status = ConnectionStatus::Connected,
+                Ok(ProviderTokenResponse::Rejected) if summary.is_expired() => {}
+                Ok(ProviderTokenResponse::Rejected) => status = ConnectionStatus::Revoked,
+                Err(e) => probe_error = Some(e.to_string()),
+            }
+        }
+
+        reports.push(ProviderConnectionReport {
+            provider: summary.provider,
+            status,
+            scopes: summary.scopes,
+            expires_at: summary.expires_at,
+            has_refresh_token: summary.has_refresh_token,
+            last_sync,
+            probe_error,
+        });
+    }
+    Ok(reports)
+}
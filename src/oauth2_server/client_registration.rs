@@ -60,6 +60,10 @@ impl ClientRegistrationManager {
             .response_types
             .unwrap_or_else(|| vec!["code".to_owned()]);
 
+        // Public clients cannot keep a secret, so PKCE is mandatory for them;
+        // confidential clients require it unless they explicitly opt out
+        let require_pkce = request.is_public_client() || request.require_pkce.unwrap_or(true);
+
         let created_at = Utc::now();
         let expires_at = Some(created_at + Duration::days(365)); // 1 year expiry
 
@@ -76,6 +80,7 @@ impl ClientRegistrationManager {
             scope: request.scope.clone(), // Safe: Option<String> ownership for OAuth client
             created_at,
             expires_at,
+            require_pkce,
         };
 
         // Store in database
@@ -106,6 +111,7 @@ impl ClientRegistrationManager {
             scope: request
                 .scope
                 .or_else(|| Some("fitness:read activities:read profile:read".to_owned())),
+            require_pkce,
         })
     }
 
@@ -209,6 +215,12 @@ impl ClientRegistrationManager {
             }
         }
 
+        if request.is_public_client() && request.require_pkce == Some(false) {
+            return Err(OAuth2Error::invalid_request(
+                "Public clients (token_endpoint_auth_method 'none') must require PKCE",
+            ));
+        }
+
         // Validate grant types
         if let Some(ref grant_types) = request.grant_types {
             for grant_type in grant_types {
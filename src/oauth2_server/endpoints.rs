@@ -10,15 +10,19 @@
 
 use super::client_registration::ClientRegistrationManager;
 use super::models::{
-    AuthorizeRequest, AuthorizeResponse, OAuth2AuthCode, OAuth2Error, TokenRequest, TokenResponse,
+    AuthorizeRequest, AuthorizeResponse, IntrospectionRequest, IntrospectionResponse,
+    OAuth2AuthCode, OAuth2Error, OAuth2IssuedAccessToken, RevocationOutcome, RevocationRequest,
+    TokenRequest, TokenResponse,
 };
 use crate::admin::jwks::JwksManager;
 use crate::auth::{AuthManager, Claims, JwtValidationError};
 use crate::database_plugins::factory::Database;
 use crate::database_plugins::DatabaseProvider;
 use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::models::TenantId;
+use crate::security::audit::SecurityAuditor;
 use base64::{engine::general_purpose, Engine as _};
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::dangerous::insecure_decode;
 use ring::rand::{SecureRandom, SystemRandom};
 use sha2::{Digest, Sha256};
@@ -62,18 +66,24 @@ fn validate_pkce_verifier_format(verifier: &str) -> Result<(), OAuth2Error> {
     Ok(())
 }
 
-/// Compute PKCE challenge from verifier using S256 method
-fn compute_pkce_challenge(verifier: &str, method: &str) -> Result<String, OAuth2Error> {
-    if method != "S256" {
-        return Err(OAuth2Error::invalid_grant(
+/// Compute PKCE challenge from verifier (`S256`, or `plain` when the server allows it)
+fn compute_pkce_challenge(
+    verifier: &str,
+    method: &str,
+    require_s256: bool,
+) -> Result<String, OAuth2Error> {
+    match method {
+        "S256" => {
+            let mut hasher = Sha256::new();
+            hasher.update(verifier.as_bytes());
+            let hash = hasher.finalize();
+            Ok(general_purpose::URL_SAFE_NO_PAD.encode(hash))
+        }
+        "plain" if !require_s256 => Ok(verifier.to_owned()),
+        _ => Err(OAuth2Error::invalid_grant(
             "Only S256 code_challenge_method is supported (plain method is not allowed for security reasons)",
-        ));
+        )),
     }
-
-    let mut hasher = Sha256::new();
-    hasher.update(verifier.as_bytes());
-    let hash = hasher.finalize();
-    Ok(general_purpose::URL_SAFE_NO_PAD.encode(hash))
 }
 
 /// Verify PKCE challenge using constant-time comparison
@@ -82,6 +92,7 @@ fn verify_pkce_challenge(
     code_verifier: Option<&str>,
     code_challenge_method: Option<&str>,
     client_id: &str,
+    require_s256: bool,
 ) -> Result<(), OAuth2Error> {
     let verifier = code_verifier
         .ok_or_else(|| OAuth2Error::invalid_grant("code_verifier is required (PKCE)"))?;
@@ -89,7 +100,7 @@ fn verify_pkce_challenge(
     validate_pkce_verifier_format(verifier)?;
 
     let method = code_challenge_method.unwrap_or("S256");
-    let computed_challenge = compute_pkce_challenge(verifier, method)?;
+    let computed_challenge = compute_pkce_challenge(verifier, method, require_s256)?;
 
     // Constant-time comparison to prevent timing attacks
     if computed_challenge
@@ -114,6 +125,7 @@ pub struct OAuth2AuthorizationServer {
     auth_manager: Arc<AuthManager>,
     jwks_manager: Arc<JwksManager>,
     database: Arc<Database>,
+    require_s256: bool,
 }
 
 impl OAuth2AuthorizationServer {
@@ -131,9 +143,17 @@ impl OAuth2AuthorizationServer {
             auth_manager,
             jwks_manager,
             database,
+            require_s256: true,
         }
     }
 
+    /// Set whether `plain` PKCE challenges are rejected (default: true, only `S256` accepted)
+    #[must_use]
+    pub const fn with_require_s256(mut self, require_s256: bool) -> Self {
+        self.require_s256 = require_s256;
+        self
+    }
+
     /// Handle authorization request (GET /oauth/authorize)
     ///
     /// # Errors
@@ -200,15 +220,23 @@ impl OAuth2AuthorizationServer {
                 ));
             }
 
-            // Validate code_challenge_method - only S256 is allowed (RFC 7636 security best practice)
-            let method = request.code_challenge_method.as_deref().unwrap_or("S256");
-            if method != "S256" {
-                return Err(OAuth2Error::invalid_request(
-                    "code_challenge_method must be 'S256' (plain method is not supported for security reasons)",
-                ));
+            // Validate code_challenge_method - S256 always, plain only when the server allows it
+            match request.code_challenge_method.as_deref().unwrap_or("S256") {
+                "S256" => {}
+                "plain" if !self.require_s256 => {}
+                "plain" => {
+                    return Err(OAuth2Error::invalid_request(
+                        "code_challenge_method must be 'S256' (plain method is not supported for security reasons)",
+                    ));
+                }
+                _ => {
+                    return Err(OAuth2Error::invalid_request(
+                        "code_challenge_method must be 'S256' or 'plain'",
+                    ));
+                }
             }
-        } else {
-            // PKCE is required for authorization code flow
+        } else if client.require_pkce {
+            // PKCE is required for this client's authorization code flow
             return Err(OAuth2Error::invalid_request(
                 "code_challenge is required for authorization_code flow (PKCE)",
             ));
@@ -314,6 +342,355 @@ impl OAuth2AuthorizationServer {
         }
     }
 
+    /// Handle token introspection request (POST /oauth2/introspect, RFC 7662)
+    ///
+    /// Access tokens are checked by signature, expiry, and revocation, refresh
+    /// tokens by lookup in `oauth2_refresh_tokens`. Both kinds are only visible
+    /// to the client they were issued to. Every token that is not active for
+    /// the caller yields the same bare `{"active": false}` response.
+    ///
+    /// # Errors
+    /// Returns `invalid_client` if the caller's client credentials are invalid
+    pub async fn introspect(
+        &self,
+        request: IntrospectionRequest,
+    ) -> Result<IntrospectionResponse, OAuth2Error> {
+        self.client_manager
+            .validate_client(&request.client_id, &request.client_secret)
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    client_id = %request.client_id,
+                    error = ?e,
+                    "OAuth client validation failed for token introspection"
+                );
+            })?;
+
+        // The hint only decides lookup order (RFC 7662 Section 2.1)
+        let response = if request.token_type_hint.as_deref() == Some("refresh_token") {
+            match self
+                .introspect_refresh_token(&request.token, &request.client_id)
+                .await
+            {
+                Some(response) => response,
+                None => {
+                    self.introspect_access_token(&request.token, &request.client_id)
+                        .await
+                }
+            }
+        } else {
+            let response = self
+                .introspect_access_token(&request.token, &request.client_id)
+                .await;
+            if response.active {
+                response
+            } else {
+                self.introspect_refresh_token(&request.token, &request.client_id)
+                    .await
+                    .unwrap_or_else(IntrospectionResponse::inactive)
+            }
+        };
+
+        debug!(
+            client_id = %request.client_id,
+            active = response.active,
+            "Token introspection completed"
+        );
+        Ok(response)
+    }
+
+    /// Introspect a JWT access token issued by this server
+    ///
+    /// The token is only active when `client_id` owns it: client-credentials tokens
+    /// by their subject, user-authorized tokens by the client recorded in
+    /// `oauth2_access_tokens`.
+    async fn introspect_access_token(&self, token: &str, client_id: &str) -> IntrospectionResponse {
+        let Ok(claims) = self
+            .auth_manager
+            .validate_token_detailed(token, &self.jwks_manager)
+        else {
+            return IntrospectionResponse::inactive();
+        };
+
+        let client_id = if let Some(owner) = claims.sub.strip_prefix("client:") {
+            if owner != client_id {
+                return IntrospectionResponse::inactive();
+            }
+            Some(owner.to_owned())
+        } else {
+            if !self.is_access_token_owned_by(&claims, client_id).await {
+                return IntrospectionResponse::inactive();
+            }
+            // User-authorized tokens are only active while the user still exists
+            let Ok(user_id) = Uuid::parse_str(&claims.sub) else {
+                return IntrospectionResponse::inactive();
+            };
+            // SECURITY: Global lookup — token introspection has no tenant context
+            match self.database.get_user_global(user_id).await {
+                Ok(Some(_)) => Some(client_id.to_owned()),
+                Ok(None) => return IntrospectionResponse::inactive(),
+                Err(e) => {
+                    error!("Database error during access token introspection: {}", e);
+                    return IntrospectionResponse::inactive();
+                }
+            }
+        };
+
+        IntrospectionResponse {
+            active: true,
+            scope: (!claims.providers.is_empty()).then(|| claims.providers.join(" ")),
+            client_id,
+            exp: Some(claims.exp),
+            sub: Some(claims.sub),
+            tenant_id: claims.active_tenant_id,
+        }
+    }
+
+    /// Introspect a stored refresh token
+    ///
+    /// Returns `None` when the token is not an active refresh token of `client_id`.
+    async fn introspect_refresh_token(
+        &self,
+        token: &str,
+        client_id: &str,
+    ) -> Option<IntrospectionResponse> {
+        let refresh_token = self
+            .database
+            .get_oauth2_refresh_token(token)
+            .await
+            .inspect_err(|e| {
+                error!("Database error during refresh token introspection: {}", e);
+            })
+            .ok()??;
+
+        if refresh_token.revoked
+            || refresh_token.expires_at <= Utc::now()
+            || refresh_token.client_id != client_id
+        {
+            return None;
+        }
+
+        Some(IntrospectionResponse {
+            active: true,
+            scope: refresh_token.scope,
+            client_id: Some(refresh_token.client_id),
+            exp: Some(refresh_token.expires_at.timestamp()),
+            sub: Some(refresh_token.user_id.to_string()),
+            tenant_id: Some(refresh_token.tenant_id),
+        })
+    }
+
+    /// Handle token revocation request (POST /oauth2/revoke, RFC 7009)
+    ///
+    /// Revoking a refresh token also revokes every access token issued alongside it;
+    /// revoking an access token revokes only that token. Unknown tokens, tokens that
+    /// are already revoked, and tokens issued to another client are left untouched,
+    /// so the caller cannot tell them apart from a successful revocation.
+    ///
+    /// # Errors
+    /// Returns `invalid_client` if the caller's client credentials are invalid, or
+    /// `invalid_request` if the revocation cannot be stored
+    pub async fn revoke(
+        &self,
+        request: RevocationRequest,
+        source_ip: Option<String>,
+    ) -> Result<RevocationOutcome, OAuth2Error> {
+        self.client_manager
+            .validate_client(&request.client_id, &request.client_secret)
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    client_id = %request.client_id,
+                    error = ?e,
+                    "OAuth client validation failed for token revocation"
+                );
+            })?;
+
+        // The hint only decides lookup order (RFC 7009 Section 2.1)
+        let access_token_first = request.token_type_hint.as_deref() == Some("access_token");
+        let outcome = self
+            .revoke_matching_token(
+                &request.token,
+                &request.client_id,
+                access_token_first,
+                source_ip,
+            )
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to revoke token for client_id={}: {:#}",
+                    request.client_id, e
+                );
+                OAuth2Error::invalid_request("Failed to revoke token")
+            })?;
+
+        debug!(
+            client_id = %request.client_id,
+            refresh_token_revoked = outcome.refresh_token_revoked,
+            access_tokens_revoked = outcome.access_tokens_revoked,
+            "Token revocation completed"
+        );
+        Ok(outcome)
+    }
+
+    /// Revoke `token` as whichever token type it turns out to be
+    async fn revoke_matching_token(
+        &self,
+        token: &str,
+        client_id: &str,
+        access_token_first: bool,
+        source_ip: Option<String>,
+    ) -> AppResult<RevocationOutcome> {
+        let outcome = if access_token_first {
+            match self
+                .revoke_access_token(token, client_id, source_ip.clone())
+                .await?
+            {
+                Some(outcome) => Some(outcome),
+                None => {
+                    self.revoke_refresh_token(token, client_id, source_ip)
+                        .await?
+                }
+            }
+        } else {
+            match self
+                .revoke_refresh_token(token, client_id, source_ip.clone())
+                .await?
+            {
+                Some(outcome) => Some(outcome),
+                None => {
+                    self.revoke_access_token(token, client_id, source_ip)
+                        .await?
+                }
+            }
+        };
+        Ok(outcome.unwrap_or_default())
+    }
+
+    /// Revoke a refresh token of `client_id` and the access tokens issued with it
+    ///
+    /// Returns `None` when the token is not a refresh token of `client_id`.
+    async fn revoke_refresh_token(
+        &self,
+        token: &str,
+        client_id: &str,
+        source_ip: Option<String>,
+    ) -> AppResult<Option<RevocationOutcome>> {
+        let Some(refresh_token) = self.database.get_oauth2_refresh_token(token).await? else {
+            return Ok(None);
+        };
+        if refresh_token.client_id != client_id {
+            warn!(
+                "Client {} attempted to revoke a refresh token issued to another client",
+                client_id
+            );
+            return Ok(None);
+        }
+
+        if !refresh_token.revoked {
+            self.database.revoke_oauth2_refresh_token(token).await?;
+        }
+        let outcome = RevocationOutcome {
+            refresh_token_revoked: !refresh_token.revoked,
+            access_tokens_revoked: self
+                .database
+                .revoke_oauth2_access_tokens_for_refresh_token(token)
+                .await?,
+        };
+
+        if outcome != RevocationOutcome::default() {
+            self.audit_revocation(
+                client_id,
+                refresh_token.user_id,
+                refresh_token.tenant_id.parse().ok(),
+                "refresh_token",
+                outcome.access_tokens_revoked,
+                source_ip,
+            )
+            .await;
+        }
+        Ok(Some(outcome))
+    }
+
+    /// Revoke a single access token issued to `client_id`
+    ///
+    /// Returns `None` when the token is not a recorded access token of `client_id`.
+    /// Expired tokens are already unusable and are not recorded as revoked.
+    async fn revoke_access_token(
+        &self,
+        token: &str,
+        client_id: &str,
+        source_ip: Option<String>,
+    ) -> AppResult<Option<RevocationOutcome>> {
+        let Ok(claims) = self
+            .auth_manager
+            .validate_token_detailed(token, &self.jwks_manager)
+        else {
+            return Ok(None);
+        };
+        let Some(access_token) = self.database.get_oauth2_access_token(&claims.jti).await? else {
+            return Ok(None);
+        };
+        if access_token.client_id != client_id {
+            warn!(
+                "Client {} attempted to revoke an access token issued to another client",
+                client_id
+            );
+            return Ok(None);
+        }
+        if access_token.revoked {
+            return Ok(Some(RevocationOutcome::default()));
+        }
+
+        self.database
+            .revoke_oauth2_access_token(&access_token.jti)
+            .await?;
+        self.audit_revocation(
+            client_id,
+            access_token.user_id,
+            claims.active_tenant_id.and_then(|t| t.parse().ok()),
+            "access_token",
+            1,
+            source_ip,
+        )
+        .await;
+
+        Ok(Some(RevocationOutcome {
+            refresh_token_revoked: false,
+            access_tokens_revoked: 1,
+        }))
+    }
+
+    /// Record a revocation in the security audit log
+    ///
+    /// The revocation has already happened, so audit failures are logged but not returned.
+    async fn audit_revocation(
+        &self,
+        client_id: &str,
+        user_id: Uuid,
+        tenant_id: Option<TenantId>,
+        token_type: &str,
+        access_tokens_revoked: u64,
+        source_ip: Option<String>,
+    ) {
+        if let Err(e) = SecurityAuditor::new(self.database.clone())
+            .log_token_revocation(
+                client_id,
+                Some(user_id),
+                tenant_id,
+                token_type,
+                access_tokens_revoked,
+                source_ip,
+            )
+            .await
+        {
+            error!(
+                "Failed to audit token revocation for client_id={}: {}",
+                client_id, e
+            );
+        }
+    }
+
     /// Handle authorization code grant
     async fn handle_authorization_code_grant(
         &self,
@@ -380,6 +757,15 @@ impl OAuth2AuthorizationServer {
                 );
                 OAuth2Error::invalid_request("Failed to store refresh token")
             })?;
+        self.record_access_token(&access_token, &refresh_token_value, &refresh_token)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to record access token for client_id={}: {:#}",
+                    request.client_id, e
+                );
+                OAuth2Error::invalid_request("Failed to store access token")
+            })?;
 
         Ok(TokenResponse {
             access_token,
@@ -479,6 +865,15 @@ impl OAuth2AuthorizationServer {
                 );
                 OAuth2Error::invalid_request("Failed to store new refresh token")
             })?;
+        self.record_access_token(&access_token, &new_refresh_token_value, &new_refresh_token)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to record access token for client_id={}: {:#}",
+                    request.client_id, e
+                );
+                OAuth2Error::invalid_request("Failed to store access token")
+            })?;
 
         info!(
             "Refresh token rotated for client {} and user {}",
@@ -663,6 +1058,7 @@ impl OAuth2AuthorizationServer {
                 code_verifier,
                 auth_code.code_challenge_method.as_deref(),
                 client_id,
+                self.require_s256,
             )?;
         } else if code_verifier.is_some() {
             // Client provided verifier but no challenge was stored
@@ -763,6 +1159,69 @@ impl OAuth2AuthorizationServer {
             .await
     }
 
+    /// Record an access token issued alongside `refresh_token` (database operation)
+    ///
+    /// Links the token's `jti` to the plaintext `refresh_token_value` (hashed on storage)
+    /// so revoking the refresh token also revokes the access token.
+    async fn record_access_token(
+        &self,
+        access_token: &str,
+        refresh_token_value: &str,
+        refresh_token: &super::models::OAuth2RefreshToken,
+    ) -> AppResult<()> {
+        let claims = insecure_decode::<Claims>(access_token)
+            .map_err(|e| AppError::internal(format!("Failed to decode issued access token: {e}")))?
+            .claims;
+        let expires_at = DateTime::from_timestamp(claims.exp, 0)
+            .ok_or_else(|| AppError::internal("Issued access token has an invalid expiry"))?;
+
+        self.database
+            .store_oauth2_access_token(&OAuth2IssuedAccessToken {
+                jti: claims.jti,
+                refresh_token: refresh_token_value.to_owned(),
+                client_id: refresh_token.client_id.clone(), // Safe: Clone for storage
+                user_id: refresh_token.user_id,
+                expires_at,
+                revoked: false,
+            })
+            .await
+    }
+
+    /// Whether an access token has been revoked (RFC 7009)
+    ///
+    /// Database errors count as revoked so a failed lookup never keeps a token active.
+    async fn is_access_token_revoked(&self, claims: &Claims) -> bool {
+        match self.database.get_oauth2_access_token(&claims.jti).await {
+            Ok(access_token) => access_token.is_some_and(|t| t.revoked),
+            Err(e) => {
+                error!(
+                    "Database error while checking access token revocation: {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+
+    /// Whether a user-authorized access token is unrevoked and was issued to `client_id`
+    ///
+    /// Tokens without a record in `oauth2_access_tokens` and database errors count as
+    /// not owned, so introspection never reveals a token to the wrong client.
+    async fn is_access_token_owned_by(&self, claims: &Claims, client_id: &str) -> bool {
+        match self.database.get_oauth2_access_token(&claims.jti).await {
+            Ok(access_token) => {
+                access_token.is_some_and(|t| !t.revoked && t.client_id == client_id)
+            }
+            Err(e) => {
+                error!(
+                    "Database error while checking access token ownership: {}",
+                    e
+                );
+                false
+            }
+        }
+    }
+
     /// Validate and consume refresh token
     async fn validate_and_consume_refresh_token(
         &self,
@@ -827,6 +1286,10 @@ impl OAuth2AuthorizationServer {
     ) -> AppResult<super::models::ValidateRefreshResponse> {
         use super::models::{ValidateRefreshResponse, ValidationStatus};
 
+        if self.is_access_token_revoked(&claims).await {
+            return Ok(Self::create_invalid_response("token_revoked"));
+        }
+
         match Uuid::parse_str(&claims.sub) {
             // SECURITY: Global lookup — OAuth2 token validation, no tenant context
             Ok(user_id) => match self.database.get_user_global(user_id).await {
@@ -891,6 +1354,19 @@ impl OAuth2AuthorizationServer {
             refresh_token_data.scope.as_deref(),
         ) {
             Ok(new_access_token) => {
+                if let Err(e) = self
+                    .record_access_token(
+                        &new_access_token,
+                        refresh_token_value,
+                        &refresh_token_data,
+                    )
+                    .await
+                {
+                    error!("Failed to record refreshed access token: {}", e);
+                    return Ok(Self::create_invalid_response(
+                        "refresh_failed_token_generation",
+                    ));
+                }
                 info!(
                     "Successfully refreshed access token for user {}",
                     claims.sub
@@ -23,6 +23,18 @@ pub struct ClientRegistrationRequest {
     pub response_types: Option<Vec<String>>,
     /// Scopes the client can request
     pub scope: Option<String>,
+    /// Token endpoint authentication method (`none` registers a public client)
+    pub token_endpoint_auth_method: Option<String>,
+    /// Whether authorization requests must use PKCE (always true for public clients)
+    pub require_pkce: Option<bool>,
+}
+
+impl ClientRegistrationRequest {
+    /// Whether the client registered as public (`token_endpoint_auth_method` of `none`)
+    #[must_use]
+    pub fn is_public_client(&self) -> bool {
+        self.token_endpoint_auth_method.as_deref() == Some("none")
+    }
 }
 
 /// OAuth 2.0 Client Registration Response (RFC 7591)
@@ -48,6 +60,8 @@ pub struct ClientRegistrationResponse {
     pub client_uri: Option<String>,
     /// Scopes this client can request
     pub scope: Option<String>,
+    /// Whether authorization requests must use PKCE
+    pub require_pkce: bool,
 }
 
 /// OAuth 2.0 Authorization Request
@@ -114,6 +128,76 @@ pub struct TokenResponse {
     pub refresh_token: Option<String>,
 }
 
+/// OAuth 2.0 Token Introspection Request (RFC 7662)
+#[derive(Debug, Deserialize)]
+pub struct IntrospectionRequest {
+    /// Token to introspect (access or refresh token)
+    pub token: String,
+    /// Optional hint about the token type (`access_token` or `refresh_token`)
+    pub token_type_hint: Option<String>,
+    /// Client ID of the caller
+    pub client_id: String,
+    /// Client secret of the caller
+    pub client_secret: String,
+}
+
+/// OAuth 2.0 Token Introspection Response (RFC 7662)
+///
+/// Inactive tokens carry no other fields, so unknown, revoked, and expired
+/// tokens are indistinguishable to the caller.
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+pub struct IntrospectionResponse {
+    /// Whether the token is currently active
+    pub active: bool,
+    /// Space-separated scopes granted to the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Client the token was issued to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Expiration time (seconds since Unix epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    /// Subject of the token (user ID, or `client:<id>` for client credentials)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    /// Tenant the token is scoped to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+}
+
+impl IntrospectionResponse {
+    /// Response for a token that is unknown, revoked, expired, or not visible to the caller
+    #[must_use]
+    pub fn inactive() -> Self {
+        Self::default()
+    }
+}
+
+/// OAuth 2.0 Token Revocation Request (RFC 7009)
+#[derive(Debug, Deserialize)]
+pub struct RevocationRequest {
+    /// Token to revoke (access or refresh token)
+    pub token: String,
+    /// Optional hint about the token type (`access_token` or `refresh_token`)
+    pub token_type_hint: Option<String>,
+    /// Client ID of the caller
+    pub client_id: String,
+    /// Client secret of the caller
+    pub client_secret: String,
+}
+
+/// What a token revocation request revoked
+///
+/// Never sent to the client: RFC 7009 responds with an empty 200 regardless.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RevocationOutcome {
+    /// Whether an active refresh token was revoked
+    pub refresh_token_revoked: bool,
+    /// Number of access tokens revoked (directly or through their refresh token)
+    pub access_tokens_revoked: u64,
+}
+
 /// OAuth 2.0 Error Response
 #[derive(Debug, Serialize)]
 pub struct OAuth2Error {
@@ -196,7 +280,9 @@ impl OAuth2Error {
 }
 
 // Database persistence types re-exported from pierre-core for unified type identity
-pub use pierre_core::models::{OAuth2AuthCode, OAuth2Client, OAuth2RefreshToken, OAuth2State};
+pub use pierre_core::models::{
+    OAuth2AuthCode, OAuth2Client, OAuth2IssuedAccessToken, OAuth2RefreshToken, OAuth2State,
+};
 
 /// OAuth 2.0 Access Token
 #[derive(Debug, Clone)]
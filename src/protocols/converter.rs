@@ -8,12 +8,14 @@
 //!
 //! Converts between different protocol formats (MCP, A2A) and the universal format.
 
-use crate::a2a::protocol::{A2AErrorResponse, A2ARequest, A2AResponse};
+use crate::a2a::protocol::{A2ARequest, A2AResponse};
+use crate::errors::ErrorCode;
 use crate::mcp::schema::{Content, Tool, ToolCall, ToolResponse};
 use crate::protocols::universal::{UniversalRequest, UniversalResponse, UniversalTool};
 use crate::protocols::{ProtocolError, ProtocolType};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Write;
 use tracing::{debug, warn};
 
@@ -127,18 +129,15 @@ impl ProtocolConverter {
                 result: response.result,
                 error: None,
                 id: request_id,
+                metadata: HashMap::new(),
             }
         } else {
-            A2AResponse {
-                jsonrpc: "2.0".into(),
-                result: None,
-                error: Some(A2AErrorResponse {
-                    code: -32603,
-                    message: response.error.unwrap_or_else(|| "Internal error".into()),
-                    data: None,
-                }),
-                id: request_id,
-            }
+            A2AResponse::error_with_code(
+                request_id,
+                -32603,
+                ErrorCode::InternalError,
+                response.error.unwrap_or_else(|| "Internal error".into()),
+            )
         }
     }
 
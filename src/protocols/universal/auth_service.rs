@@ -12,10 +12,11 @@ use crate::mcp::resources::ServerResources;
 use crate::models::{TenantId, UserOAuthToken};
 use crate::oauth2_client::client::fitbit::refresh_fitbit_token;
 use crate::oauth2_client::client::strava::refresh_strava_token;
+use crate::oauth2_client::OAuth2Token;
 use crate::protocols::universal::UniversalResponse;
 use crate::providers::synthetic_provider::SyntheticProvider;
-use crate::providers::{CoreFitnessProvider, OAuth2Credentials};
-use crate::tenant::{TenantContext, TenantRole};
+use crate::providers::{CoreFitnessProvider, FileProvider, OAuth2Credentials};
+use crate::tenant::{TenantContext, TenantOAuthCredentials, TenantRole};
 use crate::utils::http_client::api_client;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
@@ -279,14 +280,37 @@ impl AuthService {
             return Ok(Box::new(provider));
         }
 
+        // Imported files don't use OAuth either - serve the user's stored imports
+        if provider_name == oauth_providers::FILE {
+            let activities = self
+                .resources
+                .database
+                .get_imported_activities(user_id)
+                .await
+                .map_err(|e| UniversalResponse {
+                    success: false,
+                    result: None,
+                    error: Some(format!("Failed to load imported activities: {e}")),
+                    metadata: None,
+                })?;
+            debug!(
+                user_id = %user_id,
+                activities = activities.len(),
+                "Creating file provider from imported activities"
+            );
+            return Ok(Box::new(FileProvider::with_activities(activities)));
+        }
+
         // Get valid token for the provider (with automatic refresh if needed)
         match self
             .get_valid_token(user_id, provider_name, tenant_id)
             .await
         {
             Ok(Some(token_data)) => {
-                self.create_provider_with_token(provider_name, token_data, tenant_id)
-                    .await
+                let provider = self
+                    .create_provider_with_token(provider_name, token_data, tenant_id)
+                    .await?;
+                Ok(self.limit_for_tenant(provider, user_id, tenant_id))
             }
             Ok(None) => Err(UniversalResponse {
                 success: false,
@@ -305,6 +329,23 @@ impl AuthService {
         }
     }
 
+    /// Count the provider's calls against the tenant's concurrent request limit
+    fn limit_for_tenant(
+        &self,
+        provider: Box<dyn CoreFitnessProvider>,
+        user_id: Uuid,
+        tenant_id: Option<&str>,
+    ) -> Box<dyn CoreFitnessProvider> {
+        match tenant_id.and_then(|id| id.parse::<TenantId>().ok()) {
+            Some(tenant_id) => Box::new(
+                self.resources
+                    .provider_registry
+                    .limit_for_tenant(provider, tenant_id, user_id),
+            ),
+            None => provider,
+        }
+    }
+
     /// Create provider with token and tenant-aware credentials
     async fn create_provider_with_token(
         &self,
@@ -454,32 +495,34 @@ impl AuthService {
         provider: &str,
         refresh_token: &str,
     ) -> Result<TokenData, OAuthError> {
-        // Get tenant-specific OAuth credentials, falling back to defaults
-        let (client_id, client_secret) = if tenant_id.is_empty() {
-            Self::get_default_oauth_credentials(provider)
-        } else {
-            self.get_tenant_oauth_credentials(tenant_id, provider).await
-        }
-        .map_err(|e| OAuthError::TokenRefreshFailed(e.error.unwrap_or_default()))?;
-
-        // Call provider-specific token refresh
-        let new_token = match provider.to_lowercase().as_str() {
-            "strava" => {
-                let http_client = api_client();
-                refresh_strava_token(&http_client, &client_id, &client_secret, refresh_token)
-                    .await
-                    .map_err(|e| OAuthError::TokenRefreshFailed(e.to_string()))?
-            }
-            "fitbit" => {
-                let http_client = api_client();
-                refresh_fitbit_token(&http_client, &client_id, &client_secret, refresh_token)
-                    .await
-                    .map_err(|e| OAuthError::TokenRefreshFailed(e.to_string()))?
+        // Tenant credentials may be mid-rotation, so refresh through the tenant client
+        // to fall back to the previous secret; otherwise use the default credentials
+        let new_token = match self
+            .find_tenant_oauth_credentials(tenant_id, provider)
+            .await?
+        {
+            Some(credentials) => {
+                self.resources
+                    .tenant_oauth_client
+                    .with_secret_fallback(&credentials, &self.resources.database, |client_secret| {
+                        let client_id = credentials.client_id.clone();
+                        async move {
+                            Self::request_token_refresh(
+                                provider,
+                                &client_id,
+                                &client_secret,
+                                refresh_token,
+                            )
+                            .await
+                        }
+                    })
+                    .await?
             }
-            other => {
-                return Err(OAuthError::TokenRefreshFailed(format!(
-                    "Token refresh not supported for provider: {other}"
-                )));
+            None => {
+                let (client_id, client_secret) = Self::get_default_oauth_credentials(provider)
+                    .map_err(|e| OAuthError::TokenRefreshFailed(e.error.unwrap_or_default()))?;
+                Self::request_token_refresh(provider, &client_id, &client_secret, refresh_token)
+                    .await?
             }
         };
 
@@ -514,6 +557,53 @@ impl AuthService {
         })
     }
 
+    /// Stored OAuth credentials of a tenant, if it has its own for the provider
+    async fn find_tenant_oauth_credentials(
+        &self,
+        tenant_id: &str,
+        provider: &str,
+    ) -> Result<Option<TenantOAuthCredentials>, OAuthError> {
+        if tenant_id.is_empty() {
+            return Ok(None);
+        }
+        let tenant_uuid: TenantId = tenant_id.parse().map_err(|_| {
+            OAuthError::TokenRefreshFailed(format!("Invalid tenant_id format: {tenant_id}"))
+        })?;
+
+        (*self.resources.database)
+            .get_tenant_oauth_credentials(tenant_uuid, provider)
+            .await
+            .map_err(|e| {
+                OAuthError::TokenRefreshFailed(format!(
+                    "Failed to get tenant OAuth credentials: {e}"
+                ))
+            })
+    }
+
+    /// Call the provider's token endpoint to refresh an access token
+    async fn request_token_refresh(
+        provider: &str,
+        client_id: &str,
+        client_secret: &str,
+        refresh_token: &str,
+    ) -> Result<OAuth2Token, OAuthError> {
+        let http_client = api_client();
+        match provider.to_lowercase().as_str() {
+            "strava" => {
+                refresh_strava_token(&http_client, client_id, client_secret, refresh_token).await
+            }
+            "fitbit" => {
+                refresh_fitbit_token(&http_client, client_id, client_secret, refresh_token).await
+            }
+            other => {
+                return Err(OAuthError::TokenRefreshFailed(format!(
+                    "Token refresh not supported for provider: {other}"
+                )));
+            }
+        }
+        .map_err(|e| OAuthError::TokenRefreshFailed(e.to_string()))
+    }
+
     /// Check if user has valid authentication for a provider
     pub async fn has_valid_auth(
         &self,
@@ -5,6 +5,7 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use super::auth_service::AuthService;
+use super::handlers::provider_helpers::extract_provider;
 use super::handlers::{
     handle_activate_coach, handle_admin_assign_coach, handle_admin_create_system_coach,
     handle_admin_delete_system_coach, handle_admin_get_system_coach,
@@ -33,6 +34,7 @@ use super::handlers::{
     handle_track_sleep_trends, handle_update_user_configuration, handle_validate_configuration,
 };
 use super::tool_registry::{ToolId, ToolInfo, ToolRegistry};
+use crate::config::environment::default_provider;
 use crate::constants::time_constants::SECONDS_PER_HOUR_F64;
 use crate::intelligence::physiological_constants::business_thresholds::{
     DEFAULT_HR_EFFORT_SCORE, DISTANCE_SCORE_DIVISOR, DURATION_SCORE_FACTOR, MAX_SCORE,
@@ -45,7 +47,11 @@ use crate::mcp::resources::ServerResources;
 use crate::models::Activity;
 use crate::protocols::universal::{UniversalRequest, UniversalResponse};
 use crate::protocols::ProtocolError;
+use crate::services::oauth_step_up;
+use serde_json::json;
 use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
 
 /// Intelligence service interface for analysis operations
 /// Provides abstraction layer for future intelligence module integration
@@ -520,6 +526,11 @@ impl UniversalExecutor {
             ProtocolError::InternalError(format!("Tool {tool_id:?} not registered"))
         })?;
 
+        // Ask for missing provider scopes before running the tool
+        if let Some(response) = self.scope_step_up_response(&request).await {
+            return Ok(response);
+        }
+
         // Create executor instance for handler execution
         let executor = Self::new(self.resources.clone()); // Safe: Arc clone for executor creation
 
@@ -539,6 +550,44 @@ impl UniversalExecutor {
         }
     }
 
+    /// Build a step-up response when the tool needs provider scopes the user has not granted
+    ///
+    /// Failures are logged and fall through to normal execution, which surfaces
+    /// any underlying connection problem through the tool's own error path.
+    async fn scope_step_up_response(
+        &self,
+        request: &UniversalRequest,
+    ) -> Option<UniversalResponse> {
+        let user_id = Uuid::parse_str(&request.user_id).ok()?;
+        let provider = request
+            .parameters
+            .as_object()
+            .map_or_else(default_provider, extract_provider);
+
+        let step_up = oauth_step_up::check_tool_scopes(
+            &self.resources,
+            &request.tool_name,
+            &provider,
+            user_id,
+            request.tenant_id.as_deref(),
+        )
+        .await
+        .inspect_err(|e| {
+            warn!(
+                "Scope step-up check failed for {}: {}",
+                request.tool_name, e
+            )
+        })
+        .ok()??;
+
+        Some(UniversalResponse {
+            success: false,
+            result: Some(json!({ "step_up": step_up })),
+            error: Some(step_up.message()),
+            metadata: None,
+        })
+    }
+
     /// List all available tools for MCP schema generation
     #[must_use]
     pub fn list_tools(&self) -> Vec<ToolId> {
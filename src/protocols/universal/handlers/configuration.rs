@@ -20,6 +20,7 @@ use crate::intelligence::physiological_constants::physiological_defaults::{
 };
 use crate::protocols::universal::{UniversalRequest, UniversalResponse, UniversalToolExecutor};
 use crate::protocols::ProtocolError;
+use crate::services::provider_priority::{apply_provider_priority, PROVIDER_PRIORITY_KEY};
 use crate::utils::uuid::parse_user_id_for_protocol;
 use std::collections::HashMap;
 use std::future::Future;
@@ -270,7 +271,7 @@ pub fn handle_update_user_configuration(
             .unwrap_or_else(|| serde_json::json!({}));
 
         // Build complete configuration structure
-        let configuration = serde_json::json!({
+        let mut configuration = serde_json::json!({
             "active_profile": profile,
             "profile": {
                 "name": profile,
@@ -282,6 +283,23 @@ pub fn handle_update_user_configuration(
             "last_modified": chrono::Utc::now().to_rfc3339()
         });
 
+        // Keep (or replace) the provider priority, which lives in the same document
+        if let Err(e) = apply_provider_priority(
+            &*executor.resources.database,
+            &user_uuid.to_string(),
+            request.parameters.get(PROVIDER_PRIORITY_KEY),
+            &mut configuration,
+        )
+        .await
+        {
+            return Ok(UniversalResponse {
+                success: false,
+                result: None,
+                error: Some(format!("Failed to update user configuration: {e}")),
+                metadata: None,
+            });
+        }
+
         // Save user configuration in database
         let config_json = serde_json::to_string(&configuration).map_err(|e| {
             ProtocolError::SerializationError(format!("Failed to serialize config: {e}"))
@@ -6,6 +6,8 @@
 
 use crate::cache::{factory::Cache, CacheKey, CacheResource};
 use crate::config::environment::default_provider;
+use crate::constants::json_fields::NO_CACHE;
+use crate::database_plugins::DatabaseProvider;
 use crate::formatters::{format_output, OutputFormat};
 use crate::intelligence::physiological_constants::api_limits::{
     safe_limit_json_detailed, safe_limit_json_summary, safe_limit_toon_detailed,
@@ -13,10 +15,12 @@ use crate::intelligence::physiological_constants::api_limits::{
     DEFAULT_ACTIVITY_LIMIT_U32, MAX_ACTIVITY_LIMIT, TOKENS_PER_ACTIVITY_DETAILED,
     TOKENS_PER_ACTIVITY_SUMMARY, USABLE_CONTEXT_TOKENS,
 };
-use crate::models::{Activity, Athlete, SportType, Stats, TenantId};
+use crate::models::{Activity, ActivityProjection, Athlete, SportType, Stats, TenantId};
 use crate::protocols::universal::{UniversalRequest, UniversalResponse, UniversalToolExecutor};
 use crate::protocols::ProtocolError;
 use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+use crate::providers::utils::deduplicate_activities;
+use crate::services::duplicate_merge::apply_duplicate_merges;
 use crate::utils::uuid::parse_user_id_for_protocol;
 use serde::Serialize;
 use serde_json::{json, to_value, Value};
@@ -141,7 +145,7 @@ impl TokenEstimate {
     /// Token counts are small enough that f64 precision loss is negligible
     #[allow(clippy::cast_precision_loss)]
     fn from_activities(count: usize, mode: &str) -> Self {
-        let tokens_per_activity = if mode == "summary" {
+        let tokens_per_activity = if mode == "summary" || mode == "fields" {
             TOKENS_PER_ACTIVITY_SUMMARY
         } else {
             TOKENS_PER_ACTIVITY_DETAILED
@@ -411,6 +415,7 @@ struct CachedActivitiesParams<'a> {
     tenant_id: Option<String>,
     provider_name: &'a str,
     mode: &'a str,
+    fields: Option<&'a ActivityProjection>,
     output_format: OutputFormat,
     limit: usize,
     offset: usize,
@@ -616,6 +621,7 @@ async fn try_get_cached_activities(
             tenant_id: params.tenant_id,
             provider_name: params.provider_name,
             mode: params.mode,
+            fields: params.fields,
             output_format: params.output_format,
             pagination: Some(&pagination),
             default_time_window_applied: params.default_time_window_applied,
@@ -653,6 +659,93 @@ async fn cache_activities_result(
     }
 }
 
+/// `provider` value that fetches from every connected provider and merges duplicates
+const ALL_CONNECTED_PROVIDERS: &str = "all";
+
+/// Fetch activities from every provider the user has connected
+///
+/// Providers that fail authentication or fetching are skipped with a warning.
+/// When more than one provider returns data, the same session synced through
+/// several providers (e.g. Garmin uploads imported by Strava) is merged into one.
+async fn fetch_activities_from_connected_providers(
+    executor: &UniversalToolExecutor,
+    user_uuid: Uuid,
+    tenant_id: Option<&str>,
+    query_params: &ActivityQueryParams,
+    no_cache: bool,
+) -> Result<Vec<Activity>, UniversalResponse> {
+    let connections = executor
+        .resources
+        .database
+        .get_user_provider_connections(user_uuid, None)
+        .await
+        .map_err(|e| UniversalResponse {
+            success: false,
+            result: None,
+            error: Some(format!("Failed to load provider connections: {e}")),
+            metadata: None,
+        })?;
+
+    let mut provider_names: Vec<String> = connections.into_iter().map(|c| c.provider).collect();
+    provider_names.sort();
+    provider_names.dedup();
+
+    let mut activities = Vec::new();
+    let mut sources = 0_usize;
+    for provider_name in &provider_names {
+        let provider = match executor
+            .auth_service
+            .create_authenticated_provider(provider_name, user_uuid, tenant_id)
+            .await
+        {
+            Ok(provider) => provider,
+            Err(response) => {
+                warn!(provider = %provider_name, error = ?response.error, "Skipping provider that failed authentication");
+                continue;
+            }
+        };
+
+        let fetched = executor
+            .resources
+            .activity_cache
+            .get_or_fetch(user_uuid, provider_name, query_params, no_cache, || {
+                provider.get_activities_with_params(query_params)
+            })
+            .await;
+        match fetched {
+            Ok(fetched) => {
+                sources += 1;
+                activities.extend(fetched);
+            }
+            Err(e) => {
+                warn!(provider = %provider_name, error = %e, "Skipping provider whose activities could not be fetched");
+            }
+        }
+    }
+
+    if sources == 0 {
+        return Err(UniversalResponse {
+            success: false,
+            result: None,
+            error: Some("No connected provider returned activities".to_owned()),
+            metadata: None,
+        });
+    }
+
+    if sources > 1 {
+        let fetched_count = activities.len();
+        activities = deduplicate_activities(activities);
+        info!(
+            sources,
+            fetched_count,
+            merged_count = activities.len(),
+            "Deduplicated activities across connected providers"
+        );
+    }
+
+    Ok(activities)
+}
+
 /// Filter activities by sport type (case-insensitive)
 /// Handles both standard sport types (serialized as strings like "run")
 /// and Other variants (serialized as {"other":"NordicSki"})
@@ -719,13 +812,19 @@ fn build_activities_metadata(
     map
 }
 
-/// Prepare activity data for response based on mode (summary or detailed)
+/// Prepare activity data for response based on field projection or mode (summary or detailed)
 /// Returns the JSON value and mode string, or error message string if serialization fails
 fn prepare_activity_data(
     activities: &[Activity],
     mode: &str,
+    fields: Option<&ActivityProjection>,
 ) -> Result<(Value, &'static str), String> {
-    if mode == "summary" {
+    if let Some(projection) = fields {
+        projection
+            .project_all(activities)
+            .map(|v| (v, "fields"))
+            .map_err(|e| format!("Failed to project activity fields: {e}"))
+    } else if mode == "summary" {
         let summaries: Vec<ActivitySummary> =
             activities.iter().map(ActivitySummary::from).collect();
         to_value(&summaries)
@@ -763,6 +862,8 @@ struct ActivitiesResponseParams<'a> {
     tenant_id: Option<String>,
     provider_name: &'a str,
     mode: &'a str,
+    /// Explicit field projection, which takes precedence over `mode`
+    fields: Option<&'a ActivityProjection>,
     output_format: OutputFormat,
     pagination: Option<&'a PaginationInfo>,
     default_time_window_applied: bool,
@@ -773,6 +874,7 @@ struct ActivitiesResponseParams<'a> {
 /// Build success response for activities with mode and format support
 /// `mode="summary"` returns minimal fields (id, name, `sport_type`, `start_date`, distance, duration)
 /// `mode="detailed"` returns full activity data (default for backwards compatibility when not specified)
+/// `fields` returns only the listed activity fields and overrides `mode`
 /// `format="json"` (default) or `format="toon"` for token-efficient LLM output
 /// `pagination` enables clients to paginate through large result sets
 /// `default_time_window_applied` indicates if the 90-day default was used
@@ -783,14 +885,15 @@ fn build_activities_success_response(params: ActivitiesResponseParams<'_>) -> Un
         tenant_id,
         provider_name,
         mode,
+        fields,
         output_format,
         pagination,
         default_time_window_applied,
         analysis_type,
     } = params;
 
-    // Prepare the data based on mode
-    let (data_value, mode_used) = match prepare_activity_data(activities, mode) {
+    // Prepare the data based on field projection or mode
+    let (data_value, mode_used) = match prepare_activity_data(activities, mode, fields) {
         Ok(result) => result,
         Err(error) => {
             return UniversalResponse {
@@ -1025,6 +1128,10 @@ pub fn handle_get_activities(
             .and_then(|v| v.as_str())
             .unwrap_or("summary");
 
+        // Extract optional field projection; overrides mode when present
+        let fields = ActivityProjection::from_param(request.parameters.get("fields"))
+            .map_err(|e| ProtocolError::InvalidParameters(e.message))?;
+
         // Extract analysis_type parameter for data sufficiency guidance
         // Valid values: general_overview (default), weekly_summary, trend_analysis,
         // race_preparation, recovery_assessment
@@ -1091,12 +1198,19 @@ pub fn handle_get_activities(
             .and_then(|v| v.as_str())
             .map(str::to_owned);
 
+        // Extract no_cache override: bypass cached results and fetch fresh provider data
+        let no_cache = request
+            .parameters
+            .get(NO_CACHE)
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
         // Build query params
         let query_params = ActivityQueryParams {
             limit: Some(limit),
             offset,
-            before,
-            after,
+            before: before.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0)),
+            after: after.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0)),
         };
 
         // Create cache key for activities
@@ -1150,22 +1264,27 @@ pub fn handle_get_activities(
             has_more: returned_count == limit,
         };
 
-        // Try to get from cache first
-        if let Some(cached_response) = try_get_cached_activities(CachedActivitiesParams {
-            cache: &executor.resources.cache,
-            cache_key: &cache_key,
-            user_uuid,
-            tenant_id: request.tenant_id.clone(),
-            provider_name: &provider_name,
-            mode,
-            output_format,
-            limit,
-            offset: offset.unwrap_or(0),
-            default_time_window_applied,
-            analysis_type,
-        })
-        .await
-        {
+        // Try to get from cache first, unless the caller asked for fresh data
+        let cached_response = if no_cache {
+            None
+        } else {
+            try_get_cached_activities(CachedActivitiesParams {
+                cache: &executor.resources.cache,
+                cache_key: &cache_key,
+                user_uuid,
+                tenant_id: request.tenant_id.clone(),
+                provider_name: &provider_name,
+                mode,
+                fields: fields.as_ref(),
+                output_format,
+                limit,
+                offset: offset.unwrap_or(0),
+                default_time_window_applied,
+                analysis_type,
+            })
+            .await
+        };
+        if let Some(cached_response) = cached_response {
             // Report completion if we got from cache
             if let Some(reporter) = &request.progress_reporter {
                 reporter.report(
@@ -1195,96 +1314,139 @@ pub fn handle_get_activities(
             }
         }
 
-        // Create authenticated provider
-        match executor
-            .auth_service
-            .create_authenticated_provider(&provider_name, user_uuid, request.tenant_id.as_deref())
+        // Fetch from every connected provider, or create the single authenticated provider
+        let activities = if provider_name == ALL_CONNECTED_PROVIDERS {
+            match fetch_activities_from_connected_providers(
+                executor,
+                user_uuid,
+                request.tenant_id.as_deref(),
+                &query_params,
+                no_cache,
+            )
             .await
-        {
-            Ok(provider) => {
-                // Report progress after successful auth
-                if let Some(reporter) = &request.progress_reporter {
-                    reporter.report(
-                        50.0,
-                        Some(100.0),
-                        Some(format!(
-                            "Authenticated - fetching activities from {provider_name}..."
-                        )),
-                    );
-                }
-
-                // Check cancellation before API call
-                if let Some(token) = &request.cancellation_token {
-                    if token.is_cancelled().await {
-                        return Err(ProtocolError::OperationCancelled(
-                            "get_activities cancelled before API call".to_owned(),
-                        ));
-                    }
-                }
-
-                // Get activities from provider with full query params
-                match provider.get_activities_with_params(&query_params).await {
-                    Ok(activities) => {
-                        // Apply sport_type filter if specified (server-side filtering)
-                        let mut filtered_activities = filter_activities_by_sport_type(
-                            activities,
-                            sport_type_filter.as_deref(),
-                        );
+            {
+                Ok(activities) => activities,
+                Err(response) => return Ok(response),
+            }
+        } else {
+            let provider = match executor
+                .auth_service
+                .create_authenticated_provider(
+                    &provider_name,
+                    user_uuid,
+                    request.tenant_id.as_deref(),
+                )
+                .await
+            {
+                Ok(provider) => provider,
+                Err(response) => return Ok(response),
+            };
 
-                        // Sort by start_date descending (newest first) for consistent ordering
-                        filtered_activities.sort_by_key(|a| Reverse(a.start_date()));
+            // Report progress after successful auth
+            if let Some(reporter) = &request.progress_reporter {
+                reporter.report(
+                    50.0,
+                    Some(100.0),
+                    Some(format!(
+                        "Authenticated - fetching activities from {provider_name}..."
+                    )),
+                );
+            }
 
-                        // Report completion
-                        if let Some(reporter) = &request.progress_reporter {
-                            reporter.report(
-                                100.0,
-                                Some(100.0),
-                                Some(format!(
-                                    "Successfully fetched {} activities{}",
-                                    filtered_activities.len(),
-                                    sport_type_filter
-                                        .as_ref()
-                                        .map_or(String::new(), |st| format!(" (filtered by {st})"))
-                                )),
-                            );
-                        }
+            // Check cancellation before API call
+            if let Some(token) = &request.cancellation_token {
+                if token.is_cancelled().await {
+                    return Err(ProtocolError::OperationCancelled(
+                        "get_activities cancelled before API call".to_owned(),
+                    ));
+                }
+            }
 
-                        // Cache the filtered activities (cache key includes sport_type)
-                        cache_activities_result(
-                            &executor.resources.cache,
-                            &cache_key,
-                            &filtered_activities,
-                            per_page,
-                        )
-                        .await;
-
-                        // Create pagination info for fresh results
-                        let pagination = create_pagination(filtered_activities.len());
-
-                        Ok(build_activities_success_response(
-                            ActivitiesResponseParams {
-                                activities: &filtered_activities,
-                                user_uuid,
-                                tenant_id: request.tenant_id,
-                                provider_name: &provider_name,
-                                mode,
-                                output_format,
-                                pagination: Some(&pagination),
-                                default_time_window_applied,
-                                analysis_type,
-                            },
-                        ))
-                    }
-                    Err(e) => Ok(UniversalResponse {
+            // Get activities from provider with full query params, via the activity cache
+            let fetched = executor
+                .resources
+                .activity_cache
+                .get_or_fetch(user_uuid, &provider_name, &query_params, no_cache, || {
+                    provider.get_activities_with_params(&query_params)
+                })
+                .await;
+            match fetched {
+                Ok(activities) => activities,
+                Err(e) => {
+                    return Ok(UniversalResponse {
                         success: false,
                         result: None,
                         error: Some(format!("Failed to fetch activities: {e}")),
                         metadata: None,
-                    }),
+                    })
                 }
             }
-            Err(response) => Ok(response),
+        };
+
+        // Hide duplicates the user merged by hand and fill their primaries' gaps
+        let activities = match executor
+            .resources
+            .database
+            .list_duplicate_merges(user_uuid, tenant_uuid)
+            .await
+        {
+            Ok(merges) => apply_duplicate_merges(activities, &merges),
+            Err(e) => {
+                warn!(user_id = %user_uuid, error = %e, "Failed to load duplicate merges; listing activities unmerged");
+                activities
+            }
+        };
+
+        // Apply sport_type filter if specified (server-side filtering)
+        let mut filtered_activities =
+            filter_activities_by_sport_type(activities, sport_type_filter.as_deref());
+
+        // Sort by start_date descending (newest first) for consistent ordering
+        filtered_activities.sort_by_key(|a| Reverse(a.start_date()));
+        // Merged multi-provider results can exceed the per-provider limit
+        filtered_activities.truncate(limit);
+
+        // Report completion
+        if let Some(reporter) = &request.progress_reporter {
+            reporter.report(
+                100.0,
+                Some(100.0),
+                Some(format!(
+                    "Successfully fetched {} activities{}",
+                    filtered_activities.len(),
+                    sport_type_filter
+                        .as_ref()
+                        .map_or(String::new(), |st| format!(" (filtered by {st})"))
+                )),
+            );
         }
+
+        // Cache the filtered activities (cache key includes sport_type)
+        cache_activities_result(
+            &executor.resources.cache,
+            &cache_key,
+            &filtered_activities,
+            per_page,
+        )
+        .await;
+
+        // Create pagination info for fresh results
+        let pagination = create_pagination(filtered_activities.len());
+
+        Ok(build_activities_success_response(
+            ActivitiesResponseParams {
+                activities: &filtered_activities,
+                user_uuid,
+                tenant_id: request.tenant_id,
+                provider_name: &provider_name,
+                mode,
+                fields: fields.as_ref(),
+                output_format,
+                pagination: Some(&pagination),
+                default_time_window_applied,
+                analysis_type,
+            },
+        ))
     })
 }
 
@@ -11,6 +11,10 @@ use crate::constants::limits::{self, METERS_PER_KILOMETER};
 use crate::constants::time_constants;
 use crate::constants::units::METERS_PER_KM;
 use crate::errors::{AppResult, ErrorCode};
+use crate::intelligence::analysis_cache::{algorithm_versions, AnalysisInput, AnalysisResultCache};
+use crate::intelligence::custom_metrics::{
+    compile_definitions, CompiledCustomMetric, CustomMetricDefinition,
+};
 use crate::intelligence::physiological_constants::api_limits::{
     DEFAULT_ACTIVITY_LIMIT, MAX_ACTIVITY_LIMIT,
 };
@@ -27,10 +31,10 @@ use crate::intelligence::physiological_constants::hr_estimation::ASSUMED_MAX_HR;
 use crate::intelligence::physiological_constants::unit_conversions::MS_TO_KMH_FACTOR;
 use crate::intelligence::training_load::TrainingLoad;
 use crate::intelligence::{
-    HardEasyPattern, MetricType, OvertrainingSignals, PatternDetector, PerformancePredictor,
-    RiskLevel, SafeMetricExtractor, SleepAnalyzer, StatisticalAnalyzer, TrainingLoadCalculator,
-    TrainingStatus, TrendDataPoint, TrendDirection, TssDataPoint, VolumeProgressionPattern,
-    VolumeTrend, WeeklySchedulePattern,
+    HardEasyPattern, MetricType, MetricsCalculator, OvertrainingSignals, PatternDetector,
+    PerformancePredictor, RiskLevel, SafeMetricExtractor, SleepAnalyzer, StatisticalAnalyzer,
+    TrainingLoadCalculator, TrainingStatus, TrendDataPoint, TrendDirection, TssDataPoint,
+    VolumeProgressionPattern, VolumeTrend, WeeklySchedulePattern,
 };
 use crate::mcp::sampling_peer::SamplingPeer;
 use crate::mcp::schema::{Content, CreateMessageRequest, ModelPreferences, PromptMessage};
@@ -45,10 +49,64 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration as StdDuration;
 use tracing::{info, warn};
+use uuid::Uuid;
 
 use super::{apply_format_to_response, extract_output_format};
 
+/// Result of an activity analysis and its cache metadata, if it went through the cache
+type AnalysisWithCacheMetadata = (serde_json::Value, Option<serde_json::Value>);
+
+/// Run an activity analysis through the analysis result cache
+///
+/// The cache key hashes the exact activities, `parameters`, and algorithm version.
+/// These analyses are relative to today, so the current UTC date is added to the
+/// parameters as well. If the input cannot be hashed the analysis is computed directly.
+async fn compute_cached_analysis<F>(
+    executor: &UniversalToolExecutor,
+    request: &UniversalRequest,
+    user_uuid: Uuid,
+    provider_name: &str,
+    mut input: AnalysisInput<'_>,
+    compute: F,
+) -> AnalysisWithCacheMetadata
+where
+    F: Fn() -> serde_json::Value + Send + Sync,
+{
+    if let Some(params) = input.parameters.as_object_mut() {
+        params.insert(
+            "as_of".to_owned(),
+            serde_json::Value::String(Utc::now().date_naive().to_string()),
+        );
+    }
+
+    let tenant_id = request
+        .tenant_id
+        .as_deref()
+        .and_then(|t| t.parse::<TenantId>().ok())
+        .unwrap_or_else(TenantId::nil);
+    let ttl = StdDuration::from_secs(executor.resources.config.cache.ttl.analysis_secs);
+
+    match AnalysisResultCache::new(&executor.resources.cache, ttl)
+        .get_or_compute(tenant_id, user_uuid, provider_name, &input, &compute)
+        .await
+    {
+        Ok(cached) => {
+            let metadata = cached.metadata();
+            (cached.result, Some(metadata))
+        }
+        Err(e) => {
+            warn!(
+                analysis = input.analysis,
+                error = %e,
+                "Analysis cache unavailable, computing directly"
+            );
+            (compute(), None)
+        }
+    }
+}
+
 /// Activity parameters extracted from request
 struct ActivityParameters {
     distance: f64,
@@ -399,6 +457,78 @@ fn build_metrics_response(
     }
 }
 
+/// Parse and compile the optional `custom_metrics` definitions from a request
+///
+/// # Errors
+/// Returns `ProtocolError::InvalidParameters` if the definitions are malformed or
+/// fail expression validation
+fn parse_custom_metrics(
+    request: &UniversalRequest,
+) -> Result<Vec<CompiledCustomMetric>, ProtocolError> {
+    let Some(value) = request
+        .parameters
+        .get("custom_metrics")
+        .filter(|value| !value.is_null())
+    else {
+        return Ok(Vec::new());
+    };
+
+    let definitions: Vec<CustomMetricDefinition> =
+        serde_json::from_value(value.clone()).map_err(|e| {
+            ProtocolError::InvalidParameters(format!("Invalid custom_metrics parameter: {e}"))
+        })?;
+
+    compile_definitions(&definitions)
+        .map_err(|e| ProtocolError::InvalidParameters(format!("Invalid custom metric: {e}")))
+}
+
+/// Evaluate custom metrics for an activity and add them to the response result
+///
+/// Metrics that cannot be evaluated (missing fields, division by zero) are
+/// reported with a `null` value so callers can tell them apart from typos.
+fn attach_custom_metrics(
+    response: &mut UniversalResponse,
+    activity: &Activity,
+    calculator: &MetricsCalculator,
+    custom_metrics: &[CompiledCustomMetric],
+) -> Result<(), ProtocolError> {
+    if custom_metrics.is_empty() {
+        return Ok(());
+    }
+
+    let metrics = calculator
+        .calculate_metrics_with_custom(activity, custom_metrics)
+        .map_err(|e| {
+            ProtocolError::ExecutionFailed(format!("Failed to calculate custom metrics: {e}"))
+        })?;
+
+    let values: serde_json::Map<String, serde_json::Value> = custom_metrics
+        .iter()
+        .map(|custom| {
+            (
+                custom.name().to_owned(),
+                serde_json::json!({
+                    "value": metrics.custom_metrics.get(custom.name()),
+                    "unit": custom.unit(),
+                    "expression": custom.expression().as_str(),
+                }),
+            )
+        })
+        .collect();
+
+    if let Some(result) = response
+        .result
+        .as_mut()
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        result.insert(
+            "custom_metrics".to_owned(),
+            serde_json::Value::Object(values),
+        );
+    }
+    Ok(())
+}
+
 /// Fetch activity from provider and calculate metrics (helper for `activity_id` path)
 async fn fetch_and_calculate_metrics(
     executor: &UniversalToolExecutor,
@@ -406,6 +536,7 @@ async fn fetch_and_calculate_metrics(
     activity_id: &str,
     provider_name: &str,
     user_uuid: uuid::Uuid,
+    custom_metrics: &[CompiledCustomMetric],
 ) -> Result<UniversalResponse, ProtocolError> {
     // Get valid token
     let token_data = match executor
@@ -484,12 +615,21 @@ async fn fetch_and_calculate_metrics(
     let (max_hr, max_hr_source) = determine_max_heart_rate(params.max_hr_provided, params.user_age);
     let metrics = calculate_activity_metrics(&params, max_hr);
 
-    Ok(build_metrics_response(
-        &params,
-        &metrics,
-        max_hr,
-        &max_hr_source,
-    ))
+    let mut response = build_metrics_response(&params, &metrics, max_hr, &max_hr_source);
+
+    // Custom expressions see the same max HR as the response and the user's FTP
+    let calculator = MetricsCalculator::new().with_user_data(
+        request
+            .parameters
+            .get("ftp")
+            .and_then(serde_json::Value::as_f64),
+        None,
+        Some(max_hr),
+        None,
+        None,
+    );
+    attach_custom_metrics(&mut response, &activity, &calculator, custom_metrics)?;
+    Ok(response)
 }
 
 /// Handle `calculate_metrics` tool - calculate custom fitness metrics (async)
@@ -505,6 +645,9 @@ pub async fn handle_calculate_metrics(
     // Extract output format parameter: "json" (default) or "toon"
     let output_format = extract_output_format(&request);
 
+    // Validate user-defined metrics before any provider calls
+    let custom_metrics = parse_custom_metrics(&request)?;
+
     // Check if activity_id is provided (schema-compliant path)
     if let Some(activity_id) = request
         .parameters
@@ -521,15 +664,26 @@ pub async fn handle_calculate_metrics(
                 )
             })?;
 
-        let result =
-            fetch_and_calculate_metrics(executor, &request, activity_id, provider_name, user_uuid)
-                .await?;
+        let result = fetch_and_calculate_metrics(
+            executor,
+            &request,
+            activity_id,
+            provider_name,
+            user_uuid,
+            &custom_metrics,
+        )
+        .await?;
 
         // Apply format transformation
         return Ok(apply_format_to_response(result, "metrics", output_format));
     }
 
     // Fallback path: activity object provided directly
+    if !custom_metrics.is_empty() {
+        return Err(ProtocolError::InvalidParameters(
+            "custom_metrics requires activity_id and provider".to_owned(),
+        ));
+    }
     let params = parse_activity_parameters(&request)?;
     let (max_hr, max_hr_source) = determine_max_heart_rate(params.max_hr_provided, params.user_age);
     let metrics = calculate_activity_metrics(&params, max_hr);
@@ -1657,7 +1811,20 @@ pub fn handle_calculate_fitness_score(
                             );
                         }
 
-                        let mut analysis = calculate_fitness_metrics(&activities, timeframe);
+                        let (mut analysis, analysis_cache) = compute_cached_analysis(
+                            executor,
+                            &request,
+                            user_uuid,
+                            &provider_name,
+                            AnalysisInput {
+                                analysis: "fitness_score",
+                                algorithm_version: algorithm_versions::FITNESS_SCORE,
+                                activities: &activities,
+                                parameters: serde_json::json!({ "timeframe": timeframe }),
+                            },
+                            || calculate_fitness_metrics(&activities, timeframe),
+                        )
+                        .await;
 
                         // If sleep_provider is specified, fetch recovery data and adjust score
                         let recovery_info = if let Some(sleep_provider_name) = sleep_provider {
@@ -1740,6 +1907,9 @@ pub fn handle_calculate_fitness_score(
                                         serde_json::Value::Bool(true),
                                     );
                                 }
+                                if let Some(cache) = analysis_cache {
+                                    map.insert("analysis_cache".to_owned(), cache);
+                                }
                                 map
                             }),
                         };
@@ -1855,7 +2025,20 @@ pub fn handle_predict_performance(
                             );
                         }
 
-                        let prediction = predict_race_performance(&activities, target_sport);
+                        let (prediction, analysis_cache) = compute_cached_analysis(
+                            executor,
+                            &request,
+                            user_uuid,
+                            &provider_name,
+                            AnalysisInput {
+                                analysis: "performance_prediction",
+                                algorithm_version: algorithm_versions::PERFORMANCE_PREDICTION,
+                                activities: &activities,
+                                parameters: serde_json::json!({ "target_sport": target_sport }),
+                            },
+                            || predict_race_performance(&activities, target_sport),
+                        )
+                        .await;
 
                         // Report completion
                         if let Some(reporter) = &request.progress_reporter {
@@ -1876,6 +2059,9 @@ pub fn handle_predict_performance(
                                     "user_id".to_owned(),
                                     serde_json::Value::String(user_uuid.to_string()),
                                 );
+                                if let Some(cache) = analysis_cache {
+                                    map.insert("analysis_cache".to_owned(), cache);
+                                }
                                 map
                             }),
                         };
@@ -1915,6 +2101,7 @@ pub fn handle_predict_performance(
 /// - `provider` (optional): Activity provider (default: configured default)
 /// - `sleep_provider` (optional): Sleep/recovery provider for cross-provider analysis
 /// - `timeframe` (optional): "week", "month", etc.
+/// - `explain` (optional): Attach a computation trace to each insight (default: false)
 #[must_use]
 #[allow(clippy::too_many_lines)]
 pub fn handle_analyze_training_load(
@@ -1952,6 +2139,13 @@ pub fn handle_analyze_training_load(
             .get("sleep_provider")
             .and_then(|v| v.as_str());
 
+        // Attach computation traces to insights only on request
+        let explain = request
+            .parameters
+            .get("explain")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
         // Extract output format parameter: "json" (default) or "toon"
         let output_format = extract_output_format(&request);
 
@@ -2014,7 +2208,23 @@ pub fn handle_analyze_training_load(
                             );
                         }
 
-                        let mut analysis = analyze_detailed_training_load(&activities, timeframe);
+                        let (mut analysis, analysis_cache) = compute_cached_analysis(
+                            executor,
+                            &request,
+                            user_uuid,
+                            &provider_name,
+                            AnalysisInput {
+                                analysis: "training_load",
+                                algorithm_version: algorithm_versions::TRAINING_LOAD,
+                                activities: &activities,
+                                parameters: serde_json::json!({
+                                    "timeframe": timeframe,
+                                    "explain": explain,
+                                }),
+                            },
+                            || analyze_detailed_training_load(&activities, timeframe, explain),
+                        )
+                        .await;
 
                         // If sleep_provider is specified, fetch recovery context
                         let recovery_context = if let Some(sleep_provider_name) = sleep_provider {
@@ -2102,6 +2312,9 @@ pub fn handle_analyze_training_load(
                                         serde_json::Value::Bool(true),
                                     );
                                 }
+                                if let Some(cache) = analysis_cache {
+                                    map.insert("analysis_cache".to_owned(), cache);
+                                }
                                 map
                             }),
                         };
@@ -4482,7 +4695,11 @@ fn calculate_prediction_confidence(
 // ============================================================================
 
 /// Analyze training load with detailed TSS/CTL/ATL/TSB metrics
-fn analyze_detailed_training_load(activities: &[Activity], timeframe: &str) -> serde_json::Value {
+fn analyze_detailed_training_load(
+    activities: &[Activity],
+    timeframe: &str,
+    explain: bool,
+) -> serde_json::Value {
     use TrainingLoadCalculator;
 
     if activities.is_empty() {
@@ -4530,6 +4747,14 @@ fn analyze_detailed_training_load(activities: &[Activity], timeframe: &str) -> s
         "low"
     };
 
+    let overtraining_insight = TrainingLoadCalculator::overtraining_insight(
+        &training_load,
+        &IntelligenceConfig::global()
+            .sleep_recovery
+            .training_stress_balance,
+    )
+    .explained(explain);
+
     // Taper recommendations
     let taper_recommendation = if tsb > 10.0 {
         "Well tapered - ready for peak performance"
@@ -4569,6 +4794,7 @@ fn analyze_detailed_training_load(activities: &[Activity], timeframe: &str) -> s
         "periodization_suggestions": periodization_suggestions,
         "training_zones": classify_training_load(ctl),
         "recommendations": generate_load_recommendations(ctl, atl, tsb),
+        "insights": [overtraining_insight],
         "activities_analyzed": training_load.tss_history.len(),
         "interpretation": {
             "ctl": "Chronic Training Load - fitness level (42-day average TSS)",
@@ -98,6 +98,8 @@ pub async fn create_configured_provider_with_tenant(
         .create_provider(provider_name)
         .map_err(|e| format!("Failed to create {provider_name} provider: {e}"))?;
 
+    let tenant_scope = tenant_ctx.as_ref().map(|ctx| (ctx.tenant_id, ctx.user_id));
+
     // Resolve OAuth credentials using tenant priority chain when context is available:
     //   1. User-specific credentials (per-user OAuth app)
     //   2. Tenant-specific credentials (stored in database)
@@ -163,7 +165,13 @@ pub async fn create_configured_provider_with_tenant(
         .await
         .map_err(|e| format!("Failed to set {provider_name} provider credentials: {e}"))?;
 
-    Ok(provider)
+    // Count the provider's calls against the tenant's concurrent request limit
+    Ok(match tenant_scope {
+        Some((tenant_id, user_id)) => {
+            Box::new(provider_registry.limit_for_tenant(provider, tenant_id, user_id))
+        }
+        None => provider,
+    })
 }
 
 /// Create a no-token response for a specific provider
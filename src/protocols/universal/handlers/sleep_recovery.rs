@@ -151,7 +151,8 @@ pub async fn fetch_provider_sleep_data(
 }
 
 /// Convert a provider `SleepSession` to the intelligence layer `SleepData` format
-fn convert_sleep_session_to_data(session: &SleepSession) -> SleepData {
+#[must_use]
+pub fn convert_sleep_session_to_data(session: &SleepSession) -> SleepData {
     // Calculate stage durations from sleep stages
     let mut deep_minutes: u32 = 0;
     let mut rem_minutes: u32 = 0;
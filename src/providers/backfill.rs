@@ -0,0 +1,217 @@
+// ABOUTME: Bounded activity backfill that stops paging at the tenant's max_backfill_days window
+// ABOUTME: Persists a resume marker when the window cuts a backfill short and warns the user via OAuth notifications
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Bounded Activity Backfill
+//!
+//! A newly connected account can have years of history, and paging through all of
+//! it costs one provider request per page. [`backfill_activities`] pages newest
+//! first and stops at the tenant's `max_backfill_days` window (default 365 days,
+//! matching `MAX_TIMEFRAME_DAYS`). When the window cuts paging short it stores a
+//! [`BackfillMarker`] so a later run, e.g. after the window is raised, continues
+//! from where this one stopped instead of starting over, and it sends the user a
+//! warning through the OAuth notification channel. Fetched activities are then
+//! credited to the user's goals (see [`crate::services::goal_progress`]).
+
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::mcp::resources::ServerResources;
+#[cfg(feature = "transport-sse")]
+use crate::models::OAuthNotification;
+use crate::models::{Activity, TenantId};
+use crate::pagination::Cursor;
+use crate::providers::activity_iterator::{
+    create_windowed_activity_stream, ActivityWindow, StreamConfig,
+};
+use crate::providers::core::FitnessProvider;
+use crate::services::goal_progress::update_goals_from_activities;
+
+/// Where a truncated backfill stopped for a user's provider connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillMarker {
+    /// User whose activities were being backfilled
+    pub user_id: Uuid,
+    /// Tenant the connection belongs to
+    pub tenant_id: TenantId,
+    /// Provider name
+    pub provider: String,
+    /// Cursor the next run resumes paging from
+    pub resume_cursor: Cursor,
+    /// Backfill window in effect when paging stopped
+    pub window_days: u32,
+    /// Activities fetched by the run that stopped
+    pub activities_fetched: u32,
+    /// When paging stopped
+    pub stopped_at: DateTime<Utc>,
+}
+
+/// Outcome of a backfill run
+#[derive(Debug, Clone)]
+pub struct BackfillResult {
+    /// Activities fetched within the window, newest first
+    pub activities: Vec<Activity>,
+    /// Backfill window applied to this run
+    pub window_days: u32,
+    /// Marker stored when the window cut paging short
+    pub marker: Option<BackfillMarker>,
+}
+
+impl BackfillResult {
+    /// Whether older activities were left unfetched because of the window
+    #[must_use]
+    pub const fn truncated(&self) -> bool {
+        self.marker.is_some()
+    }
+}
+
+/// Backfill a user's activities from a provider within the tenant's window
+///
+/// Resumes from a marker left by an earlier truncated run if there is one. A
+/// truncated run replaces the marker and warns the user unless it stopped at the
+/// same place as the previous run; a run that reaches the end of the provider's
+/// history clears the marker.
+///
+/// # Errors
+///
+/// Returns an error if the tenant's window or marker cannot be read or stored,
+/// or if the provider fails while paging
+pub async fn backfill_activities(
+    resources: &ServerResources,
+    provider: &dyn FitnessProvider,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    page_size: usize,
+) -> AppResult<BackfillResult> {
+    let database = &resources.database;
+    let provider_name = provider.name();
+    let window_days = database.get_tenant_max_backfill_days(tenant_id).await?;
+    let previous_cursor = database
+        .get_backfill_marker(user_id, tenant_id, provider_name)
+        .await?
+        .map(|marker| marker.resume_cursor);
+
+    let window = ActivityWindow::last_days(window_days).resuming_from(previous_cursor.clone());
+    let stop = window.stop.clone();
+
+    let config = StreamConfig::for_provider(provider_name).with_requested_page_size(page_size);
+    let activities: Vec<Activity> = create_windowed_activity_stream(provider, config, window)
+        .try_collect()
+        .await
+        .map_err(|e| AppError::external_service(provider_name, e.to_string()))?;
+
+    update_goal_progress(resources, user_id, tenant_id, provider_name, &activities).await;
+
+    let Some(resume_cursor) = stop.resume_cursor() else {
+        database
+            .clear_backfill_marker(user_id, tenant_id, provider_name)
+            .await?;
+        debug!(
+            %user_id,
+            provider = provider_name,
+            fetched = activities.len(),
+            "Activity backfill reached the end of provider history"
+        );
+        return Ok(BackfillResult {
+            activities,
+            window_days,
+            marker: None,
+        });
+    };
+
+    let marker = BackfillMarker {
+        user_id,
+        tenant_id,
+        provider: provider_name.to_owned(),
+        resume_cursor,
+        window_days,
+        activities_fetched: u32::try_from(activities.len()).unwrap_or(u32::MAX),
+        stopped_at: Utc::now(),
+    };
+    database.save_backfill_marker(&marker).await?;
+    info!(
+        %user_id,
+        provider = provider_name,
+        window_days,
+        fetched = activities.len(),
+        "Activity backfill stopped at the tenant backfill window"
+    );
+    // A rerun that stops at the same place has already warned the user
+    if previous_cursor.as_ref() != Some(&marker.resume_cursor) {
+        notify_truncated(resources, &marker).await;
+    }
+
+    Ok(BackfillResult {
+        activities,
+        window_days,
+        marker: Some(marker),
+    })
+}
+
+/// Credit fetched activities to the user's goals without failing the backfill
+async fn update_goal_progress(
+    resources: &ServerResources,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    provider_name: &str,
+    activities: &[Activity],
+) {
+    if let Err(e) =
+        update_goals_from_activities(resources, user_id, tenant_id, provider_name, activities).await
+    {
+        warn!(%user_id, provider = provider_name, error = %e, "Failed to update goal progress");
+    }
+}
+
+/// Warn the user through the OAuth notification channel that history was cut off
+async fn notify_truncated(resources: &ServerResources, marker: &BackfillMarker) {
+    let message = format!(
+        "Activity import from {} stopped at the {}-day backfill limit; older activities were not imported",
+        marker.provider, marker.window_days
+    );
+
+    let notification_id = match resources
+        .database
+        .store_oauth_notification(marker.user_id, &marker.provider, true, &message, None)
+        .await
+    {
+        Ok(id) => id,
+        Err(e) => {
+            warn!(user_id = %marker.user_id, error = %e, "Failed to store backfill warning");
+            return;
+        }
+    };
+    debug!(
+        user_id = %marker.user_id,
+        notification_id = %notification_id,
+        "Stored backfill warning notification"
+    );
+
+    #[cfg(feature = "transport-sse")]
+    {
+        let notification = OAuthNotification {
+            id: notification_id,
+            user_id: marker.user_id.to_string(),
+            provider: marker.provider.clone(),
+            success: true,
+            message,
+            expires_at: None,
+            created_at: Utc::now(),
+            read_at: None,
+        };
+        // Users without an open notification stream see the stored notification later
+        if let Err(e) = resources
+            .sse_manager
+            .send_notification(marker.user_id, &notification)
+            .await
+        {
+            debug!(user_id = %marker.user_id, error = %e, "Backfill warning not pushed over SSE");
+        }
+    }
+}
@@ -69,13 +69,15 @@ use crate::cache::memory::InMemoryCache;
 use crate::cache::{CacheConfig, CacheKey, CacheProvider, CacheResource, CacheTtlConfig};
 use crate::errors::AppResult;
 use crate::models::{
-    Activity, Athlete, HealthMetrics, PersonalRecord, RecoveryMetrics, SleepSession, Stats,
+    Activity, ActivitySplit, Athlete, AthleteZones, Gear, HealthMetrics, PersonalRecord,
+    RecoveryMetrics, Segment, SegmentEffort, SleepSession, SplitUnit, Stats, TimeSeries,
 };
 use crate::pagination::{CursorPage, PaginationParams};
 use crate::providers::core::{
     ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig,
 };
-use crate::providers::errors::ProviderError;
+use crate::providers::errors::{ProviderError, ProviderResult};
+use crate::providers::rate_limit_budget::ProviderBudget;
 
 /// Cache policy for controlling caching behavior per-request
 ///
@@ -397,8 +399,8 @@ impl<C: CacheProvider> CachingFitnessProvider<C> {
         let key = self.cache_key(CacheResource::ActivityList {
             page: u32::try_from(page).unwrap_or(1),
             per_page: u32::try_from(per_page).unwrap_or(50),
-            before: params.before,
-            after: params.after,
+            before: params.before_timestamp(),
+            after: params.after_timestamp(),
             sport_type: None,
         });
 
@@ -507,6 +509,10 @@ impl<C: CacheProvider + 'static> FitnessProvider for CachingFitnessProvider<C> {
         self.inner.config()
     }
 
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.inner.rate_limit_budget()
+    }
+
     async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
         self.inner.set_credentials(credentials).await
     }
@@ -589,6 +595,40 @@ impl<C: CacheProvider + 'static> FitnessProvider for CachingFitnessProvider<C> {
         self.inner.get_health_metrics(start_date, end_date).await
     }
 
+    async fn list_gear(&self) -> ProviderResult<Vec<Gear>> {
+        // Gear distance changes with every activity; pass through without caching.
+        self.inner.list_gear().await
+    }
+
+    async fn list_segment_efforts(&self, activity_id: &str) -> ProviderResult<Vec<SegmentEffort>> {
+        // PR ranks change whenever the athlete rides the segment again; pass through.
+        self.inner.list_segment_efforts(activity_id).await
+    }
+
+    async fn get_segment(&self, segment_id: &str) -> ProviderResult<Segment> {
+        // Course records and the athlete's best change over time; pass through.
+        self.inner.get_segment(segment_id).await
+    }
+
+    async fn get_activity_hr_series(&self, activity_id: &str) -> ProviderResult<TimeSeries<u16>> {
+        // Fetched only when analyzing a single activity; pass through.
+        self.inner.get_activity_hr_series(activity_id).await
+    }
+
+    async fn get_athlete_zones(&self) -> ProviderResult<AthleteZones> {
+        // Zones change rarely but are fetched once per analysis; pass through.
+        self.inner.get_athlete_zones().await
+    }
+
+    async fn get_activity_splits(
+        &self,
+        activity_id: &str,
+        unit: SplitUnit,
+    ) -> ProviderResult<Vec<ActivitySplit>> {
+        // Fetched only when breaking down a single activity; pass through.
+        self.inner.get_activity_splits(activity_id, unit).await
+    }
+
     async fn disconnect(&self) -> AppResult<()> {
         // Invalidate user cache on disconnect
         if let Err(e) = self.invalidate_user_cache().await {
@@ -0,0 +1,111 @@
+// ABOUTME: Database-backed storage for resumable Garmin sessions in the user OAuth token table
+// ABOUTME: Keeps the OAuth2 bearer as the access token and the OAuth1 token plus login time as the refresh token
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use pierre_core::models::TenantId;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::garmin_session::{
+    GarminOAuth1Token, GarminOAuth2Token, GarminSession, GarminSessionStore,
+};
+use crate::constants::oauth_providers;
+use crate::database_plugins::{factory::Database, DatabaseProvider};
+use crate::errors::{AppError, AppResult};
+use crate::models::UserOAuthToken;
+
+/// `token_type` marking a `user_oauth_tokens` row written by this store
+pub const GARMIN_SESSION_TOKEN_TYPE: &str = "garmin_session";
+
+/// Session fields stored in the encrypted refresh token column
+#[derive(Serialize, Deserialize)]
+struct StoredLogin {
+    oauth1: Option<GarminOAuth1Token>,
+    last_login_at: DateTime<Utc>,
+}
+
+/// Garmin session store for one user in one tenant
+///
+/// The database encrypts both token columns, so OAuth1 secrets are never stored
+/// in plaintext. Rows written by the regular OAuth connect flow are not sessions
+/// and are reported as missing.
+pub struct DatabaseGarminSessionStore {
+    database: Arc<Database>,
+    user_id: Uuid,
+    tenant_id: TenantId,
+}
+
+impl DatabaseGarminSessionStore {
+    /// Create a store for a user's session in a tenant
+    #[must_use]
+    pub const fn new(database: Arc<Database>, user_id: Uuid, tenant_id: TenantId) -> Self {
+        Self {
+            database,
+            user_id,
+            tenant_id,
+        }
+    }
+}
+
+#[async_trait]
+impl GarminSessionStore for DatabaseGarminSessionStore {
+    async fn load_session(&self) -> AppResult<Option<GarminSession>> {
+        let Some(token) = self
+            .database
+            .get_user_oauth_token(self.user_id, self.tenant_id, oauth_providers::GARMIN)
+            .await?
+            .filter(|token| token.token_type == GARMIN_SESSION_TOKEN_TYPE)
+        else {
+            return Ok(None);
+        };
+
+        let stored: StoredLogin = token
+            .refresh_token
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| AppError::internal(format!("Stored Garmin session is invalid: {e}")))?
+            .ok_or_else(|| AppError::internal("Stored Garmin session has no login record"))?;
+        let oauth2 = token
+            .expires_at
+            .filter(|_| !token.access_token.is_empty())
+            .map(|expires_at| GarminOAuth2Token {
+                access_token: token.access_token,
+                expires_at,
+            });
+
+        Ok(Some(GarminSession {
+            oauth1: stored.oauth1,
+            oauth2,
+            last_login_at: stored.last_login_at,
+        }))
+    }
+
+    async fn save_session(&self, session: &GarminSession) -> AppResult<()> {
+        let stored = serde_json::to_string(&StoredLogin {
+            oauth1: session.oauth1.clone(),
+            last_login_at: session.last_login_at,
+        })?;
+        let mut token = UserOAuthToken::new(
+            self.user_id,
+            self.tenant_id.to_string(),
+            oauth_providers::GARMIN.to_owned(),
+            session
+                .oauth2
+                .as_ref()
+                .map(|bearer| bearer.access_token.clone())
+                .unwrap_or_default(),
+            Some(stored),
+            session.oauth2.as_ref().map(|bearer| bearer.expires_at),
+            None,
+        );
+        GARMIN_SESSION_TOKEN_TYPE.clone_into(&mut token.token_type);
+        self.database.upsert_user_oauth_token(&token).await
+    }
+}
@@ -45,6 +45,8 @@ pub use pierre_providers::coros_provider;
 pub use pierre_providers::fitbit_provider;
 #[cfg(feature = "provider-garmin")]
 pub use pierre_providers::garmin_provider;
+#[cfg(feature = "provider-garmin")]
+pub use pierre_providers::garmin_session;
 #[cfg(feature = "provider-strava")]
 pub use pierre_providers::strava_provider;
 #[cfg(feature = "provider-terra")]
@@ -52,19 +54,30 @@ pub use pierre_providers::terra;
 #[cfg(feature = "provider-whoop")]
 pub use pierre_providers::whoop_provider;
 pub use pierre_providers::*;
-pub use pierre_providers::{activity_iterator, circuit_breaker, core, http_client, spi, utils};
+pub use pierre_providers::{
+    activity_iterator, circuit_breaker, core, http_client, rate_limit_budget, retry_budget, spi,
+    tenant_concurrency, utils,
+};
 
 // Local modules that remain in the main crate (database/cache/config dependencies)
 
+/// Bounded activity backfill with per-tenant window and resume markers
+pub mod backfill;
 /// Caching decorator for transparent API response caching
 pub mod caching_provider;
 /// Provider error types and result aliases
 pub mod errors;
+/// Database-backed storage for resumable Garmin sessions
+#[cfg(feature = "provider-garmin")]
+pub mod garmin_session_store;
 /// Global provider registry and factory
 pub mod registry;
 /// Synthetic provider for development and testing
 #[cfg(feature = "provider-synthetic")]
 pub mod synthetic_provider;
+/// Seedable synthetic sleep and HRV generator for recovery testing
+#[cfg(feature = "provider-synthetic")]
+pub mod synthetic_sleep_provider;
 
 // Re-export caching provider types
 pub use caching_provider::{
@@ -80,3 +93,5 @@ pub use registry::{
 };
 #[cfg(feature = "provider-synthetic")]
 pub use synthetic_provider::{get_synthetic_database_pool, set_synthetic_database_pool};
+#[cfg(feature = "provider-synthetic")]
+pub use synthetic_sleep_provider::{SleepAnomaly, SyntheticSleepConfig, SyntheticSleepProvider};
@@ -5,8 +5,14 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use super::caching_provider::CachingFitnessProvider;
+#[cfg(feature = "provider-synthetic")]
+use super::core::ProviderValidationMode;
 use super::core::{FitnessProvider, ProviderConfig, ProviderFactory, TenantProvider};
-use super::spi::{ProviderBundle, ProviderCapabilities, ProviderDescriptor};
+use super::file_provider::{self, FileProviderFactory};
+use super::rate_limit_budget::{ProviderBudgetConfig, ProviderBudgetTracker};
+use super::retry_budget::{RetryBudget, RetryBudgetConfig};
+use super::spi::{FileDescriptor, ProviderBundle, ProviderCapabilities, ProviderDescriptor};
+use super::tenant_concurrency::{TenantConcurrencyConfig, TenantConcurrencyLimiter};
 use crate::cache::memory::InMemoryCache;
 use crate::cache::{CacheConfig, CacheTtlConfig};
 use crate::config::admin::service::AdminConfigService;
@@ -17,16 +23,9 @@ use crate::config::admin::service::AdminConfigService;
     feature = "provider-whoop",
     feature = "provider-coros"
 ))]
-use crate::config::environment::load_provider_env_config;
-#[cfg(any(
-    feature = "provider-strava",
-    feature = "provider-garmin",
-    feature = "provider-fitbit",
-    feature = "provider-terra",
-    feature = "provider-whoop",
-    feature = "provider-coros",
-    feature = "provider-synthetic"
-))]
+use crate::config::environment::{
+    load_provider_env_config, load_provider_request_timeout, load_provider_validation_mode,
+};
 use crate::constants::oauth_providers;
 use crate::errors::{AppError, AppResult};
 use pierre_core::models::TenantId;
@@ -88,19 +87,30 @@ pub struct ProviderRegistry {
     factories: HashMap<&'static str, Box<dyn ProviderFactory>>,
     default_configs: HashMap<&'static str, ProviderConfig>,
     descriptors: HashMap<&'static str, Box<dyn ProviderDescriptor>>,
+    concurrency_limiter: Arc<TenantConcurrencyLimiter>,
+    budget_tracker: Arc<ProviderBudgetTracker>,
+    retry_budget: Arc<RetryBudget>,
 }
 
 impl ProviderRegistry {
     /// Create a new provider registry with default providers
     ///
     /// Providers are configured from environment variables with fallback to hardcoded defaults.
-    /// See `load_provider_env_config()` for environment variable format.
+    /// See `load_provider_env_config()` for environment variable format. Tenant
+    /// concurrency limits come from `TenantConcurrencyConfig::from_env()`,
+    /// provider budget thresholds from `ProviderBudgetConfig::from_env()`, and
+    /// the retry budget from `RetryBudgetConfig::from_env()`.
     #[must_use]
     pub fn new() -> Self {
         let mut registry = Self {
             factories: HashMap::new(),
             default_configs: HashMap::new(),
             descriptors: HashMap::new(),
+            concurrency_limiter: Arc::new(TenantConcurrencyLimiter::new(
+                TenantConcurrencyConfig::from_env(),
+            )),
+            budget_tracker: Arc::new(ProviderBudgetTracker::new(ProviderBudgetConfig::from_env())),
+            retry_budget: Arc::new(RetryBudget::new(RetryBudgetConfig::from_env())),
         };
 
         // Register all enabled providers
@@ -111,6 +121,7 @@ impl ProviderRegistry {
         Self::register_whoop(&mut registry);
         Self::register_coros(&mut registry);
         Self::register_synthetic(&mut registry);
+        Self::register_file(&mut registry);
 
         // Log registered providers at startup
         let providers = registry.supported_providers().join(", ");
@@ -146,6 +157,8 @@ impl ProviderRegistry {
                 api_base_url,
                 revoke_url,
                 default_scopes: scopes,
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::STRAVA),
+                validation_mode: load_provider_validation_mode(oauth_providers::STRAVA),
             },
         );
     }
@@ -176,6 +189,8 @@ impl ProviderRegistry {
                 api_base_url,
                 revoke_url,
                 default_scopes: scopes,
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::GARMIN),
+                validation_mode: load_provider_validation_mode(oauth_providers::GARMIN),
             },
         );
     }
@@ -209,6 +224,8 @@ impl ProviderRegistry {
                 api_base_url,
                 revoke_url,
                 default_scopes: scopes,
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::FITBIT),
+                validation_mode: load_provider_validation_mode(oauth_providers::FITBIT),
             },
         );
     }
@@ -237,6 +254,8 @@ impl ProviderRegistry {
                     .split(',')
                     .map(str::to_owned)
                     .collect(),
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::TERRA),
+                validation_mode: load_provider_validation_mode(oauth_providers::TERRA),
             },
         );
     }
@@ -270,6 +289,8 @@ impl ProviderRegistry {
                 api_base_url,
                 revoke_url,
                 default_scopes: scopes,
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::WHOOP),
+                validation_mode: load_provider_validation_mode(oauth_providers::WHOOP),
             },
         );
     }
@@ -307,6 +328,8 @@ impl ProviderRegistry {
                 api_base_url,
                 revoke_url,
                 default_scopes: scopes,
+                request_timeout_secs: load_provider_request_timeout(oauth_providers::COROS),
+                validation_mode: load_provider_validation_mode(oauth_providers::COROS),
             },
         );
     }
@@ -332,6 +355,8 @@ impl ProviderRegistry {
                 api_base_url: "http://localhost/synthetic/api".to_owned(),
                 revoke_url: None,
                 default_scopes: vec!["activity:read_all".to_owned()],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
             },
         );
 
@@ -353,6 +378,8 @@ impl ProviderRegistry {
                 api_base_url: "http://localhost/synthetic_sleep/api".to_owned(),
                 revoke_url: None,
                 default_scopes: vec!["sleep:read".to_owned()],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
             },
         );
     }
@@ -360,6 +387,68 @@ impl ProviderRegistry {
     #[cfg(not(feature = "provider-synthetic"))]
     fn register_synthetic(_registry: &mut Self) {}
 
+    /// Register the file import pseudo-provider (always available, no OAuth)
+    fn register_file(registry: &mut Self) {
+        registry.register_factory(oauth_providers::FILE, Box::new(FileProviderFactory));
+        registry.register_descriptor(oauth_providers::FILE, Box::new(FileDescriptor));
+        registry.set_default_config(oauth_providers::FILE, file_provider::default_config());
+    }
+
+    /// Replace the limiter capping concurrent provider requests per tenant
+    #[must_use]
+    pub fn with_concurrency_limiter(mut self, limiter: Arc<TenantConcurrencyLimiter>) -> Self {
+        self.concurrency_limiter = limiter;
+        self
+    }
+
+    /// Limiter shared by every tenant provider this registry creates
+    #[must_use]
+    pub fn concurrency_limiter(&self) -> Arc<TenantConcurrencyLimiter> {
+        Arc::clone(&self.concurrency_limiter)
+    }
+
+    /// Replace the tracker of provider-advertised rate-limit budgets
+    #[must_use]
+    pub fn with_budget_tracker(mut self, tracker: Arc<ProviderBudgetTracker>) -> Self {
+        self.budget_tracker = tracker;
+        self
+    }
+
+    /// Budget tracker shared by every tenant provider this registry creates
+    #[must_use]
+    pub fn budget_tracker(&self) -> Arc<ProviderBudgetTracker> {
+        Arc::clone(&self.budget_tracker)
+    }
+
+    /// Replace the retry budget shared by provider retry loops
+    #[must_use]
+    pub fn with_retry_budget(mut self, budget: Arc<RetryBudget>) -> Self {
+        self.retry_budget = budget;
+        self
+    }
+
+    /// Retry budget that `with_budgeted_retry` callers draw from per tenant and provider
+    #[must_use]
+    pub fn retry_budget(&self) -> Arc<RetryBudget> {
+        Arc::clone(&self.retry_budget)
+    }
+
+    /// Wrap an already configured provider so its calls count against the tenant's limits
+    ///
+    /// Calls take a slot from the tenant's concurrency limit and are held back
+    /// while the tenant's remaining budget with the provider is nearly spent.
+    #[must_use]
+    pub fn limit_for_tenant(
+        &self,
+        provider: Box<dyn FitnessProvider>,
+        tenant_id: TenantId,
+        user_id: Uuid,
+    ) -> TenantProvider {
+        TenantProvider::new(provider, tenant_id, user_id)
+            .with_concurrency_limiter(self.concurrency_limiter())
+            .with_budget_tracker(self.budget_tracker())
+    }
+
     /// Register a provider factory
     pub fn register_factory(
         &mut self,
@@ -450,6 +539,20 @@ impl ProviderRegistry {
             .map(|d| d.capabilities())
     }
 
+    /// Get the union of capabilities across several providers
+    ///
+    /// Unknown provider names contribute nothing.
+    #[must_use]
+    pub fn combined_capabilities<'a>(
+        &self,
+        provider_names: impl IntoIterator<Item = &'a str>,
+    ) -> ProviderCapabilities {
+        provider_names
+            .into_iter()
+            .filter_map(|name| self.get_capabilities(name))
+            .fold(ProviderCapabilities::empty(), ProviderCapabilities::union)
+    }
+
     /// Get provider display name
     #[must_use]
     pub fn get_display_name(&self, provider_name: &str) -> Option<&'static str> {
@@ -536,7 +639,7 @@ impl ProviderRegistry {
         user_id: Uuid,
     ) -> AppResult<TenantProvider> {
         let provider = self.create_provider(provider_name)?;
-        Ok(TenantProvider::new(provider, tenant_id, user_id))
+        Ok(self.limit_for_tenant(provider, tenant_id, user_id))
     }
 
     /// Create a tenant-aware provider with custom configuration
@@ -552,7 +655,7 @@ impl ProviderRegistry {
         user_id: Uuid,
     ) -> AppResult<TenantProvider> {
         let provider = self.create_provider_with_config(provider_name, config)?;
-        Ok(TenantProvider::new(provider, tenant_id, user_id))
+        Ok(self.limit_for_tenant(provider, tenant_id, user_id))
     }
 
     /// Create a caching provider with default configuration
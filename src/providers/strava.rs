@@ -435,10 +435,10 @@ impl FitnessProvider for StravaProvider {
         ];
 
         // Add timestamp filters if provided (Strava native pagination)
-        if let Some(before) = params.before {
+        if let Some(before) = params.before_timestamp() {
             query.push(("before", before.to_string()));
         }
-        if let Some(after) = params.after {
+        if let Some(after) = params.after_timestamp() {
             query.push(("after", after.to_string()));
         }
 
@@ -37,7 +37,7 @@ use crate::models::{
 };
 use crate::pagination::{Cursor, CursorPage, PaginationParams};
 use crate::providers::core::{
-    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig,
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
 };
 use crate::providers::errors::ProviderError;
 use async_trait::async_trait;
@@ -200,6 +200,8 @@ impl SyntheticProvider {
                 api_base_url: format!("http://localhost/{name}/api"),
                 revoke_url: None,
                 default_scopes: vec!["activity:read_all".to_owned(), "sleep:read".to_owned()],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
             },
             provider_name: name,
             user_id: None,
@@ -802,13 +804,14 @@ impl SyntheticProvider {
 
         for activity in &activities_snapshot {
             // Fastest pace (lowest seconds per meter)
-            if let (Some(distance), true) =
-                (activity.distance_meters(), activity.duration_seconds() > 0)
-            {
+            if let (Some(distance), true) = (
+                activity.distance_meters(),
+                activity.moving_time_seconds() > 0,
+            ) {
                 if distance > 0.0 {
-                    // Duration in seconds, precision loss acceptable for pace calculation
+                    // Moving time in seconds, precision loss acceptable for pace calculation
                     #[allow(clippy::cast_precision_loss)]
-                    let pace_sec_per_meter = activity.duration_seconds() as f64 / distance;
+                    let pace_sec_per_meter = activity.moving_time_seconds() as f64 / distance;
 
                     let entry =
                         records
@@ -818,6 +821,7 @@ impl SyntheticProvider {
                                 metric: PrMetric::FastestPace,
                                 value: pace_sec_per_meter,
                                 date: activity.start_date(),
+                                segment_id: None,
                             });
 
                     if pace_sec_per_meter < entry.value {
@@ -826,6 +830,7 @@ impl SyntheticProvider {
                             metric: PrMetric::FastestPace,
                             value: pace_sec_per_meter,
                             date: activity.start_date(),
+                            segment_id: None,
                         };
                     }
                 }
@@ -841,6 +846,7 @@ impl SyntheticProvider {
                             metric: PrMetric::LongestDistance,
                             value: distance,
                             date: activity.start_date(),
+                            segment_id: None,
                         });
 
                 if distance > entry.value {
@@ -849,6 +855,7 @@ impl SyntheticProvider {
                         metric: PrMetric::LongestDistance,
                         value: distance,
                         date: activity.start_date(),
+                        segment_id: None,
                     };
                 }
             }
@@ -862,6 +869,7 @@ impl SyntheticProvider {
                         metric: PrMetric::HighestElevation,
                         value: elevation,
                         date: activity.start_date(),
+                        segment_id: None,
                     });
 
                 if elevation > entry.value {
@@ -870,6 +878,7 @@ impl SyntheticProvider {
                         metric: PrMetric::HighestElevation,
                         value: elevation,
                         date: activity.start_date(),
+                        segment_id: None,
                     };
                 }
             }
@@ -888,6 +897,7 @@ impl SyntheticProvider {
                             metric: PrMetric::FastestTime,
                             value: duration,
                             date: activity.start_date(),
+                            segment_id: None,
                         });
 
                 if duration < entry.value {
@@ -896,6 +906,7 @@ impl SyntheticProvider {
                         metric: PrMetric::FastestTime,
                         value: duration,
                         date: activity.start_date(),
+                        segment_id: None,
                     };
                 }
             }
@@ -942,10 +953,10 @@ impl SyntheticProvider {
         // Build and execute query
         let mut sql_query = sqlx::query(&query).bind(&user_id_str);
 
-        if let Some(after_ts) = params.after {
+        if let Some(after_ts) = params.after_timestamp() {
             sql_query = sql_query.bind(after_ts);
         }
-        if let Some(before_ts) = params.before {
+        if let Some(before_ts) = params.before_timestamp() {
             sql_query = sql_query.bind(before_ts);
         }
 
@@ -1146,16 +1157,7 @@ impl FitnessProvider for SyntheticProvider {
         sorted.sort_by_key(|b| Reverse(b.start_date()));
 
         // Apply time filtering if before/after specified
-        if let Some(after_ts) = params.after {
-            if let Some(after_dt) = chrono::DateTime::from_timestamp(after_ts, 0) {
-                sorted.retain(|a| a.start_date() >= after_dt);
-            }
-        }
-        if let Some(before_ts) = params.before {
-            if let Some(before_dt) = chrono::DateTime::from_timestamp(before_ts, 0) {
-                sorted.retain(|a| a.start_date() < before_dt);
-            }
-        }
+        params.retain_in_range(&mut sorted);
 
         Ok(sorted.into_iter().skip(offset).take(limit).collect())
     }
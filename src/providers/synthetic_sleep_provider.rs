@@ -0,0 +1,493 @@
+// ABOUTME: Seedable synthetic sleep and HRV data generator for recovery scoring tests
+// ABOUTME: Produces multi-night sleep stages, RMSSD trends, and injectable bad-night anomalies
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Synthetic Sleep Provider
+//!
+//! Generates realistic multi-night sleep data for exercising `SleepAnalyzer` and
+//! `RecoveryCalculator` end to end without mocking provider HTTP APIs.
+//!
+//! Each night is built from four sleep cycles whose deep, REM, and light totals
+//! follow the configured proportions (deep front-loaded, REM back-loaded, as in
+//! real sleep architecture). Overnight RMSSD starts at a baseline and drifts by a
+//! fixed amount per night with seeded noise, so improving, stable, and declining
+//! HRV trends can all be produced deterministically. Individual nights can be
+//! turned into "bad nights" with shorter sleep, less deep/REM sleep, and
+//! suppressed HRV.
+
+use crate::constants::oauth_providers;
+use crate::errors::AppResult;
+use crate::models::{
+    Activity, Athlete, PersonalRecord, SleepSession, SleepStage, SleepStageType, Stats,
+};
+use crate::pagination::{CursorPage, PaginationParams};
+use crate::providers::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
+use crate::providers::errors::ProviderError;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use tracing::instrument;
+
+/// Relative share of each cycle's deep sleep (front-loaded)
+const DEEP_CYCLE_WEIGHTS: [u32; 4] = [40, 30, 20, 10];
+/// Relative share of each cycle's REM sleep (back-loaded)
+const REM_CYCLE_WEIGHTS: [u32; 4] = [10, 20, 30, 40];
+/// Relative share of each cycle's light sleep
+const LIGHT_CYCLE_WEIGHTS: [u32; 4] = [25, 25, 25, 25];
+
+/// A night whose sleep and HRV are deliberately degraded
+#[derive(Debug, Clone, PartialEq)]
+pub struct SleepAnomaly {
+    /// Night index, 0 being the oldest generated night
+    pub night: u32,
+    /// Minutes of sleep lost compared with a normal night
+    pub sleep_loss_minutes: u32,
+    /// Percentage by which RMSSD is suppressed (0-100)
+    pub hrv_drop_percent: f64,
+    /// Multiplier applied to the deep and REM proportions (0.0-1.0)
+    pub restorative_sleep_factor: f64,
+    /// Additional awakenings during the night
+    pub extra_wake_count: u32,
+}
+
+impl SleepAnomaly {
+    /// Typical bad night: two hours short, 30% lower HRV, and half the deep/REM sleep
+    #[must_use]
+    pub const fn bad_night(night: u32) -> Self {
+        Self {
+            night,
+            sleep_loss_minutes: 120,
+            hrv_drop_percent: 30.0,
+            restorative_sleep_factor: 0.5,
+            extra_wake_count: 4,
+        }
+    }
+}
+
+/// Configuration for generated sleep data
+#[derive(Debug, Clone)]
+pub struct SyntheticSleepConfig {
+    /// Seed for the random number generator (same seed, same nights)
+    pub seed: u64,
+    /// Number of nights to generate
+    pub nights: u32,
+    /// Wake time of the most recent night
+    pub last_wake_time: DateTime<Utc>,
+    /// Total sleep time on a normal night (minutes)
+    pub total_sleep_minutes: u32,
+    /// Random variation applied to total sleep time (± minutes)
+    pub sleep_jitter_minutes: u32,
+    /// Fraction of total sleep spent in deep sleep
+    pub deep_fraction: f64,
+    /// Fraction of total sleep spent in REM sleep (light sleep is the remainder)
+    pub rem_fraction: f64,
+    /// Minutes awake after sleep onset on a normal night
+    pub awake_minutes: u32,
+    /// Overnight RMSSD on the oldest night (milliseconds)
+    pub rmssd_baseline_ms: f64,
+    /// Change in RMSSD from one night to the next (negative for a declining trend)
+    pub rmssd_nightly_change_ms: f64,
+    /// Random variation applied to RMSSD (± milliseconds)
+    pub rmssd_noise_ms: f64,
+    /// Nights to degrade
+    pub anomalies: Vec<SleepAnomaly>,
+}
+
+impl Default for SyntheticSleepConfig {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            nights: 7,
+            last_wake_time: Utc::now(),
+            total_sleep_minutes: 450,
+            sleep_jitter_minutes: 15,
+            deep_fraction: 0.20,
+            rem_fraction: 0.25,
+            awake_minutes: 20,
+            rmssd_baseline_ms: 55.0,
+            rmssd_nightly_change_ms: 0.0,
+            rmssd_noise_ms: 2.0,
+            anomalies: Vec::new(),
+        }
+    }
+}
+
+impl SyntheticSleepConfig {
+    fn validate(&self) -> Result<(), ProviderError> {
+        let fractions_valid = (0.0..=1.0).contains(&self.deep_fraction)
+            && (0.0..=1.0).contains(&self.rem_fraction)
+            && self.deep_fraction + self.rem_fraction <= 1.0;
+        if !fractions_valid {
+            return Err(Self::invalid(
+                "deep_fraction and rem_fraction must be between 0 and 1 and sum to at most 1",
+            ));
+        }
+        if self.rmssd_baseline_ms <= 0.0 || self.rmssd_noise_ms < 0.0 {
+            return Err(Self::invalid(
+                "rmssd_baseline_ms must be positive and rmssd_noise_ms non-negative",
+            ));
+        }
+        if self.sleep_jitter_minutes >= self.total_sleep_minutes {
+            return Err(Self::invalid(
+                "sleep_jitter_minutes must be smaller than total_sleep_minutes",
+            ));
+        }
+        if let Some(anomaly) = self.anomalies.iter().find(|a| {
+            a.night >= self.nights
+                || !(0.0..=100.0).contains(&a.hrv_drop_percent)
+                || !(0.0..=1.0).contains(&a.restorative_sleep_factor)
+        }) {
+            return Err(Self::invalid(&format!(
+                "Invalid anomaly for night {} of {}",
+                anomaly.night, self.nights
+            )));
+        }
+        Ok(())
+    }
+
+    fn invalid(details: &str) -> ProviderError {
+        ProviderError::ConfigurationError {
+            provider: oauth_providers::SYNTHETIC_SLEEP.to_owned(),
+            details: details.to_owned(),
+        }
+    }
+}
+
+/// Synthetic provider serving generated multi-night sleep and HRV data
+///
+/// Only the sleep portions of `FitnessProvider` return data; activity queries
+/// return empty results.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pierre_mcp_server::providers::synthetic_sleep_provider::{
+///     SleepAnomaly, SyntheticSleepConfig, SyntheticSleepProvider,
+/// };
+///
+/// fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     // A week of steadily declining HRV with a bad night on day five
+///     let provider = SyntheticSleepProvider::new(&SyntheticSleepConfig {
+///         rmssd_nightly_change_ms: -3.0,
+///         anomalies: vec![SleepAnomaly::bad_night(4)],
+///         ..SyntheticSleepConfig::default()
+///     })?;
+///     assert_eq!(provider.sessions().len(), 7);
+///     Ok(())
+/// }
+/// ```
+pub struct SyntheticSleepProvider {
+    /// Generated sessions, oldest first
+    sessions: Vec<SleepSession>,
+    /// Provider configuration
+    config: ProviderConfig,
+}
+
+impl SyntheticSleepProvider {
+    /// Create a provider with nights generated from `sleep_config`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProviderError::ConfigurationError` if the stage proportions, RMSSD
+    /// values, or anomalies are out of range.
+    pub fn new(sleep_config: &SyntheticSleepConfig) -> Result<Self, ProviderError> {
+        let sessions = Self::generate_sessions(sleep_config)?;
+        let name = oauth_providers::SYNTHETIC_SLEEP;
+
+        Ok(Self {
+            sessions,
+            config: ProviderConfig {
+                name: name.to_owned(),
+                auth_url: format!("http://localhost/{name}/auth"),
+                token_url: format!("http://localhost/{name}/token"),
+                api_base_url: format!("http://localhost/{name}/api"),
+                revoke_url: None,
+                default_scopes: vec!["sleep:read".to_owned()],
+                request_timeout_secs: None,
+                validation_mode: ProviderValidationMode::Lenient,
+            },
+        })
+    }
+
+    /// Generated sleep sessions, oldest first
+    #[must_use]
+    pub fn sessions(&self) -> &[SleepSession] {
+        &self.sessions
+    }
+
+    /// Overnight RMSSD values (milliseconds), oldest first
+    #[must_use]
+    pub fn rmssd_values(&self) -> Vec<f64> {
+        self.sessions
+            .iter()
+            .filter_map(|s| s.hrv_during_sleep)
+            .collect()
+    }
+
+    /// Generate sleep sessions without constructing a provider
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProviderError::ConfigurationError` if the configuration is invalid.
+    pub fn generate_sessions(
+        sleep_config: &SyntheticSleepConfig,
+    ) -> Result<Vec<SleepSession>, ProviderError> {
+        sleep_config.validate()?;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(sleep_config.seed);
+        Ok((0..sleep_config.nights)
+            .map(|night| Self::generate_night(sleep_config, night, &mut rng))
+            .collect())
+    }
+
+    /// Generate one night, 0 being the oldest
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn generate_night(
+        sleep_config: &SyntheticSleepConfig,
+        night: u32,
+        rng: &mut ChaCha8Rng,
+    ) -> SleepSession {
+        let anomaly = sleep_config.anomalies.iter().find(|a| a.night == night);
+
+        let jitter = i64::from(sleep_config.sleep_jitter_minutes);
+        let jittered_minutes = i64::from(sleep_config.total_sleep_minutes)
+            + rng.gen_range(-jitter..=jitter)
+            - i64::from(anomaly.map_or(0, |a| a.sleep_loss_minutes));
+        let total_sleep_time = u32::try_from(jittered_minutes.max(60)).unwrap_or(60);
+
+        let restorative_factor = anomaly.map_or(1.0, |a| a.restorative_sleep_factor);
+        let deep_minutes =
+            (f64::from(total_sleep_time) * sleep_config.deep_fraction * restorative_factor).round()
+                as u32;
+        let rem_minutes =
+            (f64::from(total_sleep_time) * sleep_config.rem_fraction * restorative_factor).round()
+                as u32;
+        let light_minutes = total_sleep_time.saturating_sub(deep_minutes + rem_minutes);
+        let extra_wake_count = anomaly.map_or(0, |a| a.extra_wake_count);
+        let awake_minutes = sleep_config.awake_minutes + extra_wake_count * 10;
+
+        let nights_before_last = i64::from(sleep_config.nights - 1 - night);
+        let sleep_end = sleep_config.last_wake_time - Duration::days(nights_before_last);
+        let time_in_bed = total_sleep_time + awake_minutes;
+        let sleep_start = sleep_end - Duration::minutes(i64::from(time_in_bed));
+
+        let stages = Self::build_stages(
+            sleep_start,
+            deep_minutes,
+            rem_minutes,
+            light_minutes,
+            awake_minutes,
+        );
+
+        let noise = if sleep_config.rmssd_noise_ms > 0.0 {
+            rng.gen_range(-sleep_config.rmssd_noise_ms..=sleep_config.rmssd_noise_ms)
+        } else {
+            0.0
+        };
+        let trend_rmssd = sleep_config
+            .rmssd_nightly_change_ms
+            .mul_add(f64::from(night), sleep_config.rmssd_baseline_ms)
+            + noise;
+        let hrv_drop = anomaly.map_or(0.0, |a| a.hrv_drop_percent) / 100.0;
+        let rmssd = (trend_rmssd * (1.0 - hrv_drop)).max(5.0);
+
+        #[allow(clippy::cast_precision_loss)]
+        let sleep_efficiency = total_sleep_time as f32 / time_in_bed as f32 * 100.0;
+        #[allow(clippy::cast_precision_loss)]
+        let sleep_score = 60.0
+            + (deep_minutes as f32 / 60.0 * 8.0).min(20.0)
+            + (rem_minutes as f32 / 60.0 * 5.0).min(15.0)
+            - (extra_wake_count as f32 * 2.0);
+
+        SleepSession {
+            id: format!(
+                "synthetic_sleep_{}_{}",
+                sleep_end.format("%Y%m%d"),
+                sleep_config.seed
+            ),
+            start_time: sleep_start,
+            end_time: sleep_end,
+            time_in_bed,
+            total_sleep_time,
+            sleep_efficiency,
+            sleep_score: Some(sleep_score.clamp(0.0, 100.0)),
+            stages,
+            hrv_during_sleep: Some((rmssd * 10.0).round() / 10.0),
+            respiratory_rate: Some(rng.gen_range(14.0..16.5)),
+            temperature_variation: Some(rng.gen_range(-0.3..0.2)),
+            wake_count: Some(rng.gen_range(1..3) + extra_wake_count),
+            sleep_onset_latency: Some(rng.gen_range(5..20)),
+            provider: oauth_providers::SYNTHETIC_SLEEP.to_owned(),
+        }
+    }
+
+    /// Lay out four Light -> Deep -> Light -> REM cycles with a brief awakening before the last
+    fn build_stages(
+        sleep_start: DateTime<Utc>,
+        deep_minutes: u32,
+        rem_minutes: u32,
+        light_minutes: u32,
+        awake_minutes: u32,
+    ) -> Vec<SleepStage> {
+        let deep = split_minutes(deep_minutes, DEEP_CYCLE_WEIGHTS);
+        let rem = split_minutes(rem_minutes, REM_CYCLE_WEIGHTS);
+        let light = split_minutes(light_minutes, LIGHT_CYCLE_WEIGHTS);
+
+        let mut layout = Vec::with_capacity(17);
+        for cycle in 0..4 {
+            if cycle == 3 {
+                layout.push((SleepStageType::Awake, awake_minutes));
+            }
+            let light_first = light[cycle] / 2;
+            layout.push((SleepStageType::Light, light_first));
+            layout.push((SleepStageType::Deep, deep[cycle]));
+            layout.push((SleepStageType::Light, light[cycle] - light_first));
+            layout.push((SleepStageType::Rem, rem[cycle]));
+        }
+
+        let mut current_time = sleep_start;
+        layout
+            .into_iter()
+            .filter(|(_, duration)| *duration > 0)
+            .map(|(stage_type, duration_minutes)| {
+                let stage = SleepStage {
+                    stage_type,
+                    start_time: current_time,
+                    duration_minutes,
+                };
+                current_time += Duration::minutes(i64::from(duration_minutes));
+                stage
+            })
+            .collect()
+    }
+
+    fn sessions_between(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Vec<SleepSession> {
+        self.sessions
+            .iter()
+            .filter(|s| s.end_time >= start_date && s.start_time <= end_date)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Split `total` minutes across cycles by weight, giving the rounding remainder to the last cycle
+fn split_minutes(total: u32, weights: [u32; 4]) -> [u32; 4] {
+    let weight_sum: u32 = weights.iter().sum();
+    let mut parts = weights.map(|w| total * w / weight_sum);
+    let assigned: u32 = parts.iter().sum();
+    parts[3] += total - assigned;
+    parts
+}
+
+#[async_trait]
+impl FitnessProvider for SyntheticSleepProvider {
+    fn name(&self) -> &'static str {
+        oauth_providers::SYNTHETIC_SLEEP
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        &self.config
+    }
+
+    async fn set_credentials(&self, _credentials: OAuth2Credentials) -> AppResult<()> {
+        // No-op: synthetic provider doesn't need credentials
+        Ok(())
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        Ok(Athlete {
+            id: format!("{}_athlete_001", oauth_providers::SYNTHETIC_SLEEP),
+            username: "test_sleeper".to_owned(),
+            firstname: Some("Synthetic".to_owned()),
+            lastname: Some("Sleeper".to_owned()),
+            profile_picture: None,
+            provider: oauth_providers::SYNTHETIC_SLEEP.to_owned(),
+        })
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        _params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        // Sleep-only provider: no activities
+        Ok(Vec::new())
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        _params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        Ok(CursorPage::new(Vec::new(), None, None, false))
+    }
+
+    async fn get_activity(&self, id: &str) -> AppResult<Activity> {
+        Err(ProviderError::NotFound {
+            provider: oauth_providers::SYNTHETIC_SLEEP.to_owned(),
+            resource_type: "Activity".to_owned(),
+            resource_id: id.to_owned(),
+        }
+        .into())
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        Ok(Stats {
+            total_activities: 0,
+            total_distance: 0.0,
+            total_duration: 0,
+            total_elevation_gain: 0.0,
+        })
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        Ok(Vec::new())
+    }
+
+    #[instrument(
+        skip(self),
+        fields(provider = "synthetic_sleep", api_call = "get_sleep_sessions")
+    )]
+    async fn get_sleep_sessions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<Vec<SleepSession>, ProviderError> {
+        Ok(self.sessions_between(start_date, end_date))
+    }
+
+    #[instrument(
+        skip(self),
+        fields(provider = "synthetic_sleep", api_call = "get_latest_sleep_session")
+    )]
+    async fn get_latest_sleep_session(&self) -> Result<SleepSession, ProviderError> {
+        self.sessions
+            .iter()
+            .max_by_key(|s| s.end_time)
+            .cloned()
+            .ok_or_else(|| ProviderError::NotFound {
+                provider: oauth_providers::SYNTHETIC_SLEEP.to_owned(),
+                resource_type: "SleepSession".to_owned(),
+                resource_id: "latest".to_owned(),
+            })
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
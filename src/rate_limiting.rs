@@ -11,8 +11,9 @@
 //! authentication methods.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 use uuid::Uuid;
@@ -20,6 +21,7 @@ use uuid::Uuid;
 use crate::api_keys::{ApiKey, ApiKeyTier};
 use crate::config::environment::RateLimitConfig;
 use crate::constants::tiers;
+use crate::errors::AppError;
 use crate::models::TenantId;
 use crate::models::{Tenant, User, UserTier};
 
@@ -67,6 +69,165 @@ pub struct UnifiedRateLimitInfo {
     pub auth_method: String,
 }
 
+/// How a tenant's rate limit decisions are applied to requests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitMode {
+    /// Requests over the limit are rejected
+    #[default]
+    Enforce,
+    /// Requests over the limit are recorded as would-be blocks but still forwarded
+    Observe,
+}
+
+impl RateLimitMode {
+    /// Database string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enforce => "enforce",
+            Self::Observe => "observe",
+        }
+    }
+}
+
+impl FromStr for RateLimitMode {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "enforce" => Ok(Self::Enforce),
+            "observe" => Ok(Self::Observe),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid rate limit mode: {s}"
+            ))),
+        }
+    }
+}
+
+/// Algorithm used to count requests against a rate limit
+///
+/// `FixedWindow` resets usage at window boundaries, so a client can spend its
+/// whole limit just before a boundary and again just after it. The other
+/// algorithms smooth usage across the boundary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Count requests since the start of the current window
+    #[default]
+    FixedWindow,
+    /// Sliding-window counter: the previous window's count is weighted by how
+    /// much of it still overlaps the trailing window
+    SlidingWindow,
+    /// Token bucket holding `limit` tokens, refilled evenly over one window
+    TokenBucket,
+}
+
+impl RateLimitAlgorithm {
+    /// Configuration string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::FixedWindow => "fixed_window",
+            Self::SlidingWindow => "sliding_window",
+            Self::TokenBucket => "token_bucket",
+        }
+    }
+
+    /// Earliest request timestamp needed to compute usage at `now`
+    #[must_use]
+    pub fn history_start(self, window: Duration, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::FixedWindow => fixed_window_start(window, now),
+            Self::SlidingWindow => fixed_window_start(window, now) - window,
+            Self::TokenBucket => now - window,
+        }
+    }
+
+    /// Usage counted against `limit` at `now`
+    ///
+    /// `request_times` are the timestamps of past requests in ascending order;
+    /// requests before [`Self::history_start`] are ignored. A request is allowed
+    /// while the returned usage is below `limit`.
+    #[must_use]
+    pub fn effective_usage(
+        self,
+        request_times: &[DateTime<Utc>],
+        limit: u32,
+        window: Duration,
+        now: DateTime<Utc>,
+    ) -> u32 {
+        let count_between = |start: DateTime<Utc>, end: DateTime<Utc>| {
+            let count = request_times
+                .iter()
+                .filter(|t| **t >= start && **t < end)
+                .count();
+            u32::try_from(count).unwrap_or(u32::MAX)
+        };
+
+        match self {
+            Self::FixedWindow => count_between(
+                fixed_window_start(window, now),
+                now + Duration::milliseconds(1),
+            ),
+            Self::SlidingWindow => {
+                let current_start = fixed_window_start(window, now);
+                let previous = count_between(current_start - window, current_start);
+                let current = count_between(current_start, now + Duration::milliseconds(1));
+                let elapsed_fraction = millis(now - current_start) / millis(window);
+                ceil_to_u32(f64::from(previous).mul_add(1.0 - elapsed_fraction, f64::from(current)))
+            }
+            Self::TokenBucket => {
+                let capacity = f64::from(limit);
+                let refill_per_milli = capacity / millis(window);
+                let mut tokens = capacity;
+                let mut last = now - window;
+                for &time in request_times.iter().filter(|t| **t > last && **t <= now) {
+                    tokens = millis(time - last)
+                        .mul_add(refill_per_milli, tokens)
+                        .min(capacity);
+                    tokens = (tokens - 1.0).max(0.0);
+                    last = time;
+                }
+                tokens = millis(now - last)
+                    .mul_add(refill_per_milli, tokens)
+                    .min(capacity);
+                ceil_to_u32(capacity - tokens)
+            }
+        }
+    }
+}
+
+impl FromStr for RateLimitAlgorithm {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fixed_window" => Ok(Self::FixedWindow),
+            "sliding_window" => Ok(Self::SlidingWindow),
+            "token_bucket" => Ok(Self::TokenBucket),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid rate limit algorithm: {s}"
+            ))),
+        }
+    }
+}
+
+/// Start of the epoch-aligned window of length `window` containing `now`
+fn fixed_window_start(window: Duration, now: DateTime<Utc>) -> DateTime<Utc> {
+    let window_secs = window.num_seconds().max(1);
+    let start = now.timestamp() - now.timestamp().rem_euclid(window_secs);
+    DateTime::from_timestamp(start, 0).unwrap_or(now)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.num_milliseconds() as f64
+}
+
+fn ceil_to_u32(value: f64) -> u32 {
+    value.ceil().clamp(0.0, f64::from(u32::MAX)) as u32
+}
+
 /// Tenant-specific rate limit tier configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TenantRateLimitTier {
@@ -325,6 +486,8 @@ impl Default for TenantRateLimitConfig {
 pub struct UnifiedRateLimitCalculator {
     /// Tenant-specific rate limit configurations
     tenant_config: TenantRateLimitConfig,
+    /// Algorithm used to count API key and JWT usage
+    algorithm: RateLimitAlgorithm,
 }
 
 impl UnifiedRateLimitCalculator {
@@ -333,21 +496,40 @@ impl UnifiedRateLimitCalculator {
     pub fn new() -> Self {
         Self {
             tenant_config: TenantRateLimitConfig::new(),
+            algorithm: RateLimitAlgorithm::default(),
         }
     }
 
     /// Create a new unified rate limit calculator with config
     #[must_use]
     pub fn new_with_config(config: RateLimitConfig) -> Self {
+        let algorithm = config.algorithm;
         Self {
             tenant_config: TenantRateLimitConfig::new_with_config(config),
+            algorithm,
         }
     }
 
     /// Create calculator with custom tenant configuration
     #[must_use]
     pub const fn with_tenant_config(tenant_config: TenantRateLimitConfig) -> Self {
-        Self { tenant_config }
+        Self {
+            tenant_config,
+            algorithm: RateLimitAlgorithm::FixedWindow,
+        }
+    }
+
+    /// Set the algorithm used to count usage
+    #[must_use]
+    pub const fn with_algorithm(mut self, algorithm: RateLimitAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Algorithm used to count usage
+    #[must_use]
+    pub const fn algorithm(&self) -> RateLimitAlgorithm {
+        self.algorithm
     }
 
     /// Calculate rate limit status for an API key
@@ -649,6 +831,8 @@ pub struct OAuth2RateLimitConfig {
     pub token_rpm: u32,
     /// Requests per minute for registration endpoint
     pub register_rpm: u32,
+    /// Requests per minute for token introspection endpoint
+    pub introspect_rpm: u32,
 }
 
 impl OAuth2RateLimitConfig {
@@ -660,6 +844,7 @@ impl OAuth2RateLimitConfig {
             authorize_rpm: oauth_rate_limiting::AUTHORIZE_RPM, // 1 per second
             token_rpm: oauth_rate_limiting::TOKEN_RPM,         // 1 per 2 seconds
             register_rpm: oauth_rate_limiting::REGISTER_RPM,   // 1 per 6 seconds
+            introspect_rpm: oauth_rate_limiting::INTROSPECT_RPM, // 1 per second
         }
     }
 
@@ -670,16 +855,23 @@ impl OAuth2RateLimitConfig {
             authorize_rpm: config.oauth_authorize_rpm,
             token_rpm: config.oauth_token_rpm,
             register_rpm: config.oauth_register_rpm,
+            introspect_rpm: config.oauth_introspect_rpm,
         }
     }
 
     /// Create custom `OAuth2` rate limit configuration
     #[must_use]
-    pub const fn custom(authorize_rpm: u32, token_rpm: u32, register_rpm: u32) -> Self {
+    pub const fn custom(
+        authorize_rpm: u32,
+        token_rpm: u32,
+        register_rpm: u32,
+        introspect_rpm: u32,
+    ) -> Self {
         Self {
             authorize_rpm,
             token_rpm,
             register_rpm,
+            introspect_rpm,
         }
     }
 
@@ -690,6 +882,7 @@ impl OAuth2RateLimitConfig {
             "authorize" => self.authorize_rpm,
             "token" => self.token_rpm,
             "register" => self.register_rpm,
+            "introspect" => self.introspect_rpm,
             _ => 60,
         }
     }
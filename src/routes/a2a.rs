@@ -15,6 +15,7 @@ use crate::{
     database_plugins::DatabaseProvider,
     errors::AppError,
     mcp::resources::ServerResources,
+    providers::registry::global_registry,
     security::cookies::get_cookie_value,
 };
 use axum::{
@@ -174,12 +175,27 @@ impl A2ARoutes {
     }
 
     /// Handle agent card discovery endpoint (public endpoint)
+    ///
+    /// Anonymous callers get every tool. Authenticated callers only get the
+    /// tools their connected providers can serve.
     async fn handle_agent_card_discovery(
         State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
     ) -> Json<AgentCard> {
-        // Yield to scheduler for cooperative multitasking
-        task::yield_now().await;
-        Json(AgentCard::with_base_url(&resources.config.base_url))
+        let card = AgentCard::with_base_url(&resources.config.base_url);
+        let Ok(auth) = Self::authenticate(&headers, &resources).await else {
+            return Json(card);
+        };
+
+        let connections = resources
+            .database
+            .get_user_provider_connections(auth.user_id, None)
+            .await
+            .unwrap_or_default();
+        let supported = global_registry()
+            .combined_capabilities(connections.iter().map(|c| c.provider.as_str()));
+
+        Json(card.restricted_to(supported))
     }
 
     /// List all A2A clients for authenticated user
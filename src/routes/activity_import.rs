@@ -0,0 +1,229 @@
+// ABOUTME: Activity file import route accepting GPX and TCX uploads as multipart form data
+// ABOUTME: Parses, validates, and stores uploaded activities for the `file` pseudo-provider
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Activity file import routes
+//!
+//! - `POST /api/activities/import` - Upload a GPX or TCX file in the `file` field
+//!   of a `multipart/form-data` body
+//!
+//! Files are limited to `MAX_REQUEST_SIZE` bytes. Parsed activities go through
+//! the import validator, which skips re-uploads of activities already imported
+//! and rejects implausible records, and are then stored for the `file`
+//! provider so every tool that takes `provider: "file"` can analyze them.
+
+use crate::{
+    auth::AuthResult,
+    constants::{limits::MAX_REQUEST_SIZE, oauth_providers},
+    database_plugins::DatabaseProvider,
+    errors::AppError,
+    mcp::resources::ServerResources,
+    models::{ConnectionType, TenantId},
+    providers::{
+        activity_import::{ActivityImportValidator, ImportRecord, ImportReport},
+        file_formats::{detect_format, parse_activity_file},
+    },
+    security::cookies::get_cookie_value,
+    services::goal_progress::update_goals_from_activities,
+};
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, Multipart, State},
+    http::HeaderMap,
+    routing::post,
+    Json, Router,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Room for multipart boundaries and part headers on top of the file itself
+const MULTIPART_OVERHEAD_BYTES: usize = 16 * 1024;
+
+/// Name of the multipart field carrying the file
+const FILE_FIELD: &str = "file";
+
+/// Response for a file import
+#[derive(Debug, Serialize)]
+pub struct ActivityImportResponse {
+    /// Provider name to pass to tools for imported activities
+    pub provider: String,
+    /// What happened to the uploaded activity
+    pub report: ImportReport,
+}
+
+/// An uploaded file read from the multipart body
+struct Upload {
+    file_name: String,
+    content_type: Option<String>,
+    bytes: Bytes,
+}
+
+/// Activity file import routes
+pub struct ActivityImportRoutes;
+
+impl ActivityImportRoutes {
+    /// Create all activity import routes
+    pub fn routes(resources: Arc<ServerResources>) -> Router {
+        Router::new()
+            .route("/api/activities/import", post(Self::handle_import))
+            .layer(DefaultBodyLimit::max(
+                MAX_REQUEST_SIZE + MULTIPART_OVERHEAD_BYTES,
+            ))
+            .with_state(resources)
+    }
+
+    /// Extract and authenticate user from authorization header or cookie
+    async fn authenticate(
+        headers: &HeaderMap,
+        resources: &Arc<ServerResources>,
+    ) -> Result<AuthResult, AppError> {
+        let auth_value =
+            if let Some(auth_header) = headers.get("authorization").and_then(|h| h.to_str().ok()) {
+                auth_header.to_owned()
+            } else if let Some(token) = get_cookie_value(headers, "auth_token") {
+                format!("Bearer {token}")
+            } else {
+                return Err(AppError::auth_invalid(
+                    "Missing authorization header or cookie",
+                ));
+            };
+
+        resources
+            .auth_middleware
+            .authenticate_request(Some(&auth_value))
+            .await
+            .map_err(|e| AppError::auth_invalid(format!("Authentication failed: {e}")))
+    }
+
+    /// Resolve the tenant to import into, preferring the JWT's active tenant
+    async fn get_user_tenant(
+        auth: &AuthResult,
+        resources: &Arc<ServerResources>,
+    ) -> Result<TenantId, AppError> {
+        if let Some(tenant_id) = auth.active_tenant_id {
+            return Ok(TenantId::from(tenant_id));
+        }
+        let tenants = resources
+            .database
+            .list_tenants_for_user(auth.user_id)
+            .await?;
+        tenants.first().map(|t| t.id).ok_or_else(|| {
+            AppError::invalid_input(format!("User {} has no tenant assigned", auth.user_id))
+        })
+    }
+
+    /// Read the `file` field from the multipart body
+    async fn read_upload(multipart: &mut Multipart) -> Result<Upload, AppError> {
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|e| AppError::invalid_input(format!("Invalid multipart body: {e}")))?
+        {
+            if field.name() != Some(FILE_FIELD) {
+                continue;
+            }
+            let file_name = field.file_name().unwrap_or("upload").to_owned();
+            let content_type = field.content_type().map(str::to_owned);
+            let bytes = field.bytes().await.map_err(|e| {
+                AppError::invalid_input(format!("Failed to read uploaded file: {e}"))
+            })?;
+            return Ok(Upload {
+                file_name,
+                content_type,
+                bytes,
+            });
+        }
+        Err(AppError::invalid_input(format!(
+            "Missing '{FILE_FIELD}' field in multipart body"
+        )))
+    }
+
+    /// Handle POST /api/activities/import
+    async fn handle_import(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        mut multipart: Multipart,
+    ) -> Result<Json<ActivityImportResponse>, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+        let tenant_id = Self::get_user_tenant(&auth, &resources).await?;
+        let upload = Self::read_upload(&mut multipart).await?;
+
+        if upload.bytes.len() > MAX_REQUEST_SIZE {
+            return Err(AppError::invalid_input(format!(
+                "File is {} bytes; the limit is {MAX_REQUEST_SIZE} bytes",
+                upload.bytes.len()
+            )));
+        }
+        let format =
+            detect_format(&upload.file_name, upload.content_type.as_deref()).ok_or_else(|| {
+                AppError::invalid_input(format!(
+                    "Unsupported file type for '{}'; upload a .gpx or .tcx file",
+                    upload.file_name
+                ))
+            })?;
+
+        let record = match parse_activity_file(format, &upload.file_name, &upload.bytes) {
+            Ok(activity) => ImportRecord::parsed(format, &upload.file_name, activity),
+            Err(e) => ImportRecord::failed(format, &upload.file_name, e.to_string()),
+        };
+        let existing = resources
+            .database
+            .get_imported_activities(auth.user_id)
+            .await?;
+        let batch = ActivityImportValidator::default().validate(vec![record], &existing);
+
+        for activity in &batch.activities {
+            resources
+                .database
+                .store_imported_activity(
+                    auth.user_id,
+                    &format.to_string(),
+                    &upload.file_name,
+                    activity,
+                )
+                .await?;
+        }
+
+        if !batch.activities.is_empty() {
+            resources
+                .database
+                .register_provider_connection(
+                    auth.user_id,
+                    tenant_id,
+                    oauth_providers::FILE,
+                    &ConnectionType::Manual,
+                    None,
+                )
+                .await?;
+            if let Err(e) = update_goals_from_activities(
+                &resources,
+                auth.user_id,
+                tenant_id,
+                oauth_providers::FILE,
+                &batch.activities,
+            )
+            .await
+            {
+                warn!(user_id = %auth.user_id, error = %e, "Failed to update goal progress");
+            }
+        }
+
+        info!(
+            user_id = %auth.user_id,
+            file_name = %upload.file_name,
+            %format,
+            imported = batch.report.imported,
+            deduped = batch.report.deduped,
+            rejected = batch.report.rejected,
+            "Activity file imported"
+        );
+
+        Ok(Json(ActivityImportResponse {
+            provider: oauth_providers::FILE.to_owned(),
+            report: batch.report,
+        }))
+    }
+}
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use axum::{
     body::Bytes,
@@ -13,6 +13,7 @@ use axum::{
     response::IntoResponse,
     Extension, Json,
 };
+use serde::de::DeserializeOwned;
 use serde_json::{from_slice, json, to_value};
 use tokio::task;
 use tracing::{info, warn};
@@ -28,10 +29,13 @@ use crate::{
     errors::{AppError, AppResult},
     models::User,
 };
+use uuid::Uuid;
 
 use super::types::{
-    AdminResponse, ListApiKeysQuery, ProvisionApiKeyRequest, ProvisionApiKeyResponse,
-    RateLimitInfo, RevokeKeyRequest,
+    AdminResponse, BulkProvisionApiKeyItem, BulkProvisionApiKeysRequest,
+    BulkProvisionApiKeysResponse, BulkProvisionFailure, BulkProvisionSkipped, BulkProvisionedKey,
+    ListApiKeysQuery, ProvisionApiKeyRequest, ProvisionApiKeyResponse, RateLimitInfo,
+    RevokeKeyRequest,
 };
 use super::AdminApiContext;
 
@@ -82,9 +86,8 @@ async fn get_existing_user(database: &Database, email: &str) -> AppResult<User>
         })
 }
 
-/// Create and store API key
-pub(super) async fn create_and_store_api_key(
-    ctx: &AdminApiContext,
+/// Generate an API key for a provisioning request without storing it
+fn generate_api_key(
     user: &User,
     request: &ProvisionApiKeyRequest,
     tier: &ApiKeyTier,
@@ -127,6 +130,19 @@ pub(super) async fn create_and_store_api_key(
         }
     }
 
+    Ok((final_api_key, api_key_string))
+}
+
+/// Create and store API key
+pub(super) async fn create_and_store_api_key(
+    ctx: &AdminApiContext,
+    user: &User,
+    request: &ProvisionApiKeyRequest,
+    tier: &ApiKeyTier,
+    admin_token: &ValidatedAdminToken,
+) -> Result<(ApiKey, String), String> {
+    let (final_api_key, api_key_string) = generate_api_key(user, request, tier, admin_token)?;
+
     if let Err(e) = ctx.database.create_api_key(&final_api_key).await {
         return Err(format!("Failed to create API key: {e}"));
     }
@@ -156,11 +172,11 @@ fn create_provision_response(
     }
 }
 
-/// Parse and validate provision API key request
-fn parse_provision_request(
+/// Parse and validate a single or bulk provision API key request
+fn parse_provision_request<T: DeserializeOwned>(
     body: &Bytes,
-) -> Result<ProvisionApiKeyRequest, (StatusCode, Json<AdminResponse>)> {
-    from_slice::<ProvisionApiKeyRequest>(body).map_err(|e| {
+) -> Result<T, (StatusCode, Json<AdminResponse>)> {
+    from_slice::<T>(body).map_err(|e| {
         warn!(error = %e, "Invalid JSON body in provision API key request");
         (
             StatusCode::BAD_REQUEST,
@@ -256,7 +272,7 @@ pub(super) async fn handle_provision_api_key(
     Extension(admin_token): Extension<ValidatedAdminToken>,
     body: Bytes,
 ) -> AppResult<impl IntoResponse> {
-    let request = match parse_provision_request(&body) {
+    let request = match parse_provision_request::<ProvisionApiKeyRequest>(&body) {
         Ok(req) => req,
         Err(response) => return Ok(response),
     };
@@ -334,6 +350,227 @@ pub(super) async fn handle_provision_api_key(
     ))
 }
 
+/// Maximum number of rows accepted by a single bulk provisioning request
+const BULK_PROVISION_MAX_KEYS: usize = 500;
+
+/// Number of keys stored per database transaction during bulk provisioning
+const BULK_PROVISION_CHUNK_SIZE: usize = 50;
+
+/// Validated bulk provisioning row whose key is generated but not yet stored
+struct PendingBulkKey {
+    index: usize,
+    user: User,
+    tier: ApiKeyTier,
+    period_name: String,
+    api_key: ApiKey,
+    api_key_string: String,
+}
+
+/// Result of validating a bulk provisioning row
+enum BulkRowOutcome {
+    Pending(Box<PendingBulkKey>),
+    Skipped(BulkProvisionSkipped),
+}
+
+/// Validate a bulk provisioning row and generate its key
+///
+/// `planned` tracks keys already generated earlier in the batch so duplicate
+/// rows are skipped like keys that already exist in the database.
+async fn prepare_bulk_row(
+    database: &Database,
+    admin_token: &ValidatedAdminToken,
+    request: &BulkProvisionApiKeysRequest,
+    index: usize,
+    item: &BulkProvisionApiKeyItem,
+    planned: &mut HashMap<(Uuid, &'static str), String>,
+) -> Result<BulkRowOutcome, String> {
+    let tier = validate_tier(&item.tier)?;
+    let user = get_existing_user(database, &item.user_email)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if request.skip_existing.unwrap_or(true) {
+        let existing_id = match planned.get(&(user.id, tier.as_str())) {
+            Some(id) => Some(id.clone()),
+            None => database
+                .get_user_api_keys(user.id)
+                .await
+                .map_err(|e| format!("Failed to look up existing API keys: {e}"))?
+                .into_iter()
+                .find(|key| key.is_active && key.tier == tier)
+                .map(|key| key.id),
+        };
+        if let Some(existing_api_key_id) = existing_id {
+            return Ok(BulkRowOutcome::Skipped(BulkProvisionSkipped {
+                index,
+                user_email: item.user_email.clone(),
+                tier: tier.as_str().to_owned(),
+                existing_api_key_id,
+            }));
+        }
+    }
+
+    let provision_request = ProvisionApiKeyRequest {
+        user_email: item.user_email.clone(),
+        tier: item.tier.clone(),
+        description: None,
+        expires_in_days: request.expires_in_days,
+        rate_limit_requests: item.rate_limit.as_ref().map(|limit| limit.requests),
+        rate_limit_period: item.rate_limit.as_ref().map(|limit| limit.period.clone()),
+    };
+    let (api_key, api_key_string) =
+        generate_api_key(&user, &provision_request, &tier, admin_token)?;
+    planned.insert((user.id, tier.as_str()), api_key.id.clone());
+
+    Ok(BulkRowOutcome::Pending(Box::new(PendingBulkKey {
+        index,
+        user,
+        tier,
+        period_name: provision_request
+            .rate_limit_period
+            .unwrap_or_else(|| "month".to_owned()),
+        api_key,
+        api_key_string,
+    })))
+}
+
+/// Store one chunk of generated keys in a single transaction and record the outcome
+async fn store_bulk_chunk(
+    database: &Database,
+    admin_token: &ValidatedAdminToken,
+    chunk: Vec<PendingBulkKey>,
+    response: &mut BulkProvisionApiKeysResponse,
+) {
+    let api_keys: Vec<ApiKey> = chunk.iter().map(|row| row.api_key.clone()).collect();
+
+    if let Err(e) = database.create_api_keys_batch(&api_keys).await {
+        warn!(error = %e, keys = chunk.len(), "Failed to store bulk provisioning chunk");
+        response
+            .errors
+            .extend(chunk.into_iter().map(|row| BulkProvisionFailure {
+                index: row.index,
+                user_email: row.user.email,
+                error: format!("Failed to create API key: {e}"),
+            }));
+        return;
+    }
+
+    for row in chunk {
+        record_provisioning_audit(
+            database,
+            admin_token,
+            &row.api_key,
+            &row.user.email,
+            &row.tier,
+            &row.period_name,
+        )
+        .await;
+
+        let key = create_provision_response(
+            &row.api_key,
+            row.api_key_string,
+            &row.user,
+            &row.tier,
+            &row.period_name,
+        );
+        response.created.push(BulkProvisionedKey {
+            index: row.index,
+            user_email: row.user.email,
+            key,
+        });
+    }
+}
+
+/// Handle bulk API key provisioning (POST /admin/api-keys/bulk)
+///
+/// Rows are validated independently, so an invalid row is reported in
+/// `errors` without blocking the others. Valid keys are stored in chunks of
+/// `BULK_PROVISION_CHUNK_SIZE`, each inside its own transaction.
+pub(super) async fn handle_bulk_provision_api_keys(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    body: Bytes,
+) -> AppResult<impl IntoResponse> {
+    let request = match parse_provision_request::<BulkProvisionApiKeysRequest>(&body) {
+        Ok(req) => req,
+        Err(response) => return Ok(response),
+    };
+
+    if let Err(response) = check_provision_permission(&admin_token) {
+        return Ok(response);
+    }
+
+    if request.keys.is_empty() || request.keys.len() > BULK_PROVISION_MAX_KEYS {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            Json(AdminResponse {
+                success: false,
+                message: format!(
+                    "Bulk provisioning requires between 1 and {BULK_PROVISION_MAX_KEYS} keys, got {}",
+                    request.keys.len()
+                ),
+                data: None,
+            }),
+        ));
+    }
+
+    info!(
+        "Bulk provisioning {} API keys by service: {}",
+        request.keys.len(),
+        admin_token.service_name
+    );
+
+    let database = context.database.as_ref();
+    let mut response = BulkProvisionApiKeysResponse {
+        created: Vec::new(),
+        skipped: Vec::new(),
+        errors: Vec::new(),
+    };
+    let mut planned = HashMap::new();
+    let mut pending = Vec::new();
+
+    for (index, item) in request.keys.iter().enumerate() {
+        match prepare_bulk_row(database, &admin_token, &request, index, item, &mut planned).await {
+            Ok(BulkRowOutcome::Pending(row)) => pending.push(*row),
+            Ok(BulkRowOutcome::Skipped(skipped)) => response.skipped.push(skipped),
+            Err(error) => response.errors.push(BulkProvisionFailure {
+                index,
+                user_email: item.user_email.clone(),
+                error,
+            }),
+        }
+    }
+
+    let mut pending = pending.into_iter().peekable();
+    while pending.peek().is_some() {
+        let chunk: Vec<PendingBulkKey> = pending.by_ref().take(BULK_PROVISION_CHUNK_SIZE).collect();
+        store_bulk_chunk(database, &admin_token, chunk, &mut response).await;
+    }
+    response.errors.sort_by_key(|failure| failure.index);
+
+    info!(
+        "Bulk provisioning by service {}: {} created, {} skipped, {} failed",
+        admin_token.service_name,
+        response.created.len(),
+        response.skipped.len(),
+        response.errors.len()
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(AdminResponse {
+            success: response.errors.is_empty(),
+            message: format!(
+                "Provisioned {} API keys ({} skipped, {} failed)",
+                response.created.len(),
+                response.skipped.len(),
+                response.errors.len()
+            ),
+            data: to_value(&response).ok(),
+        }),
+    ))
+}
+
 /// Handle API key revocation
 pub(super) async fn handle_revoke_api_key(
     State(context): State<Arc<AdminApiContext>>,
@@ -0,0 +1,171 @@
+// ABOUTME: Admin feature flag route handlers
+// ABOUTME: Defines, lists, sets, and clears runtime feature flags globally or per tenant
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde_json::json;
+use tracing::info;
+
+use crate::{
+    admin::models::{AdminPermission, ValidatedAdminToken},
+    errors::{AppError, AppResult, ErrorCode},
+    models::TenantId,
+};
+
+use super::api_keys::json_response;
+use super::types::{DefineFeatureFlagRequest, FeatureFlagsQuery, SetFeatureFlagRequest};
+use super::AdminApiContext;
+
+/// Parse an optional tenant ID, requiring a super-admin token when one is given
+///
+/// Admin tokens have no tenant binding, so only super-admins may read or
+/// change a tenant's values.
+fn tenant_scope(
+    admin_token: &ValidatedAdminToken,
+    tenant_id: Option<&str>,
+) -> AppResult<Option<TenantId>> {
+    let Some(tenant_id) = tenant_id else {
+        return Ok(None);
+    };
+    if !admin_token.is_super_admin {
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Per-tenant feature flags require super-admin privileges",
+        ));
+    }
+    tenant_id
+        .parse()
+        .map(Some)
+        .map_err(|_| AppError::invalid_input(format!("Invalid tenant ID: {tenant_id}")))
+}
+
+/// List every flag with its value in effect, globally or for `?tenant_id=`
+pub(super) async fn handle_list_feature_flags(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Query(query): Query<FeatureFlagsQuery>,
+) -> AppResult<impl IntoResponse> {
+    admin_token.require_permission(&AdminPermission::ViewConfiguration)?;
+    let tenant_id = tenant_scope(&admin_token, query.tenant_id.as_deref())?;
+
+    let flags = context.feature_flags.list(tenant_id);
+
+    Ok(json_response(
+        json!({
+            "feature_flags": flags,
+            "count": flags.len()
+        }),
+        StatusCode::OK,
+    ))
+}
+
+/// Define a flag, or update the default and description of an existing one
+pub(super) async fn handle_define_feature_flag(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Json(request): Json<DefineFeatureFlagRequest>,
+) -> AppResult<impl IntoResponse> {
+    admin_token.require_permission(&AdminPermission::ManageConfiguration)?;
+
+    let flag = context
+        .feature_flags
+        .define(&request.key, request.default_value, request.description)
+        .await?;
+
+    info!(
+        "Admin {} defined feature flag {} ({})",
+        admin_token.service_name, flag.key, flag.value_type
+    );
+
+    Ok(json_response(
+        json!({ "feature_flag": flag }),
+        StatusCode::OK,
+    ))
+}
+
+/// Set a flag's value globally, or for one tenant when `tenant_id` is given
+pub(super) async fn handle_set_feature_flag(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(key): Path<String>,
+    Json(request): Json<SetFeatureFlagRequest>,
+) -> AppResult<impl IntoResponse> {
+    admin_token.require_permission(&AdminPermission::ManageConfiguration)?;
+    let tenant_id = tenant_scope(&admin_token, request.tenant_id.as_deref())?;
+
+    context
+        .feature_flags
+        .set(&key, tenant_id, request.value)
+        .await?;
+
+    info!(
+        "Admin {} set feature flag {} for {}",
+        admin_token.service_name,
+        key,
+        tenant_id.map_or_else(|| "all tenants".to_owned(), |id| format!("tenant {id}"))
+    );
+
+    Ok(json_response(
+        json!({
+            "key": key,
+            "tenant_id": tenant_id,
+            "value": context.feature_flags.value(&key, tenant_id)
+        }),
+        StatusCode::OK,
+    ))
+}
+
+/// Remove a flag's global value, or a tenant's value with `?tenant_id=`
+pub(super) async fn handle_clear_feature_flag(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(key): Path<String>,
+    Query(query): Query<FeatureFlagsQuery>,
+) -> AppResult<impl IntoResponse> {
+    admin_token.require_permission(&AdminPermission::ManageConfiguration)?;
+    let tenant_id = tenant_scope(&admin_token, query.tenant_id.as_deref())?;
+
+    let cleared = context.feature_flags.clear(&key, tenant_id).await?;
+
+    info!(
+        "Admin {} cleared feature flag {} value (removed: {})",
+        admin_token.service_name, key, cleared
+    );
+
+    Ok(json_response(
+        json!({
+            "key": key,
+            "cleared": cleared
+        }),
+        StatusCode::OK,
+    ))
+}
+
+/// Delete a flag along with its global and tenant values
+pub(super) async fn handle_delete_feature_flag(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(key): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    admin_token.require_permission(&AdminPermission::ManageConfiguration)?;
+
+    if !context.feature_flags.delete(&key).await? {
+        return Err(AppError::not_found(format!("Feature flag '{key}'")));
+    }
+
+    info!(
+        "Admin {} deleted feature flag {}",
+        admin_token.service_name, key
+    );
+
+    Ok(json_response(json!({ "deleted": key }), StatusCode::OK))
+}
@@ -0,0 +1,180 @@
+// ABOUTME: Admin impersonation route handler for support debugging
+// ABOUTME: Issues short-lived, read-only user tokens to super-admin tokens and audits every attempt
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Extension, Json};
+use chrono::DateTime;
+use serde_json::json;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    admin::models::ValidatedAdminToken,
+    database_plugins::DatabaseProvider,
+    errors::{AppError, AppResult, ErrorCode},
+    security::audit::{AuditEvent, AuditEventType, AuditSeverity, SecurityAuditor},
+};
+
+use super::api_keys::json_response;
+use super::types::AdminImpersonateRequest;
+use super::AdminApiContext;
+
+/// Record an impersonation attempt, warning instead of failing the request if the audit log is unavailable
+async fn audit_impersonation(
+    context: &AdminApiContext,
+    admin_token: &ValidatedAdminToken,
+    target_user_id: Option<Uuid>,
+    request: &AdminImpersonateRequest,
+    result: &str,
+    metadata: serde_json::Value,
+) {
+    let severity = if result == "success" {
+        AuditSeverity::Warning
+    } else {
+        AuditSeverity::Error
+    };
+    let mut event = AuditEvent::new(
+        AuditEventType::UserImpersonated,
+        severity,
+        format!(
+            "Admin token {} requested impersonation of user {}",
+            admin_token.token_id, request.target_user_id
+        ),
+        "impersonate".to_owned(),
+        result.to_owned(),
+    )
+    .with_resource(format!("user:{}", request.target_user_id))
+    .with_metadata(json!({
+        "admin_token_id": admin_token.token_id,
+        "service_name": admin_token.service_name,
+        "reason": request.reason,
+        "details": metadata,
+    }));
+    if let Some(user_id) = target_user_id {
+        event = event.with_user_id(user_id);
+    }
+
+    if let Err(e) = SecurityAuditor::new(context.database.clone())
+        .log_event(event)
+        .await
+    {
+        warn!("Failed to record impersonation audit event: {}", e);
+    }
+}
+
+/// Issue a short-lived token that acts as a user, for support debugging
+///
+/// Only super-admin tokens may call this. The token carries the admin token ID
+/// in `impersonated_by`, is limited to read-only tools, and cannot be refreshed.
+pub(super) async fn handle_impersonate_user(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Json(request): Json<AdminImpersonateRequest>,
+) -> AppResult<impl IntoResponse> {
+    if !admin_token.is_super_admin {
+        audit_impersonation(
+            &context,
+            &admin_token,
+            None,
+            &request,
+            "denied",
+            json!({ "error": "not a super-admin token" }),
+        )
+        .await;
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "User impersonation requires super-admin privileges",
+        ));
+    }
+
+    if request.reason.trim().is_empty() {
+        return Err(AppError::invalid_input(
+            "A reason is required to impersonate a user",
+        ));
+    }
+
+    let target_user_id = Uuid::parse_str(&request.target_user_id)
+        .map_err(|e| AppError::invalid_input(format!("Invalid target user ID: {e}")))?;
+
+    // SECURITY: Global lookup — impersonation target can be in any tenant
+    let target_user = context
+        .database
+        .get_user_global(target_user_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("User {target_user_id}")))?;
+
+    if target_user.role.is_super_admin() {
+        audit_impersonation(
+            &context,
+            &admin_token,
+            Some(target_user_id),
+            &request,
+            "denied",
+            json!({ "error": "target is a super admin" }),
+        )
+        .await;
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Cannot impersonate a super admin",
+        ));
+    }
+
+    // Same default tenant choice as user-initiated impersonation
+    let active_tenant_id = context
+        .database
+        .list_tenants_for_user(target_user.id)
+        .await
+        .ok()
+        .and_then(|tenants| tenants.first().map(|t| t.id.to_string()));
+
+    let (token, claims) = context.auth_manager.generate_admin_impersonation_token(
+        &target_user,
+        &admin_token.token_id,
+        &context.jwks_manager,
+        active_tenant_id,
+    )?;
+    let expires_at = DateTime::from_timestamp(claims.exp, 0)
+        .ok_or_else(|| AppError::internal("Impersonation token expiry out of range"))?;
+
+    audit_impersonation(
+        &context,
+        &admin_token,
+        Some(target_user_id),
+        &request,
+        "success",
+        json!({
+            "jti": claims.jti,
+            "scope": claims.scope,
+            "expires_at": expires_at.to_rfc3339(),
+        }),
+    )
+    .await;
+
+    info!(
+        impersonated_by = %admin_token.token_id,
+        service_name = %admin_token.service_name,
+        target_user_id = %target_user_id,
+        jti = %claims.jti,
+        "Admin token issued impersonation token"
+    );
+
+    Ok(json_response(
+        json!({
+            "token": token,
+            "token_type": "Bearer",
+            "expires_at": expires_at.to_rfc3339(),
+            "scope": claims.scope,
+            "impersonated_by": admin_token.token_id,
+            "target_user": {
+                "id": target_user.id,
+                "email": target_user.email,
+                "role": target_user.role.as_str(),
+            },
+        }),
+        StatusCode::OK,
+    ))
+}
@@ -11,19 +11,25 @@
 //! wrappers that delegate business logic to service layers.
 
 mod api_keys;
+mod feature_flags;
+mod impersonate;
 mod settings;
 mod setup;
 mod store;
 mod tokens;
 mod types;
+mod usage;
 mod users;
+mod webhooks;
 
 pub use types::{
-    AdminResponse, AdminSetupRequest, AdminSetupResponse, ApproveUserRequest, AutoApprovalResponse,
-    CoachReviewQuery, DeleteUserRequest, ListApiKeysQuery, ListPendingCoachesQuery, ListUsersQuery,
-    ProvisionApiKeyRequest, ProvisionApiKeyResponse, RateLimitInfo, RejectCoachRequest,
-    RevokeKeyRequest, SuspendUserRequest, TenantCreatedInfo, UpdateAutoApprovalRequest,
-    UserActivityQuery,
+    AdminImpersonateRequest, AdminResponse, AdminSetupRequest, AdminSetupResponse,
+    ApproveUserRequest, AutoApprovalResponse, CoachReviewQuery, DefineFeatureFlagRequest,
+    DeleteUserRequest, FeatureFlagsQuery, ListApiKeysQuery, ListPendingCoachesQuery,
+    ListUsersQuery, ListWebhooksQuery, ProvisionApiKeyRequest, ProvisionApiKeyResponse,
+    RateLimitInfo, RegisterWebhookRequest, RejectCoachRequest, RevokeKeyRequest,
+    SetFeatureFlagRequest, SuspendUserRequest, TenantCreatedInfo, TenantUsageQuery,
+    UpdateAutoApprovalRequest, UserActivityQuery, WebhookDeliveriesQuery,
 };
 
 use std::sync::Arc;
@@ -38,9 +44,11 @@ use tracing::info;
 use crate::{
     admin::{auth::AdminAuthService, jwks::JwksManager, middleware::admin_auth_middleware},
     auth::AuthManager,
+    config::UserDeletionConfig,
     database_plugins::factory::Database,
     mcp::ToolSelectionService,
     routes::tool_selection::{ToolSelectionContext, ToolSelectionRoutes},
+    services::feature_flags::FeatureFlags,
 };
 
 /// Admin API context shared across all endpoints
@@ -60,6 +68,10 @@ pub struct AdminApiContext {
     pub admin_api_key_monthly_limit: u32,
     /// Tool selection service for managing per-tenant MCP tool availability
     pub tool_selection: Arc<ToolSelectionService>,
+    /// Recovery window applied when admins delete users
+    pub user_deletion: UserDeletionConfig,
+    /// Runtime feature flags managed through the admin API
+    pub feature_flags: Arc<FeatureFlags>,
 }
 
 impl AdminApiContext {
@@ -79,6 +91,7 @@ impl AdminApiContext {
             jwks_manager.clone(),
             admin_token_cache_ttl_secs,
         );
+        let feature_flags = Arc::new(FeatureFlags::new(database.clone()));
         Self {
             database,
             auth_service,
@@ -87,8 +100,17 @@ impl AdminApiContext {
             jwks_manager,
             admin_api_key_monthly_limit,
             tool_selection,
+            user_deletion: UserDeletionConfig::from_env(),
+            feature_flags,
         }
     }
+
+    /// Share the server's feature flags so admin changes apply without a restart
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlags>) -> Self {
+        self.feature_flags = feature_flags;
+        self
+    }
 }
 
 /// Admin routes implementation (Axum)
@@ -128,6 +150,22 @@ impl AdminRoutes {
             middleware::from_fn_with_state(auth_service.clone(), admin_auth_middleware),
         );
 
+        let webhook_routes = Self::webhook_routes(context.clone()).layer(
+            middleware::from_fn_with_state(auth_service.clone(), admin_auth_middleware),
+        );
+
+        let feature_flag_routes = Self::feature_flag_routes(context.clone()).layer(
+            middleware::from_fn_with_state(auth_service.clone(), admin_auth_middleware),
+        );
+
+        let impersonation_routes = Self::impersonation_routes(context.clone()).layer(
+            middleware::from_fn_with_state(auth_service.clone(), admin_auth_middleware),
+        );
+
+        let usage_routes = Self::usage_routes(context.clone()).layer(
+            middleware::from_fn_with_state(auth_service.clone(), admin_auth_middleware),
+        );
+
         // Store review routes for admin coach review queue
         let store_review_routes = Self::store_review_routes(context.clone()).layer(
             middleware::from_fn_with_state(auth_service, admin_auth_middleware),
@@ -143,6 +181,10 @@ impl AdminRoutes {
             .merge(admin_token_routes)
             .merge(tool_selection_routes)
             .merge(store_review_routes)
+            .merge(webhook_routes)
+            .merge(feature_flag_routes)
+            .merge(impersonation_routes)
+            .merge(usage_routes)
             .merge(setup_routes)
     }
 
@@ -150,6 +192,10 @@ impl AdminRoutes {
     fn api_key_routes(context: Arc<AdminApiContext>) -> Router {
         Router::new()
             .route("/admin/provision", post(api_keys::handle_provision_api_key))
+            .route(
+                "/admin/api-keys/bulk",
+                post(api_keys::handle_bulk_provision_api_keys),
+            )
             .route("/admin/revoke", post(api_keys::handle_revoke_api_key))
             .route("/admin/list", get(api_keys::handle_list_api_keys))
             .route("/admin/token-info", get(api_keys::handle_token_info))
@@ -182,6 +228,10 @@ impl AdminRoutes {
                 get(users::handle_get_user_activity),
             )
             .route("/admin/users/:user_id", delete(users::handle_delete_user))
+            .route(
+                "/admin/users/:user_id/restore",
+                post(users::handle_restore_user),
+            )
             .with_state(context)
     }
 
@@ -240,6 +290,68 @@ impl AdminRoutes {
             .with_state(context)
     }
 
+    /// Tenant webhook registration and delivery log routes (Axum)
+    fn webhook_routes(context: Arc<AdminApiContext>) -> Router {
+        Router::new()
+            .route("/admin/webhooks", post(webhooks::handle_register_webhook))
+            .route("/admin/webhooks", get(webhooks::handle_list_webhooks))
+            .route(
+                "/admin/webhooks/:webhook_id",
+                delete(webhooks::handle_delete_webhook),
+            )
+            .route(
+                "/admin/webhooks/:webhook_id/deliveries",
+                get(webhooks::handle_list_webhook_deliveries),
+            )
+            .with_state(context)
+    }
+
+    /// Runtime feature flag routes (Axum)
+    fn feature_flag_routes(context: Arc<AdminApiContext>) -> Router {
+        Router::new()
+            .route(
+                "/admin/feature-flags",
+                get(feature_flags::handle_list_feature_flags),
+            )
+            .route(
+                "/admin/feature-flags",
+                post(feature_flags::handle_define_feature_flag),
+            )
+            .route(
+                "/admin/feature-flags/:key",
+                put(feature_flags::handle_set_feature_flag),
+            )
+            .route(
+                "/admin/feature-flags/:key",
+                delete(feature_flags::handle_delete_feature_flag),
+            )
+            .route(
+                "/admin/feature-flags/:key/value",
+                delete(feature_flags::handle_clear_feature_flag),
+            )
+            .with_state(context)
+    }
+
+    /// Tenant usage reporting routes for billing (Axum)
+    fn usage_routes(context: Arc<AdminApiContext>) -> Router {
+        Router::new()
+            .route(
+                "/admin/tenants/:tenant_id/tool-usage",
+                get(usage::handle_get_tenant_tool_usage),
+            )
+            .with_state(context)
+    }
+
+    /// Support impersonation routes (Axum)
+    fn impersonation_routes(context: Arc<AdminApiContext>) -> Router {
+        Router::new()
+            .route(
+                "/admin/impersonate",
+                post(impersonate::handle_impersonate_user),
+            )
+            .with_state(context)
+    }
+
     /// Store review queue routes for admin coach approval (Axum)
     fn store_review_routes(context: Arc<AdminApiContext>) -> Router {
         Router::new()
@@ -9,9 +9,12 @@
 //! This module contains all DTOs (Data Transfer Objects) used by the admin
 //! routes for serialization and deserialization of API requests and responses.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::models::FeatureFlagValue;
+
 /// API key provisioning request
 #[derive(Debug, Deserialize)]
 pub struct ProvisionApiKeyRequest {
@@ -29,6 +32,28 @@ pub struct ProvisionApiKeyRequest {
     pub rate_limit_period: Option<String>,
 }
 
+/// Single row of a bulk API key provisioning request
+#[derive(Debug, Deserialize)]
+pub struct BulkProvisionApiKeyItem {
+    /// Email of the user to provision the key for
+    pub user_email: String,
+    /// Tier level for the API key (trial/starter/professional/enterprise)
+    pub tier: String,
+    /// Optional rate limit override for this key
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+/// Bulk API key provisioning request
+#[derive(Debug, Deserialize)]
+pub struct BulkProvisionApiKeysRequest {
+    /// Keys to provision, processed in order
+    pub keys: Vec<BulkProvisionApiKeyItem>,
+    /// Skip users that already have an active key of the requested tier (default: true)
+    pub skip_existing: Option<bool>,
+    /// Number of days until the provisioned keys expire
+    pub expires_in_days: Option<u32>,
+}
+
 /// API key revocation request
 #[derive(Debug, Deserialize)]
 pub struct RevokeKeyRequest {
@@ -168,7 +193,7 @@ pub struct ProvisionApiKeyResponse {
 }
 
 /// Rate limit information
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitInfo {
     /// Maximum number of requests allowed
     pub requests: u32,
@@ -176,6 +201,53 @@ pub struct RateLimitInfo {
     pub period: String,
 }
 
+/// API key created by a bulk provisioning request
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkProvisionedKey {
+    /// Position of the row in the request
+    pub index: usize,
+    /// Email of the user the key was provisioned for
+    pub user_email: String,
+    /// The provisioned key (the full key is shown only once)
+    #[serde(flatten)]
+    pub key: ProvisionApiKeyResponse,
+}
+
+/// Bulk provisioning row skipped because the user already has an active key of that tier
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkProvisionSkipped {
+    /// Position of the row in the request
+    pub index: usize,
+    /// Email of the user from the row
+    pub user_email: String,
+    /// Requested tier
+    pub tier: String,
+    /// ID of the active key the user already holds
+    pub existing_api_key_id: String,
+}
+
+/// Bulk provisioning row that could not be provisioned
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkProvisionFailure {
+    /// Position of the row in the request
+    pub index: usize,
+    /// Email of the user from the row
+    pub user_email: String,
+    /// Why the row failed
+    pub error: String,
+}
+
+/// Bulk API key provisioning response
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkProvisionApiKeysResponse {
+    /// Keys that were created
+    pub created: Vec<BulkProvisionedKey>,
+    /// Rows skipped because a matching active key already exists
+    pub skipped: Vec<BulkProvisionSkipped>,
+    /// Rows that failed, with the reason
+    pub errors: Vec<BulkProvisionFailure>,
+}
+
 /// Generic admin response
 #[derive(Debug, Clone, Serialize)]
 pub struct AdminResponse {
@@ -210,3 +282,77 @@ pub struct TenantCreatedInfo {
     /// Subscription plan
     pub plan: String,
 }
+
+/// Tenant webhook registration request
+#[derive(Debug, Deserialize)]
+pub struct RegisterWebhookRequest {
+    /// Tenant whose events are delivered
+    pub tenant_id: String,
+    /// HTTPS endpoint events are POSTed to
+    pub url: String,
+    /// Event types to deliver (e.g. `activity.synced`, `goal.completed`)
+    pub event_types: Vec<String>,
+    /// Signing secret; generated when omitted
+    pub secret: Option<String>,
+}
+
+/// Query parameters for listing webhooks
+#[derive(Debug, Deserialize)]
+pub struct ListWebhooksQuery {
+    /// Restrict the listing to one tenant
+    pub tenant_id: Option<String>,
+}
+
+/// Query parameters for listing a webhook's deliveries
+#[derive(Debug, Deserialize)]
+pub struct WebhookDeliveriesQuery {
+    /// Filter by status (`pending`, `delivered`, `dead_letter`)
+    pub status: Option<String>,
+    /// Maximum number of results (default: 50, max: 200)
+    pub limit: Option<u32>,
+}
+
+/// Request to impersonate a user with a super-admin token
+#[derive(Debug, Deserialize)]
+pub struct AdminImpersonateRequest {
+    /// User to act as
+    pub target_user_id: String,
+    /// Why support needs to act as the user; recorded in the audit log
+    pub reason: String,
+}
+
+/// Query parameters for a tenant's tool usage report
+#[derive(Debug, Deserialize)]
+pub struct TenantUsageQuery {
+    /// Start of the range (defaults to 30 days before `end_date`)
+    pub start_date: Option<DateTime<Utc>>,
+    /// End of the range, exclusive (defaults to now)
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+/// Query parameters for listing feature flags
+#[derive(Debug, Deserialize)]
+pub struct FeatureFlagsQuery {
+    /// Resolve values for this tenant instead of globally
+    pub tenant_id: Option<String>,
+}
+
+/// Request to define a feature flag or update its default
+#[derive(Debug, Deserialize)]
+pub struct DefineFeatureFlagRequest {
+    /// Flag key (e.g. `tool.get_activities`)
+    pub key: String,
+    /// Default value; its type (bool, number, or string) becomes the flag's type
+    pub default_value: FeatureFlagValue,
+    /// What the flag controls
+    pub description: Option<String>,
+}
+
+/// Request to set a feature flag's value
+#[derive(Debug, Deserialize)]
+pub struct SetFeatureFlagRequest {
+    /// New value, of the flag's type
+    pub value: FeatureFlagValue,
+    /// Tenant the value applies to; sets the global value when omitted
+    pub tenant_id: Option<String>,
+}
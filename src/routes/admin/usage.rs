@@ -0,0 +1,65 @@
+// ABOUTME: Admin tenant usage route handler for usage-based billing
+// ABOUTME: Reports per-tool, per-day tool calls of a tenant's members over a date range
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension,
+};
+use chrono::{Duration, Utc};
+use serde_json::json;
+use tracing::info;
+
+use crate::{
+    admin::models::ValidatedAdminToken,
+    errors::{AppError, AppResult, ErrorCode},
+    models::TenantId,
+    services::tenant_usage::tenant_tool_usage_report,
+};
+
+use super::api_keys::json_response;
+use super::types::TenantUsageQuery;
+use super::AdminApiContext;
+
+/// Days covered when no start date is given
+const DEFAULT_USAGE_WINDOW_DAYS: i64 = 30;
+
+/// Report a tenant's tool calls per tool and per day
+///
+/// Admin tokens have no tenant binding, so only super-admins may read a
+/// tenant's usage.
+pub(super) async fn handle_get_tenant_tool_usage(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(tenant_id): Path<String>,
+    Query(query): Query<TenantUsageQuery>,
+) -> AppResult<impl IntoResponse> {
+    if !admin_token.is_super_admin {
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Tenant usage reports require super-admin privileges",
+        ));
+    }
+    let tenant_id: TenantId = tenant_id
+        .parse()
+        .map_err(|_| AppError::invalid_input(format!("Invalid tenant ID: {tenant_id}")))?;
+
+    let end = query.end_date.unwrap_or_else(Utc::now);
+    let start = query
+        .start_date
+        .unwrap_or_else(|| end - Duration::days(DEFAULT_USAGE_WINDOW_DAYS));
+    let report = tenant_tool_usage_report(context.database.as_ref(), tenant_id, start, end).await?;
+
+    info!(
+        "Admin {} read tool usage of tenant {} ({} calls)",
+        admin_token.service_name, tenant_id, report.total_calls
+    );
+
+    Ok(json_response(json!({ "usage": report }), StatusCode::OK))
+}
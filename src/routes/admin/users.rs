@@ -422,8 +422,11 @@ pub(super) async fn handle_suspend_user(
 
 /// Handle user deletion workflow
 ///
-/// Permanently deletes a user and all associated data (cascades via foreign keys).
-/// This action cannot be undone.
+/// Soft-deletes the user: they are hidden from lookups and cannot sign in, but can
+/// be restored via `POST /admin/users/:user_id/restore` until the recovery window
+/// expires. The background cleanup task then permanently deletes the user and all
+/// associated data (cascades via foreign keys). With a recovery window of zero the
+/// user is deleted permanently right away.
 pub(super) async fn handle_delete_user(
     State(context): State<Arc<AdminApiContext>>,
     Extension(admin_token): Extension<ValidatedAdminToken>,
@@ -469,16 +472,49 @@ pub(super) async fn handle_delete_user(
         })?;
 
     let user_email = user.email.clone();
+    let reason = request.reason.as_deref().unwrap_or("No reason provided");
 
-    ctx.database.delete_user(user_uuid).await.map_err(|e| {
-        error!(error = %e, "Failed to delete user from database");
-        AppError::internal(format!("Failed to delete user: {e}"))
-    })?;
+    if !ctx.user_deletion.soft_delete_enabled() {
+        ctx.database.delete_user(user_uuid).await.map_err(|e| {
+            error!(error = %e, "Failed to delete user from database");
+            AppError::internal(format!("Failed to delete user: {e}"))
+        })?;
 
-    let reason = request.reason.as_deref().unwrap_or("No reason provided");
+        info!(
+            "User {} ({}) deleted permanently. Reason: {}",
+            user_id, user_email, reason
+        );
+
+        return Ok(json_response(
+            AdminResponse {
+                success: true,
+                message: "User deleted successfully".to_owned(),
+                data: to_value(json!({
+                    "deleted_user": {
+                        "id": user_id,
+                        "email": user_email,
+                    },
+                    "reason": reason
+                }))
+                .ok(),
+            },
+            StatusCode::OK,
+        ));
+    }
+
+    let deleted_at = Utc::now();
+    ctx.database
+        .soft_delete_user(user_uuid, deleted_at)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to soft-delete user");
+            AppError::internal(format!("Failed to delete user: {e}"))
+        })?;
+
+    let recoverable_until = ctx.user_deletion.restorable_until(deleted_at);
     info!(
-        "User {} ({}) deleted successfully. Reason: {}",
-        user_id, user_email, reason
+        "User {} ({}) deleted, recoverable until {}. Reason: {}",
+        user_id, user_email, recoverable_until, reason
     );
 
     Ok(json_response(
@@ -490,6 +526,8 @@ pub(super) async fn handle_delete_user(
                     "id": user_id,
                     "email": user_email,
                 },
+                "deleted_at": deleted_at.to_rfc3339(),
+                "recoverable_until": recoverable_until.to_rfc3339(),
                 "reason": reason
             }))
             .ok(),
@@ -498,6 +536,66 @@ pub(super) async fn handle_delete_user(
     ))
 }
 
+/// Handle restoring a soft-deleted user
+///
+/// Only users deleted within the recovery window can be restored; after that the
+/// user has been (or is about to be) permanently purged.
+pub(super) async fn handle_restore_user(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(user_id): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    if !admin_token
+        .permissions
+        .has_permission(&AdminPerm::ManageUsers)
+    {
+        return Ok(json_response(
+            AdminResponse {
+                success: false,
+                message: "Permission denied: ManageUsers required".to_owned(),
+                data: None,
+            },
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    info!(
+        "Restoring user {} by service: {}",
+        user_id, admin_token.service_name
+    );
+
+    let ctx = context.as_ref();
+    let user_uuid = Uuid::parse_str(&user_id).map_err(|e| {
+        error!(error = %e, "Invalid user ID format");
+        AppError::invalid_input(format!("Invalid user ID format: {e}"))
+    })?;
+
+    let deleted_since = ctx.user_deletion.purge_cutoff(Utc::now());
+    let user = ctx
+        .database
+        .restore_deleted_user(user_uuid, deleted_since)
+        .await
+        .inspect_err(|e| warn!(error = %e, "Failed to restore user {}", user_id))?;
+
+    info!("User {} ({}) restored successfully", user_id, user.email);
+
+    Ok(json_response(
+        AdminResponse {
+            success: true,
+            message: "User restored successfully".to_owned(),
+            data: to_value(json!({
+                "user": {
+                    "id": user.id.to_string(),
+                    "email": user.email,
+                    "user_status": user_status_str(user.user_status),
+                },
+            }))
+            .ok(),
+        },
+        StatusCode::OK,
+    ))
+}
+
 /// Handle password reset for a user (admin only)
 ///
 /// Issues a one-time reset token instead of a temporary password. The admin
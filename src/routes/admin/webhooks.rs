@@ -0,0 +1,217 @@
+// ABOUTME: Admin tenant webhook route handlers
+// ABOUTME: Registers, lists, and deletes tenant callback URLs and exposes their delivery log
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::Utc;
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    admin::models::{AdminPermission, ValidatedAdminToken},
+    database_plugins::DatabaseProvider,
+    errors::{AppError, AppResult, ErrorCode},
+    models::TenantId,
+    services::webhook_delivery::{generate_webhook_secret, validate_webhook_url},
+    tenant::webhooks::{TenantWebhook, WebhookDeliveryStatus, WebhookEventType},
+};
+
+use super::api_keys::json_response;
+use super::types::{ListWebhooksQuery, RegisterWebhookRequest, WebhookDeliveriesQuery};
+use super::AdminApiContext;
+
+/// Default number of deliveries returned
+const DEFAULT_DELIVERY_LIMIT: u32 = 50;
+/// Maximum number of deliveries returned
+const MAX_DELIVERY_LIMIT: u32 = 200;
+/// Shortest signing secret accepted from callers
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// Webhooks belong to a tenant, and admin tokens have no tenant binding, so
+/// only super-admin tokens may act on them
+fn require_webhook_access(
+    admin_token: &ValidatedAdminToken,
+    permission: &AdminPermission,
+) -> AppResult<()> {
+    admin_token.require_permission(permission)?;
+    if admin_token.is_super_admin {
+        Ok(())
+    } else {
+        Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Tenant webhook operations require super-admin privileges",
+        ))
+    }
+}
+
+fn parse_tenant_id(value: &str) -> AppResult<TenantId> {
+    value
+        .parse()
+        .map_err(|_| AppError::invalid_input(format!("Invalid tenant ID: {value}")))
+}
+
+/// Register a webhook for a tenant
+///
+/// The signing secret is returned only in this response.
+pub(super) async fn handle_register_webhook(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Json(request): Json<RegisterWebhookRequest>,
+) -> AppResult<impl IntoResponse> {
+    require_webhook_access(&admin_token, &AdminPermission::ManageConfiguration)?;
+
+    let tenant_id = parse_tenant_id(&request.tenant_id)?;
+    context
+        .database
+        .get_tenant_by_id(tenant_id)
+        .await
+        .map_err(|_| AppError::not_found(format!("Tenant {tenant_id}")))?;
+    let url = validate_webhook_url(&request.url)?;
+
+    if request.event_types.is_empty() {
+        return Err(AppError::invalid_input(
+            "At least one event type is required",
+        ));
+    }
+    let mut event_types = request
+        .event_types
+        .iter()
+        .map(|event| event.parse::<WebhookEventType>())
+        .collect::<AppResult<Vec<_>>>()?;
+    event_types.sort_unstable();
+    event_types.dedup();
+
+    let secret = match request.secret {
+        Some(secret) if secret.len() < MIN_SECRET_LENGTH => {
+            return Err(AppError::invalid_input(format!(
+                "Webhook secret must be at least {MIN_SECRET_LENGTH} characters"
+            )));
+        }
+        Some(secret) => secret,
+        None => generate_webhook_secret(),
+    };
+
+    let now = Utc::now();
+    let webhook = TenantWebhook {
+        id: Uuid::new_v4(),
+        tenant_id,
+        url: url.to_string(),
+        secret,
+        event_types,
+        active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    context.database.create_tenant_webhook(&webhook).await?;
+
+    info!(
+        "Admin {} registered webhook {} for tenant {}",
+        admin_token.service_name, webhook.id, tenant_id
+    );
+
+    Ok(json_response(
+        json!({
+            "webhook": webhook,
+            "secret": webhook.secret,
+        }),
+        StatusCode::CREATED,
+    ))
+}
+
+/// List registered webhooks, optionally for one tenant
+pub(super) async fn handle_list_webhooks(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Query(query): Query<ListWebhooksQuery>,
+) -> AppResult<impl IntoResponse> {
+    require_webhook_access(&admin_token, &AdminPermission::ViewConfiguration)?;
+
+    let tenant_id = query
+        .tenant_id
+        .as_deref()
+        .map(parse_tenant_id)
+        .transpose()?;
+    let webhooks = context.database.list_tenant_webhooks(tenant_id).await?;
+
+    Ok(json_response(
+        json!({
+            "webhooks": webhooks,
+            "count": webhooks.len()
+        }),
+        StatusCode::OK,
+    ))
+}
+
+/// Delete a webhook and its delivery log
+pub(super) async fn handle_delete_webhook(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(webhook_id): Path<Uuid>,
+) -> AppResult<impl IntoResponse> {
+    require_webhook_access(&admin_token, &AdminPermission::ManageConfiguration)?;
+
+    if !context.database.delete_tenant_webhook(webhook_id).await? {
+        return Err(AppError::not_found(format!("Webhook {webhook_id}")));
+    }
+
+    info!(
+        "Admin {} deleted webhook {}",
+        admin_token.service_name, webhook_id
+    );
+
+    Ok(json_response(
+        json!({ "deleted": webhook_id }),
+        StatusCode::OK,
+    ))
+}
+
+/// List a webhook's deliveries, e.g. `?status=dead_letter` for failed ones
+pub(super) async fn handle_list_webhook_deliveries(
+    State(context): State<Arc<AdminApiContext>>,
+    Extension(admin_token): Extension<ValidatedAdminToken>,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookDeliveriesQuery>,
+) -> AppResult<impl IntoResponse> {
+    require_webhook_access(&admin_token, &AdminPermission::ViewConfiguration)?;
+
+    if context
+        .database
+        .get_tenant_webhook(webhook_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::not_found(format!("Webhook {webhook_id}")));
+    }
+    let status = query
+        .status
+        .as_deref()
+        .map(str::parse::<WebhookDeliveryStatus>)
+        .transpose()?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+        .clamp(1, MAX_DELIVERY_LIMIT);
+
+    let deliveries = context
+        .database
+        .list_webhook_deliveries(webhook_id, status, limit)
+        .await?;
+
+    Ok(json_response(
+        json!({
+            "deliveries": deliveries,
+            "count": deliveries.len()
+        }),
+        StatusCode::OK,
+    ))
+}
@@ -16,10 +16,12 @@
 mod types;
 
 pub use types::{
-    ChangePasswordRequest, CompleteResetRequest, ConnectionStatus, FirebaseLoginRequest,
-    LoginRequest, LoginResponse, OAuth2ErrorResponse, OAuth2TokenRequest, OAuth2TokenResponse,
-    OAuthAuthorizationResponse, OAuthStatus, ProviderStatus, ProvidersStatusResponse,
-    RefreshTokenRequest, RegisterRequest, RegisterResponse, SessionResponse, UpdateProfileRequest,
+    AuthSessionInfo, AuthSessionsResponse, ChangePasswordRequest, CompleteResetRequest,
+    ConnectionStatus, FirebaseLoginRequest, LoginRequest, LoginResponse, OAuth2ErrorResponse,
+    OAuth2TokenRequest, OAuth2TokenResponse, OAuthAuthorizationResponse, OAuthStatus,
+    ProviderCapabilitiesResponse, ProviderCapabilityMatrix, ProviderStatus,
+    ProvidersStatusResponse, RefreshTokenRequest, RegisterRequest, RegisterResponse,
+    RevokeSessionsQuery, RevokeSessionsResponse, SessionResponse, UpdateProfileRequest,
     UpdateProfileResponse, UserInfo, UserStatsResponse,
 };
 
@@ -48,20 +50,27 @@ use urlencoding::encode;
 
 use crate::mcp::oauth_flow_manager::OAuthTemplateRenderer;
 use crate::services::oauth_flow as oauth_flow_service;
+use crate::services::oauth_step_up;
+use crate::services::strava_webhooks;
 use crate::{
     admin::{AdminAuthService, FirebaseAuth, FirebaseClaims},
     config::environment::get_oauth_config,
-    constants::{error_messages, limits, tiers},
+    constants::{error_messages, limits, oauth_providers, tiers},
     context::{AuthContext, ConfigContext, DataContext, NotificationContext, ServerContext},
-    database_plugins::{factory::Database, DatabaseProvider},
+    database_plugins::{
+        factory::{Database, DatabaseType},
+        DatabaseProvider,
+    },
     errors::{AppError, AppResult, ErrorCode},
     mcp::{resources::ServerResources, schema::OAuthCompletedNotification},
-    models::{ConnectionType, Tenant, TenantId, User, UserOAuthToken, UserStatus, UserTier},
+    models::{
+        AuthSession, ConnectionType, Tenant, TenantId, User, UserOAuthToken, UserStatus, UserTier,
+    },
     oauth2_client::{OAuth2Client, OAuth2Config, OAuth2Token, OAuthClientState, PkceParams},
     permissions::UserRole,
     providers::ProviderDescriptor,
     security::cookies::{clear_auth_cookie, get_cookie_value, set_auth_cookie, set_csrf_cookie},
-    tenant::{TenantContext, TenantRole},
+    tenant::{TenantContext, TenantOAuthCredentials, TenantRole},
     utils::{
         auth::extract_bearer_token_owned,
         errors::{auth_error, user_state_error, validation_error},
@@ -477,8 +486,11 @@ impl AuthService {
 
     /// Handle token refresh - implementation from existing routes.rs
     ///
+    /// The session of the presented token is revoked, so each token can be
+    /// refreshed at most once and a revoked session cannot be refreshed.
+    ///
     /// # Errors
-    /// Returns error if refresh token is invalid or token generation fails
+    /// Returns error if refresh token is invalid or revoked, or token generation fails
     pub async fn refresh_token(&self, request: RefreshTokenRequest) -> AppResult<LoginResponse> {
         info!("Token refresh attempt for user with refresh token");
 
@@ -488,6 +500,15 @@ impl AuthService {
             .auth_manager()
             .validate_token(&request.token, self.auth.jwks_manager())
             .map_err(|_| AppError::auth_invalid("Invalid or expired token"))?;
+        if token_claims.is_impersonation() {
+            warn!(
+                impersonated_by = ?token_claims.impersonated_by,
+                "Rejected refresh of an impersonation token"
+            );
+            return Err(AppError::auth_invalid(
+                "Impersonation tokens cannot be refreshed",
+            ));
+        }
         let user_id = uuid::Uuid::parse_str(&token_claims.sub)
             .map_err(|e| AppError::auth_invalid(format!("Invalid token format: {e}")))?;
 
@@ -497,6 +518,28 @@ impl AuthService {
             return Err(AppError::auth_invalid("User ID mismatch"));
         }
 
+        // A revoked session cannot be refreshed; a live one is consumed so the old
+        // token stops working. Revoking only succeeds once, so concurrent refreshes
+        // of the same token cannot both win.
+        if self
+            .data
+            .database()
+            .get_auth_session(&token_claims.jti)
+            .await?
+            .is_some()
+            && !self
+                .data
+                .database()
+                .revoke_auth_session(user_id, &token_claims.jti)
+                .await?
+        {
+            warn!(
+                user_id = %user_id,
+                "Rejected refresh of a revoked session"
+            );
+            return Err(AppError::auth_invalid("Session has been revoked"));
+        }
+
         // Get user from database
         let user = self
             .data
@@ -580,6 +623,8 @@ struct ParsedOAuthState {
     pkce_code_verifier: Option<String>,
     /// Tenant ID from the OAuth state, used for tenant-specific credential lookup
     tenant_id: Option<uuid::Uuid>,
+    /// Scopes requested when the flow was initiated (only the missing ones for a step-up)
+    requested_scope: Option<String>,
 }
 
 impl OAuthService {
@@ -616,6 +661,26 @@ impl OAuthService {
         code: &str,
         state: &str,
         provider: &str,
+    ) -> AppResult<OAuthCallbackResponse> {
+        self.handle_callback_with_scope(code, state, provider, None)
+            .await
+    }
+
+    /// Handle OAuth callback, recording the scopes the provider reported as granted
+    ///
+    /// Providers such as Strava report the scopes the user actually approved on the
+    /// callback URL rather than in the token response. `granted_scope` is stored on
+    /// the token when the token response does not carry its own scope, so partial
+    /// grants can be detected before a tool call reaches the provider.
+    ///
+    /// # Errors
+    /// Returns error if OAuth state is invalid/expired/reused or callback processing fails
+    pub async fn handle_callback_with_scope(
+        &self,
+        code: &str,
+        state: &str,
+        provider: &str,
+        granted_scope: Option<&str>,
     ) -> AppResult<OAuthCallbackResponse> {
         // Validate provider is supported before consuming state
         self.validate_provider(provider)?;
@@ -627,6 +692,7 @@ impl OAuthService {
         let mobile_redirect_url = parsed_state.mobile_redirect_url;
         let pkce_code_verifier = parsed_state.pkce_code_verifier;
         let state_tenant_id = parsed_state.tenant_id;
+        let requested_scope = parsed_state.requested_scope;
 
         info!(
             "Processing OAuth callback for user {} provider {}{}",
@@ -644,7 +710,7 @@ impl OAuthService {
 
         // Exchange OAuth code for access token (with PKCE if verifier was stored)
         // Pass tenant_id from state so exchange uses tenant-specific credentials if available
-        let token = self
+        let mut token = self
             .exchange_oauth_code(
                 code,
                 provider,
@@ -659,12 +725,35 @@ impl OAuthService {
             user_id, provider
         );
 
+        // Providers that omit the grant from the token response report it on the callback
+        if token.scope.is_none() {
+            token.scope = granted_scope.map(str::to_owned);
+        }
+
+        // A scope step-up only requested the missing scopes; keep the earlier grant
+        let token_tenant_id: TenantId = tenant_id
+            .parse()
+            .map_err(|_| AppError::internal(format!("Invalid tenant_id for user: {tenant_id}")))?;
+        oauth_step_up::merge_granted_scopes(
+            self.data.database().as_ref(),
+            user_id,
+            token_tenant_id,
+            provider,
+            requested_scope.as_deref(),
+            &mut token,
+        )
+        .await?;
+
         // Store token and send notifications
         let expires_at = self
             .store_oauth_token(user_id, tenant_id, provider, &token)
             .await?;
         self.send_oauth_notifications(user_id, provider, &expires_at)
             .await?;
+        if provider == oauth_providers::STRAVA {
+            self.link_strava_athlete(user_id, token_tenant_id, &token)
+                .await;
+        }
         self.notify_bridge_oauth_success(provider, &token).await;
 
         Ok(OAuthCallbackResponse {
@@ -731,6 +820,7 @@ impl OAuthService {
             mobile_redirect_url,
             pkce_code_verifier,
             tenant_id,
+            requested_scope: client_state.scope,
         })
     }
 
@@ -799,6 +889,8 @@ impl OAuthService {
     ///
     /// When `tenant_id` is provided, attempts to use tenant-specific OAuth credentials
     /// (`client_id`, `client_secret`) before falling back to environment configuration.
+    /// Tenant credentials in the middle of a secret rotation fall back to the previous
+    /// secret if the new one is rejected.
     async fn exchange_oauth_code(
         &self,
         code: &str,
@@ -807,37 +899,54 @@ impl OAuthService {
         pkce_code_verifier: Option<&str>,
         tenant_id: Option<uuid::Uuid>,
     ) -> AppResult<OAuth2Token> {
-        let oauth_config = self
+        let (oauth_config, tenant_credentials) = self
             .create_oauth_config_with_tenant(provider, tenant_id)
             .await?;
-        let oauth_client = OAuth2Client::new(oauth_config);
-
-        let token = if let Some(verifier) = pkce_code_verifier {
-            // Use PKCE-enhanced token exchange when verifier was stored with the state
-            let pkce = PkceParams {
-                code_verifier: verifier.to_owned(),
-                code_challenge: String::new(),
-                code_challenge_method: "S256".to_owned(),
-            };
-            oauth_client
-                .exchange_code_with_pkce(code, &pkce)
-                .await
-                .map_err(|e| {
-                    error!(
-                        "OAuth PKCE token exchange failed for {provider} - user_id: {user_id}, error: {e}",
-                    );
-                    AppError::internal(format!("Failed to exchange OAuth code for token: {e}"))
-                })?
-        } else {
-            oauth_client.exchange_code(code).await.map_err(|e| {
-                error!(
-                    "OAuth token exchange failed for {provider} - user_id: {user_id}, error: {e}",
-                );
-                AppError::internal(format!("Failed to exchange OAuth code for token: {e}"))
-            })?
+
+        let exchange = |client_secret: String| {
+            let oauth_client = OAuth2Client::new(OAuth2Config {
+                client_secret,
+                ..oauth_config.clone()
+            });
+            async move {
+                if let Some(verifier) = pkce_code_verifier {
+                    // Use PKCE-enhanced token exchange when verifier was stored with the state
+                    let pkce = PkceParams {
+                        code_verifier: verifier.to_owned(),
+                        code_challenge: String::new(),
+                        code_challenge_method: "S256".to_owned(),
+                    };
+                    oauth_client
+                        .exchange_code_with_pkce(code, &pkce)
+                        .await
+                        .map_err(|e| {
+                            error!(
+                                "OAuth PKCE token exchange failed for {provider} - user_id: {user_id}, error: {e}",
+                            );
+                            AppError::internal(format!(
+                                "Failed to exchange OAuth code for token: {e}"
+                            ))
+                        })
+                } else {
+                    oauth_client.exchange_code(code).await.map_err(|e| {
+                        error!(
+                            "OAuth token exchange failed for {provider} - user_id: {user_id}, error: {e}",
+                        );
+                        AppError::internal(format!("Failed to exchange OAuth code for token: {e}"))
+                    })
+                }
+            }
         };
 
-        Ok(token)
+        match tenant_credentials {
+            Some(credentials) => {
+                self.config
+                    .tenant_oauth_client()
+                    .with_secret_fallback(&credentials, self.data.database(), exchange)
+                    .await
+            }
+            None => exchange(oauth_config.client_secret.clone()).await,
+        }
     }
 
     /// Create `OAuth2` config for provider using descriptor and configuration
@@ -904,7 +1013,8 @@ impl OAuthService {
 
     /// Create `OAuth2` config using tenant-specific credentials when available
     ///
-    /// Looks up tenant credentials from the database when `tenant_id` is provided.
+    /// Looks up tenant credentials from the database when `tenant_id` is provided
+    /// and returns them alongside the config, so callers can honour secret rotation.
     /// Falls back to environment-based configuration if no tenant credentials are found
     /// or if `tenant_id` is None.
     ///
@@ -914,7 +1024,7 @@ impl OAuthService {
         &self,
         provider: &str,
         tenant_id: Option<uuid::Uuid>,
-    ) -> AppResult<OAuth2Config> {
+    ) -> AppResult<(OAuth2Config, Option<TenantOAuthCredentials>)> {
         // Try tenant-specific credentials first
         if let Some(tid) = tenant_id {
             let tid = TenantId::from(tid);
@@ -958,20 +1068,21 @@ impl OAuthService {
 
                 let scopes = creds.scopes.join(params.scope_separator);
 
-                return Ok(OAuth2Config {
-                    client_id: creds.client_id,
-                    client_secret: creds.client_secret,
+                let oauth_config = OAuth2Config {
+                    client_id: creds.client_id.clone(),
+                    client_secret: creds.client_secret.clone(),
                     auth_url: endpoints.auth_url.to_owned(),
                     token_url: endpoints.token_url.to_owned(),
-                    redirect_uri: creds.redirect_uri,
+                    redirect_uri: creds.redirect_uri.clone(),
                     scopes: vec![scopes],
                     use_pkce: params.use_pkce,
-                });
+                };
+                return Ok((oauth_config, Some(creds)));
             }
         }
 
         // Fall back to environment-based configuration
-        self.create_oauth_config(provider)
+        Ok((self.create_oauth_config(provider)?, None))
     }
 
     /// Store OAuth token in database
@@ -1040,6 +1151,11 @@ impl OAuthService {
         let notification_id = self
             .store_oauth_notification(user_id, provider, expires_at)
             .await?;
+        // PostgreSQL pushes stored notifications through LISTEN/NOTIFY; broadcasting
+        // here as well would deliver them twice
+        if self.data.database().database_type() == DatabaseType::PostgreSQL {
+            return Ok(());
+        }
         self.broadcast_oauth_notification(&notification_id, user_id, provider);
         Ok(())
     }
@@ -1164,6 +1280,29 @@ impl OAuthService {
         }
     }
 
+    /// Remember which Strava athlete the user connected so webhook events can be routed to them
+    ///
+    /// Best-effort: without the link the user's activities are still synced by polling.
+    async fn link_strava_athlete(
+        &self,
+        user_id: uuid::Uuid,
+        tenant_id: TenantId,
+        token: &OAuth2Token,
+    ) {
+        let api_base_url = &self.config.config().external_services.strava_api.base_url;
+        if let Err(e) = strava_webhooks::link_strava_athlete(
+            self.data.database().as_ref(),
+            api_base_url,
+            &token.access_token,
+            user_id,
+            tenant_id,
+        )
+        .await
+        {
+            warn!("Failed to link Strava athlete for user {}: {}", user_id, e);
+        }
+    }
+
     /// Notify bridge about successful OAuth (for client-side token storage and focus recovery)
     async fn notify_bridge_oauth_success(&self, provider: &str, token: &OAuth2Token) {
         let oauth_callback_port = self.config.config().oauth_callback_port;
@@ -1495,6 +1634,14 @@ impl AuthRoutes {
             .route("/api/auth/logout", post(Self::handle_logout))
             .route("/api/auth/session", get(Self::handle_session))
             .route("/api/auth/refresh", post(Self::handle_refresh))
+            .route(
+                "/api/auth/sessions",
+                get(Self::handle_list_sessions).delete(Self::handle_revoke_sessions),
+            )
+            .route(
+                "/api/auth/sessions/:session_id",
+                delete(Self::handle_revoke_session),
+            )
             .route("/api/user/profile", put(Self::handle_update_profile))
             .route(
                 "/api/user/change-password",
@@ -1513,6 +1660,10 @@ impl AuthRoutes {
             )
             .route("/api/oauth/status", get(Self::handle_oauth_status))
             .route("/api/providers", get(Self::handle_providers_status))
+            .route(
+                "/api/providers/capabilities",
+                get(Self::handle_provider_capabilities),
+            )
             .route(
                 "/api/oauth/auth/:provider/:user_id",
                 get(Self::handle_oauth_auth_initiate),
@@ -1635,7 +1786,7 @@ impl AuthRoutes {
     ///
     /// Authenticates users via Firebase ID tokens (Google Sign-In, Apple, etc.)
     #[tracing::instrument(
-        skip(resources, request),
+        skip(resources, request_headers, request),
         fields(
             route = "firebase_login",
             user_id = Empty,
@@ -1645,6 +1796,7 @@ impl AuthRoutes {
     )]
     async fn handle_firebase_login(
         State(resources): State<Arc<ServerResources>>,
+        request_headers: HeaderMap,
         Json(request): Json<FirebaseLoginRequest>,
     ) -> Result<Response, AppError> {
         // Check if Firebase is configured
@@ -1669,6 +1821,7 @@ impl AuthRoutes {
                     .jwt_token
                     .clone() // Safe: JWT string ownership for cookie
                     .ok_or_else(|| AppError::internal("JWT token missing from login response"))?;
+                Self::record_session(&resources, &jwt_token, &request_headers).await?;
 
                 // Parse user ID for CSRF token generation
                 let user_id = uuid::Uuid::parse_str(&response.user.user_id)
@@ -1690,7 +1843,12 @@ impl AuthRoutes {
                 let mut headers = HeaderMap::new();
 
                 // Set httpOnly auth cookie (24 hour expiry to match JWT)
-                set_auth_cookie(&mut headers, &jwt_token, 24 * 60 * 60);
+                set_auth_cookie(
+                    &mut headers,
+                    &jwt_token,
+                    24 * 60 * 60,
+                    &resources.config.sse.session_cookie,
+                );
 
                 // Set CSRF cookie (30 minute expiry to match CSRF token)
                 set_csrf_cookie(&mut headers, &csrf_token, 30 * 60);
@@ -1706,7 +1864,7 @@ impl AuthRoutes {
 
     /// Handle token refresh (Axum)
     #[tracing::instrument(
-        skip(resources, request),
+        skip(resources, request_headers, request),
         fields(
             route = "token_refresh",
             user_id = %request.user_id,
@@ -1715,6 +1873,7 @@ impl AuthRoutes {
     )]
     async fn handle_refresh(
         State(resources): State<Arc<ServerResources>>,
+        request_headers: HeaderMap,
         Json(request): Json<RefreshTokenRequest>,
     ) -> Result<Response, AppError> {
         let server_context = ServerContext::from(resources.as_ref());
@@ -1731,6 +1890,7 @@ impl AuthRoutes {
                     .jwt_token
                     .clone() // Safe: JWT string ownership for cookie
                     .ok_or_else(|| AppError::internal("JWT token missing from refresh response"))?;
+                Self::record_session(&resources, &jwt_token, &request_headers).await?;
 
                 // Parse user ID for CSRF token generation
                 let user_id = uuid::Uuid::parse_str(&response.user.user_id)
@@ -1752,7 +1912,12 @@ impl AuthRoutes {
                 let mut headers = HeaderMap::new();
 
                 // Set httpOnly auth cookie (24 hour expiry to match JWT)
-                set_auth_cookie(&mut headers, &jwt_token, 24 * 60 * 60);
+                set_auth_cookie(
+                    &mut headers,
+                    &jwt_token,
+                    24 * 60 * 60,
+                    &resources.config.sse.session_cookie,
+                );
 
                 // Set CSRF cookie (30 minute expiry to match CSRF token)
                 set_csrf_cookie(&mut headers, &csrf_token, 30 * 60);
@@ -1767,7 +1932,9 @@ impl AuthRoutes {
     }
 
     /// Handle user logout (Axum)
-    async fn handle_logout() -> Result<Response, AppError> {
+    async fn handle_logout(
+        State(resources): State<Arc<ServerResources>>,
+    ) -> Result<Response, AppError> {
         // Yield to allow async context (required for Axum handler)
         task::yield_now().await;
 
@@ -1775,7 +1942,7 @@ impl AuthRoutes {
         let mut headers = HeaderMap::new();
 
         // Clear auth cookie
-        clear_auth_cookie(&mut headers);
+        clear_auth_cookie(&mut headers, &resources.config.sse.session_cookie);
 
         // Return success response
         Ok((
@@ -1814,6 +1981,13 @@ impl AuthRoutes {
         let user_id = auth_result.user_id;
         Span::current().record("user_id", user_id.to_string());
 
+        // A fresh session token would outlive the impersonation token
+        if auth_result.auth_method.impersonated_by().is_some() {
+            return Err(AppError::auth_invalid(
+                "Impersonation tokens cannot be exchanged for a session",
+            ));
+        }
+
         // Look up user details from database
         let user = resources
             .database
@@ -1829,6 +2003,7 @@ impl AuthRoutes {
             .auth_manager()
             .generate_token(&user, server_context.auth().jwks_manager())
             .map_err(|e| AppError::auth_invalid(format!("Failed to generate token: {e}")))?;
+        Self::record_session(&resources, &jwt_token, &headers).await?;
 
         // Generate fresh CSRF token
         let csrf_token = resources
@@ -1839,7 +2014,12 @@ impl AuthRoutes {
 
         // Refresh the httpOnly auth cookie with the new JWT
         let mut response_headers = HeaderMap::new();
-        set_auth_cookie(&mut response_headers, &jwt_token, 24 * 60 * 60);
+        set_auth_cookie(
+            &mut response_headers,
+            &jwt_token,
+            24 * 60 * 60,
+            &resources.config.sse.session_cookie,
+        );
         set_csrf_cookie(&mut response_headers, &csrf_token, 30 * 60);
 
         Span::current().record("success", true);
@@ -1861,6 +2041,188 @@ impl AuthRoutes {
         Ok((StatusCode::OK, response_headers, Json(session_response)).into_response())
     }
 
+    /// List the authenticated user's active login sessions
+    ///
+    /// Sessions are JWTs issued by login, refresh, and session restore that
+    /// have neither expired nor been revoked.
+    #[tracing::instrument(
+        skip(resources, headers),
+        fields(
+            route = "list_sessions",
+            user_id = Empty,
+        )
+    )]
+    async fn handle_list_sessions(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+    ) -> Result<Json<AuthSessionsResponse>, AppError> {
+        let auth_result = resources
+            .auth_middleware
+            .authenticate_request_with_headers(&headers)
+            .await?;
+        Span::current().record("user_id", auth_result.user_id.to_string());
+
+        let current = Self::current_session_id(&resources, &headers);
+        let sessions = resources
+            .database
+            .list_active_auth_sessions(auth_result.user_id)
+            .await?
+            .into_iter()
+            .map(|session| AuthSessionInfo {
+                current: current.as_deref() == Some(session.id.as_str()),
+                issued_at: session.issued_at.to_rfc3339(),
+                expires_at: session.expires_at.to_rfc3339(),
+                id: session.id,
+                client: session.client,
+                ip_address: session.ip_address,
+            })
+            .collect();
+
+        Ok(Json(AuthSessionsResponse { sessions }))
+    }
+
+    /// Revoke one of the authenticated user's sessions
+    ///
+    /// Requests made with the revoked session's token are rejected from then on.
+    #[tracing::instrument(
+        skip(resources, headers),
+        fields(
+            route = "revoke_session",
+            user_id = Empty,
+        )
+    )]
+    async fn handle_revoke_session(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Path(session_id): Path<String>,
+    ) -> Result<Json<RevokeSessionsResponse>, AppError> {
+        let auth_result = resources
+            .auth_middleware
+            .authenticate_request_with_headers(&headers)
+            .await?;
+        Span::current().record("user_id", auth_result.user_id.to_string());
+
+        if !resources
+            .database
+            .revoke_auth_session(auth_result.user_id, &session_id)
+            .await?
+        {
+            return Err(AppError::not_found(format!("Session {session_id}")));
+        }
+
+        info!(
+            "Revoked session {} for user {}",
+            session_id, auth_result.user_id
+        );
+        Ok(Json(RevokeSessionsResponse { revoked: 1 }))
+    }
+
+    /// Revoke all of the authenticated user's sessions
+    ///
+    /// With `?except_current=true` the session making the request stays signed in.
+    #[tracing::instrument(
+        skip(resources, headers, query),
+        fields(
+            route = "revoke_sessions",
+            user_id = Empty,
+        )
+    )]
+    async fn handle_revoke_sessions(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Query(query): Query<RevokeSessionsQuery>,
+    ) -> Result<Json<RevokeSessionsResponse>, AppError> {
+        let auth_result = resources
+            .auth_middleware
+            .authenticate_request_with_headers(&headers)
+            .await?;
+        Span::current().record("user_id", auth_result.user_id.to_string());
+
+        let keep = if query.except_current {
+            Some(
+                Self::current_session_id(&resources, &headers).ok_or_else(|| {
+                    AppError::invalid_input("Request is not made with a session token")
+                })?,
+            )
+        } else {
+            None
+        };
+        let revoked = resources
+            .database
+            .revoke_auth_sessions(auth_result.user_id, keep.as_deref())
+            .await?;
+
+        info!(
+            "Revoked {} sessions for user {}",
+            revoked, auth_result.user_id
+        );
+        Ok(Json(RevokeSessionsResponse { revoked }))
+    }
+
+    /// Record a login session for a freshly issued JWT
+    async fn record_session(
+        resources: &ServerResources,
+        jwt_token: &str,
+        headers: &HeaderMap,
+    ) -> AppResult<()> {
+        let claims = resources
+            .auth_manager
+            .validate_token(jwt_token, &resources.jwks_manager)?;
+        let user_id = uuid::Uuid::parse_str(&claims.sub)
+            .map_err(|e| AppError::internal(format!("Invalid user ID format: {e}")))?;
+        let timestamp =
+            |seconds: i64| chrono::DateTime::from_timestamp(seconds, 0).unwrap_or_else(Utc::now);
+
+        resources
+            .database
+            .record_auth_session(&AuthSession {
+                id: claims.jti,
+                user_id,
+                client: headers
+                    .get(header::USER_AGENT)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_owned),
+                ip_address: Self::client_ip(headers),
+                issued_at: timestamp(claims.iat),
+                expires_at: timestamp(claims.exp),
+                revoked_at: None,
+            })
+            .await
+    }
+
+    /// Session ID (`jti`) of the JWT the request was authenticated with
+    ///
+    /// Checks the auth cookie first, matching the order used by the auth middleware.
+    fn current_session_id(resources: &ServerResources, headers: &HeaderMap) -> Option<String> {
+        let bearer = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| extract_bearer_token_owned(value).ok());
+
+        get_cookie_value(headers, "auth_token")
+            .into_iter()
+            .chain(bearer)
+            .find_map(|token| {
+                resources
+                    .auth_manager
+                    .validate_token(&token, &resources.jwks_manager)
+                    .ok()
+            })
+            .map(|claims| claims.jti)
+    }
+
+    /// Client IP from proxy headers, if the request went through one
+    fn client_ip(headers: &HeaderMap) -> Option<String> {
+        let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        header_value("x-forwarded-for")
+            .and_then(|value| value.split(',').next())
+            .or_else(|| header_value("x-real-ip"))
+            .map(str::trim)
+            .filter(|ip| !ip.is_empty())
+            .map(str::to_owned)
+    }
+
     /// Handle user profile update (Axum)
     ///
     /// Updates the authenticated user's display name.
@@ -2161,7 +2523,7 @@ impl AuthRoutes {
     ///
     /// Response format: RFC 6749 Section 5.1 compliant JSON
     #[tracing::instrument(
-        skip(resources, request),
+        skip(resources, request_headers, request),
         fields(
             route = "oauth2_token",
             grant_type = %request.grant_type,
@@ -2172,6 +2534,7 @@ impl AuthRoutes {
     )]
     async fn handle_oauth2_token(
         State(resources): State<Arc<ServerResources>>,
+        request_headers: HeaderMap,
         Form(request): Form<OAuth2TokenRequest>,
     ) -> Result<Response, AppError> {
         // Validate grant_type
@@ -2205,6 +2568,7 @@ impl AuthRoutes {
                     .jwt_token
                     .clone()
                     .ok_or_else(|| AppError::internal("JWT token missing from login response"))?;
+                Self::record_session(&resources, &jwt_token, &request_headers).await?;
 
                 // Parse expiration to calculate expires_in
                 let expires_at = chrono::DateTime::parse_from_rfc3339(&response.expires_at)
@@ -2238,7 +2602,12 @@ impl AuthRoutes {
 
                 // Build response with secure cookies for web clients
                 let mut headers = HeaderMap::new();
-                set_auth_cookie(&mut headers, &jwt_token, 24 * 60 * 60);
+                set_auth_cookie(
+                    &mut headers,
+                    &jwt_token,
+                    24 * 60 * 60,
+                    &resources.config.sse.session_cookie,
+                );
                 set_csrf_cookie(&mut headers, &csrf_token, 30 * 60);
 
                 Ok((StatusCode::OK, headers, Json(oauth2_response)).into_response())
@@ -2305,7 +2674,13 @@ impl AuthRoutes {
         // Check if we should redirect to a separate frontend URL
         let frontend_url = server_context.config().config().frontend_url.clone();
 
-        match oauth_routes.handle_callback(code, state, &provider).await {
+        // Strava reports the scopes the user approved only on the callback URL
+        let granted_scope = params.get("scope").map(String::as_str);
+
+        match oauth_routes
+            .handle_callback_with_scope(code, state, &provider, granted_scope)
+            .await
+        {
             Ok(response) => {
                 // Priority: mobile redirect URL > frontend URL > render template
                 // Mobile apps pass redirect URL through OAuth state for deep linking
@@ -2545,6 +2920,32 @@ impl AuthRoutes {
         Ok((StatusCode::OK, Json(response)).into_response())
     }
 
+    /// Handle provider capability discovery (public endpoint)
+    ///
+    /// Returns the capability matrix of every registered provider so clients
+    /// can avoid calling tools a provider cannot serve.
+    async fn handle_provider_capabilities() -> Json<ProviderCapabilitiesResponse> {
+        use crate::providers::registry::global_registry;
+
+        // Yield to scheduler for cooperative multitasking
+        task::yield_now().await;
+
+        let registry = global_registry();
+        let mut providers: Vec<ProviderCapabilityMatrix> = registry
+            .supported_providers()
+            .into_iter()
+            .filter_map(|name| registry.get_descriptor(name))
+            .map(|descriptor| ProviderCapabilityMatrix {
+                provider: descriptor.name().to_owned(),
+                display_name: descriptor.display_name().to_owned(),
+                capabilities: descriptor.capabilities().matrix(),
+            })
+            .collect();
+        providers.sort_by(|a, b| a.provider.cmp(&b.provider));
+
+        Json(ProviderCapabilitiesResponse { providers })
+    }
+
     /// Parse a user ID string to UUID
     fn parse_user_id(user_id_str: &str) -> Result<uuid::Uuid, AppError> {
         uuid::Uuid::parse_str(user_id_str).map_err(|_| {
@@ -9,6 +9,8 @@
 //! This module contains all DTOs (Data Transfer Objects) used by the authentication
 //! routes for serialization and deserialization of API requests and responses.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// User registration request
@@ -129,6 +131,45 @@ pub struct SessionResponse {
     pub csrf_token: String,
 }
 
+/// An active login session as listed by `GET /api/auth/sessions`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthSessionInfo {
+    /// Session ID (the token's `jti`)
+    pub id: String,
+    /// Client that requested the token (User-Agent), if sent
+    pub client: Option<String>,
+    /// IP address the token was requested from, if known
+    pub ip_address: Option<String>,
+    /// When the token was issued (ISO 8601 format)
+    pub issued_at: String,
+    /// When the token expires (ISO 8601 format)
+    pub expires_at: String,
+    /// Whether this is the session making the request
+    pub current: bool,
+}
+
+/// Response for `GET /api/auth/sessions`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthSessionsResponse {
+    /// Active sessions, newest first
+    pub sessions: Vec<AuthSessionInfo>,
+}
+
+/// Query parameters for `DELETE /api/auth/sessions`
+#[derive(Debug, Default, Deserialize)]
+pub struct RevokeSessionsQuery {
+    /// Keep the session making the request signed in
+    #[serde(default)]
+    pub except_current: bool,
+}
+
+/// Response for revoking one or all sessions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevokeSessionsResponse {
+    /// Number of sessions revoked
+    pub revoked: u64,
+}
+
 /// User stats response for dashboard
 #[derive(Debug, Serialize)]
 pub struct UserStatsResponse {
@@ -264,3 +305,21 @@ pub struct ProvidersStatusResponse {
     /// List of all available providers with their status
     pub providers: Vec<ProviderStatus>,
 }
+
+/// Capability matrix for one registered provider
+#[derive(Debug, Serialize)]
+pub struct ProviderCapabilityMatrix {
+    /// Provider identifier (e.g., "strava", "synthetic")
+    pub provider: String,
+    /// Human-readable display name (e.g., "Strava", "Synthetic")
+    pub display_name: String,
+    /// Every known capability mapped to whether the provider supports it
+    pub capabilities: BTreeMap<&'static str, bool>,
+}
+
+/// Response for the /api/providers/capabilities endpoint
+#[derive(Debug, Serialize)]
+pub struct ProviderCapabilitiesResponse {
+    /// Capability matrix per registered provider, ordered by provider name
+    pub providers: Vec<ProviderCapabilityMatrix>,
+}
@@ -1158,6 +1158,9 @@ impl CoachesRoutes {
                 "/coaches/:id/assignments",
                 get(Self::handle_admin_list_assignments),
             )
+            .route("/coach-context", get(Self::handle_admin_get_context))
+            .route("/coach-context", put(Self::handle_admin_set_context))
+            .route("/coach-context", delete(Self::handle_admin_delete_context))
             // Store management routes (ASY-228)
             .route("/store/stats", get(Self::handle_admin_store_stats))
             .route("/store/review-queue", get(Self::handle_admin_review_queue))
@@ -1385,6 +1388,88 @@ impl CoachesRoutes {
         Ok((StatusCode::OK, Json(response)).into_response())
     }
 
+    /// Verify the coach a context is scoped to is a system coach in the tenant
+    async fn verify_context_coach(
+        manager: &CoachesManager,
+        coach_id: Option<&str>,
+        tenant_id: TenantId,
+    ) -> Result<(), AppError> {
+        if let Some(id) = coach_id {
+            manager
+                .get_system_coach(id, tenant_id)
+                .await?
+                .ok_or_else(|| AppError::not_found(format!("System coach {id}")))?;
+        }
+        Ok(())
+    }
+
+    /// Handle GET /admin/coach-context - Get the stored coach context
+    ///
+    /// With `coach_id`, falls back to the tenant-wide context when the coach has none.
+    async fn handle_admin_get_context(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Query(query): Query<CoachContextQuery>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+        require_admin(auth.user_id, &resources.database).await?;
+        let tenant_id = Self::get_user_tenant(&auth, &resources).await?;
+
+        let manager = Self::get_coaches_manager(&resources)?;
+        let context = manager
+            .get_coach_context(tenant_id, query.coach_id.as_deref())
+            .await?
+            .ok_or_else(|| AppError::not_found("Coach context"))?;
+
+        Ok((StatusCode::OK, Json(context)).into_response())
+    }
+
+    /// Handle PUT /admin/coach-context - Store the tenant or coach context
+    async fn handle_admin_set_context(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Json(body): Json<SetCoachContextBody>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+        require_admin(auth.user_id, &resources.database).await?;
+        let tenant_id = Self::get_user_tenant(&auth, &resources).await?;
+
+        let manager = Self::get_coaches_manager(&resources)?;
+        Self::verify_context_coach(&manager, body.coach_id.as_deref(), tenant_id).await?;
+
+        let context = manager
+            .set_coach_context(
+                tenant_id,
+                body.coach_id.as_deref(),
+                &body.system_context,
+                auth.user_id,
+            )
+            .await?;
+
+        Ok((StatusCode::OK, Json(context)).into_response())
+    }
+
+    /// Handle DELETE /admin/coach-context - Remove the tenant or coach context
+    async fn handle_admin_delete_context(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Query(query): Query<CoachContextQuery>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+        require_admin(auth.user_id, &resources.database).await?;
+        let tenant_id = Self::get_user_tenant(&auth, &resources).await?;
+
+        let manager = Self::get_coaches_manager(&resources)?;
+        if !manager
+            .delete_coach_context(tenant_id, query.coach_id.as_deref())
+            .await?
+        {
+            return Err(AppError::not_found("Coach context"));
+        }
+
+        Ok((StatusCode::NO_CONTENT, ()).into_response())
+    }
+
     // ============================================
     // Admin Store Management Routes (ASY-228)
     // ============================================
@@ -1631,6 +1716,22 @@ impl From<AdminCreateCoachBody> for DbCreateSystemCoachRequest {
     }
 }
 
+/// Query parameters selecting a coach context scope
+#[derive(Debug, Deserialize, Default)]
+pub struct CoachContextQuery {
+    /// System coach the context applies to; omit for the tenant-wide context
+    pub coach_id: Option<String>,
+}
+
+/// Request body for storing a coach context
+#[derive(Debug, Deserialize)]
+pub struct SetCoachContextBody {
+    /// Template with `{{athlete_name}}`, `{{ctl}}`, `{{atl}}` or `{{tsb}}` placeholders
+    pub system_context: String,
+    /// System coach the context applies to; omit for the tenant-wide context
+    pub coach_id: Option<String>,
+}
+
 /// Request body for assigning/unassigning coaches
 #[derive(Debug, Deserialize)]
 pub struct AssignCoachBody {
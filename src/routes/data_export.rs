@@ -0,0 +1,134 @@
+// ABOUTME: User data export route for GDPR portability requests
+// ABOUTME: Streams the authenticated user's data archive as a downloadable JSON attachment
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! User data export routes
+//!
+//! - `GET /api/users/me/export` - Download everything stored about the current user
+//!
+//! The archive has the same shape as the `export_user_data` MCP tool result.
+//! Every export is recorded as a `DataExported` audit event.
+
+use crate::{
+    auth::AuthResult,
+    database_plugins::DatabaseProvider,
+    errors::AppError,
+    mcp::resources::ServerResources,
+    models::TenantId,
+    security::cookies::get_cookie_value,
+    services::data_export::{export_user_data, ExportOptions, MAX_EXPORT_ACTIVITIES},
+};
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Query parameters for the export endpoint
+#[derive(Debug, Default, Deserialize)]
+struct ExportQuery {
+    /// Days of activity history to include
+    activity_days: Option<u32>,
+    /// Maximum activities per provider
+    max_activities: Option<usize>,
+}
+
+/// User data export routes
+pub struct DataExportRoutes;
+
+impl DataExportRoutes {
+    /// Create all data export routes
+    pub fn routes(resources: Arc<ServerResources>) -> Router {
+        Router::new()
+            .route("/api/users/me/export", get(Self::handle_export))
+            .with_state(resources)
+    }
+
+    /// Extract and authenticate user from authorization header or cookie
+    async fn authenticate(
+        headers: &HeaderMap,
+        resources: &Arc<ServerResources>,
+    ) -> Result<AuthResult, AppError> {
+        let auth_value =
+            if let Some(auth_header) = headers.get("authorization").and_then(|h| h.to_str().ok()) {
+                auth_header.to_owned()
+            } else if let Some(token) = get_cookie_value(headers, "auth_token") {
+                format!("Bearer {token}")
+            } else {
+                return Err(AppError::auth_invalid(
+                    "Missing authorization header or cookie",
+                ));
+            };
+
+        resources
+            .auth_middleware
+            .authenticate_request(Some(&auth_value))
+            .await
+            .map_err(|e| AppError::auth_invalid(format!("Authentication failed: {e}")))
+    }
+
+    /// Resolve the tenant to export from, preferring the JWT's active tenant
+    async fn get_user_tenant(
+        auth: &AuthResult,
+        resources: &Arc<ServerResources>,
+    ) -> Result<TenantId, AppError> {
+        if let Some(tenant_id) = auth.active_tenant_id {
+            return Ok(TenantId::from(tenant_id));
+        }
+        let tenants = resources
+            .database
+            .list_tenants_for_user(auth.user_id)
+            .await?;
+        tenants.first().map(|t| t.id).ok_or_else(|| {
+            AppError::invalid_input(format!("User {} has no tenant assigned", auth.user_id))
+        })
+    }
+
+    /// Handle GET /api/users/me/export
+    async fn handle_export(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Query(query): Query<ExportQuery>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+        let tenant_id = Self::get_user_tenant(&auth, &resources).await?;
+
+        let defaults = ExportOptions::default();
+        let options = ExportOptions {
+            activity_days: query.activity_days.unwrap_or(defaults.activity_days),
+            max_activities: query
+                .max_activities
+                .unwrap_or(defaults.max_activities)
+                .clamp(1, MAX_EXPORT_ACTIVITIES),
+        };
+
+        let export = export_user_data(&resources, auth.user_id, tenant_id, &options, None).await?;
+        let body = serde_json::to_vec_pretty(&export)?;
+        let filename = format!(
+            "pierre-export-{}-{}.json",
+            auth.user_id,
+            export.exported_at.format("%Y%m%d")
+        );
+
+        Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/json".to_owned()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{filename}\""),
+                ),
+                (header::CACHE_CONTROL, "no-store".to_owned()),
+            ],
+            Body::from(body),
+        )
+            .into_response())
+    }
+}
@@ -0,0 +1,38 @@
+// ABOUTME: Prometheus scrape endpoint serving the in-process metrics registry
+// ABOUTME: Renders counters and histograms in the text exposition format at /metrics
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Prometheus metrics route
+//!
+//! Like the health endpoints, `/metrics` is unauthenticated so a scraper can
+//! reach it; deployments should restrict it to the monitoring network.
+
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+
+use crate::metrics::MetricsRegistry;
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Metrics routes implementation
+pub struct MetricsRoutes;
+
+impl MetricsRoutes {
+    /// Create the `/metrics` scrape route
+    pub fn routes() -> Router {
+        Router::new().route("/metrics", get(Self::handle_metrics))
+    }
+
+    /// Render the global registry for a Prometheus scrape
+    async fn handle_metrics() -> impl IntoResponse {
+        (
+            [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+            MetricsRegistry::global().render(),
+        )
+    }
+}
@@ -20,6 +20,9 @@
 /// Health check and system status routes
 pub mod health;
 
+/// Prometheus metrics scrape route
+pub mod metrics;
+
 // ═══════════════════════════════════════════════════════════════
 // PROTOCOL FEATURES
 // ═══════════════════════════════════════════════════════════════
@@ -36,6 +39,10 @@ pub mod mcp;
 #[cfg(feature = "protocol-rest")]
 pub mod auth;
 
+/// Strava push subscription callback (verification challenge and events)
+#[cfg(feature = "provider-strava")]
+pub mod strava_webhooks;
+
 // ═══════════════════════════════════════════════════════════════
 // TRANSPORT FEATURES
 // ═══════════════════════════════════════════════════════════════
@@ -68,6 +75,14 @@ pub mod configuration;
 #[cfg(feature = "client-settings")]
 pub mod fitness;
 
+/// User data export routes for portability requests
+#[cfg(feature = "client-settings")]
+pub mod data_export;
+
+/// GPX and TCX activity file import routes
+#[cfg(feature = "client-settings")]
+pub mod activity_import;
+
 /// Chat conversation routes for AI assistants
 #[cfg(feature = "client-chat")]
 pub mod chat;
@@ -145,6 +160,9 @@ pub mod openapi;
 /// Health check route handlers
 pub use health::HealthRoutes;
 
+/// Prometheus metrics route handlers
+pub use metrics::MetricsRoutes;
+
 // Protocol re-exports
 #[cfg(feature = "protocol-a2a")]
 pub use a2a::A2ARoutes;
@@ -162,6 +180,9 @@ pub use auth::{
 #[cfg(feature = "protocol-rest")]
 pub use crate::auth::SetupStatusResponse;
 
+#[cfg(feature = "provider-strava")]
+pub use strava_webhooks::StravaWebhookRoutes;
+
 // Transport re-exports
 #[cfg(feature = "transport-websocket")]
 pub use websocket::WebSocketRoutes;
@@ -180,6 +201,12 @@ pub use configuration::ConfigurationRoutes;
 #[cfg(feature = "client-settings")]
 pub use fitness::FitnessConfigurationRoutes;
 
+#[cfg(feature = "client-settings")]
+pub use data_export::DataExportRoutes;
+
+#[cfg(feature = "client-settings")]
+pub use activity_import::ActivityImportRoutes;
+
 #[cfg(feature = "client-chat")]
 pub use chat::ChatRoutes;
 
@@ -19,11 +19,12 @@ use crate::{
         client_registration::ClientRegistrationManager,
         endpoints::OAuth2AuthorizationServer,
         models::{
-            AuthorizeRequest, ClientRegistrationRequest, OAuth2Error, TokenRequest,
-            ValidateRefreshRequest,
+            AuthorizeRequest, ClientRegistrationRequest, IntrospectionRequest, OAuth2Error,
+            RevocationRequest, TokenRequest, ValidateRefreshRequest,
         },
         rate_limiting::OAuth2RateLimiter,
     },
+    security::cookies::oauth_session_cookie,
     utils::html::escape_html_attribute,
 };
 use axum::{
@@ -100,6 +101,10 @@ impl OAuth2Routes {
             .route("/oauth2/authorize", get(Self::handle_authorization))
             // OAuth 2.0 Token endpoint
             .route("/oauth2/token", post(Self::handle_token))
+            // RFC 7662: Token Introspection
+            .route("/oauth2/introspect", post(Self::handle_introspect))
+            // RFC 7009: Token Revocation
+            .route("/oauth2/revoke", post(Self::handle_revoke))
             // Login page and submission
             .route("/oauth2/login", get(Self::handle_oauth_login_page))
             .route("/oauth2/login", post(Self::handle_oauth_login_submit))
@@ -118,12 +123,20 @@ impl OAuth2Routes {
     async fn handle_discovery(State(context): State<OAuth2Context>) -> Json<serde_json::Value> {
         let issuer_url = context.config.oauth2_server.issuer_url.clone();
 
+        let code_challenge_methods = if context.config.oauth2_server.require_s256 {
+            vec!["S256"]
+        } else {
+            vec!["S256", "plain"]
+        };
+
         // Use spawn_blocking for JSON serialization (CPU-bound operation)
         let discovery_json = spawn_blocking(move || {
             serde_json::json!({
                 "issuer": issuer_url,
                 "authorization_endpoint": format!("{issuer_url}/oauth2/authorize"),
                 "token_endpoint": format!("{issuer_url}/oauth2/token"),
+                "introspection_endpoint": format!("{issuer_url}/oauth2/introspect"),
+                "revocation_endpoint": format!("{issuer_url}/oauth2/revoke"),
                 "registration_endpoint": format!("{issuer_url}/oauth2/register"),
                 "jwks_uri": format!("{issuer_url}/.well-known/jwks.json"),
                 "grant_types_supported": ["authorization_code", "client_credentials", "refresh_token"],
@@ -131,7 +144,7 @@ impl OAuth2Routes {
                 "token_endpoint_auth_methods_supported": ["client_secret_post"],
                 "scopes_supported": ["fitness:read", "activities:read", "profile:read"],
                 "response_modes_supported": ["query"],
-                "code_challenge_methods_supported": ["S256"]
+                "code_challenge_methods_supported": code_challenge_methods
             })
         })
         .await
@@ -277,7 +290,8 @@ impl OAuth2Routes {
             context.database.clone(),
             context.auth_manager.clone(),
             context.jwks_manager.clone(),
-        );
+        )
+        .with_require_s256(context.config.oauth2_server.require_s256);
 
         match auth_server
             .authorize(request, Some(authenticated_user_id), tenant_id)
@@ -325,7 +339,9 @@ impl OAuth2Routes {
         // Extract client IP from connection using Axum's ConnectInfo extractor
         let client_ip = addr.ip();
 
-        if let Some(rate_limit_response) = Self::check_token_rate_limit(&context, client_ip) {
+        if let Some(rate_limit_response) =
+            Self::check_endpoint_rate_limit(&context, "token", client_ip)
+        {
             return rate_limit_response;
         }
 
@@ -338,13 +354,18 @@ impl OAuth2Routes {
             context.database,
             context.auth_manager,
             context.jwks_manager,
-        );
+        )
+        .with_require_s256(context.config.oauth2_server.require_s256);
 
         Self::execute_token_exchange(auth_server, request, &form).await
     }
 
-    fn check_token_rate_limit(context: &OAuth2Context, client_ip: IpAddr) -> Option<Response> {
-        let rate_status = context.rate_limiter.check_rate_limit("token", client_ip);
+    fn check_endpoint_rate_limit(
+        context: &OAuth2Context,
+        endpoint: &str,
+        client_ip: IpAddr,
+    ) -> Option<Response> {
+        let rate_status = context.rate_limiter.check_rate_limit(endpoint, client_ip);
 
         if rate_status.is_limited {
             Some(
@@ -362,6 +383,73 @@ impl OAuth2Routes {
         }
     }
 
+    /// Handle token introspection request (POST /oauth2/introspect, RFC 7662)
+    async fn handle_introspect(
+        State(context): State<OAuth2Context>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> Response {
+        if let Some(rate_limit_response) =
+            Self::check_endpoint_rate_limit(&context, "introspect", addr.ip())
+        {
+            return rate_limit_response;
+        }
+
+        let request = match Self::parse_introspection_request(&form) {
+            Ok(req) => req,
+            Err(error) => return (StatusCode::BAD_REQUEST, Json(error)).into_response(),
+        };
+
+        let auth_server = OAuth2AuthorizationServer::new(
+            context.database,
+            context.auth_manager,
+            context.jwks_manager,
+        );
+
+        match auth_server.introspect(request).await {
+            Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+            // RFC 7662 Section 2.3: failed client authentication is a 401
+            Err(error) => (StatusCode::UNAUTHORIZED, Json(error)).into_response(),
+        }
+    }
+
+    /// Handle token revocation request (POST /oauth2/revoke, RFC 7009)
+    async fn handle_revoke(
+        State(context): State<OAuth2Context>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> Response {
+        // Revocation shares the token endpoint's rate limit
+        if let Some(rate_limit_response) =
+            Self::check_endpoint_rate_limit(&context, "token", addr.ip())
+        {
+            return rate_limit_response;
+        }
+
+        let request = match Self::parse_revocation_request(&form) {
+            Ok(req) => req,
+            Err(error) => return (StatusCode::BAD_REQUEST, Json(error)).into_response(),
+        };
+
+        let auth_server = OAuth2AuthorizationServer::new(
+            context.database,
+            context.auth_manager,
+            context.jwks_manager,
+        );
+
+        match auth_server
+            .revoke(request, Some(addr.ip().to_string()))
+            .await
+        {
+            // RFC 7009 Section 2.2: 200 with an empty body, even for unknown tokens
+            Ok(_) => StatusCode::OK.into_response(),
+            Err(error) if error.error == "invalid_client" => {
+                (StatusCode::UNAUTHORIZED, Json(error)).into_response()
+            }
+            Err(error) => (StatusCode::BAD_REQUEST, Json(error)).into_response(),
+        }
+    }
+
     fn parse_and_log_token_request(
         form: &HashMap<String, String>,
     ) -> Result<TokenRequest, OAuth2Error> {
@@ -693,22 +781,10 @@ impl OAuth2Routes {
                 );
 
                 // Set session cookie and redirect to authorization endpoint
-                // Cookie security: HttpOnly prevents XSS, Secure enforces HTTPS, SameSite=Lax prevents CSRF
-                // Max-Age matches JWT expiration (24 hours = 86400 seconds)
-                // Only set Secure flag when issuer URL uses HTTPS (allows HTTP in development)
-                let secure_flag = if context
-                    .config
-                    .oauth2_server
-                    .issuer_url
-                    .starts_with("https://")
-                {
-                    "; Secure"
-                } else {
-                    ""
-                };
-                let cookie_header = format!(
-                    "pierre_session={token}; HttpOnly{secure_flag}; Path=/; SameSite=Lax; Max-Age=86400"
-                );
+                // HttpOnly prevents XSS; Secure, SameSite, Domain, Path and Max-Age
+                // come from the SESSION_COOKIE_* configuration
+                let cookie_header =
+                    oauth_session_cookie(&token, &context.config.sse.session_cookie);
 
                 (
                     StatusCode::FOUND,
@@ -1142,6 +1218,60 @@ impl OAuth2Routes {
         })
     }
 
+    /// Parse form data into `IntrospectionRequest`
+    fn parse_introspection_request(
+        form: &HashMap<String, String>,
+    ) -> Result<IntrospectionRequest, OAuth2Error> {
+        let token = form
+            .get("token")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing token parameter"))?
+            .clone(); // Safe: String ownership for OAuth2 request struct
+
+        let client_id = form
+            .get("client_id")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing client_id parameter"))?
+            .clone(); // Safe: String ownership for OAuth validation
+
+        let client_secret = form
+            .get("client_secret")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing client_secret parameter"))?
+            .replace(' ', "+");
+
+        Ok(IntrospectionRequest {
+            token,
+            token_type_hint: form.get("token_type_hint").cloned(),
+            client_id,
+            client_secret,
+        })
+    }
+
+    /// Parse form data into `RevocationRequest`
+    fn parse_revocation_request(
+        form: &HashMap<String, String>,
+    ) -> Result<RevocationRequest, OAuth2Error> {
+        let token = form
+            .get("token")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing token parameter"))?
+            .clone(); // Safe: String ownership for OAuth2 request struct
+
+        let client_id = form
+            .get("client_id")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing client_id parameter"))?
+            .clone(); // Safe: String ownership for OAuth validation
+
+        let client_secret = form
+            .get("client_secret")
+            .ok_or_else(|| OAuth2Error::invalid_request("Missing client_secret parameter"))?
+            .replace(' ', "+");
+
+        Ok(RevocationRequest {
+            token,
+            token_type_hint: form.get("token_type_hint").cloned(),
+            client_id,
+            client_secret,
+        })
+    }
+
     /// Authenticate user credentials using `AuthManager` (proper architecture)
     async fn authenticate_user_with_auth_manager(
         database: Arc<Database>,
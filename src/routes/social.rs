@@ -48,8 +48,9 @@ use crate::{
         Activity, AdaptedInsight, FriendConnection, FriendStatus, InsightReaction, InsightType,
         ReactionType, ShareVisibility, SharedInsight, TenantId, TrainingPhase, UserSocialSettings,
     },
+    pagination::Cursor,
     protocols::universal::auth_service::AuthService,
-    security::cookies::get_cookie_value,
+    security::{cookies::get_cookie_value, cursor_signing::CursorScope},
     services::social_insights,
 };
 
@@ -689,8 +690,8 @@ pub struct ListInsightsQuery {
     pub insight_type: Option<String>,
     /// Maximum results
     pub limit: Option<i64>,
-    /// Offset for pagination
-    pub offset: Option<i64>,
+    /// Signed cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
 }
 
 /// Query parameters for user search
@@ -709,8 +710,8 @@ pub struct SearchUsersQuery {
 pub struct FeedQuery {
     /// Maximum results
     pub limit: Option<i64>,
-    /// Offset for pagination
-    pub offset: Option<i64>,
+    /// Signed cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
 }
 
 /// Query parameters for insight suggestions
@@ -735,8 +736,8 @@ pub struct SuggestionsQuery {
 pub struct ListFriendsQuery {
     /// Maximum results
     pub limit: Option<i64>,
-    /// Offset for pagination
-    pub offset: Option<i64>,
+    /// Signed cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
 }
 
 /// Query parameters for listing adapted insights
@@ -745,8 +746,8 @@ pub struct ListFriendsQuery {
 pub struct ListAdaptedQuery {
     /// Maximum results
     pub limit: Option<i64>,
-    /// Offset for pagination
-    pub offset: Option<i64>,
+    /// Signed cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
 }
 
 // ============================================================================
@@ -869,6 +870,42 @@ impl SocialRoutes {
         Ok(SocialManager::new(pool.clone()))
     }
 
+    /// Verify a page cursor issued for `scope` and return the offset it holds
+    ///
+    /// A missing cursor starts at the first page.
+    fn page_offset(
+        resources: &ServerResources,
+        cursor: Option<&str>,
+        scope: &CursorScope,
+    ) -> Result<i64, AppError> {
+        resources
+            .cursor_signer
+            .verify_param(cursor, scope)?
+            .map_or(Ok(0), |cursor| {
+                cursor
+                    .as_str()
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|offset| *offset >= 0)
+                    .ok_or_else(|| AppError::invalid_cursor("Invalid cursor format"))
+            })
+    }
+
+    /// Signed cursor for the page starting at `offset`, if one follows
+    fn next_page_cursor(
+        resources: &ServerResources,
+        has_more: bool,
+        offset: i64,
+        scope: &CursorScope,
+    ) -> Option<String> {
+        has_more.then(|| {
+            resources
+                .cursor_signer
+                .sign(&Cursor::from_string(offset.to_string()), scope)
+                .to_string()
+        })
+    }
+
     /// Get LLM provider from resources or create from environment
     ///
     /// Uses injected provider if available (for testing), otherwise falls back to
@@ -947,7 +984,8 @@ impl SocialRoutes {
         let social = Self::get_social_manager(&resources)?;
 
         let limit = query.limit.unwrap_or(50).clamp(1, 100);
-        let offset = query.offset.unwrap_or(0).max(0);
+        let scope = CursorScope::new("social.friends").for_user(auth.user_id);
+        let offset = Self::page_offset(&resources, query.cursor.as_deref(), &scope)?;
 
         let friends = social
             .get_friends_paginated(auth.user_id, limit, offset)
@@ -957,11 +995,7 @@ impl SocialRoutes {
         #[allow(clippy::cast_possible_truncation)] // limit is clamped to small values
         let limit_usize = limit as usize;
         let has_more = friends.len() >= limit_usize;
-        let next_cursor = if has_more {
-            Some((offset + limit).to_string())
-        } else {
-            None
-        };
+        let next_cursor = Self::next_page_cursor(&resources, has_more, offset + limit, &scope);
 
         // Build response with friend user info
         let mut friends_with_info = Vec::with_capacity(friends.len());
@@ -1295,11 +1329,18 @@ impl SocialRoutes {
 
         let insight_type = query
             .insight_type
-            .map(|t| InsightType::from_str(&t))
+            .as_deref()
+            .map(InsightType::from_str)
             .transpose()?;
 
         let limit = query.limit.unwrap_or(50).clamp(1, 100);
-        let offset = query.offset.unwrap_or(0).max(0);
+        let scope = CursorScope::new("social.insights")
+            .for_user(auth.user_id)
+            .with_query(format!(
+                "insight_type={}",
+                insight_type.as_ref().map_or("", InsightType::as_str)
+            ));
+        let offset = Self::page_offset(&resources, query.cursor.as_deref(), &scope)?;
 
         let insights = social
             .get_user_shared_insights(auth.user_id, insight_type, limit, offset)
@@ -1309,11 +1350,7 @@ impl SocialRoutes {
         #[allow(clippy::cast_possible_truncation)] // limit is clamped to small values
         let limit_usize = limit as usize;
         let has_more = insights.len() >= limit_usize;
-        let next_cursor = if has_more {
-            Some((offset + limit).to_string())
-        } else {
-            None
-        };
+        let next_cursor = Self::next_page_cursor(&resources, has_more, offset + limit, &scope);
 
         let response = ListInsightsResponse {
             total: insights.len(),
@@ -2031,7 +2068,8 @@ impl SocialRoutes {
         let social = Self::get_social_manager(&resources)?;
 
         let limit = query.limit.unwrap_or(50).clamp(1, 100);
-        let offset = query.offset.unwrap_or(0).max(0);
+        let scope = CursorScope::new("social.feed").for_user(auth.user_id);
+        let offset = Self::page_offset(&resources, query.cursor.as_deref(), &scope)?;
 
         // Get full feed items with author, reactions, and user-specific state
         let feed_items = social
@@ -2064,11 +2102,7 @@ impl SocialRoutes {
         #[allow(clippy::cast_possible_truncation)] // limit is clamped to small values
         let limit_usize = limit as usize;
         let has_more = items.len() >= limit_usize;
-        let next_cursor = if has_more {
-            Some((offset + limit).to_string())
-        } else {
-            None
-        };
+        let next_cursor = Self::next_page_cursor(&resources, has_more, offset + limit, &scope);
 
         let response = FeedResponse {
             items,
@@ -2144,7 +2178,8 @@ impl SocialRoutes {
         let social = Self::get_social_manager(&resources)?;
 
         let limit = query.limit.unwrap_or(50).clamp(1, 100);
-        let offset = query.offset.unwrap_or(0).max(0);
+        let scope = CursorScope::new("social.adapted").for_user(auth.user_id);
+        let offset = Self::page_offset(&resources, query.cursor.as_deref(), &scope)?;
 
         let adapted = social
             .get_user_adapted_insights_paginated(auth.user_id, limit, offset)
@@ -2154,11 +2189,7 @@ impl SocialRoutes {
         #[allow(clippy::cast_possible_truncation)] // limit is clamped to small values
         let limit_usize = limit as usize;
         let has_more = adapted.len() >= limit_usize;
-        let next_cursor = if has_more {
-            Some((offset + limit).to_string())
-        } else {
-            None
-        };
+        let next_cursor = Self::next_page_cursor(&resources, has_more, offset + limit, &scope);
 
         let response = ListAdaptedInsightsResponse {
             total: adapted.len(),
@@ -30,8 +30,8 @@ use crate::{
     errors::AppError,
     mcp::resources::ServerResources,
     models::TenantId,
-    pagination::StoreSortOrder,
-    security::cookies::get_cookie_value,
+    pagination::{Cursor, StoreSortOrder},
+    security::{cookies::get_cookie_value, cursor_signing::CursorScope},
 };
 
 /// Query parameters for browsing published coaches
@@ -295,9 +295,26 @@ impl StoreRoutes {
             .map_or(StoreSortOrder::Newest, StoreSortOrder::parse);
         let limit = query.limit.unwrap_or(20).clamp(1, 100);
 
+        // Cursors are only valid for the same user, filter, and sort order
+        let scope = CursorScope::new("store.coaches")
+            .for_user(auth.user_id)
+            .with_query(format!(
+                "category={}&sort_by={}",
+                category.as_ref().map_or("", CoachCategory::as_str),
+                sort_by.as_str()
+            ));
+        let cursor = resources
+            .cursor_signer
+            .verify_param(query.cursor.as_deref(), &scope)?;
+
         // Use cursor-based pagination for efficient infinite scrolling
         let page = manager
-            .get_published_coaches_cursor(category, sort_by, limit, query.cursor.as_deref())
+            .get_published_coaches_cursor(
+                category,
+                sort_by,
+                limit,
+                cursor.as_ref().map(Cursor::as_str),
+            )
             .await?;
 
         let store_coaches: Vec<StoreCoach> = page.items.into_iter().map(StoreCoach::from).collect();
@@ -313,7 +330,9 @@ impl StoreRoutes {
 
         let response = BrowseCoachesResponse {
             coaches: store_coaches,
-            next_cursor: page.next_cursor.map(|c| c.to_string()),
+            next_cursor: page
+                .next_cursor
+                .map(|c| resources.cursor_signer.sign(&c, &scope).to_string()),
             has_more: page.has_more,
             metadata: Self::build_metadata(),
         };
@@ -0,0 +1,123 @@
+// ABOUTME: Strava push subscription callback answering verification challenges and receiving events
+// ABOUTME: Checks the subscription ID, acknowledges at once, and applies the event in the background
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Strava webhook routes
+//!
+//! - `GET /api/webhooks/strava` - Answer Strava's `hub.challenge` when a
+//!   subscription is registered
+//! - `POST /api/webhooks/strava` - Receive an activity or athlete event
+//!
+//! Strava expects events to be acknowledged within two seconds, so after the
+//! subscription ID is checked the event is processed in a background task.
+//! Events are rate-limited per source IP.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use crate::config::StravaWebhookConfig;
+use crate::errors::{AppError, AppResult};
+use crate::mcp::resources::ServerResources;
+use crate::oauth2_server::rate_limiting::OAuth2RateLimiter;
+use crate::services::strava_webhooks::{
+    verify_challenge, StravaChallengeQuery, StravaWebhookEvent, StravaWebhookProcessor,
+};
+
+/// Path Strava calls for challenges and events
+pub const STRAVA_WEBHOOK_PATH: &str = "/api/webhooks/strava";
+
+/// Rate limiter key for incoming events
+const RATE_LIMIT_ENDPOINT: &str = "strava_webhook";
+
+#[derive(Clone)]
+struct StravaWebhookState {
+    resources: Arc<ServerResources>,
+    config: Arc<StravaWebhookConfig>,
+    rate_limiter: Arc<OAuth2RateLimiter>,
+}
+
+/// Strava webhook routes
+pub struct StravaWebhookRoutes;
+
+impl StravaWebhookRoutes {
+    /// Create the Strava webhook routes configured from the environment
+    pub fn routes(resources: Arc<ServerResources>) -> Router {
+        Self::routes_with_config(resources, StravaWebhookConfig::from_env())
+    }
+
+    /// Create the Strava webhook routes with an explicit configuration
+    pub fn routes_with_config(
+        resources: Arc<ServerResources>,
+        config: StravaWebhookConfig,
+    ) -> Router {
+        let rate_limiter = Arc::new(OAuth2RateLimiter::from_rate_limit_config(
+            resources.config.rate_limiting.clone(),
+        ));
+        let state = StravaWebhookState {
+            resources,
+            config: Arc::new(config),
+            rate_limiter,
+        };
+        Router::new()
+            .route(
+                STRAVA_WEBHOOK_PATH,
+                get(Self::handle_challenge).post(Self::handle_event),
+            )
+            .with_state(state)
+    }
+
+    /// Echo the challenge back when the verify token matches
+    async fn handle_challenge(
+        State(state): State<StravaWebhookState>,
+        Query(query): Query<StravaChallengeQuery>,
+    ) -> AppResult<Json<Value>> {
+        let Some(verify_token) = state.config.verify_token.as_deref() else {
+            return Err(AppError::not_found("Strava webhook subscription"));
+        };
+        Ok(Json(verify_challenge(&query, verify_token)?))
+    }
+
+    /// Acknowledge an event from a known subscription and process it in the background
+    async fn handle_event(
+        State(state): State<StravaWebhookState>,
+        connect_info: Option<ConnectInfo<SocketAddr>>,
+        Json(event): Json<StravaWebhookEvent>,
+    ) -> AppResult<StatusCode> {
+        let client_ip = connect_info.map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |info| info.0.ip());
+        let rate_status = state
+            .rate_limiter
+            .check_rate_limit(RATE_LIMIT_ENDPOINT, client_ip);
+        if rate_status.is_limited {
+            return Err(AppError::rate_limit_exceeded(rate_status.limit));
+        }
+
+        let processor = StravaWebhookProcessor::new(state.resources);
+        processor.verify_subscription(&event).await?;
+
+        tokio::spawn(async move {
+            match processor.process_with_stored_tokens(&event).await {
+                Ok(outcome) => debug!(
+                    "Processed Strava {:?} {:?} event for object {}: {:?}",
+                    event.aspect_type, event.object_type, event.object_id, outcome
+                ),
+                Err(e) => warn!(
+                    "Failed to process Strava {:?} event for object {}: {}",
+                    event.aspect_type, event.object_id, e
+                ),
+            }
+        });
+
+        Ok(StatusCode::OK)
+    }
+}
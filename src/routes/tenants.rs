@@ -18,7 +18,7 @@ use crate::{
     mcp::resources::ServerResources, models::TenantId, tenant_routes,
 };
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
@@ -85,6 +85,14 @@ impl TenantRoutes {
             .route("/tenants", get(Self::handle_list_tenants))
             .route("/tenants/switch", post(Self::handle_switch_tenant))
             .route("/tenants/my", get(Self::handle_list_my_tenants))
+            .route(
+                "/tenants/:tenant_id/tool-usage",
+                get(Self::handle_get_tenant_tool_usage),
+            )
+            .route(
+                "/tenants/:tenant_id/oauth/:provider/rotate-secret",
+                post(Self::handle_rotate_oauth_secret),
+            )
             .with_state(resources)
     }
 
@@ -131,6 +139,48 @@ impl TenantRoutes {
         Ok((StatusCode::OK, Json(response)).into_response())
     }
 
+    /// Handle tenant-wide tool usage analytics (owners and admins only)
+    async fn handle_get_tenant_tool_usage(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Path(tenant_id): Path<String>,
+        Query(query): Query<tenant_routes::TenantToolUsageQuery>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+
+        let response = tenant_routes::get_tenant_tool_usage(
+            tenant_id,
+            query,
+            auth,
+            resources.database.clone(),
+        )
+        .await?;
+
+        Ok((StatusCode::OK, Json(response)).into_response())
+    }
+
+    /// Handle rotating a tenant's OAuth client secret (owners only)
+    async fn handle_rotate_oauth_secret(
+        State(resources): State<Arc<ServerResources>>,
+        headers: HeaderMap,
+        Path((tenant_id, provider)): Path<(String, String)>,
+        Json(request): Json<tenant_routes::RotateTenantOAuthSecretRequest>,
+    ) -> Result<Response, AppError> {
+        let auth = Self::authenticate(&headers, &resources).await?;
+
+        let response = tenant_routes::rotate_tenant_oauth_secret(
+            tenant_id,
+            provider,
+            request,
+            auth,
+            resources.database.clone(),
+            &resources.tenant_oauth_client,
+        )
+        .await?;
+
+        Ok((StatusCode::OK, Json(response)).into_response())
+    }
+
     /// Handle switching active tenant
     ///
     /// Validates that the user belongs to the target tenant, then returns a new JWT
@@ -142,6 +192,13 @@ impl TenantRoutes {
     ) -> Result<Response, AppError> {
         let auth = Self::authenticate(&headers, &resources).await?;
 
+        // Switching mints a regular token, which would outlive the impersonation token
+        if auth.auth_method.impersonated_by().is_some() {
+            return Err(AppError::auth_invalid(
+                "Impersonation tokens cannot switch tenants",
+            ));
+        }
+
         info!(
             user_id = %auth.user_id,
             target_tenant = %request.tenant_id,
@@ -15,13 +15,14 @@
 use crate::database_plugins::factory::Database;
 use crate::database_plugins::DatabaseProvider;
 use crate::errors::AppResult;
+use crate::middleware::current_request_id;
 use pierre_core::models::TenantId;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 // Re-export DTOs from pierre-core (canonical definitions)
-pub use pierre_core::models::{AuditEvent, AuditEventType, AuditSeverity};
+pub use pierre_core::models::{AuditEvent, AuditEventFilter, AuditEventType, AuditSeverity};
 
 /// Audit logger for security events
 pub struct SecurityAuditor {
@@ -107,7 +108,12 @@ impl SecurityAuditor {
     /// # Errors
     ///
     /// Returns an error if the audit event cannot be stored
-    pub async fn log_event(&self, event: AuditEvent) -> AppResult<()> {
+    pub async fn log_event(&self, mut event: AuditEvent) -> AppResult<()> {
+        // Tie the event to the HTTP request that triggered it
+        if let Some(request_id) = current_request_id() {
+            event = event.with_request_id(request_id.as_str());
+        }
+
         // Log to structured logger first (for immediate visibility)
         Self::log_to_structured_logger(&event);
 
@@ -237,6 +243,97 @@ impl SecurityAuditor {
         self.log_event(event).await
     }
 
+    /// Log OAuth 2.0 token revocation (RFC 7009)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit event cannot be logged
+    pub async fn log_token_revocation(
+        &self,
+        client_id: &str,
+        user_id: Option<Uuid>,
+        tenant_id: Option<TenantId>,
+        token_type: &str, // "access_token" or "refresh_token"
+        access_tokens_revoked: u64,
+        source_ip: Option<String>,
+    ) -> AppResult<()> {
+        let mut event = AuditEvent::new(
+            AuditEventType::TokenRevoked,
+            AuditSeverity::Info,
+            format!("OAuth2 {token_type} revoked by client {client_id}"),
+            "revoke".to_owned(),
+            "success".to_owned(),
+        )
+        .with_resource(format!("oauth2_client:{client_id}"))
+        .with_metadata(serde_json::json!({
+            "client_id": client_id,
+            "token_type": token_type,
+            "access_tokens_revoked": access_tokens_revoked,
+        }));
+
+        if let Some(uid) = user_id {
+            event = event.with_user_id(uid);
+        }
+
+        if let Some(tid) = tenant_id {
+            event = event.with_tenant_id(tid);
+        }
+
+        if let Some(ip) = source_ip {
+            event = event.with_source_ip(ip);
+        }
+
+        self.log_event(event).await
+    }
+
+    /// Log a Strava deauthorization event received by webhook
+    ///
+    /// Confirmed events removed the user's Strava connection; unconfirmed ones
+    /// were ignored because Strava still accepted the stored token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit event cannot be logged
+    pub async fn log_strava_deauthorization(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        athlete_id: i64,
+        confirmed: bool,
+    ) -> AppResult<()> {
+        let event = if confirmed {
+            AuditEvent::new(
+                AuditEventType::OAuthCredentialsDeleted,
+                AuditSeverity::Warning,
+                format!("Strava connection removed after athlete {athlete_id} revoked access"),
+                "deauthorize".to_owned(),
+                "success".to_owned(),
+            )
+        } else {
+            AuditEvent::new(
+                AuditEventType::SecurityPolicyViolation,
+                AuditSeverity::Warning,
+                format!(
+                    "Unconfirmed Strava deauthorization for athlete {athlete_id}: access still granted"
+                ),
+                "deauthorize".to_owned(),
+                "rejected".to_owned(),
+            )
+        };
+
+        let event = event
+            .with_user_id(user_id)
+            .with_tenant_id(tenant_id)
+            .with_resource(format!("oauth_token:{user_id}:strava"))
+            .with_metadata(serde_json::json!({
+                "provider": "strava",
+                "athlete_id": athlete_id,
+                "confirmed": confirmed,
+            }));
+
+        self.log_event(event).await
+    }
+
     /// Log tool execution
     ///
     /// # Errors
@@ -283,6 +380,39 @@ impl SecurityAuditor {
         self.log_event(event).await
     }
 
+    /// Log a user data export
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit event cannot be logged
+    pub async fn log_data_export(
+        &self,
+        user_id: Uuid,
+        tenant_id: TenantId,
+        sections: serde_json::Value,
+        source_ip: Option<String>,
+    ) -> AppResult<()> {
+        let mut event = AuditEvent::new(
+            AuditEventType::DataExported,
+            AuditSeverity::Info,
+            format!("User data exported for user {user_id}"),
+            "export".to_owned(),
+            "success".to_owned(),
+        )
+        .with_user_id(user_id)
+        .with_tenant_id(tenant_id)
+        .with_resource(format!("user:{user_id}"))
+        .with_metadata(serde_json::json!({
+            "sections": sections,
+        }));
+
+        if let Some(ip) = source_ip {
+            event = event.with_source_ip(ip);
+        }
+
+        self.log_event(event).await
+    }
+
     /// Log authentication event
     ///
     /// # Errors
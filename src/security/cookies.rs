@@ -10,8 +10,14 @@
 //! security flags to prevent XSS, CSRF, and session hijacking attacks.
 
 use std::env;
+use std::fmt;
+use std::str::FromStr;
 
 use axum::http::{header, HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::config::SessionCookieConfig;
+use crate::errors::AppError;
 
 /// Cookie security configuration
 pub struct SecureCookieConfig {
@@ -29,10 +35,13 @@ pub struct SecureCookieConfig {
     pub same_site: SameSitePolicy,
     /// Cookie path
     pub path: String,
+    /// Cookie domain (`None` restricts the cookie to the host that set it)
+    pub domain: Option<String>,
 }
 
 /// `SameSite` cookie policy
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SameSitePolicy {
     /// Strict: Cookie only sent in first-party context
     Strict,
@@ -42,6 +51,39 @@ pub enum SameSitePolicy {
     None,
 }
 
+impl SameSitePolicy {
+    /// Attribute value as written in the `Set-Cookie` header
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Strict => "Strict",
+            Self::Lax => "Lax",
+            Self::None => "None",
+        }
+    }
+}
+
+impl fmt::Display for SameSitePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SameSitePolicy {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "lax" => Ok(Self::Lax),
+            "none" => Ok(Self::None),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid SameSite policy: {s} (expected strict, lax, or none)"
+            ))),
+        }
+    }
+}
+
 impl SecureCookieConfig {
     /// Create a new secure cookie configuration with defaults
     ///
@@ -60,6 +102,29 @@ impl SecureCookieConfig {
             secure,
             same_site: SameSitePolicy::Strict,
             path: "/".to_owned(),
+            domain: None,
+        }
+    }
+
+    /// Create a session cookie with the configured `Secure`, `SameSite`, domain and path
+    ///
+    /// Session cookies are always `HttpOnly`.
+    #[must_use]
+    pub fn session(
+        name: String,
+        value: String,
+        max_age_secs: i64,
+        config: &SessionCookieConfig,
+    ) -> Self {
+        Self {
+            name,
+            value,
+            max_age_secs,
+            http_only: true,
+            secure: config.secure,
+            same_site: config.same_site,
+            path: config.path.clone(),
+            domain: config.domain.clone(),
         }
     }
 
@@ -75,6 +140,11 @@ impl SecureCookieConfig {
         // Path
         let _ = write!(cookie, "; Path={}", self.path);
 
+        // Domain
+        if let Some(domain) = &self.domain {
+            let _ = write!(cookie, "; Domain={domain}");
+        }
+
         // HttpOnly
         if self.http_only {
             cookie.push_str("; HttpOnly");
@@ -86,11 +156,7 @@ impl SecureCookieConfig {
         }
 
         // SameSite
-        match self.same_site {
-            SameSitePolicy::Strict => cookie.push_str("; SameSite=Strict"),
-            SameSitePolicy::Lax => cookie.push_str("; SameSite=Lax"),
-            SameSitePolicy::None => cookie.push_str("; SameSite=None"),
-        }
+        let _ = write!(cookie, "; SameSite={}", self.same_site);
 
         cookie
     }
@@ -102,8 +168,19 @@ impl SecureCookieConfig {
 /// * `headers` - HTTP headers to modify
 /// * `token` - JWT token to store in cookie
 /// * `max_age_secs` - Cookie expiration in seconds
-pub fn set_auth_cookie(headers: &mut HeaderMap, token: &str, max_age_secs: i64) {
-    let cookie = SecureCookieConfig::new("auth_token".to_owned(), token.to_owned(), max_age_secs);
+/// * `config` - Configured session cookie attributes
+pub fn set_auth_cookie(
+    headers: &mut HeaderMap,
+    token: &str,
+    max_age_secs: i64,
+    config: &SessionCookieConfig,
+) {
+    let cookie = SecureCookieConfig::session(
+        "auth_token".to_owned(),
+        token.to_owned(),
+        max_age_secs,
+        config,
+    );
 
     if let Ok(header_value) = HeaderValue::from_str(&cookie.build()) {
         headers.insert(header::SET_COOKIE, header_value);
@@ -131,19 +208,37 @@ pub fn set_csrf_cookie(headers: &mut HeaderMap, csrf_token: &str, max_age_secs:
 
 /// Clear authentication cookie
 ///
+/// Browsers only replace a cookie whose domain and path match, so the
+/// expired cookie carries the same configured attributes as the one it clears.
+///
 /// # Arguments
 /// * `headers` - HTTP headers to modify
-pub fn clear_auth_cookie(headers: &mut HeaderMap) {
-    let mut cookie = "auth_token=; Max-Age=0; Path=/; HttpOnly; SameSite=Strict".to_owned();
-    if infer_secure_flag() {
-        cookie.push_str("; Secure");
-    }
+/// * `config` - Configured session cookie attributes
+pub fn clear_auth_cookie(headers: &mut HeaderMap, config: &SessionCookieConfig) {
+    let cookie = SecureCookieConfig::session("auth_token".to_owned(), String::new(), 0, config);
 
-    if let Ok(header_value) = HeaderValue::from_str(&cookie) {
+    if let Ok(header_value) = HeaderValue::from_str(&cookie.build()) {
         headers.insert(header::SET_COOKIE, header_value);
     }
 }
 
+/// Build the `pierre_session` cookie used by the `OAuth2` authorization flow
+///
+/// # Arguments
+/// * `token` - Session JWT to store in the cookie
+/// * `config` - Configured session cookie attributes, including Max-Age
+#[must_use]
+pub fn oauth_session_cookie(token: &str, config: &SessionCookieConfig) -> String {
+    let max_age_secs = i64::try_from(config.max_age_secs).unwrap_or(i64::MAX);
+    SecureCookieConfig::session(
+        "pierre_session".to_owned(),
+        token.to_owned(),
+        max_age_secs,
+        config,
+    )
+    .build()
+}
+
 /// Derive the `Secure` cookie flag from the `BASE_URL` environment variable.
 ///
 /// Returns `true` when `BASE_URL` starts with `https://` or is unset (fail-secure),
@@ -0,0 +1,152 @@
+// ABOUTME: HMAC signing for pagination cursors handed to clients
+// ABOUTME: Binds each cursor to the endpoint, user, and query it was issued for and rejects tampering
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Signed pagination cursors
+//!
+//! Cursors encode positions (timestamps, IDs, offsets) in plain base64, so a
+//! client could forge one to start a page at an arbitrary row. Before a
+//! cursor leaves the server it is signed with a system secret, and the
+//! signature covers the [`CursorScope`] it was issued for. A cursor that was
+//! modified, or that is replayed against another endpoint, user, or query,
+//! fails verification with [`ErrorCode::InvalidCursor`](crate::errors::ErrorCode::InvalidCursor).
+//!
+//! Signed cursors have the form `<cursor>.<signature>`, both parts URL-safe
+//! base64 without padding.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::hmac;
+use uuid::Uuid;
+
+use crate::database_plugins::factory::Database;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::pagination::Cursor;
+
+/// System secret type holding the cursor signing key
+pub const CURSOR_SIGNING_SECRET: &str = "cursor_signing_secret";
+
+/// Prefix separating cursor signatures from other HMACs made with the same key
+const SIGNATURE_DOMAIN: &[u8] = b"pierre-cursor-v1";
+
+/// The query a cursor was issued for
+///
+/// Every field is covered by the signature, so a cursor only verifies
+/// against the same scope it was signed with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorScope {
+    endpoint: String,
+    user_id: Option<Uuid>,
+    query: String,
+}
+
+impl CursorScope {
+    /// Scope for cursors issued by `endpoint` (e.g. `store.coaches`)
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            user_id: None,
+            query: String::new(),
+        }
+    }
+
+    /// Restrict the cursor to the user who requested the page
+    #[must_use]
+    pub const fn for_user(mut self, user_id: Uuid) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Restrict the cursor to the filters of the query (e.g. `category=training`)
+    #[must_use]
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = query.into();
+        self
+    }
+}
+
+/// Signs outgoing cursors and verifies incoming ones
+pub struct CursorSigner {
+    key: hmac::Key,
+}
+
+impl CursorSigner {
+    /// Create a signer using `secret` as the HMAC-SHA256 key
+    #[must_use]
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret),
+        }
+    }
+
+    /// Create a signer from the database's cursor signing secret, generating it on first use
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret cannot be read or stored
+    pub async fn from_database(database: &Database) -> AppResult<Self> {
+        let secret = database
+            .get_or_create_system_secret(CURSOR_SIGNING_SECRET)
+            .await?;
+        Ok(Self::new(secret.as_bytes()))
+    }
+
+    /// Sign `cursor` for use within `scope`
+    #[must_use]
+    pub fn sign(&self, cursor: &Cursor, scope: &CursorScope) -> Cursor {
+        let tag = hmac::sign(&self.key, &Self::message(cursor.as_str(), scope));
+        Cursor::from_string(format!(
+            "{}.{}",
+            cursor.as_str(),
+            URL_SAFE_NO_PAD.encode(tag.as_ref())
+        ))
+    }
+
+    /// Verify a signed cursor received for `scope` and return the cursor it wraps
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidCursor` error if the cursor is malformed, was
+    /// modified, or was signed for a different scope
+    pub fn verify(&self, signed: &Cursor, scope: &CursorScope) -> AppResult<Cursor> {
+        let invalid =
+            || AppError::invalid_cursor("Cursor is invalid or was issued for another query");
+        let (cursor, signature) = signed.as_str().rsplit_once('.').ok_or_else(invalid)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+        hmac::verify(&self.key, &Self::message(cursor, scope), &signature)
+            .map_err(|_| invalid())?;
+        Ok(Cursor::from_string(cursor.to_owned()))
+    }
+
+    /// Verify an optional cursor string taken from a request
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidCursor` error if the cursor fails [`Self::verify`]
+    pub fn verify_param(
+        &self,
+        signed: Option<&str>,
+        scope: &CursorScope,
+    ) -> AppResult<Option<Cursor>> {
+        signed
+            .map(|signed| self.verify(&Cursor::from_string(signed.to_owned()), scope))
+            .transpose()
+    }
+
+    /// Bytes covered by the signature; fields are NUL-separated so they cannot run together
+    fn message(cursor: &str, scope: &CursorScope) -> Vec<u8> {
+        let user = scope.user_id.map(|id| id.to_string()).unwrap_or_default();
+        [
+            SIGNATURE_DOMAIN,
+            scope.endpoint.as_bytes(),
+            user.as_bytes(),
+            scope.query.as_bytes(),
+            cursor.as_bytes(),
+        ]
+        .join(&0u8)
+    }
+}
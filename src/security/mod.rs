@@ -35,6 +35,8 @@ pub mod audit;
 pub mod cookies;
 /// CSRF protection token management
 pub mod csrf;
+/// HMAC-signed pagination cursors bound to the query they were issued for
+pub mod cursor_signing;
 /// Encryption key rotation management
 pub mod key_rotation;
 
@@ -0,0 +1,257 @@
+// ABOUTME: Delta sync returning only activities created, changed, or deleted since a timestamp
+// ABOUTME: Compares provider activities with stored content hashes to detect edits and removals
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Activity Delta Sync
+//!
+//! Providers only filter activities by start time, so [`sync_activity_delta`]
+//! keeps an [`ActivitySyncRecord`] per activity with a hash of its content.
+//! Each sync fetches activities that started up to [`DELTA_LOOKBACK_DAYS`]
+//! before `since` (the provider's `after` filter) and compares them with the
+//! stored records:
+//!
+//! - an activity whose hash changed, or that reappeared, is marked updated now
+//! - an activity not seen before is new now, except when nothing was recorded
+//!   for the window yet (the first sync), where its start time is the only
+//!   evidence of when it was created
+//! - a recorded activity missing from a complete window is marked deleted now
+//!
+//! The result holds every activity whose record changed at or after `since`,
+//! and the IDs of activities deleted at or after `since`. Deletions are best
+//! effort: they are only detected within the lookback window, and not at all
+//! when the window held more than [`MAX_DELTA_ACTIVITIES`] activities.
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::models::{Activity, ActivitySyncRecord, TenantId};
+use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+
+/// Days before `since` re-fetched to catch edits and deletions of recent activities
+pub const DELTA_LOOKBACK_DAYS: i64 = 14;
+
+/// Maximum activities fetched from the provider in one delta sync
+pub const MAX_DELTA_ACTIVITIES: usize = 500;
+
+/// Activities that changed since a timestamp, and the ones that were deleted
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityDelta {
+    /// Provider the activities came from
+    pub provider: String,
+    /// Changes at or after this time are included
+    pub since: DateTime<Utc>,
+    /// Pass as `since` on the next call to continue from this sync
+    pub synced_at: DateTime<Utc>,
+    /// Activities created or updated since `since`, most recent first
+    pub activities: Vec<Activity>,
+    /// IDs of activities deleted since `since`
+    pub deleted_ids: Vec<String>,
+    /// Whether deletions were checked; false when the lookback window was truncated
+    pub deletions_checked: bool,
+}
+
+/// Result of comparing fetched activities with their stored records
+#[derive(Debug, Clone, Default)]
+pub struct ActivityDiff {
+    /// Records that are new or changed and need to be stored
+    pub changed_records: Vec<ActivitySyncRecord>,
+    /// Current record for every activity in the window, keyed by activity ID
+    pub records: HashMap<String, ActivitySyncRecord>,
+}
+
+/// Hash identifying an activity's content
+///
+/// # Errors
+///
+/// Returns an error if the activity cannot be serialized
+pub fn activity_content_hash(activity: &Activity) -> AppResult<String> {
+    let bytes = serde_json::to_vec(activity)?;
+    Ok(format!("{:x}", Sha256::digest(bytes)))
+}
+
+/// Compare activities fetched for a window with the records stored for it
+///
+/// `window_complete` says whether the provider returned every activity in the
+/// window; only then are missing activities marked deleted.
+///
+/// # Errors
+///
+/// Returns an error if an activity cannot be hashed
+pub fn diff_activities(
+    previous: Vec<ActivitySyncRecord>,
+    activities: &[Activity],
+    window_complete: bool,
+    now: DateTime<Utc>,
+) -> AppResult<ActivityDiff> {
+    let first_sync = previous.is_empty();
+    let mut records: HashMap<String, ActivitySyncRecord> = previous
+        .into_iter()
+        .map(|record| (record.activity_id.clone(), record))
+        .collect();
+    let mut changed_records = Vec::new();
+    let mut seen = HashSet::with_capacity(activities.len());
+
+    for activity in activities {
+        let content_hash = activity_content_hash(activity)?;
+        seen.insert(activity.id());
+        let unchanged = records.get(activity.id()).is_some_and(|record| {
+            record.content_hash == content_hash && record.deleted_at.is_none()
+        });
+        if unchanged {
+            continue;
+        }
+
+        let updated_at = if first_sync {
+            activity.start_date()
+        } else {
+            now
+        };
+        let record = ActivitySyncRecord {
+            activity_id: activity.id().to_owned(),
+            start_date: activity.start_date(),
+            content_hash,
+            updated_at,
+            deleted_at: None,
+        };
+        changed_records.push(record.clone());
+        records.insert(record.activity_id.clone(), record);
+    }
+
+    if window_complete {
+        for record in records.values_mut() {
+            if record.deleted_at.is_none() && !seen.contains(record.activity_id.as_str()) {
+                record.deleted_at = Some(now);
+                changed_records.push(record.clone());
+            }
+        }
+    }
+
+    Ok(ActivityDiff {
+        changed_records,
+        records,
+    })
+}
+
+/// Query for the activities an incremental sync of `provider_name` has not seen
+///
+/// The provider's last sync time becomes the `after` bound so only activities
+/// recorded since then are downloaded. Providers that have never synced get an
+/// unbounded query.
+///
+/// # Errors
+///
+/// Returns an error if the last sync time cannot be read
+pub async fn incremental_activity_query<D: DatabaseProvider>(
+    database: &D,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    provider_name: &str,
+    limit: Option<usize>,
+) -> AppResult<ActivityQueryParams> {
+    let last_sync = database
+        .get_provider_last_sync(user_id, tenant_id, provider_name)
+        .await?;
+    Ok(ActivityQueryParams {
+        limit,
+        offset: None,
+        before: None,
+        after: last_sync,
+    })
+}
+
+/// Fetch a provider's activities and return only what changed since `since`
+///
+/// Without `since`, the provider's last sync time is used, and every activity
+/// is returned if the provider has never synced. The stored records and the
+/// provider's last sync time are updated to `now`.
+///
+/// # Errors
+///
+/// Returns an error if the provider fetch or a database operation fails
+pub async fn sync_activity_delta<D: DatabaseProvider>(
+    database: &D,
+    provider: &dyn FitnessProvider,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    since: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> AppResult<ActivityDelta> {
+    let provider_name = provider.name();
+    let mut query = match since {
+        Some(since) => ActivityQueryParams {
+            limit: Some(MAX_DELTA_ACTIVITIES),
+            offset: None,
+            before: None,
+            after: Some(since),
+        },
+        None => {
+            incremental_activity_query(
+                database,
+                user_id,
+                tenant_id,
+                provider_name,
+                Some(MAX_DELTA_ACTIVITIES),
+            )
+            .await?
+        }
+    };
+    let since = query.after.unwrap_or(DateTime::UNIX_EPOCH);
+    let window_start = since - Duration::days(DELTA_LOOKBACK_DAYS);
+    query.after = Some(window_start);
+
+    let activities = provider.get_activities_with_params(&query).await?;
+    let window_complete = activities.len() < MAX_DELTA_ACTIVITIES;
+
+    let previous = database
+        .get_activity_sync_records(user_id, tenant_id, provider_name, window_start)
+        .await?;
+    let diff = diff_activities(previous, &activities, window_complete, now)?;
+    if !diff.changed_records.is_empty() {
+        database
+            .upsert_activity_sync_records(user_id, tenant_id, provider_name, &diff.changed_records)
+            .await?;
+    }
+    database
+        .update_provider_last_sync(user_id, tenant_id, provider_name, now)
+        .await?;
+
+    let mut deleted_ids: Vec<String> = diff
+        .records
+        .values()
+        .filter(|record| {
+            record
+                .deleted_at
+                .is_some_and(|deleted_at| deleted_at >= since)
+        })
+        .map(|record| record.activity_id.clone())
+        .collect();
+    deleted_ids.sort_unstable();
+
+    let mut changed: Vec<Activity> = activities
+        .into_iter()
+        .filter(|activity| {
+            diff.records
+                .get(activity.id())
+                .is_some_and(|record| record.updated_at >= since)
+        })
+        .collect();
+    changed.sort_by_key(|activity| Reverse(activity.start_date()));
+
+    Ok(ActivityDelta {
+        provider: provider_name.to_owned(),
+        since,
+        synced_at: now,
+        activities: changed,
+        deleted_ids,
+        deletions_checked: window_complete,
+    })
+}
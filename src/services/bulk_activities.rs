@@ -0,0 +1,206 @@
+// ABOUTME: Concurrent activity fetch across every provider a user has connected
+// ABOUTME: Fans out with a bounded concurrency limit, merges duplicates, and annotates per-provider failures
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, StreamExt};
+use serde::Serialize;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::mcp::resources::ServerResources;
+use crate::models::{Activity, TenantId};
+use crate::protocols::universal::auth_service::AuthService;
+use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+use crate::providers::utils::{deduplicate_activities_with_config, DeduplicationConfig};
+
+/// Providers queried at the same time when not specified
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+
+/// Activities requested from each provider when not specified
+pub const DEFAULT_BULK_ACTIVITIES_PER_PROVIDER: usize = 100;
+
+/// Time range and limits for a bulk activity fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkActivityQuery {
+    /// Only activities starting at or after this time
+    pub after: Option<DateTime<Utc>>,
+    /// Only activities starting before this time
+    pub before: Option<DateTime<Utc>>,
+    /// Maximum activities requested from each provider
+    pub limit_per_provider: usize,
+    /// Maximum providers queried at the same time
+    pub concurrency: usize,
+}
+
+impl BulkActivityQuery {
+    /// Query for activities in `[after, before)` with default limits
+    #[must_use]
+    pub const fn with_time_range(
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            after,
+            before,
+            limit_per_provider: DEFAULT_BULK_ACTIVITIES_PER_PROVIDER,
+            concurrency: DEFAULT_BULK_CONCURRENCY,
+        }
+    }
+
+    fn params(&self) -> ActivityQueryParams {
+        ActivityQueryParams {
+            limit: Some(self.limit_per_provider),
+            offset: None,
+            before: self.before,
+            after: self.after,
+        }
+    }
+}
+
+impl Default for BulkActivityQuery {
+    fn default() -> Self {
+        Self::with_time_range(None, None)
+    }
+}
+
+/// A provider that could not be created or queried
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProviderFetchError {
+    /// Provider name
+    pub provider: String,
+    /// Why its activities are missing
+    pub error: String,
+}
+
+/// Activities merged from several providers, with the providers that failed
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkActivities {
+    /// Deduplicated activities, oldest first
+    pub activities: Vec<Activity>,
+    /// Providers whose activities are included, sorted by name
+    pub providers: Vec<String>,
+    /// Providers whose activities are missing, sorted by name
+    pub errors: Vec<ProviderFetchError>,
+}
+
+impl BulkActivities {
+    /// Whether at least one provider's activities are missing
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
+/// Create and query each named provider concurrently and merge the results
+///
+/// At most `query.concurrency` providers are in flight at once. A provider
+/// that cannot be created or whose fetch fails is reported in `errors` and the
+/// remaining providers' activities are still returned.
+pub async fn fetch_activities_concurrently<I, F, Fut>(
+    provider_names: I,
+    query: &BulkActivityQuery,
+    dedup_config: &DeduplicationConfig,
+    create_provider: F,
+) -> BulkActivities
+where
+    I: IntoIterator<Item = String>,
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Box<dyn FitnessProvider>, String>>,
+{
+    let params = query.params();
+    let create_provider = &create_provider;
+    let params = &params;
+
+    let outcomes: Vec<(String, Result<Vec<Activity>, String>)> = stream::iter(provider_names)
+        .map(|provider_name| async move {
+            let outcome = match create_provider(provider_name.clone()).await {
+                Ok(provider) => provider
+                    .get_activities_with_params(params)
+                    .await
+                    .map_err(|e| format!("Failed to fetch activities: {e}")),
+                Err(error) => Err(error),
+            };
+            (provider_name, outcome)
+        })
+        .buffer_unordered(query.concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut activities = Vec::new();
+    let mut providers = Vec::new();
+    let mut errors = Vec::new();
+    for (provider, outcome) in outcomes {
+        match outcome {
+            Ok(fetched) => {
+                activities.extend(fetched);
+                providers.push(provider);
+            }
+            Err(error) => {
+                warn!(provider = %provider, error = %error, "Bulk activity fetch failed for provider");
+                errors.push(ProviderFetchError { provider, error });
+            }
+        }
+    }
+    providers.sort_unstable();
+    errors.sort_unstable_by(|a, b| a.provider.cmp(&b.provider));
+
+    BulkActivities {
+        activities: deduplicate_activities_with_config(activities, dedup_config),
+        providers,
+        errors,
+    }
+}
+
+/// Fetch a user's activities from every provider they have connected in a tenant
+///
+/// Providers are authenticated with the user's stored credentials and queried
+/// concurrently; see [`fetch_activities_concurrently`] for how failures are reported.
+///
+/// # Errors
+///
+/// Returns an error only if the user's connections cannot be read.
+pub async fn bulk_get_activities(
+    resources: &Arc<ServerResources>,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    query: &BulkActivityQuery,
+) -> AppResult<BulkActivities> {
+    let providers: BTreeSet<String> = resources
+        .database
+        .get_user_oauth_tokens(user_id, Some(tenant_id))
+        .await?
+        .into_iter()
+        .map(|token| token.provider)
+        .collect();
+
+    let auth_service = AuthService::new(Arc::clone(resources));
+    let auth_service = &auth_service;
+    let tenant = tenant_id.to_string();
+    let tenant = tenant.as_str();
+
+    Ok(fetch_activities_concurrently(
+        providers,
+        query,
+        &DeduplicationConfig::from_env(),
+        |provider_name| async move {
+            auth_service
+                .create_authenticated_provider(&provider_name, user_id, Some(tenant))
+                .await
+                .map_err(|response| {
+                    response
+                        .error
+                        .unwrap_or_else(|| "Authentication failed".to_owned())
+                })
+        },
+    )
+    .await)
+}
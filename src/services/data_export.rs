@@ -0,0 +1,414 @@
+// ABOUTME: User data export for GDPR portability requests shared by the MCP tool and REST route
+// ABOUTME: Assembles profile, goals, insights, connections, and recent activities with secrets redacted
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::database_plugins::{shared, DatabaseProvider};
+use crate::errors::{AppError, AppResult};
+use crate::mcp::resources::ServerResources;
+use crate::models::{Activity, TenantId, User, UserOAuthApp, UserOAuthToken};
+use crate::protocols::universal::auth_service::AuthService;
+use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+use crate::security::audit::SecurityAuditor;
+
+/// Version of the archive layout, bumped when sections change shape
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Days of activity history exported when not specified
+pub const DEFAULT_EXPORT_ACTIVITY_DAYS: u32 = 365;
+
+/// Upper bound on activities exported per provider
+pub const MAX_EXPORT_ACTIVITIES: usize = 2_000;
+
+/// Upper bound on stored insights exported
+const MAX_EXPORT_INSIGHTS: u32 = 1_000;
+
+/// Activities requested per provider call while exporting
+const EXPORT_PAGE_SIZE: usize = 100;
+
+/// Replacement for values whose key marks them as secret or encrypted
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Key fragments identifying values that must never leave the server
+const SENSITIVE_KEY_FRAGMENTS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "encrypted",
+    "api_key",
+    "credential",
+];
+
+/// How much activity history an export includes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Days of history to export, counted back from now
+    pub activity_days: u32,
+    /// Maximum activities exported per provider
+    pub max_activities: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            activity_days: DEFAULT_EXPORT_ACTIVITY_DAYS,
+            max_activities: MAX_EXPORT_ACTIVITIES,
+        }
+    }
+}
+
+/// Account details, without the password hash or identity provider ids
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedAccount {
+    /// User ID
+    pub id: Uuid,
+    /// Email address
+    pub email: String,
+    /// Display name
+    pub display_name: Option<String>,
+    /// Subscription tier
+    pub tier: String,
+    /// Permission role
+    pub role: String,
+    /// Account approval status
+    pub status: String,
+    /// Sign-in method (`email`, `google`, ...)
+    pub auth_provider: String,
+    /// When the account was created
+    pub created_at: DateTime<Utc>,
+    /// Last recorded activity on the account
+    pub last_active: DateTime<Utc>,
+}
+
+impl From<&User> for ExportedAccount {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            email: user.email.clone(),
+            display_name: user.display_name.clone(),
+            tier: shared::enums::user_tier_to_str(&user.tier).to_owned(),
+            role: shared::enums::user_role_to_str(&user.role).to_owned(),
+            status: shared::enums::user_status_to_str(&user.user_status).to_owned(),
+            auth_provider: user.auth_provider.clone(),
+            created_at: user.created_at,
+            last_active: user.last_active,
+        }
+    }
+}
+
+/// A connected fitness provider, without its access or refresh token
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedConnection {
+    /// Provider name
+    pub provider: String,
+    /// Granted OAuth scopes
+    pub scopes: Option<String>,
+    /// When the current access token expires
+    pub expires_at: Option<DateTime<Utc>>,
+    /// When the provider was first connected
+    pub connected_at: DateTime<Utc>,
+    /// When the connection was last refreshed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&UserOAuthToken> for ExportedConnection {
+    fn from(token: &UserOAuthToken) -> Self {
+        Self {
+            provider: token.provider.clone(),
+            scopes: token.scope.clone(),
+            expires_at: token.expires_at,
+            connected_at: token.created_at,
+            updated_at: token.updated_at,
+        }
+    }
+}
+
+/// A user-supplied OAuth app, without its client secret
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedOAuthApp {
+    /// Provider name
+    pub provider: String,
+    /// Public client ID
+    pub client_id: String,
+    /// Registered redirect URI
+    pub redirect_uri: String,
+    /// When the app was configured
+    pub created_at: DateTime<Utc>,
+    /// When the app was last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&UserOAuthApp> for ExportedOAuthApp {
+    fn from(app: &UserOAuthApp) -> Self {
+        Self {
+            provider: app.provider.clone(),
+            client_id: app.client_id.clone(),
+            redirect_uri: app.redirect_uri.clone(),
+            created_at: app.created_at,
+            updated_at: app.updated_at,
+        }
+    }
+}
+
+/// Activities exported from one provider
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderActivities {
+    /// Provider name
+    pub provider: String,
+    /// Activities, most recent first
+    pub activities: Vec<Activity>,
+    /// Whether history in the window was left out because of `max_activities`
+    pub truncated: bool,
+    /// Why the provider could not be read, if it failed part way or entirely
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ProviderActivities {
+    /// Record a provider that could not be queried at all
+    #[must_use]
+    pub fn failed(provider: &str, error: impl Into<String>) -> Self {
+        Self {
+            provider: provider.to_owned(),
+            activities: Vec::new(),
+            truncated: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Everything stored about a user, as returned by `export_user_data`
+#[derive(Debug, Clone, Serialize)]
+pub struct UserDataExport {
+    /// Archive layout version
+    pub format_version: u32,
+    /// When the export was assembled
+    pub exported_at: DateTime<Utc>,
+    /// Tenant the export was taken in
+    pub tenant_id: TenantId,
+    /// Account details
+    pub account: ExportedAccount,
+    /// Profile document, with secret-looking fields redacted
+    pub profile: Option<Value>,
+    /// Goals, with secret-looking fields redacted
+    pub goals: Vec<Value>,
+    /// Stored insights, with secret-looking fields redacted
+    pub insights: Vec<Value>,
+    /// Connected fitness providers
+    pub connections: Vec<ExportedConnection>,
+    /// User-supplied OAuth apps
+    pub oauth_apps: Vec<ExportedOAuthApp>,
+    /// Recent activities per connected provider
+    pub activities: Vec<ProviderActivities>,
+}
+
+impl UserDataExport {
+    /// Number of records in each section, recorded in the audit trail
+    #[must_use]
+    pub fn section_counts(&self) -> Value {
+        json!({
+            "profile": usize::from(self.profile.is_some()),
+            "goals": self.goals.len(),
+            "insights": self.insights.len(),
+            "connections": self.connections.len(),
+            "oauth_apps": self.oauth_apps.len(),
+            "activities": self
+                .activities
+                .iter()
+                .map(|provider| provider.activities.len())
+                .sum::<usize>(),
+        })
+    }
+}
+
+/// Replace every value stored under a secret-looking key with `REDACTED`, at any depth
+pub fn redact_sensitive_fields(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if SENSITIVE_KEY_FRAGMENTS
+                    .iter()
+                    .any(|fragment| key.contains(fragment))
+                {
+                    *field = Value::String(REDACTED.to_owned());
+                } else {
+                    redact_sensitive_fields(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_sensitive_fields),
+        _ => {}
+    }
+}
+
+/// Page through a provider's activities inside the export window
+///
+/// Pages are fetched `EXPORT_PAGE_SIZE` at a time until the window is exhausted
+/// or `max_activities` is reached. A failure after the first page keeps what was
+/// already read and records the error.
+pub async fn collect_provider_activities(
+    provider_name: &str,
+    provider: &dyn FitnessProvider,
+    options: &ExportOptions,
+) -> ProviderActivities {
+    let after = Utc::now()
+        .checked_sub_signed(Duration::days(i64::from(options.activity_days)))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let mut activities = Vec::new();
+
+    while activities.len() < options.max_activities {
+        let requested = EXPORT_PAGE_SIZE.min(options.max_activities - activities.len());
+        let params = ActivityQueryParams {
+            limit: Some(requested),
+            offset: Some(activities.len()),
+            before: None,
+            after: Some(after),
+        };
+        let page = match provider.get_activities_with_params(&params).await {
+            Ok(page) => page,
+            Err(e) => {
+                return ProviderActivities {
+                    provider: provider_name.to_owned(),
+                    activities,
+                    truncated: false,
+                    error: Some(format!("Failed to fetch activities: {e}")),
+                }
+            }
+        };
+        let fetched = page.len();
+        activities.extend(page);
+
+        if fetched < requested {
+            return ProviderActivities {
+                provider: provider_name.to_owned(),
+                activities,
+                truncated: false,
+                error: None,
+            };
+        }
+    }
+
+    ProviderActivities {
+        provider: provider_name.to_owned(),
+        activities,
+        truncated: true,
+        error: None,
+    }
+}
+
+/// Assemble the stored sections of a user's export around already collected activities
+///
+/// # Errors
+///
+/// Returns `AppError::NotFound` if the user does not exist in the tenant, or a
+/// database error if a section cannot be read.
+pub async fn build_user_data_export<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    activities: Vec<ProviderActivities>,
+) -> AppResult<UserDataExport> {
+    let user = database
+        .get_user(user_id, tenant_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("User {user_id}")))?;
+
+    let mut profile = database.get_user_profile(user_id).await?;
+    let mut goals = database.get_user_goals(user_id).await?;
+    let mut insights = database
+        .get_user_insights(user_id, None, Some(MAX_EXPORT_INSIGHTS))
+        .await?;
+    profile.iter_mut().for_each(redact_sensitive_fields);
+    goals.iter_mut().for_each(redact_sensitive_fields);
+    insights.iter_mut().for_each(redact_sensitive_fields);
+
+    let connections = database
+        .get_user_oauth_tokens(user_id, Some(tenant_id))
+        .await?
+        .iter()
+        .map(ExportedConnection::from)
+        .collect();
+    let oauth_apps = database
+        .list_user_oauth_apps(user_id)
+        .await?
+        .iter()
+        .map(ExportedOAuthApp::from)
+        .collect();
+
+    Ok(UserDataExport {
+        format_version: EXPORT_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        tenant_id,
+        account: ExportedAccount::from(&user),
+        profile,
+        goals,
+        insights,
+        connections,
+        oauth_apps,
+        activities,
+    })
+}
+
+/// Export everything stored about a user and record the export in the audit log
+///
+/// Activities are read from every provider the user has connected in the tenant.
+/// A provider that cannot be reached is reported in its section rather than
+/// failing the whole export.
+///
+/// # Errors
+///
+/// Returns an error if a stored section cannot be read or the audit event
+/// cannot be written; an export is never returned without its audit record.
+pub async fn export_user_data(
+    resources: &Arc<ServerResources>,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    options: &ExportOptions,
+    source_ip: Option<String>,
+) -> AppResult<UserDataExport> {
+    let providers: BTreeSet<String> = resources
+        .database
+        .get_user_oauth_tokens(user_id, Some(tenant_id))
+        .await?
+        .into_iter()
+        .map(|token| token.provider)
+        .collect();
+
+    let auth_service = AuthService::new(Arc::clone(resources));
+    let tenant = tenant_id.to_string();
+    let mut activities = Vec::with_capacity(providers.len());
+    for provider_name in &providers {
+        match auth_service
+            .create_authenticated_provider(provider_name, user_id, Some(&tenant))
+            .await
+        {
+            Ok(provider) => activities
+                .push(collect_provider_activities(provider_name, provider.as_ref(), options).await),
+            Err(response) => activities.push(ProviderActivities::failed(
+                provider_name,
+                response
+                    .error
+                    .unwrap_or_else(|| "Authentication failed".to_owned()),
+            )),
+        }
+    }
+
+    let export =
+        build_user_data_export(&*resources.database, user_id, tenant_id, activities).await?;
+
+    SecurityAuditor::new(Arc::clone(&resources.database))
+        .log_data_export(user_id, tenant_id, export.section_counts(), source_ip)
+        .await?;
+
+    Ok(export)
+}
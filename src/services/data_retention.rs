@@ -0,0 +1,91 @@
+// ABOUTME: Retention maintenance that purges expired analytics and audit rows in bounded batches
+// ABOUTME: Applies the per-table DataRetentionConfig windows and reports how many rows each table lost
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::task;
+use tracing::{debug, error, info};
+
+use crate::config::{DataRetentionConfig, RetentionTable};
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+
+/// Outcome of purging one table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TablePurge {
+    /// Table that was purged
+    pub table: &'static str,
+    /// Rows deleted in this run
+    pub purged: u64,
+    /// Whether every expired row was deleted (false if a batch failed)
+    pub completed: bool,
+}
+
+/// Delete rows older than each table's retention window
+///
+/// Tables retained forever are skipped. Each table is purged in batches of
+/// `config.batch_size()` rows, yielding between batches so request traffic is
+/// never blocked behind one long delete. A failing table is logged and left
+/// for the next run without stopping the others.
+pub async fn purge_expired_records<DB: DatabaseProvider>(
+    database: &DB,
+    config: &DataRetentionConfig,
+    now: DateTime<Utc>,
+) -> Vec<TablePurge> {
+    let mut results = Vec::new();
+    for table in RetentionTable::ALL {
+        let Some(cutoff) = config.purge_cutoff(table, now) else {
+            debug!("Retention disabled for {}, skipping purge", table);
+            continue;
+        };
+
+        let mut purged = 0;
+        let completed =
+            match purge_table(database, table, cutoff, config.batch_size(), &mut purged).await {
+                Ok(()) => true,
+                Err(e) => {
+                    error!("Failed to purge expired rows from {}: {}", table, e);
+                    false
+                }
+            };
+        if purged > 0 {
+            info!(
+                table = %table,
+                purged,
+                retention_days = config.retention_days(table),
+                "Purged expired records"
+            );
+        }
+
+        results.push(TablePurge {
+            table: table.table_name(),
+            purged,
+            completed,
+        });
+    }
+    results
+}
+
+/// Delete batches from `table` until fewer than `batch_size` rows are expired
+async fn purge_table<DB: DatabaseProvider>(
+    database: &DB,
+    table: RetentionTable,
+    cutoff: DateTime<Utc>,
+    batch_size: u32,
+    purged: &mut u64,
+) -> AppResult<()> {
+    loop {
+        let deleted = database
+            .purge_records_before(table, cutoff, batch_size)
+            .await?;
+        *purged += deleted;
+        if deleted < u64::from(batch_size) {
+            return Ok(());
+        }
+        // Let queued requests reach the database between batches
+        task::yield_now().await;
+    }
+}
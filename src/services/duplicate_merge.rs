@@ -0,0 +1,327 @@
+// ABOUTME: Manual merges of duplicate or split activities recorded for the same session
+// ABOUTME: Fills the primary's gaps or joins split recordings, hides the secondary, and undoes merges
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Duplicate Activity Merges
+//!
+//! Automatic deduplication only merges records whose start time and duration
+//! line up. When it misses a pair, the user can merge it by hand:
+//! [`merge_duplicate_activities`] stores a [`DuplicateActivityMerge`] holding a
+//! snapshot of the secondary activity. Listings then pass through
+//! [`apply_duplicate_merges`], which drops every secondary and fills each
+//! primary's empty fields from its secondaries, so the primary's own values
+//! always win.
+//!
+//! A workout recorded as two parts (a paused watch, a dead battery) is merged
+//! with [`merge_split_recordings`] instead: the earlier recording is the
+//! primary, and listings replace it with both parts joined by
+//! [`MergedActivity`], summing totals and concatenating streams.
+//!
+//! Nothing about either activity is changed at the provider, so
+//! [`unmerge_duplicate_activities`] only has to delete the merge for both to be
+//! listed separately again.
+//!
+//! Merges do not chain: an activity that is hidden behind a primary cannot
+//! become a primary itself, and a primary cannot be hidden while activities
+//! are merged into it.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::models::{
+    Activity, ActivityMergeConfig, ActivityMergeKind, DuplicateActivityMerge, MergedActivity,
+    TenantId,
+};
+
+/// Rules for re-joining stored split merges in listings
+///
+/// The merge window and sport were checked when the merge was made, so only
+/// overlapping recordings are refused here.
+const LISTING_SPLIT_MERGE_CONFIG: ActivityMergeConfig = ActivityMergeConfig {
+    max_gap_seconds: u64::MAX,
+    require_same_sport: false,
+};
+
+/// The primary activity after a merge, with the merge that was stored
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateMergeOutcome {
+    /// The primary activity with gaps filled from every activity merged into it
+    pub activity: Activity,
+    /// The stored merge, including the fields the secondary filled
+    pub merge: DuplicateActivityMerge,
+}
+
+/// Whether `activity` is the activity identified by `provider` and `id`
+fn is_activity(activity: &Activity, provider: &str, id: &str) -> bool {
+    activity.provider() == provider && activity.id() == id
+}
+
+/// Fill `primary`'s empty fields from `secondary` and return the names of the fields filled
+///
+/// # Errors
+///
+/// Returns an error if either activity cannot be serialized
+pub fn fill_gaps(primary: &mut Activity, secondary: &Activity) -> AppResult<Vec<String>> {
+    let before = serde_json::to_value(&*primary)?;
+    primary.fill_missing_from(secondary);
+    let after = serde_json::to_value(&*primary)?;
+
+    let (Value::Object(before), Value::Object(after)) = (before, after) else {
+        return Ok(Vec::new());
+    };
+    let mut filled: Vec<String> = after
+        .into_iter()
+        .filter(|(field, value)| !value.is_null() && before.get(field).is_none_or(Value::is_null))
+        .map(|(field, _)| field)
+        .collect();
+    filled.sort_unstable();
+    Ok(filled)
+}
+
+/// Join a split recording onto its primary, leaving the primary as it is if they no longer fit
+fn join_split_recording(activity: &mut Activity, secondary: &Activity) {
+    match MergedActivity::merge(
+        activity.clone(),
+        secondary.clone(),
+        &LISTING_SPLIT_MERGE_CONFIG,
+    ) {
+        Ok(merged) => *activity = merged.activity,
+        Err(e) => warn!(
+            "Listing {} activity {} without its split recording {}: {e}",
+            activity.provider(),
+            activity.id(),
+            secondary.id()
+        ),
+    }
+}
+
+/// Hide merged secondaries and fill or join their primaries
+///
+/// A secondary found among `activities` is used as it is now; otherwise the
+/// snapshot taken when it was merged is used.
+#[must_use]
+pub fn apply_duplicate_merges(
+    activities: Vec<Activity>,
+    merges: &[DuplicateActivityMerge],
+) -> Vec<Activity> {
+    if merges.is_empty() {
+        return activities;
+    }
+
+    let (secondaries, mut visible): (Vec<Activity>, Vec<Activity>) =
+        activities.into_iter().partition(|activity| {
+            merges
+                .iter()
+                .any(|merge| is_activity(activity, &merge.secondary_provider, &merge.secondary_id))
+        });
+
+    for activity in &mut visible {
+        for merge in merges
+            .iter()
+            .filter(|merge| is_activity(activity, &merge.primary_provider, &merge.primary_id))
+        {
+            let secondary = secondaries
+                .iter()
+                .find(|secondary| {
+                    is_activity(secondary, &merge.secondary_provider, &merge.secondary_id)
+                })
+                .unwrap_or(&merge.secondary);
+            match merge.kind {
+                ActivityMergeKind::Duplicate => activity.fill_missing_from(secondary),
+                ActivityMergeKind::Split => join_split_recording(activity, secondary),
+            }
+        }
+    }
+    visible
+}
+
+/// Check that `secondary` can be hidden behind `primary` given the user's existing merges
+fn validate_merge(
+    merges: &[DuplicateActivityMerge],
+    primary: &Activity,
+    secondary: &Activity,
+) -> AppResult<()> {
+    if is_activity(primary, secondary.provider(), secondary.id()) {
+        return Err(AppError::invalid_input(
+            "An activity cannot be merged into itself",
+        ));
+    }
+
+    for merge in merges {
+        if is_activity(secondary, &merge.secondary_provider, &merge.secondary_id) {
+            return Err(AppError::invalid_input(format!(
+                "{} activity {} is already merged into {} activity {}",
+                merge.secondary_provider,
+                merge.secondary_id,
+                merge.primary_provider,
+                merge.primary_id
+            )));
+        }
+        if is_activity(primary, &merge.secondary_provider, &merge.secondary_id) {
+            return Err(AppError::invalid_input(format!(
+                "{} activity {} is merged into {} activity {}; unmerge it before using it as a primary",
+                merge.secondary_provider,
+                merge.secondary_id,
+                merge.primary_provider,
+                merge.primary_id
+            )));
+        }
+        if is_activity(secondary, &merge.primary_provider, &merge.primary_id) {
+            return Err(AppError::invalid_input(format!(
+                "{} activity {} has activities merged into it; unmerge them before merging it",
+                merge.primary_provider, merge.primary_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The primary with every merge already made into it applied
+fn current_primary(primary: Activity, merges: &[DuplicateActivityMerge]) -> AppResult<Activity> {
+    apply_duplicate_merges(vec![primary], merges)
+        .pop()
+        .ok_or_else(|| AppError::internal("Primary activity was hidden by its own merges"))
+}
+
+/// Store a merge of `secondary` into the merged primary `activity`
+async fn store_merge<D: DatabaseProvider>(
+    database: &D,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    activity: Activity,
+    merge: DuplicateActivityMerge,
+) -> AppResult<DuplicateMergeOutcome> {
+    database
+        .create_duplicate_merge(user_id, tenant_id, &merge)
+        .await?;
+    Ok(DuplicateMergeOutcome { activity, merge })
+}
+
+/// Merge `secondary` into `primary` and hide it from future listings
+///
+/// The primary keeps every value it has; only its empty fields are filled,
+/// first from activities already merged into it, then from `secondary`.
+///
+/// # Errors
+///
+/// Returns `AppError::InvalidInput` if both are the same activity, the secondary
+/// is already merged, the primary is hidden behind another activity, or the
+/// secondary has activities merged into it. Returns an error if the merge
+/// cannot be stored.
+pub async fn merge_duplicate_activities<D: DatabaseProvider>(
+    database: &D,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    primary: Activity,
+    secondary: Activity,
+    now: DateTime<Utc>,
+) -> AppResult<DuplicateMergeOutcome> {
+    let merges = database.list_duplicate_merges(user_id, tenant_id).await?;
+    validate_merge(&merges, &primary, &secondary)?;
+
+    let mut activity = current_primary(primary, &merges)?;
+    let filled_fields = fill_gaps(&mut activity, &secondary)?;
+
+    let merge = DuplicateActivityMerge {
+        kind: ActivityMergeKind::Duplicate,
+        primary_provider: activity.provider().to_owned(),
+        primary_id: activity.id().to_owned(),
+        secondary_provider: secondary.provider().to_owned(),
+        secondary_id: secondary.id().to_owned(),
+        secondary,
+        filled_fields,
+        merged_at: now,
+    };
+    store_merge(database, user_id, tenant_id, activity, merge).await
+}
+
+/// Join two split recordings of one workout and hide the later one from future listings
+///
+/// The recordings may be passed in either order; the earlier one becomes the
+/// primary and keeps its id. Listings show the primary joined with every
+/// recording merged into it, so a workout split three ways is merged one part
+/// at a time.
+///
+/// # Errors
+///
+/// Returns `AppError::InvalidInput` for the same reasons as
+/// [`merge_duplicate_activities`], or if the recordings overlap, are further
+/// apart than `config` allows, or differ in sport while it requires the same
+/// sport. Returns an error if the merge cannot be stored.
+pub async fn merge_split_recordings<D: DatabaseProvider>(
+    database: &D,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    first: Activity,
+    second: Activity,
+    config: &ActivityMergeConfig,
+    now: DateTime<Utc>,
+) -> AppResult<DuplicateMergeOutcome> {
+    let (primary, secondary) = if second.start_date() < first.start_date() {
+        (second, first)
+    } else {
+        (first, second)
+    };
+
+    let merges = database.list_duplicate_merges(user_id, tenant_id).await?;
+    validate_merge(&merges, &primary, &secondary)?;
+
+    let current = current_primary(primary, &merges)?;
+    let activity = MergedActivity::merge(current, secondary.clone(), config)?.activity;
+
+    let merge = DuplicateActivityMerge {
+        kind: ActivityMergeKind::Split,
+        primary_provider: activity.provider().to_owned(),
+        primary_id: activity.id().to_owned(),
+        secondary_provider: secondary.provider().to_owned(),
+        secondary_id: secondary.id().to_owned(),
+        secondary,
+        filled_fields: Vec::new(),
+        merged_at: now,
+    };
+    store_merge(database, user_id, tenant_id, activity, merge).await
+}
+
+/// Undo the merge of a secondary activity into a primary one
+///
+/// Returns the deleted merge; its snapshot is the secondary as it was merged.
+///
+/// # Errors
+///
+/// Returns a `ResourceNotFound` error if the secondary is not merged into that
+/// primary, or an error if the merge cannot be deleted.
+pub async fn unmerge_duplicate_activities<D: DatabaseProvider>(
+    database: &D,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    (primary_provider, primary_id): (&str, &str),
+    (secondary_provider, secondary_id): (&str, &str),
+) -> AppResult<DuplicateActivityMerge> {
+    let merge = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await?
+        .into_iter()
+        .find(|merge| {
+            merge.primary_provider == primary_provider
+                && merge.primary_id == primary_id
+                && merge.secondary_provider == secondary_provider
+                && merge.secondary_id == secondary_id
+        })
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "Merge of {secondary_provider} activity {secondary_id} into {primary_provider} activity {primary_id}"
+            ))
+        })?;
+
+    database
+        .delete_duplicate_merge(user_id, tenant_id, secondary_provider, secondary_id)
+        .await?;
+    Ok(merge)
+}
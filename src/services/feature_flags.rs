@@ -0,0 +1,399 @@
+// ABOUTME: Runtime feature flags cached in memory and refreshed periodically from the database
+// ABOUTME: Resolves typed flag values per tenant and applies admin changes without a restart
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::{debug, warn};
+
+use crate::database_plugins::factory::Database;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::intelligence::algorithms::RecoveryAggregationAlgorithm;
+use crate::models::{
+    EffectiveFeatureFlag, FeatureFlag, FeatureFlagSource, FeatureFlagType, FeatureFlagValue,
+    TenantFeatureFlag, TenantId,
+};
+
+/// Prefix of boolean flags gating an MCP tool (`tool.<tool_name>`)
+pub const TOOL_FLAG_PREFIX: &str = "tool.";
+
+/// String flag selecting the recovery score aggregation algorithm
+///
+/// Accepts any name understood by [`RecoveryAggregationAlgorithm`]'s `FromStr`
+/// (e.g. `weighted_average`, `geometric_mean`, `harmonic_mean`).
+pub const RECOVERY_AGGREGATION_FLAG: &str = "algorithm.recovery_aggregation";
+
+/// Longest accepted flag key
+const MAX_KEY_LENGTH: usize = 128;
+
+/// Key of the flag gating `tool_name`
+#[must_use]
+pub fn tool_flag_key(tool_name: &str) -> String {
+    format!("{TOOL_FLAG_PREFIX}{tool_name}")
+}
+
+/// Flags and tenant values loaded from the database
+#[derive(Default)]
+struct Snapshot {
+    flags: HashMap<String, FeatureFlag>,
+    tenant_values: HashMap<(String, TenantId), FeatureFlagValue>,
+}
+
+/// Admin-configurable runtime feature flags
+///
+/// Flags are read from an in-memory snapshot, so lookups never touch the
+/// database. The snapshot is reloaded after every change made through this
+/// instance and periodically by [`Self::spawn_refresh_task`], which picks up
+/// changes made by other instances.
+///
+/// A flag's value for a tenant resolves in this order:
+/// 1. **Tenant value** - set for that tenant only
+/// 2. **Global value** - set for every tenant
+/// 3. **Default value** - given when the flag was defined
+pub struct FeatureFlags {
+    database: Arc<Database>,
+    snapshot: RwLock<Arc<Snapshot>>,
+}
+
+impl FeatureFlags {
+    /// Create an empty flag cache backed by `database`
+    ///
+    /// Call [`Self::refresh`] to load the stored flags.
+    #[must_use]
+    pub fn new(database: Arc<Database>) -> Self {
+        Self {
+            database,
+            snapshot: RwLock::new(Arc::new(Snapshot::default())),
+        }
+    }
+
+    /// Reload every flag and tenant value from the database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flags cannot be loaded; the previous snapshot is kept
+    pub async fn refresh(&self) -> AppResult<()> {
+        let flags = self.database.list_feature_flags().await?;
+        let tenant_values = self.database.list_tenant_feature_flags().await?;
+
+        let snapshot = Snapshot {
+            flags: flags
+                .into_iter()
+                .map(|flag| (flag.key.clone(), flag))
+                .collect(),
+            tenant_values: tenant_values
+                .into_iter()
+                .map(|value| ((value.key, value.tenant_id), value.value))
+                .collect(),
+        };
+        debug!(
+            "Loaded {} feature flags with {} tenant values",
+            snapshot.flags.len(),
+            snapshot.tenant_values.len()
+        );
+        *self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Arc::new(snapshot);
+        Ok(())
+    }
+
+    /// Refresh the flags every `period` until they are dropped
+    pub fn spawn_refresh_task(self: &Arc<Self>, period: Duration) {
+        let flags = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let Some(flags) = flags.upgrade() else {
+                    break;
+                };
+                if let Err(e) = flags.refresh().await {
+                    warn!("Failed to refresh feature flags: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Value of `key` for `tenant_id` (or globally when `None`)
+    ///
+    /// Returns `None` when the flag is not defined.
+    #[must_use]
+    pub fn value(&self, key: &str, tenant_id: Option<TenantId>) -> Option<FeatureFlagValue> {
+        let snapshot = self.snapshot();
+        let flag = snapshot.flags.get(key)?;
+        Some(Self::resolve(&snapshot, flag, tenant_id).0)
+    }
+
+    /// Value of a boolean flag, or `None` when it is undefined or not a boolean
+    #[must_use]
+    pub fn bool_value(&self, key: &str, tenant_id: Option<TenantId>) -> Option<bool> {
+        self.value(key, tenant_id)?.as_bool()
+    }
+
+    /// Value of a numeric flag, or `None` when it is undefined or not a number
+    #[must_use]
+    pub fn number_value(&self, key: &str, tenant_id: Option<TenantId>) -> Option<f64> {
+        self.value(key, tenant_id)?.as_f64()
+    }
+
+    /// Value of a string flag, or `None` when it is undefined or not a string
+    #[must_use]
+    pub fn string_value(&self, key: &str, tenant_id: Option<TenantId>) -> Option<String> {
+        self.value(key, tenant_id)?.as_str().map(str::to_owned)
+    }
+
+    /// Whether the `tool.<tool_name>` flag enables the tool for `tenant_id`
+    ///
+    /// Returns `None` when no flag gates the tool.
+    #[must_use]
+    pub fn tool_enabled(&self, tool_name: &str, tenant_id: TenantId) -> Option<bool> {
+        self.bool_value(&tool_flag_key(tool_name), Some(tenant_id))
+    }
+
+    /// Recovery aggregation algorithm selected for `tenant_id`
+    ///
+    /// Returns `None` when the flag is not defined or names an unknown algorithm.
+    #[must_use]
+    pub fn recovery_aggregation(
+        &self,
+        tenant_id: Option<TenantId>,
+    ) -> Option<RecoveryAggregationAlgorithm> {
+        let name = self.string_value(RECOVERY_AGGREGATION_FLAG, tenant_id)?;
+        name.parse()
+            .inspect_err(|e| warn!("Ignoring {RECOVERY_AGGREGATION_FLAG} flag: {}", e))
+            .ok()
+    }
+
+    /// Every flag with the value in effect for `tenant_id` (or globally when `None`)
+    #[must_use]
+    pub fn list(&self, tenant_id: Option<TenantId>) -> Vec<EffectiveFeatureFlag> {
+        let snapshot = self.snapshot();
+        let mut flags: Vec<EffectiveFeatureFlag> = snapshot
+            .flags
+            .values()
+            .map(|flag| {
+                let (value, source) = Self::resolve(&snapshot, flag, tenant_id);
+                EffectiveFeatureFlag {
+                    flag: flag.clone(),
+                    value,
+                    source,
+                }
+            })
+            .collect();
+        flags.sort_by(|a, b| a.flag.key.cmp(&b.flag.key));
+        flags
+    }
+
+    /// Define a flag, or update the default and description of an existing one
+    ///
+    /// The flag's type is the type of `default_value` and cannot change once
+    /// defined; delete and re-define the flag to change it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key or value is invalid, the type differs from
+    /// the existing flag's, or the flag cannot be stored
+    pub async fn define(
+        &self,
+        key: &str,
+        default_value: FeatureFlagValue,
+        description: Option<String>,
+    ) -> AppResult<FeatureFlag> {
+        validate_key(key)?;
+        validate_value(key, &default_value)?;
+
+        let global_value = match self.flag(key) {
+            Some(existing) if existing.value_type != default_value.value_type() => {
+                return Err(AppError::invalid_input(format!(
+                    "Feature flag '{key}' holds {} values, not {}",
+                    existing.value_type,
+                    default_value.value_type()
+                )));
+            }
+            Some(existing) => existing.global_value,
+            None => None,
+        };
+
+        let flag = FeatureFlag {
+            key: key.to_owned(),
+            description,
+            value_type: default_value.value_type(),
+            default_value,
+            global_value,
+            updated_at: Utc::now(),
+        };
+        self.database.upsert_feature_flag(&flag).await?;
+        self.refresh().await?;
+        Ok(flag)
+    }
+
+    /// Set the value of a flag for `tenant_id`, or globally when `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flag is not defined, the value has the wrong
+    /// type, or the value cannot be stored
+    pub async fn set(
+        &self,
+        key: &str,
+        tenant_id: Option<TenantId>,
+        value: FeatureFlagValue,
+    ) -> AppResult<()> {
+        let flag = self.require_flag(key)?;
+        if value.value_type() != flag.value_type {
+            return Err(AppError::invalid_input(format!(
+                "Feature flag '{key}' expects a {} value, got {}",
+                flag.value_type,
+                value.value_type()
+            )));
+        }
+        validate_value(key, &value)?;
+
+        let now = Utc::now();
+        match tenant_id {
+            Some(tenant_id) => {
+                self.database
+                    .upsert_tenant_feature_flag(&TenantFeatureFlag {
+                        key: key.to_owned(),
+                        tenant_id,
+                        value,
+                        updated_at: now,
+                    })
+                    .await?;
+            }
+            None => {
+                self.database
+                    .upsert_feature_flag(&FeatureFlag {
+                        global_value: Some(value),
+                        updated_at: now,
+                        ..flag
+                    })
+                    .await?;
+            }
+        }
+        self.refresh().await
+    }
+
+    /// Remove the value set for `tenant_id` (or the global value when `None`)
+    ///
+    /// Returns whether a value was removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flag is not defined or the value cannot be removed
+    pub async fn clear(&self, key: &str, tenant_id: Option<TenantId>) -> AppResult<bool> {
+        let flag = self.require_flag(key)?;
+        let cleared = match tenant_id {
+            Some(tenant_id) => {
+                self.database
+                    .delete_tenant_feature_flag(key, tenant_id)
+                    .await?
+            }
+            None if flag.global_value.is_none() => false,
+            None => {
+                self.database
+                    .upsert_feature_flag(&FeatureFlag {
+                        global_value: None,
+                        updated_at: Utc::now(),
+                        ..flag
+                    })
+                    .await?;
+                true
+            }
+        };
+        self.refresh().await?;
+        Ok(cleared)
+    }
+
+    /// Delete a flag along with its global and tenant values
+    ///
+    /// Returns whether the flag existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flag cannot be deleted
+    pub async fn delete(&self, key: &str) -> AppResult<bool> {
+        let deleted = self.database.delete_feature_flag(key).await?;
+        self.refresh().await?;
+        Ok(deleted)
+    }
+
+    fn snapshot(&self) -> Arc<Snapshot> {
+        self.snapshot
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn flag(&self, key: &str) -> Option<FeatureFlag> {
+        self.snapshot().flags.get(key).cloned()
+    }
+
+    fn require_flag(&self, key: &str) -> AppResult<FeatureFlag> {
+        self.flag(key)
+            .ok_or_else(|| AppError::not_found(format!("Feature flag '{key}'")))
+    }
+
+    fn resolve(
+        snapshot: &Snapshot,
+        flag: &FeatureFlag,
+        tenant_id: Option<TenantId>,
+    ) -> (FeatureFlagValue, FeatureFlagSource) {
+        if let Some(value) = tenant_id
+            .and_then(|tenant_id| snapshot.tenant_values.get(&(flag.key.clone(), tenant_id)))
+        {
+            return (value.clone(), FeatureFlagSource::Tenant);
+        }
+        if let Some(value) = &flag.global_value {
+            return (value.clone(), FeatureFlagSource::Global);
+        }
+        (flag.default_value.clone(), FeatureFlagSource::Default)
+    }
+}
+
+/// Keys are lowercase ASCII letters, digits, `.`, `_`, and `-`
+fn validate_key(key: &str) -> AppResult<()> {
+    let valid_chars = key
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'));
+    if key.is_empty() || key.len() > MAX_KEY_LENGTH || !valid_chars {
+        return Err(AppError::invalid_input(format!(
+            "Invalid feature flag key '{key}': use up to {MAX_KEY_LENGTH} lowercase letters, digits, '.', '_', or '-'"
+        )));
+    }
+    Ok(())
+}
+
+/// Reject values the flags the server reads could not use
+fn validate_value(key: &str, value: &FeatureFlagValue) -> AppResult<()> {
+    if key.starts_with(TOOL_FLAG_PREFIX) && value.value_type() != FeatureFlagType::Bool {
+        return Err(AppError::invalid_input(format!(
+            "Tool feature flag '{key}' must hold bool values"
+        )));
+    }
+    if key == RECOVERY_AGGREGATION_FLAG {
+        let name = value.as_str().ok_or_else(|| {
+            AppError::invalid_input(format!("Feature flag '{key}' must hold string values"))
+        })?;
+        name.parse::<RecoveryAggregationAlgorithm>()?;
+    }
+    if let FeatureFlagValue::Number(number) = value {
+        if !number.is_finite() {
+            return Err(AppError::invalid_input(format!(
+                "Feature flag '{key}' must be a finite number"
+            )));
+        }
+    }
+    Ok(())
+}
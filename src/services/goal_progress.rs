@@ -0,0 +1,354 @@
+// ABOUTME: Automatic goal progress from synced activities for distance, time, and frequency goals
+// ABOUTME: Advances current_value for matching activities, marks goals completed, and notifies the user
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Goal Auto-Progress
+//!
+//! After activities are synced, [`update_goals_from_activities`] credits them to
+//! the user's active goals so `current_value` no longer has to be set by hand.
+//! An activity counts toward a goal when its sport matches the goal's sport and
+//! it started between the goal's `created_at` and `target_date`:
+//!
+//! - `distance` goals add the activity distance in kilometers
+//! - `time` goals add the activity duration in hours
+//! - `frequency` goals count sessions in the current Monday-to-Sunday week (UTC);
+//!   the count starts over with the first session of a new week
+//!
+//! Credited activity IDs are stored on the goal, so a sync that returns the same
+//! activities again does not count them twice. Goals created with
+//! `auto_progress: false` are left alone. When `current_value` reaches
+//! `target_value` the goal is marked `completed`, the user gets a notification,
+//! and a `goal.completed` event is queued for the tenant's webhooks.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, Duration, Utc};
+use serde_json::{json, Value};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::constants::units::{METERS_PER_KM, SECONDS_PER_HOUR};
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::mcp::resources::ServerResources;
+#[cfg(feature = "transport-sse")]
+use crate::models::OAuthNotification;
+use crate::models::{Activity, SportTypeNormalizer, TenantId};
+use crate::services::webhook_delivery::WebhookDispatcher;
+use crate::tenant::webhooks::WebhookEventType;
+
+/// Status of a goal that is still being worked toward
+const STATUS_ACTIVE: &str = "active";
+/// Status of a goal whose target has been reached
+const STATUS_COMPLETED: &str = "completed";
+
+/// Goal types whose progress can be derived from activities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrackedGoalType {
+    Distance,
+    Time,
+    Frequency,
+}
+
+impl TrackedGoalType {
+    fn parse(goal_type: &str) -> Option<Self> {
+        match goal_type.to_lowercase().as_str() {
+            "distance" => Some(Self::Distance),
+            "time" => Some(Self::Time),
+            "frequency" => Some(Self::Frequency),
+            _ => None,
+        }
+    }
+
+    /// Amount a single activity adds to the goal
+    fn contribution(self, activity: &Activity) -> f64 {
+        match self {
+            Self::Distance => activity.distance_meters().unwrap_or(0.0) / METERS_PER_KM,
+            // Safe: activity durations are far below 2^52 seconds
+            #[allow(clippy::cast_precision_loss)]
+            Self::Time => activity.duration_seconds() as f64 / SECONDS_PER_HOUR,
+            Self::Frequency => 1.0,
+        }
+    }
+}
+
+/// Progress applied to one goal by a batch of synced activities
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalProgressUpdate {
+    /// Goal ID
+    pub goal_id: String,
+    /// Goal title
+    pub title: String,
+    /// Goal type (`distance`, `time`, or `frequency`)
+    pub goal_type: String,
+    /// Progress before the activities were applied
+    pub previous_value: f64,
+    /// Progress after the activities were applied
+    pub current_value: f64,
+    /// Value at which the goal is completed
+    pub target_value: f64,
+    /// Whether these activities completed the goal
+    pub completed: bool,
+}
+
+/// Apply synced activities to a stored goal
+///
+/// `goal` is the goal object as returned by `get_user_goals` and is updated in
+/// place. Returns `None` when the goal is not auto-tracked or none of the
+/// activities counted toward it.
+#[must_use]
+pub fn apply_activities_to_goal(
+    goal: &mut Value,
+    activities: &[Activity],
+    now: DateTime<Utc>,
+) -> Option<GoalProgressUpdate> {
+    let str_field =
+        |goal: &Value, key: &str| goal.get(key).and_then(Value::as_str).map(str::to_owned);
+    let time_field = |goal: &Value, key: &str| {
+        str_field(goal, key)
+            .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+
+    if !goal
+        .get("auto_progress")
+        .and_then(Value::as_bool)
+        .unwrap_or(true)
+        || str_field(goal, "status").is_some_and(|status| status != STATUS_ACTIVE)
+    {
+        return None;
+    }
+
+    let goal_id = str_field(goal, "id")?;
+    let goal_type_name = str_field(goal, "goal_type")?;
+    let goal_type = TrackedGoalType::parse(&goal_type_name)?;
+    let target_value = goal.get("target_value").and_then(Value::as_f64)?;
+    let window_start = time_field(goal, "created_at")?;
+    let window_end = time_field(goal, "target_date").unwrap_or(DateTime::<Utc>::MAX_UTC);
+    let sport = SportTypeNormalizer::global()
+        .normalize(&str_field(goal, "sport").unwrap_or_else(|| "Running".to_owned()));
+
+    let previous_value = goal
+        .get("current_value")
+        .and_then(Value::as_f64)
+        .unwrap_or(0.0);
+    let mut counted: Vec<String> = goal
+        .get("counted_activity_ids")
+        .and_then(Value::as_array)
+        .map(|ids| {
+            ids.iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut seen: HashSet<String> = counted.iter().cloned().collect();
+    let mut week_start = time_field(goal, "week_start");
+
+    let mut matching: Vec<&Activity> = activities
+        .iter()
+        .filter(|activity| {
+            *activity.sport_type() == sport
+                && activity.start_date() >= window_start
+                && activity.start_date() <= window_end
+        })
+        .collect();
+    matching.sort_by_key(|activity| activity.start_date());
+
+    let mut current_value = previous_value;
+    let mut changed = false;
+    for activity in matching {
+        if seen.contains(activity.id()) {
+            continue;
+        }
+        if goal_type == TrackedGoalType::Frequency {
+            let activity_week = start_of_week(activity.start_date());
+            match week_start {
+                // Sessions from a week that has already been superseded no longer count
+                Some(current_week) if activity_week < current_week => continue,
+                Some(current_week) if activity_week == current_week => {}
+                _ => {
+                    week_start = Some(activity_week);
+                    current_value = 0.0;
+                }
+            }
+        }
+        current_value += goal_type.contribution(activity);
+        seen.insert(activity.id().to_owned());
+        counted.push(activity.id().to_owned());
+        changed = true;
+    }
+
+    if !changed {
+        return None;
+    }
+
+    let completed = target_value > 0.0 && current_value >= target_value;
+    let obj = goal.as_object_mut()?;
+    obj.insert("current_value".into(), json!(current_value));
+    if target_value > 0.0 {
+        obj.insert(
+            "progress_percentage".into(),
+            json!((current_value / target_value * 100.0).clamp(0.0, 100.0)),
+        );
+    }
+    obj.insert("counted_activity_ids".into(), json!(counted));
+    if let Some(week_start) = week_start {
+        obj.insert("week_start".into(), json!(week_start.to_rfc3339()));
+    }
+    obj.insert("last_updated".into(), json!(now.to_rfc3339()));
+    obj.insert(
+        "status".into(),
+        json!(if completed {
+            STATUS_COMPLETED
+        } else {
+            STATUS_ACTIVE
+        }),
+    );
+    if completed {
+        obj.insert("completed_at".into(), json!(now.to_rfc3339()));
+    }
+
+    Some(GoalProgressUpdate {
+        goal_id,
+        title: str_field(goal, "title").unwrap_or_else(|| "Fitness Goal".to_owned()),
+        goal_type: goal_type_name,
+        previous_value,
+        current_value,
+        target_value,
+        completed,
+    })
+}
+
+/// Credit synced activities to a user's active goals
+///
+/// Each goal that advanced is saved; goals that were completed notify the user
+/// and queue a `goal.completed` webhook event for the tenant. Notification
+/// failures are logged and do not fail the update.
+///
+/// # Errors
+///
+/// Returns an error if the user's goals cannot be loaded or an updated goal cannot be saved
+pub async fn update_goals_from_activities(
+    resources: &ServerResources,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    provider: &str,
+    activities: &[Activity],
+) -> AppResult<Vec<GoalProgressUpdate>> {
+    if activities.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let now = Utc::now();
+    let mut updates = Vec::new();
+    for mut goal in resources.database.get_user_goals(user_id).await? {
+        let Some(update) = apply_activities_to_goal(&mut goal, activities, now) else {
+            continue;
+        };
+        resources
+            .database
+            .update_goal_data(&update.goal_id, user_id, goal)
+            .await?;
+        debug!(
+            %user_id,
+            goal_id = %update.goal_id,
+            current_value = update.current_value,
+            "Goal progress updated from synced activities"
+        );
+
+        if update.completed {
+            info!(%user_id, goal_id = %update.goal_id, "Goal completed from synced activities");
+            notify_completed(resources, user_id, provider, &update).await;
+            queue_completed_webhook(resources, user_id, tenant_id, &update).await;
+        }
+        updates.push(update);
+    }
+
+    Ok(updates)
+}
+
+/// Midnight UTC on the Monday of the week containing `date`
+fn start_of_week(date: DateTime<Utc>) -> DateTime<Utc> {
+    let day = date.date_naive() - Duration::days(i64::from(date.weekday().num_days_from_monday()));
+    day.and_hms_opt(0, 0, 0)
+        .map_or(date, |midnight| midnight.and_utc())
+}
+
+/// Tell the user through the OAuth notification channel that a goal was reached
+async fn notify_completed(
+    resources: &ServerResources,
+    user_id: Uuid,
+    provider: &str,
+    update: &GoalProgressUpdate,
+) {
+    let message = format!("Goal completed: {}", update.title);
+
+    let notification_id = match resources
+        .database
+        .store_oauth_notification(user_id, provider, true, &message, None)
+        .await
+    {
+        Ok(id) => id,
+        Err(e) => {
+            warn!(%user_id, error = %e, "Failed to store goal completion notification");
+            return;
+        }
+    };
+    debug!(%user_id, %notification_id, "Stored goal completion notification");
+
+    #[cfg(feature = "transport-sse")]
+    {
+        let notification = OAuthNotification {
+            id: notification_id,
+            user_id: user_id.to_string(),
+            provider: provider.to_owned(),
+            success: true,
+            message,
+            expires_at: None,
+            created_at: Utc::now(),
+            read_at: None,
+        };
+        // Users without an open notification stream see the stored notification later
+        if let Err(e) = resources
+            .sse_manager
+            .send_notification(user_id, &notification)
+            .await
+        {
+            debug!(%user_id, error = %e, "Goal completion not pushed over SSE");
+        }
+    }
+}
+
+/// Queue a `goal.completed` event for the tenant's webhooks
+async fn queue_completed_webhook(
+    resources: &ServerResources,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    update: &GoalProgressUpdate,
+) {
+    let data = json!({
+        "user_id": user_id,
+        "goal_id": update.goal_id,
+        "title": update.title,
+        "goal_type": update.goal_type,
+        "target_value": update.target_value,
+        "current_value": update.current_value,
+    });
+    let dispatcher = match WebhookDispatcher::new(Arc::clone(&resources.database)) {
+        Ok(dispatcher) => dispatcher,
+        Err(e) => {
+            warn!(%user_id, error = %e, "Failed to queue goal.completed webhook event");
+            return;
+        }
+    };
+    if let Err(e) = dispatcher
+        .enqueue(tenant_id, WebhookEventType::GoalCompleted, data)
+        .await
+    {
+        warn!(%user_id, error = %e, "Failed to queue goal.completed webhook event");
+    }
+}
@@ -16,6 +16,9 @@ pub mod coaches;
 /// OAuth flow orchestration: state validation, redirect URL parsing
 pub mod oauth_flow;
 
+/// OAuth scope step-up: re-authorization for scopes a tool needs but the user has not granted
+pub mod oauth_step_up;
+
 /// Recipe import/export and markdown conversion
 pub mod recipes;
 
@@ -27,3 +30,42 @@ pub mod chat_orchestration;
 
 /// Social insights: friend-request validation, user search enrichment, insight adaptation
 pub mod social_insights;
+
+/// Provider priority: per-user choice of which provider's metrics win for duplicate activities
+pub mod provider_priority;
+
+/// User data export: portability archive of profile, goals, insights, connections, and activities
+pub mod data_export;
+
+/// Outbound tenant webhooks: event queueing, signed delivery, retries, and dead-lettering
+pub mod webhook_delivery;
+
+/// Signing key rollover: new JWT signing key with an overlap window for the retiring one
+pub mod signing_key_rollover;
+
+/// Data retention: batched purge of analytics and audit rows past their retention window
+pub mod data_retention;
+
+/// Bulk activity fetch: concurrent fan-out across a user's connected providers with merged results
+pub mod bulk_activities;
+
+/// Goal auto-progress: credits synced activities to distance, time, and frequency goals
+pub mod goal_progress;
+
+/// Activity delta sync: activities created, changed, or deleted since a timestamp
+pub mod activity_delta;
+
+/// Feature flags: typed runtime flags cached in memory, set globally or per tenant by admins
+pub mod feature_flags;
+
+/// Strava push subscriptions: callback verification and activity create/update/delete events
+pub mod strava_webhooks;
+
+/// Insight re-analysis: batched, concurrent, checkpointed regeneration of every user's insights
+pub mod reanalysis;
+
+/// Tenant tool usage: per-tool, per-day call counts across API keys, A2A clients, and JWTs for billing
+pub mod tenant_usage;
+
+/// Duplicate activity merges: manual merges that fill a primary's gaps and hide the duplicate, and their undo
+pub mod duplicate_merge;
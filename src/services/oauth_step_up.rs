@@ -0,0 +1,260 @@
+// ABOUTME: Provider OAuth scope step-up for tools that need scopes the user has not granted
+// ABOUTME: Builds re-authorization URLs for only the missing scopes and merges the upgraded grant
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::BTreeSet;
+use std::env;
+
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::constants::oauth_config::AUTHORIZATION_EXPIRES_MINUTES;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::mcp::resources::ServerResources;
+use crate::models::TenantId;
+use crate::oauth2_client::{OAuth2Token, OAuthClientState};
+use crate::tenant::{TenantContext, TenantRole};
+
+/// Re-authorization needed before a tool can run against a provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScopeStepUp {
+    /// Tool that triggered the step-up
+    pub tool_name: String,
+    /// Provider to re-authorize
+    pub provider: String,
+    /// Scopes the current grant is missing, and the only scopes requested
+    pub missing_scopes: Vec<String>,
+    /// URL the user opens to grant the missing scopes
+    pub authorization_url: String,
+    /// OAuth state bound to this step-up
+    pub state: String,
+    /// Minutes until the state expires
+    pub expires_in_minutes: u32,
+}
+
+impl ScopeStepUp {
+    /// Explanation for the user naming the permissions to grant
+    #[must_use]
+    pub fn message(&self) -> String {
+        format!(
+            "{} needs additional {} permissions ({}). Re-authorize using the provided URL and try again.",
+            self.tool_name,
+            self.provider,
+            self.missing_scopes.join(", ")
+        )
+    }
+}
+
+/// Split a stored or requested scope string into individual scopes
+///
+/// Providers separate scopes with commas (Strava) or spaces (Fitbit), so both are accepted.
+#[must_use]
+pub fn parse_scopes(scopes: &str) -> BTreeSet<String> {
+    scopes
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Required scopes absent from `granted`, in the order they were required
+///
+/// A granted `<resource>:read_all` scope also covers `<resource>:read`, matching
+/// Strava's scope hierarchy.
+#[must_use]
+pub fn missing_scopes(granted: &str, required: &[String]) -> Vec<String> {
+    let granted = parse_scopes(granted);
+    required
+        .iter()
+        .filter(|scope| {
+            !granted.contains(*scope)
+                && !scope
+                    .strip_suffix(":read")
+                    .is_some_and(|resource| granted.contains(&format!("{resource}:read_all")))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Scopes `tool_name` needs from `provider`
+///
+/// Combines the requirements the tool declares in the registry with any
+/// configured through `PIERRE_TOOL_REQUIRED_SCOPES`, without duplicates.
+#[must_use]
+pub fn required_scopes(
+    resources: &ServerResources,
+    tool_name: &str,
+    provider: &str,
+) -> Vec<String> {
+    let declared = resources
+        .tool_registry
+        .get(tool_name)
+        .map(|tool| tool.required_scopes())
+        .unwrap_or_default()
+        .iter()
+        .filter(|requirement| requirement.provider.eq_ignore_ascii_case(provider))
+        .flat_map(|requirement| requirement.scopes.iter().map(|scope| (*scope).to_owned()));
+    let configured = resources
+        .tool_scope_requirements
+        .required_scopes(tool_name, provider)
+        .iter()
+        .cloned();
+
+    let mut required: Vec<String> = Vec::new();
+    for scope in declared.chain(configured) {
+        if !required.contains(&scope) {
+            required.push(scope);
+        }
+    }
+    required
+}
+
+/// Union of two scope strings, comma-separated as stored on OAuth tokens
+#[must_use]
+pub fn merge_scopes(existing: &str, granted: &str) -> String {
+    let mut merged = parse_scopes(existing);
+    merged.extend(parse_scopes(granted));
+    merged.into_iter().collect::<Vec<_>>().join(",")
+}
+
+/// Check whether `tool_name` needs scopes the user's `provider` token lacks
+///
+/// Returns `None` when the tool has no scope requirements, the user has no token
+/// (the regular "connect your account" path applies), the token does not record
+/// its granted scopes, or the token already covers every required scope.
+/// Otherwise stores a fresh OAuth state and returns a re-authorization URL
+/// requesting only the missing scopes.
+///
+/// # Errors
+///
+/// Returns an error if the token lookup, URL generation, or state storage fails
+pub async fn check_tool_scopes(
+    resources: &ServerResources,
+    tool_name: &str,
+    provider: &str,
+    user_id: Uuid,
+    tenant_id: Option<&str>,
+) -> AppResult<Option<ScopeStepUp>> {
+    let required = required_scopes(resources, tool_name, provider);
+    if required.is_empty() {
+        return Ok(None);
+    }
+    let Some(tenant_id) = tenant_id.and_then(|t| t.parse::<TenantId>().ok()) else {
+        return Ok(None);
+    };
+
+    let database = &resources.database;
+    let Some(token) = database
+        .get_user_oauth_token(user_id, tenant_id, provider)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    // Tokens stored without their grant cannot be checked; let the provider decide
+    let Some(granted) = token.scope.as_deref() else {
+        return Ok(None);
+    };
+    let missing = missing_scopes(granted, &required);
+    if missing.is_empty() {
+        return Ok(None);
+    }
+
+    let tenant_name = database
+        .get_tenant_by_id(tenant_id)
+        .await
+        .map_or_else(|_| "Unknown Tenant".to_owned(), |t| t.name);
+    let tenant_context = TenantContext {
+        tenant_id,
+        tenant_name,
+        user_id,
+        user_role: TenantRole::Member,
+    };
+
+    let state = format!("{user_id}:{}", Uuid::new_v4());
+    let authorization_url = resources
+        .tenant_oauth_client
+        .get_step_up_authorization_url(&tenant_context, provider, &state, &missing, database)
+        .await?;
+
+    let now = Utc::now();
+    let base_url = env::var("BASE_URL")
+        .unwrap_or_else(|_| format!("http://localhost:{}", resources.config.http_port));
+    database
+        .store_oauth_client_state(&OAuthClientState {
+            state: state.clone(),
+            provider: provider.to_owned(),
+            user_id: Some(user_id),
+            tenant_id: Some(tenant_id.to_string()),
+            redirect_uri: format!("{base_url}/api/oauth/callback/{provider}"),
+            scope: Some(missing.join(",")),
+            pkce_code_verifier: None,
+            created_at: now,
+            expires_at: now + Duration::minutes(i64::from(AUTHORIZATION_EXPIRES_MINUTES)),
+            used: false,
+        })
+        .await?;
+
+    info!(
+        "Tool {} needs {:?} from {} for user {}; issued scope step-up",
+        tool_name, missing, provider, user_id
+    );
+
+    Ok(Some(ScopeStepUp {
+        tool_name: tool_name.to_owned(),
+        provider: provider.to_owned(),
+        missing_scopes: missing,
+        authorization_url,
+        state,
+        expires_in_minutes: AUTHORIZATION_EXPIRES_MINUTES,
+    }))
+}
+
+/// Keep previously granted scopes when a callback completes a scope step-up
+///
+/// A step-up requests only the missing scopes, so the token issued for it is
+/// recorded with the union of the stored grant and the newly granted scopes.
+/// Callbacks that re-requested every stored scope (a full reconnect) are left
+/// untouched, as is a callback whose requested scopes are unknown.
+///
+/// # Errors
+///
+/// Returns an error if the stored token cannot be read
+pub async fn merge_granted_scopes<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: Uuid,
+    tenant_id: TenantId,
+    provider: &str,
+    requested_scope: Option<&str>,
+    token: &mut OAuth2Token,
+) -> AppResult<()> {
+    let Some(requested_scope) = requested_scope else {
+        return Ok(());
+    };
+    let Some(existing) = database
+        .get_user_oauth_token(user_id, tenant_id, provider)
+        .await?
+    else {
+        return Ok(());
+    };
+    let existing_scope = existing.scope.unwrap_or_default();
+
+    let requested = parse_scopes(requested_scope);
+    if parse_scopes(&existing_scope).is_subset(&requested) {
+        return Ok(());
+    }
+
+    let granted = token.scope.as_deref().unwrap_or(requested_scope);
+    let merged = merge_scopes(&existing_scope, granted);
+    debug!(
+        "Merged step-up grant for user {} provider {}: {}",
+        user_id, provider, merged
+    );
+    token.scope = Some(merged);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+// ABOUTME: Per-user provider priority settings for merging duplicate activities across providers
+// ABOUTME: Stores the preference inside the user's configuration document and validates updates
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use serde_json::{Map, Value};
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::providers::unified_timeline::ProviderPriority;
+
+/// Key holding the provider priority inside the user configuration document
+pub const PROVIDER_PRIORITY_KEY: &str = "provider_priority";
+
+/// Parse and validate a provider priority supplied by a client
+///
+/// # Errors
+///
+/// Returns `AppError::InvalidInput` if the value does not describe a provider
+/// priority, lists an empty provider name, or uses non-positive tolerances.
+pub fn parse_provider_priority(value: &Value) -> AppResult<ProviderPriority> {
+    let priority: ProviderPriority = serde_json::from_value(value.clone())
+        .map_err(|e| AppError::invalid_input(format!("Invalid provider priority: {e}")))?;
+
+    let has_empty_name = priority
+        .default_order
+        .iter()
+        .chain(priority.metric_overrides.values().flatten())
+        .any(|provider| provider.trim().is_empty());
+    if has_empty_name {
+        return Err(AppError::invalid_input(
+            "Provider priority contains an empty provider name",
+        ));
+    }
+    if priority.match_start_tolerance_seconds <= 0
+        || priority.match_duration_tolerance_percent <= 0.0
+    {
+        return Err(AppError::invalid_input(
+            "Provider priority match tolerances must be positive",
+        ));
+    }
+    Ok(priority)
+}
+
+/// Load a user's stored configuration document, if any
+async fn load_configuration<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: &str,
+) -> AppResult<Option<Value>> {
+    database
+        .get_user_configuration(user_id)
+        .await?
+        .map(|json| {
+            serde_json::from_str(&json).map_err(|e| {
+                AppError::internal(format!("Stored user configuration is not valid JSON: {e}"))
+            })
+        })
+        .transpose()
+}
+
+/// Load the provider priority a user configured, falling back to the defaults
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be read or the stored
+/// priority no longer parses.
+pub async fn load_provider_priority<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: &str,
+) -> AppResult<ProviderPriority> {
+    let stored = load_configuration(database, user_id).await?;
+    stored
+        .as_ref()
+        .and_then(|config| config.get(PROVIDER_PRIORITY_KEY))
+        .map_or_else(|| Ok(ProviderPriority::default()), parse_provider_priority)
+}
+
+/// Store a user's provider priority without touching the rest of their configuration
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be read or written.
+pub async fn save_provider_priority<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: &str,
+    priority: &ProviderPriority,
+) -> AppResult<()> {
+    let mut config = load_configuration(database, user_id)
+        .await?
+        .filter(Value::is_object)
+        .unwrap_or_else(|| Value::Object(Map::new()));
+    config[PROVIDER_PRIORITY_KEY] = serde_json::to_value(priority)
+        .map_err(|e| AppError::internal(format!("Failed to serialize provider priority: {e}")))?;
+
+    let config_json = serde_json::to_string(&config)
+        .map_err(|e| AppError::internal(format!("Failed to serialize config: {e}")))?;
+    database
+        .save_user_configuration(user_id, &config_json)
+        .await
+}
+
+/// Set the provider priority on a configuration document that is about to replace the stored one
+///
+/// Uses `requested` when the client sent a new priority, otherwise carries the
+/// stored priority over so rewriting the configuration does not drop it.
+///
+/// # Errors
+///
+/// Returns `AppError::InvalidInput` if `requested` is invalid, or a database
+/// error if the stored configuration cannot be read.
+pub async fn apply_provider_priority<DB: DatabaseProvider>(
+    database: &DB,
+    user_id: &str,
+    requested: Option<&Value>,
+    configuration: &mut Value,
+) -> AppResult<()> {
+    let priority = match requested {
+        Some(value) => {
+            let priority = parse_provider_priority(value)?;
+            Some(serde_json::to_value(&priority).map_err(|e| {
+                AppError::internal(format!("Failed to serialize provider priority: {e}"))
+            })?)
+        }
+        None => load_configuration(database, user_id)
+            .await?
+            .and_then(|mut stored| stored.get_mut(PROVIDER_PRIORITY_KEY).map(Value::take)),
+    };
+
+    if let (Some(priority), Some(config)) = (priority, configuration.as_object_mut()) {
+        config.insert(PROVIDER_PRIORITY_KEY.to_owned(), priority);
+    }
+    Ok(())
+}
@@ -0,0 +1,311 @@
+// ABOUTME: Maintenance job that regenerates insights for every matching user in bounded batches
+// ABOUTME: Streams users by cursor, analyzes them concurrently, and checkpoints so interrupted runs resume
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! Insight re-analysis
+//!
+//! After an analysis algorithm changes, every user's stored insights are
+//! stale. [`reanalyze_users`] walks the users matching a
+//! [`ReanalysisFilter`] one page (batch) at a time through the user cursor
+//! API, analyzes each page with bounded concurrency, and stores the resulting
+//! insights. A [`ReanalysisCheckpoint`] holding the next page's cursor is
+//! saved after every batch, so running the same job again after an
+//! interruption continues with the first unfinished batch instead of starting
+//! over.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use futures_util::{stream, StreamExt};
+use serde_json::{json, Value};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::AppResult;
+use crate::intelligence::TrainingLoadCalculator;
+use crate::lifecycle::shutdown::ShutdownCoordinator;
+use crate::mcp::resources::ServerResources;
+use crate::models::{ReanalysisCheckpoint, TenantId, User, UserStatus};
+use crate::pagination::{Cursor, PaginationParams};
+use crate::services::bulk_activities::{bulk_get_activities, BulkActivityQuery};
+
+/// Users analyzed at the same time when not specified
+pub const DEFAULT_REANALYSIS_CONCURRENCY: usize = 4;
+
+/// Users fetched and checkpointed together when not specified
+pub const DEFAULT_REANALYSIS_BATCH_SIZE: usize = 50;
+
+/// Days of activity history the training load insight is computed from
+const TRAINING_LOAD_HISTORY_DAYS: i64 = 90;
+
+/// Which users a re-analysis job covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReanalysisFilter {
+    /// Only users with this account status
+    pub status: UserStatus,
+    /// Only members of this tenant, or every user when `None`
+    pub tenant_id: Option<TenantId>,
+}
+
+impl ReanalysisFilter {
+    /// Every active user, in any tenant
+    #[must_use]
+    pub const fn active_users() -> Self {
+        Self {
+            status: UserStatus::Active,
+            tenant_id: None,
+        }
+    }
+
+    /// Restrict the job to members of one tenant
+    #[must_use]
+    pub const fn in_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = Some(tenant_id);
+        self
+    }
+}
+
+/// Concurrency and batching limits for a re-analysis job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReanalysisOptions {
+    /// Maximum users analyzed at the same time
+    pub concurrency: usize,
+    /// Users fetched per page; progress is checkpointed after each one
+    pub batch_size: usize,
+}
+
+impl ReanalysisOptions {
+    /// Options with the given limits; zero values are treated as one
+    #[must_use]
+    pub const fn new(concurrency: usize, batch_size: usize) -> Self {
+        Self {
+            concurrency,
+            batch_size,
+        }
+    }
+}
+
+impl Default for ReanalysisOptions {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_REANALYSIS_CONCURRENCY,
+            DEFAULT_REANALYSIS_BATCH_SIZE,
+        )
+    }
+}
+
+/// Regenerate insights for every user matching `filter`
+///
+/// `analyze` is called once per user with the tenant the user is analyzed in
+/// (the filter's tenant, or the user's first tenant) and returns the
+/// insights to store. A user whose analysis or storage fails is counted in
+/// `users_failed` and the job moves on.
+///
+/// The job is identified by `job_id`. If a checkpoint for it exists the run
+/// resumes from the saved cursor; a completed job returns its checkpoint
+/// without analyzing anyone again. When `shutdown` is triggered the job
+/// stops before the next batch and returns the unfinished checkpoint.
+///
+/// # Errors
+///
+/// Returns an error if users cannot be listed or the checkpoint cannot be
+/// read or saved
+pub async fn reanalyze_users<DB, F, Fut>(
+    database: &DB,
+    shutdown: &ShutdownCoordinator,
+    job_id: &str,
+    filter: &ReanalysisFilter,
+    options: &ReanalysisOptions,
+    analyze: F,
+) -> AppResult<ReanalysisCheckpoint>
+where
+    DB: DatabaseProvider,
+    F: Fn(User, Option<TenantId>) -> Fut,
+    Fut: Future<Output = AppResult<Vec<Value>>>,
+{
+    let mut checkpoint = match database.get_reanalysis_checkpoint(job_id).await? {
+        Some(checkpoint) if checkpoint.is_complete() => {
+            info!("Re-analysis job {} already completed", job_id);
+            return Ok(checkpoint);
+        }
+        Some(checkpoint) => {
+            info!(
+                "Resuming re-analysis job {} after {} users",
+                job_id,
+                checkpoint.users_processed + checkpoint.users_failed
+            );
+            checkpoint
+        }
+        None => ReanalysisCheckpoint::new(job_id),
+    };
+    let status = filter.status.to_string();
+    let analyze = &analyze;
+
+    loop {
+        if shutdown.is_triggered() {
+            info!("Re-analysis job {} interrupted, progress saved", job_id);
+            return Ok(checkpoint);
+        }
+
+        let params = PaginationParams::forward(
+            checkpoint.cursor.clone().map(Cursor::from_string),
+            options.batch_size.max(1),
+        );
+        let page = database
+            .get_users_by_status_cursor(&status, &params)
+            .await?;
+
+        let results: Vec<_> = stream::iter(page.items)
+            .map(|user| async move {
+                let user_id = user.id;
+                let outcome = match analysis_scope(database, &user, filter).await {
+                    Ok(AnalysisScope::Tenant(tenant_id)) => {
+                        analyze(user, tenant_id).await.map(Some)
+                    }
+                    Ok(AnalysisScope::Skip) => Ok(None),
+                    Err(e) => Err(e),
+                };
+                (user_id, outcome)
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .collect()
+            .await;
+
+        for (user_id, outcome) in results {
+            let stored = match outcome {
+                Ok(Some(insights)) => database.store_insights_batch(user_id, insights).await,
+                // Not a member of the filtered tenant
+                Ok(None) => continue,
+                Err(e) => Err(e),
+            };
+            match stored {
+                Ok(ids) => {
+                    checkpoint.users_processed += 1;
+                    checkpoint.insights_stored = checkpoint
+                        .insights_stored
+                        .saturating_add(u32::try_from(ids.len()).unwrap_or(u32::MAX));
+                }
+                Err(e) => {
+                    warn!("Re-analysis of user {} failed: {}", user_id, e);
+                    checkpoint.users_failed += 1;
+                }
+            }
+        }
+
+        checkpoint.cursor = page.next_cursor.map(|cursor| cursor.as_str().to_owned());
+        checkpoint.updated_at = Utc::now();
+        if !page.has_more {
+            checkpoint.completed_at = Some(checkpoint.updated_at);
+        }
+        database.save_reanalysis_checkpoint(&checkpoint).await?;
+
+        info!(
+            job_id,
+            users_processed = checkpoint.users_processed,
+            users_failed = checkpoint.users_failed,
+            insights_stored = checkpoint.insights_stored,
+            "Re-analysis batch finished"
+        );
+        if checkpoint.is_complete() {
+            return Ok(checkpoint);
+        }
+    }
+}
+
+/// Whether and in which tenant a user is analyzed
+enum AnalysisScope {
+    /// Analyze the user in this tenant, or without one if they belong to none
+    Tenant(Option<TenantId>),
+    /// The user is not a member of the filtered tenant
+    Skip,
+}
+
+async fn analysis_scope<DB: DatabaseProvider>(
+    database: &DB,
+    user: &User,
+    filter: &ReanalysisFilter,
+) -> AppResult<AnalysisScope> {
+    let tenants = database.list_tenants_for_user(user.id).await?;
+    Ok(match filter.tenant_id {
+        Some(tenant_id) if tenants.iter().any(|tenant| tenant.id == tenant_id) => {
+            AnalysisScope::Tenant(Some(tenant_id))
+        }
+        Some(_) => AnalysisScope::Skip,
+        None => AnalysisScope::Tenant(tenants.first().map(|tenant| tenant.id)),
+    })
+}
+
+/// Regenerate every matching user's training load insight
+///
+/// Runs [`reanalyze_users`] with [`training_load_insights`] as the analysis,
+/// stopping early when the server shuts down.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`reanalyze_users`]
+pub async fn reanalyze_training_load(
+    resources: &Arc<ServerResources>,
+    job_id: &str,
+    filter: &ReanalysisFilter,
+    options: &ReanalysisOptions,
+) -> AppResult<ReanalysisCheckpoint> {
+    reanalyze_users(
+        resources.database.as_ref(),
+        &resources.shutdown,
+        job_id,
+        filter,
+        options,
+        |user, tenant_id| training_load_insights(resources, user.id, tenant_id),
+    )
+    .await
+}
+
+/// Compute a user's current training load insight from their recent activities
+///
+/// Users without a tenant have no provider connections and get no insight.
+///
+/// # Errors
+///
+/// Returns an error if the user's connections cannot be read or the load
+/// cannot be calculated
+pub async fn training_load_insights(
+    resources: &Arc<ServerResources>,
+    user_id: Uuid,
+    tenant_id: Option<TenantId>,
+) -> AppResult<Vec<Value>> {
+    let Some(tenant_id) = tenant_id else {
+        return Ok(Vec::new());
+    };
+    let now = Utc::now();
+    let query = BulkActivityQuery::with_time_range(
+        Some(now - Duration::days(TRAINING_LOAD_HISTORY_DAYS)),
+        Some(now),
+    );
+    let activities = bulk_get_activities(resources, user_id, tenant_id, &query)
+        .await?
+        .activities;
+
+    let load = TrainingLoadCalculator::new().calculate_training_load(
+        &activities,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let status = TrainingLoadCalculator::interpret_tsb(load.tsb);
+
+    Ok(vec![json!({
+        "type": "training_load",
+        "ctl": load.ctl,
+        "atl": load.atl,
+        "tsb": load.tsb,
+        "status": status,
+        "activity_count": activities.len(),
+        "generated_at": now.to_rfc3339(),
+    })])
+}
@@ -0,0 +1,153 @@
+// ABOUTME: JWT signing key rollover that switches to a new RSA key while the old one keeps validating
+// ABOUTME: Persists the new and retiring keys, deletes keys past their overlap window, and runs as a background worker
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::task::{self, JoinHandle};
+use tokio::time::interval;
+use tracing::{debug, info, warn};
+
+use crate::admin::jwks::{JwksManager, KeyRollover};
+use crate::config::SigningKeyRolloverConfig;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+
+/// Switch signing to a freshly generated key and persist the change
+///
+/// The new key is saved as active before the previous keys are marked as
+/// retiring, so an interrupted rollover never leaves the database without an
+/// active key. Retiring keys stay published in JWKS and keep validating the
+/// tokens they signed until their overlap window ends.
+///
+/// # Errors
+///
+/// Returns an error if key generation fails or the keys cannot be persisted
+pub async fn roll_over_signing_key<DB: DatabaseProvider>(
+    database: &DB,
+    jwks_manager: &Arc<JwksManager>,
+    config: &SigningKeyRolloverConfig,
+) -> AppResult<KeyRollover> {
+    let key_size_bits = config.key_size_bits();
+    let overlap = config.overlap();
+
+    // RSA key generation is CPU-bound and takes seconds at 4096 bits
+    let manager = Arc::clone(jwks_manager);
+    let rollover = task::spawn_blocking(move || manager.begin_rollover(key_size_bits, overlap))
+        .await
+        .map_err(|e| AppError::internal(format!("Signing key generation task failed: {e}")))??;
+
+    let new_key = jwks_manager.get_key(&rollover.new_kid).ok_or_else(|| {
+        AppError::internal(format!("Rolled over key not found: {}", rollover.new_kid))
+    })?;
+    database
+        .save_rsa_keypair(
+            &rollover.new_kid,
+            &new_key.export_private_key_pem()?,
+            &new_key.export_public_key_pem()?,
+            new_key.created_at,
+            true,
+            i32::try_from(key_size_bits).map_err(|e| {
+                AppError::invalid_input(format!("RSA key size too large for database: {e}"))
+            })?,
+        )
+        .await?;
+
+    for kid in &rollover.retiring_kids {
+        let retires_at = jwks_manager
+            .get_key(kid)
+            .and_then(|key| key.retires_at)
+            .unwrap_or(rollover.retires_at);
+        database.retire_rsa_keypair(kid, retires_at).await?;
+    }
+
+    info!(
+        new_kid = %rollover.new_kid,
+        retiring = ?rollover.retiring_kids,
+        retires_at = %rollover.retires_at,
+        "Rolled over JWT signing key"
+    );
+    Ok(rollover)
+}
+
+/// Drop retiring keys whose overlap window has ended and delete them from the database
+///
+/// Returns the key IDs that were removed.
+///
+/// # Errors
+///
+/// Returns an error if a retired key cannot be deleted from the database
+pub async fn retire_expired_signing_keys<DB: DatabaseProvider>(
+    database: &DB,
+    jwks_manager: &JwksManager,
+    now: DateTime<Utc>,
+) -> AppResult<Vec<String>> {
+    let retired = jwks_manager.retire_expired_keys(now);
+    for kid in &retired {
+        database.delete_rsa_keypair(kid).await?;
+    }
+    if !retired.is_empty() {
+        info!(retired = ?retired, "Retired JWT signing keys past their overlap window");
+    }
+    Ok(retired)
+}
+
+/// Background worker that rolls over the signing key when it is due
+pub struct SigningKeyRolloverWorker<DB> {
+    database: Arc<DB>,
+    jwks_manager: Arc<JwksManager>,
+    config: SigningKeyRolloverConfig,
+}
+
+impl<DB: DatabaseProvider + 'static> SigningKeyRolloverWorker<DB> {
+    /// Create a worker for the shared JWKS manager
+    #[must_use]
+    pub const fn new(
+        database: Arc<DB>,
+        jwks_manager: Arc<JwksManager>,
+        config: SigningKeyRolloverConfig,
+    ) -> Self {
+        Self {
+            database,
+            jwks_manager,
+            config,
+        }
+    }
+
+    /// Run one check: roll over if the active key is due, then retire expired keys
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rollover or a key deletion fails
+    pub async fn run_once(&self) -> AppResult<()> {
+        if self.jwks_manager.should_rotate_keys() {
+            roll_over_signing_key(self.database.as_ref(), &self.jwks_manager, &self.config).await?;
+        } else {
+            debug!("JWT signing key is not due for rollover");
+        }
+        retire_expired_signing_keys(self.database.as_ref(), &self.jwks_manager, Utc::now()).await?;
+        Ok(())
+    }
+
+    /// Check on the configured interval until the server shuts down
+    pub fn spawn(self: Arc<Self>) -> JoinHandle<()> {
+        let check_interval = self.config.check_interval();
+        info!(
+            "Starting signing key rollover worker - checking every {:?}, overlap {}h",
+            check_interval,
+            self.config.overlap().num_hours()
+        );
+        tokio::spawn(async move {
+            let mut timer = interval(check_interval);
+            loop {
+                timer.tick().await;
+                if let Err(e) = self.run_once().await {
+                    warn!("Signing key rollover check failed: {}", e);
+                }
+            }
+        })
+    }
+}
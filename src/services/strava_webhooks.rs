@@ -0,0 +1,826 @@
+// ABOUTME: Strava push subscriptions: registration, verification challenge, and event processing
+// ABOUTME: Turns activity create/update/delete and deauthorization events into store updates and notifications
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Strava Push Subscriptions
+//!
+//! Instead of polling, Strava can post an event to a callback URL whenever an
+//! athlete who authorized the application creates, edits, or deletes an
+//! activity. An application has at most one subscription, registered with
+//! [`StravaWebhookManager::ensure_subscription`]. While registering, Strava
+//! sends a `GET` challenge to the callback URL which [`verify_challenge`]
+//! answers.
+//!
+//! Events only carry IDs, so [`StravaWebhookProcessor`] maps the event's
+//! `owner_id` to the users linked to that athlete, fetches the activity with
+//! each user's credentials, and updates the activity's [`ActivitySyncRecord`].
+//! Strava's aspect types are handled as follows:
+//!
+//! - `create` stores the activity, credits it to the user's goals, and queues
+//!   an `activity.synced` webhook event for the tenant
+//! - `update` refreshes the activity; when it became private and can no longer
+//!   be fetched, it is treated as deleted. Updates that leave the content
+//!   unchanged are ignored.
+//! - `delete` marks the activity deleted
+//! - an athlete `update` with `authorized: false` removes the user's Strava
+//!   connection once Strava confirms it by rejecting the stored token
+//!
+//! Events whose `subscription_id` does not match the stored subscription are
+//! rejected, so a forged request cannot trigger fetches. Since subscription IDs
+//! are not secret, a deauthorization is only applied after Strava answers
+//! `401` to each linked user's token ([`strava_access_revoked`]); otherwise it
+//! is ignored and recorded as a security policy violation. Removals are
+//! recorded in the security audit log.
+
+use std::future::Future;
+use std::slice;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use subtle::ConstantTimeEq;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::config::oauth::get_oauth_config;
+use crate::config::StravaWebhookConfig;
+use crate::constants::oauth_providers;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::mcp::resources::ServerResources;
+use crate::models::{
+    Activity, ActivitySyncRecord, StravaAthleteLink, StravaWebhookSubscription, TenantId,
+};
+use crate::protocols::universal::auth_service::AuthService;
+use crate::providers::core::FitnessProvider;
+use crate::security::audit::SecurityAuditor;
+use crate::services::activity_delta::activity_content_hash;
+use crate::services::goal_progress::update_goals_from_activities;
+use crate::services::webhook_delivery::WebhookDispatcher;
+use crate::tenant::webhooks::WebhookEventType;
+
+/// `hub.mode` Strava sends when verifying a callback URL
+const SUBSCRIBE_MODE: &str = "subscribe";
+
+/// Query parameters of Strava's callback verification challenge
+#[derive(Debug, Clone, Deserialize)]
+pub struct StravaChallengeQuery {
+    /// Always `subscribe`
+    #[serde(rename = "hub.mode")]
+    pub mode: String,
+    /// Random string that must be echoed back
+    #[serde(rename = "hub.challenge")]
+    pub challenge: String,
+    /// Token given when the subscription was created
+    #[serde(rename = "hub.verify_token")]
+    pub verify_token: String,
+}
+
+/// Answer Strava's callback verification challenge
+///
+/// Returns the `{"hub.challenge": ...}` body Strava expects.
+///
+/// # Errors
+///
+/// Returns a permission error if the mode is not `subscribe` or the verify
+/// token does not match
+pub fn verify_challenge(query: &StravaChallengeQuery, verify_token: &str) -> AppResult<Value> {
+    let token_matches: bool = query
+        .verify_token
+        .as_bytes()
+        .ct_eq(verify_token.as_bytes())
+        .into();
+    if query.mode != SUBSCRIBE_MODE || !token_matches {
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Strava webhook verification failed",
+        ));
+    }
+    Ok(json!({ "hub.challenge": query.challenge }))
+}
+
+/// What changed about the object an event refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StravaAspectType {
+    /// The object was created
+    Create,
+    /// Some of the object's fields changed (see `updates`)
+    Update,
+    /// The object was deleted
+    Delete,
+}
+
+/// Kind of object an event refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StravaObjectType {
+    /// An activity; `object_id` is the activity ID
+    Activity,
+    /// An athlete; `object_id` is the athlete ID
+    Athlete,
+}
+
+/// Event Strava posts to the callback URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StravaWebhookEvent {
+    /// What changed
+    pub aspect_type: StravaAspectType,
+    /// When the change happened, in seconds since the epoch
+    pub event_time: i64,
+    /// Activity or athlete ID
+    pub object_id: i64,
+    /// Whether `object_id` is an activity or an athlete
+    pub object_type: StravaObjectType,
+    /// Athlete who owns the object
+    pub owner_id: i64,
+    /// Subscription the event was sent for
+    pub subscription_id: i64,
+    /// Changed fields for updates, such as `title`, `type`, `private`, or `authorized`
+    #[serde(default)]
+    pub updates: Map<String, Value>,
+}
+
+impl StravaWebhookEvent {
+    /// Boolean value of a changed field; Strava sends these as `"true"` or `"false"`
+    #[must_use]
+    pub fn update_flag(&self, field: &str) -> Option<bool> {
+        match self.updates.get(field)? {
+            Value::Bool(value) => Some(*value),
+            Value::String(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Whether this event reports that the athlete revoked the application's access
+    #[must_use]
+    pub fn is_deauthorization(&self) -> bool {
+        self.object_type == StravaObjectType::Athlete
+            && self.aspect_type == StravaAspectType::Update
+            && self.update_flag("authorized") == Some(false)
+    }
+}
+
+/// Result of processing one event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StravaEventOutcome {
+    /// The activity was stored or refreshed for these users
+    ActivityStored {
+        /// Users whose store changed
+        users: Vec<Uuid>,
+    },
+    /// The activity was marked deleted for these users
+    ActivityDeleted {
+        /// Users whose store changed
+        users: Vec<Uuid>,
+    },
+    /// The athlete revoked access and these users' Strava connections were removed
+    Deauthorized {
+        /// Users whose connection was removed
+        users: Vec<Uuid>,
+    },
+    /// Nothing to do: no linked user, an unchanged activity, or an unhandled event
+    Ignored,
+}
+
+/// A subscription as listed by Strava
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoteSubscription {
+    /// Subscription ID
+    pub id: i64,
+    /// Callback URL events are posted to
+    pub callback_url: String,
+}
+
+/// Client for Strava's push subscription API
+pub struct StravaSubscriptionClient {
+    client: Client,
+    endpoint: String,
+    client_id: String,
+    client_secret: String,
+}
+
+impl StravaSubscriptionClient {
+    /// Create a client for the application identified by `client_id`
+    ///
+    /// `api_base_url` is Strava's API base, e.g. `https://www.strava.com/api/v3`.
+    #[must_use]
+    pub fn new(api_base_url: &str, client_id: String, client_secret: String) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint: format!("{}/push_subscriptions", api_base_url.trim_end_matches('/')),
+            client_id,
+            client_secret,
+        }
+    }
+
+    /// Create a client with the server's Strava application credentials
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the Strava client ID or secret is not set
+    pub fn from_server_config(api_base_url: &str) -> AppResult<Self> {
+        let oauth = get_oauth_config(oauth_providers::STRAVA);
+        let (Some(client_id), Some(client_secret)) = (oauth.client_id, oauth.client_secret) else {
+            return Err(AppError::config(
+                "Strava webhooks require STRAVA_CLIENT_ID and STRAVA_CLIENT_SECRET",
+            ));
+        };
+        Ok(Self::new(api_base_url, client_id, client_secret))
+    }
+
+    /// Strava application (client) ID
+    #[must_use]
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// List the application's subscriptions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Strava cannot be reached or rejects the request
+    pub async fn list(&self) -> AppResult<Vec<RemoteSubscription>> {
+        let response = self
+            .client
+            .get(&self.endpoint)
+            .query(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| AppError::external_service("Strava", format!("List failed: {e}")))?;
+        Self::check_status(&response, "list subscriptions")?;
+        response.json().await.map_err(|e| {
+            AppError::external_service("Strava", format!("Invalid subscription list: {e}"))
+        })
+    }
+
+    /// Register `callback_url`; Strava verifies it with a challenge before answering
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Strava cannot be reached or the callback fails verification
+    pub async fn create(&self, callback_url: &str, verify_token: &str) -> AppResult<i64> {
+        #[derive(Deserialize)]
+        struct Created {
+            id: i64,
+        }
+
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .form(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("callback_url", callback_url),
+                ("verify_token", verify_token),
+            ])
+            .send()
+            .await
+            .map_err(|e| AppError::external_service("Strava", format!("Subscribe failed: {e}")))?;
+        Self::check_status(&response, "create subscription")?;
+        let created: Created = response.json().await.map_err(|e| {
+            AppError::external_service("Strava", format!("Invalid subscription response: {e}"))
+        })?;
+        Ok(created.id)
+    }
+
+    /// Delete a subscription
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Strava cannot be reached or rejects the request
+    pub async fn delete(&self, subscription_id: i64) -> AppResult<()> {
+        let response = self
+            .client
+            .delete(format!("{}/{subscription_id}", self.endpoint))
+            .query(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::external_service("Strava", format!("Unsubscribe failed: {e}"))
+            })?;
+        Self::check_status(&response, "delete subscription")
+    }
+
+    fn check_status(response: &reqwest::Response, action: &str) -> AppResult<()> {
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(AppError::external_service(
+                "Strava",
+                format!("Failed to {action}: HTTP {}", response.status()),
+            ))
+        }
+    }
+}
+
+/// Link the Strava athlete authenticated by `access_token` to the user who connected them
+///
+/// Webhook events identify athletes by their Strava ID only, so the link is
+/// made when the user completes the Strava OAuth flow.
+///
+/// # Errors
+///
+/// Returns an error if the athlete cannot be fetched or the link cannot be stored
+pub async fn link_strava_athlete<D: DatabaseProvider>(
+    database: &D,
+    api_base_url: &str,
+    access_token: &str,
+    user_id: Uuid,
+    tenant_id: TenantId,
+) -> AppResult<StravaAthleteLink> {
+    #[derive(Deserialize)]
+    struct AthleteSummary {
+        id: i64,
+    }
+
+    let response = Client::new()
+        .get(format!("{}/athlete", api_base_url.trim_end_matches('/')))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(|e| AppError::external_service("Strava", format!("Athlete fetch failed: {e}")))?;
+    StravaSubscriptionClient::check_status(&response, "fetch athlete")?;
+    let athlete: AthleteSummary = response
+        .json()
+        .await
+        .map_err(|e| AppError::external_service("Strava", format!("Invalid athlete: {e}")))?;
+
+    let link = StravaAthleteLink {
+        athlete_id: athlete.id,
+        user_id,
+        tenant_id,
+        linked_at: Utc::now(),
+    };
+    database.link_strava_athlete(&link).await?;
+    debug!(%user_id, "Linked Strava athlete {}", athlete.id);
+    Ok(link)
+}
+
+/// Ask Strava whether `access_token` was revoked
+///
+/// Returns `true` when Strava answers `401 Unauthorized` and `false` when it
+/// still accepts the token.
+///
+/// # Errors
+///
+/// Returns an error if Strava cannot be reached or answers with another error
+pub async fn strava_access_revoked(api_base_url: &str, access_token: &str) -> AppResult<bool> {
+    let response = Client::new()
+        .get(format!("{}/athlete", api_base_url.trim_end_matches('/')))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(|e| AppError::external_service("Strava", format!("Athlete fetch failed: {e}")))?;
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Ok(true);
+    }
+    StravaSubscriptionClient::check_status(&response, "fetch athlete")?;
+    Ok(false)
+}
+
+/// Registers this server's push subscription with Strava
+pub struct StravaWebhookManager<'a> {
+    resources: &'a ServerResources,
+    client: StravaSubscriptionClient,
+}
+
+impl<'a> StravaWebhookManager<'a> {
+    /// Create a manager using `client` to talk to Strava
+    #[must_use]
+    pub const fn new(resources: &'a ServerResources, client: StravaSubscriptionClient) -> Self {
+        Self { resources, client }
+    }
+
+    /// Make sure Strava posts events to the configured callback URL
+    ///
+    /// An existing subscription for the same URL is kept. Strava allows one
+    /// subscription per application, so one for another URL is deleted and
+    /// replaced. The subscription is stored so incoming events can be checked
+    /// against its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if webhooks are not configured, Strava rejects a
+    /// request, or the subscription cannot be stored
+    pub async fn ensure_subscription(
+        &self,
+        config: &StravaWebhookConfig,
+    ) -> AppResult<StravaWebhookSubscription> {
+        let (Some(callback_url), Some(verify_token)) = (&config.callback_url, &config.verify_token)
+        else {
+            return Err(AppError::config("Strava webhooks are not configured"));
+        };
+
+        let mut subscription_id = None;
+        for remote in self.client.list().await? {
+            if remote.callback_url == *callback_url {
+                subscription_id = Some(remote.id);
+            } else {
+                info!(
+                    "Replacing Strava subscription {} for {}",
+                    remote.id, remote.callback_url
+                );
+                self.client.delete(remote.id).await?;
+            }
+        }
+        let id = match subscription_id {
+            Some(id) => id,
+            None => self.client.create(callback_url, verify_token).await?,
+        };
+
+        let subscription = StravaWebhookSubscription {
+            id,
+            client_id: self.client.client_id().to_owned(),
+            callback_url: callback_url.clone(),
+            created_at: Utc::now(),
+        };
+        self.resources
+            .database
+            .save_strava_webhook_subscription(&subscription)
+            .await?;
+        info!(
+            "Strava push subscription {} active for {}",
+            id, callback_url
+        );
+        Ok(subscription)
+    }
+}
+
+/// Applies Strava events to the activity store
+pub struct StravaWebhookProcessor {
+    resources: Arc<ServerResources>,
+    api_base_url: String,
+}
+
+impl StravaWebhookProcessor {
+    /// Create a processor talking to the configured Strava API
+    #[must_use]
+    pub fn new(resources: Arc<ServerResources>) -> Self {
+        let api_base_url = resources
+            .config
+            .external_services
+            .strava_api
+            .base_url
+            .clone();
+        Self {
+            resources,
+            api_base_url,
+        }
+    }
+
+    /// Use another Strava API base URL for deauthorization checks
+    #[must_use]
+    pub fn with_api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        self.api_base_url = api_base_url.into();
+        self
+    }
+
+    /// Check that an event was sent for a subscription this server registered
+    ///
+    /// # Errors
+    ///
+    /// Returns a permission error for unknown subscription IDs
+    pub async fn verify_subscription(&self, event: &StravaWebhookEvent) -> AppResult<()> {
+        self.resources
+            .database
+            .get_strava_webhook_subscription(event.subscription_id)
+            .await?
+            .map(|_| ())
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorCode::PermissionDenied,
+                    format!("Unknown Strava subscription {}", event.subscription_id),
+                )
+            })
+    }
+
+    /// Process an event, authenticating with each linked user's stored Strava token
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a provider cannot be created or a store update fails
+    pub async fn process_with_stored_tokens(
+        &self,
+        event: &StravaWebhookEvent,
+    ) -> AppResult<StravaEventOutcome> {
+        let auth_service = AuthService::new(Arc::clone(&self.resources));
+        let auth_service = &auth_service;
+        self.process_event(event, |user_id, tenant_id| async move {
+            auth_service
+                .create_authenticated_provider(
+                    oauth_providers::STRAVA,
+                    user_id,
+                    Some(&tenant_id.to_string()),
+                )
+                .await
+                .map_err(|response| {
+                    AppError::auth_invalid(
+                        response
+                            .error
+                            .unwrap_or_else(|| "Strava authentication failed".to_owned()),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Process an event, using `create_provider` to get each linked user's Strava provider
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a provider cannot be created, an activity fetch
+    /// fails for another reason than the activity being gone, or a store
+    /// update fails
+    pub async fn process_event<F, Fut>(
+        &self,
+        event: &StravaWebhookEvent,
+        create_provider: F,
+    ) -> AppResult<StravaEventOutcome>
+    where
+        F: Fn(Uuid, TenantId) -> Fut,
+        Fut: Future<Output = AppResult<Box<dyn FitnessProvider>>>,
+    {
+        let links = self
+            .resources
+            .database
+            .get_strava_athlete_links(event.owner_id)
+            .await?;
+        if links.is_empty() {
+            debug!(
+                "Ignoring Strava event for unlinked athlete {}",
+                event.owner_id
+            );
+            return Ok(StravaEventOutcome::Ignored);
+        }
+
+        if event.is_deauthorization() {
+            return self.deauthorize(event.owner_id, &links).await;
+        }
+        if event.object_type != StravaObjectType::Activity {
+            return Ok(StravaEventOutcome::Ignored);
+        }
+
+        let activity_id = event.object_id.to_string();
+        let mut stored = Vec::new();
+        let mut deleted = Vec::new();
+        for link in &links {
+            let gone = match event.aspect_type {
+                StravaAspectType::Delete => true,
+                StravaAspectType::Create | StravaAspectType::Update => {
+                    let provider = create_provider(link.user_id, link.tenant_id).await?;
+                    match provider.get_activity(&activity_id).await {
+                        Ok(activity) => {
+                            if self.store_activity(link, event, activity).await? {
+                                stored.push(link.user_id);
+                            }
+                            false
+                        }
+                        // A private activity may no longer be visible with the granted scopes
+                        Err(e)
+                            if e.code == ErrorCode::ResourceNotFound
+                                || event.update_flag("private") == Some(true) =>
+                        {
+                            debug!(
+                                "Strava activity {} is no longer visible: {}",
+                                activity_id, e
+                            );
+                            true
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+            };
+            if gone && self.mark_deleted(link, &activity_id).await? {
+                deleted.push(link.user_id);
+            }
+        }
+
+        Ok(match (stored.is_empty(), deleted.is_empty()) {
+            (false, _) => StravaEventOutcome::ActivityStored { users: stored },
+            (true, false) => StravaEventOutcome::ActivityDeleted { users: deleted },
+            (true, true) => StravaEventOutcome::Ignored,
+        })
+    }
+
+    /// Record a fetched activity; returns `false` when its content did not change
+    async fn store_activity(
+        &self,
+        link: &StravaAthleteLink,
+        event: &StravaWebhookEvent,
+        activity: Activity,
+    ) -> AppResult<bool> {
+        let content_hash = activity_content_hash(&activity)?;
+        let previous = self
+            .find_record(link, activity.id(), Some(activity.start_date()))
+            .await?;
+        if previous
+            .as_ref()
+            .is_some_and(|r| r.content_hash == content_hash && r.deleted_at.is_none())
+        {
+            return Ok(false);
+        }
+
+        let record = ActivitySyncRecord {
+            activity_id: activity.id().to_owned(),
+            start_date: activity.start_date(),
+            content_hash,
+            updated_at: Utc::now(),
+            deleted_at: None,
+        };
+        self.resources
+            .database
+            .upsert_activity_sync_records(
+                link.user_id,
+                link.tenant_id,
+                oauth_providers::STRAVA,
+                &[record],
+            )
+            .await?;
+        self.resources
+            .activity_cache
+            .invalidate(link.user_id, oauth_providers::STRAVA);
+
+        let aspect = if previous.is_none() {
+            StravaAspectType::Create
+        } else {
+            StravaAspectType::Update
+        };
+        if event.aspect_type == StravaAspectType::Create && previous.is_none() {
+            if let Err(e) = update_goals_from_activities(
+                &self.resources,
+                link.user_id,
+                link.tenant_id,
+                oauth_providers::STRAVA,
+                slice::from_ref(&activity),
+            )
+            .await
+            {
+                warn!(user_id = %link.user_id, error = %e, "Failed to credit Strava activity to goals");
+            }
+        }
+        self.notify(link, activity.id(), aspect, Some(&activity))
+            .await;
+        Ok(true)
+    }
+
+    /// Mark a recorded activity deleted; returns `false` when it was unknown or already deleted
+    async fn mark_deleted(&self, link: &StravaAthleteLink, activity_id: &str) -> AppResult<bool> {
+        let Some(mut record) = self.find_record(link, activity_id, None).await? else {
+            return Ok(false);
+        };
+        if record.deleted_at.is_some() {
+            return Ok(false);
+        }
+
+        let now = Utc::now();
+        record.updated_at = now;
+        record.deleted_at = Some(now);
+        self.resources
+            .database
+            .upsert_activity_sync_records(
+                link.user_id,
+                link.tenant_id,
+                oauth_providers::STRAVA,
+                &[record],
+            )
+            .await?;
+        self.resources
+            .activity_cache
+            .invalidate(link.user_id, oauth_providers::STRAVA);
+        self.notify(link, activity_id, StravaAspectType::Delete, None)
+            .await;
+        Ok(true)
+    }
+
+    /// Look up the sync record of an activity, narrowing the scan by its start time when known
+    async fn find_record(
+        &self,
+        link: &StravaAthleteLink,
+        activity_id: &str,
+        started_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<ActivitySyncRecord>> {
+        Ok(self
+            .resources
+            .database
+            .get_activity_sync_records(
+                link.user_id,
+                link.tenant_id,
+                oauth_providers::STRAVA,
+                started_at.unwrap_or(DateTime::UNIX_EPOCH),
+            )
+            .await?
+            .into_iter()
+            .find(|record| record.activity_id == activity_id))
+    }
+
+    /// Remove the Strava connection of every user linked to a deauthorized athlete
+    ///
+    /// Nothing is removed unless Strava confirms that none of the linked users'
+    /// tokens grants access anymore.
+    async fn deauthorize(
+        &self,
+        athlete_id: i64,
+        links: &[StravaAthleteLink],
+    ) -> AppResult<StravaEventOutcome> {
+        for link in links {
+            if !self.access_revoked(link).await? {
+                warn!(user_id = %link.user_id, "Ignoring unconfirmed deauthorization of Strava athlete {}", athlete_id);
+                self.audit_deauthorization(link, athlete_id, false).await;
+                return Ok(StravaEventOutcome::Ignored);
+            }
+        }
+
+        let database = &self.resources.database;
+        for link in links {
+            database
+                .delete_user_oauth_token(link.user_id, link.tenant_id, oauth_providers::STRAVA)
+                .await?;
+            database
+                .remove_provider_connection(link.user_id, link.tenant_id, oauth_providers::STRAVA)
+                .await?;
+            self.resources
+                .activity_cache
+                .invalidate(link.user_id, oauth_providers::STRAVA);
+            info!(user_id = %link.user_id, "Strava access revoked by athlete {}", athlete_id);
+            self.audit_deauthorization(link, athlete_id, true).await;
+        }
+        database.unlink_strava_athlete(athlete_id).await?;
+
+        Ok(StravaEventOutcome::Deauthorized {
+            users: links.iter().map(|link| link.user_id).collect(),
+        })
+    }
+
+    /// Whether Strava no longer grants access with the user's stored token
+    async fn access_revoked(&self, link: &StravaAthleteLink) -> AppResult<bool> {
+        let token = AuthService::new(Arc::clone(&self.resources))
+            .get_valid_token(
+                link.user_id,
+                oauth_providers::STRAVA,
+                Some(&link.tenant_id.to_string()),
+            )
+            .await
+            .map_err(|e| AppError::database(format!("Strava token lookup failed: {e}")))?;
+        // No token left, or Strava refused to refresh it: there is no access to revoke
+        let Some(token) = token else {
+            return Ok(true);
+        };
+        strava_access_revoked(&self.api_base_url, &token.access_token).await
+    }
+
+    /// Record a deauthorization in the security audit log
+    async fn audit_deauthorization(
+        &self,
+        link: &StravaAthleteLink,
+        athlete_id: i64,
+        confirmed: bool,
+    ) {
+        if let Err(e) = SecurityAuditor::new(Arc::clone(&self.resources.database))
+            .log_strava_deauthorization(link.user_id, link.tenant_id, athlete_id, confirmed)
+            .await
+        {
+            warn!(user_id = %link.user_id, error = %e, "Failed to audit Strava deauthorization");
+        }
+    }
+
+    /// Queue an `activity.synced` event for the tenant's webhooks
+    async fn notify(
+        &self,
+        link: &StravaAthleteLink,
+        activity_id: &str,
+        aspect: StravaAspectType,
+        activity: Option<&Activity>,
+    ) {
+        let data = json!({
+            "user_id": link.user_id,
+            "provider": oauth_providers::STRAVA,
+            "activity_id": activity_id,
+            "aspect_type": aspect,
+            "activity": activity,
+        });
+        let dispatcher = match WebhookDispatcher::new(Arc::clone(&self.resources.database)) {
+            Ok(dispatcher) => dispatcher,
+            Err(e) => {
+                warn!(user_id = %link.user_id, error = %e, "Failed to queue activity.synced webhook event");
+                return;
+            }
+        };
+        if let Err(e) = dispatcher
+            .enqueue(link.tenant_id, WebhookEventType::ActivitySynced, data)
+            .await
+        {
+            warn!(user_id = %link.user_id, error = %e, "Failed to queue activity.synced webhook event");
+        }
+    }
+}
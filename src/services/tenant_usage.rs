@@ -0,0 +1,99 @@
+// ABOUTME: Per-tenant tool usage reports for usage-based billing
+// ABOUTME: Rolls daily per-tool call counts up into per-tool totals and a grand total for a date range
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::models::{TenantDailyToolUsage, TenantId, ToolCallCounts};
+
+/// Calls of one tool by a tenant's members over the whole report range
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TenantToolUsageTotal {
+    /// Tool that was called
+    pub tool_name: String,
+    /// Calls over the report range
+    pub calls: ToolCallCounts,
+    /// Calls across every authentication method
+    pub total_calls: u64,
+}
+
+/// Tool usage of a tenant's members over `[start, end)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TenantToolUsageReport {
+    /// Tenant the usage is billed to
+    pub tenant_id: TenantId,
+    /// Start of the range (inclusive)
+    pub start: DateTime<Utc>,
+    /// End of the range (exclusive)
+    pub end: DateTime<Utc>,
+    /// Calls of every tool across every authentication method
+    pub total_calls: u64,
+    /// Per-tool totals, most called first
+    pub tools: Vec<TenantToolUsageTotal>,
+    /// Per-tool counts for each UTC day with usage, oldest first
+    pub daily: Vec<TenantDailyToolUsage>,
+}
+
+impl TenantToolUsageReport {
+    /// Build a report from daily per-tool counts
+    #[must_use]
+    pub fn from_daily(
+        tenant_id: TenantId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        daily: Vec<TenantDailyToolUsage>,
+    ) -> Self {
+        let mut by_tool: BTreeMap<&str, ToolCallCounts> = BTreeMap::new();
+        for day in &daily {
+            *by_tool.entry(day.tool_name.as_str()).or_default() += day.calls;
+        }
+        let mut tools: Vec<TenantToolUsageTotal> = by_tool
+            .into_iter()
+            .map(|(tool_name, calls)| TenantToolUsageTotal {
+                tool_name: tool_name.to_owned(),
+                calls,
+                total_calls: calls.total(),
+            })
+            .collect();
+        tools.sort_by(|a, b| b.total_calls.cmp(&a.total_calls));
+
+        Self {
+            tenant_id,
+            start,
+            end,
+            total_calls: tools.iter().map(|tool| tool.total_calls).sum(),
+            tools,
+            daily,
+        }
+    }
+}
+
+/// Report the tool calls made by a tenant's members over `[start, end)`
+///
+/// # Errors
+///
+/// Returns an error if the range is empty or the usage cannot be read
+pub async fn tenant_tool_usage_report<DB: DatabaseProvider>(
+    database: &DB,
+    tenant_id: TenantId,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> AppResult<TenantToolUsageReport> {
+    if start >= end {
+        return Err(AppError::invalid_input("start must be before end"));
+    }
+
+    let daily = database
+        .get_tenant_daily_tool_usage(tenant_id, start, end)
+        .await?;
+    Ok(TenantToolUsageReport::from_daily(
+        tenant_id, start, end, daily,
+    ))
+}
@@ -0,0 +1,375 @@
+// ABOUTME: Outbound webhook delivery worker that pushes signed tenant events to callback URLs
+// ABOUTME: Queues one delivery per subscribed webhook, retries with backoff, and dead-letters after max attempts
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use reqwest::{header, Client, StatusCode};
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+use tokio::time::interval;
+use tracing::{debug, info, warn};
+use url::{Host, Url};
+use uuid::Uuid;
+
+use crate::database_plugins::{factory::Database, DatabaseProvider};
+use crate::errors::{AppError, AppResult};
+use crate::models::TenantId;
+use crate::tenant::webhooks::{
+    sign_payload, TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+    WebhookEventType, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER,
+};
+
+/// Per-request timeout for webhook POSTs
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a claimed delivery is hidden from other workers
+const CLAIM_LEASE: Duration = Duration::from_secs(60);
+
+/// Deliveries claimed per poll
+const CLAIM_BATCH_SIZE: u32 = 50;
+
+/// Longest error message stored for an attempt
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Prefix of generated webhook signing secrets
+const SECRET_PREFIX: &str = "whsec_";
+
+/// How failed deliveries are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    /// Attempts made before a delivery is dead-lettered
+    pub max_attempts: u32,
+    /// Delay before the second attempt; doubles for each later attempt
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            initial_backoff: Duration::from_secs(30),
+            max_backoff: Duration::from_secs(3_600),
+        }
+    }
+}
+
+impl WebhookRetryPolicy {
+    /// Delay after `attempts` failed attempts
+    #[must_use]
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(16);
+        self.initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff)
+    }
+}
+
+/// Result of a single HTTP attempt
+enum AttemptOutcome {
+    /// Receiver acknowledged with 2xx
+    Delivered(StatusCode),
+    /// 5xx, 408, 429, or no response; worth retrying
+    Retryable(Option<StatusCode>, String),
+    /// Any other response, or a disabled webhook; retrying would not change the answer
+    Rejected(Option<StatusCode>, String),
+}
+
+/// Generate a random signing secret for a new webhook
+#[must_use]
+pub fn generate_webhook_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("{SECRET_PREFIX}{}", URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Check that a webhook URL is absolute and uses HTTPS
+///
+/// Plain HTTP is accepted for loopback hosts so receivers can be tested locally.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` if the URL cannot be parsed, has no host, or uses
+/// any scheme other than HTTPS for a non-loopback host
+pub fn validate_webhook_url(url: &str) -> AppResult<Url> {
+    let parsed = Url::parse(url)
+        .map_err(|e| AppError::invalid_input(format!("Invalid webhook URL: {e}")))?;
+    let is_loopback = match parsed.host() {
+        Some(Host::Domain(domain)) => domain == "localhost",
+        Some(Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(Host::Ipv6(ip)) => ip.is_loopback(),
+        None => return Err(AppError::invalid_input("Webhook URL must include a host")),
+    };
+    match parsed.scheme() {
+        "https" => Ok(parsed),
+        "http" if is_loopback => Ok(parsed),
+        scheme => Err(AppError::invalid_input(format!(
+            "Webhook URL must use https, got {scheme}"
+        ))),
+    }
+}
+
+/// Queues tenant events and delivers them to registered webhooks
+///
+/// Events are persisted as pending deliveries before any request is made, and
+/// a delivery only leaves the pending state once the receiver answers 2xx or
+/// retries are exhausted. Delivery is therefore at-least-once: a crash between
+/// sending and recording an attempt causes the delivery to be sent again.
+pub struct WebhookDispatcher {
+    database: Arc<Database>,
+    client: Client,
+    retry_policy: WebhookRetryPolicy,
+}
+
+impl WebhookDispatcher {
+    /// Create a dispatcher with the default retry policy
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built
+    pub fn new(database: Arc<Database>) -> AppResult<Self> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("Pierre-Webhooks/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| AppError::internal(format!("Failed to build webhook client: {e}")))?;
+        Ok(Self {
+            database,
+            client,
+            retry_policy: WebhookRetryPolicy::default(),
+        })
+    }
+
+    /// Replace the retry policy
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: WebhookRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Queue an event for every active webhook of `tenant_id` subscribed to it
+    ///
+    /// Returns the IDs of the queued deliveries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if webhooks cannot be listed or a delivery cannot be queued
+    pub async fn enqueue(
+        &self,
+        tenant_id: TenantId,
+        event_type: WebhookEventType,
+        data: Value,
+    ) -> AppResult<Vec<Uuid>> {
+        let webhooks = self.database.list_tenant_webhooks(Some(tenant_id)).await?;
+        let now = Utc::now();
+        let payload = json!({
+            "id": Uuid::new_v4(),
+            "type": event_type,
+            "tenant_id": tenant_id,
+            "created_at": now.to_rfc3339(),
+            "data": data,
+        });
+
+        let mut queued = Vec::new();
+        for webhook in webhooks.iter().filter(|w| w.subscribes_to(event_type)) {
+            let delivery = WebhookDelivery {
+                id: Uuid::new_v4(),
+                webhook_id: webhook.id,
+                tenant_id,
+                event_type,
+                payload: payload.clone(),
+                status: WebhookDeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: now,
+                last_status_code: None,
+                last_error: None,
+                created_at: now,
+                completed_at: None,
+            };
+            self.database.create_webhook_delivery(&delivery).await?;
+            queued.push(delivery.id);
+        }
+
+        debug!(
+            "Queued {} {} webhook deliveries for tenant {}",
+            queued.len(),
+            event_type,
+            tenant_id
+        );
+        Ok(queued)
+    }
+
+    /// Make one attempt for every delivery that is currently due
+    ///
+    /// Returns the number of deliveries attempted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if due deliveries cannot be claimed
+    pub async fn process_due_deliveries(&self) -> AppResult<usize> {
+        let now = Utc::now();
+        let lease_until = now
+            + chrono::Duration::from_std(CLAIM_LEASE)
+                .map_err(|e| AppError::internal(format!("Invalid claim lease: {e}")))?;
+        let deliveries = self
+            .database
+            .claim_due_webhook_deliveries(now, lease_until, CLAIM_BATCH_SIZE)
+            .await?;
+
+        let count = deliveries.len();
+        for delivery in deliveries {
+            let delivery_id = delivery.id;
+            // A failure here leaves the delivery claimed; it is retried once the lease expires
+            if let Err(e) = self.attempt_delivery(delivery).await {
+                warn!("Failed to record webhook delivery {}: {}", delivery_id, e);
+            }
+        }
+        Ok(count)
+    }
+
+    /// Poll for due deliveries on a background task until the runtime shuts down
+    #[must_use]
+    pub fn spawn(self: Arc<Self>, poll_interval: Duration) -> JoinHandle<()> {
+        info!(
+            "Starting webhook delivery worker - polling every {:?}",
+            poll_interval
+        );
+        tokio::spawn(async move {
+            let mut timer = interval(poll_interval);
+            loop {
+                timer.tick().await;
+                if let Err(e) = self.process_due_deliveries().await {
+                    warn!("Webhook delivery poll failed: {}", e);
+                }
+            }
+        })
+    }
+
+    /// Send one attempt for `delivery` and persist the outcome
+    async fn attempt_delivery(&self, mut delivery: WebhookDelivery) -> AppResult<()> {
+        let attempted_at = Utc::now();
+        let started = Instant::now();
+        let outcome = match self
+            .database
+            .get_tenant_webhook(delivery.webhook_id)
+            .await?
+        {
+            Some(webhook) if webhook.active => self.send(&webhook, &delivery).await,
+            Some(_) => AttemptOutcome::Rejected(None, "Webhook is inactive".to_owned()),
+            // Deleting a webhook deletes its deliveries, so there is nothing left to record
+            None => return Ok(()),
+        };
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        delivery.attempts += 1;
+        let (status_code, error) = match outcome {
+            AttemptOutcome::Delivered(status) => {
+                delivery.status = WebhookDeliveryStatus::Delivered;
+                delivery.completed_at = Some(attempted_at);
+                (Some(status.as_u16()), None)
+            }
+            AttemptOutcome::Retryable(status, error)
+                if delivery.attempts < self.retry_policy.max_attempts =>
+            {
+                let backoff =
+                    chrono::Duration::from_std(self.retry_policy.backoff(delivery.attempts))
+                        .unwrap_or(chrono::Duration::MAX);
+                delivery.next_attempt_at = attempted_at
+                    .checked_add_signed(backoff)
+                    .unwrap_or(DateTime::<Utc>::MAX_UTC);
+                (status.map(|s| s.as_u16()), Some(error))
+            }
+            AttemptOutcome::Retryable(status, error) | AttemptOutcome::Rejected(status, error) => {
+                delivery.status = WebhookDeliveryStatus::DeadLetter;
+                delivery.completed_at = Some(attempted_at);
+                (status.map(|s| s.as_u16()), Some(error))
+            }
+        };
+        delivery.last_status_code = status_code;
+        delivery.last_error.clone_from(&error);
+
+        if delivery.status == WebhookDeliveryStatus::DeadLetter {
+            warn!(
+                "Webhook delivery {} to webhook {} dead-lettered after {} attempts: {}",
+                delivery.id,
+                delivery.webhook_id,
+                delivery.attempts,
+                error.as_deref().unwrap_or("unknown error")
+            );
+        }
+
+        let attempt = WebhookDeliveryAttempt {
+            delivery_id: delivery.id,
+            attempt: delivery.attempts,
+            status_code,
+            error,
+            duration_ms,
+            attempted_at,
+        };
+        self.database
+            .record_webhook_delivery_attempt(&delivery, &attempt)
+            .await
+    }
+
+    /// POST the signed payload to the webhook URL
+    async fn send(&self, webhook: &TenantWebhook, delivery: &WebhookDelivery) -> AttemptOutcome {
+        let body = match serde_json::to_vec(&delivery.payload) {
+            Ok(body) => body,
+            Err(e) => {
+                return AttemptOutcome::Retryable(None, format!("Failed to encode payload: {e}"))
+            }
+        };
+        let signature = sign_payload(&webhook.secret, Utc::now().timestamp(), &body);
+
+        let response = self
+            .client
+            .post(&webhook.url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(EVENT_HEADER, delivery.event_type.as_str())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .body(body)
+            .send()
+            .await;
+
+        match response {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    return AttemptOutcome::Delivered(status);
+                }
+                let error = truncate_error(format!("Receiver responded with {status}"));
+                if status.is_server_error()
+                    || status == StatusCode::REQUEST_TIMEOUT
+                    || status == StatusCode::TOO_MANY_REQUESTS
+                {
+                    AttemptOutcome::Retryable(Some(status), error)
+                } else {
+                    AttemptOutcome::Rejected(Some(status), error)
+                }
+            }
+            Err(e) => {
+                AttemptOutcome::Retryable(None, truncate_error(format!("Request failed: {e}")))
+            }
+        }
+    }
+}
+
+fn truncate_error(mut error: String) -> String {
+    if error.len() > MAX_ERROR_LENGTH {
+        let mut end = MAX_ERROR_LENGTH;
+        while !error.is_char_boundary(end) {
+            end -= 1;
+        }
+        error.truncate(end);
+    }
+    error
+}
@@ -1,9 +1,13 @@
 // ABOUTME: SSE stream implementation for A2A task progress updates
-// ABOUTME: Provides real-time task status changes and completion notifications
+// ABOUTME: Provides real-time task status changes, result chunks, and completion notifications
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
+use crate::a2a::protocol::TaskStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tokio::sync::broadcast;
 
 /// SSE stream for A2A task status updates
@@ -46,3 +50,69 @@ impl A2ATaskStream {
         self.sender.receiver_count()
     }
 }
+
+/// Event streamed to `GET /a2a/tasks/:task_id/events` subscribers
+///
+/// Serialized as the SSE `data` payload; [`A2ATaskEvent::event_name`] is used as
+/// the SSE event name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum A2ATaskEvent {
+    /// Task moved to a new status
+    Status {
+        /// Task the event belongs to
+        task_id: String,
+        /// Status the task moved to
+        status: TaskStatus,
+        /// When the transition happened
+        timestamp: DateTime<Utc>,
+    },
+    /// Incremental piece of the task result produced while running
+    Chunk {
+        /// Task the event belongs to
+        task_id: String,
+        /// Partial result data
+        data: Value,
+    },
+    /// Final event carrying the result or error; the stream closes after it
+    Done {
+        /// Task the event belongs to
+        task_id: String,
+        /// Terminal status of the task
+        status: TaskStatus,
+        /// Task result (if completed successfully)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        result: Option<Value>,
+        /// Error message (if failed)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+}
+
+impl A2ATaskEvent {
+    /// SSE event name for this event
+    #[must_use]
+    pub const fn event_name(&self) -> &'static str {
+        match self {
+            Self::Status { .. } => "status",
+            Self::Chunk { .. } => "chunk",
+            Self::Done { .. } => "done",
+        }
+    }
+
+    /// Task the event belongs to
+    #[must_use]
+    pub fn task_id(&self) -> &str {
+        match self {
+            Self::Status { task_id, .. }
+            | Self::Chunk { task_id, .. }
+            | Self::Done { task_id, .. } => task_id,
+        }
+    }
+
+    /// Whether this is the final event of the stream
+    #[must_use]
+    pub const fn is_done(&self) -> bool {
+        matches!(self, Self::Done { .. })
+    }
+}
@@ -5,7 +5,9 @@
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 use super::{
-    a2a_task_stream::A2ATaskStream, notifications::NotificationStream, protocol::McpProtocolStream,
+    a2a_task_stream::{A2ATaskEvent, A2ATaskStream},
+    notifications::NotificationStream,
+    protocol::McpProtocolStream,
 };
 use crate::constants::network_config::SSE_BROADCAST_CHANNEL_SIZE;
 use crate::errors::AppError;
@@ -16,7 +18,7 @@ use crate::models::OAuthNotification;
 use chrono::{Duration, Utc};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::{broadcast, RwLock};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::middleware::redact_session_id;
@@ -60,6 +62,8 @@ pub struct SseManager {
     notification_streams: Arc<RwLock<HashMap<Uuid, NotificationStream>>>,
     protocol_streams: Arc<RwLock<HashMap<String, McpProtocolStream>>>,
     a2a_task_streams: Arc<RwLock<HashMap<String, A2ATaskStream>>>,
+    /// Per-task event channels shared by all `/a2a/tasks/:task_id/events` subscribers
+    a2a_task_event_streams: Arc<RwLock<HashMap<String, A2ATaskStream>>>,
     connection_metadata: Arc<RwLock<HashMap<String, ConnectionMetadata>>>,
     /// Maps `user_id` to their active `session_ids` for protocol streams
     user_sessions: Arc<RwLock<HashMap<Uuid, Vec<String>>>>,
@@ -75,6 +79,7 @@ impl SseManager {
             notification_streams: Arc::new(RwLock::new(HashMap::new())),
             protocol_streams: Arc::new(RwLock::new(HashMap::new())),
             a2a_task_streams: Arc::new(RwLock::new(HashMap::new())),
+            a2a_task_event_streams: Arc::new(RwLock::new(HashMap::new())),
             connection_metadata: Arc::new(RwLock::new(HashMap::new())),
             user_sessions: Arc::new(RwLock::new(HashMap::new())),
             buffer_size,
@@ -492,4 +497,58 @@ impl SseManager {
         let streams = self.a2a_task_streams.read().await;
         streams.len()
     }
+
+    /// Subscribe to status, chunk, and done events for an A2A task
+    ///
+    /// All subscribers of a task share one channel, created on first subscription.
+    pub async fn subscribe_a2a_task_events(&self, task_id: &str) -> broadcast::Receiver<String> {
+        let mut streams = self.a2a_task_event_streams.write().await;
+        streams
+            .entry(task_id.to_owned())
+            .or_insert_with(|| A2ATaskStream::new(self.buffer_size))
+            .subscribe()
+    }
+
+    /// Publish an event to every subscriber of its A2A task
+    ///
+    /// Returns the number of subscribers that received the event. The task's
+    /// channel is dropped after a `done` event or once nobody is listening.
+    pub async fn publish_a2a_task_event(&self, event: &A2ATaskEvent) -> usize {
+        let task_id = event.task_id();
+        let mut streams = self.a2a_task_event_streams.write().await;
+        let Some(stream) = streams.get(task_id) else {
+            return 0;
+        };
+
+        let data = match serde_json::to_string(event) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("Failed to serialize event for A2A task {}: {}", task_id, e);
+                return 0;
+            }
+        };
+
+        let delivered = stream.send_update(data).unwrap_or(0);
+        if delivered == 0 || event.is_done() {
+            streams.remove(task_id);
+        }
+        debug!(
+            "Published {} event for A2A task {} to {} subscribers",
+            event.event_name(),
+            task_id,
+            delivered
+        );
+        delivered
+    }
+
+    /// Drop an A2A task's event channel if it has no subscribers left
+    pub async fn release_a2a_task_events(&self, task_id: &str) {
+        let mut streams = self.a2a_task_event_streams.write().await;
+        if streams
+            .get(task_id)
+            .is_some_and(|stream| stream.subscriber_count() == 0)
+        {
+            streams.remove(task_id);
+        }
+    }
 }
@@ -11,7 +11,7 @@
 //!
 //! ## Components
 //!
-//! - `a2a_task_stream`: A2A task progress streaming
+//! - `a2a_task_stream`: A2A task progress streaming and task events
 //! - `manager`: Central SSE connection management
 //! - `notifications`: OAuth notification streaming
 //! - `protocol`: MCP protocol streaming
@@ -37,7 +37,7 @@ pub mod routes;
 
 // Re-exports
 #[cfg(feature = "protocol-a2a")]
-pub use a2a_task_stream::A2ATaskStream;
+pub use a2a_task_stream::{A2ATaskEvent, A2ATaskStream};
 
 pub use manager::{ConnectionMetadata, ConnectionType, SseManager};
 
@@ -6,7 +6,7 @@
 
 use crate::{
     database::oauth_notifications::OAuthNotification,
-    errors::AppError,
+    errors::{AppError, ErrorCode},
     mcp::{
         protocol::{McpRequest, McpResponse},
         resources::ServerResources,
@@ -108,8 +108,12 @@ impl McpProtocolStream {
     /// - JSON serialization fails
     /// - Sending the error event fails
     pub async fn send_error(&self, error_message: &str) -> Result<(), AppError> {
-        let error_response =
-            McpResponse::error(Some(Value::Null), -32603, error_message.to_owned());
+        let error_response = McpResponse::error_with_code(
+            Some(Value::Null),
+            -32603,
+            ErrorCode::InternalError,
+            error_message,
+        );
 
         let sender_guard = self.sender.read().await;
 
@@ -4,7 +4,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
-use super::manager::SseManager;
+use super::{a2a_task_stream::A2ATaskEvent, manager::SseManager};
+use crate::a2a::protocol::A2ATask;
 use crate::config::environment::SseBufferStrategy;
 use crate::database_plugins::DatabaseProvider;
 use crate::errors::AppError;
@@ -19,7 +20,7 @@ use axum::{
 };
 use futures_util::stream::Stream;
 use std::{convert::Infallible, sync::Arc, time::Duration};
-use tokio::sync::broadcast;
+use tokio::{sync::broadcast, time::timeout};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
@@ -38,6 +39,10 @@ impl SseRoutes {
             )
             .route("/mcp/sse/:session_id", get(Self::handle_protocol_sse))
             .route("/a2a/tasks/:task_id/stream", get(Self::handle_a2a_task_sse))
+            .route(
+                "/a2a/tasks/:task_id/events",
+                get(Self::handle_a2a_task_events_sse),
+            )
             .with_state((manager, resources))
     }
 
@@ -395,4 +400,189 @@ impl SseRoutes {
                 .text("keepalive"),
         ))
     }
+
+    /// Handle A2A task events SSE connection
+    ///
+    /// REQUIRES: A2A session token (Bearer token in Authorization header)
+    ///
+    /// Streams the task's current status, then every status transition and result
+    /// chunk as it happens. A final `done` event carries the result or error and
+    /// closes the stream. The stream also closes after
+    /// `SSE_CONNECTION_TIMEOUT_SECS` without an event.
+    ///
+    /// Security: Only sessions of the client that owns the task may subscribe.
+    async fn handle_a2a_task_events_sse(
+        Path(task_id): Path<String>,
+        headers: HeaderMap,
+        State((manager, resources)): State<(Arc<SseManager>, Arc<ServerResources>)>,
+    ) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+        info!("New A2A task events SSE connection for task: {}", task_id);
+
+        let auth_header = headers
+            .get("authorization")
+            .and_then(|h| h.to_str().ok())
+            .ok_or_else(|| {
+                warn!(task_id = %task_id, "Missing Authorization header for A2A task events");
+                AppError::auth_invalid(
+                    "Missing Authorization header - A2A session token required for task events",
+                )
+            })?;
+
+        let session_token = extract_token(auth_header).map_err(|_| {
+            warn!(task_id = %task_id, "Invalid Authorization header format for A2A task events");
+            AppError::auth_invalid("Invalid Authorization header format")
+        })?;
+
+        let session = resources
+            .database
+            .get_a2a_session(&session_token)
+            .await
+            .map_err(|e| {
+                error!(task_id = %task_id, error = %e, "Failed to look up A2A session");
+                AppError::internal(format!("Failed to look up A2A session: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!(task_id = %task_id, "Invalid or expired A2A session token for task events");
+                AppError::auth_invalid("Invalid or expired A2A session token")
+            })?;
+
+        // Subscribe before reading the task so no transition between the read and
+        // the subscription is missed
+        let receiver = manager.subscribe_a2a_task_events(&task_id).await;
+
+        let task = match Self::authorize_a2a_task(&resources, &task_id, &session.client_id).await {
+            Ok(task) => task,
+            Err(e) => {
+                drop(receiver);
+                manager.release_a2a_task_events(&task_id).await;
+                return Err(e);
+            }
+        };
+
+        if let Err(e) = resources
+            .database
+            .update_a2a_session_activity(&session_token)
+            .await
+        {
+            warn!(task_id = %task_id, error = %e, "Failed to update A2A session activity");
+        }
+
+        let idle_timeout = Duration::from_secs(resources.config.sse.connection_timeout_secs);
+        let stream = Self::a2a_task_event_stream(task, receiver, manager, idle_timeout);
+
+        // Configure keepalive with 15-second interval
+        Ok(Sse::new(stream).keep_alive(
+            KeepAlive::new()
+                .interval(Duration::from_secs(15))
+                .text("keepalive"),
+        ))
+    }
+
+    /// Fetch a task and verify it belongs to the session's client
+    async fn authorize_a2a_task(
+        resources: &ServerResources,
+        task_id: &str,
+        client_id: &str,
+    ) -> Result<A2ATask, AppError> {
+        let task = resources
+            .database
+            .get_a2a_task(task_id)
+            .await
+            .map_err(|e| {
+                error!(task_id = %task_id, error = %e, "Failed to fetch task for event streaming");
+                AppError::internal(format!("Failed to fetch task: {e}"))
+            })?
+            .ok_or_else(|| {
+                warn!(task_id = %task_id, "Task not found for event streaming");
+                AppError::not_found(format!("Task {task_id} not found"))
+            })?;
+
+        if task.client_id != client_id {
+            warn!(
+                task_id = %task_id,
+                session_client = %client_id,
+                "A2A session attempting to stream another client's task"
+            );
+            return Err(AppError::auth_invalid(
+                "Cannot stream events for another client's task",
+            ));
+        }
+
+        Ok(task)
+    }
+
+    /// Build the event stream for a task: current status, live events, then `done`
+    fn a2a_task_event_stream(
+        task: A2ATask,
+        mut receiver: broadcast::Receiver<String>,
+        manager: Arc<SseManager>,
+        idle_timeout: Duration,
+    ) -> impl Stream<Item = Result<Event, Infallible>> {
+        async_stream::stream! {
+            let task_id = task.id.clone();
+            let mut event_id: u64 = 0;
+
+            let current = A2ATaskEvent::Status {
+                task_id: task_id.clone(),
+                status: task.status.clone(),
+                timestamp: task.updated_at,
+            };
+            event_id += 1;
+            yield Ok::<_, Infallible>(Self::a2a_task_event(event_id, &current));
+
+            if task.status.is_terminal() {
+                // Finished before the client subscribed: report the stored outcome
+                let done = A2ATaskEvent::Done {
+                    task_id: task_id.clone(),
+                    status: task.status,
+                    result: task.result,
+                    error: task.error,
+                };
+                event_id += 1;
+                yield Ok(Self::a2a_task_event(event_id, &done));
+            } else {
+                loop {
+                    match timeout(idle_timeout, receiver.recv()).await {
+                        Ok(Ok(message)) => {
+                            let Ok(event) = serde_json::from_str::<A2ATaskEvent>(&message) else {
+                                warn!("Dropping malformed event for A2A task {}", task_id);
+                                continue;
+                            };
+                            event_id += 1;
+                            yield Ok(Self::a2a_task_event(event_id, &event));
+                            if event.is_done() {
+                                break;
+                            }
+                        }
+                        Ok(Err(broadcast::error::RecvError::Lagged(skipped))) => {
+                            warn!(
+                                "SSE buffer overflow for task events {}: {} messages dropped",
+                                task_id, skipped
+                            );
+                        }
+                        Ok(Err(broadcast::error::RecvError::Closed)) => {
+                            info!("SSE event channel closed for task: {}", task_id);
+                            break;
+                        }
+                        Err(_) => {
+                            info!("Closing idle SSE event stream for task: {}", task_id);
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Clean up the task's channel once its last subscriber is gone
+            drop(receiver);
+            manager.release_a2a_task_events(&task_id).await;
+        }
+    }
+
+    /// Convert a task event to an SSE event
+    fn a2a_task_event(event_id: u64, event: &A2ATaskEvent) -> Event {
+        Event::default()
+            .id(event_id.to_string())
+            .data(serde_json::to_string(event).unwrap_or_else(|_| "{}".to_owned()))
+            .event(event.event_name())
+    }
 }
@@ -14,6 +14,9 @@ use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::config::LlmProviderType;
+use crate::database_plugins::shared::encryption::{
+    create_llm_credentials_aad_context, HasEncryption,
+};
 use crate::database_plugins::{factory::Database, DatabaseProvider};
 use crate::errors::{AppError, AppResult};
 
@@ -265,8 +268,9 @@ impl TenantLlmManager {
         // Create AAD context for encryption
         let aad_context = Self::create_aad_context(tenant_id, user_id, request.provider);
 
-        // Encrypt the API key
-        let api_key_encrypted = database.encrypt_data_with_aad(&request.api_key, &aad_context)?;
+        // Encrypt the API key with the tenant's data key
+        let api_key_encrypted =
+            database.encrypt_data_for_tenant(tenant_id, &request.api_key, &aad_context)?;
 
         let record = LlmCredentialRecord {
             id,
@@ -384,7 +388,7 @@ impl TenantLlmManager {
         database: &Database,
     ) -> Option<LlmCredentials> {
         let aad_context = Self::create_aad_context(tenant_id, user_id, provider);
-        match database.decrypt_data_with_aad(&record.api_key_encrypted, &aad_context) {
+        match database.decrypt_data_for_tenant(tenant_id, &record.api_key_encrypted, &aad_context) {
             Ok(api_key) => Some(LlmCredentials {
                 tenant_id,
                 user_id,
@@ -575,13 +579,7 @@ impl TenantLlmManager {
         user_id: Option<Uuid>,
         provider: LlmProvider,
     ) -> String {
-        let user_part = user_id.map_or_else(|| "tenant-default".to_owned(), |u| u.to_string());
-        format!(
-            "{}|{}|{}|user_llm_credentials",
-            tenant_id,
-            user_part,
-            provider.as_str()
-        )
+        create_llm_credentials_aad_context(tenant_id, user_id, provider.as_str())
     }
 }
 
@@ -10,6 +10,7 @@
 //! - Per-tenant OAuth credential management
 //! - Per-tenant LLM API key management
 //! - Tenant-isolated rate limiting
+//! - Signed outbound webhooks to tenant callback URLs
 //! - Enterprise-ready `SaaS` deployment
 //! - Secure tenant data isolation
 
@@ -21,6 +22,8 @@ pub mod oauth_client;
 pub mod oauth_manager;
 /// Tenant database schema and models
 pub mod schema;
+/// Outbound webhook models and payload signing
+pub mod webhooks;
 
 pub use llm_manager::{
     CredentialSource, LlmCredentialRecord, LlmCredentialSummary, LlmCredentials, LlmProvider,
@@ -29,6 +32,9 @@ pub use llm_manager::{
 pub use oauth_client::{StoreCredentialsRequest, TenantOAuthClient};
 pub use oauth_manager::{CredentialConfig, TenantOAuthCredentials, TenantOAuthManager};
 pub use schema::{Tenant, TenantProviderUsage, TenantRole, TenantUser};
+pub use webhooks::{
+    TenantWebhook, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookEventType,
+};
 
 use pierre_core::models::TenantId;
 use serde::{Deserialize, Serialize};
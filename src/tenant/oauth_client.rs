@@ -9,11 +9,14 @@
 
 use super::oauth_manager::{CredentialConfig, TenantOAuthCredentials, TenantOAuthManager};
 use super::TenantContext;
-use crate::database_plugins::factory::Database;
+use crate::database_plugins::{factory::Database, DatabaseProvider};
 use crate::errors::{AppError, AppResult};
 use crate::oauth2_client::{OAuth2Client, OAuth2Config, OAuth2Token, PkceParams};
+use chrono::{DateTime, Duration, Utc};
 use pierre_core::models::TenantId;
 use std::env;
+use std::fmt::Display;
+use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{info, warn};
@@ -63,6 +66,28 @@ impl TenantOAuthClient {
         provider: &str,
         database: &Database,
     ) -> AppResult<OAuth2Client> {
+        let credentials = self
+            .get_rate_limited_credentials(tenant_context, provider, database)
+            .await?;
+
+        // Build OAuth2Config from tenant credentials
+        let oauth_config = Self::build_oauth_config(&credentials, provider)?;
+
+        info!(
+            "Created OAuth client for tenant={}, provider={}, client_id={}",
+            tenant_context.tenant_id, provider, credentials.client_id
+        );
+
+        Ok(OAuth2Client::new(oauth_config))
+    }
+
+    /// Load tenant credentials after checking the tenant's daily rate limit
+    async fn get_rate_limited_credentials(
+        &self,
+        tenant_context: &TenantContext,
+        provider: &str,
+        database: &Database,
+    ) -> AppResult<TenantOAuthCredentials> {
         // Check rate limit first
         let manager = self.oauth_manager.lock().await;
         let (current_usage, daily_limit) =
@@ -76,20 +101,107 @@ impl TenantOAuthClient {
         }
 
         // Get tenant credentials
-        let credentials = manager
+        manager
             .get_credentials(tenant_context.tenant_id, provider, database)
+            .await
+    }
+
+    /// Run a token request with the tenant's credentials, falling back to the previous secret during a rotation
+    async fn request_token<F, Fut>(
+        &self,
+        tenant_context: &TenantContext,
+        provider: &str,
+        database: &Database,
+        request: F,
+    ) -> AppResult<OAuth2Token>
+    where
+        F: Fn(OAuth2Client) -> Fut,
+        Fut: Future<Output = AppResult<OAuth2Token>>,
+    {
+        let credentials = self
+            .get_rate_limited_credentials(tenant_context, provider, database)
             .await?;
-        drop(manager);
+        let request = &request;
 
-        // Build OAuth2Config from tenant credentials
-        let oauth_config = Self::build_oauth_config(&credentials, provider)?;
+        self.with_secret_fallback(&credentials, database, |client_secret| {
+            let oauth_config = Self::build_oauth_config(
+                &TenantOAuthCredentials {
+                    client_secret,
+                    ..credentials.clone()
+                },
+                provider,
+            );
+            async move { request(OAuth2Client::new(oauth_config?)).await }
+        })
+        .await
+    }
 
-        info!(
-            "Created OAuth client for tenant={}, provider={}, client_id={}",
-            tenant_context.tenant_id, provider, credentials.client_id
+    /// Run a request authenticated with a tenant's client secret, honouring secret rotation
+    ///
+    /// `request` is called with the client secret to use. The active secret is
+    /// always tried first. If the request fails while a rotated-out secret is
+    /// still within its grace period, it is retried with that secret. The first
+    /// success with the new secret promotes it, purging the previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt when no secret was accepted
+    pub async fn with_secret_fallback<T, E, F, Fut>(
+        &self,
+        credentials: &TenantOAuthCredentials,
+        database: &Database,
+        request: F,
+    ) -> Result<T, E>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Display,
+    {
+        let (tenant_id, provider) = (credentials.tenant_id, credentials.provider.as_str());
+
+        let (error, previous_secret) = match (
+            request(credentials.client_secret.clone()).await,
+            &credentials.previous_client_secret,
+        ) {
+            (Ok(response), Some(_)) => {
+                self.promote_secret(tenant_id, provider, database).await;
+                return Ok(response);
+            }
+            (Ok(response), None) => return Ok(response),
+            (Err(e), None) => return Err(e),
+            (Err(e), Some(previous_secret)) => (e, previous_secret.clone()),
+        };
+
+        warn!(
+            "OAuth request with new client secret failed for tenant={}, provider={}, retrying with previous secret: {}",
+            tenant_id, provider, error
         );
+        request(previous_secret).await
+    }
 
-        Ok(OAuth2Client::new(oauth_config))
+    /// Promote a pending client secret once it has been accepted by the provider
+    async fn promote_secret(&self, tenant_id: TenantId, provider: &str, database: &Database) {
+        // The token request already succeeded, so a failed promotion is retried on the next request
+        match database
+            .promote_tenant_oauth_secret(tenant_id, provider)
+            .await
+        {
+            Ok(true) => {
+                self.oauth_manager
+                    .lock()
+                    .await
+                    .evict_credentials(tenant_id, provider);
+                info!(
+                    "Promoted rotated OAuth client secret for tenant={}, provider={}",
+                    tenant_id, provider
+                );
+            }
+            Ok(false) => {}
+            Err(e) => warn!(
+                "Failed to promote rotated OAuth client secret for tenant={}, provider={}: {}",
+                tenant_id, provider, e
+            ),
+        }
     }
 
     /// Get authorization URL for tenant-specific OAuth flow
@@ -115,6 +227,32 @@ impl TenantOAuthClient {
         })
     }
 
+    /// Get authorization URL requesting only `scopes` for a tenant-specific scope step-up
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if OAuth client creation or authorization URL generation fails
+    pub async fn get_step_up_authorization_url(
+        &self,
+        tenant_context: &TenantContext,
+        provider: &str,
+        state: &str,
+        scopes: &[String],
+        database: &Database,
+    ) -> AppResult<String> {
+        let oauth_client = self
+            .get_oauth_client(tenant_context, provider, database)
+            .await?;
+        oauth_client
+            .get_authorization_url_for_scopes(state, scopes)
+            .map_err(|e| {
+                AppError::external_service(
+                    "oauth2",
+                    format!("OAuth step-up authorization URL generation failed: {e}"),
+                )
+            })
+    }
+
     /// Get authorization URL with PKCE for tenant-specific OAuth flow
     ///
     /// # Errors
@@ -153,12 +291,21 @@ impl TenantOAuthClient {
         code: &str,
         database: &Database,
     ) -> AppResult<OAuth2Token> {
-        let oauth_client = self
-            .get_oauth_client(tenant_context, provider, database)
+        let token = self
+            .request_token(
+                tenant_context,
+                provider,
+                database,
+                |oauth_client| async move {
+                    oauth_client.exchange_code(code).await.map_err(|e| {
+                        AppError::external_service(
+                            "oauth2",
+                            format!("OAuth code exchange failed: {e}"),
+                        )
+                    })
+                },
+            )
             .await?;
-        let token = oauth_client.exchange_code(code).await.map_err(|e| {
-            AppError::external_service("oauth2", format!("OAuth code exchange failed: {e}"))
-        })?;
 
         // Increment usage counter
         self.oauth_manager.lock().await.increment_usage(
@@ -189,18 +336,24 @@ impl TenantOAuthClient {
         pkce: &PkceParams,
         database: &Database,
     ) -> AppResult<OAuth2Token> {
-        let oauth_client = self
-            .get_oauth_client(tenant_context, provider, database)
+        let token = self
+            .request_token(
+                tenant_context,
+                provider,
+                database,
+                |oauth_client| async move {
+                    oauth_client
+                        .exchange_code_with_pkce(code, pkce)
+                        .await
+                        .map_err(|e| {
+                            AppError::external_service(
+                                "oauth2",
+                                format!("OAuth code exchange with PKCE failed: {e}"),
+                            )
+                        })
+                },
+            )
             .await?;
-        let token = oauth_client
-            .exchange_code_with_pkce(code, pkce)
-            .await
-            .map_err(|e| {
-                AppError::external_service(
-                    "oauth2",
-                    format!("OAuth code exchange with PKCE failed: {e}"),
-                )
-            })?;
 
         // Increment usage counter
         self.oauth_manager.lock().await.increment_usage(
@@ -230,15 +383,24 @@ impl TenantOAuthClient {
         refresh_token: &str,
         database: &Database,
     ) -> AppResult<OAuth2Token> {
-        let oauth_client = self
-            .get_oauth_client(tenant_context, provider, database)
+        let token = self
+            .request_token(
+                tenant_context,
+                provider,
+                database,
+                |oauth_client| async move {
+                    oauth_client
+                        .refresh_token(refresh_token)
+                        .await
+                        .map_err(|e| {
+                            AppError::external_service(
+                                "oauth2",
+                                format!("OAuth token refresh failed: {e}"),
+                            )
+                        })
+                },
+            )
             .await?;
-        let token = oauth_client
-            .refresh_token(refresh_token)
-            .await
-            .map_err(|e| {
-                AppError::external_service("oauth2", format!("OAuth token refresh failed: {e}"))
-            })?;
 
         // Increment usage counter
         self.oauth_manager.lock().await.increment_usage(
@@ -311,6 +473,49 @@ impl TenantOAuthClient {
         manager.store_credentials(tenant_id, provider, config)
     }
 
+    /// Rotate a tenant's stored OAuth client secret
+    ///
+    /// The new secret is used from now on, while the current secret keeps being
+    /// accepted as a fallback for `grace_period`. The new secret is promoted and
+    /// the old one purged after the first successful token exchange. Returns
+    /// when the current secret stops being accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tenant has no stored credentials for the provider
+    /// or the new secret cannot be stored
+    pub async fn rotate_secret(
+        &self,
+        tenant_id: TenantId,
+        provider: &str,
+        new_secret: &str,
+        grace_period: Duration,
+        database: &Database,
+    ) -> AppResult<DateTime<Utc>> {
+        if new_secret.trim().is_empty() {
+            return Err(AppError::invalid_input(
+                "New client secret must not be empty",
+            ));
+        }
+
+        let previous_expires_at = Utc::now() + grace_period;
+        database
+            .rotate_tenant_oauth_secret(tenant_id, provider, new_secret, previous_expires_at)
+            .await?;
+        self.oauth_manager
+            .lock()
+            .await
+            .evict_credentials(tenant_id, provider);
+
+        info!(
+            "Rotated OAuth client secret for tenant={}, provider={}, grace_period={}h",
+            tenant_id,
+            provider,
+            grace_period.num_hours()
+        );
+        Ok(previous_expires_at)
+    }
+
     /// Build `OAuth2Config` from tenant credentials
     fn build_oauth_config(
         credentials: &TenantOAuthCredentials,
@@ -12,7 +12,7 @@ use crate::constants::rate_limits::{
 };
 use crate::database_plugins::{factory::Database, DatabaseProvider};
 use crate::errors::{AppError, AppResult};
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use pierre_core::models::TenantId;
 use std::collections::HashMap;
 use std::env;
@@ -20,6 +20,34 @@ use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+/// Hours a rotated-out client secret stays valid when `PIERRE_OAUTH_SECRET_GRACE_PERIOD_HOURS` is unset
+pub const DEFAULT_SECRET_ROTATION_GRACE_HOURS: u32 = 24;
+
+/// How long the previous client secret keeps working after a secret rotation
+///
+/// Reads `PIERRE_OAUTH_SECRET_GRACE_PERIOD_HOURS` (default: 24). Invalid values
+/// fall back to the default.
+#[must_use]
+pub fn secret_rotation_grace_period() -> Duration {
+    let hours = env::var("PIERRE_OAUTH_SECRET_GRACE_PERIOD_HOURS")
+        .ok()
+        .and_then(|value| {
+            value
+                .trim()
+                .parse()
+                .inspect_err(|e| {
+                    warn!(
+                        "Invalid PIERRE_OAUTH_SECRET_GRACE_PERIOD_HOURS '{}': {}",
+                        value, e
+                    );
+                })
+                .ok()
+        })
+        .unwrap_or(DEFAULT_SECRET_ROTATION_GRACE_HOURS);
+
+    Duration::hours(i64::from(hours))
+}
+
 /// Credential configuration for storing OAuth credentials
 #[derive(Debug, Clone)]
 pub struct CredentialConfig {
@@ -45,7 +73,11 @@ pub struct TenantOAuthCredentials {
     /// OAuth client ID (public)
     pub client_id: String,
     /// OAuth client secret (decrypted)
+    ///
+    /// During a secret rotation this is the new, pending secret.
     pub client_secret: String,
+    /// Secret being rotated out, still accepted until its grace period ends
+    pub previous_client_secret: Option<String>,
     /// OAuth redirect URI
     pub redirect_uri: String,
     /// OAuth scopes
@@ -155,6 +187,7 @@ impl TenantOAuthManager {
             provider: provider.to_owned(),
             client_id: config.client_id,
             client_secret: config.client_secret,
+            previous_client_secret: None,
             redirect_uri: config.redirect_uri,
             scopes: config.scopes,
             rate_limit_per_day: Self::default_rate_limit_for_provider(provider),
@@ -165,6 +198,11 @@ impl TenantOAuthManager {
         Ok(())
     }
 
+    /// Drop cached credentials so the next lookup reads them from the database
+    pub fn evict_credentials(&mut self, tenant_id: TenantId, provider: &str) {
+        self.credentials.remove(&(tenant_id, provider.to_owned()));
+    }
+
     /// Check tenant's daily rate limit usage
     ///
     /// # Errors
@@ -249,6 +287,7 @@ impl TenantOAuthManager {
                 provider: "strava".to_owned(),
                 client_id: client_id.clone(),
                 client_secret: client_secret.clone(),
+                previous_client_secret: None,
                 redirect_uri,
                 scopes: if strava_config.scopes.is_empty() {
                     "activity:read_all".split(',').map(str::to_owned).collect()
@@ -285,6 +324,7 @@ impl TenantOAuthManager {
                 provider: "fitbit".to_owned(),
                 client_id: client_id.clone(),
                 client_secret: client_secret.clone(),
+                previous_client_secret: None,
                 redirect_uri,
                 scopes: if fitbit_config.scopes.is_empty() {
                     vec![
@@ -331,6 +371,7 @@ impl TenantOAuthManager {
                 provider: "garmin".to_owned(),
                 client_id: client_id.clone(),
                 client_secret: client_secret.clone(),
+                previous_client_secret: None,
                 redirect_uri,
                 scopes: if garmin_config.scopes.is_empty() {
                     vec!["wellness:read".to_owned(), "activities:read".to_owned()]
@@ -367,6 +408,7 @@ impl TenantOAuthManager {
                 provider: "whoop".to_owned(),
                 client_id: client_id.clone(),
                 client_secret: client_secret.clone(),
+                previous_client_secret: None,
                 redirect_uri,
                 scopes: if whoop_config.scopes.is_empty() {
                     vec![
@@ -411,6 +453,7 @@ impl TenantOAuthManager {
                 provider: "terra".to_owned(),
                 client_id: client_id.clone(),
                 client_secret: client_secret.clone(),
+                previous_client_secret: None,
                 redirect_uri,
                 scopes: if terra_config.scopes.is_empty() {
                     vec![
@@ -455,6 +498,7 @@ impl TenantOAuthManager {
                     provider: provider.to_owned(),
                     client_id: user_app.client_id,
                     client_secret: user_app.client_secret,
+                    previous_client_secret: None,
                     redirect_uri: user_app.redirect_uri,
                     scopes: Self::default_scopes_for_provider(provider),
                     rate_limit_per_day: Self::default_rate_limit_for_provider(provider),
@@ -0,0 +1,227 @@
+// ABOUTME: Outbound webhook models and HMAC-SHA256 payload signing for tenant callback URLs
+// ABOUTME: Defines registered webhooks, delivery records, delivery attempts, and the signature header format
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+//! # Tenant Webhooks
+//!
+//! Tenants register callback URLs and the event types they want pushed to them.
+//! Every event becomes one [`WebhookDelivery`] per subscribed webhook, and every
+//! HTTP request made for a delivery is recorded as a [`WebhookDeliveryAttempt`].
+//!
+//! ## Signatures
+//!
+//! Each request carries an `X-Pierre-Signature` header of the form
+//! `t=<unix seconds>,v1=<hex HMAC-SHA256>`. The HMAC is computed with the
+//! webhook secret over `"<unix seconds>.<raw body>"`, so receivers can reject
+//! replayed requests by checking the timestamp.
+//!
+//! ## Delivery semantics
+//!
+//! Deliveries are at-least-once. Receivers should deduplicate on the
+//! `X-Pierre-Delivery` header, which is stable across retries.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use pierre_core::models::TenantId;
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+/// Header carrying the payload signature
+pub const SIGNATURE_HEADER: &str = "X-Pierre-Signature";
+/// Header carrying the event type
+pub const EVENT_HEADER: &str = "X-Pierre-Event";
+/// Header carrying the delivery ID, stable across retries
+pub const DELIVERY_HEADER: &str = "X-Pierre-Delivery";
+
+/// Events that can be pushed to tenant webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    /// New activities were synced from a provider
+    #[serde(rename = "activity.synced")]
+    ActivitySynced,
+    /// A user reached a goal's target
+    #[serde(rename = "goal.completed")]
+    GoalCompleted,
+}
+
+impl WebhookEventType {
+    /// All event types a webhook can subscribe to
+    pub const ALL: [Self; 2] = [Self::ActivitySynced, Self::GoalCompleted];
+
+    /// Wire and database string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ActivitySynced => "activity.synced",
+            Self::GoalCompleted => "goal.completed",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebhookEventType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|event| event.as_str() == s)
+            .ok_or_else(|| AppError::invalid_input(format!("Unknown webhook event type: {s}")))
+    }
+}
+
+/// Lifecycle of a single event delivery to a single webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its next attempt
+    Pending,
+    /// Acknowledged by the receiver with a 2xx response
+    Delivered,
+    /// Gave up after the maximum attempts or a non-retryable response
+    DeadLetter,
+}
+
+impl WebhookDeliveryStatus {
+    /// Database string representation
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::DeadLetter => "dead_letter",
+        }
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "delivered" => Ok(Self::Delivered),
+            "dead_letter" => Ok(Self::DeadLetter),
+            _ => Err(AppError::invalid_input(format!(
+                "Invalid webhook delivery status: {s}"
+            ))),
+        }
+    }
+}
+
+/// A callback URL registered by a tenant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantWebhook {
+    /// Webhook ID
+    pub id: Uuid,
+    /// Owning tenant
+    pub tenant_id: TenantId,
+    /// HTTPS endpoint events are POSTed to
+    pub url: String,
+    /// Signing secret, stored encrypted and never serialized
+    #[serde(skip_serializing, default)]
+    pub secret: String,
+    /// Events this webhook receives
+    pub event_types: Vec<WebhookEventType>,
+    /// Inactive webhooks receive no new deliveries
+    pub active: bool,
+    /// When the webhook was registered
+    pub created_at: DateTime<Utc>,
+    /// When the webhook was last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TenantWebhook {
+    /// Whether this webhook should receive `event_type`
+    #[must_use]
+    pub fn subscribes_to(&self, event_type: WebhookEventType) -> bool {
+        self.active && self.event_types.contains(&event_type)
+    }
+}
+
+/// One event queued for one webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Delivery ID, sent as `X-Pierre-Delivery`
+    pub id: Uuid,
+    /// Target webhook
+    pub webhook_id: Uuid,
+    /// Owning tenant
+    pub tenant_id: TenantId,
+    /// Event being delivered
+    pub event_type: WebhookEventType,
+    /// Request body
+    pub payload: Value,
+    /// Current delivery state
+    pub status: WebhookDeliveryStatus,
+    /// HTTP attempts made so far
+    pub attempts: u32,
+    /// Earliest time the next attempt may run
+    pub next_attempt_at: DateTime<Utc>,
+    /// Status code of the most recent attempt, if a response was received
+    pub last_status_code: Option<u16>,
+    /// Error from the most recent failed attempt
+    pub last_error: Option<String>,
+    /// When the event was queued
+    pub created_at: DateTime<Utc>,
+    /// When the delivery was delivered or dead-lettered
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Record of a single HTTP request made for a delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryAttempt {
+    /// Delivery this attempt belongs to
+    pub delivery_id: Uuid,
+    /// 1-based attempt number
+    pub attempt: u32,
+    /// Response status, or `None` when no response was received
+    pub status_code: Option<u16>,
+    /// Transport or response error
+    pub error: Option<String>,
+    /// Request duration in milliseconds
+    pub duration_ms: u64,
+    /// When the request was made
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// Compute the `X-Pierre-Signature` header value for a request body
+#[must_use]
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let mut ctx = hmac::Context::with_key(&key);
+    ctx.update(timestamp.to_string().as_bytes());
+    ctx.update(b".");
+    ctx.update(body);
+    format!("t={timestamp},v1={}", hex::encode(ctx.sign().as_ref()))
+}
+
+/// Check an `X-Pierre-Signature` header value against a request body
+///
+/// Receivers should additionally reject timestamps outside their replay window.
+#[must_use]
+pub fn verify_signature(secret: &str, header_value: &str, body: &[u8]) -> bool {
+    let Some(timestamp) = header_value
+        .split(',')
+        .find_map(|part| part.strip_prefix("t="))
+        .and_then(|t| t.parse::<i64>().ok())
+    else {
+        return false;
+    };
+    let expected = sign_payload(secret, timestamp, body);
+    expected.as_bytes().ct_eq(header_value.as_bytes()).into()
+}
@@ -15,11 +15,16 @@ use crate::{
         oauth_providers,
         time::{DAY_SECONDS, HOUR_SECONDS},
     },
+    dashboard_routes::ToolUsage,
     database_plugins::{factory::Database, shared::encryption::HasEncryption, DatabaseProvider},
     errors::{AppError, AppResult, ErrorCode},
     models::{AuthorizationCode, OAuthApp, Tenant, TenantId},
-    tenant::TenantOAuthCredentials,
+    tenant::{
+        oauth_manager::secret_rotation_grace_period, TenantOAuthClient, TenantOAuthCredentials,
+        TenantRole,
+    },
 };
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{info, warn};
@@ -144,6 +149,24 @@ pub struct TenantOAuthProvider {
     pub enabled: bool,
 }
 
+/// Request to rotate a tenant's OAuth client secret
+#[derive(Debug, Deserialize)]
+pub struct RotateTenantOAuthSecretRequest {
+    /// New OAuth client secret from the provider
+    pub client_secret: String,
+    /// Hours the current secret keeps being accepted (default: `PIERRE_OAUTH_SECRET_GRACE_PERIOD_HOURS`)
+    pub grace_period_hours: Option<u32>,
+}
+
+/// Response after rotating a tenant's OAuth client secret
+#[derive(Debug, Serialize)]
+pub struct RotateTenantOAuthSecretResponse {
+    /// Provider name
+    pub provider: String,
+    /// ISO 8601 timestamp until which the previous secret is still accepted
+    pub previous_secret_expires_at: String,
+}
+
 // OAuth App Registration for MCP clients
 
 /// Request to register a new OAuth application
@@ -234,6 +257,37 @@ pub struct OAuthTokenResponse {
     pub scope: String,
 }
 
+// Tenant Usage Analytics Types
+
+/// Default look-back window for tenant tool usage when no start date is given
+const DEFAULT_TOOL_USAGE_WINDOW_DAYS: i64 = 30;
+
+/// Query parameters for tenant-wide tool usage
+#[derive(Debug, Default, Deserialize)]
+pub struct TenantToolUsageQuery {
+    /// Start of the range (defaults to 30 days before `end_date`)
+    pub start_date: Option<DateTime<Utc>>,
+    /// End of the range (defaults to now)
+    pub end_date: Option<DateTime<Utc>>,
+    /// Maximum number of tools to return, most used first
+    pub limit: Option<usize>,
+}
+
+/// Tool usage summed across all members of a tenant
+#[derive(Debug, Serialize)]
+pub struct TenantToolUsageResponse {
+    /// UUID of the tenant
+    pub tenant_id: String,
+    /// ISO 8601 start of the aggregated range
+    pub start_date: String,
+    /// ISO 8601 end of the aggregated range
+    pub end_date: String,
+    /// Total requests across all tools in the range
+    pub total_requests: u64,
+    /// Per-tool usage, most used first
+    pub tools: Vec<ToolUsage>,
+}
+
 // Route Handler Implementations
 
 /// Create a new tenant organization
@@ -410,6 +464,7 @@ pub async fn configure_tenant_oauth(
         provider: oauth_request.provider.clone(), // Safe: String ownership for OAuth credentials
         client_id: oauth_request.client_id.clone(), // Safe: String ownership for OAuth credentials
         client_secret: oauth_request.client_secret,
+        previous_client_secret: None,
         redirect_uri: oauth_request.redirect_uri.clone(), // Safe: String ownership for OAuth credentials
         scopes: oauth_request.scopes.clone(), // Safe: Option<String> ownership for OAuth credentials
         rate_limit_per_day: oauth_request.rate_limit_per_day.unwrap_or(15000),
@@ -491,6 +546,150 @@ pub async fn get_tenant_oauth(
     Ok(TenantOAuthListResponse { providers })
 }
 
+/// Rotate the OAuth client secret of a tenant's provider
+///
+/// The new secret is used right away while the current one stays valid for the
+/// grace period, so in-flight OAuth flows keep working during the switch.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Tenant not found or access denied
+/// - The tenant has no credentials for the provider, or the new secret is empty
+/// - Database operations fail
+pub async fn rotate_tenant_oauth_secret(
+    tenant_id: String,
+    provider: String,
+    request: RotateTenantOAuthSecretRequest,
+    auth_result: AuthResult,
+    database: Arc<Database>,
+    oauth_client: &TenantOAuthClient,
+) -> AppResult<RotateTenantOAuthSecretResponse> {
+    info!(
+        "Rotating {} OAuth client secret for tenant: {}",
+        provider, tenant_id
+    );
+
+    let tenant_uuid: TenantId = tenant_id.parse().map_err(|e| {
+        warn!(
+            tenant_id = %tenant_id,
+            user_id = %auth_result.user_id,
+            error = %e,
+            "Failed to parse tenant ID for OAuth operation"
+        );
+        AppError::invalid_input(format!("Invalid tenant ID format: {e}"))
+    })?;
+
+    // Verify user owns this tenant
+    let tenant = database
+        .get_tenant_by_id(tenant_uuid)
+        .await
+        .map_err(|e| AppError::database(e.to_string()))?;
+
+    if tenant.owner_user_id != auth_result.user_id {
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Access denied to this tenant",
+        ));
+    }
+
+    let grace_period = request
+        .grace_period_hours
+        .map_or_else(secret_rotation_grace_period, |hours| {
+            Duration::hours(i64::from(hours))
+        });
+    let previous_secret_expires_at = oauth_client
+        .rotate_secret(
+            tenant_uuid,
+            &provider,
+            &request.client_secret,
+            grace_period,
+            &database,
+        )
+        .await?;
+
+    Ok(RotateTenantOAuthSecretResponse {
+        provider,
+        previous_secret_expires_at: previous_secret_expires_at.to_rfc3339(),
+    })
+}
+
+/// Get tool usage aggregated across all members of a tenant
+///
+/// Only tenant owners and admins may view tenant-wide usage.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Tenant ID is malformed or the date range is invalid
+/// - User is not an owner or admin of the tenant
+/// - Database operations fail
+pub async fn get_tenant_tool_usage(
+    tenant_id: String,
+    query: TenantToolUsageQuery,
+    auth_result: AuthResult,
+    database: Arc<Database>,
+) -> AppResult<TenantToolUsageResponse> {
+    info!("Getting tool usage for tenant: {}", tenant_id);
+
+    let tenant_uuid: TenantId = tenant_id.parse().map_err(|e| {
+        warn!(
+            tenant_id = %tenant_id,
+            user_id = %auth_result.user_id,
+            error = %e,
+            "Failed to parse tenant ID for usage analytics"
+        );
+        AppError::invalid_input(format!("Invalid tenant ID format: {e}"))
+    })?;
+
+    // Verify user is an owner or admin of this tenant
+    let role = database
+        .get_user_tenant_role(auth_result.user_id, tenant_uuid)
+        .await
+        .map_err(|e| AppError::database(e.to_string()))?
+        .map(|role| TenantRole::from_db_string(&role));
+
+    if !matches!(role, Some(TenantRole::Owner | TenantRole::Admin)) {
+        warn!(
+            tenant_id = %tenant_id,
+            user_id = %auth_result.user_id,
+            "Non-admin attempted to view tenant tool usage"
+        );
+        return Err(AppError::new(
+            ErrorCode::PermissionDenied,
+            "Access denied to this tenant",
+        ));
+    }
+
+    let end_date = query.end_date.unwrap_or_else(Utc::now);
+    let start_date = query
+        .start_date
+        .unwrap_or_else(|| end_date - Duration::days(DEFAULT_TOOL_USAGE_WINDOW_DAYS));
+    if start_date >= end_date {
+        return Err(AppError::invalid_input(
+            "start_date must be before end_date",
+        ));
+    }
+
+    let mut tools = database
+        .get_tenant_tool_usage(tenant_uuid, start_date, end_date)
+        .await
+        .map_err(|e| AppError::database(e.to_string()))?;
+
+    let total_requests = tools.iter().map(|tool| tool.request_count).sum();
+    if let Some(limit) = query.limit {
+        tools.truncate(limit);
+    }
+
+    Ok(TenantToolUsageResponse {
+        tenant_id: tenant_uuid.to_string(),
+        start_date: start_date.to_rfc3339(),
+        end_date: end_date.to_rfc3339(),
+        total_requests,
+        tools,
+    })
+}
+
 /// Register OAuth application for MCP clients
 ///
 /// # Errors
@@ -20,12 +20,14 @@ use std::fmt;
 use std::sync::Arc;
 
 use serde_json::Value;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::cache::factory::Cache;
 use crate::database_plugins::factory::Database;
 use crate::database_plugins::DatabaseProvider;
 use crate::errors::{AppError, AppResult, ErrorCode};
+use crate::formatters::UnitSystem;
 use crate::intelligence::ActivityIntelligence;
 use crate::mcp::resources::ServerResources;
 use crate::mcp::tool_selection::ToolSelectionService;
@@ -197,6 +199,24 @@ impl ToolExecutionContext {
         }
     }
 
+    /// Resolve the user's preferred unit system from their fitness profile
+    ///
+    /// Falls back to metric when no profile is stored, the preference is
+    /// missing, or the lookup fails, since tool output is SI by default.
+    pub async fn unit_system(&self) -> UnitSystem {
+        match self.resources.database.get_user_profile(self.user_id).await {
+            Ok(profile) => profile
+                .as_ref()
+                .and_then(|p| p.pointer("/preferences/preferred_units"))
+                .and_then(Value::as_str)
+                .map_or_else(UnitSystem::default, UnitSystem::from_str_param),
+            Err(e) => {
+                warn!(user_id = %self.user_id, error = %e, "Failed to load unit preference, using metric");
+                UnitSystem::default()
+            }
+        }
+    }
+
     /// Get a reference to the database
     #[must_use]
     pub fn database(&self) -> &Database {
@@ -41,7 +41,7 @@ use crate::errors::AppResult;
 use crate::mcp::schema::JsonSchema;
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
-use crate::tools::traits::{McpTool, ToolCapabilities};
+use crate::tools::traits::{McpTool, ScopeRequirement, ToolCapabilities};
 
 /// Audit decorator for MCP tools.
 ///
@@ -116,6 +116,10 @@ impl McpTool for AuditedTool {
         self.inner.capabilities()
     }
 
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        self.inner.required_scopes()
+    }
+
     #[instrument(
         skip(self, args, context),
         fields(
@@ -8,29 +8,81 @@
 //!
 //! This module provides tools for fitness analytics:
 //! - `AnalyzeTrainingLoadTool` - Calculate CTL/ATL/TSB training metrics
+//! - `GetTrainingLoadTrendTool` - Daily CTL/ATL/TSB series for charting
 //! - `DetectPatternsTool` - Detect training patterns and overtraining signs
 //! - `CalculateFitnessScoreTool` - Calculate overall fitness score
+//! - `PredictRaceTimesTool` - Predict 5K to marathon times from recent runs
+//! - `CompareActivitiesTool` - Compare two activities side by side
+//! - `AnalyzeTimeInZonesTool` - Time spent in each heart rate and power zone
 //!
 //! These tools use the intelligence module directly for efficient analysis.
 
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde_json::{json, Value};
-use tracing::info;
+use tracing::{debug, info};
 
 use crate::config::environment::default_provider;
-use crate::errors::AppResult;
-use crate::intelligence::{PatternDetector, RiskLevel, TrainingLoadCalculator, TrainingStatus};
+use crate::config::intelligence::IntelligenceConfig;
+use crate::errors::{AppError, AppResult};
+use crate::intelligence::algorithms::vo2max_estimator::{
+    HeartRateEffort, RaceResult, Vo2maxEstimate, Vo2maxEstimationAlgorithm, Vo2maxEstimationInput,
+};
+use crate::intelligence::analysis_config::ConfidenceLevel;
+use crate::intelligence::physiological_constants::physiological_defaults::DEFAULT_MAX_HR;
+use crate::intelligence::{
+    AdvancedMetrics, MetricsCalculator, PatternDetector, PerformancePredictor, RiskLevel,
+    TimeInZones, TimeInZonesCalculator, TrainingLoadCalculator, TrainingStatus, TssDataPoint,
+    ZoneAnalysis,
+};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
-use crate::models::Activity;
+use crate::models::{Activity, AthleteZones, SportType, TimeSeriesData};
 use crate::protocols::universal::auth_service::AuthService;
 use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+use crate::providers::errors::ProviderError;
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
 use crate::tools::traits::{McpTool, ToolCapabilities};
 
+use super::data::parse_time_bound;
+
+/// Default number of recent activities considered for race predictions
+const DEFAULT_PREDICTION_ACTIVITIES: usize = 50;
+
+/// Maximum number of recent activities fetched for race predictions
+const MAX_PREDICTION_ACTIVITIES: usize = 200;
+
+/// Longest custom target distance accepted for race predictions (100 km)
+const MAX_PREDICTION_DISTANCE_METERS: f64 = 100_000.0;
+
+/// Standard race distances reported by `predict_race_times`
+const STANDARD_RACE_DISTANCES: [(&str, f64); 4] = [
+    ("5K", 5_000.0),
+    ("10K", 10_000.0),
+    ("Half Marathon", 21_097.5),
+    ("Marathon", 42_195.0),
+];
+
+/// Fraction of max heart rate used as LTHR when none is supplied for zone comparison
+const ESTIMATED_LTHR_FRACTION_OF_MAX_HR: f64 = 0.9;
+
+/// Maximum number of activities analyzed for time in zones over a date range
+const MAX_ZONE_ACTIVITIES: usize = 30;
+
+/// Default number of days in a training load trend
+const DEFAULT_TREND_DAYS: i64 = 90;
+
+/// Longest training load trend window in days
+const MAX_TREND_DAYS: i64 = 365;
+
+/// Days of history fetched before a trend window so CTL starts from real load (one CTL window)
+const TREND_WARMUP_DAYS: i64 = 42;
+
+/// Maximum number of activities fetched for a training load trend
+const MAX_TREND_ACTIVITIES: usize = 1000;
+
 // ============================================================================
 // Helper functions for provider creation and activity fetching
 // ============================================================================
@@ -57,14 +109,14 @@ async fn create_provider(
 /// Fetch activities for a given time period
 async fn fetch_activities(
     provider: &dyn FitnessProvider,
-    after_timestamp: i64,
+    after: DateTime<Utc>,
     limit: usize,
 ) -> Result<Vec<Activity>, String> {
     let query_params = ActivityQueryParams {
         limit: Some(limit),
         offset: None,
         before: None,
-        after: Some(after_timestamp),
+        after: Some(after),
     };
 
     provider
@@ -73,6 +125,62 @@ async fn fetch_activities(
         .map_err(|e| format!("Failed to fetch activities: {e}"))
 }
 
+/// Fetch a single activity by ID
+async fn fetch_activity(
+    provider: &dyn FitnessProvider,
+    activity_id: &str,
+    provider_name: &str,
+) -> Result<Activity, ToolResult> {
+    provider.get_activity(activity_id).await.map_err(|e| {
+        ToolResult::error(json!({
+            "error": format!("Failed to fetch activity {activity_id}: {e}"),
+            "provider": provider_name
+        }))
+    })
+}
+
+/// Give an activity without a heart rate stream the provider's separate heart rate series
+///
+/// Returns the scopes to grant when the provider refused for lack of one. Any
+/// other failure leaves the activity unchanged so zones use what it already has.
+async fn attach_heart_rate_series(
+    provider: &dyn FitnessProvider,
+    activity: &mut Activity,
+) -> Option<Vec<String>> {
+    if activity
+        .time_series_data()
+        .is_some_and(|ts| ts.heart_rate.is_some())
+    {
+        return None;
+    }
+
+    match provider.get_activity_hr_series(activity.id()).await {
+        Ok(series) => {
+            activity.attach_heart_rate_series(&series);
+            None
+        }
+        Err(ProviderError::MissingScope { scopes, .. }) => Some(scopes),
+        Err(e) => {
+            debug!("No heart rate series for activity {}: {}", activity.id(), e);
+            None
+        }
+    }
+}
+
+/// Note that heart rate zones were skipped because the provider withheld a scope
+fn missing_scope_json(provider_name: &str, scopes: &[String]) -> Value {
+    let message = format!(
+        "Heart rate zones need {} access from {provider_name}. Reconnect the provider to grant it.",
+        scopes.join(", ")
+    );
+    json!({
+        "error_type": "missing_scope",
+        "provider": provider_name,
+        "missing_scopes": scopes,
+        "error": message,
+    })
+}
+
 /// Build pattern detection JSON response
 fn build_pattern_response(
     activities: &[Activity],
@@ -225,6 +333,656 @@ fn build_fitness_score_response(activities: &[Activity], provider_name: &str) ->
     })
 }
 
+/// Rate prediction confidence from effort recency, qualifying efforts, and run volume
+fn race_prediction_confidence(
+    runs: &[Activity],
+    qualifying_efforts: usize,
+    best_date: DateTime<Utc>,
+) -> &'static str {
+    let days_since_best = (Utc::now() - best_date).num_days();
+    let recency_score = match days_since_best {
+        d if d < 30 => 2,
+        d if d < 90 => 1,
+        _ => 0,
+    };
+    let effort_score = match qualifying_efforts {
+        n if n >= 3 => 2,
+        2 => 1,
+        _ => 0,
+    };
+    let volume_score = match runs.len() {
+        n if n >= 20 => 2,
+        n if n >= 10 => 1,
+        _ => 0,
+    };
+
+    match recency_score + effort_score + volume_score {
+        score if score >= 5 => "high",
+        score if score >= 3 => "medium",
+        _ => "low",
+    }
+}
+
+/// Estimate `VO2max` from recent runs with the given model
+///
+/// Every run with a distance counts as a race result and every run with average
+/// heart rate and speed as a steady-state effort. The highest recorded heart rate
+/// stands in for the measured maximum. Activities carry no resting heart rate, so
+/// models that need one return an error.
+///
+/// # Errors
+///
+/// Returns an error if the runs lack the data the model needs
+pub fn estimate_vo2max_from_runs(
+    runs: &[Activity],
+    algorithm: Vo2maxEstimationAlgorithm,
+) -> AppResult<Vo2maxEstimate> {
+    #[allow(clippy::cast_precision_loss)]
+    let recent_races = runs
+        .iter()
+        .filter_map(|run| {
+            let distance_meters = run.distance_meters()?;
+            let time_seconds = run.duration_seconds() as f64;
+            (distance_meters > 0.0 && time_seconds > 0.0).then_some(RaceResult {
+                distance_meters,
+                time_seconds,
+            })
+        })
+        .collect();
+    let heart_rate_efforts = runs
+        .iter()
+        .filter_map(|run| {
+            Some(HeartRateEffort {
+                speed_mps: run.average_speed()?,
+                average_heart_rate: f64::from(run.average_heart_rate()?),
+            })
+        })
+        .collect();
+    let input = Vo2maxEstimationInput {
+        recent_races,
+        heart_rate_efforts,
+        resting_heart_rate: None,
+        max_heart_rate: runs
+            .iter()
+            .filter_map(Activity::max_heart_rate)
+            .max()
+            .map(f64::from),
+        age: None,
+    };
+
+    algorithm.estimator().estimate(&input)
+}
+
+/// `VO2max` estimate from the configured model, or the reason it is unavailable
+fn vo2max_json(runs: &[Activity]) -> Value {
+    let algorithm = IntelligenceConfig::global().algorithms.vo2max;
+    match estimate_vo2max_from_runs(runs, algorithm) {
+        Ok(estimate) => json!({
+            "value": (estimate.vo2max * 10.0).round() / 10.0,
+            "confidence": match estimate.confidence {
+                ConfidenceLevel::VeryHigh => "very_high",
+                ConfidenceLevel::High => "high",
+                ConfidenceLevel::Medium => "medium",
+                ConfidenceLevel::Low => "low",
+            },
+            "algorithm": algorithm.name(),
+        }),
+        Err(e) => json!({
+            "value": null,
+            "algorithm": algorithm.name(),
+            "message": e.to_string(),
+        }),
+    }
+}
+
+/// Format a single race time prediction
+fn race_prediction_json(name: &str, distance_meters: f64, time_seconds: f64) -> Value {
+    json!({
+        "distance": name,
+        "distance_meters": distance_meters,
+        "predicted_time_seconds": time_seconds.round(),
+        "predicted_time_formatted": PerformancePredictor::format_time(time_seconds),
+        "predicted_pace_min_km": PerformancePredictor::format_pace_per_km(distance_meters / time_seconds),
+    })
+}
+
+/// Predict race times from recent activities
+///
+/// Uses the fastest recent run that looks like a race effort (at least 3 km, under
+/// two hours, faster than 8:00/km) to derive VDOT, then predicts 5K, 10K, half and
+/// full marathon times plus the optional `target_distance_meters`. Returns an
+/// `insufficient_data` error result when no run qualifies.
+#[must_use]
+pub fn build_race_predictions_result(
+    activities: &[Activity],
+    target_distance_meters: Option<f64>,
+    provider_name: &str,
+) -> ToolResult {
+    let runs: Vec<Activity> = activities
+        .iter()
+        .filter(|a| {
+            matches!(
+                a.sport_type(),
+                SportType::Run | SportType::VirtualRun | SportType::TrailRunning
+            )
+        })
+        .cloned()
+        .collect();
+
+    let Some(best) = PerformancePredictor::find_best_performance(&runs) else {
+        return ToolResult::error(json!({
+            "error": "insufficient_data",
+            "message": "Race predictions need at least one recent run of 3 km or more, under 2 hours, faster than 8:00/km",
+            "activities_analyzed": activities.len(),
+            "runs_found": runs.len(),
+            "provider": provider_name
+        }));
+    };
+
+    let best_distance = best.distance_meters().unwrap_or_default();
+    #[allow(clippy::cast_precision_loss)]
+    let best_time = best.duration_seconds() as f64;
+
+    let predicted =
+        PerformancePredictor::calculate_vdot(best_distance, best_time).and_then(|vdot| {
+            let mut targets: Vec<(String, f64)> = STANDARD_RACE_DISTANCES
+                .iter()
+                .map(|(name, distance)| ((*name).to_owned(), *distance))
+                .collect();
+            if let Some(distance) = target_distance_meters {
+                targets.push((format!("{:.1}km", distance / 1000.0), distance));
+            }
+            targets
+                .into_iter()
+                .map(|(name, distance)| {
+                    PerformancePredictor::predict_time_vdot(vdot, distance)
+                        .map(|time| race_prediction_json(&name, distance, time))
+                })
+                .collect::<AppResult<Vec<Value>>>()
+                .map(|predictions| (vdot, predictions))
+        });
+
+    let (vdot, mut predictions) = match predicted {
+        Ok(result) => result,
+        Err(e) => {
+            return ToolResult::error(json!({
+                "error": "insufficient_data",
+                "message": format!("Best recent run cannot be used for race predictions: {e}"),
+                "activities_analyzed": activities.len(),
+                "runs_found": runs.len(),
+                "provider": provider_name
+            }));
+        }
+    };
+
+    let target = target_distance_meters.and_then(|_| predictions.pop());
+    let qualifying_efforts = runs
+        .iter()
+        .filter(|run| PerformancePredictor::is_race_effort(run))
+        .count();
+
+    ToolResult::ok(json!({
+        "vdot": (vdot * 10.0).round() / 10.0,
+        "predictions": predictions,
+        "target_distance": target,
+        "confidence": race_prediction_confidence(&runs, qualifying_efforts, best.start_date()),
+        "vo2max": vo2max_json(&runs),
+        "based_on": {
+            "activity_id": best.id(),
+            "distance_meters": best_distance,
+            "time_seconds": best_time,
+            "pace_min_km": PerformancePredictor::format_pace_per_km(best_distance / best_time),
+            "date": best.start_date().to_rfc3339()
+        },
+        "activities_analyzed": activities.len(),
+        "runs_analyzed": runs.len(),
+        "provider": provider_name
+    }))
+}
+
+/// Sport family whose activities share pace, distance, and power semantics
+fn sport_family(sport: &SportType) -> Option<&'static str> {
+    match sport {
+        SportType::Run | SportType::VirtualRun | SportType::TrailRunning => Some("running"),
+        SportType::Ride
+        | SportType::VirtualRide
+        | SportType::EbikeRide
+        | SportType::MountainBike
+        | SportType::GravelRide => Some("cycling"),
+        _ => None,
+    }
+}
+
+/// Whether two activities are the same kind of sport (treadmill and road runs count as one)
+fn same_sport(first: &SportType, second: &SportType) -> bool {
+    first == second
+        || sport_family(first).is_some_and(|family| sport_family(second) == Some(family))
+}
+
+/// Pace in seconds per kilometer, when the activity has a distance
+fn pace_seconds_per_km(activity: &Activity) -> Option<f64> {
+    activity
+        .distance_meters()
+        .filter(|distance| *distance > 0.0)
+        .map(|distance| activity.duration_seconds() as f64 / (distance / 1000.0))
+}
+
+/// Round to the given number of decimal places
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10_f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+/// A metric available on both activities being compared
+struct ComparedMetric {
+    name: &'static str,
+    baseline: f64,
+    activity: f64,
+}
+
+impl ComparedMetric {
+    fn delta(&self) -> f64 {
+        self.activity - self.baseline
+    }
+
+    /// Change relative to the baseline, or `None` when the baseline is zero
+    fn delta_percent(&self) -> Option<f64> {
+        (self.baseline.abs() > f64::EPSILON).then(|| self.delta() / self.baseline * 100.0)
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "baseline": round_to(self.baseline, 3),
+            "activity": round_to(self.activity, 3),
+            "delta": round_to(self.delta(), 3),
+            "delta_percent": self.delta_percent().map(|percent| round_to(percent, 1)),
+        })
+    }
+}
+
+/// Metric values for both activities: `(name, baseline, activity, sport_specific)`
+type MetricPair = (&'static str, Option<f64>, Option<f64>, bool);
+
+/// Collect comparable metrics for the activity and its baseline
+fn metric_pairs(
+    activity: &Activity,
+    activity_metrics: &AdvancedMetrics,
+    baseline: &Activity,
+    baseline_metrics: &AdvancedMetrics,
+) -> Vec<MetricPair> {
+    let hr = |a: &Activity| a.average_heart_rate().map(f64::from);
+    let max_hr = |a: &Activity| a.max_heart_rate().map(f64::from);
+    let power = |a: &Activity| a.average_power().map(f64::from);
+
+    vec![
+        (
+            "distance_meters",
+            baseline.distance_meters(),
+            activity.distance_meters(),
+            true,
+        ),
+        (
+            "duration_seconds",
+            Some(baseline.duration_seconds() as f64),
+            Some(activity.duration_seconds() as f64),
+            false,
+        ),
+        (
+            "pace_seconds_per_km",
+            pace_seconds_per_km(baseline),
+            pace_seconds_per_km(activity),
+            true,
+        ),
+        ("average_heart_rate", hr(baseline), hr(activity), false),
+        ("max_heart_rate", max_hr(baseline), max_hr(activity), false),
+        ("average_power", power(baseline), power(activity), true),
+        (
+            "normalized_power",
+            baseline_metrics.normalized_power,
+            activity_metrics.normalized_power,
+            true,
+        ),
+        (
+            "elevation_gain_meters",
+            baseline.elevation_gain(),
+            activity.elevation_gain(),
+            false,
+        ),
+        (
+            "aerobic_efficiency",
+            baseline_metrics.aerobic_efficiency,
+            activity_metrics.aerobic_efficiency,
+            true,
+        ),
+        (
+            "training_stress_score",
+            baseline_metrics.training_stress_score,
+            activity_metrics.training_stress_score,
+            false,
+        ),
+    ]
+}
+
+/// Zone percentages as an array, zone 1 first
+const fn zone_percentages(zones: &ZoneAnalysis) -> [f64; 5] {
+    [
+        zones.zone1_percentage,
+        zones.zone2_percentage,
+        zones.zone3_percentage,
+        zones.zone4_percentage,
+        zones.zone5_percentage,
+    ]
+}
+
+/// Per-zone share of time for both activities with the change in percentage points
+fn zone_comparison_json(baseline: &ZoneAnalysis, activity: &ZoneAnalysis) -> Value {
+    let zones: serde_json::Map<String, Value> = zone_percentages(baseline)
+        .into_iter()
+        .zip(zone_percentages(activity))
+        .enumerate()
+        .map(|(index, (baseline_pct, activity_pct))| {
+            (
+                format!("zone{}", index + 1),
+                json!({
+                    "baseline_percent": round_to(baseline_pct, 1),
+                    "activity_percent": round_to(activity_pct, 1),
+                    "delta_points": round_to(activity_pct - baseline_pct, 1),
+                }),
+            )
+        })
+        .collect();
+    Value::Object(zones)
+}
+
+/// Time series samples as `f32`, when the series is present and non-empty
+fn series_f32(series: Option<&[u32]>) -> Option<Vec<f32>> {
+    series
+        .filter(|values| !values.is_empty())
+        .map(|values| values.iter().map(|&value| value as f32).collect())
+}
+
+/// Compare heart rate and power zone distributions when both activities have streams
+///
+/// Heart rate zones use the calculator's LTHR, or one estimated from the higher
+/// max heart rate of the two activities. Power zones require the calculator's FTP.
+fn zone_distribution_json(
+    activity: &Activity,
+    baseline: &Activity,
+    calculator: &MetricsCalculator,
+) -> Value {
+    let hr_series =
+        |a: &Activity| series_f32(a.time_series_data().and_then(|ts| ts.heart_rate.as_deref()));
+    let power_series =
+        |a: &Activity| series_f32(a.time_series_data().and_then(|ts| ts.power.as_deref()));
+
+    let mut distribution = serde_json::Map::new();
+
+    if let (Some(baseline_hr), Some(activity_hr)) = (hr_series(baseline), hr_series(activity)) {
+        let estimated_lthr = baseline
+            .max_heart_rate()
+            .max(activity.max_heart_rate())
+            .map(|max_hr| f64::from(max_hr) * ESTIMATED_LTHR_FRACTION_OF_MAX_HR);
+        let lthr = calculator
+            .lthr
+            .map(|lthr| (lthr, "provided"))
+            .or_else(|| estimated_lthr.map(|lthr| (lthr, "estimated_from_max_hr")));
+        if let Some((lthr, source)) = lthr {
+            distribution.insert(
+                "heart_rate".to_owned(),
+                json!({
+                    "lthr": round_to(lthr, 1),
+                    "lthr_source": source,
+                    "zones": zone_comparison_json(
+                        &ZoneAnalysis::from_heart_rate_data(&baseline_hr, lthr),
+                        &ZoneAnalysis::from_heart_rate_data(&activity_hr, lthr),
+                    ),
+                }),
+            );
+        }
+    }
+
+    if let (Some(ftp), Some(baseline_power), Some(activity_power)) = (
+        calculator.ftp,
+        power_series(baseline),
+        power_series(activity),
+    ) {
+        distribution.insert(
+            "power".to_owned(),
+            json!({
+                "ftp": ftp,
+                "zones": zone_comparison_json(
+                    &ZoneAnalysis::from_power_data(&baseline_power, ftp),
+                    &ZoneAnalysis::from_power_data(&activity_power, ftp),
+                ),
+            }),
+        );
+    }
+
+    Value::Object(distribution)
+}
+
+/// Describe the most relevant differences in a few sentences
+fn comparison_summary(compared: &[ComparedMetric], sport_mismatch: Option<(&str, &str)>) -> String {
+    let find = |name: &str| compared.iter().find(|metric| metric.name == name);
+    let mut sentences = Vec::new();
+
+    if let Some((activity_sport, baseline_sport)) = sport_mismatch {
+        sentences.push(format!(
+            "Different sports ({activity_sport} vs {baseline_sport}): only sport-independent metrics were compared."
+        ));
+    }
+
+    if let Some(percent) = find("pace_seconds_per_km").and_then(ComparedMetric::delta_percent) {
+        let direction = if percent < 0.0 { "faster" } else { "slower" };
+        sentences.push(format!(
+            "Pace was {:.1}% {direction} than the baseline.",
+            percent.abs()
+        ));
+    }
+
+    if let Some(metric) = find("distance_meters").filter(|metric| metric.delta().abs() >= 1.0) {
+        let direction = if metric.delta() > 0.0 {
+            "longer"
+        } else {
+            "shorter"
+        };
+        sentences.push(format!(
+            "Distance was {:.2} km {direction}.",
+            metric.delta().abs() / 1000.0
+        ));
+    }
+
+    if let Some(metric) = find("average_heart_rate").filter(|metric| metric.delta().abs() >= 1.0) {
+        let direction = if metric.delta() > 0.0 {
+            "higher"
+        } else {
+            "lower"
+        };
+        sentences.push(format!(
+            "Average heart rate was {:.0} bpm {direction}.",
+            metric.delta().abs()
+        ));
+    }
+
+    if let Some(percent) = find("aerobic_efficiency").and_then(ComparedMetric::delta_percent) {
+        let direction = if percent >= 0.0 {
+            "improved"
+        } else {
+            "declined"
+        };
+        sentences.push(format!(
+            "Aerobic efficiency {direction} by {:.1}%.",
+            percent.abs()
+        ));
+    }
+
+    if sentences.is_empty() {
+        "No comparable metrics showed a notable difference.".to_owned()
+    } else {
+        sentences.join(" ")
+    }
+}
+
+/// Brief identification of an activity in a comparison
+fn activity_summary_json(activity: &Activity) -> Value {
+    json!({
+        "id": activity.id(),
+        "name": activity.name(),
+        "sport_type": activity.sport_type().display_name(),
+        "start_date": activity.start_date().to_rfc3339(),
+    })
+}
+
+/// Compare an activity against a baseline activity
+///
+/// Deltas are `activity - baseline`, with percentages relative to the baseline.
+/// Only metrics present on both activities are compared. When the sports differ
+/// (runs and treadmill runs count as the same sport) pace, distance, power, and
+/// efficiency are skipped and `sport_mismatch` is set.
+///
+/// # Errors
+/// Returns an error if metrics calculation fails for either activity
+pub fn build_activity_comparison(
+    activity: &Activity,
+    baseline: &Activity,
+    calculator: &MetricsCalculator,
+    provider_name: &str,
+) -> AppResult<Value> {
+    let activity_metrics = calculator.calculate_metrics(activity)?;
+    let baseline_metrics = calculator.calculate_metrics(baseline)?;
+    let sport_mismatch = !same_sport(activity.sport_type(), baseline.sport_type());
+
+    let mut compared = Vec::new();
+    let mut skipped = Vec::new();
+    let mut unavailable = Vec::new();
+    for (name, baseline_value, activity_value, sport_specific) in
+        metric_pairs(activity, &activity_metrics, baseline, &baseline_metrics)
+    {
+        match (baseline_value, activity_value) {
+            _ if sport_specific && sport_mismatch => skipped.push(name),
+            (Some(baseline), Some(activity)) => compared.push(ComparedMetric {
+                name,
+                baseline,
+                activity,
+            }),
+            _ => unavailable.push(name),
+        }
+    }
+
+    let metrics: serde_json::Map<String, Value> = compared
+        .iter()
+        .map(|metric| (metric.name.to_owned(), metric.to_json()))
+        .collect();
+    let summary = comparison_summary(
+        &compared,
+        sport_mismatch.then(|| {
+            (
+                activity.sport_type().display_name(),
+                baseline.sport_type().display_name(),
+            )
+        }),
+    );
+
+    Ok(json!({
+        "activity": activity_summary_json(activity),
+        "baseline": activity_summary_json(baseline),
+        "sport_mismatch": sport_mismatch,
+        "metrics": metrics,
+        "skipped_metrics": skipped,
+        "unavailable_metrics": unavailable,
+        "zone_distribution": zone_distribution_json(activity, baseline, calculator),
+        "summary": summary,
+        "provider": provider_name
+    }))
+}
+
+/// Time in zones for one stream of every activity that has it
+///
+/// Returns the combined breakdown and the IDs of activities without the stream.
+fn time_in_zones_across<'a>(
+    activities: &'a [Activity],
+    calculator: &TimeInZonesCalculator,
+    stream: impl Fn(&TimeSeriesData) -> Option<&[u32]>,
+) -> (TimeInZones, Vec<&'a str>) {
+    let mut total = TimeInZones::empty(calculator.zones());
+    let mut missing = Vec::new();
+    for activity in activities {
+        let computed = activity.time_series_data().and_then(|series| {
+            stream(series).map(|values| calculator.compute(&series.timestamps, values))
+        });
+        match computed.filter(|time| !time.is_empty()) {
+            Some(time) => total.add(&time),
+            None => missing.push(activity.id()),
+        }
+    }
+    (total, missing)
+}
+
+/// Format a time-in-zones breakdown with minutes alongside seconds
+fn time_in_zones_json(time: &TimeInZones, zone_source: &str) -> Value {
+    let zones: Vec<Value> = time
+        .zones
+        .iter()
+        .map(|zone| {
+            json!({
+                "zone": zone.zone,
+                "min": zone.min,
+                "max": zone.max,
+                "seconds": zone.seconds.round(),
+                "minutes": round_to(zone.seconds / 60.0, 1),
+                "percentage": round_to(zone.percentage, 1),
+            })
+        })
+        .collect();
+    json!({
+        "zone_source": zone_source,
+        "zone_distribution": time.distribution(),
+        "total_seconds": time.total_seconds.round(),
+        "zones": zones,
+    })
+}
+
+/// Compute time in heart rate (and optionally power) zones over activities
+///
+/// Zones are passed with their source (`"athlete"` for the provider's zones,
+/// `"config"` for zones derived from max heart rate or FTP). Activities without
+/// a heart rate stream are listed and left out. Returns a `no_heart_rate_data`
+/// error result when none of the activities has one.
+#[must_use]
+pub fn build_time_in_zones_result(
+    activities: &[Activity],
+    heart_rate_zones: (&TimeInZonesCalculator, &str),
+    power_zones: Option<(&TimeInZonesCalculator, &str)>,
+    provider_name: &str,
+) -> ToolResult {
+    let (heart_rate, without_heart_rate) =
+        time_in_zones_across(activities, heart_rate_zones.0, |series| {
+            series.heart_rate.as_deref()
+        });
+    if heart_rate.is_empty() {
+        return ToolResult::error(json!({
+            "error": "no_heart_rate_data",
+            "message": "None of the requested activities has a heart rate stream to compute time in zones from",
+            "activity_ids": without_heart_rate,
+            "provider": provider_name
+        }));
+    }
+
+    let power = power_zones.and_then(|(calculator, source)| {
+        let (power, _) =
+            time_in_zones_across(activities, calculator, |series| series.power.as_deref());
+        (!power.is_empty()).then(|| time_in_zones_json(&power, source))
+    });
+
+    ToolResult::ok(json!({
+        "activities_analyzed": activities.len() - without_heart_rate.len(),
+        "activities_without_heart_rate": without_heart_rate,
+        "heart_rate": time_in_zones_json(&heart_rate, heart_rate_zones.1),
+        "power": power,
+        "provider": provider_name
+    }))
+}
+
 // ============================================================================
 // AnalyzeTrainingLoadTool - Calculate CTL/ATL/TSB
 // ============================================================================
@@ -263,6 +1021,16 @@ impl McpTool for AnalyzeTrainingLoadTool {
                 ),
             },
         );
+        properties.insert(
+            "explain".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(
+                    "Attach a computation_trace to each insight listing its inputs, thresholds, and formula. Default: false."
+                        .to_owned(),
+                ),
+            },
+        );
         JsonSchema {
             schema_type: "object".to_owned(),
             properties: Some(properties),
@@ -285,6 +1053,10 @@ impl McpTool for AnalyzeTrainingLoadTool {
             .and_then(Value::as_i64)
             .unwrap_or(42)
             .min(180);
+        let explain = args
+            .get("explain")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
 
         let provider = match create_provider(context, &provider_name).await {
             Ok(p) => p,
@@ -292,7 +1064,7 @@ impl McpTool for AnalyzeTrainingLoadTool {
         };
 
         let after = Utc::now() - Duration::days(days);
-        let activities = match fetch_activities(provider.as_ref(), after.timestamp(), 500).await {
+        let activities = match fetch_activities(provider.as_ref(), after, 500).await {
             Ok(acts) => acts,
             Err(e) => {
                 return Ok(ToolResult::error(json!({
@@ -334,6 +1106,14 @@ impl McpTool for AnalyzeTrainingLoadTool {
             load.ctl, load.atl, load.tsb, status
         );
 
+        let overtraining_insight = TrainingLoadCalculator::overtraining_insight(
+            &load,
+            &IntelligenceConfig::global()
+                .sleep_recovery
+                .training_stress_balance,
+        )
+        .explained(explain);
+
         Ok(ToolResult::ok(json!({
             "training_load": {
                 "ctl": load.ctl,
@@ -350,6 +1130,7 @@ impl McpTool for AnalyzeTrainingLoadTool {
                 TrainingStatus::Overreaching => "High fatigue, prioritize recovery",
                 TrainingStatus::Detraining => "Very fresh but risk of fitness loss without training",
             },
+            "insights": [overtraining_insight],
             "analysis_period": {
                 "days": days,
                 "activities_analyzed": activities.len()
@@ -359,21 +1140,60 @@ impl McpTool for AnalyzeTrainingLoadTool {
     }
 }
 
+/// Build the `get_training_load_trend` result for the days from `start` through `end`
+///
+/// `tss_history` should reach back before `start` so CTL is warmed up.
+#[must_use]
+pub fn build_training_load_trend_result(
+    tss_history: &[TssDataPoint],
+    start: NaiveDate,
+    end: NaiveDate,
+    provider_name: &str,
+) -> ToolResult {
+    let trend =
+        TrainingLoadCalculator::new().calculate_training_load_trend(tss_history, start, end);
+    let sessions_in_window = tss_history
+        .iter()
+        .filter(|point| (start..=end).contains(&point.date.date_naive()))
+        .count();
+    let current = trend.points.last().map(|point| {
+        json!({
+            "ctl": point.ctl,
+            "atl": point.atl,
+            "tsb": point.tsb
+        })
+    });
+
+    ToolResult::ok(json!({
+        "window": {
+            "start": start.to_string(),
+            "end": end.to_string(),
+            "days": trend.points.len()
+        },
+        "series": trend.points,
+        "current": current,
+        "status": format!("{:?}", trend.status),
+        "overtraining_risk": trend.overtraining_risk,
+        "activities_analyzed": sessions_in_window,
+        "provider": provider_name
+    }))
+}
+
 // ============================================================================
-// DetectPatternsTool - Detect training patterns
+// GetTrainingLoadTrendTool - Daily CTL/ATL/TSB series
 // ============================================================================
 
-/// Tool for detecting training patterns and potential issues.
-pub struct DetectPatternsTool;
+/// Tool returning the daily CTL/ATL/TSB curve over a window.
+pub struct GetTrainingLoadTrendTool;
 
 #[async_trait]
-impl McpTool for DetectPatternsTool {
+impl McpTool for GetTrainingLoadTrendTool {
     fn name(&self) -> &'static str {
-        "detect_patterns"
+        "get_training_load_trend"
     }
 
     fn description(&self) -> &'static str {
-        "Detect training patterns including hard/easy day balance, weekly schedule consistency, volume progression, and overtraining warning signs"
+        "Get a daily time series of CTL (fitness), ATL (fatigue), and TSB (form) over a window for charting, with the training status and overtraining risk at the end of the window"
     }
 
     fn input_schema(&self) -> JsonSchema {
@@ -388,11 +1208,11 @@ impl McpTool for DetectPatternsTool {
             },
         );
         properties.insert(
-            "weeks".to_owned(),
+            "days".to_owned(),
             PropertySchema {
                 property_type: "integer".to_owned(),
                 description: Some(
-                    "Number of weeks to analyze for patterns. Default: 4.".to_owned(),
+                    "Number of days in the series, ending today. Default: 90, max: 365.".to_owned(),
                 ),
             },
         );
@@ -413,66 +1233,78 @@ impl McpTool for DetectPatternsTool {
             .and_then(Value::as_str)
             .map_or_else(default_provider, String::from);
 
-        let weeks = args
-            .get("weeks")
+        let days = args
+            .get("days")
             .and_then(Value::as_i64)
-            .unwrap_or(4)
-            .min(12);
+            .unwrap_or(DEFAULT_TREND_DAYS)
+            .clamp(1, MAX_TREND_DAYS);
 
         let provider = match create_provider(context, &provider_name).await {
             Ok(p) => p,
             Err(result) => return Ok(result),
         };
 
-        let after = Utc::now() - Duration::weeks(weeks);
-        let activities = match fetch_activities(provider.as_ref(), after.timestamp(), 200).await {
-            Ok(acts) => acts,
+        let end = Utc::now().date_naive();
+        let start = end - Duration::days(days - 1);
+        let after = Utc::now() - Duration::days(days + TREND_WARMUP_DAYS);
+        let activities =
+            match fetch_activities(provider.as_ref(), after, MAX_TREND_ACTIVITIES).await {
+                Ok(acts) => acts,
+                Err(e) => {
+                    return Ok(ToolResult::error(json!({
+                        "error": e,
+                        "provider": provider_name
+                    })));
+                }
+            };
+
+        let load = match TrainingLoadCalculator::new().calculate_training_load(
+            &activities,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) {
+            Ok(l) => l,
             Err(e) => {
                 return Ok(ToolResult::error(json!({
-                    "error": e,
+                    "error": format!("Failed to calculate training load: {e}"),
                     "provider": provider_name
                 })));
             }
         };
 
-        if activities.len() < 3 {
-            return Ok(ToolResult::ok(json!({
-                "message": "Insufficient activities for pattern detection (need at least 3)",
-                "weeks_analyzed": weeks,
-                "activities_found": activities.len(),
-                "provider": provider_name
-            })));
-        }
-
-        info!(
-            "Pattern detection: {} activities over {} weeks",
-            activities.len(),
-            weeks
+        debug!(
+            "Training load trend: {} days from {} sessions",
+            days,
+            load.tss_history.len()
         );
 
-        Ok(ToolResult::ok(build_pattern_response(
-            &activities,
-            weeks,
+        Ok(build_training_load_trend_result(
+            &load.tss_history,
+            start,
+            end,
             &provider_name,
-        )))
+        ))
     }
 }
 
 // ============================================================================
-// CalculateFitnessScoreTool - Calculate overall fitness score
+// DetectPatternsTool - Detect training patterns
 // ============================================================================
 
-/// Tool for calculating an overall fitness score.
-pub struct CalculateFitnessScoreTool;
+/// Tool for detecting training patterns and potential issues.
+pub struct DetectPatternsTool;
 
 #[async_trait]
-impl McpTool for CalculateFitnessScoreTool {
+impl McpTool for DetectPatternsTool {
     fn name(&self) -> &'static str {
-        "calculate_fitness_score"
+        "detect_patterns"
     }
 
     fn description(&self) -> &'static str {
-        "Calculate an overall fitness score (0-100) based on training consistency, CTL, training volume, and recovery balance"
+        "Detect training patterns including hard/easy day balance, weekly schedule consistency, volume progression, and overtraining warning signs"
     }
 
     fn input_schema(&self) -> JsonSchema {
@@ -486,6 +1318,15 @@ impl McpTool for CalculateFitnessScoreTool {
                 ),
             },
         );
+        properties.insert(
+            "weeks".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some(
+                    "Number of weeks to analyze for patterns. Default: 4.".to_owned(),
+                ),
+            },
+        );
         JsonSchema {
             schema_type: "object".to_owned(),
             properties: Some(properties),
@@ -503,13 +1344,103 @@ impl McpTool for CalculateFitnessScoreTool {
             .and_then(Value::as_str)
             .map_or_else(default_provider, String::from);
 
-        let provider = match create_provider(context, &provider_name).await {
+        let weeks = args
+            .get("weeks")
+            .and_then(Value::as_i64)
+            .unwrap_or(4)
+            .min(12);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let after = Utc::now() - Duration::weeks(weeks);
+        let activities = match fetch_activities(provider.as_ref(), after, 200).await {
+            Ok(acts) => acts,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": e,
+                    "provider": provider_name
+                })));
+            }
+        };
+
+        if activities.len() < 3 {
+            return Ok(ToolResult::ok(json!({
+                "message": "Insufficient activities for pattern detection (need at least 3)",
+                "weeks_analyzed": weeks,
+                "activities_found": activities.len(),
+                "provider": provider_name
+            })));
+        }
+
+        info!(
+            "Pattern detection: {} activities over {} weeks",
+            activities.len(),
+            weeks
+        );
+
+        Ok(ToolResult::ok(build_pattern_response(
+            &activities,
+            weeks,
+            &provider_name,
+        )))
+    }
+}
+
+// ============================================================================
+// CalculateFitnessScoreTool - Calculate overall fitness score
+// ============================================================================
+
+/// Tool for calculating an overall fitness score.
+pub struct CalculateFitnessScoreTool;
+
+#[async_trait]
+impl McpTool for CalculateFitnessScoreTool {
+    fn name(&self) -> &'static str {
+        "calculate_fitness_score"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculate an overall fitness score (0-100) based on training consistency, CTL, training volume, and recovery balance"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query. Defaults to configured provider.".to_owned(),
+                ),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let provider = match create_provider(context, &provider_name).await {
             Ok(p) => p,
             Err(result) => return Ok(result),
         };
 
         let after = Utc::now() - Duration::weeks(6);
-        let activities = match fetch_activities(provider.as_ref(), after.timestamp(), 200).await {
+        let activities = match fetch_activities(provider.as_ref(), after, 200).await {
             Ok(acts) => acts,
             Err(e) => {
                 return Ok(ToolResult::error(json!({
@@ -541,6 +1472,480 @@ impl McpTool for CalculateFitnessScoreTool {
     }
 }
 
+// ============================================================================
+// PredictRaceTimesTool - Predict race times from recent runs
+// ============================================================================
+
+/// Tool for predicting race times from recent running performances.
+pub struct PredictRaceTimesTool;
+
+#[async_trait]
+impl McpTool for PredictRaceTimesTool {
+    fn name(&self) -> &'static str {
+        "predict_race_times"
+    }
+
+    fn description(&self) -> &'static str {
+        "Predict 5K, 10K, half marathon and marathon race times (plus an optional custom distance) from the best recent run using Jack Daniels' VDOT methodology, with a confidence rating"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query. Defaults to configured provider.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "distance_meters".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Optional custom race distance in meters to predict in addition to the standard distances (max 100000)."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "recent_activities_limit".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some(
+                    "Number of recent activities to consider. Default: 50, max: 200.".to_owned(),
+                ),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA | ToolCapabilities::ANALYTICS
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let target_distance = args.get("distance_meters").and_then(Value::as_f64);
+        if let Some(distance) = target_distance {
+            if distance <= 0.0 || distance > MAX_PREDICTION_DISTANCE_METERS {
+                return Ok(ToolResult::error(json!({
+                    "error": "invalid_input",
+                    "message": format!(
+                        "distance_meters must be greater than 0 and at most {MAX_PREDICTION_DISTANCE_METERS}"
+                    ),
+                    "distance_meters": distance
+                })));
+            }
+        }
+
+        let limit = args
+            .get("recent_activities_limit")
+            .and_then(Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(DEFAULT_PREDICTION_ACTIVITIES)
+            .clamp(1, MAX_PREDICTION_ACTIVITIES);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let query_params = ActivityQueryParams {
+            limit: Some(limit),
+            offset: None,
+            before: None,
+            after: None,
+        };
+        let activities = match provider.get_activities_with_params(&query_params).await {
+            Ok(acts) => acts,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to fetch activities: {e}"),
+                    "provider": provider_name
+                })));
+            }
+        };
+
+        info!(
+            "Race time prediction for user {} ({} activities)",
+            context.user_id,
+            activities.len()
+        );
+
+        Ok(build_race_predictions_result(
+            &activities,
+            target_distance,
+            &provider_name,
+        ))
+    }
+}
+
+// ============================================================================
+// CompareActivitiesTool - Compare two activities side by side
+// ============================================================================
+
+/// Tool for comparing an activity against a baseline activity.
+pub struct CompareActivitiesTool;
+
+#[async_trait]
+impl McpTool for CompareActivitiesTool {
+    fn name(&self) -> &'static str {
+        "compare_activities"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compare two activities side by side: pace, heart rate, power, distance, elevation, efficiency, and zone distribution with percentage deltas and a short summary"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("ID of the activity to evaluate.".to_owned()),
+            },
+        );
+        properties.insert(
+            "compare_activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "ID of the baseline activity to compare against. Deltas are relative to this activity."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query. Defaults to configured provider.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "lthr".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Lactate threshold heart rate for heart rate zones. Estimated from max heart rate when omitted."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "ftp".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Functional threshold power, enables power zones and power-based TSS."
+                        .to_owned(),
+                ),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: Some(vec![
+                "activity_id".to_owned(),
+                "compare_activity_id".to_owned(),
+            ]),
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA | ToolCapabilities::ANALYTICS
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let activity_id = args
+            .get("activity_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input("activity_id is required"))?;
+        let compare_activity_id = args
+            .get("compare_activity_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input("compare_activity_id is required"))?;
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+        let calculator = MetricsCalculator::new().with_user_data(
+            args.get("ftp").and_then(Value::as_f64),
+            args.get("lthr").and_then(Value::as_f64),
+            None,
+            None,
+            None,
+        );
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let mut activity =
+            match fetch_activity(provider.as_ref(), activity_id, &provider_name).await {
+                Ok(activity) => activity,
+                Err(result) => return Ok(result),
+            };
+        let mut baseline =
+            match fetch_activity(provider.as_ref(), compare_activity_id, &provider_name).await {
+                Ok(activity) => activity,
+                Err(result) => return Ok(result),
+            };
+
+        // Real heart rate series give measured zones where the summary has none
+        let activity_scopes = attach_heart_rate_series(provider.as_ref(), &mut activity).await;
+        let baseline_scopes = attach_heart_rate_series(provider.as_ref(), &mut baseline).await;
+        let missing_scopes = activity_scopes.or(baseline_scopes);
+
+        info!(
+            "Comparing activity {} against {} for user {}",
+            activity_id, compare_activity_id, context.user_id
+        );
+
+        match build_activity_comparison(&activity, &baseline, &calculator, &provider_name) {
+            Ok(mut comparison) => {
+                if let Some(scopes) = missing_scopes {
+                    comparison["missing_scope"] = missing_scope_json(&provider_name, &scopes);
+                }
+                Ok(ToolResult::ok(comparison))
+            }
+            Err(e) => Ok(ToolResult::error(json!({
+                "error": format!("Failed to compare activities: {e}"),
+                "provider": provider_name
+            }))),
+        }
+    }
+}
+
+// ============================================================================
+// AnalyzeTimeInZonesTool - Time spent in each heart rate and power zone
+// ============================================================================
+
+/// Tool for computing time spent in each training zone from activity streams.
+pub struct AnalyzeTimeInZonesTool;
+
+impl AnalyzeTimeInZonesTool {
+    /// Activities to analyze: the one requested, or those within the date range
+    async fn fetch_activities(
+        provider: &dyn FitnessProvider,
+        args: &Value,
+        provider_name: &str,
+    ) -> Result<Vec<Activity>, ToolResult> {
+        if let Some(activity_id) = args.get("activity_id").and_then(Value::as_str) {
+            return fetch_activity(provider, activity_id, provider_name)
+                .await
+                .map(|activity| vec![activity]);
+        }
+
+        let bounds = parse_time_bound(args, "after")
+            .and_then(|after| parse_time_bound(args, "before").map(|before| (after, before)));
+        let (after, before) = match bounds {
+            Ok((Some(after), before)) => (after, before),
+            Ok((None, _)) => {
+                return Err(ToolResult::error(json!({
+                    "error": "invalid_input",
+                    "message": "Provide activity_id, or after (and optionally before) for a date range"
+                })));
+            }
+            Err(message) => {
+                return Err(ToolResult::error(json!({
+                    "error": "invalid_input",
+                    "message": message
+                })));
+            }
+        };
+
+        let query_params = ActivityQueryParams {
+            limit: Some(MAX_ZONE_ACTIVITIES),
+            offset: None,
+            before,
+            after: Some(after),
+        };
+        provider
+            .get_activities_with_params(&query_params)
+            .await
+            .map_err(|e| {
+                ToolResult::error(json!({
+                    "error": format!("Failed to fetch activities: {e}"),
+                    "provider": provider_name
+                }))
+            })
+    }
+}
+
+#[async_trait]
+impl McpTool for AnalyzeTimeInZonesTool {
+    fn name(&self) -> &'static str {
+        "analyze_time_in_zones"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compute minutes spent in each heart rate zone (and power zone when available) for an activity or date range, using the athlete's own zones when the provider has them"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "ID of a single activity to analyze. Takes precedence over after/before."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "after".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Start of the date range (YYYY-MM-DD, RFC 3339, or Unix timestamp). Up to 30 activities are analyzed."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "before".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "End of the date range (YYYY-MM-DD, RFC 3339, or Unix timestamp). Defaults to now."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query. Defaults to configured provider.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "max_hr".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Maximum heart rate for zones when the provider has no athlete zones. Default: 200."
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "ftp".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Functional threshold power for power zones when the provider has no athlete power zones."
+                        .to_owned(),
+                ),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA | ToolCapabilities::ANALYTICS
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let mut activities =
+            match Self::fetch_activities(provider.as_ref(), &args, &provider_name).await {
+                Ok(activities) => activities,
+                Err(result) => return Ok(result),
+            };
+
+        let mut missing_scopes = None;
+        for activity in &mut activities {
+            let scopes = attach_heart_rate_series(provider.as_ref(), activity).await;
+            missing_scopes = missing_scopes.or(scopes);
+        }
+
+        // The athlete's own zones beat zones derived from max heart rate or FTP
+        let athlete_zones = provider.get_athlete_zones().await.unwrap_or_else(|e| {
+            debug!("No athlete zones from {}: {}", provider_name, e);
+            AthleteZones::default()
+        });
+        let heart_rate_zones = if athlete_zones.heart_rate.is_empty() {
+            let max_hr = args
+                .get("max_hr")
+                .and_then(Value::as_u64)
+                .unwrap_or(DEFAULT_MAX_HR);
+            (
+                TimeInZonesCalculator::heart_rate_from_max_hr(
+                    u32::try_from(max_hr).unwrap_or(u32::MAX),
+                ),
+                "config",
+            )
+        } else {
+            (
+                TimeInZonesCalculator::new(athlete_zones.heart_rate),
+                "athlete",
+            )
+        };
+        let power_zones = if athlete_zones.power.is_empty() {
+            args.get("ftp")
+                .and_then(Value::as_f64)
+                .filter(|ftp| *ftp > 0.0)
+                .map(|ftp| (TimeInZonesCalculator::power_from_ftp(ftp), "config"))
+        } else {
+            Some((TimeInZonesCalculator::new(athlete_zones.power), "athlete"))
+        };
+
+        info!(
+            "Time in zones for user {} ({} activities, {} zones)",
+            context.user_id,
+            activities.len(),
+            heart_rate_zones.1
+        );
+
+        let mut result = build_time_in_zones_result(
+            &activities,
+            (&heart_rate_zones.0, heart_rate_zones.1),
+            power_zones
+                .as_ref()
+                .map(|(calculator, source)| (calculator, *source)),
+            &provider_name,
+        );
+        if let Some(scopes) = missing_scopes {
+            result.content["missing_scope"] = missing_scope_json(&provider_name, &scopes);
+        }
+        Ok(result)
+    }
+}
+
 // ============================================================================
 // Module exports
 // ============================================================================
@@ -550,7 +1955,11 @@ impl McpTool for CalculateFitnessScoreTool {
 pub fn create_analytics_tools() -> Vec<Box<dyn McpTool>> {
     vec![
         Box::new(AnalyzeTrainingLoadTool),
+        Box::new(GetTrainingLoadTrendTool),
         Box::new(DetectPatternsTool),
         Box::new(CalculateFitnessScoreTool),
+        Box::new(PredictRaceTimesTool),
+        Box::new(CompareActivitiesTool),
+        Box::new(AnalyzeTimeInZonesTool),
     ]
 }
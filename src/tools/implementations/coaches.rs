@@ -20,19 +20,29 @@
 //! - `HideCoachTool` - Hide a coach from listings
 //! - `ShowCoachTool` - Show a hidden coach
 //! - `ListHiddenCoachesTool` - List hidden coaches
+//! - `GetCoachContextTool` - Get the coaching system context with athlete variables
 
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
 use serde_json::{json, Value};
 
+use crate::coaches::{
+    references_training_load, render_coach_context, CoachContextVariables, DEFAULT_COACH_CONTEXT,
+};
+use crate::config::environment::default_provider;
 use crate::database::coaches::{
     Coach, CoachCategory, CoachListItem, CoachesManager, CreateCoachRequest, ListCoachesFilter,
     UpdateCoachRequest,
 };
+use crate::database_plugins::DatabaseProvider;
 use crate::errors::{AppError, AppResult};
+use crate::intelligence::{TrainingLoad, TrainingLoadCalculator};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
 use crate::models::TenantId;
+use crate::protocols::universal::auth_service::AuthService;
+use crate::providers::core::ActivityQueryParams;
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
 use crate::tools::traits::{McpTool, ToolCapabilities};
@@ -1101,6 +1111,157 @@ impl McpTool for ListHiddenCoachesTool {
     }
 }
 
+// ============================================================================
+// GetCoachContextTool
+// ============================================================================
+
+/// Days of activity history used to estimate training load for the context
+const CONTEXT_TRAINING_LOAD_DAYS: i64 = 90;
+
+/// Maximum activities fetched when estimating training load for the context
+const CONTEXT_ACTIVITY_LIMIT: usize = 500;
+
+/// Name the coach context uses for the athlete
+///
+/// Falls back to the email address, then to a generic name.
+async fn athlete_name(ctx: &ToolExecutionContext, tenant_id: TenantId) -> AppResult<String> {
+    let user = ctx
+        .resources
+        .database
+        .get_user(ctx.user_id, tenant_id)
+        .await?;
+    Ok(user.map_or_else(
+        || "the athlete".to_owned(),
+        |user| user.display_name.unwrap_or(user.email),
+    ))
+}
+
+/// Current training load from the athlete's recent activities
+///
+/// Returns `None` when the provider is not connected or has no recent activities,
+/// so the context can still be rendered.
+async fn current_training_load(
+    ctx: &ToolExecutionContext,
+    provider_name: &str,
+) -> Option<TrainingLoad> {
+    let auth_service = AuthService::new(ctx.resources.clone());
+    let tenant_id = ctx.tenant_id.map(|id| id.to_string());
+    let provider = auth_service
+        .create_authenticated_provider(provider_name, ctx.user_id, tenant_id.as_deref())
+        .await
+        .ok()?;
+
+    let params = ActivityQueryParams {
+        limit: Some(CONTEXT_ACTIVITY_LIMIT),
+        offset: None,
+        before: None,
+        after: Some(Utc::now() - Duration::days(CONTEXT_TRAINING_LOAD_DAYS)),
+    };
+    let activities = provider.get_activities_with_params(&params).await.ok()?;
+    if activities.is_empty() {
+        return None;
+    }
+
+    TrainingLoadCalculator::new()
+        .calculate_training_load(&activities, None, None, None, None, None)
+        .ok()
+}
+
+/// Tool for getting the coaching system context with athlete variables filled in.
+pub struct GetCoachContextTool;
+
+#[async_trait]
+impl McpTool for GetCoachContextTool {
+    fn name(&self) -> &'static str {
+        "get_coach_context"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get the system context to apply before coaching the user. Uses the context configured for the coach (or the active coach), then the tenant, then a default, with the athlete's name and current training load filled in."
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "coach_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Coach whose context to use. Defaults to the active coach.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider used for training load variables. Defaults to the configured provider."
+                        .to_owned(),
+                ),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::COACHES | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, ctx: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let manager = get_coaches_manager(ctx)?;
+        let tenant_id = get_tenant_id(ctx);
+
+        let coach_id = match args.get("coach_id").and_then(Value::as_str) {
+            Some(id) => Some(id.to_owned()),
+            None => manager
+                .get_active_coach(ctx.user_id, tenant_id)
+                .await?
+                .map(|coach| coach.id.to_string()),
+        };
+
+        let stored = manager
+            .get_coach_context(tenant_id, coach_id.as_deref())
+            .await?;
+        let (template, source) = match &stored {
+            Some(context) if context.coach_id.is_some() => {
+                (context.system_context.as_str(), "coach")
+            }
+            Some(context) => (context.system_context.as_str(), "tenant"),
+            None => (DEFAULT_COACH_CONTEXT, "default"),
+        };
+
+        let mut variables = CoachContextVariables::for_athlete(athlete_name(ctx, tenant_id).await?);
+        if references_training_load(template) {
+            let provider_name = args
+                .get("provider")
+                .and_then(Value::as_str)
+                .map_or_else(default_provider, String::from);
+            if let Some(load) = current_training_load(ctx, &provider_name).await {
+                variables = variables.with_training_load(load.ctl, load.atl, load.tsb);
+            }
+        }
+
+        Ok(ToolResult::ok(json!({
+            "system_context": render_coach_context(template, &variables),
+            "source": source,
+            "coach_id": coach_id,
+            "variables": {
+                "athlete_name": variables.athlete_name,
+                "ctl": variables.ctl,
+                "atl": variables.atl,
+                "tsb": variables.tsb,
+            },
+            "updated_at": stored.map(|context| context.updated_at.to_rfc3339()),
+        })))
+    }
+}
+
 // ============================================================================
 // Module exports
 // ============================================================================
@@ -1122,5 +1283,6 @@ pub fn create_coach_tools() -> Vec<Box<dyn McpTool>> {
         Box::new(HideCoachTool),
         Box::new(ShowCoachTool),
         Box::new(ListHiddenCoachesTool),
+        Box::new(GetCoachContextTool),
     ]
 }
@@ -37,6 +37,7 @@ use crate::intelligence::physiological_constants::physiological_defaults::{
     DEFAULT_SPORT_EFFICIENCY, TRAINING_ZONE_COUNT,
 };
 use crate::mcp::schema::{JsonSchema, PropertySchema};
+use crate::services::provider_priority::{apply_provider_priority, PROVIDER_PRIORITY_KEY};
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
 use crate::tools::traits::{McpTool, ToolCapabilities};
@@ -492,6 +493,16 @@ impl McpTool for UpdateUserConfigurationTool {
                 description: Some("Configuration parameters to update".to_owned()),
             },
         );
+        properties.insert(
+            PROVIDER_PRIORITY_KEY.to_owned(),
+            PropertySchema {
+                property_type: "object".to_owned(),
+                description: Some(
+                    "Which provider's metrics win when an activity exists on several providers"
+                        .to_owned(),
+                ),
+            },
+        );
         JsonSchema {
             schema_type: "object".to_owned(),
             properties: Some(properties),
@@ -512,7 +523,7 @@ impl McpTool for UpdateUserConfigurationTool {
             .unwrap_or("custom");
         let parameters = args.get("parameters").cloned().unwrap_or_else(|| json!({}));
 
-        let configuration = json!({
+        let mut configuration = json!({
             "active_profile": profile,
             "profile": {
                 "name": profile,
@@ -524,6 +535,14 @@ impl McpTool for UpdateUserConfigurationTool {
             "last_modified": chrono::Utc::now().to_rfc3339()
         });
 
+        apply_provider_priority(
+            &*ctx.resources.database,
+            &user_id_str,
+            args.get(PROVIDER_PRIORITY_KEY),
+            &mut configuration,
+        )
+        .await?;
+
         let config_json = serde_json::to_string(&configuration)
             .map_err(|e| AppError::internal(format!("Failed to serialize config: {e}")))?;
 
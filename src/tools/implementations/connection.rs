@@ -1,5 +1,5 @@
 // ABOUTME: Connection management tools implementing the McpTool trait.
-// ABOUTME: Provides connect_provider, get_connection_status, disconnect_provider tools.
+// ABOUTME: Provides connect_provider, get_connection_status, list_connected_providers, disconnect_provider tools.
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -9,6 +9,7 @@
 //! This module contains tools for managing provider connections:
 //! - `ConnectProviderTool` - Initiate OAuth flow for a provider
 //! - `GetConnectionStatusTool` - Check provider connection status
+//! - `ListConnectedProvidersTool` - Connection status, scopes, and last sync per provider
 //! - `DisconnectProviderTool` - Disconnect and revoke OAuth tokens
 
 use std::collections::HashMap;
@@ -29,6 +30,9 @@ use crate::database_plugins::DatabaseProvider;
 use crate::errors::{AppError, AppResult, ErrorCode};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
 use crate::models::TenantId;
+use crate::oauth2_client::token_inspection::{
+    list_provider_connections, AthleteProfileCheck, ProviderTokenCheck,
+};
 use crate::oauth2_client::OAuthClientState;
 use crate::protocols::universal::auth_service::AuthService;
 use crate::tenant::{TenantContext, TenantRole};
@@ -383,6 +387,72 @@ impl McpTool for GetConnectionStatusTool {
     }
 }
 
+// ============================================================================
+// ListConnectedProvidersTool - Stored connection health per provider
+// ============================================================================
+
+/// Tool for listing the providers a user has linked and whether their tokens are usable.
+///
+/// Reads stored tokens only, unless `probe` asks for a live check against each provider.
+pub struct ListConnectedProvidersTool;
+
+#[async_trait]
+impl McpTool for ListConnectedProvidersTool {
+    fn name(&self) -> &'static str {
+        "list_connected_providers"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the OAuth fitness providers with the user's connection status (connected, expired, revoked, not_connected), granted scopes, token expiry, and last sync time. Set probe to validate tokens live with each provider"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "probe".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(
+                    "Validate each stored token with a live provider call, which detects revoked tokens (default: false)".to_owned(),
+                ),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let probe = args.get("probe").and_then(Value::as_bool).unwrap_or(false);
+        let tenant_id = TenantId::from(context.require_tenant()?);
+
+        let mut providers = context.provider_registry().oauth_providers();
+        providers.sort_unstable();
+
+        let check = probe.then_some(&AthleteProfileCheck as &dyn ProviderTokenCheck);
+        let connections = list_provider_connections(
+            context.database(),
+            check,
+            context.user_id,
+            tenant_id,
+            &providers,
+        )
+        .await?;
+
+        Ok(ToolResult::ok(json!({
+            "providers": connections,
+            "probed": probe
+        })))
+    }
+}
+
 // ============================================================================
 // DisconnectProviderTool - Disconnect OAuth provider
 // ============================================================================
@@ -472,6 +542,7 @@ pub fn create_connection_tools() -> Vec<Box<dyn McpTool>> {
     vec![
         Box::new(ConnectProviderTool),
         Box::new(GetConnectionStatusTool),
+        Box::new(ListConnectedProvidersTool),
         Box::new(DisconnectProviderTool),
     ]
 }
@@ -1,5 +1,5 @@
 // ABOUTME: Data access tools implementing the McpTool trait as wrappers.
-// ABOUTME: Delegates to existing handlers for get_activities, get_athlete, get_stats.
+// ABOUTME: Delegates to existing handlers for get_activities, get_athlete, get_stats, plus list_gear, get_segment_efforts, get_activity_splits, get_activity_weather, search_activities, get_activities_delta, merge_activities, unmerge_activities, and export_user_data.
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -10,25 +10,97 @@
 //! - `GetActivitiesTool` - Retrieve user activities with filtering and pagination
 //! - `GetAthleteTool` - Get athlete profile information
 //! - `GetStatsTool` - Get aggregated activity statistics
+//! - `ListGearTool` - List shoes and bikes with mileage and replacement warnings
+//! - `GetSegmentEffortsTool` - List an activity's segment efforts and the segment PRs they set
+//! - `GetActivitySplitsTool` - Per-kilometer or per-mile splits from provider laps or the activity stream
+//! - `GetActivityWeatherTool` - Historical weather at an activity's start location and time
+//! - `SearchActivitiesTool` - Find activities matching sport, distance, duration, date, name, and elevation filters
+//! - `GetActivitiesDeltaTool` - Activities created or updated since a timestamp, plus deleted activity IDs
+//! - `MergeActivitiesTool` - Merge a duplicate activity, or join a split recording, into the primary record of the same session
+//! - `UnmergeActivitiesTool` - Undo a merge so both activities are listed again
+//! - `ExportUserDataTool` - Export the user's stored data and recent activities for portability
 //!
 //! These tools wrap the universal protocol handlers and expose them via the
-//! `McpTool` interface.
+//! `McpTool` interface. `ListGearTool`, `GetSegmentEffortsTool`, `GetActivitySplitsTool`, and `SearchActivitiesTool` call the provider directly;
+//! `GetActivityWeatherTool` adds the weather service, `GetActivitiesDeltaTool` delegates to the delta sync service,
+//! `MergeActivitiesTool` and `UnmergeActivitiesTool` delegate to the duplicate merge service,
+//! and `ExportUserDataTool` delegates to the data export service.
 
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde_json::{json, Value};
+use tracing::{debug, warn};
 
-use crate::errors::AppResult;
+use crate::cache::{CacheKey, CacheResource};
+use crate::config::environment::default_provider;
+use crate::constants::oauth_providers;
+use crate::database_plugins::DatabaseProvider;
+use crate::errors::{AppError, AppResult};
+use crate::intelligence::gear_wear::DEFAULT_SHOE_REPLACEMENT_KM;
+use crate::intelligence::weather::WeatherService;
+use crate::intelligence::{GearWearMonitor, SegmentPrDetector, SplitCalculator, WeatherConditions};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
+use crate::models::{
+    Activity, ActivityFilter, ActivityMergeConfig, ActivityMergeKind, ActivityProjection,
+    ActivitySortKey, ActivitySplit, Segment, SegmentEffort, SplitUnit, TenantId,
+};
+use crate::protocols::universal::auth_service::AuthService;
 use crate::protocols::universal::executor::UniversalExecutor;
 use crate::protocols::universal::handlers::fitness_api::{
     handle_get_activities, handle_get_athlete, handle_get_stats,
 };
 use crate::protocols::universal::{UniversalRequest, UniversalResponse};
+use crate::providers::core::{ActivityQueryParams, FitnessProvider};
+use crate::providers::errors::ProviderError;
+use crate::services::activity_delta::{sync_activity_delta, DELTA_LOOKBACK_DAYS};
+use crate::services::data_export::{
+    export_user_data, ExportOptions, DEFAULT_EXPORT_ACTIVITY_DAYS, MAX_EXPORT_ACTIVITIES,
+};
+use crate::services::duplicate_merge::{
+    apply_duplicate_merges, merge_duplicate_activities, merge_split_recordings,
+    unmerge_duplicate_activities,
+};
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
-use crate::tools::traits::{McpTool, ToolCapabilities};
+use crate::tools::traits::{McpTool, ScopeRequirement, ToolCapabilities};
+
+/// Provider scopes needed to read activities and their segment efforts
+const ACTIVITY_READ_SCOPES: &[ScopeRequirement] = &[
+    ScopeRequirement::new(
+        oauth_providers::STRAVA,
+        &[oauth_providers::STRAVA_ACTIVITY_READ_SCOPE],
+    ),
+    ScopeRequirement::new(
+        oauth_providers::FITBIT,
+        &[oauth_providers::FITBIT_ACTIVITY_SCOPE],
+    ),
+];
+
+/// Provider scopes needed to read the athlete profile
+const ATHLETE_READ_SCOPES: &[ScopeRequirement] = &[
+    ScopeRequirement::new(
+        oauth_providers::STRAVA,
+        &[oauth_providers::STRAVA_READ_SCOPE],
+    ),
+    ScopeRequirement::new(
+        oauth_providers::FITBIT,
+        &[oauth_providers::FITBIT_PROFILE_SCOPE],
+    ),
+];
+
+/// Provider scopes needed to read the athlete's gear, which Strava only includes in the full profile
+const GEAR_READ_SCOPES: &[ScopeRequirement] = &[
+    ScopeRequirement::new(
+        oauth_providers::STRAVA,
+        &[oauth_providers::STRAVA_PROFILE_READ_ALL_SCOPE],
+    ),
+    ScopeRequirement::new(
+        oauth_providers::FITBIT,
+        &[oauth_providers::FITBIT_PROFILE_SCOPE],
+    ),
+];
 
 // ============================================================================
 // Helper functions for converting between request/response types
@@ -110,7 +182,7 @@ impl McpTool for GetActivitiesTool {
             PropertySchema {
                 property_type: "string".to_owned(),
                 description: Some(
-                    "Fitness provider to query (e.g., 'strava', 'fitbit'). Defaults to configured default provider.".to_owned(),
+                    "Fitness provider to query (e.g., 'strava', 'fitbit'), or 'all' to merge activities from every connected provider with duplicates removed. Defaults to configured default provider.".to_owned(),
                 ),
             },
         );
@@ -172,6 +244,16 @@ impl McpTool for GetActivitiesTool {
             },
         );
 
+        properties.insert(
+            "fields".to_owned(),
+            PropertySchema {
+                property_type: "array".to_owned(),
+                description: Some(
+                    "Activity fields to return (e.g., ['id', 'distance']). Overrides 'mode'; unknown names are rejected with the list of valid fields.".to_owned(),
+                ),
+            },
+        );
+
         properties.insert(
             "format".to_owned(),
             PropertySchema {
@@ -183,6 +265,17 @@ impl McpTool for GetActivitiesTool {
             },
         );
 
+        properties.insert(
+            "no_cache".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(
+                    "Bypass cached activity lists and fetch fresh data from the provider."
+                        .to_owned(),
+                ),
+            },
+        );
+
         JsonSchema {
             schema_type: "object".to_owned(),
             properties: Some(properties),
@@ -194,7 +287,20 @@ impl McpTool for GetActivitiesTool {
         ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
     }
 
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
     async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        // Reject unknown field names before touching the provider
+        if let Err(e) = ActivityProjection::from_param(args.get("fields")) {
+            return Ok(ToolResult::error(json!({
+                "error": e.message,
+                "error_type": "invalid_field",
+                "valid_fields": ActivityProjection::valid_fields(),
+            })));
+        }
+
         let executor = UniversalExecutor::new(context.resources.clone());
         let request = build_universal_request("get_activities", &args, context);
 
@@ -260,6 +366,10 @@ impl McpTool for GetAthleteTool {
         ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
     }
 
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ATHLETE_READ_SCOPES
+    }
+
     async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
         let executor = UniversalExecutor::new(context.resources.clone());
         let request = build_universal_request("get_athlete", &args, context);
@@ -340,6 +450,1403 @@ impl McpTool for GetStatsTool {
     }
 }
 
+// ============================================================================
+// ListGearTool - List shoes and bikes with mileage warnings
+// ============================================================================
+
+/// Create an authenticated provider, returning a tool error on failure
+async fn create_provider(
+    context: &ToolExecutionContext,
+    provider_name: &str,
+) -> Result<Box<dyn FitnessProvider>, ToolResult> {
+    let auth_service = AuthService::new(context.resources.clone());
+    let tenant_id = context.tenant_id.map(|id| id.to_string());
+
+    auth_service
+        .create_authenticated_provider(provider_name, context.user_id, tenant_id.as_deref())
+        .await
+        .map_err(|response| {
+            ToolResult::error(json!({
+                "error": response.error.unwrap_or_else(|| "Authentication failed".to_owned()),
+                "provider": provider_name
+            }))
+        })
+}
+
+/// Tool for listing the user's gear and flagging worn-out shoes.
+pub struct ListGearTool;
+
+#[async_trait]
+impl McpTool for ListGearTool {
+    fn name(&self) -> &'static str {
+        "list_gear"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the user's shoes and bikes with total distance logged, and warn about active shoes past a replacement mileage threshold"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+                ),
+            },
+        );
+
+        properties.insert(
+            "shoe_replacement_km".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(format!(
+                    "Distance in kilometers after which shoes should be replaced. Default: {DEFAULT_SHOE_REPLACEMENT_KM}"
+                )),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        GEAR_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let threshold_km = args
+            .get("shoe_replacement_km")
+            .and_then(Value::as_f64)
+            .unwrap_or(DEFAULT_SHOE_REPLACEMENT_KM);
+        if threshold_km <= 0.0 {
+            return Ok(ToolResult::error(json!({
+                "error": "invalid_input",
+                "message": "shoe_replacement_km must be greater than 0",
+                "shoe_replacement_km": threshold_km
+            })));
+        }
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let gear = match provider.list_gear().await {
+            Ok(gear) => gear,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to list gear: {e}"),
+                    "provider": provider_name
+                })));
+            }
+        };
+
+        let warnings = GearWearMonitor::new(threshold_km).check(&gear);
+
+        Ok(ToolResult::ok(json!({
+            "provider": provider_name,
+            "gear": gear,
+            "shoe_replacement_km": threshold_km,
+            "warnings": warnings
+        })))
+    }
+}
+
+// ============================================================================
+// GetSegmentEffortsTool - Segment efforts, course records, and segment PRs
+// ============================================================================
+
+/// Maximum distinct segments whose details are fetched for one activity
+pub const MAX_SEGMENT_DETAILS: usize = 20;
+
+/// Tool for listing an activity's segment efforts and the PRs they set.
+pub struct GetSegmentEffortsTool;
+
+#[async_trait]
+impl McpTool for GetSegmentEffortsTool {
+    fn name(&self) -> &'static str {
+        "get_segment_efforts"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the segment efforts recorded during an activity with PR and KOM ranks, flag new segment personal records, and optionally include each segment's course records and the athlete's best time"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("ID of the activity whose segment efforts to list.".to_owned()),
+            },
+        );
+
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+                ),
+            },
+        );
+
+        properties.insert(
+            "include_segments".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(format!(
+                    "Also fetch each segment's details (KOM/QOM times, effort counts, the athlete's best). One extra request per segment, at most {MAX_SEGMENT_DETAILS}. Default: false"
+                )),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: Some(vec!["activity_id".to_owned()]),
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let activity_id = args
+            .get("activity_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input("activity_id is required"))?;
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+        let include_segments = args
+            .get("include_segments")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let efforts = match provider.list_segment_efforts(activity_id).await {
+            Ok(efforts) => efforts,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to list segment efforts: {e}"),
+                    "provider": provider_name,
+                    "activity_id": activity_id
+                })));
+            }
+        };
+
+        let personal_records = SegmentPrDetector::new().detect(activity_id, &efforts);
+
+        let mut result = json!({
+            "provider": provider_name,
+            "activity_id": activity_id,
+            "segment_efforts": efforts,
+            "personal_records": personal_records
+        });
+        if include_segments {
+            result["segments"] = json!(fetch_segments(provider.as_ref(), &efforts).await);
+        }
+
+        Ok(ToolResult::ok(result))
+    }
+}
+
+/// Fetch details for the distinct segments of `efforts`, skipping failures
+async fn fetch_segments(provider: &dyn FitnessProvider, efforts: &[SegmentEffort]) -> Vec<Segment> {
+    let mut segment_ids: Vec<&str> = Vec::new();
+    for segment_id in efforts.iter().filter_map(|e| e.segment_id.as_deref()) {
+        if !segment_ids.contains(&segment_id) {
+            segment_ids.push(segment_id);
+        }
+    }
+
+    let mut segments = Vec::with_capacity(segment_ids.len().min(MAX_SEGMENT_DETAILS));
+    for segment_id in segment_ids.into_iter().take(MAX_SEGMENT_DETAILS) {
+        match provider.get_segment(segment_id).await {
+            Ok(segment) => segments.push(segment),
+            Err(e) => warn!(segment_id, error = %e, "Failed to fetch segment details"),
+        }
+    }
+    segments
+}
+
+// ============================================================================
+// GetActivitySplitsTool - Per-kilometer or per-mile splits
+// ============================================================================
+
+/// Tool for breaking an activity down into per-kilometer or per-mile splits.
+pub struct GetActivitySplitsTool;
+
+#[async_trait]
+impl McpTool for GetActivitySplitsTool {
+    fn name(&self) -> &'static str {
+        "get_activity_splits"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get an activity's per-kilometer or per-mile splits with pace, heart rate, and elevation. Uses the provider's splits or laps when available, otherwise computes splits from the activity's GPS or speed stream"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("ID of the activity to split.".to_owned()),
+            },
+        );
+
+        properties.insert(
+            "unit".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("Split distance: 'km' or 'mile'. Default: 'km'".to_owned()),
+            },
+        );
+
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+                ),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: Some(vec!["activity_id".to_owned()]),
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let activity_id = args
+            .get("activity_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input("activity_id is required"))?;
+        let unit = match args.get("unit").and_then(Value::as_str) {
+            Some(value) => SplitUnit::parse(value).ok_or_else(|| {
+                AppError::invalid_input(format!("Invalid unit '{value}', expected 'km' or 'mile'"))
+            })?,
+            None => SplitUnit::default(),
+        };
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        match provider.get_activity_splits(activity_id, unit).await {
+            Ok(splits) if !splits.is_empty() => {
+                return Ok(splits_result(
+                    &provider_name,
+                    activity_id,
+                    unit,
+                    "provider",
+                    &splits,
+                ));
+            }
+            Ok(_) | Err(ProviderError::UnsupportedFeature { .. }) => {}
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to get activity splits: {e}"),
+                    "provider": provider_name,
+                    "activity_id": activity_id
+                })));
+            }
+        }
+
+        // No provider splits; compute them from the activity stream
+        let activity = match provider.get_activity(activity_id).await {
+            Ok(activity) => activity,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to get activity: {e}"),
+                    "provider": provider_name,
+                    "activity_id": activity_id
+                })));
+            }
+        };
+        let splits = activity
+            .time_series_data()
+            .map(|series| SplitCalculator::new(unit).compute(series))
+            .unwrap_or_default();
+        if splits.is_empty() {
+            return Ok(ToolResult::error(json!({
+                "error": "Activity has no laps, splits, or GPS/speed stream to compute splits from",
+                "provider": provider_name,
+                "activity_id": activity_id
+            })));
+        }
+
+        Ok(splits_result(
+            &provider_name,
+            activity_id,
+            unit,
+            "stream",
+            &splits,
+        ))
+    }
+}
+
+/// Tool response for a list of splits
+fn splits_result(
+    provider_name: &str,
+    activity_id: &str,
+    unit: SplitUnit,
+    source: &str,
+    splits: &[ActivitySplit],
+) -> ToolResult {
+    ToolResult::ok(json!({
+        "provider": provider_name,
+        "activity_id": activity_id,
+        "unit": unit.as_str(),
+        "source": source,
+        "split_count": splits.len(),
+        "splits": splits
+    }))
+}
+
+// ============================================================================
+// GetActivityWeatherTool - Historical weather at an activity's start
+// ============================================================================
+
+/// Where an activity started: its start coordinates, else the first GPS fix of its stream
+#[must_use]
+pub fn activity_start_location(activity: &Activity) -> Option<(f64, f64)> {
+    activity
+        .start_latitude()
+        .zip(activity.start_longitude())
+        .or_else(|| {
+            activity
+                .time_series_data()
+                .and_then(|series| series.gps_coordinates.as_ref())
+                .and_then(|coordinates| coordinates.first().copied())
+        })
+}
+
+/// Tool for fetching the historical weather at an activity's start location and time.
+pub struct GetActivityWeatherTool;
+
+impl GetActivityWeatherTool {
+    /// Cached conditions for the activity, or fetch them from the weather API and cache them
+    async fn weather_for(
+        context: &ToolExecutionContext,
+        key: &CacheKey,
+        activity: &Activity,
+        (latitude, longitude): (f64, f64),
+    ) -> Result<(WeatherConditions, bool), String> {
+        let cache = &context.resources.cache;
+        match cache.get::<WeatherConditions>(key).await {
+            Ok(Some(weather)) => return Ok((weather, true)),
+            Ok(None) => {}
+            Err(e) => warn!("Weather cache read failed for {}: {}", activity.id(), e),
+        }
+
+        let weather = WeatherService::with_default_config()
+            .get_weather_at_time(latitude, longitude, activity.start_date())
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Err(e) = cache
+            .set(key, &weather, key.resource.recommended_ttl())
+            .await
+        {
+            warn!("Weather cache write failed for {}: {}", activity.id(), e);
+        }
+        Ok((weather, false))
+    }
+}
+
+#[async_trait]
+impl McpTool for GetActivityWeatherTool {
+    fn name(&self) -> &'static str {
+        "get_activity_weather"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get the historical weather (temperature, humidity, wind, and conditions) at an activity's start location and time"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "activity_id".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("ID of the activity to get weather for.".to_owned()),
+            },
+        );
+
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+                ),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: Some(vec!["activity_id".to_owned()]),
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let activity_id = args
+            .get("activity_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input("activity_id is required"))?;
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+        let activity = match provider.get_activity(activity_id).await {
+            Ok(activity) => activity,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to get activity: {e}"),
+                    "provider": provider_name,
+                    "activity_id": activity_id
+                })));
+            }
+        };
+
+        let Some(location) = activity_start_location(&activity) else {
+            return Ok(ToolResult::error(json!({
+                "error": "no_location_data",
+                "message": "Activity has no start coordinates or GPS stream to look up weather for",
+                "provider": provider_name,
+                "activity_id": activity_id
+            })));
+        };
+
+        let key = CacheKey::new(
+            context.tenant_id.map_or_else(TenantId::nil, TenantId::from),
+            context.user_id,
+            provider_name.clone(),
+            CacheResource::ActivityWeather {
+                activity_id: activity_id.to_owned(),
+            },
+        );
+        let (weather, cache_hit) = match Self::weather_for(context, &key, &activity, location).await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to get weather: {e}"),
+                    "provider": provider_name,
+                    "activity_id": activity_id
+                })));
+            }
+        };
+        debug!(
+            "Weather for activity {} (cache hit: {}): {}",
+            activity_id, cache_hit, weather.conditions
+        );
+
+        Ok(ToolResult::ok(json!({
+            "provider": provider_name,
+            "activity_id": activity_id,
+            "start_date": activity.start_date().to_rfc3339(),
+            "location": {
+                "latitude": location.0,
+                "longitude": location.1
+            },
+            "weather": weather,
+            "cache_hit": cache_hit
+        })))
+    }
+}
+
+// ============================================================================
+// SearchActivitiesTool - Find activities matching filters
+// ============================================================================
+
+/// Activities requested per provider call while scanning for matches
+const SEARCH_PAGE_SIZE: usize = 100;
+
+/// Maximum activities examined by a single search
+pub const MAX_SEARCH_SCAN: usize = 500;
+
+/// Matches returned when `limit` is not given
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+/// Upper bound on the `limit` argument
+const MAX_SEARCH_LIMIT: usize = 100;
+
+/// Activities matching a filter, gathered from a bounded provider scan
+#[derive(Debug, Clone)]
+pub struct ActivityScan {
+    /// Matching activities in provider order
+    pub matches: Vec<Activity>,
+    /// Activities fetched from the provider
+    pub scanned: usize,
+    /// Whether the scan stopped at the limit with more history possibly unread
+    pub truncated: bool,
+}
+
+/// Page through a provider's activities and keep those matching `filter`
+///
+/// The date range is passed to the provider so it can be applied upstream; the
+/// remaining criteria are evaluated locally over at most `max_scan` activities.
+///
+/// # Errors
+///
+/// Returns an error if a provider request fails
+pub async fn scan_activities(
+    provider: &dyn FitnessProvider,
+    filter: &ActivityFilter,
+    max_scan: usize,
+) -> AppResult<ActivityScan> {
+    let mut matches = Vec::new();
+    let mut scanned = 0;
+
+    while scanned < max_scan {
+        let requested = SEARCH_PAGE_SIZE.min(max_scan - scanned);
+        let params = ActivityQueryParams {
+            limit: Some(requested),
+            offset: Some(scanned),
+            before: filter.before,
+            after: filter.after,
+        };
+        let page = provider.get_activities_with_params(&params).await?;
+        let fetched = page.len();
+        scanned += fetched;
+        matches.extend(filter.apply(page));
+
+        if fetched < requested {
+            return Ok(ActivityScan {
+                matches,
+                scanned,
+                truncated: false,
+            });
+        }
+    }
+
+    Ok(ActivityScan {
+        matches,
+        scanned,
+        truncated: true,
+    })
+}
+
+/// Parse a date bound given as epoch seconds, an RFC 3339 timestamp, or a `YYYY-MM-DD` date
+pub(crate) fn parse_time_bound(args: &Value, key: &str) -> Result<Option<DateTime<Utc>>, String> {
+    let Some(value) = args.get(key).filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+
+    if let Some(seconds) = value.as_i64() {
+        return DateTime::from_timestamp(seconds, 0)
+            .map(Some)
+            .ok_or_else(|| format!("{key} is not a valid Unix timestamp: {seconds}"));
+    }
+
+    let text = value
+        .as_str()
+        .ok_or_else(|| format!("{key} must be a date string or Unix timestamp"))?;
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(text) {
+        return Ok(Some(timestamp.with_timezone(&Utc)));
+    }
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|midnight| Some(midnight.and_utc()))
+        .ok_or_else(|| format!("{key} must be YYYY-MM-DD or RFC 3339, got '{text}'"))
+}
+
+/// Build an `ActivityFilter` from tool arguments expressed in km and minutes
+fn parse_activity_filter(args: &Value) -> Result<ActivityFilter, String> {
+    let km_to_meters = |key: &str| args.get(key).and_then(Value::as_f64).map(|km| km * 1000.0);
+    let minutes_to_seconds = |key: &str| {
+        args.get(key)
+            .and_then(Value::as_u64)
+            .map(|minutes| minutes.saturating_mul(60))
+    };
+
+    let filter = ActivityFilter {
+        sport_type: args
+            .get("sport_type")
+            .and_then(Value::as_str)
+            .map(str::to_owned),
+        min_distance_meters: km_to_meters("min_distance_km"),
+        max_distance_meters: km_to_meters("max_distance_km"),
+        min_duration_seconds: minutes_to_seconds("min_duration_minutes"),
+        max_duration_seconds: minutes_to_seconds("max_duration_minutes"),
+        after: parse_time_bound(args, "after")?,
+        before: parse_time_bound(args, "before")?,
+        name_contains: args
+            .get("name_contains")
+            .and_then(Value::as_str)
+            .filter(|needle| !needle.is_empty())
+            .map(str::to_owned),
+        min_elevation_gain: args.get("min_elevation_gain").and_then(Value::as_f64),
+    };
+    filter.validate().map_err(|e| e.to_string())?;
+    Ok(filter)
+}
+
+/// Tool for searching activities by sport, distance, duration, date, name, and elevation.
+pub struct SearchActivitiesTool;
+
+#[async_trait]
+impl McpTool for SearchActivitiesTool {
+    fn name(&self) -> &'static str {
+        "search_activities"
+    }
+
+    fn description(&self) -> &'static str {
+        "Search the user's activities by sport type, distance, duration, date range, name text, and elevation gain, sorted by a chosen key (e.g. long runs with lots of climbing last month)"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        let mut add = |name: &str, property_type: &str, description: String| {
+            properties.insert(
+                name.to_owned(),
+                PropertySchema {
+                    property_type: property_type.to_owned(),
+                    description: Some(description),
+                },
+            );
+        };
+
+        add(
+            "provider",
+            "string",
+            "Fitness provider to search (e.g., 'strava'). Defaults to configured default provider."
+                .to_owned(),
+        );
+        add(
+            "sport_type",
+            "string",
+            "Sport type to match, case-insensitive (e.g., 'run', 'ride', 'trail_running')."
+                .to_owned(),
+        );
+        add(
+            "min_distance_km",
+            "number",
+            "Minimum distance in kilometers.".to_owned(),
+        );
+        add(
+            "max_distance_km",
+            "number",
+            "Maximum distance in kilometers.".to_owned(),
+        );
+        add(
+            "min_duration_minutes",
+            "integer",
+            "Minimum moving time in minutes.".to_owned(),
+        );
+        add(
+            "max_duration_minutes",
+            "integer",
+            "Maximum moving time in minutes.".to_owned(),
+        );
+        add(
+            "after",
+            "string",
+            "Only activities starting on or after this date (YYYY-MM-DD, RFC 3339, or Unix seconds).".to_owned(),
+        );
+        add(
+            "before",
+            "string",
+            "Only activities starting before this date (YYYY-MM-DD, RFC 3339, or Unix seconds)."
+                .to_owned(),
+        );
+        add(
+            "name_contains",
+            "string",
+            "Text the activity name must contain, case-insensitive.".to_owned(),
+        );
+        add(
+            "min_elevation_gain",
+            "number",
+            "Minimum elevation gain in meters.".to_owned(),
+        );
+        add(
+            "sort_by",
+            "string",
+            "Sort key: 'date' (default), 'distance', 'duration', 'elevation_gain', or 'name'."
+                .to_owned(),
+        );
+        add(
+            "order",
+            "string",
+            "Sort order: 'desc' (default) or 'asc'.".to_owned(),
+        );
+        add(
+            "limit",
+            "integer",
+            format!("Maximum matches to return. Default: {DEFAULT_SEARCH_LIMIT}, max: {MAX_SEARCH_LIMIT}"),
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+
+        let invalid = |message: String| {
+            ToolResult::error(json!({
+                "error": "invalid_input",
+                "message": message
+            }))
+        };
+
+        let filter = match parse_activity_filter(&args) {
+            Ok(filter) => filter,
+            Err(message) => return Ok(invalid(message)),
+        };
+        let sort_key = match ActivitySortKey::parse(
+            args.get("sort_by")
+                .and_then(Value::as_str)
+                .unwrap_or("date"),
+        ) {
+            Ok(key) => key,
+            Err(e) => return Ok(invalid(e.to_string())),
+        };
+        let descending = match args.get("order").and_then(Value::as_str).unwrap_or("desc") {
+            "desc" => true,
+            "asc" => false,
+            other => {
+                return Ok(invalid(format!(
+                    "order must be 'asc' or 'desc', got '{other}'"
+                )))
+            }
+        };
+        let limit = args
+            .get("limit")
+            .and_then(Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(DEFAULT_SEARCH_LIMIT)
+            .clamp(1, MAX_SEARCH_LIMIT);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        let scan = match scan_activities(provider.as_ref(), &filter, MAX_SEARCH_SCAN).await {
+            Ok(scan) => scan,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to search activities: {e}"),
+                    "provider": provider_name
+                })));
+            }
+        };
+
+        // Hide duplicates the user merged by hand and fill their primaries' gaps
+        let tenant_id = context.tenant_id.map_or_else(TenantId::nil, TenantId::from);
+        let mut matches = match context
+            .resources
+            .database
+            .list_duplicate_merges(context.user_id, tenant_id)
+            .await
+        {
+            Ok(merges) => apply_duplicate_merges(scan.matches, &merges),
+            Err(e) => {
+                warn!("Failed to load duplicate merges; searching unmerged activities: {e}");
+                scan.matches
+            }
+        };
+        let total_matches = matches.len();
+        sort_key.sort(&mut matches, descending);
+        matches.truncate(limit);
+
+        let activities: Vec<Value> = matches
+            .iter()
+            .map(|activity| {
+                json!({
+                    "id": activity.id(),
+                    "name": activity.name(),
+                    "sport_type": activity.sport_type(),
+                    "start_date": activity.start_date().to_rfc3339(),
+                    "distance_meters": activity.distance_meters(),
+                    "duration_seconds": activity.duration_seconds(),
+                    "elevation_gain": activity.elevation_gain()
+                })
+            })
+            .collect();
+
+        Ok(ToolResult::ok(json!({
+            "provider": provider_name,
+            "filters": filter,
+            "sort_by": sort_key,
+            "order": if descending { "desc" } else { "asc" },
+            "total_matches": total_matches,
+            "returned": activities.len(),
+            "activities": activities,
+            "scanned": scan.scanned,
+            "scan_truncated": scan.truncated
+        })))
+    }
+}
+
+// ============================================================================
+// GetActivitiesDeltaTool - Changes since a timestamp for caching clients
+// ============================================================================
+
+/// Tool for fetching only the activities that changed since a previous sync.
+pub struct GetActivitiesDeltaTool;
+
+#[async_trait]
+impl McpTool for GetActivitiesDeltaTool {
+    fn name(&self) -> &'static str {
+        "get_activities_delta"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get only the activities created or updated since a timestamp, plus the IDs of activities deleted since then, so clients that cache activities do not re-fetch everything. Pass the returned synced_at as since on the next call"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Fitness provider to query (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+                ),
+            },
+        );
+
+        properties.insert(
+            "since".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(format!(
+                    "Return changes at or after this time (RFC 3339, YYYY-MM-DD, or Unix timestamp). Defaults to the provider's last sync. Edits and deletions are detected for activities that started up to {DELTA_LOOKBACK_DAYS} days before it."
+                )),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let since = match parse_time_bound(&args, "since") {
+            Ok(since) => since,
+            Err(message) => {
+                return Ok(ToolResult::error(json!({
+                    "error": "invalid_input",
+                    "message": message
+                })));
+            }
+        };
+        let provider_name = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+        let tenant_id = TenantId::from(context.require_tenant()?);
+
+        let provider = match create_provider(context, &provider_name).await {
+            Ok(p) => p,
+            Err(result) => return Ok(result),
+        };
+
+        match sync_activity_delta(
+            context.resources.database.as_ref(),
+            provider.as_ref(),
+            context.user_id,
+            tenant_id,
+            since,
+            Utc::now(),
+        )
+        .await
+        {
+            Ok(delta) => Ok(ToolResult::ok(json!({
+                "provider": delta.provider,
+                "since": delta.since.to_rfc3339(),
+                "synced_at": delta.synced_at.to_rfc3339(),
+                "count": delta.activities.len(),
+                "activities": delta.activities,
+                "deleted_ids": delta.deleted_ids,
+                "deletions_checked": delta.deletions_checked,
+            }))),
+            Err(e) => Ok(ToolResult::error(json!({
+                "error": format!("Failed to sync activity changes: {e}"),
+                "provider": provider_name
+            }))),
+        }
+    }
+}
+
+// ============================================================================
+// MergeActivitiesTool / UnmergeActivitiesTool - Manual duplicate merges
+// ============================================================================
+
+/// Fetch one activity for a merge, returning a tool error on failure
+async fn fetch_activity_for_merge(
+    context: &ToolExecutionContext,
+    provider_name: &str,
+    activity_id: &str,
+) -> Result<Activity, ToolResult> {
+    let provider = create_provider(context, provider_name).await?;
+    provider.get_activity(activity_id).await.map_err(|e| {
+        ToolResult::error(json!({
+            "error": format!("Failed to get activity: {e}"),
+            "provider": provider_name,
+            "activity_id": activity_id
+        }))
+    })
+}
+
+/// Drop the user's cached activity listings so they reflect a merge or unmerge
+async fn invalidate_activity_lists(context: &ToolExecutionContext, tenant_id: TenantId) {
+    let pattern = format!(
+        "tenant:{tenant_id}:user:{}:provider:*:activity_list:*",
+        context.user_id
+    );
+    if let Err(e) = context.resources.cache.invalidate_pattern(&pattern).await {
+        warn!("Failed to invalidate activity lists after a merge change: {e}");
+    }
+}
+
+/// Input schema shared by the merge and unmerge tools
+fn merge_input_schema() -> JsonSchema {
+    let mut properties = HashMap::new();
+
+    properties.insert(
+        "primary_id".to_owned(),
+        PropertySchema {
+            property_type: "string".to_owned(),
+            description: Some(
+                "ID of the activity whose fields win and that stays listed.".to_owned(),
+            ),
+        },
+    );
+    properties.insert(
+        "secondary_id".to_owned(),
+        PropertySchema {
+            property_type: "string".to_owned(),
+            description: Some(
+                "ID of the duplicate that fills the primary's gaps and is hidden from listings."
+                    .to_owned(),
+            ),
+        },
+    );
+    properties.insert(
+        "primary_provider".to_owned(),
+        PropertySchema {
+            property_type: "string".to_owned(),
+            description: Some(
+                "Provider of the primary activity (e.g., 'strava'). Defaults to configured default provider.".to_owned(),
+            ),
+        },
+    );
+    properties.insert(
+        "secondary_provider".to_owned(),
+        PropertySchema {
+            property_type: "string".to_owned(),
+            description: Some(
+                "Provider of the secondary activity (e.g., 'garmin'). Defaults to configured default provider.".to_owned(),
+            ),
+        },
+    );
+
+    JsonSchema {
+        schema_type: "object".to_owned(),
+        properties: Some(properties),
+        required: Some(vec!["primary_id".to_owned(), "secondary_id".to_owned()]),
+    }
+}
+
+/// Primary and secondary `(provider, id)` pairs named by merge tool arguments
+fn merge_targets(args: &Value) -> AppResult<((String, String), (String, String))> {
+    let target = |provider_key: &str, id_key: &str| -> AppResult<(String, String)> {
+        let id = args
+            .get(id_key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::invalid_input(format!("{id_key} is required")))?;
+        let provider = args
+            .get(provider_key)
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+        Ok((provider, id.to_owned()))
+    };
+    Ok((
+        target("primary_provider", "primary_id")?,
+        target("secondary_provider", "secondary_id")?,
+    ))
+}
+
+/// Split merge rules from the optional `max_gap_seconds` and `require_same_sport` arguments
+fn split_merge_config(args: &Value) -> ActivityMergeConfig {
+    let defaults = ActivityMergeConfig::default();
+    ActivityMergeConfig {
+        max_gap_seconds: args
+            .get("max_gap_seconds")
+            .and_then(Value::as_u64)
+            .unwrap_or(defaults.max_gap_seconds),
+        require_same_sport: args
+            .get("require_same_sport")
+            .and_then(Value::as_bool)
+            .unwrap_or(defaults.require_same_sport),
+    }
+}
+
+/// Tool for merging a duplicate activity, or joining a split recording, into the primary record of the same session.
+pub struct MergeActivitiesTool;
+
+#[async_trait]
+impl McpTool for MergeActivitiesTool {
+    fn name(&self) -> &'static str {
+        "merge_activities"
+    }
+
+    fn description(&self) -> &'static str {
+        "Merge two records of the same session into one: the primary keeps its values and fills its missing fields (e.g. heart rate or power) from the secondary, which is hidden from future listings. With kind 'split', joins two recordings of one workout instead: totals are summed, averages weighted, and streams concatenated under the earlier recording. Undo with unmerge_activities"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut schema = merge_input_schema();
+        let properties = schema.properties.get_or_insert_with(HashMap::new);
+
+        properties.insert(
+            "kind".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "'duplicate' (default) when both records cover the whole session, 'split' when each covers part of one workout. A split merge keeps the earlier recording as the primary.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "max_gap_seconds".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some(
+                    "Split merges only: longest pause allowed between the first recording ending and the second starting. Defaults to 900.".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "require_same_sport".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(
+                    "Split merges only: refuse recordings of different sports. Defaults to true."
+                        .to_owned(),
+                ),
+            },
+        );
+
+        schema
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH
+            | ToolCapabilities::READS_DATA
+            | ToolCapabilities::WRITES_DATA
+    }
+
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        ACTIVITY_READ_SCOPES
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let ((primary_provider, primary_id), (secondary_provider, secondary_id)) =
+            merge_targets(&args)?;
+        let kind = args
+            .get("kind")
+            .and_then(Value::as_str)
+            .map_or(Ok(ActivityMergeKind::Duplicate), str::parse)?;
+        let tenant_id = TenantId::from(context.require_tenant()?);
+
+        let primary = match fetch_activity_for_merge(context, &primary_provider, &primary_id).await
+        {
+            Ok(activity) => activity,
+            Err(result) => return Ok(result),
+        };
+        let secondary =
+            match fetch_activity_for_merge(context, &secondary_provider, &secondary_id).await {
+                Ok(activity) => activity,
+                Err(result) => return Ok(result),
+            };
+
+        let database = context.resources.database.as_ref();
+        let outcome = match kind {
+            ActivityMergeKind::Duplicate => {
+                merge_duplicate_activities(
+                    database,
+                    context.user_id,
+                    tenant_id,
+                    primary,
+                    secondary,
+                    Utc::now(),
+                )
+                .await
+            }
+            ActivityMergeKind::Split => {
+                merge_split_recordings(
+                    database,
+                    context.user_id,
+                    tenant_id,
+                    primary,
+                    secondary,
+                    &split_merge_config(&args),
+                    Utc::now(),
+                )
+                .await
+            }
+        };
+
+        match outcome {
+            Ok(outcome) => {
+                invalidate_activity_lists(context, tenant_id).await;
+                let merge = outcome.merge;
+                Ok(ToolResult::ok(json!({
+                    "activity": outcome.activity,
+                    "merge": {
+                        "kind": merge.kind,
+                        "primary_provider": merge.primary_provider,
+                        "primary_id": merge.primary_id,
+                        "secondary_provider": merge.secondary_provider,
+                        "secondary_id": merge.secondary_id,
+                        "filled_fields": merge.filled_fields,
+                        "merged_at": merge.merged_at.to_rfc3339(),
+                    }
+                })))
+            }
+            Err(e) => Ok(ToolResult::error(json!({
+                "error": format!("Failed to merge activities: {e}"),
+                "primary_id": primary_id,
+                "secondary_id": secondary_id
+            }))),
+        }
+    }
+}
+
+/// Tool for undoing a merge so both activities are listed separately again.
+pub struct UnmergeActivitiesTool;
+
+#[async_trait]
+impl McpTool for UnmergeActivitiesTool {
+    fn name(&self) -> &'static str {
+        "unmerge_activities"
+    }
+
+    fn description(&self) -> &'static str {
+        "Undo a merge made with merge_activities: the secondary is listed again and the primary no longer borrows its fields"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        merge_input_schema()
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::WRITES_DATA
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let ((primary_provider, primary_id), (secondary_provider, secondary_id)) =
+            merge_targets(&args)?;
+        let tenant_id = TenantId::from(context.require_tenant()?);
+
+        match unmerge_duplicate_activities(
+            context.resources.database.as_ref(),
+            context.user_id,
+            tenant_id,
+            (&primary_provider, &primary_id),
+            (&secondary_provider, &secondary_id),
+        )
+        .await
+        {
+            Ok(merge) => {
+                invalidate_activity_lists(context, tenant_id).await;
+                Ok(ToolResult::ok(json!({
+                    "kind": merge.kind,
+                    "primary_provider": merge.primary_provider,
+                    "primary_id": merge.primary_id,
+                    "restored": merge.secondary,
+                    "filled_fields": merge.filled_fields,
+                    "merged_at": merge.merged_at.to_rfc3339(),
+                })))
+            }
+            Err(e) => Ok(ToolResult::error(json!({
+                "error": format!("Failed to unmerge activities: {e}"),
+                "primary_id": primary_id,
+                "secondary_id": secondary_id
+            }))),
+        }
+    }
+}
+
+// ============================================================================
+// ExportUserDataTool - Portable archive of everything stored about the user
+// ============================================================================
+
+/// Tool for exporting the user's data for portability requests.
+pub struct ExportUserDataTool;
+
+#[async_trait]
+impl McpTool for ExportUserDataTool {
+    fn name(&self) -> &'static str {
+        "export_user_data"
+    }
+
+    fn description(&self) -> &'static str {
+        "Export everything stored about the user (profile, goals, insights, provider connections, OAuth apps, and recent activities) as a structured archive for data portability. Secrets and tokens are never included"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+
+        properties.insert(
+            "activity_days".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some(format!(
+                    "Days of activity history to include. Default: {DEFAULT_EXPORT_ACTIVITY_DAYS}"
+                )),
+            },
+        );
+
+        properties.insert(
+            "max_activities".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some(format!(
+                    "Maximum activities per provider. Default and max: {MAX_EXPORT_ACTIVITIES}"
+                )),
+            },
+        );
+
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, context: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let tenant_id = TenantId::from(context.require_tenant()?);
+        let defaults = ExportOptions::default();
+        let options = ExportOptions {
+            activity_days: args
+                .get("activity_days")
+                .and_then(Value::as_u64)
+                .and_then(|days| u32::try_from(days).ok())
+                .unwrap_or(defaults.activity_days),
+            max_activities: args
+                .get("max_activities")
+                .and_then(Value::as_u64)
+                .and_then(|n| usize::try_from(n).ok())
+                .unwrap_or(defaults.max_activities)
+                .clamp(1, MAX_EXPORT_ACTIVITIES),
+        };
+
+        let export = export_user_data(
+            &context.resources,
+            context.user_id,
+            tenant_id,
+            &options,
+            None,
+        )
+        .await?;
+
+        Ok(ToolResult::ok(serde_json::to_value(export)?))
+    }
+}
+
 // ============================================================================
 // Module exports
 // ============================================================================
@@ -351,5 +1858,14 @@ pub fn create_data_tools() -> Vec<Box<dyn McpTool>> {
         Box::new(GetActivitiesTool),
         Box::new(GetAthleteTool),
         Box::new(GetStatsTool),
+        Box::new(ListGearTool),
+        Box::new(GetSegmentEffortsTool),
+        Box::new(GetActivitySplitsTool),
+        Box::new(GetActivityWeatherTool),
+        Box::new(SearchActivitiesTool),
+        Box::new(GetActivitiesDeltaTool),
+        Box::new(MergeActivitiesTool),
+        Box::new(UnmergeActivitiesTool),
+        Box::new(ExportUserDataTool),
     ]
 }
@@ -344,6 +344,16 @@ impl McpTool for SetGoalTool {
                 ),
             },
         );
+        properties.insert(
+            "auto_progress".to_owned(),
+            PropertySchema {
+                property_type: "boolean".to_owned(),
+                description: Some(
+                    "Update progress automatically from synced activities (distance, time, and frequency goals). Default: true"
+                        .to_owned(),
+                ),
+            },
+        );
         JsonSchema {
             schema_type: "object".to_owned(),
             properties: Some(properties),
@@ -378,6 +388,11 @@ impl McpTool for SetGoalTool {
 
         let sport = args.get("sport").and_then(Value::as_str);
 
+        let auto_progress = args
+            .get("auto_progress")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+
         if target_value <= 0.0 {
             return Ok(ToolResult::error(json!({
                 "error": "target_value must be a positive number",
@@ -402,7 +417,10 @@ impl McpTool for SetGoalTool {
             "title": title,
             "sport": sport.unwrap_or("Running"),
             "created_at": created_at.to_rfc3339(),
-            "target_date": target_date.to_rfc3339()
+            "target_date": target_date.to_rfc3339(),
+            "current_value": 0.0,
+            "status": "active",
+            "auto_progress": auto_progress
         });
 
         match context
@@ -426,6 +444,7 @@ impl McpTool for SetGoalTool {
                     "sport": sport.unwrap_or("Running"),
                     "created_at": created_at.to_rfc3339(),
                     "target_date": target_date.to_rfc3339(),
+                    "auto_progress": auto_progress,
                     "status": "created"
                 })))
             }
@@ -634,7 +653,7 @@ impl McpTool for TrackProgressTool {
             limit: Some(200),
             offset: None,
             before: None,
-            after: Some(details.created_at.timestamp()),
+            after: Some(details.created_at),
         };
 
         let activities = provider
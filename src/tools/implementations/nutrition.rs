@@ -38,7 +38,7 @@ use crate::tools::traits::{McpTool, ToolCapabilities};
 // ============================================================================
 
 /// Parse gender from string
-fn parse_gender(gender_str: &str) -> AppResult<Gender> {
+pub(crate) fn parse_gender(gender_str: &str) -> AppResult<Gender> {
     match gender_str.to_lowercase().as_str() {
         "male" => Ok(Gender::Male),
         "female" => Ok(Gender::Female),
@@ -63,7 +63,7 @@ fn parse_activity_level(activity_str: &str) -> AppResult<ActivityLevel> {
 }
 
 /// Parse training goal from string
-fn parse_training_goal(goal_str: &str) -> AppResult<TrainingGoal> {
+pub(crate) fn parse_training_goal(goal_str: &str) -> AppResult<TrainingGoal> {
     match goal_str.to_lowercase().as_str() {
         "maintenance" => Ok(TrainingGoal::Maintenance),
         "weight_loss" => Ok(TrainingGoal::WeightLoss),
@@ -1,5 +1,5 @@
 // ABOUTME: Recipe management tools for meal planning and nutrition.
-// ABOUTME: Implements validate_recipe, save_recipe, list_recipes, search_recipes, etc.
+// ABOUTME: Implements validate_recipe, save_recipe, list_recipes, search_recipes, suggest_recipes, etc.
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -14,6 +14,7 @@
 //! - `GetRecipeTool` - Get recipe details
 //! - `DeleteRecipeTool` - Delete a recipe
 //! - `SearchRecipesTool` - Search recipes
+//! - `SuggestRecipesTool` - Rank saved recipes for today's training
 
 use std::collections::HashMap;
 
@@ -27,15 +28,20 @@ use crate::database::recipes::RecipeManager;
 use crate::errors::{AppError, AppResult};
 use crate::external::{UsdaClient, UsdaClientConfig};
 use crate::intelligence::recipes::{
-    convert_to_grams, DietaryRestriction, IngredientUnit, MacroTargets, MealTiming, Recipe,
-    RecipeConstraints, RecipeIngredient, SkillLevel,
+    convert_to_grams, rank_recipes, DayIntensity, DietaryRestriction, IngredientUnit, MacroTargets,
+    MealTiming, Recipe, RecipeConstraints, RecipeIngredient, RecipeSuggestionCriteria, SkillLevel,
 };
+use crate::intelligence::{calculate_daily_nutrition_needs, DailyNutritionParams, TrainingGoal};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
 use crate::models::TenantId;
 use crate::tools::context::ToolExecutionContext;
+use crate::tools::implementations::nutrition::{parse_gender, parse_training_goal};
 use crate::tools::result::ToolResult;
 use crate::tools::traits::{McpTool, ToolCapabilities};
 
+/// Most saved recipes considered when suggesting recipes
+const MAX_SUGGESTION_CANDIDATES: u32 = 200;
+
 // ============================================================================
 // Helper functions
 // ============================================================================
@@ -60,6 +66,34 @@ fn parse_meal_timing(s: &str) -> MealTiming {
     }
 }
 
+fn parse_day_intensity(s: &str) -> AppResult<DayIntensity> {
+    match s.to_lowercase().as_str() {
+        "rest" | "none" => Ok(DayIntensity::Rest),
+        "low" | "easy" => Ok(DayIntensity::Low),
+        "moderate" | "medium" => Ok(DayIntensity::Moderate),
+        "high" | "hard" => Ok(DayIntensity::High),
+        other => Err(AppError::invalid_input(format!(
+            "Invalid training_intensity '{other}'. Must be: rest, low, moderate, high"
+        ))),
+    }
+}
+
+fn parse_skill_level(s: &str) -> AppResult<SkillLevel> {
+    match s.to_lowercase().as_str() {
+        "beginner" => Ok(SkillLevel::Beginner),
+        "intermediate" => Ok(SkillLevel::Intermediate),
+        "advanced" => Ok(SkillLevel::Advanced),
+        other => Err(AppError::invalid_input(format!(
+            "Invalid skill_level '{other}'. Must be: beginner, intermediate, advanced"
+        ))),
+    }
+}
+
+/// Round to one decimal place for display
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
 fn parse_ingredient_unit(s: &str) -> IngredientUnit {
     match s.to_lowercase().as_str() {
         "milliliters" | "ml" => IngredientUnit::Milliliters,
@@ -1021,6 +1055,271 @@ impl McpTool for SearchRecipesTool {
     }
 }
 
+// ============================================================================
+// SuggestRecipesTool
+// ============================================================================
+
+/// Tool for ranking saved recipes against the day's training and nutrition needs.
+pub struct SuggestRecipesTool;
+
+#[async_trait]
+impl McpTool for SuggestRecipesTool {
+    fn name(&self) -> &'static str {
+        "suggest_recipes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggest saved recipes that fit today's training intensity, macro targets, and dietary restrictions"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "training_intensity".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Planned or completed training today: rest, low, moderate, high".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "weight_kg".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some("Body weight in kilograms".to_owned()),
+            },
+        );
+        properties.insert(
+            "height_cm".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some("Height in centimeters".to_owned()),
+            },
+        );
+        properties.insert(
+            "age".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some("Age in years".to_owned()),
+            },
+        );
+        properties.insert(
+            "gender".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some("Gender: male or female".to_owned()),
+            },
+        );
+        properties.insert(
+            "training_goal".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "maintenance (default), weight_loss, muscle_gain, endurance_performance"
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "macro_targets".to_owned(),
+            PropertySchema {
+                property_type: "object".to_owned(),
+                description: Some(
+                    "Daily protein_g, carbs_g, and fat_g overriding the calculated needs"
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "meal_timing".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "pre_training, post_training, rest_day, or general (default: post_training on training days, rest_day otherwise)".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "dietary_restrictions".to_owned(),
+            PropertySchema {
+                property_type: "array".to_owned(),
+                description: Some("Dietary restrictions like gluten_free, vegan".to_owned()),
+            },
+        );
+        properties.insert(
+            "skill_level".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Cooking skill: beginner, intermediate (default), advanced".to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "limit".to_owned(),
+            PropertySchema {
+                property_type: "integer".to_owned(),
+                description: Some("Maximum suggestions (default: 5)".to_owned()),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: Some(vec![
+                "training_intensity".to_owned(),
+                "weight_kg".to_owned(),
+                "height_cm".to_owned(),
+                "age".to_owned(),
+                "gender".to_owned(),
+            ]),
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, ctx: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let intensity = parse_day_intensity(
+            args.get("training_intensity")
+                .and_then(Value::as_str)
+                .ok_or_else(|| AppError::invalid_input("training_intensity is required"))?,
+        )?;
+
+        let weight_kg = args
+            .get("weight_kg")
+            .and_then(Value::as_f64)
+            .ok_or_else(|| AppError::invalid_input("weight_kg is required"))?;
+        let height_cm = args
+            .get("height_cm")
+            .and_then(Value::as_f64)
+            .ok_or_else(|| AppError::invalid_input("height_cm is required"))?;
+        let age = args
+            .get("age")
+            .and_then(Value::as_u64)
+            .and_then(|age| u32::try_from(age).ok())
+            .filter(|age| *age <= 150)
+            .ok_or_else(|| AppError::invalid_input("age is required and must be 0-150"))?;
+        let gender = parse_gender(
+            args.get("gender")
+                .and_then(Value::as_str)
+                .ok_or_else(|| AppError::invalid_input("gender is required"))?,
+        )?;
+        let training_goal = args
+            .get("training_goal")
+            .and_then(Value::as_str)
+            .map_or(Ok(TrainingGoal::Maintenance), parse_training_goal)?;
+
+        let meal_timing = args
+            .get("meal_timing")
+            .and_then(Value::as_str)
+            .map_or_else(|| intensity.default_meal_timing(), parse_meal_timing);
+        let skill_level = args
+            .get("skill_level")
+            .and_then(Value::as_str)
+            .map_or(Ok(SkillLevel::default()), parse_skill_level)?;
+        let dietary_restrictions =
+            parse_dietary_restrictions(args.get("dietary_restrictions").and_then(Value::as_array));
+
+        #[allow(clippy::cast_possible_truncation)]
+        let limit = args
+            .get("limit")
+            .and_then(Value::as_u64)
+            .map_or(5, |v| v.clamp(1, 20) as usize);
+
+        let nutrition_config = &IntelligenceConfig::global().nutrition;
+        let mut needs = calculate_daily_nutrition_needs(
+            &DailyNutritionParams {
+                weight_kg,
+                height_cm,
+                age,
+                gender,
+                activity_level: intensity.activity_level(),
+                training_goal,
+            },
+            &nutrition_config.bmr,
+            &nutrition_config.activity_factors,
+            &nutrition_config.macronutrients,
+        )
+        .map_err(|e| AppError::internal(format!("Nutrition calculation failed: {e}")))?;
+
+        // The user's own daily macro targets take precedence over the calculated ones
+        if let Some(targets) = args.get("macro_targets") {
+            if let Some(protein_g) = targets.get("protein_g").and_then(Value::as_f64) {
+                needs.protein_g = protein_g;
+            }
+            if let Some(carbs_g) = targets.get("carbs_g").and_then(Value::as_f64) {
+                needs.carbs_g = carbs_g;
+            }
+            if let Some(fat_g) = targets.get("fat_g").and_then(Value::as_f64) {
+                needs.fat_g = fat_g;
+            }
+        }
+
+        let criteria = RecipeSuggestionCriteria::for_meal(&needs, meal_timing)
+            .with_dietary_restrictions(dietary_restrictions)
+            .with_skill_level(skill_level);
+
+        let manager = get_recipe_manager(ctx)?;
+        let recipes = manager
+            .list_recipes(
+                ctx.user_id,
+                get_tenant_id(ctx),
+                None,
+                Some(MAX_SUGGESTION_CANDIDATES),
+                None,
+            )
+            .await?;
+        let considered = recipes.len();
+
+        let suggestions: Vec<Value> = rank_recipes(recipes, &criteria)
+            .into_iter()
+            .take(limit)
+            .map(|suggestion| {
+                let r = suggestion.recipe;
+                json!({
+                    "id": r.id.to_string(),
+                    "name": r.name,
+                    "score": round1(suggestion.score),
+                    "meal_timing": r.meal_timing,
+                    "required_skill": suggestion.required_skill,
+                    "total_time_mins": r.total_time_mins(),
+                    "tags": r.tags,
+                    "nutrition_per_serving": r.nutrition.map(|n| json!({
+                        "calories": n.calories.round(),
+                        "protein_g": round1(n.protein_g),
+                        "carbs_g": round1(n.carbs_g),
+                        "fat_g": round1(n.fat_g),
+                    })),
+                })
+            })
+            .collect();
+
+        let targets = &criteria.meal_targets;
+        Ok(ToolResult::ok(json!({
+            "training_intensity": intensity,
+            "meal_timing": meal_timing,
+            "skill_level": skill_level,
+            "daily_needs": {
+                "tdee": needs.tdee.round(),
+                "protein_g": round1(needs.protein_g),
+                "carbs_g": round1(needs.carbs_g),
+                "fat_g": round1(needs.fat_g),
+            },
+            "meal_targets": {
+                "calories": targets.calories.map(f64::round),
+                "protein_g": targets.protein_g.map(round1),
+                "carbs_g": targets.carbs_g.map(round1),
+                "fat_g": targets.fat_g.map(round1),
+            },
+            "suggestions": suggestions,
+            "count": suggestions.len(),
+            "considered": considered,
+        })))
+    }
+}
+
 // ============================================================================
 // Module exports
 // ============================================================================
@@ -1036,5 +1335,6 @@ pub fn create_recipe_tools() -> Vec<Box<dyn McpTool>> {
         Box::new(GetRecipeTool),
         Box::new(DeleteRecipeTool),
         Box::new(SearchRecipesTool),
+        Box::new(SuggestRecipesTool),
     ]
 }
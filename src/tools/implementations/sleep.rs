@@ -1,5 +1,5 @@
 // ABOUTME: Sleep and recovery tools for rest optimization.
-// ABOUTME: Implements analyze_sleep_quality, calculate_recovery_score, suggest_rest_day, track_sleep_trends, optimize_sleep_schedule.
+// ABOUTME: Implements analyze_sleep_quality, calculate_recovery_score, suggest_rest_day, get_recovery_score, track_sleep_trends, optimize_sleep_schedule.
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -10,6 +10,7 @@
 //! - `AnalyzeSleepQualityTool` - Analyze sleep patterns and generate quality scores
 //! - `CalculateRecoveryScoreTool` - Calculate holistic recovery score
 //! - `SuggestRestDayTool` - AI-powered rest day recommendation
+//! - `GetRecoveryScoreTool` - Recovery score from provider sleep, HRV, and training data
 //! - `TrackSleepTrendsTool` - Track sleep trends over time
 //! - `OptimizeSleepScheduleTool` - Sleep schedule recommendations
 //!
@@ -19,16 +20,23 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use serde_json::{json, Value};
 use tracing::warn;
 
+use crate::config::environment::default_provider;
 use crate::config::intelligence::SleepRecoveryConfig;
 use crate::config::IntelligenceConfig;
 use crate::errors::{AppError, AppResult};
 use crate::intelligence::algorithms::RecoveryAggregationAlgorithm;
-use crate::intelligence::{RecoveryCalculator, SleepAnalyzer, SleepData, TrainingLoad};
+use crate::intelligence::{
+    RecoveryCalculator, SleepAnalyzer, SleepData, TrainingLoad, TrainingLoadCalculator,
+};
 use crate::mcp::schema::{JsonSchema, PropertySchema};
+use crate::models::{Activity, SleepSession, TenantId};
+use crate::protocols::universal::auth_service::AuthService;
+use crate::protocols::universal::handlers::sleep_recovery::convert_sleep_session_to_data;
+use crate::providers::core::{ActivityQueryParams, FitnessProvider};
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::result::ToolResult;
 use crate::tools::traits::{McpTool, ToolCapabilities};
@@ -88,6 +96,37 @@ fn parse_training_load(args: &Value) -> TrainingLoad {
         })
 }
 
+/// Weighted-average recovery aggregation using the configured weights
+const fn configured_weighted_average(config: &SleepRecoveryConfig) -> RecoveryAggregationAlgorithm {
+    RecoveryAggregationAlgorithm::WeightedAverage {
+        tsb_weight_full: config.recovery_scoring.tsb_weight_full,
+        sleep_weight_full: config.recovery_scoring.sleep_weight_full,
+        hrv_weight_full: config.recovery_scoring.hrv_weight_full,
+        tsb_weight_no_hrv: config.recovery_scoring.tsb_weight_no_hrv,
+        sleep_weight_no_hrv: config.recovery_scoring.sleep_weight_no_hrv,
+    }
+}
+
+/// Recovery aggregation algorithm selected by the `algorithm.recovery_aggregation` flag
+///
+/// Falls back to the configured weighted average when the flag is unset, and
+/// keeps the configured weights when the flag selects the weighted average.
+fn recovery_algorithm(
+    ctx: &ToolExecutionContext,
+    config: &SleepRecoveryConfig,
+) -> RecoveryAggregationAlgorithm {
+    match ctx
+        .resources
+        .feature_flags
+        .recovery_aggregation(ctx.tenant_id.map(TenantId::from))
+    {
+        None | Some(RecoveryAggregationAlgorithm::WeightedAverage { .. }) => {
+            configured_weighted_average(config)
+        }
+        Some(algorithm) => algorithm,
+    }
+}
+
 // ============================================================================
 // AnalyzeSleepQualityTool
 // ============================================================================
@@ -282,14 +321,8 @@ impl McpTool for CalculateRecoveryScoreTool {
             None
         };
 
-        // Get recovery aggregation algorithm with config weights
-        let algorithm = RecoveryAggregationAlgorithm::WeightedAverage {
-            tsb_weight_full: config.recovery_scoring.tsb_weight_full,
-            sleep_weight_full: config.recovery_scoring.sleep_weight_full,
-            hrv_weight_full: config.recovery_scoring.hrv_weight_full,
-            tsb_weight_no_hrv: config.recovery_scoring.tsb_weight_no_hrv,
-            sleep_weight_no_hrv: config.recovery_scoring.sleep_weight_no_hrv,
-        };
+        // Get recovery aggregation algorithm, honouring the tenant's feature flag
+        let algorithm = recovery_algorithm(ctx, config);
 
         // Calculate holistic recovery score
         let recovery_score = RecoveryCalculator::calculate_recovery_score(
@@ -417,13 +450,7 @@ impl McpTool for SuggestRestDayTool {
         };
 
         // Calculate recovery score
-        let algorithm = RecoveryAggregationAlgorithm::WeightedAverage {
-            tsb_weight_full: config.recovery_scoring.tsb_weight_full,
-            sleep_weight_full: config.recovery_scoring.sleep_weight_full,
-            hrv_weight_full: config.recovery_scoring.hrv_weight_full,
-            tsb_weight_no_hrv: config.recovery_scoring.tsb_weight_no_hrv,
-            sleep_weight_no_hrv: config.recovery_scoring.sleep_weight_no_hrv,
-        };
+        let algorithm = recovery_algorithm(ctx, config);
 
         let recovery_score = RecoveryCalculator::calculate_recovery_score(
             &training_load,
@@ -469,6 +496,262 @@ impl McpTool for SuggestRestDayTool {
     }
 }
 
+// ============================================================================
+// GetRecoveryScoreTool
+// ============================================================================
+
+/// Days of sleep history fetched for the latest session and its HRV trend
+const RECOVERY_SLEEP_LOOKBACK_DAYS: i64 = 7;
+
+/// Tool computing today's recovery score from provider sleep, HRV, and training data.
+///
+/// Unlike `calculate_recovery_score`, which takes sleep data as input, this tool
+/// pulls the latest sleep session and overnight HRV from the sleep provider and
+/// recent activities from the activity provider.
+pub struct GetRecoveryScoreTool;
+
+#[async_trait]
+impl McpTool for GetRecoveryScoreTool {
+    fn name(&self) -> &'static str {
+        "get_recovery_score"
+    }
+
+    fn description(&self) -> &'static str {
+        "Get today's recovery score from the latest provider sleep session, overnight HRV, and training stress balance"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        let mut properties = HashMap::new();
+        properties.insert(
+            "provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Provider for recent activities used to compute training stress balance"
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "sleep_provider".to_owned(),
+            PropertySchema {
+                property_type: "string".to_owned(),
+                description: Some(
+                    "Sleep-capable provider for sleep and HRV data (defaults to provider)"
+                        .to_owned(),
+                ),
+            },
+        );
+        properties.insert(
+            "baseline_hrv".to_owned(),
+            PropertySchema {
+                property_type: "number".to_owned(),
+                description: Some("User's baseline HRV (RMSSD, ms)".to_owned()),
+            },
+        );
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: Some(properties),
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::REQUIRES_AUTH | ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(&self, args: Value, ctx: &ToolExecutionContext) -> AppResult<ToolResult> {
+        let activity_provider = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, String::from);
+        let sleep_provider = args
+            .get("sleep_provider")
+            .and_then(Value::as_str)
+            .map_or_else(|| activity_provider.clone(), String::from);
+        let baseline_hrv = args.get("baseline_hrv").and_then(Value::as_f64);
+
+        let supports_sleep = ctx
+            .resources
+            .provider_registry
+            .get_capabilities(&sleep_provider)
+            .is_some_and(|caps| caps.supports_sleep());
+        if !supports_sleep {
+            return Ok(ToolResult::error(json!({
+                "error": format!("Provider '{sleep_provider}' does not support sleep tracking"),
+                "sleep_provider": sleep_provider,
+            })));
+        }
+
+        tracing::debug!(
+            user_id = %ctx.user_id,
+            activity_provider = %activity_provider,
+            sleep_provider = %sleep_provider,
+            "Getting recovery score"
+        );
+
+        let provider = match create_provider(ctx, &activity_provider).await {
+            Ok(provider) => provider,
+            Err(result) => return Ok(result),
+        };
+        let query_params = ActivityQueryParams {
+            limit: usize::try_from(ctx.resources.config.sleep_tool_params.activity_limit).ok(),
+            ..ActivityQueryParams::default()
+        };
+        let activities = match provider.get_activities_with_params(&query_params).await {
+            Ok(activities) => activities,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to fetch activities: {e}"),
+                    "provider": activity_provider,
+                })));
+            }
+        };
+
+        let sleep_source = if sleep_provider == activity_provider {
+            provider
+        } else {
+            match create_provider(ctx, &sleep_provider).await {
+                Ok(provider) => provider,
+                Err(result) => return Ok(result),
+            }
+        };
+        let end_date = Utc::now();
+        let start_date = end_date - Duration::days(RECOVERY_SLEEP_LOOKBACK_DAYS);
+        let sessions = match sleep_source.get_sleep_sessions(start_date, end_date).await {
+            Ok(sessions) => sessions,
+            Err(e) => {
+                return Ok(ToolResult::error(json!({
+                    "error": format!("Failed to fetch sleep data: {e}"),
+                    "sleep_provider": sleep_provider,
+                })));
+            }
+        };
+
+        build_recovery_score_result(
+            &sessions,
+            &activities,
+            baseline_hrv,
+            &activity_provider,
+            &sleep_provider,
+        )
+    }
+}
+
+/// Create an authenticated provider for the calling user
+async fn create_provider(
+    ctx: &ToolExecutionContext,
+    provider_name: &str,
+) -> Result<Box<dyn FitnessProvider>, ToolResult> {
+    let auth_service = AuthService::new(ctx.resources.clone());
+    let tenant_id = ctx.tenant_id.map(|id| id.to_string());
+
+    auth_service
+        .create_authenticated_provider(provider_name, ctx.user_id, tenant_id.as_deref())
+        .await
+        .map_err(|response| {
+            ToolResult::error(json!({
+                "error": response.error.unwrap_or_else(|| "Authentication failed".to_owned()),
+                "provider": provider_name
+            }))
+        })
+}
+
+/// Compute a recovery score from provider sleep sessions and recent activities
+///
+/// The most recent session supplies sleep quality and current overnight HRV; the
+/// other sessions' HRV values form the weekly average it is compared against.
+/// Training stress balance comes from `activities`. When the latest session has no
+/// HRV, the score uses the configured no-HRV weights for TSB and sleep. Returns an
+/// error result when there are no sleep sessions.
+///
+/// # Errors
+///
+/// Returns an error if training load, sleep quality, or recovery calculation fails
+pub fn build_recovery_score_result(
+    sessions: &[SleepSession],
+    activities: &[Activity],
+    baseline_hrv: Option<f64>,
+    activity_provider: &str,
+    sleep_provider: &str,
+) -> AppResult<ToolResult> {
+    let Some(latest) = sessions.iter().max_by_key(|s| s.end_time) else {
+        return Ok(ToolResult::error(json!({
+            "error": format!(
+                "No sleep data available from '{sleep_provider}' for the last {RECOVERY_SLEEP_LOOKBACK_DAYS} days"
+            ),
+            "sleep_provider": sleep_provider,
+        })));
+    };
+
+    let config = &IntelligenceConfig::global().sleep_recovery;
+    let sleep_data = convert_sleep_session_to_data(latest);
+
+    let training_load = TrainingLoadCalculator::new()
+        .calculate_training_load(activities, None, None, None, None, None)
+        .map_err(|e| AppError::internal(format!("Training load calculation failed: {e}")))?;
+
+    let sleep_quality = SleepAnalyzer::calculate_sleep_quality(&sleep_data, config)
+        .map_err(|e| AppError::internal(format!("Sleep quality calculation failed: {e}")))?;
+
+    let hrv_analysis = if let Some(rmssd) = sleep_data.hrv_rmssd_ms {
+        let recent_hrv: Vec<f64> = sessions
+            .iter()
+            .filter(|s| s.end_time < latest.end_time)
+            .filter_map(|s| s.hrv_during_sleep)
+            .collect();
+
+        Some(
+            SleepAnalyzer::analyze_hrv_trends(rmssd, &recent_hrv, baseline_hrv, config)
+                .map_err(|e| AppError::internal(format!("HRV analysis failed: {e}")))?,
+        )
+    } else {
+        None
+    };
+
+    // Without HRV the weighted average falls back to the no-HRV TSB/sleep weights
+    let algorithm = configured_weighted_average(config);
+
+    let recovery_score = RecoveryCalculator::calculate_recovery_score(
+        &training_load,
+        &sleep_quality,
+        hrv_analysis.as_ref(),
+        config,
+        &algorithm,
+    )
+    .map_err(|e| AppError::internal(format!("Recovery score calculation failed: {e}")))?;
+
+    let rest_day = RecoveryCalculator::recommend_rest_day(
+        &recovery_score,
+        &sleep_data,
+        &training_load,
+        config,
+    )
+    .map_err(|e| AppError::internal(format!("Rest day recommendation failed: {e}")))?;
+
+    Ok(ToolResult::ok(json!({
+        "recovery_score": recovery_score,
+        "rest_day": rest_day,
+        "training_load": {
+            "ctl": training_load.ctl,
+            "atl": training_load.atl,
+            "tsb": training_load.tsb,
+        },
+        "sleep": {
+            "date": sleep_data.date.to_rfc3339(),
+            "duration_hours": sleep_data.duration_hours,
+            "quality_score": sleep_quality.overall_score,
+            "hrv_rmssd_ms": sleep_data.hrv_rmssd_ms,
+        },
+        "hrv_status": hrv_analysis.as_ref().map(|h| h.recovery_status),
+        "providers_used": {
+            "activity_provider": activity_provider,
+            "sleep_provider": sleep_provider,
+        },
+        "calculated_at": Utc::now().to_rfc3339(),
+    })))
+}
+
 // ============================================================================
 // Sleep Trends Helpers
 // ============================================================================
@@ -902,6 +1185,7 @@ pub fn create_sleep_tools() -> Vec<Box<dyn McpTool>> {
         Box::new(AnalyzeSleepQualityTool),
         Box::new(CalculateRecoveryScoreTool),
         Box::new(SuggestRestDayTool),
+        Box::new(GetRecoveryScoreTool),
         Box::new(TrackSleepTrendsTool),
         Box::new(OptimizeSleepScheduleTool),
     ]
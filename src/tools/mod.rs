@@ -110,4 +110,6 @@ pub use decorators::AuditedTool;
 pub use errors::ToolError;
 pub use registry::{register_external_tool, ToolRegistry};
 pub use result::{NotificationType, ToolNotification, ToolResult};
-pub use traits::{McpTool, ToolBundle, ToolCapabilities, ToolDescriptor, ToolFactory};
+pub use traits::{
+    McpTool, ScopeRequirement, ToolBundle, ToolCapabilities, ToolDescriptor, ToolFactory,
+};
@@ -18,17 +18,54 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use serde_json::{json, Value};
+use tokio::time::timeout;
 use tracing::{debug, info, warn};
 
+use crate::config::environment::default_provider;
+use crate::database_plugins::DatabaseProvider;
 use crate::errors::AppResult;
+use crate::formatters::apply_unit_system;
 use crate::mcp::schema::ToolSchema;
+use crate::metrics::MetricsRegistry;
+use crate::services::oauth_step_up::{self, ScopeStepUp};
 
 use super::context::ToolExecutionContext;
 use super::errors::ToolError;
 use super::result::ToolResult;
 use super::traits::{McpTool, ToolBundle, ToolCapabilities};
 
+/// Build the `missing_scope` error returned instead of running a tool
+///
+/// Names every scope the user's provider token lacks and carries the
+/// re-consent URL that requests only those scopes.
+fn missing_scope_result(step_up: &ScopeStepUp) -> ToolResult {
+    ToolResult::error(json!({
+        "error": step_up.message(),
+        "error_type": "missing_scope",
+        "provider": step_up.provider,
+        "missing_scopes": step_up.missing_scopes,
+        "reconsent_url": step_up.authorization_url,
+        "state": step_up.state,
+        "expires_in_minutes": step_up.expires_in_minutes,
+    }))
+}
+
+/// Build the `tool_timeout` error returned when a tool exceeds its time limit
+fn timeout_result(name: &str, limit: Duration) -> ToolResult {
+    ToolResult::error(json!({
+        "error": format!(
+            "{name} did not finish within {} seconds and was cancelled. Try again with a narrower request.",
+            limit.as_secs()
+        ),
+        "error_type": "tool_timeout",
+        "tool": name,
+        "timeout_secs": limit.as_secs(),
+    }))
+}
+
 /// Central registry for MCP tools.
 ///
 /// Provides thread-safe registration and lookup of tools with support for:
@@ -316,7 +353,11 @@ impl ToolRegistry {
     /// This method:
     /// 1. Looks up the tool in the registry
     /// 2. Checks admin privileges if required
-    /// 3. Executes the tool with the provided context
+    /// 3. Returns a `missing_scope` error if the user's provider token lacks a required scope
+    /// 4. Executes the tool with the provided context, returning a `tool_timeout`
+    ///    error if it outlives its catalog or default time limit
+    /// 5. Records the request and its latency in the metrics registry
+    /// 6. Converts successful output to the user's preferred unit system
     ///
     /// # Arguments
     ///
@@ -333,7 +374,7 @@ impl ToolRegistry {
     pub async fn execute(
         &self,
         name: &str,
-        args: serde_json::Value,
+        args: Value,
         context: &ToolExecutionContext,
     ) -> AppResult<ToolResult> {
         // Look up the tool
@@ -344,8 +385,95 @@ impl ToolRegistry {
             context.require_admin().await?;
         }
 
-        // Execute the tool
-        tool.execute(args, context).await
+        // Ask for missing provider scopes instead of letting the provider reject the call
+        if let Some(step_up) = Self::scope_step_up(name, &args, context).await {
+            return Ok(missing_scope_result(&step_up));
+        }
+
+        // Execute the tool within its time limit. Timing out drops the tool's
+        // future, which cancels any provider request still in flight.
+        let started = Instant::now();
+        let result = match Self::execution_timeout(name, context).await {
+            Some(limit) => match timeout(limit, tool.execute(args, context)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!(
+                        tool = name,
+                        timeout_secs = limit.as_secs(),
+                        "Tool execution timed out"
+                    );
+                    MetricsRegistry::global().record_tool_request(
+                        name,
+                        context.tenant_id,
+                        false,
+                        started.elapsed(),
+                    );
+                    return Ok(timeout_result(name, limit));
+                }
+            },
+            None => tool.execute(args, context).await,
+        };
+        MetricsRegistry::global().record_tool_request(
+            name,
+            context.tenant_id,
+            result.is_ok(),
+            started.elapsed(),
+        );
+
+        // Tools return SI values; present them in the user's preferred units
+        let mut result = result?;
+        if !result.is_error {
+            let units = context.unit_system().await;
+            if apply_unit_system(&mut result.content, units) {
+                debug!(tool = name, units = %units, "Converted tool output units");
+            }
+        }
+        Ok(result)
+    }
+
+    /// Time limit for a tool: its catalog override, else the configured default
+    ///
+    /// Returns `None` when the applicable timeout is zero (unbounded). A failed
+    /// catalog lookup falls back to the default.
+    async fn execution_timeout(name: &str, context: &ToolExecutionContext) -> Option<Duration> {
+        let default_secs = context.resources.config.mcp.tool_timeout_secs;
+        let secs = match context.database().get_tool_catalog_entry(name).await {
+            Ok(entry) => entry
+                .and_then(|entry| entry.timeout_secs)
+                .unwrap_or(default_secs),
+            Err(e) => {
+                warn!("Timeout lookup failed for {}: {}", name, e);
+                default_secs
+            }
+        };
+        (secs > 0).then_some(Duration::from_secs(secs))
+    }
+
+    /// Check the user's provider token against the scopes the tool needs
+    ///
+    /// Failures are logged and fall through to normal execution, which surfaces
+    /// any underlying connection problem through the tool's own error path.
+    async fn scope_step_up(
+        name: &str,
+        args: &Value,
+        context: &ToolExecutionContext,
+    ) -> Option<ScopeStepUp> {
+        let provider = args
+            .get("provider")
+            .and_then(Value::as_str)
+            .map_or_else(default_provider, str::to_owned);
+        let tenant_id = context.tenant_id.map(|id| id.to_string());
+
+        oauth_step_up::check_tool_scopes(
+            &context.resources,
+            name,
+            &provider,
+            context.user_id,
+            tenant_id.as_deref(),
+        )
+        .await
+        .inspect_err(|e| warn!("Scope check failed for {}: {}", name, e))
+        .ok()?
     }
 
     /// Register all built-in tools based on feature flags
@@ -9,6 +9,7 @@
 // - Option field cloning for response construction
 
 use crate::errors::AppError;
+use crate::jsonrpc::JsonRpcError;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::LazyLock;
@@ -232,15 +233,10 @@ impl ResponseFormatter {
         serde_json::json!({
             "jsonrpc": "2.0",
             "id": request_id,
-            "error": {
-                "code": error.code.http_status(),
-                "message": error.message,
-                "data": {
-                    "timestamp": chrono::Utc::now().to_rfc3339(),
-                    "processing_time_ms": processing_time_ms,
-                    "version": self.version,
-                }
-            }
+            "error": JsonRpcError::from_app_error(error)
+                .with_extra("timestamp", Value::from(chrono::Utc::now().to_rfc3339()))
+                .with_extra("processing_time_ms", Value::from(processing_time_ms))
+                .with_extra("version", Value::from(self.version.as_str())),
         })
     }
 
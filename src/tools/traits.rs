@@ -10,6 +10,7 @@
 //! the `McpTool` trait which provides:
 //! - Tool metadata (name, description, input schema)
 //! - Capability flags for filtering and validation
+//! - Provider OAuth scope requirements
 //! - Async execution with context
 //!
 //! The design mirrors the `FitnessProvider` trait pattern from `src/providers/core.rs`
@@ -155,6 +156,28 @@ impl ToolCapabilities {
     }
 }
 
+/// Provider OAuth scopes a tool needs to read its data from one provider
+///
+/// Declared per tool through [`McpTool::required_scopes`]. Before a tool runs,
+/// the dispatcher compares these with the scopes stored on the user's token for
+/// the requested provider and answers with a `missing_scope` error instead of
+/// letting the provider reject the call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeRequirement {
+    /// Provider the scopes apply to (e.g., "strava")
+    pub provider: &'static str,
+    /// Scopes the user's token for the provider must include
+    pub scopes: &'static [&'static str],
+}
+
+impl ScopeRequirement {
+    /// Require `scopes` whenever the tool is called for `provider`
+    #[must_use]
+    pub const fn new(provider: &'static str, scopes: &'static [&'static str]) -> Self {
+        Self { provider, scopes }
+    }
+}
+
 /// The main trait that all MCP tools must implement.
 ///
 /// This trait provides a consistent interface for tool discovery, validation,
@@ -224,6 +247,15 @@ pub trait McpTool: Send + Sync {
     /// - Caching decisions
     fn capabilities(&self) -> ToolCapabilities;
 
+    /// Provider OAuth scopes the tool needs, per provider
+    ///
+    /// Tools that do not read provider data keep the default (no requirements).
+    /// Requirements configured through `PIERRE_TOOL_REQUIRED_SCOPES` are checked
+    /// in addition to these.
+    fn required_scopes(&self) -> &'static [ScopeRequirement] {
+        &[]
+    }
+
     /// Execute the tool with given arguments and context
     ///
     /// # Arguments
@@ -7,6 +7,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, missing_docs)]
 
 use pierre_mcp_server::a2a::agent_card::AgentCard;
+use pierre_mcp_server::providers::ProviderCapabilities;
 
 #[test]
 fn test_agent_card_structure() {
@@ -124,3 +125,19 @@ fn test_authentication_methods() {
     assert_eq!(api_key.header_name, "Authorization");
     assert_eq!(api_key.prefix, Some("Bearer".to_owned()));
 }
+
+#[test]
+fn test_agent_card_restricted_to_connected_capabilities() {
+    let full = AgentCard::new();
+    assert!(full.tools.iter().any(|t| t.name == "get_activities"));
+
+    // A sleep-only provider cannot serve activity tools
+    let sleep_only = AgentCard::new().restricted_to(ProviderCapabilities::SLEEP_TRACKING);
+    let names: Vec<&str> = sleep_only.tools.iter().map(|t| t.name.as_str()).collect();
+    assert!(!names.contains(&"get_activities"));
+    assert!(!names.contains(&"analyze_activity"));
+    assert!(names.contains(&"set_goal"));
+
+    let activities = AgentCard::new().restricted_to(ProviderCapabilities::activity_only());
+    assert_eq!(activities.tools.len(), full.tools.len());
+}
@@ -0,0 +1,307 @@
+// ABOUTME: Tests for streaming A2A task status transitions and results over SSE
+// ABOUTME: Covers event ordering, the final done event, session-token auth, and tasks finished before subscribing
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::response::Response;
+use axum::Router;
+use pierre_mcp_server::{
+    a2a::{
+        client::ClientRegistrationRequest,
+        protocol::TaskStatus,
+        task_updates::{publish_task_chunk, update_task_status},
+    },
+    config::environment::{DatabaseConfig, DatabaseUrl, ServerConfig},
+    database_plugins::DatabaseProvider,
+    mcp::resources::{ServerResources, ServerResourcesOptions},
+    sse::{A2ATaskEvent, SseRoutes},
+};
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+struct TaskEventsSetup {
+    resources: Arc<ServerResources>,
+    user_id: Uuid,
+    client_id: String,
+    session_token: String,
+}
+
+impl TaskEventsSetup {
+    async fn new() -> Self {
+        common::init_server_config();
+        let database = common::create_test_database().await.unwrap();
+        let auth_manager = common::create_test_auth_manager();
+        let cache = common::create_test_cache().await.unwrap();
+        let (user_id, _user) = common::create_test_user(&database).await.unwrap();
+
+        let config = Arc::new(ServerConfig {
+            database: DatabaseConfig {
+                url: DatabaseUrl::Memory,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let resources = Arc::new(
+            ServerResources::new(
+                (*database).clone(),
+                (*auth_manager).clone(),
+                "test_jwt_secret",
+                config,
+                cache,
+                ServerResourcesOptions {
+                    rsa_key_size_bits: Some(2048),
+                    jwks_manager: Some(common::get_shared_test_jwks()),
+                    llm_provider: None,
+                },
+            )
+            .await,
+        );
+
+        let client_id = register_client(&resources, user_id).await;
+        let session_token = create_session(&resources, &client_id).await;
+
+        Self {
+            resources,
+            user_id,
+            client_id,
+            session_token,
+        }
+    }
+
+    fn routes(&self) -> Router {
+        SseRoutes::routes(
+            Arc::clone(&self.resources.sse_manager),
+            Arc::clone(&self.resources),
+        )
+    }
+
+    async fn create_task(&self) -> String {
+        self.resources
+            .database
+            .create_a2a_task(&self.client_id, None, "weekly_report", &json!({"weeks": 4}))
+            .await
+            .unwrap()
+    }
+
+    async fn subscribe(&self, task_id: &str, token: &str) -> Response {
+        let request = Request::builder()
+            .uri(format!("/a2a/tasks/{task_id}/events"))
+            .header("Authorization", format!("Bearer {token}"))
+            .body(Body::empty())
+            .unwrap();
+        self.routes().oneshot(request).await.unwrap()
+    }
+}
+
+async fn register_client(resources: &ServerResources, user_id: Uuid) -> String {
+    let request = ClientRegistrationRequest {
+        name: "Task Events Client".to_owned(),
+        description: "Client streaming task events".to_owned(),
+        capabilities: vec!["fitness-data-analysis".to_owned()],
+        redirect_uris: vec![],
+        contact_email: "events@example.com".to_owned(),
+    };
+    resources
+        .a2a_client_manager
+        .register_client(request, user_id)
+        .await
+        .unwrap()
+        .client_id
+}
+
+async fn create_session(resources: &ServerResources, client_id: &str) -> String {
+    resources
+        .database
+        .create_a2a_session(client_id, None, &["read".to_owned()], 24)
+        .await
+        .unwrap()
+}
+
+/// Read the whole stream, which must close on its own after the `done` event
+async fn read_events(response: Response) -> Vec<(String, A2ATaskEvent)> {
+    let bytes = timeout(
+        Duration::from_secs(5),
+        to_bytes(response.into_body(), usize::MAX),
+    )
+    .await
+    .expect("stream did not close after the done event")
+    .unwrap();
+
+    String::from_utf8(bytes.to_vec())
+        .unwrap()
+        .split("\n\n")
+        .filter_map(|block| {
+            let field = |name: &str| {
+                block
+                    .lines()
+                    .find_map(|line| line.strip_prefix(name))
+                    .map(str::trim)
+            };
+            let event_name = field("event:")?;
+            let data = field("data:")?;
+            Some((event_name.to_owned(), serde_json::from_str(data).unwrap()))
+        })
+        .collect()
+}
+
+fn status_of(event: &A2ATaskEvent) -> &TaskStatus {
+    match event {
+        A2ATaskEvent::Status { status, .. } | A2ATaskEvent::Done { status, .. } => status,
+        A2ATaskEvent::Chunk { .. } => panic!("chunk events carry no status"),
+    }
+}
+
+#[tokio::test]
+async fn test_events_arrive_in_order_and_stream_closes_after_done() {
+    let setup = TaskEventsSetup::new().await;
+    let task_id = setup.create_task().await;
+
+    let response = setup.subscribe(&task_id, &setup.session_token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let resources = &setup.resources;
+    update_task_status(resources, &task_id, TaskStatus::Running, None, None)
+        .await
+        .unwrap();
+    publish_task_chunk(resources, &task_id, json!({"week": 1, "distance_km": 42.0})).await;
+    update_task_status(
+        resources,
+        &task_id,
+        TaskStatus::Completed,
+        Some(json!({"total_distance_km": 168.0})),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let events = read_events(response).await;
+    let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["status", "status", "chunk", "status", "done"]);
+
+    let statuses: Vec<&TaskStatus> = events
+        .iter()
+        .filter(|(name, _)| name == "status")
+        .map(|(_, event)| status_of(event))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            &TaskStatus::Pending,
+            &TaskStatus::Running,
+            &TaskStatus::Completed
+        ]
+    );
+
+    assert_eq!(
+        events[2].1,
+        A2ATaskEvent::Chunk {
+            task_id: task_id.clone(),
+            data: json!({"week": 1, "distance_km": 42.0}),
+        }
+    );
+    assert_eq!(
+        events[4].1,
+        A2ATaskEvent::Done {
+            task_id: task_id.clone(),
+            status: TaskStatus::Completed,
+            result: Some(json!({"total_distance_km": 168.0})),
+            error: None,
+        }
+    );
+
+    // The task's channel is released once the stream closes
+    let late = A2ATaskEvent::Chunk {
+        task_id,
+        data: Value::Null,
+    };
+    assert_eq!(resources.sse_manager.publish_a2a_task_event(&late).await, 0);
+}
+
+#[tokio::test]
+async fn test_failed_task_done_event_carries_error() {
+    let setup = TaskEventsSetup::new().await;
+    let task_id = setup.create_task().await;
+
+    let response = setup.subscribe(&task_id, &setup.session_token).await;
+    update_task_status(
+        &setup.resources,
+        &task_id,
+        TaskStatus::Failed,
+        None,
+        Some("Provider unavailable".to_owned()),
+    )
+    .await
+    .unwrap();
+
+    let events = read_events(response).await;
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        events[2].1,
+        A2ATaskEvent::Done {
+            task_id,
+            status: TaskStatus::Failed,
+            result: None,
+            error: Some("Provider unavailable".to_owned()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_task_finished_before_subscribing_streams_done_immediately() {
+    let setup = TaskEventsSetup::new().await;
+    let task_id = setup.create_task().await;
+    update_task_status(
+        &setup.resources,
+        &task_id,
+        TaskStatus::Completed,
+        Some(json!({"ok": true})),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let response = setup.subscribe(&task_id, &setup.session_token).await;
+    let events = read_events(response).await;
+
+    let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["status", "done"]);
+    assert_eq!(status_of(&events[1].1), &TaskStatus::Completed);
+}
+
+#[tokio::test]
+async fn test_requires_session_of_owning_client() {
+    let setup = TaskEventsSetup::new().await;
+    let task_id = setup.create_task().await;
+
+    let missing = setup.routes().oneshot(
+        Request::builder()
+            .uri(format!("/a2a/tasks/{task_id}/events"))
+            .body(Body::empty())
+            .unwrap(),
+    );
+    assert_eq!(missing.await.unwrap().status(), StatusCode::UNAUTHORIZED);
+
+    let unknown = setup.subscribe(&task_id, "sess_not-a-real-session").await;
+    assert_eq!(unknown.status(), StatusCode::UNAUTHORIZED);
+
+    let other_client = register_client(&setup.resources, setup.user_id).await;
+    let other_session = create_session(&setup.resources, &other_client).await;
+    let foreign = setup.subscribe(&task_id, &other_session).await;
+    assert_eq!(foreign.status(), StatusCode::UNAUTHORIZED);
+
+    let not_found = setup.subscribe("no-such-task", &setup.session_token).await;
+    assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,216 @@
+// ABOUTME: Tests for the per-tenant activity backfill window enforced while paging provider history
+// ABOUTME: Covers stopping at the window boundary, the persisted resume marker, resuming, and the truncation warning
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![cfg(feature = "provider-synthetic")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{Duration, Utc};
+use futures_util::TryStreamExt;
+use pierre_mcp_server::{
+    database_plugins::DatabaseProvider,
+    mcp::resources::ServerResources,
+    models::{Activity, ActivityBuilder, SportType, TenantId},
+    pagination::Cursor,
+    providers::{
+        activity_iterator::{create_windowed_activity_stream, ActivityWindow, StreamConfig},
+        backfill::backfill_activities,
+        synthetic_provider::SyntheticProvider,
+    },
+};
+use uuid::Uuid;
+
+const PAGE_SIZE: usize = 10;
+
+/// 30 runs, one every 10 days, the newest 5 days ago
+fn history() -> Vec<Activity> {
+    (0..30)
+        .map(|i| {
+            let start = Utc::now() - Duration::days(5 + 10 * i);
+            ActivityBuilder::new(
+                format!("run-{i:02}"),
+                "Run",
+                SportType::Run,
+                start,
+                1800,
+                "synthetic",
+            )
+            .build()
+        })
+        .collect()
+}
+
+fn ids(activities: &[Activity]) -> Vec<&str> {
+    activities.iter().map(Activity::id).collect()
+}
+
+async fn user_tenant(resources: &ServerResources, user_id: Uuid) -> TenantId {
+    resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id
+}
+
+#[tokio::test]
+async fn test_windowed_stream_stops_at_boundary() {
+    let provider = SyntheticProvider::with_activities(history());
+    let window = ActivityWindow::last_days(100);
+    let stop = window.stop.clone();
+
+    let activities: Vec<Activity> =
+        create_windowed_activity_stream(&provider, StreamConfig::with_page_size(PAGE_SIZE), window)
+            .try_collect()
+            .await
+            .unwrap();
+
+    // Runs 5 through 95 days ago are inside the window
+    assert_eq!(activities.len(), 10);
+    let not_before = Utc::now() - Duration::days(100);
+    assert!(activities.iter().all(|a| a.start_date() >= not_before));
+
+    let last = activities.last().unwrap();
+    let resume = stop.resume_cursor().unwrap();
+    assert_eq!(resume, Cursor::new(last.start_date(), last.id()));
+}
+
+#[tokio::test]
+async fn test_windowed_stream_covering_all_history_records_no_stop() {
+    let provider = SyntheticProvider::with_activities(history());
+    let window = ActivityWindow::last_days(1000);
+    let stop = window.stop.clone();
+
+    let activities: Vec<Activity> =
+        create_windowed_activity_stream(&provider, StreamConfig::with_page_size(PAGE_SIZE), window)
+            .try_collect()
+            .await
+            .unwrap();
+
+    assert_eq!(activities.len(), 30);
+    assert!(stop.resume_cursor().is_none());
+}
+
+#[tokio::test]
+async fn test_default_window_is_365_days() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+
+    let days = resources
+        .database
+        .get_tenant_max_backfill_days(tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(days, 365);
+
+    assert!(resources
+        .database
+        .set_tenant_max_backfill_days(tenant_id, 0)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_truncated_backfill_persists_marker_and_warns_user() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+    resources
+        .database
+        .set_tenant_max_backfill_days(tenant_id, 100)
+        .await
+        .unwrap();
+    let provider = SyntheticProvider::with_activities(history());
+
+    let result = backfill_activities(&resources, &provider, user_id, tenant_id, PAGE_SIZE)
+        .await
+        .unwrap();
+
+    assert!(result.truncated());
+    assert_eq!(result.window_days, 100);
+    assert_eq!(
+        ids(&result.activities),
+        (0..10).map(|i| format!("run-{i:02}")).collect::<Vec<_>>()
+    );
+
+    let stored = resources
+        .database
+        .get_backfill_marker(user_id, tenant_id, "synthetic")
+        .await
+        .unwrap()
+        .unwrap();
+    let last = result.activities.last().unwrap();
+    assert_eq!(
+        stored.resume_cursor,
+        Cursor::new(last.start_date(), last.id())
+    );
+    assert_eq!(stored.window_days, 100);
+    assert_eq!(stored.activities_fetched, 10);
+    assert_eq!(Some(stored), result.marker);
+
+    let notifications = resources
+        .database
+        .get_unread_oauth_notifications(user_id)
+        .await
+        .unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].provider, "synthetic");
+    assert!(notifications[0].message.contains("100-day backfill limit"));
+}
+
+#[tokio::test]
+async fn test_rerun_resumes_from_marker_and_clears_it_when_history_ends() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+    let database = &resources.database;
+    database
+        .set_tenant_max_backfill_days(tenant_id, 100)
+        .await
+        .unwrap();
+    let provider = SyntheticProvider::with_activities(history());
+
+    backfill_activities(&resources, &provider, user_id, tenant_id, PAGE_SIZE)
+        .await
+        .unwrap();
+
+    // Same window: nothing new in range, marker unchanged, no second warning
+    let rerun = backfill_activities(&resources, &provider, user_id, tenant_id, PAGE_SIZE)
+        .await
+        .unwrap();
+    assert!(rerun.activities.is_empty());
+    assert!(rerun.truncated());
+    assert_eq!(
+        database
+            .get_unread_oauth_notifications(user_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Raised window: continues after the last imported run instead of starting over
+    database
+        .set_tenant_max_backfill_days(tenant_id, 1000)
+        .await
+        .unwrap();
+    let resumed = backfill_activities(&resources, &provider, user_id, tenant_id, PAGE_SIZE)
+        .await
+        .unwrap();
+    assert!(!resumed.truncated());
+    assert_eq!(
+        ids(&resumed.activities),
+        (10..30).map(|i| format!("run-{i:02}")).collect::<Vec<_>>()
+    );
+    assert!(database
+        .get_backfill_marker(user_id, tenant_id, "synthetic")
+        .await
+        .unwrap()
+        .is_none());
+}
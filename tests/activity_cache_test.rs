@@ -0,0 +1,229 @@
+// ABOUTME: Tests for the per-user, per-provider activity cache in front of provider calls
+// ABOUTME: Covers hit/miss, query keying, TTL expiry with a fake clock, no_cache bypass, and webhook invalidation
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use pierre_mcp_server::cache::activity::{ActivityCache, Clock};
+use pierre_mcp_server::errors::{AppError, AppResult};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::providers::core::ActivityQueryParams;
+use uuid::Uuid;
+
+const TTL: Duration = Duration::from_secs(60);
+
+struct FakeClock {
+    now: Mutex<Instant>,
+}
+
+impl FakeClock {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            now: Mutex::new(Instant::now()),
+        })
+    }
+
+    fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+fn cache_with_clock() -> (ActivityCache, Arc<FakeClock>) {
+    let clock = FakeClock::new();
+    let cache = ActivityCache::with_clock(TTL, Arc::clone(&clock) as Arc<dyn Clock>);
+    (cache, clock)
+}
+
+fn params(limit: usize) -> ActivityQueryParams {
+    ActivityQueryParams {
+        limit: Some(limit),
+        ..ActivityQueryParams::default()
+    }
+}
+
+fn activity(id: &str, provider: &str) -> Activity {
+    ActivityBuilder::new(id, "Run", SportType::Run, Utc::now(), 1800, provider).build()
+}
+
+/// Fetch through the cache, counting how often the provider is called
+async fn fetch(
+    cache: &ActivityCache,
+    calls: &AtomicUsize,
+    user_id: Uuid,
+    provider: &str,
+    params: &ActivityQueryParams,
+    no_cache: bool,
+) -> AppResult<Vec<Activity>> {
+    cache
+        .get_or_fetch(user_id, provider, params, no_cache, || async {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![activity(&format!("{provider}-{call}"), provider)])
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_miss_then_hit() {
+    let (cache, _clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+
+    let first = fetch(&cache, &calls, user_id, "strava", &params(10), false)
+        .await
+        .unwrap();
+    let second = fetch(&cache, &calls, user_id, "strava", &params(10), false)
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(first[0].id(), second[0].id());
+}
+
+#[tokio::test]
+async fn test_different_query_params_are_cached_separately() {
+    let (cache, _clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+
+    fetch(&cache, &calls, user_id, "strava", &params(10), false)
+        .await
+        .unwrap();
+    fetch(&cache, &calls, user_id, "strava", &params(20), false)
+        .await
+        .unwrap();
+    let with_after = ActivityQueryParams {
+        after: Some(Utc::now()),
+        ..params(10)
+    };
+    fetch(&cache, &calls, user_id, "strava", &with_after, false)
+        .await
+        .unwrap();
+    fetch(&cache, &calls, Uuid::new_v4(), "strava", &params(10), false)
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_entries_expire_after_ttl() {
+    let (cache, clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+
+    fetch(&cache, &calls, user_id, "garmin", &params(10), false)
+        .await
+        .unwrap();
+    clock.advance(TTL - Duration::from_secs(1));
+    assert!(cache.get(user_id, "garmin", &params(10)).is_some());
+
+    clock.advance(Duration::from_secs(1));
+    assert!(cache.get(user_id, "garmin", &params(10)).is_none());
+
+    fetch(&cache, &calls, user_id, "garmin", &params(10), false)
+        .await
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_no_cache_bypasses_and_refreshes_entry() {
+    let (cache, _clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+
+    fetch(&cache, &calls, user_id, "strava", &params(10), false)
+        .await
+        .unwrap();
+    let fresh = fetch(&cache, &calls, user_id, "strava", &params(10), true)
+        .await
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // The fresh result replaces the cached one
+    let cached = cache.get(user_id, "strava", &params(10)).unwrap();
+    assert_eq!(cached[0].id(), fresh[0].id());
+}
+
+#[tokio::test]
+async fn test_failed_fetch_is_not_cached() {
+    let (cache, _clock) = cache_with_clock();
+    let user_id = Uuid::new_v4();
+
+    let result = cache
+        .get_or_fetch(user_id, "strava", &params(10), false, || async {
+            Err(AppError::external_service("strava", "rate limited"))
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert!(cache.get(user_id, "strava", &params(10)).is_none());
+}
+
+#[tokio::test]
+async fn test_webhook_invalidation_drops_only_that_user_and_provider() {
+    let (cache, _clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+    let other_user = Uuid::new_v4();
+
+    for limit in [10, 20] {
+        fetch(&cache, &calls, user_id, "strava", &params(limit), false)
+            .await
+            .unwrap();
+    }
+    fetch(&cache, &calls, user_id, "garmin", &params(10), false)
+        .await
+        .unwrap();
+    fetch(&cache, &calls, other_user, "strava", &params(10), false)
+        .await
+        .unwrap();
+
+    // Webhook reports a new Strava activity for `user_id`
+    assert_eq!(cache.invalidate(user_id, "strava"), 2);
+
+    assert!(cache.get(user_id, "strava", &params(10)).is_none());
+    assert!(cache.get(user_id, "strava", &params(20)).is_none());
+    assert!(cache.get(user_id, "garmin", &params(10)).is_some());
+    assert!(cache.get(other_user, "strava", &params(10)).is_some());
+
+    fetch(&cache, &calls, user_id, "strava", &params(10), false)
+        .await
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn test_invalidate_user_drops_all_providers() {
+    let (cache, _clock) = cache_with_clock();
+    let calls = AtomicUsize::new(0);
+    let user_id = Uuid::new_v4();
+
+    for provider in ["strava", "garmin", "whoop"] {
+        fetch(&cache, &calls, user_id, provider, &params(10), false)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(cache.invalidate_user(user_id), 3);
+    assert_eq!(cache.invalidate_user(user_id), 0);
+}
+
+#[test]
+fn test_default_ttl_matches_analytics_cache_ttl() {
+    assert_eq!(ActivityCache::default().ttl(), Duration::from_secs(3600));
+}
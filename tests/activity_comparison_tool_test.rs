@@ -0,0 +1,238 @@
+// ABOUTME: Tests for the compare_activities tool built on MetricsCalculator and ZoneAnalysis
+// ABOUTME: Verifies metric deltas between two synthetic runs, zone distribution shifts, and sport mismatches
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::intelligence::MetricsCalculator;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData};
+use pierre_mcp_server::tools::implementations::analytics::{
+    build_activity_comparison, CompareActivitiesTool,
+};
+use pierre_mcp_server::tools::traits::{McpTool, ToolCapabilities};
+use serde_json::Value;
+
+fn last_week() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 3, 7, 0, 0).unwrap()
+}
+
+fn heart_rate_series(halves: [u32; 2]) -> TimeSeriesData {
+    let heart_rate: Vec<u32> = halves.iter().flat_map(|&bpm| [bpm; 50]).collect();
+    TimeSeriesData {
+        timestamps: (0..100).map(|i| i * 30).collect(),
+        heart_rate: Some(heart_rate),
+        power: None,
+        cadence: None,
+        speed: None,
+        altitude: None,
+        temperature: None,
+        gps_coordinates: None,
+    }
+}
+
+/// Last week's tempo run: 10 km in 50:00 (5:00/km) at 150 bpm
+fn baseline_run() -> Activity {
+    ActivityBuilder::new(
+        "tempo-1",
+        "Tempo Run",
+        SportType::Run,
+        last_week(),
+        3000,
+        "synthetic",
+    )
+    .distance_meters(10_000.0)
+    .average_speed(10_000.0 / 3000.0)
+    .average_heart_rate(150)
+    .max_heart_rate(170)
+    .elevation_gain(100.0)
+    .time_series_data(heart_rate_series([140, 150]))
+    .build()
+}
+
+/// Today's tempo run: 10 km in 45:00 (4:30/km) at 155 bpm
+fn faster_run() -> Activity {
+    ActivityBuilder::new(
+        "tempo-2",
+        "Tempo Run",
+        SportType::Run,
+        last_week() + Duration::days(7),
+        2700,
+        "synthetic",
+    )
+    .distance_meters(10_000.0)
+    .average_speed(10_000.0 / 2700.0)
+    .average_heart_rate(155)
+    .max_heart_rate(175)
+    .elevation_gain(120.0)
+    .time_series_data(heart_rate_series([150, 165]))
+    .build()
+}
+
+fn assert_close(value: &Value, expected: f64) {
+    let actual = value.as_f64().unwrap();
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_compares_two_runs_of_known_difference() {
+    let calculator = MetricsCalculator::new().with_user_data(None, Some(160.0), None, None, None);
+    let comparison =
+        build_activity_comparison(&faster_run(), &baseline_run(), &calculator, "synthetic")
+            .unwrap();
+
+    assert_eq!(comparison["sport_mismatch"], false);
+    assert_eq!(comparison["activity"]["id"], "tempo-2");
+    assert_eq!(comparison["baseline"]["id"], "tempo-1");
+
+    let metrics = &comparison["metrics"];
+    assert_close(&metrics["pace_seconds_per_km"]["baseline"], 300.0);
+    assert_close(&metrics["pace_seconds_per_km"]["activity"], 270.0);
+    assert_close(&metrics["pace_seconds_per_km"]["delta"], -30.0);
+    assert_close(&metrics["pace_seconds_per_km"]["delta_percent"], -10.0);
+    assert_close(&metrics["duration_seconds"]["delta"], -300.0);
+    assert_close(&metrics["distance_meters"]["delta_percent"], 0.0);
+    assert_close(&metrics["average_heart_rate"]["delta"], 5.0);
+    assert_close(&metrics["average_heart_rate"]["delta_percent"], 3.3);
+    assert_close(&metrics["max_heart_rate"]["delta"], 5.0);
+    assert_close(&metrics["elevation_gain_meters"]["delta_percent"], 20.0);
+    // Speed per heartbeat: (10000/2700/155) / (10000/3000/150) = 1.0753
+    assert_close(&metrics["aerobic_efficiency"]["delta_percent"], 7.5);
+
+    // No power data on either run
+    let unavailable = comparison["unavailable_metrics"].as_array().unwrap();
+    assert!(unavailable.contains(&Value::from("average_power")));
+    assert!(comparison["skipped_metrics"].as_array().unwrap().is_empty());
+
+    // With LTHR 160 the zone boundaries are 128/144/160/176 bpm
+    let hr_zones = &comparison["zone_distribution"]["heart_rate"];
+    assert_eq!(hr_zones["lthr_source"], "provided");
+    assert_close(&hr_zones["zones"]["zone2"]["baseline_percent"], 50.0);
+    assert_close(&hr_zones["zones"]["zone2"]["delta_points"], -50.0);
+    assert_close(&hr_zones["zones"]["zone3"]["delta_points"], 0.0);
+    assert_close(&hr_zones["zones"]["zone4"]["activity_percent"], 50.0);
+    assert_close(&hr_zones["zones"]["zone4"]["delta_points"], 50.0);
+    assert!(comparison["zone_distribution"]["power"].is_null());
+
+    let summary = comparison["summary"].as_str().unwrap();
+    assert!(summary.contains("Pace was 10.0% faster"), "{summary}");
+    assert!(
+        summary.contains("Average heart rate was 5 bpm higher"),
+        "{summary}"
+    );
+    assert!(
+        summary.contains("Aerobic efficiency improved by 7.5%"),
+        "{summary}"
+    );
+}
+
+#[test]
+fn test_estimates_lthr_from_max_heart_rate_when_not_provided() {
+    let comparison = build_activity_comparison(
+        &faster_run(),
+        &baseline_run(),
+        &MetricsCalculator::new(),
+        "synthetic",
+    )
+    .unwrap();
+
+    let hr_zones = &comparison["zone_distribution"]["heart_rate"];
+    assert_eq!(hr_zones["lthr_source"], "estimated_from_max_hr");
+    assert_close(&hr_zones["lthr"], 157.5);
+}
+
+#[test]
+fn test_different_sports_only_compare_shared_metrics() {
+    let ride = ActivityBuilder::new(
+        "ride-1",
+        "Endurance Ride",
+        SportType::Ride,
+        last_week() + Duration::days(1),
+        3600,
+        "synthetic",
+    )
+    .distance_meters(30_000.0)
+    .average_speed(30_000.0 / 3600.0)
+    .average_heart_rate(135)
+    .average_power(180)
+    .elevation_gain(300.0)
+    .build();
+
+    let comparison = build_activity_comparison(
+        &ride,
+        &baseline_run(),
+        &MetricsCalculator::new(),
+        "synthetic",
+    )
+    .unwrap();
+
+    assert_eq!(comparison["sport_mismatch"], true);
+
+    let metrics = comparison["metrics"].as_object().unwrap();
+    assert!(!metrics.contains_key("pace_seconds_per_km"));
+    assert!(!metrics.contains_key("distance_meters"));
+    assert!(!metrics.contains_key("aerobic_efficiency"));
+    assert_close(&metrics["duration_seconds"]["delta_percent"], 20.0);
+    assert_close(&metrics["average_heart_rate"]["delta"], -15.0);
+
+    let skipped = comparison["skipped_metrics"].as_array().unwrap();
+    for name in ["distance_meters", "pace_seconds_per_km", "average_power"] {
+        assert!(skipped.contains(&Value::from(name)), "{name} not skipped");
+    }
+
+    let summary = comparison["summary"].as_str().unwrap();
+    assert!(summary.starts_with("Different sports"), "{summary}");
+    assert!(!summary.contains("Pace"), "{summary}");
+}
+
+#[test]
+fn test_treadmill_and_road_runs_are_the_same_sport() {
+    let treadmill = ActivityBuilder::new(
+        "treadmill-1",
+        "Treadmill Tempo",
+        SportType::VirtualRun,
+        last_week() + Duration::days(2),
+        2850,
+        "synthetic",
+    )
+    .distance_meters(10_000.0)
+    .build();
+
+    let comparison = build_activity_comparison(
+        &treadmill,
+        &baseline_run(),
+        &MetricsCalculator::new(),
+        "synthetic",
+    )
+    .unwrap();
+
+    assert_eq!(comparison["sport_mismatch"], false);
+    assert_close(
+        &comparison["metrics"]["pace_seconds_per_km"]["delta_percent"],
+        -5.0,
+    );
+}
+
+#[test]
+fn test_compare_activities_tool_metadata() {
+    let tool = CompareActivitiesTool;
+    assert_eq!(tool.name(), "compare_activities");
+    assert!(!tool.description().is_empty());
+
+    let schema = tool.input_schema();
+    assert_eq!(
+        schema.required.unwrap(),
+        ["activity_id", "compare_activity_id"]
+    );
+
+    let caps = tool.capabilities();
+    assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+    assert!(caps.contains(ToolCapabilities::READS_DATA));
+    assert!(!caps.contains(ToolCapabilities::WRITES_DATA));
+}
@@ -0,0 +1,240 @@
+// ABOUTME: Tests for cross-provider activity deduplication when syncing overlapping sources
+// ABOUTME: Covers near-duplicate merging, provider priority, and no false merges of distinct sessions
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::providers::utils::{
+    deduplicate_activities, deduplicate_activities_with_config, DeduplicationConfig,
+};
+
+fn morning() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 14, 7, 0, 0).unwrap()
+}
+
+fn run(
+    provider: &str,
+    id: &str,
+    start: DateTime<Utc>,
+    duration_seconds: u64,
+    distance: f64,
+) -> Activity {
+    ActivityBuilder::new(
+        id,
+        "Morning Run",
+        SportType::Run,
+        start,
+        duration_seconds,
+        provider,
+    )
+    .distance_meters(distance)
+    .build()
+}
+
+/// Garmin watch upload and the copy Strava imported from it
+fn garmin_and_strava_copy() -> (Activity, Activity) {
+    let garmin = ActivityBuilder::new(
+        "garmin-1",
+        "Morning Run",
+        SportType::Run,
+        morning(),
+        3000,
+        "garmin",
+    )
+    .distance_meters(10_000.0)
+    .average_heart_rate(152)
+    .aerobic_training_effect(3.4)
+    .build();
+
+    let strava = ActivityBuilder::new(
+        "strava-1",
+        "Morning Run",
+        SportType::Run,
+        morning() + Duration::seconds(40),
+        2970,
+        "strava",
+    )
+    .distance_meters(10_080.0)
+    .average_heart_rate(149)
+    .suffer_score(87)
+    .city("Montreal".to_owned())
+    .build();
+
+    (garmin, strava)
+}
+
+#[test]
+fn test_near_duplicate_pair_is_merged_keeping_preferred_provider() {
+    let (garmin, strava) = garmin_and_strava_copy();
+
+    // Input order does not matter
+    let merged =
+        deduplicate_activities_with_config(vec![strava, garmin], &DeduplicationConfig::default());
+
+    assert_eq!(merged.len(), 1);
+    let activity = &merged[0];
+    assert_eq!(activity.provider(), "garmin");
+    assert_eq!(activity.id(), "garmin-1");
+    assert_eq!(activity.start_date(), morning());
+    // Fields both sources report come from the preferred provider
+    assert_eq!(activity.average_heart_rate(), Some(152));
+    assert_eq!(activity.aerobic_training_effect(), Some(3.4));
+    // Fields only the other provider reports are merged in
+    assert_eq!(activity.suffer_score(), Some(87));
+    assert_eq!(activity.city(), Some("Montreal"));
+}
+
+#[test]
+fn test_provider_priority_is_configurable() {
+    let (garmin, strava) = garmin_and_strava_copy();
+    let config = DeduplicationConfig {
+        provider_priority: vec!["strava".to_owned(), "garmin".to_owned()],
+        ..DeduplicationConfig::default()
+    };
+
+    let merged = deduplicate_activities_with_config(vec![garmin, strava], &config);
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].id(), "strava-1");
+    assert_eq!(merged[0].average_heart_rate(), Some(149));
+    assert_eq!(merged[0].aerobic_training_effect(), Some(3.4));
+}
+
+#[test]
+fn test_start_time_window_is_configurable() {
+    let garmin = run("garmin", "g", morning(), 3000, 10_000.0);
+    let strava = run(
+        "strava",
+        "s",
+        morning() + Duration::seconds(120),
+        3000,
+        10_000.0,
+    );
+
+    let default_window = deduplicate_activities_with_config(
+        vec![garmin.clone(), strava.clone()],
+        &DeduplicationConfig::default(),
+    );
+    assert_eq!(
+        default_window.len(),
+        2,
+        "120s apart is outside the 90s default"
+    );
+
+    let wide_window = DeduplicationConfig {
+        start_tolerance_seconds: 180,
+        ..DeduplicationConfig::default()
+    };
+    assert_eq!(
+        deduplicate_activities_with_config(vec![garmin, strava], &wide_window).len(),
+        1
+    );
+}
+
+#[test]
+fn test_three_providers_recording_one_session_merge_into_one() {
+    let activities = vec![
+        run(
+            "strava",
+            "s",
+            morning() + Duration::seconds(30),
+            2990,
+            10_020.0,
+        ),
+        run(
+            "coros",
+            "c",
+            morning() + Duration::seconds(5),
+            3005,
+            9_990.0,
+        ),
+        run("garmin", "g", morning(), 3000, 10_000.0),
+    ];
+
+    let merged = deduplicate_activities(activities);
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].provider(), "garmin");
+}
+
+#[test]
+fn test_back_to_back_sessions_from_one_provider_are_not_merged() {
+    // Two identical interval reps recorded as separate activities a minute apart
+    let first = run("garmin", "rep-1", morning(), 300, 1_000.0);
+    let second = run(
+        "garmin",
+        "rep-2",
+        morning() + Duration::seconds(60),
+        300,
+        1_000.0,
+    );
+
+    let merged =
+        deduplicate_activities_with_config(vec![first, second], &DeduplicationConfig::default());
+
+    let ids: Vec<&str> = merged.iter().map(Activity::id).collect();
+    assert_eq!(ids, vec!["rep-1", "rep-2"]);
+}
+
+#[test]
+fn test_distinct_sessions_across_providers_are_not_merged() {
+    // Warm-up on the watch, then the main run logged on Strava a minute later
+    let warm_up = run("garmin", "warm-up", morning(), 900, 2_500.0);
+    let main_run = run(
+        "strava",
+        "main",
+        morning() + Duration::seconds(60),
+        2400,
+        8_000.0,
+    );
+
+    // Brick workout: a ride and a run that start within the window
+    let ride = ActivityBuilder::new(
+        "ride",
+        "Brick Ride",
+        SportType::Ride,
+        morning() + Duration::hours(3),
+        1800,
+        "garmin",
+    )
+    .distance_meters(15_000.0)
+    .build();
+    let brick_run = ActivityBuilder::new(
+        "brick-run",
+        "Brick Run",
+        SportType::Run,
+        morning() + Duration::hours(3) + Duration::seconds(45),
+        1800,
+        "strava",
+    )
+    .distance_meters(5_500.0)
+    .build();
+
+    // Same start and duration but clearly different distances
+    let treadmill = run(
+        "fitbit",
+        "treadmill",
+        morning() + Duration::hours(8),
+        1800,
+        5_000.0,
+    );
+    let outdoor = run(
+        "strava",
+        "outdoor",
+        morning() + Duration::hours(8) + Duration::seconds(20),
+        1800,
+        6_000.0,
+    );
+
+    let merged = deduplicate_activities_with_config(
+        vec![warm_up, main_run, ride, brick_run, treadmill, outdoor],
+        &DeduplicationConfig::default(),
+    );
+
+    assert_eq!(merged.len(), 6, "No distinct sessions should be merged");
+}
@@ -0,0 +1,265 @@
+// ABOUTME: Tests for since-based activity delta sync against stored activity content hashes
+// ABOUTME: Verifies only activities created or updated after `since` are returned, plus deleted IDs
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![cfg(feature = "provider-synthetic")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use pierre_mcp_server::{
+    database_plugins::DatabaseProvider,
+    errors::AppResult,
+    models::{Activity, ActivityBuilder, Athlete, PersonalRecord, SportType, Stats},
+    pagination::{CursorPage, PaginationParams},
+    providers::core::{ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig},
+    providers::synthetic_provider::SyntheticProvider,
+    services::activity_delta::{
+        diff_activities, incremental_activity_query, sync_activity_delta, DELTA_LOOKBACK_DAYS,
+    },
+};
+
+fn run(id: &str, start: DateTime<Utc>, distance_meters: f64) -> Activity {
+    ActivityBuilder::new(id, "Run", SportType::Run, start, 1800, "synthetic")
+        .distance_meters(distance_meters)
+        .build()
+}
+
+fn ids(activities: &[Activity]) -> Vec<&str> {
+    activities.iter().map(Activity::id).collect()
+}
+
+/// Synthetic provider that records the `after` bound of every activity query
+struct RecordingProvider {
+    inner: SyntheticProvider,
+    requested_after: Mutex<Vec<Option<DateTime<Utc>>>>,
+}
+
+#[async_trait]
+impl FitnessProvider for RecordingProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        self.inner.config()
+    }
+
+    async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
+        self.inner.set_credentials(credentials).await
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        self.inner.get_athlete().await
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        self.requested_after.lock().unwrap().push(params.after);
+        self.inner.get_activities_with_params(params).await
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        self.inner.get_activities_cursor(params).await
+    }
+
+    async fn get_activity(&self, id: &str) -> AppResult<Activity> {
+        self.inner.get_activity(id).await
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        self.inner.get_stats().await
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        self.inner.get_personal_records().await
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_diff_only_marks_deletions_for_complete_window() {
+    let now = Utc::now();
+    let earlier = now - Duration::days(1);
+    let previous = diff_activities(
+        Vec::new(),
+        &[run("a", earlier, 5000.0), run("b", earlier, 8000.0)],
+        true,
+        earlier,
+    )
+    .unwrap()
+    .changed_records;
+    assert_eq!(previous.len(), 2);
+
+    let fetched = [run("a", earlier, 5000.0)];
+    let truncated = diff_activities(previous.clone(), &fetched, false, now).unwrap();
+    assert!(truncated.changed_records.is_empty());
+
+    let complete = diff_activities(previous, &fetched, true, now).unwrap();
+    assert_eq!(complete.changed_records.len(), 1);
+    assert_eq!(complete.changed_records[0].activity_id, "b");
+    assert_eq!(complete.changed_records[0].deleted_at, Some(now));
+}
+
+#[tokio::test]
+async fn test_delta_returns_only_activities_changed_since() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let database = resources.database.as_ref();
+
+    // Batch A before `since`, batch B after it
+    let first_sync = Utc::now();
+    let since = first_sync - Duration::days(5);
+    let batch_a = vec![
+        run("a-1", first_sync - Duration::days(10), 5000.0),
+        run("a-2", first_sync - Duration::days(9), 6000.0),
+    ];
+    let batch_b = vec![
+        run("b-1", first_sync - Duration::days(2), 7000.0),
+        run("b-2", first_sync - Duration::days(1), 8000.0),
+    ];
+    let provider =
+        SyntheticProvider::with_activities(batch_a.iter().chain(&batch_b).cloned().collect());
+
+    let delta = sync_activity_delta(
+        database,
+        &provider,
+        user_id,
+        tenant_id,
+        Some(since),
+        first_sync,
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&delta.activities), vec!["b-2", "b-1"]);
+    assert!(delta.deleted_ids.is_empty());
+    assert!(delta.deletions_checked);
+    assert_eq!(delta.synced_at, first_sync);
+
+    // Edit a-1, delete b-1 and add c-1 before the next sync
+    let second_sync = first_sync + Duration::hours(1);
+    provider
+        .set_activities(vec![
+            run("a-1", first_sync - Duration::days(10), 5100.0),
+            batch_a[1].clone(),
+            batch_b[1].clone(),
+            run("c-1", first_sync - Duration::minutes(30), 4000.0),
+        ])
+        .unwrap();
+
+    let delta = sync_activity_delta(
+        database,
+        &provider,
+        user_id,
+        tenant_id,
+        Some(delta.synced_at),
+        second_sync,
+    )
+    .await
+    .unwrap();
+    assert_eq!(ids(&delta.activities), vec!["c-1", "a-1"]);
+    assert_eq!(delta.deleted_ids, vec!["b-1"]);
+
+    // Nothing changed since the second sync
+    let delta = sync_activity_delta(
+        database,
+        &provider,
+        user_id,
+        tenant_id,
+        Some(second_sync),
+        second_sync + Duration::hours(1),
+    )
+    .await
+    .unwrap();
+    assert!(delta.activities.is_empty());
+    assert!(delta.deleted_ids.is_empty());
+}
+
+#[tokio::test]
+async fn test_second_sync_requests_only_activities_after_last_sync() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let database = resources.database.as_ref();
+    let lookback = Duration::days(DELTA_LOOKBACK_DAYS);
+
+    let first_sync = Utc::now();
+    let provider = RecordingProvider {
+        inner: SyntheticProvider::with_activities(vec![
+            run("old", first_sync - Duration::days(400), 5000.0),
+            run("recent", first_sync - Duration::days(1), 6000.0),
+        ]),
+        requested_after: Mutex::new(Vec::new()),
+    };
+
+    // A provider that never synced gets an unbounded query
+    let query = incremental_activity_query(database, user_id, tenant_id, provider.name(), None)
+        .await
+        .unwrap();
+    assert_eq!(query.after, None);
+
+    let delta = sync_activity_delta(database, &provider, user_id, tenant_id, None, first_sync)
+        .await
+        .unwrap();
+    assert_eq!(ids(&delta.activities), vec!["recent", "old"]);
+
+    // The last sync time now feeds `after`
+    let query = incremental_activity_query(database, user_id, tenant_id, provider.name(), None)
+        .await
+        .unwrap();
+    assert_eq!(query.after, Some(first_sync));
+
+    provider
+        .inner
+        .set_activities(vec![
+            run("old", first_sync - Duration::days(400), 5000.0),
+            run("recent", first_sync - Duration::days(1), 6000.0),
+            run("new", first_sync + Duration::minutes(10), 7000.0),
+        ])
+        .unwrap();
+    let second_sync = first_sync + Duration::hours(1);
+    let delta = sync_activity_delta(database, &provider, user_id, tenant_id, None, second_sync)
+        .await
+        .unwrap();
+    assert_eq!(delta.since, first_sync);
+    assert_eq!(ids(&delta.activities), vec!["new"]);
+
+    // Only the lookback window before the last sync is re-requested, not the full history
+    let requested = provider.requested_after.lock().unwrap().clone();
+    assert_eq!(requested.len(), 2);
+    assert_eq!(requested[1], Some(first_sync - lookback));
+}
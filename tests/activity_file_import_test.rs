@@ -0,0 +1,177 @@
+// ABOUTME: Tests for GPX and TCX activity file parsing and the `file` pseudo-provider
+// ABOUTME: Parses sample files with HR, cadence, and power extensions and checks the normalized fields
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::constants::oauth_providers;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::SportType;
+use pierre_mcp_server::providers::activity_import::ImportSource;
+use pierre_mcp_server::providers::core::FitnessProvider;
+use pierre_mcp_server::providers::errors::ProviderError;
+use pierre_mcp_server::providers::file_formats::{detect_format, parse_activity_file};
+use pierre_mcp_server::providers::spi::{FileDescriptor, ProviderDescriptor};
+use pierre_mcp_server::providers::FileProvider;
+
+const SAMPLE_GPX: &str = include_str!("fixtures/activity_files/morning_run.gpx");
+const SAMPLE_TCX: &str = include_str!("fixtures/activity_files/evening_ride.tcx");
+
+#[test]
+fn test_parse_gpx_with_extensions() {
+    let activity =
+        parse_activity_file(ImportSource::Gpx, "morning_run.gpx", SAMPLE_GPX.as_bytes()).unwrap();
+
+    assert!(activity.id().starts_with("gpx-"));
+    assert_eq!(activity.name(), "Morning Run");
+    assert_eq!(*activity.sport_type(), SportType::Run);
+    assert_eq!(activity.provider(), oauth_providers::FILE);
+    assert_eq!(
+        activity.start_date(),
+        Utc.with_ymd_and_hms(2025, 6, 1, 6, 30, 0).unwrap()
+    );
+    assert_eq!(activity.duration_seconds(), 180);
+
+    // Three 0.003 degree steps north, about 333.6 m each
+    let distance = activity.distance_meters().unwrap();
+    assert!((distance - 1000.8).abs() < 1.0, "distance {distance}");
+    assert!((activity.elevation_gain().unwrap() - 12.0).abs() < 1e-9);
+    assert_eq!(activity.average_heart_rate(), Some(135));
+    assert_eq!(activity.max_heart_rate(), Some(150));
+    assert_eq!(activity.average_cadence(), Some(83));
+    assert_eq!(activity.max_cadence(), Some(86));
+    assert_eq!(activity.average_power(), None);
+    assert_eq!(activity.temperature(), Some(18.5));
+    assert_eq!(activity.start_latitude(), Some(46.5));
+    assert_eq!(activity.start_longitude(), Some(6.6));
+    assert!((activity.average_speed().unwrap() - distance / 180.0).abs() < 1e-9);
+
+    let streams = activity.time_series_data().unwrap();
+    assert_eq!(streams.timestamps, vec![0, 60, 120, 180]);
+    assert_eq!(streams.heart_rate, Some(vec![120, 130, 140, 150]));
+    assert_eq!(streams.cadence, Some(vec![80, 82, 84, 86]));
+    assert_eq!(streams.altitude, Some(vec![100.0, 105.0, 103.0, 110.0]));
+    assert_eq!(streams.gps_coordinates.as_ref().unwrap().len(), 4);
+    assert_eq!(streams.speed.as_ref().unwrap().len(), 4);
+    assert!(streams.power.is_none());
+}
+
+#[test]
+fn test_parse_tcx_with_laps() {
+    let activity =
+        parse_activity_file(ImportSource::Tcx, "evening_ride.tcx", SAMPLE_TCX.as_bytes()).unwrap();
+
+    assert!(activity.id().starts_with("tcx-"));
+    assert_eq!(activity.name(), "Evening Ride");
+    assert_eq!(*activity.sport_type(), SportType::Ride);
+    assert_eq!(
+        activity.start_date(),
+        Utc.with_ymd_and_hms(2025, 6, 2, 17, 0, 0).unwrap()
+    );
+
+    // Summary values come from the lap totals
+    assert_eq!(activity.duration_seconds(), 600);
+    assert_eq!(activity.distance_meters(), Some(5000.0));
+    assert_eq!(activity.calories(), Some(240));
+    assert!((activity.elevation_gain().unwrap() - 20.0).abs() < 1e-9);
+    assert_eq!(activity.average_heart_rate(), Some(133));
+    assert_eq!(activity.max_heart_rate(), Some(150));
+    assert_eq!(activity.average_cadence(), Some(90));
+    assert_eq!(activity.max_cadence(), Some(95));
+    assert_eq!(activity.average_power(), Some(220));
+    assert_eq!(activity.max_power(), Some(260));
+    assert!((activity.max_speed().unwrap() - 2600.0 / 300.0).abs() < 1e-3);
+
+    let streams = activity.time_series_data().unwrap();
+    assert_eq!(streams.timestamps, vec![0, 300, 600]);
+    assert_eq!(streams.power, Some(vec![180, 220, 260]));
+    assert_eq!(streams.heart_rate, Some(vec![110, 140, 150]));
+}
+
+#[test]
+fn test_same_file_yields_same_id() {
+    let first = parse_activity_file(ImportSource::Gpx, "a.gpx", SAMPLE_GPX.as_bytes()).unwrap();
+    let second = parse_activity_file(ImportSource::Gpx, "b.gpx", SAMPLE_GPX.as_bytes()).unwrap();
+    assert_eq!(first.id(), second.id());
+}
+
+#[test]
+fn test_invalid_files_are_rejected() {
+    let truncated = &SAMPLE_GPX[..SAMPLE_GPX.len() / 2];
+    let error =
+        parse_activity_file(ImportSource::Gpx, "broken.gpx", truncated.as_bytes()).unwrap_err();
+    assert!(
+        matches!(error, ProviderError::InvalidData { .. }),
+        "{error:?}"
+    );
+
+    // A TCX file is not a GPX file
+    assert!(parse_activity_file(ImportSource::Gpx, "ride.gpx", SAMPLE_TCX.as_bytes()).is_err());
+
+    let no_times = r#"<gpx><trk><trkseg><trkpt lat="1" lon="2"/></trkseg></trk></gpx>"#;
+    let error =
+        parse_activity_file(ImportSource::Gpx, "untimed.gpx", no_times.as_bytes()).unwrap_err();
+    assert!(error.to_string().contains("timestamped"), "{error}");
+}
+
+#[test]
+fn test_detect_format() {
+    assert_eq!(detect_format("run.GPX", None), Some(ImportSource::Gpx));
+    assert_eq!(detect_format("ride.tcx", None), Some(ImportSource::Tcx));
+    assert_eq!(
+        detect_format("upload", Some("application/gpx+xml")),
+        Some(ImportSource::Gpx)
+    );
+    assert_eq!(detect_format("activity.fit", None), None);
+    assert_eq!(detect_format("notes.txt", Some("text/plain")), None);
+}
+
+#[tokio::test]
+async fn test_imported_activities_are_served_by_file_provider() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+
+    let run =
+        parse_activity_file(ImportSource::Gpx, "morning_run.gpx", SAMPLE_GPX.as_bytes()).unwrap();
+    let ride =
+        parse_activity_file(ImportSource::Tcx, "evening_ride.tcx", SAMPLE_TCX.as_bytes()).unwrap();
+    database
+        .store_imported_activity(user_id, "gpx", "morning_run.gpx", &run)
+        .await
+        .unwrap();
+    database
+        .store_imported_activity(user_id, "tcx", "evening_ride.tcx", &ride)
+        .await
+        .unwrap();
+    // Re-importing the same file replaces the stored copy
+    database
+        .store_imported_activity(user_id, "gpx", "morning_run.gpx", &run)
+        .await
+        .unwrap();
+
+    let stored = database.get_imported_activities(user_id).await.unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored[0].id(), ride.id());
+    assert_eq!(
+        stored[1].time_series_data().unwrap().heart_rate,
+        Some(vec![120, 130, 140, 150])
+    );
+
+    let provider = FileProvider::with_activities(stored);
+    let activities = provider.get_activities(Some(10), None).await.unwrap();
+    assert_eq!(activities.len(), 2);
+    assert_eq!(
+        provider.get_activity(run.id()).await.unwrap().name(),
+        "Morning Run"
+    );
+    assert_eq!(provider.get_stats().await.unwrap().total_activities, 2);
+
+    assert_eq!(FileDescriptor.name(), oauth_providers::FILE);
+    assert!(!FileDescriptor.requires_oauth());
+}
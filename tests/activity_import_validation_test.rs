@@ -0,0 +1,240 @@
+// ABOUTME: Tests for the activity import validation report over FIT, Apple Health, and TCX batches
+// ABOUTME: Verifies imported, deduplicated, and rejected counts and per-rejection reasons
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::providers::activity_import::{
+    ActivityImportValidator, ImportRecord, ImportSource, ImportValidationConfig, RejectionReason,
+};
+
+fn morning() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 4, 12, 7, 0, 0).unwrap()
+}
+
+fn activity(
+    id: &str,
+    sport_type: SportType,
+    start: DateTime<Utc>,
+    duration_seconds: u64,
+    distance_meters: f64,
+    provider: &str,
+) -> Activity {
+    ActivityBuilder::new(
+        id,
+        "Imported",
+        sport_type,
+        start,
+        duration_seconds,
+        provider,
+    )
+    .distance_meters(distance_meters)
+    .build()
+}
+
+/// Already synced from Strava before the import ran
+fn existing() -> Vec<Activity> {
+    vec![activity(
+        "strava-1",
+        SportType::Ride,
+        morning() - Duration::days(1),
+        5400,
+        45_000.0,
+        "strava",
+    )]
+}
+
+fn mixed_batch() -> Vec<ImportRecord> {
+    vec![
+        // New run from a FIT file
+        ImportRecord::parsed(
+            ImportSource::Fit,
+            "run.fit",
+            activity("fit-run", SportType::Run, morning(), 3600, 10_000.0, "fit"),
+        ),
+        // Same ride Strava already has, recorded 30 seconds later by the watch
+        ImportRecord::parsed(
+            ImportSource::Fit,
+            "ride.fit",
+            activity(
+                "fit-ride",
+                SportType::Ride,
+                morning() - Duration::days(1) + Duration::seconds(30),
+                5350,
+                45_200.0,
+                "fit",
+            ),
+        ),
+        // Apple Health also captured the FIT run
+        ImportRecord::parsed(
+            ImportSource::AppleHealth,
+            "export.xml#workout-17",
+            activity(
+                "hk-run",
+                SportType::Run,
+                morning() + Duration::seconds(5),
+                3590,
+                9_950.0,
+                "apple_health",
+            ),
+        ),
+        // New swim from Apple Health
+        ImportRecord::parsed(
+            ImportSource::AppleHealth,
+            "export.xml#workout-18",
+            activity(
+                "hk-swim",
+                SportType::Swim,
+                morning() + Duration::days(1),
+                2400,
+                2_000.0,
+                "apple_health",
+            ),
+        ),
+        // Accidental start/stop
+        ImportRecord::parsed(
+            ImportSource::Tcx,
+            "blip.tcx",
+            activity("tcx-blip", SportType::Run, morning(), 20, 30.0, "tcx"),
+        ),
+        // Corrupt distance
+        ImportRecord::parsed(
+            ImportSource::Tcx,
+            "corrupt.tcx",
+            activity(
+                "tcx-corrupt",
+                SportType::Walk,
+                morning() - Duration::days(3),
+                1800,
+                -50.0,
+                "tcx",
+            ),
+        ),
+        // Clock set years ahead
+        ImportRecord::parsed(
+            ImportSource::Tcx,
+            "future.tcx",
+            activity(
+                "tcx-future",
+                SportType::Run,
+                Utc::now() + Duration::days(400),
+                1800,
+                5_000.0,
+                "tcx",
+            ),
+        ),
+        ImportRecord::failed(ImportSource::Fit, "truncated.fit", "CRC mismatch"),
+    ]
+}
+
+#[test]
+fn test_mixed_batch_report_matches_outcomes() {
+    let batch = ActivityImportValidator::default().validate(mixed_batch(), &existing());
+    let report = &batch.report;
+
+    assert_eq!(report.total, 8);
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.deduped, 2);
+    assert_eq!(report.rejected, 4);
+    assert_eq!(
+        report.imported + report.deduped + report.rejected,
+        report.total
+    );
+
+    assert_eq!(report.imported_ids, vec!["fit-run", "hk-swim"]);
+    let imported: Vec<&str> = batch.activities.iter().map(Activity::id).collect();
+    assert_eq!(imported, report.imported_ids);
+
+    let duplicates: Vec<(&str, &str)> = report
+        .duplicates
+        .iter()
+        .map(|d| (d.activity_id.as_str(), d.duplicate_of.as_str()))
+        .collect();
+    assert_eq!(
+        duplicates,
+        vec![("fit-ride", "strava-1"), ("hk-run", "fit-run")]
+    );
+    assert_eq!(report.duplicates[1].source, ImportSource::AppleHealth);
+
+    let reasons: Vec<(&str, &str)> = report
+        .rejections
+        .iter()
+        .map(|r| (r.file_name.as_str(), r.reason.code()))
+        .collect();
+    assert_eq!(
+        reasons,
+        vec![
+            ("blip.tcx", "too_short"),
+            ("corrupt.tcx", "invalid_distance"),
+            ("future.tcx", "future_start_date"),
+            ("truncated.fit", "parse_failed"),
+        ]
+    );
+    assert_eq!(
+        report.rejections[0].reason,
+        RejectionReason::TooShort {
+            duration_seconds: 20,
+            min_duration_seconds: 60,
+        }
+    );
+    assert_eq!(report.rejections[3].activity_id, None);
+    assert!(report.rejections[3]
+        .reason
+        .to_string()
+        .contains("CRC mismatch"));
+
+    let by_reason = report.rejections_by_reason();
+    assert_eq!(by_reason.len(), 4);
+    assert!(by_reason.values().all(|count| *count == 1));
+}
+
+#[test]
+fn test_thresholds_are_configurable() {
+    let config = ImportValidationConfig {
+        min_duration_seconds: 10,
+        dedup_start_tolerance_seconds: 1,
+        ..ImportValidationConfig::default()
+    };
+    let batch = ActivityImportValidator::new(config).validate(mixed_batch(), &existing());
+    let report = &batch.report;
+
+    // The blip is now long enough and only exact-start duplicates are merged
+    assert_eq!(report.imported, 5);
+    assert_eq!(report.deduped, 0);
+    assert_eq!(report.rejected, 3);
+    assert!(report.imported_ids.contains(&"tcx-blip".to_owned()));
+}
+
+#[test]
+fn test_reimporting_same_file_is_deduplicated_by_id() {
+    let run = activity("fit-run", SportType::Run, morning(), 3600, 10_000.0, "fit");
+    let batch = ActivityImportValidator::default().validate(
+        vec![ImportRecord::parsed(
+            ImportSource::Fit,
+            "run.fit",
+            run.clone(),
+        )],
+        &[run],
+    );
+
+    assert_eq!(batch.report.imported, 0);
+    assert_eq!(batch.report.deduped, 1);
+    assert_eq!(batch.report.duplicates[0].duplicate_of, "fit-run");
+}
+
+#[test]
+fn test_report_serializes_reasons_with_tags() {
+    let batch = ActivityImportValidator::default().validate(mixed_batch(), &existing());
+    let json = serde_json::to_value(&batch.report).unwrap();
+
+    assert_eq!(json["rejected"], 4);
+    assert_eq!(json["rejections"][0]["source"], "tcx");
+    assert_eq!(json["rejections"][0]["reason"]["reason"], "too_short");
+    assert_eq!(json["rejections"][3]["reason"]["message"], "CRC mismatch");
+    assert_eq!(json["duplicates"][1]["source"], "apple_health");
+}
@@ -7,8 +7,9 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
 
+use pierre_mcp_server::constants::oauth_providers;
 use pierre_mcp_server::providers::activity_iterator::{
-    StreamConfig, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, MIN_PAGE_SIZE,
+    ProviderPageLimits, StreamConfig, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, MIN_PAGE_SIZE,
 };
 
 #[test]
@@ -48,3 +49,32 @@ fn test_stream_config_builder_chain() {
     assert_eq!(config.page_size, 30);
     assert_eq!(config.max_activities, Some(500));
 }
+
+#[test]
+fn test_provider_page_limits() {
+    let strava = ProviderPageLimits::for_provider(oauth_providers::STRAVA);
+    assert_eq!(strava.default_page_size, 30);
+    assert_eq!(strava.max_page_size, 200);
+
+    let garmin = ProviderPageLimits::for_provider(oauth_providers::GARMIN);
+    assert_eq!(garmin.default_page_size, 20);
+    assert_eq!(garmin.max_page_size, 100);
+
+    let other = ProviderPageLimits::for_provider("synthetic");
+    assert_eq!(other.default_page_size, DEFAULT_PAGE_SIZE);
+    assert_eq!(other.max_page_size, MAX_PAGE_SIZE);
+}
+
+#[test]
+fn test_stream_config_clamps_to_provider_max() {
+    let garmin = StreamConfig::for_provider(oauth_providers::GARMIN);
+    assert_eq!(garmin.page_size, 20);
+    assert_eq!(garmin.with_requested_page_size(200).page_size, 100);
+    assert_eq!(garmin.with_requested_page_size(60).page_size, 60);
+
+    let strava = StreamConfig::for_provider(oauth_providers::STRAVA);
+    assert_eq!(strava.page_size, 30);
+    assert_eq!(strava.with_requested_page_size(200).page_size, 200);
+    assert_eq!(strava.with_requested_page_size(500).page_size, 200);
+    assert_eq!(strava.with_requested_page_size(1).page_size, MIN_PAGE_SIZE);
+}
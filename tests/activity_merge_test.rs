@@ -0,0 +1,168 @@
+// ABOUTME: Tests for merging split recordings of one workout into a canonical activity
+// ABOUTME: Validates summed totals, concatenated streams, adjacency rules, and undo
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::models::{
+    Activity, ActivityBuilder, ActivityMergeConfig, MergedActivity, SportType, TimeSeriesData,
+};
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 7, 0, 0).unwrap()
+}
+
+fn stream(timestamps: Vec<u32>, heart_rate: Vec<u32>) -> TimeSeriesData {
+    TimeSeriesData {
+        timestamps,
+        heart_rate: Some(heart_rate),
+        power: None,
+        cadence: None,
+        speed: None,
+        altitude: None,
+        temperature: None,
+        gps_coordinates: None,
+    }
+}
+
+/// First half: 30 minutes, 6 km at 140 bpm
+fn first_half() -> Activity {
+    ActivityBuilder::new(
+        "run-1",
+        "Morning Run",
+        SportType::Run,
+        start(),
+        1800,
+        "garmin",
+    )
+    .distance_meters(6000.0)
+    .calories(400)
+    .average_heart_rate(140)
+    .max_heart_rate(160)
+    .time_series_data(stream(vec![0, 600, 1200], vec![130, 140, 150]))
+    .build()
+}
+
+/// Second half: starts after a 2 minute pause, 20 minutes, 4 km at 155 bpm
+fn second_half() -> Activity {
+    ActivityBuilder::new(
+        "run-2",
+        "Morning Run (2)",
+        SportType::Run,
+        start() + Duration::seconds(1920),
+        1200,
+        "garmin",
+    )
+    .distance_meters(4000.0)
+    .calories(300)
+    .average_heart_rate(155)
+    .max_heart_rate(170)
+    .time_series_data(stream(vec![0, 600], vec![150, 165]))
+    .build()
+}
+
+#[test]
+fn test_adjacent_activities_merge_into_one() {
+    let merged =
+        MergedActivity::merge(first_half(), second_half(), &ActivityMergeConfig::default())
+            .unwrap();
+    let activity = &merged.activity;
+
+    assert_eq!(activity.id(), "run-1");
+    assert_eq!(activity.name(), "Morning Run");
+    assert_eq!(activity.start_date(), start());
+    assert_eq!(activity.duration_seconds(), 3000);
+    assert_eq!(activity.distance_meters(), Some(10_000.0));
+    assert_eq!(activity.calories(), Some(700));
+    assert_eq!(activity.max_heart_rate(), Some(170));
+    // (140 × 1800 + 155 × 1200) / 3000 = 146
+    assert_eq!(activity.average_heart_rate(), Some(146));
+
+    let streams = activity.time_series_data().unwrap();
+    assert_eq!(streams.timestamps, vec![0, 600, 1200, 1920, 2520]);
+    assert_eq!(
+        streams.heart_rate.as_deref(),
+        Some([130, 140, 150, 150, 165].as_slice())
+    );
+    assert!(streams.power.is_none());
+}
+
+#[test]
+fn test_merge_accepts_recordings_in_either_order() {
+    let merged =
+        MergedActivity::merge(second_half(), first_half(), &ActivityMergeConfig::default())
+            .unwrap();
+
+    assert_eq!(merged.activity.id(), "run-1");
+    assert_eq!(merged.activity.duration_seconds(), 3000);
+}
+
+#[test]
+fn test_undo_restores_original_recordings() {
+    let merged =
+        MergedActivity::merge(first_half(), second_half(), &ActivityMergeConfig::default())
+            .unwrap();
+    let (first, second) = merged.undo();
+
+    assert_eq!(first.id(), "run-1");
+    assert_eq!(first.distance_meters(), Some(6000.0));
+    assert_eq!(first.time_series_data().unwrap().timestamps.len(), 3);
+    assert_eq!(second.id(), "run-2");
+    assert_eq!(second.distance_meters(), Some(4000.0));
+    assert_eq!(second.time_series_data().unwrap().timestamps, vec![0, 600]);
+}
+
+#[test]
+fn test_merge_rejects_gap_beyond_window() {
+    let config = ActivityMergeConfig {
+        max_gap_seconds: 60,
+        ..ActivityMergeConfig::default()
+    };
+    assert!(MergedActivity::merge(first_half(), second_half(), &config).is_err());
+}
+
+#[test]
+fn test_merge_rejects_overlapping_recordings() {
+    let overlapping = ActivityBuilder::new(
+        "run-2",
+        "Morning Run (2)",
+        SportType::Run,
+        start() + Duration::seconds(900),
+        1200,
+        "garmin",
+    )
+    .build();
+    assert!(
+        MergedActivity::merge(first_half(), overlapping, &ActivityMergeConfig::default()).is_err()
+    );
+}
+
+#[test]
+fn test_merge_sport_rule_is_configurable() {
+    let ride = ActivityBuilder::new(
+        "ride-1",
+        "Ride",
+        SportType::Ride,
+        start() + Duration::seconds(1800),
+        600,
+        "garmin",
+    )
+    .build();
+
+    assert!(
+        MergedActivity::merge(first_half(), ride.clone(), &ActivityMergeConfig::default()).is_err()
+    );
+
+    let config = ActivityMergeConfig {
+        require_same_sport: false,
+        ..ActivityMergeConfig::default()
+    };
+    let merged = MergedActivity::merge(first_half(), ride, &config).unwrap();
+    assert_eq!(merged.activity.sport_type(), &SportType::Run);
+    // Ride has no stream, so the merged activity cannot carry a partial one
+    assert!(merged.activity.time_series_data().is_none());
+}
@@ -0,0 +1,180 @@
+// ABOUTME: Tests for detecting paused segments and deriving moving time from speed streams
+// ABOUTME: Verifies moving time excludes stationary gaps while elapsed time keeps them, and provider fallbacks
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::Utc;
+use pierre_mcp_server::models::{
+    Activity, ActivityBuilder, ActivityPause, PauseDetectionConfig, SportType, TimeSeriesData,
+};
+
+fn speed_stream(samples: &[(u32, f32)]) -> TimeSeriesData {
+    TimeSeriesData {
+        timestamps: samples.iter().map(|(t, _)| *t).collect(),
+        heart_rate: None,
+        power: None,
+        cadence: None,
+        speed: Some(samples.iter().map(|(_, s)| *s).collect()),
+        altitude: None,
+        temperature: None,
+        gps_coordinates: None,
+    }
+}
+
+/// 10 minutes running, 5 minutes stood still, 10 more minutes running (1 Hz samples)
+fn run_with_stationary_gap() -> TimeSeriesData {
+    let samples: Vec<(u32, f32)> = (0..=1500)
+        .map(|t| {
+            let speed = if (600..=900).contains(&t) { 0.0 } else { 3.0 };
+            (t, speed)
+        })
+        .collect();
+    speed_stream(&samples)
+}
+
+fn run(elapsed_seconds: u64, stream: Option<TimeSeriesData>) -> Activity {
+    ActivityBuilder::new(
+        "run-1",
+        "Interrupted Run",
+        SportType::Run,
+        Utc::now(),
+        elapsed_seconds,
+        "garmin",
+    )
+    .distance_meters(3600.0)
+    .time_series_data_opt(stream)
+    .build()
+}
+
+#[test]
+fn test_stationary_gap_is_excluded_from_moving_time() {
+    let activity = run(1500, Some(run_with_stationary_gap()));
+
+    assert_eq!(activity.elapsed_time_seconds(), 1500);
+    assert_eq!(activity.duration_seconds(), 1500);
+    assert_eq!(activity.moving_time_seconds(), 1200);
+}
+
+#[test]
+fn test_detect_pauses_reports_stationary_segment() {
+    let pauses = PauseDetectionConfig::default()
+        .detect_pauses(&run_with_stationary_gap())
+        .unwrap();
+
+    assert_eq!(
+        pauses,
+        vec![ActivityPause {
+            start_offset: 600,
+            end_offset: 900,
+        }]
+    );
+    assert_eq!(pauses[0].duration_seconds(), 300);
+}
+
+#[test]
+fn test_short_stops_count_as_moving() {
+    // A 2 second stop at a crossing is shorter than the minimum pause
+    let samples: Vec<(u32, f32)> = (0..=120)
+        .map(|t| (t, if (60..63).contains(&t) { 0.0 } else { 3.0 }))
+        .collect();
+    let stream = speed_stream(&samples);
+
+    assert!(PauseDetectionConfig::default()
+        .detect_pauses(&stream)
+        .unwrap()
+        .is_empty());
+    assert_eq!(run(120, Some(stream)).moving_time_seconds(), 120);
+}
+
+#[test]
+fn test_stops_split_by_brief_movement_are_merged() {
+    // Two 8 second stops separated by 3 seconds of shuffling forward
+    let samples: Vec<(u32, f32)> = (0..=60)
+        .map(|t| {
+            let stopped = (20..=28).contains(&t) || (31..=39).contains(&t);
+            (t, if stopped { 0.0 } else { 3.0 })
+        })
+        .collect();
+
+    let pauses = PauseDetectionConfig::default()
+        .detect_pauses(&speed_stream(&samples))
+        .unwrap();
+
+    assert_eq!(
+        pauses,
+        vec![ActivityPause {
+            start_offset: 20,
+            end_offset: 39,
+        }]
+    );
+}
+
+#[test]
+fn test_recording_gap_is_a_pause() {
+    // Auto-pause: the device stops sampling for 5 minutes
+    let stream = speed_stream(&[(0, 3.0), (300, 3.0), (600, 3.0), (601, 3.0), (900, 3.0)]);
+    let config = PauseDetectionConfig {
+        max_sample_gap_seconds: 400,
+        ..PauseDetectionConfig::default()
+    };
+
+    assert_eq!(config.moving_time_seconds(&stream), Some(900));
+
+    let auto_paused = speed_stream(&[(0, 3.0), (1, 3.0), (301, 3.0), (302, 3.0)]);
+    assert_eq!(
+        PauseDetectionConfig::default().moving_time_seconds(&auto_paused),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_provider_moving_time_used_without_speed_stream() {
+    let activity = ActivityBuilder::new(
+        "run-2",
+        "Strava Run",
+        SportType::Run,
+        Utc::now(),
+        2000,
+        "strava",
+    )
+    .moving_time_seconds(1700)
+    .build();
+
+    assert_eq!(activity.elapsed_time_seconds(), 2000);
+    assert_eq!(activity.moving_time_seconds(), 1700);
+
+    // Without either source moving time is the elapsed time
+    assert_eq!(run(2000, None).moving_time_seconds(), 2000);
+}
+
+#[test]
+fn test_speed_stream_overrides_provider_moving_time() {
+    let activity = ActivityBuilder::new(
+        "run-3",
+        "Garmin Run",
+        SportType::Run,
+        Utc::now(),
+        1500,
+        "garmin",
+    )
+    .moving_time_seconds(1500)
+    .time_series_data(run_with_stationary_gap())
+    .build();
+
+    assert_eq!(activity.moving_time_seconds(), 1200);
+}
+
+#[test]
+fn test_moving_time_is_serialized_alongside_elapsed_time() {
+    let json = serde_json::to_value(run(1500, Some(run_with_stationary_gap()))).unwrap();
+
+    assert_eq!(json["duration_seconds"], 1500);
+    assert_eq!(json["moving_time_seconds"], 1200);
+
+    let restored: Activity = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.moving_time_seconds(), 1200);
+}
@@ -0,0 +1,146 @@
+// ABOUTME: Tests for the `fields` projection on activity tools
+// ABOUTME: Covers exact key selection, payload reduction, aliases, and invalid field errors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use pierre_mcp_server::{
+    errors::ErrorCode,
+    models::{Activity, ActivityBuilder, ActivityProjection, SportType, ACTIVITY_FIELDS},
+    tools::{
+        implementations::data::GetActivitiesTool, traits::McpTool, AuthMethod, ToolExecutionContext,
+    },
+};
+use serde_json::{json, Value};
+
+fn activity() -> Activity {
+    ActivityBuilder::new(
+        "act-1",
+        "Morning Run",
+        SportType::Run,
+        Utc::now(),
+        2400,
+        "strava",
+    )
+    .distance_meters(8_000.0)
+    .average_heart_rate(152)
+    .calories(540)
+    .city("Montreal".to_owned())
+    .build()
+}
+
+fn keys(value: &Value) -> Vec<&str> {
+    value
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect()
+}
+
+#[test]
+fn test_id_and_distance_returns_exactly_those_keys() {
+    let projection = ActivityProjection::new(["id", "distance"]).unwrap();
+
+    let projected = projection.project(&activity()).unwrap();
+
+    let mut projected_keys = keys(&projected);
+    projected_keys.sort_unstable();
+    assert_eq!(projected_keys, ["distance", "id"]);
+    assert_eq!(projected["id"], "act-1");
+    assert_eq!(projected["distance"], 8_000.0);
+}
+
+#[test]
+fn test_projection_reduces_payload() {
+    let activities = vec![activity(), activity(), activity()];
+    let projection = ActivityProjection::new(["id", "distance"]).unwrap();
+
+    let full = serde_json::to_string(&activities).unwrap();
+    let projected = serde_json::to_string(&projection.project_all(&activities).unwrap()).unwrap();
+
+    assert!(
+        projected.len() * 3 < full.len(),
+        "projected {} bytes vs full {} bytes",
+        projected.len(),
+        full.len()
+    );
+}
+
+#[test]
+fn test_serialized_names_and_missing_values() {
+    let projection = ActivityProjection::new(["distance_meters", "max_power"]).unwrap();
+
+    let projected = projection.project(&activity()).unwrap();
+
+    assert_eq!(
+        projected,
+        json!({"distance_meters": 8_000.0, "max_power": null})
+    );
+}
+
+#[test]
+fn test_every_serialized_activity_field_is_valid() {
+    let full = serde_json::to_value(activity()).unwrap();
+    for key in keys(&full) {
+        assert!(ACTIVITY_FIELDS.contains(&key), "{key} is not projectable");
+    }
+    assert!(ActivityProjection::new(ACTIVITY_FIELDS).is_ok());
+}
+
+#[test]
+fn test_unknown_field_lists_valid_fields() {
+    let error = ActivityProjection::new(["id", "pace"]).unwrap_err();
+
+    assert_eq!(error.code, ErrorCode::InvalidInput);
+    assert!(error.message.contains("'fields'"));
+    assert!(error.message.contains("pace"));
+    assert!(error.message.contains("distance_meters"));
+}
+
+#[test]
+fn test_from_param() {
+    assert!(ActivityProjection::from_param(None).unwrap().is_none());
+    assert!(ActivityProjection::from_param(Some(&Value::Null))
+        .unwrap()
+        .is_none());
+
+    let projection = ActivityProjection::from_param(Some(&json!(["name", "duration"])))
+        .unwrap()
+        .unwrap();
+    assert_eq!(projection.keys().collect::<Vec<_>>(), ["name", "duration"]);
+
+    assert!(ActivityProjection::from_param(Some(&json!("id,distance"))).is_err());
+    assert!(ActivityProjection::from_param(Some(&json!([]))).is_err());
+}
+
+#[tokio::test]
+async fn test_get_activities_rejects_unknown_fields() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer);
+
+    let result = GetActivitiesTool
+        .execute(json!({"fields": ["id", "pace"]}), &context)
+        .await
+        .unwrap();
+
+    assert!(result.is_error);
+    assert_eq!(result.content["error_type"], "invalid_field");
+    let valid: Vec<&str> = result.content["valid_fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    assert!(valid.contains(&"id"));
+    assert!(valid.contains(&"distance"));
+}
@@ -0,0 +1,199 @@
+// ABOUTME: Tests for weekly and monthly activity rollup aggregation queries
+// ABOUTME: Verifies month-boundary bucketing, UTC normalization of offsets, and empty buckets
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{DateTime, TimeZone, Utc};
+use pierre_mcp_server::database::{Granularity, RollupBucket};
+use pierre_mcp_server::database_plugins::{factory::Database, DatabaseProvider};
+use uuid::Uuid;
+
+struct TestActivity {
+    start_date: &'static str,
+    duration_seconds: i64,
+    distance_meters: f64,
+    average_heart_rate: Option<i64>,
+}
+
+async fn insert_activity(database: &Database, user_id: Uuid, activity: &TestActivity) {
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+    sqlx::query(
+        r"
+        INSERT INTO synthetic_activities (
+            id, user_id, tenant_id, name, sport_type, start_date,
+            duration_seconds, distance_meters, elevation_gain, average_heart_rate
+        ) VALUES (?, ?, ?, 'Run', 'run', ?, ?, ?, 10.0, ?)
+        ",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id.to_string())
+    .bind(tenant_id.to_string())
+    .bind(activity.start_date)
+    .bind(activity.duration_seconds)
+    .bind(activity.distance_meters)
+    .bind(activity.average_heart_rate)
+    .execute(database.sqlite_pool().unwrap())
+    .await
+    .unwrap();
+}
+
+fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+}
+
+/// Activities straddling the January/February boundary, two of them recorded with non-UTC offsets
+async fn seed_activities(database: &Database, user_id: Uuid) {
+    for activity in [
+        TestActivity {
+            start_date: "2025-01-30T10:00:00+00:00",
+            duration_seconds: 1800,
+            distance_meters: 5000.0,
+            average_heart_rate: Some(140),
+        },
+        // Local February 1st in Moscow is still January 31st in UTC
+        TestActivity {
+            start_date: "2025-02-01T01:00:00+03:00",
+            duration_seconds: 3600,
+            distance_meters: 10_000.0,
+            average_heart_rate: Some(150),
+        },
+        // Local January 31st in New York is already February 1st in UTC
+        TestActivity {
+            start_date: "2025-01-31T23:30:00-05:00",
+            duration_seconds: 2400,
+            distance_meters: 8000.0,
+            average_heart_rate: Some(160),
+        },
+        TestActivity {
+            start_date: "2025-02-03T07:00:00+00:00",
+            duration_seconds: 1200,
+            distance_meters: 4000.0,
+            average_heart_rate: None,
+        },
+        TestActivity {
+            start_date: "2025-03-15T08:00:00+00:00",
+            duration_seconds: 3000,
+            distance_meters: 9000.0,
+            average_heart_rate: Some(145),
+        },
+        // Outside every queried range
+        TestActivity {
+            start_date: "2025-05-02T08:00:00+00:00",
+            duration_seconds: 3000,
+            distance_meters: 9000.0,
+            average_heart_rate: Some(145),
+        },
+    ] {
+        insert_activity(database, user_id, &activity).await;
+    }
+}
+
+#[tokio::test]
+#[allow(clippy::float_cmp)] // Sums of exact literal float values
+async fn test_monthly_rollups_bucket_across_month_boundaries_in_utc() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let (other_user_id, _) = common::create_test_user_with_email(&database, "other@example.com")
+        .await
+        .unwrap();
+    seed_activities(&database, user_id).await;
+    seed_activities(&database, other_user_id).await;
+
+    let buckets = database
+        .get_activity_rollups(
+            user_id,
+            Granularity::Month,
+            utc(2025, 1, 1),
+            utc(2025, 5, 1),
+        )
+        .await
+        .unwrap();
+
+    let starts: Vec<_> = buckets.iter().map(|b| b.bucket_start).collect();
+    assert_eq!(
+        starts,
+        [
+            utc(2025, 1, 1),
+            utc(2025, 2, 1),
+            utc(2025, 3, 1),
+            utc(2025, 4, 1)
+        ]
+    );
+
+    assert_eq!(
+        buckets[0],
+        RollupBucket {
+            bucket_start: utc(2025, 1, 1),
+            activity_count: 2,
+            total_distance_meters: 15_000.0,
+            total_duration_seconds: 5400,
+            total_elevation_gain_meters: 20.0,
+            average_heart_rate: Some(145.0),
+        }
+    );
+
+    // Activities without heart rate do not drag the average down
+    assert_eq!(buckets[1].activity_count, 2);
+    assert_eq!(buckets[1].total_distance_meters, 12_000.0);
+    assert_eq!(buckets[1].average_heart_rate, Some(160.0));
+
+    assert_eq!(buckets[2].activity_count, 1);
+
+    // Months without activity still appear as empty rows
+    assert_eq!(
+        buckets[3],
+        RollupBucket {
+            bucket_start: utc(2025, 4, 1),
+            activity_count: 0,
+            total_distance_meters: 0.0,
+            total_duration_seconds: 0,
+            total_elevation_gain_meters: 0.0,
+            average_heart_rate: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_weekly_rollups_start_on_monday_and_fill_gaps() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    seed_activities(&database, user_id).await;
+
+    // The range starts mid-week: its first bucket is that week's Monday, but
+    // activities before the range start are excluded
+    let buckets = database
+        .get_activity_rollups(
+            user_id,
+            Granularity::Week,
+            utc(2025, 1, 31),
+            utc(2025, 2, 17),
+        )
+        .await
+        .unwrap();
+
+    let summary: Vec<_> = buckets
+        .iter()
+        .map(|b| (b.bucket_start, b.activity_count))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (utc(2025, 1, 27), 2),
+            (utc(2025, 2, 3), 1),
+            (utc(2025, 2, 10), 0)
+        ]
+    );
+    assert_eq!(buckets[0].total_duration_seconds, 6000);
+
+    // An empty range is rejected
+    let result = database
+        .get_activity_rollups(user_id, Granularity::Week, utc(2025, 2, 1), utc(2025, 2, 1))
+        .await;
+    assert!(result.is_err());
+}
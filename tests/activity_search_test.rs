@@ -0,0 +1,277 @@
+// ABOUTME: Tests for ActivityFilter evaluation and the search_activities provider scan
+// ABOUTME: Covers combined filters, empty results, sort keys, validation, and bounded scanning
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::models::{
+    Activity, ActivityBuilder, ActivityFilter, ActivitySortKey, SportType,
+};
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use pierre_mcp_server::tools::implementations::data::scan_activities;
+
+fn day(n: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 9, 1, 7, 0, 0).unwrap() + Duration::days(n)
+}
+
+fn activity(
+    id: &str,
+    name: &str,
+    sport: SportType,
+    start: DateTime<Utc>,
+    km: f64,
+    minutes: u64,
+    elevation: f64,
+) -> Activity {
+    ActivityBuilder::new(id, name, sport, start, minutes * 60, "synthetic")
+        .distance_meters(km * 1000.0)
+        .elevation_gain(elevation)
+        .build()
+}
+
+/// A month of mixed training: long mountain runs, flat runs, rides, and a yoga session
+fn training_month() -> Vec<Activity> {
+    vec![
+        activity(
+            "1",
+            "Mountain Long Run",
+            SportType::Run,
+            day(2),
+            24.0,
+            150,
+            1200.0,
+        ),
+        activity("2", "Easy Run", SportType::Run, day(4), 8.0, 45, 40.0),
+        activity(
+            "3",
+            "Alpine Loop",
+            SportType::Ride,
+            day(6),
+            80.0,
+            200,
+            1500.0,
+        ),
+        activity(
+            "4",
+            "Long Run by the River",
+            SportType::Run,
+            day(9),
+            21.0,
+            120,
+            60.0,
+        ),
+        activity(
+            "5",
+            "mountain trail long run",
+            SportType::Run,
+            day(16),
+            28.0,
+            190,
+            1600.0,
+        ),
+        activity("6", "Tempo Run", SportType::Run, day(20), 12.0, 55, 90.0),
+        ActivityBuilder::new(
+            "7",
+            "Morning Yoga",
+            SportType::Yoga,
+            day(21),
+            3600,
+            "synthetic",
+        )
+        .build(),
+        activity(
+            "8",
+            "Mountain Long Run",
+            SportType::Run,
+            day(40),
+            26.0,
+            170,
+            1400.0,
+        ),
+    ]
+}
+
+fn ids(activities: &[Activity]) -> Vec<&str> {
+    activities.iter().map(Activity::id).collect()
+}
+
+#[test]
+fn test_combined_filters_select_long_mountain_runs_last_month() {
+    let filter = ActivityFilter {
+        sport_type: Some("RUN".to_owned()),
+        min_distance_meters: Some(20_000.0),
+        after: Some(day(0)),
+        before: Some(day(30)),
+        name_contains: Some("Mountain".to_owned()),
+        min_elevation_gain: Some(1000.0),
+        ..ActivityFilter::default()
+    };
+    filter.validate().unwrap();
+
+    // The river run is long but flat, the ride is not a run, and activity 8 is outside the range
+    assert_eq!(ids(&filter.apply(training_month())), ["1", "5"]);
+}
+
+#[test]
+fn test_distance_and_duration_bounds_are_inclusive() {
+    let filter = ActivityFilter {
+        min_distance_meters: Some(8_000.0),
+        max_distance_meters: Some(12_000.0),
+        min_duration_seconds: Some(45 * 60),
+        max_duration_seconds: Some(55 * 60),
+        ..ActivityFilter::default()
+    };
+
+    assert_eq!(ids(&filter.apply(training_month())), ["2", "6"]);
+}
+
+#[test]
+fn test_activities_without_a_value_do_not_match_its_bound() {
+    let filter = ActivityFilter {
+        max_distance_meters: Some(50_000.0),
+        ..ActivityFilter::default()
+    };
+
+    let matched = filter.apply(training_month());
+    assert!(!ids(&matched).contains(&"7"), "yoga has no distance");
+    assert!(ActivityFilter::default()
+        .apply(training_month())
+        .iter()
+        .any(|a| a.id() == "7"));
+}
+
+#[test]
+fn test_filters_with_no_matches_return_empty() {
+    let filter = ActivityFilter {
+        sport_type: Some("swim".to_owned()),
+        ..ActivityFilter::default()
+    };
+    assert!(filter.apply(training_month()).is_empty());
+
+    let filter = ActivityFilter {
+        sport_type: Some("run".to_owned()),
+        min_distance_meters: Some(100_000.0),
+        ..ActivityFilter::default()
+    };
+    assert!(filter.apply(training_month()).is_empty());
+    assert!(filter.apply(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_invalid_bounds_are_rejected() {
+    let inverted = ActivityFilter {
+        min_distance_meters: Some(10_000.0),
+        max_distance_meters: Some(5_000.0),
+        ..ActivityFilter::default()
+    };
+    assert!(inverted.validate().is_err());
+
+    let negative = ActivityFilter {
+        min_elevation_gain: Some(-1.0),
+        ..ActivityFilter::default()
+    };
+    assert!(negative.validate().is_err());
+
+    let empty_range = ActivityFilter {
+        after: Some(day(5)),
+        before: Some(day(5)),
+        ..ActivityFilter::default()
+    };
+    assert!(empty_range.validate().is_err());
+
+    assert!(ActivityFilter::default().is_empty());
+    ActivityFilter::default().validate().unwrap();
+}
+
+#[test]
+fn test_sort_keys_order_results() {
+    let mut activities = training_month();
+
+    ActivitySortKey::Distance.sort(&mut activities, true);
+    // Yoga has no distance and stays last
+    assert_eq!(ids(&activities), ["3", "5", "8", "1", "4", "6", "2", "7"]);
+
+    ActivitySortKey::ElevationGain.sort(&mut activities, false);
+    assert_eq!(ids(&activities)[..3], ["2", "4", "6"]);
+    assert_eq!(ids(&activities)[7], "7");
+
+    ActivitySortKey::Date.sort(&mut activities, true);
+    assert_eq!(ids(&activities)[0], "8");
+
+    ActivitySortKey::Name.sort(&mut activities, false);
+    assert_eq!(activities[0].name(), "Alpine Loop");
+
+    assert_eq!(
+        ActivitySortKey::parse("elevation").unwrap(),
+        ActivitySortKey::ElevationGain
+    );
+    assert!(ActivitySortKey::parse("pace").is_err());
+}
+
+#[tokio::test]
+async fn test_scan_pushes_date_range_and_filters_the_rest() {
+    let provider = SyntheticProvider::with_activities(training_month());
+    let filter = ActivityFilter {
+        sport_type: Some("run".to_owned()),
+        min_elevation_gain: Some(1000.0),
+        after: Some(day(0)),
+        before: Some(day(30)),
+        ..ActivityFilter::default()
+    };
+
+    let scan = scan_activities(&provider, &filter, 500).await.unwrap();
+
+    // Activity 8 is excluded by the provider, so only 7 activities are scanned
+    assert_eq!(scan.scanned, 7);
+    assert!(!scan.truncated);
+    let mut found = ids(&scan.matches);
+    found.sort_unstable();
+    assert_eq!(found, ["1", "5"]);
+}
+
+#[tokio::test]
+async fn test_scan_with_no_matches_returns_empty() {
+    let provider = SyntheticProvider::with_activities(training_month());
+    let filter = ActivityFilter {
+        name_contains: Some("marathon".to_owned()),
+        ..ActivityFilter::default()
+    };
+
+    let scan = scan_activities(&provider, &filter, 500).await.unwrap();
+
+    assert!(scan.matches.is_empty());
+    assert_eq!(scan.scanned, 8);
+    assert!(!scan.truncated);
+}
+
+#[tokio::test]
+async fn test_scan_stops_at_limit() {
+    let runs: Vec<Activity> = (0..250)
+        .map(|n| {
+            activity(
+                &format!("run-{n}"),
+                "Daily Run",
+                SportType::Run,
+                day(-n),
+                10.0,
+                50,
+                50.0,
+            )
+        })
+        .collect();
+    let provider = SyntheticProvider::with_activities(runs);
+
+    let scan = scan_activities(&provider, &ActivityFilter::default(), 150)
+        .await
+        .unwrap();
+
+    assert_eq!(scan.scanned, 150);
+    assert_eq!(scan.matches.len(), 150);
+    assert!(scan.truncated);
+    // Most recent activities are scanned first
+    assert_eq!(scan.matches[0].id(), "run-0");
+}
@@ -0,0 +1,191 @@
+// ABOUTME: Tests for activity splits from provider laps and computed from activity streams
+// ABOUTME: Covers Strava split and lap mapping plus per-km splits from GPS and speed streams with GPS gaps
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use pierre_mcp_server::intelligence::SplitCalculator;
+use pierre_mcp_server::models::{SplitUnit, TimeSeriesData};
+use pierre_mcp_server::providers::strava_provider::{DetailedActivityResponse, StravaProvider};
+
+/// GET /activities/{id} for a track workout recorded without GPS, so Strava reports laps only
+const TRACK_WORKOUT_JSON: &str = r#"{
+    "id": 99887766554,
+    "resource_state": 3,
+    "name": "Track 3x1600",
+    "type": "Run",
+    "start_date": "2024-07-09T12:00:00Z",
+    "distance": 4800.0,
+    "elapsed_time": 1230,
+    "laps": [
+        {"id": 1, "elapsed_time": 400, "moving_time": 400, "distance": 1600.0, "total_elevation_gain": 0.0, "average_speed": 4.0, "average_heartrate": 162.5, "max_heartrate": 170.0},
+        {"id": 2, "elapsed_time": 420, "moving_time": 410, "distance": 1600.0, "total_elevation_gain": 0.0, "average_speed": 3.9, "average_heartrate": 166.0, "max_heartrate": 174.0},
+        {"id": 3, "elapsed_time": 410, "moving_time": 410, "distance": 1600.0, "total_elevation_gain": 0.0, "average_speed": 3.9, "average_heartrate": 168.2, "max_heartrate": 178.0}
+    ]
+}"#;
+
+/// GET /activities/{id} with Strava's per-km splits alongside the auto-lap
+const SPLITS_JSON: &str = r#"{
+    "id": 99887766555,
+    "resource_state": 3,
+    "name": "Easy Run",
+    "type": "Run",
+    "start_date": "2024-07-10T12:00:00Z",
+    "distance": 2400.0,
+    "elapsed_time": 780,
+    "splits_metric": [
+        {"distance": 1000.4, "elapsed_time": 320, "elevation_difference": 3.2, "moving_time": 318, "split": 1, "average_speed": 3.14, "average_heartrate": 141.0, "pace_zone": 2},
+        {"distance": 999.8, "elapsed_time": 330, "elevation_difference": -1.4, "moving_time": 330, "split": 2, "average_speed": 3.03, "average_heartrate": 145.5, "pace_zone": 2},
+        {"distance": 399.8, "elapsed_time": 130, "elevation_difference": 0.0, "moving_time": 130, "split": 3, "average_speed": 3.08, "average_heartrate": 147.0, "pace_zone": 2}
+    ],
+    "laps": [
+        {"id": 7, "elapsed_time": 780, "moving_time": 778, "distance": 2400.0}
+    ]
+}"#;
+
+/// Meters per degree of latitude on the calculator's spherical Earth
+const METERS_PER_DEGREE_LAT: f64 = 6_371_000.0 * std::f64::consts::PI / 180.0;
+
+/// Steady 4 m/s run due north, one sample per second, for `distance_meters`
+fn steady_run(distance_meters: u32) -> TimeSeriesData {
+    let samples = distance_meters / 4 + 1;
+    let timestamps: Vec<u32> = (0..samples).collect();
+    TimeSeriesData {
+        gps_coordinates: Some(
+            timestamps
+                .iter()
+                .map(|t| (45.0 + f64::from(t * 4) / METERS_PER_DEGREE_LAT, 7.0))
+                .collect(),
+        ),
+        speed: Some(vec![4.0; timestamps.len()]),
+        heart_rate: Some(timestamps.iter().map(|t| 140 + t / 100).collect()),
+        // Climbs one meter every 50 seconds
+        altitude: Some(
+            timestamps
+                .iter()
+                .map(|t| 200.0 + f32::from(u16::try_from(t / 50).unwrap()))
+                .collect(),
+        ),
+        power: None,
+        cadence: None,
+        temperature: None,
+        timestamps,
+    }
+}
+
+#[test]
+fn test_strava_laps_used_when_no_unit_splits() {
+    let detailed: DetailedActivityResponse = serde_json::from_str(TRACK_WORKOUT_JSON).unwrap();
+    let splits = StravaProvider::convert_splits(detailed, SplitUnit::Km);
+
+    assert_eq!(splits.len(), 3);
+    assert_eq!(
+        splits.iter().map(|s| s.index).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    // Pace is over moving time: 410 s for 1.6 km is 256.25 s/km
+    let second = &splits[1];
+    assert!((second.distance_meters - 1600.0).abs() < 0.01);
+    assert!((second.elapsed_time_seconds - 420.0).abs() < f64::EPSILON);
+    assert!((second.pace_seconds_per_unit.unwrap() - 256.25).abs() < 0.01);
+    assert!((second.average_heart_rate.unwrap() - 166.0).abs() < 0.01);
+    assert_eq!(second.max_heart_rate, Some(174));
+    assert!(splits.iter().all(|s| !s.partial));
+
+    // The same laps paced per mile
+    let miles = StravaProvider::convert_splits(
+        serde_json::from_str(TRACK_WORKOUT_JSON).unwrap(),
+        SplitUnit::Mile,
+    );
+    assert!((miles[0].pace_seconds_per_unit.unwrap() - 400.0 / 1600.0 * 1609.344).abs() < 0.01);
+}
+
+#[test]
+fn test_strava_unit_splits_preferred_over_laps() {
+    let detailed: DetailedActivityResponse = serde_json::from_str(SPLITS_JSON).unwrap();
+    let splits = StravaProvider::convert_splits(detailed, SplitUnit::Km);
+
+    assert_eq!(splits.len(), 3);
+    assert!(!splits[0].partial);
+    assert!(!splits[1].partial);
+    assert!(splits[2].partial);
+    assert_eq!(splits[0].elevation_change_meters, Some(f64::from(3.2_f32)));
+    assert!((splits[1].average_heart_rate.unwrap() - 145.5).abs() < 0.01);
+
+    // No imperial splits were recorded, so miles fall back to the single lap
+    let miles =
+        StravaProvider::convert_splits(serde_json::from_str(SPLITS_JSON).unwrap(), SplitUnit::Mile);
+    assert_eq!(miles.len(), 1);
+    assert!((miles[0].distance_meters - 2400.0).abs() < 0.01);
+}
+
+#[test]
+fn test_km_splits_computed_from_gps_stream() {
+    let splits = SplitCalculator::new(SplitUnit::Km).compute(&steady_run(3500));
+
+    assert_eq!(splits.len(), 4);
+    for split in &splits[..3] {
+        assert!(!split.partial);
+        assert!((split.distance_meters - 1000.0).abs() < 0.01);
+        // 4 m/s is 250 s/km
+        assert!((split.elapsed_time_seconds - 250.0).abs() < 0.1);
+        assert!((split.pace_seconds_per_unit.unwrap() - 250.0).abs() < 0.1);
+        assert!(split.average_heart_rate.is_some());
+        assert!((split.elevation_gain_meters.unwrap() - 5.0).abs() <= 1.0);
+    }
+
+    let last = &splits[3];
+    assert!(last.partial);
+    assert!((last.distance_meters - 500.0).abs() < 0.5);
+    assert!((last.pace_seconds_per_unit.unwrap() - 250.0).abs() < 0.5);
+
+    // Heart rate rises through the run
+    assert!(splits[3].average_heart_rate.unwrap() > splits[0].average_heart_rate.unwrap());
+    assert_eq!(splits[0].max_heart_rate, Some(142));
+}
+
+#[test]
+fn test_gps_gap_is_bridged() {
+    let mut series = steady_run(3000);
+    // Signal lost for two minutes around the first kilometer mark
+    let coordinates = series.gps_coordinates.as_mut().unwrap();
+    for point in &mut coordinates[200..320] {
+        *point = (0.0, 0.0);
+    }
+
+    let splits = SplitCalculator::new(SplitUnit::Km).compute(&series);
+
+    assert_eq!(splits.len(), 3);
+    for split in &splits {
+        assert!(!split.partial);
+        assert!((split.elapsed_time_seconds - 250.0).abs() < 0.1);
+    }
+}
+
+#[test]
+fn test_speed_stream_used_without_gps() {
+    let mut series = steady_run(2000);
+    series.gps_coordinates = None;
+
+    let splits = SplitCalculator::new(SplitUnit::Km).compute(&series);
+
+    assert_eq!(splits.len(), 2);
+    assert!((splits[0].pace_seconds_per_unit.unwrap() - 250.0).abs() < 0.1);
+
+    series.speed = None;
+    assert!(SplitCalculator::new(SplitUnit::Km)
+        .compute(&series)
+        .is_empty());
+}
+
+#[test]
+fn test_split_unit_parsing() {
+    assert_eq!(SplitUnit::parse("KM"), Some(SplitUnit::Km));
+    assert_eq!(SplitUnit::parse("miles"), Some(SplitUnit::Mile));
+    assert_eq!(SplitUnit::parse("furlong"), None);
+    assert_eq!(SplitUnit::default(), SplitUnit::Km);
+}
@@ -0,0 +1,129 @@
+// ABOUTME: Tests for time-zone-aware activity start times and local time-of-day classification
+// ABOUTME: Covers the Activity timezone fields, Strava timezone parsing, and analyzer time-of-day buckets
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Timelike, Utc};
+use pierre_mcp_server::intelligence::{ActivityAnalyzer, TimeOfDay};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::providers::core::ProviderValidationMode;
+use pierre_mcp_server::providers::strava_provider::{DetailedActivityResponse, StravaProvider};
+
+const TOKYO_OFFSET_SECONDS: i32 = 9 * 3600;
+
+/// 07:00 in Tokyo, which is 22:00 UTC on the previous day
+fn tokyo_morning_run() -> Activity {
+    ActivityBuilder::new(
+        "tz-1",
+        "Morning Run",
+        SportType::Run,
+        Utc.with_ymd_and_hms(2025, 3, 31, 22, 0, 0).unwrap(),
+        2400,
+        "strava",
+    )
+    .distance_meters(8000.0)
+    .average_heart_rate(148)
+    .start_timezone("Asia/Tokyo".to_owned())
+    .utc_offset_seconds(TOKYO_OFFSET_SECONDS)
+    .build()
+}
+
+#[test]
+fn test_local_start_date_applies_utc_offset() {
+    let activity = tokyo_morning_run();
+
+    let local = activity.local_start_date().unwrap();
+    assert_eq!(local.hour(), 7);
+    assert_eq!(local.offset().local_minus_utc(), TOKYO_OFFSET_SECONDS);
+    assert_eq!(activity.start_timezone(), Some("Asia/Tokyo"));
+    assert_eq!(activity.start_date().hour(), 22);
+}
+
+#[test]
+fn test_local_start_date_is_none_without_offset() {
+    let activity = ActivityBuilder::new("tz-2", "Run", SportType::Run, Utc::now(), 1800, "strava")
+        .start_timezone("Asia/Tokyo".to_owned())
+        .build();
+
+    assert!(activity.local_start_date().is_none());
+}
+
+#[test]
+fn test_morning_in_plus_nine_zone_is_classified_morning() {
+    let intelligence = ActivityAnalyzer::new()
+        .analyze_activity(&tokyo_morning_run(), None)
+        .unwrap();
+
+    let time_of_day = intelligence.contextual_factors.time_of_day;
+    assert!(
+        matches!(time_of_day, TimeOfDay::Morning),
+        "expected Morning, got {time_of_day:?}"
+    );
+}
+
+#[test]
+fn test_evening_in_negative_offset_zone_is_classified_evening() {
+    // 18:30 in Denver (UTC-7) is 01:30 UTC the next day
+    let activity = ActivityBuilder::new(
+        "tz-3",
+        "Evening Ride",
+        SportType::Ride,
+        Utc.with_ymd_and_hms(2025, 6, 2, 1, 30, 0).unwrap(),
+        3600,
+        "garmin",
+    )
+    .utc_offset_seconds(-7 * 3600)
+    .build();
+
+    let intelligence = ActivityAnalyzer::new()
+        .analyze_activity(&activity, None)
+        .unwrap();
+
+    let time_of_day = intelligence.contextual_factors.time_of_day;
+    assert!(
+        matches!(time_of_day, TimeOfDay::Evening),
+        "expected Evening, got {time_of_day:?}"
+    );
+}
+
+#[test]
+fn test_strava_timezone_and_offset_are_populated() {
+    let detailed: DetailedActivityResponse = serde_json::from_str(
+        r#"{
+            "id": 13579,
+            "name": "Imperial Palace Loop",
+            "type": "Run",
+            "start_date": "2025-03-31T22:00:00Z",
+            "timezone": "(GMT+09:00) Asia/Tokyo",
+            "utc_offset": 32400.0,
+            "elapsed_time": 2400,
+            "distance": 8000.0
+        }"#,
+    )
+    .unwrap();
+
+    let activity =
+        StravaProvider::convert_detailed_strava_activity(detailed, ProviderValidationMode::Lenient)
+            .unwrap();
+
+    assert_eq!(activity.start_timezone(), Some("Asia/Tokyo"));
+    assert_eq!(activity.utc_offset_seconds(), Some(TOKYO_OFFSET_SECONDS));
+    assert_eq!(activity.local_start_date().unwrap().hour(), 7);
+}
+
+#[test]
+fn test_timezone_fields_round_trip_through_json() {
+    let activity = tokyo_morning_run();
+
+    let json = serde_json::to_value(&activity).unwrap();
+    assert_eq!(json["start_timezone"], "Asia/Tokyo");
+    assert_eq!(json["utc_offset_seconds"], TOKYO_OFFSET_SECONDS);
+
+    let restored: Activity = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.utc_offset_seconds(), Some(TOKYO_OFFSET_SECONDS));
+    assert_eq!(restored.start_timezone(), Some("Asia/Tokyo"));
+}
@@ -0,0 +1,155 @@
+// ABOUTME: Tests for the get_activity_weather tool and OpenWeather response parsing
+// ABOUTME: Uses a recorded timemachine response and imported activities with and without GPS
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+use pierre_mcp_server::cache::{CacheKey, CacheResource};
+use pierre_mcp_server::constants::oauth_providers;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::intelligence::weather::WeatherService;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData};
+use pierre_mcp_server::tools::implementations::data::{
+    activity_start_location, GetActivityWeatherTool,
+};
+use pierre_mcp_server::tools::traits::McpTool;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext};
+use serde_json::json;
+
+/// GET /data/3.0/onecall/timemachine for Lausanne at 2025-06-01 06:30 UTC
+const OPENWEATHER_FIXTURE: &str = include_str!("fixtures/weather/openweather_timemachine.json");
+
+const LAUSANNE: (f64, f64) = (46.5197, 6.6323);
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 6, 30, 0).unwrap()
+}
+
+fn run(id: &str) -> ActivityBuilder {
+    ActivityBuilder::new(id, "Lakeside Run", SportType::Run, start(), 2700, "file")
+}
+
+fn gps_series(points: Vec<(f64, f64)>) -> TimeSeriesData {
+    TimeSeriesData {
+        timestamps: (0..).step_by(60).take(points.len()).collect(),
+        heart_rate: None,
+        power: None,
+        cadence: None,
+        speed: None,
+        altitude: None,
+        temperature: None,
+        gps_coordinates: Some(points),
+    }
+}
+
+#[test]
+fn test_openweather_fixture_conditions() {
+    let weather = WeatherService::parse_openweather_response(OPENWEATHER_FIXTURE, start()).unwrap();
+
+    assert_eq!(weather.conditions, "Clouds - scattered clouds");
+    assert!((weather.temperature_celsius - 17.86).abs() <= 0.5);
+    assert_eq!(weather.humidity_percentage, Some(72.0));
+    // 3.1 m/s
+    assert!((weather.wind_speed_kmh.unwrap() - 11.16).abs() <= 0.5);
+
+    assert!(WeatherService::parse_openweather_response(r#"{"data": []}"#, start()).is_err());
+    assert!(WeatherService::parse_openweather_response("<html>", start()).is_err());
+}
+
+#[test]
+fn test_start_location_falls_back_to_gps_stream() {
+    let with_start = run("a")
+        .start_latitude(LAUSANNE.0)
+        .start_longitude(LAUSANNE.1)
+        .build();
+    assert_eq!(activity_start_location(&with_start), Some(LAUSANNE));
+
+    let stream_only = run("b")
+        .time_series_data(gps_series(vec![LAUSANNE, (46.52, 6.64)]))
+        .build();
+    assert_eq!(activity_start_location(&stream_only), Some(LAUSANNE));
+
+    assert_eq!(activity_start_location(&run("c").build()), None);
+}
+
+#[tokio::test]
+async fn test_get_activity_weather_tool() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+
+    let located: Activity = run("file-located")
+        .start_latitude(LAUSANNE.0)
+        .start_longitude(LAUSANNE.1)
+        .build();
+    let indoor: Activity = run("file-indoor").build();
+    for activity in [&located, &indoor] {
+        resources
+            .database
+            .store_imported_activity(user_id, "gpx", "run.gpx", activity)
+            .await
+            .unwrap();
+    }
+
+    // Seed the cache with the recorded response so no request leaves the test
+    let recorded =
+        WeatherService::parse_openweather_response(OPENWEATHER_FIXTURE, start()).unwrap();
+    let key = CacheKey::new(
+        tenant_id,
+        user_id,
+        oauth_providers::FILE.to_owned(),
+        CacheResource::ActivityWeather {
+            activity_id: located.id().to_owned(),
+        },
+    );
+    resources
+        .cache
+        .set(&key, &recorded, key.resource.recommended_ttl())
+        .await
+        .unwrap();
+
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+
+    let result = GetActivityWeatherTool
+        .execute(
+            json!({"activity_id": located.id(), "provider": oauth_providers::FILE}),
+            &context,
+        )
+        .await
+        .unwrap();
+    assert!(!result.is_error, "{}", result.content);
+    assert_eq!(result.content["location"]["latitude"], LAUSANNE.0);
+    assert_eq!(result.content["location"]["longitude"], LAUSANNE.1);
+    assert_eq!(result.content["start_date"], start().to_rfc3339());
+    assert_eq!(
+        result.content["weather"]["conditions"],
+        "Clouds - scattered clouds"
+    );
+    assert_eq!(result.content["weather"]["humidity_percentage"], 72.0);
+    assert_eq!(result.content["cache_hit"], true);
+
+    let result = GetActivityWeatherTool
+        .execute(
+            json!({"activity_id": indoor.id(), "provider": oauth_providers::FILE}),
+            &context,
+        )
+        .await
+        .unwrap();
+    assert!(result.is_error);
+    assert_eq!(result.content["error"], "no_location_data");
+    assert_eq!(result.content["activity_id"], indoor.id());
+}
@@ -0,0 +1,282 @@
+// ABOUTME: Tests for the admin impersonation endpoint used for support debugging
+// ABOUTME: Covers the impersonated_by claim, short expiry, refresh rejection, auditing, and super-admin gating
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+mod helpers;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use base64::{engine::general_purpose, Engine};
+use helpers::axum_test::AxumTestRequest;
+use pierre_mcp_server::{
+    admin::{
+        jwks::JwksManager,
+        jwt::AdminJwtManager,
+        models::{AdminPermission, AdminPermissions, GeneratedAdminToken},
+        AdminAuthService,
+    },
+    auth::{AuthManager, ADMIN_IMPERSONATION_SCOPE},
+    constants::{
+        limits::ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES, system_config::STARTER_MONTHLY_LIMIT,
+    },
+    database_plugins::{factory::Database, DatabaseProvider},
+    mcp::ToolSelectionService,
+    models::User,
+    permissions::UserRole,
+    routes::admin::{AdminApiContext, AdminRoutes},
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const JWT_SECRET: &str = "test_admin_jwt_secret_for_impersonation_testing";
+
+struct ImpersonationTestSetup {
+    context: AdminApiContext,
+    database: Arc<Database>,
+    auth_manager: Arc<AuthManager>,
+    jwks_manager: Arc<JwksManager>,
+    super_admin_token: GeneratedAdminToken,
+    user_manager_token: GeneratedAdminToken,
+    user_id: Uuid,
+}
+
+impl ImpersonationTestSetup {
+    async fn new() -> Result<Self> {
+        let database = common::create_test_database().await?;
+        let auth_manager = common::create_test_auth_manager();
+        let jwks_manager = common::get_shared_test_jwks();
+        let database_arc = Arc::new((*database).clone());
+        let context = AdminApiContext::new(
+            database_arc.clone(),
+            JWT_SECRET,
+            auth_manager.clone(),
+            jwks_manager.clone(),
+            STARTER_MONTHLY_LIMIT,
+            AdminAuthService::DEFAULT_CACHE_TTL_SECS,
+            Arc::new(ToolSelectionService::new(database_arc.clone())),
+        );
+
+        let (user_id, _user) = common::create_test_user(&database).await?;
+
+        let super_admin_token = Self::create_admin_token(
+            &database,
+            &jwks_manager,
+            "support_console",
+            AdminPermissions::super_admin(),
+            true,
+        )
+        .await?;
+        let user_manager_token = Self::create_admin_token(
+            &database,
+            &jwks_manager,
+            "user_manager",
+            AdminPermissions::new(vec![AdminPermission::ManageUsers]),
+            false,
+        )
+        .await?;
+
+        Ok(Self {
+            context,
+            database: database_arc,
+            auth_manager,
+            jwks_manager,
+            super_admin_token,
+            user_manager_token,
+            user_id,
+        })
+    }
+
+    async fn create_admin_token(
+        database: &Database,
+        jwks_manager: &JwksManager,
+        service_name: &str,
+        permissions: AdminPermissions,
+        is_super_admin: bool,
+    ) -> Result<GeneratedAdminToken> {
+        let token_id = format!("admin_{}", Uuid::new_v4().simple());
+        let jwt_token = AdminJwtManager::new().generate_token(
+            &token_id,
+            service_name,
+            &permissions,
+            is_super_admin,
+            None,
+            jwks_manager,
+        )?;
+        let token = GeneratedAdminToken {
+            token_id,
+            service_name: service_name.to_owned(),
+            token_prefix: AdminJwtManager::generate_token_prefix(&jwt_token),
+            jwt_token,
+            permissions,
+            is_super_admin,
+            expires_at: None,
+            created_at: chrono::Utc::now(),
+        };
+
+        let Database::SQLite(sqlite_db) = database else {
+            return Err(anyhow::anyhow!("SQLite required for admin token setup"));
+        };
+        sqlx::query(
+            r"
+            INSERT INTO admin_tokens (
+                id, service_name, service_description, token_hash, token_prefix,
+                jwt_secret_hash, permissions, is_super_admin, is_active,
+                created_at, expires_at, usage_count
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ",
+        )
+        .bind(&token.token_id)
+        .bind(&token.service_name)
+        .bind(Some("Test admin token"))
+        .bind(AdminJwtManager::hash_token_for_storage(&token.jwt_token)?)
+        .bind(&token.token_prefix)
+        .bind(AdminJwtManager::hash_secret(JWT_SECRET))
+        .bind(token.permissions.to_json()?)
+        .bind(token.is_super_admin)
+        .bind(true)
+        .bind(token.created_at)
+        .bind(token.expires_at)
+        .bind(0)
+        .execute(sqlite_db.pool())
+        .await?;
+
+        Ok(token)
+    }
+
+    async fn impersonate(&self, admin_token: &GeneratedAdminToken, target: Uuid) -> (u16, Value) {
+        let response = AxumTestRequest::post("/admin/impersonate")
+            .header(
+                "authorization",
+                &format!("Bearer {}", admin_token.jwt_token),
+            )
+            .json(&json!({
+                "target_user_id": target.to_string(),
+                "reason": "Investigating sync issue reported in ticket 4521",
+            }))
+            .send(AdminRoutes::routes(self.context.clone()))
+            .await;
+        (response.status(), response.json())
+    }
+}
+
+#[tokio::test]
+async fn test_impersonation_token_is_flagged_and_short_lived() -> Result<()> {
+    let setup = ImpersonationTestSetup::new().await?;
+
+    let (status, body) = setup
+        .impersonate(&setup.super_admin_token, setup.user_id)
+        .await;
+    assert_eq!(status, 200);
+    assert_eq!(body["impersonated_by"], setup.super_admin_token.token_id);
+
+    let token = body["token"].as_str().unwrap();
+    let claims = setup
+        .auth_manager
+        .validate_token(token, &setup.jwks_manager)?;
+    assert_eq!(claims.sub, setup.user_id.to_string());
+    assert_eq!(
+        claims.impersonated_by.as_deref(),
+        Some(setup.super_admin_token.token_id.as_str())
+    );
+    assert_eq!(claims.scope.as_deref(), Some(ADMIN_IMPERSONATION_SCOPE));
+    assert!(claims.exp - claims.iat <= ADMIN_IMPERSONATION_TOKEN_EXPIRY_MINUTES * 60);
+
+    // The claim is serialized under its documented name
+    let payload = token.split('.').nth(1).unwrap();
+    let decoded: Value =
+        serde_json::from_slice(&general_purpose::URL_SAFE_NO_PAD.decode(payload)?)?;
+    assert_eq!(decoded["impersonated_by"], setup.super_admin_token.token_id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_impersonation_is_audited() -> Result<()> {
+    let setup = ImpersonationTestSetup::new().await?;
+    let (status, _) = setup
+        .impersonate(&setup.super_admin_token, setup.user_id)
+        .await;
+    assert_eq!(status, 200);
+
+    let events = setup
+        .database
+        .get_audit_events(None, Some("UserImpersonated"), None)
+        .await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].user_id, Some(setup.user_id));
+    assert_eq!(events[0].result, "success");
+    assert_eq!(
+        events[0].metadata["admin_token_id"],
+        setup.super_admin_token.token_id
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_impersonation_token_cannot_be_refreshed() -> Result<()> {
+    let setup = ImpersonationTestSetup::new().await?;
+    let (_, body) = setup
+        .impersonate(&setup.super_admin_token, setup.user_id)
+        .await;
+    let token = body["token"].as_str().unwrap();
+
+    let user = setup
+        .database
+        .get_user_global(setup.user_id)
+        .await?
+        .unwrap();
+    assert!(setup
+        .auth_manager
+        .refresh_token(token, &user, &setup.jwks_manager)
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_non_super_admin_cannot_impersonate() -> Result<()> {
+    let setup = ImpersonationTestSetup::new().await?;
+
+    let (status, body) = setup
+        .impersonate(&setup.user_manager_token, setup.user_id)
+        .await;
+    assert_eq!(status, 403);
+    assert!(body.get("token").is_none());
+
+    // The denied attempt is still recorded
+    let events = setup
+        .database
+        .get_audit_events(None, Some("UserImpersonated"), None)
+        .await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].result, "denied");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_super_admin_user_cannot_be_impersonated() -> Result<()> {
+    let setup = ImpersonationTestSetup::new().await?;
+    let mut super_admin = User::new(
+        "root@example.com".to_owned(),
+        "password_hash".to_owned(),
+        Some("Root".to_owned()),
+    );
+    super_admin.role = UserRole::SuperAdmin;
+    setup.database.create_user(&super_admin).await?;
+
+    let (status, _) = setup
+        .impersonate(&setup.super_admin_token, super_admin.id)
+        .await;
+    assert_eq!(status, 403);
+
+    Ok(())
+}
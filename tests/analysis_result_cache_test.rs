@@ -0,0 +1,172 @@
+// ABOUTME: Tests for analysis result caching keyed on a hash of the exact inputs
+// ABOUTME: Verifies identical inputs hit the cache and changed activities or versions recompute
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::cache::factory::Cache;
+use pierre_mcp_server::cache::CacheConfig;
+use pierre_mcp_server::intelligence::analysis_cache::{AnalysisInput, AnalysisResultCache};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TenantId};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn run(id: &str, day: u32, distance_meters: f64) -> Activity {
+    ActivityBuilder::new(
+        id,
+        "Morning Run",
+        SportType::Run,
+        Utc.with_ymd_and_hms(2025, 6, day, 7, 0, 0).unwrap(),
+        1800,
+        "strava",
+    )
+    .distance_meters(distance_meters)
+    .average_heart_rate(145)
+    .build()
+}
+
+fn input(activities: &[Activity], algorithm_version: u32) -> AnalysisInput<'_> {
+    AnalysisInput {
+        analysis: "fitness_score",
+        algorithm_version,
+        activities,
+        parameters: json!({ "timeframe": "last_30_days", "as_of": "2025-06-10" }),
+    }
+}
+
+async fn test_cache() -> Cache {
+    Cache::new(CacheConfig {
+        enable_background_cleanup: false,
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_identical_inputs_hit_cache_and_changes_recompute() {
+    let cache = test_cache().await;
+    let analysis_cache = AnalysisResultCache::new(&cache, Duration::from_secs(3600));
+    let tenant_id = TenantId::new();
+    let user_id = Uuid::new_v4();
+    let computations = AtomicUsize::new(0);
+    let compute = || -> Value {
+        let count = computations.fetch_add(1, Ordering::SeqCst) + 1;
+        json!({ "fitness_score": 42, "computation": count })
+    };
+
+    let activities = vec![run("a1", 1, 5000.0), run("a2", 3, 8000.0)];
+    let first = analysis_cache
+        .get_or_compute(
+            tenant_id,
+            user_id,
+            "strava",
+            &input(&activities, 1),
+            &compute,
+        )
+        .await
+        .unwrap();
+    assert!(!first.cache_hit);
+    assert_eq!(computations.load(Ordering::SeqCst), 1);
+
+    // Identical inputs (a fresh copy of the same activities) reuse the stored result
+    let same_activities = vec![run("a1", 1, 5000.0), run("a2", 3, 8000.0)];
+    let second = analysis_cache
+        .get_or_compute(
+            tenant_id,
+            user_id,
+            "strava",
+            &input(&same_activities, 1),
+            &compute,
+        )
+        .await
+        .unwrap();
+    assert!(second.cache_hit);
+    assert_eq!(second.input_hash, first.input_hash);
+    assert_eq!(second.result, first.result);
+    assert_eq!(second.computed_at, first.computed_at);
+    assert_eq!(computations.load(Ordering::SeqCst), 1);
+
+    // Changing a single activity changes the hash and forces recomputation
+    let edited_activities = vec![run("a1", 1, 5000.0), run("a2", 3, 8100.0)];
+    let edited = analysis_cache
+        .get_or_compute(
+            tenant_id,
+            user_id,
+            "strava",
+            &input(&edited_activities, 1),
+            &compute,
+        )
+        .await
+        .unwrap();
+    assert!(!edited.cache_hit);
+    assert_ne!(edited.input_hash, first.input_hash);
+    assert_eq!(computations.load(Ordering::SeqCst), 2);
+
+    // Bumping the algorithm version forces recomputation for the same activities
+    let upgraded = analysis_cache
+        .get_or_compute(
+            tenant_id,
+            user_id,
+            "strava",
+            &input(&activities, 2),
+            &compute,
+        )
+        .await
+        .unwrap();
+    assert!(!upgraded.cache_hit);
+    assert_eq!(upgraded.algorithm_version, 2);
+    assert_ne!(upgraded.input_hash, first.input_hash);
+    assert_eq!(computations.load(Ordering::SeqCst), 3);
+
+    // The original version's result is still served for the original inputs
+    let original = analysis_cache
+        .get_or_compute(
+            tenant_id,
+            user_id,
+            "strava",
+            &input(&activities, 1),
+            &compute,
+        )
+        .await
+        .unwrap();
+    assert!(original.cache_hit);
+    assert_eq!(original.result, first.result);
+    assert_eq!(computations.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_zero_ttl_disables_analysis_caching() {
+    let cache = test_cache().await;
+    let analysis_cache = AnalysisResultCache::new(&cache, Duration::ZERO);
+    let activities = vec![run("a1", 1, 5000.0)];
+    let tenant_id = TenantId::new();
+    let user_id = Uuid::new_v4();
+    let computations = AtomicUsize::new(0);
+    let compute = || -> Value {
+        computations.fetch_add(1, Ordering::SeqCst);
+        json!({ "fitness_score": 10 })
+    };
+
+    for _ in 0..2 {
+        let result = analysis_cache
+            .get_or_compute(
+                tenant_id,
+                user_id,
+                "strava",
+                &input(&activities, 1),
+                &compute,
+            )
+            .await
+            .unwrap();
+        assert!(!result.cache_hit);
+    }
+    assert_eq!(computations.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,266 @@
+// ABOUTME: Tests for the pluggable analytics sink receiving API key and A2A usage records
+// ABOUTME: Verifies hot-path forwarding, batching thresholds, flushes before rollups, and the database default
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use pierre_mcp_server::a2a::client::{A2AClientManager, ClientRegistrationRequest};
+use pierre_mcp_server::a2a::system_user::A2ASystemUserService;
+use pierre_mcp_server::analytics::{AnalyticsSink, BatchingAnalyticsSink};
+use pierre_mcp_server::api_keys::{ApiKeyUsage, ApiKeyUsageStats};
+use pierre_mcp_server::database::{A2AUsage, A2AUsageStats};
+use pierre_mcp_server::errors::AppResult;
+use pierre_mcp_server::jsonrpc::JsonRpcResponse;
+use pierre_mcp_server::mcp::multitenant::MultiTenantMcpServer;
+use serde_json::{json, Value};
+
+/// Sink remembering the tool names of every call it receives, batch by batch
+#[derive(Default)]
+struct RecordingSink {
+    api_key_batches: Mutex<Vec<Vec<String>>>,
+    a2a_batches: Mutex<Vec<Vec<String>>>,
+}
+
+impl RecordingSink {
+    fn api_key_batches(&self) -> Vec<Vec<String>> {
+        self.api_key_batches.lock().unwrap().clone()
+    }
+
+    fn a2a_batches(&self) -> Vec<Vec<String>> {
+        self.a2a_batches.lock().unwrap().clone()
+    }
+
+    fn total(batches: &Mutex<Vec<Vec<String>>>) -> u32 {
+        let count: usize = batches.lock().unwrap().iter().map(Vec::len).sum();
+        u32::try_from(count).unwrap()
+    }
+}
+
+#[async_trait]
+impl AnalyticsSink for RecordingSink {
+    async fn record_api_key_usage(&self, usage: ApiKeyUsage) -> AppResult<()> {
+        self.api_key_batches
+            .lock()
+            .unwrap()
+            .push(vec![usage.tool_name]);
+        Ok(())
+    }
+
+    async fn record_a2a_usage(&self, usage: A2AUsage) -> AppResult<()> {
+        self.a2a_batches.lock().unwrap().push(vec![usage.tool_name]);
+        Ok(())
+    }
+
+    async fn record_api_key_usage_batch(&self, usages: Vec<ApiKeyUsage>) -> AppResult<()> {
+        self.api_key_batches
+            .lock()
+            .unwrap()
+            .push(usages.into_iter().map(|usage| usage.tool_name).collect());
+        Ok(())
+    }
+
+    async fn record_a2a_usage_batch(&self, usages: Vec<A2AUsage>) -> AppResult<()> {
+        self.a2a_batches
+            .lock()
+            .unwrap()
+            .push(usages.into_iter().map(|usage| usage.tool_name).collect());
+        Ok(())
+    }
+
+    async fn api_key_usage_stats(
+        &self,
+        api_key_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<ApiKeyUsageStats> {
+        let total_requests = Self::total(&self.api_key_batches);
+        Ok(ApiKeyUsageStats {
+            api_key_id: api_key_id.to_owned(),
+            period_start: start_date,
+            period_end: end_date,
+            total_requests,
+            successful_requests: total_requests,
+            failed_requests: 0,
+            total_response_time_ms: 0,
+            tool_usage: json!({}),
+        })
+    }
+
+    async fn a2a_usage_stats(
+        &self,
+        client_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<A2AUsageStats> {
+        let total_requests = Self::total(&self.a2a_batches);
+        Ok(A2AUsageStats {
+            client_id: client_id.to_owned(),
+            period_start: start_date,
+            period_end: end_date,
+            total_requests,
+            successful_requests: total_requests,
+            failed_requests: 0,
+            avg_response_time_ms: None,
+            total_request_bytes: None,
+            total_response_bytes: None,
+        })
+    }
+}
+
+fn tool_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_owned()).collect()
+}
+
+async fn record_tool_call(sink: &dyn AnalyticsSink, tool_name: &str) {
+    let response = JsonRpcResponse::success(Some(Value::from(1)), json!({"ok": true}));
+    MultiTenantMcpServer::record_api_key_usage(
+        sink,
+        "key-1",
+        tool_name,
+        Duration::from_millis(12),
+        &response,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_api_key_usage_is_forwarded_to_sink() {
+    let sink = RecordingSink::default();
+
+    record_tool_call(&sink, "get_activities").await;
+
+    assert_eq!(
+        sink.api_key_batches(),
+        vec![tool_names(&["get_activities"])]
+    );
+}
+
+#[tokio::test]
+async fn test_a2a_usage_is_forwarded_to_sink() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let sink = Arc::new(RecordingSink::default());
+    let manager = A2AClientManager::new(
+        Arc::clone(&resources.database),
+        Arc::new(A2ASystemUserService::new(Arc::clone(&resources.database))),
+        Arc::clone(&sink),
+    );
+
+    manager
+        .record_usage("client-1", "analyze_activity", true)
+        .await
+        .unwrap();
+
+    assert_eq!(sink.a2a_batches(), vec![tool_names(&["analyze_activity"])]);
+}
+
+#[tokio::test]
+async fn test_batching_sink_forwards_full_batches() {
+    let inner = Arc::new(RecordingSink::default());
+    let sink = BatchingAnalyticsSink::new(Arc::clone(&inner), 3);
+
+    for tool_name in ["a", "b", "c", "d"] {
+        record_tool_call(&sink, tool_name).await;
+    }
+
+    // The first three records went out together; the fourth waits for the next batch
+    assert_eq!(inner.api_key_batches(), vec![tool_names(&["a", "b", "c"])]);
+    assert_eq!(sink.pending_records(), 1);
+
+    sink.flush().await.unwrap();
+    assert_eq!(
+        inner.api_key_batches(),
+        vec![tool_names(&["a", "b", "c"]), tool_names(&["d"])]
+    );
+    assert_eq!(sink.pending_records(), 0);
+
+    // Flushing an empty buffer forwards nothing
+    sink.flush().await.unwrap();
+    assert_eq!(inner.api_key_batches().len(), 2);
+}
+
+#[tokio::test]
+async fn test_batching_sink_flushes_before_rollups() {
+    let inner = Arc::new(RecordingSink::default());
+    let sink = BatchingAnalyticsSink::new(Arc::clone(&inner), 100);
+
+    record_tool_call(&sink, "get_activities").await;
+    record_tool_call(&sink, "get_athlete").await;
+    assert!(inner.api_key_batches().is_empty());
+
+    let stats = sink
+        .api_key_usage_stats("key-1", Utc::now() - chrono::Duration::hours(1), Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(stats.total_requests, 2);
+    assert_eq!(
+        inner.api_key_batches(),
+        vec![tool_names(&["get_activities", "get_athlete"])]
+    );
+}
+
+#[tokio::test]
+async fn test_batching_sink_background_flush() {
+    let inner = Arc::new(RecordingSink::default());
+    let sink = Arc::new(BatchingAnalyticsSink::new(Arc::clone(&inner), 100));
+    sink.spawn_flush_task(Duration::from_millis(20));
+
+    record_tool_call(sink.as_ref(), "get_activities").await;
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while inner.api_key_batches().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("buffered record was never flushed");
+    assert_eq!(sink.pending_records(), 0);
+}
+
+#[tokio::test]
+async fn test_database_sink_is_the_default() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let credentials = resources
+        .a2a_client_manager
+        .register_client(
+            ClientRegistrationRequest {
+                name: "Analytics Client".to_owned(),
+                description: "Client recording usage".to_owned(),
+                capabilities: vec!["fitness-data-analysis".to_owned()],
+                redirect_uris: vec![],
+                contact_email: "analytics@example.com".to_owned(),
+            },
+            user_id,
+        )
+        .await
+        .unwrap();
+
+    resources
+        .a2a_client_manager
+        .record_usage(&credentials.client_id, "analyze_activity", true)
+        .await
+        .unwrap();
+
+    let stats = resources
+        .analytics
+        .a2a_usage_stats(
+            &credentials.client_id,
+            Utc::now() - chrono::Duration::hours(1),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(stats.total_requests, 1);
+    assert_eq!(stats.successful_requests, 1);
+}
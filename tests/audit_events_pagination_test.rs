@@ -0,0 +1,182 @@
+// ABOUTME: Tests for filtered, cursor-paginated audit event queries
+// ABOUTME: Covers time-range, severity, and user filters and stable paging while events keep arriving
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::pagination::{Cursor, PaginationParams};
+use pierre_mcp_server::security::audit::{
+    AuditEvent, AuditEventFilter, AuditEventType, AuditSeverity,
+};
+use uuid::Uuid;
+
+fn base_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 8, 0, 0).unwrap()
+}
+
+fn event_at(timestamp: DateTime<Utc>, severity: AuditSeverity) -> AuditEvent {
+    let mut event = AuditEvent::new(
+        AuditEventType::UserLogin,
+        severity,
+        "login".to_owned(),
+        "login".to_owned(),
+        "success".to_owned(),
+    );
+    event.timestamp = timestamp;
+    event
+}
+
+#[tokio::test]
+async fn test_time_range_severity_and_user_filters() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+
+    // One event per minute; every third one is a warning and even minutes belong to the user
+    for minute in 0..10 {
+        let severity = if minute % 3 == 0 {
+            AuditSeverity::Warning
+        } else {
+            AuditSeverity::Info
+        };
+        let mut event = event_at(base_time() + Duration::minutes(minute), severity);
+        if minute % 2 == 0 {
+            event = event.with_user_id(user_id);
+        }
+        database.store_audit_event(&event).await.unwrap();
+    }
+
+    let minutes = |events: &[AuditEvent]| -> Vec<i64> {
+        events
+            .iter()
+            .map(|event| (event.timestamp - base_time()).num_minutes())
+            .collect()
+    };
+    let params = PaginationParams::forward(None, 50);
+
+    // Start is inclusive and end is exclusive
+    let window = AuditEventFilter::default().with_time_range(
+        Some(base_time() + Duration::minutes(3)),
+        Some(base_time() + Duration::minutes(7)),
+    );
+    let page = database
+        .get_audit_events_page(&window, &params)
+        .await
+        .unwrap();
+    assert_eq!(minutes(&page.items), vec![6, 5, 4, 3]);
+    assert!(!page.has_more);
+    assert!(page.next_cursor.is_none());
+
+    let open_ended =
+        AuditEventFilter::default().with_time_range(Some(base_time() + Duration::minutes(8)), None);
+    let page = database
+        .get_audit_events_page(&open_ended, &params)
+        .await
+        .unwrap();
+    assert_eq!(minutes(&page.items), vec![9, 8]);
+
+    let warnings = window.clone().with_severity(AuditSeverity::Warning);
+    let page = database
+        .get_audit_events_page(&warnings, &params)
+        .await
+        .unwrap();
+    assert_eq!(minutes(&page.items), vec![6, 3]);
+
+    let user_events = window.with_user_id(user_id);
+    let page = database
+        .get_audit_events_page(&user_events, &params)
+        .await
+        .unwrap();
+    assert_eq!(minutes(&page.items), vec![6, 4]);
+    assert!(page
+        .items
+        .iter()
+        .all(|event| event.user_id == Some(user_id)));
+
+    let no_match = AuditEventFilter::default().with_event_type("DataExported");
+    let page = database
+        .get_audit_events_page(&no_match, &params)
+        .await
+        .unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_cursor_pagination_is_stable_under_concurrent_inserts() {
+    let database = common::create_test_database().await.unwrap();
+
+    // 25 events in pairs sharing a timestamp, so ties are broken by event ID
+    let mut expected = Vec::new();
+    for n in 0..25 {
+        let event = event_at(base_time() + Duration::seconds(n / 2), AuditSeverity::Info);
+        database.store_audit_event(&event).await.unwrap();
+        expected.push(event);
+    }
+    expected.sort_by_key(|event| Reverse((event.timestamp, event.event_id.to_string())));
+    let expected_ids: Vec<Uuid> = expected.iter().map(|event| event.event_id).collect();
+
+    let filter = AuditEventFilter::default();
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = database
+            .get_audit_events_page(&filter, &PaginationParams::forward(cursor, 10))
+            .await
+            .unwrap();
+        seen.extend(page.items.iter().map(|event| event.event_id));
+
+        // New events land while the client is between pages
+        let writer = {
+            let database = Arc::clone(&database);
+            tokio::spawn(async move {
+                for _ in 0..3 {
+                    let event = event_at(Utc::now() - Duration::hours(1), AuditSeverity::Info);
+                    database.store_audit_event(&event).await.unwrap();
+                }
+            })
+        };
+        writer.await.unwrap();
+
+        if !page.has_more {
+            assert!(page.next_cursor.is_none());
+            break;
+        }
+        assert_eq!(page.count, 10);
+        cursor = page.next_cursor;
+    }
+
+    assert_eq!(seen, expected_ids);
+    assert_eq!(seen.iter().collect::<HashSet<_>>().len(), 25);
+
+    // A fresh first page starts from the newest events, including the late arrivals
+    let first = database
+        .get_audit_events_page(&filter, &PaginationParams::forward(None, 5))
+        .await
+        .unwrap();
+    assert!(first
+        .items
+        .iter()
+        .all(|event| !expected_ids.contains(&event.event_id)));
+}
+
+#[tokio::test]
+async fn test_invalid_cursor_is_rejected() {
+    let database = common::create_test_database().await.unwrap();
+    let params =
+        PaginationParams::forward(Some(Cursor::from_string("not-a-cursor".to_owned())), 10);
+
+    let result = database
+        .get_audit_events_page(&AuditEventFilter::default(), &params)
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,260 @@
+// ABOUTME: HTTP integration tests for listing and revoking login sessions
+// ABOUTME: Revokes one or all sessions and checks which tokens are still accepted
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+//! Integration tests for `/api/auth/sessions`
+
+mod common;
+mod helpers;
+
+use helpers::axum_test::AxumTestRequest;
+use pierre_mcp_server::{
+    config::environment::{
+        AppBehaviorConfig, BackupConfig, DatabaseConfig, DatabaseUrl, Environment, SecurityConfig,
+        SecurityHeadersConfig, ServerConfig,
+    },
+    database_plugins::DatabaseProvider,
+    mcp::resources::{ServerResources, ServerResourcesOptions},
+    routes::auth::{AuthRoutes, AuthSessionsResponse, RevokeSessionsResponse},
+};
+use serde_json::Value;
+use std::sync::Arc;
+
+async fn setup() -> Arc<ServerResources> {
+    common::init_server_config();
+    let database = common::create_test_database().await.unwrap();
+    let auth_manager = common::create_test_auth_manager();
+    let cache = common::create_test_cache().await.unwrap();
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config = Arc::new(ServerConfig {
+        http_port: 8081,
+        database: DatabaseConfig {
+            url: DatabaseUrl::Memory,
+            backup: BackupConfig {
+                directory: temp_dir.path().to_path_buf(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        app_behavior: AppBehaviorConfig {
+            ci_mode: true,
+            auto_approve_users: false,
+            ..Default::default()
+        },
+        security: SecurityConfig {
+            headers: SecurityHeadersConfig {
+                environment: Environment::Testing,
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    Arc::new(
+        ServerResources::new(
+            (*database).clone(),
+            (*auth_manager).clone(),
+            "test_jwt_secret",
+            config,
+            cache,
+            ServerResourcesOptions {
+                rsa_key_size_bits: Some(2048),
+                jwks_manager: Some(common::get_shared_test_jwks()),
+                llm_provider: None,
+            },
+        )
+        .await,
+    )
+}
+
+/// Log in through the ROPC endpoint and return the access token
+async fn login(routes: &axum::Router, email: &str, user_agent: &str, ip: &str) -> String {
+    let form = [
+        ("grant_type", "password"),
+        ("username", email),
+        ("password", "password123"),
+    ];
+    let response = AxumTestRequest::post("/oauth/token")
+        .header("User-Agent", user_agent)
+        .header("X-Forwarded-For", &format!("{ip}, 10.0.0.1"))
+        .form(&form)
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 200, "login failed");
+
+    let body: Value = response.json();
+    body["access_token"].as_str().unwrap().to_owned()
+}
+
+async fn list_sessions(routes: &axum::Router, token: &str) -> (u16, Option<AuthSessionsResponse>) {
+    let response = AxumTestRequest::get("/api/auth/sessions")
+        .header("Authorization", &format!("Bearer {token}"))
+        .send(routes.clone())
+        .await;
+    let status = response.status();
+    (status, (status == 200).then(|| response.json()))
+}
+
+#[tokio::test]
+async fn test_login_sessions_are_listed() {
+    let resources = setup().await;
+    let (_, user) = common::create_test_user(&resources.database).await.unwrap();
+    let routes = AuthRoutes::routes(resources.clone());
+
+    let laptop = login(&routes, &user.email, "Firefox/128.0", "203.0.113.7").await;
+    let _phone = login(&routes, &user.email, "PierreMobile/2.1", "198.51.100.4").await;
+
+    let (status, sessions) = list_sessions(&routes, &laptop).await;
+    assert_eq!(status, 200);
+    let sessions = sessions.unwrap().sessions;
+    assert_eq!(sessions.len(), 2);
+
+    let current: Vec<_> = sessions.iter().filter(|s| s.current).collect();
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0].client.as_deref(), Some("Firefox/128.0"));
+    assert_eq!(current[0].ip_address.as_deref(), Some("203.0.113.7"));
+    assert!(current[0].expires_at > current[0].issued_at);
+    assert!(sessions
+        .iter()
+        .any(|s| s.client.as_deref() == Some("PierreMobile/2.1")));
+}
+
+#[tokio::test]
+async fn test_revoked_session_token_is_rejected() {
+    let resources = setup().await;
+    let (user_id, user) = common::create_test_user(&resources.database).await.unwrap();
+    let routes = AuthRoutes::routes(resources.clone());
+
+    let laptop = login(&routes, &user.email, "Firefox/128.0", "203.0.113.7").await;
+    let phone = login(&routes, &user.email, "PierreMobile/2.1", "198.51.100.4").await;
+
+    let phone_session = resources
+        .auth_manager
+        .validate_token(&phone, &resources.jwks_manager)
+        .unwrap()
+        .jti;
+
+    let response = AxumTestRequest::delete(&format!("/api/auth/sessions/{phone_session}"))
+        .header("Authorization", &format!("Bearer {laptop}"))
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 200);
+    let revoked: RevokeSessionsResponse = response.json();
+    assert_eq!(revoked.revoked, 1);
+
+    // The revoked token no longer authenticates, the other session still does
+    let (status, _) = list_sessions(&routes, &phone).await;
+    assert_eq!(status, 401);
+    let (status, sessions) = list_sessions(&routes, &laptop).await;
+    assert_eq!(status, 200);
+    let sessions = sessions.unwrap().sessions;
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].current);
+
+    let session = resources
+        .database
+        .get_auth_session(&phone_session)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.user_id, user_id);
+    assert!(session.revoked_at.is_some());
+
+    // Revoking it again, or an unknown session, is a 404
+    let response = AxumTestRequest::delete(&format!("/api/auth/sessions/{phone_session}"))
+        .header("Authorization", &format!("Bearer {laptop}"))
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_revoke_all_sessions() {
+    let resources = setup().await;
+    let (_, user) = common::create_test_user(&resources.database).await.unwrap();
+    let routes = AuthRoutes::routes(resources.clone());
+
+    let laptop = login(&routes, &user.email, "Firefox/128.0", "203.0.113.7").await;
+    let phone = login(&routes, &user.email, "PierreMobile/2.1", "198.51.100.4").await;
+    let tablet = login(&routes, &user.email, "PierreTablet/1.0", "198.51.100.9").await;
+
+    // Sign out everywhere else
+    let response = AxumTestRequest::delete("/api/auth/sessions?except_current=true")
+        .header("Authorization", &format!("Bearer {laptop}"))
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 200);
+    let revoked: RevokeSessionsResponse = response.json();
+    assert_eq!(revoked.revoked, 2);
+
+    assert_eq!(list_sessions(&routes, &phone).await.0, 401);
+    assert_eq!(list_sessions(&routes, &tablet).await.0, 401);
+    assert_eq!(list_sessions(&routes, &laptop).await.0, 200);
+
+    // Sign out everywhere
+    let response = AxumTestRequest::delete("/api/auth/sessions")
+        .header("Authorization", &format!("Bearer {laptop}"))
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 200);
+    let revoked: RevokeSessionsResponse = response.json();
+    assert_eq!(revoked.revoked, 1);
+    assert_eq!(list_sessions(&routes, &laptop).await.0, 401);
+}
+
+async fn refresh(routes: &axum::Router, token: &str, user_id: &str) -> (u16, Option<String>) {
+    let response = AxumTestRequest::post("/api/auth/refresh")
+        .json(&serde_json::json!({ "token": token, "user_id": user_id }))
+        .send(routes.clone())
+        .await;
+    let status = response.status();
+    let token = (status == 200).then(|| {
+        let body: Value = response.json();
+        body["jwt_token"].as_str().unwrap().to_owned()
+    });
+    (status, token)
+}
+
+#[tokio::test]
+async fn test_revoked_session_cannot_be_refreshed() {
+    let resources = setup().await;
+    let (user_id, user) = common::create_test_user(&resources.database).await.unwrap();
+    let user_id = user_id.to_string();
+    let routes = AuthRoutes::routes(resources.clone());
+
+    let laptop = login(&routes, &user.email, "Firefox/128.0", "203.0.113.7").await;
+    let phone = login(&routes, &user.email, "PierreMobile/2.1", "198.51.100.4").await;
+
+    let phone_session = resources
+        .auth_manager
+        .validate_token(&phone, &resources.jwks_manager)
+        .unwrap()
+        .jti;
+    let response = AxumTestRequest::delete(&format!("/api/auth/sessions/{phone_session}"))
+        .header("Authorization", &format!("Bearer {laptop}"))
+        .send(routes.clone())
+        .await;
+    assert_eq!(response.status(), 200);
+
+    // The revoked token cannot be exchanged for a fresh one
+    assert_eq!(refresh(&routes, &phone, &user_id).await, (401, None));
+
+    // Refreshing a live session replaces it: the old token stops working
+    let (status, refreshed) = refresh(&routes, &laptop, &user_id).await;
+    assert_eq!(status, 200);
+    let refreshed = refreshed.unwrap();
+    assert_eq!(list_sessions(&routes, &laptop).await.0, 401);
+    assert_eq!(refresh(&routes, &laptop, &user_id).await, (401, None));
+
+    let (status, sessions) = list_sessions(&routes, &refreshed).await;
+    assert_eq!(status, 200);
+    let sessions = sessions.unwrap().sessions;
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].current);
+}
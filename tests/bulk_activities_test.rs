@@ -0,0 +1,251 @@
+// ABOUTME: Tests for the concurrent activity fetch across a user's connected providers
+// ABOUTME: Covers partial results when one provider fails, deduplication, time ranges, and the concurrency bound
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::errors::{AppError, AppResult};
+use pierre_mcp_server::models::{
+    Activity, ActivityBuilder, Athlete, PersonalRecord, SportType, Stats,
+};
+use pierre_mcp_server::pagination::{CursorPage, PaginationParams};
+use pierre_mcp_server::providers::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig,
+};
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use pierre_mcp_server::providers::utils::DeduplicationConfig;
+use pierre_mcp_server::services::bulk_activities::{
+    fetch_activities_concurrently, BulkActivityQuery,
+};
+
+fn morning() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 14, 7, 0, 0).unwrap()
+}
+
+fn run(provider: &str, id: &str, start: DateTime<Utc>, distance: f64) -> Activity {
+    ActivityBuilder::new(id, "Morning Run", SportType::Run, start, 3000, provider)
+        .distance_meters(distance)
+        .build()
+}
+
+/// Synthetic provider whose activity queries always fail
+struct FailingProvider {
+    inner: SyntheticProvider,
+}
+
+impl FailingProvider {
+    fn new(name: &'static str) -> Self {
+        Self {
+            inner: SyntheticProvider::with_name(name),
+        }
+    }
+
+    fn unavailable(&self) -> AppError {
+        AppError::external_service(self.inner.name(), "503 Service Unavailable")
+    }
+}
+
+#[async_trait]
+impl FitnessProvider for FailingProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        self.inner.config()
+    }
+
+    async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
+        self.inner.set_credentials(credentials).await
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        Err(self.unavailable())
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        _params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        Err(self.unavailable())
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        _params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        Err(self.unavailable())
+    }
+
+    async fn get_activity(&self, _id: &str) -> AppResult<Activity> {
+        Err(self.unavailable())
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        Err(self.unavailable())
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        Err(self.unavailable())
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+fn providers(names: &[&str]) -> Vec<String> {
+    names.iter().map(|&name| name.to_owned()).collect()
+}
+
+#[tokio::test]
+async fn test_failing_provider_does_not_hide_other_results() {
+    let activities = vec![
+        run("garmin", "garmin-1", morning(), 10_000.0),
+        run("garmin", "garmin-2", morning() + Duration::days(1), 8_000.0),
+    ];
+
+    let result = fetch_activities_concurrently(
+        providers(&["garmin", "strava"]),
+        &BulkActivityQuery::default(),
+        &DeduplicationConfig::default(),
+        |name| {
+            let activities = activities.clone();
+            async move {
+                let provider: Box<dyn FitnessProvider> = match name.as_str() {
+                    "garmin" => Box::new(SyntheticProvider::with_activities_and_name(
+                        activities, "garmin",
+                    )),
+                    _ => Box::new(FailingProvider::new("strava")),
+                };
+                Ok(provider)
+            }
+        },
+    )
+    .await;
+
+    assert!(result.is_partial());
+    assert_eq!(result.providers, vec!["garmin"]);
+    let ids: Vec<_> = result.activities.iter().map(Activity::id).collect();
+    assert_eq!(ids, vec!["garmin-1", "garmin-2"]);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].provider, "strava");
+    assert!(result.errors[0].error.contains("503"));
+}
+
+#[tokio::test]
+async fn test_provider_creation_failure_is_annotated() {
+    let result = fetch_activities_concurrently(
+        providers(&["garmin", "whoop"]),
+        &BulkActivityQuery::default(),
+        &DeduplicationConfig::default(),
+        |name| async move {
+            if name == "whoop" {
+                return Err("No valid whoop token found".to_owned());
+            }
+            let provider: Box<dyn FitnessProvider> =
+                Box::new(SyntheticProvider::with_activities_and_name(
+                    vec![run("garmin", "garmin-1", morning(), 10_000.0)],
+                    "garmin",
+                ));
+            Ok(provider)
+        },
+    )
+    .await;
+
+    assert_eq!(result.activities.len(), 1);
+    assert_eq!(result.errors[0].provider, "whoop");
+    assert_eq!(result.errors[0].error, "No valid whoop token found");
+}
+
+#[tokio::test]
+async fn test_results_are_deduplicated_and_time_filtered() {
+    let query = BulkActivityQuery::with_time_range(
+        Some(morning() - Duration::hours(1)),
+        Some(morning() + Duration::hours(12)),
+    );
+
+    let result = fetch_activities_concurrently(
+        providers(&["garmin", "strava"]),
+        &query,
+        &DeduplicationConfig::default(),
+        |name| async move {
+            let activities = match name.as_str() {
+                "garmin" => vec![
+                    run("garmin", "garmin-1", morning(), 10_000.0),
+                    // Outside the requested range
+                    run(
+                        "garmin",
+                        "garmin-old",
+                        morning() - Duration::days(3),
+                        5_000.0,
+                    ),
+                ],
+                _ => vec![run(
+                    "strava",
+                    "strava-1",
+                    morning() + Duration::seconds(40),
+                    10_050.0,
+                )],
+            };
+            let provider: Box<dyn FitnessProvider> =
+                Box::new(SyntheticProvider::with_activities(activities));
+            Ok(provider)
+        },
+    )
+    .await;
+
+    assert!(!result.is_partial());
+    assert_eq!(result.providers, vec!["garmin", "strava"]);
+    // The Strava copy of the Garmin run is merged into the preferred Garmin record
+    assert_eq!(result.activities.len(), 1);
+    assert_eq!(result.activities[0].id(), "garmin-1");
+}
+
+#[tokio::test]
+async fn test_concurrency_is_bounded() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let query = BulkActivityQuery {
+        concurrency: 2,
+        ..BulkActivityQuery::default()
+    };
+
+    let result = fetch_activities_concurrently(
+        providers(&["a", "b", "c", "d", "e"]),
+        &query,
+        &DeduplicationConfig::default(),
+        |name| {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(StdDuration::from_millis(20)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Err(format!("{name} is not connected"))
+            }
+        },
+    )
+    .await;
+
+    assert_eq!(result.errors.len(), 5);
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+}
@@ -8,7 +8,21 @@
 use pierre_mcp_server::providers::circuit_breaker::{
     CircuitBreaker, CircuitBreakerConfig, CircuitState,
 };
+use pierre_mcp_server::providers::errors::ProviderError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Breaker that opens on the first failure and may probe again immediately
+fn half_open_breaker(max_probes: usize, success_threshold: u32) -> CircuitBreaker {
+    let config = CircuitBreakerConfig::new(1, Duration::ZERO, success_threshold)
+        .with_half_open_max_probes(max_probes);
+    let cb = CircuitBreaker::with_config("test", config);
+    cb.record_failure();
+    assert_eq!(cb.state(), CircuitState::Open);
+    cb
+}
 
 #[test]
 fn test_circuit_breaker_starts_closed() {
@@ -142,3 +156,99 @@ fn test_interleaved_failures_and_successes() {
     cb.record_failure();
     assert_eq!(cb.state(), CircuitState::Open);
 }
+
+#[test]
+fn test_half_open_probe_defaults() {
+    assert_eq!(CircuitBreakerConfig::default().half_open_max_probes, 1);
+    assert_eq!(CircuitBreakerConfig::strict().half_open_max_probes, 1);
+    assert_eq!(CircuitBreakerConfig::lenient().half_open_max_probes, 3);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_half_open_admits_at_most_max_probes_concurrently() {
+    const MAX_PROBES: usize = 3;
+    const CALLS: usize = 32;
+
+    let cb = Arc::new(half_open_breaker(MAX_PROBES, 1));
+    let entered = Arc::new(AtomicUsize::new(0));
+    let rejected = Arc::new(AtomicUsize::new(0));
+    // Probes stay in flight until every call has been admitted or rejected
+    let release = Arc::new(Semaphore::new(0));
+
+    let handles: Vec<_> = (0..CALLS)
+        .map(|_| {
+            let cb = cb.clone();
+            let entered = entered.clone();
+            let rejected = rejected.clone();
+            let release = release.clone();
+            tokio::spawn(async move {
+                let result = cb
+                    .call(async {
+                        entered.fetch_add(1, Ordering::SeqCst);
+                        release.acquire().await.unwrap().forget();
+                        Ok::<_, ProviderError>(())
+                    })
+                    .await;
+                if matches!(result, Err(ProviderError::CircuitBreakerOpen { .. })) {
+                    rejected.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+
+    while entered.load(Ordering::SeqCst) + rejected.load(Ordering::SeqCst) < CALLS {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(entered.load(Ordering::SeqCst), MAX_PROBES);
+    assert_eq!(rejected.load(Ordering::SeqCst), CALLS - MAX_PROBES);
+    assert_eq!(cb.state(), CircuitState::HalfOpen);
+
+    release.add_permits(CALLS);
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    assert_eq!(cb.state(), CircuitState::Closed);
+}
+
+#[test]
+fn test_half_open_closes_on_majority_of_probes() {
+    let cb = half_open_breaker(3, 1);
+
+    assert!(cb.is_allowed());
+    assert!(cb.is_allowed());
+    assert!(cb.is_allowed());
+    assert!(!cb.is_allowed());
+
+    // One success of three is not yet a majority
+    cb.record_success();
+    assert_eq!(cb.state(), CircuitState::HalfOpen);
+    cb.record_success();
+    assert_eq!(cb.state(), CircuitState::Closed);
+}
+
+#[test]
+fn test_half_open_reopens_on_any_probe_failure() {
+    let cb = half_open_breaker(5, 1);
+    assert!(cb.is_allowed());
+    assert!(cb.is_allowed());
+    cb.record_success();
+    assert_eq!(cb.state(), CircuitState::HalfOpen);
+
+    cb.record_failure();
+    assert_eq!(cb.state(), CircuitState::Open);
+}
+
+#[test]
+fn test_completed_probe_frees_its_slot() {
+    // A single probe slot with two successes needed still recovers
+    let cb = half_open_breaker(1, 2);
+
+    assert!(cb.is_allowed());
+    assert!(!cb.is_allowed());
+    cb.record_success();
+    assert_eq!(cb.state(), CircuitState::HalfOpen);
+
+    assert!(cb.is_allowed());
+    cb.record_success();
+    assert_eq!(cb.state(), CircuitState::Closed);
+}
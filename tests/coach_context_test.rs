@@ -0,0 +1,167 @@
+// ABOUTME: Tests for per-tenant and per-coach coach context and the get_coach_context tool
+// ABOUTME: Verifies stored templates are rendered with athlete variables and the default fallback
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use pierre_mcp_server::coaches::{
+    references_training_load, render_coach_context, CoachContextVariables, DEFAULT_COACH_CONTEXT,
+};
+use pierre_mcp_server::database::coaches::{CoachCategory, CreateCoachRequest};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::tools::implementations::coaches::GetCoachContextTool;
+use pierre_mcp_server::tools::traits::McpTool;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext};
+use serde_json::json;
+
+// Not connected in tests, so training load variables render as unknown
+const UNCONNECTED_PROVIDER: &str = "strava";
+
+#[test]
+fn test_render_substitutes_athlete_variables() {
+    let template = "Coach {{athlete_name}}. CTL {{ctl}}, ATL {{atl}}, TSB {{tsb}}. {{other}}";
+    let variables =
+        CoachContextVariables::for_athlete("Ada").with_training_load(52.345, 60.0, -7.66);
+
+    assert_eq!(
+        render_coach_context(template, &variables),
+        "Coach Ada. CTL 52.3, ATL 60.0, TSB -7.7. {{other}}"
+    );
+    assert_eq!(
+        render_coach_context(template, &CoachContextVariables::for_athlete("Ada")),
+        "Coach Ada. CTL unknown, ATL unknown, TSB unknown. {{other}}"
+    );
+
+    assert!(references_training_load(DEFAULT_COACH_CONTEXT));
+    assert!(!references_training_load("Coach {{athlete_name}} kindly"));
+}
+
+#[tokio::test]
+async fn test_stored_context_is_returned_with_substitutions() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+    let args = json!({ "provider": UNCONNECTED_PROVIDER });
+
+    let manager = resources.coaches_manager().unwrap();
+    manager
+        .set_coach_context(
+            tenant_id,
+            None,
+            "Coach {{athlete_name}} with a CTL of {{ctl}}.",
+            user_id,
+        )
+        .await
+        .unwrap();
+
+    let result = GetCoachContextTool
+        .execute(args.clone(), &context)
+        .await
+        .unwrap();
+    assert!(!result.is_error, "{}", result.content);
+    assert_eq!(result.content["source"], "tenant");
+    assert_eq!(
+        result.content["system_context"],
+        "Coach Test User with a CTL of unknown."
+    );
+    assert_eq!(result.content["variables"]["athlete_name"], "Test User");
+
+    // Storing again replaces the tenant context
+    manager
+        .set_coach_context(tenant_id, None, "Keep {{athlete_name}} honest.", user_id)
+        .await
+        .unwrap();
+
+    // A coach context overrides the tenant one once that coach is active
+    let coach = manager
+        .create(
+            user_id,
+            tenant_id,
+            &CreateCoachRequest {
+                title: "Marathon Coach".to_owned(),
+                description: None,
+                system_prompt: "You coach marathoners.".to_owned(),
+                category: CoachCategory::default(),
+                tags: Vec::new(),
+                sample_prompts: Vec::new(),
+            },
+        )
+        .await
+        .unwrap();
+    let coach_id = coach.id.to_string();
+    manager
+        .set_coach_context(
+            tenant_id,
+            Some(&coach_id),
+            "Build {{athlete_name}} toward race day.",
+            user_id,
+        )
+        .await
+        .unwrap();
+
+    let result = GetCoachContextTool
+        .execute(args.clone(), &context)
+        .await
+        .unwrap();
+    assert_eq!(result.content["source"], "tenant");
+    assert_eq!(result.content["system_context"], "Keep Test User honest.");
+
+    manager
+        .activate_coach(&coach_id, user_id, tenant_id)
+        .await
+        .unwrap();
+    let result = GetCoachContextTool.execute(args, &context).await.unwrap();
+    assert_eq!(result.content["source"], "coach");
+    assert_eq!(result.content["coach_id"], coach_id.as_str());
+    assert_eq!(
+        result.content["system_context"],
+        "Build Test User toward race day."
+    );
+
+    assert!(manager
+        .set_coach_context(tenant_id, None, "   ", user_id)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_missing_context_falls_back_to_default() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+
+    let result = GetCoachContextTool
+        .execute(json!({ "provider": UNCONNECTED_PROVIDER }), &context)
+        .await
+        .unwrap();
+    assert!(!result.is_error, "{}", result.content);
+    assert_eq!(result.content["source"], "default");
+    assert!(result.content["updated_at"].is_null());
+    assert!(result.content["variables"]["ctl"].is_null());
+
+    let text = result.content["system_context"].as_str().unwrap();
+    assert!(text.contains("working with Test User"), "{text}");
+    assert!(text.contains("(CTL) is unknown"), "{text}");
+    assert!(!text.contains("{{"), "{text}");
+}
@@ -10,7 +10,11 @@
 use chrono::Utc;
 use pierre_mcp_server::config::environment::HttpClientConfig;
 use pierre_mcp_server::constants::{init_server_config, oauth_providers};
-use pierre_mcp_server::providers::core::{FitnessProvider, OAuth2Credentials, ProviderConfig};
+use pierre_mcp_server::intelligence::TrainingLoadCalculator;
+use pierre_mcp_server::models::SportType;
+use pierre_mcp_server::providers::core::{
+    FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use pierre_mcp_server::providers::coros_provider::CorosProvider;
 use pierre_mcp_server::providers::registry::{get_supported_providers, global_registry};
 use pierre_mcp_server::utils::http_client::initialize_http_clients;
@@ -57,6 +61,8 @@ fn test_coros_provider_with_custom_config() {
         api_base_url: "https://custom.coros.com/api".to_owned(),
         revoke_url: Some("https://custom.coros.com/revoke".to_owned()),
         default_scopes: vec!["custom:scope".to_owned()],
+        request_timeout_secs: None,
+        validation_mode: ProviderValidationMode::Lenient,
     };
 
     let provider = CorosProvider::with_config(custom_config.clone());
@@ -512,6 +518,60 @@ mod mock_api_tests {
     }
 }
 
+// ============================================================================
+// Workout Conversion Tests
+// ============================================================================
+
+/// Recorded COROS workout with device-computed training load and effects
+const WORKOUT_FIXTURE: &str = include_str!("fixtures/coros/workout_threshold_run.json");
+
+#[test]
+#[allow(clippy::float_cmp)] // Values copied verbatim from the fixture
+fn test_coros_workout_maps_device_training_metrics() {
+    let activity = CorosProvider::convert_workout_json(WORKOUT_FIXTURE).unwrap();
+
+    assert_eq!(activity.id(), "418842011239710720");
+    assert_eq!(activity.sport_type(), &SportType::Run);
+    assert_eq!(activity.duration_seconds(), 3390);
+    assert_eq!(activity.average_heart_rate(), Some(158));
+    assert_eq!(activity.average_power(), Some(291));
+    assert_eq!(activity.training_stress_score(), Some(112.0));
+    assert_eq!(activity.aerobic_training_effect(), Some(3.8));
+    assert_eq!(activity.anaerobic_training_effect(), Some(2.1));
+
+    // Device training load wins over the heart-rate estimate
+    let tss = TrainingLoadCalculator::new()
+        .calculate_tss(&activity, None, Some(165.0), None, None, None)
+        .unwrap();
+    assert!((tss - 112.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_coros_workout_without_device_metrics_falls_back_to_estimates() {
+    let mut workout: serde_json::Value = serde_json::from_str(WORKOUT_FIXTURE).unwrap();
+    let fields = workout.as_object_mut().unwrap();
+    for key in ["trainingLoad", "aerobicEffect", "anaerobicEffect"] {
+        fields.remove(key);
+    }
+
+    let activity = CorosProvider::convert_workout_json(&workout.to_string()).unwrap();
+
+    assert_eq!(activity.training_stress_score(), None);
+    assert_eq!(activity.aerobic_training_effect(), None);
+    assert_eq!(activity.anaerobic_training_effect(), None);
+
+    let tss = TrainingLoadCalculator::new()
+        .calculate_tss(&activity, None, Some(165.0), None, None, None)
+        .unwrap();
+    assert!(tss > 0.0);
+    assert!((tss - 112.0).abs() > 1.0);
+}
+
+#[test]
+fn test_coros_workout_json_rejects_malformed_payload() {
+    assert!(CorosProvider::convert_workout_json(r#"{"id": "w1"}"#).is_err());
+}
+
 // ============================================================================
 // Error Handling Tests
 // ============================================================================
@@ -0,0 +1,154 @@
+// ABOUTME: Tests for the configurable CORS allowlist on the HTTP layer
+// ABOUTME: Covers allowed and disallowed origins, preflight requests, credentials, and the wildcard fallback
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use axum::{
+    body::Body,
+    http::{
+        header::{
+            ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_METHODS,
+            ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_HEADERS,
+            ACCESS_CONTROL_REQUEST_METHOD, ORIGIN, VARY,
+        },
+        Method, Request, Response, StatusCode,
+    },
+    routing::get,
+    Json, Router,
+};
+use pierre_mcp_server::config::environment::CorsConfig;
+use pierre_mcp_server::middleware::setup_cors;
+use serde_json::json;
+use tower::ServiceExt;
+
+const DASHBOARD_ORIGIN: &str = "https://app.example.com";
+
+fn allowlist_config() -> CorsConfig {
+    CorsConfig {
+        allowed_origins: format!("{DASHBOARD_ORIGIN}, https://admin.example.com"),
+        allow_localhost_dev: false,
+    }
+}
+
+fn app(config: &CorsConfig) -> Router {
+    Router::new()
+        .route(
+            "/api/user/profile",
+            get(|| async { Json(json!({ "status": "ok" })) }).post(|| async { StatusCode::OK }),
+        )
+        .layer(setup_cors(config))
+}
+
+async fn send(config: &CorsConfig, request: Request<Body>) -> Response<Body> {
+    app(config).oneshot(request).await.unwrap()
+}
+
+fn get_from(origin: &str) -> Request<Body> {
+    Request::builder()
+        .uri("/api/user/profile")
+        .header(ORIGIN, origin)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_allowed_origin_is_echoed_with_credentials() {
+    let response = send(&allowlist_config(), get_from(DASHBOARD_ORIGIN)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers();
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], DASHBOARD_ORIGIN);
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+    // Responses vary by origin so caches don't serve one origin's headers to another
+    assert!(headers.get_all(VARY).iter().any(|v| v
+        .to_str()
+        .unwrap()
+        .to_lowercase()
+        .contains("origin")));
+
+    // The second allowlisted origin is echoed too
+    let admin = send(&allowlist_config(), get_from("https://admin.example.com")).await;
+    assert_eq!(
+        admin.headers()[ACCESS_CONTROL_ALLOW_ORIGIN],
+        "https://admin.example.com"
+    );
+}
+
+#[tokio::test]
+async fn test_disallowed_origin_gets_no_cors_headers() {
+    let response = send(&allowlist_config(), get_from("https://evil.example.net")).await;
+
+    let headers = response.headers();
+    assert!(headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+    assert!(headers.get(ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+
+    // A lookalike suffix is not an allowlisted origin
+    let lookalike = send(
+        &allowlist_config(),
+        get_from("https://app.example.com.evil.net"),
+    )
+    .await;
+    assert!(lookalike
+        .headers()
+        .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_preflight_request_for_allowed_origin() {
+    let preflight = |origin: &str| {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/api/user/profile")
+            .header(ORIGIN, origin)
+            .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(ACCESS_CONTROL_REQUEST_HEADERS, "content-type,x-csrf-token")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = send(&allowlist_config(), preflight(DASHBOARD_ORIGIN)).await;
+    assert!(response.status().is_success());
+    let headers = response.headers();
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], DASHBOARD_ORIGIN);
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+    let methods = headers[ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+    assert!(methods.contains("POST"));
+
+    let denied = send(&allowlist_config(), preflight("https://evil.example.net")).await;
+    assert!(denied.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_wildcard_allows_any_origin_without_credentials() {
+    for allowed_origins in ["*", ""] {
+        let config = CorsConfig {
+            allowed_origins: allowed_origins.to_owned(),
+            allow_localhost_dev: true,
+        };
+        assert!(config.allows_any_origin());
+
+        let response = send(&config, get_from("https://anywhere.example.org")).await;
+        let headers = response.headers();
+        assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(headers.get(ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+    }
+}
+
+#[test]
+fn test_origin_list_parsing() {
+    let config = CorsConfig {
+        allowed_origins: " https://a.example.com ,,https://b.example.com ".to_owned(),
+        allow_localhost_dev: false,
+    };
+    assert_eq!(
+        config.origins(),
+        vec!["https://a.example.com", "https://b.example.com"]
+    );
+    assert!(!config.allows_any_origin());
+    assert!(!allowlist_config().allows_any_origin());
+}
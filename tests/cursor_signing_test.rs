@@ -0,0 +1,135 @@
+// ABOUTME: Tests for HMAC-signed pagination cursors bound to endpoint, user, and query
+// ABOUTME: Verifies round-trips, tamper detection, cross-endpoint rejection, and the store route's invalid_cursor error
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+mod helpers;
+
+use axum::http::StatusCode;
+use chrono::Utc;
+use helpers::axum_test::AxumTestRequest;
+use pierre_mcp_server::errors::ErrorCode;
+use pierre_mcp_server::pagination::{Cursor, StoreCursor};
+use pierre_mcp_server::routes::store::StoreRoutes;
+use pierre_mcp_server::security::cursor_signing::{CursorScope, CursorSigner};
+use serde_json::Value;
+use uuid::Uuid;
+
+fn signer() -> CursorSigner {
+    CursorSigner::new(b"test-cursor-signing-secret")
+}
+
+fn activities_scope(user_id: Uuid) -> CursorScope {
+    CursorScope::new("activities").for_user(user_id)
+}
+
+#[test]
+fn test_signed_cursor_round_trip() {
+    let signer = signer();
+    let user_id = Uuid::new_v4();
+    let cursor = Cursor::new(Utc::now(), "activity-42");
+
+    let signed = signer.sign(&cursor, &activities_scope(user_id));
+    assert_ne!(signed, cursor);
+
+    let verified = signer.verify(&signed, &activities_scope(user_id)).unwrap();
+    assert_eq!(verified, cursor);
+    assert_eq!(verified.decode().unwrap().1, "activity-42");
+}
+
+#[test]
+fn test_tampered_cursor_is_rejected() {
+    let signer = signer();
+    let user_id = Uuid::new_v4();
+    let scope = activities_scope(user_id);
+    let signed = signer.sign(&Cursor::new(Utc::now(), "activity-42"), &scope);
+    let (_, signature) = signed.as_str().rsplit_once('.').unwrap();
+
+    // Swap in a forged position but keep the original signature
+    let forged = Cursor::new(Utc::now(), "activity-1");
+    let tampered = Cursor::from_string(format!("{}.{signature}", forged.as_str()));
+    let error = signer.verify(&tampered, &scope).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidCursor);
+
+    // Unsigned cursors and garbage are rejected too
+    for raw in [forged.as_str(), "not-a-cursor", "abc.%%%"] {
+        let error = signer
+            .verify(&Cursor::from_string(raw.to_owned()), &scope)
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidCursor);
+    }
+
+    // A cursor signed with another key does not verify
+    let other = CursorSigner::new(b"another-secret");
+    let error = other.verify(&signed, &scope).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidCursor);
+}
+
+#[test]
+fn test_cursor_from_another_endpoint_is_rejected() {
+    let signer = signer();
+    let user_id = Uuid::new_v4();
+    let signed = signer.sign(
+        &Cursor::new(Utc::now(), "activity-42"),
+        &activities_scope(user_id),
+    );
+
+    let other_endpoint = CursorScope::new("audit_events").for_user(user_id);
+    assert_eq!(
+        signer.verify(&signed, &other_endpoint).unwrap_err().code,
+        ErrorCode::InvalidCursor
+    );
+
+    // The same endpoint for another user, or with other filters, is rejected as well
+    assert_eq!(
+        signer
+            .verify(&signed, &activities_scope(Uuid::new_v4()))
+            .unwrap_err()
+            .code,
+        ErrorCode::InvalidCursor
+    );
+    assert_eq!(
+        signer
+            .verify(
+                &signed,
+                &activities_scope(user_id).with_query("sport_type=run")
+            )
+            .unwrap_err()
+            .code,
+        ErrorCode::InvalidCursor
+    );
+}
+
+#[test]
+fn test_verify_param_accepts_missing_cursor() {
+    let scope = activities_scope(Uuid::new_v4());
+
+    assert_eq!(signer().verify_param(None, &scope).unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_store_rejects_forged_cursor() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (_, user) = common::create_test_user(&resources.database).await.unwrap();
+    let token = resources
+        .auth_manager
+        .generate_token(&user, &resources.jwks_manager)
+        .unwrap();
+    let router = StoreRoutes::router(&resources);
+
+    // A well-formed but unsigned store cursor, as a client could build by hand
+    let forged = StoreCursor::newest(Uuid::new_v4().to_string(), Some(Utc::now())).encode();
+    let response = AxumTestRequest::get(&format!("/api/store/coaches?cursor={forged}"))
+        .header("authorization", &format!("Bearer {token}"))
+        .send(router)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "InvalidCursor");
+}
@@ -0,0 +1,222 @@
+// ABOUTME: Tests for user-defined custom metric expressions
+// ABOUTME: Validates expression evaluation, field whitelisting, and rejection of unsafe input
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::Utc;
+use pierre_mcp_server::intelligence::custom_metrics::{
+    compile_definitions, CustomMetricDefinition, CustomMetricError, CustomMetricExpression,
+    MAX_CUSTOM_METRICS, MAX_EXPRESSION_DEPTH, MAX_EXPRESSION_LENGTH,
+};
+use pierre_mcp_server::intelligence::metrics::AdvancedMetrics;
+use pierre_mcp_server::intelligence::MetricsCalculator;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+
+fn create_test_activity() -> Activity {
+    ActivityBuilder::new(
+        "custom_metric_activity",
+        "Threshold Ride",
+        SportType::Ride,
+        Utc::now(),
+        7200, // 2 hours
+        "test_provider",
+    )
+    .distance_meters(60_000.0)
+    .average_heart_rate(150)
+    .average_power(200)
+    .training_stress_score(120.0)
+    .build()
+}
+
+fn definition(name: &str, expression: &str) -> CustomMetricDefinition {
+    CustomMetricDefinition {
+        name: name.to_owned(),
+        expression: expression.to_owned(),
+        unit: None,
+    }
+}
+
+#[test]
+fn test_ratio_expression_evaluates_correctly() {
+    let activity = create_test_activity();
+    let metric = definition("tss_per_hour", "training_stress_score / duration_hours")
+        .compile()
+        .unwrap();
+
+    let value = metric
+        .evaluate(&activity, &AdvancedMetrics::default())
+        .unwrap();
+
+    assert!((value - 60.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_operator_precedence_and_parentheses() {
+    let activity = create_test_activity();
+    let metrics = AdvancedMetrics::default();
+
+    let flat = CustomMetricExpression::parse("1 + 2 * 3").unwrap();
+    assert!((flat.evaluate(&activity, &metrics).unwrap() - 7.0).abs() < 1e-9);
+
+    let grouped = CustomMetricExpression::parse("(1 + 2) * -3").unwrap();
+    assert!((grouped.evaluate(&activity, &metrics).unwrap() + 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_unsafe_expressions_are_rejected() {
+    assert!(matches!(
+        CustomMetricExpression::parse("std::process::exit(1)"),
+        Err(CustomMetricError::UnexpectedCharacter(':'))
+    ));
+    assert!(matches!(
+        CustomMetricExpression::parse("exp(average_power)"),
+        Err(CustomMetricError::Syntax(_))
+    ));
+    assert!(matches!(
+        CustomMetricExpression::parse("user_password / 2"),
+        Err(CustomMetricError::UnknownField(field)) if field == "user_password"
+    ));
+
+    let deeply_nested = format!(
+        "{}1{}",
+        "(".repeat(MAX_EXPRESSION_DEPTH + 1),
+        ")".repeat(MAX_EXPRESSION_DEPTH + 1)
+    );
+    assert!(matches!(
+        CustomMetricExpression::parse(&deeply_nested),
+        Err(CustomMetricError::TooLarge(_))
+    ));
+}
+
+#[test]
+fn test_invalid_names_and_duplicates_are_rejected() {
+    assert!(matches!(
+        definition("TSS Per Hour", "trimp").compile(),
+        Err(CustomMetricError::InvalidName(_))
+    ));
+
+    let duplicates = [
+        definition("ratio", "trimp"),
+        definition("ratio", "calories"),
+    ];
+    assert!(matches!(
+        compile_definitions(&duplicates),
+        Err(CustomMetricError::InvalidName(_))
+    ));
+}
+
+#[test]
+fn test_division_by_zero_and_missing_fields_yield_none() {
+    let activity = create_test_activity();
+    let metrics = AdvancedMetrics::default();
+
+    let zero_division = CustomMetricExpression::parse("distance_meters / 0").unwrap();
+    assert!(zero_division.evaluate(&activity, &metrics).is_none());
+
+    let missing_field = CustomMetricExpression::parse("calories / duration_hours").unwrap();
+    assert!(missing_field.evaluate(&activity, &metrics).is_none());
+}
+
+#[test]
+fn test_custom_metrics_exposed_on_analysis_output() {
+    let activity = create_test_activity();
+    let compiled = compile_definitions(&[
+        definition("watts_per_beat", "average_power / average_heart_rate"),
+        definition("kcal_per_km", "calories / distance_km"),
+    ])
+    .unwrap();
+
+    let metrics = MetricsCalculator::new()
+        .calculate_metrics_with_custom(&activity, &compiled)
+        .unwrap();
+
+    let watts_per_beat = metrics.custom_metrics.get("watts_per_beat").unwrap();
+    assert!((watts_per_beat - 200.0 / 150.0).abs() < 1e-9);
+    assert!(!metrics.custom_metrics.contains_key("kcal_per_km"));
+}
+
+#[test]
+fn test_custom_metrics_use_the_calculator_user_settings() {
+    let activity = ActivityBuilder::new(
+        "custom_metric_ftp_activity",
+        "Threshold Ride",
+        SportType::Ride,
+        Utc::now(),
+        3600,
+        "test_provider",
+    )
+    .average_power(200)
+    .build();
+    let compiled = compile_definitions(&[definition("if_copy", "intensity_factor")]).unwrap();
+
+    // Without an FTP there is no intensity factor to reference
+    let anonymous = MetricsCalculator::new()
+        .calculate_metrics_with_custom(&activity, &compiled)
+        .unwrap();
+    assert!(!anonymous.custom_metrics.contains_key("if_copy"));
+
+    // With the user's FTP the expression sees the same value as the metrics
+    let metrics = MetricsCalculator::new()
+        .with_user_data(Some(250.0), None, Some(185.0), None, None)
+        .calculate_metrics_with_custom(&activity, &compiled)
+        .unwrap();
+    let intensity_factor = metrics.intensity_factor.unwrap();
+    assert!((intensity_factor - 0.8).abs() < 1e-9);
+    assert!((metrics.custom_metrics["if_copy"] - intensity_factor).abs() < 1e-9);
+}
+
+#[test]
+fn test_compiled_metric_exposes_unit_and_expression() {
+    let activity = create_test_activity();
+    let compiled = compile_definitions(&[CustomMetricDefinition {
+        name: "tss_per_hour".to_owned(),
+        expression: " training_stress_score / duration_hours ".to_owned(),
+        unit: Some("TSS/h".to_owned()),
+    }])
+    .unwrap();
+
+    assert_eq!(compiled[0].name(), "tss_per_hour");
+    assert_eq!(compiled[0].unit(), Some("TSS/h"));
+    assert_eq!(
+        compiled[0].expression().as_str(),
+        "training_stress_score / duration_hours"
+    );
+
+    let metrics = MetricsCalculator::new()
+        .calculate_metrics_with_custom(&activity, &compiled)
+        .unwrap();
+    assert_eq!(
+        metrics
+            .custom_metric_units
+            .get("tss_per_hour")
+            .map(String::as_str),
+        Some("TSS/h")
+    );
+}
+
+#[test]
+fn test_expression_length_is_measured_in_characters() {
+    // Over the limit in UTF-8 bytes but not in characters, so the tokenizer
+    // (not the length check) is what rejects it
+    let multibyte = "é".repeat(MAX_EXPRESSION_LENGTH / 2 + 1);
+    assert!(multibyte.len() > MAX_EXPRESSION_LENGTH);
+    assert!(matches!(
+        CustomMetricExpression::parse(&multibyte),
+        Err(CustomMetricError::UnexpectedCharacter('é'))
+    ));
+}
+
+#[test]
+fn test_too_many_definitions_are_rejected() {
+    let definitions: Vec<_> = (0..=MAX_CUSTOM_METRICS)
+        .map(|i| definition(&format!("metric_{i}"), "trimp"))
+        .collect();
+    assert!(matches!(
+        compile_definitions(&definitions),
+        Err(CustomMetricError::TooLarge(_))
+    ));
+}
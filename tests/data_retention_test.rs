@@ -0,0 +1,182 @@
+// ABOUTME: Tests for the data retention purge of analytics and audit tables
+// ABOUTME: Seeds expired and recent rows and checks only expired rows are deleted in batches
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, Utc};
+use pierre_mcp_server::a2a::auth::A2AClient;
+use pierre_mcp_server::api_keys::ApiKeyUsage;
+use pierre_mcp_server::config::{DataRetentionConfig, RetentionTable};
+use pierre_mcp_server::database::a2a::A2AUsage;
+use pierre_mcp_server::database_plugins::factory::Database;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::{AuditEvent, AuditEventType, AuditSeverity};
+use pierre_mcp_server::services::data_retention::{purge_expired_records, TablePurge};
+
+mod common;
+
+/// Ages in days of the seeded rows: three past every default window, two recent
+const ROW_AGES_DAYS: [i64; 5] = [400, 500, 600, 1, 10];
+
+async fn seed_usage_and_audit_rows(database: &Database, now: DateTime<Utc>) {
+    let (user_id, _) = common::create_test_user(database).await.unwrap();
+    let api_key = common::create_and_store_test_api_key(database, user_id, "retention")
+        .await
+        .unwrap();
+
+    let client = A2AClient {
+        id: "retention_client".to_owned(),
+        name: "Retention Client".to_owned(),
+        description: "A2A client for retention tests".to_owned(),
+        public_key: "retention_public_key".to_owned(),
+        user_id,
+        capabilities: vec!["fitness-data-analysis".into()],
+        redirect_uris: vec!["https://retention.example.com".into()],
+        permissions: vec!["read_activities".into()],
+        rate_limit_requests: 1000,
+        rate_limit_window_seconds: 3600,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    database
+        .create_a2a_client(&client, "retention_secret", &api_key.id)
+        .await
+        .unwrap();
+
+    for age in ROW_AGES_DAYS {
+        let timestamp = now - Duration::days(age);
+
+        database
+            .record_api_key_usage(&ApiKeyUsage {
+                id: None,
+                api_key_id: api_key.id.clone(),
+                timestamp,
+                tool_name: "get_activities".to_owned(),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+
+        database
+            .record_a2a_usage(&A2AUsage {
+                id: None,
+                client_id: client.id.clone(),
+                session_token: None,
+                timestamp,
+                tool_name: "get_activities".to_owned(),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+                protocol_version: "1.0".to_owned(),
+                client_capabilities: vec![],
+                granted_scopes: vec![],
+            })
+            .await
+            .unwrap();
+
+        let mut event = AuditEvent::new(
+            AuditEventType::UserLogin,
+            AuditSeverity::Info,
+            "User logged in".to_owned(),
+            "login".to_owned(),
+            "success".to_owned(),
+        );
+        event.timestamp = timestamp;
+        database.store_audit_event(&event).await.unwrap();
+    }
+}
+
+async fn row_count(database: &Database, table: RetentionTable) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(database.sqlite_pool().unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_purge_deletes_only_expired_rows() {
+    let database = common::create_test_database().await.unwrap();
+    let now = Utc::now();
+    seed_usage_and_audit_rows(&database, now).await;
+
+    // A batch size smaller than the expired set exercises multiple batches
+    let config = DataRetentionConfig::default().with_batch_size(2);
+    let results = purge_expired_records(database.as_ref(), &config, now).await;
+
+    assert_eq!(
+        results,
+        vec![
+            TablePurge {
+                table: "api_key_usage",
+                purged: 3,
+                completed: true,
+            },
+            TablePurge {
+                table: "a2a_usage",
+                purged: 3,
+                completed: true,
+            },
+            TablePurge {
+                table: "audit_events",
+                purged: 3,
+                completed: true,
+            },
+        ]
+    );
+    for table in RetentionTable::ALL {
+        assert_eq!(row_count(&database, table).await, 2, "{table}");
+    }
+
+    // A second run finds nothing left to purge
+    let rerun = purge_expired_records(database.as_ref(), &config, now).await;
+    assert!(rerun.iter().all(|result| result.purged == 0));
+}
+
+#[tokio::test]
+async fn test_zero_day_window_keeps_rows_forever() {
+    let database = common::create_test_database().await.unwrap();
+    let now = Utc::now();
+    seed_usage_and_audit_rows(&database, now).await;
+
+    let config = DataRetentionConfig::default().with_retention_days(RetentionTable::AuditEvents, 0);
+    let results = purge_expired_records(database.as_ref(), &config, now).await;
+
+    assert!(results.iter().all(|result| result.table != "audit_events"));
+    assert_eq!(row_count(&database, RetentionTable::AuditEvents).await, 5);
+    assert_eq!(row_count(&database, RetentionTable::ApiKeyUsage).await, 2);
+}
+
+#[tokio::test]
+async fn test_per_table_windows_set_the_cutoff() {
+    let now = Utc::now();
+    let config = DataRetentionConfig::default()
+        .with_retention_days(RetentionTable::ApiKeyUsage, 7)
+        .with_batch_size(0);
+
+    assert_eq!(
+        config.purge_cutoff(RetentionTable::ApiKeyUsage, now),
+        Some(now - Duration::days(7))
+    );
+    assert_eq!(
+        config.purge_cutoff(RetentionTable::AuditEvents, now),
+        Some(now - Duration::days(365))
+    );
+    assert_eq!(config.retention_days(RetentionTable::A2aUsage), 90);
+    // Batches always delete at least one row
+    assert_eq!(config.batch_size(), 1);
+}
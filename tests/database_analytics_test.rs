@@ -1,5 +1,5 @@
 // ABOUTME: Integration tests for database analytics functionality
-// ABOUTME: Tests JWT usage tracking, goals management, insights storage, and system stats
+// ABOUTME: Tests JWT usage tracking, goals management, insights storage and de-duplication, and system stats
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -129,6 +129,133 @@ async fn test_insights_storage() {
     assert_eq!(insights[0]["type"], "performance_trend");
 }
 
+fn analysis_batch() -> Vec<serde_json::Value> {
+    vec![
+        serde_json::json!({
+            "type": "performance_trend",
+            "message": "Your pace has improved by 5% over the last month",
+            "severity": "positive"
+        }),
+        serde_json::json!({
+            "type": "recovery",
+            "message": "Three hard sessions in a row, consider an easy day",
+            "severity": "warning"
+        }),
+        serde_json::json!({
+            "type": "consistency",
+            "message": "You trained on 5 of the last 7 days",
+            "severity": "positive"
+        }),
+    ]
+}
+
+#[tokio::test]
+async fn test_insights_batch_rerun_creates_no_duplicates() {
+    let db = common::create_test_database()
+        .await
+        .expect("Failed to create test database");
+
+    let user = create_test_user(&db).await;
+
+    let first_ids = db
+        .store_insights_batch(user.id, analysis_batch())
+        .await
+        .expect("Failed to store insight batch");
+    assert_eq!(first_ids.len(), 3);
+
+    // Re-running the same analysis returns the existing insights
+    let second_ids = db
+        .store_insights_batch(user.id, analysis_batch())
+        .await
+        .expect("Failed to store insight batch again");
+    assert_eq!(second_ids, first_ids);
+
+    let insights = db
+        .get_user_insights(user.id, None, Some(10))
+        .await
+        .expect("Failed to get user insights");
+    assert_eq!(insights.len(), 3);
+
+    // Key order does not make an insight distinct
+    let reordered = serde_json::json!({
+        "severity": "positive",
+        "message": "Your pace has improved by 5% over the last month",
+        "type": "performance_trend"
+    });
+    let reordered_id = db
+        .store_insight(user.id, reordered)
+        .await
+        .expect("Failed to store insight");
+    assert_eq!(reordered_id, first_ids[0]);
+
+    // Identical insights for another user are kept separately
+    let other = create_test_user(&db).await;
+    let other_ids = db
+        .store_insights_batch(other.id, analysis_batch())
+        .await
+        .expect("Failed to store insight batch for other user");
+    assert!(other_ids.iter().all(|id| !first_ids.contains(id)));
+}
+
+#[tokio::test]
+async fn test_insights_batch_mixed_new_and_existing() {
+    let db = common::create_test_database()
+        .await
+        .expect("Failed to create test database");
+
+    let user = create_test_user(&db).await;
+    let existing = analysis_batch();
+
+    let existing_id = db
+        .store_insight(user.id, existing[0].clone())
+        .await
+        .expect("Failed to store insight");
+
+    let new_insight = serde_json::json!({
+        "type": "volume",
+        "message": "Weekly distance is up 12%",
+        "severity": "info"
+    });
+    let ids = db
+        .store_insights_batch(
+            user.id,
+            vec![
+                existing[0].clone(),
+                new_insight.clone(),
+                existing[1].clone(),
+                // Repeated within the same batch
+                new_insight,
+            ],
+        )
+        .await
+        .expect("Failed to store mixed insight batch");
+
+    assert_eq!(ids.len(), 4);
+    assert_eq!(ids[0], existing_id);
+    assert_ne!(ids[1], existing_id);
+    assert_eq!(ids[3], ids[1]);
+
+    let insights = db
+        .get_user_insights(user.id, None, Some(10))
+        .await
+        .expect("Failed to get user insights");
+    assert_eq!(insights.len(), 3);
+    let types: Vec<&str> = insights
+        .iter()
+        .map(|insight| insight["type"].as_str().unwrap())
+        .collect();
+    for expected in ["performance_trend", "recovery", "volume"] {
+        assert_eq!(types.iter().filter(|t| **t == expected).count(), 1);
+    }
+
+    // An empty batch is a no-op
+    assert!(db
+        .store_insights_batch(user.id, Vec::new())
+        .await
+        .expect("Failed to store empty batch")
+        .is_empty());
+}
+
 #[tokio::test]
 async fn test_system_stats() {
     let db = common::create_test_database()
@@ -0,0 +1,321 @@
+// ABOUTME: Tests for manual merges of duplicate activities and their undo
+// ABOUTME: Merges a heart-rate-only and a power-only record of one ride, joins a split run, then unmerges them
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, TimeZone, Utc};
+use pierre_mcp_server::{
+    database_plugins::{factory::Database, DatabaseProvider},
+    models::{
+        Activity, ActivityBuilder, ActivityMergeConfig, ActivityMergeKind, SportType, TenantId,
+    },
+    services::duplicate_merge::{
+        apply_duplicate_merges, merge_duplicate_activities, merge_split_recordings,
+        unmerge_duplicate_activities,
+    },
+};
+use uuid::Uuid;
+
+/// The ride as recorded by a heart rate watch: heart rate, no power
+fn watch_ride() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 7, 0, 0).unwrap();
+    ActivityBuilder::new(
+        "w-1",
+        "Morning Ride",
+        SportType::Ride,
+        start,
+        3600,
+        "garmin",
+    )
+    .distance_meters(30_000.0)
+    .average_heart_rate(142)
+    .max_heart_rate(171)
+    .calories(800)
+    .build()
+}
+
+/// The same ride as recorded by a bike computer: power, no heart rate
+fn bike_ride() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 7, 0, 40).unwrap();
+    ActivityBuilder::new("s-1", "Ride", SportType::Ride, start, 3560, "strava")
+        .distance_meters(29_800.0)
+        .average_power(210)
+        .max_power(650)
+        .calories(760)
+        .build()
+}
+
+/// An unrelated run later that day
+fn evening_run() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 18, 0, 0).unwrap();
+    ActivityBuilder::new("s-2", "Evening Run", SportType::Run, start, 1800, "strava")
+        .average_heart_rate(150)
+        .build()
+}
+
+/// The first part of a run whose recording was stopped at a traffic light
+fn run_first_part() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 2, 7, 0, 0).unwrap();
+    ActivityBuilder::new("p-1", "Morning Run", SportType::Run, start, 1800, "garmin")
+        .distance_meters(6000.0)
+        .average_heart_rate(140)
+        .calories(400)
+        .build()
+}
+
+/// The rest of that run, restarted five minutes later
+fn run_second_part() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 2, 7, 35, 0).unwrap();
+    ActivityBuilder::new("p-2", "Morning Run", SportType::Run, start, 1200, "garmin")
+        .distance_meters(4000.0)
+        .average_heart_rate(150)
+        .calories(300)
+        .build()
+}
+
+async fn setup() -> (Arc<Database>, Uuid, TenantId) {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+    (database, user_id, tenant_id)
+}
+
+fn listed(activities: &[Activity]) -> Vec<&str> {
+    activities.iter().map(Activity::id).collect()
+}
+
+#[tokio::test]
+async fn test_merge_fills_gaps_from_secondary_and_hides_it() {
+    let (database, user_id, tenant_id) = setup().await;
+    let now = Utc::now();
+
+    let outcome = merge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        watch_ride(),
+        bike_ride(),
+        now,
+    )
+    .await
+    .unwrap();
+
+    // The merged record has both heart rate and power; the primary's own values win
+    let merged = &outcome.activity;
+    assert_eq!(merged.id(), "w-1");
+    assert_eq!(merged.provider(), "garmin");
+    assert_eq!(merged.average_heart_rate(), Some(142));
+    assert_eq!(merged.average_power(), Some(210));
+    assert_eq!(merged.calories(), Some(800));
+    assert_eq!(merged.distance_meters(), Some(30_000.0));
+
+    // Provenance records both sides and what the secondary supplied
+    let merge = &outcome.merge;
+    assert_eq!(merge.primary_provider, "garmin");
+    assert_eq!(merge.primary_id, "w-1");
+    assert_eq!(merge.secondary_provider, "strava");
+    assert_eq!(merge.secondary_id, "s-1");
+    assert_eq!(merge.filled_fields, ["average_power", "max_power"]);
+    assert_eq!(merge.merged_at, now);
+
+    // Listings hide the secondary and show the merged primary
+    let merges = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(merges.len(), 1);
+    let listing = apply_duplicate_merges(vec![watch_ride(), bike_ride(), evening_run()], &merges);
+    assert_eq!(listed(&listing), ["w-1", "s-2"]);
+    assert_eq!(listing[0].average_heart_rate(), Some(142));
+    assert_eq!(listing[0].average_power(), Some(210));
+    assert_eq!(listing[1].average_power(), None);
+
+    // A listing without the secondary (e.g. only the watch's provider) fills from the snapshot
+    let watch_only = apply_duplicate_merges(vec![watch_ride()], &merges);
+    assert_eq!(watch_only[0].max_power(), Some(650));
+}
+
+#[tokio::test]
+async fn test_unmerge_restores_both_activities() {
+    let (database, user_id, tenant_id) = setup().await;
+    merge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        watch_ride(),
+        bike_ride(),
+        Utc::now(),
+    )
+    .await
+    .unwrap();
+
+    let removed = unmerge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        ("garmin", "w-1"),
+        ("strava", "s-1"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(removed.secondary.id(), "s-1");
+    assert_eq!(removed.secondary.average_power(), Some(210));
+
+    let merges = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await
+        .unwrap();
+    assert!(merges.is_empty());
+
+    // Both records are listed again, each with only its own metrics
+    let listing = apply_duplicate_merges(vec![watch_ride(), bike_ride(), evening_run()], &merges);
+    assert_eq!(listed(&listing), ["w-1", "s-1", "s-2"]);
+    assert_eq!(listing[0].average_heart_rate(), Some(142));
+    assert_eq!(listing[0].average_power(), None);
+    assert_eq!(listing[1].average_power(), Some(210));
+    assert_eq!(listing[1].average_heart_rate(), None);
+
+    // The merge is gone, so undoing it again fails
+    assert!(unmerge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        ("garmin", "w-1"),
+        ("strava", "s-1"),
+    )
+    .await
+    .is_err());
+
+    // And the pair can be merged again afterwards
+    let again = merge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        watch_ride(),
+        bike_ride(),
+        Utc::now() + Duration::minutes(1),
+    )
+    .await
+    .unwrap();
+    assert_eq!(again.activity.average_power(), Some(210));
+}
+
+#[tokio::test]
+async fn test_merge_rejects_self_repeated_and_chained_merges() {
+    let (database, user_id, tenant_id) = setup().await;
+    let merge = |primary: Activity, secondary: Activity| {
+        let database = database.clone();
+        async move {
+            merge_duplicate_activities(
+                database.as_ref(),
+                user_id,
+                tenant_id,
+                primary,
+                secondary,
+                Utc::now(),
+            )
+            .await
+        }
+    };
+
+    assert!(merge(watch_ride(), watch_ride()).await.is_err());
+    merge(watch_ride(), bike_ride()).await.unwrap();
+
+    // The secondary is already hidden behind the watch ride
+    assert!(merge(evening_run(), bike_ride()).await.is_err());
+    // A hidden activity cannot become a primary
+    assert!(merge(bike_ride(), evening_run()).await.is_err());
+    // An activity with merges of its own cannot be hidden
+    assert!(merge(evening_run(), watch_ride()).await.is_err());
+
+    let merges = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(merges.len(), 1);
+
+    // Another user's listings are unaffected
+    let (other_user, _) = common::create_test_user_with_email(&database, "other@example.com")
+        .await
+        .unwrap();
+    let other_merges = database
+        .list_duplicate_merges(other_user, tenant_id)
+        .await
+        .unwrap();
+    assert!(other_merges.is_empty());
+}
+
+#[tokio::test]
+async fn test_split_merge_joins_recordings_under_the_earlier_one() {
+    let (database, user_id, tenant_id) = setup().await;
+    let split = |first: Activity, second: Activity| {
+        let database = database.clone();
+        async move {
+            merge_split_recordings(
+                database.as_ref(),
+                user_id,
+                tenant_id,
+                first,
+                second,
+                &ActivityMergeConfig::default(),
+                Utc::now(),
+            )
+            .await
+        }
+    };
+
+    // Hours apart and a different sport, so not one workout
+    assert!(split(run_first_part(), watch_ride()).await.is_err());
+
+    // Passed latest first, the earlier part still becomes the primary
+    let outcome = split(run_second_part(), run_first_part()).await.unwrap();
+    assert_eq!(outcome.merge.kind, ActivityMergeKind::Split);
+    assert_eq!(outcome.merge.primary_id, "p-1");
+    assert_eq!(outcome.merge.secondary_id, "p-2");
+    assert!(outcome.merge.filled_fields.is_empty());
+    assert_eq!(outcome.activity.duration_seconds(), 3000);
+    assert_eq!(outcome.activity.distance_meters(), Some(10_000.0));
+    assert_eq!(outcome.activity.calories(), Some(700));
+    assert_eq!(outcome.activity.average_heart_rate(), Some(144));
+
+    // Listings show the joined run in place of both parts
+    let merges = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(merges[0].kind, ActivityMergeKind::Split);
+    let listing = apply_duplicate_merges(
+        vec![run_second_part(), run_first_part(), evening_run()],
+        &merges,
+    );
+    assert_eq!(listed(&listing), ["p-1", "s-2"]);
+    assert_eq!(listing[0].duration_seconds(), 3000);
+    assert_eq!(listing[0].distance_meters(), Some(10_000.0));
+
+    // Undoing the merge lists both parts with their own totals again
+    let removed = unmerge_duplicate_activities(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        ("garmin", "p-1"),
+        ("garmin", "p-2"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(removed.kind, ActivityMergeKind::Split);
+    let merges = database
+        .list_duplicate_merges(user_id, tenant_id)
+        .await
+        .unwrap();
+    let listing = apply_duplicate_merges(vec![run_first_part(), run_second_part()], &merges);
+    assert_eq!(listed(&listing), ["p-1", "p-2"]);
+    assert_eq!(listing[0].duration_seconds(), 1800);
+}
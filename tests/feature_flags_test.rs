@@ -0,0 +1,280 @@
+// ABOUTME: Tests for admin-configurable feature flags cached from the database
+// ABOUTME: Verifies typed values, tenant/global/default resolution, and tool gating without a restart
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use pierre_mcp_server::config::ToolSelectionConfig;
+use pierre_mcp_server::intelligence::algorithms::RecoveryAggregationAlgorithm;
+use pierre_mcp_server::mcp::tool_selection::ToolSelectionService;
+use pierre_mcp_server::models::{
+    FeatureFlagSource, FeatureFlagValue, TenantId, ToolEnablementSource,
+};
+use pierre_mcp_server::services::feature_flags::{FeatureFlags, RECOVERY_AGGREGATION_FLAG};
+
+async fn enabled_tool_names(service: &ToolSelectionService, tenant_id: TenantId) -> Vec<String> {
+    service
+        .get_enabled_tools(tenant_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tool| tool.tool_name)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_tool_flag_toggles_tool_without_restart() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let flags = &resources.feature_flags;
+    let tool_selection = &resources.tool_selection;
+    let tenant_id = TenantId::new();
+
+    // Prime the tool selection cache before any flag exists
+    assert!(enabled_tool_names(tool_selection, tenant_id)
+        .await
+        .contains(&"get_activities".to_owned()));
+
+    flags
+        .define("tool.get_activities", FeatureFlagValue::Bool(true), None)
+        .await
+        .unwrap();
+    flags
+        .set(
+            "tool.get_activities",
+            Some(tenant_id),
+            FeatureFlagValue::Bool(false),
+        )
+        .await
+        .unwrap();
+
+    assert!(!tool_selection
+        .is_tool_enabled(tenant_id, "get_activities")
+        .await
+        .unwrap());
+    assert!(!enabled_tool_names(tool_selection, tenant_id)
+        .await
+        .contains(&"get_activities".to_owned()));
+    let gated = tool_selection
+        .get_effective_tools(tenant_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|tool| tool.tool_name == "get_activities")
+        .unwrap();
+    assert_eq!(gated.source, ToolEnablementSource::FeatureFlag);
+
+    // Other tenants still see the flag's default
+    assert!(tool_selection
+        .is_tool_enabled(TenantId::new(), "get_activities")
+        .await
+        .unwrap());
+
+    // Clearing the tenant's value makes the tool available again
+    assert!(flags
+        .clear("tool.get_activities", Some(tenant_id))
+        .await
+        .unwrap());
+    assert!(tool_selection
+        .is_tool_enabled(tenant_id, "get_activities")
+        .await
+        .unwrap());
+    assert!(enabled_tool_names(tool_selection, tenant_id)
+        .await
+        .contains(&"get_activities".to_owned()));
+}
+
+#[tokio::test]
+async fn test_global_disabling_beats_tool_flag() {
+    let database = common::create_test_database().await.unwrap();
+    let flags = Arc::new(FeatureFlags::new(database.clone()));
+    let service = ToolSelectionService::with_config(
+        database,
+        ToolSelectionConfig::with_disabled_tools(vec!["get_activities".to_owned()]),
+    )
+    .with_feature_flags(flags.clone());
+    let tenant_id = TenantId::new();
+
+    flags
+        .define("tool.get_activities", FeatureFlagValue::Bool(true), None)
+        .await
+        .unwrap();
+
+    assert!(!service
+        .is_tool_enabled(tenant_id, "get_activities")
+        .await
+        .unwrap());
+    assert!(!enabled_tool_names(&service, tenant_id)
+        .await
+        .contains(&"get_activities".to_owned()));
+}
+
+#[tokio::test]
+async fn test_tenant_value_overrides_global_value_and_default() {
+    let database = common::create_test_database().await.unwrap();
+    let flags = FeatureFlags::new(database);
+    let tenant_id = TenantId::new();
+
+    flags
+        .define(
+            "insights.max_per_week",
+            FeatureFlagValue::Number(3.0),
+            Some("Insights generated per week".to_owned()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        flags.number_value("insights.max_per_week", Some(tenant_id)),
+        Some(3.0)
+    );
+
+    flags
+        .set("insights.max_per_week", None, FeatureFlagValue::Number(5.0))
+        .await
+        .unwrap();
+    flags
+        .set(
+            "insights.max_per_week",
+            Some(tenant_id),
+            FeatureFlagValue::Number(10.0),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(flags.number_value("insights.max_per_week", None), Some(5.0));
+    assert_eq!(
+        flags.number_value("insights.max_per_week", Some(TenantId::new())),
+        Some(5.0)
+    );
+    assert_eq!(
+        flags.number_value("insights.max_per_week", Some(tenant_id)),
+        Some(10.0)
+    );
+
+    let listed = flags.list(Some(tenant_id));
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].source, FeatureFlagSource::Tenant);
+    assert_eq!(
+        flags.list(None)[0].source,
+        FeatureFlagSource::Global,
+        "without a tenant the global value applies"
+    );
+
+    // Removing the global value falls back to the default
+    assert!(flags.clear("insights.max_per_week", None).await.unwrap());
+    assert_eq!(flags.number_value("insights.max_per_week", None), Some(3.0));
+    assert_eq!(flags.list(None)[0].source, FeatureFlagSource::Default);
+
+    // Deleting the flag removes every value
+    assert!(flags.delete("insights.max_per_week").await.unwrap());
+    assert_eq!(flags.value("insights.max_per_week", Some(tenant_id)), None);
+    assert!(!flags.delete("insights.max_per_week").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_flag_values_must_match_the_flag_type() {
+    let database = common::create_test_database().await.unwrap();
+    let flags = FeatureFlags::new(database);
+
+    flags
+        .define("tool.get_athlete", FeatureFlagValue::Bool(true), None)
+        .await
+        .unwrap();
+
+    flags
+        .set("tool.get_athlete", None, FeatureFlagValue::Number(1.0))
+        .await
+        .unwrap_err();
+    flags
+        .define(
+            "tool.get_athlete",
+            FeatureFlagValue::String("on".to_owned()),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(flags.bool_value("tool.get_athlete", None), Some(true));
+
+    // Tool flags must be booleans, and keys are validated
+    flags
+        .define("tool.list_gear", FeatureFlagValue::Number(1.0), None)
+        .await
+        .unwrap_err();
+    flags
+        .define("Bad Key", FeatureFlagValue::Bool(true), None)
+        .await
+        .unwrap_err();
+
+    // Values cannot be set on undefined flags
+    flags
+        .set("undefined.flag", None, FeatureFlagValue::Bool(true))
+        .await
+        .unwrap_err();
+}
+
+#[tokio::test]
+async fn test_refresh_picks_up_changes_from_another_instance() {
+    let database = common::create_test_database().await.unwrap();
+    let writer = FeatureFlags::new(database.clone());
+    let reader = FeatureFlags::new(database);
+
+    writer
+        .define("tool.get_athlete", FeatureFlagValue::Bool(false), None)
+        .await
+        .unwrap();
+    assert_eq!(reader.bool_value("tool.get_athlete", None), None);
+
+    reader.refresh().await.unwrap();
+    assert_eq!(reader.bool_value("tool.get_athlete", None), Some(false));
+}
+
+#[tokio::test]
+async fn test_recovery_aggregation_flag_selects_algorithm() {
+    let database = common::create_test_database().await.unwrap();
+    let flags = FeatureFlags::new(database);
+    let tenant_id = TenantId::new();
+
+    assert_eq!(flags.recovery_aggregation(Some(tenant_id)), None);
+
+    flags
+        .define(
+            RECOVERY_AGGREGATION_FLAG,
+            FeatureFlagValue::String("weighted_average".to_owned()),
+            None,
+        )
+        .await
+        .unwrap();
+    flags
+        .set(
+            RECOVERY_AGGREGATION_FLAG,
+            Some(tenant_id),
+            FeatureFlagValue::String("geometric_mean".to_owned()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        flags.recovery_aggregation(Some(tenant_id)),
+        Some(RecoveryAggregationAlgorithm::GeometricMean)
+    );
+    assert!(matches!(
+        flags.recovery_aggregation(None),
+        Some(RecoveryAggregationAlgorithm::WeightedAverage { .. })
+    ));
+
+    // Unknown algorithm names are rejected
+    flags
+        .set(
+            RECOVERY_AGGREGATION_FLAG,
+            None,
+            FeatureFlagValue::String("median".to_owned()),
+        )
+        .await
+        .unwrap_err();
+}
@@ -0,0 +1,194 @@
+// ABOUTME: Tests for Fitbit intraday heart rate series mapped from recorded API payloads
+// ABOUTME: Covers series normalization, the missing_scope error, and measured zone distribution
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, TimeZone, Utc};
+use pierre_mcp_server::errors::{AppError, ErrorCode};
+use pierre_mcp_server::intelligence::ActivityAnalyzer;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeries};
+use pierre_mcp_server::providers::errors::ProviderError;
+use pierre_mcp_server::providers::fitbit_provider::{
+    FitbitIntradayHeartRateResponse, FitbitProvider,
+};
+use serde_json::from_str;
+
+/// GET /1/user/-/activities/heart/date/2024-05-11/1d/1sec/time/07:30/07:32.json
+const INTRADAY_SECOND_JSON: &str = r#"{
+    "activities-heart": [
+        {
+            "dateTime": "2024-05-11",
+            "value": {
+                "customHeartRateZones": [],
+                "heartRateZones": [
+                    {"caloriesOut": 2.1, "max": 112, "min": 30, "minutes": 0, "name": "Out of Range"},
+                    {"caloriesOut": 8.4, "max": 136, "min": 112, "minutes": 1, "name": "Fat Burn"},
+                    {"caloriesOut": 6.2, "max": 166, "min": 136, "minutes": 1, "name": "Cardio"},
+                    {"caloriesOut": 0.9, "max": 220, "min": 166, "minutes": 0, "name": "Peak"}
+                ],
+                "value": "62.4"
+            }
+        }
+    ],
+    "activities-heart-intraday": {
+        "dataset": [
+            {"time": "07:30:10", "value": 76},
+            {"time": "07:30:15", "value": 98},
+            {"time": "07:30:20", "value": 112},
+            {"time": "07:30:27", "value": 131},
+            {"time": "07:30:41", "value": 148},
+            {"time": "07:31:02", "value": 163},
+            {"time": "07:31:15", "value": 170},
+            {"time": "07:31:30", "value": 150}
+        ],
+        "datasetInterval": 1,
+        "datasetType": "second"
+    }
+}"#;
+
+/// The same endpoint at 1min detail, as returned to apps without 1sec access
+const INTRADAY_MINUTE_JSON: &str = r#"{
+    "activities-heart": [{"dateTime": "2024-05-11", "value": {"heartRateZones": []}}],
+    "activities-heart-intraday": {
+        "dataset": [
+            {"time": "07:29:00", "value": 71},
+            {"time": "07:30:00", "value": 95},
+            {"time": "07:31:00", "value": 128},
+            {"time": "07:32:00", "value": 141},
+            {"time": "07:33:00", "value": 152},
+            {"time": "07:34:00", "value": 110}
+        ],
+        "datasetInterval": 1,
+        "datasetType": "minute"
+    }
+}"#;
+
+fn activity_start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, 11, 7, 30, 15).unwrap()
+}
+
+fn recorded_series() -> TimeSeries<u16> {
+    let response: FitbitIntradayHeartRateResponse = from_str(INTRADAY_SECOND_JSON).unwrap();
+    FitbitProvider::convert_intraday_heart_rate(response, activity_start(), 60).unwrap()
+}
+
+fn fitbit_run(max_heart_rate: Option<u32>) -> Activity {
+    ActivityBuilder::new(
+        "48213377521",
+        "Run",
+        SportType::Run,
+        activity_start(),
+        60,
+        "fitbit",
+    )
+    .max_heart_rate_opt(max_heart_rate)
+    .build()
+}
+
+#[test]
+fn test_intraday_second_series_maps_to_activity_offsets() {
+    let series = recorded_series();
+
+    assert_eq!(series.start_time, activity_start());
+    assert_eq!(series.resolution_seconds, 1);
+    // Samples before the start and after the end of the activity are dropped
+    assert_eq!(series.offsets, vec![0, 5, 12, 26, 47, 60]);
+    assert_eq!(series.values, vec![98, 112, 131, 148, 163, 170]);
+
+    let (first_time, first_value) = series.samples().next().unwrap();
+    assert_eq!(first_time, activity_start());
+    assert_eq!(*first_value, 98);
+}
+
+#[test]
+fn test_intraday_minute_series_keeps_the_minute_covering_the_start() {
+    let response: FitbitIntradayHeartRateResponse = from_str(INTRADAY_MINUTE_JSON).unwrap();
+    let series =
+        FitbitProvider::convert_intraday_heart_rate(response, activity_start(), 180).unwrap();
+
+    assert_eq!(series.resolution_seconds, 60);
+    assert_eq!(series.offsets, vec![0, 45, 105, 165]);
+    assert_eq!(series.values, vec![95, 128, 141, 152]);
+}
+
+#[test]
+fn test_intraday_invalid_sample_time_is_rejected() {
+    let json = INTRADAY_MINUTE_JSON.replace("07:31:00", "7.31");
+    let response: FitbitIntradayHeartRateResponse = from_str(&json).unwrap();
+
+    let result = FitbitProvider::convert_intraday_heart_rate(response, activity_start(), 180);
+    assert!(matches!(result, Err(ProviderError::InvalidData { .. })));
+}
+
+#[test]
+fn test_intraday_endpoint_covers_the_activity() {
+    assert_eq!(
+        FitbitProvider::intraday_heart_rate_endpoint(activity_start(), 3600),
+        "user/-/activities/heart/date/2024-05-11/1d/1sec/time/07:30/08:31.json"
+    );
+
+    // Ranges cannot span days; the series stops at midnight
+    let late = Utc.with_ymd_and_hms(2024, 5, 11, 23, 30, 0).unwrap();
+    assert_eq!(
+        FitbitProvider::intraday_heart_rate_endpoint(late, 3600),
+        "user/-/activities/heart/date/2024-05-11/1d/1sec/time/23:30/23:59.json"
+    );
+}
+
+#[test]
+fn test_missing_scope_maps_to_permission_denied() {
+    let error = ProviderError::MissingScope {
+        provider: "fitbit".to_owned(),
+        scopes: vec!["heartrate".to_owned()],
+    };
+    assert!(!error.is_retryable());
+
+    let app_error = AppError::from(error);
+    assert_eq!(app_error.code, ErrorCode::PermissionDenied);
+    assert!(app_error.message.contains("heartrate"));
+}
+
+#[test]
+fn test_attached_series_fills_heart_rate_summary() {
+    let mut activity = fitbit_run(None);
+    let series = recorded_series();
+
+    assert!(activity.attach_heart_rate_series(&series));
+    let streams = activity.time_series_data().unwrap();
+    assert_eq!(streams.timestamps, series.offsets);
+    assert_eq!(
+        streams.heart_rate.as_deref(),
+        Some(&[98, 112, 131, 148, 163, 170][..])
+    );
+    assert_eq!(activity.max_heart_rate(), Some(170));
+    assert_eq!(activity.average_heart_rate(), Some(137));
+
+    // An existing heart rate stream is never replaced
+    assert!(!activity.attach_heart_rate_series(&series));
+}
+
+#[test]
+fn test_zone_distribution_prefers_measured_series() {
+    let mut activity = fitbit_run(Some(180));
+    activity.attach_heart_rate_series(&recorded_series());
+
+    let intelligence = ActivityAnalyzer::new()
+        .analyze_activity(&activity, None)
+        .unwrap();
+    let zones = intelligence
+        .performance_indicators
+        .zone_distribution
+        .unwrap();
+
+    // 98/180 = 54% ... 170/180 = 94% of max HR: one sample in each of zones 1-4, two in zone 5
+    let one_sample = 100.0 / 6.0;
+    assert!((zones.zone1_recovery - one_sample).abs() < 0.01);
+    assert!((zones.zone2_endurance - one_sample).abs() < 0.01);
+    assert!((zones.zone3_tempo - one_sample).abs() < 0.01);
+    assert!((zones.zone4_threshold - one_sample).abs() < 0.01);
+    assert!((zones.zone5_vo2max - 2.0 * one_sample).abs() < 0.01);
+}
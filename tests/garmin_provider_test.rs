@@ -7,16 +7,26 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
 
-use chrono::Utc;
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
 use pierre_mcp_server::config::environment::HttpClientConfig;
 use pierre_mcp_server::constants::{
     api_provider_limits, init_server_config, oauth, oauth_providers,
 };
-use pierre_mcp_server::providers::core::{FitnessProvider, OAuth2Credentials, ProviderConfig};
+use pierre_mcp_server::providers::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use pierre_mcp_server::providers::garmin_provider::GarminProvider;
 use pierre_mcp_server::providers::registry::{get_supported_providers, global_registry};
 use pierre_mcp_server::utils::http_client::initialize_http_clients;
-use std::sync::Once;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Once};
+use tokio::net::TcpListener;
 
 /// Ensure HTTP clients and server config are initialized only once across all tests
 static INIT_HTTP_CLIENTS: Once = Once::new();
@@ -60,6 +70,8 @@ fn test_garmin_provider_with_custom_config() {
         api_base_url: "https://custom.garmin.com/api".to_owned(),
         revoke_url: Some("https://custom.garmin.com/revoke".to_owned()),
         default_scopes: vec!["custom:scope".to_owned()],
+        request_timeout_secs: None,
+        validation_mode: ProviderValidationMode::Lenient,
     };
 
     let provider = GarminProvider::with_config(custom_config.clone());
@@ -453,3 +465,93 @@ fn test_garmin_rate_limit_constants() {
         60
     );
 }
+
+/// Activities in the Garmin stub, newest first, one hour apart
+const STUB_ACTIVITY_COUNT: usize = 500;
+
+type PageRequests = Arc<Mutex<Vec<(usize, usize)>>>;
+
+fn stub_start_time(index: usize) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+        - Duration::hours(i64::try_from(index).unwrap())
+}
+
+async fn stub_activity_page(
+    State(requests): State<PageRequests>,
+    Query(query): Query<HashMap<String, usize>>,
+) -> Json<Value> {
+    let start = query["start"];
+    let limit = query["limit"];
+    requests.lock().unwrap().push((start, limit));
+    let page: Vec<Value> = (start..STUB_ACTIVITY_COUNT.min(start + limit))
+        .map(|index| {
+            json!({
+                "activity_id": index,
+                "activity_name": format!("Run {index}"),
+                "activity_type": "running",
+                "start_time_gmt": stub_start_time(index).to_rfc3339(),
+            })
+        })
+        .collect();
+    Json(Value::Array(page))
+}
+
+/// Serve the Garmin activity list endpoint, returning the API base URL
+async fn start_garmin_activity_stub(requests: PageRequests) -> String {
+    let app = Router::new()
+        .route(
+            "/activitylist-service/activities/search/activities",
+            get(stub_activity_page),
+        )
+        .with_state(requests);
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    base_url
+}
+
+#[tokio::test]
+async fn test_garmin_date_bounds_apply_before_limit_and_offset() {
+    ensure_http_clients_initialized();
+    let requests = PageRequests::default();
+    let base_url = start_garmin_activity_stub(requests.clone()).await;
+
+    let provider = GarminProvider::with_config(ProviderConfig {
+        api_base_url: base_url,
+        ..GarminProvider::new().config().clone()
+    });
+    provider
+        .set_credentials(OAuth2Credentials {
+            client_id: "test_client_id".to_owned(),
+            client_secret: "test_client_secret".to_owned(),
+            access_token: Some("test_access_token".to_owned()),
+            refresh_token: None,
+            expires_at: Some(Utc::now() + Duration::hours(1)),
+            scopes: vec![],
+        })
+        .await
+        .unwrap();
+
+    // Activities 221..=240 are in range, all on the second Garmin page
+    let params = ActivityQueryParams {
+        limit: Some(5),
+        offset: Some(3),
+        before: Some(stub_start_time(220)),
+        after: Some(stub_start_time(240)),
+    };
+
+    let activities = provider.get_activities_with_params(&params).await.unwrap();
+    let ids: Vec<&str> = activities.iter().map(|activity| activity.id()).collect();
+    assert_eq!(ids, ["224", "225", "226", "227", "228"]);
+
+    // Paging stops once the list is older than the `after` bound
+    let max_page = api_provider_limits::garmin::MAX_ACTIVITIES_PER_REQUEST;
+    assert_eq!(
+        *requests.lock().unwrap(),
+        [(0, max_page), (max_page, max_page)]
+    );
+}
@@ -0,0 +1,196 @@
+// ABOUTME: Tests for resumable Garmin sessions stored in the user OAuth token table
+// ABOUTME: Covers token reuse within the login interval, OAuth1 exchange, and the login rate guard
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use pierre_mcp_server::constants::oauth_providers;
+use pierre_mcp_server::database_plugins::{factory::Database, DatabaseProvider};
+use pierre_mcp_server::errors::{AppError, AppResult};
+use pierre_mcp_server::providers::garmin_provider::GarminProvider;
+use pierre_mcp_server::providers::garmin_session::{
+    GarminLoginClient, GarminOAuth1Token, GarminOAuth2Token, GarminSession, GarminSessionOutcome,
+    GarminSessionStore,
+};
+use pierre_mcp_server::providers::garmin_session_store::{
+    DatabaseGarminSessionStore, GARMIN_SESSION_TOKEN_TYPE,
+};
+use uuid::Uuid;
+
+mod common;
+
+/// Login client that counts calls and rejects one OAuth1 token
+#[derive(Default)]
+struct CountingLoginClient {
+    logins: AtomicUsize,
+    exchanges: AtomicUsize,
+    rejected_oauth1: Option<&'static str>,
+}
+
+#[async_trait]
+impl GarminLoginClient for CountingLoginClient {
+    async fn login(&self) -> AppResult<GarminOAuth1Token> {
+        let n = self.logins.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(GarminOAuth1Token {
+            oauth_token: format!("oauth1-token-{n}"),
+            oauth_token_secret: format!("oauth1-secret-{n}"),
+        })
+    }
+
+    async fn exchange(&self, oauth1: &GarminOAuth1Token) -> AppResult<GarminOAuth2Token> {
+        if self.rejected_oauth1 == Some(oauth1.oauth_token.as_str()) {
+            return Err(AppError::auth_invalid("OAuth1 token revoked"));
+        }
+        let n = self.exchanges.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(GarminOAuth2Token {
+            access_token: format!("bearer-{n}-for-{}", oauth1.oauth_token),
+            expires_at: Utc::now() + Duration::hours(1),
+        })
+    }
+}
+
+async fn session_store() -> Result<(Arc<Database>, DatabaseGarminSessionStore)> {
+    let database = common::create_test_database().await?;
+    let email = format!("garmin-{}@example.com", Uuid::new_v4());
+    let (user_id, _user) = common::create_test_user_with_email(&database, &email).await?;
+    let tenant_id = database.list_tenants_for_user(user_id).await?[0].id;
+    let store = DatabaseGarminSessionStore::new(Arc::clone(&database), user_id, tenant_id);
+    Ok((database, store))
+}
+
+#[tokio::test]
+async fn test_second_authenticate_within_interval_reuses_tokens() -> Result<()> {
+    let (_database, store) = session_store().await?;
+    let client = CountingLoginClient::default();
+
+    let first = GarminProvider::new();
+    assert_eq!(
+        first.authenticate_with_session(&store, &client).await?,
+        GarminSessionOutcome::LoggedIn
+    );
+
+    // A fresh provider instance, as created per request, resumes the stored session
+    let second = GarminProvider::new();
+    assert_eq!(
+        second.authenticate_with_session(&store, &client).await?,
+        GarminSessionOutcome::Reused
+    );
+    assert_eq!(client.logins.load(Ordering::SeqCst), 1);
+    assert_eq!(client.exchanges.load(Ordering::SeqCst), 1);
+
+    let session = store.load_session().await?.unwrap();
+    assert_eq!(session.oauth1.unwrap().oauth_token, "oauth1-token-1");
+    assert_eq!(
+        session.oauth2.unwrap().access_token,
+        "bearer-1-for-oauth1-token-1"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_tokens_are_encrypted_at_rest() -> Result<()> {
+    let (database, store) = session_store().await?;
+    let client = CountingLoginClient::default();
+    GarminProvider::new()
+        .authenticate_with_session(&store, &client)
+        .await?;
+
+    let pool = match database.as_ref() {
+        Database::SQLite(sqlite) => sqlite.pool(),
+        #[cfg(feature = "postgresql")]
+        Database::PostgreSQL(_) => return Ok(()),
+    };
+    let (access_token, refresh_token, token_type): (String, String, String) = sqlx::query_as(
+        "SELECT access_token, refresh_token, token_type FROM user_oauth_tokens WHERE provider = ?",
+    )
+    .bind(oauth_providers::GARMIN)
+    .fetch_one(pool)
+    .await?;
+    assert_eq!(token_type, GARMIN_SESSION_TOKEN_TYPE);
+    assert!(!access_token.contains("bearer-1"));
+    assert!(!refresh_token.contains("oauth1-secret-1"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_bearer_is_exchanged_without_login() -> Result<()> {
+    let (_database, store) = session_store().await?;
+    store
+        .save_session(&GarminSession {
+            oauth1: Some(GarminOAuth1Token {
+                oauth_token: "stored-oauth1".to_owned(),
+                oauth_token_secret: "stored-secret".to_owned(),
+            }),
+            oauth2: Some(GarminOAuth2Token {
+                access_token: "expired-bearer".to_owned(),
+                expires_at: Utc::now() - Duration::minutes(1),
+            }),
+            last_login_at: Utc::now() - Duration::days(30),
+        })
+        .await?;
+    let client = CountingLoginClient::default();
+
+    let outcome = GarminProvider::new()
+        .authenticate_with_session(&store, &client)
+        .await?;
+
+    assert_eq!(outcome, GarminSessionOutcome::Exchanged);
+    assert_eq!(client.logins.load(Ordering::SeqCst), 0);
+    let session = store.load_session().await?.unwrap();
+    assert_eq!(
+        session.oauth2.unwrap().access_token,
+        "bearer-1-for-stored-oauth1"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rejected_tokens_do_not_log_in_within_interval() -> Result<()> {
+    let (_database, store) = session_store().await?;
+    let rejected_session = GarminSession {
+        oauth1: Some(GarminOAuth1Token {
+            oauth_token: "revoked-oauth1".to_owned(),
+            oauth_token_secret: "revoked-secret".to_owned(),
+        }),
+        oauth2: None,
+        last_login_at: Utc::now() - Duration::minutes(2),
+    };
+    store.save_session(&rejected_session).await?;
+    let client = CountingLoginClient {
+        rejected_oauth1: Some("revoked-oauth1"),
+        ..CountingLoginClient::default()
+    };
+
+    let error = GarminProvider::new()
+        .authenticate_with_session(&store, &client)
+        .await
+        .unwrap_err();
+    assert!(error.message.contains("Rate limit exceeded (login)"));
+    assert_eq!(client.logins.load(Ordering::SeqCst), 0);
+
+    // Once the interval has passed, a new login replaces the rejected token
+    store
+        .save_session(&GarminSession {
+            last_login_at: Utc::now() - Duration::minutes(10),
+            ..rejected_session
+        })
+        .await?;
+    let outcome = GarminProvider::new()
+        .authenticate_with_session(&store, &client)
+        .await?;
+    assert_eq!(outcome, GarminSessionOutcome::LoggedIn);
+    assert_eq!(client.logins.load(Ordering::SeqCst), 1);
+    let session = store.load_session().await?.unwrap();
+    assert_eq!(session.oauth1.unwrap().oauth_token, "oauth1-token-1");
+    assert!(Utc::now() - session.last_login_at < Duration::minutes(1));
+    Ok(())
+}
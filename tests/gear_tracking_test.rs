@@ -0,0 +1,124 @@
+// ABOUTME: Tests for gear tracking: Strava gear mapping and shoe mileage replacement warnings
+// ABOUTME: Covers /athlete and /gear/{id} payload conversion and the GearWearMonitor threshold
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use pierre_mcp_server::intelligence::gear_wear::DEFAULT_SHOE_REPLACEMENT_KM;
+use pierre_mcp_server::intelligence::GearWearMonitor;
+use pierre_mcp_server::models::{Gear, GearType};
+use pierre_mcp_server::providers::strava_provider::{
+    StravaAthleteGearResponse, StravaGearResponse, StravaProvider,
+};
+
+/// Gear section of a GET /athlete response (other athlete fields are ignored)
+const ATHLETE_JSON: &str = r#"{
+    "id": 1234567,
+    "username": "runner",
+    "shoes": [
+        {"id": "g1001", "primary": true, "name": "Daily Trainers", "resource_state": 2, "distance": 845210.0},
+        {"id": "g1002", "primary": false, "name": "Race Flats", "resource_state": 2, "distance": 121500.0, "retired": false}
+    ],
+    "bikes": [
+        {"id": "b2001", "primary": true, "name": "Road Bike", "resource_state": 2, "distance": 5230400.0}
+    ]
+}"#;
+
+/// GET /gear/g1001 response
+const GEAR_DETAIL_JSON: &str = r#"{
+    "id": "g1001",
+    "primary": true,
+    "resource_state": 3,
+    "distance": 845210.0,
+    "brand_name": "Saucony",
+    "model_name": "Ride 17",
+    "description": "Everyday miles",
+    "name": "Daily Trainers",
+    "retired": false
+}"#;
+
+fn shoe(id: &str, distance_km: f64, retired: bool) -> Gear {
+    Gear {
+        id: id.to_owned(),
+        name: format!("Shoe {id}"),
+        gear_type: GearType::Shoes,
+        distance_meters: distance_km * 1000.0,
+        retired,
+        primary: false,
+        brand: None,
+        model: None,
+        provider: "strava".to_owned(),
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // Distances are copied verbatim from the payload
+fn test_strava_gear_mapping_from_athlete_and_detail() {
+    let athlete: StravaAthleteGearResponse = serde_json::from_str(ATHLETE_JSON).unwrap();
+    assert_eq!(athlete.shoes.len(), 2);
+    assert_eq!(athlete.bikes.len(), 1);
+
+    // Detailed response adds brand and model to the summary
+    let detail: StravaGearResponse = serde_json::from_str(GEAR_DETAIL_JSON).unwrap();
+    let trainers = StravaProvider::convert_gear(detail, GearType::Shoes);
+    assert_eq!(trainers.id, "g1001");
+    assert_eq!(trainers.name, "Daily Trainers");
+    assert_eq!(trainers.gear_type, GearType::Shoes);
+    assert_eq!(trainers.distance_meters, 845_210.0);
+    assert!(trainers.primary);
+    assert!(!trainers.retired);
+    assert_eq!(trainers.brand.as_deref(), Some("Saucony"));
+    assert_eq!(trainers.model.as_deref(), Some("Ride 17"));
+    assert_eq!(trainers.provider, "strava");
+
+    // Summary entries still map when the detail request is unavailable
+    let flats = StravaProvider::convert_gear(athlete.shoes[1].clone(), GearType::Shoes);
+    assert_eq!(flats.name, "Race Flats");
+    assert!(!flats.primary);
+    assert!(flats.brand.is_none());
+
+    let bike = StravaProvider::convert_gear(athlete.bikes[0].clone(), GearType::Bike);
+    assert_eq!(bike.id, "b2001");
+    assert_eq!(bike.gear_type, GearType::Bike);
+    assert_eq!(bike.distance_meters, 5_230_400.0);
+}
+
+#[test]
+fn test_strava_gear_without_name_falls_back_to_id() {
+    let gear: StravaGearResponse =
+        serde_json::from_str(r#"{"id": "g3003", "distance": 0.0}"#).unwrap();
+    let mapped = StravaProvider::convert_gear(gear, GearType::Shoes);
+    assert_eq!(mapped.name, "g3003");
+    assert!(!mapped.retired);
+}
+
+#[test]
+fn test_shoe_mileage_warning_threshold() {
+    let gear = vec![
+        shoe("worn", 845.2, false),
+        shoe("fresh", 121.5, false),
+        shoe("at_limit", 800.0, false),
+        shoe("retired", 1200.0, true),
+        Gear {
+            gear_type: GearType::Bike,
+            ..shoe("bike", 5230.4, false)
+        },
+    ];
+
+    let warnings = GearWearMonitor::default().check(&gear);
+    assert_eq!(warnings.len(), 1, "Only active shoes over the limit warn");
+    let warning = &warnings[0];
+    assert_eq!(warning.gear_id, "worn");
+    assert!((warning.distance_km - 845.2).abs() < 1e-9);
+    assert!((warning.threshold_km - DEFAULT_SHOE_REPLACEMENT_KM).abs() < f64::EPSILON);
+    assert!((warning.over_by_km - 45.2).abs() < 1e-9);
+    assert!(warning.message.contains("Shoe worn"));
+
+    // A lower configured threshold flags more shoes
+    let strict = GearWearMonitor::new(500.0);
+    let flagged: Vec<String> = strict.check(&gear).into_iter().map(|w| w.gear_id).collect();
+    assert_eq!(flagged, vec!["worn", "at_limit"]);
+}
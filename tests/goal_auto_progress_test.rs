@@ -0,0 +1,185 @@
+// ABOUTME: Tests for automatic goal progress from synced activities
+// ABOUTME: Covers distance accumulation, weekly frequency counting, sport matching, and persistence
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::services::goal_progress::{
+    apply_activities_to_goal, update_goals_from_activities,
+};
+use serde_json::{json, Value};
+
+/// Monday 2025-06-02, 00:00 UTC
+fn monday() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 2, 0, 0, 0).unwrap()
+}
+
+fn goal(goal_type: &str, target_value: f64, sport: &str) -> Value {
+    json!({
+        "id": "goal-1",
+        "goal_type": goal_type,
+        "target_value": target_value,
+        "title": "Test Goal",
+        "sport": sport,
+        "created_at": monday().to_rfc3339(),
+        "target_date": (monday() + Duration::days(30)).to_rfc3339(),
+    })
+}
+
+fn activity(id: &str, sport: SportType, start: DateTime<Utc>, distance_km: f64) -> Activity {
+    ActivityBuilder::new(id, id, sport, start, 3600, "synthetic")
+        .distance_meters(distance_km * 1000.0)
+        .build()
+}
+
+#[test]
+fn test_distance_goal_advances_after_matching_run() {
+    let mut goal = goal("distance", 20.0, "Running");
+    let run = activity("run-1", SportType::Run, monday() + Duration::hours(7), 12.5);
+
+    let update = apply_activities_to_goal(&mut goal, &[run.clone()], Utc::now()).unwrap();
+
+    assert!((update.previous_value - 0.0).abs() < f64::EPSILON);
+    assert!((update.current_value - 12.5).abs() < 1e-9);
+    assert!(!update.completed);
+    assert_eq!(goal["status"], "active");
+    assert!((goal["progress_percentage"].as_f64().unwrap() - 62.5).abs() < 1e-9);
+
+    // Re-syncing the same activity does not count it twice
+    assert!(apply_activities_to_goal(&mut goal, &[run], Utc::now()).is_none());
+
+    let second = activity("run-2", SportType::Run, monday() + Duration::days(2), 8.0);
+    let update = apply_activities_to_goal(&mut goal, &[second], Utc::now()).unwrap();
+    assert!(update.completed);
+    assert_eq!(goal["status"], "completed");
+    assert!(goal.get("completed_at").is_some());
+}
+
+#[test]
+fn test_frequency_goal_counts_sessions_per_week() {
+    let mut goal = goal("frequency", 3.0, "Running");
+    let week_one: Vec<Activity> = (0..2)
+        .map(|day| {
+            activity(
+                &format!("w1-{day}"),
+                SportType::Run,
+                monday() + Duration::days(day) + Duration::hours(6),
+                5.0,
+            )
+        })
+        .collect();
+
+    let update = apply_activities_to_goal(&mut goal, &week_one, Utc::now()).unwrap();
+    assert!((update.current_value - 2.0).abs() < f64::EPSILON);
+    assert!(!update.completed);
+
+    // The first session of a new week starts the count over
+    let next_week = monday() + Duration::weeks(1) + Duration::hours(6);
+    let update = apply_activities_to_goal(
+        &mut goal,
+        &[activity("w2-0", SportType::Run, next_week, 5.0)],
+        Utc::now(),
+    )
+    .unwrap();
+    assert!((update.current_value - 1.0).abs() < f64::EPSILON);
+
+    // A late-arriving session from the previous week no longer counts
+    let late = activity("w1-late", SportType::Run, monday() + Duration::days(4), 5.0);
+    assert!(apply_activities_to_goal(&mut goal, &[late], Utc::now()).is_none());
+
+    let rest_of_week: Vec<Activity> = (1..3)
+        .map(|day| {
+            activity(
+                &format!("w2-{day}"),
+                SportType::Run,
+                next_week + Duration::days(day),
+                5.0,
+            )
+        })
+        .collect();
+    let update = apply_activities_to_goal(&mut goal, &rest_of_week, Utc::now()).unwrap();
+    assert!((update.current_value - 3.0).abs() < f64::EPSILON);
+    assert!(update.completed);
+}
+
+#[test]
+fn test_non_matching_sport_does_not_advance() {
+    let mut goal = goal("distance", 20.0, "Running");
+    let ride = activity(
+        "ride-1",
+        SportType::Ride,
+        monday() + Duration::hours(7),
+        40.0,
+    );
+
+    assert!(apply_activities_to_goal(&mut goal, &[ride], Utc::now()).is_none());
+    assert!(goal.get("current_value").is_none());
+}
+
+#[test]
+fn test_activities_outside_window_or_opted_out_do_not_advance() {
+    let mut time_goal = goal("time", 5.0, "Running");
+    let before = activity("early", SportType::Run, monday() - Duration::days(1), 10.0);
+    let after = activity("late", SportType::Run, monday() + Duration::days(31), 10.0);
+    assert!(apply_activities_to_goal(&mut time_goal, &[before, after], Utc::now()).is_none());
+
+    let run = activity("run", SportType::Run, monday() + Duration::days(1), 10.0);
+    let update = apply_activities_to_goal(&mut time_goal, &[run.clone()], Utc::now()).unwrap();
+    assert!((update.current_value - 1.0).abs() < 1e-9);
+
+    let mut manual = goal("distance", 20.0, "Running");
+    manual["auto_progress"] = json!(false);
+    assert!(apply_activities_to_goal(&mut manual, &[run], Utc::now()).is_none());
+}
+
+#[tokio::test]
+async fn test_update_goals_from_activities_persists_progress() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+
+    let mut goal_data = goal("distance", 10.0, "Running");
+    goal_data.as_object_mut().unwrap().remove("id");
+    let goal_id = resources
+        .database
+        .create_goal(user_id, goal_data)
+        .await
+        .unwrap();
+
+    let runs = vec![
+        activity("run-1", SportType::Run, monday() + Duration::hours(7), 6.0),
+        activity("run-2", SportType::Run, monday() + Duration::days(1), 6.0),
+        activity(
+            "ride-1",
+            SportType::Ride,
+            monday() + Duration::days(2),
+            50.0,
+        ),
+    ];
+    let updates = update_goals_from_activities(&resources, user_id, tenant_id, "synthetic", &runs)
+        .await
+        .unwrap();
+
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].goal_id, goal_id);
+    assert!(updates[0].completed);
+
+    let stored = resources.database.get_user_goals(user_id).await.unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0]["status"], "completed");
+    assert!((stored[0]["current_value"].as_f64().unwrap() - 12.0).abs() < 1e-9);
+    assert_eq!(stored[0]["counted_activity_ids"], json!(["run-1", "run-2"]));
+}
@@ -0,0 +1,161 @@
+// ABOUTME: Tests for GPS/distance spike detection over per-point activity speed streams
+// ABOUTME: Verifies teleport spikes are flagged, clean tracks are not, and per-sport limits apply
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::config::intelligence::{ActivityAnalyzerConfig, SpeedAnomalyConfig};
+use pierre_mcp_server::intelligence::{
+    ActivityAnalyzerTrait, AdvancedActivityAnalyzer, Confidence, PatternDetector,
+};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData};
+
+/// Roughly 3 m/s northwards per one-second sample
+const LAT_STEP_DEGREES: f64 = 3.0 / 111_195.0;
+
+const POINTS: u32 = 120;
+
+fn track(teleport_at: Option<u32>) -> Vec<(f64, f64)> {
+    (0..POINTS)
+        .map(|i| {
+            let lat = f64::from(i).mul_add(LAT_STEP_DEGREES, 45.0);
+            if Some(i) == teleport_at {
+                // One fix jumps ~1.1 km east and the next one snaps back
+                (lat, 7.014)
+            } else {
+                (lat, 7.0)
+            }
+        })
+        .collect()
+}
+
+fn series(speed: Option<Vec<f32>>, gps_coordinates: Option<Vec<(f64, f64)>>) -> TimeSeriesData {
+    TimeSeriesData {
+        timestamps: (0..POINTS).collect(),
+        heart_rate: None,
+        power: None,
+        cadence: None,
+        speed,
+        altitude: None,
+        temperature: None,
+        gps_coordinates,
+    }
+}
+
+fn activity(sport_type: SportType, series: TimeSeriesData) -> Activity {
+    ActivityBuilder::new(
+        "gps-track",
+        "Morning Run",
+        sport_type,
+        Utc.with_ymd_and_hms(2025, 5, 1, 7, 0, 0).unwrap(),
+        u64::from(POINTS),
+        "test",
+    )
+    .distance_meters(3.0 * f64::from(POINTS))
+    .average_heart_rate(150)
+    .time_series_data(series)
+    .build()
+}
+
+fn gps_run(teleport_at: Option<u32>) -> Activity {
+    activity(SportType::Run, series(None, Some(track(teleport_at))))
+}
+
+#[test]
+fn test_teleport_spike_is_detected() {
+    let anomalies =
+        PatternDetector::detect_speed_anomalies(&gps_run(Some(60)), &SpeedAnomalyConfig::default());
+
+    // The jump out and the jump back are consecutive samples: one anomaly
+    assert_eq!(anomalies.len(), 1);
+    let anomaly = &anomalies[0];
+    assert_eq!(anomaly.anomaly_type, "gps_speed_spike");
+    assert_eq!(anomaly.affected_metric, "speed");
+    assert!(matches!(anomaly.confidence, Confidence::High));
+    assert!(anomaly.actual_value.unwrap() > 1000.0);
+    // The median baseline ignores the spike and reflects the ~3 m/s jog
+    assert!((anomaly.expected_value.unwrap() - 3.0).abs() < 0.1);
+    assert!(anomaly.description.contains("60s"));
+}
+
+#[test]
+fn test_clean_track_produces_no_anomalies() {
+    let anomalies =
+        PatternDetector::detect_speed_anomalies(&gps_run(None), &SpeedAnomalyConfig::default());
+    assert!(anomalies.is_empty());
+
+    let without_streams = ActivityBuilder::new(
+        "no-streams",
+        "Treadmill",
+        SportType::Run,
+        Utc::now(),
+        1800,
+        "test",
+    )
+    .build();
+    assert!(PatternDetector::detect_speed_anomalies(
+        &without_streams,
+        &SpeedAnomalyConfig::default()
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_speed_stream_limits_follow_sport_table() {
+    // Sustained 50 km/h for ten samples in the middle of an easy effort
+    let speed: Vec<f32> = (0..POINTS)
+        .map(|i| if (50..60).contains(&i) { 13.9 } else { 8.0 })
+        .collect();
+    let series = series(Some(speed), None);
+    let config = SpeedAnomalyConfig::default();
+
+    let run =
+        PatternDetector::detect_speed_anomalies(&activity(SportType::Run, series.clone()), &config);
+    assert_eq!(run.len(), 1);
+    assert!((run[0].actual_value.unwrap() - 13.9).abs() < 0.01);
+
+    let ride = PatternDetector::detect_speed_anomalies(&activity(SportType::Ride, series), &config);
+    assert!(ride.is_empty());
+}
+
+#[test]
+fn test_configured_limit_and_min_spike_points_are_respected() {
+    let mut config = SpeedAnomalyConfig::default();
+    config.max_speed_by_sport.insert(SportType::Run, 2.5);
+
+    // The whole clean jog now exceeds the limit, so the baseline is implausible too
+    let anomalies = PatternDetector::detect_speed_anomalies(&gps_run(None), &config);
+    assert_eq!(anomalies.len(), 1);
+    assert!(matches!(anomalies[0].confidence, Confidence::Medium));
+    assert!((anomalies[0].expected_value.unwrap() - 2.5).abs() < f64::EPSILON);
+
+    // A two-sample teleport is ignored when three consecutive samples are required
+    let config = SpeedAnomalyConfig {
+        min_spike_points: 3,
+        ..SpeedAnomalyConfig::default()
+    };
+    assert!(PatternDetector::detect_speed_anomalies(&gps_run(Some(60)), &config).is_empty());
+}
+
+#[tokio::test]
+async fn test_analyzer_attaches_speed_anomalies_to_insights() {
+    let analyzer = AdvancedActivityAnalyzer::with_config(ActivityAnalyzerConfig::default());
+
+    let insights = analyzer.analyze_activity(&gps_run(Some(60))).await.unwrap();
+    let spikes: Vec<_> = insights
+        .anomalies
+        .iter()
+        .filter(|a| a.anomaly_type == "gps_speed_spike")
+        .collect();
+    assert_eq!(spikes.len(), 1);
+
+    let insights = analyzer.analyze_activity(&gps_run(None)).await.unwrap();
+    assert!(insights
+        .anomalies
+        .iter()
+        .all(|a| a.anomaly_type != "gps_speed_spike"));
+}
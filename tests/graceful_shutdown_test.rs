@@ -0,0 +1,204 @@
+// ABOUTME: Tests for graceful shutdown of the HTTP server and running A2A tasks
+// ABOUTME: Verifies in-flight requests finish before exit, the grace period bound, and interrupted task status
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::routing::get;
+use axum::Router;
+use pierre_mcp_server::a2a::client::ClientRegistrationRequest;
+use pierre_mcp_server::a2a::protocol::TaskStatus;
+use pierre_mcp_server::a2a::task_updates::update_task_status;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::lifecycle::shutdown::{
+    finish_shutdown, interrupt_running_tasks, serve_with_graceful_shutdown, ShutdownCoordinator,
+    INTERRUPTED_TASK_ERROR,
+};
+use pierre_mcp_server::mcp::resources::ServerResources;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::time::{sleep, timeout, Instant};
+
+/// Serve a router whose only route takes `delay` to respond
+async fn spawn_slow_server(
+    delay: Duration,
+    coordinator: &Arc<ShutdownCoordinator>,
+) -> (String, tokio::task::JoinHandle<bool>) {
+    let app = Router::new().route(
+        "/slow",
+        get(move || async move {
+            sleep(delay).await;
+            "finished"
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/slow", listener.local_addr().unwrap());
+    let coordinator = Arc::clone(coordinator);
+    let server = tokio::spawn(async move {
+        serve_with_graceful_shutdown(listener, app, coordinator)
+            .await
+            .unwrap()
+    });
+    (url, server)
+}
+
+async fn wait_for_in_flight(coordinator: &ShutdownCoordinator) {
+    timeout(Duration::from_secs(5), async {
+        while coordinator.in_flight_requests() == 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("request never reached the server");
+}
+
+#[tokio::test]
+async fn test_in_flight_request_completes_before_server_exits() {
+    let coordinator = Arc::new(ShutdownCoordinator::new(Duration::from_secs(10)));
+    let (url, server) = spawn_slow_server(Duration::from_millis(300), &coordinator).await;
+
+    let request = tokio::spawn({
+        let url = url.clone();
+        async move { reqwest::get(url).await }
+    });
+    wait_for_in_flight(&coordinator).await;
+
+    assert!(coordinator.trigger());
+    assert!(!coordinator.trigger(), "second trigger must not reset");
+
+    let response = request.await.unwrap().unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text().await.unwrap(), "finished");
+
+    let drained = timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server did not exit after draining")
+        .unwrap();
+    assert!(drained);
+    assert_eq!(coordinator.in_flight_requests(), 0);
+
+    // The listener is gone, so new connections are refused
+    assert!(reqwest::get(url).await.is_err());
+}
+
+#[tokio::test]
+async fn test_grace_period_bounds_the_drain() {
+    let coordinator = Arc::new(ShutdownCoordinator::new(Duration::from_millis(200)));
+    let (url, server) = spawn_slow_server(Duration::from_secs(30), &coordinator).await;
+
+    let _request = tokio::spawn(async move { reqwest::get(url).await });
+    wait_for_in_flight(&coordinator).await;
+
+    let started = Instant::now();
+    coordinator.trigger();
+    let drained = timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server ignored the grace period")
+        .unwrap();
+    assert!(!drained);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_tasks_running_at_deadline_are_interrupted() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let mut resources = (*resources).clone();
+    resources.shutdown = Arc::new(ShutdownCoordinator::new(Duration::from_millis(200)));
+    let resources = Arc::new(resources);
+    let client_id = register_client(&resources).await;
+
+    let stuck = create_running_task(&resources, &client_id).await;
+    let finishing = create_running_task(&resources, &client_id).await;
+    assert_eq!(resources.shutdown.running_tasks().len(), 2);
+
+    resources.shutdown.trigger();
+    let completer = tokio::spawn({
+        let resources = Arc::clone(&resources);
+        let finishing = finishing.clone();
+        async move {
+            sleep(Duration::from_millis(50)).await;
+            update_task_status(
+                &resources,
+                &finishing,
+                TaskStatus::Completed,
+                Some(json!({"ok": true})),
+                None,
+            )
+            .await
+            .unwrap();
+        }
+    });
+
+    let deadline = resources.shutdown.triggered().await;
+    let interrupted = interrupt_running_tasks(&resources, deadline).await;
+    completer.await.unwrap();
+    assert_eq!(interrupted, vec![stuck.clone()]);
+    assert!(resources.shutdown.running_tasks().is_empty());
+
+    let task = resources
+        .database
+        .get_a2a_task(&stuck)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.status, TaskStatus::Interrupted);
+    assert!(task.status.is_terminal());
+    assert_eq!(task.error_message.as_deref(), Some(INTERRUPTED_TASK_ERROR));
+    assert!(task.completed_at.is_some());
+
+    let task = resources
+        .database
+        .get_a2a_task(&finishing)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.status, TaskStatus::Completed);
+
+    let interrupted_tasks = resources
+        .database
+        .list_a2a_tasks(Some(&client_id), Some(&TaskStatus::Interrupted), None, None)
+        .await
+        .unwrap();
+    assert_eq!(interrupted_tasks.len(), 1);
+
+    // Nothing left to wait for, so shutdown closes the pool right away
+    assert!(finish_shutdown(&resources).await.is_empty());
+    assert!(resources.database.sqlite_pool().unwrap().is_closed());
+}
+
+async fn register_client(resources: &ServerResources) -> String {
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let request = ClientRegistrationRequest {
+        name: "Shutdown Client".to_owned(),
+        description: "Client with long-running tasks".to_owned(),
+        capabilities: vec!["fitness-data-analysis".to_owned()],
+        redirect_uris: vec![],
+        contact_email: "shutdown@example.com".to_owned(),
+    };
+    resources
+        .a2a_client_manager
+        .register_client(request, user_id)
+        .await
+        .unwrap()
+        .client_id
+}
+
+async fn create_running_task(resources: &ServerResources, client_id: &str) -> String {
+    let task_id = resources
+        .database
+        .create_a2a_task(client_id, None, "weekly_report", &json!({"weeks": 4}))
+        .await
+        .unwrap();
+    update_task_status(resources, &task_id, TaskStatus::Running, None, None)
+        .await
+        .unwrap();
+    task_id
+}
@@ -0,0 +1,178 @@
+// ABOUTME: Tests for grade-adjusted pace (GAP) from distance and altitude streams
+// ABOUTME: Verifies uphill GAP beats measured pace, flat GAP matches it, and noisy or missing elevation is handled
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::config::intelligence::ActivityAnalyzerConfig;
+use pierre_mcp_server::intelligence::metrics::grade_adjusted_pace;
+use pierre_mcp_server::intelligence::{
+    ActivityAnalyzerTrait, AdvancedActivityAnalyzer, MetricsCalculator,
+};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData};
+
+/// One sample per second at a steady 3 m/s
+const POINTS: u32 = 600;
+const SPEED: f32 = 3.0;
+
+/// Measured pace of the synthetic runs: 1000 m / 3 m/s
+const MEASURED_PACE: f64 = 1000.0 / 3.0;
+
+fn distances() -> Vec<f64> {
+    (0..POINTS)
+        .map(|i| f64::from(i) * f64::from(SPEED))
+        .collect()
+}
+
+fn run(sport_type: SportType, altitude: Option<Vec<f32>>) -> Activity {
+    ActivityBuilder::new(
+        "gap-run",
+        "Hill Repeats",
+        sport_type,
+        Utc.with_ymd_and_hms(2025, 6, 1, 7, 0, 0).unwrap(),
+        u64::from(POINTS - 1),
+        "test",
+    )
+    .distance_meters(f64::from(SPEED) * f64::from(POINTS - 1))
+    .average_speed(f64::from(SPEED))
+    .average_heart_rate(150)
+    .time_series_data(TimeSeriesData {
+        timestamps: (0..POINTS).collect(),
+        heart_rate: None,
+        power: None,
+        cadence: None,
+        speed: Some(vec![SPEED; POINTS as usize]),
+        altitude,
+        temperature: None,
+        gps_coordinates: None,
+    })
+    .build()
+}
+
+/// A steady 8% climb
+fn uphill_altitudes() -> Vec<f32> {
+    std::iter::successors(Some(100.0_f32), |altitude| {
+        Some(0.08_f32.mul_add(SPEED, *altitude))
+    })
+    .take(POINTS as usize)
+    .collect()
+}
+
+#[test]
+fn test_uphill_segment_gap_is_faster_than_measured_pace() {
+    let altitudes: Vec<f64> = uphill_altitudes().into_iter().map(f64::from).collect();
+    let elapsed = f64::from(POINTS - 1);
+    let measured = elapsed / (distances()[POINTS as usize - 1] / 1000.0);
+
+    let gap = grade_adjusted_pace(&distances(), &altitudes, elapsed).unwrap();
+
+    assert!(gap < measured, "GAP {gap} should beat measured {measured}");
+    // Minetti puts an 8% climb at roughly 1.5x the flat cost
+    assert!(gap > measured / 1.7 && gap < measured / 1.3);
+}
+
+#[test]
+fn test_downhill_segment_gap_is_slower_than_measured_pace() {
+    let altitudes: Vec<f64> = distances()
+        .iter()
+        .map(|distance| 0.03_f64.mul_add(-distance, 500.0))
+        .collect();
+    let elapsed = f64::from(POINTS - 1);
+    let measured = elapsed / (distances()[POINTS as usize - 1] / 1000.0);
+
+    let gap = grade_adjusted_pace(&distances(), &altitudes, elapsed).unwrap();
+
+    // A gentle descent costs less than flat running
+    assert!(gap > measured);
+}
+
+#[test]
+fn test_flat_segment_gap_matches_measured_pace() {
+    let altitudes = vec![42.0; POINTS as usize];
+    let elapsed = f64::from(POINTS - 1);
+    let measured = elapsed / (distances()[POINTS as usize - 1] / 1000.0);
+
+    let gap = grade_adjusted_pace(&distances(), &altitudes, elapsed).unwrap();
+
+    assert!((gap - measured).abs() < 1e-6);
+}
+
+#[test]
+fn test_noisy_flat_altitude_is_smoothed() {
+    // Barometric jitter of +/-1.5 m on every sample of a flat course
+    let altitudes: Vec<f64> = (0..POINTS)
+        .map(|i| if i % 2 == 0 { 101.5 } else { 98.5 })
+        .collect();
+    let elapsed = f64::from(POINTS - 1);
+    let measured = elapsed / (distances()[POINTS as usize - 1] / 1000.0);
+
+    let gap = grade_adjusted_pace(&distances(), &altitudes, elapsed).unwrap();
+
+    assert!(
+        (gap - measured).abs() / measured < 0.02,
+        "noise should not move GAP: {gap} vs {measured}"
+    );
+}
+
+#[test]
+fn test_invalid_streams_return_none() {
+    let elapsed = f64::from(POINTS - 1);
+    assert!(grade_adjusted_pace(&distances(), &[], elapsed).is_none());
+    assert!(grade_adjusted_pace(&distances(), &[1.0, 2.0], elapsed).is_none());
+    assert!(grade_adjusted_pace(&[0.0], &[1.0], elapsed).is_none());
+    assert!(grade_adjusted_pace(&[0.0, 0.0], &[1.0, 1.0], elapsed).is_none());
+    assert!(grade_adjusted_pace(&distances(), &vec![0.0; POINTS as usize], 0.0).is_none());
+}
+
+#[test]
+fn test_metrics_calculator_requires_elevation_data() {
+    let uphill = run(SportType::Run, Some(uphill_altitudes()));
+    let gap = MetricsCalculator::calculate_grade_adjusted_pace(&uphill).unwrap();
+    assert!(gap < MEASURED_PACE);
+
+    assert!(MetricsCalculator::calculate_grade_adjusted_pace(&run(SportType::Run, None)).is_none());
+    assert!(MetricsCalculator::calculate_grade_adjusted_pace(&run(
+        SportType::Ride,
+        Some(uphill_altitudes())
+    ))
+    .is_none());
+
+    let metrics = MetricsCalculator::new().calculate_metrics(&uphill).unwrap();
+    assert_eq!(metrics.grade_adjusted_pace, Some(gap));
+}
+
+#[tokio::test]
+async fn test_analyzer_reports_gap_insight_with_elevation() {
+    let analyzer = AdvancedActivityAnalyzer::with_config(ActivityAnalyzerConfig::default());
+
+    let insights = analyzer
+        .analyze_activity(&run(SportType::Run, Some(uphill_altitudes())))
+        .await
+        .unwrap();
+    let gap_insight = insights
+        .insights
+        .iter()
+        .find(|insight| insight.insight_type == "grade_adjusted_pace")
+        .expect("uphill run should carry a GAP insight");
+    let gap = gap_insight.metadata["grade_adjusted_pace_seconds_per_km"]
+        .as_f64()
+        .unwrap();
+    let measured = gap_insight.metadata["measured_pace_seconds_per_km"]
+        .as_f64()
+        .unwrap();
+    assert!(gap < measured);
+    assert!(gap_insight.message.contains("5:33/km measured"));
+
+    let flat = analyzer
+        .analyze_activity(&run(SportType::Run, None))
+        .await
+        .unwrap();
+    assert!(flat
+        .insights
+        .iter()
+        .all(|insight| insight.insight_type != "grade_adjusted_pace"));
+}
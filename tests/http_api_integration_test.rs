@@ -105,6 +105,7 @@ async fn setup_test_environment() -> Result<(Arc<Database>, AuthService, OAuthSe
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -118,6 +119,7 @@ async fn setup_test_environment() -> Result<(Arc<Database>, AuthService, OAuthSe
         provider: "fitbit".to_owned(),
         client_id: "test_fitbit_client_id".to_owned(),
         client_secret: "test_fitbit_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/fitbit".to_owned(),
         scopes: vec!["activity".to_owned(), "profile".to_owned()],
         rate_limit_per_day: 15000,
@@ -0,0 +1,149 @@
+// ABOUTME: Tests for the provider HTTP record/replay layer
+// ABOUTME: Records a Strava activity from a local server once, then replays it offline into an Activity
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use axum::{http::header::CONTENT_TYPE, routing::get, Router};
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::models::SportType;
+use pierre_mcp_server::providers::core::ProviderValidationMode;
+use pierre_mcp_server::providers::http_client::{shared_client, SendError};
+use pierre_mcp_server::providers::http_replay::{Fixture, HttpReplay, RecordedRequest, ReplayMode};
+use pierre_mcp_server::providers::strava_provider::{DetailedActivityResponse, StravaProvider};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// GET /activities/{id}, trimmed to the fields Pierre reads
+const ACTIVITY_JSON: &str = r#"{
+    "id": 12345678987,
+    "resource_state": 3,
+    "name": "Lunch Run",
+    "type": "Run",
+    "start_date": "2024-06-02T11:05:00Z",
+    "timezone": "(GMT-08:00) America/Los_Angeles",
+    "utc_offset": -25200.0,
+    "distance": 10012.3,
+    "elapsed_time": 2950,
+    "total_elevation_gain": 84.0,
+    "average_speed": 3.39,
+    "max_speed": 4.8,
+    "average_heartrate": 151.2,
+    "max_heartrate": 174.0,
+    "kudos_count": 7
+}"#;
+
+const ACTIVITY_PATH: &str = "/api/v3/activities/12345678987";
+
+/// Serve the recorded activity as the Strava API would
+async fn start_strava_stub() -> (String, JoinHandle<()>) {
+    let app = Router::new().route(
+        ACTIVITY_PATH,
+        get(|| async { ([(CONTENT_TYPE, "application/json")], ACTIVITY_JSON) }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}{ACTIVITY_PATH}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (url, server)
+}
+
+async fn fetch_activity(replay: &HttpReplay, url: &str, token: &str) -> DetailedActivityResponse {
+    let request = shared_client().get(url).bearer_auth(token);
+    let response = replay.send(request).await.unwrap();
+    assert!(response.status().is_success());
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_recorded_activity_replays_offline() {
+    let fixtures = tempfile::tempdir().unwrap();
+    let (url, server) = start_strava_stub().await;
+
+    let recorder = HttpReplay::new(fixtures.path(), ReplayMode::Record);
+    fetch_activity(&recorder, &url, "recording-token").await;
+
+    // The provider is gone; only the fixture can answer now
+    server.abort();
+    let _ = server.await;
+
+    let replayer = HttpReplay::new(fixtures.path(), ReplayMode::Replay);
+    let detailed = fetch_activity(&replayer, &url, "a-different-token").await;
+    let activity =
+        StravaProvider::convert_detailed_strava_activity(detailed, ProviderValidationMode::Lenient)
+            .unwrap();
+
+    assert_eq!(activity.id(), "12345678987");
+    assert_eq!(activity.name(), "Lunch Run");
+    assert_eq!(activity.sport_type(), &SportType::Run);
+    assert_eq!(
+        activity.start_date(),
+        Utc.with_ymd_and_hms(2024, 6, 2, 11, 5, 0).unwrap()
+    );
+    assert_eq!(activity.duration_seconds(), 2950);
+    assert!((activity.distance_meters().unwrap() - 10012.3).abs() < 0.01);
+    assert_eq!(activity.max_heart_rate(), Some(174));
+}
+
+#[tokio::test]
+async fn test_fixture_is_keyed_by_request_and_omits_credentials() {
+    let fixtures = tempfile::tempdir().unwrap();
+    let (url, server) = start_strava_stub().await;
+
+    let recorder = HttpReplay::new(fixtures.path(), ReplayMode::Record);
+    fetch_activity(&recorder, &url, "secret-access-token").await;
+    server.abort();
+
+    let request = RecordedRequest {
+        method: "GET".to_owned(),
+        url: url.clone(),
+        body: None,
+    };
+    let path = recorder.fixture_path(&request);
+    let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+    assert!(file_name.starts_with("get-127_0_0_1_api_v3_activities_12345678987-"));
+
+    let json = std::fs::read_to_string(&path).unwrap();
+    assert!(!json.contains("secret-access-token"));
+    let fixture: Fixture = serde_json::from_str(&json).unwrap();
+    assert_eq!(fixture.request.url, url);
+    assert_eq!(fixture.response.status, 200);
+
+    // A different request has a different signature
+    let other = RecordedRequest {
+        url: format!("{url}?include_all_efforts=true"),
+        ..request.clone()
+    };
+    assert_ne!(request.signature(), other.signature());
+}
+
+#[tokio::test]
+async fn test_replay_without_fixture_fails() {
+    let fixtures = tempfile::tempdir().unwrap();
+    let replayer = HttpReplay::new(fixtures.path(), ReplayMode::Replay);
+
+    let request = shared_client().get("https://www.strava.com/api/v3/athlete");
+    let result = replayer.send(request).await;
+
+    match result {
+        Err(SendError::Replay(message)) => assert!(message.contains("No fixture")),
+        other => panic!("expected a replay error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_replay_mode_defaults_to_replay() {
+    assert_eq!(
+        ReplayMode::from_str_or_default("RECORD"),
+        ReplayMode::Record
+    );
+    assert_eq!(
+        ReplayMode::from_str_or_default("replay"),
+        ReplayMode::Replay
+    );
+    assert_eq!(ReplayMode::from_str_or_default(""), ReplayMode::Replay);
+}
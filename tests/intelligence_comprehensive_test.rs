@@ -488,6 +488,7 @@ fn test_advanced_insight() {
         confidence: Confidence::High,
         severity: InsightSeverity::Info,
         metadata,
+        computation_trace: None,
     };
 
     assert_eq!(insight.insight_type, "pace_improvement");
@@ -688,6 +689,7 @@ fn test_activity_insights_serialization() {
             running_effectiveness: None,
             stride_efficiency: None,
             ground_contact_balance: None,
+            grade_adjusted_pace: None,
 
             // Recovery and physiological metrics
             estimated_recovery_time: None,
@@ -699,6 +701,7 @@ fn test_activity_insights_serialization() {
             altitude_adjustment: None,
 
             custom_metrics: HashMap::new(),
+            custom_metric_units: HashMap::new(),
         },
         recommendations: vec!["Focus on consistent pacing".to_owned()],
         anomalies: vec![],
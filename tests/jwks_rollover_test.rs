@@ -0,0 +1,190 @@
+// ABOUTME: Tests for RSA signing key rollover with an overlap window for the retiring key
+// ABOUTME: Covers kid-based validation during the overlap, JWKS contents, retirement, and persistence
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+use jsonwebtoken::decode_header;
+use pierre_mcp_server::{
+    admin::jwks::JwksManager,
+    auth::AuthManager,
+    config::SigningKeyRolloverConfig,
+    database_plugins::DatabaseProvider,
+    models::User,
+    services::signing_key_rollover::{retire_expired_signing_keys, roll_over_signing_key},
+};
+
+/// Test key size; production keys are 4096 bits
+const KEY_SIZE_BITS: usize = 2048;
+
+fn test_user() -> User {
+    User::new(
+        "rollover@example.com".to_owned(),
+        "password_hash".to_owned(),
+        Some("Rollover User".to_owned()),
+    )
+}
+
+fn jwks_with_initial_key() -> Result<Arc<JwksManager>> {
+    let mut jwks_manager = JwksManager::new();
+    jwks_manager.generate_rsa_key_pair_with_size("initial_key", KEY_SIZE_BITS)?;
+    Ok(Arc::new(jwks_manager))
+}
+
+fn kid_of(token: &str) -> String {
+    decode_header(token).unwrap().kid.unwrap()
+}
+
+#[tokio::test]
+async fn test_retiring_key_validates_during_overlap() -> Result<()> {
+    let jwks_manager = jwks_with_initial_key()?;
+    let auth_manager = AuthManager::new(24);
+    let user = test_user();
+    let old_token = auth_manager.generate_token(&user, &jwks_manager)?;
+
+    let rollover = jwks_manager.begin_rollover(KEY_SIZE_BITS, Duration::hours(24))?;
+    assert_eq!(rollover.retiring_kids, vec!["initial_key".to_owned()]);
+    assert_eq!(jwks_manager.get_active_key()?.kid, rollover.new_kid);
+
+    // New tokens are signed by the new key, and the kid selects the right key for each token
+    let new_token = auth_manager.generate_token(&user, &jwks_manager)?;
+    assert_eq!(kid_of(&old_token), "initial_key");
+    assert_eq!(kid_of(&new_token), rollover.new_kid);
+    assert_eq!(
+        auth_manager.validate_token(&old_token, &jwks_manager)?.sub,
+        user.id.to_string()
+    );
+    assert_eq!(
+        auth_manager.validate_token(&new_token, &jwks_manager)?.sub,
+        user.id.to_string()
+    );
+
+    let retiring = jwks_manager.get_key("initial_key").unwrap();
+    assert!(!retiring.is_active);
+    assert_eq!(retiring.retires_at, Some(rollover.retires_at));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_jwks_lists_both_keys_during_overlap() -> Result<()> {
+    let jwks_manager = jwks_with_initial_key()?;
+    let rollover = jwks_manager.begin_rollover(KEY_SIZE_BITS, Duration::hours(24))?;
+
+    let kids: Vec<String> = jwks_manager
+        .get_jwks()?
+        .keys
+        .into_iter()
+        .map(|key| key.kid)
+        .collect();
+
+    // Newest key first
+    assert_eq!(kids, vec![rollover.new_kid, "initial_key".to_owned()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retired_key_stops_validating_after_overlap() -> Result<()> {
+    let jwks_manager = jwks_with_initial_key()?;
+    let auth_manager = AuthManager::new(24);
+    let user = test_user();
+    let old_token = auth_manager.generate_token(&user, &jwks_manager)?;
+
+    let rollover = jwks_manager.begin_rollover(KEY_SIZE_BITS, Duration::hours(1))?;
+    let new_token = auth_manager.generate_token(&user, &jwks_manager)?;
+
+    // Nothing expires before the overlap window ends
+    let early = rollover.retires_at - Duration::minutes(1);
+    assert!(jwks_manager.retire_expired_keys(early).is_empty());
+
+    let retired = jwks_manager.retire_expired_keys(rollover.retires_at);
+    assert_eq!(retired, vec!["initial_key".to_owned()]);
+
+    assert!(auth_manager
+        .validate_token(&old_token, &jwks_manager)
+        .is_err());
+    assert!(auth_manager
+        .validate_token(&new_token, &jwks_manager)
+        .is_ok());
+    let jwks = jwks_manager.get_jwks()?;
+    assert_eq!(jwks.keys.len(), 1);
+    assert_eq!(jwks.keys[0].kid, rollover.new_kid);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_second_rollover_keeps_earlier_retirement() -> Result<()> {
+    let jwks_manager = jwks_with_initial_key()?;
+    let first = jwks_manager.begin_rollover(KEY_SIZE_BITS, Duration::hours(1))?;
+    let second = jwks_manager.begin_rollover(KEY_SIZE_BITS, Duration::hours(24))?;
+
+    assert!(second.retiring_kids.contains(&"initial_key".to_owned()));
+    assert_eq!(
+        jwks_manager.get_key("initial_key").unwrap().retires_at,
+        Some(first.retires_at)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rollover_is_persisted_across_restarts() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let jwks_manager = jwks_with_initial_key()?;
+    let initial = jwks_manager.get_active_key()?;
+    database
+        .save_rsa_keypair(
+            "initial_key",
+            &initial.export_private_key_pem()?,
+            &initial.export_public_key_pem()?,
+            initial.created_at,
+            true,
+            2048,
+        )
+        .await?;
+
+    let auth_manager = AuthManager::new(24);
+    let old_token = auth_manager.generate_token(&test_user(), &jwks_manager)?;
+
+    let config = SigningKeyRolloverConfig::default()
+        .with_key_size_bits(KEY_SIZE_BITS)
+        .with_overlap_hours(6);
+    let rollover = roll_over_signing_key(database.as_ref(), &jwks_manager, &config).await?;
+
+    let rows = database.load_rsa_keypairs().await?;
+    assert_eq!(rows.len(), 2);
+    let (_, _, _, _, old_active, old_retires_at) =
+        rows.iter().find(|row| row.0 == "initial_key").unwrap();
+    assert!(!old_active);
+    assert_eq!(*old_retires_at, Some(rollover.retires_at));
+    let (_, _, _, _, new_active, new_retires_at) =
+        rows.iter().find(|row| row.0 == rollover.new_kid).unwrap();
+    assert!(*new_active);
+    assert!(new_retires_at.is_none());
+
+    // A restarted server signs with the new key and still accepts the old token
+    let mut restarted = JwksManager::new();
+    restarted.load_keys_from_database(rows)?;
+    assert_eq!(restarted.get_active_key()?.kid, rollover.new_kid);
+    assert!(auth_manager.validate_token(&old_token, &restarted).is_ok());
+
+    let retired =
+        retire_expired_signing_keys(database.as_ref(), &jwks_manager, rollover.retires_at).await?;
+    assert_eq!(retired, vec!["initial_key".to_owned()]);
+    let rows = database.load_rsa_keypairs().await?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].0, rollover.new_kid);
+
+    Ok(())
+}
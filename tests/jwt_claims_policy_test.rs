@@ -0,0 +1,211 @@
+// ABOUTME: Tests for configurable JWT issuer and audience enforcement
+// ABOUTME: Covers matching, mismatched, and missing iss/aud claims plus the missing-claims grace flag
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, Algorithm, Header};
+use pierre_mcp_server::{
+    admin::jwks::JwksManager,
+    auth::{AuthManager, JwtClaimsPolicy},
+    config::AuthConfig,
+    models::{AuthRequest, User},
+};
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+const ISSUER: &str = "https://auth.example.com";
+const AUDIENCE: &str = "pierre-api";
+
+fn test_user() -> User {
+    User::new(
+        "claims@example.com".into(),
+        "hashed_password_123".into(),
+        Some("Claims User".into()),
+    )
+}
+
+fn policy(allow_missing_claims: bool) -> JwtClaimsPolicy {
+    JwtClaimsPolicy {
+        issuer: ISSUER.to_owned(),
+        audience: AUDIENCE.to_owned(),
+        allow_missing_claims,
+    }
+}
+
+fn auth_manager(allow_missing_claims: bool) -> AuthManager {
+    AuthManager::new(24).with_claims_policy(policy(allow_missing_claims))
+}
+
+/// Sign a token with the shared test key, adding only the given `iss`/`aud` claims
+fn sign_token(
+    jwks_manager: &JwksManager,
+    user: &User,
+    iss: Option<&str>,
+    aud: Option<&str>,
+) -> String {
+    let now = Utc::now();
+    let mut claims = Map::new();
+    claims.insert("sub".into(), json!(user.id.to_string()));
+    claims.insert("email".into(), json!(user.email));
+    claims.insert("iat".into(), json!(now.timestamp()));
+    claims.insert("exp".into(), json!((now + Duration::hours(1)).timestamp()));
+    claims.insert("jti".into(), json!(Uuid::new_v4().to_string()));
+    claims.insert("providers".into(), json!([]));
+    if let Some(iss) = iss {
+        claims.insert("iss".into(), json!(iss));
+    }
+    if let Some(aud) = aud {
+        claims.insert("aud".into(), json!(aud));
+    }
+
+    let key = jwks_manager.get_active_key().unwrap();
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(key.kid.clone());
+    encode(
+        &header,
+        &Value::Object(claims),
+        &key.encoding_key().unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_issued_tokens_carry_configured_claims() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let manager = auth_manager(false);
+    let user = test_user();
+
+    let token = manager.generate_token(&user, &jwks_manager).unwrap();
+    let claims = manager.validate_token(&token, &jwks_manager).unwrap();
+
+    assert_eq!(claims.iss, ISSUER);
+    assert_eq!(claims.aud, AUDIENCE);
+    assert!(manager
+        .validate_token_detailed(&token, &jwks_manager)
+        .is_ok());
+}
+
+#[test]
+fn test_matching_claims_are_accepted() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let user = test_user();
+    let token = sign_token(&jwks_manager, &user, Some(ISSUER), Some(AUDIENCE));
+
+    let claims = auth_manager(false)
+        .validate_token(&token, &jwks_manager)
+        .unwrap();
+    assert_eq!(claims.sub, user.id.to_string());
+}
+
+#[test]
+fn test_mismatched_issuer_is_rejected() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let token = sign_token(
+        &jwks_manager,
+        &test_user(),
+        Some("https://other-service.example.com"),
+        Some(AUDIENCE),
+    );
+
+    let error = auth_manager(false)
+        .validate_token(&token, &jwks_manager)
+        .unwrap_err();
+    assert!(error.to_string().contains("iss"), "{error}");
+}
+
+#[test]
+fn test_mismatched_audience_is_rejected() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let token = sign_token(
+        &jwks_manager,
+        &test_user(),
+        Some(ISSUER),
+        Some("another-api"),
+    );
+
+    let response = auth_manager(false).authenticate(&AuthRequest { token }, &jwks_manager);
+    assert!(!response.authenticated);
+    assert!(response.error.unwrap().contains("aud"));
+}
+
+#[test]
+fn test_tokens_from_default_manager_are_rejected_by_custom_policy() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let user = test_user();
+    let token = AuthManager::new(24)
+        .generate_token(&user, &jwks_manager)
+        .unwrap();
+
+    assert!(auth_manager(false)
+        .validate_token(&token, &jwks_manager)
+        .is_err());
+    // Grace period only covers missing claims, never mismatched ones
+    assert!(auth_manager(true)
+        .validate_token(&token, &jwks_manager)
+        .is_err());
+}
+
+#[test]
+fn test_missing_claims_are_rejected_without_grace_period() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let user = test_user();
+    let manager = auth_manager(false);
+
+    for (iss, aud) in [(None, Some(AUDIENCE)), (Some(ISSUER), None), (None, None)] {
+        let token = sign_token(&jwks_manager, &user, iss, aud);
+        let error = manager
+            .validate_token_detailed(&token, &jwks_manager)
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("missing"),
+            "iss={iss:?} aud={aud:?}: {error}"
+        );
+    }
+}
+
+#[test]
+fn test_missing_claims_are_accepted_during_grace_period() {
+    let jwks_manager = common::get_shared_test_jwks();
+    let user = test_user();
+    let manager = auth_manager(true);
+
+    let token = sign_token(&jwks_manager, &user, None, None);
+    let claims = manager.validate_token(&token, &jwks_manager).unwrap();
+    assert_eq!(claims.sub, user.id.to_string());
+    assert!(claims.iss.is_empty());
+    assert!(claims.aud.is_empty());
+
+    // Refreshing a legacy token yields one with the configured claims
+    let refreshed = manager.refresh_token(&token, &user, &jwks_manager).unwrap();
+    let refreshed_claims = auth_manager(false)
+        .validate_token(&refreshed, &jwks_manager)
+        .unwrap();
+    assert_eq!(refreshed_claims.iss, ISSUER);
+    assert_eq!(refreshed_claims.aud, AUDIENCE);
+}
+
+#[test]
+fn test_policy_from_auth_config() {
+    let default_policy = JwtClaimsPolicy::from_auth_config(&AuthConfig::default());
+    assert_eq!(default_policy.issuer, "pierre-mcp-server");
+    assert_eq!(default_policy.audience, "mcp");
+    assert!(!default_policy.allow_missing_claims);
+
+    let config = AuthConfig {
+        jwt_issuer: ISSUER.to_owned(),
+        jwt_audience: AUDIENCE.to_owned(),
+        jwt_allow_missing_claims: true,
+        ..AuthConfig::default()
+    };
+    let custom_policy = JwtClaimsPolicy::from_auth_config(&config);
+    assert_eq!(custom_policy.issuer, ISSUER);
+    assert_eq!(custom_policy.audience, AUDIENCE);
+    assert!(custom_policy.allow_missing_claims);
+}
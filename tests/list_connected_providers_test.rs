@@ -0,0 +1,171 @@
+// ABOUTME: Tests for the list_connected_providers tool and per-provider connection reports
+// ABOUTME: Verifies healthy, expired, revoked, and missing connections with scopes and last sync time
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::errors::{AppError, AppResult};
+use pierre_mcp_server::mcp::resources::ServerResources;
+use pierre_mcp_server::models::{TenantId, UserOAuthToken};
+use pierre_mcp_server::oauth2_client::token_inspection::{
+    list_provider_connections, ConnectionStatus, ProviderTokenCheck, ProviderTokenResponse,
+};
+use pierre_mcp_server::tools::implementations::connection::ListConnectedProvidersTool;
+use pierre_mcp_server::tools::traits::McpTool;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Provider check that rejects Strava tokens and cannot reach Fitbit
+struct RejectingCheck;
+
+#[async_trait]
+impl ProviderTokenCheck for RejectingCheck {
+    async fn check(&self, provider: &str, _access_token: &str) -> AppResult<ProviderTokenResponse> {
+        match provider {
+            "strava" => Ok(ProviderTokenResponse::Rejected),
+            _ => Err(AppError::external_service(provider, "connection refused")),
+        }
+    }
+}
+
+/// Seed a user with a healthy Strava token and an expired Fitbit token
+async fn seed_connections(resources: &ServerResources) -> (Uuid, TenantId) {
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+
+    for (provider, expires_in, scope) in [
+        ("strava", Duration::hours(6), "read,activity:read_all"),
+        ("fitbit", -Duration::hours(1), "activity heartrate"),
+    ] {
+        let token = UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            provider.to_owned(),
+            format!("{provider}-access"),
+            Some(format!("{provider}-refresh")),
+            Some(Utc::now() + expires_in),
+            Some(scope.to_owned()),
+        );
+        resources
+            .database
+            .upsert_user_oauth_token(&token)
+            .await
+            .unwrap();
+    }
+    (user_id, tenant_id)
+}
+
+fn provider_entry<'a>(content: &'a Value, provider: &str) -> &'a Value {
+    content["providers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["provider"] == provider)
+        .unwrap_or_else(|| panic!("{provider} missing from {content}"))
+}
+
+#[tokio::test]
+async fn test_reports_healthy_and_expired_connections() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, tenant_id) = seed_connections(&resources).await;
+    let last_sync = Utc::now() - Duration::minutes(30);
+    resources
+        .database
+        .update_provider_last_sync(user_id, tenant_id, "strava", last_sync)
+        .await
+        .unwrap();
+
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+    let result = ListConnectedProvidersTool
+        .execute(json!({}), &context)
+        .await
+        .unwrap();
+    assert!(!result.is_error, "{}", result.content);
+    assert_eq!(result.content["probed"], false);
+
+    let strava = provider_entry(&result.content, "strava");
+    assert_eq!(strava["status"], "connected");
+    assert_eq!(strava["scopes"], json!(["read", "activity:read_all"]));
+    assert_eq!(strava["has_refresh_token"], true);
+    assert!(strava["last_sync"].is_string());
+    assert!(strava.get("probe_error").is_none());
+
+    let fitbit = provider_entry(&result.content, "fitbit");
+    assert_eq!(fitbit["status"], "expired");
+    assert_eq!(fitbit["scopes"], json!(["activity", "heartrate"]));
+    assert!(fitbit["last_sync"].is_null());
+
+    // Every other OAuth provider is listed as not connected
+    for entry in result.content["providers"].as_array().unwrap() {
+        if entry["provider"] != "strava" && entry["provider"] != "fitbit" {
+            assert_eq!(entry["status"], "not_connected", "{entry}");
+            assert_eq!(entry["scopes"], json!([]));
+        }
+    }
+    assert!(result.content["providers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|entry| entry["provider"] != "synthetic"));
+}
+
+#[tokio::test]
+async fn test_probe_detects_revoked_tokens() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, tenant_id) = seed_connections(&resources).await;
+    let providers = ["fitbit", "garmin", "strava"];
+
+    let stored =
+        list_provider_connections(&resources.database, None, user_id, tenant_id, &providers)
+            .await
+            .unwrap();
+    let statuses: Vec<ConnectionStatus> = stored.iter().map(|report| report.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            ConnectionStatus::Expired,
+            ConnectionStatus::NotConnected,
+            ConnectionStatus::Connected
+        ]
+    );
+
+    let probed = list_provider_connections(
+        &resources.database,
+        Some(&RejectingCheck),
+        user_id,
+        tenant_id,
+        &providers,
+    )
+    .await
+    .unwrap();
+
+    // A failed check keeps the stored status and explains why
+    assert_eq!(probed[0].status, ConnectionStatus::Expired);
+    assert!(probed[0]
+        .probe_error
+        .as_deref()
+        .unwrap()
+        .contains("connection refused"));
+    // Providers without a token are never checked
+    assert_eq!(probed[1].status, ConnectionStatus::NotConnected);
+    assert!(probed[1].probe_error.is_none());
+    // A rejected token that has not expired was revoked
+    assert_eq!(probed[2].status, ConnectionStatus::Revoked);
+}
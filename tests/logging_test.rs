@@ -10,9 +10,61 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
 
-use pierre_mcp_server::logging::{LogFormat, LoggingConfig};
+use chrono::DateTime;
+use pierre_mcp_server::config::logging::LoggingConfig as RedactionSettings;
+use pierre_mcp_server::logging::{
+    JsonEventFormat, LogFormat, LogRedactor, LoggingConfig, RedactingMakeWriter,
+    RequestContextLayer,
+};
+use pierre_mcp_server::middleware::{create_mcp_span, create_request_span, RequestContext};
+use serde_json::Value;
 use serial_test::serial;
 use std::env;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing::info;
+use tracing::subscriber::with_default;
+use tracing_subscriber::fmt::{self, MakeWriter};
+use tracing_subscriber::layer::SubscriberExt;
+use uuid::Uuid;
+
+/// In-memory writer capturing formatted log output
+#[derive(Clone, Default)]
+struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedOutput {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+
+    fn lines(&self) -> Vec<Value> {
+        let bytes = self.0.lock().unwrap().clone();
+        String::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("every log line must be valid JSON"))
+            .collect()
+    }
+}
+
+impl io::Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedOutput {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
 
 #[test]
 #[serial]
@@ -49,3 +101,161 @@ fn test_default_logging_config() {
     assert_eq!(config.service_name, "pierre-mcp-server");
     assert!(!config.output.location); // Should be false for development
 }
+
+#[test]
+#[serial]
+fn test_pierre_log_format_takes_precedence() {
+    env::set_var("PIERRE_LOG_FORMAT", "json");
+    env::set_var("LOG_FORMAT", "compact");
+
+    let config = LoggingConfig::from_env();
+    assert!(matches!(config.format, LogFormat::Json));
+
+    env::remove_var("PIERRE_LOG_FORMAT");
+    let config = LoggingConfig::from_env();
+    assert!(matches!(config.format, LogFormat::Compact));
+
+    env::remove_var("LOG_FORMAT");
+    let config = LoggingConfig::from_env();
+    assert!(matches!(config.format, LogFormat::Pretty));
+}
+
+#[test]
+fn test_json_output_includes_request_scoped_context() {
+    let output = CapturedOutput::default();
+    let subscriber = tracing_subscriber::registry()
+        .with(RequestContextLayer)
+        .with(
+            fmt::layer()
+                .event_format(JsonEventFormat::default())
+                .with_writer(output.clone()),
+        );
+
+    let user_id = Uuid::new_v4();
+    let tenant_id = Uuid::new_v4();
+    let context = RequestContext::new().with_auth(user_id, Some(tenant_id), "Bearer".to_owned());
+
+    with_default(subscriber, || {
+        info!("before request");
+
+        let request_span = create_request_span("POST", "/mcp");
+        let _request = request_span.enter();
+        // Identifiers are recorded after the span is created, as the auth middleware does
+        context.record_in_span();
+
+        let mcp_span = create_mcp_span("tools/call");
+        let _mcp = mcp_span.enter();
+        info!(tool = "get_activities", count = 3, "tool executed");
+    });
+
+    let lines = output.lines();
+    assert_eq!(lines.len(), 2);
+
+    for line in &lines {
+        for key in [
+            "timestamp",
+            "level",
+            "target",
+            "message",
+            "tenant_id",
+            "user_id",
+            "request_id",
+        ] {
+            assert!(line.get(key).is_some(), "missing key {key} in {line}");
+        }
+    }
+
+    let outside = &lines[0];
+    assert_eq!(outside["message"], "before request");
+    assert!(outside["tenant_id"].is_null());
+    assert!(outside["user_id"].is_null());
+    assert!(outside["request_id"].is_null());
+
+    // Context recorded on the outer request span is visible inside nested spans
+    let inside = &lines[1];
+    assert_eq!(inside["level"], "INFO");
+    assert_eq!(inside["target"], "logging_test");
+    assert_eq!(inside["message"], "tool executed");
+    assert_eq!(inside["tenant_id"], tenant_id.to_string());
+    assert_eq!(inside["user_id"], user_id.to_string());
+    assert_eq!(inside["request_id"], context.request_id);
+    assert_eq!(inside["span"], "mcp_operation");
+    assert_eq!(inside["fields"]["tool"], "get_activities");
+    assert_eq!(inside["fields"]["count"], 3);
+    assert!(inside["timestamp"]
+        .as_str()
+        .is_some_and(|ts| DateTime::parse_from_rfc3339(ts).is_ok()));
+}
+
+const SENSITIVE_LINE_EMAIL: &str = "alice.runner@example.com";
+const SENSITIVE_LINE_KEY: &str = "pk_live_8fK2mQ9xLp3Rt7Vw1Yz4Bc6Dn0Hj5Sa2";
+
+fn log_sensitive_line() {
+    info!(
+        email = SENSITIVE_LINE_EMAIL,
+        "API key {SENSITIVE_LINE_KEY} used by {SENSITIVE_LINE_EMAIL} with Bearer eyJhbGciOiJIUzI1NiJ9.e30.sig"
+    );
+}
+
+#[test]
+fn test_pretty_output_masks_emails_and_api_keys() {
+    let output = CapturedOutput::default();
+    let writer = RedactingMakeWriter::new(output.clone(), Arc::new(LogRedactor::default()));
+    let subscriber =
+        tracing_subscriber::registry().with(fmt::layer().with_ansi(false).with_writer(writer));
+
+    with_default(subscriber, log_sensitive_line);
+
+    let text = output.text();
+    assert!(!text.contains(SENSITIVE_LINE_EMAIL), "{text}");
+    assert!(!text.contains(SENSITIVE_LINE_KEY), "{text}");
+    assert!(!text.contains("eyJhbGciOiJIUzI1NiJ9"), "{text}");
+    assert!(text.contains("a***@e***.com"), "{text}");
+    assert!(text.contains("API key [REDACTED] used by"), "{text}");
+    assert!(text.contains("Bearer [REDACTED]"), "{text}");
+}
+
+#[test]
+fn test_json_output_masks_emails_and_api_keys() {
+    let output = CapturedOutput::default();
+    let writer = RedactingMakeWriter::new(output.clone(), Arc::new(LogRedactor::default()));
+    let subscriber = tracing_subscriber::registry()
+        .with(RequestContextLayer)
+        .with(
+            fmt::layer()
+                .event_format(JsonEventFormat::default())
+                .with_writer(writer),
+        );
+
+    with_default(subscriber, log_sensitive_line);
+
+    let lines = output.lines();
+    assert_eq!(lines.len(), 1);
+    let message = lines[0]["message"].as_str().unwrap();
+    assert!(!message.contains(SENSITIVE_LINE_EMAIL), "{message}");
+    assert!(!message.contains(SENSITIVE_LINE_KEY), "{message}");
+    assert!(message.contains("Bearer [REDACTED]"), "{message}");
+    assert_eq!(lines[0]["fields"]["email"], "a***@e***.com");
+}
+
+#[test]
+fn test_redaction_allowlist_and_disable() {
+    let redactor = LogRedactor::default().with_allowlist(["@example.com"]);
+    let line = redactor.redact("alice.runner@example.com shared pk_trial_AbCdEfGh12345678");
+    assert_eq!(line, "alice.runner@example.com shared [REDACTED]");
+
+    let redactor = LogRedactor::default().with_allowlist([SENSITIVE_LINE_KEY]);
+    let line = redactor.redact(&format!("key {SENSITIVE_LINE_KEY}"));
+    assert_eq!(line, format!("key {SENSITIVE_LINE_KEY}"));
+
+    // The bare prefix in help messages is not a key
+    let hint = "expected 'pk_live_...'";
+    assert_eq!(LogRedactor::default().redact(hint), hint);
+
+    let disabled = LogRedactor::from_settings(&RedactionSettings {
+        redact_pii: false,
+        ..RedactionSettings::default()
+    });
+    let line = format!("{SENSITIVE_LINE_EMAIL} {SENSITIVE_LINE_KEY}");
+    assert_eq!(disabled.redact(&line), line);
+}
@@ -0,0 +1,140 @@
+// ABOUTME: Tests for the configuration parameter catalog exposed as MCP resources
+// ABOUTME: Lists resources, reads one, and compares it with the configuration catalog route
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use pierre_mcp_server::{
+    config::routes::ConfigurationRoutes,
+    mcp::{
+        multitenant::{McpRequest, McpResponse, MultiTenantMcpServer},
+        resources::ServerResources,
+    },
+};
+use serde_json::{json, Value};
+
+const PARAMETER: &str = "heart_rate.anaerobic_threshold";
+
+async fn send(
+    method: &str,
+    params: Option<Value>,
+    resources: &Arc<ServerResources>,
+) -> McpResponse {
+    let request = McpRequest {
+        jsonrpc: "2.0".to_owned(),
+        method: method.to_owned(),
+        params,
+        id: Some(json!(1)),
+        auth_token: None,
+        headers: None,
+        metadata: HashMap::new(),
+    };
+    MultiTenantMcpServer::handle_request(request, resources)
+        .await
+        .unwrap()
+}
+
+/// The catalog as returned by `GET /api/configuration/catalog`
+fn route_catalog(resources: &Arc<ServerResources>) -> Value {
+    let response = ConfigurationRoutes::new(resources.clone())
+        .get_configuration_catalog(None)
+        .unwrap();
+    serde_json::to_value(response.catalog).unwrap()
+}
+
+fn route_parameter(catalog: &Value, key: &str) -> Value {
+    catalog["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|category| category["modules"].as_array().unwrap())
+        .flat_map(|module| module["parameters"].as_array().unwrap())
+        .find(|parameter| parameter["key"] == key)
+        .cloned()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_resources_list_covers_configuration_catalog() {
+    let resources = common::create_test_server_resources().await.unwrap();
+
+    let response = send("resources/list", None, &resources).await;
+    assert!(response.error.is_none());
+    let listed = response.result.unwrap()["resources"]
+        .as_array()
+        .unwrap()
+        .clone();
+
+    let catalog = route_catalog(&resources);
+    assert_eq!(json!(listed.len()), catalog["total_parameters"]);
+
+    let entry = listed
+        .iter()
+        .find(|resource| resource["name"] == PARAMETER)
+        .unwrap();
+    assert_eq!(entry["uri"], format!("pierre://config/{PARAMETER}"));
+    assert_eq!(entry["mimeType"], "application/json");
+    assert_eq!(
+        entry["description"],
+        route_parameter(&catalog, PARAMETER)["description"]
+    );
+    assert!(listed.iter().all(|resource| resource["uri"]
+        .as_str()
+        .unwrap()
+        .starts_with("pierre://config/")));
+}
+
+#[tokio::test]
+async fn test_resources_read_matches_configuration_route() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let uri = format!("pierre://config/{PARAMETER}");
+
+    let response = send("resources/read", Some(json!({ "uri": uri })), &resources).await;
+    assert!(response.error.is_none());
+    let result = response.result.unwrap();
+    let contents = &result["contents"][0];
+    assert_eq!(contents["uri"], uri);
+    assert_eq!(contents["mimeType"], "application/json");
+    let read: Value = serde_json::from_str(contents["text"].as_str().unwrap()).unwrap();
+
+    let expected = route_parameter(&route_catalog(&resources), PARAMETER);
+    assert_eq!(read["key"], PARAMETER);
+    assert_eq!(read["value"], expected["default_value"]);
+    assert_eq!(read["value"], json!({ "type": "Float", "value": 85.0 }));
+    assert_eq!(read["data_type"], expected["data_type"]);
+    assert_eq!(read["valid_range"], expected["valid_range"]);
+    assert_eq!(read["units"], expected["units"]);
+    assert_eq!(read["description"], expected["description"]);
+}
+
+#[tokio::test]
+async fn test_resources_read_rejects_unknown_and_missing_uris() {
+    let resources = common::create_test_server_resources().await.unwrap();
+
+    let unknown = send(
+        "resources/read",
+        Some(json!({ "uri": "pierre://config/heart_rate.not_a_parameter" })),
+        &resources,
+    )
+    .await;
+    assert_eq!(unknown.error.unwrap().code, -32601);
+
+    let other_scheme = send(
+        "resources/read",
+        Some(json!({ "uri": "file:///etc/passwd" })),
+        &resources,
+    )
+    .await;
+    assert_eq!(other_scheme.error.unwrap().code, -32601);
+
+    let missing = send("resources/read", None, &resources).await;
+    assert_eq!(missing.error.unwrap().code, -32602);
+}
@@ -256,6 +256,7 @@ impl MultiTenantMcpClient {
             provider: "strava".to_owned(),
             client_id: "test_client_id".to_owned(),
             client_secret: "test_client_secret".to_owned(),
+            previous_client_secret: None,
             redirect_uri: "http://localhost:3000/auth/callback".to_owned(),
             scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
             rate_limit_per_day: 1000,
@@ -269,6 +270,7 @@ impl MultiTenantMcpClient {
             provider: "fitbit".to_owned(),
             client_id: "test_fitbit_client_id".to_owned(),
             client_secret: "test_fitbit_client_secret".to_owned(),
+            previous_client_secret: None,
             redirect_uri: "http://localhost:3000/auth/callback".to_owned(),
             scopes: vec!["activity".to_owned(), "profile".to_owned()],
             rate_limit_per_day: 1000,
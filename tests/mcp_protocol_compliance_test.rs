@@ -14,6 +14,7 @@ use pierre_mcp_server::mcp::{
     schema::*,
 };
 use serde_json::{json, Value};
+use std::collections::HashMap;
 
 mod common;
 
@@ -183,6 +184,7 @@ async fn test_jsonrpc_message_format_compliance() {
         id: Some(Value::Number(serde_json::Number::from(123))),
         result: Some(json!({"tools": []})),
         error: None,
+        metadata: HashMap::new(),
     };
 
     let serialized = serde_json::to_value(&response).expect("Should serialize");
@@ -400,6 +402,7 @@ async fn test_error_response_format_compliance() {
         id: Some(Value::Number(serde_json::Number::from(101))),
         result: None,
         error: Some(error),
+        metadata: HashMap::new(),
     };
 
     let serialized = serde_json::to_value(&response).expect("Should serialize");
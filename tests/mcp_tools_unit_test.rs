@@ -14,18 +14,18 @@
 //! - Parameter validation tests
 //! - Factory function tests
 //!
-//! ## Test Categories (67 tools total)
+//! ## Test Categories (84 tools total)
 //!
-//! - Coaches (13 tools)
+//! - Coaches (14 tools)
 //! - Configuration (6 tools)
 //! - Fitness Config (4 tools)
 //! - Nutrition (5 tools)
-//! - Recipes (7 tools)
-//! - Sleep (5 tools)
-//! - Data (3 tools)
-//! - Analytics (3 tools)
+//! - Recipes (8 tools)
+//! - Sleep (6 tools)
+//! - Data (12 tools)
+//! - Analytics (7 tools)
 //! - Goals (4 tools)
-//! - Connection (3 tools)
+//! - Connection (4 tools)
 //! - Admin (8 tools)
 //! - Mobility (6 tools)
 //!
@@ -40,15 +40,16 @@
 use pierre_mcp_server::tools::traits::{McpTool, ToolCapabilities};
 
 // ============================================================================
-// COACHES TOOLS TESTS (13 tools)
+// COACHES TOOLS TESTS (14 tools)
 // ============================================================================
 
 mod coaches_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::coaches::{
         ActivateCoachTool, CreateCoachTool, DeactivateCoachTool, DeleteCoachTool,
-        GetActiveCoachTool, GetCoachTool, HideCoachTool, ListCoachesTool, ListHiddenCoachesTool,
-        SearchCoachesTool, ShowCoachTool, ToggleCoachFavoriteTool, UpdateCoachTool,
+        GetActiveCoachTool, GetCoachContextTool, GetCoachTool, HideCoachTool, ListCoachesTool,
+        ListHiddenCoachesTool, SearchCoachesTool, ShowCoachTool, ToggleCoachFavoriteTool,
+        UpdateCoachTool,
     };
 
     #[test]
@@ -254,12 +255,29 @@ mod coaches_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_get_coach_context_tool_metadata() {
+        let tool = GetCoachContextTool;
+        assert_eq!(tool.name(), "get_coach_context");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("coach_id"));
+        assert!(props.contains_key("provider"));
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_create_coach_tools_factory() {
         use pierre_mcp_server::tools::implementations::coaches::create_coach_tools;
 
         let tools = create_coach_tools();
-        assert_eq!(tools.len(), 13, "Expected 13 coach tools");
+        assert_eq!(tools.len(), 14, "Expected 14 coach tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         let expected_names = [
@@ -276,6 +294,7 @@ mod coaches_tests {
             "hide_coach",
             "show_coach",
             "list_hidden_coaches",
+            "get_coach_context",
         ];
 
         for expected in expected_names {
@@ -649,14 +668,14 @@ mod nutrition_tests {
 }
 
 // ============================================================================
-// RECIPES TOOLS TESTS (7 tools)
+// RECIPES TOOLS TESTS (8 tools)
 // ============================================================================
 
 mod recipes_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::recipes::{
         DeleteRecipeTool, GetRecipeConstraintsTool, GetRecipeTool, ListRecipesTool, SaveRecipeTool,
-        SearchRecipesTool, ValidateRecipeTool,
+        SearchRecipesTool, SuggestRecipesTool, ValidateRecipeTool,
     };
 
     #[test]
@@ -750,12 +769,30 @@ mod recipes_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_suggest_recipes_tool_metadata() {
+        let tool = SuggestRecipesTool;
+        assert_eq!(tool.name(), "suggest_recipes");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let required = schema
+            .required
+            .as_ref()
+            .expect("Should have required fields");
+        assert!(required.contains(&"training_intensity".to_owned()));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_create_recipe_tools_factory() {
         use pierre_mcp_server::tools::implementations::recipes::create_recipe_tools;
 
         let tools = create_recipe_tools();
-        assert_eq!(tools.len(), 7, "Expected 7 recipe tools");
+        assert_eq!(tools.len(), 8, "Expected 8 recipe tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         let expected_names = [
@@ -766,6 +803,7 @@ mod recipes_tests {
             "get_recipe",
             "delete_recipe",
             "search_recipes",
+            "suggest_recipes",
         ];
 
         for expected in expected_names {
@@ -790,14 +828,14 @@ mod recipes_tests {
 }
 
 // ============================================================================
-// SLEEP TOOLS TESTS (5 tools)
+// SLEEP TOOLS TESTS (6 tools)
 // ============================================================================
 
 mod sleep_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::sleep::{
-        AnalyzeSleepQualityTool, CalculateRecoveryScoreTool, OptimizeSleepScheduleTool,
-        SuggestRestDayTool, TrackSleepTrendsTool,
+        AnalyzeSleepQualityTool, CalculateRecoveryScoreTool, GetRecoveryScoreTool,
+        OptimizeSleepScheduleTool, SuggestRestDayTool, TrackSleepTrendsTool,
     };
 
     #[test]
@@ -833,6 +871,17 @@ mod sleep_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_get_recovery_score_tool_metadata() {
+        let tool = GetRecoveryScoreTool;
+        assert_eq!(tool.name(), "get_recovery_score");
+        assert!(!tool.description().is_empty());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_track_sleep_trends_tool_metadata() {
         let tool = TrackSleepTrendsTool;
@@ -860,13 +909,14 @@ mod sleep_tests {
         use pierre_mcp_server::tools::implementations::sleep::create_sleep_tools;
 
         let tools = create_sleep_tools();
-        assert_eq!(tools.len(), 5, "Expected 5 sleep tools");
+        assert_eq!(tools.len(), 6, "Expected 6 sleep tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         let expected_names = [
             "analyze_sleep_quality",
             "calculate_recovery_score",
             "suggest_rest_day",
+            "get_recovery_score",
             "track_sleep_trends",
             "optimize_sleep_schedule",
         ];
@@ -913,7 +963,9 @@ mod sleep_tests {
 mod data_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::data::{
-        GetActivitiesTool, GetAthleteTool, GetStatsTool,
+        ExportUserDataTool, GetActivitiesDeltaTool, GetActivitiesTool, GetActivitySplitsTool,
+        GetActivityWeatherTool, GetAthleteTool, GetSegmentEffortsTool, GetStatsTool, ListGearTool,
+        MergeActivitiesTool, SearchActivitiesTool, UnmergeActivitiesTool,
     };
 
     #[test]
@@ -922,6 +974,10 @@ mod data_tests {
         assert_eq!(tool.name(), "get_activities");
         assert!(!tool.description().is_empty());
 
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert_eq!(props["fields"].property_type, "array");
+
         let caps = tool.capabilities();
         assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
         assert!(caps.contains(ToolCapabilities::READS_DATA));
@@ -949,15 +1005,186 @@ mod data_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_list_gear_tool_metadata() {
+        let tool = ListGearTool;
+        assert_eq!(tool.name(), "list_gear");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("shoe_replacement_km"));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_get_segment_efforts_tool_metadata() {
+        let tool = GetSegmentEffortsTool;
+        assert_eq!(tool.name(), "get_segment_efforts");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("include_segments"));
+        assert_eq!(schema.required, Some(vec!["activity_id".to_owned()]));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_get_activity_splits_tool_metadata() {
+        let tool = GetActivitySplitsTool;
+        assert_eq!(tool.name(), "get_activity_splits");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("unit"));
+        assert_eq!(schema.required, Some(vec!["activity_id".to_owned()]));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_get_activity_weather_tool_metadata() {
+        let tool = GetActivityWeatherTool;
+        assert_eq!(tool.name(), "get_activity_weather");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        assert_eq!(schema.required, Some(vec!["activity_id".to_owned()]));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_search_activities_tool_metadata() {
+        let tool = SearchActivitiesTool;
+        assert_eq!(tool.name(), "search_activities");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        for key in [
+            "sport_type",
+            "min_distance_km",
+            "after",
+            "name_contains",
+            "sort_by",
+        ] {
+            assert!(props.contains_key(key), "Missing property: {key}");
+        }
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_get_activities_delta_tool_metadata() {
+        let tool = GetActivitiesDeltaTool;
+        assert_eq!(tool.name(), "get_activities_delta");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("provider"));
+        assert!(props.contains_key("since"));
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
+    #[test]
+    fn test_merge_activities_tool_metadata() {
+        let tool = MergeActivitiesTool;
+        assert_eq!(tool.name(), "merge_activities");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("primary_provider"));
+        assert!(props.contains_key("secondary_provider"));
+        assert!(props.contains_key("kind"));
+        assert!(props.contains_key("max_gap_seconds"));
+        assert!(props.contains_key("require_same_sport"));
+        let required = schema.required.as_ref().unwrap();
+        assert!(required.contains(&"primary_id".to_owned()));
+        assert!(required.contains(&"secondary_id".to_owned()));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+        assert!(caps.contains(ToolCapabilities::WRITES_DATA));
+    }
+
+    #[test]
+    fn test_unmerge_activities_tool_metadata() {
+        let tool = UnmergeActivitiesTool;
+        assert_eq!(tool.name(), "unmerge_activities");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let required = schema.required.as_ref().unwrap();
+        assert!(required.contains(&"primary_id".to_owned()));
+        assert!(required.contains(&"secondary_id".to_owned()));
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::WRITES_DATA));
+    }
+
+    #[test]
+    fn test_export_user_data_tool_metadata() {
+        let tool = ExportUserDataTool;
+        assert_eq!(tool.name(), "export_user_data");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("activity_days"));
+        assert!(props.contains_key("max_activities"));
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_create_data_tools_factory() {
         use pierre_mcp_server::tools::implementations::data::create_data_tools;
 
         let tools = create_data_tools();
-        assert_eq!(tools.len(), 3, "Expected 3 data tools");
+        assert_eq!(tools.len(), 12, "Expected 12 data tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
-        let expected_names = ["get_activities", "get_athlete", "get_stats"];
+        let expected_names = [
+            "get_activities",
+            "get_athlete",
+            "get_stats",
+            "list_gear",
+            "get_segment_efforts",
+            "get_activity_splits",
+            "get_activity_weather",
+            "search_activities",
+            "get_activities_delta",
+            "merge_activities",
+            "unmerge_activities",
+            "export_user_data",
+        ];
 
         for expected in expected_names {
             assert!(names.contains(&expected), "Missing: {expected}");
@@ -965,11 +1192,14 @@ mod data_tests {
     }
 
     #[test]
-    fn test_all_data_tools_are_read_only() {
+    fn test_data_tools_are_read_only_except_merges() {
         use pierre_mcp_server::tools::implementations::data::create_data_tools;
 
         let tools = create_data_tools();
-        for tool in &tools {
+        for tool in tools
+            .iter()
+            .filter(|t| !matches!(t.name(), "merge_activities" | "unmerge_activities"))
+        {
             assert!(
                 !tool.capabilities().contains(ToolCapabilities::WRITES_DATA),
                 "Tool {} should not have WRITES_DATA",
@@ -980,13 +1210,14 @@ mod data_tests {
 }
 
 // ============================================================================
-// ANALYTICS TOOLS TESTS (3 tools)
+// ANALYTICS TOOLS TESTS (7 tools)
 // ============================================================================
 
 mod analytics_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::analytics::{
-        AnalyzeTrainingLoadTool, CalculateFitnessScoreTool, DetectPatternsTool,
+        AnalyzeTimeInZonesTool, AnalyzeTrainingLoadTool, CalculateFitnessScoreTool,
+        DetectPatternsTool, GetTrainingLoadTrendTool,
     };
 
     #[test]
@@ -1000,6 +1231,22 @@ mod analytics_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_get_training_load_trend_tool_metadata() {
+        let tool = GetTrainingLoadTrendTool;
+        assert_eq!(tool.name(), "get_training_load_trend");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("days"));
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_detect_patterns_tool_metadata() {
         let tool = DetectPatternsTool;
@@ -1022,18 +1269,33 @@ mod analytics_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_analyze_time_in_zones_tool_metadata() {
+        let tool = AnalyzeTimeInZonesTool;
+        assert_eq!(tool.name(), "analyze_time_in_zones");
+        assert!(!tool.description().is_empty());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_create_analytics_tools_factory() {
         use pierre_mcp_server::tools::implementations::analytics::create_analytics_tools;
 
         let tools = create_analytics_tools();
-        assert_eq!(tools.len(), 3, "Expected 3 analytics tools");
+        assert_eq!(tools.len(), 7, "Expected 7 analytics tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         let expected_names = [
             "analyze_training_load",
+            "get_training_load_trend",
             "detect_patterns",
             "calculate_fitness_score",
+            "predict_race_times",
+            "compare_activities",
+            "analyze_time_in_zones",
         ];
 
         for expected in expected_names {
@@ -1147,13 +1409,14 @@ mod goals_tests {
 }
 
 // ============================================================================
-// CONNECTION TOOLS TESTS (3 tools)
+// CONNECTION TOOLS TESTS (4 tools)
 // ============================================================================
 
 mod connection_tests {
     use super::*;
     use pierre_mcp_server::tools::implementations::connection::{
         ConnectProviderTool, DisconnectProviderTool, GetConnectionStatusTool,
+        ListConnectedProvidersTool,
     };
 
     #[test]
@@ -1178,6 +1441,22 @@ mod connection_tests {
         assert!(caps.contains(ToolCapabilities::READS_DATA));
     }
 
+    #[test]
+    fn test_list_connected_providers_tool_metadata() {
+        let tool = ListConnectedProvidersTool;
+        assert_eq!(tool.name(), "list_connected_providers");
+        assert!(!tool.description().is_empty());
+
+        let schema = tool.input_schema();
+        let props = schema.properties.as_ref().unwrap();
+        assert!(props.contains_key("probe"));
+        assert!(schema.required.is_none());
+
+        let caps = tool.capabilities();
+        assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+        assert!(caps.contains(ToolCapabilities::READS_DATA));
+    }
+
     #[test]
     fn test_disconnect_provider_tool_metadata() {
         let tool = DisconnectProviderTool;
@@ -1194,12 +1473,13 @@ mod connection_tests {
         use pierre_mcp_server::tools::implementations::connection::create_connection_tools;
 
         let tools = create_connection_tools();
-        assert_eq!(tools.len(), 3, "Expected 3 connection tools");
+        assert_eq!(tools.len(), 4, "Expected 4 connection tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         let expected_names = [
             "connect_provider",
             "get_connection_status",
+            "list_connected_providers",
             "disconnect_provider",
         ];
 
@@ -1404,7 +1684,7 @@ fn test_total_tool_count() {
         + admin.len()
         + mobility.len();
 
-    assert_eq!(total, 67, "Expected 67 tools across all categories");
+    assert_eq!(total, 84, "Expected 84 tools across all categories");
 }
 
 #[test]
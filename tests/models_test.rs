@@ -170,6 +170,7 @@ fn test_personal_record_creation() {
         metric: PrMetric::LongestDistance,
         value: 42195.0, // Marathon distance in meters
         date: Utc::now(),
+        segment_id: None,
     };
 
     assert_eq!(pr.activity_id, "12345");
@@ -563,6 +564,7 @@ fn create_test_activity_with_segments() -> Activity {
     .segment_efforts(vec![
         SegmentEffort {
             id: "seg_001".into(),
+            segment_id: None,
             name: "Steep Climb".into(),
             elapsed_time: 600,
             moving_time: Some(590),
@@ -580,6 +582,7 @@ fn create_test_activity_with_segments() -> Activity {
         },
         SegmentEffort {
             id: "seg_002".into(),
+            segment_id: None,
             name: "Fast Descent".into(),
             elapsed_time: 300,
             moving_time: Some(295),
@@ -671,6 +674,7 @@ fn test_activity_detailed_fields_serialization() {
     // Test that detailed fields serialize/deserialize correctly
     let segment = SegmentEffort {
         id: "seg_test".into(),
+        segment_id: None,
         name: "Test Segment".into(),
         elapsed_time: 180,
         moving_time: Some(175),
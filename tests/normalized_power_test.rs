@@ -0,0 +1,162 @@
+// ABOUTME: Tests for normalized power, intensity factor, and variability index on cycling power streams
+// ABOUTME: Verifies NP against a reference rolling-average calculation and the analyzer insight for rides
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::intelligence::{
+    ActivityAnalyzerTrait, AdvancedActivityAnalyzer, MetricsCalculator,
+};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData};
+
+const FTP: f64 = 250.0;
+
+/// NP of ten minutes at 300 W followed by ten minutes at 100 W, worked out by hand
+const BLOCKS_REFERENCE_NP: f64 = 252.378;
+
+/// Ten minutes at 300 W then ten minutes at 100 W, one sample per second
+fn block_stream() -> Vec<u32> {
+    [vec![300; 600], vec![100; 600]].concat()
+}
+
+/// Straightforward NP: average each 30-sample window, raise to the 4th, mean, 4th root
+fn reference_normalized_power(power: &[u32]) -> f64 {
+    let averages: Vec<f64> = power
+        .windows(30)
+        .map(|window| window.iter().map(|&p| f64::from(p)).sum::<f64>() / 30.0)
+        .collect();
+    let count = f64::from(u32::try_from(averages.len()).unwrap());
+    (averages.iter().map(|avg| avg.powi(4)).sum::<f64>() / count).powf(0.25)
+}
+
+fn ride(sport_type: SportType, power: Vec<u32>) -> Activity {
+    let samples = u32::try_from(power.len()).unwrap();
+    let average = power.iter().sum::<u32>() / samples;
+    ActivityBuilder::new(
+        "np-ride",
+        "Over-Unders",
+        sport_type,
+        Utc.with_ymd_and_hms(2025, 6, 7, 8, 0, 0).unwrap(),
+        u64::from(samples),
+        "test",
+    )
+    .average_power(average)
+    .time_series_data(TimeSeriesData {
+        timestamps: (0..samples).collect(),
+        heart_rate: None,
+        power: Some(power),
+        cadence: None,
+        speed: None,
+        altitude: None,
+        temperature: None,
+        gps_coordinates: None,
+    })
+    .build()
+}
+
+#[test]
+fn test_normalized_power_matches_reference() {
+    let calculator = MetricsCalculator::new();
+    let power = block_stream();
+
+    let np = calculator.calculate_normalized_power(&power).unwrap();
+
+    assert!((np - BLOCKS_REFERENCE_NP).abs() < 0.01, "NP was {np}");
+    assert!((np - reference_normalized_power(&power)).abs() < 1e-9);
+
+    // Alternating minutes at 250 W and 150 W over an hour
+    let intervals: Vec<u32> = (0..3600)
+        .map(|second| if (second / 60) % 2 == 0 { 250 } else { 150 })
+        .collect();
+    let np = calculator.calculate_normalized_power(&intervals).unwrap();
+    assert!((np - reference_normalized_power(&intervals)).abs() < 1e-9);
+    assert!((np - 211.626).abs() < 0.01, "NP was {np}");
+}
+
+#[test]
+fn test_steady_stream_normalized_power_equals_power() {
+    let calculator = MetricsCalculator::new();
+
+    let np = calculator.calculate_normalized_power(&[220; 1800]).unwrap();
+    assert!((np - 220.0).abs() < 1e-9);
+
+    // Shorter than a single 30-second window
+    assert!(calculator.calculate_normalized_power(&[220; 29]).is_none());
+}
+
+#[test]
+fn test_intensity_factor_and_variability_index_from_stream() {
+    let calculator = MetricsCalculator::new().with_user_data(Some(FTP), None, None, None, None);
+
+    let metrics = calculator
+        .calculate_metrics(&ride(SportType::Ride, block_stream()))
+        .unwrap();
+
+    let np = metrics.normalized_power.unwrap();
+    assert!((np - BLOCKS_REFERENCE_NP).abs() < 0.01);
+    assert!((metrics.intensity_factor.unwrap() - np / FTP).abs() < 1e-9);
+    assert!((metrics.variability_index.unwrap() - np / 200.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_provider_normalized_power_preferred() {
+    let calculator = MetricsCalculator::new().with_user_data(Some(FTP), None, None, None, None);
+    let activity = ActivityBuilder::new(
+        "np-summary",
+        "Tempo Ride",
+        SportType::Ride,
+        Utc.with_ymd_and_hms(2025, 6, 8, 8, 0, 0).unwrap(),
+        3600,
+        "test",
+    )
+    .average_power(200)
+    .normalized_power(220)
+    .build();
+
+    let metrics = calculator.calculate_metrics(&activity).unwrap();
+
+    assert_eq!(metrics.normalized_power, Some(220.0));
+    assert!((metrics.intensity_factor.unwrap() - 0.88).abs() < 1e-9);
+    assert!((metrics.variability_index.unwrap() - 1.1).abs() < 1e-9);
+
+    // Without an FTP there is no intensity factor
+    let metrics = MetricsCalculator::new()
+        .calculate_metrics(&activity)
+        .unwrap();
+    assert!(metrics.intensity_factor.is_none());
+}
+
+#[tokio::test]
+async fn test_analyzer_reports_normalized_power_for_rides() {
+    let analyzer = AdvancedActivityAnalyzer::with_user_data(Some(FTP), None, None, None, None);
+
+    let insights = analyzer
+        .analyze_activity(&ride(SportType::VirtualRide, block_stream()))
+        .await
+        .unwrap();
+
+    assert!(insights.metrics.normalized_power.is_some());
+    let np_insight = insights
+        .insights
+        .iter()
+        .find(|insight| insight.insight_type == "normalized_power")
+        .expect("ride with power should carry a normalized power insight");
+    let np = np_insight.metadata["normalized_power"].as_f64().unwrap();
+    assert!((np - BLOCKS_REFERENCE_NP).abs() < 0.01);
+    assert!(np_insight.metadata.contains_key("intensity_factor"));
+    // VI of 1.26 is well above the highly variable threshold
+    assert!(np_insight.message.contains("Highly variable"));
+
+    let run = analyzer
+        .analyze_activity(&ride(SportType::Run, block_stream()))
+        .await
+        .unwrap();
+    assert!(run
+        .insights
+        .iter()
+        .all(|insight| insight.insight_type != "normalized_power"));
+}
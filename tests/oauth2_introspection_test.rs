@@ -0,0 +1,290 @@
+// ABOUTME: Tests for RFC 7662 token introspection on the OAuth 2.0 authorization server
+// ABOUTME: Verifies active, expired, and revoked access and refresh tokens and client authentication
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{Duration, Utc};
+use pierre_mcp_server::{
+    auth::AuthManager,
+    database_plugins::DatabaseProvider,
+    oauth2_server::{
+        client_registration::ClientRegistrationManager,
+        endpoints::OAuth2AuthorizationServer,
+        models::{
+            ClientRegistrationRequest, IntrospectionRequest, IntrospectionResponse,
+            OAuth2IssuedAccessToken, OAuth2RefreshToken,
+        },
+    },
+    rate_limiting::OAuth2RateLimitConfig,
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn introspection_request(
+    token: &str,
+    token_type_hint: Option<&str>,
+    client_id: &str,
+    client_secret: &str,
+) -> IntrospectionRequest {
+    IntrospectionRequest {
+        token: token.to_owned(),
+        token_type_hint: token_type_hint.map(str::to_owned),
+        client_id: client_id.to_owned(),
+        client_secret: client_secret.to_owned(),
+    }
+}
+
+fn refresh_token(
+    token: &str,
+    client_id: &str,
+    user_id: Uuid,
+    tenant_id: &str,
+    expires_in: Duration,
+) -> OAuth2RefreshToken {
+    OAuth2RefreshToken {
+        token: token.to_owned(),
+        client_id: client_id.to_owned(),
+        user_id,
+        tenant_id: tenant_id.to_owned(),
+        scope: Some("fitness:read activities:read".to_owned()),
+        expires_at: Utc::now() + expires_in,
+        created_at: Utc::now(),
+        revoked: false,
+    }
+}
+
+#[tokio::test]
+async fn test_introspection_reports_active_expired_and_revoked_tokens() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, user) = common::create_test_user(&database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0]
+        .id
+        .to_string();
+    let jwks_manager = common::get_shared_test_jwks();
+    let auth_manager = Arc::new(AuthManager::new(24));
+    let server = OAuth2AuthorizationServer::new(
+        database.clone(),
+        auth_manager.clone(),
+        jwks_manager.clone(),
+    );
+
+    let registration_manager = ClientRegistrationManager::new(database.clone());
+    let mut clients = Vec::new();
+    for name in ["Resource Server", "Other Client"] {
+        let registration = registration_manager
+            .register_client(ClientRegistrationRequest {
+                redirect_uris: vec!["https://example.com/callback".to_owned()],
+                client_name: Some(name.to_owned()),
+                client_uri: None,
+                grant_types: None,
+                response_types: None,
+                scope: None,
+                token_endpoint_auth_method: None,
+                require_pkce: None,
+            })
+            .await
+            .unwrap();
+        clients.push((registration.client_id, registration.client_secret));
+    }
+    let (client_id, client_secret) = clients[0].clone();
+    let (other_client_id, other_client_secret) = clients[1].clone();
+
+    // Active access token
+    let access_token = auth_manager
+        .generate_oauth_access_token(
+            &jwks_manager,
+            &user_id,
+            &["fitness:read".to_owned(), "activities:read".to_owned()],
+            None,
+        )
+        .unwrap();
+    let jti = auth_manager
+        .validate_token_detailed(&access_token, &jwks_manager)
+        .unwrap()
+        .jti;
+    database
+        .store_oauth2_access_token(&OAuth2IssuedAccessToken {
+            jti,
+            refresh_token: "access-token-refresh-token".to_owned(),
+            client_id: client_id.clone(),
+            user_id,
+            expires_at: Utc::now() + Duration::hours(1),
+            revoked: false,
+        })
+        .await
+        .unwrap();
+    let response = server
+        .introspect(introspection_request(
+            &access_token,
+            None,
+            &client_id,
+            &client_secret,
+        ))
+        .await
+        .unwrap();
+    assert!(response.active);
+    assert_eq!(response.client_id.as_deref(), Some(client_id.as_str()));
+    assert_eq!(
+        response.scope.as_deref(),
+        Some("fitness:read activities:read")
+    );
+    assert_eq!(response.sub, Some(user_id.to_string()));
+    assert!(response.exp.unwrap() > Utc::now().timestamp());
+
+    // Access tokens are not visible to other clients
+    let response = server
+        .introspect(introspection_request(
+            &access_token,
+            Some("access_token"),
+            &other_client_id,
+            &other_client_secret,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response, IntrospectionResponse::inactive());
+
+    // Active refresh token, found even with a misleading hint
+    let active_refresh = refresh_token(
+        "active-refresh-token",
+        &client_id,
+        user_id,
+        &tenant_id,
+        Duration::days(30),
+    );
+    database
+        .store_oauth2_refresh_token(&active_refresh)
+        .await
+        .unwrap();
+    for hint in [Some("refresh_token"), Some("access_token"), None] {
+        let response = server
+            .introspect(introspection_request(
+                "active-refresh-token",
+                hint,
+                &client_id,
+                &client_secret,
+            ))
+            .await
+            .unwrap();
+        assert!(response.active);
+        assert_eq!(response.client_id.as_deref(), Some(client_id.as_str()));
+        assert_eq!(response.tenant_id.as_deref(), Some(tenant_id.as_str()));
+        assert_eq!(response.sub, Some(user_id.to_string()));
+        assert_eq!(
+            response.scope.as_deref(),
+            Some("fitness:read activities:read")
+        );
+    }
+
+    // Refresh tokens are not visible to other clients
+    let response = server
+        .introspect(introspection_request(
+            "active-refresh-token",
+            Some("refresh_token"),
+            &other_client_id,
+            &other_client_secret,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response, IntrospectionResponse::inactive());
+
+    // Expired access and refresh tokens
+    let expired_access_token = AuthManager::new(-1)
+        .generate_token(&user, &jwks_manager)
+        .unwrap();
+    database
+        .store_oauth2_refresh_token(&refresh_token(
+            "expired-refresh-token",
+            &client_id,
+            user_id,
+            &tenant_id,
+            Duration::hours(-1),
+        ))
+        .await
+        .unwrap();
+
+    // Revoked refresh token
+    database
+        .revoke_oauth2_refresh_token("active-refresh-token")
+        .await
+        .unwrap();
+
+    // Expired, revoked, and unknown tokens all look the same
+    for token in [
+        expired_access_token.as_str(),
+        "expired-refresh-token",
+        "active-refresh-token",
+        "never-issued-token",
+    ] {
+        let response = server
+            .introspect(introspection_request(
+                token,
+                Some("refresh_token"),
+                &client_id,
+                &client_secret,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response, IntrospectionResponse::inactive());
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({ "active": false })
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_introspection_requires_valid_client_credentials() {
+    let database = common::create_test_database().await.unwrap();
+    let jwks_manager = common::get_shared_test_jwks();
+    let server = OAuth2AuthorizationServer::new(
+        database.clone(),
+        Arc::new(AuthManager::new(24)),
+        jwks_manager,
+    );
+
+    let registration = ClientRegistrationManager::new(database)
+        .register_client(ClientRegistrationRequest {
+            redirect_uris: vec!["https://example.com/callback".to_owned()],
+            client_name: Some("Resource Server".to_owned()),
+            client_uri: None,
+            grant_types: None,
+            response_types: None,
+            scope: None,
+            token_endpoint_auth_method: None,
+            require_pkce: None,
+        })
+        .await
+        .unwrap();
+
+    let error = server
+        .introspect(introspection_request(
+            "any-token",
+            None,
+            &registration.client_id,
+            "wrong-secret",
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_client");
+
+    let error = server
+        .introspect(introspection_request(
+            "any-token",
+            None,
+            "unknown-client",
+            &registration.client_secret,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_client");
+
+    // Introspection has its own configurable rate limit
+    assert_eq!(OAuth2RateLimitConfig::new().get_limit("introspect"), 60);
+}
@@ -72,6 +72,8 @@ async fn setup_test_env() -> (
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let registration_response = registration_manager
@@ -380,6 +382,8 @@ async fn test_auth_code_client_binding() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
     let second_client_response = registration_manager
         .register_client(second_client_request)
@@ -0,0 +1,247 @@
+// ABOUTME: Tests for the per-client PKCE requirement and the server-wide S256 enforcement policy
+// ABOUTME: Verifies missing challenges, plain method handling, public client rules, and S256 round trips
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use base64::{engine::general_purpose, Engine as _};
+use pierre_mcp_server::{
+    auth::AuthManager,
+    database_plugins::factory::Database,
+    oauth2_server::{
+        client_registration::ClientRegistrationManager,
+        endpoints::OAuth2AuthorizationServer,
+        models::{
+            AuthorizeRequest, ClientRegistrationRequest, ClientRegistrationResponse, TokenRequest,
+        },
+    },
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const REDIRECT_URI: &str = "https://example.com/callback";
+
+fn registration_request(
+    token_endpoint_auth_method: Option<&str>,
+    require_pkce: Option<bool>,
+) -> ClientRegistrationRequest {
+    ClientRegistrationRequest {
+        redirect_uris: vec![REDIRECT_URI.to_owned()],
+        client_name: Some("PKCE Policy Client".to_owned()),
+        client_uri: None,
+        grant_types: None,
+        response_types: None,
+        scope: None,
+        token_endpoint_auth_method: token_endpoint_auth_method.map(str::to_owned),
+        require_pkce,
+    }
+}
+
+async fn register(
+    database: &Arc<Database>,
+    token_endpoint_auth_method: Option<&str>,
+    require_pkce: Option<bool>,
+) -> ClientRegistrationResponse {
+    ClientRegistrationManager::new(database.clone())
+        .register_client(registration_request(
+            token_endpoint_auth_method,
+            require_pkce,
+        ))
+        .await
+        .unwrap()
+}
+
+fn server(database: &Arc<Database>) -> OAuth2AuthorizationServer {
+    OAuth2AuthorizationServer::new(
+        database.clone(),
+        Arc::new(AuthManager::new(24)),
+        common::get_shared_test_jwks(),
+    )
+}
+
+fn authorize_request(
+    client_id: &str,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<&str>,
+) -> AuthorizeRequest {
+    AuthorizeRequest {
+        response_type: "code".to_owned(),
+        client_id: client_id.to_owned(),
+        redirect_uri: REDIRECT_URI.to_owned(),
+        scope: Some("fitness:read".to_owned()),
+        state: Some("pkce-policy-state".to_owned()),
+        code_challenge,
+        code_challenge_method: code_challenge_method.map(str::to_owned),
+    }
+}
+
+fn token_request(
+    client: &ClientRegistrationResponse,
+    code: String,
+    code_verifier: Option<String>,
+) -> TokenRequest {
+    TokenRequest {
+        grant_type: "authorization_code".to_owned(),
+        code: Some(code),
+        redirect_uri: Some(REDIRECT_URI.to_owned()),
+        client_id: client.client_id.clone(),
+        client_secret: client.client_secret.clone(),
+        scope: None,
+        refresh_token: None,
+        code_verifier,
+    }
+}
+
+/// Generate a PKCE `code_verifier` (43 base64url characters)
+fn generate_code_verifier() -> String {
+    use ring::rand::{SecureRandom, SystemRandom};
+    let mut random_bytes = [0u8; 32];
+    SystemRandom::new().fill(&mut random_bytes).unwrap();
+    general_purpose::URL_SAFE_NO_PAD.encode(random_bytes)
+}
+
+fn s256_challenge(code_verifier: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[tokio::test]
+async fn test_missing_challenge_follows_client_policy() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let server = server(&database);
+
+    // Public clients always require PKCE
+    let public_client = register(&database, Some("none"), None).await;
+    assert!(public_client.require_pkce);
+    let error = server
+        .authorize(
+            authorize_request(&public_client.client_id, None, None),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_request");
+    assert!(error
+        .error_description
+        .unwrap()
+        .contains("code_challenge is required"));
+
+    // Public clients cannot opt out at registration
+    let error = ClientRegistrationManager::new(database.clone())
+        .register_client(registration_request(Some("none"), Some(false)))
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_request");
+
+    // Confidential clients require PKCE by default but may opt out
+    let default_client = register(&database, None, None).await;
+    assert!(default_client.require_pkce);
+    let opted_out_client = register(&database, Some("client_secret_post"), Some(false)).await;
+    assert!(!opted_out_client.require_pkce);
+
+    let response = server
+        .authorize(
+            authorize_request(&opted_out_client.client_id, None, None),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap();
+    server
+        .token(token_request(&opted_out_client, response.code, None))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_plain_method_rejected_unless_s256_not_required() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let client = register(&database, None, None).await;
+    let code_verifier = generate_code_verifier();
+
+    // The default policy only accepts S256
+    let error = server(&database)
+        .authorize(
+            authorize_request(
+                &client.client_id,
+                Some(code_verifier.clone()),
+                Some("plain"),
+            ),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_request");
+    assert!(error.error_description.unwrap().contains("S256"));
+
+    // With S256 enforcement disabled, a plain challenge completes the flow
+    let lenient_server = server(&database).with_require_s256(false);
+    let response = lenient_server
+        .authorize(
+            authorize_request(
+                &client.client_id,
+                Some(code_verifier.clone()),
+                Some("plain"),
+            ),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap();
+    lenient_server
+        .token(token_request(&client, response.code, Some(code_verifier)))
+        .await
+        .unwrap();
+
+    // Unknown methods are rejected regardless of the policy
+    let error = lenient_server
+        .authorize(
+            authorize_request(
+                &client.client_id,
+                Some(generate_code_verifier()),
+                Some("S512"),
+            ),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_request");
+}
+
+#[tokio::test]
+async fn test_valid_s256_round_trip_for_public_client() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let server = server(&database);
+    let client = register(&database, Some("none"), None).await;
+
+    let code_verifier = generate_code_verifier();
+    let response = server
+        .authorize(
+            authorize_request(
+                &client.client_id,
+                Some(s256_challenge(&code_verifier)),
+                Some("S256"),
+            ),
+            Some(user_id),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.state.as_deref(), Some("pkce-policy-state"));
+
+    let tokens = server
+        .token(token_request(&client, response.code, Some(code_verifier)))
+        .await
+        .unwrap();
+    assert!(!tokens.access_token.is_empty());
+}
@@ -74,6 +74,8 @@ async fn test_rate_limit_client_registration() {
             grant_types: None,
             response_types: None,
             scope: None,
+            token_endpoint_auth_method: None,
+            require_pkce: None,
         };
 
         let result = registration_manager
@@ -0,0 +1,229 @@
+// ABOUTME: Tests for RFC 7009 token revocation on the OAuth 2.0 authorization server
+// ABOUTME: Verifies refresh tokens revoke their access tokens, repeat and unknown tokens succeed, and audit
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{Duration, Utc};
+use pierre_mcp_server::{
+    auth::AuthManager,
+    database_plugins::{factory::Database, DatabaseProvider},
+    oauth2_server::{
+        client_registration::ClientRegistrationManager,
+        endpoints::OAuth2AuthorizationServer,
+        models::{
+            ClientRegistrationRequest, IntrospectionRequest, OAuth2RefreshToken, RevocationOutcome,
+            RevocationRequest, TokenRequest, TokenResponse,
+        },
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Client {
+    id: String,
+    secret: String,
+}
+
+async fn register_client(database: &Arc<Database>, name: &str) -> Client {
+    let registration = ClientRegistrationManager::new(database.clone())
+        .register_client(ClientRegistrationRequest {
+            redirect_uris: vec!["https://example.com/callback".to_owned()],
+            client_name: Some(name.to_owned()),
+            client_uri: None,
+            grant_types: None,
+            response_types: None,
+            scope: None,
+            token_endpoint_auth_method: None,
+            require_pkce: None,
+        })
+        .await
+        .unwrap();
+    Client {
+        id: registration.client_id,
+        secret: registration.client_secret,
+    }
+}
+
+/// Issue an access and refresh token pair through the refresh token grant
+async fn issue_tokens(
+    server: &OAuth2AuthorizationServer,
+    database: &Arc<Database>,
+    client: &Client,
+    user_id: Uuid,
+    tenant_id: &str,
+) -> TokenResponse {
+    let seed = format!("seed-{}", Uuid::new_v4());
+    database
+        .store_oauth2_refresh_token(&OAuth2RefreshToken {
+            token: seed.clone(),
+            client_id: client.id.clone(),
+            user_id,
+            tenant_id: tenant_id.to_owned(),
+            scope: Some("fitness:read".to_owned()),
+            expires_at: Utc::now() + Duration::days(30),
+            created_at: Utc::now(),
+            revoked: false,
+        })
+        .await
+        .unwrap();
+
+    server
+        .token(TokenRequest {
+            grant_type: "refresh_token".to_owned(),
+            code: None,
+            redirect_uri: None,
+            client_id: client.id.clone(),
+            client_secret: client.secret.clone(),
+            scope: None,
+            refresh_token: Some(seed),
+            code_verifier: None,
+        })
+        .await
+        .unwrap()
+}
+
+async fn revoke(
+    server: &OAuth2AuthorizationServer,
+    client: &Client,
+    token: &str,
+    token_type_hint: Option<&str>,
+) -> RevocationOutcome {
+    server
+        .revoke(
+            RevocationRequest {
+                token: token.to_owned(),
+                token_type_hint: token_type_hint.map(str::to_owned),
+                client_id: client.id.clone(),
+                client_secret: client.secret.clone(),
+            },
+            Some("127.0.0.1".to_owned()),
+        )
+        .await
+        .unwrap()
+}
+
+async fn is_active(server: &OAuth2AuthorizationServer, client: &Client, token: &str) -> bool {
+    server
+        .introspect(IntrospectionRequest {
+            token: token.to_owned(),
+            token_type_hint: None,
+            client_id: client.id.clone(),
+            client_secret: client.secret.clone(),
+        })
+        .await
+        .unwrap()
+        .active
+}
+
+async fn revocation_audit_events(database: &Database) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM audit_events WHERE event_type = 'TokenRevoked'")
+        .fetch_one(database.sqlite_pool().unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_revoking_refresh_token_revokes_derived_access_tokens() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0]
+        .id
+        .to_string();
+    let server = OAuth2AuthorizationServer::new(
+        database.clone(),
+        Arc::new(AuthManager::new(24)),
+        common::get_shared_test_jwks(),
+    );
+    let client = register_client(&database, "Revoking Client").await;
+
+    let tokens = issue_tokens(&server, &database, &client, user_id, &tenant_id).await;
+    let refresh_token = tokens.refresh_token.unwrap();
+    assert!(is_active(&server, &client, &tokens.access_token).await);
+    assert!(is_active(&server, &client, &refresh_token).await);
+
+    // Revoking a valid refresh token also revokes the access token issued with it
+    let outcome = revoke(&server, &client, &refresh_token, Some("refresh_token")).await;
+    assert_eq!(
+        outcome,
+        RevocationOutcome {
+            refresh_token_revoked: true,
+            access_tokens_revoked: 1,
+        }
+    );
+    assert!(!is_active(&server, &client, &refresh_token).await);
+    assert!(!is_active(&server, &client, &tokens.access_token).await);
+    assert_eq!(revocation_audit_events(&database).await, 1);
+
+    // Revoking an already-revoked token still succeeds without auditing a second revocation
+    let outcome = revoke(&server, &client, &refresh_token, None).await;
+    assert_eq!(outcome, RevocationOutcome::default());
+    assert_eq!(revocation_audit_events(&database).await, 1);
+
+    // Unknown tokens succeed per RFC 7009 Section 2.2
+    for hint in [Some("refresh_token"), Some("access_token"), None] {
+        let outcome = revoke(&server, &client, "never-issued-token", hint).await;
+        assert_eq!(outcome, RevocationOutcome::default());
+    }
+    assert_eq!(revocation_audit_events(&database).await, 1);
+}
+
+#[tokio::test]
+async fn test_revoking_access_token_and_other_clients_tokens() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0]
+        .id
+        .to_string();
+    let server = OAuth2AuthorizationServer::new(
+        database.clone(),
+        Arc::new(AuthManager::new(24)),
+        common::get_shared_test_jwks(),
+    );
+    let client = register_client(&database, "Token Owner").await;
+    let other_client = register_client(&database, "Other Client").await;
+
+    let tokens = issue_tokens(&server, &database, &client, user_id, &tenant_id).await;
+    let refresh_token = tokens.refresh_token.unwrap();
+
+    // Another client's revocation request succeeds but leaves the tokens untouched
+    for token in [tokens.access_token.as_str(), refresh_token.as_str()] {
+        let outcome = revoke(&server, &other_client, token, None).await;
+        assert_eq!(outcome, RevocationOutcome::default());
+        assert!(is_active(&server, &client, token).await);
+    }
+
+    // Revoking an access token revokes only that token
+    let outcome = revoke(&server, &client, &tokens.access_token, Some("access_token")).await;
+    assert_eq!(
+        outcome,
+        RevocationOutcome {
+            refresh_token_revoked: false,
+            access_tokens_revoked: 1,
+        }
+    );
+    assert!(!is_active(&server, &client, &tokens.access_token).await);
+    assert!(is_active(&server, &client, &refresh_token).await);
+    assert_eq!(revocation_audit_events(&database).await, 1);
+
+    // Invalid client credentials are rejected before any lookup
+    let error = server
+        .revoke(
+            RevocationRequest {
+                token: refresh_token.clone(),
+                token_type_hint: None,
+                client_id: client.id.clone(),
+                client_secret: "wrong-secret".to_owned(),
+            },
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.error, "invalid_client");
+    assert!(is_active(&server, &client, &refresh_token).await);
+}
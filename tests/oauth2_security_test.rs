@@ -53,6 +53,8 @@ async fn test_redirect_uri_https_enforcement() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -68,6 +70,8 @@ async fn test_redirect_uri_https_enforcement() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -88,6 +92,8 @@ async fn test_redirect_uri_https_enforcement() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -103,6 +109,8 @@ async fn test_redirect_uri_https_enforcement() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -145,6 +153,8 @@ async fn test_redirect_uri_fragment_rejection() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -192,6 +202,8 @@ async fn test_redirect_uri_wildcard_rejection() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -239,6 +251,8 @@ async fn test_redirect_uri_oob_urn() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager.register_client(oob_registration).await;
@@ -278,6 +292,8 @@ async fn test_argon2id_client_secret_hashing() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let registration_response = registration_manager
@@ -344,6 +360,8 @@ async fn test_client_secret_validation() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let registration_response = registration_manager
@@ -408,6 +426,8 @@ async fn test_empty_redirect_uri_rejection() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -454,6 +474,8 @@ async fn test_malformed_uri_rejection() {
         grant_types: None,
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -500,6 +522,8 @@ async fn test_unsupported_grant_type_rejection() {
         grant_types: Some(vec!["implicit".to_owned()]),
         response_types: None,
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
@@ -546,6 +570,8 @@ async fn test_unsupported_response_type_rejection() {
         grant_types: None,
         response_types: Some(vec!["token".to_owned()]),
         scope: None,
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let result = registration_manager
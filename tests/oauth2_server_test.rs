@@ -315,6 +315,7 @@ fn test_client_registration_response() {
         client_name: Some("Test App".to_owned()),
         client_uri: None,
         scope: Some("read".to_owned()),
+        require_pkce: true,
     };
 
     let json = serde_json::to_string(&response).unwrap();
@@ -340,6 +341,7 @@ fn test_oauth2_client_creation() {
         scope: Some("read write".to_owned()),
         created_at: Utc::now(),
         expires_at: None,
+        require_pkce: true,
     };
 
     assert_eq!(client.client_id, "public_client_id");
@@ -361,6 +363,7 @@ fn test_oauth2_client_clone() {
         scope: None,
         created_at: Utc::now(),
         expires_at: Some(Utc::now() + Duration::days(365)),
+        require_pkce: true,
     };
 
     let cloned = client.clone();
@@ -696,6 +699,7 @@ fn test_multiple_redirect_uris() {
         scope: None,
         created_at: Utc::now(),
         expires_at: None,
+        require_pkce: true,
     };
 
     assert_eq!(client.redirect_uris.len(), 3);
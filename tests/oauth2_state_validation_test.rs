@@ -37,6 +37,8 @@ async fn create_test_client(
         grant_types: Some(vec!["authorization_code".to_owned()]),
         response_types: Some(vec!["code".to_owned()]),
         scope: Some("read write".to_owned()),
+        token_endpoint_auth_method: None,
+        require_pkce: None,
     };
 
     let response = registration_manager
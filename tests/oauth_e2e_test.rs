@@ -768,6 +768,7 @@ async fn test_oauth_state_csrf_protection() {
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -276,6 +276,7 @@ async fn test_oauth_authorization_url_generation() {
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -291,6 +292,7 @@ async fn test_oauth_authorization_url_generation() {
         provider: "fitbit".to_owned(),
         client_id: "test_fitbit_client_id".to_owned(),
         client_secret: "test_fitbit_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/fitbit".to_owned(),
         scopes: vec!["activity".to_owned(), "profile".to_owned()],
         rate_limit_per_day: 15000,
@@ -1288,6 +1290,7 @@ async fn test_oauth_urls_contain_required_parameters() {
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -1302,6 +1305,7 @@ async fn test_oauth_urls_contain_required_parameters() {
         provider: "fitbit".to_owned(),
         client_id: "test_fitbit_client_id".to_owned(),
         client_secret: "test_fitbit_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/fitbit".to_owned(),
         scopes: vec!["activity".to_owned(), "profile".to_owned()],
         rate_limit_per_day: 15000,
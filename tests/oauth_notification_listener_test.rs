@@ -0,0 +1,152 @@
+// ABOUTME: PostgreSQL integration tests for LISTEN/NOTIFY push delivery of OAuth notifications
+// ABOUTME: Verifies stored notifications reach the broadcast channel once, including after reconnects
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+#![cfg(feature = "postgresql")]
+
+use std::time::Duration;
+
+use chrono::Utc;
+use pierre_mcp_server::{
+    database_plugins::{factory::Database, DatabaseProvider},
+    mcp::schema::OAuthCompletedNotification,
+    models::{User, UserStatus, UserTier},
+    permissions::UserRole,
+};
+use tokio::sync::broadcast;
+use tokio::time::timeout;
+use uuid::Uuid;
+
+mod common;
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+async fn create_pg_test_user(db: &Database) -> Uuid {
+    let user_id = Uuid::new_v4();
+    let user = User {
+        id: user_id,
+        email: format!("test-{user_id}@example.com"),
+        display_name: Some("Test User".to_owned()),
+        password_hash: "test_hash".to_owned(),
+        tier: UserTier::Starter,
+        is_active: true,
+        user_status: UserStatus::Active,
+        is_admin: false,
+        role: UserRole::User,
+        approved_by: None,
+        approved_at: Some(Utc::now()),
+        created_at: Utc::now(),
+        last_active: Utc::now(),
+        strava_token: None,
+        fitbit_token: None,
+        firebase_uid: None,
+        auth_provider: String::new(),
+    };
+
+    db.create_user(&user).await.expect("Failed to create user");
+    user_id
+}
+
+async fn next_notification(
+    receiver: &mut broadcast::Receiver<OAuthCompletedNotification>,
+) -> OAuthCompletedNotification {
+    timeout(DELIVERY_TIMEOUT, receiver.recv())
+        .await
+        .expect("OAuth notification was not delivered in time")
+        .expect("Broadcast channel closed")
+}
+
+#[tokio::test]
+async fn test_pg_stored_notification_is_pushed_once() {
+    let isolated_db = match common::IsolatedPostgresDb::new().await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("Skipping test: PostgreSQL not available: {e}");
+            return;
+        }
+    };
+    let db = isolated_db
+        .get_database()
+        .await
+        .expect("Failed to get database");
+    let Database::PostgreSQL(pg) = &db else {
+        panic!("Expected a PostgreSQL database");
+    };
+
+    let (sender, mut receiver) = broadcast::channel(16);
+    let listener = pg.spawn_oauth_notification_listener(sender);
+
+    let user_id = create_pg_test_user(&db).await;
+    db.store_oauth_notification(user_id, "strava", true, "Strava connected", None)
+        .await
+        .expect("Failed to store notification");
+
+    let notification = next_notification(&mut receiver).await;
+    assert_eq!(notification.params.provider, "strava");
+    assert!(notification.params.success);
+    assert_eq!(notification.params.message, "Strava connected");
+    assert_eq!(notification.params.user_id, Some(user_id.to_string()));
+
+    // Startup replay and NOTIFY may both see the row; it must only be delivered once
+    assert!(
+        timeout(Duration::from_millis(500), receiver.recv())
+            .await
+            .is_err(),
+        "notification was delivered more than once"
+    );
+
+    listener.abort();
+}
+
+#[tokio::test]
+async fn test_pg_listener_reconnects_and_replays_missed_notifications() {
+    let isolated_db = match common::IsolatedPostgresDb::new().await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("Skipping test: PostgreSQL not available: {e}");
+            return;
+        }
+    };
+    let db = isolated_db
+        .get_database()
+        .await
+        .expect("Failed to get database");
+    let Database::PostgreSQL(pg) = &db else {
+        panic!("Expected a PostgreSQL database");
+    };
+
+    let (sender, mut receiver) = broadcast::channel(16);
+    let listener = pg.spawn_oauth_notification_listener(sender);
+    let user_id = create_pg_test_user(&db).await;
+
+    db.store_oauth_notification(user_id, "strava", true, "first", None)
+        .await
+        .unwrap();
+    assert_eq!(
+        next_notification(&mut receiver).await.params.message,
+        "first"
+    );
+
+    // Drop the listener's connection, then store while it is disconnected
+    let pool = sqlx::PgPool::connect(&isolated_db.url).await.unwrap();
+    sqlx::query(
+        "SELECT pg_terminate_backend(pid) FROM pg_stat_activity \
+         WHERE datname = current_database() AND query LIKE 'LISTEN%'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    db.store_oauth_notification(user_id, "garmin", true, "second", None)
+        .await
+        .unwrap();
+
+    let notification = next_notification(&mut receiver).await;
+    assert_eq!(notification.params.provider, "garmin");
+    assert_eq!(notification.params.message, "second");
+
+    listener.abort();
+}
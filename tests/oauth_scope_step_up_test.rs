@@ -0,0 +1,213 @@
+// ABOUTME: Tests for provider OAuth scope step-up when a tool needs scopes the user has not granted
+// ABOUTME: Verifies the step-up URL requests only the missing scope and completion upgrades the token
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use pierre_mcp_server::config::ToolScopeRequirements;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::{TenantId, UserOAuthToken};
+use pierre_mcp_server::oauth2_client::OAuth2Token;
+use pierre_mcp_server::protocols::universal::{UniversalRequest, UniversalToolExecutor};
+use pierre_mcp_server::services::oauth_step_up::{
+    check_tool_scopes, merge_granted_scopes, missing_scopes, parse_scopes,
+};
+use pierre_mcp_server::tenant::CredentialConfig;
+use serde_json::json;
+use url::Url;
+
+mod common;
+
+const INITIAL_SCOPES: &str = "read,activity:read_all";
+
+fn provider_token(access_token: &str, scope: Option<&str>) -> OAuth2Token {
+    OAuth2Token {
+        access_token: access_token.to_owned(),
+        token_type: "Bearer".to_owned(),
+        expires_at: Some(Utc::now() + Duration::hours(6)),
+        refresh_token: Some(format!("{access_token}-refresh")),
+        scope: scope.map(str::to_owned),
+    }
+}
+
+#[test]
+fn test_scope_requirements_parse_from_env_format() {
+    let requirements = ToolScopeRequirements::parse(
+        "get_athlete/strava=profile:read_all; analyze_sleep_quality/Fitbit=sleep,heartrate;broken",
+    );
+
+    assert_eq!(
+        requirements.required_scopes("get_athlete", "strava"),
+        ["profile:read_all"]
+    );
+    assert_eq!(
+        requirements.required_scopes("analyze_sleep_quality", "fitbit"),
+        ["sleep", "heartrate"]
+    );
+    assert!(requirements
+        .required_scopes("get_activities", "strava")
+        .is_empty());
+
+    assert_eq!(
+        missing_scopes(
+            "activity sleep",
+            &["sleep".to_owned(), "heartrate".to_owned()]
+        ),
+        ["heartrate"]
+    );
+}
+
+#[tokio::test]
+async fn test_missing_scope_returns_step_up_url_that_upgrades_token() {
+    let base = common::create_test_server_resources().await.unwrap();
+    let mut resources = (*base).clone();
+    resources.tool_scope_requirements = Arc::new(ToolScopeRequirements::default().require(
+        "get_activities",
+        "strava",
+        &["profile:read_all"],
+    ));
+    let resources = Arc::new(resources);
+    let database = resources.database.clone();
+
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id: TenantId = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+
+    resources
+        .tenant_oauth_client
+        .oauth_manager
+        .lock()
+        .await
+        .store_credentials(
+            tenant_id,
+            "strava",
+            CredentialConfig {
+                client_id: "step-up-client".to_owned(),
+                client_secret: "step-up-secret".to_owned(),
+                redirect_uri: "http://localhost:8081/api/oauth/callback/strava".to_owned(),
+                scopes: vec!["activity:read_all".to_owned()],
+                configured_by: user_id,
+            },
+        )
+        .unwrap();
+
+    database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            "strava".to_owned(),
+            "initial-token".to_owned(),
+            Some("initial-refresh".to_owned()),
+            Some(Utc::now() + Duration::hours(6)),
+            Some(INITIAL_SCOPES.to_owned()),
+        ))
+        .await
+        .unwrap();
+
+    // The tool needs a scope the user never granted
+    let executor = UniversalToolExecutor::new(resources.clone());
+    let response = executor
+        .execute_tool(UniversalRequest {
+            tool_name: "get_activities".to_owned(),
+            parameters: json!({ "provider": "strava", "limit": 5 }),
+            user_id: user_id.to_string(),
+            protocol: "test".to_owned(),
+            tenant_id: Some(tenant_id.to_string()),
+            progress_token: None,
+            cancellation_token: None,
+            progress_reporter: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(!response.success);
+    assert!(response.error.unwrap().contains("profile:read_all"));
+    let step_up = &response.result.unwrap()["step_up"];
+    assert_eq!(step_up["provider"], "strava");
+    assert_eq!(step_up["missing_scopes"], json!(["profile:read_all"]));
+
+    // The URL requests only the additional scope
+    let url = Url::parse(step_up["authorization_url"].as_str().unwrap()).unwrap();
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+    assert_eq!(query("scope").as_deref(), Some("profile:read_all"));
+    let state = step_up["state"].as_str().unwrap();
+    assert_eq!(query("state").as_deref(), Some(state));
+
+    // Completing the flow: the callback consumes the state and stores the upgraded token
+    let client_state = database
+        .consume_oauth_client_state(state, "strava", Utc::now())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(client_state.scope.as_deref(), Some("profile:read_all"));
+
+    let mut upgraded = provider_token("upgraded-token", None);
+    merge_granted_scopes(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        "strava",
+        client_state.scope.as_deref(),
+        &mut upgraded,
+    )
+    .await
+    .unwrap();
+    database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            "strava".to_owned(),
+            upgraded.access_token.clone(),
+            upgraded.refresh_token.clone(),
+            upgraded.expires_at,
+            upgraded.scope.clone(),
+        ))
+        .await
+        .unwrap();
+
+    let stored = database
+        .get_user_oauth_token(user_id, tenant_id, "strava")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "upgraded-token");
+    assert_eq!(
+        parse_scopes(stored.scope.as_deref().unwrap()),
+        parse_scopes("read,activity:read_all,profile:read_all")
+    );
+
+    // The upgraded token satisfies the tool, so no further step-up is issued
+    assert!(check_tool_scopes(
+        &resources,
+        "get_activities",
+        "strava",
+        user_id,
+        Some(&tenant_id.to_string())
+    )
+    .await
+    .unwrap()
+    .is_none());
+
+    // A full reconnect that re-requests every stored scope replaces the grant as issued
+    let mut reconnect = provider_token("reconnect-token", Some("read,activity:read_all"));
+    merge_granted_scopes(
+        database.as_ref(),
+        user_id,
+        tenant_id,
+        "strava",
+        Some("read,activity:read_all,profile:read_all"),
+        &mut reconnect,
+    )
+    .await
+    .unwrap();
+    assert_eq!(reconnect.scope.as_deref(), Some("read,activity:read_all"));
+}
@@ -0,0 +1,174 @@
+// ABOUTME: Tests for inspecting and validating stored provider OAuth tokens behind pierre-cli token oauth
+// ABOUTME: Seeds encrypted tokens in a test database and validates them with a mocked provider check
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use pierre_mcp_server::{
+    database_plugins::{factory::Database, DatabaseProvider},
+    errors::{AppError, AppResult, ErrorCode},
+    models::UserOAuthToken,
+    oauth2_client::token_inspection::{
+        list_user_oauth_tokens, validate_user_oauth_token, ProviderTokenCheck,
+        ProviderTokenResponse, TokenValidity,
+    },
+};
+use uuid::Uuid;
+
+const ACCESS_TOKEN: &str = "strava-access-secret";
+const REFRESH_TOKEN: &str = "strava-refresh-secret";
+
+/// Provider check that answers with a fixed response and records the token it was given
+struct MockCheck {
+    response: AppResult<ProviderTokenResponse>,
+    seen_tokens: Mutex<Vec<String>>,
+}
+
+impl MockCheck {
+    fn new(response: AppResult<ProviderTokenResponse>) -> Self {
+        Self {
+            response,
+            seen_tokens: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl ProviderTokenCheck for MockCheck {
+    async fn check(&self, provider: &str, access_token: &str) -> AppResult<ProviderTokenResponse> {
+        assert_eq!(provider, "strava");
+        self.seen_tokens
+            .lock()
+            .unwrap()
+            .push(access_token.to_owned());
+        self.response.clone()
+    }
+}
+
+/// Seed a Strava token for the test user, expiring `expires_in` from now
+async fn seed_token(database: &Database, expires_in: Duration) -> Uuid {
+    let (user_id, _) = common::create_test_user(database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+    let token = UserOAuthToken::new(
+        user_id,
+        tenant_id.to_string(),
+        "strava".to_owned(),
+        ACCESS_TOKEN.to_owned(),
+        Some(REFRESH_TOKEN.to_owned()),
+        Some(Utc::now() + expires_in),
+        Some("read,activity:read_all".to_owned()),
+    );
+    database.upsert_user_oauth_token(&token).await.unwrap();
+    user_id
+}
+
+#[tokio::test]
+async fn test_list_summarizes_tokens_without_secrets() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, Duration::hours(6)).await;
+
+    let tokens = list_user_oauth_tokens(&database, "test@example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    let token = &tokens[0];
+    assert_eq!(token.provider, "strava");
+    assert_eq!(token.scopes, ["read", "activity:read_all"]);
+    assert!(token.has_refresh_token);
+    assert!(!token.is_expired());
+
+    let rendered = format!("{token:?} {}", serde_json::to_string(token).unwrap());
+    assert!(!rendered.contains(ACCESS_TOKEN));
+    assert!(!rendered.contains(REFRESH_TOKEN));
+}
+
+#[tokio::test]
+async fn test_list_unknown_user_is_not_found() {
+    let database = common::create_test_database().await.unwrap();
+
+    let error = list_user_oauth_tokens(&database, "nobody@example.com")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ResourceNotFound);
+}
+
+#[tokio::test]
+async fn test_validate_passes_decrypted_token_to_provider() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, Duration::hours(6)).await;
+    let check = MockCheck::new(Ok(ProviderTokenResponse::Accepted));
+
+    let reports = validate_user_oauth_token(&database, &check, "test@example.com", "strava")
+        .await
+        .unwrap();
+
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].validity, TokenValidity::Valid);
+    assert_eq!(*check.seen_tokens.lock().unwrap(), [ACCESS_TOKEN]);
+}
+
+#[tokio::test]
+async fn test_rejected_token_past_expiry_is_expired() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, -Duration::hours(1)).await;
+    let check = MockCheck::new(Ok(ProviderTokenResponse::Rejected));
+
+    let reports = validate_user_oauth_token(&database, &check, "test@example.com", "strava")
+        .await
+        .unwrap();
+
+    assert_eq!(reports[0].validity, TokenValidity::Expired);
+    assert!(reports[0].token.is_expired());
+}
+
+#[tokio::test]
+async fn test_rejected_token_before_expiry_is_revoked() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, Duration::hours(6)).await;
+    let check = MockCheck::new(Ok(ProviderTokenResponse::Rejected));
+
+    let reports = validate_user_oauth_token(&database, &check, "test@example.com", "strava")
+        .await
+        .unwrap();
+
+    assert_eq!(reports[0].validity, TokenValidity::Revoked);
+}
+
+#[tokio::test]
+async fn test_unreachable_provider_is_an_error_not_a_verdict() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, Duration::hours(6)).await;
+    let check = MockCheck::new(Err(AppError::external_service(
+        "strava",
+        "Network error: connection refused",
+    )));
+
+    let error = validate_user_oauth_token(&database, &check, "test@example.com", "strava")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::ExternalServiceError);
+}
+
+#[tokio::test]
+async fn test_validate_without_token_for_provider_is_not_found() {
+    let database = common::create_test_database().await.unwrap();
+    seed_token(&database, Duration::hours(6)).await;
+    let check = MockCheck::new(Ok(ProviderTokenResponse::Accepted));
+
+    let error = validate_user_oauth_token(&database, &check, "test@example.com", "fitbit")
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code, ErrorCode::ResourceNotFound);
+    assert!(check.seen_tokens.lock().unwrap().is_empty());
+}
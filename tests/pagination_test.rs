@@ -1,5 +1,5 @@
 // ABOUTME: Unit tests for cursor-based pagination module
-// ABOUTME: Tests cursor encoding, decoding, and pagination parameter handling
+// ABOUTME: Tests cursor encoding, decoding, parameter handling, and in-memory cursor pages
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
@@ -7,7 +7,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
 
-use chrono::Utc;
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use pierre_mcp_server::pagination::{Cursor, CursorPage, PaginationDirection, PaginationParams};
 
 #[test]
@@ -70,3 +70,135 @@ fn test_pagination_params_backward() {
     assert_eq!(params.direction, PaginationDirection::Backward);
     assert!(params.cursor.is_none());
 }
+
+#[derive(Debug, Clone, PartialEq)]
+struct Goal {
+    id: String,
+    created_at: DateTime<Utc>,
+}
+
+/// Five goals sorted newest first, one hour apart
+fn sorted_goals() -> Vec<Goal> {
+    let newest = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    (0..5)
+        .map(|i| Goal {
+            id: format!("goal-{i}"),
+            created_at: newest - Duration::hours(i),
+        })
+        .collect()
+}
+
+fn goal_key(goal: &Goal) -> (DateTime<Utc>, String) {
+    (goal.created_at, goal.id.clone())
+}
+
+fn page_ids(page: &CursorPage<Goal>) -> Vec<&str> {
+    page.items.iter().map(|goal| goal.id.as_str()).collect()
+}
+
+#[test]
+fn test_from_sorted_slice_first_page() {
+    let goals = sorted_goals();
+    let params = PaginationParams::forward(None, 2);
+
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert_eq!(page_ids(&page), vec!["goal-0", "goal-1"]);
+    assert_eq!(page.count, 2);
+    assert!(page.has_more);
+    assert_eq!(
+        page.next_cursor,
+        Some(Cursor::new(goals[1].created_at, "goal-1"))
+    );
+    assert!(page.prev_cursor.is_none());
+}
+
+#[test]
+fn test_from_sorted_slice_middle_page() {
+    let goals = sorted_goals();
+    let first =
+        CursorPage::from_sorted_slice(&goals, &PaginationParams::forward(None, 2), goal_key)
+            .unwrap();
+
+    let params = PaginationParams::forward(first.next_cursor, 2);
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert_eq!(page_ids(&page), vec!["goal-2", "goal-3"]);
+    assert!(page.has_more);
+    assert_eq!(
+        page.next_cursor,
+        Some(Cursor::new(goals[3].created_at, "goal-3"))
+    );
+    assert_eq!(
+        page.prev_cursor,
+        Some(Cursor::new(goals[2].created_at, "goal-2"))
+    );
+}
+
+#[test]
+fn test_from_sorted_slice_last_page() {
+    let goals = sorted_goals();
+    let cursor = Cursor::new(goals[3].created_at, "goal-3");
+    let params = PaginationParams::forward(Some(cursor), 2);
+
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert_eq!(page_ids(&page), vec!["goal-4"]);
+    assert!(!page.has_more);
+    assert!(page.next_cursor.is_none());
+    assert!(page.prev_cursor.is_some());
+}
+
+#[test]
+fn test_from_sorted_slice_empty_input() {
+    let goals: Vec<Goal> = Vec::new();
+    let params = PaginationParams::forward(None, 10);
+
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert!(page.items.is_empty());
+    assert_eq!(page.count, 0);
+    assert!(!page.has_more);
+    assert!(page.next_cursor.is_none());
+    assert!(page.prev_cursor.is_none());
+}
+
+#[test]
+fn test_from_sorted_slice_cursor_survives_removed_item() {
+    let mut goals = sorted_goals();
+    let cursor = Cursor::new(goals[1].created_at, "goal-1");
+    goals.remove(1);
+
+    let params = PaginationParams::forward(Some(cursor), 2);
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert_eq!(page_ids(&page), vec!["goal-2", "goal-3"]);
+}
+
+#[test]
+fn test_from_sorted_slice_backward_page() {
+    let goals = sorted_goals();
+    let cursor = Cursor::new(goals[3].created_at, "goal-3");
+    let params = PaginationParams::backward(Some(cursor), 2);
+
+    let page = CursorPage::from_sorted_slice(&goals, &params, goal_key).unwrap();
+
+    assert_eq!(page_ids(&page), vec!["goal-1", "goal-2"]);
+    assert!(page.has_more);
+    assert_eq!(
+        page.next_cursor,
+        Some(Cursor::new(goals[1].created_at, "goal-1"))
+    );
+    assert_eq!(
+        page.prev_cursor,
+        Some(Cursor::new(goals[2].created_at, "goal-2"))
+    );
+}
+
+#[test]
+fn test_from_sorted_slice_rejects_invalid_cursor() {
+    let goals = sorted_goals();
+    let params = PaginationParams::forward(Some(Cursor::from_string("not a cursor".to_owned())), 2);
+
+    assert!(CursorPage::from_sorted_slice(&goals, &params, goal_key).is_err());
+}
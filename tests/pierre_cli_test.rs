@@ -254,3 +254,38 @@ fn test_cli_database_url_option() {
         "Help should work with database-url option"
     );
 }
+
+#[test]
+fn test_cli_token_oauth_help() {
+    let (exit_code, stdout, _stderr) = run_cli(&["token", "oauth", "--help"]);
+
+    assert_eq!(exit_code, 0, "Token oauth help should exit with 0");
+    assert!(
+        stdout.contains("list"),
+        "Token oauth help should mention 'list' command"
+    );
+    assert!(
+        stdout.contains("validate"),
+        "Token oauth help should mention 'validate' command"
+    );
+}
+
+#[test]
+fn test_cli_token_oauth_validate_missing_provider() {
+    let (exit_code, _stdout, stderr) = run_cli(&[
+        "token",
+        "oauth",
+        "validate",
+        "--user",
+        "athlete@example.com",
+    ]);
+
+    assert_ne!(
+        exit_code, 0,
+        "Token oauth validate without provider should exit with non-zero"
+    );
+    assert!(
+        stderr.contains("--provider"),
+        "Should indicate missing --provider"
+    );
+}
@@ -0,0 +1,167 @@
+// ABOUTME: Tests for reconciling athlete profile fields across multiple providers
+// ABOUTME: Validates precedence and recency rules, conflict flagging, and tolerances
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::models::{SportType, UserPhysiologicalProfile};
+use pierre_mcp_server::providers::profile_aggregation::{
+    ProfileAggregationConfig, ProfileField, ProviderProfile, ReconciliationRule,
+    UnifiedAthleteProfile,
+};
+use uuid::Uuid;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+}
+
+/// Garmin reports 72 kg a week ago; Strava reports 75 kg today
+fn conflicting_weights() -> Vec<ProviderProfile> {
+    vec![
+        ProviderProfile {
+            weight_kg: Some(72.0),
+            max_heart_rate: Some(188),
+            ..ProviderProfile::new("garmin", now() - Duration::days(7))
+        },
+        ProviderProfile {
+            weight_kg: Some(75.0),
+            ftp_watts: Some(250),
+            max_heart_rate: Some(189),
+            ..ProviderProfile::new("strava", now())
+        },
+    ]
+}
+
+fn config(precedence: &[&str], rule: ReconciliationRule) -> ProfileAggregationConfig {
+    ProfileAggregationConfig {
+        provider_precedence: precedence.iter().map(|p| (*p).to_owned()).collect(),
+        rule,
+        ..ProfileAggregationConfig::default()
+    }
+}
+
+#[test]
+fn test_configured_precedence_wins_and_conflict_is_flagged() {
+    let profile = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &config(&["garmin", "strava"], ReconciliationRule::Precedence),
+    );
+
+    let weight = profile.weight_kg.as_ref().unwrap();
+    assert!((weight.value - 72.0).abs() < f64::EPSILON);
+    assert_eq!(weight.provider, "garmin");
+
+    assert!(profile.has_conflicts());
+    let conflict = profile.conflict(ProfileField::Weight).unwrap();
+    assert_eq!(conflict.resolved_provider, "garmin");
+    assert_eq!(conflict.values.len(), 2);
+    assert_eq!(conflict.values[0].provider, "garmin");
+    assert_eq!(conflict.values[1].provider, "strava");
+}
+
+#[test]
+fn test_reversed_precedence_picks_other_provider() {
+    let profile = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &config(&["strava", "garmin"], ReconciliationRule::Precedence),
+    );
+
+    assert_eq!(profile.weight_kg.unwrap().provider, "strava");
+    assert_eq!(
+        profile
+            .conflict(ProfileField::Weight)
+            .unwrap()
+            .resolved_provider,
+        "strava"
+    );
+}
+
+#[test]
+fn test_most_recent_rule_ignores_precedence() {
+    let profile = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &config(&["garmin", "strava"], ReconciliationRule::MostRecent),
+    );
+
+    let weight = profile.weight_kg.unwrap();
+    assert!((weight.value - 75.0).abs() < f64::EPSILON);
+    assert_eq!(weight.provider, "strava");
+}
+
+#[test]
+fn test_values_within_tolerance_are_not_conflicts() {
+    let profile = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &config(&["garmin", "strava"], ReconciliationRule::Precedence),
+    );
+
+    // 188 vs 189 bpm is within the default 2 bpm tolerance
+    assert_eq!(profile.max_heart_rate.as_ref().unwrap().value, 188);
+    assert!(profile.conflict(ProfileField::MaxHeartRate).is_none());
+}
+
+#[test]
+fn test_single_source_field_is_used_without_conflict() {
+    let profile = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &ProfileAggregationConfig::default(),
+    );
+
+    let ftp = profile.ftp_watts.unwrap();
+    assert_eq!(ftp.value, 250);
+    assert_eq!(ftp.provider, "strava");
+    assert!(profile.conflict(ProfileField::Ftp).is_none());
+}
+
+#[test]
+fn test_unlisted_providers_rank_last_and_fall_back_to_recency() {
+    let profiles = vec![
+        ProviderProfile {
+            weight_kg: Some(70.0),
+            ..ProviderProfile::new("polar", now() - Duration::days(1))
+        },
+        ProviderProfile {
+            weight_kg: Some(71.5),
+            ..ProviderProfile::new("suunto", now())
+        },
+    ];
+    let profile = UnifiedAthleteProfile::reconcile(
+        &profiles,
+        &config(&["garmin"], ReconciliationRule::Precedence),
+    );
+
+    assert_eq!(profile.weight_kg.unwrap().provider, "suunto");
+    assert!(profile.conflict(ProfileField::Weight).is_some());
+}
+
+#[test]
+fn test_unified_profile_applies_to_physiological_profile() {
+    let unified = UnifiedAthleteProfile::reconcile(
+        &conflicting_weights(),
+        &config(&["garmin", "strava"], ReconciliationRule::Precedence),
+    );
+    let mut profile = UserPhysiologicalProfile::new(Uuid::new_v4(), SportType::Run);
+    unified.apply_to(&mut profile);
+
+    assert_eq!(profile.weight, Some(72.0));
+    assert_eq!(profile.max_hr, Some(188));
+}
+
+#[test]
+fn test_config_deserializes_with_defaults() {
+    let config: ProfileAggregationConfig =
+        serde_json::from_str(r#"{"provider_precedence": ["whoop"], "rule": "most_recent"}"#)
+            .unwrap();
+
+    assert_eq!(config.provider_precedence, vec!["whoop".to_owned()]);
+    assert_eq!(config.rule, ReconciliationRule::MostRecent);
+    assert!(
+        (config.weight_tolerance_kg - ProfileAggregationConfig::default().weight_tolerance_kg)
+            .abs()
+            < f64::EPSILON
+    );
+}
@@ -0,0 +1,154 @@
+// ABOUTME: Tests for the Prometheus metrics registry and the /metrics scrape endpoint
+// ABOUTME: Exercises a tool, a circuit breaker, and rate limiting, then asserts the exposed series
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use pierre_mcp_server::metrics::{
+    MetricsRegistry, CIRCUIT_BREAKER_TRANSITIONS_TOTAL, PROVIDER_API_CALLS_TOTAL,
+    PROVIDER_API_DURATION_SECONDS, RATE_LIMIT_REJECTIONS_TOTAL, TOOL_REQUESTS_TOTAL,
+    TOOL_REQUEST_DURATION_SECONDS,
+};
+use pierre_mcp_server::models::TenantId;
+use pierre_mcp_server::providers::circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitState,
+};
+use pierre_mcp_server::routes::MetricsRoutes;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext, ToolRegistry};
+use serde_json::json;
+use tower::ServiceExt;
+
+mod common;
+
+async fn scrape() -> Result<(StatusCode, String, String)> {
+    let response = MetricsRoutes::routes()
+        .oneshot(Request::builder().uri("/metrics").body(Body::empty())?)
+        .await?;
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|value| value.to_str().unwrap().to_owned())
+        .unwrap_or_default();
+    let body = to_bytes(response.into_body(), usize::MAX).await?;
+    Ok((status, content_type, String::from_utf8(body.to_vec())?))
+}
+
+#[tokio::test]
+async fn test_scrape_exposes_tool_requests_after_execution() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let (user_id, _user) = common::create_test_user(&resources.database).await?;
+    let tenant_id = TenantId::new();
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+
+    let mut registry = ToolRegistry::new();
+    registry.register_builtin_tools();
+    let _ = registry.execute("list_coaches", json!({}), &context).await;
+
+    let tenant = tenant_id.to_string();
+    assert_eq!(
+        MetricsRegistry::global().counter_value(
+            TOOL_REQUESTS_TOTAL,
+            &[("tool", "list_coaches"), ("tenant", &tenant)]
+        ),
+        1
+    );
+
+    let (status, content_type, body) = scrape().await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.starts_with("text/plain; version=0.0.4"));
+    for name in [
+        TOOL_REQUESTS_TOTAL,
+        TOOL_REQUEST_DURATION_SECONDS,
+        PROVIDER_API_CALLS_TOTAL,
+        PROVIDER_API_DURATION_SECONDS,
+        RATE_LIMIT_REJECTIONS_TOTAL,
+        CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+    ] {
+        assert!(body.contains(&format!("# TYPE {name} ")), "missing {name}");
+    }
+    assert!(body.contains(&format!(
+        "{TOOL_REQUESTS_TOTAL}{{tool=\"list_coaches\",tenant=\"{tenant}\","
+    )));
+    assert!(body.contains(&format!(
+        "{TOOL_REQUEST_DURATION_SECONDS}_bucket{{tool=\"list_coaches\",le=\"+Inf\"}}"
+    )));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_transitions_are_counted() -> Result<()> {
+    let provider = "metrics-test-provider";
+    let breaker = CircuitBreaker::with_config(
+        provider,
+        CircuitBreakerConfig::new(1, Duration::from_millis(0), 1),
+    );
+
+    breaker.record_failure();
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert!(breaker.is_allowed());
+    breaker.record_success();
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    let registry = MetricsRegistry::global();
+    for state in ["open", "half_open", "closed"] {
+        assert_eq!(
+            registry.counter_value(
+                CIRCUIT_BREAKER_TRANSITIONS_TOTAL,
+                &[("provider", provider), ("state", state)]
+            ),
+            1,
+            "transitions to {state}"
+        );
+    }
+
+    let (_, _, body) = scrape().await?;
+    assert!(body.contains(&format!(
+        "{CIRCUIT_BREAKER_TRANSITIONS_TOTAL}{{provider=\"{provider}\",state=\"open\"}} 1"
+    )));
+    Ok(())
+}
+
+#[test]
+fn test_render_histograms_and_escaped_labels() {
+    let registry = MetricsRegistry::new();
+    registry.record_provider_call("strava", true, Duration::from_millis(40));
+    registry.record_provider_call("strava", false, Duration::from_secs(3));
+    registry.record_rate_limit_rejection("api_key");
+    registry.record_circuit_transition("quote\"provider", "open");
+
+    let body = registry.render();
+
+    assert!(
+        body.contains("pierre_provider_api_calls_total{provider=\"strava\",outcome=\"success\"} 1")
+    );
+    assert!(
+        body.contains("pierre_provider_api_calls_total{provider=\"strava\",outcome=\"error\"} 1")
+    );
+    // Buckets are cumulative: only the 40 ms call fits under 50 ms
+    assert!(body.contains(
+        "pierre_provider_api_duration_seconds_bucket{provider=\"strava\",le=\"0.05\"} 1"
+    ));
+    assert!(body
+        .contains("pierre_provider_api_duration_seconds_bucket{provider=\"strava\",le=\"5\"} 2"));
+    assert!(body.contains(
+        "pierre_provider_api_duration_seconds_bucket{provider=\"strava\",le=\"+Inf\"} 2"
+    ));
+    assert!(body.contains("pierre_provider_api_duration_seconds_count{provider=\"strava\"} 2"));
+    assert!(body.contains("pierre_rate_limit_rejections_total{auth_method=\"api_key\"} 1"));
+    assert!(body.contains("provider=\"quote\\\"provider\""));
+    assert_eq!(
+        registry.counter_value(PROVIDER_API_CALLS_TOTAL, &[("provider", "strava")]),
+        2
+    );
+}
@@ -0,0 +1,240 @@
+// ABOUTME: Tests for the machine-readable error details carried by MCP and A2A error responses
+// ABOUTME: Maps representative AppError and A2AError values to their JSON-RPC code and details payload
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use pierre_mcp_server::a2a::protocol::{A2AError, A2AErrorResponse};
+use pierre_mcp_server::errors::{AppError, ErrorCode, ErrorDetails};
+use pierre_mcp_server::mcp::multitenant::McpResponse;
+use pierre_mcp_server::protocols::converter::ProtocolConverter;
+use pierre_mcp_server::protocols::universal::UniversalResponse;
+use pierre_mcp_server::providers::errors::ProviderError;
+use serde_json::{json, Value};
+
+/// Serialize an MCP error response for `error` and return its `error` object
+fn mcp_error_json(error: &AppError) -> Value {
+    let response = McpResponse::from_app_error(Some(json!(7)), error);
+    let serialized = serde_json::to_value(&response).unwrap();
+    assert_eq!(serialized["jsonrpc"], "2.0");
+    assert_eq!(serialized["id"], 7);
+    assert!(serialized.get("result").is_none());
+    serialized["error"].clone()
+}
+
+#[test]
+fn test_mcp_error_shape_for_representative_app_errors() {
+    let cases = [
+        (
+            AppError::invalid_input("limit must be positive"),
+            -32602,
+            "InvalidInput",
+            false,
+        ),
+        (
+            AppError::not_found("Activity 42"),
+            -32601,
+            "ResourceNotFound",
+            false,
+        ),
+        (AppError::auth_required(), -32603, "AuthRequired", false),
+        (
+            AppError::new(ErrorCode::PermissionDenied, "admin only"),
+            -32603,
+            "PermissionDenied",
+            false,
+        ),
+        (
+            AppError::new(ErrorCode::RateLimitExceeded, "slow down"),
+            -32603,
+            "RateLimitExceeded",
+            true,
+        ),
+        (
+            AppError::new(ErrorCode::ExternalServiceUnavailable, "strava down"),
+            -32603,
+            "ExternalServiceUnavailable",
+            true,
+        ),
+        (
+            AppError::database("connection refused"),
+            -32603,
+            "DatabaseError",
+            false,
+        ),
+        (AppError::internal("boom"), -32603, "InternalError", false),
+    ];
+
+    for (error, code, error_code, retriable) in cases {
+        let body = mcp_error_json(&error);
+        assert_eq!(body["code"], code, "{error}");
+        assert_eq!(body["message"], error.to_string());
+        assert_eq!(
+            body["data"],
+            json!({
+                "code": error_code,
+                "message": error.to_string(),
+                "retriable": retriable,
+            }),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn test_provider_rate_limit_carries_retry_after() {
+    let error = AppError::from(ProviderError::RateLimitExceeded {
+        provider: "strava".to_owned(),
+        retry_after_secs: 120,
+        limit_type: "15-minute".to_owned(),
+    });
+    assert!(error.is_retriable());
+
+    let body = mcp_error_json(&error);
+    assert_eq!(body["data"]["code"], "ExternalServiceError");
+    assert_eq!(body["data"]["retriable"], true);
+    assert_eq!(body["data"]["retry_after_secs"], 120);
+
+    let details = ErrorDetails::from(&AppError::from(ProviderError::CircuitBreakerOpen {
+        provider: "fitbit".to_owned(),
+        retry_after_secs: 30,
+    }));
+    assert!(details.retriable);
+    assert_eq!(details.retry_after_secs, Some(30));
+}
+
+#[test]
+fn test_details_round_trip_from_error_object() {
+    let error =
+        AppError::new(ErrorCode::ResourceLocked, "goal is being edited").with_retry_after(5);
+    let response = McpResponse::from_app_error(None, &error);
+
+    let details = response.error.unwrap().details().unwrap();
+    assert_eq!(details.code, ErrorCode::ResourceLocked);
+    assert_eq!(details.message, error.to_string());
+    assert!(details.retriable);
+    assert_eq!(details.retry_after_secs, Some(5));
+}
+
+#[test]
+fn test_a2a_error_shape_for_representative_errors() {
+    let cases = [
+        (
+            A2AError::InvalidRequest("missing tool".into()),
+            -32602,
+            ErrorCode::InvalidInput,
+            false,
+        ),
+        (
+            A2AError::AuthenticationFailed("bad key".into()),
+            -32001,
+            ErrorCode::AuthInvalid,
+            false,
+        ),
+        (
+            A2AError::ClientDeactivated("client-1".into()),
+            -32004,
+            ErrorCode::PermissionDenied,
+            false,
+        ),
+        (
+            A2AError::RateLimitExceeded("100/min".into()),
+            -32005,
+            ErrorCode::RateLimitExceeded,
+            true,
+        ),
+        (
+            A2AError::SessionExpired("session-1".into()),
+            -32006,
+            ErrorCode::AuthExpired,
+            false,
+        ),
+        (
+            A2AError::ResourceNotFound("task-1".into()),
+            -32009,
+            ErrorCode::ResourceNotFound,
+            false,
+        ),
+        (
+            A2AError::ServiceUnavailable("maintenance".into()),
+            -32010,
+            ErrorCode::ResourceUnavailable,
+            true,
+        ),
+        (
+            A2AError::DatabaseError("locked".into()),
+            -32000,
+            ErrorCode::DatabaseError,
+            false,
+        ),
+    ];
+
+    for (error, code, error_code, retriable) in cases {
+        let message = error.to_string();
+        let response = A2AErrorResponse::from(error);
+        assert_eq!(response.code, code, "{message}");
+
+        let details = response.details().unwrap();
+        assert_eq!(details.code, error_code, "{message}");
+        assert_eq!(details.message, response.message);
+        assert_eq!(details.retriable, retriable, "{message}");
+        assert_eq!(details.retry_after_secs, None);
+    }
+}
+
+#[test]
+fn test_mcp_and_a2a_share_the_details_shape() {
+    let error = AppError::not_found("Task task-1");
+    let mcp = McpResponse::from_app_error(Some(json!(1)), &error)
+        .error
+        .unwrap();
+    let a2a = A2AErrorResponse::from(A2AError::ResourceNotFound("Task task-1".into()));
+
+    let mcp_data = mcp.data.unwrap();
+    let a2a_data = a2a.data.unwrap();
+    let keys = |data: &Value| {
+        let mut keys: Vec<String> = data.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    };
+    assert_eq!(keys(&mcp_data), keys(&a2a_data));
+    assert_eq!(mcp_data["code"], a2a_data["code"]);
+}
+
+#[test]
+fn test_protocol_extras_sit_next_to_details() {
+    let error = A2AErrorResponse::with_error_code(
+        -32601,
+        ErrorCode::ResourceNotFound,
+        "Method 'x' not found",
+    )
+    .with_extra("available_methods", json!(["client.info"]));
+
+    let data = error.data.clone().unwrap();
+    assert_eq!(data["code"], "ResourceNotFound");
+    assert_eq!(data["available_methods"], json!(["client.info"]));
+    assert_eq!(error.details().unwrap().message, "Method 'x' not found");
+}
+
+#[test]
+fn test_failed_universal_response_converts_to_a2a_error_with_details() {
+    let response = ProtocolConverter::universal_to_a2a(
+        UniversalResponse {
+            success: false,
+            result: None,
+            error: Some("Tool not found".into()),
+            metadata: None,
+        },
+        Some(json!(3)),
+    );
+
+    let error = response.error.unwrap();
+    assert_eq!(error.code, -32603);
+    let details = error.details().unwrap();
+    assert_eq!(details.code, ErrorCode::InternalError);
+    assert_eq!(details.message, "Tool not found");
+    assert!(!details.retriable);
+}
@@ -0,0 +1,252 @@
+// ABOUTME: Tests for the per-tenant provider connectivity health check
+// ABOUTME: Covers synthetic provider probes, degraded reporting, probe concurrency, and caching
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use anyhow::Result;
+use async_trait::async_trait;
+use pierre_mcp_server::constants::oauth_providers;
+use pierre_mcp_server::database_plugins::{factory::Database, DatabaseProvider};
+use pierre_mcp_server::errors::{AppError, AppResult};
+use pierre_mcp_server::health::{
+    HealthStatus, ProviderHealthChecker, ProviderHealthConfig, ProviderProbe, TokenValidityProbe,
+};
+use pierre_mcp_server::models::{TenantId, UserOAuthToken};
+use pierre_mcp_server::tenant::TenantOAuthCredentials;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use uuid::Uuid;
+
+mod common;
+
+/// Create a user with their own tenant and return both ids
+async fn create_tenant(database: &Database) -> Result<(Uuid, TenantId)> {
+    let email = format!("health-{}@example.com", Uuid::new_v4());
+    let (user_id, _user) = common::create_test_user_with_email(database, &email).await?;
+    let tenant_id = database.list_tenants_for_user(user_id).await?[0].id;
+    Ok((user_id, tenant_id))
+}
+
+async fn configure_provider(
+    database: &Database,
+    tenant_id: TenantId,
+    provider: &str,
+) -> Result<()> {
+    database
+        .store_tenant_oauth_credentials(&TenantOAuthCredentials {
+            tenant_id,
+            provider: provider.to_owned(),
+            client_id: format!("{provider}_client_id"),
+            client_secret: format!("{provider}_client_secret"),
+            previous_client_secret: None,
+            redirect_uri: format!("http://localhost:8081/api/oauth/callback/{provider}"),
+            scopes: vec!["read".to_owned()],
+            rate_limit_per_day: 1000,
+        })
+        .await?;
+    Ok(())
+}
+
+/// Probe that fails for strava, hangs for fitbit, and succeeds otherwise
+struct FlakyProbe;
+
+#[async_trait]
+impl ProviderProbe for FlakyProbe {
+    async fn probe(&self, _tenant_id: TenantId, provider: &str) -> AppResult<()> {
+        match provider {
+            "strava" => Err(AppError::external_service(
+                "strava",
+                "503 Service Unavailable",
+            )),
+            "fitbit" => {
+                sleep(Duration::from_secs(5)).await;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Probe that records how many probes ran and the peak number in flight
+#[derive(Default)]
+struct CountingProbe {
+    calls: AtomicUsize,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+#[async_trait]
+impl ProviderProbe for CountingProbe {
+    async fn probe(&self, _tenant_id: TenantId, _provider: &str) -> AppResult<()> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(running, Ordering::SeqCst);
+        sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_synthetic_provider_probe_reports_healthy() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let database = &resources.database;
+    let (user_id, tenant_id) = create_tenant(database).await?;
+    configure_provider(database, tenant_id, oauth_providers::SYNTHETIC).await?;
+    database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            oauth_providers::SYNTHETIC.to_owned(),
+            "synthetic_access_token".to_owned(),
+            None,
+            None,
+            None,
+        ))
+        .await?;
+
+    let checker = ProviderHealthChecker::new(
+        Arc::clone(database),
+        Arc::new(TokenValidityProbe::new(Arc::clone(&resources))),
+        ProviderHealthConfig::default(),
+    );
+    let report = checker.check().await?;
+
+    assert_eq!(report.status, HealthStatus::Healthy);
+    assert!(!report.cached);
+    assert_eq!(report.providers.len(), 1);
+    let entry = &report.providers[0];
+    assert_eq!(entry.provider, oauth_providers::SYNTHETIC);
+    assert_eq!(entry.tenant, tenant_id);
+    assert_eq!(entry.status, HealthStatus::Healthy);
+    assert!(entry.message.is_none());
+
+    let json = serde_json::to_value(entry)?;
+    assert_eq!(json["provider"], "synthetic");
+    assert_eq!(json["tenant"], tenant_id.to_string());
+    assert_eq!(json["status"], "healthy");
+    assert!(json["latency_ms"].is_u64());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_provider_without_connected_user_is_degraded() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let database = &resources.database;
+    let (_user_id, tenant_id) = create_tenant(database).await?;
+    configure_provider(database, tenant_id, oauth_providers::SYNTHETIC).await?;
+
+    let checker = ProviderHealthChecker::new(
+        Arc::clone(database),
+        Arc::new(TokenValidityProbe::new(Arc::clone(&resources))),
+        ProviderHealthConfig::default(),
+    );
+    let report = checker.check().await?;
+
+    assert_eq!(report.status, HealthStatus::Degraded);
+    assert_eq!(report.providers[0].status, HealthStatus::Degraded);
+    assert!(report.providers[0].message.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failing_and_slow_providers_degrade_instead_of_failing() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let database = &resources.database;
+    let (_user_id, tenant_id) = create_tenant(database).await?;
+    for provider in ["strava", "fitbit", oauth_providers::SYNTHETIC] {
+        configure_provider(database, tenant_id, provider).await?;
+    }
+
+    let config = ProviderHealthConfig {
+        probe_timeout: Duration::from_millis(50),
+        ..ProviderHealthConfig::default()
+    };
+    let checker = ProviderHealthChecker::new(Arc::clone(database), Arc::new(FlakyProbe), config);
+    let report = checker.check().await?;
+
+    assert_eq!(report.status, HealthStatus::Degraded);
+    assert_eq!(report.providers.len(), 3);
+    assert!(report
+        .providers
+        .iter()
+        .all(|entry| entry.status != HealthStatus::Unhealthy));
+
+    let entry_for = |provider: &str| {
+        report
+            .providers
+            .iter()
+            .find(|entry| entry.provider == provider)
+            .unwrap()
+    };
+    assert_eq!(entry_for("strava").status, HealthStatus::Degraded);
+    assert!(entry_for("strava")
+        .message
+        .as_deref()
+        .unwrap()
+        .contains("503"));
+    assert_eq!(entry_for("fitbit").status, HealthStatus::Degraded);
+    assert!(entry_for("fitbit")
+        .message
+        .as_deref()
+        .unwrap()
+        .contains("timed out"));
+    assert_eq!(entry_for("synthetic").status, HealthStatus::Healthy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_probes_are_bounded_and_results_cached() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let database = &resources.database;
+    let providers = ["strava", "fitbit", "garmin", "whoop", "terra", "coros"];
+    for _ in 0..2 {
+        let (_user_id, tenant_id) = create_tenant(database).await?;
+        for provider in providers {
+            configure_provider(database, tenant_id, provider).await?;
+        }
+    }
+
+    let probe = Arc::new(CountingProbe::default());
+    let config = ProviderHealthConfig {
+        max_concurrent_probes: 2,
+        ..ProviderHealthConfig::default()
+    };
+    let checker = ProviderHealthChecker::new(Arc::clone(database), Arc::clone(&probe), config);
+
+    let first = checker.check().await?;
+    assert_eq!(first.providers.len(), providers.len() * 2);
+    assert_eq!(probe.calls.load(Ordering::SeqCst), providers.len() * 2);
+    let peak = probe.max_in_flight.load(Ordering::SeqCst);
+    assert!(peak <= 2, "at most 2 probes may run at once, saw {peak}");
+
+    // Within the TTL the cached report is returned without probing again
+    let second = checker.check().await?;
+    assert!(second.cached);
+    assert_eq!(second.checked_at, first.checked_at);
+    assert_eq!(probe.calls.load(Ordering::SeqCst), providers.len() * 2);
+
+    // A zero TTL probes every time
+    let uncached = ProviderHealthChecker::new(
+        Arc::clone(database),
+        Arc::clone(&probe),
+        ProviderHealthConfig {
+            cache_ttl: Duration::ZERO,
+            ..config
+        },
+    );
+    uncached.check().await?;
+    assert!(!uncached.check().await?.cached);
+    assert_eq!(probe.calls.load(Ordering::SeqCst), providers.len() * 6);
+
+    Ok(())
+}
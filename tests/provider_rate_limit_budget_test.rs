@@ -0,0 +1,335 @@
+// ABOUTME: Tests for provider-advertised rate-limit budgets parsed from response headers
+// ABOUTME: Covers Strava, Fitbit and standard header parsing, budget updates, and throttling near zero
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use pierre_mcp_server::constants::oauth_providers;
+use pierre_mcp_server::errors::AppResult;
+use pierre_mcp_server::models::{Activity, Athlete, PersonalRecord, Stats, TenantId};
+use pierre_mcp_server::pagination::{CursorPage, PaginationParams};
+use pierre_mcp_server::providers::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig,
+};
+use pierre_mcp_server::providers::rate_limit_budget::{
+    parse_fitbit_headers, parse_standard_headers, parse_strava_headers, BudgetDecision,
+    BudgetObserver, BudgetState, ProviderBudget, ProviderBudgetConfig, ProviderBudgetTracker,
+};
+use pierre_mcp_server::providers::registry::ProviderRegistry;
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use uuid::Uuid;
+
+fn observed_at() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 8, 7, 30).unwrap()
+}
+
+fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in pairs {
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes()).unwrap(),
+            HeaderValue::from_str(value).unwrap(),
+        );
+    }
+    headers
+}
+
+fn strava_usage(short_term: u32, daily: u32, now: DateTime<Utc>) -> ProviderBudget {
+    parse_strava_headers(
+        &headers(&[
+            ("X-RateLimit-Limit", "600,30000"),
+            ("X-RateLimit-Usage", &format!("{short_term},{daily}")),
+        ]),
+        now,
+    )
+    .unwrap()
+}
+
+fn tracker() -> ProviderBudgetTracker {
+    ProviderBudgetTracker::new(ProviderBudgetConfig {
+        throttle_below_percent: 10,
+        reserve: 2,
+        max_delay: Duration::from_millis(10),
+    })
+}
+
+/// Synthetic athlete lookups that report a Strava budget shrinking by one per call
+struct MeteredProvider {
+    inner: SyntheticProvider,
+    calls: Arc<AtomicU32>,
+    budget: BudgetObserver,
+}
+
+impl MeteredProvider {
+    fn new(calls: &Arc<AtomicU32>) -> Box<Self> {
+        Box::new(Self {
+            inner: SyntheticProvider::new(),
+            calls: Arc::clone(calls),
+            budget: BudgetObserver::default(),
+        })
+    }
+}
+
+#[async_trait]
+impl FitnessProvider for MeteredProvider {
+    fn name(&self) -> &'static str {
+        oauth_providers::STRAVA
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        self.inner.config()
+    }
+
+    fn rate_limit_budget(&self) -> Option<ProviderBudget> {
+        self.budget.latest()
+    }
+
+    async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
+        self.inner.set_credentials(credentials).await
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        // Both windows run low together so the test does not depend on the clock
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        self.budget
+            .observe(Some(strava_usage(595 + call, 29_995 + call, Utc::now())));
+        self.inner.get_athlete().await
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        self.inner.get_activities_with_params(params).await
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        self.inner.get_activities_cursor(params).await
+    }
+
+    async fn get_activity(&self, id: &str) -> AppResult<Activity> {
+        self.inner.get_activity(id).await
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        self.inner.get_stats().await
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        self.inner.get_personal_records().await
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_strava_usage_headers() {
+    let budget = parse_strava_headers(
+        &headers(&[
+            ("X-RateLimit-Limit", "600,30000"),
+            ("X-RateLimit-Usage", "314,27536"),
+            ("X-ReadRateLimit-Limit", "300,3000"),
+            ("X-ReadRateLimit-Usage", "290,1200"),
+        ]),
+        observed_at(),
+    )
+    .unwrap();
+
+    let windows: Vec<(&str, u32, u32)> = budget
+        .windows
+        .iter()
+        .map(|w| (w.window.as_str(), w.limit, w.remaining()))
+        .collect();
+    assert_eq!(
+        windows,
+        vec![
+            ("15min", 600, 286),
+            ("daily", 30000, 2464),
+            ("read_15min", 300, 10),
+            ("read_daily", 3000, 1800),
+        ]
+    );
+    assert_eq!(
+        budget.windows[0].resets_at,
+        Utc.with_ymd_and_hms(2025, 6, 1, 8, 15, 0).unwrap()
+    );
+    assert_eq!(
+        budget.windows[1].resets_at,
+        Utc.with_ymd_and_hms(2025, 6, 2, 0, 0, 0).unwrap()
+    );
+    assert_eq!(budget.remaining(observed_at()), Some(10));
+
+    // Responses without usage headers leave the budget unknown
+    assert!(parse_strava_headers(&HeaderMap::new(), observed_at()).is_none());
+    assert!(parse_strava_headers(
+        &headers(&[("X-RateLimit-Limit", "600,30000")]),
+        observed_at()
+    )
+    .is_none());
+}
+
+#[test]
+fn test_parse_remaining_style_headers() {
+    let fitbit = parse_fitbit_headers(
+        &headers(&[
+            ("Fitbit-Rate-Limit-Limit", "150"),
+            ("Fitbit-Rate-Limit-Remaining", "12"),
+            ("Fitbit-Rate-Limit-Reset", "1350"),
+        ]),
+        observed_at(),
+    )
+    .unwrap();
+    assert_eq!(fitbit.windows[0].window, "hourly");
+    assert_eq!(fitbit.windows[0].remaining(), 12);
+    assert_eq!(
+        fitbit.windows[0].resets_at,
+        Utc.with_ymd_and_hms(2025, 6, 1, 8, 30, 0).unwrap()
+    );
+
+    let whoop = parse_standard_headers(
+        &headers(&[
+            (
+                "X-RateLimit-Limit",
+                "100, 100;window=60, 10000;window=86400",
+            ),
+            ("X-RateLimit-Remaining", "97"),
+            ("X-RateLimit-Reset", "30"),
+        ]),
+        observed_at(),
+    )
+    .unwrap();
+    assert_eq!(whoop.windows[0].limit, 100);
+    assert_eq!(whoop.windows[0].used, 3);
+}
+
+#[test]
+fn test_budget_state_updates_and_throttles_near_zero() {
+    let tracker = tracker();
+    let tenant_id = TenantId::new();
+    let strava = oauth_providers::STRAVA;
+    let now = observed_at();
+
+    assert_eq!(
+        tracker.decide(tenant_id, strava, now),
+        BudgetDecision::Proceed
+    );
+
+    tracker.record(tenant_id, strava, strava_usage(100, 5000, now));
+    assert_eq!(
+        tracker.decide(tenant_id, strava, now),
+        BudgetDecision::Proceed
+    );
+
+    // 40 of 600 left is under 10%: delayed, more so as the budget shrinks
+    tracker.record(tenant_id, strava, strava_usage(560, 5000, now));
+    let BudgetDecision::Delay(early) = tracker.decide(tenant_id, strava, now) else {
+        panic!("expected a delay with 40 requests left");
+    };
+    tracker.record(tenant_id, strava, strava_usage(590, 5000, now));
+    let BudgetDecision::Delay(late) = tracker.decide(tenant_id, strava, now) else {
+        panic!("expected a delay with 10 requests left");
+    };
+    assert!(early < late && late < Duration::from_millis(10));
+
+    // Down to the reserve: rejected until the quarter hour
+    tracker.record(tenant_id, strava, strava_usage(598, 5000, now));
+    assert_eq!(
+        tracker.decide(tenant_id, strava, now),
+        BudgetDecision::Reject {
+            retry_after_secs: 450
+        }
+    );
+
+    // An older response arriving late does not restore the budget
+    let earlier = now - chrono::Duration::seconds(5);
+    tracker.record(tenant_id, strava, strava_usage(100, 5000, earlier));
+    assert_eq!(
+        tracker.budget(tenant_id, strava).unwrap().remaining(now),
+        Some(2)
+    );
+
+    // Other tenants and providers keep their own budgets
+    assert_eq!(
+        tracker.decide(TenantId::new(), strava, now),
+        BudgetDecision::Proceed
+    );
+    assert_eq!(
+        tracker.decide(tenant_id, oauth_providers::FITBIT, now),
+        BudgetDecision::Proceed
+    );
+
+    // Once the window resets the budget no longer holds requests back
+    let after_reset = Utc.with_ymd_and_hms(2025, 6, 1, 8, 15, 1).unwrap();
+    assert_eq!(
+        tracker.decide(tenant_id, strava, after_reset),
+        BudgetDecision::Proceed
+    );
+}
+
+#[tokio::test]
+async fn test_tenant_provider_records_budget_and_rejects_near_exhaustion() {
+    let registry = ProviderRegistry::new().with_budget_tracker(Arc::new(tracker()));
+    let tenant_id = TenantId::new();
+    let calls = Arc::new(AtomicU32::new(0));
+
+    // 4, then 3 requests left after these calls; the second is delayed, not rejected
+    for _ in 0..2 {
+        let provider =
+            registry.limit_for_tenant(MeteredProvider::new(&calls), tenant_id, Uuid::new_v4());
+        provider.get_athlete().await.unwrap();
+    }
+    let budget = registry
+        .budget_tracker()
+        .budget(tenant_id, oauth_providers::STRAVA)
+        .unwrap();
+    assert_eq!(budget.remaining(Utc::now()), Some(3));
+
+    // The third call leaves 2, the reserve, so the fourth never reaches the provider
+    let provider =
+        registry.limit_for_tenant(MeteredProvider::new(&calls), tenant_id, Uuid::new_v4());
+    provider.get_athlete().await.unwrap();
+    let rejected = provider.get_athlete().await.unwrap_err();
+    assert!(
+        rejected.to_string().contains("provider budget"),
+        "{rejected}"
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let budgets = registry.budget_tracker().budgets();
+    assert_eq!(budgets.len(), 1);
+    assert_eq!(budgets[0].tenant_id, tenant_id);
+    assert_eq!(budgets[0].provider, oauth_providers::STRAVA);
+    assert_eq!(budgets[0].remaining, Some(2));
+    assert_eq!(budgets[0].state, BudgetState::Exhausted);
+
+    // Another tenant with the same provider is unaffected
+    let other = registry.limit_for_tenant(
+        MeteredProvider::new(&Arc::new(AtomicU32::new(0))),
+        TenantId::new(),
+        Uuid::new_v4(),
+    );
+    assert!(other.get_athlete().await.is_ok());
+}
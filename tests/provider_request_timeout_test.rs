@@ -0,0 +1,120 @@
+// ABOUTME: Tests for per-provider request timeout overrides
+// ABOUTME: A slow local Strava stub times out a 5s-configured provider while a 30s-configured one succeeds
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use axum::{http::header::CONTENT_TYPE, routing::get, Router};
+use chrono::Utc;
+use pierre_mcp_server::config::environment::HttpClientConfig;
+use pierre_mcp_server::constants::{init_server_config, oauth_providers};
+use pierre_mcp_server::providers::core::{
+    FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
+use pierre_mcp_server::providers::strava_provider::StravaProvider;
+use pierre_mcp_server::utils::http_client::initialize_http_clients;
+use tokio::net::TcpListener;
+
+/// How long the stub waits before answering
+const STUB_DELAY: Duration = Duration::from_secs(7);
+
+static INIT: Once = Once::new();
+
+fn ensure_initialized() {
+    INIT.call_once(|| {
+        let _ = init_server_config();
+        initialize_http_clients(HttpClientConfig::default());
+    });
+}
+
+/// Serve GET /athlete after `STUB_DELAY`, returning the API base URL
+async fn start_slow_strava_stub() -> String {
+    let app = Router::new().route(
+        "/athlete",
+        get(|| async {
+            tokio::time::sleep(STUB_DELAY).await;
+            (
+                [(CONTENT_TYPE, "application/json")],
+                r#"{"id": 42, "username": "slow_runner", "firstname": "Slow", "lastname": "Runner"}"#,
+            )
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    base_url
+}
+
+async fn provider_with_timeout(api_base_url: &str, timeout_secs: u64) -> StravaProvider {
+    let provider = StravaProvider::with_config(ProviderConfig {
+        name: oauth_providers::STRAVA.to_owned(),
+        auth_url: format!("{api_base_url}/oauth/authorize"),
+        token_url: format!("{api_base_url}/oauth/token"),
+        api_base_url: api_base_url.to_owned(),
+        revoke_url: None,
+        default_scopes: vec!["activity:read_all".to_owned()],
+        request_timeout_secs: Some(timeout_secs),
+        validation_mode: ProviderValidationMode::Lenient,
+    });
+    provider
+        .set_credentials(OAuth2Credentials {
+            client_id: "test_client_id".to_owned(),
+            client_secret: "test_client_secret".to_owned(),
+            access_token: Some("a".repeat(40)),
+            refresh_token: Some("test_refresh_token".to_owned()),
+            expires_at: Some(Utc::now() + chrono::Duration::hours(1)),
+            scopes: vec!["activity:read_all".to_owned()],
+        })
+        .await
+        .unwrap();
+    provider
+}
+
+#[tokio::test]
+async fn test_provider_timeout_override_applies_per_provider() {
+    ensure_initialized();
+    let base_url = start_slow_strava_stub().await;
+
+    let short = provider_with_timeout(&base_url, 5).await;
+    let long = provider_with_timeout(&base_url, 30).await;
+
+    // Both requests hit the same slow stub concurrently
+    let started = Instant::now();
+    let (short_result, long_result) = tokio::join!(
+        async {
+            let result = short.get_athlete().await;
+            (result, started.elapsed())
+        },
+        long.get_athlete()
+    );
+
+    let (short_result, short_elapsed) = short_result;
+    assert!(short_result.is_err());
+    assert!(short_elapsed < STUB_DELAY);
+
+    let athlete = long_result.unwrap();
+    assert_eq!(athlete.id, "42");
+    assert_eq!(athlete.username, "slow_runner");
+}
+
+#[test]
+fn test_provider_timeout_defaults_to_shared_client() {
+    ensure_initialized();
+    let config = StravaProvider::new().config().clone();
+    assert_eq!(config.request_timeout_secs, None);
+    assert_eq!(config.request_timeout(), None);
+
+    let overridden = ProviderConfig {
+        request_timeout_secs: Some(5),
+        ..config
+    };
+    assert_eq!(overridden.request_timeout(), Some(Duration::from_secs(5)));
+}
@@ -174,6 +174,40 @@ fn test_synthetic_config_no_oauth() {
     assert!(config.default_scopes.is_empty());
 }
 
+#[test]
+#[cfg(feature = "provider-synthetic")]
+fn test_synthetic_capability_matrix_matches_descriptor() {
+    let desc = SyntheticDescriptor;
+    let matrix = desc.capabilities().matrix();
+
+    assert_eq!(matrix.len(), ProviderCapabilities::NAMED.len());
+    assert_eq!(matrix["oauth"], desc.requires_oauth());
+    assert_eq!(
+        matrix["activities"],
+        desc.capabilities().supports_activities()
+    );
+    assert_eq!(matrix["sleep"], desc.supports_sleep());
+    assert_eq!(matrix["recovery"], desc.supports_recovery());
+    assert_eq!(matrix["health"], desc.supports_health());
+
+    let supported: Vec<&str> = matrix
+        .iter()
+        .filter(|(_, supported)| **supported)
+        .map(|(name, _)| *name)
+        .collect();
+    assert_eq!(supported, ["activities", "health", "recovery", "sleep"]);
+}
+
+#[test]
+#[cfg(feature = "provider-strava")]
+fn test_strava_capability_matrix() {
+    let matrix = StravaDescriptor.capabilities().matrix();
+    assert!(matrix["gear"]);
+    assert!(matrix["segments"]);
+    assert!(!matrix["heart_rate_series"]);
+    assert!(!matrix["webhooks"]);
+}
+
 #[test]
 fn test_capabilities_bitflags() {
     // Test bitflag operations
@@ -244,7 +278,7 @@ fn test_garmin_full_descriptor() {
 
 #[cfg(feature = "provider-synthetic")]
 mod activity_query_params_tests {
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
     use pierre_mcp_server::providers::ActivityQueryParams;
     use pierre_mcp_server::providers::CoreFitnessProvider;
@@ -341,21 +375,19 @@ mod activity_query_params_tests {
         let now = Utc::now();
 
         // Filter for activities after 3 days ago
-        let after_timestamp = (now - Duration::days(3)).timestamp();
+        let after = now - Duration::days(3);
         let params = ActivityQueryParams {
             limit: None,
             offset: None,
             before: None,
-            after: Some(after_timestamp),
+            after: Some(after),
         };
 
         let activities = provider.get_activities_with_params(&params).await.unwrap();
 
         // Should only get the 2 most recent activities (1 hour ago and 1 day ago)
         assert_eq!(activities.len(), 2);
-        assert!(activities
-            .iter()
-            .all(|a| a.start_date().timestamp() >= after_timestamp));
+        assert!(activities.iter().all(|a| a.start_date() >= after));
     }
 
     #[tokio::test]
@@ -364,11 +396,11 @@ mod activity_query_params_tests {
         let now = Utc::now();
 
         // Filter for activities before 2 days ago
-        let before_timestamp = (now - Duration::days(2)).timestamp();
+        let before = now - Duration::days(2);
         let params = ActivityQueryParams {
             limit: None,
             offset: None,
-            before: Some(before_timestamp),
+            before: Some(before),
             after: None,
         };
 
@@ -376,9 +408,7 @@ mod activity_query_params_tests {
 
         // Should only get the 2 older activities (7 days ago and 30 days ago)
         assert_eq!(activities.len(), 2);
-        assert!(activities
-            .iter()
-            .all(|a| a.start_date().timestamp() < before_timestamp));
+        assert!(activities.iter().all(|a| a.start_date() < before));
     }
 
     #[tokio::test]
@@ -387,14 +417,14 @@ mod activity_query_params_tests {
         let now = Utc::now();
 
         // Filter for activities between 10 days ago and 2 days ago
-        let after_timestamp = (now - Duration::days(10)).timestamp();
-        let before_timestamp = (now - Duration::days(2)).timestamp();
+        let after = now - Duration::days(10);
+        let before = now - Duration::days(2);
 
         let params = ActivityQueryParams {
             limit: None,
             offset: None,
-            before: Some(before_timestamp),
-            after: Some(after_timestamp),
+            before: Some(before),
+            after: Some(after),
         };
 
         let activities = provider.get_activities_with_params(&params).await.unwrap();
@@ -416,8 +446,8 @@ mod activity_query_params_tests {
 
     #[tokio::test]
     async fn test_activity_query_params_with_time_range() {
-        let before = 1_700_000_000_i64;
-        let after = 1_690_000_000_i64;
+        let before = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let after = DateTime::from_timestamp(1_690_000_000, 0).unwrap();
         let params = ActivityQueryParams::with_time_range(Some(before), Some(after));
 
         assert!(params.limit.is_none());
@@ -426,3 +456,111 @@ mod activity_query_params_tests {
         assert_eq!(params.after, Some(after));
     }
 }
+
+// ============================================================================
+// Tests for provider query strings emitted from ActivityQueryParams
+// ============================================================================
+
+mod activity_query_endpoint_tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use pierre_mcp_server::providers::ActivityQueryParams;
+
+    fn after() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 1, 6, 30, 0).unwrap()
+    }
+
+    fn before() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 8, 18, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_contains_uses_inclusive_after_and_exclusive_before() {
+        let params = ActivityQueryParams::with_time_range(Some(before()), Some(after()));
+
+        assert!(params.contains(after()));
+        assert!(!params.contains(before()));
+        assert!(!params.contains(after() - chrono::Duration::seconds(1)));
+        assert!(ActivityQueryParams::default().contains(before()));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-strava")]
+    fn test_strava_endpoint_emits_epoch_before_and_after() {
+        use pierre_mcp_server::providers::strava_provider::StravaProvider;
+
+        let params = ActivityQueryParams {
+            limit: Some(50),
+            offset: None,
+            before: Some(before()),
+            after: Some(after()),
+        };
+        let endpoint = StravaProvider::activities_endpoint(&params);
+
+        assert!(endpoint.starts_with("athlete/activities?per_page=50&page=1"));
+        assert!(endpoint.contains(&format!("&before={}", before().timestamp())));
+        assert!(endpoint.contains(&format!("&after={}", after().timestamp())));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-strava")]
+    fn test_strava_endpoint_omits_unset_bounds() {
+        use pierre_mcp_server::providers::strava_provider::StravaProvider;
+
+        let endpoint = StravaProvider::activities_endpoint(&ActivityQueryParams::with_pagination(
+            Some(10),
+            None,
+        ));
+
+        assert!(!endpoint.contains("before="));
+        assert!(!endpoint.contains("after="));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-fitbit")]
+    fn test_fitbit_endpoint_emits_date_range() {
+        use pierre_mcp_server::providers::fitbit_provider::FitbitProvider;
+
+        let params = ActivityQueryParams::with_time_range(Some(before()), Some(after()));
+        let endpoint = FitbitProvider::activities_endpoint(&params);
+
+        assert!(endpoint.contains("beforeDate=2025-03-08"));
+        assert!(endpoint.contains("afterDate=2025-03-01"));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-fitbit")]
+    fn test_fitbit_endpoint_after_only_ends_today() {
+        use pierre_mcp_server::providers::fitbit_provider::FitbitProvider;
+
+        let params = ActivityQueryParams::with_time_range(None, Some(after()));
+        let endpoint = FitbitProvider::activities_endpoint(&params);
+        let today = Utc::now().date_naive().format("%Y-%m-%d").to_string();
+
+        assert!(endpoint.contains(&format!("beforeDate={today}")));
+        assert!(endpoint.contains("afterDate=2025-03-01"));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-whoop")]
+    fn test_whoop_endpoint_emits_start_and_end() {
+        use pierre_mcp_server::providers::whoop_provider::WhoopProvider;
+
+        let params = ActivityQueryParams::with_time_range(Some(before()), Some(after()));
+        let endpoint = WhoopProvider::workouts_endpoint(&params);
+
+        assert!(endpoint.contains("&start=2025-03-01T06:30:00.000Z"));
+        assert!(endpoint.contains("&end=2025-03-08T18:00:00.000Z"));
+    }
+
+    #[test]
+    #[cfg(feature = "provider-coros")]
+    fn test_coros_endpoint_emits_start_and_end_dates() {
+        use pierre_mcp_server::providers::coros_provider::CorosProvider;
+
+        let params = ActivityQueryParams::with_time_range(Some(before()), Some(after()));
+        let endpoint = CorosProvider::workouts_endpoint(&params);
+
+        assert!(endpoint.contains("&start_date=2025-03-01"));
+        assert!(endpoint.contains("&end_date=2025-03-08"));
+    }
+}
@@ -0,0 +1,195 @@
+// ABOUTME: Tests for lenient and strict validation of malformed provider responses
+// ABOUTME: Feeds a Strava activity with missing and out-of-range fields through both modes
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use pierre_mcp_server::providers::core::{ProviderConfig, ProviderValidationMode};
+use pierre_mcp_server::providers::errors::ProviderError;
+use pierre_mcp_server::providers::strava_provider::{StravaActivityResponse, StravaProvider};
+use tracing::subscriber::set_default;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Summary activity missing `elapsed_time`, with a negative distance and a one-element `start_latlng`
+const MALFORMED_ACTIVITY_JSON: &str = r#"{
+    "id": 31415926535,
+    "name": "Corrupted Upload",
+    "type": "Ride",
+    "start_date": "2025-05-04T06:30:00Z",
+    "distance": -1.0,
+    "average_watts": 182.0,
+    "start_latlng": [46.5]
+}"#;
+
+const VALID_ACTIVITY_JSON: &str = r#"{
+    "id": 31415926536,
+    "name": "Morning Ride",
+    "type": "Ride",
+    "start_date": "2025-05-05T06:30:00Z",
+    "distance": 42195.0,
+    "elapsed_time": 5400,
+    "start_latlng": [46.5, 6.6]
+}"#;
+
+/// In-memory writer capturing formatted log output
+#[derive(Clone, Default)]
+struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedOutput {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn activity(json: &str) -> StravaActivityResponse {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_strict_mode_rejects_malformed_activity() {
+    let error = StravaProvider::validate_activity(
+        &activity(MALFORMED_ACTIVITY_JSON),
+        ProviderValidationMode::Strict,
+    )
+    .unwrap_err();
+
+    match error {
+        ProviderError::MalformedResponse {
+            provider, field, ..
+        } => {
+            assert_eq!(provider, "strava");
+            assert_eq!(field, "elapsed_time");
+        }
+        other => panic!("expected MalformedResponse, got {other:?}"),
+    }
+    assert!(!ProviderError::MalformedResponse {
+        provider: "strava".to_owned(),
+        field: "distance".to_owned(),
+        reason: "negative".to_owned(),
+    }
+    .is_retryable());
+
+    let conversion = StravaProvider::convert_activity(
+        activity(MALFORMED_ACTIVITY_JSON),
+        ProviderValidationMode::Strict,
+    );
+    let message = conversion.unwrap_err().to_string();
+    assert!(message.contains("elapsed_time"), "{message}");
+}
+
+#[test]
+fn test_strict_mode_reports_out_of_range_field() {
+    let json =
+        MALFORMED_ACTIVITY_JSON.replace(r#""distance""#, r#""elapsed_time": 3600, "distance""#);
+
+    let error = StravaProvider::validate_activity(&activity(&json), ProviderValidationMode::Strict)
+        .unwrap_err();
+
+    assert!(
+        matches!(&error, ProviderError::MalformedResponse { field, .. } if field == "distance"),
+        "unexpected error: {error:?}"
+    );
+    assert!(error.to_string().contains("-1"));
+}
+
+#[test]
+fn test_lenient_mode_coerces_with_warning() {
+    let output = CapturedOutput::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(output.clone())
+        .finish();
+    let _guard = set_default(subscriber);
+
+    let converted = StravaProvider::convert_activity(
+        activity(MALFORMED_ACTIVITY_JSON),
+        ProviderValidationMode::Lenient,
+    )
+    .unwrap();
+
+    assert_eq!(converted.duration_seconds(), 0);
+    assert_eq!(converted.distance_meters(), Some(-1.0));
+    assert_eq!(converted.average_power(), Some(182));
+    assert_eq!(converted.start_latitude(), Some(46.5));
+    assert_eq!(converted.start_longitude(), None);
+
+    let captured = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    for field in ["elapsed_time", "distance", "start_latlng"] {
+        assert!(
+            captured.contains(field),
+            "missing warning for {field}: {captured}"
+        );
+    }
+    assert!(captured.contains("WARN"));
+}
+
+#[test]
+fn test_valid_activity_passes_strict_mode() {
+    let converted = StravaProvider::convert_activity(
+        activity(VALID_ACTIVITY_JSON),
+        ProviderValidationMode::Strict,
+    )
+    .unwrap();
+
+    assert_eq!(converted.duration_seconds(), 5400);
+    assert_eq!(converted.start_longitude(), Some(6.6));
+}
+
+#[test]
+fn test_validation_mode_parsing_and_default() {
+    assert_eq!(
+        ProviderValidationMode::parse("STRICT"),
+        Some(ProviderValidationMode::Strict)
+    );
+    assert_eq!(
+        ProviderValidationMode::parse(" lenient "),
+        Some(ProviderValidationMode::Lenient)
+    );
+    assert_eq!(ProviderValidationMode::parse("paranoid"), None);
+    assert_eq!(
+        ProviderValidationMode::default(),
+        ProviderValidationMode::Lenient
+    );
+
+    // Configs serialized before the field existed stay lenient
+    let config: ProviderConfig = serde_json::from_value(serde_json::json!({
+        "name": "strava",
+        "auth_url": "https://www.strava.com/oauth/authorize",
+        "token_url": "https://www.strava.com/oauth/token",
+        "api_base_url": "https://www.strava.com/api/v3",
+        "revoke_url": null,
+        "default_scopes": ["activity:read_all"]
+    }))
+    .unwrap();
+    assert_eq!(config.validation_mode, ProviderValidationMode::Lenient);
+
+    let strict: ProviderConfig = serde_json::from_value(serde_json::json!({
+        "name": "strava",
+        "auth_url": "",
+        "token_url": "",
+        "api_base_url": "",
+        "revoke_url": null,
+        "default_scopes": [],
+        "validation_mode": "strict"
+    }))
+    .unwrap();
+    assert_eq!(strict.validation_mode, ProviderValidationMode::Strict);
+}
@@ -0,0 +1,125 @@
+// ABOUTME: Integration tests for the predict_race_times tool using synthetic provider data
+// ABOUTME: Verifies standard distance predictions, custom distances, and insufficient data errors
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod helpers;
+
+use helpers::test_utils::{create_synthetic_provider_with_scenario, TestScenario};
+use pierre_mcp_server::intelligence::algorithms::Vo2maxEstimationAlgorithm;
+use pierre_mcp_server::models::Activity;
+use pierre_mcp_server::providers::core::{ActivityQueryParams, FitnessProvider};
+use pierre_mcp_server::tools::implementations::analytics::{
+    build_race_predictions_result, estimate_vo2max_from_runs, PredictRaceTimesTool,
+};
+use pierre_mcp_server::tools::traits::{McpTool, ToolCapabilities};
+
+async fn recent_activities(scenario: TestScenario, limit: usize) -> Vec<Activity> {
+    let provider = create_synthetic_provider_with_scenario(scenario);
+    let params = ActivityQueryParams {
+        limit: Some(limit),
+        offset: None,
+        before: None,
+        after: None,
+    };
+    provider.get_activities_with_params(&params).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_predicts_standard_distances_from_synthetic_runs() {
+    let activities = recent_activities(TestScenario::BeginnerRunnerImproving, 50).await;
+    let result = build_race_predictions_result(&activities, None, "synthetic");
+
+    assert!(!result.is_error, "unexpected error: {}", result.content);
+    let predictions = result.content["predictions"].as_array().unwrap();
+    let names: Vec<&str> = predictions
+        .iter()
+        .map(|p| p["distance"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["5K", "10K", "Half Marathon", "Marathon"]);
+
+    // Longer races must take longer
+    let times: Vec<f64> = predictions
+        .iter()
+        .map(|p| p["predicted_time_seconds"].as_f64().unwrap())
+        .collect();
+    assert!(times.windows(2).all(|pair| pair[0] < pair[1]), "{times:?}");
+
+    let confidence = result.content["confidence"].as_str().unwrap();
+    assert!(["high", "medium", "low"].contains(&confidence));
+    assert!(result.content["target_distance"].is_null());
+    assert!(result.content["vdot"].as_f64().unwrap() >= 30.0);
+}
+
+#[tokio::test]
+async fn test_predicts_custom_target_distance() {
+    let activities = recent_activities(TestScenario::BeginnerRunnerImproving, 50).await;
+    let result = build_race_predictions_result(&activities, Some(15_000.0), "synthetic");
+
+    assert!(!result.is_error);
+    let target = &result.content["target_distance"];
+    assert!((target["distance_meters"].as_f64().unwrap() - 15_000.0).abs() < f64::EPSILON);
+
+    let predictions = result.content["predictions"].as_array().unwrap();
+    assert_eq!(predictions.len(), 4);
+    let ten_k = predictions[1]["predicted_time_seconds"].as_f64().unwrap();
+    let half = predictions[2]["predicted_time_seconds"].as_f64().unwrap();
+    let fifteen_k = target["predicted_time_seconds"].as_f64().unwrap();
+    assert!(ten_k < fifteen_k && fifteen_k < half);
+}
+
+#[tokio::test]
+async fn test_vo2max_estimate_uses_the_selected_model() {
+    let activities = recent_activities(TestScenario::BeginnerRunnerImproving, 50).await;
+    let result = build_race_predictions_result(&activities, None, "synthetic");
+    assert!(!result.is_error);
+    let vo2max = &result.content["vo2max"];
+    assert_eq!(vo2max["algorithm"], "daniels_vdot");
+    assert!(vo2max["value"].as_f64().unwrap() >= 20.0);
+
+    let daniels =
+        estimate_vo2max_from_runs(&activities, Vo2maxEstimationAlgorithm::DanielsVdot).unwrap();
+    assert_eq!(daniels.algorithm, Vo2maxEstimationAlgorithm::DanielsVdot);
+
+    // Activities carry no resting heart rate, so the heart rate ratio model cannot run
+    let ratio = estimate_vo2max_from_runs(&activities, Vo2maxEstimationAlgorithm::HeartRateRatio);
+    assert!(ratio.is_err());
+}
+
+#[tokio::test]
+async fn test_cyclist_without_runs_returns_insufficient_data() {
+    let activities = recent_activities(TestScenario::ExperiencedCyclistConsistent, 50).await;
+    let result = build_race_predictions_result(&activities, None, "synthetic");
+
+    assert!(result.is_error);
+    assert_eq!(result.content["error"], "insufficient_data");
+    assert_eq!(result.content["runs_found"], 0);
+}
+
+#[test]
+fn test_no_activities_returns_insufficient_data() {
+    let result = build_race_predictions_result(&[], None, "synthetic");
+
+    assert!(result.is_error);
+    assert_eq!(result.content["error"], "insufficient_data");
+}
+
+#[test]
+fn test_predict_race_times_tool_metadata() {
+    let tool = PredictRaceTimesTool;
+    assert_eq!(tool.name(), "predict_race_times");
+    assert!(!tool.description().is_empty());
+
+    let properties = tool.input_schema().properties.unwrap();
+    assert!(properties.contains_key("distance_meters"));
+    assert!(properties.contains_key("recent_activities_limit"));
+
+    let caps = tool.capabilities();
+    assert!(caps.contains(ToolCapabilities::REQUIRES_AUTH));
+    assert!(caps.contains(ToolCapabilities::READS_DATA));
+    assert!(!caps.contains(ToolCapabilities::WRITES_DATA));
+}
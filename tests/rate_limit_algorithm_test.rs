@@ -0,0 +1,181 @@
+// ABOUTME: Tests for the fixed-window, sliding-window, and token-bucket rate limit algorithms
+// ABOUTME: Verifies boundary bursts, window recovery, and algorithm selection in the auth middleware
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::{
+    api_keys::{ApiKeyManager, ApiKeyTier, ApiKeyUsage, CreateApiKeyRequest},
+    config::environment::RateLimitConfig,
+    database_plugins::{factory::Database, DatabaseProvider},
+    middleware::McpAuthMiddleware,
+    rate_limiting::RateLimitAlgorithm,
+};
+use uuid::Uuid;
+
+const LIMIT: u32 = 10;
+
+fn window() -> Duration {
+    Duration::hours(1)
+}
+
+/// A window boundary: midnight is a multiple of the hour-long window since the epoch
+fn boundary() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+}
+
+/// Attempt each burst of requests in order, returning how many were admitted
+fn admitted(algorithm: RateLimitAlgorithm, bursts: &[(DateTime<Utc>, u32)]) -> u32 {
+    let mut accepted = Vec::new();
+    for &(time, attempts) in bursts {
+        for _ in 0..attempts {
+            if algorithm.effective_usage(&accepted, LIMIT, window(), time) < LIMIT {
+                accepted.push(time);
+            }
+        }
+    }
+    u32::try_from(accepted.len()).unwrap()
+}
+
+#[test]
+fn test_boundary_burst_only_doubles_the_limit_with_fixed_window() {
+    // A full burst just before the boundary and another just after it
+    let bursts = [
+        (boundary() - Duration::seconds(1), LIMIT),
+        (boundary() + Duration::seconds(1), LIMIT),
+    ];
+
+    assert_eq!(
+        admitted(RateLimitAlgorithm::FixedWindow, &bursts),
+        2 * LIMIT
+    );
+    assert_eq!(admitted(RateLimitAlgorithm::SlidingWindow, &bursts), LIMIT);
+    assert_eq!(admitted(RateLimitAlgorithm::TokenBucket, &bursts), LIMIT);
+}
+
+#[test]
+fn test_sliding_window_and_token_bucket_recover_gradually() {
+    let burst = vec![boundary() - Duration::seconds(1); LIMIT as usize];
+    let halfway = boundary() + Duration::minutes(30);
+
+    // Half of the previous window still overlaps the trailing window
+    assert_eq!(
+        RateLimitAlgorithm::SlidingWindow.effective_usage(&burst, LIMIT, window(), halfway),
+        5
+    );
+    // Half a window refills half of the bucket
+    assert_eq!(
+        RateLimitAlgorithm::TokenBucket.effective_usage(&burst, LIMIT, window(), halfway),
+        5
+    );
+
+    // Two windows later nothing counts any more
+    let later = boundary() + Duration::hours(2);
+    for algorithm in [
+        RateLimitAlgorithm::FixedWindow,
+        RateLimitAlgorithm::SlidingWindow,
+        RateLimitAlgorithm::TokenBucket,
+    ] {
+        assert_eq!(algorithm.effective_usage(&burst, LIMIT, window(), later), 0);
+        assert!(algorithm.history_start(window(), later) > burst[0]);
+    }
+}
+
+#[test]
+fn test_fixed_window_is_the_default_algorithm() {
+    assert_eq!(
+        RateLimitConfig::default().algorithm,
+        RateLimitAlgorithm::FixedWindow
+    );
+    assert_eq!(
+        "sliding_window".parse::<RateLimitAlgorithm>().unwrap(),
+        RateLimitAlgorithm::SlidingWindow
+    );
+    assert_eq!(
+        "token_bucket".parse::<RateLimitAlgorithm>().unwrap(),
+        RateLimitAlgorithm::TokenBucket
+    );
+    assert!("leaky_bucket".parse::<RateLimitAlgorithm>().is_err());
+}
+
+/// Store an API key allowed `LIMIT` requests per hour and record `used` requests at `at`
+async fn api_key_used_at(
+    database: &Database,
+    user_id: Uuid,
+    used: u32,
+    at: DateTime<Utc>,
+) -> String {
+    let request = CreateApiKeyRequest {
+        name: "Algorithm Test Key".to_owned(),
+        description: None,
+        tier: ApiKeyTier::Starter,
+        rate_limit_requests: None,
+        expires_in_days: None,
+    };
+    let (mut api_key, full_key) = ApiKeyManager::new()
+        .create_api_key(user_id, request)
+        .unwrap();
+    api_key.rate_limit_requests = LIMIT;
+    api_key.rate_limit_window_seconds = u32::try_from(window().num_seconds()).unwrap();
+    database.create_api_key(&api_key).await.unwrap();
+
+    for i in 0..used {
+        database
+            .record_api_key_usage(&ApiKeyUsage {
+                id: None,
+                api_key_id: api_key.id.clone(),
+                timestamp: at,
+                tool_name: format!("test_tool_{i}"),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    full_key
+}
+
+#[tokio::test]
+async fn test_api_key_authentication_honors_sliding_window() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let middleware = McpAuthMiddleware::new(
+        (*common::create_test_auth_manager()).clone(),
+        database.clone(),
+        common::get_shared_test_jwks(),
+        RateLimitConfig {
+            algorithm: RateLimitAlgorithm::SlidingWindow,
+            ..RateLimitConfig::default()
+        },
+    );
+
+    // A burst a moment ago still counts in full, whichever window it fell in
+    let exhausted_key = api_key_used_at(&database, user_id, LIMIT, Utc::now()).await;
+    let error = middleware
+        .authenticate_request(Some(&exhausted_key))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Rate limit"));
+
+    // Usage older than the previous window no longer counts
+    let recovered_key =
+        api_key_used_at(&database, user_id, LIMIT, Utc::now() - Duration::hours(3)).await;
+    let auth = middleware
+        .authenticate_request(Some(&recovered_key))
+        .await
+        .unwrap();
+    assert!(!auth.rate_limit.is_rate_limited);
+    assert_eq!(auth.rate_limit.remaining, Some(LIMIT));
+}
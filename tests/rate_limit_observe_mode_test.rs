@@ -0,0 +1,203 @@
+// ABOUTME: Tests for the per-tenant rate limit observe mode in the rate limiting middleware
+// ABOUTME: Verifies over-limit requests are forwarded and flagged in observe mode and rejected in enforce mode
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, Request, StatusCode},
+    middleware::from_fn_with_state,
+    routing::get,
+    Router,
+};
+use chrono::Utc;
+use pierre_mcp_server::{
+    api_keys::{ApiKeyManager, ApiKeyTier, ApiKeyUsage, CreateApiKeyRequest},
+    database_plugins::DatabaseProvider,
+    mcp::resources::ServerResources,
+    middleware::{headers, rate_limiting_middleware, RateLimitMode},
+    models::TenantId,
+};
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Handler that authenticates like the real routes and rejects what auth rejects
+async fn protected_handler(
+    State(resources): State<Arc<ServerResources>>,
+    headers: HeaderMap,
+) -> StatusCode {
+    match resources
+        .auth_middleware
+        .authenticate_request_with_headers(&headers)
+        .await
+    {
+        Ok(_) => StatusCode::OK,
+        Err(_) => StatusCode::TOO_MANY_REQUESTS,
+    }
+}
+
+fn app(resources: &Arc<ServerResources>) -> Router {
+    Router::new()
+        .route("/protected", get(protected_handler))
+        .layer(from_fn_with_state(
+            resources.clone(),
+            rate_limiting_middleware,
+        ))
+        .with_state(resources.clone())
+}
+
+/// Create an API key allowed `limit` requests and record `used` of them
+async fn api_key_with_usage(
+    resources: &ServerResources,
+    user_id: Uuid,
+    limit: u32,
+    used: u32,
+) -> String {
+    let request = CreateApiKeyRequest {
+        name: "Observe Mode Key".to_owned(),
+        description: None,
+        tier: ApiKeyTier::Starter,
+        rate_limit_requests: None,
+        expires_in_days: None,
+    };
+    let (mut api_key, full_key) = ApiKeyManager::new()
+        .create_api_key(user_id, request)
+        .unwrap();
+    api_key.rate_limit_requests = limit;
+    resources.database.create_api_key(&api_key).await.unwrap();
+
+    for i in 0..used {
+        resources
+            .database
+            .record_api_key_usage(&ApiKeyUsage {
+                id: None,
+                api_key_id: api_key.id.clone(),
+                timestamp: Utc::now(),
+                tool_name: format!("test_tool_{i}"),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    full_key
+}
+
+async fn user_tenant(resources: &ServerResources, user_id: Uuid) -> TenantId {
+    resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id
+}
+
+fn request(api_key: &str) -> Request<Body> {
+    Request::builder()
+        .uri("/protected")
+        .header("authorization", api_key)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_observe_mode_forwards_over_limit_request_with_header() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+    let api_key = api_key_with_usage(&resources, user_id, 2, 2).await;
+
+    resources
+        .database
+        .set_tenant_rate_limit_mode(tenant_id, RateLimitMode::Observe)
+        .await
+        .unwrap();
+
+    let response = app(&resources).oneshot(request(&api_key)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let response_headers = response.headers();
+    assert_eq!(
+        response_headers[headers::RATE_LIMIT_OBSERVED_EXCEEDED],
+        "true"
+    );
+    assert_eq!(response_headers[headers::RATE_LIMIT_LIMIT], "2");
+    assert_eq!(response_headers[headers::RATE_LIMIT_REMAINING], "0");
+    assert!(response_headers.contains_key(headers::RATE_LIMIT_RESET));
+
+    // The would-be block is recorded for review before limits are tightened
+    let events = resources
+        .database
+        .get_audit_events(None, None, None)
+        .await
+        .unwrap();
+    // Action and resource are not persisted, so the path travels in the metadata
+    let observed = events
+        .iter()
+        .find(|event| event.metadata["mode"] == "observe")
+        .unwrap();
+    assert_eq!(observed.result, "observed");
+    assert_eq!(observed.user_id, Some(user_id));
+    assert_eq!(observed.metadata["path"], "/protected");
+}
+
+#[tokio::test]
+async fn test_enforce_mode_still_rejects_over_limit_request() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+    let api_key = api_key_with_usage(&resources, user_id, 2, 2).await;
+
+    // Tenants enforce their limits unless configured otherwise
+    assert_eq!(
+        resources
+            .database
+            .get_tenant_rate_limit_mode(tenant_id)
+            .await
+            .unwrap(),
+        RateLimitMode::Enforce
+    );
+
+    let response = app(&resources).oneshot(request(&api_key)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(!response
+        .headers()
+        .contains_key(headers::RATE_LIMIT_OBSERVED_EXCEEDED));
+}
+
+#[tokio::test]
+async fn test_under_limit_request_gets_headers_without_observed_flag() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = user_tenant(&resources, user_id).await;
+    let api_key = api_key_with_usage(&resources, user_id, 5, 1).await;
+
+    resources
+        .database
+        .set_tenant_rate_limit_mode(tenant_id, RateLimitMode::Observe)
+        .await
+        .unwrap();
+
+    let response = app(&resources).oneshot(request(&api_key)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[headers::RATE_LIMIT_REMAINING], "4");
+    assert!(!response
+        .headers()
+        .contains_key(headers::RATE_LIMIT_OBSERVED_EXCEEDED));
+}
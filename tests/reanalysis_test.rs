@@ -0,0 +1,215 @@
+// ABOUTME: Tests for the batched insight re-analysis maintenance job
+// ABOUTME: Verifies every user gets fresh insights and that an interrupted run resumes from its checkpoint
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use pierre_mcp_server::database_plugins::factory::Database;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::errors::AppResult;
+use pierre_mcp_server::lifecycle::shutdown::ShutdownCoordinator;
+use pierre_mcp_server::models::{TenantId, User, UserStatus};
+use pierre_mcp_server::services::reanalysis::{
+    reanalyze_users, ReanalysisFilter, ReanalysisOptions,
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Analysis version written by the "new algorithm" in these tests
+const NEW_VERSION: u32 = 2;
+
+/// Create `count` active users with distinct creation times and a stale insight each
+async fn create_users(database: &Database, count: i64) -> Vec<Uuid> {
+    let base = Utc::now() - chrono::Duration::hours(1);
+    let mut user_ids = Vec::new();
+    for i in 0..count {
+        let mut user = User::new(
+            format!("reanalysis-{i}@example.com"),
+            "not-a-real-hash".to_owned(),
+            None,
+        );
+        user.user_status = UserStatus::Active;
+        user.created_at = base + chrono::Duration::seconds(i);
+        database.create_user(&user).await.unwrap();
+        database
+            .store_insights_batch(
+                user.id,
+                vec![json!({"type": "training_load", "version": 1})],
+            )
+            .await
+            .unwrap();
+        user_ids.push(user.id);
+    }
+    user_ids
+}
+
+/// Analysis that records how often each user was analyzed
+fn counting_analyzer(
+    calls: &Arc<Mutex<HashMap<Uuid, u32>>>,
+    on_call: impl Fn(u32),
+) -> impl Fn(User, Option<TenantId>) -> std::future::Ready<AppResult<Vec<Value>>> + '_ {
+    move |user, _tenant_id| {
+        let total = {
+            let mut calls = calls.lock().unwrap();
+            *calls.entry(user.id).or_insert(0) += 1;
+            calls.values().sum::<u32>()
+        };
+        on_call(total);
+        std::future::ready(Ok(vec![json!({
+            "type": "training_load",
+            "version": NEW_VERSION,
+            "user_id": user.id,
+        })]))
+    }
+}
+
+async fn assert_fresh_insight(database: &Database, user_id: Uuid) {
+    let insights = database
+        .get_user_insights(user_id, None, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(insights[0]["version"], NEW_VERSION, "user {user_id}");
+}
+
+#[tokio::test]
+async fn test_reanalysis_refreshes_every_user() {
+    let database = common::create_test_database().await.unwrap();
+    let user_ids = create_users(&database, 5).await;
+    let shutdown = ShutdownCoordinator::new(Duration::from_secs(1));
+    let calls = Arc::new(Mutex::new(HashMap::new()));
+
+    let checkpoint = reanalyze_users(
+        database.as_ref(),
+        &shutdown,
+        "refresh-all",
+        &ReanalysisFilter::active_users(),
+        &ReanalysisOptions::new(3, 2),
+        counting_analyzer(&calls, |_| {}),
+    )
+    .await
+    .unwrap();
+
+    assert!(checkpoint.is_complete());
+    assert_eq!(checkpoint.users_processed, 5);
+    assert_eq!(checkpoint.users_failed, 0);
+    assert_eq!(checkpoint.insights_stored, 5);
+    for user_id in &user_ids {
+        assert_eq!(calls.lock().unwrap()[user_id], 1);
+        assert_fresh_insight(&database, *user_id).await;
+    }
+
+    // Running a completed job again analyzes nobody
+    let rerun = reanalyze_users(
+        database.as_ref(),
+        &shutdown,
+        "refresh-all",
+        &ReanalysisFilter::active_users(),
+        &ReanalysisOptions::default(),
+        counting_analyzer(&calls, |_| panic!("completed job must not analyze users")),
+    )
+    .await
+    .unwrap();
+    assert_eq!(rerun, checkpoint);
+}
+
+#[tokio::test]
+async fn test_interrupted_reanalysis_resumes_from_checkpoint() {
+    let database = common::create_test_database().await.unwrap();
+    let user_ids = create_users(&database, 7).await;
+    let calls = Arc::new(Mutex::new(HashMap::new()));
+    let filter = ReanalysisFilter::active_users();
+    let options = ReanalysisOptions::new(2, 2);
+
+    // Shut down while the second batch is running; that batch still finishes
+    let shutdown = Arc::new(ShutdownCoordinator::new(Duration::from_secs(1)));
+    let trigger = Arc::clone(&shutdown);
+    let interrupted = reanalyze_users(
+        database.as_ref(),
+        &shutdown,
+        "resume-job",
+        &filter,
+        &options,
+        counting_analyzer(&calls, move |total| {
+            if total == 3 {
+                trigger.trigger();
+            }
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert!(!interrupted.is_complete());
+    assert_eq!(interrupted.users_processed, 4);
+    assert!(interrupted.cursor.is_some());
+    assert_eq!(
+        database
+            .get_reanalysis_checkpoint("resume-job")
+            .await
+            .unwrap()
+            .unwrap(),
+        interrupted
+    );
+
+    // A restarted process picks up after the last finished batch
+    let restarted = ShutdownCoordinator::new(Duration::from_secs(1));
+    let resumed = reanalyze_users(
+        database.as_ref(),
+        &restarted,
+        "resume-job",
+        &filter,
+        &options,
+        counting_analyzer(&calls, |_| {}),
+    )
+    .await
+    .unwrap();
+
+    assert!(resumed.is_complete());
+    assert_eq!(resumed.users_processed, 7);
+    assert_eq!(resumed.insights_stored, 7);
+    assert_eq!(resumed.started_at, interrupted.started_at);
+    for user_id in &user_ids {
+        assert_eq!(
+            calls.lock().unwrap()[user_id],
+            1,
+            "user {user_id} analyzed more than once"
+        );
+        assert_fresh_insight(&database, *user_id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_tenant_filter_skips_other_tenants_users() {
+    let database = common::create_test_database().await.unwrap();
+    let (member_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(member_id).await.unwrap()[0].id;
+    let outsiders = create_users(&database, 2).await;
+    let shutdown = ShutdownCoordinator::new(Duration::from_secs(1));
+    let calls = Arc::new(Mutex::new(HashMap::new()));
+
+    let checkpoint = reanalyze_users(
+        database.as_ref(),
+        &shutdown,
+        "tenant-job",
+        &ReanalysisFilter::active_users().in_tenant(tenant_id),
+        &ReanalysisOptions::default(),
+        counting_analyzer(&calls, |_| {}),
+    )
+    .await
+    .unwrap();
+
+    assert!(checkpoint.is_complete());
+    assert_eq!(checkpoint.users_processed, 1);
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.get(&member_id), Some(&1));
+    assert!(outsiders.iter().all(|id| !calls.contains_key(id)));
+}
@@ -0,0 +1,159 @@
+// ABOUTME: Tests for the suggest_recipes tool and training-aware recipe ranking
+// ABOUTME: Verifies training intensity shifts suggestions toward carbs and that filters are respected
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::intelligence::recipes::{
+    required_skill_level, satisfies_restriction, DietaryRestriction, MealTiming, Recipe,
+    SkillLevel, ValidatedNutrition,
+};
+use pierre_mcp_server::tools::implementations::recipes::SuggestRecipesTool;
+use pierre_mcp_server::tools::traits::McpTool;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn nutrition(calories: f64, protein_g: f64, carbs_g: f64, fat_g: f64) -> ValidatedNutrition {
+    ValidatedNutrition {
+        calories,
+        protein_g,
+        carbs_g,
+        fat_g,
+        fiber_g: None,
+        sodium_mg: Some(450.0),
+        sugar_g: Some(6.0),
+        validated_at: Utc::now(),
+    }
+}
+
+fn recipe(user_id: Uuid, name: &str, per_serving: ValidatedNutrition) -> Recipe {
+    Recipe::new(user_id, name, 2)
+        .with_prep_time(10)
+        .with_cook_time(15)
+        .with_instruction("Cook")
+        .with_instruction("Serve")
+        .with_nutrition(per_serving)
+}
+
+fn athlete(intensity: &str) -> Value {
+    json!({
+        "training_intensity": intensity,
+        "weight_kg": 70.0,
+        "height_cm": 175.0,
+        "age": 30,
+        "gender": "male",
+    })
+}
+
+#[tokio::test]
+async fn test_high_intensity_day_suggests_higher_carb_recipes_than_rest_day() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+
+    let manager = resources.recipe_manager().unwrap();
+    for saved in [
+        recipe(user_id, "Pasta Bowl", nutrition(740.0, 30.0, 105.0, 20.0)),
+        recipe(user_id, "Chicken Salad", nutrition(400.0, 20.0, 30.0, 22.0)),
+        // Written for before a session, so never offered on a rest day
+        recipe(user_id, "Rice Cakes", nutrition(420.0, 8.0, 90.0, 3.0))
+            .with_meal_timing(MealTiming::PreTraining),
+    ] {
+        manager
+            .create_recipe(user_id, tenant_id, &saved)
+            .await
+            .unwrap();
+    }
+
+    let context = ToolExecutionContext::new(user_id, Arc::clone(&resources), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+
+    let rest = SuggestRecipesTool
+        .execute(athlete("rest"), &context)
+        .await
+        .unwrap();
+    let high = SuggestRecipesTool
+        .execute(athlete("high"), &context)
+        .await
+        .unwrap();
+    assert!(!rest.is_error, "{}", rest.content);
+    assert!(!high.is_error, "{}", high.content);
+
+    assert_eq!(rest.content["meal_timing"], "rest_day");
+    assert_eq!(high.content["meal_timing"], "post_training");
+    assert_eq!(rest.content["considered"], 3);
+    assert_eq!(rest.content["count"], 2);
+
+    let carbs = |result: &Value, key: &str| result[key]["carbs_g"].as_f64().unwrap();
+    assert!(carbs(&high.content, "daily_needs") > carbs(&rest.content, "daily_needs"));
+    assert!(carbs(&high.content, "meal_targets") > carbs(&rest.content, "meal_targets"));
+
+    let top_carbs =
+        |result: &Value| result["suggestions"][0]["nutrition_per_serving"]["carbs_g"].as_f64();
+    assert_eq!(rest.content["suggestions"][0]["name"], "Chicken Salad");
+    assert_eq!(high.content["suggestions"][0]["name"], "Pasta Bowl");
+    assert!(top_carbs(&high.content).unwrap() > top_carbs(&rest.content).unwrap());
+
+    // Explicit daily targets replace the calculated needs
+    let mut low_carb = athlete("high");
+    low_carb["macro_targets"] = json!({"protein_g": 140.0, "carbs_g": 120.0, "fat_g": 110.0});
+    let result = SuggestRecipesTool
+        .execute(low_carb, &context)
+        .await
+        .unwrap();
+    assert_eq!(result.content["daily_needs"]["carbs_g"], 120.0);
+    assert_eq!(result.content["suggestions"][0]["name"], "Chicken Salad");
+
+    // Nothing saved is tagged vegan
+    let mut vegan = athlete("high");
+    vegan["dietary_restrictions"] = json!(["vegan"]);
+    let result = SuggestRecipesTool.execute(vegan, &context).await.unwrap();
+    assert_eq!(result.content["count"], 0);
+}
+
+#[test]
+fn test_restriction_and_skill_filters() {
+    let user_id = Uuid::new_v4();
+    let bowl = recipe(user_id, "Tofu Bowl", nutrition(500.0, 25.0, 15.0, 30.0)).with_tag("vegan");
+
+    assert!(satisfies_restriction(&bowl, &DietaryRestriction::Vegan));
+    assert!(satisfies_restriction(
+        &bowl,
+        &DietaryRestriction::Vegetarian
+    ));
+    assert!(satisfies_restriction(&bowl, &DietaryRestriction::Keto));
+    assert!(satisfies_restriction(&bowl, &DietaryRestriction::LowSodium));
+    assert!(!satisfies_restriction(
+        &bowl,
+        &DietaryRestriction::GlutenFree
+    ));
+    assert!(!satisfies_restriction(
+        &Recipe::new(user_id, "Unvalidated", 1),
+        &DietaryRestriction::LowSugar
+    ));
+
+    assert_eq!(required_skill_level(&bowl), SkillLevel::Beginner);
+    let braise = Recipe::new(user_id, "Braise", 4)
+        .with_prep_time(30)
+        .with_cook_time(180);
+    assert_eq!(required_skill_level(&braise), SkillLevel::Advanced);
+    assert_eq!(
+        required_skill_level(&braise.with_tag("intermediate")),
+        SkillLevel::Intermediate
+    );
+}
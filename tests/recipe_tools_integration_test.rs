@@ -1,12 +1,12 @@
 // ABOUTME: Integration tests for recipe MCP tool handlers ("Combat des Chefs" architecture)
-// ABOUTME: Tests get_recipe_constraints, validate_recipe, save_recipe, list_recipes, get_recipe, delete_recipe, search_recipes
+// ABOUTME: Tests get_recipe_constraints, validate_recipe, save_recipe, list_recipes, get_recipe, delete_recipe, search_recipes, suggest_recipes
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // Copyright (c) 2025 Pierre Fitness Intelligence
 
 //! Recipe Tool Handler Integration Tests
 //!
-//! Tests the 7 recipe MCP tools via the `UniversalToolExecutor`:
+//! Tests the 8 recipe MCP tools via the `UniversalToolExecutor`:
 //! - `get_recipe_constraints`: Get macro targets for LLM recipe generation
 //! - `validate_recipe`: Validate recipe nutrition via USDA
 //! - `save_recipe`: Save recipe to user's collection
@@ -14,6 +14,7 @@
 //! - `get_recipe`: Get specific recipe by ID
 //! - `delete_recipe`: Delete recipe from collection
 //! - `search_recipes`: Search recipes by name/tags/description
+//! - `suggest_recipes`: Rank saved recipes for the day's training
 
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
@@ -169,6 +170,7 @@ async fn test_recipe_tools_registered() -> Result<()> {
         "get_recipe",
         "delete_recipe",
         "search_recipes",
+        "suggest_recipes",
     ];
 
     for expected_tool in expected_tools {
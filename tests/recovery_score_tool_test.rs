@@ -0,0 +1,145 @@
+// ABOUTME: Integration tests for the get_recovery_score tool using the synthetic sleep provider
+// ABOUTME: Verifies recovery categories, readiness, rest day advice, and the no-HRV weighting path
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![cfg(feature = "provider-synthetic")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{Duration, Utc};
+use pierre_mcp_server::models::SleepSession;
+use pierre_mcp_server::providers::core::FitnessProvider;
+use pierre_mcp_server::providers::synthetic_sleep_provider::{
+    SleepAnomaly, SyntheticSleepConfig, SyntheticSleepProvider,
+};
+use pierre_mcp_server::tools::implementations::sleep::{
+    build_recovery_score_result, GetRecoveryScoreTool,
+};
+use pierre_mcp_server::tools::traits::McpTool;
+use serde_json::Value;
+
+/// 8.5 hours a night with textbook stage proportions and no noise
+fn well_rested_config() -> SyntheticSleepConfig {
+    SyntheticSleepConfig {
+        total_sleep_minutes: 510,
+        sleep_jitter_minutes: 0,
+        rmssd_noise_ms: 0.0,
+        ..SyntheticSleepConfig::default()
+    }
+}
+
+/// Fetch the past week of sleep the same way the tool does
+async fn last_week_of_sleep(config: &SyntheticSleepConfig) -> Vec<SleepSession> {
+    let provider = SyntheticSleepProvider::new(config).unwrap();
+    let end_date = Utc::now();
+    provider
+        .get_sleep_sessions(end_date - Duration::days(7), end_date)
+        .await
+        .unwrap()
+}
+
+fn recovery_result(sessions: &[SleepSession]) -> Value {
+    let result =
+        build_recovery_score_result(sessions, &[], None, "synthetic", "synthetic_sleep").unwrap();
+    assert!(!result.is_error, "unexpected error: {}", result.content);
+    result.content
+}
+
+#[tokio::test]
+async fn test_well_rested_week_with_rising_hrv_is_excellent() {
+    let sessions = last_week_of_sleep(&SyntheticSleepConfig {
+        rmssd_nightly_change_ms: 5.0,
+        ..well_rested_config()
+    })
+    .await;
+    assert_eq!(sessions.len(), 7);
+
+    let content = recovery_result(&sessions);
+    let score = &content["recovery_score"];
+
+    // TSB 0 scores 85, perfect sleep 100, recovered HRV 100: 0.4*85 + 0.4*100 + 0.2*100
+    assert!((score["overall_score"].as_f64().unwrap() - 94.0).abs() < 0.5);
+    assert_eq!(score["recovery_category"], "excellent");
+    assert_eq!(score["training_readiness"], "ready_for_hard");
+    assert_eq!(score["data_completeness"], "full");
+    assert_eq!(score["components"]["components_available"], 3);
+    assert_eq!(content["hrv_status"], "recovered");
+    assert_eq!(content["rest_day"]["rest_recommended"], false);
+    assert_eq!(content["training_load"]["tsb"], 0.0);
+    assert_eq!(
+        content["providers_used"]["sleep_provider"],
+        "synthetic_sleep"
+    );
+}
+
+#[tokio::test]
+async fn test_short_broken_last_night_needs_rest() {
+    let sessions = last_week_of_sleep(&SyntheticSleepConfig {
+        sleep_jitter_minutes: 0,
+        rmssd_noise_ms: 0.0,
+        anomalies: vec![SleepAnomaly {
+            night: 6,
+            sleep_loss_minutes: 270,
+            hrv_drop_percent: 50.0,
+            restorative_sleep_factor: 0.2,
+            extra_wake_count: 6,
+        }],
+        ..SyntheticSleepConfig::default()
+    })
+    .await;
+
+    let content = recovery_result(&sessions);
+    let score = &content["recovery_score"];
+
+    // Three hours of fragmented sleep and suppressed HRV drag a fresh TSB down to fair
+    assert_eq!(score["recovery_category"], "fair");
+    assert_eq!(score["training_readiness"], "rest_needed");
+    assert_eq!(content["hrv_status"], "fatigued");
+    assert_eq!(content["rest_day"]["rest_recommended"], true);
+    assert!((content["sleep"]["duration_hours"].as_f64().unwrap() - 3.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_missing_hrv_uses_no_hrv_weighting() {
+    let mut sessions = last_week_of_sleep(&well_rested_config()).await;
+    for session in &mut sessions {
+        session.hrv_during_sleep = None;
+    }
+
+    let content = recovery_result(&sessions);
+    let score = &content["recovery_score"];
+
+    // No-HRV weights: 0.5*85 (TSB) + 0.5*100 (sleep)
+    assert!((score["overall_score"].as_f64().unwrap() - 92.5).abs() < 0.5);
+    assert_eq!(score["data_completeness"], "partial");
+    assert_eq!(score["components"]["components_available"], 2);
+    assert!(score["components"]["hrv_score"].is_null());
+    assert!(content["hrv_status"].is_null());
+    assert!(!score["limitations"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_no_sleep_sessions_is_an_error_result() {
+    let result =
+        build_recovery_score_result(&[], &[], None, "synthetic", "synthetic_sleep").unwrap();
+
+    assert!(result.is_error);
+    assert!(result.content["error"]
+        .as_str()
+        .unwrap()
+        .contains("No sleep data"));
+}
+
+#[test]
+fn test_tool_metadata() {
+    let tool = GetRecoveryScoreTool;
+    assert_eq!(tool.name(), "get_recovery_score");
+
+    let schema = tool.input_schema();
+    let properties = schema.properties.unwrap();
+    assert!(properties.contains_key("sleep_provider"));
+    assert!(properties.contains_key("baseline_hrv"));
+    assert!(schema.required.is_none());
+}
@@ -5,8 +5,9 @@
 //!
 //! Tests the request ID middleware functionality including:
 //! - UUID generation for each request
+//! - Reuse of a client-supplied `X-Request-Id`
 //! - Request ID propagation through request/response lifecycle
-//! - Request ID availability in handlers via extensions
+//! - Request ID availability in handlers, log events and JSON-RPC envelopes
 
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
@@ -14,12 +15,25 @@ use axum::{
     body::{to_bytes, Body},
     http::{Request as HttpRequest, StatusCode},
     middleware,
+    response::Response,
     routing::get,
-    Extension, Router,
+    Extension, Json, Router,
 };
-use pierre_mcp_server::middleware::request_id::{request_id_middleware, RequestId};
+use pierre_mcp_server::jsonrpc::{JsonRpcResponse, REQUEST_ID_METADATA_KEY};
+use pierre_mcp_server::logging::{JsonEventFormat, RequestContextLayer};
+use pierre_mcp_server::middleware::request_id::{
+    current_request_id, request_id_middleware, RequestId,
+};
+use pierre_mcp_server::models::{AuditEvent, AuditEventType, AuditSeverity};
+use serde_json::{json, Value};
 use std::error::Error;
+use std::io;
+use std::sync::{Arc, Mutex};
 use tower::ServiceExt;
+use tracing::info;
+use tracing::subscriber::set_default;
+use tracing_subscriber::fmt::{self, MakeWriter};
+use tracing_subscriber::layer::SubscriberExt;
 use uuid::Uuid;
 
 const REQUEST_ID_HEADER: &str = "x-request-id";
@@ -28,6 +42,52 @@ async fn test_handler(Extension(request_id): Extension<RequestId>) -> String {
     format!("Request ID: {}", request_id.as_str())
 }
 
+async fn logging_handler() -> &'static str {
+    info!("handling request");
+    "ok"
+}
+
+async fn jsonrpc_handler() -> Json<JsonRpcResponse> {
+    Json(JsonRpcResponse::success(Some(json!(1)), json!({"ok": true})).with_current_request_id())
+}
+
+fn app_with(route: Router) -> Router {
+    route.layer(middleware::from_fn(request_id_middleware))
+}
+
+fn response_request_id(response: &Response) -> String {
+    response
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .expect("request ID header present")
+        .to_str()
+        .unwrap()
+        .to_owned()
+}
+
+/// In-memory writer capturing formatted log output
+#[derive(Clone, Default)]
+struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for CapturedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedOutput {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
 #[tokio::test]
 async fn test_request_id_middleware_generates_id() -> Result<(), Box<dyn Error>> {
     let app = Router::new()
@@ -73,3 +133,132 @@ async fn test_request_id_available_in_handler() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_incoming_request_id_is_echoed() -> Result<(), Box<dyn Error>> {
+    let app = app_with(Router::new().route("/", get(test_handler)));
+
+    let request = HttpRequest::builder()
+        .uri("/")
+        .header(REQUEST_ID_HEADER, "frontend-7f3a9c21")
+        .body(Body::empty())?;
+
+    let response = app.oneshot(request).await?;
+
+    assert_eq!(response_request_id(&response), "frontend-7f3a9c21");
+    let body = to_bytes(response.into_body(), usize::MAX).await?;
+    assert_eq!(
+        String::from_utf8(body.to_vec())?,
+        "Request ID: frontend-7f3a9c21"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unusable_incoming_request_id_is_replaced() -> Result<(), Box<dyn Error>> {
+    let too_long = "a".repeat(200);
+    for incoming in ["", "has spaces", "line\"break", too_long.as_str()] {
+        let app = app_with(Router::new().route("/", get(test_handler)));
+        let request = HttpRequest::builder()
+            .uri("/")
+            .header(REQUEST_ID_HEADER, incoming)
+            .body(Body::empty())?;
+
+        let response = app.oneshot(request).await?;
+
+        let request_id = response_request_id(&response);
+        assert!(
+            Uuid::parse_str(&request_id).is_ok(),
+            "{incoming:?} should be replaced by a generated UUID, got {request_id}"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_request_id_appears_in_log_lines() -> Result<(), Box<dyn Error>> {
+    let output = CapturedOutput::default();
+    let subscriber = tracing_subscriber::registry()
+        .with(RequestContextLayer)
+        .with(
+            fmt::layer()
+                .event_format(JsonEventFormat::default())
+                .with_writer(output.clone()),
+        );
+    let _guard = set_default(subscriber);
+
+    let app = app_with(Router::new().route("/", get(logging_handler)));
+    let request = HttpRequest::builder()
+        .uri("/")
+        .header(REQUEST_ID_HEADER, "trace-logged-1")
+        .body(Body::empty())?;
+
+    let response = app.oneshot(request).await?;
+    assert_eq!(response_request_id(&response), "trace-logged-1");
+
+    let captured = String::from_utf8(output.0.lock().unwrap().clone())?;
+    let line = captured
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .find(|line| line["message"] == "handling request")
+        .expect("handler log line captured");
+    assert_eq!(line["request_id"], "trace-logged-1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_jsonrpc_response_carries_request_id() -> Result<(), Box<dyn Error>> {
+    let app = app_with(Router::new().route("/", get(jsonrpc_handler)));
+    let request = HttpRequest::builder().uri("/").body(Body::empty())?;
+
+    let response = app.oneshot(request).await?;
+    let header_id = response_request_id(&response);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await?;
+    let envelope: Value = serde_json::from_slice(&body)?;
+    assert_eq!(envelope["metadata"][REQUEST_ID_METADATA_KEY], header_id);
+
+    // Outside the middleware there is no request ID to attach
+    assert!(current_request_id().is_none());
+    let detached = JsonRpcResponse::success(Some(json!(2)), json!({})).with_current_request_id();
+    assert!(detached.metadata.is_empty());
+    assert!(serde_json::to_value(&detached)?.get("metadata").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_event_metadata_records_request_id() {
+    let event = AuditEvent::new(
+        AuditEventType::SecurityPolicyViolation,
+        AuditSeverity::Warning,
+        "Rate limit exceeded".to_owned(),
+        "rate_limit".to_owned(),
+        "observed".to_owned(),
+    );
+
+    let tagged = event
+        .clone()
+        .with_metadata(json!({"tier": "starter"}))
+        .with_request_id("req-1");
+    assert_eq!(
+        tagged.metadata,
+        json!({"tier": "starter", "request_id": "req-1"})
+    );
+
+    let empty = event.clone().with_request_id("req-2");
+    assert_eq!(empty.metadata, json!({"request_id": "req-2"}));
+
+    // An ID already present is not overwritten
+    let kept = tagged.with_request_id("req-3");
+    assert_eq!(kept.metadata["request_id"], "req-1");
+
+    let scalar = event.with_metadata(json!("note")).with_request_id("req-4");
+    assert_eq!(
+        scalar.metadata,
+        json!({"value": "note", "request_id": "req-4"})
+    );
+}
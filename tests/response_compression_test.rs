@@ -0,0 +1,194 @@
+// ABOUTME: Tests for Accept-Encoding response compression negotiation on the HTTP layer
+// ABOUTME: Verifies gzip/deflate round trips, Vary headers, size thresholds, and codec configuration
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::io::Read;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{
+        header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE, VARY},
+        Request, Response,
+    },
+    routing::get,
+    Json, Router,
+};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use pierre_mcp_server::config::mcp::CompressionConfig;
+use pierre_mcp_server::constants::limits;
+use pierre_mcp_server::middleware::{setup_compression, CompressionCodec};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+const MIN_SIZE: usize = 1024;
+
+/// A `get_activities`-style payload well above the test threshold
+fn large_payload() -> Value {
+    let activities: Vec<Value> = (0..200)
+        .map(|i| {
+            json!({
+                "id": format!("activity-{i}"),
+                "name": "Morning Run",
+                "sport_type": "run",
+                "distance_meters": 10_000.0,
+                "duration_seconds": 3000,
+            })
+        })
+        .collect();
+    json!({ "activities": activities })
+}
+
+fn app(config: &CompressionConfig) -> Router {
+    Router::new()
+        .route("/large", get(|| async { Json(large_payload()) }))
+        .route("/small", get(|| async { Json(json!({ "status": "ok" })) }))
+        .route(
+            "/archive",
+            get(|| async {
+                (
+                    [(CONTENT_TYPE, "application/gzip")],
+                    vec![0_u8; MIN_SIZE * 4],
+                )
+            }),
+        )
+        .layer(setup_compression(config))
+}
+
+fn test_config() -> CompressionConfig {
+    CompressionConfig {
+        min_size_bytes: MIN_SIZE,
+        codecs: CompressionCodec::ALL.to_vec(),
+    }
+}
+
+async fn get_with_encoding(
+    config: &CompressionConfig,
+    path: &str,
+    accept_encoding: Option<&str>,
+) -> Response<Body> {
+    let mut request = Request::builder().uri(path);
+    if let Some(encoding) = accept_encoding {
+        request = request.header(ACCEPT_ENCODING, encoding);
+    }
+    app(config)
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body_bytes(response: Response<Body>) -> Vec<u8> {
+    to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn test_gzip_round_trip_sets_content_encoding_and_vary() {
+    let response = get_with_encoding(&test_config(), "/large", Some("gzip")).await;
+
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+
+    let compressed = body_bytes(response).await;
+    let mut decompressed = String::new();
+    GzDecoder::new(compressed.as_slice())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert!(compressed.len() < decompressed.len());
+    assert_eq!(
+        serde_json::from_str::<Value>(&decompressed).unwrap(),
+        large_payload()
+    );
+}
+
+#[tokio::test]
+async fn test_deflate_round_trip() {
+    let response = get_with_encoding(&test_config(), "/large", Some("deflate")).await;
+
+    assert_eq!(response.headers()[CONTENT_ENCODING], "deflate");
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+
+    let compressed = body_bytes(response).await;
+    let mut decompressed = String::new();
+    ZlibDecoder::new(compressed.as_slice())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&decompressed).unwrap(),
+        large_payload()
+    );
+}
+
+#[tokio::test]
+async fn test_brotli_preferred_when_client_ranks_it_highest() {
+    let response = get_with_encoding(&test_config(), "/large", Some("gzip;q=0.5, br;q=1.0")).await;
+
+    assert_eq!(response.headers()[CONTENT_ENCODING], "br");
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+}
+
+#[tokio::test]
+async fn test_identity_when_client_does_not_accept_encoding() {
+    let response = get_with_encoding(&test_config(), "/large", None).await;
+
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    // Caches must still key on Accept-Encoding for a compressible response
+    assert_eq!(response.headers()[VARY], "accept-encoding");
+
+    let body = body_bytes(response).await;
+    assert_eq!(
+        serde_json::from_slice::<Value>(&body).unwrap(),
+        large_payload()
+    );
+}
+
+#[tokio::test]
+async fn test_small_and_already_compressed_payloads_are_not_compressed() {
+    let small = get_with_encoding(&test_config(), "/small", Some("gzip")).await;
+    assert!(!small.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(
+        serde_json::from_slice::<Value>(&body_bytes(small).await).unwrap(),
+        json!({ "status": "ok" })
+    );
+
+    let archive = get_with_encoding(&test_config(), "/archive", Some("gzip")).await;
+    assert!(!archive.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(body_bytes(archive).await.len(), MIN_SIZE * 4);
+}
+
+#[tokio::test]
+async fn test_only_enabled_codecs_are_negotiated() {
+    let gzip_only = CompressionConfig {
+        codecs: vec![CompressionCodec::Gzip],
+        ..test_config()
+    };
+    let response = get_with_encoding(&gzip_only, "/large", Some("br, gzip;q=0.5")).await;
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+
+    let disabled = CompressionConfig {
+        codecs: Vec::new(),
+        ..test_config()
+    };
+    let response = get_with_encoding(&disabled, "/large", Some("gzip, br")).await;
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+}
+
+#[test]
+fn test_default_threshold_is_a_tenth_of_max_response_size() {
+    let config = CompressionConfig::default();
+    assert_eq!(config.min_size_bytes, limits::MAX_RESPONSE_SIZE / 10);
+    assert_eq!(config.codecs, CompressionCodec::ALL);
+
+    assert_eq!(
+        "Brotli".parse::<CompressionCodec>().unwrap(),
+        CompressionCodec::Br
+    );
+    assert!("zstd".parse::<CompressionCodec>().is_err());
+}
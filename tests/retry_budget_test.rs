@@ -0,0 +1,111 @@
+// ABOUTME: Tests for the per-tenant, per-provider retry budget shared by provider retry loops
+// ABOUTME: Drains the budget with failing calls, checks retries stop, then resume once tokens refill
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use pierre_mcp_server::metrics::{
+    MetricsRegistry, RETRY_BUDGET_EXHAUSTED_TOTAL, RETRY_BUDGET_REMAINING,
+};
+use pierre_mcp_server::models::TenantId;
+use pierre_mcp_server::providers::errors::{ProviderError, ProviderResult};
+use pierre_mcp_server::providers::retry_budget::{RetryBudget, RetryBudgetConfig};
+use pierre_mcp_server::providers::utils::{with_budgeted_retry, RetryBackoffConfig};
+
+const PROVIDER: &str = "strava";
+
+/// Three retries per call, without backoff delays
+const NO_DELAY: RetryBackoffConfig = RetryBackoffConfig::new(3, 0, 0);
+
+/// Run one call that always fails with a retryable error; returns how often it was attempted
+async fn failing_call(budget: &RetryBudget, tenant_id: TenantId) -> u32 {
+    let attempts = AtomicU32::new(0);
+    let result: ProviderResult<()> =
+        with_budgeted_retry("outage", &NO_DELAY, budget, tenant_id, PROVIDER, || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(ProviderError::NetworkError("connection reset".to_owned())) }
+        })
+        .await;
+    assert!(matches!(result, Err(ProviderError::NetworkError(_))));
+    attempts.into_inner()
+}
+
+#[tokio::test]
+async fn test_retries_stop_when_budget_drains_and_resume_after_refill() {
+    let budget = RetryBudget::new(RetryBudgetConfig::new(10, 20.0));
+    let tenant_id = TenantId::new();
+
+    // Many failing calls: the first ones retry until the ten tokens are spent
+    let mut attempts = Vec::new();
+    for _ in 0..8 {
+        attempts.push(failing_call(&budget, tenant_id).await);
+    }
+    assert_eq!(attempts[..3], [4, 4, 4]);
+    assert_eq!(attempts[3], 2, "one token left for the fourth call");
+    assert!(
+        attempts[4..].iter().all(|&count| count == 1),
+        "an empty budget must fail fast: {attempts:?}"
+    );
+    assert_eq!(budget.remaining(tenant_id, PROVIDER), 0);
+
+    let tenant = tenant_id.0.to_string();
+    let labels = [("tenant", tenant.as_str()), ("provider", PROVIDER)];
+    let metrics = MetricsRegistry::global();
+    assert_eq!(
+        metrics.counter_value(RETRY_BUDGET_EXHAUSTED_TOTAL, &labels),
+        5
+    );
+    assert!(metrics
+        .gauge_value(RETRY_BUDGET_REMAINING, &labels)
+        .is_some_and(|remaining| remaining < 1.0));
+
+    // At 20 tokens per second the bucket is back above three tokens after 250ms
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert!(budget.remaining(tenant_id, PROVIDER) >= 3);
+    assert_eq!(failing_call(&budget, tenant_id).await, 4);
+    assert!(metrics
+        .gauge_value(RETRY_BUDGET_REMAINING, &labels)
+        .is_some_and(|remaining| remaining < 10.0));
+}
+
+#[tokio::test]
+async fn test_retry_budget_is_separate_per_tenant_and_provider() {
+    let budget = RetryBudget::new(RetryBudgetConfig::new(2, 0.0));
+    let drained = TenantId::new();
+    let other = TenantId::new();
+
+    assert_eq!(failing_call(&budget, drained).await, 3);
+    assert_eq!(failing_call(&budget, drained).await, 1);
+    assert_eq!(budget.remaining(drained, PROVIDER), 0);
+
+    // Neither another tenant nor another provider of the same tenant is affected
+    assert_eq!(budget.remaining(other, PROVIDER), 2);
+    assert_eq!(budget.remaining(drained, "fitbit"), 2);
+    assert_eq!(failing_call(&budget, other).await, 3);
+}
+
+#[tokio::test]
+async fn test_successful_calls_do_not_spend_budget() {
+    let budget = RetryBudget::new(RetryBudgetConfig::new(1, 0.0));
+    let tenant_id = TenantId::new();
+
+    for _ in 0..5 {
+        let result = with_budgeted_retry(
+            "healthy",
+            &NO_DELAY,
+            &budget,
+            tenant_id,
+            PROVIDER,
+            || async { Ok::<_, ProviderError>("ok") },
+        )
+        .await;
+        assert_eq!(result.unwrap(), "ok");
+    }
+    assert_eq!(budget.remaining(tenant_id, PROVIDER), 1);
+}
@@ -728,6 +728,153 @@ async fn test_provision_api_key_malformed_json() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_bulk_provision_api_keys_partial_failure() -> Result<()> {
+    let setup = AdminTestSetup::new().await?;
+    let routes = setup.routes();
+
+    let first = create_approved_user(&setup.context.database, "cohort1@example.com").await?;
+    let second = create_approved_user(&setup.context.database, "cohort2@example.com").await?;
+
+    let request_body = json!({
+        "keys": [
+            {
+                "user_email": first.email,
+                "tier": "starter",
+                "rate_limit": { "requests": 500, "period": "day" }
+            },
+            { "user_email": "not-an-email", "tier": "starter" },
+            { "user_email": second.email, "tier": "professional" }
+        ]
+    });
+
+    let response = AxumTestRequest::post("/admin/api-keys/bulk")
+        .header(
+            "authorization",
+            &setup.auth_header(&setup.admin_token.jwt_token),
+        )
+        .header("content-type", "application/json")
+        .json(&request_body)
+        .send(routes.clone())
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    let body: Value = serde_json::from_slice(&response.bytes())?;
+    assert_eq!(body["success"], false);
+
+    // The invalid row is reported without blocking the valid ones
+    let errors = body["data"]["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["index"], 1);
+    assert_eq!(errors[0]["user_email"], "not-an-email");
+
+    let created = body["data"]["created"].as_array().unwrap();
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[0]["index"], 0);
+    assert_eq!(created[0]["user_email"], first.email);
+    assert_eq!(created[0]["rate_limit"]["requests"], 500);
+    assert_eq!(created[0]["rate_limit"]["period"], "day");
+    assert_eq!(created[1]["index"], 2);
+    assert_eq!(created[1]["tier"], "professional");
+    for key in created {
+        assert!(key["api_key"].as_str().unwrap().starts_with("pk_live_"));
+    }
+
+    for user in [&first, &second] {
+        let keys = setup.context.database.get_user_api_keys(user.id).await?;
+        assert_eq!(keys.len(), 1);
+    }
+
+    // Each created key goes through the provisioning audit path
+    let provisioned = setup
+        .context
+        .database
+        .get_admin_provisioned_keys(
+            Some(&setup.admin_token.token_id),
+            chrono::Utc::now() - chrono::Duration::hours(1),
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        )
+        .await?;
+    assert_eq!(provisioned.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_provision_api_keys_skips_existing_keys() -> Result<()> {
+    let setup = AdminTestSetup::new().await?;
+    let routes = setup.routes();
+
+    let provision = |skip_existing: bool| {
+        AxumTestRequest::post("/admin/api-keys/bulk")
+            .header(
+                "authorization",
+                &setup.auth_header(&setup.admin_token.jwt_token),
+            )
+            .header("content-type", "application/json")
+            .json(&json!({
+                "keys": [
+                    { "user_email": setup.user.email.clone(), "tier": "starter" },
+                    { "user_email": setup.user.email.clone(), "tier": "starter" }
+                ],
+                "skip_existing": skip_existing
+            }))
+            .send(routes.clone())
+    };
+
+    // The duplicate row in the same batch is skipped
+    let body: Value = serde_json::from_slice(&provision(true).await.bytes())?;
+    assert_eq!(body["success"], true);
+    let created = body["data"]["created"].as_array().unwrap();
+    assert_eq!(created.len(), 1);
+    let skipped = body["data"]["skipped"].as_array().unwrap();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0]["index"], 1);
+    assert_eq!(skipped[0]["existing_api_key_id"], created[0]["api_key_id"]);
+
+    // The active key from the first batch is skipped as well
+    let body: Value = serde_json::from_slice(&provision(true).await.bytes())?;
+    assert!(body["data"]["created"].as_array().unwrap().is_empty());
+    assert_eq!(body["data"]["skipped"].as_array().unwrap().len(), 2);
+
+    // Skipping can be turned off
+    let body: Value = serde_json::from_slice(&provision(false).await.bytes())?;
+    assert_eq!(body["data"]["created"].as_array().unwrap().len(), 2);
+
+    let keys = setup
+        .context
+        .database
+        .get_user_api_keys(setup.user_id)
+        .await?;
+    assert_eq!(keys.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_provision_api_keys_rejects_empty_batch() -> Result<()> {
+    let setup = AdminTestSetup::new().await?;
+    let routes = setup.routes();
+
+    let response = AxumTestRequest::post("/admin/api-keys/bulk")
+        .header(
+            "authorization",
+            &setup.auth_header(&setup.admin_token.jwt_token),
+        )
+        .header("content-type", "application/json")
+        .json(&json!({ "keys": [] }))
+        .send(routes.clone())
+        .await;
+
+    assert_eq!(response.status(), 400);
+
+    let body: Value = serde_json::from_slice(&response.bytes())?;
+    assert_eq!(body["success"], false);
+
+    Ok(())
+}
+
 // ============================================================================
 // API Key Revocation Tests
 // ============================================================================
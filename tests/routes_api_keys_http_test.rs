@@ -16,6 +16,7 @@
 mod common;
 mod helpers;
 
+use axum::middleware::from_fn_with_state;
 use helpers::axum_test::AxumTestRequest;
 use pierre_mcp_server::{
     config::environment::{
@@ -23,6 +24,8 @@ use pierre_mcp_server::{
         SecurityHeadersConfig, ServerConfig,
     },
     mcp::resources::{ServerResources, ServerResourcesOptions},
+    middleware::impersonation_read_only_layer,
+    models::User,
     routes::api_keys::ApiKeyRoutes,
 };
 use serde_json::json;
@@ -32,6 +35,7 @@ use std::sync::Arc;
 struct ApiKeyTestSetup {
     resources: Arc<ServerResources>,
     user_id: uuid::Uuid,
+    user: User,
     jwt_token: String,
 }
 
@@ -95,6 +99,7 @@ impl ApiKeyTestSetup {
         Ok(Self {
             resources,
             user_id,
+            user,
             jwt_token,
         })
     }
@@ -106,6 +111,29 @@ impl ApiKeyTestSetup {
     fn auth_header(&self) -> String {
         format!("Bearer {}", self.jwt_token)
     }
+
+    /// Routes behind the impersonation layer the HTTP server applies globally
+    fn routes_with_impersonation_layer(&self) -> axum::Router {
+        self.routes().layer(from_fn_with_state(
+            self.resources.clone(),
+            impersonation_read_only_layer,
+        ))
+    }
+
+    /// Authorization header for an admin impersonation token acting as the test user
+    fn impersonation_header(&self) -> String {
+        let (token, _claims) = self
+            .resources
+            .auth_manager
+            .generate_admin_impersonation_token(
+                &self.user,
+                "admin_support_console",
+                &self.resources.jwks_manager,
+                None,
+            )
+            .unwrap();
+        format!("Bearer {token}")
+    }
 }
 
 // ============================================================================
@@ -556,3 +584,53 @@ async fn test_user_isolation() {
     let keys = body["api_keys"].as_array().unwrap();
     assert_eq!(keys.len(), 0, "User 2 should not see User 1's keys");
 }
+
+// ============================================================================
+// Impersonation Token Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_impersonation_token_cannot_create_or_deactivate_api_keys() {
+    let setup = ApiKeyTestSetup::new().await.expect("Setup failed");
+    let impersonation = setup.impersonation_header();
+
+    let response = AxumTestRequest::post("/api/keys")
+        .header("authorization", &impersonation)
+        .json(&json!({ "name": "Impersonated Key", "rate_limit_requests": 1000 }))
+        .send(setup.routes_with_impersonation_layer())
+        .await;
+    assert_eq!(response.status(), 403);
+
+    let key = common::create_and_store_test_api_key(
+        setup.resources.database.as_ref(),
+        setup.user_id,
+        "Existing Key",
+    )
+    .await
+    .expect("Failed to create test key");
+    let response = AxumTestRequest::delete(&format!("/api/keys/{}", key.id))
+        .header("authorization", &impersonation)
+        .send(setup.routes_with_impersonation_layer())
+        .await;
+    assert_eq!(response.status(), 403);
+
+    // Reads still work, and nothing was created or deactivated
+    let response = AxumTestRequest::get("/api/keys")
+        .header("authorization", &impersonation)
+        .send(setup.routes_with_impersonation_layer())
+        .await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json();
+    let keys = body["api_keys"].as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["name"], "Existing Key");
+    assert_eq!(keys[0]["is_active"], true);
+
+    // The user's own token may still create keys
+    let response = AxumTestRequest::post("/api/keys")
+        .header("authorization", &setup.auth_header())
+        .json(&json!({ "name": "User Key", "rate_limit_requests": 1000 }))
+        .send(setup.routes_with_impersonation_layer())
+        .await;
+    assert_eq!(response.status(), 201);
+}
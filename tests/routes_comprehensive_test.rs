@@ -145,6 +145,7 @@ async fn create_test_oauth_routes() -> Result<(OAuthService, TenantId, Arc<Datab
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -158,6 +159,7 @@ async fn create_test_oauth_routes() -> Result<(OAuthService, TenantId, Arc<Datab
         provider: "fitbit".to_owned(),
         client_id: "test_fitbit_client_id".to_owned(),
         client_secret: "test_fitbit_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/fitbit".to_owned(),
         scopes: vec!["activity".to_owned(), "profile".to_owned()],
         rate_limit_per_day: 15000,
@@ -1762,6 +1764,7 @@ async fn test_complete_auth_flow() -> Result<()> {
         provider: "strava".to_owned(),
         client_id: "test_client_id".to_owned(),
         client_secret: "test_client_secret".to_owned(),
+        previous_client_secret: None,
         redirect_uri: "http://localhost:8080/oauth/callback/strava".to_owned(),
         scopes: vec!["read".to_owned(), "activity:read_all".to_owned()],
         rate_limit_per_day: 15000,
@@ -26,6 +26,7 @@ use pierre_mcp_server::{
     mcp::resources::{ServerResources, ServerResourcesOptions},
     models::{Tenant, TenantId, User},
     routes::tenants::TenantRoutes,
+    tenant::TenantOAuthCredentials,
 };
 use serde_json::json;
 use std::sync::Arc;
@@ -693,3 +694,112 @@ async fn test_switch_between_multiple_tenants() {
     assert_eq!(body2["active_tenant_id"], tenant2.id.to_string());
     assert_eq!(body2["tenant_name"], "Tenant Two");
 }
+
+// ============================================================================
+// POST /tenants/:tenant_id/oauth/:provider/rotate-secret - Secret Rotation Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_rotate_tenant_oauth_secret() {
+    let setup = TenantTestSetup::new().await.expect("Setup failed");
+    let routes = setup.routes();
+    let database = &setup.resources.database;
+
+    let tenant_id = database.list_tenants_for_user(setup.user_id).await.unwrap()[0].id;
+    database
+        .store_tenant_oauth_credentials(&TenantOAuthCredentials {
+            tenant_id,
+            provider: "strava".to_owned(),
+            client_id: "strava_client_id".to_owned(),
+            client_secret: "strava_secret_v1".to_owned(),
+            previous_client_secret: None,
+            redirect_uri: "http://localhost:8081/api/oauth/callback/strava".to_owned(),
+            scopes: vec!["read".to_owned()],
+            rate_limit_per_day: 1000,
+        })
+        .await
+        .unwrap();
+
+    let response = AxumTestRequest::post(&format!(
+        "/tenants/{}/oauth/strava/rotate-secret",
+        tenant_id
+    ))
+    .header("authorization", &setup.auth_header())
+    .json(&json!({ "client_secret": "strava_secret_v2", "grace_period_hours": 2 }))
+    .send(routes.clone())
+    .await;
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["provider"], "strava");
+    let expires_at =
+        chrono::DateTime::parse_from_rfc3339(body["previous_secret_expires_at"].as_str().unwrap())
+            .unwrap();
+    assert!(expires_at > chrono::Utc::now() + chrono::Duration::minutes(110));
+
+    let credentials = database
+        .get_tenant_oauth_credentials(tenant_id, "strava")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(credentials.client_secret, "strava_secret_v2");
+    assert_eq!(
+        credentials.previous_client_secret.as_deref(),
+        Some("strava_secret_v1")
+    );
+
+    // A provider without stored credentials cannot be rotated
+    let response = AxumTestRequest::post(&format!(
+        "/tenants/{}/oauth/fitbit/rotate-secret",
+        tenant_id
+    ))
+    .header("authorization", &setup.auth_header())
+    .json(&json!({ "client_secret": "fitbit_secret" }))
+    .send(routes)
+    .await;
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_rotate_tenant_oauth_secret_requires_owner() {
+    let setup = TenantTestSetup::new().await.expect("Setup failed");
+    let routes = setup.routes();
+
+    let other_user = User::new(
+        "rotation-owner@example.com".to_owned(),
+        "password_hash".to_owned(),
+        Some("Rotation Owner".to_owned()),
+    );
+    setup
+        .resources
+        .database
+        .create_user(&other_user)
+        .await
+        .expect("Failed to create other user");
+    let tenant = Tenant {
+        id: TenantId::new(),
+        name: "Rotation Owner Tenant".to_owned(),
+        slug: "rotation-owner-tenant".to_owned(),
+        domain: None,
+        plan: "starter".to_owned(),
+        owner_user_id: other_user.id,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    };
+    setup
+        .resources
+        .database
+        .create_tenant(&tenant)
+        .await
+        .expect("Failed to create tenant");
+
+    let response = AxumTestRequest::post(&format!(
+        "/tenants/{}/oauth/strava/rotate-secret",
+        tenant.id
+    ))
+    .header("authorization", &setup.auth_header())
+    .json(&json!({ "client_secret": "stolen_secret" }))
+    .send(routes)
+    .await;
+    assert_eq!(response.status(), 403);
+}
@@ -67,11 +67,16 @@ async fn test_rsa_keypair_persistence() -> Result<()> {
     let loaded_keys = database.load_rsa_keypairs().await?;
 
     assert_eq!(loaded_keys.len(), 1, "Should have exactly one key");
-    let (loaded_kid, loaded_private, loaded_public, _, loaded_active) = &loaded_keys[0];
+    let (loaded_kid, loaded_private, loaded_public, _, loaded_active, loaded_retires_at) =
+        &loaded_keys[0];
     assert_eq!(loaded_kid, &kid, "Key ID should match");
     assert_eq!(loaded_private, &private_pem, "Private key PEM should match");
     assert_eq!(loaded_public, &public_pem, "Public key PEM should match");
     assert!(*loaded_active, "Key should be marked as active");
+    assert!(
+        loaded_retires_at.is_none(),
+        "Active key should not be retiring"
+    );
 
     Ok(())
 }
@@ -0,0 +1,104 @@
+// ABOUTME: Tests for the schema version guard that stops older binaries opening newer databases
+// ABOUTME: Simulates a migration applied by a future release and checks startup is refused
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use anyhow::Result;
+use pierre_mcp_server::database::Database;
+use pierre_mcp_server::database_plugins::{DatabaseProvider, SchemaVersion};
+use tempfile::TempDir;
+
+/// Far beyond any migration shipped with this binary
+const FUTURE_VERSION: i64 = 99_991_231_000_001;
+
+fn database_url(dir: &TempDir) -> String {
+    format!("sqlite:{}", dir.path().join("schema.db").display())
+}
+
+/// Record a migration as if a newer release had applied it
+async fn insert_future_migration(database: &Database) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+         VALUES (?, 'future_release_columns', 1, X'00', 0)",
+    )
+    .bind(FUTURE_VERSION)
+    .execute(database.pool())
+    .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fresh_database_reports_matching_versions() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let database = Database::new(&database_url(&dir), vec![0u8; 32]).await?;
+
+    let version = database.schema_version().await?;
+    assert!(version.supported.is_some());
+    assert_eq!(version.applied, version.supported);
+    version.ensure_supported()?;
+
+    let json = serde_json::to_value(version)?;
+    assert_eq!(json["applied"], json["supported"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_startup_refuses_schema_newer_than_binary() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let url = database_url(&dir);
+    let database = Database::new(&url, vec![0u8; 32]).await?;
+    let supported = database.schema_version().await?.supported;
+    insert_future_migration(&database).await?;
+
+    let version = database.schema_version().await?;
+    assert_eq!(version.applied, Some(FUTURE_VERSION));
+    assert_eq!(version.supported, supported);
+
+    // Re-running migrations on the live pool is refused
+    let err = database.migrate().await.unwrap_err().to_string();
+    assert!(err.contains("newer than this binary supports"), "{err}");
+    database.pool().close().await;
+
+    // So is opening the database again, as an older binary would at startup
+    let Err(err) = Database::new(&url, vec![0u8; 32]).await else {
+        panic!("startup must fail against a newer schema");
+    };
+    let err = err.to_string();
+    assert!(
+        err.contains(&FUTURE_VERSION.to_string()),
+        "error should name the database version: {err}"
+    );
+    assert!(
+        err.contains(&supported.unwrap().to_string()),
+        "error should name the supported version: {err}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unversioned_or_older_schemas_are_accepted() {
+    let cases = [
+        (None, Some(20_260_219_000_001)),
+        (Some(20_260_101_000_001), Some(20_260_219_000_001)),
+        (Some(20_260_219_000_001), Some(20_260_219_000_001)),
+        (None, None),
+    ];
+    for (applied, supported) in cases {
+        SchemaVersion { applied, supported }
+            .ensure_supported()
+            .unwrap();
+    }
+
+    assert!(SchemaVersion {
+        applied: Some(20_260_301_000_001),
+        supported: Some(20_260_219_000_001),
+    }
+    .ensure_supported()
+    .is_err());
+}
@@ -5,10 +5,28 @@
 #![allow(missing_docs)]
 
 use axum::http::{header, HeaderMap};
+use pierre_mcp_server::config::{Environment, SessionCookieConfig};
 use pierre_mcp_server::security::cookies::{
-    get_cookie_value, set_auth_cookie, set_csrf_cookie, SecureCookieConfig,
+    clear_auth_cookie, get_cookie_value, oauth_session_cookie, set_auth_cookie, set_csrf_cookie,
+    SameSitePolicy, SecureCookieConfig,
 };
 
+fn set_cookie_header(headers: &HeaderMap) -> anyhow::Result<&str> {
+    Ok(headers
+        .get(header::SET_COOKIE)
+        .ok_or_else(|| anyhow::anyhow!("Cookie header should be set"))?
+        .to_str()?)
+}
+
+fn cross_subdomain_config() -> SessionCookieConfig {
+    SessionCookieConfig {
+        same_site: SameSitePolicy::None,
+        domain: Some("example.com".to_owned()),
+        path: "/api".to_owned(),
+        ..SessionCookieConfig::default()
+    }
+}
+
 #[test]
 fn test_secure_cookie_config() {
     let config = SecureCookieConfig::new("test".to_owned(), "value".to_owned(), 3600);
@@ -41,12 +59,14 @@ fn test_secure_cookie_config() {
 #[test]
 fn test_auth_cookie() -> anyhow::Result<()> {
     let mut headers = HeaderMap::new();
-    set_auth_cookie(&mut headers, "test_token", 3600);
+    set_auth_cookie(
+        &mut headers,
+        "test_token",
+        3600,
+        &SessionCookieConfig::default(),
+    );
 
-    let cookie_header = headers
-        .get(header::SET_COOKIE)
-        .ok_or_else(|| anyhow::anyhow!("Cookie header should be set"))?
-        .to_str()?;
+    let cookie_header = set_cookie_header(&headers)?;
 
     assert!(
         cookie_header.contains("auth_token=test_token"),
@@ -60,18 +80,129 @@ fn test_auth_cookie() -> anyhow::Result<()> {
         cookie_header.contains("Secure"),
         "Auth cookie should be Secure"
     );
+    assert!(
+        cookie_header.contains("SameSite=Lax"),
+        "Auth cookie should default to SameSite=Lax"
+    );
+    assert!(
+        !cookie_header.contains("Domain="),
+        "Auth cookie should be host-only by default"
+    );
     Ok(())
 }
 
+#[test]
+fn test_auth_cookie_reflects_configured_attributes() -> anyhow::Result<()> {
+    let mut headers = HeaderMap::new();
+    set_auth_cookie(&mut headers, "test_token", 3600, &cross_subdomain_config());
+
+    assert_eq!(
+        set_cookie_header(&headers)?,
+        "auth_token=test_token; Max-Age=3600; Path=/api; Domain=example.com; HttpOnly; Secure; SameSite=None"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_clear_auth_cookie_matches_configured_domain_and_path() -> anyhow::Result<()> {
+    let mut headers = HeaderMap::new();
+    clear_auth_cookie(&mut headers, &cross_subdomain_config());
+
+    assert_eq!(
+        set_cookie_header(&headers)?,
+        "auth_token=; Max-Age=0; Path=/api; Domain=example.com; HttpOnly; Secure; SameSite=None"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_oauth_session_cookie_uses_configured_max_age() {
+    let config = SessionCookieConfig {
+        max_age_secs: 7200,
+        same_site: SameSitePolicy::Strict,
+        secure: false,
+        ..SessionCookieConfig::default()
+    };
+
+    assert_eq!(
+        oauth_session_cookie("jwt", &config),
+        "pierre_session=jwt; Max-Age=7200; Path=/; HttpOnly; SameSite=Strict"
+    );
+}
+
+#[test]
+fn test_session_cookie_defaults_are_secure() {
+    let config = SessionCookieConfig::default();
+
+    assert!(config.secure);
+    assert_eq!(config.same_site, SameSitePolicy::Lax);
+    assert_eq!(config.domain, None);
+    assert_eq!(config.path, "/");
+    assert!(config.validate(&Environment::Production).is_ok());
+}
+
+#[test]
+fn test_insecure_session_cookie_rejected_in_production() {
+    let config = SessionCookieConfig {
+        secure: false,
+        ..SessionCookieConfig::default()
+    };
+
+    assert!(config.validate(&Environment::Production).is_err());
+    assert!(config.validate(&Environment::Development).is_ok());
+}
+
+#[test]
+fn test_same_site_none_requires_secure() {
+    let config = SessionCookieConfig {
+        secure: false,
+        ..cross_subdomain_config()
+    };
+
+    assert!(config.validate(&Environment::Development).is_err());
+    assert!(cross_subdomain_config()
+        .validate(&Environment::Production)
+        .is_ok());
+}
+
+#[test]
+fn test_invalid_cookie_domain_and_path_rejected() {
+    let domain = SessionCookieConfig {
+        domain: Some("example.com; Secure".to_owned()),
+        ..SessionCookieConfig::default()
+    };
+    assert!(domain.validate(&Environment::Development).is_err());
+
+    let path = SessionCookieConfig {
+        path: "api".to_owned(),
+        ..SessionCookieConfig::default()
+    };
+    assert!(path.validate(&Environment::Development).is_err());
+}
+
+#[test]
+fn test_same_site_policy_parsing() {
+    assert_eq!(
+        "lax".parse::<SameSitePolicy>().ok(),
+        Some(SameSitePolicy::Lax)
+    );
+    assert_eq!(
+        " Strict ".parse::<SameSitePolicy>().ok(),
+        Some(SameSitePolicy::Strict)
+    );
+    assert_eq!(
+        "NONE".parse::<SameSitePolicy>().ok(),
+        Some(SameSitePolicy::None)
+    );
+    assert!("relaxed".parse::<SameSitePolicy>().is_err());
+}
+
 #[test]
 fn test_csrf_cookie() -> anyhow::Result<()> {
     let mut headers = HeaderMap::new();
     set_csrf_cookie(&mut headers, "csrf_test_token", 1800);
 
-    let cookie_header = headers
-        .get(header::SET_COOKIE)
-        .ok_or_else(|| anyhow::anyhow!("Cookie header should be set"))?
-        .to_str()?;
+    let cookie_header = set_cookie_header(&headers)?;
 
     assert!(
         cookie_header.contains("csrf_token=csrf_test_token"),
@@ -1128,7 +1128,7 @@ async fn test_get_feed_with_pagination() {
     let setup = SocialRoutesTestSetup::new().await.expect("Setup failed");
     let routes = setup.routes();
 
-    let response = AxumTestRequest::get("/api/social/feed?limit=10&offset=0")
+    let response = AxumTestRequest::get("/api/social/feed?limit=10")
         .header("authorization", &setup.auth_header())
         .send(routes)
         .await;
@@ -1136,6 +1136,85 @@ async fn test_get_feed_with_pagination() {
     assert_eq!(response.status(), 200);
 }
 
+#[tokio::test]
+async fn test_insight_cursors_reject_tampering_and_replay() {
+    let setup = SocialRoutesTestSetup::new().await.expect("Setup failed");
+    let (_, friend_token) = setup.create_second_user().await.unwrap();
+
+    for number in 1..=3 {
+        let response = AxumTestRequest::post("/api/social/insights")
+            .header("authorization", &setup.auth_header())
+            .json(&json!({
+                "insight_type": "milestone",
+                "content": format!("Milestone number {number} reached"),
+                "visibility": "public"
+            }))
+            .send(setup.routes())
+            .await;
+        assert_eq!(response.status(), 201);
+    }
+
+    let list = |uri: String, token: String| {
+        let routes = setup.routes();
+        async move {
+            AxumTestRequest::get(&uri)
+                .header("authorization", &token)
+                .send(routes)
+                .await
+        }
+    };
+
+    let first = list(
+        "/api/social/insights?insight_type=milestone&limit=2".to_owned(),
+        setup.auth_header(),
+    )
+    .await;
+    assert_eq!(first.status(), 200);
+    let first: serde_json::Value = first.json();
+    assert_eq!(first["total"], 2);
+    let cursor = first["next_cursor"].as_str().unwrap().to_owned();
+    assert_ne!(cursor, "2");
+
+    let second = list(
+        format!("/api/social/insights?insight_type=milestone&limit=2&cursor={cursor}"),
+        setup.auth_header(),
+    )
+    .await;
+    assert_eq!(second.status(), 200);
+    let second: serde_json::Value = second.json();
+    assert_eq!(second["total"], 1);
+    assert_eq!(second["has_more"], false);
+
+    // A bare offset, an edited cursor, another filter, another endpoint, and another user
+    let (_, signature) = cursor.split_once('.').unwrap();
+    let rejected = [
+        (
+            "/api/social/insights?insight_type=milestone&cursor=2".to_owned(),
+            setup.auth_header(),
+        ),
+        (
+            format!("/api/social/insights?insight_type=milestone&cursor=0.{signature}"),
+            setup.auth_header(),
+        ),
+        (
+            format!("/api/social/insights?insight_type=training_tip&cursor={cursor}"),
+            setup.auth_header(),
+        ),
+        (
+            format!("/api/social/feed?cursor={cursor}"),
+            setup.auth_header(),
+        ),
+        (
+            format!("/api/social/insights?insight_type=milestone&cursor={cursor}"),
+            format!("Bearer {friend_token}"),
+        ),
+    ];
+    for (uri, token) in rejected {
+        let response = list(uri.clone(), token).await;
+        assert_eq!(response.status(), 400, "{uri}");
+    }
+}
+
 #[tokio::test]
 async fn test_get_feed_missing_auth() {
     let setup = SocialRoutesTestSetup::new().await.expect("Setup failed");
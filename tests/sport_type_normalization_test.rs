@@ -0,0 +1,129 @@
+// ABOUTME: Tests for normalizing provider sport strings into canonical SportType variants
+// ABOUTME: Covers real Strava, Garmin, and Fitbit labels, config overrides, and unknown type preservation
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use pierre_mcp_server::config::FitnessConfig;
+use pierre_mcp_server::models::{SportType, SportTypeNormalizer};
+
+fn assert_normalizes(cases: &[(&str, SportType)]) {
+    let normalizer = SportTypeNormalizer::new();
+    for (raw, expected) in cases {
+        assert_eq!(&normalizer.normalize(raw), expected, "normalizing {raw:?}");
+    }
+}
+
+#[test]
+fn test_strava_activity_types() {
+    assert_normalizes(&[
+        ("Run", SportType::Run),
+        ("TrailRun", SportType::TrailRunning),
+        ("VirtualRide", SportType::VirtualRide),
+        ("EBikeRide", SportType::EbikeRide),
+        ("EMountainBikeRide", SportType::EbikeRide),
+        ("MountainBikeRide", SportType::MountainBike),
+        ("NordicSki", SportType::CrossCountrySkiing),
+        ("AlpineSki", SportType::AlpineSkiing),
+        ("StandUpPaddling", SportType::Paddleboarding),
+        ("WeightTraining", SportType::StrengthTraining),
+        ("StairStepper", SportType::Workout),
+    ]);
+}
+
+#[test]
+fn test_garmin_activity_types() {
+    assert_normalizes(&[
+        ("running", SportType::Run),
+        ("trail_running", SportType::TrailRunning),
+        ("treadmill_running", SportType::VirtualRun),
+        ("indoor_cycling", SportType::VirtualRide),
+        ("gravel_cycling", SportType::GravelRide),
+        ("open_water_swimming", SportType::Swim),
+        ("resort_skiing_snowboarding", SportType::AlpineSkiing),
+        (
+            "backcountry_skiing_snowboarding",
+            SportType::BackcountrySkiing,
+        ),
+        ("strength_training", SportType::StrengthTraining),
+        ("fitness_equipment", SportType::Workout),
+    ]);
+}
+
+#[test]
+fn test_fitbit_activity_names() {
+    assert_normalizes(&[
+        ("Walk", SportType::Walk),
+        ("Outdoor Bike", SportType::Ride),
+        ("Treadmill", SportType::VirtualRun),
+        ("Spinning", SportType::VirtualRide),
+        ("Weights", SportType::StrengthTraining),
+        ("Aerobic Workout", SportType::Workout),
+    ]);
+}
+
+#[test]
+fn test_naming_styles_resolve_to_the_same_sport() {
+    assert_normalizes(&[
+        ("VirtualRide", SportType::VirtualRide),
+        ("virtual_ride", SportType::VirtualRide),
+        ("Virtual Ride", SportType::VirtualRide),
+        ("VIRTUAL-RIDE", SportType::VirtualRide),
+    ]);
+}
+
+#[test]
+fn test_unknown_type_preserves_original_label() {
+    let normalizer = SportTypeNormalizer::new();
+    assert_eq!(
+        normalizer.normalize("Pickleball"),
+        SportType::Other("Pickleball".to_owned())
+    );
+    assert_eq!(
+        normalizer.normalize("wheelchair_push_run"),
+        SportType::Other("wheelchair_push_run".to_owned())
+    );
+}
+
+#[test]
+fn test_overrides_take_precedence_over_defaults() {
+    let normalizer = SportTypeNormalizer::new()
+        .with_alias("Zwift", "virtual_ride")
+        .with_alias("walking", "hike");
+
+    assert_eq!(normalizer.normalize("zwift"), SportType::VirtualRide);
+    assert_eq!(normalizer.normalize("Walking"), SportType::Hike);
+    // Entries without an override still use the built-in table
+    assert_eq!(normalizer.normalize("walk"), SportType::Walk);
+}
+
+#[test]
+fn test_fitness_config_mappings_act_as_overrides() {
+    let mut config = FitnessConfig::default();
+    config
+        .sport_types
+        .insert("Pickleball".to_owned(), "tennis".to_owned());
+
+    let normalizer = SportTypeNormalizer::from_fitness_config(&config);
+    assert_eq!(normalizer.normalize("pickleball"), SportType::Tennis);
+    assert_eq!(
+        normalizer.normalize("MountainBikeRide"),
+        SportType::MountainBike
+    );
+
+    assert_eq!(
+        SportType::from_provider_string("Pickleball", &config),
+        SportType::Tennis
+    );
+    assert_eq!(
+        SportType::from_provider_string("trail_run", &config),
+        SportType::TrailRunning
+    );
+    assert_eq!(
+        SportType::from_provider_string("Wheelchair", &config),
+        SportType::Other("Wheelchair".to_owned())
+    );
+}
@@ -0,0 +1,102 @@
+// ABOUTME: Tests for SQLite connection pool configuration and pool statistics
+// ABOUTME: Verifies journal mode, busy timeout, and foreign key pragmas are applied to pooled connections
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use anyhow::Result;
+use pierre_mcp_server::config::environment::SqlitePoolConfig;
+use pierre_mcp_server::database::Database;
+use pierre_mcp_server::database_plugins::{DatabaseProvider, PoolStats};
+use tempfile::TempDir;
+
+async fn open_database(dir: &TempDir, config: &SqlitePoolConfig) -> Result<Database> {
+    let url = format!("sqlite:{}", dir.path().join("pool.db").display());
+    Ok(Database::new_with_pool_config(&url, vec![0u8; 32], config).await?)
+}
+
+async fn journal_mode(database: &Database) -> Result<String> {
+    Ok(sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(database.pool())
+        .await?)
+}
+
+#[tokio::test]
+async fn test_wal_mode_pragma_is_applied() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config = SqlitePoolConfig {
+        max_connections: 4,
+        busy_timeout_ms: 2_500,
+        wal_mode: true,
+        foreign_keys: true,
+    };
+    let database = open_database(&dir, &config).await?;
+
+    assert_eq!(journal_mode(&database).await?, "wal");
+    let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+        .fetch_one(database.pool())
+        .await?;
+    assert_eq!(busy_timeout, 2_500);
+    let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(database.pool())
+        .await?;
+    assert_eq!(foreign_keys, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rollback_journal_used_when_wal_disabled() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config = SqlitePoolConfig {
+        foreign_keys: false,
+        ..SqlitePoolConfig::default()
+    };
+    let database = open_database(&dir, &config).await?;
+
+    assert_eq!(journal_mode(&database).await?, "delete");
+    let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(database.pool())
+        .await?;
+    assert_eq!(foreign_keys, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pool_stats_reflect_configuration_and_usage() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config = SqlitePoolConfig {
+        max_connections: 3,
+        ..SqlitePoolConfig::default()
+    };
+    let database = open_database(&dir, &config).await?;
+
+    let held = database.pool().acquire().await?;
+    let stats = database.pool_stats();
+    assert_eq!(stats.max_connections, 3);
+    assert!(stats.active >= 1, "held connection must count as active");
+    assert!(stats.active + stats.idle <= 3);
+    assert!(stats.pending.is_none());
+    drop(held);
+
+    let json = serde_json::to_value(database.pool_stats())?;
+    for key in ["max_connections", "active", "idle", "pending"] {
+        assert!(json.get(key).is_some(), "missing key {key}");
+    }
+
+    assert_eq!(
+        PoolStats::from_pool_counts(10, 4, 7),
+        PoolStats {
+            max_connections: 10,
+            active: 0,
+            idle: 4,
+            pending: None,
+        }
+    );
+
+    Ok(())
+}
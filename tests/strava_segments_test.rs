@@ -0,0 +1,283 @@
+// ABOUTME: Tests for Strava segment efforts and segments mapped from recorded API payloads
+// ABOUTME: Covers effort and segment conversion plus segment PR detection in the intelligence layer
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use pierre_mcp_server::intelligence::SegmentPrDetector;
+use pierre_mcp_server::models::{PrMetric, SegmentEffort, SportType};
+use pierre_mcp_server::providers::core::ProviderValidationMode;
+use pierre_mcp_server::providers::strava_provider::{
+    DetailedActivityResponse, StravaProvider, StravaSegmentResponse,
+};
+
+/// GET /activities/{id}?include_all_efforts=true, trimmed to the fields Pierre reads
+const ACTIVITY_WITH_EFFORTS_JSON: &str = r#"{
+    "id": 11223344556,
+    "resource_state": 3,
+    "name": "Morning Ride",
+    "type": "Ride",
+    "start_date": "2024-05-11T14:20:00Z",
+    "distance": 42180.4,
+    "elapsed_time": 6120,
+    "total_elevation_gain": 612.0,
+    "segment_efforts": [
+        {
+            "id": 3210987654321,
+            "resource_state": 2,
+            "name": "Hawk Hill",
+            "activity": {"id": 11223344556, "resource_state": 1},
+            "athlete": {"id": 134815, "resource_state": 1},
+            "elapsed_time": 390,
+            "moving_time": 385,
+            "start_date": "2024-05-11T14:32:10Z",
+            "start_date_local": "2024-05-11T07:32:10Z",
+            "distance": 2684.8,
+            "start_index": 712,
+            "end_index": 1102,
+            "average_cadence": 84.2,
+            "device_watts": true,
+            "average_watts": 251.3,
+            "average_heartrate": 162.4,
+            "max_heartrate": 171.0,
+            "segment": {
+                "id": 229781,
+                "resource_state": 2,
+                "name": "Hawk Hill",
+                "activity_type": "Ride",
+                "distance": 2684.82,
+                "average_grade": 5.7,
+                "maximum_grade": 14.2,
+                "elevation_high": 245.2,
+                "elevation_low": 92.4,
+                "start_latlng": [37.8331119, -122.4834356],
+                "end_latlng": [37.8280722, -122.4981393],
+                "climb_category": 1,
+                "city": "San Francisco",
+                "state": "CA",
+                "country": "United States",
+                "private": false,
+                "hazardous": false,
+                "starred": false
+            },
+            "pr_rank": 1,
+            "kom_rank": null,
+            "achievements": [{"type_id": 3, "type": "pr", "rank": 1}],
+            "hidden": false
+        },
+        {
+            "id": 3210987654322,
+            "resource_state": 2,
+            "name": "Conzelman Descent",
+            "elapsed_time": 214,
+            "moving_time": 214,
+            "start_date": "2024-05-11T14:41:02Z",
+            "distance": 2210.1,
+            "segment": {
+                "id": 4313,
+                "resource_state": 2,
+                "name": "Conzelman Descent",
+                "activity_type": "Ride",
+                "distance": 2210.1,
+                "average_grade": -6.1,
+                "elevation_high": 245.0,
+                "elevation_low": 110.3,
+                "climb_category": 0
+            },
+            "pr_rank": 3,
+            "kom_rank": null
+        },
+        {
+            "id": 3210987654323,
+            "resource_state": 2,
+            "name": "Broken effort",
+            "elapsed_time": 60,
+            "distance": 400.0
+        }
+    ]
+}"#;
+
+/// GET /segments/229781
+const SEGMENT_DETAIL_JSON: &str = r#"{
+    "id": 229781,
+    "resource_state": 3,
+    "name": "Hawk Hill",
+    "activity_type": "Ride",
+    "distance": 2684.82,
+    "average_grade": 5.7,
+    "maximum_grade": 14.2,
+    "elevation_high": 245.2,
+    "elevation_low": 92.4,
+    "climb_category": 1,
+    "city": "San Francisco",
+    "state": "CA",
+    "country": "United States",
+    "private": false,
+    "hazardous": false,
+    "starred": false,
+    "created_at": "2009-09-21T20:29:41Z",
+    "updated_at": "2018-02-15T09:04:18Z",
+    "total_elevation_gain": 155.733,
+    "map": {"id": "s229781", "polyline": "}g|eFnpqjVl@En@Md@", "resource_state": 3},
+    "effort_count": 309974,
+    "athlete_count": 30623,
+    "star_count": 2428,
+    "athlete_segment_stats": {
+        "pr_elapsed_time": 390,
+        "pr_date": "2024-05-11",
+        "effort_count": 12
+    },
+    "xoms": {"kom": "6:12", "qom": "7:40", "overall": "6:12"}
+}"#;
+
+fn effort(segment_id: &str, elapsed_time: u64, pr_rank: Option<u32>) -> SegmentEffort {
+    SegmentEffort {
+        id: format!("effort-{segment_id}-{elapsed_time}"),
+        segment_id: Some(segment_id.to_owned()),
+        name: format!("Segment {segment_id}"),
+        elapsed_time,
+        moving_time: None,
+        start_date: Utc.with_ymd_and_hms(2024, 5, 11, 14, 0, 0).unwrap(),
+        distance: 1000.0,
+        average_heart_rate: None,
+        max_heart_rate: None,
+        average_cadence: None,
+        average_watts: None,
+        kom_rank: None,
+        pr_rank,
+        climb_category: None,
+        average_grade: None,
+        elevation_gain: None,
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // Values are copied verbatim from the payload
+fn test_strava_segment_efforts_mapping() {
+    let detailed: DetailedActivityResponse =
+        serde_json::from_str(ACTIVITY_WITH_EFFORTS_JSON).unwrap();
+    let efforts = StravaProvider::convert_segment_efforts(detailed.segment_efforts.unwrap());
+
+    // The effort without a start date is dropped
+    assert_eq!(efforts.len(), 2);
+
+    let hawk_hill = &efforts[0];
+    assert_eq!(hawk_hill.id, "3210987654321");
+    assert_eq!(hawk_hill.segment_id.as_deref(), Some("229781"));
+    assert_eq!(hawk_hill.name, "Hawk Hill");
+    assert_eq!(hawk_hill.elapsed_time, 390);
+    assert_eq!(hawk_hill.moving_time, Some(385));
+    assert_eq!(
+        hawk_hill.start_date,
+        Utc.with_ymd_and_hms(2024, 5, 11, 14, 32, 10).unwrap()
+    );
+    assert_eq!(hawk_hill.distance, f64::from(2684.8_f32));
+    assert_eq!(hawk_hill.average_watts, Some(251));
+    assert_eq!(hawk_hill.average_heart_rate, Some(162));
+    assert_eq!(hawk_hill.max_heart_rate, Some(171));
+    assert_eq!(hawk_hill.pr_rank, Some(1));
+    assert_eq!(hawk_hill.kom_rank, None);
+    assert_eq!(hawk_hill.climb_category, Some(1));
+    assert_eq!(hawk_hill.average_grade, Some(5.7));
+    let gain = hawk_hill.elevation_gain.unwrap();
+    assert!((gain - 152.8).abs() < 1e-9, "elevation gain {gain}");
+
+    let descent = &efforts[1];
+    assert_eq!(descent.segment_id.as_deref(), Some("4313"));
+    assert_eq!(descent.pr_rank, Some(3));
+    assert_eq!(descent.average_heart_rate, None);
+}
+
+#[test]
+fn test_detailed_activity_carries_segment_efforts() {
+    let detailed: DetailedActivityResponse =
+        serde_json::from_str(ACTIVITY_WITH_EFFORTS_JSON).unwrap();
+
+    let activity =
+        StravaProvider::convert_detailed_strava_activity(detailed, ProviderValidationMode::Lenient)
+            .unwrap();
+
+    assert_eq!(activity.id(), "11223344556");
+    assert_eq!(activity.segment_efforts().map(Vec::len), Some(2));
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // Values are copied verbatim from the payload
+fn test_strava_segment_detail_mapping() {
+    let segment: StravaSegmentResponse = serde_json::from_str(SEGMENT_DETAIL_JSON).unwrap();
+
+    let segment = StravaProvider::convert_segment(segment);
+
+    assert_eq!(segment.id, "229781");
+    assert_eq!(segment.name, "Hawk Hill");
+    assert_eq!(segment.sport_type, Some(SportType::Ride));
+    assert_eq!(segment.distance_meters, 2684.82);
+    assert_eq!(segment.maximum_grade, Some(14.2));
+    assert_eq!(segment.total_elevation_gain, Some(155.733));
+    assert_eq!(segment.region.as_deref(), Some("CA"));
+    assert_eq!(segment.effort_count, Some(309_974));
+    assert_eq!(segment.athlete_count, Some(30_623));
+    assert_eq!(segment.kom_time.as_deref(), Some("6:12"));
+    assert_eq!(segment.qom_time.as_deref(), Some("7:40"));
+    assert_eq!(segment.pr_elapsed_time, Some(390));
+    assert_eq!(segment.pr_date, NaiveDate::from_ymd_opt(2024, 5, 11));
+    assert_eq!(segment.athlete_effort_count, Some(12));
+    assert_eq!(segment.provider, "strava");
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // Elapsed seconds convert exactly
+fn test_new_segment_pr_emitted_as_personal_record() {
+    let detailed: DetailedActivityResponse =
+        serde_json::from_str(ACTIVITY_WITH_EFFORTS_JSON).unwrap();
+    let activity =
+        StravaProvider::convert_detailed_strava_activity(detailed, ProviderValidationMode::Lenient)
+            .unwrap();
+
+    let records = SegmentPrDetector::new().detect_in_activity(&activity);
+
+    // Hawk Hill is ranked first; the descent was only the third-best effort
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record.activity_id, "11223344556");
+    assert_eq!(record.metric, PrMetric::SegmentTime);
+    assert_eq!(record.value, 390.0);
+    assert_eq!(record.segment_id.as_deref(), Some("229781"));
+    assert_eq!(
+        record.date,
+        Utc.with_ymd_and_hms(2024, 5, 11, 14, 32, 10).unwrap()
+    );
+}
+
+#[test]
+fn test_unranked_efforts_compare_against_previous_bests() {
+    let previous_bests = HashMap::from([("a".to_owned(), 300), ("b".to_owned(), 200)]);
+    let detector = SegmentPrDetector::with_previous_bests(previous_bests);
+    let efforts = [
+        effort("a", 290, None),
+        effort("b", 210, None),
+        effort("c", 100, None),
+    ];
+
+    let records = detector.detect("act-1", &efforts);
+
+    // Segment c has no known best, so its first effort is not a record
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].segment_id.as_deref(), Some("a"));
+}
+
+#[test]
+fn test_repeated_segment_keeps_fastest_effort() {
+    let efforts = [effort("loop", 320, Some(2)), effort("loop", 305, Some(1))];
+
+    let records = SegmentPrDetector::new().detect("act-1", &efforts);
+
+    assert_eq!(records.len(), 1);
+    assert!((records[0].value - 305.0).abs() < f64::EPSILON);
+}
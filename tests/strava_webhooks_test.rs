@@ -0,0 +1,464 @@
+// ABOUTME: Tests for Strava push subscriptions and webhook event processing
+// ABOUTME: Covers the verification handshake, subscription ID checks, end-to-end events, and deauthorization checks
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+mod helpers;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{TimeZone, Utc};
+use helpers::axum_test::AxumTestRequest;
+use pierre_mcp_server::config::StravaWebhookConfig;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::errors::{AppResult, ErrorCode};
+use pierre_mcp_server::mcp::resources::ServerResources;
+use pierre_mcp_server::models::{
+    Activity, ActivityBuilder, SportType, StravaAthleteLink, StravaWebhookSubscription, TenantId,
+    UserOAuthToken,
+};
+use pierre_mcp_server::providers::core::FitnessProvider;
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use pierre_mcp_server::routes::StravaWebhookRoutes;
+use pierre_mcp_server::services::strava_webhooks::{
+    verify_challenge, StravaChallengeQuery, StravaEventOutcome, StravaWebhookEvent,
+    StravaWebhookProcessor,
+};
+use pierre_mcp_server::tenant::webhooks::{TenantWebhook, WebhookDeliveryStatus, WebhookEventType};
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+const VERIFY_TOKEN: &str = "strava-verify-token";
+const SUBSCRIPTION_ID: i64 = 120_475;
+const ATHLETE_ID: i64 = 134_815;
+const ACTIVITY_ID: i64 = 1_360_128_428;
+
+fn challenge(mode: &str, verify_token: &str) -> StravaChallengeQuery {
+    StravaChallengeQuery {
+        mode: mode.to_owned(),
+        challenge: "15f7d1a91c1f40f8a748fd134752feb3".to_owned(),
+        verify_token: verify_token.to_owned(),
+    }
+}
+
+fn event(aspect_type: &str, subscription_id: i64) -> StravaWebhookEvent {
+    serde_json::from_value(json!({
+        "aspect_type": aspect_type,
+        "event_time": 1_749_880_800,
+        "object_id": ACTIVITY_ID,
+        "object_type": "activity",
+        "owner_id": ATHLETE_ID,
+        "subscription_id": subscription_id,
+        "updates": {}
+    }))
+    .unwrap()
+}
+
+fn strava_run() -> Activity {
+    let start = Utc.with_ymd_and_hms(2025, 6, 14, 7, 0, 0).unwrap();
+    ActivityBuilder::new(
+        ACTIVITY_ID.to_string(),
+        "Morning Run",
+        SportType::Run,
+        start,
+        3000,
+        "strava",
+    )
+    .distance_meters(10_000.0)
+    .build()
+}
+
+/// Register a subscription, link the athlete to a new user, and add a tenant webhook
+async fn setup(resources: &ServerResources) -> (Uuid, TenantId, TenantWebhook) {
+    let database = &resources.database;
+    let (user_id, _) = common::create_test_user(database).await.unwrap();
+    let tenant_id = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+
+    database
+        .save_strava_webhook_subscription(&StravaWebhookSubscription {
+            id: SUBSCRIPTION_ID,
+            client_id: "12345".to_owned(),
+            callback_url: "https://api.example.com/api/webhooks/strava".to_owned(),
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    database
+        .link_strava_athlete(&StravaAthleteLink {
+            athlete_id: ATHLETE_ID,
+            user_id,
+            tenant_id,
+            linked_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let now = Utc::now();
+    let webhook = TenantWebhook {
+        id: Uuid::new_v4(),
+        tenant_id,
+        url: "https://hooks.example.com/pierre".to_owned(),
+        secret: "whsec_test_secret".to_owned(),
+        event_types: vec![WebhookEventType::ActivitySynced],
+        active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    database.create_tenant_webhook(&webhook).await.unwrap();
+
+    (user_id, tenant_id, webhook)
+}
+
+/// Provider factory serving the activity from a synthetic Strava account
+async fn mocked_strava(
+    activities: Vec<Activity>,
+    _user_id: Uuid,
+    _tenant_id: TenantId,
+) -> AppResult<Box<dyn FitnessProvider>> {
+    Ok(Box::new(SyntheticProvider::with_activities_and_name(
+        activities, "strava",
+    )))
+}
+
+/// Serve Strava's `GET /athlete` with `status`, returning the API base URL
+async fn strava_athlete_api(status: StatusCode) -> String {
+    let app = Router::new().route(
+        "/athlete",
+        get(move || async move { (status, Json(json!({ "id": ATHLETE_ID }))) }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    url
+}
+
+/// Store a Strava token for the user and return a deauthorization event for their athlete
+async fn deauthorization_setup(
+    resources: &ServerResources,
+    user_id: Uuid,
+    tenant_id: TenantId,
+) -> StravaWebhookEvent {
+    resources
+        .database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            "strava".to_owned(),
+            "strava_access_token".to_owned(),
+            None,
+            None,
+            None,
+        ))
+        .await
+        .unwrap();
+
+    let deauthorize: StravaWebhookEvent = serde_json::from_value(json!({
+        "aspect_type": "update",
+        "event_time": 1_749_880_800,
+        "object_id": ATHLETE_ID,
+        "object_type": "athlete",
+        "owner_id": ATHLETE_ID,
+        "subscription_id": SUBSCRIPTION_ID,
+        "updates": { "authorized": "false" }
+    }))
+    .unwrap();
+    assert!(deauthorize.is_deauthorization());
+    deauthorize
+}
+
+/// Provider factory for events that must be handled without fetching anything
+async fn no_fetch(_user_id: Uuid, _tenant_id: TenantId) -> AppResult<Box<dyn FitnessProvider>> {
+    panic!("this event must not fetch from Strava")
+}
+
+#[test]
+fn test_verification_handshake() {
+    let body = verify_challenge(&challenge("subscribe", VERIFY_TOKEN), VERIFY_TOKEN).unwrap();
+    assert_eq!(
+        body,
+        json!({ "hub.challenge": "15f7d1a91c1f40f8a748fd134752feb3" })
+    );
+
+    let wrong_token = verify_challenge(&challenge("subscribe", "guessed"), VERIFY_TOKEN);
+    assert_eq!(wrong_token.unwrap_err().code, ErrorCode::PermissionDenied);
+
+    let wrong_mode = verify_challenge(&challenge("unsubscribe", VERIFY_TOKEN), VERIFY_TOKEN);
+    assert_eq!(wrong_mode.unwrap_err().code, ErrorCode::PermissionDenied);
+}
+
+#[tokio::test]
+async fn test_verification_handshake_route() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let router = || {
+        StravaWebhookRoutes::routes_with_config(
+            Arc::clone(&resources),
+            StravaWebhookConfig::new("https://api.example.com/api/webhooks/strava", VERIFY_TOKEN),
+        )
+    };
+
+    let response = AxumTestRequest::get(&format!(
+        "/api/webhooks/strava?hub.mode=subscribe&hub.challenge=abc123&hub.verify_token={VERIFY_TOKEN}"
+    ))
+    .send(router())
+    .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["hub.challenge"], "abc123");
+
+    let response = AxumTestRequest::get(
+        "/api/webhooks/strava?hub.mode=subscribe&hub.challenge=abc123&hub.verify_token=guessed",
+    )
+    .send(router())
+    .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_create_event_processed_end_to_end() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, tenant_id, webhook) = setup(&resources).await;
+    let processor = StravaWebhookProcessor::new(Arc::clone(&resources));
+    let activities = vec![strava_run()];
+
+    let create = event("create", SUBSCRIPTION_ID);
+    processor.verify_subscription(&create).await.unwrap();
+    let outcome = processor
+        .process_event(&create, |user_id, tenant_id| {
+            mocked_strava(activities.clone(), user_id, tenant_id)
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        StravaEventOutcome::ActivityStored {
+            users: vec![user_id]
+        }
+    );
+
+    // The activity is in the store and a tenant notification is queued
+    let records = resources
+        .database
+        .get_activity_sync_records(user_id, tenant_id, "strava", chrono::DateTime::UNIX_EPOCH)
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].activity_id, ACTIVITY_ID.to_string());
+    assert!(records[0].deleted_at.is_none());
+
+    let deliveries = resources
+        .database
+        .list_webhook_deliveries(webhook.id, Some(WebhookDeliveryStatus::Pending), 10)
+        .await
+        .unwrap();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event_type, WebhookEventType::ActivitySynced);
+    assert_eq!(deliveries[0].payload["data"]["aspect_type"], "create");
+    assert_eq!(
+        deliveries[0].payload["data"]["activity_id"],
+        ACTIVITY_ID.to_string()
+    );
+
+    // Strava may deliver an event more than once; unchanged content is not stored again
+    let outcome = processor
+        .process_event(&create, |user_id, tenant_id| {
+            mocked_strava(activities.clone(), user_id, tenant_id)
+        })
+        .await
+        .unwrap();
+    assert_eq!(outcome, StravaEventOutcome::Ignored);
+
+    // A delete event marks the stored activity deleted without fetching it
+    let outcome = processor
+        .process_event(&event("delete", SUBSCRIPTION_ID), no_fetch)
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        StravaEventOutcome::ActivityDeleted {
+            users: vec![user_id]
+        }
+    );
+    let records = resources
+        .database
+        .get_activity_sync_records(user_id, tenant_id, "strava", chrono::DateTime::UNIX_EPOCH)
+        .await
+        .unwrap();
+    assert!(records[0].deleted_at.is_some());
+}
+
+#[tokio::test]
+async fn test_update_to_private_activity_counts_as_deletion() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _, _) = setup(&resources).await;
+    let processor = StravaWebhookProcessor::new(Arc::clone(&resources));
+
+    processor
+        .process_event(&event("create", SUBSCRIPTION_ID), |user_id, tenant_id| {
+            mocked_strava(vec![strava_run()], user_id, tenant_id)
+        })
+        .await
+        .unwrap();
+
+    // The activity is no longer visible to the application after going private
+    let mut update = event("update", SUBSCRIPTION_ID);
+    update.updates.insert("private".to_owned(), json!("true"));
+    let outcome = processor
+        .process_event(&update, |user_id, tenant_id| {
+            mocked_strava(Vec::new(), user_id, tenant_id)
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        StravaEventOutcome::ActivityDeleted {
+            users: vec![user_id]
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_subscription_is_rejected() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    setup(&resources).await;
+    let processor = StravaWebhookProcessor::new(Arc::clone(&resources));
+
+    let forged = event("create", SUBSCRIPTION_ID + 1);
+    assert_eq!(
+        processor
+            .verify_subscription(&forged)
+            .await
+            .unwrap_err()
+            .code,
+        ErrorCode::PermissionDenied
+    );
+
+    let router = StravaWebhookRoutes::routes_with_config(
+        Arc::clone(&resources),
+        StravaWebhookConfig::new("https://api.example.com/api/webhooks/strava", VERIFY_TOKEN),
+    );
+    let response = AxumTestRequest::post("/api/webhooks/strava")
+        .json(&forged)
+        .send(router)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_deauthorization_removes_strava_connection() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, tenant_id, _) = setup(&resources).await;
+    let deauthorize = deauthorization_setup(&resources, user_id, tenant_id).await;
+
+    // Strava rejects the stored token, confirming the deauthorization
+    let api_base_url = strava_athlete_api(StatusCode::UNAUTHORIZED).await;
+    let processor =
+        StravaWebhookProcessor::new(Arc::clone(&resources)).with_api_base_url(api_base_url);
+
+    let outcome = processor
+        .process_event(&deauthorize, no_fetch)
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        StravaEventOutcome::Deauthorized {
+            users: vec![user_id]
+        }
+    );
+    assert!(resources
+        .database
+        .get_strava_athlete_links(ATHLETE_ID)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(resources
+        .database
+        .get_user_oauth_token(user_id, tenant_id, "strava")
+        .await
+        .unwrap()
+        .is_none());
+
+    let audited = resources
+        .database
+        .get_audit_events(Some(tenant_id), Some("OAuthCredentialsDeleted"), None)
+        .await
+        .unwrap();
+    assert_eq!(audited.len(), 1);
+    assert_eq!(audited[0].user_id, Some(user_id));
+}
+
+#[tokio::test]
+async fn test_unconfirmed_deauthorization_is_ignored() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, tenant_id, _) = setup(&resources).await;
+    let deauthorize = deauthorization_setup(&resources, user_id, tenant_id).await;
+
+    // Strava still accepts the stored token, so the event did not come from a revocation
+    let api_base_url = strava_athlete_api(StatusCode::OK).await;
+    let processor =
+        StravaWebhookProcessor::new(Arc::clone(&resources)).with_api_base_url(api_base_url);
+
+    let outcome = processor
+        .process_event(&deauthorize, no_fetch)
+        .await
+        .unwrap();
+    assert_eq!(outcome, StravaEventOutcome::Ignored);
+    assert_eq!(
+        resources
+            .database
+            .get_strava_athlete_links(ATHLETE_ID)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(resources
+        .database
+        .get_user_oauth_token(user_id, tenant_id, "strava")
+        .await
+        .unwrap()
+        .is_some());
+
+    let audited = resources
+        .database
+        .get_audit_events(Some(tenant_id), Some("SecurityPolicyViolation"), None)
+        .await
+        .unwrap();
+    assert_eq!(audited.len(), 1);
+    assert_eq!(audited[0].user_id, Some(user_id));
+}
+
+#[tokio::test]
+async fn test_event_route_is_rate_limited() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    setup(&resources).await;
+    let router = StravaWebhookRoutes::routes_with_config(
+        Arc::clone(&resources),
+        StravaWebhookConfig::new("https://api.example.com/api/webhooks/strava", VERIFY_TOKEN),
+    );
+
+    // Requests from the same source share one budget; rejected events still count against it
+    let forged = event("create", SUBSCRIPTION_ID + 1);
+    let mut last_status = StatusCode::OK;
+    for _ in 0..100 {
+        last_status = AxumTestRequest::post("/api/webhooks/strava")
+            .json(&forged)
+            .send(router.clone())
+            .await
+            .status_code();
+        if last_status == StatusCode::TOO_MANY_REQUESTS {
+            break;
+        }
+    }
+    assert_eq!(last_status, StatusCode::TOO_MANY_REQUESTS);
+}
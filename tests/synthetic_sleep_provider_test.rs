@@ -0,0 +1,149 @@
+// ABOUTME: Tests for the seedable synthetic sleep and HRV provider
+// ABOUTME: Verifies deterministic generation, stage proportions, bad nights, and HRV trend detection
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![cfg(feature = "provider-synthetic")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{Duration, TimeZone, Utc};
+use pierre_mcp_server::config::intelligence::{IntelligenceConfig, SleepRecoveryConfig};
+use pierre_mcp_server::intelligence::sleep_analysis::{HrvTrend, SleepAnalyzer, SleepData};
+use pierre_mcp_server::models::{SleepSession, SleepStageType};
+use pierre_mcp_server::providers::core::FitnessProvider;
+use pierre_mcp_server::providers::synthetic_sleep_provider::{
+    SleepAnomaly, SyntheticSleepConfig, SyntheticSleepProvider,
+};
+
+fn test_config() -> SleepRecoveryConfig {
+    IntelligenceConfig::default().sleep_recovery
+}
+
+fn sleep_config() -> SyntheticSleepConfig {
+    SyntheticSleepConfig {
+        last_wake_time: Utc.with_ymd_and_hms(2025, 6, 8, 6, 30, 0).unwrap(),
+        ..SyntheticSleepConfig::default()
+    }
+}
+
+fn stage_minutes(session: &SleepSession, stage_type: SleepStageType) -> u32 {
+    session
+        .stages
+        .iter()
+        .filter(|s| s.stage_type == stage_type)
+        .map(|s| s.duration_minutes)
+        .sum()
+}
+
+fn to_sleep_data(session: &SleepSession) -> SleepData {
+    let hours = |stage_type| Some(f64::from(stage_minutes(session, stage_type)) / 60.0);
+    SleepData {
+        date: session.start_time,
+        duration_hours: f64::from(session.total_sleep_time) / 60.0,
+        deep_sleep_hours: hours(SleepStageType::Deep),
+        rem_sleep_hours: hours(SleepStageType::Rem),
+        light_sleep_hours: hours(SleepStageType::Light),
+        awake_hours: hours(SleepStageType::Awake),
+        efficiency_percent: Some(f64::from(session.sleep_efficiency)),
+        hrv_rmssd_ms: session.hrv_during_sleep,
+        resting_hr_bpm: None,
+        provider_score: session.sleep_score.map(f64::from),
+    }
+}
+
+#[tokio::test]
+async fn test_declining_hrv_week_is_detected() {
+    let provider = SyntheticSleepProvider::new(&SyntheticSleepConfig {
+        rmssd_baseline_ms: 60.0,
+        rmssd_nightly_change_ms: -3.0,
+        rmssd_noise_ms: 1.0,
+        ..sleep_config()
+    })
+    .unwrap();
+
+    let week_end = Utc.with_ymd_and_hms(2025, 6, 8, 12, 0, 0).unwrap();
+    let sessions = provider
+        .get_sleep_sessions(week_end - Duration::days(7), week_end)
+        .await
+        .unwrap();
+    assert_eq!(sessions.len(), 7);
+
+    let latest = provider.get_latest_sleep_session().await.unwrap();
+    let rmssd_values = provider.rmssd_values();
+    assert_eq!(latest.hrv_during_sleep, rmssd_values.last().copied());
+
+    let analysis = SleepAnalyzer::analyze_hrv_trends(
+        latest.hrv_during_sleep.unwrap(),
+        &rmssd_values,
+        Some(60.0),
+        &test_config(),
+    )
+    .unwrap();
+    assert_eq!(analysis.trend, HrvTrend::Declining);
+    assert!(analysis.baseline_deviation_percent.unwrap() < -20.0);
+}
+
+#[test]
+fn test_same_seed_generates_same_nights_with_configured_proportions() {
+    let config = SyntheticSleepConfig {
+        deep_fraction: 0.15,
+        rem_fraction: 0.30,
+        ..sleep_config()
+    };
+    let first = SyntheticSleepProvider::new(&config).unwrap();
+    let second = SyntheticSleepProvider::new(&config).unwrap();
+    let reseeded = SyntheticSleepProvider::new(&SyntheticSleepConfig {
+        seed: 7,
+        ..config.clone()
+    })
+    .unwrap();
+
+    assert_eq!(first.rmssd_values(), second.rmssd_values());
+    assert_ne!(first.rmssd_values(), reseeded.rmssd_values());
+
+    for session in first.sessions() {
+        let deep = stage_minutes(session, SleepStageType::Deep);
+        let rem = stage_minutes(session, SleepStageType::Rem);
+        let light = stage_minutes(session, SleepStageType::Light);
+        assert_eq!(deep + rem + light, session.total_sleep_time);
+
+        let total = f64::from(session.total_sleep_time);
+        assert!((f64::from(deep) / total - 0.15).abs() < 0.01);
+        assert!((f64::from(rem) / total - 0.30).abs() < 0.01);
+        assert_eq!(session.end_time.time(), config.last_wake_time.time());
+    }
+
+    let invalid = SyntheticSleepProvider::new(&SyntheticSleepConfig {
+        deep_fraction: 0.6,
+        rem_fraction: 0.5,
+        ..sleep_config()
+    });
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn test_bad_night_lowers_hrv_and_sleep_quality() {
+    let provider = SyntheticSleepProvider::new(&SyntheticSleepConfig {
+        anomalies: vec![SleepAnomaly::bad_night(3)],
+        ..sleep_config()
+    })
+    .unwrap();
+    let sessions = provider.sessions();
+    let normal = &sessions[2];
+    let bad = &sessions[3];
+
+    assert!(bad.hrv_during_sleep.unwrap() < normal.hrv_during_sleep.unwrap() * 0.8);
+    assert!(bad.total_sleep_time + 60 < normal.total_sleep_time);
+    assert!(bad.wake_count.unwrap() > normal.wake_count.unwrap());
+
+    let config = test_config();
+    let normal_quality = SleepAnalyzer::calculate_sleep_quality(&to_sleep_data(normal), &config)
+        .unwrap()
+        .overall_score;
+    let bad_quality = SleepAnalyzer::calculate_sleep_quality(&to_sleep_data(bad), &config)
+        .unwrap()
+        .overall_score;
+    assert!(bad_quality < normal_quality);
+}
@@ -0,0 +1,349 @@
+// ABOUTME: Tests for the per-tenant tool usage report used for billing
+// ABOUTME: Seeds API key, A2A, and JWT usage across two tenants and checks per-tool, per-day totals stay isolated
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+mod helpers;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use helpers::axum_test::AxumTestRequest;
+use pierre_mcp_server::{
+    a2a::auth::A2AClient,
+    admin::{
+        jwt::AdminJwtManager,
+        models::{AdminPermission, AdminPermissions},
+        AdminAuthService,
+    },
+    api_keys::ApiKeyUsage,
+    constants::system_config::STARTER_MONTHLY_LIMIT,
+    database::a2a::A2AUsage,
+    database_plugins::{factory::Database, DatabaseProvider},
+    mcp::ToolSelectionService,
+    models::{TenantId, ToolCallCounts},
+    rate_limiting::JwtUsage,
+    routes::admin::{AdminApiContext, AdminRoutes},
+    services::tenant_usage::tenant_tool_usage_report,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+const JWT_SECRET: &str = "test_admin_jwt_secret_for_tenant_usage_testing";
+
+/// Noon UTC `days` days ago, so seeded calls never straddle midnight
+fn days_ago(days: i64) -> DateTime<Utc> {
+    (Utc::now() - Duration::days(days))
+        .date_naive()
+        .and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+        .and_utc()
+}
+
+async fn record_api_key_calls(
+    database: &Database,
+    api_key_id: &str,
+    tool_name: &str,
+    at: DateTime<Utc>,
+    count: usize,
+) {
+    for _ in 0..count {
+        database
+            .record_api_key_usage(&ApiKeyUsage {
+                id: None,
+                api_key_id: api_key_id.to_owned(),
+                timestamp: at,
+                tool_name: tool_name.to_owned(),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+    }
+}
+
+async fn record_a2a_call(database: &Database, client_id: &str, tool_name: &str, at: DateTime<Utc>) {
+    database
+        .record_a2a_usage(&A2AUsage {
+            id: None,
+            client_id: client_id.to_owned(),
+            session_token: None,
+            timestamp: at,
+            tool_name: tool_name.to_owned(),
+            response_time_ms: Some(100),
+            status_code: 200,
+            error_message: None,
+            request_size_bytes: None,
+            response_size_bytes: None,
+            ip_address: None,
+            user_agent: None,
+            protocol_version: "1.0".to_owned(),
+            client_capabilities: vec![],
+            granted_scopes: vec![],
+        })
+        .await
+        .unwrap();
+}
+
+async fn record_jwt_call(database: &Database, user_id: Uuid, endpoint: &str, at: DateTime<Utc>) {
+    database
+        .record_jwt_usage(&JwtUsage {
+            id: None,
+            user_id,
+            timestamp: at,
+            endpoint: endpoint.to_owned(),
+            method: "POST".to_owned(),
+            status_code: 200,
+            response_time_ms: Some(100),
+            request_size_bytes: None,
+            response_size_bytes: None,
+            ip_address: None,
+            user_agent: None,
+        })
+        .await
+        .unwrap();
+}
+
+async fn create_a2a_client(database: &Database, user_id: Uuid, api_key_id: &str) -> String {
+    let now = Utc::now();
+    let suffix = Uuid::new_v4().simple();
+    let client = A2AClient {
+        id: format!("billing_client_{suffix}"),
+        name: "Billing Client".to_owned(),
+        description: "A2A client for usage tests".to_owned(),
+        public_key: format!("billing_public_key_{suffix}"),
+        user_id,
+        capabilities: vec!["fitness-data-analysis".into()],
+        redirect_uris: vec!["https://billing.example.com".into()],
+        permissions: vec!["read_activities".into()],
+        rate_limit_requests: 1000,
+        rate_limit_window_seconds: 3600,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    database
+        .create_a2a_client(&client, "billing_secret", api_key_id)
+        .await
+        .unwrap();
+    client.id
+}
+
+struct UsageFixture {
+    database: Arc<Database>,
+    tenant_a: TenantId,
+    tenant_b: TenantId,
+}
+
+/// Two tenants with mixed API key, A2A, and JWT usage; tenant A has a second member
+async fn setup() -> UsageFixture {
+    let database = common::create_test_database().await.unwrap();
+    let (owner_a, _) = common::create_test_user_with_email(&database, "owner-a@example.com")
+        .await
+        .unwrap();
+    let (member_a, _) = common::create_test_user_with_email(&database, "member-a@example.com")
+        .await
+        .unwrap();
+    let (owner_b, _) = common::create_test_user_with_email(&database, "owner-b@example.com")
+        .await
+        .unwrap();
+    let tenant_a = database.list_tenants_for_user(owner_a).await.unwrap()[0].id;
+    let tenant_b = database.list_tenants_for_user(owner_b).await.unwrap()[0].id;
+
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO tenant_users (id, tenant_id, user_id, role, invited_at, joined_at) VALUES (?, ?, ?, 'member', ?, ?)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(tenant_a.to_string())
+    .bind(member_a.to_string())
+    .bind(&now)
+    .bind(&now)
+    .execute(database.sqlite_pool().unwrap())
+    .await
+    .unwrap();
+
+    let key_a = common::create_and_store_test_api_key(&database, owner_a, "owner-a")
+        .await
+        .unwrap();
+    let member_key = common::create_and_store_test_api_key(&database, member_a, "member-a")
+        .await
+        .unwrap();
+    let key_b = common::create_and_store_test_api_key(&database, owner_b, "owner-b")
+        .await
+        .unwrap();
+    let client_a = create_a2a_client(&database, member_a, &member_key.id).await;
+    let client_b = create_a2a_client(&database, owner_b, &key_b.id).await;
+
+    // Tenant A: 3 days ago and 2 days ago
+    record_api_key_calls(&database, &key_a.id, "get_activities", days_ago(3), 2).await;
+    record_api_key_calls(&database, &member_key.id, "get_activities", days_ago(2), 1).await;
+    record_a2a_call(&database, &client_a, "get_activities", days_ago(2)).await;
+    record_a2a_call(&database, &client_a, "analyze_activity", days_ago(3)).await;
+    record_jwt_call(&database, owner_a, "get_activities", days_ago(2)).await;
+    // Outside the reported range
+    record_api_key_calls(&database, &key_a.id, "get_activities", days_ago(40), 4).await;
+
+    // Tenant B
+    record_api_key_calls(&database, &key_b.id, "get_activities", days_ago(2), 5).await;
+    record_a2a_call(&database, &client_b, "get_athlete", days_ago(3)).await;
+    record_jwt_call(&database, owner_b, "get_athlete", days_ago(3)).await;
+
+    UsageFixture {
+        database,
+        tenant_a,
+        tenant_b,
+    }
+}
+
+fn counts(api_key: u64, a2a: u64, jwt: u64) -> ToolCallCounts {
+    ToolCallCounts { api_key, a2a, jwt }
+}
+
+#[tokio::test]
+async fn test_report_totals_per_tool_and_day_for_each_tenant() {
+    let fixture = setup().await;
+    let start = days_ago(10);
+    let end = Utc::now();
+
+    let report = tenant_tool_usage_report(fixture.database.as_ref(), fixture.tenant_a, start, end)
+        .await
+        .unwrap();
+    assert_eq!(report.tenant_id, fixture.tenant_a);
+    assert_eq!(report.total_calls, 6);
+
+    let daily: Vec<_> = report
+        .daily
+        .iter()
+        .map(|day| (day.date, day.tool_name.as_str(), day.calls))
+        .collect();
+    let (day3, day2) = (days_ago(3).date_naive(), days_ago(2).date_naive());
+    assert_eq!(
+        daily,
+        vec![
+            (day3, "analyze_activity", counts(0, 1, 0)),
+            (day3, "get_activities", counts(2, 0, 0)),
+            (day2, "get_activities", counts(1, 1, 1)),
+        ]
+    );
+
+    assert_eq!(report.tools.len(), 2);
+    assert_eq!(report.tools[0].tool_name, "get_activities");
+    assert_eq!(report.tools[0].calls, counts(3, 1, 1));
+    assert_eq!(report.tools[0].total_calls, 5);
+    assert_eq!(report.tools[1].tool_name, "analyze_activity");
+    assert_eq!(report.tools[1].total_calls, 1);
+
+    // Tenant B sees only its own members' calls
+    let report = tenant_tool_usage_report(fixture.database.as_ref(), fixture.tenant_b, start, end)
+        .await
+        .unwrap();
+    assert_eq!(report.total_calls, 7);
+    assert_eq!(report.tools[0].tool_name, "get_activities");
+    assert_eq!(report.tools[0].calls, counts(5, 0, 0));
+    assert_eq!(report.tools[1].tool_name, "get_athlete");
+    assert_eq!(report.tools[1].calls, counts(0, 1, 1));
+
+    // A tenant without usage gets an empty report, and empty ranges are rejected
+    let report = tenant_tool_usage_report(fixture.database.as_ref(), TenantId::new(), start, end)
+        .await
+        .unwrap();
+    assert_eq!(report.total_calls, 0);
+    assert!(report.daily.is_empty());
+    tenant_tool_usage_report(fixture.database.as_ref(), fixture.tenant_a, end, start)
+        .await
+        .unwrap_err();
+}
+
+async fn admin_token(database: &Database, is_super_admin: bool) -> String {
+    let permissions = if is_super_admin {
+        AdminPermissions::super_admin()
+    } else {
+        AdminPermissions::new(vec![AdminPermission::ViewConfiguration])
+    };
+    let token_id = format!("admin_{}", Uuid::new_v4().simple());
+    let jwt_token = AdminJwtManager::new()
+        .generate_token(
+            &token_id,
+            "billing_service",
+            &permissions,
+            is_super_admin,
+            None,
+            &common::get_shared_test_jwks(),
+        )
+        .unwrap();
+
+    sqlx::query(
+        r"
+        INSERT INTO admin_tokens (
+            id, service_name, service_description, token_hash, token_prefix,
+            jwt_secret_hash, permissions, is_super_admin, is_active,
+            created_at, expires_at, usage_count
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ",
+    )
+    .bind(&token_id)
+    .bind("billing_service")
+    .bind(Some("Test admin token"))
+    .bind(AdminJwtManager::hash_token_for_storage(&jwt_token).unwrap())
+    .bind(AdminJwtManager::generate_token_prefix(&jwt_token))
+    .bind(AdminJwtManager::hash_secret(JWT_SECRET))
+    .bind(permissions.to_json().unwrap())
+    .bind(is_super_admin)
+    .bind(true)
+    .bind(Utc::now())
+    .bind(None::<DateTime<Utc>>)
+    .bind(0)
+    .execute(database.sqlite_pool().unwrap())
+    .await
+    .unwrap();
+
+    jwt_token
+}
+
+#[tokio::test]
+async fn test_admin_route_reports_tenant_usage_to_super_admins_only() {
+    let fixture = setup().await;
+    let context = AdminApiContext::new(
+        fixture.database.clone(),
+        JWT_SECRET,
+        common::create_test_auth_manager(),
+        common::get_shared_test_jwks(),
+        STARTER_MONTHLY_LIMIT,
+        AdminAuthService::DEFAULT_CACHE_TTL_SECS,
+        Arc::new(ToolSelectionService::new(fixture.database.clone())),
+    );
+    let uri = format!(
+        "/admin/tenants/{}/tool-usage?start_date={}",
+        fixture.tenant_a,
+        days_ago(10).format("%Y-%m-%dT%H:%M:%SZ")
+    );
+
+    let super_admin = admin_token(&fixture.database, true).await;
+    let response = AxumTestRequest::get(&uri)
+        .header("authorization", &format!("Bearer {super_admin}"))
+        .send(AdminRoutes::routes(context.clone()))
+        .await;
+    assert_eq!(response.status(), 200);
+    let body: Value = response.json();
+    assert_eq!(body["usage"]["total_calls"], 6);
+    assert_eq!(body["usage"]["tools"][0]["tool_name"], "get_activities");
+    assert_eq!(body["usage"]["tools"][0]["calls"]["a2a"], 1);
+    assert_eq!(body["usage"]["daily"].as_array().unwrap().len(), 3);
+
+    let viewer = admin_token(&fixture.database, false).await;
+    let response = AxumTestRequest::get(&uri)
+        .header("authorization", &format!("Bearer {viewer}"))
+        .send(AdminRoutes::routes(context))
+        .await;
+    assert_eq!(response.status(), 403);
+}
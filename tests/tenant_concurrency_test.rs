@@ -0,0 +1,227 @@
+// ABOUTME: Tests for the per-tenant cap on concurrent outbound provider requests
+// ABOUTME: Covers the concurrency bound under a burst, queue overflow, queue timeout, and per-tenant limits
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use pierre_mcp_server::errors::AppResult;
+use pierre_mcp_server::models::{Activity, Athlete, PersonalRecord, Stats, TenantId};
+use pierre_mcp_server::pagination::{CursorPage, PaginationParams};
+use pierre_mcp_server::providers::core::{
+    ActivityQueryParams, FitnessProvider, OAuth2Credentials, ProviderConfig,
+};
+use pierre_mcp_server::providers::errors::ProviderError;
+use pierre_mcp_server::providers::registry::ProviderRegistry;
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use pierre_mcp_server::providers::tenant_concurrency::{
+    TenantConcurrencyConfig, TenantConcurrencyLimiter,
+};
+use tokio::time::sleep;
+use uuid::Uuid;
+
+/// Tracks how many provider calls are running at once
+#[derive(Default)]
+struct ConcurrencyProbe {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// Synthetic provider whose athlete lookup takes a while and records concurrency
+struct SlowProvider {
+    inner: SyntheticProvider,
+    probe: Arc<ConcurrencyProbe>,
+}
+
+#[async_trait]
+impl FitnessProvider for SlowProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        self.inner.config()
+    }
+
+    async fn set_credentials(&self, credentials: OAuth2Credentials) -> AppResult<()> {
+        self.inner.set_credentials(credentials).await
+    }
+
+    async fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    async fn refresh_token_if_needed(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn get_athlete(&self) -> AppResult<Athlete> {
+        let running = self.probe.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.probe.peak.fetch_max(running, Ordering::SeqCst);
+        sleep(Duration::from_millis(20)).await;
+        self.probe.current.fetch_sub(1, Ordering::SeqCst);
+        self.inner.get_athlete().await
+    }
+
+    async fn get_activities_with_params(
+        &self,
+        params: &ActivityQueryParams,
+    ) -> AppResult<Vec<Activity>> {
+        self.inner.get_activities_with_params(params).await
+    }
+
+    async fn get_activities_cursor(
+        &self,
+        params: &PaginationParams,
+    ) -> AppResult<CursorPage<Activity>> {
+        self.inner.get_activities_cursor(params).await
+    }
+
+    async fn get_activity(&self, id: &str) -> AppResult<Activity> {
+        self.inner.get_activity(id).await
+    }
+
+    async fn get_stats(&self) -> AppResult<Stats> {
+        self.inner.get_stats().await
+    }
+
+    async fn get_personal_records(&self) -> AppResult<Vec<PersonalRecord>> {
+        self.inner.get_personal_records().await
+    }
+
+    async fn disconnect(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+fn limiter(
+    default_limit: usize,
+    max_queued: usize,
+    queue_timeout: Duration,
+) -> TenantConcurrencyLimiter {
+    TenantConcurrencyLimiter::new(TenantConcurrencyConfig {
+        default_limit,
+        max_queued,
+        queue_timeout,
+        ..TenantConcurrencyConfig::default()
+    })
+}
+
+#[tokio::test]
+async fn test_burst_never_exceeds_tenant_limit() {
+    let registry = ProviderRegistry::new().with_concurrency_limiter(Arc::new(limiter(
+        3,
+        64,
+        Duration::from_secs(10),
+    )));
+    let tenant_id = TenantId::new();
+    let probe = Arc::new(ConcurrencyProbe::default());
+
+    // Each call gets its own provider instance, as separate tool calls would
+    let calls = (0..24).map(|_| {
+        let provider = registry.limit_for_tenant(
+            Box::new(SlowProvider {
+                inner: SyntheticProvider::new(),
+                probe: Arc::clone(&probe),
+            }),
+            tenant_id,
+            Uuid::new_v4(),
+        );
+        async move { provider.get_athlete().await }
+    });
+    let results = join_all(calls).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert!(probe.peak.load(Ordering::SeqCst) <= 3);
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 3);
+
+    let counts = registry.concurrency_limiter().in_flight();
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts[0].tenant_id, tenant_id);
+    assert_eq!(counts[0].limit, 3);
+    assert_eq!(counts[0].in_flight, 0);
+    assert_eq!(counts[0].queued, 0);
+}
+
+#[tokio::test]
+async fn test_tenants_are_limited_independently() {
+    let limiter = limiter(1, 0, Duration::from_secs(10));
+    let busy_tenant = TenantId::new();
+
+    let _held = limiter.acquire(busy_tenant, "strava").await.unwrap();
+
+    // Another tenant still gets a slot right away
+    assert!(limiter.acquire(TenantId::new(), "strava").await.is_ok());
+
+    let busy = limiter
+        .in_flight()
+        .into_iter()
+        .find(|count| count.tenant_id == busy_tenant)
+        .unwrap();
+    assert_eq!(busy.in_flight, 1);
+}
+
+#[tokio::test]
+async fn test_full_queue_rejects_new_requests() {
+    let limiter = Arc::new(limiter(1, 1, Duration::from_secs(10)));
+    let tenant_id = TenantId::new();
+    let held = limiter.acquire(tenant_id, "strava").await.unwrap();
+
+    let waiter = {
+        let limiter = Arc::clone(&limiter);
+        tokio::spawn(async move { limiter.acquire(tenant_id, "strava").await.map(|_| ()) })
+    };
+    while limiter.in_flight()[0].queued == 0 {
+        sleep(Duration::from_millis(1)).await;
+    }
+
+    let rejected = limiter.acquire(tenant_id, "strava").await;
+    assert!(matches!(
+        rejected,
+        Err(ProviderError::RateLimitExceeded { .. })
+    ));
+
+    // The queued request gets the slot once it is released
+    drop(held);
+    assert!(waiter.await.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn test_queued_request_times_out() {
+    let limiter = limiter(1, 4, Duration::from_millis(50));
+    let tenant_id = TenantId::new();
+    let _held = limiter.acquire(tenant_id, "fitbit").await.unwrap();
+
+    let result = limiter.acquire(tenant_id, "fitbit").await;
+
+    assert!(matches!(
+        result,
+        Err(ProviderError::Timeout { ref provider, .. }) if provider == "fitbit"
+    ));
+    assert_eq!(limiter.in_flight()[0].queued, 0);
+}
+
+#[test]
+fn test_per_tenant_limit_overrides_default() {
+    let large_tenant = TenantId::new();
+    let blocked_tenant = TenantId::new();
+    let config = TenantConcurrencyConfig::default()
+        .with_tenant_limit(large_tenant, 32)
+        .with_tenant_limit(blocked_tenant, 0);
+
+    assert_eq!(config.limit_for(large_tenant), 32);
+    assert_eq!(
+        config.limit_for(TenantId::new()),
+        TenantConcurrencyConfig::default().default_limit
+    );
+    // A zero limit would block the tenant forever, so it is raised to one
+    assert_eq!(config.limit_for(blocked_tenant), 1);
+}
@@ -0,0 +1,145 @@
+// ABOUTME: Tests for per-tenant data keys derived from the database encryption key
+// ABOUTME: Verifies tenant isolation of ciphertext and re-encryption of secrets stored under the DEK
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use pierre_mcp_server::database_plugins::shared::encryption::{
+    decrypt_with_key, derive_tenant_data_key, encrypt_with_key, is_tenant_encrypted, HasEncryption,
+    TENANT_KEY_PREFIX,
+};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::TenantId;
+use pierre_mcp_server::tenant::oauth_manager::TenantOAuthCredentials;
+use sqlx::Row;
+
+const DEK: [u8; 32] = [7u8; 32];
+
+#[test]
+fn test_tenant_ciphertext_needs_that_tenants_key() {
+    let tenant_a = TenantId::new();
+    let tenant_b = TenantId::new();
+    let key_a = derive_tenant_data_key(&DEK, tenant_a).unwrap();
+    let key_b = derive_tenant_data_key(&DEK, tenant_b).unwrap();
+
+    assert_eq!(key_a, derive_tenant_data_key(&DEK, tenant_a).unwrap());
+    assert_ne!(key_a, key_b);
+    assert_ne!(key_a, DEK);
+
+    let aad = "shared|aad|context";
+    let encrypted = encrypt_with_key(&key_a, "client-secret", aad).unwrap();
+    assert_eq!(
+        decrypt_with_key(&key_a, &encrypted, aad).unwrap(),
+        "client-secret"
+    );
+    assert!(decrypt_with_key(&key_b, &encrypted, aad).is_err());
+    assert!(decrypt_with_key(&DEK, &encrypted, aad).is_err());
+}
+
+#[tokio::test]
+async fn test_database_tenant_encryption_is_isolated_per_tenant() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let database = resources.database.as_ref();
+    let tenant_a = TenantId::new();
+    let tenant_b = TenantId::new();
+    let aad = "webhook|aad";
+
+    let encrypted = database
+        .encrypt_data_for_tenant(tenant_a, "signing-secret", aad)
+        .unwrap();
+    assert!(is_tenant_encrypted(&encrypted));
+    assert_eq!(
+        database
+            .decrypt_data_for_tenant(tenant_a, &encrypted, aad)
+            .unwrap(),
+        "signing-secret"
+    );
+
+    // Neither another tenant's key nor the global DEK opens it
+    assert!(database
+        .decrypt_data_for_tenant(tenant_b, &encrypted, aad)
+        .is_err());
+    let sealed = encrypted.strip_prefix(TENANT_KEY_PREFIX).unwrap();
+    assert!(HasEncryption::decrypt_data_with_aad(database, sealed, aad).is_err());
+
+    // Values stored under the DEK before tenant keys still decrypt
+    let legacy = HasEncryption::encrypt_data_with_aad(database, "signing-secret", aad).unwrap();
+    assert!(!is_tenant_encrypted(&legacy));
+    assert_eq!(
+        database
+            .decrypt_data_for_tenant(tenant_a, &legacy, aad)
+            .unwrap(),
+        "signing-secret"
+    );
+}
+
+#[tokio::test]
+async fn test_reencrypt_moves_legacy_secrets_to_tenant_key() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let (user_id, _) = common::create_test_user(&resources.database).await.unwrap();
+    let tenant_id = resources
+        .database
+        .list_tenants_for_user(user_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let database = resources.database.as_ref();
+
+    database
+        .store_tenant_oauth_credentials(&TenantOAuthCredentials {
+            tenant_id,
+            provider: "strava".to_owned(),
+            client_id: "client-id".to_owned(),
+            client_secret: "new-secret".to_owned(),
+            previous_client_secret: None,
+            redirect_uri: "https://example.com/callback".to_owned(),
+            scopes: vec!["read".to_owned()],
+            rate_limit_per_day: 1000,
+        })
+        .await
+        .unwrap();
+
+    let pool = database.sqlite_pool().unwrap();
+    let stored_secret = || async {
+        sqlx::query(
+            "SELECT client_secret_encrypted FROM tenant_oauth_credentials WHERE tenant_id = ?",
+        )
+        .bind(tenant_id.to_string())
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .get::<String, _>("client_secret_encrypted")
+    };
+    assert!(is_tenant_encrypted(&stored_secret().await));
+
+    // Rewind the row to how older versions stored it, under the global DEK
+    let aad = format!("{tenant_id}|strava|tenant_oauth_credentials");
+    let legacy = HasEncryption::encrypt_data_with_aad(database, "legacy-secret", &aad).unwrap();
+    sqlx::query("UPDATE tenant_oauth_credentials SET client_secret_encrypted = ?")
+        .bind(&legacy)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    assert_eq!(database.reencrypt_tenant_secrets().await.unwrap(), 1);
+    let migrated = stored_secret().await;
+    assert!(is_tenant_encrypted(&migrated));
+    assert!(database
+        .decrypt_data_for_tenant(TenantId::new(), &migrated, &aad)
+        .is_err());
+
+    let credentials = database
+        .get_tenant_oauth_credentials(tenant_id, "strava")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(credentials.client_secret, "legacy-secret");
+
+    // Already migrated rows are skipped
+    assert_eq!(database.reencrypt_tenant_secrets().await.unwrap(), 0);
+}
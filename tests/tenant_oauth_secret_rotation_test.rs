@@ -0,0 +1,302 @@
+// ABOUTME: Tests for rotating tenant OAuth client secrets with a grace period
+// ABOUTME: Covers dual-secret decryption, fallback to the previous secret, expiry, promotion, and purging
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use pierre_mcp_server::config::environment::OAuthConfig;
+use pierre_mcp_server::database_plugins::{factory::Database, DatabaseProvider};
+use pierre_mcp_server::models::TenantId;
+use pierre_mcp_server::tenant::{TenantOAuthClient, TenantOAuthCredentials, TenantOAuthManager};
+use std::sync::Arc;
+use uuid::Uuid;
+
+mod common;
+
+const OLD_SECRET: &str = "strava_secret_v1";
+const NEW_SECRET: &str = "strava_secret_v2";
+
+/// Create a tenant with stored Strava credentials using `OLD_SECRET`
+async fn setup_tenant(database: &Database) -> Result<TenantId> {
+    let email = format!("rotation-{}@example.com", Uuid::new_v4());
+    let (user_id, _user) = common::create_test_user_with_email(database, &email).await?;
+    let tenant_id = database.list_tenants_for_user(user_id).await?[0].id;
+
+    database
+        .store_tenant_oauth_credentials(&TenantOAuthCredentials {
+            tenant_id,
+            provider: "strava".to_owned(),
+            client_id: "strava_client_id".to_owned(),
+            client_secret: OLD_SECRET.to_owned(),
+            previous_client_secret: None,
+            redirect_uri: "http://localhost:8081/api/oauth/callback/strava".to_owned(),
+            scopes: vec!["read".to_owned()],
+            rate_limit_per_day: 1000,
+        })
+        .await?;
+    Ok(tenant_id)
+}
+
+async fn load_strava(database: &Database, tenant_id: TenantId) -> Result<TenantOAuthCredentials> {
+    Ok(database
+        .get_tenant_oauth_credentials(tenant_id, "strava")
+        .await?
+        .expect("strava credentials stored"))
+}
+
+#[tokio::test]
+async fn test_both_secrets_decrypt_during_grace_window() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+
+    database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Utc::now() + Duration::hours(1),
+        )
+        .await?;
+
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, NEW_SECRET);
+    assert_eq!(
+        credentials.previous_client_secret.as_deref(),
+        Some(OLD_SECRET)
+    );
+    assert_eq!(credentials.client_id, "strava_client_id");
+
+    // Listing providers resolves the same pair of secrets
+    let listed = database.get_tenant_oauth_providers(tenant_id).await?;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].client_secret, NEW_SECRET);
+    assert_eq!(
+        listed[0].previous_client_secret.as_deref(),
+        Some(OLD_SECRET)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_previous_secret_is_dropped_once_grace_period_ends() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+
+    database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Utc::now() - Duration::minutes(1),
+        )
+        .await?;
+
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, NEW_SECRET);
+    assert!(credentials.previous_client_secret.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_promotion_purges_previous_secret() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+
+    database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Utc::now() + Duration::hours(1),
+        )
+        .await?;
+    assert!(
+        database
+            .promote_tenant_oauth_secret(tenant_id, "strava")
+            .await?
+    );
+
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, NEW_SECRET);
+    assert!(credentials.previous_client_secret.is_none());
+
+    // Nothing left to promote
+    assert!(
+        !database
+            .promote_tenant_oauth_secret(tenant_id, "strava")
+            .await?
+    );
+
+    // The next rotation falls back to the promoted secret, not the purged original
+    database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "strava",
+            "strava_secret_v3",
+            Utc::now() + Duration::hours(1),
+        )
+        .await?;
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, "strava_secret_v3");
+    assert_eq!(
+        credentials.previous_client_secret.as_deref(),
+        Some(NEW_SECRET)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storing_credentials_discards_pending_rotation() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+
+    database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Utc::now() + Duration::hours(1),
+        )
+        .await?;
+
+    let mut replacement = load_strava(&database, tenant_id).await?;
+    replacement.client_secret = "replacement_secret".to_owned();
+    replacement.previous_client_secret = None;
+    database
+        .store_tenant_oauth_credentials(&replacement)
+        .await?;
+
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, "replacement_secret");
+    assert!(credentials.previous_client_secret.is_none());
+    assert!(
+        !database
+            .promote_tenant_oauth_secret(tenant_id, "strava")
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rotating_requires_existing_credentials() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+
+    let result = database
+        .rotate_tenant_oauth_secret(
+            tenant_id,
+            "fitbit",
+            NEW_SECRET,
+            Utc::now() + Duration::hours(1),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let client = TenantOAuthClient::new(TenantOAuthManager::new(Arc::new(OAuthConfig::default())));
+    let result = client
+        .rotate_secret(tenant_id, "strava", "  ", Duration::hours(1), &database)
+        .await;
+    assert!(result.is_err(), "empty secrets must be rejected");
+    assert!(load_strava(&database, tenant_id)
+        .await?
+        .previous_client_secret
+        .is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_rotation_takes_effect_immediately() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+    let client = TenantOAuthClient::new(TenantOAuthManager::new(Arc::new(OAuthConfig::default())));
+
+    client
+        .rotate_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Duration::hours(24),
+            &database,
+        )
+        .await?;
+
+    let credentials = client
+        .get_tenant_credentials(tenant_id, "strava", &database)
+        .await?
+        .expect("tenant credentials");
+    assert_eq!(credentials.client_secret, NEW_SECRET);
+    assert_eq!(
+        credentials.previous_client_secret.as_deref(),
+        Some(OLD_SECRET)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_secret_fallback_uses_previous_secret_until_new_one_is_accepted() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let tenant_id = setup_tenant(&database).await?;
+    let client = TenantOAuthClient::new(TenantOAuthManager::new(Arc::new(OAuthConfig::default())));
+    client
+        .rotate_secret(
+            tenant_id,
+            "strava",
+            NEW_SECRET,
+            Duration::hours(24),
+            &database,
+        )
+        .await?;
+
+    // The provider does not know the new secret yet: the previous one is used
+    // and the rotation stays pending
+    let credentials = load_strava(&database, tenant_id).await?;
+    let used = client
+        .with_secret_fallback(&credentials, &database, |secret| async move {
+            if secret == OLD_SECRET {
+                Ok(secret)
+            } else {
+                Err(format!("invalid_client: {secret}"))
+            }
+        })
+        .await;
+    assert_eq!(used.as_deref(), Ok(OLD_SECRET));
+    assert_eq!(
+        load_strava(&database, tenant_id)
+            .await?
+            .previous_client_secret
+            .as_deref(),
+        Some(OLD_SECRET)
+    );
+
+    // Once the provider accepts the new secret, it is promoted
+    let used = client
+        .with_secret_fallback(&credentials, &database, |secret| async move {
+            Ok::<_, String>(secret)
+        })
+        .await;
+    assert_eq!(used.as_deref(), Ok(NEW_SECRET));
+    let credentials = load_strava(&database, tenant_id).await?;
+    assert_eq!(credentials.client_secret, NEW_SECRET);
+    assert!(credentials.previous_client_secret.is_none());
+
+    // Without a previous secret a rejection is final
+    let result = client
+        .with_secret_fallback(&credentials, &database, |secret| async move {
+            Err::<String, _>(format!("invalid_client: {secret}"))
+        })
+        .await;
+    assert_eq!(result, Err(format!("invalid_client: {NEW_SECRET}")));
+
+    Ok(())
+}
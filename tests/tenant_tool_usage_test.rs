@@ -0,0 +1,219 @@
+// ABOUTME: Tests for tenant-wide tool usage analytics aggregated across tenant members
+// ABOUTME: Verifies member usage roll-up, outsider exclusion, and owner/admin-only access
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{create_and_store_test_api_key, create_test_database, create_test_user_with_email};
+use pierre_mcp_server::{
+    api_keys::ApiKeyUsage,
+    auth::{AuthMethod, AuthResult},
+    database_plugins::{factory::Database, DatabaseProvider},
+    errors::ErrorCode,
+    models::TenantId,
+    rate_limiting::UnifiedRateLimitInfo,
+    tenant_routes::{get_tenant_tool_usage, TenantToolUsageQuery},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn auth_result(user_id: Uuid) -> AuthResult {
+    AuthResult {
+        user_id,
+        auth_method: AuthMethod::JwtToken {
+            tier: "premium".to_owned(),
+        },
+        rate_limit: UnifiedRateLimitInfo {
+            is_rate_limited: false,
+            limit: Some(1000),
+            remaining: Some(1000),
+            reset_at: None,
+            tier: "premium".to_owned(),
+            auth_method: "jwt".to_owned(),
+        },
+        active_tenant_id: None,
+    }
+}
+
+async fn add_tenant_member(database: &Database, tenant_id: TenantId, user_id: Uuid, role: &str) {
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO tenant_users (id, tenant_id, user_id, role, invited_at, joined_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(tenant_id.to_string())
+    .bind(user_id.to_string())
+    .bind(role)
+    .bind(&now)
+    .bind(&now)
+    .execute(database.sqlite_pool().unwrap())
+    .await
+    .unwrap();
+}
+
+/// Record `count` calls of `tool_name` against a fresh API key for `user_id`
+async fn record_usage(database: &Database, user_id: Uuid, tool_name: &str, count: usize) {
+    let api_key = create_and_store_test_api_key(database, user_id, tool_name)
+        .await
+        .unwrap();
+    for i in 0..count {
+        database
+            .record_api_key_usage(&ApiKeyUsage {
+                id: None,
+                api_key_id: api_key.id.clone(),
+                timestamp: Utc::now() - Duration::minutes(i64::try_from(i).unwrap() + 1),
+                tool_name: tool_name.to_owned(),
+                response_time_ms: Some(100),
+                status_code: 200,
+                error_message: None,
+                request_size_bytes: None,
+                response_size_bytes: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+    }
+}
+
+struct TenantFixture {
+    database: Arc<Database>,
+    tenant_id: TenantId,
+    owner_id: Uuid,
+    admin_id: Uuid,
+    member_id: Uuid,
+}
+
+/// Owner, admin and member each use tools; an outsider's usage must not count
+async fn setup() -> TenantFixture {
+    let database = create_test_database().await.unwrap();
+    let (owner_id, _) = create_test_user_with_email(&database, "owner@example.com")
+        .await
+        .unwrap();
+    let (admin_id, _) = create_test_user_with_email(&database, "admin@example.com")
+        .await
+        .unwrap();
+    let (member_id, _) = create_test_user_with_email(&database, "member@example.com")
+        .await
+        .unwrap();
+    let (outsider_id, _) = create_test_user_with_email(&database, "outsider@example.com")
+        .await
+        .unwrap();
+
+    let tenant_id = database.list_tenants_for_user(owner_id).await.unwrap()[0].id;
+    add_tenant_member(&database, tenant_id, admin_id, "admin").await;
+    add_tenant_member(&database, tenant_id, member_id, "member").await;
+
+    record_usage(&database, owner_id, "get_activities", 3).await;
+    record_usage(&database, admin_id, "get_activities", 2).await;
+    record_usage(&database, member_id, "get_activities", 4).await;
+    record_usage(&database, member_id, "analyze_activity", 1).await;
+    record_usage(&database, outsider_id, "get_activities", 10).await;
+
+    TenantFixture {
+        database,
+        tenant_id,
+        owner_id,
+        admin_id,
+        member_id,
+    }
+}
+
+#[tokio::test]
+async fn test_tenant_roll_up_sums_member_usage() {
+    let fixture = setup().await;
+
+    let response = get_tenant_tool_usage(
+        fixture.tenant_id.to_string(),
+        TenantToolUsageQuery::default(),
+        auth_result(fixture.owner_id),
+        fixture.database.clone(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.tenant_id, fixture.tenant_id.to_string());
+    assert_eq!(response.total_requests, 10);
+    assert_eq!(response.tools.len(), 2);
+    assert_eq!(response.tools[0].tool_name, "get_activities");
+    assert_eq!(response.tools[0].request_count, 9);
+    assert_eq!(response.tools[1].tool_name, "analyze_activity");
+    assert_eq!(response.tools[1].request_count, 1);
+    assert!((response.tools[0].success_rate - 100.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_tenant_roll_up_respects_range_and_limit() {
+    let fixture = setup().await;
+
+    let query = TenantToolUsageQuery {
+        start_date: Some(Utc::now() - Duration::seconds(150)),
+        end_date: Some(Utc::now()),
+        limit: Some(1),
+    };
+    let response = get_tenant_tool_usage(
+        fixture.tenant_id.to_string(),
+        query,
+        auth_result(fixture.admin_id),
+        fixture.database.clone(),
+    )
+    .await
+    .unwrap();
+
+    // Only the calls recorded 1 and 2 minutes ago fall within the range
+    assert_eq!(response.total_requests, 7);
+    assert_eq!(response.tools.len(), 1);
+    assert_eq!(response.tools[0].tool_name, "get_activities");
+    assert_eq!(response.tools[0].request_count, 6);
+}
+
+#[tokio::test]
+async fn test_tenant_roll_up_denies_non_admins() {
+    let fixture = setup().await;
+
+    let err = get_tenant_tool_usage(
+        fixture.tenant_id.to_string(),
+        TenantToolUsageQuery::default(),
+        auth_result(fixture.member_id),
+        fixture.database.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code, ErrorCode::PermissionDenied);
+
+    let err = get_tenant_tool_usage(
+        fixture.tenant_id.to_string(),
+        TenantToolUsageQuery::default(),
+        auth_result(Uuid::new_v4()),
+        fixture.database.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code, ErrorCode::PermissionDenied);
+}
+
+#[tokio::test]
+async fn test_tenant_roll_up_rejects_inverted_range() {
+    let fixture = setup().await;
+
+    let query = TenantToolUsageQuery {
+        start_date: Some(Utc::now()),
+        end_date: Some(Utc::now() - Duration::days(1)),
+        limit: None,
+    };
+    let err = get_tenant_tool_usage(
+        fixture.tenant_id.to_string(),
+        query,
+        auth_result(fixture.owner_id),
+        fixture.database.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidInput);
+}
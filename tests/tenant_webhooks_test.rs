@@ -0,0 +1,262 @@
+// ABOUTME: Tests for signed outbound tenant webhooks
+// ABOUTME: Covers HMAC signature generation, retry-then-success delivery, and dead-lettering
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use chrono::Utc;
+use pierre_mcp_server::database_plugins::{factory::Database, DatabaseProvider};
+use pierre_mcp_server::services::webhook_delivery::{WebhookDispatcher, WebhookRetryPolicy};
+use pierre_mcp_server::tenant::webhooks::{
+    sign_payload, verify_signature, TenantWebhook, WebhookDeliveryStatus, WebhookEventType,
+    DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER,
+};
+use serde_json::json;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+mod common;
+
+const SECRET: &str = "whsec_test_secret";
+
+/// A request received by the test receiver
+#[derive(Clone)]
+struct Received {
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+/// Test receiver that answers with the scripted statuses, then 200
+#[derive(Clone)]
+struct Receiver {
+    statuses: Arc<Mutex<Vec<StatusCode>>>,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+async fn receive(State(receiver): State<Receiver>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    receiver
+        .received
+        .lock()
+        .unwrap()
+        .push(Received { headers, body });
+    let mut statuses = receiver.statuses.lock().unwrap();
+    if statuses.is_empty() {
+        StatusCode::OK
+    } else {
+        statuses.remove(0)
+    }
+}
+
+/// Start a receiver on a random local port and return its URL
+async fn start_receiver(statuses: Vec<StatusCode>) -> Result<(String, Receiver)> {
+    let receiver = Receiver {
+        statuses: Arc::new(Mutex::new(statuses)),
+        received: Arc::new(Mutex::new(Vec::new())),
+    };
+    let app = Router::new()
+        .route("/hook", post(receive))
+        .with_state(receiver.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}/hook", listener.local_addr()?);
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    Ok((url, receiver))
+}
+
+/// Create a tenant with one webhook subscribed to `goal.completed`
+async fn setup_webhook(url: &str) -> Result<(Arc<Database>, TenantWebhook)> {
+    let database = common::create_test_database().await?;
+    let email = format!("webhooks-{}@example.com", Uuid::new_v4());
+    let (user_id, _user) = common::create_test_user_with_email(&database, &email).await?;
+    let tenant_id = database.list_tenants_for_user(user_id).await?[0].id;
+
+    let now = Utc::now();
+    let webhook = TenantWebhook {
+        id: Uuid::new_v4(),
+        tenant_id,
+        url: url.to_owned(),
+        secret: SECRET.to_owned(),
+        event_types: vec![WebhookEventType::GoalCompleted],
+        active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    database.create_tenant_webhook(&webhook).await?;
+    Ok((database, webhook))
+}
+
+fn immediate_retries(max_attempts: u32) -> WebhookRetryPolicy {
+    WebhookRetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    }
+}
+
+#[test]
+fn test_signature_generation_and_verification() {
+    let body = br#"{"hello":"world"}"#;
+    let signature = sign_payload(SECRET, 1_700_000_000, body);
+    assert_eq!(
+        signature,
+        "t=1700000000,v1=86748dbec9cc87a9219f8a96632da703271bf5e85aa0afa2b310c37ba059d514"
+    );
+
+    assert!(verify_signature(SECRET, &signature, body));
+    assert!(!verify_signature(
+        SECRET,
+        &signature,
+        br#"{"hello":"there"}"#
+    ));
+    assert!(!verify_signature("whsec_other_secret", &signature, body));
+    // The timestamp is signed, so it cannot be replaced to dodge a replay window
+    let replayed = signature.replace("t=1700000000", "t=1800000000");
+    assert!(!verify_signature(SECRET, &replayed, body));
+    assert!(!verify_signature(SECRET, "garbage", body));
+}
+
+#[tokio::test]
+async fn test_retry_then_success() -> Result<()> {
+    let (url, receiver) = start_receiver(vec![
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::BAD_GATEWAY,
+    ])
+    .await?;
+    let (database, webhook) = setup_webhook(&url).await?;
+    let dispatcher =
+        WebhookDispatcher::new(Arc::clone(&database))?.with_retry_policy(immediate_retries(5));
+
+    let queued = dispatcher
+        .enqueue(
+            webhook.tenant_id,
+            WebhookEventType::GoalCompleted,
+            json!({ "goal_id": "goal-1" }),
+        )
+        .await?;
+    assert_eq!(queued.len(), 1);
+    // Not subscribed, so nothing is queued
+    let ignored = dispatcher
+        .enqueue(
+            webhook.tenant_id,
+            WebhookEventType::ActivitySynced,
+            json!({}),
+        )
+        .await?;
+    assert!(ignored.is_empty());
+
+    for _ in 0..3 {
+        assert_eq!(dispatcher.process_due_deliveries().await?, 1);
+    }
+    assert_eq!(dispatcher.process_due_deliveries().await?, 0);
+
+    let deliveries = database
+        .list_webhook_deliveries(webhook.id, None, 10)
+        .await?;
+    assert_eq!(deliveries.len(), 1);
+    let delivery = &deliveries[0];
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(delivery.attempts, 3);
+    assert_eq!(delivery.last_status_code, Some(200));
+    assert!(delivery.last_error.is_none());
+    assert!(delivery.completed_at.is_some());
+
+    let attempts = database.list_webhook_delivery_attempts(delivery.id).await?;
+    let statuses: Vec<_> = attempts.iter().map(|a| a.status_code).collect();
+    assert_eq!(statuses, vec![Some(503), Some(502), Some(200)]);
+    assert_eq!(
+        attempts.iter().map(|a| a.attempt).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let received = receiver.received.lock().unwrap().clone();
+    assert_eq!(received.len(), 3);
+    for request in &received {
+        let signature = request.headers[SIGNATURE_HEADER].to_str()?;
+        assert!(verify_signature(SECRET, signature, &request.body));
+        assert_eq!(request.headers[EVENT_HEADER], "goal.completed");
+        // Receivers deduplicate on a delivery ID that is stable across retries
+        assert_eq!(
+            request.headers[DELIVERY_HEADER].to_str()?,
+            delivery.id.to_string()
+        );
+        assert_eq!(request.body, received[0].body);
+    }
+    let payload: serde_json::Value = serde_json::from_slice(&received[0].body)?;
+    assert_eq!(payload["type"], "goal.completed");
+    assert_eq!(payload["data"]["goal_id"], "goal-1");
+    assert_eq!(payload["tenant_id"], webhook.tenant_id.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dead_letter_after_max_attempts() -> Result<()> {
+    let (url, receiver) = start_receiver(vec![StatusCode::INTERNAL_SERVER_ERROR; 10]).await?;
+    let (database, webhook) = setup_webhook(&url).await?;
+    let dispatcher =
+        WebhookDispatcher::new(Arc::clone(&database))?.with_retry_policy(immediate_retries(3));
+
+    dispatcher
+        .enqueue(
+            webhook.tenant_id,
+            WebhookEventType::GoalCompleted,
+            json!({ "goal_id": "goal-2" }),
+        )
+        .await?;
+    for _ in 0..3 {
+        assert_eq!(dispatcher.process_due_deliveries().await?, 1);
+    }
+    // Dead letters are never picked up again
+    assert_eq!(dispatcher.process_due_deliveries().await?, 0);
+    assert_eq!(receiver.received.lock().unwrap().len(), 3);
+
+    let dead = database
+        .list_webhook_deliveries(webhook.id, Some(WebhookDeliveryStatus::DeadLetter), 10)
+        .await?;
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].attempts, 3);
+    assert_eq!(dead[0].last_status_code, Some(500));
+    assert!(dead[0].last_error.as_deref().unwrap().contains("500"));
+    assert_eq!(
+        database
+            .list_webhook_delivery_attempts(dead[0].id)
+            .await?
+            .len(),
+        3
+    );
+
+    // Client errors are not retried
+    *receiver.statuses.lock().unwrap() = vec![StatusCode::GONE];
+    dispatcher
+        .enqueue(
+            webhook.tenant_id,
+            WebhookEventType::GoalCompleted,
+            json!({ "goal_id": "goal-3" }),
+        )
+        .await?;
+    assert_eq!(dispatcher.process_due_deliveries().await?, 1);
+    assert_eq!(dispatcher.process_due_deliveries().await?, 0);
+    let dead = database
+        .list_webhook_deliveries(webhook.id, Some(WebhookDeliveryStatus::DeadLetter), 10)
+        .await?;
+    assert_eq!(dead.len(), 2);
+    assert_eq!(dead[0].attempts, 1);
+    assert_eq!(dead[0].last_status_code, Some(410));
+
+    Ok(())
+}
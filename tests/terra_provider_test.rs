@@ -16,7 +16,7 @@
 #![allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
 #![allow(missing_docs)]
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use pierre_mcp_server::models::{
     Activity, ActivityBuilder, HealthMetrics, MealType, RecoveryMetrics, SleepSession, SportType,
 };
@@ -30,16 +30,35 @@ use pierre_mcp_server::providers::terra::webhook::{
     SignatureValidation, WebhookResult, WebhookSignatureValidator,
 };
 use pierre_mcp_server::providers::terra::{
-    TerraApiClient, TerraApiConfig, TerraConverters, TerraDataCache, TerraDescriptor,
-    TerraProvider, TerraWebhookHandler,
+    CacheClock, TerraApiClient, TerraApiConfig, TerraCacheConfig, TerraCacheMetrics,
+    TerraConverters, TerraDataCache, TerraDescriptor, TerraProvider, TerraWebhookHandler,
 };
 use ring::hmac;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Clock that only moves when the test advances it
+struct ManualClock(Mutex<DateTime<Utc>>);
+
+impl ManualClock {
+    fn new() -> Arc<Self> {
+        Arc::new(Self(Mutex::new(Utc::now())))
+    }
+
+    fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl CacheClock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
 fn make_test_user() -> TerraUser {
     TerraUser {
         user_id: "test_user_123".to_owned(),
@@ -224,6 +243,99 @@ async fn test_cache_stats() {
     assert_eq!(stats.total_activities, 3);
 }
 
+#[tokio::test]
+async fn test_cache_entries_expire_after_ttl() {
+    let clock = ManualClock::new();
+    let config = TerraCacheConfig {
+        ttl: Duration::hours(1),
+        ..TerraCacheConfig::default()
+    };
+    let cache = TerraDataCache::with_clock(config, Arc::clone(&clock));
+
+    cache
+        .store_activity("user1", make_test_activity("a1", 1))
+        .await;
+    assert!(!cache.needs_refresh("user1").await);
+
+    clock.advance(Duration::minutes(59));
+    assert_eq!(cache.get_activities("user1", None, None).await.len(), 1);
+    assert!(cache.get_activity("user1", "a1").await.is_some());
+
+    clock.advance(Duration::minutes(2));
+    assert!(cache.get_activities("user1", None, None).await.is_empty());
+    assert!(cache.get_activity("user1", "a1").await.is_none());
+    assert!(cache.needs_refresh("user1").await);
+
+    // A requested re-fetch counts as fresh until the TTL passes again
+    cache.mark_refresh_requested("user1").await;
+    assert!(!cache.needs_refresh("user1").await);
+    clock.advance(Duration::hours(1));
+    assert!(cache.needs_refresh("user1").await);
+
+    cache.cleanup_expired().await;
+    assert_eq!(cache.get_stats().await.user_count, 0);
+    assert!(cache.needs_refresh("unknown_user").await);
+}
+
+#[tokio::test]
+async fn test_cache_evicts_least_recently_used_user() {
+    let config = TerraCacheConfig {
+        max_users: 2,
+        ..TerraCacheConfig::default()
+    };
+    let cache = TerraDataCache::with_config(config);
+
+    cache
+        .store_activity("user1", make_test_activity("a1", 1))
+        .await;
+    cache
+        .store_activity("user2", make_test_activity("a2", 1))
+        .await;
+
+    // Reading user1 makes user2 the least recently used
+    assert_eq!(cache.get_activities("user1", None, None).await.len(), 1);
+    cache
+        .store_activity("user3", make_test_activity("a3", 1))
+        .await;
+
+    assert!(cache.get_activities("user2", None, None).await.is_empty());
+    assert_eq!(cache.get_activities("user1", None, None).await.len(), 1);
+    assert_eq!(cache.get_activities("user3", None, None).await.len(), 1);
+    assert_eq!(cache.get_stats().await.user_count, 2);
+    assert_eq!(cache.stats().evictions, 1);
+
+    // Adding data for a cached user never evicts
+    cache
+        .store_activity("user1", make_test_activity("a4", 2))
+        .await;
+    assert_eq!(cache.stats().evictions, 1);
+}
+
+#[tokio::test]
+async fn test_cache_counts_hits_and_misses() {
+    let cache = TerraDataCache::new_in_memory();
+    assert_eq!(cache.stats(), TerraCacheMetrics::default());
+
+    cache
+        .store_activity("user1", make_test_activity("a1", 1))
+        .await;
+
+    cache.get_activities("user1", None, None).await;
+    cache.get_activity("user1", "a1").await;
+    cache.get_activity("user1", "missing").await;
+    cache.get_activities("user2", None, None).await;
+    cache.get_latest_sleep_session("user1").await;
+
+    assert_eq!(
+        cache.stats(),
+        TerraCacheMetrics {
+            hits: 2,
+            misses: 3,
+            evictions: 0,
+        }
+    );
+}
+
 // ============================================================================
 // Provider Tests
 // ============================================================================
@@ -438,6 +438,7 @@ async fn setup_tenant_oauth_credentials(
         provider: "strava".to_owned(),
         client_id: client_id.clone(),
         client_secret: client_secret.clone(),
+        previous_client_secret: None,
         redirect_uri: env::var("STRAVA_REDIRECT_URI")
             .unwrap_or_else(|_| "http://localhost:8080/auth/strava/callback".to_owned()),
         scopes: vec![
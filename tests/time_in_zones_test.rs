@@ -0,0 +1,207 @@
+// ABOUTME: Tests for time-in-zone computation from heart rate and power streams
+// ABOUTME: Covers duration-weighted zone breakdowns, Strava athlete zones, and the no_heart_rate_data error
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::intelligence::TimeInZonesCalculator;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType, TimeSeriesData, ZoneRange};
+use pierre_mcp_server::providers::strava_provider::{StravaProvider, StravaZonesResponse};
+use pierre_mcp_server::tools::implementations::analytics::build_time_in_zones_result;
+
+/// GET /athlete/zones for an athlete with custom heart rate zones and an FTP of 250 W
+const STRAVA_ZONES_JSON: &str = r#"{
+    "heart_rate": {
+        "custom_zones": true,
+        "zones": [
+            {"min": 0, "max": 120},
+            {"min": 120, "max": 140},
+            {"min": 140, "max": 160},
+            {"min": 160, "max": 175},
+            {"min": 175, "max": -1}
+        ]
+    },
+    "power": {
+        "zones": [
+            {"min": 0, "max": 137},
+            {"min": 138, "max": 187},
+            {"min": 188, "max": 225},
+            {"min": 226, "max": 262},
+            {"min": 263, "max": 300},
+            {"min": 301, "max": 375},
+            {"min": 376, "max": -1}
+        ]
+    }
+}"#;
+
+/// Zones at 60/70/80/90% of a 200 bpm max: <120, 120-140, 140-160, 160-180, 180+
+fn config_zones() -> TimeInZonesCalculator {
+    TimeInZonesCalculator::heart_rate_from_max_hr(200)
+}
+
+/// One sample per second: 10 min easy, 20 min endurance, 15 min tempo, 10 min threshold, 5 min VO2max
+fn structured_workout() -> (Vec<u32>, Vec<u32>) {
+    let blocks = [(110, 600), (130, 1200), (150, 900), (170, 600), (185, 300)];
+    let heart_rate: Vec<u32> = blocks
+        .iter()
+        .flat_map(|&(bpm, seconds)| std::iter::repeat_n(bpm, seconds))
+        .collect();
+    let timestamps = (0..).take(heart_rate.len()).collect();
+    (timestamps, heart_rate)
+}
+
+fn run_with_series(id: &str, series: Option<TimeSeriesData>) -> Activity {
+    ActivityBuilder::new(
+        id,
+        "Structured Run",
+        SportType::Run,
+        Utc.with_ymd_and_hms(2025, 6, 3, 7, 0, 0).unwrap(),
+        3600,
+        "synthetic",
+    )
+    .time_series_data_opt(series)
+    .build()
+}
+
+fn heart_rate_series(timestamps: Vec<u32>, heart_rate: Vec<u32>) -> TimeSeriesData {
+    TimeSeriesData {
+        timestamps,
+        heart_rate: Some(heart_rate),
+        power: None,
+        cadence: None,
+        speed: None,
+        altitude: None,
+        temperature: None,
+        gps_coordinates: None,
+    }
+}
+
+#[test]
+fn test_synthetic_stream_time_breakdown() {
+    let (timestamps, heart_rate) = structured_workout();
+    let time = config_zones().compute(&timestamps, &heart_rate);
+
+    let seconds: Vec<f64> = time.zones.iter().map(|zone| zone.seconds).collect();
+    assert_eq!(seconds, [600.0, 1200.0, 900.0, 600.0, 300.0]);
+    assert!((time.total_seconds - 3600.0).abs() < f64::EPSILON);
+
+    let distribution = time.distribution().unwrap();
+    assert!((distribution.zone1_recovery - 16.666_666).abs() < 0.01);
+    assert!((distribution.zone2_endurance - 33.333_332).abs() < 0.01);
+    assert!((distribution.zone3_tempo - 25.0).abs() < 0.01);
+    assert!((distribution.zone4_threshold - 16.666_666).abs() < 0.01);
+    assert!((distribution.zone5_vo2max - 8.333_333).abs() < 0.01);
+
+    assert_eq!(time.zones[4].min, 180);
+    assert_eq!(time.zones[4].max, None);
+}
+
+#[test]
+fn test_sparse_stream_weighted_by_interval() {
+    // Garmin-style samples every 15 seconds: 2 min in zone 2 then 1 min in zone 4
+    let timestamps: Vec<u32> = (0..12).map(|i| i * 15).collect();
+    let heart_rate = [vec![130; 8], vec![165; 4]].concat();
+
+    let time = config_zones().compute(&timestamps, &heart_rate);
+
+    assert!((time.zones[1].seconds - 120.0).abs() < f64::EPSILON);
+    // The last sample lasts as long as the interval before it
+    assert!((time.zones[3].seconds - 60.0).abs() < f64::EPSILON);
+    assert!((time.total_seconds - 180.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_pauses_and_dropouts_not_counted() {
+    // Ten minutes of auto-pause between two samples, then a strap dropout
+    let timestamps = vec![0, 10, 20, 620, 630, 640];
+    let heart_rate = vec![150, 150, 150, 150, 0, 150];
+
+    let time = config_zones().compute(&timestamps, &heart_rate);
+
+    // 10 + 10 + 60 (capped gap) + 10 + 10 (last sample); the zero reading is skipped
+    assert!((time.zones[2].seconds - 100.0).abs() < f64::EPSILON);
+    assert!((time.total_seconds - 100.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_strava_athlete_zones_conversion() {
+    let response: StravaZonesResponse = serde_json::from_str(STRAVA_ZONES_JSON).unwrap();
+    let zones = StravaProvider::convert_zones(response);
+
+    assert_eq!(zones.heart_rate.len(), 5);
+    assert_eq!(
+        zones.heart_rate[1],
+        ZoneRange {
+            min: 120,
+            max: Some(140)
+        }
+    );
+    assert_eq!(zones.heart_rate[4].max, None);
+    assert_eq!(zones.power.len(), 7);
+    assert_eq!(zones.power[6].min, 376);
+
+    // Threshold and VO2max blocks land in the athlete's zones 4 and 5
+    let (timestamps, heart_rate) = structured_workout();
+    let time = TimeInZonesCalculator::new(zones.heart_rate).compute(&timestamps, &heart_rate);
+    assert!((time.zones[3].seconds - 600.0).abs() < f64::EPSILON);
+    assert!((time.zones[4].seconds - 300.0).abs() < f64::EPSILON);
+
+    // Seven power zones have no five-zone distribution
+    let power = TimeInZonesCalculator::new(zones.power).compute(&[0, 1], &[200, 200]);
+    assert!(power.distribution().is_none());
+    assert!((power.zones[2].seconds - 2.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_power_zones_from_ftp() {
+    let calculator = TimeInZonesCalculator::power_from_ftp(250.0);
+    let upper: Vec<Option<u32>> = calculator.zones().iter().map(|zone| zone.max).collect();
+    assert_eq!(upper, [Some(138), Some(188), Some(225), Some(263), None]);
+}
+
+#[test]
+fn test_result_combines_activities_and_lists_missing_heart_rate() {
+    let (timestamps, heart_rate) = structured_workout();
+    let activities = vec![
+        run_with_series(
+            "run-1",
+            Some(heart_rate_series(timestamps.clone(), heart_rate.clone())),
+        ),
+        run_with_series("run-2", Some(heart_rate_series(timestamps, heart_rate))),
+        run_with_series("run-3", None),
+    ];
+
+    let calculator = config_zones();
+    let result =
+        build_time_in_zones_result(&activities, (&calculator, "config"), None, "synthetic");
+
+    assert!(!result.is_error, "unexpected error: {}", result.content);
+    assert_eq!(result.content["activities_analyzed"], 2);
+    assert_eq!(result.content["activities_without_heart_rate"][0], "run-3");
+
+    let heart_rate = &result.content["heart_rate"];
+    assert_eq!(heart_rate["zone_source"], "config");
+    assert_eq!(heart_rate["total_seconds"], 7200.0);
+    assert_eq!(heart_rate["zones"][1]["seconds"], 2400.0);
+    assert_eq!(heart_rate["zones"][1]["minutes"], 40.0);
+    assert_eq!(heart_rate["zones"][2]["percentage"], 25.0);
+    assert!(heart_rate["zone_distribution"].is_object());
+    assert!(result.content["power"].is_null());
+}
+
+#[test]
+fn test_no_heart_rate_data_error() {
+    let activities = vec![run_with_series("run-1", None)];
+
+    let calculator = config_zones();
+    let result =
+        build_time_in_zones_result(&activities, (&calculator, "config"), None, "synthetic");
+
+    assert!(result.is_error);
+    assert_eq!(result.content["error"], "no_heart_rate_data");
+    assert_eq!(result.content["activity_ids"][0], "run-1");
+}
@@ -0,0 +1,175 @@
+// ABOUTME: Tests for gating tools on the provider OAuth scopes they declare
+// ABOUTME: Verifies partial grants yield a structured missing_scope error with a re-consent URL
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::mcp::resources::ServerResources;
+use pierre_mcp_server::models::{TenantId, UserOAuthToken};
+use pierre_mcp_server::services::oauth_step_up::{check_tool_scopes, required_scopes};
+use pierre_mcp_server::tenant::CredentialConfig;
+use pierre_mcp_server::tools::{AuthMethod, ToolExecutionContext};
+use serde_json::json;
+use url::Url;
+use uuid::Uuid;
+
+mod common;
+
+/// Create a user holding a Strava token granted `scope`
+async fn setup_with_grant(scope: Option<&str>) -> (Arc<ServerResources>, Uuid, TenantId) {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let database = resources.database.clone();
+
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let tenant_id: TenantId = database.list_tenants_for_user(user_id).await.unwrap()[0].id;
+
+    resources
+        .tenant_oauth_client
+        .oauth_manager
+        .lock()
+        .await
+        .store_credentials(
+            tenant_id,
+            "strava",
+            CredentialConfig {
+                client_id: "gating-client".to_owned(),
+                client_secret: "gating-secret".to_owned(),
+                redirect_uri: "http://localhost:8081/api/oauth/callback/strava".to_owned(),
+                scopes: vec!["activity:read_all".to_owned()],
+                configured_by: user_id,
+            },
+        )
+        .unwrap();
+
+    database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            "strava".to_owned(),
+            "partial-token".to_owned(),
+            Some("partial-refresh".to_owned()),
+            Some(Utc::now() + Duration::hours(6)),
+            scope.map(str::to_owned),
+        ))
+        .await
+        .unwrap();
+
+    (resources, user_id, tenant_id)
+}
+
+#[tokio::test]
+async fn test_tools_declare_provider_scopes() {
+    let resources = common::create_test_server_resources().await.unwrap();
+
+    assert_eq!(
+        required_scopes(&resources, "get_activities", "strava"),
+        ["activity:read"]
+    );
+    assert_eq!(
+        required_scopes(&resources, "get_athlete", "strava"),
+        ["read"]
+    );
+    assert_eq!(
+        required_scopes(&resources, "list_gear", "strava"),
+        ["profile:read_all"]
+    );
+    assert_eq!(
+        required_scopes(&resources, "get_athlete", "fitbit"),
+        ["profile"]
+    );
+    // Providers without declared requirements are not gated
+    assert!(required_scopes(&resources, "get_athlete", "garmin").is_empty());
+    assert!(required_scopes(&resources, "get_stats", "strava").is_empty());
+}
+
+#[tokio::test]
+async fn test_partial_grant_returns_missing_scope_error() {
+    let (resources, user_id, tenant_id) = setup_with_grant(Some("read,activity:read")).await;
+    let context = ToolExecutionContext::new(user_id, resources.clone(), AuthMethod::JwtBearer)
+        .with_tenant(tenant_id);
+
+    let result = resources
+        .tool_registry
+        .execute("list_gear", json!({ "provider": "strava" }), &context)
+        .await
+        .unwrap();
+
+    assert!(result.is_error);
+    let content = &result.content;
+    assert_eq!(content["error_type"], "missing_scope");
+    assert_eq!(content["provider"], "strava");
+    assert_eq!(content["missing_scopes"], json!(["profile:read_all"]));
+    assert!(content["error"]
+        .as_str()
+        .unwrap()
+        .contains("profile:read_all"));
+
+    // The re-consent URL requests only the missing scope
+    let url = Url::parse(content["reconsent_url"].as_str().unwrap()).unwrap();
+    let scope = url
+        .query_pairs()
+        .find(|(k, _)| k == "scope")
+        .map(|(_, v)| v.into_owned());
+    assert_eq!(scope.as_deref(), Some("profile:read_all"));
+}
+
+#[tokio::test]
+async fn test_granted_scopes_pass_the_gate() {
+    // activity:read_all covers the activity:read that get_activities declares
+    let (resources, user_id, tenant_id) =
+        setup_with_grant(Some("read,activity:read_all,profile:read_all")).await;
+    let tenant = tenant_id.to_string();
+
+    for tool in ["get_activities", "get_athlete", "list_gear"] {
+        assert!(
+            check_tool_scopes(&resources, tool, "strava", user_id, Some(&tenant))
+                .await
+                .unwrap()
+                .is_none(),
+            "{tool} should not need a step-up"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_default_grant_reads_the_athlete_but_not_gear() {
+    // What Strava grants for the default activity:read_all request
+    let (resources, user_id, tenant_id) = setup_with_grant(Some("read,activity:read_all")).await;
+    let tenant = tenant_id.to_string();
+
+    assert!(
+        check_tool_scopes(&resources, "get_athlete", "strava", user_id, Some(&tenant))
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let step_up = check_tool_scopes(&resources, "list_gear", "strava", user_id, Some(&tenant))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(step_up.missing_scopes, ["profile:read_all"]);
+}
+
+#[tokio::test]
+async fn test_unknown_grant_is_not_gated() {
+    // Tokens stored without a scope leave the decision to the provider
+    let (resources, user_id, tenant_id) = setup_with_grant(None).await;
+
+    assert!(check_tool_scopes(
+        &resources,
+        "get_athlete",
+        "strava",
+        user_id,
+        Some(&tenant_id.to_string())
+    )
+    .await
+    .unwrap()
+    .is_none());
+}
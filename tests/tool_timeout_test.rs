@@ -0,0 +1,155 @@
+// ABOUTME: Tests for per-tool execution timeouts enforced by the tool registry
+// ABOUTME: Runs a deliberately slow synthetic tool and checks the structured tool_timeout error
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::errors::AppResult;
+use pierre_mcp_server::mcp::resources::ServerResources;
+use pierre_mcp_server::mcp::schema::JsonSchema;
+use pierre_mcp_server::tools::{
+    AuthMethod, McpTool, ToolCapabilities, ToolExecutionContext, ToolRegistry, ToolResult,
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+mod common;
+
+/// Sets its flag when dropped, i.e. when the tool's future is cancelled
+struct CancelGuard(Arc<AtomicBool>);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Tool that sleeps far longer than any test timeout, standing in for a hung provider call
+struct SlowTool {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl McpTool for SlowTool {
+    fn name(&self) -> &'static str {
+        "slow_synthetic_tool"
+    }
+
+    fn description(&self) -> &'static str {
+        "Deliberately slow tool for timeout testing"
+    }
+
+    fn input_schema(&self) -> JsonSchema {
+        JsonSchema {
+            schema_type: "object".to_owned(),
+            properties: None,
+            required: None,
+        }
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities::READS_DATA
+    }
+
+    async fn execute(
+        &self,
+        _args: Value,
+        _context: &ToolExecutionContext,
+    ) -> AppResult<ToolResult> {
+        let _guard = CancelGuard(self.cancelled.clone());
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(ToolResult::ok(json!({ "finished": true })))
+    }
+}
+
+/// Test resources whose default tool timeout is `timeout_secs`
+async fn resources_with_timeout(timeout_secs: u64) -> Arc<ServerResources> {
+    let base = common::create_test_server_resources().await.unwrap();
+    let mut resources = (*base).clone();
+    let mut config = (*resources.config).clone();
+    config.mcp.tool_timeout_secs = timeout_secs;
+    resources.config = Arc::new(config);
+    Arc::new(resources)
+}
+
+#[tokio::test]
+async fn test_slow_tool_times_out_with_structured_error() {
+    let resources = resources_with_timeout(1).await;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let mut registry = ToolRegistry::new();
+    registry.register_external_tool(Arc::new(SlowTool {
+        cancelled: cancelled.clone(),
+    }));
+    let context = ToolExecutionContext::new(Uuid::new_v4(), resources, AuthMethod::JwtBearer);
+
+    let started = Instant::now();
+    let result = registry
+        .execute("slow_synthetic_tool", json!({}), &context)
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert!(result.is_error);
+    assert_eq!(result.content["error_type"], "tool_timeout");
+    assert_eq!(result.content["tool"], "slow_synthetic_tool");
+    assert_eq!(result.content["timeout_secs"], 1);
+
+    // The tool's in-flight work was dropped rather than left running
+    assert!(cancelled.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_zero_timeout_leaves_tools_unbounded() {
+    let resources = resources_with_timeout(0).await;
+    let mut registry = ToolRegistry::new();
+    registry.register_external_tool(Arc::new(SlowTool {
+        cancelled: Arc::new(AtomicBool::new(false)),
+    }));
+    let context = ToolExecutionContext::new(Uuid::new_v4(), resources, AuthMethod::JwtBearer);
+
+    let outcome = tokio::time::timeout(
+        Duration::from_millis(1500),
+        registry.execute("slow_synthetic_tool", json!({}), &context),
+    )
+    .await;
+
+    // Still running when the outer deadline cut it off
+    assert!(outcome.is_err());
+}
+
+#[tokio::test]
+async fn test_catalog_overrides_timeout_for_long_running_tools() {
+    let resources = common::create_test_server_resources().await.unwrap();
+    let database = &resources.database;
+
+    let export = database
+        .get_tool_catalog_entry("export_user_data")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(export.timeout_secs, Some(300));
+
+    let training_load = database
+        .get_tool_catalog_entry("analyze_training_load")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(training_load.timeout_secs, Some(180));
+
+    // Other tools use the server-wide default
+    let activities = database
+        .get_tool_catalog_entry("get_activities")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(activities.timeout_secs, None);
+}
@@ -7,11 +7,13 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![allow(missing_docs)]
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use pierre_mcp_server::config::intelligence::TsbConfig;
 use pierre_mcp_server::intelligence::{
-    RiskLevel, TrainingLoad, TrainingLoadCalculator, TrainingStatus,
+    InsightSeverity, RiskLevel, TrainingLoad, TrainingLoadCalculator, TrainingStatus, TssDataPoint,
 };
-use pierre_mcp_server::models::{Activity, SportType};
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SportType};
+use pierre_mcp_server::tools::implementations::analytics::build_training_load_trend_result;
 
 fn create_test_activity(
     date: DateTime<Utc>,
@@ -19,8 +21,6 @@ fn create_test_activity(
     avg_power: Option<u32>,
     avg_hr: Option<u32>,
 ) -> Activity {
-    use pierre_mcp_server::models::ActivityBuilder;
-
     let mut builder = ActivityBuilder::new(
         format!("test_{}", date.timestamp()),
         "Test Activity",
@@ -41,6 +41,43 @@ fn create_test_activity(
     builder.build()
 }
 
+/// Activity with a recorded TSS, starting at 07:00 UTC on `day`
+fn create_tss_activity(day: NaiveDate, tss: f32) -> Activity {
+    let start = day
+        .and_time(NaiveTime::from_hms_opt(7, 0, 0).unwrap())
+        .and_utc();
+    ActivityBuilder::new(
+        format!("tss_{day}"),
+        "Workout",
+        SportType::Ride,
+        start,
+        3600,
+        "test",
+    )
+    .training_stress_score(tss)
+    .build()
+}
+
+fn day(offset: i64) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 1).unwrap() + Duration::days(offset)
+}
+
+/// Six weeks of easy rides every other day, then a three-week block of hard daily rides
+fn base_then_build_block() -> Vec<Activity> {
+    let base = (0..42)
+        .step_by(2)
+        .map(|offset| create_tss_activity(day(offset), 40.0));
+    let build = (42..63).map(|offset| create_tss_activity(day(offset), 100.0));
+    base.chain(build).collect()
+}
+
+fn tss_history(activities: &[Activity]) -> Vec<TssDataPoint> {
+    TrainingLoadCalculator::new()
+        .calculate_training_load(activities, None, None, None, None, None)
+        .unwrap()
+        .tss_history
+}
+
 #[test]
 fn test_calculate_tsb() {
     let ctl = 100.0;
@@ -141,3 +178,149 @@ fn test_overtraining_risk_detection() {
     let risk = TrainingLoadCalculator::check_overtraining_risk(&low_risk);
     assert_eq!(risk.risk_level, RiskLevel::Low);
 }
+
+#[test]
+fn test_overtraining_insight_explains_computation() {
+    let load = TrainingLoad {
+        ctl: 60.0,
+        atl: 84.0,
+        tsb: -24.0,
+        tss_history: Vec::new(),
+    };
+    let tsb_config = TsbConfig {
+        fatigued_tsb: -20.0,
+        ..TsbConfig::default()
+    };
+
+    let insight = TrainingLoadCalculator::overtraining_insight(&load, &tsb_config).explained(true);
+    assert_eq!(insight.insight_type, "overtraining_risk");
+    // ATL is 40% above CTL and TSB is below the fatigue threshold
+    assert!(matches!(insight.severity, InsightSeverity::Critical));
+
+    let trace = insight.computation_trace.expect("explain attaches a trace");
+    assert!(trace.formula.contains("TSB = CTL - ATL"));
+    let inputs: Vec<(&str, f64)> = trace
+        .inputs
+        .iter()
+        .map(|input| (input.name.as_str(), input.value))
+        .collect();
+    assert_eq!(inputs, vec![("ctl", 60.0), ("atl", 84.0), ("tsb", -24.0)]);
+
+    // The TSB threshold comes from the intelligence config, not a constant
+    let fatigue = trace
+        .thresholds
+        .iter()
+        .find(|threshold| threshold.name == "fatigued_tsb")
+        .unwrap();
+    assert!((fatigue.value - -20.0).abs() < f64::EPSILON);
+    assert_eq!(fatigue.comparison, "tsb < fatigued_tsb");
+    assert_eq!(
+        fatigue.source,
+        "sleep_recovery.training_stress_balance.fatigued_tsb"
+    );
+    assert!(fatigue.triggered);
+
+    let very_high_atl = trace
+        .thresholds
+        .iter()
+        .find(|threshold| threshold.name == "very_high_atl")
+        .unwrap();
+    assert!(!very_high_atl.triggered);
+}
+
+#[test]
+fn test_overtraining_insight_omits_trace_by_default() {
+    let load = TrainingLoad {
+        ctl: 90.0,
+        atl: 80.0,
+        tsb: 10.0,
+        tss_history: Vec::new(),
+    };
+
+    let insight =
+        TrainingLoadCalculator::overtraining_insight(&load, &TsbConfig::default()).explained(false);
+    assert!(matches!(insight.severity, InsightSeverity::Info));
+    assert!(insight.computation_trace.is_none());
+
+    let json = serde_json::to_value(&insight).unwrap();
+    assert!(json.get("computation_trace").is_none());
+}
+
+#[test]
+fn test_training_load_trend_over_build_block() {
+    let history = tss_history(&base_then_build_block());
+    let trend =
+        TrainingLoadCalculator::new().calculate_training_load_trend(&history, day(42), day(62));
+
+    // One point per day of the window, oldest first
+    assert_eq!(trend.points.len(), 21);
+    assert_eq!(trend.points[0].day.date.date_naive(), day(42));
+    assert_eq!(trend.points[20].day.date.date_naive(), day(62));
+
+    // The base block before the window warms up CTL
+    let first = &trend.points[0];
+    assert!(
+        first.ctl > 10.0,
+        "CTL should carry the base block: {}",
+        first.ctl
+    );
+
+    // CTL rises every day of the build while TSB drops into fatigue
+    for pair in trend.points.windows(2) {
+        assert!(pair[1].ctl > pair[0].ctl);
+    }
+    let last = &trend.points[20];
+    assert!(last.ctl > first.ctl + 30.0);
+    assert!(last.tsb < first.tsb);
+    assert!(last.tsb < -10.0);
+    assert!((last.tsb - (last.ctl - last.atl)).abs() < 1e-9);
+
+    assert_eq!(trend.status, TrainingStatus::Overreaching);
+    assert_eq!(trend.overtraining_risk.risk_level, RiskLevel::High);
+}
+
+#[test]
+fn test_training_load_trend_decays_through_gaps() {
+    let history = tss_history(&base_then_build_block());
+    let trend =
+        TrainingLoadCalculator::new().calculate_training_load_trend(&history, day(10), day(19));
+
+    assert_eq!(trend.points.len(), 10);
+    for pair in trend.points.windows(2) {
+        let (previous, today) = (&pair[0], &pair[1]);
+        if today.day.tss < f64::EPSILON {
+            // Rest days count as zero TSS and both averages decay
+            assert!(today.ctl < previous.ctl);
+            assert!(today.atl < previous.atl);
+        } else {
+            assert!((today.day.tss - 40.0).abs() < f64::EPSILON);
+            assert!(today.atl > previous.atl);
+        }
+    }
+
+    // Activities after the window are ignored
+    let truncated = TrainingLoadCalculator::new().calculate_training_load_trend(
+        &history[..history.len() - 21],
+        day(10),
+        day(19),
+    );
+    assert!((truncated.points[9].ctl - trend.points[9].ctl).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_training_load_trend_tool_result() {
+    let history = tss_history(&base_then_build_block());
+    let result = build_training_load_trend_result(&history, day(42), day(62), "strava");
+
+    assert!(!result.is_error);
+    let content = result.content;
+    assert_eq!(content["window"]["start"], "2025-02-12");
+    assert_eq!(content["window"]["days"], 21);
+    assert_eq!(content["series"].as_array().unwrap().len(), 21);
+    assert!(content["series"][0]["date"].is_string());
+    assert!(content["series"][0]["ctl"].is_number());
+    assert_eq!(content["current"]["tsb"], content["series"][20]["tsb"]);
+    assert_eq!(content["status"], "Overreaching");
+    assert_eq!(content["overtraining_risk"]["risk_level"], "High");
+    assert_eq!(content["activities_analyzed"], 21);
+}
@@ -0,0 +1,219 @@
+// ABOUTME: Tests for periodized training plan generation toward a target race
+// ABOUTME: Validates CTL build, taper shape, weekly time limits, and JSON/TOON output
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::formatters::{format_output, OutputFormat};
+use pierre_mcp_server::intelligence::training_plan::{TrainingPhase, WorkoutType};
+use pierre_mcp_server::intelligence::{
+    Goal, GoalStatus, GoalType, TimeAvailability, TimeFrame, TrainingLoad, TrainingPlan,
+    TrainingPlanGenerator,
+};
+
+const MARATHON_METERS: f64 = 42_195.0;
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 3, 1, 8, 0, 0).unwrap()
+}
+
+fn race_goal(goal_type: GoalType, weeks_out: i64) -> Goal {
+    Goal {
+        id: "goal-marathon".to_owned(),
+        user_id: "athlete".to_owned(),
+        title: "Spring marathon".to_owned(),
+        description: "Run a marathon".to_owned(),
+        goal_type,
+        target_value: 3.5 * 3600.0,
+        target_date: start() + Duration::weeks(weeks_out),
+        current_value: 0.0,
+        created_at: start(),
+        updated_at: start(),
+        status: GoalStatus::Active,
+    }
+}
+
+fn marathon_goal(weeks_out: i64) -> Goal {
+    race_goal(
+        GoalType::Time {
+            sport: "Run".to_owned(),
+            distance: MARATHON_METERS,
+        },
+        weeks_out,
+    )
+}
+
+fn current_load(ctl: f64, tsb: f64) -> TrainingLoad {
+    TrainingLoad {
+        ctl,
+        atl: ctl - tsb,
+        tsb,
+        tss_history: vec![],
+    }
+}
+
+fn availability(hours_per_week: f64) -> TimeAvailability {
+    TimeAvailability {
+        hours_per_week,
+        preferred_days: vec![],
+        preferred_duration_minutes: Some(60),
+    }
+}
+
+fn marathon_plan(hours_per_week: f64) -> TrainingPlan {
+    TrainingPlanGenerator::generate(
+        &marathon_goal(12),
+        start(),
+        &current_load(45.0, -5.0),
+        &availability(hours_per_week),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_marathon_block_builds_ctl_monotonically() {
+    let plan = marathon_plan(10.0);
+    assert_eq!(plan.weeks.len(), 12);
+
+    let loading: Vec<_> = plan
+        .weeks
+        .iter()
+        .take_while(|w| w.phase != TrainingPhase::Taper)
+        .collect();
+    assert_eq!(loading.len(), 9);
+    assert_eq!(loading[0].phase, TrainingPhase::Base);
+    assert_eq!(loading[8].phase, TrainingPhase::Peak);
+
+    let mut previous_ctl = plan.starting_ctl;
+    for week in &loading {
+        assert!(
+            week.projected_ctl >= previous_ctl - 1e-9,
+            "CTL dropped in week {}: {} -> {}",
+            week.week_number,
+            previous_ctl,
+            week.projected_ctl
+        );
+        previous_ctl = week.projected_ctl;
+    }
+
+    // CTL rises substantially without exceeding the safe ramp rate
+    assert!(previous_ctl > plan.starting_ctl + 20.0);
+    assert!((previous_ctl - plan.projected_peak_ctl()).abs() < 1e-9);
+    let mut previous_ctl = plan.starting_ctl;
+    for week in &loading {
+        assert!(week.projected_ctl - previous_ctl <= 5.0 + 1e-9);
+        previous_ctl = week.projected_ctl;
+    }
+
+    // Recovery weeks hold load below the surrounding build weeks
+    let recovery = loading
+        .iter()
+        .find(|w| w.phase == TrainingPhase::Recovery)
+        .unwrap();
+    let next = &plan.weeks[recovery.week_number as usize];
+    assert!(recovery.target_tss < next.target_tss);
+}
+
+#[test]
+fn test_marathon_block_tapers_into_race_day() {
+    let plan = marathon_plan(10.0);
+    let taper: Vec<_> = plan.taper_weeks().collect();
+    assert_eq!(taper.len(), 3);
+
+    let pre_taper = &plan.weeks[8];
+    let mut previous_tss = pre_taper.target_tss;
+    for week in &taper {
+        assert!(week.target_tss < previous_tss);
+        previous_tss = week.target_tss;
+    }
+
+    // Athlete arrives fresh while keeping most of their fitness
+    let race_week = taper[2];
+    assert!(race_week.projected_tsb > 0.0);
+    assert!(race_week.projected_tsb > pre_taper.projected_tsb);
+    assert!(race_week.projected_ctl >= 0.8 * plan.projected_peak_ctl());
+
+    let race = race_week.sessions.last().unwrap();
+    assert_eq!(race.workout_type, WorkoutType::Race);
+    assert_eq!(race.date, plan.race_date);
+    assert_eq!(
+        race_week
+            .sessions
+            .iter()
+            .filter(|s| s.workout_type == WorkoutType::Race)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_plan_respects_weekly_time_availability() {
+    let generous = marathon_plan(10.0);
+    let limited = marathon_plan(5.0);
+
+    for week in &limited.weeks {
+        assert!(
+            week.planned_hours <= 5.0 + 1e-9,
+            "week {} needs {} hours",
+            week.week_number,
+            week.planned_hours
+        );
+        let session_tss: f64 = week.sessions.iter().map(|s| s.target_tss).sum();
+        assert!((session_tss - week.target_tss).abs() < 1e-6);
+    }
+    assert!(limited.projected_peak_ctl() < generous.projected_peak_ctl());
+    assert!(limited.target_peak_ctl < generous.target_peak_ctl);
+}
+
+#[test]
+fn test_plan_rejects_unsupported_goals() {
+    let distance_goal = race_goal(
+        GoalType::Distance {
+            sport: "Run".to_owned(),
+            timeframe: TimeFrame::Month,
+        },
+        12,
+    );
+    assert!(TrainingPlanGenerator::generate(
+        &distance_goal,
+        start(),
+        &current_load(45.0, 0.0),
+        &availability(8.0),
+    )
+    .is_err());
+
+    // Three weeks is too short for a build and a taper
+    assert!(TrainingPlanGenerator::generate(
+        &marathon_goal(3),
+        start(),
+        &current_load(45.0, 0.0),
+        &availability(8.0),
+    )
+    .is_err());
+
+    assert!(TrainingPlanGenerator::generate(
+        &marathon_goal(12),
+        start(),
+        &current_load(45.0, 0.0),
+        &availability(0.0),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_plan_serializes_to_json_and_toon() {
+    let plan = marathon_plan(8.0);
+
+    let json = serde_json::to_value(&plan).unwrap();
+    assert_eq!(json["weeks"].as_array().unwrap().len(), 12);
+    assert_eq!(json["weeks"][0]["phase"], "base");
+    assert_eq!(json["weeks"][11]["sessions"][5]["workout_type"], "race");
+
+    let toon = format_output(&plan, OutputFormat::Toon).unwrap();
+    assert!(toon.data.contains("goal-marathon"));
+    assert!(toon.data.contains("taper"));
+}
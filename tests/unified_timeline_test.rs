@@ -0,0 +1,209 @@
+// ABOUTME: Tests for merging duplicate activities across providers into the unified timeline
+// ABOUTME: Verifies per-user provider priority decides which provider's metrics reach the canonical record
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::{Activity, ActivityBuilder, SegmentEffort, SportType};
+use pierre_mcp_server::providers::unified_timeline::{
+    MetricCategory, ProviderPriority, UnifiedTimeline,
+};
+use pierre_mcp_server::services::provider_priority::{
+    apply_provider_priority, load_provider_priority, save_provider_priority,
+};
+use serde_json::json;
+
+mod common;
+
+fn ride_start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 8, 0, 0).unwrap()
+}
+
+fn segment(name: &str) -> SegmentEffort {
+    SegmentEffort {
+        id: format!("effort-{name}"),
+        segment_id: None,
+        name: name.to_owned(),
+        elapsed_time: 300,
+        moving_time: Some(295),
+        start_date: ride_start() + Duration::minutes(20),
+        distance: 2000.0,
+        average_heart_rate: None,
+        max_heart_rate: None,
+        average_cadence: None,
+        average_watts: None,
+        kom_rank: None,
+        pr_rank: Some(1),
+        climb_category: Some(4),
+        average_grade: Some(4.5),
+        elevation_gain: Some(90.0),
+    }
+}
+
+/// Garmin's watch copy: chest-strap heart rate, power, no segments
+fn garmin_ride() -> Activity {
+    ActivityBuilder::new(
+        "garmin-1",
+        "Morning Ride",
+        SportType::Ride,
+        ride_start(),
+        3600,
+        "garmin",
+    )
+    .distance_meters(30_000.0)
+    .average_heart_rate(142)
+    .max_heart_rate(171)
+    .average_power(210)
+    .build()
+}
+
+/// Strava's synced copy: optical heart rate, segments, no power
+fn strava_ride() -> Activity {
+    ActivityBuilder::new(
+        "strava-1",
+        "Morning Ride",
+        SportType::Ride,
+        ride_start() + Duration::seconds(30),
+        3590,
+        "strava",
+    )
+    .distance_meters(29_900.0)
+    .average_heart_rate(139)
+    .max_heart_rate(176)
+    .segment_efforts(vec![segment("Col Climb")])
+    .build()
+}
+
+#[test]
+fn test_configured_priority_picks_metrics_for_canonical_record() {
+    let priority = ProviderPriority::default()
+        .prefer(MetricCategory::HeartRate, &["garmin", "strava"])
+        .prefer(MetricCategory::Segments, &["strava"]);
+
+    let timeline = UnifiedTimeline::build(vec![strava_ride(), garmin_ride()], &priority);
+
+    assert_eq!(timeline.len(), 1);
+    let canonical = &timeline.activities[0];
+    assert_eq!(canonical.sources.len(), 2);
+
+    assert_eq!(canonical.activity.average_heart_rate(), Some(142));
+    assert_eq!(canonical.activity.max_heart_rate(), Some(171));
+    assert_eq!(
+        canonical.source_for(MetricCategory::HeartRate),
+        Some("garmin")
+    );
+
+    let segments = canonical.activity.segment_efforts().unwrap();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].name, "Col Climb");
+    assert_eq!(
+        canonical.source_for(MetricCategory::Segments),
+        Some("strava")
+    );
+}
+
+#[test]
+fn test_reversed_priority_flips_winning_provider() {
+    let priority = ProviderPriority::default()
+        .prefer(MetricCategory::HeartRate, &["strava", "garmin"])
+        .prefer(MetricCategory::Summary, &["strava"]);
+
+    let timeline = UnifiedTimeline::build(vec![garmin_ride(), strava_ride()], &priority);
+
+    let canonical = &timeline.activities[0];
+    assert_eq!(canonical.activity.average_heart_rate(), Some(139));
+    assert_eq!(
+        canonical.source_for(MetricCategory::HeartRate),
+        Some("strava")
+    );
+    assert_eq!(canonical.activity.provider(), "strava");
+    assert_eq!(canonical.activity.id(), "strava-1");
+}
+
+#[test]
+fn test_missing_metric_falls_back_to_next_provider() {
+    // Strava is preferred for power, but only Garmin recorded it
+    let priority = ProviderPriority::default().prefer(MetricCategory::Power, &["strava", "garmin"]);
+
+    let timeline = UnifiedTimeline::build(vec![garmin_ride(), strava_ride()], &priority);
+
+    let canonical = &timeline.activities[0];
+    assert_eq!(canonical.activity.average_power(), Some(210));
+    assert_eq!(canonical.source_for(MetricCategory::Power), Some("garmin"));
+    assert_eq!(canonical.source_for(MetricCategory::Cadence), None);
+}
+
+#[test]
+fn test_unrelated_activities_stay_separate() {
+    let evening = ActivityBuilder::new(
+        "strava-2",
+        "Evening Ride",
+        SportType::Ride,
+        ride_start() + Duration::hours(9),
+        3600,
+        "strava",
+    )
+    .build();
+
+    let timeline = UnifiedTimeline::build(
+        vec![garmin_ride(), evening, strava_ride()],
+        &ProviderPriority::default(),
+    );
+
+    assert_eq!(timeline.len(), 2);
+    // Newest first
+    assert_eq!(timeline.activities[0].activity.id(), "strava-2");
+    assert_eq!(timeline.activities[1].sources.len(), 2);
+    // Default precedence trusts the device vendor for the summary
+    assert_eq!(timeline.activities[1].activity.provider(), "garmin");
+}
+
+#[tokio::test]
+async fn test_provider_priority_persists_per_user() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, _) = common::create_test_user(&database).await.unwrap();
+    let user_id = user_id.to_string();
+
+    assert_eq!(
+        load_provider_priority(&*database, &user_id).await.unwrap(),
+        ProviderPriority::default()
+    );
+
+    let priority = ProviderPriority::default()
+        .prefer(MetricCategory::HeartRate, &["garmin"])
+        .prefer(MetricCategory::Segments, &["strava"]);
+    save_provider_priority(&*database, &user_id, &priority)
+        .await
+        .unwrap();
+    assert_eq!(
+        load_provider_priority(&*database, &user_id).await.unwrap(),
+        priority
+    );
+
+    // Rewriting the rest of the configuration keeps the stored priority
+    let mut configuration = json!({ "active_profile": "custom" });
+    apply_provider_priority(&*database, &user_id, None, &mut configuration)
+        .await
+        .unwrap();
+    database
+        .save_user_configuration(&user_id, &configuration.to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        load_provider_priority(&*database, &user_id).await.unwrap(),
+        priority
+    );
+
+    // Invalid client input is rejected
+    let invalid = json!({ "default_order": ["garmin", " "] });
+    assert!(
+        apply_provider_priority(&*database, &user_id, Some(&invalid), &mut configuration)
+            .await
+            .is_err()
+    );
+}
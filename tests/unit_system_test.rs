@@ -0,0 +1,160 @@
+// ABOUTME: Tests for unit system conversion of tool output
+// ABOUTME: Converts a known metric activity to imperial and back, checking values and annotations
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{TimeZone, Utc};
+use pierre_mcp_server::{
+    formatters::{apply_unit_system, convert_units, UnitSystem, UNITS_KEY},
+    models::{ActivityBuilder, SportType},
+};
+use serde_json::{json, Value};
+
+const TOLERANCE: f64 = 1e-6;
+
+/// A 10 km run in 50 minutes at 20 °C, with the athlete's weight alongside
+fn metric_output() -> Value {
+    let activity = ActivityBuilder::new(
+        "run-1",
+        "Tempo Run",
+        SportType::Run,
+        Utc.with_ymd_and_hms(2024, 6, 1, 7, 0, 0).unwrap(),
+        3000,
+        "strava",
+    )
+    .distance_meters(10_000.0)
+    .elevation_gain(120.0)
+    .average_speed(10_000.0 / 3000.0)
+    .temperature(20.0)
+    .build();
+
+    json!({
+        "activity": activity,
+        "pace_seconds_per_km": 300.0,
+        "pace_min_km": "5:00",
+        "weight_kg": 70.0,
+    })
+}
+
+fn number(value: &Value, pointer: &str) -> f64 {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_f64)
+        .unwrap_or_else(|| panic!("{pointer} is not a number in {value}"))
+}
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() < tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_unit_system_from_str() {
+    assert_eq!(UnitSystem::from_str_param("imperial"), UnitSystem::Imperial);
+    assert_eq!(UnitSystem::from_str_param("Imperial"), UnitSystem::Imperial);
+    assert_eq!(UnitSystem::from_str_param("metric"), UnitSystem::Metric);
+    // Unknown defaults to metric
+    assert_eq!(UnitSystem::from_str_param("furlongs"), UnitSystem::Metric);
+    assert_eq!(UnitSystem::from_str_param(""), UnitSystem::Metric);
+}
+
+#[test]
+fn test_metric_activity_to_imperial() {
+    let mut output = metric_output();
+
+    assert!(apply_unit_system(&mut output, UnitSystem::Imperial));
+
+    // Suffixed fields are renamed to their imperial unit
+    assert!(output.pointer("/activity/distance_meters").is_none());
+    assert_close(number(&output, "/activity/distance_miles"), 6.213_712, 1e-6);
+    assert_close(
+        number(&output, "/activity/elevation_gain"),
+        393.700_787,
+        1e-6,
+    );
+    assert_close(number(&output, "/activity/average_speed"), 7.456_454, 1e-6);
+    assert_close(number(&output, "/activity/temperature"), 68.0, TOLERANCE);
+    assert_close(number(&output, "/pace_seconds_per_mile"), 482.803_2, 1e-6);
+    assert_eq!(output["pace_min_mile"], "8:03");
+    assert_close(number(&output, "/weight_lb"), 154.323_584, 1e-6);
+
+    // Non-unit fields are untouched
+    assert_eq!(output["activity"]["duration_seconds"], 3000);
+    assert_eq!(output["activity"]["name"], "Tempo Run");
+
+    let units = &output[UNITS_KEY];
+    assert_eq!(units["system"], "imperial");
+    assert_eq!(units["distance"], "mi");
+    assert_eq!(units["elevation"], "ft");
+    assert_eq!(units["pace"], "min/mi");
+    assert_eq!(units["temperature"], "°F");
+    assert_eq!(units["weight"], "lb");
+}
+
+#[test]
+fn test_imperial_round_trip_restores_metric_values() {
+    let original = metric_output();
+    let mut output = original.clone();
+
+    assert!(convert_units(
+        &mut output,
+        UnitSystem::Metric,
+        UnitSystem::Imperial
+    ));
+    assert!(convert_units(
+        &mut output,
+        UnitSystem::Imperial,
+        UnitSystem::Metric
+    ));
+
+    for pointer in [
+        "/activity/distance_meters",
+        "/activity/elevation_gain",
+        "/activity/average_speed",
+        "/activity/temperature",
+        "/pace_seconds_per_km",
+        "/weight_kg",
+    ] {
+        assert_close(
+            number(&output, pointer),
+            number(&original, pointer),
+            TOLERANCE,
+        );
+    }
+    // "m:ss" paces round to the nearest second in each direction
+    assert_eq!(output["pace_min_km"], "5:00");
+    assert_eq!(output[UNITS_KEY]["system"], "metric");
+    assert_eq!(output[UNITS_KEY]["distance"], "m");
+}
+
+#[test]
+fn test_metric_preference_leaves_output_unchanged() {
+    let original = metric_output();
+    let mut output = original.clone();
+
+    assert!(!apply_unit_system(&mut output, UnitSystem::Metric));
+    assert_eq!(output, original);
+}
+
+#[test]
+fn test_unconvertible_values_are_left_alone() {
+    let mut output = json!({
+        "pace_min_km": "N/A",
+        "distance": "5K",
+        "weight": "25%",
+        "segments": [{"distance": 1609.344}],
+    });
+
+    assert!(apply_unit_system(&mut output, UnitSystem::Imperial));
+
+    assert_eq!(output["pace_min_km"], "N/A");
+    assert_eq!(output["distance"], "5K");
+    assert_eq!(output["weight"], "25%");
+    assert_close(number(&output, "/segments/0/distance"), 1.0, TOLERANCE);
+}
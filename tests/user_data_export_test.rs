@@ -0,0 +1,201 @@
+// ABOUTME: Tests for the user data export used by the export_user_data tool and REST route
+// ABOUTME: Verifies archive sections, secret redaction, bounded activity paging, and audit logging
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use pierre_mcp_server::models::{ActivityBuilder, SportType, UserOAuthToken};
+use pierre_mcp_server::providers::synthetic_provider::SyntheticProvider;
+use pierre_mcp_server::services::data_export::{
+    build_user_data_export, collect_provider_activities, export_user_data, ExportOptions, REDACTED,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+mod common;
+
+const ACCESS_TOKEN: &str = "strava-access-token-do-not-leak";
+const REFRESH_TOKEN: &str = "strava-refresh-token-do-not-leak";
+const APP_SECRET: &str = "user-app-client-secret-do-not-leak";
+const PROFILE_SECRET: &str = "profile-api-key-do-not-leak";
+
+fn recent_runs(count: i64) -> SyntheticProvider {
+    let activities = (0..count)
+        .map(|n| {
+            ActivityBuilder::new(
+                format!("run-{n}"),
+                "Easy Run",
+                SportType::Run,
+                Utc::now() - Duration::hours(n),
+                1800,
+                "synthetic",
+            )
+            .build()
+        })
+        .collect();
+    SyntheticProvider::with_activities(activities)
+}
+
+#[tokio::test]
+async fn test_export_contains_all_sections_without_secrets() -> Result<()> {
+    let database = common::create_test_database().await?;
+    let email = format!("export-{}@example.com", Uuid::new_v4());
+    let (user_id, user) = common::create_test_user_with_email(&database, &email).await?;
+    let tenant_id = database.list_tenants_for_user(user_id).await?[0].id;
+
+    database
+        .upsert_user_profile(
+            user_id,
+            json!({
+                "weight_kg": 68.5,
+                "integrations": { "coach_api_key": PROFILE_SECRET }
+            }),
+        )
+        .await?;
+    database
+        .create_goal(user_id, json!({ "title": "Sub-3 marathon" }))
+        .await?;
+    database
+        .store_insight(
+            user_id,
+            json!({ "type": "training", "summary": "Consistent week" }),
+        )
+        .await?;
+    database
+        .store_user_oauth_app(
+            user_id,
+            "strava",
+            "my-client-id",
+            APP_SECRET,
+            "http://localhost/callback",
+        )
+        .await?;
+    database
+        .upsert_user_oauth_token(&UserOAuthToken::new(
+            user_id,
+            tenant_id.to_string(),
+            "strava".to_owned(),
+            ACCESS_TOKEN.to_owned(),
+            Some(REFRESH_TOKEN.to_owned()),
+            Some(Utc::now() + Duration::hours(6)),
+            Some("activity:read_all".to_owned()),
+        ))
+        .await?;
+
+    let provider = recent_runs(3);
+    let activities =
+        collect_provider_activities("synthetic", &provider, &ExportOptions::default()).await;
+    let export = build_user_data_export(&*database, user_id, tenant_id, vec![activities]).await?;
+    let archive = serde_json::to_value(&export)?;
+
+    for section in [
+        "format_version",
+        "exported_at",
+        "account",
+        "profile",
+        "goals",
+        "insights",
+        "connections",
+        "oauth_apps",
+        "activities",
+    ] {
+        assert!(archive.get(section).is_some(), "missing section {section}");
+    }
+    assert_eq!(archive["account"]["email"], email);
+    assert_eq!(archive["goals"].as_array().unwrap().len(), 1);
+    assert_eq!(archive["insights"].as_array().unwrap().len(), 1);
+    assert_eq!(archive["connections"][0]["provider"], "strava");
+    assert_eq!(archive["connections"][0]["scopes"], "activity:read_all");
+    assert_eq!(archive["oauth_apps"][0]["client_id"], "my-client-id");
+    assert_eq!(
+        archive["activities"][0]["activities"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(archive["profile"]["weight_kg"], 68.5);
+    assert_eq!(
+        archive["profile"]["integrations"]["coach_api_key"],
+        REDACTED
+    );
+
+    let serialized = archive.to_string();
+    for secret in [
+        ACCESS_TOKEN,
+        REFRESH_TOKEN,
+        APP_SECRET,
+        PROFILE_SECRET,
+        user.password_hash.as_str(),
+    ] {
+        assert!(!serialized.contains(secret), "archive leaked {secret}");
+    }
+    for field in [
+        "access_token",
+        "refresh_token",
+        "client_secret",
+        "password_hash",
+    ] {
+        assert!(!serialized.contains(field), "archive contains {field}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_activity_collection_is_bounded() {
+    let provider = recent_runs(250);
+
+    let options = ExportOptions {
+        max_activities: 150,
+        ..ExportOptions::default()
+    };
+    let exported = collect_provider_activities("synthetic", &provider, &options).await;
+    assert_eq!(exported.activities.len(), 150);
+    assert!(exported.truncated);
+    assert!(exported.error.is_none());
+
+    // Only the window is read, so a short window is never truncated
+    let options = ExportOptions {
+        activity_days: 2,
+        ..ExportOptions::default()
+    };
+    let exported = collect_provider_activities("synthetic", &provider, &options).await;
+    assert_eq!(exported.activities.len(), 48);
+    assert!(!exported.truncated);
+}
+
+#[tokio::test]
+async fn test_export_records_data_export_audit_event() -> Result<()> {
+    let resources = common::create_test_server_resources().await?;
+    let email = format!("export-audit-{}@example.com", Uuid::new_v4());
+    let (user_id, _user) = common::create_test_user_with_email(&resources.database, &email).await?;
+    let tenant_id = resources.database.list_tenants_for_user(user_id).await?[0].id;
+
+    let export = export_user_data(
+        &resources,
+        user_id,
+        tenant_id,
+        &ExportOptions::default(),
+        None,
+    )
+    .await?;
+    assert_eq!(export.account.id, user_id);
+    assert!(export.activities.is_empty(), "no providers are connected");
+
+    let events = resources
+        .database
+        .get_audit_events(Some(tenant_id), Some("DataExported"), None)
+        .await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].user_id, Some(user_id));
+    assert_eq!(events[0].metadata["sections"]["goals"], 0);
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+// ABOUTME: Tests for soft-deleting users with a recovery window before the cascading hard delete
+// ABOUTME: Verifies deleted users are hidden, restorable within the window, and purged after it
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use chrono::{Duration, TimeZone, Utc};
+use pierre_mcp_server::config::UserDeletionConfig;
+use pierre_mcp_server::database_plugins::DatabaseProvider;
+use sqlx::Row;
+
+mod common;
+
+#[tokio::test]
+async fn test_soft_deleted_user_is_hidden_restorable_and_purged() {
+    let database = common::create_test_database().await.unwrap();
+    let (user_id, user) = common::create_test_user(&database).await.unwrap();
+    let config = UserDeletionConfig::with_recovery_window_days(30);
+    let deleted_at = Utc::now();
+
+    database
+        .soft_delete_user(user_id, deleted_at)
+        .await
+        .unwrap();
+
+    // Excluded from normal queries
+    assert!(database.get_user_global(user_id).await.unwrap().is_none());
+    assert!(database
+        .get_user_by_email(&user.email)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(database.get_user_count().await.unwrap(), 0);
+    assert!(database
+        .soft_delete_user(user_id, deleted_at)
+        .await
+        .is_err());
+
+    // Restorable within the window
+    let restored = database
+        .restore_deleted_user(user_id, config.purge_cutoff(Utc::now()))
+        .await
+        .unwrap();
+    assert_eq!(restored.id, user_id);
+    assert!(database.get_user_global(user_id).await.unwrap().is_some());
+    assert_eq!(database.get_user_count().await.unwrap(), 1);
+
+    // Not restorable once the window has passed
+    database
+        .soft_delete_user(user_id, deleted_at)
+        .await
+        .unwrap();
+    let after_window = deleted_at + config.recovery_window() + Duration::hours(1);
+    assert!(database
+        .restore_deleted_user(user_id, config.purge_cutoff(after_window))
+        .await
+        .is_err());
+
+    // Purge skips users still inside the window, then removes the row after it
+    assert_eq!(
+        database
+            .purge_deleted_users(config.purge_cutoff(Utc::now()))
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        database
+            .purge_deleted_users(config.purge_cutoff(after_window))
+            .await
+            .unwrap(),
+        1
+    );
+
+    let remaining: i64 = sqlx::query("SELECT COUNT(*) AS count FROM users WHERE id = ?1")
+        .bind(user_id.to_string())
+        .fetch_one(database.sqlite_pool().unwrap())
+        .await
+        .unwrap()
+        .get("count");
+    assert_eq!(remaining, 0);
+    assert!(database
+        .restore_deleted_user(user_id, deleted_at - Duration::days(1))
+        .await
+        .is_err());
+}
+
+#[test]
+fn test_recovery_window_math() {
+    let deleted_at = Utc.with_ymd_and_hms(2025, 6, 1, 8, 0, 0).unwrap();
+    let config = UserDeletionConfig::with_recovery_window_days(14);
+
+    assert!(config.soft_delete_enabled());
+    assert_eq!(
+        config.restorable_until(deleted_at),
+        Utc.with_ymd_and_hms(2025, 6, 15, 8, 0, 0).unwrap()
+    );
+    assert_eq!(
+        config.purge_cutoff(Utc.with_ymd_and_hms(2025, 6, 15, 8, 0, 0).unwrap()),
+        deleted_at
+    );
+
+    let immediate = UserDeletionConfig::with_recovery_window_days(0);
+    assert!(!immediate.soft_delete_enabled());
+    assert_eq!(immediate.purge_cutoff(deleted_at), deleted_at);
+}
@@ -0,0 +1,207 @@
+// ABOUTME: Reference tests for runtime-selectable VO2max estimation models
+// ABOUTME: Verifies each model against published example values and checks config-driven dispatch
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2025 Pierre Fitness Intelligence
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+#![allow(missing_docs)]
+
+use pierre_mcp_server::config::intelligence::AlgorithmConfig;
+use pierre_mcp_server::intelligence::algorithms::vo2max_estimator::{
+    HeartRateEffort, RaceResult, Vo2maxEstimationAlgorithm, Vo2maxEstimationInput,
+};
+use pierre_mcp_server::intelligence::analysis_config::ConfidenceLevel;
+
+fn races(results: &[(f64, f64)]) -> Vo2maxEstimationInput {
+    Vo2maxEstimationInput {
+        recent_races: results
+            .iter()
+            .map(|&(distance_meters, time_seconds)| RaceResult {
+                distance_meters,
+                time_seconds,
+            })
+            .collect(),
+        ..Vo2maxEstimationInput::default()
+    }
+}
+
+/// Cooper (1968): 2800 m in 12 minutes → (2800 - 504.9) / 44.73 ≈ 51.3 ml/kg/min
+#[test]
+fn test_cooper_reference_value() {
+    let estimate = Vo2maxEstimationAlgorithm::CooperTest
+        .estimator()
+        .estimate(&races(&[(2800.0, 720.0)]))
+        .unwrap();
+
+    assert!(
+        (estimate.vo2max - 51.3).abs() < 0.1,
+        "got {}",
+        estimate.vo2max
+    );
+    assert_eq!(estimate.confidence, ConfidenceLevel::High);
+    assert_eq!(estimate.algorithm, Vo2maxEstimationAlgorithm::CooperTest);
+}
+
+#[test]
+fn test_cooper_requires_twelve_minute_effort() {
+    let result = Vo2maxEstimationAlgorithm::CooperTest
+        .estimator()
+        .estimate(&races(&[(5000.0, 1200.0)]));
+    assert!(result.is_err());
+}
+
+/// Daniels' Running Formula tables, VDOT 50:
+/// 5K 19:57, 10K 41:21, half marathon 1:31:35, marathon 3:10:49
+#[test]
+fn test_daniels_vdot_reference_values() {
+    let estimator = Vo2maxEstimationAlgorithm::DanielsVdot.estimator();
+    for (distance, time) in [
+        (5_000.0, 1_197.0),
+        (10_000.0, 2_481.0),
+        (21_097.5, 5_495.0),
+        (42_195.0, 11_449.0),
+    ] {
+        let estimate = estimator.estimate(&races(&[(distance, time)])).unwrap();
+        assert!(
+            (estimate.vo2max - 50.0).abs() < 0.5,
+            "{distance}m in {time}s gave VDOT {}",
+            estimate.vo2max
+        );
+    }
+}
+
+#[test]
+fn test_daniels_vdot_uses_best_race_and_rewards_agreement() {
+    // 5K at VDOT 50 and a slower 10K at roughly VDOT 49
+    let estimate = Vo2maxEstimationAlgorithm::DanielsVdot
+        .estimator()
+        .estimate(&races(&[(10_000.0, 2_520.0), (5_000.0, 1_197.0)]))
+        .unwrap();
+
+    assert!((estimate.vo2max - 50.0).abs() < 0.5);
+    assert_eq!(estimate.confidence, ConfidenceLevel::VeryHigh);
+}
+
+/// Uth et al. (2004): VO2max = 15.3 × HRmax / HRrest; 190 / 50 bpm → 58.1 ml/kg/min
+#[test]
+fn test_heart_rate_ratio_reference_value() {
+    let input = Vo2maxEstimationInput {
+        resting_heart_rate: Some(50.0),
+        max_heart_rate: Some(190.0),
+        ..Vo2maxEstimationInput::default()
+    };
+    let estimate = Vo2maxEstimationAlgorithm::HeartRateRatio
+        .estimator()
+        .estimate(&input)
+        .unwrap();
+
+    assert!((estimate.vo2max - 58.14).abs() < 0.01);
+    assert_eq!(estimate.confidence, ConfidenceLevel::High);
+}
+
+#[test]
+fn test_heart_rate_ratio_predicts_max_hr_from_age() {
+    // Tanaka: 208 - 0.7 × 40 = 180 bpm → 15.3 × 180 / 60 = 45.9
+    let input = Vo2maxEstimationInput {
+        resting_heart_rate: Some(60.0),
+        age: Some(40),
+        ..Vo2maxEstimationInput::default()
+    };
+    let estimate = Vo2maxEstimationAlgorithm::HeartRateRatio
+        .estimator()
+        .estimate(&input)
+        .unwrap();
+
+    assert!((estimate.vo2max - 45.9).abs() < 0.01);
+    assert_eq!(estimate.confidence, ConfidenceLevel::Medium);
+}
+
+/// ACSM running equation: 10 km/h (166.7 m/min) costs 36.8 ml/kg/min; at 70% of
+/// heart rate reserve that extrapolates to 3.5 + 33.3 / 0.7 ≈ 51.1 ml/kg/min
+#[test]
+fn test_firstbeat_like_reference_value() {
+    // 50 bpm resting, 190 bpm max: 70% HRR = 148 bpm
+    let effort = HeartRateEffort {
+        speed_mps: 10_000.0 / 3_600.0,
+        average_heart_rate: 148.0,
+    };
+    let input = Vo2maxEstimationInput {
+        heart_rate_efforts: vec![effort; 3],
+        resting_heart_rate: Some(50.0),
+        max_heart_rate: Some(190.0),
+        ..Vo2maxEstimationInput::default()
+    };
+    let estimate = Vo2maxEstimationAlgorithm::FirstbeatLike
+        .estimator()
+        .estimate(&input)
+        .unwrap();
+
+    assert!(
+        (estimate.vo2max - 51.1).abs() < 0.1,
+        "got {}",
+        estimate.vo2max
+    );
+    assert_eq!(estimate.confidence, ConfidenceLevel::High);
+}
+
+#[test]
+fn test_firstbeat_like_ignores_efforts_outside_linear_range() {
+    let input = Vo2maxEstimationInput {
+        // 30% HRR is too easy for HR to track oxygen uptake
+        heart_rate_efforts: vec![HeartRateEffort {
+            speed_mps: 2.5,
+            average_heart_rate: 92.0,
+        }],
+        resting_heart_rate: Some(50.0),
+        max_heart_rate: Some(190.0),
+        ..Vo2maxEstimationInput::default()
+    };
+    assert!(Vo2maxEstimationAlgorithm::FirstbeatLike
+        .estimator()
+        .estimate(&input)
+        .is_err());
+}
+
+#[test]
+fn test_algorithm_parsing_and_config_default() {
+    assert_eq!(
+        "heart_rate_ratio"
+            .parse::<Vo2maxEstimationAlgorithm>()
+            .unwrap(),
+        Vo2maxEstimationAlgorithm::HeartRateRatio
+    );
+    assert_eq!(
+        "Firstbeat".parse::<Vo2maxEstimationAlgorithm>().unwrap(),
+        Vo2maxEstimationAlgorithm::FirstbeatLike
+    );
+    assert!("bogus".parse::<Vo2maxEstimationAlgorithm>().is_err());
+
+    assert_eq!(
+        AlgorithmConfig::default().vo2max,
+        Vo2maxEstimationAlgorithm::DanielsVdot
+    );
+
+    // Legacy string value still deserializes
+    let config: AlgorithmConfig = serde_json::from_str(r#"{"vo2max": "from_vdot"}"#).unwrap();
+    assert_eq!(config.vo2max, Vo2maxEstimationAlgorithm::DanielsVdot);
+}
+
+#[test]
+fn test_every_algorithm_dispatches_to_matching_estimator() {
+    for algorithm in [
+        Vo2maxEstimationAlgorithm::CooperTest,
+        Vo2maxEstimationAlgorithm::DanielsVdot,
+        Vo2maxEstimationAlgorithm::HeartRateRatio,
+        Vo2maxEstimationAlgorithm::FirstbeatLike,
+    ] {
+        assert_eq!(algorithm.estimator().algorithm(), algorithm);
+        assert_eq!(
+            algorithm
+                .name()
+                .parse::<Vo2maxEstimationAlgorithm>()
+                .unwrap(),
+            algorithm
+        );
+    }
+}
@@ -11,7 +11,9 @@ use chrono::Utc;
 use pierre_mcp_server::config::environment::HttpClientConfig;
 use pierre_mcp_server::constants::{init_server_config, oauth_providers};
 use pierre_mcp_server::models::SportType;
-use pierre_mcp_server::providers::core::{FitnessProvider, OAuth2Credentials, ProviderConfig};
+use pierre_mcp_server::providers::core::{
+    FitnessProvider, OAuth2Credentials, ProviderConfig, ProviderValidationMode,
+};
 use pierre_mcp_server::providers::registry::{get_supported_providers, global_registry};
 use pierre_mcp_server::providers::whoop_provider::WhoopProvider;
 use pierre_mcp_server::utils::http_client::initialize_http_clients;
@@ -53,6 +55,8 @@ fn test_whoop_provider_with_custom_config() {
         api_base_url: "https://custom.whoop.com/api".to_owned(),
         revoke_url: Some("https://custom.whoop.com/revoke".to_owned()),
         default_scopes: vec!["custom:scope".to_owned()],
+        request_timeout_secs: None,
+        validation_mode: ProviderValidationMode::Lenient,
     };
 
     let provider = WhoopProvider::with_config(custom_config.clone());